@@ -2,3 +2,15 @@
 rm *.class
 javac -source 8 -target 8 *.java
 jar --create --verbose --file classes.jar --main-class HelloWorld *.class
+
+# Signed jars for jar signature verification; keys are generated for each build
+jar --create --file signed.jar --main-class HelloWorld HelloWorld.class Simple.class
+cp signed.jar signed-ec.jar
+rm -f signing.p12
+keytool -genkeypair -keystore signing.p12 -storetype PKCS12 -storepass ristretto \
+  -alias rsa -keyalg RSA -keysize 2048 -dname "CN=Ristretto RSA" -validity 36500
+keytool -genkeypair -keystore signing.p12 -storetype PKCS12 -storepass ristretto \
+  -alias ec -keyalg EC -groupname secp256r1 -dname "CN=Ristretto EC" -validity 36500
+jarsigner -keystore signing.p12 -storepass ristretto signed.jar rsa
+jarsigner -keystore signing.p12 -storepass ristretto signed-ec.jar ec
+rm signing.p12
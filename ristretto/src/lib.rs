@@ -0,0 +1,118 @@
+//! # Ristretto
+//!
+//! [![Code Coverage](https://codecov.io/gh/theseus-rs/ristretto/branch/main/graph/badge.svg)](https://codecov.io/gh/theseus-rs/ristretto)
+//! [![Benchmarks](https://img.shields.io/badge/%F0%9F%90%B0_bencher-enabled-6ec241)](https://bencher.dev/perf/theseus-rs-ristretto)
+//! [![License](https://img.shields.io/crates/l/ristretto)](https://github.com/theseus-rs/ristretto#license)
+//! [![Semantic Versioning](https://img.shields.io/badge/%E2%9A%99%EF%B8%8F_SemVer-2.0.0-blue)](https://semver.org/spec/v2.0.0.html)
+//!
+//! ## Getting Started
+//!
+//! Facade for embedding ristretto; the [class file](classfile), [class loader](classloader) and
+//! [VM](vm) crates are re-exported at matching versions, and the types that embedders use most
+//! are available from the crate root and the [prelude].  The errors of the class file and class
+//! loader crates are named [`ClassFileError`] and [`ClassLoaderError`], matching the variants of
+//! the VM [`Error`] that they convert into.
+//!
+//! ```rust,no_run
+//! use ristretto::prelude::*;
+//!
+//! # async fn example() -> Result<()> {
+//! let configuration = ConfigurationBuilder::new()
+//!     .class_path(ClassPath::from("classes.jar"))
+//!     .main_class("HelloWorld")
+//!     .build()?;
+//! let vm = VM::new(configuration).await?;
+//! let parameters: Vec<&str> = Vec::new();
+//! vm.invoke_main(parameters).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Feature flags
+//!
+//! The features of the VM (`apple`, `awt`, `jfr`, `management`, `race-detector`, `security` and
+//! `sound`) and the class loader (`native-tls`, `rustls-tls`, `signed-jars` and `url`) are
+//! forwarded with the same names and defaults.
+//!
+//! ## Safety
+//!
+//! This crate uses `#![forbid(unsafe_code)]` to ensure everything is implemented in 100% safe Rust.
+
+#![forbid(unsafe_code)]
+#![forbid(clippy::allow_attributes)]
+#![deny(clippy::pedantic)]
+#![deny(clippy::unwrap_in_result)]
+#![deny(clippy::unwrap_used)]
+
+pub use ristretto_classfile as classfile;
+pub use ristretto_classloader as classloader;
+pub use ristretto_vm as vm;
+
+pub use ristretto_classfile::Error as ClassFileError;
+pub use ristretto_classfile::{
+    ClassAccessFlags, ClassFile, ClassFileBuilder, ConstantPool, FieldAccessFlags, FieldType,
+    MethodAccessFlags, Version,
+};
+pub use ristretto_classloader::Error as ClassLoaderError;
+pub use ristretto_classloader::{
+    Class, ClassLoader, ClassPath, ClassPathEntry, Object, Reference, Value, DEFAULT_JAVA_VERSION,
+};
+pub use ristretto_vm::{
+    Configuration, ConfigurationBuilder, Error, JavaError, JavaThrowable, MethodOverride,
+    Parameters, Result, RustValue, Thread, VM,
+};
+
+/// The types that are used to configure and run a VM; import them with
+/// `use ristretto::prelude::*`.
+pub mod prelude {
+    pub use crate::{
+        Class, ClassFile, ClassFileBuilder, ClassFileError, ClassLoader, ClassLoaderError,
+        ClassPath, Configuration, ConfigurationBuilder, Error, JavaError, Reference, Result, Value,
+        VM,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::prelude::*;
+    use std::path::PathBuf;
+
+    fn classes_directory() -> PathBuf {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        cargo_manifest.join("..").join("classes")
+    }
+
+    #[test]
+    fn test_configuration() -> Result<()> {
+        let class_path = ClassPath::from(classes_directory().to_string_lossy());
+        let configuration = ConfigurationBuilder::new()
+            .class_path(class_path.clone())
+            .main_class("HelloWorld")
+            .build()?;
+        assert_eq!(&class_path, configuration.class_path());
+        assert_eq!(Some(&"HelloWorld".to_string()), configuration.main_class());
+        Ok(())
+    }
+
+    #[test]
+    fn test_class_file_and_class() -> Result<()> {
+        let class_file = ClassFileBuilder::new("Foo").build()?;
+        let class = Class::from(class_file)?;
+        assert_eq!("Foo", class.name());
+        Ok(())
+    }
+
+    #[test]
+    fn test_errors() {
+        let error: Error = ClassFileError::InvalidClass("Foo".to_string()).into();
+        assert!(matches!(error, Error::ClassFileError(_)));
+        let error: Error = ClassLoaderError::ClassNotFound("Foo".to_string()).into();
+        assert!(matches!(error, Error::ClassLoaderError(_)));
+    }
+
+    #[test]
+    fn test_values() {
+        let value: crate::vm::Value = crate::classloader::Value::Int(42);
+        assert_eq!(Value::Int(42), value);
+    }
+}
@@ -0,0 +1,186 @@
+/* Generated with cbindgen; do not edit. Regenerate with:
+ * cbindgen --config cbindgen.toml --output include/ristretto.h */
+
+#ifndef RISTRETTO_H
+#define RISTRETTO_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Status returned by the C API functions that can fail.
+typedef enum {
+  // The function completed successfully
+  RISTRETTO_STATUS_OK = 0,
+  // A required pointer argument was null
+  RISTRETTO_STATUS_NULL_POINTER = 1,
+  // An argument was invalid; e.g. a string that is not valid UTF-8
+  RISTRETTO_STATUS_INVALID_ARGUMENT = 2,
+  // The VM returned an error
+  RISTRETTO_STATUS_VM_ERROR = 3,
+  // The function panicked
+  RISTRETTO_STATUS_PANIC = 4,
+} RistrettoStatus;
+
+// Type of a [`RistrettoValue`].
+typedef enum {
+  // `boolean`, `byte`, `char`, `short` or `int`
+  RISTRETTO_VALUE_TYPE_INT = 0,
+  // `long`
+  RISTRETTO_VALUE_TYPE_LONG = 1,
+  // `float`
+  RISTRETTO_VALUE_TYPE_FLOAT = 2,
+  // `double`
+  RISTRETTO_VALUE_TYPE_DOUBLE = 3,
+  // Non-null object or array reference
+  RISTRETTO_VALUE_TYPE_OBJECT = 4,
+  // Null reference
+  RISTRETTO_VALUE_TYPE_NULL = 5,
+} RistrettoValueType;
+
+// Opaque Java value; e.g. a method parameter or return value.
+typedef struct RistrettoValue RistrettoValue;
+
+// Opaque Java Virtual Machine.
+typedef struct RistrettoVm RistrettoVm;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Get the message for the last error on the calling thread, or null if no error has occurred.
+// The message is owned by the library and is valid until the next failing call on the thread.
+const char *ristretto_last_error(void);
+
+// Create an `int` value; the value must be freed with [`ristretto_value_free`].
+RistrettoValue *ristretto_value_new_int(int32_t value);
+
+// Create a `long` value; the value must be freed with [`ristretto_value_free`].
+RistrettoValue *ristretto_value_new_long(int64_t value);
+
+// Create a `float` value; the value must be freed with [`ristretto_value_free`].
+RistrettoValue *ristretto_value_new_float(float value);
+
+// Create a `double` value; the value must be freed with [`ristretto_value_free`].
+RistrettoValue *ristretto_value_new_double(double value);
+
+// Create a null reference; the value must be freed with [`ristretto_value_free`].
+RistrettoValue *ristretto_value_new_null(void);
+
+// Create a `java.lang.String` value from a UTF-8 string; the value must be freed with
+// [`ristretto_value_free`].  The string is created in the VM when the value is passed to
+// [`ristretto_vm_invoke`](crate::ristretto_vm_invoke).
+//
+// # Safety
+// `value` must point to a nul terminated string and `result` must point to writable memory.
+RistrettoStatus ristretto_value_new_string(const char *value, RistrettoValue **result);
+
+// Get the type of the value.
+//
+// # Safety
+// `value` must be a pointer returned by this library that has not been freed.
+RistrettoValueType ristretto_value_type(const RistrettoValue *value);
+
+// Get the value of an `int` value.
+//
+// # Safety
+// `value` must be a pointer returned by this library that has not been freed and `result` must
+// point to writable memory.
+RistrettoStatus ristretto_value_get_int(const RistrettoValue *value, int32_t *result);
+
+// Get the value of a `long` value.
+//
+// # Safety
+// `value` must be a pointer returned by this library that has not been freed and `result` must
+// point to writable memory.
+RistrettoStatus ristretto_value_get_long(const RistrettoValue *value, int64_t *result);
+
+// Get the value of a `float` value.
+//
+// # Safety
+// `value` must be a pointer returned by this library that has not been freed and `result` must
+// point to writable memory.
+RistrettoStatus ristretto_value_get_float(const RistrettoValue *value, float *result);
+
+// Get the value of a `double` value.
+//
+// # Safety
+// `value` must be a pointer returned by this library that has not been freed and `result` must
+// point to writable memory.
+RistrettoStatus ristretto_value_get_double(const RistrettoValue *value, double *result);
+
+// Get the UTF-8 value of a `java.lang.String` value; the string must be freed with
+// [`ristretto_string_free`].
+//
+// # Safety
+// `value` must be a pointer returned by this library that has not been freed and `result` must
+// point to writable memory.
+RistrettoStatus ristretto_value_get_string(const RistrettoValue *value, char **result);
+
+// Free a value; null is ignored.
+//
+// # Safety
+// `value` must be null or a pointer returned by this library that has not been freed.
+void ristretto_value_free(RistrettoValue *value);
+
+// Free a string returned by this library; null is ignored.
+//
+// # Safety
+// `value` must be null or a string returned by this library that has not been freed.
+void ristretto_string_free(char *value);
+
+// Create a VM; the VM must be freed with [`ristretto_vm_free`].
+//
+// - `class_path`: class path entries (directories, jars or URLs) separated by the platform path
+//   separator
+// - `main_class`: optional main class (e.g. `com.example.Main`); may be null
+// - `java_version`: optional Java runtime version (e.g. `21`); may be null to use the default
+//
+// # Safety
+// The string arguments must be null or point to nul terminated strings, and `result` must point
+// to writable memory.
+RistrettoStatus ristretto_vm_new(const char *class_path,
+                                 const char *main_class,
+                                 const char *java_version,
+                                 RistrettoVm **result);
+
+// Shut down and free a VM; null is ignored.  A panic while shutting down is recorded as the last
+// error.
+//
+// # Safety
+// `vm` must be null or a pointer returned by [`ristretto_vm_new`] that has not been freed.
+void ristretto_vm_free(RistrettoVm *vm);
+
+// Invoke `public static void main(String[] args)` of the main class of the VM.
+//
+// # Safety
+// `vm` must be a valid VM and `arguments` must be null (when `arguments_length` is 0) or point to
+// `arguments_length` nul terminated strings.
+RistrettoStatus ristretto_vm_invoke_main(const RistrettoVm *vm,
+                                         const char *const *arguments,
+                                         size_t arguments_length);
+
+// Invoke a method (e.g. `java.lang.Integer`, `parseInt`, `(Ljava/lang/String;)I`).  To invoke a
+// method on an object reference, the object reference must be the first parameter.  The return
+// value is written to `result` and must be freed with
+// [`ristretto_value_free`](crate::ristretto_value_free); null is written for `void` methods.
+//
+// # Safety
+// `vm` must be a valid VM, the string arguments must point to nul terminated strings,
+// `parameters` must be null (when `parameters_length` is 0) or point to `parameters_length`
+// valid values, and `result` must be null or point to writable memory.
+RistrettoStatus ristretto_vm_invoke(const RistrettoVm *vm,
+                                    const char *class_name,
+                                    const char *method_name,
+                                    const char *method_descriptor,
+                                    const RistrettoValue *const *parameters,
+                                    size_t parameters_length,
+                                    RistrettoValue **result);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* RISTRETTO_H */
@@ -0,0 +1,140 @@
+use std::cell::RefCell;
+use std::ffi::{c_char, CString};
+use std::panic::{catch_unwind, UnwindSafe};
+use std::ptr;
+
+/// Status returned by the C API functions that can fail.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RistrettoStatus {
+    /// The function completed successfully
+    Ok = 0,
+    /// A required pointer argument was null
+    NullPointer = 1,
+    /// An argument was invalid; e.g. a string that is not valid UTF-8
+    InvalidArgument = 2,
+    /// The VM returned an error
+    VmError = 3,
+    /// The function panicked
+    Panic = 4,
+}
+
+/// Error returned by the implementation of a C API function.
+#[derive(Debug)]
+pub(crate) struct Error {
+    status: RistrettoStatus,
+    message: String,
+}
+
+impl Error {
+    /// Create a new error.
+    pub(crate) fn new<S: Into<String>>(status: RistrettoStatus, message: S) -> Self {
+        Error {
+            status,
+            message: message.into(),
+        }
+    }
+
+    /// Create an error for a null pointer argument.
+    pub(crate) fn null_pointer(argument: &str) -> Self {
+        Error::new(
+            RistrettoStatus::NullPointer,
+            format!("{argument} must not be null"),
+        )
+    }
+
+    /// Create an error for an invalid argument.
+    pub(crate) fn invalid_argument<S: Into<String>>(message: S) -> Self {
+        Error::new(RistrettoStatus::InvalidArgument, message)
+    }
+}
+
+impl From<ristretto_vm::Error> for Error {
+    fn from(error: ristretto_vm::Error) -> Self {
+        Error::new(RistrettoStatus::VmError, error.to_string())
+    }
+}
+
+pub(crate) type Result<T> = std::result::Result<T, Error>;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Record the message for the last error on the calling thread.
+fn set_last_error(message: &str) {
+    // Interior nul bytes cannot be represented in a C string
+    let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = Some(message));
+}
+
+/// Run the implementation of a C API function; errors and panics are recorded as the last error
+/// and converted to a status.
+pub(crate) fn call<F>(function: F) -> RistrettoStatus
+where
+    F: FnOnce() -> Result<()> + UnwindSafe,
+{
+    match catch_unwind(function) {
+        Ok(Ok(())) => RistrettoStatus::Ok,
+        Ok(Err(error)) => {
+            set_last_error(&error.message);
+            error.status
+        }
+        Err(_) => {
+            set_last_error("panic in ristretto");
+            RistrettoStatus::Panic
+        }
+    }
+}
+
+/// Get the message for the last error on the calling thread, or null if no error has occurred.
+/// The message is owned by the library and is valid until the next failing call on the thread.
+#[must_use]
+#[no_mangle]
+pub extern "C" fn ristretto_last_error() -> *const c_char {
+    LAST_ERROR.with(|last_error| {
+        last_error
+            .borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    fn last_error() -> String {
+        let message = ristretto_last_error();
+        assert!(!message.is_null());
+        unsafe { CStr::from_ptr(message) }
+            .to_string_lossy()
+            .to_string()
+    }
+
+    #[test]
+    fn test_call_ok() {
+        assert_eq!(RistrettoStatus::Ok, call(|| Ok(())));
+    }
+
+    #[test]
+    fn test_call_error() {
+        let status = call(|| Err(Error::null_pointer("vm")));
+        assert_eq!(RistrettoStatus::NullPointer, status);
+        assert_eq!("vm must not be null", last_error());
+    }
+
+    #[test]
+    fn test_call_panic() {
+        let status = call(|| panic!("test"));
+        assert_eq!(RistrettoStatus::Panic, status);
+        assert_eq!("panic in ristretto", last_error());
+    }
+
+    #[test]
+    fn test_set_last_error_nul() {
+        set_last_error("a\0b");
+        assert_eq!("a b", last_error());
+    }
+}
@@ -0,0 +1,59 @@
+//! # Ristretto C API
+//!
+//! [![Code Coverage](https://codecov.io/gh/theseus-rs/ristretto/branch/main/graph/badge.svg)](https://codecov.io/gh/theseus-rs/ristretto)
+//! [![License](https://img.shields.io/crates/l/ristretto_capi)](https://github.com/theseus-rs/ristretto#license)
+//! [![Semantic Versioning](https://img.shields.io/badge/%E2%9A%99%EF%B8%8F_SemVer-2.0.0-blue)](https://semver.org/spec/v2.0.0.html)
+//!
+//! ## Getting Started
+//!
+//! C ABI for embedding the ristretto [VM](ristretto_vm::VM) in non-Rust hosts (e.g. C, C++ or
+//! Python via `ctypes`).  The crate builds a `cdylib` and a `staticlib`; the declarations are in
+//! `include/ristretto.h`, which is generated with
+//! [cbindgen](https://github.com/mozilla/cbindgen):
+//!
+//! ```shell
+//! cbindgen --config cbindgen.toml --output include/ristretto.h
+//! ```
+//!
+//! Functions that can fail return a [`RistrettoStatus`]; the message for the last error on the
+//! calling thread is available from [`ristretto_last_error`].  Objects returned to the host are
+//! owned by the host and must be released with the matching `*_free` function.
+//!
+//! ```c
+//! RistrettoVm *vm = NULL;
+//! if (ristretto_vm_new("classes.jar", "HelloWorld", NULL, &vm) != RISTRETTO_STATUS_OK) {
+//!     fprintf(stderr, "%s\n", ristretto_last_error());
+//!     return 1;
+//! }
+//! const char *args[] = {"world"};
+//! ristretto_vm_invoke_main(vm, args, 1);
+//! ristretto_vm_free(vm);
+//! ```
+//!
+//! ## Safety
+//!
+//! The C ABI requires `unsafe` code to dereference the pointers passed by the host; every unsafe
+//! function documents the requirements that the host must uphold.
+
+#![cfg(not(target_arch = "wasm32"))]
+#![forbid(clippy::allow_attributes)]
+#![deny(clippy::pedantic)]
+#![deny(clippy::unwrap_in_result)]
+#![deny(clippy::unwrap_used)]
+#![deny(unsafe_op_in_unsafe_fn)]
+
+mod error;
+mod value;
+mod vm;
+
+pub use error::{ristretto_last_error, RistrettoStatus};
+pub use value::{
+    ristretto_string_free, ristretto_value_free, ristretto_value_get_double,
+    ristretto_value_get_float, ristretto_value_get_int, ristretto_value_get_long,
+    ristretto_value_get_string, ristretto_value_new_double, ristretto_value_new_float,
+    ristretto_value_new_int, ristretto_value_new_long, ristretto_value_new_null,
+    ristretto_value_new_string, ristretto_value_type, RistrettoValue, RistrettoValueType,
+};
+pub use vm::{
+    ristretto_vm_free, ristretto_vm_invoke, ristretto_vm_invoke_main, ristretto_vm_new, RistrettoVm,
+};
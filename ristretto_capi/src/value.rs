@@ -0,0 +1,383 @@
+use crate::error::{call, Error, Result, RistrettoStatus};
+use ristretto_vm::{RustValue, Value};
+use std::ffi::{c_char, CStr, CString};
+use std::panic::AssertUnwindSafe;
+
+/// Opaque Java value; e.g. a method parameter or return value.
+#[derive(Debug)]
+pub struct RistrettoValue(pub(crate) Value);
+
+/// Type of a [`RistrettoValue`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RistrettoValueType {
+    /// `boolean`, `byte`, `char`, `short` or `int`
+    Int = 0,
+    /// `long`
+    Long = 1,
+    /// `float`
+    Float = 2,
+    /// `double`
+    Double = 3,
+    /// Non-null object or array reference
+    Object = 4,
+    /// Null reference
+    Null = 5,
+}
+
+/// Convert a C string argument to a Rust string slice.
+///
+/// # Safety
+/// `value` must be null or point to a nul terminated string that is valid for `'a`.
+pub(crate) unsafe fn to_str<'a>(value: *const c_char, argument: &str) -> Result<&'a str> {
+    if value.is_null() {
+        return Err(Error::null_pointer(argument));
+    }
+    // SAFETY: the caller guarantees that the pointer is a valid nul terminated string
+    let value = unsafe { CStr::from_ptr(value) };
+    value
+        .to_str()
+        .map_err(|_| Error::invalid_argument(format!("{argument} must be valid UTF-8")))
+}
+
+/// Convert a pointer to a value argument to a reference.
+///
+/// # Safety
+/// `value` must be null or a pointer returned by this library that has not been freed.
+pub(crate) unsafe fn to_value<'a>(value: *const RistrettoValue) -> Result<&'a Value> {
+    // SAFETY: the caller guarantees that the pointer is null or a valid value
+    match unsafe { value.as_ref() } {
+        Some(value) => Ok(&value.0),
+        None => Err(Error::null_pointer("value")),
+    }
+}
+
+/// Transfer ownership of a value to the host.
+pub(crate) fn into_raw(value: Value) -> *mut RistrettoValue {
+    Box::into_raw(Box::new(RistrettoValue(value)))
+}
+
+/// Create an `int` value; the value must be freed with [`ristretto_value_free`].
+#[must_use]
+#[no_mangle]
+pub extern "C" fn ristretto_value_new_int(value: i32) -> *mut RistrettoValue {
+    into_raw(Value::Int(value))
+}
+
+/// Create a `long` value; the value must be freed with [`ristretto_value_free`].
+#[must_use]
+#[no_mangle]
+pub extern "C" fn ristretto_value_new_long(value: i64) -> *mut RistrettoValue {
+    into_raw(Value::Long(value))
+}
+
+/// Create a `float` value; the value must be freed with [`ristretto_value_free`].
+#[must_use]
+#[no_mangle]
+pub extern "C" fn ristretto_value_new_float(value: f32) -> *mut RistrettoValue {
+    into_raw(Value::Float(value))
+}
+
+/// Create a `double` value; the value must be freed with [`ristretto_value_free`].
+#[must_use]
+#[no_mangle]
+pub extern "C" fn ristretto_value_new_double(value: f64) -> *mut RistrettoValue {
+    into_raw(Value::Double(value))
+}
+
+/// Create a null reference; the value must be freed with [`ristretto_value_free`].
+#[must_use]
+#[no_mangle]
+pub extern "C" fn ristretto_value_new_null() -> *mut RistrettoValue {
+    into_raw(Value::Object(None))
+}
+
+/// Create a `java.lang.String` value from a UTF-8 string; the value must be freed with
+/// [`ristretto_value_free`].  The string is created in the VM when the value is passed to
+/// [`ristretto_vm_invoke`](crate::ristretto_vm_invoke).
+///
+/// # Safety
+/// `value` must point to a nul terminated string and `result` must point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn ristretto_value_new_string(
+    value: *const c_char,
+    result: *mut *mut RistrettoValue,
+) -> RistrettoStatus {
+    call(AssertUnwindSafe(|| {
+        if result.is_null() {
+            return Err(Error::null_pointer("result"));
+        }
+        // SAFETY: the caller guarantees that the value is a valid string
+        let value = unsafe { to_str(value, "value") }?;
+        // SAFETY: the result pointer was checked for null and the caller guarantees it is writable
+        unsafe { *result = into_raw(value.to_value()) };
+        Ok(())
+    }))
+}
+
+/// Get the type of the value.
+///
+/// # Safety
+/// `value` must be a pointer returned by this library that has not been freed.
+#[must_use]
+#[no_mangle]
+pub unsafe extern "C" fn ristretto_value_type(value: *const RistrettoValue) -> RistrettoValueType {
+    // SAFETY: the caller guarantees that the value is valid
+    match unsafe { value.as_ref() }.map(|value| &value.0) {
+        Some(Value::Int(_)) => RistrettoValueType::Int,
+        Some(Value::Long(_)) => RistrettoValueType::Long,
+        Some(Value::Float(_)) => RistrettoValueType::Float,
+        Some(Value::Double(_)) => RistrettoValueType::Double,
+        Some(Value::Object(Some(_))) => RistrettoValueType::Object,
+        Some(Value::Object(None) | Value::Unused) | None => RistrettoValueType::Null,
+    }
+}
+
+/// Read a primitive value into `result`.
+///
+/// # Safety
+/// `value` must be a valid value and `result` must point to writable memory.
+unsafe fn get<T>(
+    value: *const RistrettoValue,
+    result: *mut T,
+    function: fn(&Value) -> ristretto_vm::Result<T>,
+) -> RistrettoStatus {
+    call(AssertUnwindSafe(|| {
+        if result.is_null() {
+            return Err(Error::null_pointer("result"));
+        }
+        // SAFETY: the caller guarantees that the value is valid
+        let value = unsafe { to_value(value) }?;
+        let value = function(value).map_err(|error| Error::invalid_argument(error.to_string()))?;
+        // SAFETY: the result pointer was checked for null and the caller guarantees it is writable
+        unsafe { *result = value };
+        Ok(())
+    }))
+}
+
+/// Get the value of an `int` value.
+///
+/// # Safety
+/// `value` must be a pointer returned by this library that has not been freed and `result` must
+/// point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn ristretto_value_get_int(
+    value: *const RistrettoValue,
+    result: *mut i32,
+) -> RistrettoStatus {
+    // SAFETY: the caller upholds the requirements of this function
+    unsafe { get(value, result, |value| Ok(value.to_int()?)) }
+}
+
+/// Get the value of a `long` value.
+///
+/// # Safety
+/// `value` must be a pointer returned by this library that has not been freed and `result` must
+/// point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn ristretto_value_get_long(
+    value: *const RistrettoValue,
+    result: *mut i64,
+) -> RistrettoStatus {
+    // SAFETY: the caller upholds the requirements of this function
+    unsafe { get(value, result, |value| Ok(value.to_long()?)) }
+}
+
+/// Get the value of a `float` value.
+///
+/// # Safety
+/// `value` must be a pointer returned by this library that has not been freed and `result` must
+/// point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn ristretto_value_get_float(
+    value: *const RistrettoValue,
+    result: *mut f32,
+) -> RistrettoStatus {
+    // SAFETY: the caller upholds the requirements of this function
+    unsafe { get(value, result, |value| Ok(value.to_float()?)) }
+}
+
+/// Get the value of a `double` value.
+///
+/// # Safety
+/// `value` must be a pointer returned by this library that has not been freed and `result` must
+/// point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn ristretto_value_get_double(
+    value: *const RistrettoValue,
+    result: *mut f64,
+) -> RistrettoStatus {
+    // SAFETY: the caller upholds the requirements of this function
+    unsafe { get(value, result, |value| Ok(value.to_double()?)) }
+}
+
+/// Get the UTF-8 value of a `java.lang.String` value; the string must be freed with
+/// [`ristretto_string_free`].
+///
+/// # Safety
+/// `value` must be a pointer returned by this library that has not been freed and `result` must
+/// point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn ristretto_value_get_string(
+    value: *const RistrettoValue,
+    result: *mut *mut c_char,
+) -> RistrettoStatus {
+    call(AssertUnwindSafe(|| {
+        if result.is_null() {
+            return Err(Error::null_pointer("result"));
+        }
+        // SAFETY: the caller guarantees that the value is valid
+        let value = unsafe { to_value(value) }?;
+        let string: String = TryInto::<String>::try_into(value.clone())
+            .map_err(|error| Error::invalid_argument(error.to_string()))?;
+        let string = CString::new(string)
+            .map_err(|_| Error::invalid_argument("string contains a nul character"))?;
+        // SAFETY: the result pointer was checked for null and the caller guarantees it is writable
+        unsafe { *result = string.into_raw() };
+        Ok(())
+    }))
+}
+
+/// Free a value; null is ignored.
+///
+/// # Safety
+/// `value` must be null or a pointer returned by this library that has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn ristretto_value_free(value: *mut RistrettoValue) {
+    if !value.is_null() {
+        // SAFETY: the caller guarantees the value was created by this library and not yet freed
+        drop(unsafe { Box::from_raw(value) });
+    }
+}
+
+/// Free a string returned by this library; null is ignored.
+///
+/// # Safety
+/// `value` must be null or a string returned by this library that has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn ristretto_string_free(value: *mut c_char) {
+    if !value.is_null() {
+        // SAFETY: the caller guarantees the string was created by this library and not yet freed
+        drop(unsafe { CString::from_raw(value) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr;
+
+    #[test]
+    fn test_int() {
+        let value = ristretto_value_new_int(42);
+        let mut result = 0;
+        unsafe {
+            assert_eq!(RistrettoValueType::Int, ristretto_value_type(value));
+            assert_eq!(
+                RistrettoStatus::Ok,
+                ristretto_value_get_int(value, &raw mut result)
+            );
+            assert_eq!(42, result);
+            ristretto_value_free(value);
+        }
+    }
+
+    #[test]
+    fn test_long() {
+        let value = ristretto_value_new_long(42);
+        let mut result = 0;
+        unsafe {
+            assert_eq!(RistrettoValueType::Long, ristretto_value_type(value));
+            assert_eq!(
+                RistrettoStatus::Ok,
+                ristretto_value_get_long(value, &raw mut result)
+            );
+            assert_eq!(42, result);
+            ristretto_value_free(value);
+        }
+    }
+
+    #[test]
+    fn test_float() {
+        let value = ristretto_value_new_float(42.1);
+        let mut result = 0.0;
+        unsafe {
+            assert_eq!(RistrettoValueType::Float, ristretto_value_type(value));
+            assert_eq!(
+                RistrettoStatus::Ok,
+                ristretto_value_get_float(value, &raw mut result)
+            );
+            assert!((42.1 - result).abs() < 0.1);
+            ristretto_value_free(value);
+        }
+    }
+
+    #[test]
+    fn test_double() {
+        let value = ristretto_value_new_double(42.1);
+        let mut result = 0.0;
+        unsafe {
+            assert_eq!(RistrettoValueType::Double, ristretto_value_type(value));
+            assert_eq!(
+                RistrettoStatus::Ok,
+                ristretto_value_get_double(value, &raw mut result)
+            );
+            assert!((42.1 - result).abs() < 0.1);
+            ristretto_value_free(value);
+        }
+    }
+
+    #[test]
+    fn test_null() {
+        let value = ristretto_value_new_null();
+        unsafe {
+            assert_eq!(RistrettoValueType::Null, ristretto_value_type(value));
+            assert_eq!(RistrettoValueType::Null, ristretto_value_type(ptr::null()));
+            ristretto_value_free(value);
+            ristretto_value_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn test_get_wrong_type() {
+        let value = ristretto_value_new_long(42);
+        let mut result = 0;
+        unsafe {
+            assert_eq!(
+                RistrettoStatus::InvalidArgument,
+                ristretto_value_get_int(value, &raw mut result)
+            );
+            ristretto_value_free(value);
+        }
+    }
+
+    #[test]
+    fn test_get_null_pointer() {
+        let value = ristretto_value_new_int(42);
+        unsafe {
+            assert_eq!(
+                RistrettoStatus::NullPointer,
+                ristretto_value_get_int(value, ptr::null_mut())
+            );
+            let mut result = 0;
+            assert_eq!(
+                RistrettoStatus::NullPointer,
+                ristretto_value_get_int(ptr::null(), &raw mut result)
+            );
+            ristretto_value_free(value);
+        }
+    }
+
+    #[test]
+    fn test_new_string_invalid_utf8() {
+        let invalid = [0xffu8, 0];
+        let mut value = ptr::null_mut();
+        unsafe {
+            assert_eq!(
+                RistrettoStatus::InvalidArgument,
+                ristretto_value_new_string(invalid.as_ptr().cast(), &raw mut value)
+            );
+        }
+        assert!(value.is_null());
+    }
+}
@@ -0,0 +1,315 @@
+use crate::error::{call, Error, Result, RistrettoStatus};
+use crate::value::{into_raw, to_str, to_value, RistrettoValue};
+use ristretto_vm::blocking::VM;
+use ristretto_vm::{ClassPath, ConfigurationBuilder, Value};
+use std::ffi::c_char;
+use std::panic::AssertUnwindSafe;
+use std::slice;
+
+/// Opaque Java Virtual Machine.
+#[derive(Debug)]
+pub struct RistrettoVm(VM);
+
+/// Get a reference to the VM argument.
+///
+/// # Safety
+/// `vm` must be null or a pointer returned by [`ristretto_vm_new`] that has not been freed.
+unsafe fn to_vm<'a>(vm: *const RistrettoVm) -> Result<&'a VM> {
+    // SAFETY: the caller guarantees that the pointer is null or a valid VM
+    match unsafe { vm.as_ref() } {
+        Some(vm) => Ok(&vm.0),
+        None => Err(Error::null_pointer("vm")),
+    }
+}
+
+/// Get the slice for an array argument; a null array is only valid when the length is 0.
+///
+/// # Safety
+/// `array` must be null or point to `length` initialized elements.
+unsafe fn to_slice<'a, T>(array: *const T, length: usize, argument: &str) -> Result<&'a [T]> {
+    if length == 0 {
+        return Ok(&[]);
+    }
+    if array.is_null() {
+        return Err(Error::null_pointer(argument));
+    }
+    // SAFETY: the caller guarantees that the array contains `length` elements
+    Ok(unsafe { slice::from_raw_parts(array, length) })
+}
+
+/// Create a VM; the VM must be freed with [`ristretto_vm_free`].
+///
+/// - `class_path`: class path entries (directories, jars or URLs) separated by the platform path
+///   separator
+/// - `main_class`: optional main class (e.g. `com.example.Main`); may be null
+/// - `java_version`: optional Java runtime version (e.g. `21`); may be null to use the default
+///
+/// # Safety
+/// The string arguments must be null or point to nul terminated strings, and `result` must point
+/// to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn ristretto_vm_new(
+    class_path: *const c_char,
+    main_class: *const c_char,
+    java_version: *const c_char,
+    result: *mut *mut RistrettoVm,
+) -> RistrettoStatus {
+    call(AssertUnwindSafe(|| {
+        if result.is_null() {
+            return Err(Error::null_pointer("result"));
+        }
+        // SAFETY: the caller guarantees that the strings are valid
+        let class_path = unsafe { to_str(class_path, "class_path") }?;
+        let mut builder = ConfigurationBuilder::new().class_path(ClassPath::from(class_path));
+        if !main_class.is_null() {
+            // SAFETY: the caller guarantees that the strings are valid
+            builder = builder.main_class(unsafe { to_str(main_class, "main_class") }?);
+        }
+        if !java_version.is_null() {
+            // SAFETY: the caller guarantees that the strings are valid
+            builder = builder.java_version(unsafe { to_str(java_version, "java_version") }?);
+        }
+        let vm = VM::new(builder.build()?)?;
+        // SAFETY: the result pointer was checked for null and the caller guarantees it is writable
+        unsafe { *result = Box::into_raw(Box::new(RistrettoVm(vm))) };
+        Ok(())
+    }))
+}
+
+/// Shut down and free a VM; null is ignored.  A panic while shutting down is recorded as the last
+/// error.
+///
+/// # Safety
+/// `vm` must be null or a pointer returned by [`ristretto_vm_new`] that has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn ristretto_vm_free(vm: *mut RistrettoVm) {
+    if vm.is_null() {
+        return;
+    }
+    // SAFETY: the caller guarantees the VM was created by this library and not yet freed
+    let vm = unsafe { Box::from_raw(vm) };
+    let _ = call(AssertUnwindSafe(move || {
+        vm.0.shutdown();
+        Ok(())
+    }));
+}
+
+/// Invoke `public static void main(String[] args)` of the main class of the VM.
+///
+/// # Safety
+/// `vm` must be a valid VM and `arguments` must be null (when `arguments_length` is 0) or point to
+/// `arguments_length` nul terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn ristretto_vm_invoke_main(
+    vm: *const RistrettoVm,
+    arguments: *const *const c_char,
+    arguments_length: usize,
+) -> RistrettoStatus {
+    call(AssertUnwindSafe(|| {
+        // SAFETY: the caller guarantees that the VM is valid
+        let vm = unsafe { to_vm(vm) }?;
+        // SAFETY: the caller guarantees that the array contains `arguments_length` strings
+        let arguments = unsafe { to_slice(arguments, arguments_length, "arguments") }?;
+        let arguments = arguments
+            .iter()
+            // SAFETY: the caller guarantees that the strings are valid
+            .map(|argument| unsafe { to_str(*argument, "argument") })
+            .collect::<Result<Vec<&str>>>()?;
+        vm.invoke_main(arguments)?;
+        Ok(())
+    }))
+}
+
+/// Invoke a method (e.g. `java.lang.Integer`, `parseInt`, `(Ljava/lang/String;)I`).  To invoke a
+/// method on an object reference, the object reference must be the first parameter.  The return
+/// value is written to `result` and must be freed with
+/// [`ristretto_value_free`](crate::ristretto_value_free); null is written for `void` methods.
+///
+/// # Safety
+/// `vm` must be a valid VM, the string arguments must point to nul terminated strings,
+/// `parameters` must be null (when `parameters_length` is 0) or point to `parameters_length`
+/// valid values, and `result` must be null or point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn ristretto_vm_invoke(
+    vm: *const RistrettoVm,
+    class_name: *const c_char,
+    method_name: *const c_char,
+    method_descriptor: *const c_char,
+    parameters: *const *const RistrettoValue,
+    parameters_length: usize,
+    result: *mut *mut RistrettoValue,
+) -> RistrettoStatus {
+    call(AssertUnwindSafe(|| {
+        // SAFETY: the caller guarantees that the arguments are valid
+        let vm = unsafe { to_vm(vm) }?;
+        // SAFETY: the caller guarantees that the arguments are valid
+        let class_name = unsafe { to_str(class_name, "class_name") }?;
+        // SAFETY: the caller guarantees that the arguments are valid
+        let method_name = unsafe { to_str(method_name, "method_name") }?;
+        // SAFETY: the caller guarantees that the arguments are valid
+        let method_descriptor = unsafe { to_str(method_descriptor, "method_descriptor") }?;
+        // SAFETY: the caller guarantees that the array contains `parameters_length` values
+        let parameters = unsafe { to_slice(parameters, parameters_length, "parameters") }?;
+        let parameters = parameters
+            .iter()
+            // SAFETY: the caller guarantees that the values are valid
+            .map(|parameter| unsafe { to_value(*parameter) }.cloned())
+            .collect::<Result<Vec<Value>>>()?;
+
+        let value = vm.invoke(class_name, method_name, method_descriptor, parameters)?;
+        if !result.is_null() {
+            let value = value.map_or(std::ptr::null_mut(), into_raw);
+            // SAFETY: the result pointer was checked for null and the caller guarantees it is
+            // writable
+            unsafe { *result = value };
+        }
+        Ok(())
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ristretto_string_free, ristretto_value_free, ristretto_value_get_int,
+        ristretto_value_get_string, ristretto_value_new_string,
+    };
+    use std::ffi::{CStr, CString};
+    use std::path::PathBuf;
+    use std::ptr;
+
+    fn test_vm() -> *mut RistrettoVm {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_jar_path = cargo_manifest
+            .join("..")
+            .join("classes")
+            .join("classes.jar");
+        let class_path =
+            CString::new(classes_jar_path.to_string_lossy().to_string()).expect("class path");
+        let main_class = CString::new("HelloWorld").expect("main class");
+        let mut vm = ptr::null_mut();
+        let status = unsafe {
+            ristretto_vm_new(
+                class_path.as_ptr(),
+                main_class.as_ptr(),
+                ptr::null(),
+                &raw mut vm,
+            )
+        };
+        assert_eq!(RistrettoStatus::Ok, status);
+        assert!(!vm.is_null());
+        vm
+    }
+
+    #[test]
+    fn test_vm_new_null_class_path() {
+        let mut vm = ptr::null_mut();
+        let status =
+            unsafe { ristretto_vm_new(ptr::null(), ptr::null(), ptr::null(), &raw mut vm) };
+        assert_eq!(RistrettoStatus::NullPointer, status);
+        assert!(vm.is_null());
+        unsafe { ristretto_vm_free(vm) };
+    }
+
+    #[test]
+    fn test_invoke_main() {
+        let vm = test_vm();
+        let argument = CString::new("world").expect("argument");
+        let arguments = [argument.as_ptr()];
+        unsafe {
+            let status = ristretto_vm_invoke_main(vm, arguments.as_ptr(), arguments.len());
+            assert_eq!(RistrettoStatus::Ok, status);
+            ristretto_vm_free(vm);
+        }
+    }
+
+    #[test]
+    fn test_invoke() {
+        let vm = test_vm();
+        let class_name = CString::new("java.lang.Integer").expect("class");
+        let method_name = CString::new("parseInt").expect("method");
+        let descriptor = CString::new("(Ljava/lang/String;)I").expect("descriptor");
+        let string = CString::new("42").expect("string");
+        unsafe {
+            let mut parameter = ptr::null_mut();
+            let status = ristretto_value_new_string(string.as_ptr(), &raw mut parameter);
+            assert_eq!(RistrettoStatus::Ok, status);
+            let parameters = [parameter.cast_const()];
+            let mut result = ptr::null_mut();
+            let status = ristretto_vm_invoke(
+                vm,
+                class_name.as_ptr(),
+                method_name.as_ptr(),
+                descriptor.as_ptr(),
+                parameters.as_ptr(),
+                parameters.len(),
+                &raw mut result,
+            );
+            assert_eq!(RistrettoStatus::Ok, status);
+            let mut value = 0;
+            assert_eq!(
+                RistrettoStatus::Ok,
+                ristretto_value_get_int(result, &raw mut value)
+            );
+            assert_eq!(42, value);
+            ristretto_value_free(result);
+            ristretto_value_free(parameter);
+            ristretto_vm_free(vm);
+        }
+    }
+
+    #[test]
+    fn test_invoke_string_result() {
+        let vm = test_vm();
+        let class_name = CString::new("java.lang.Integer").expect("class");
+        let method_name = CString::new("toString").expect("method");
+        let descriptor = CString::new("(I)Ljava/lang/String;").expect("descriptor");
+        unsafe {
+            let parameter = crate::ristretto_value_new_int(42);
+            let parameters = [parameter.cast_const()];
+            let mut result = ptr::null_mut();
+            let status = ristretto_vm_invoke(
+                vm,
+                class_name.as_ptr(),
+                method_name.as_ptr(),
+                descriptor.as_ptr(),
+                parameters.as_ptr(),
+                parameters.len(),
+                &raw mut result,
+            );
+            assert_eq!(RistrettoStatus::Ok, status);
+            let mut string = ptr::null_mut();
+            assert_eq!(
+                RistrettoStatus::Ok,
+                ristretto_value_get_string(result, &raw mut string)
+            );
+            assert_eq!("42", CStr::from_ptr(string).to_string_lossy());
+            ristretto_string_free(string);
+            ristretto_value_free(result);
+            ristretto_value_free(parameter);
+            ristretto_vm_free(vm);
+        }
+    }
+
+    #[test]
+    fn test_invoke_method_not_found() {
+        let vm = test_vm();
+        let class_name = CString::new("java.lang.Integer").expect("class");
+        let method_name = CString::new("foo").expect("method");
+        let descriptor = CString::new("()V").expect("descriptor");
+        unsafe {
+            let status = ristretto_vm_invoke(
+                vm,
+                class_name.as_ptr(),
+                method_name.as_ptr(),
+                descriptor.as_ptr(),
+                ptr::null(),
+                0,
+                ptr::null_mut(),
+            );
+            assert_eq!(RistrettoStatus::VmError, status);
+            assert!(!crate::ristretto_last_error().is_null());
+            ristretto_vm_free(vm);
+        }
+    }
+}
@@ -1,11 +1,15 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use ristretto_classfile::{ClassFile, Result};
-use std::io::Cursor;
+use ristretto_classfile::{ClassFile, Error, Result};
+use std::io::{Cursor, Read};
+use std::{env, fs};
+use zip::ZipArchive;
 
 const CLASS_BYTES: &[u8] = include_bytes!("../../classes/Simple.class");
+const JAR_BYTES: &[u8] = include_bytes!("../../classes/classes.jar");
 
 fn benchmarks(criterion: &mut Criterion) {
     bench_lifecycle(criterion).ok();
+    bench_corpus(criterion).ok();
 }
 
 fn bench_lifecycle(criterion: &mut Criterion) -> Result<()> {
@@ -15,6 +19,11 @@ fn bench_lifecycle(criterion: &mut Criterion) -> Result<()> {
             from_bytes().ok();
         });
     });
+    criterion.bench_function("from_bytes_lazy", |bencher| {
+        bencher.iter(|| {
+            from_bytes_lazy().ok();
+        });
+    });
     criterion.bench_function("to_bytes", |bencher| {
         bencher.iter(|| {
             to_bytes(&class_file).ok();
@@ -40,6 +49,55 @@ fn from_bytes() -> Result<()> {
     Ok(())
 }
 
+fn from_bytes_lazy() -> Result<()> {
+    let mut original_bytes = Cursor::new(CLASS_BYTES.to_vec());
+    let _class_file = ClassFile::from_bytes_lazy(&mut original_bytes)?;
+    Ok(())
+}
+
+/// Benchmark parsing every class in a jar; set `RISTRETTO_BENCH_JAR` to the path of a large jar
+/// (e.g. the `rt.jar` of a Java 8 runtime) to benchmark a runtime sized corpus.
+fn bench_corpus(criterion: &mut Criterion) -> Result<()> {
+    let classes = corpus_classes()?;
+    criterion.bench_function("corpus_from_bytes", |bencher| {
+        bencher.iter(|| {
+            for class_bytes in &classes {
+                ClassFile::from_bytes(&mut Cursor::new(class_bytes.clone())).ok();
+            }
+        });
+    });
+    criterion.bench_function("corpus_from_bytes_lazy", |bencher| {
+        bencher.iter(|| {
+            for class_bytes in &classes {
+                ClassFile::from_bytes_lazy(&mut Cursor::new(class_bytes.clone())).ok();
+            }
+        });
+    });
+    Ok(())
+}
+
+fn corpus_classes() -> Result<Vec<Vec<u8>>> {
+    let jar_bytes = match env::var("RISTRETTO_BENCH_JAR") {
+        Ok(path) => fs::read(path)?,
+        Err(_) => JAR_BYTES.to_vec(),
+    };
+    let mut archive = ZipArchive::new(Cursor::new(jar_bytes))
+        .map_err(|error| Error::IoError(error.to_string()))?;
+    let mut classes = Vec::new();
+    for index in 0..archive.len() {
+        let mut file = archive
+            .by_index(index)
+            .map_err(|error| Error::IoError(error.to_string()))?;
+        if !file.name().ends_with(".class") {
+            continue;
+        }
+        let mut class_bytes = Vec::new();
+        file.read_to_end(&mut class_bytes)?;
+        classes.push(class_bytes);
+    }
+    Ok(classes)
+}
+
 fn to_bytes(class_file: &ClassFile) -> Result<()> {
     class_file.to_bytes(&mut Vec::new())?;
     Ok(())
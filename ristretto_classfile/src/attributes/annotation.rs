@@ -1,4 +1,6 @@
 use crate::attributes::annotation_value_pair::AnnotationValuePair;
+use crate::attributes::{AnnotationValue, Attribute};
+use crate::constant_pool::ConstantPool;
 use crate::error::Result;
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 use std::fmt;
@@ -8,12 +10,64 @@ use std::io::Cursor;
 ///
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.16>
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Annotation {
     pub type_index: u16,
     pub elements: Vec<AnnotationValuePair>,
 }
 
 impl Annotation {
+    /// Get the type of the annotation as a field descriptor (e.g. `Ljava/lang/Deprecated;`).
+    ///
+    /// # Errors
+    /// If the type index does not reference a UTF-8 constant.
+    pub fn type_name<'a>(&self, constant_pool: &'a ConstantPool) -> Result<&'a String> {
+        constant_pool.try_get_utf8(self.type_index)
+    }
+
+    /// Get the resolved value of the named element of the annotation.  Returns `None` if the
+    /// annotation does not define a value for the element; e.g. when the element uses its default
+    /// value.
+    ///
+    /// # Errors
+    /// If a constant pool index of the annotation is invalid.
+    pub fn value<S: AsRef<str>>(
+        &self,
+        constant_pool: &ConstantPool,
+        name: S,
+    ) -> Result<Option<AnnotationValue>> {
+        let name = name.as_ref();
+        for element in &self.elements {
+            if constant_pool.try_get_utf8(element.name_index)? == name {
+                let value = AnnotationValue::from_element(constant_pool, &element.value)?;
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Get the runtime visible annotations defined by the attributes of a class, field or method;
+    /// lazy `RuntimeVisibleAnnotations` attributes are decoded.
+    pub(crate) fn runtime_visible(
+        constant_pool: &ConstantPool,
+        attributes: &[Attribute],
+    ) -> Result<Vec<Annotation>> {
+        let mut runtime_annotations = Vec::new();
+        for attribute in attributes {
+            if let Attribute::Lazy { name_index, .. } = attribute {
+                if constant_pool.try_get_utf8(*name_index)? != "RuntimeVisibleAnnotations" {
+                    continue;
+                }
+            }
+            if let Attribute::RuntimeVisibleAnnotations { annotations, .. } =
+                attribute.decode(constant_pool)?.as_ref()
+            {
+                runtime_annotations.extend(annotations.iter().cloned());
+            }
+        }
+        Ok(runtime_annotations)
+    }
+
     /// Deserialize the annotation from bytes.
     ///
     /// # Errors
@@ -106,4 +160,68 @@ mod test {
         assert_eq!(annotation, Annotation::from_bytes(&mut bytes)?);
         Ok(())
     }
+
+    #[test]
+    fn test_value() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let type_index = constant_pool.add_utf8("Ljava/lang/annotation/Retention;")?;
+        let name_index = constant_pool.add_utf8("value")?;
+        let enum_type_index = constant_pool.add_utf8("Ljava/lang/annotation/RetentionPolicy;")?;
+        let const_name_index = constant_pool.add_utf8("RUNTIME")?;
+        let annotation = Annotation {
+            type_index,
+            elements: vec![AnnotationValuePair {
+                name_index,
+                value: AnnotationElement::Enum {
+                    type_name_index: enum_type_index,
+                    const_name_index,
+                },
+            }],
+        };
+
+        assert_eq!(
+            "Ljava/lang/annotation/Retention;",
+            annotation.type_name(&constant_pool)?
+        );
+        assert_eq!(
+            Some(AnnotationValue::Enum {
+                type_name: "Ljava/lang/annotation/RetentionPolicy;".to_string(),
+                const_name: "RUNTIME".to_string(),
+            }),
+            annotation.value(&constant_pool, "value")?
+        );
+        assert_eq!(None, annotation.value(&constant_pool, "foo")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_runtime_visible() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let visible_index = constant_pool.add_utf8("RuntimeVisibleAnnotations")?;
+        let invisible_index = constant_pool.add_utf8("RuntimeInvisibleAnnotations")?;
+        let visible = Annotation {
+            type_index: constant_pool.add_utf8("LVisible;")?,
+            elements: vec![],
+        };
+        let invisible = Annotation {
+            type_index: constant_pool.add_utf8("LInvisible;")?,
+            elements: vec![],
+        };
+        let attributes = vec![
+            Attribute::RuntimeVisibleAnnotations {
+                name_index: visible_index,
+                annotations: vec![visible.clone()],
+            },
+            Attribute::RuntimeInvisibleAnnotations {
+                name_index: invisible_index,
+                annotations: vec![invisible],
+            },
+        ];
+
+        assert_eq!(
+            vec![visible],
+            Annotation::runtime_visible(&constant_pool, &attributes)?
+        );
+        Ok(())
+    }
 }
@@ -9,6 +9,7 @@ use std::io::Cursor;
 ///
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.16>
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AnnotationElement {
     Byte {
         const_value_index: u16,
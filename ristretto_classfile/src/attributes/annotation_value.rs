@@ -0,0 +1,263 @@
+use crate::attributes::{Annotation, AnnotationElement};
+use crate::constant_pool::ConstantPool;
+use crate::error::Result;
+use std::fmt;
+
+/// Value of an annotation element with constant pool references resolved.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.16.1>
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnnotationValue {
+    Byte(i8),
+    Char(char),
+    Double(f64),
+    Float(f32),
+    Int(i32),
+    Long(i64),
+    Short(i16),
+    Boolean(bool),
+    String(String),
+    /// Enum constant; the type name is a field descriptor (e.g. `Ljava/lang/annotation/ElementType;`)
+    Enum {
+        type_name: String,
+        const_name: String,
+    },
+    /// Class literal; the class name is a return descriptor (e.g. `Ljava/lang/String;` or `V`)
+    Class(String),
+    /// Nested annotation; values of the annotation are resolved with [`Annotation::value`]
+    Annotation(Annotation),
+    Array(Vec<AnnotationValue>),
+}
+
+impl AnnotationValue {
+    /// Resolve the value of an annotation element.
+    ///
+    /// # Errors
+    /// If a constant pool index is invalid or does not reference a constant of the expected type.
+    pub fn from_element(
+        constant_pool: &ConstantPool,
+        element: &AnnotationElement,
+    ) -> Result<AnnotationValue> {
+        #[expect(clippy::cast_possible_truncation)]
+        let value = match element {
+            AnnotationElement::Byte { const_value_index } => {
+                AnnotationValue::Byte(*constant_pool.try_get_integer(*const_value_index)? as i8)
+            }
+            AnnotationElement::Char { const_value_index } => {
+                let value = *constant_pool.try_get_integer(*const_value_index)?;
+                let character = u32::try_from(value)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .unwrap_or(char::REPLACEMENT_CHARACTER);
+                AnnotationValue::Char(character)
+            }
+            AnnotationElement::Double { const_value_index } => {
+                AnnotationValue::Double(*constant_pool.try_get_double(*const_value_index)?)
+            }
+            AnnotationElement::Float { const_value_index } => {
+                AnnotationValue::Float(*constant_pool.try_get_float(*const_value_index)?)
+            }
+            AnnotationElement::Int { const_value_index } => {
+                AnnotationValue::Int(*constant_pool.try_get_integer(*const_value_index)?)
+            }
+            AnnotationElement::Long { const_value_index } => {
+                AnnotationValue::Long(*constant_pool.try_get_long(*const_value_index)?)
+            }
+            AnnotationElement::Short { const_value_index } => {
+                AnnotationValue::Short(*constant_pool.try_get_integer(*const_value_index)? as i16)
+            }
+            AnnotationElement::Boolean { const_value_index } => {
+                AnnotationValue::Boolean(*constant_pool.try_get_integer(*const_value_index)? != 0)
+            }
+            AnnotationElement::String { const_value_index } => {
+                AnnotationValue::String(constant_pool.try_get_utf8(*const_value_index)?.clone())
+            }
+            AnnotationElement::Enum {
+                type_name_index,
+                const_name_index,
+            } => AnnotationValue::Enum {
+                type_name: constant_pool.try_get_utf8(*type_name_index)?.clone(),
+                const_name: constant_pool.try_get_utf8(*const_name_index)?.clone(),
+            },
+            AnnotationElement::Class { class_info_index } => {
+                AnnotationValue::Class(constant_pool.try_get_utf8(*class_info_index)?.clone())
+            }
+            AnnotationElement::Annotation { annotation } => {
+                AnnotationValue::Annotation(annotation.clone())
+            }
+            AnnotationElement::Array { values } => {
+                let values = values
+                    .iter()
+                    .map(|value| AnnotationValue::from_element(constant_pool, value))
+                    .collect::<Result<Vec<_>>>()?;
+                AnnotationValue::Array(values)
+            }
+        };
+        Ok(value)
+    }
+}
+
+impl fmt::Display for AnnotationValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnnotationValue::Byte(value) => write!(f, "{value}"),
+            AnnotationValue::Char(value) => write!(f, "'{value}'"),
+            AnnotationValue::Double(value) => write!(f, "{value}"),
+            AnnotationValue::Float(value) => write!(f, "{value}"),
+            AnnotationValue::Int(value) => write!(f, "{value}"),
+            AnnotationValue::Long(value) => write!(f, "{value}"),
+            AnnotationValue::Short(value) => write!(f, "{value}"),
+            AnnotationValue::Boolean(value) => write!(f, "{value}"),
+            AnnotationValue::String(value) => write!(f, "\"{value}\""),
+            AnnotationValue::Enum {
+                type_name,
+                const_name,
+            } => write!(f, "{type_name}.{const_name}"),
+            AnnotationValue::Class(value) => write!(f, "{value}"),
+            AnnotationValue::Annotation(annotation) => write!(f, "{annotation}"),
+            AnnotationValue::Array(values) => {
+                let values = values
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "[{values}]")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Error::InvalidConstantPoolIndexType;
+
+    #[test]
+    fn test_from_element() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let integer_index = constant_pool.add_integer(65)?;
+        let zero_index = constant_pool.add_integer(0)?;
+        let long_index = constant_pool.add_long(42)?;
+        let float_index = constant_pool.add_float(1.5)?;
+        let double_index = constant_pool.add_double(2.5)?;
+        let string_index = constant_pool.add_utf8("foo")?;
+        let type_index = constant_pool.add_utf8("Ljava/lang/annotation/ElementType;")?;
+        let const_index = constant_pool.add_utf8("METHOD")?;
+
+        let elements = [
+            (
+                AnnotationElement::Byte {
+                    const_value_index: integer_index,
+                },
+                AnnotationValue::Byte(65),
+            ),
+            (
+                AnnotationElement::Char {
+                    const_value_index: integer_index,
+                },
+                AnnotationValue::Char('A'),
+            ),
+            (
+                AnnotationElement::Short {
+                    const_value_index: integer_index,
+                },
+                AnnotationValue::Short(65),
+            ),
+            (
+                AnnotationElement::Int {
+                    const_value_index: integer_index,
+                },
+                AnnotationValue::Int(65),
+            ),
+            (
+                AnnotationElement::Boolean {
+                    const_value_index: zero_index,
+                },
+                AnnotationValue::Boolean(false),
+            ),
+            (
+                AnnotationElement::Long {
+                    const_value_index: long_index,
+                },
+                AnnotationValue::Long(42),
+            ),
+            (
+                AnnotationElement::Float {
+                    const_value_index: float_index,
+                },
+                AnnotationValue::Float(1.5),
+            ),
+            (
+                AnnotationElement::Double {
+                    const_value_index: double_index,
+                },
+                AnnotationValue::Double(2.5),
+            ),
+            (
+                AnnotationElement::String {
+                    const_value_index: string_index,
+                },
+                AnnotationValue::String("foo".to_string()),
+            ),
+            (
+                AnnotationElement::Class {
+                    class_info_index: type_index,
+                },
+                AnnotationValue::Class("Ljava/lang/annotation/ElementType;".to_string()),
+            ),
+            (
+                AnnotationElement::Enum {
+                    type_name_index: type_index,
+                    const_name_index: const_index,
+                },
+                AnnotationValue::Enum {
+                    type_name: "Ljava/lang/annotation/ElementType;".to_string(),
+                    const_name: "METHOD".to_string(),
+                },
+            ),
+            (
+                AnnotationElement::Array {
+                    values: vec![AnnotationElement::Int {
+                        const_value_index: integer_index,
+                    }],
+                },
+                AnnotationValue::Array(vec![AnnotationValue::Int(65)]),
+            ),
+        ];
+
+        for (element, expected) in elements {
+            assert_eq!(
+                expected,
+                AnnotationValue::from_element(&constant_pool, &element)?
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_element_invalid_constant() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let string_index = constant_pool.add_utf8("foo")?;
+        let element = AnnotationElement::Int {
+            const_value_index: string_index,
+        };
+        assert_eq!(
+            Err(InvalidConstantPoolIndexType(string_index)),
+            AnnotationValue::from_element(&constant_pool, &element)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_string() {
+        let value = AnnotationValue::Array(vec![
+            AnnotationValue::String("foo".to_string()),
+            AnnotationValue::Char('a'),
+            AnnotationValue::Enum {
+                type_name: "LFoo;".to_string(),
+                const_name: "BAR".to_string(),
+            },
+        ]);
+        assert_eq!(r#"["foo", 'a', LFoo;.BAR]"#, value.to_string());
+    }
+}
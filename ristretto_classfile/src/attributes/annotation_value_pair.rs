@@ -8,6 +8,7 @@ use std::io::Cursor;
 ///
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.16>
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnnotationValuePair {
     pub name_index: u16,
     pub value: AnnotationElement,
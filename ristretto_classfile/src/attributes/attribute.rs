@@ -4,9 +4,9 @@ use crate::attributes::line_number::LineNumber;
 use crate::attributes::offset_utils;
 use crate::attributes::parameter_annotation::ParameterAnnotation;
 use crate::attributes::{
-    Annotation, AnnotationElement, ExceptionTableEntry, Exports, Instruction, LocalVariableTable,
-    LocalVariableTypeTable, MethodParameter, ModuleAccessFlags, Opens, Provides, Record, Requires,
-    StackFrame, TypeAnnotation,
+    Annotation, AnnotationElement, CustomAttribute, ExceptionTableEntry, Exports, Instruction,
+    LocalVariableTable, LocalVariableTypeTable, MethodParameter, ModuleAccessFlags, Opens,
+    Provides, Record, Requires, StackFrame, TypeAnnotation,
 };
 use crate::constant::Constant;
 use crate::constant_pool::ConstantPool;
@@ -17,6 +17,7 @@ use crate::mutf8;
 use crate::version::Version;
 use crate::Error::InvalidInstructionOffset;
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt;
 use std::io::{Cursor, Read};
@@ -36,6 +37,7 @@ const VERSION_61_0: Version = Version::Java17 { minor: 0 };
 ///
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7>
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Attribute {
     /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.2>
     ConstantValue {
@@ -193,10 +195,32 @@ pub enum Attribute {
         name_index: u16,
         class_indexes: Vec<u16>,
     },
+    /// Attribute that has not been decoded yet; see
+    /// [`ClassFile::from_bytes_lazy`](crate::ClassFile::from_bytes_lazy).
+    Lazy { name_index: u16, info: Vec<u8> },
     /// Used to support reading future classes where the structure is not known beforehand.
     Unknown { name_index: u16, info: Vec<u8> },
+    /// Attribute that is not defined by the JVM specification, decoded with a codec registered
+    /// with an [`AttributeRegistry`](crate::attributes::AttributeRegistry).
+    Custom {
+        name_index: u16,
+        value: CustomAttribute,
+    },
 }
 
+/// Attributes that are not decoded when a class file is read lazily; these attributes are the
+/// most expensive to decode and are not needed to inspect the names and descriptors of a class.
+const LAZY_ATTRIBUTES: [&str; 8] = [
+    "Code",
+    "RuntimeVisibleAnnotations",
+    "RuntimeInvisibleAnnotations",
+    "RuntimeVisibleParameterAnnotations",
+    "RuntimeInvisibleParameterAnnotations",
+    "RuntimeVisibleTypeAnnotations",
+    "RuntimeInvisibleTypeAnnotations",
+    "AnnotationDefault",
+];
+
 impl Attribute {
     /// Get the name of the Attribute.
     #[must_use]
@@ -236,7 +260,9 @@ impl Attribute {
             Attribute::NestMembers { .. } => "NestMembers",
             Attribute::Record { .. } => "Record",
             Attribute::PermittedSubclasses { .. } => "PermittedSubclasses",
+            Attribute::Lazy { .. } => "Lazy",
             Attribute::Unknown { .. } => "Unknown",
+            Attribute::Custom { .. } => "Custom",
         }
     }
 
@@ -275,8 +301,51 @@ impl Attribute {
             Attribute::NestMembers { .. } => *version >= VERSION_55_0,
             Attribute::Record { .. } => *version >= VERSION_60_0,
             Attribute::PermittedSubclasses { .. } => *version >= VERSION_61_0,
-            Attribute::Unknown { .. } => *version >= VERSION_45_3,
+            Attribute::Lazy { .. } | Attribute::Unknown { .. } | Attribute::Custom { .. } => {
+                *version >= VERSION_45_3
+            }
+        }
+    }
+
+    /// Decode a [`Lazy`](Attribute::Lazy) attribute; other attributes are returned unchanged.
+    ///
+    /// # Errors
+    /// If the lazy attribute cannot be decoded.
+    pub fn decode(&self, constant_pool: &ConstantPool) -> Result<Cow<'_, Attribute>> {
+        let Attribute::Lazy { name_index, info } = self else {
+            return Ok(Cow::Borrowed(self));
+        };
+        let mut bytes = Vec::with_capacity(info.len() + 6);
+        bytes.write_u16::<BigEndian>(*name_index)?;
+        bytes.write_u32::<BigEndian>(u32::try_from(info.len())?)?;
+        bytes.extend_from_slice(info);
+        let attribute = Attribute::from_bytes(constant_pool, &mut Cursor::new(bytes))?;
+        Ok(Cow::Owned(attribute))
+    }
+
+    /// Deserialize the Attribute from bytes; if `lazy` is true, the `Code` and annotation
+    /// attributes are not decoded and are returned as [`Lazy`](Attribute::Lazy) attributes.
+    pub(crate) fn read(
+        constant_pool: &ConstantPool,
+        bytes: &mut Cursor<Vec<u8>>,
+        lazy: bool,
+    ) -> Result<Attribute> {
+        if !lazy {
+            return Attribute::from_bytes(constant_pool, bytes);
         }
+        let position = bytes.position();
+        let name_index = bytes.read_u16::<BigEndian>()?;
+        let Some(Constant::Utf8(attribute_name)) = constant_pool.get(name_index) else {
+            return Err(InvalidAttributeNameIndex(name_index));
+        };
+        if !LAZY_ATTRIBUTES.contains(&attribute_name.as_str()) {
+            bytes.set_position(position);
+            return Attribute::from_bytes(constant_pool, bytes);
+        }
+        let info_length = bytes.read_u32::<BigEndian>()?;
+        let mut info = vec![0; info_length as usize];
+        bytes.read_exact(&mut info)?;
+        Ok(Attribute::Lazy { name_index, info })
     }
 
     /// Deserialize the Attribute from bytes.
@@ -1138,7 +1207,10 @@ impl Attribute {
                 }
                 (name_index, bytes)
             }
-            Attribute::Unknown { name_index, info } => (name_index, info.clone()),
+            Attribute::Lazy { name_index, info } | Attribute::Unknown { name_index, info } => {
+                (name_index, info.clone())
+            }
+            Attribute::Custom { name_index, value } => (name_index, value.to_bytes()?),
         };
 
         bytes.write_u16::<BigEndian>(*name_index)?;
@@ -1199,7 +1271,7 @@ impl Attribute {
                             .ok_or(InvalidInstructionOffset(u32::from(instruction_offset)))?;
                         // Calculate the byte delta offset from the last instruction offset
                         // subtracting 1 to account for the current instruction.
-                        let byte_delta_offset = if last_byte_offset == 0 {
+                        let byte_delta_offset = if first_frame {
                             first_frame = false;
                             byte_offset
                         } else {
@@ -1541,6 +1613,44 @@ mod test {
         test_attribute(&attribute, &expected_bytes, &VERSION_50_0)
     }
 
+    #[test]
+    fn test_code_stack_map_table_first_frame_at_offset_zero() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let code_index = constant_pool.add_utf8("Code")?;
+        let stack_map_table_index = constant_pool.add_utf8("StackMapTable")?;
+        let attribute = Attribute::Code {
+            name_index: code_index,
+            max_stack: 1,
+            max_locals: 0,
+            code: vec![
+                Instruction::Nop,
+                Instruction::Bipush(1),
+                Instruction::Pop,
+                Instruction::Return,
+            ],
+            exception_table: vec![],
+            attributes: vec![Attribute::StackMapTable {
+                name_index: stack_map_table_index,
+                frames: vec![
+                    StackFrame::SameFrame { frame_type: 0 },
+                    StackFrame::SameFrame { frame_type: 1 },
+                ],
+            }],
+        };
+
+        let mut bytes = Vec::new();
+        attribute.to_bytes(&mut bytes)?;
+        // The second frame is at byte offset 3, which is encoded as a delta of 2 from the frame
+        // at byte offset 0.
+        assert_eq!([0, 2, 0, 2], bytes[bytes.len() - 4..]);
+        let mut bytes = Cursor::new(bytes);
+        assert_eq!(
+            attribute,
+            Attribute::from_bytes(&constant_pool, &mut bytes)?
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_exceptions() -> Result<()> {
         let attribute = Attribute::Exceptions {
@@ -2145,4 +2255,51 @@ mod test {
         );
         test_attribute(&attribute, &expected_bytes, &VERSION_45_3)
     }
+
+    #[test]
+    fn test_lazy() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        constant_pool.add_utf8("Code")?;
+        let attribute = Attribute::Code {
+            name_index: 1,
+            max_stack: 1,
+            max_locals: 2,
+            code: vec![Instruction::Iconst_1, Instruction::Ireturn],
+            exception_table: Vec::new(),
+            attributes: Vec::new(),
+        };
+        let mut bytes = Vec::new();
+        attribute.to_bytes(&mut bytes)?;
+
+        let lazy = Attribute::read(&constant_pool, &mut Cursor::new(bytes.clone()), true)?;
+        let expected = Attribute::Lazy {
+            name_index: 1,
+            info: bytes[6..].to_vec(),
+        };
+        assert_eq!(expected, lazy);
+        assert_eq!("Lazy", lazy.name());
+        assert_eq!(attribute, *lazy.decode(&constant_pool)?);
+
+        let mut lazy_bytes = Vec::new();
+        lazy.to_bytes(&mut lazy_bytes)?;
+        assert_eq!(bytes, lazy_bytes);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_not_deferred() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        constant_pool.add_utf8("Signature")?;
+        let attribute = Attribute::Signature {
+            name_index: 1,
+            signature_index: 42,
+        };
+        let mut bytes = Vec::new();
+        attribute.to_bytes(&mut bytes)?;
+
+        let result = Attribute::read(&constant_pool, &mut Cursor::new(bytes), true)?;
+        assert_eq!(attribute, result);
+        assert!(matches!(result.decode(&constant_pool)?, Cow::Borrowed(_)));
+        Ok(())
+    }
 }
@@ -0,0 +1,1014 @@
+use crate::attributes::{
+    Attribute, ExceptionTableEntry, Instruction, InstructionOffsets, LineNumber,
+    LocalVariableTarget, StackFrame, TargetType, VerificationType,
+};
+use crate::error::Error::{InvalidCodeAttribute, InvalidInstructionOffset};
+use crate::error::Result;
+use std::collections::HashMap;
+use std::ops::{Bound, Range, RangeBounds};
+
+/// Editor for the instructions of a `Code` attribute.
+///
+/// Instructions can be inserted, deleted and replaced; every structure that refers to an
+/// instruction offset is rewritten so that it continues to refer to the same instruction:
+///
+/// - branch targets, including `goto_w`, `jsr_w`, `tableswitch` and `lookupswitch`
+/// - the exception table
+/// - `LineNumberTable`, `LocalVariableTable` and `LocalVariableTypeTable`
+/// - `StackMapTable`, including the `new` instruction offsets of uninitialized types
+/// - `RuntimeVisibleTypeAnnotations` and `RuntimeInvisibleTypeAnnotations`
+///
+/// Offsets that refer to the start of an edited range refer to the first new instruction
+/// afterward, so branches to an index where instructions are inserted execute the inserted
+/// instructions.  Offsets that refer to a deleted instruction refer to the instruction that
+/// follows the deleted range.  Branch targets of the new instructions are not rewritten and must
+/// refer to the instruction indexes of the edited code.
+///
+/// # Examples
+///
+/// ```rust
+/// use ristretto_classfile::attributes::{Attribute, CodeEditor, Instruction};
+///
+/// let mut attribute = Attribute::Code {
+///     name_index: 1,
+///     max_stack: 1,
+///     max_locals: 1,
+///     code: vec![Instruction::Iload_0, Instruction::Ifeq(3), Instruction::Nop, Instruction::Return],
+///     exception_table: Vec::new(),
+///     attributes: Vec::new(),
+/// };
+/// let mut editor = CodeEditor::new(&mut attribute)?;
+/// editor.insert(2, vec![Instruction::Nop, Instruction::Nop])?;
+/// assert_eq!(Instruction::Ifeq(5), editor.code()[1]);
+/// # Ok::<(), ristretto_classfile::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct CodeEditor<'a> {
+    code: &'a mut Vec<Instruction>,
+    exception_table: &'a mut Vec<ExceptionTableEntry>,
+    attributes: &'a mut Vec<Attribute>,
+}
+
+impl<'a> CodeEditor<'a> {
+    /// Create a new editor for a `Code` attribute.
+    ///
+    /// # Errors
+    /// If the attribute is not a `Code` attribute.
+    pub fn new(attribute: &'a mut Attribute) -> Result<Self> {
+        match attribute {
+            Attribute::Code {
+                code,
+                exception_table,
+                attributes,
+                ..
+            } => Ok(Self {
+                code,
+                exception_table,
+                attributes,
+            }),
+            _ => Err(InvalidCodeAttribute(attribute.name().to_string())),
+        }
+    }
+
+    /// Get the instructions.
+    #[must_use]
+    pub fn code(&self) -> &[Instruction] {
+        self.code
+    }
+
+    /// Get the exception table.
+    #[must_use]
+    pub fn exception_table(&self) -> &[ExceptionTableEntry] {
+        self.exception_table
+    }
+
+    /// Insert instructions before the instruction at `index`.
+    ///
+    /// # Errors
+    /// If the index is out of bounds or an offset cannot be rewritten.
+    pub fn insert(&mut self, index: usize, instructions: Vec<Instruction>) -> Result<()> {
+        self.splice(index..index, instructions)
+    }
+
+    /// Delete the instructions in `range`, e.g. `2..4` or `index..=index`.
+    ///
+    /// # Errors
+    /// If the range is out of bounds or an offset cannot be rewritten.
+    pub fn delete<R: RangeBounds<usize>>(&mut self, range: R) -> Result<()> {
+        let range = self.index_range(&range);
+        self.splice(range, Vec::new())
+    }
+
+    /// Replace the instructions in `range`, e.g. `2..4` or `index..=index`, with `instructions`.
+    ///
+    /// # Errors
+    /// If the range is out of bounds or an offset cannot be rewritten.
+    pub fn replace<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+        instructions: Vec<Instruction>,
+    ) -> Result<()> {
+        let range = self.index_range(&range);
+        self.splice(range, instructions)
+    }
+
+    /// Convert a range of instruction indexes to an exclusive range.
+    fn index_range<R: RangeBounds<usize>>(&self, range: &R) -> Range<usize> {
+        let start = match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => end.saturating_add(1),
+            Bound::Excluded(end) => *end,
+            Bound::Unbounded => self.code.len(),
+        };
+        start..end
+    }
+
+    /// Replace the instructions in `range` with `instructions` and rewrite all offsets.
+    fn splice(&mut self, range: Range<usize>, instructions: Vec<Instruction>) -> Result<()> {
+        if range.start > range.end || range.end > self.code.len() {
+            return Err(InvalidInstructionOffset(u32::try_from(range.end)?));
+        }
+        let edit = Edit {
+            range,
+            length: instructions.len(),
+        };
+        let old_bytes = InstructionOffsets::new(self.code)?;
+
+        // The edit is applied to copies so that the code is unchanged if the edit fails
+        let mut code = self.code.clone();
+        for (index, instruction) in code.iter_mut().enumerate() {
+            if !edit.range.contains(&index) {
+                edit.remap_instruction(index, instruction)?;
+            }
+        }
+        code.splice(edit.range.clone(), instructions);
+        let new_bytes = InstructionOffsets::new(&code)?;
+
+        let mut exception_table = self.exception_table.clone();
+        let catch_indexes = remap_exception_table(&edit, &mut exception_table)?;
+        let mut attributes = self.attributes.clone();
+        for attribute in &mut attributes {
+            match attribute {
+                Attribute::LineNumberTable { line_numbers, .. } => {
+                    remap_line_numbers(&edit, line_numbers)?;
+                }
+                Attribute::LocalVariableTable { variables, .. } => {
+                    let mut retained = Vec::with_capacity(variables.len());
+                    for mut variable in variables.drain(..) {
+                        if edit.remap_byte_range(
+                            &old_bytes,
+                            &new_bytes,
+                            &mut variable.start_pc,
+                            &mut variable.length,
+                        )? {
+                            retained.push(variable);
+                        }
+                    }
+                    *variables = retained;
+                }
+                Attribute::LocalVariableTypeTable { variable_types, .. } => {
+                    let mut retained = Vec::with_capacity(variable_types.len());
+                    for mut variable_type in variable_types.drain(..) {
+                        if edit.remap_byte_range(
+                            &old_bytes,
+                            &new_bytes,
+                            &mut variable_type.start_pc,
+                            &mut variable_type.length,
+                        )? {
+                            retained.push(variable_type);
+                        }
+                    }
+                    *variable_types = retained;
+                }
+                Attribute::StackMapTable { frames, .. } => {
+                    remap_frames(&edit, &old_bytes, &new_bytes, frames)?;
+                }
+                Attribute::RuntimeVisibleTypeAnnotations {
+                    type_annotations, ..
+                }
+                | Attribute::RuntimeInvisibleTypeAnnotations {
+                    type_annotations, ..
+                } => {
+                    let mut retained = Vec::with_capacity(type_annotations.len());
+                    for mut type_annotation in type_annotations.drain(..) {
+                        if edit.remap_target_type(
+                            &old_bytes,
+                            &new_bytes,
+                            &catch_indexes,
+                            &mut type_annotation.target_type,
+                        )? {
+                            retained.push(type_annotation);
+                        }
+                    }
+                    *type_annotations = retained;
+                }
+                _ => {}
+            }
+        }
+
+        *self.code = code;
+        *self.exception_table = exception_table;
+        *self.attributes = attributes;
+        Ok(())
+    }
+}
+
+/// Rewrite the exception table; entries whose range becomes empty are removed.  Returns the map of
+/// old to new exception table indexes.
+fn remap_exception_table(
+    edit: &Edit,
+    exception_table: &mut Vec<ExceptionTableEntry>,
+) -> Result<HashMap<u16, u16>> {
+    let mut indexes = HashMap::new();
+    let mut retained = Vec::with_capacity(exception_table.len());
+    for (index, mut entry) in exception_table.drain(..).enumerate() {
+        let start = edit.target(usize::from(entry.range_pc.start));
+        let end = edit.target(usize::from(entry.range_pc.end));
+        if start >= end {
+            continue;
+        }
+        entry.range_pc = u16::try_from(start)?..u16::try_from(end)?;
+        entry.handler_pc = u16::try_from(edit.target(usize::from(entry.handler_pc)))?;
+        indexes.insert(u16::try_from(index)?, u16::try_from(retained.len())?);
+        retained.push(entry);
+    }
+    *exception_table = retained;
+    Ok(indexes)
+}
+
+/// A single edit that replaces the instructions in `range` with `length` instructions.
+#[derive(Debug)]
+struct Edit {
+    range: Range<usize>,
+    length: usize,
+}
+
+impl Edit {
+    /// Get the new index for an offset of the original code.  Offsets in the edited range refer
+    /// to the start of the range.
+    fn target(&self, index: usize) -> usize {
+        if index <= self.range.start {
+            index
+        } else if index < self.range.end {
+            self.range.start
+        } else {
+            index - self.range.len() + self.length
+        }
+    }
+
+    /// Get the new index of an instruction of the original code that is not in the edited range.
+    fn moved(&self, index: usize) -> usize {
+        if index < self.range.start {
+            index
+        } else {
+            index - self.range.len() + self.length
+        }
+    }
+
+    /// Get the new index for an entry that describes the instruction at `index`; entries for the
+    /// instructions following the first instruction of the edited range are removed.
+    fn position(&self, index: usize) -> Option<usize> {
+        if index > self.range.start && index < self.range.end {
+            None
+        } else {
+            Some(self.target(index))
+        }
+    }
+
+    /// Get the new index for an entry that describes exactly the instruction at `index`; entries
+    /// for any instruction in the edited range are removed.
+    fn instruction(&self, index: usize) -> Option<usize> {
+        if self.range.contains(&index) {
+            None
+        } else {
+            Some(self.target(index))
+        }
+    }
+
+    /// Get the new instruction offset for a branch target.
+    fn branch(&self, offset: u16) -> Result<u16> {
+        Ok(u16::try_from(self.target(usize::from(offset)))?)
+    }
+
+    /// Rewrite the branch targets of the instruction at `index` of the original code.
+    fn remap_instruction(&self, index: usize, instruction: &mut Instruction) -> Result<()> {
+        match instruction {
+            Instruction::Ifeq(offset)
+            | Instruction::Ifne(offset)
+            | Instruction::Iflt(offset)
+            | Instruction::Ifge(offset)
+            | Instruction::Ifgt(offset)
+            | Instruction::Ifle(offset)
+            | Instruction::If_icmpeq(offset)
+            | Instruction::If_icmpne(offset)
+            | Instruction::If_icmplt(offset)
+            | Instruction::If_icmpge(offset)
+            | Instruction::If_icmpgt(offset)
+            | Instruction::If_icmple(offset)
+            | Instruction::If_acmpeq(offset)
+            | Instruction::If_acmpne(offset)
+            | Instruction::Goto(offset)
+            | Instruction::Jsr(offset)
+            | Instruction::Ifnull(offset)
+            | Instruction::Ifnonnull(offset) => {
+                *offset = self.branch(*offset)?;
+            }
+            Instruction::Goto_w(offset) | Instruction::Jsr_w(offset) => {
+                let target = self.target(usize::try_from(*offset)?);
+                *offset = i32::try_from(target)?;
+            }
+            Instruction::Tableswitch {
+                default, offsets, ..
+            } => {
+                // Switch offsets are relative to the index of the switch instruction, which moves
+                // if instructions are inserted before it
+                let position = i64::try_from(index)?;
+                let new_position = i64::try_from(self.moved(index))?;
+                for offset in std::iter::once(default).chain(offsets.iter_mut()) {
+                    let target = usize::try_from(position + i64::from(*offset))?;
+                    *offset = i32::try_from(i64::try_from(self.target(target))? - new_position)?;
+                }
+            }
+            Instruction::Lookupswitch { default, pairs } => {
+                let position = i64::try_from(index)?;
+                let new_position = i64::try_from(self.moved(index))?;
+                for offset in std::iter::once(default).chain(pairs.values_mut()) {
+                    let target = usize::try_from(position + i64::from(*offset))?;
+                    *offset = i32::try_from(i64::try_from(self.target(target))? - new_position)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Rewrite a range of byte offsets; returns `false` if the range becomes empty and should be
+    /// removed.
+    fn remap_byte_range(
+        &self,
+        old_bytes: &InstructionOffsets,
+        new_bytes: &InstructionOffsets,
+        start_pc: &mut u16,
+        length: &mut u16,
+    ) -> Result<bool> {
+        let end_pc = u32::from(*start_pc) + u32::from(*length);
+        let start = self.target(old_bytes.instruction_index(u32::from(*start_pc))?);
+        let end = self.target(old_bytes.instruction_index(end_pc)?);
+        if start >= end && *length > 0 {
+            return Ok(false);
+        }
+        let start_byte = new_bytes.byte_offset(start)?;
+        let end_byte = new_bytes.byte_offset(end)?;
+        *start_pc = start_byte;
+        *length = end_byte.saturating_sub(start_byte);
+        Ok(true)
+    }
+
+    /// Rewrite the offsets of a type annotation target; returns `false` if the target refers to
+    /// edited code and should be removed.
+    fn remap_target_type(
+        &self,
+        old_bytes: &InstructionOffsets,
+        new_bytes: &InstructionOffsets,
+        catch_indexes: &HashMap<u16, u16>,
+        target_type: &mut TargetType,
+    ) -> Result<bool> {
+        match target_type {
+            TargetType::Offset { offset, .. } | TargetType::TypeArgument { offset, .. } => {
+                let index = old_bytes.instruction_index(u32::from(*offset))?;
+                let Some(index) = self.instruction(index) else {
+                    return Ok(false);
+                };
+                *offset = new_bytes.byte_offset(index)?;
+            }
+            TargetType::LocalVar {
+                local_variable_targets,
+                ..
+            } => {
+                let mut retained: Vec<LocalVariableTarget> =
+                    Vec::with_capacity(local_variable_targets.len());
+                for mut target in local_variable_targets.drain(..) {
+                    if self.remap_byte_range(
+                        old_bytes,
+                        new_bytes,
+                        &mut target.start_pc,
+                        &mut target.length,
+                    )? {
+                        retained.push(target);
+                    }
+                }
+                *local_variable_targets = retained;
+                return Ok(!local_variable_targets.is_empty());
+            }
+            TargetType::Catch {
+                exception_table_index,
+                ..
+            } => {
+                let Some(index) = catch_indexes.get(exception_table_index) else {
+                    return Ok(false);
+                };
+                *exception_table_index = *index;
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+}
+
+/// Rewrite the line numbers; if several entries refer to the same instruction, the last entry is
+/// retained.
+fn remap_line_numbers(edit: &Edit, line_numbers: &mut Vec<LineNumber>) -> Result<()> {
+    let mut retained: Vec<LineNumber> = Vec::with_capacity(line_numbers.len());
+    for mut line_number in line_numbers.drain(..) {
+        let Some(start_pc) = edit.position(usize::from(line_number.start_pc)) else {
+            continue;
+        };
+        line_number.start_pc = u16::try_from(start_pc)?;
+        retained.retain(|retained| retained.start_pc != line_number.start_pc);
+        retained.push(line_number);
+    }
+    *line_numbers = retained;
+    Ok(())
+}
+
+/// Rewrite the stack map frames.  The frames are stored with instruction offset deltas, but the
+/// compact frame types are selected using the byte offset deltas of the edited code.  If several
+/// frames refer to the same instruction, the last frame is retained.  The byte offsets of the
+/// `new` instructions of uninitialized verification types are rewritten; if the `new`
+/// instruction was edited, the offset refers to the first instruction of the edited range.
+fn remap_frames(
+    edit: &Edit,
+    old_bytes: &InstructionOffsets,
+    new_bytes: &InstructionOffsets,
+    frames: &mut Vec<StackFrame>,
+) -> Result<()> {
+    let mut positioned: Vec<(usize, StackFrame)> = Vec::with_capacity(frames.len());
+    let mut offset: Option<usize> = None;
+    for mut frame in frames.drain(..) {
+        for verification_type in verification_types(&mut frame) {
+            if let VerificationType::Uninitialized { offset } = verification_type {
+                let index = old_bytes.instruction_index(u32::from(*offset))?;
+                let index = if edit.range.contains(&index) {
+                    edit.range.start
+                } else {
+                    edit.moved(index)
+                };
+                *offset = new_bytes.byte_offset(index)?;
+            }
+        }
+        let delta = usize::from(frame.offset_delta());
+        let index = offset.map_or(delta, |offset| offset + delta + 1);
+        offset = Some(index);
+        if let Some(index) = edit.position(index) {
+            if positioned.last().is_some_and(|(last, _)| *last == index) {
+                positioned.pop();
+            }
+            positioned.push((index, frame));
+        }
+    }
+
+    let mut previous: Option<(usize, u16)> = None;
+    for (index, frame) in positioned {
+        let byte = new_bytes.byte_offset(index)?;
+        let (delta, byte_delta) = match previous {
+            Some((previous_index, previous_byte)) => (
+                u16::try_from(index - previous_index - 1)?,
+                byte.saturating_sub(previous_byte).saturating_sub(1),
+            ),
+            None => (u16::try_from(index)?, byte),
+        };
+        previous = Some((index, byte));
+        frames.push(with_offset_delta(frame, delta, byte_delta)?);
+    }
+    Ok(())
+}
+
+/// Get the verification types of the locals and stack of a frame.
+fn verification_types(frame: &mut StackFrame) -> Vec<&mut VerificationType> {
+    match frame {
+        StackFrame::SameFrame { .. }
+        | StackFrame::ChopFrame { .. }
+        | StackFrame::SameFrameExtended { .. } => Vec::new(),
+        StackFrame::SameLocals1StackItemFrame { stack, .. }
+        | StackFrame::SameLocals1StackItemFrameExtended { stack, .. } => stack.iter_mut().collect(),
+        StackFrame::AppendFrame { locals, .. } => locals.iter_mut().collect(),
+        StackFrame::FullFrame { locals, stack, .. } => {
+            locals.iter_mut().chain(stack.iter_mut()).collect()
+        }
+    }
+}
+
+/// Set the instruction offset delta of a frame, converting between the compact and extended
+/// frame types based on the byte offset delta.
+fn with_offset_delta(frame: StackFrame, delta: u16, byte_delta: u16) -> Result<StackFrame> {
+    let frame = match frame {
+        StackFrame::SameFrame { .. } | StackFrame::SameFrameExtended { .. } => {
+            if byte_delta <= 63 {
+                StackFrame::SameFrame {
+                    frame_type: u8::try_from(delta)?,
+                }
+            } else {
+                StackFrame::SameFrameExtended {
+                    frame_type: 251,
+                    offset_delta: delta,
+                }
+            }
+        }
+        StackFrame::SameLocals1StackItemFrame { stack, .. }
+        | StackFrame::SameLocals1StackItemFrameExtended { stack, .. } => {
+            if byte_delta <= 63 {
+                StackFrame::SameLocals1StackItemFrame {
+                    frame_type: u8::try_from(delta + 64)?,
+                    stack,
+                }
+            } else {
+                StackFrame::SameLocals1StackItemFrameExtended {
+                    frame_type: 247,
+                    offset_delta: delta,
+                    stack,
+                }
+            }
+        }
+        StackFrame::ChopFrame { frame_type, .. } => StackFrame::ChopFrame {
+            frame_type,
+            offset_delta: delta,
+        },
+        StackFrame::AppendFrame {
+            frame_type, locals, ..
+        } => StackFrame::AppendFrame {
+            frame_type,
+            offset_delta: delta,
+            locals,
+        },
+        StackFrame::FullFrame {
+            frame_type,
+            locals,
+            stack,
+            ..
+        } => StackFrame::FullFrame {
+            frame_type,
+            offset_delta: delta,
+            locals,
+            stack,
+        },
+    };
+    Ok(frame)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::attributes::{LocalVariableTable, TypeAnnotation};
+    use crate::ClassFile;
+    use indexmap::IndexMap;
+    use std::io::Cursor;
+
+    fn code_attribute(code: Vec<Instruction>) -> Attribute {
+        Attribute::Code {
+            name_index: 1,
+            max_stack: 2,
+            max_locals: 2,
+            code,
+            exception_table: Vec::new(),
+            attributes: Vec::new(),
+        }
+    }
+
+    fn code(attribute: &Attribute) -> &Vec<Instruction> {
+        match attribute {
+            Attribute::Code { code, .. } => code,
+            _ => panic!("expected code attribute"),
+        }
+    }
+
+    #[test]
+    fn test_new_invalid_attribute() {
+        let mut attribute = Attribute::Synthetic { name_index: 1 };
+        let result = CodeEditor::new(&mut attribute);
+        assert_eq!(
+            Some(InvalidCodeAttribute("Synthetic".to_string())),
+            result.err()
+        );
+    }
+
+    #[test]
+    fn test_insert_branches() -> Result<()> {
+        let mut attribute = code_attribute(vec![
+            Instruction::Iload_0,
+            Instruction::Ifeq(4),
+            Instruction::Goto_w(0),
+            Instruction::Goto(1),
+            Instruction::Return,
+        ]);
+        let mut editor = CodeEditor::new(&mut attribute)?;
+        editor.insert(1, vec![Instruction::Nop])?;
+        editor.insert(4, vec![Instruction::Nop, Instruction::Nop])?;
+
+        let expected = vec![
+            Instruction::Iload_0,
+            Instruction::Nop,
+            Instruction::Ifeq(7),
+            Instruction::Goto_w(0),
+            Instruction::Nop,
+            Instruction::Nop,
+            Instruction::Goto(1),
+            Instruction::Return,
+        ];
+        assert_eq!(&expected, code(&attribute));
+        Ok(())
+    }
+
+    #[test]
+    fn test_delete_branches() -> Result<()> {
+        let mut attribute = code_attribute(vec![
+            Instruction::Iload_0,
+            Instruction::Ifeq(3),
+            Instruction::Nop,
+            Instruction::Nop,
+            Instruction::Goto(5),
+            Instruction::Return,
+        ]);
+        let mut editor = CodeEditor::new(&mut attribute)?;
+        editor.delete(2..4)?;
+
+        let expected = vec![
+            Instruction::Iload_0,
+            Instruction::Ifeq(2),
+            Instruction::Goto(3),
+            Instruction::Return,
+        ];
+        assert_eq!(&expected, code(&attribute));
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace_switches() -> Result<()> {
+        let mut attribute = code_attribute(vec![
+            Instruction::Iload_0,
+            Instruction::Tableswitch {
+                default: 3,
+                low: 0,
+                high: 1,
+                offsets: vec![1, 2],
+            },
+            Instruction::Nop,
+            Instruction::Nop,
+            Instruction::Lookupswitch {
+                default: 1,
+                pairs: IndexMap::from([(1, 1)]),
+            },
+            Instruction::Return,
+        ]);
+        let mut editor = CodeEditor::new(&mut attribute)?;
+        editor.replace(
+            2..3,
+            vec![Instruction::Iconst_0, Instruction::Pop, Instruction::Nop],
+        )?;
+        editor.insert(1, vec![Instruction::Nop])?;
+
+        let expected = vec![
+            Instruction::Iload_0,
+            Instruction::Nop,
+            Instruction::Tableswitch {
+                default: 5,
+                low: 0,
+                high: 1,
+                offsets: vec![1, 4],
+            },
+            Instruction::Iconst_0,
+            Instruction::Pop,
+            Instruction::Nop,
+            Instruction::Nop,
+            Instruction::Lookupswitch {
+                default: 1,
+                pairs: IndexMap::from([(1, 1)]),
+            },
+            Instruction::Return,
+        ];
+        assert_eq!(&expected, code(&attribute));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_range() -> Result<()> {
+        let mut attribute = code_attribute(vec![Instruction::Return]);
+        let mut editor = CodeEditor::new(&mut attribute)?;
+        assert_eq!(
+            Err(InvalidInstructionOffset(2)),
+            editor.insert(2, vec![Instruction::Nop])
+        );
+        assert_eq!(Err(InvalidInstructionOffset(3)), editor.delete(0..3));
+        assert_eq!(Err(InvalidInstructionOffset(2)), editor.delete(0..=1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_instructions_unchanged() -> Result<()> {
+        let mut attribute = code_attribute(vec![Instruction::Goto(1), Instruction::Return]);
+        let mut editor = CodeEditor::new(&mut attribute)?;
+        assert_eq!(
+            Err(InvalidInstructionOffset(42)),
+            editor.insert(1, vec![Instruction::Goto(42)])
+        );
+        assert_eq!(
+            &vec![Instruction::Goto(1), Instruction::Return],
+            code(&attribute)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_exception_table() -> Result<()> {
+        let mut attribute = Attribute::Code {
+            name_index: 1,
+            max_stack: 1,
+            max_locals: 1,
+            code: vec![
+                Instruction::Nop,
+                Instruction::Nop,
+                Instruction::Return,
+                Instruction::Astore_0,
+                Instruction::Return,
+            ],
+            exception_table: vec![
+                ExceptionTableEntry {
+                    range_pc: 1..2,
+                    handler_pc: 3,
+                    catch_type: 1,
+                },
+                ExceptionTableEntry {
+                    range_pc: 0..2,
+                    handler_pc: 3,
+                    catch_type: 2,
+                },
+            ],
+            attributes: vec![Attribute::RuntimeVisibleTypeAnnotations {
+                name_index: 2,
+                type_annotations: vec![TypeAnnotation {
+                    target_type: TargetType::Catch {
+                        target_type: 66,
+                        exception_table_index: 1,
+                    },
+                    type_path: Vec::new(),
+                    type_index: 3,
+                    elements: Vec::new(),
+                }],
+            }],
+        };
+        let mut editor = CodeEditor::new(&mut attribute)?;
+        editor.delete(1..2)?;
+        editor.insert(2, vec![Instruction::Nop])?;
+
+        let Attribute::Code {
+            exception_table,
+            attributes,
+            ..
+        } = &attribute
+        else {
+            panic!("expected code attribute");
+        };
+        let expected = vec![ExceptionTableEntry {
+            range_pc: 0..1,
+            handler_pc: 2,
+            catch_type: 2,
+        }];
+        assert_eq!(&expected, exception_table);
+        let Attribute::RuntimeVisibleTypeAnnotations {
+            type_annotations, ..
+        } = &attributes[0]
+        else {
+            panic!("expected type annotations");
+        };
+        assert_eq!(
+            TargetType::Catch {
+                target_type: 66,
+                exception_table_index: 0,
+            },
+            type_annotations[0].target_type
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_line_numbers_and_local_variables() -> Result<()> {
+        let mut attribute = Attribute::Code {
+            name_index: 1,
+            max_stack: 1,
+            max_locals: 1,
+            code: vec![
+                Instruction::Iconst_0,
+                Instruction::Istore_0,
+                Instruction::Sipush(42),
+                Instruction::Istore_0,
+                Instruction::Return,
+            ],
+            exception_table: Vec::new(),
+            attributes: vec![
+                Attribute::LineNumberTable {
+                    name_index: 2,
+                    line_numbers: vec![
+                        LineNumber {
+                            start_pc: 0,
+                            line_number: 1,
+                        },
+                        LineNumber {
+                            start_pc: 2,
+                            line_number: 2,
+                        },
+                        LineNumber {
+                            start_pc: 4,
+                            line_number: 3,
+                        },
+                    ],
+                },
+                Attribute::LocalVariableTable {
+                    name_index: 3,
+                    variables: vec![LocalVariableTable {
+                        start_pc: 2,
+                        length: 5,
+                        name_index: 4,
+                        descriptor_index: 5,
+                        index: 0,
+                    }],
+                },
+            ],
+        };
+        let mut editor = CodeEditor::new(&mut attribute)?;
+        editor.insert(2, vec![Instruction::Nop])?;
+        editor.replace(3..5, vec![Instruction::Bipush(42), Instruction::Istore_0])?;
+
+        let Attribute::Code { attributes, .. } = &attribute else {
+            panic!("expected code attribute");
+        };
+        let expected = vec![
+            Attribute::LineNumberTable {
+                name_index: 2,
+                line_numbers: vec![
+                    LineNumber {
+                        start_pc: 0,
+                        line_number: 1,
+                    },
+                    LineNumber {
+                        start_pc: 2,
+                        line_number: 2,
+                    },
+                    LineNumber {
+                        start_pc: 5,
+                        line_number: 3,
+                    },
+                ],
+            },
+            Attribute::LocalVariableTable {
+                name_index: 3,
+                variables: vec![LocalVariableTable {
+                    start_pc: 2,
+                    length: 5,
+                    name_index: 4,
+                    descriptor_index: 5,
+                    index: 0,
+                }],
+            },
+        ];
+        assert_eq!(&expected, attributes);
+        Ok(())
+    }
+
+    #[test]
+    fn test_stack_map_table() -> Result<()> {
+        let mut attribute = Attribute::Code {
+            name_index: 1,
+            max_stack: 1,
+            max_locals: 1,
+            code: vec![
+                Instruction::Iload_0,
+                Instruction::Ifeq(4),
+                Instruction::Iconst_1,
+                Instruction::Ireturn,
+                Instruction::Iconst_0,
+                Instruction::Ireturn,
+            ],
+            exception_table: Vec::new(),
+            attributes: vec![Attribute::StackMapTable {
+                name_index: 2,
+                frames: vec![
+                    StackFrame::SameFrame { frame_type: 4 },
+                    StackFrame::SameLocals1StackItemFrame {
+                        frame_type: 64,
+                        stack: vec![VerificationType::Integer],
+                    },
+                ],
+            }],
+        };
+        let mut editor = CodeEditor::new(&mut attribute)?;
+        editor.insert(3, vec![Instruction::Sipush(1); 40])?;
+
+        let Attribute::Code { attributes, .. } = &attribute else {
+            panic!("expected code attribute");
+        };
+        let expected = vec![Attribute::StackMapTable {
+            name_index: 2,
+            frames: vec![
+                StackFrame::SameFrameExtended {
+                    frame_type: 251,
+                    offset_delta: 44,
+                },
+                StackFrame::SameLocals1StackItemFrame {
+                    frame_type: 64,
+                    stack: vec![VerificationType::Integer],
+                },
+            ],
+        }];
+        assert_eq!(&expected, attributes);
+        Ok(())
+    }
+
+    #[test]
+    fn test_stack_map_table_uninitialized() -> Result<()> {
+        // new Object(flag ? 1 : 0) style code: the uninitialized object is on the stack at a branch
+        let mut attribute = Attribute::Code {
+            name_index: 1,
+            max_stack: 3,
+            max_locals: 1,
+            code: vec![
+                Instruction::New(2),
+                Instruction::Dup,
+                Instruction::Iload_0,
+                Instruction::Ifeq(6),
+                Instruction::Iconst_1,
+                Instruction::Goto(7),
+                Instruction::Iconst_0,
+                Instruction::Invokespecial(3),
+                Instruction::Areturn,
+            ],
+            exception_table: Vec::new(),
+            attributes: vec![Attribute::StackMapTable {
+                name_index: 4,
+                frames: vec![
+                    StackFrame::FullFrame {
+                        frame_type: 255,
+                        offset_delta: 6,
+                        locals: vec![VerificationType::Integer],
+                        stack: vec![
+                            VerificationType::Uninitialized { offset: 0 },
+                            VerificationType::Uninitialized { offset: 0 },
+                        ],
+                    },
+                    StackFrame::FullFrame {
+                        frame_type: 255,
+                        offset_delta: 0,
+                        locals: vec![VerificationType::Integer],
+                        stack: vec![
+                            VerificationType::Uninitialized { offset: 0 },
+                            VerificationType::Uninitialized { offset: 0 },
+                            VerificationType::Integer,
+                        ],
+                    },
+                ],
+            }],
+        };
+        let mut editor = CodeEditor::new(&mut attribute)?;
+        editor.insert(0, vec![Instruction::Nop, Instruction::Nop])?;
+
+        let Attribute::Code { attributes, .. } = &attribute else {
+            panic!("expected code attribute");
+        };
+        let Some(Attribute::StackMapTable { frames, .. }) = attributes.first() else {
+            panic!("expected stack map table");
+        };
+        for frame in frames {
+            let StackFrame::FullFrame { stack, .. } = frame else {
+                panic!("expected full frame");
+            };
+            assert_eq!(VerificationType::Uninitialized { offset: 2 }, stack[0]);
+            assert_eq!(VerificationType::Uninitialized { offset: 2 }, stack[1]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_class_file_round_trip() -> Result<()> {
+        let bytes = include_bytes!("../../../classes/Expressions.class").to_vec();
+        let mut class_file = ClassFile::from_bytes(&mut Cursor::new(bytes))?;
+        for method in &mut class_file.methods {
+            for attribute in &mut method.attributes {
+                if let Attribute::Code { .. } = attribute {
+                    let mut editor = CodeEditor::new(attribute)?;
+                    let length = editor.code().len();
+                    editor.insert(0, vec![Instruction::Nop])?;
+                    editor.insert(length / 2, vec![Instruction::Nop, Instruction::Nop])?;
+                    editor.delete(length / 2..length / 2 + 2)?;
+                    editor.delete(0..1)?;
+                }
+            }
+        }
+
+        let bytes = include_bytes!("../../../classes/Expressions.class").to_vec();
+        let expected = ClassFile::from_bytes(&mut Cursor::new(bytes))?;
+        assert_eq!(expected, class_file);
+        Ok(())
+    }
+}
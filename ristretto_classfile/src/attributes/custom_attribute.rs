@@ -0,0 +1,468 @@
+use crate::attributes::Attribute;
+use crate::constant_pool::ConstantPool;
+use crate::error::Error::InvalidCustomAttribute;
+use crate::error::Result;
+use std::any::{Any, TypeId};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Parse the bytes of a custom attribute, excluding the name index and length.
+type Parser = Arc<dyn Fn(&ConstantPool, &[u8]) -> Result<Arc<dyn CustomValue>> + Send + Sync>;
+
+/// Serialize the value of a custom attribute to bytes, excluding the name index and length.
+type Serializer = Arc<dyn Fn(&dyn Any) -> Result<Vec<u8>> + Send + Sync>;
+
+/// The value of a custom attribute with its type erased.
+trait CustomValue: Any + fmt::Debug + Send + Sync {
+    /// Get the value as [`Any`] so that it can be downcast to its type.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Compare the value to a value of any type.
+    fn equals(&self, other: &dyn Any) -> bool;
+}
+
+impl<T: Any + fmt::Debug + PartialEq + Send + Sync> CustomValue for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<T>().is_some_and(|other| self == other)
+    }
+}
+
+/// Create a type-erased serializer for values of type `T`.
+fn serializer<T: Any>(name: &str, serialize: fn(&T) -> Result<Vec<u8>>) -> Serializer {
+    let name = name.to_string();
+    Arc::new(move |value: &dyn Any| {
+        let Some(value) = value.downcast_ref::<T>() else {
+            return Err(InvalidCustomAttribute(name.clone()));
+        };
+        serialize(value)
+    })
+}
+
+/// An attribute that is not defined by the JVM specification, such as the `ScalaSig` attribute,
+/// with its bytes decoded to a typed value.  Custom attributes are created by the codecs of an
+/// [`AttributeRegistry`] or with [`CustomAttribute::new`].
+#[derive(Clone)]
+pub struct CustomAttribute {
+    name: String,
+    value: Arc<dyn CustomValue>,
+    serializer: Serializer,
+}
+
+impl CustomAttribute {
+    /// Create a custom attribute from a value and a function that serializes the value to the
+    /// bytes of the attribute, excluding the name index and length.
+    pub fn new<S, T>(name: S, value: T, serialize: fn(&T) -> Result<Vec<u8>>) -> Self
+    where
+        S: AsRef<str>,
+        T: Any + fmt::Debug + PartialEq + Send + Sync,
+    {
+        let name = name.as_ref();
+        Self {
+            name: name.to_string(),
+            value: Arc::new(value),
+            serializer: serializer(name, serialize),
+        }
+    }
+
+    /// Get the name of the attribute.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the value of the attribute; `None` is returned if the value is not a `T`.
+    #[must_use]
+    pub fn value<T: Any>(&self) -> Option<&T> {
+        self.value.as_any().downcast_ref::<T>()
+    }
+
+    /// Serialize the value of the attribute to bytes, excluding the name index and length.
+    ///
+    /// # Errors
+    /// If the value cannot be serialized.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        (self.serializer)(self.value.as_any())
+    }
+}
+
+impl fmt::Debug for CustomAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomAttribute")
+            .field("name", &self.name)
+            .field("value", &self.value)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for CustomAttribute {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.value.equals(other.value.as_any())
+    }
+}
+
+/// Custom attributes are serialized as their name and bytes; a deserialized custom attribute
+/// holds its bytes as a `Vec<u8>` value, since the codec that decoded it is not available.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerializedCustomAttribute {
+    name: String,
+    info: Vec<u8>,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CustomAttribute {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let info = self.to_bytes().map_err(serde::ser::Error::custom)?;
+        SerializedCustomAttribute {
+            name: self.name.clone(),
+            info,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CustomAttribute {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let SerializedCustomAttribute { name, info } =
+            SerializedCustomAttribute::deserialize(deserializer)?;
+        Ok(CustomAttribute::new(name, info, |info| Ok(info.clone())))
+    }
+}
+
+/// The parser and serializer registered for a custom attribute.
+#[derive(Clone)]
+struct Codec {
+    type_id: TypeId,
+    parser: Parser,
+    serializer: Serializer,
+}
+
+/// Registry of codecs for attributes that are not defined by the JVM specification.  Attributes
+/// without a codec are read as [`Unknown`](Attribute::Unknown) attributes holding their raw
+/// bytes; the registry decodes them to [`Custom`](Attribute::Custom) attributes holding typed
+/// values, which are serialized back to bytes when the class file is written.
+///
+/// # Examples
+///
+/// ```rust
+/// use ristretto_classfile::attributes::{Attribute, AttributeRegistry};
+/// use ristretto_classfile::ConstantPool;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Checksum(u32);
+///
+/// let mut registry = AttributeRegistry::new();
+/// registry.register(
+///     "Checksum",
+///     |_constant_pool, bytes| {
+///         let bytes = bytes.try_into().unwrap_or_default();
+///         Ok(Checksum(u32::from_be_bytes(bytes)))
+///     },
+///     |checksum| Ok(checksum.0.to_be_bytes().to_vec()),
+/// );
+///
+/// let mut constant_pool = ConstantPool::default();
+/// let name_index = constant_pool.add_utf8("Checksum")?;
+/// let attribute = Attribute::Unknown { name_index, info: vec![0, 0, 0, 42] };
+/// let attribute = registry.decode(&constant_pool, &attribute)?;
+/// let Attribute::Custom { value, .. } = attribute.as_ref() else {
+///     unreachable!("Checksum is registered");
+/// };
+/// assert_eq!(Some(&Checksum(42)), value.value::<Checksum>());
+/// # Ok::<(), ristretto_classfile::Error>(())
+/// ```
+#[derive(Clone, Default)]
+pub struct AttributeRegistry {
+    codecs: HashMap<String, Codec>,
+}
+
+impl AttributeRegistry {
+    /// Create a new registry without any codecs.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the functions that parse the bytes of an attribute to a value and serialize the
+    /// value back to bytes; the bytes exclude the attribute name index and length.  A codec
+    /// registered for a name that is already registered replaces the existing codec.
+    pub fn register<S, T>(
+        &mut self,
+        name: S,
+        parse: fn(&ConstantPool, &[u8]) -> Result<T>,
+        serialize: fn(&T) -> Result<Vec<u8>>,
+    ) where
+        S: AsRef<str>,
+        T: Any + fmt::Debug + PartialEq + Send + Sync,
+    {
+        let name = name.as_ref();
+        let parser: Parser = Arc::new(move |constant_pool: &ConstantPool, bytes: &[u8]| {
+            let value: Arc<dyn CustomValue> = Arc::new(parse(constant_pool, bytes)?);
+            Ok(value)
+        });
+        let codec = Codec {
+            type_id: TypeId::of::<T>(),
+            parser,
+            serializer: serializer(name, serialize),
+        };
+        self.codecs.insert(name.to_string(), codec);
+    }
+
+    /// Check if a codec is registered for an attribute name.
+    #[must_use]
+    pub fn is_registered(&self, name: &str) -> bool {
+        self.codecs.contains_key(name)
+    }
+
+    /// Decode an [`Unknown`](Attribute::Unknown) attribute with a registered codec to a
+    /// [`Custom`](Attribute::Custom) attribute; other attributes are returned unchanged.
+    ///
+    /// # Errors
+    /// If the attribute name index is invalid or the codec cannot parse the attribute.
+    pub fn decode<'a>(
+        &self,
+        constant_pool: &ConstantPool,
+        attribute: &'a Attribute,
+    ) -> Result<Cow<'a, Attribute>> {
+        let Attribute::Unknown { name_index, info } = attribute else {
+            return Ok(Cow::Borrowed(attribute));
+        };
+        let name = constant_pool.try_get_utf8(*name_index)?;
+        let Some(codec) = self.codecs.get(name) else {
+            return Ok(Cow::Borrowed(attribute));
+        };
+        let value = CustomAttribute {
+            name: name.clone(),
+            value: (codec.parser)(constant_pool, info)?,
+            serializer: codec.serializer.clone(),
+        };
+        Ok(Cow::Owned(Attribute::Custom {
+            name_index: *name_index,
+            value,
+        }))
+    }
+
+    /// Create a [`Custom`](Attribute::Custom) attribute for a value using the registered codec;
+    /// the attribute name is added to the constant pool.
+    ///
+    /// # Errors
+    /// If no codec is registered for the name, the codec is registered for a different type or
+    /// the name cannot be added to the constant pool.
+    pub fn encode<S, T>(
+        &self,
+        constant_pool: &mut ConstantPool,
+        name: S,
+        value: T,
+    ) -> Result<Attribute>
+    where
+        S: AsRef<str>,
+        T: Any + fmt::Debug + PartialEq + Send + Sync,
+    {
+        let name = name.as_ref();
+        let Some(codec) = self.codecs.get(name) else {
+            return Err(InvalidCustomAttribute(name.to_string()));
+        };
+        if codec.type_id != TypeId::of::<T>() {
+            return Err(InvalidCustomAttribute(name.to_string()));
+        }
+        let name_index = constant_pool.add_utf8(name)?;
+        let value = CustomAttribute {
+            name: name.to_string(),
+            value: Arc::new(value),
+            serializer: codec.serializer.clone(),
+        };
+        Ok(Attribute::Custom { name_index, value })
+    }
+}
+
+impl fmt::Debug for AttributeRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names = self.codecs.keys().collect::<Vec<_>>();
+        names.sort();
+        f.debug_struct("AttributeRegistry")
+            .field("codecs", &names)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+    use std::io::Cursor;
+
+    #[derive(Debug, PartialEq)]
+    struct SourceMap {
+        file_index: u16,
+        lines: Vec<u16>,
+    }
+
+    fn parse(constant_pool: &ConstantPool, bytes: &[u8]) -> Result<SourceMap> {
+        let mut bytes = Cursor::new(bytes);
+        let file_index = bytes.read_u16::<BigEndian>()?;
+        constant_pool.try_get_utf8(file_index)?;
+        let mut lines = Vec::new();
+        while let Ok(line) = bytes.read_u16::<BigEndian>() {
+            lines.push(line);
+        }
+        Ok(SourceMap { file_index, lines })
+    }
+
+    fn serialize(source_map: &SourceMap) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        bytes.write_u16::<BigEndian>(source_map.file_index)?;
+        for line in &source_map.lines {
+            bytes.write_u16::<BigEndian>(*line)?;
+        }
+        Ok(bytes)
+    }
+
+    fn registry() -> AttributeRegistry {
+        let mut registry = AttributeRegistry::new();
+        registry.register("SourceMap", parse, serialize);
+        registry
+    }
+
+    #[test]
+    fn test_decode() -> Result<()> {
+        let registry = registry();
+        let mut constant_pool = ConstantPool::default();
+        let name_index = constant_pool.add_utf8("SourceMap")?;
+        let file_index = constant_pool.add_utf8("Main.kt")?;
+        let attribute = Attribute::Unknown {
+            name_index,
+            info: vec![0, 2, 0, 7, 0, 9],
+        };
+
+        let decoded = registry.decode(&constant_pool, &attribute)?;
+        let Attribute::Custom { value, .. } = decoded.as_ref() else {
+            panic!("expected custom attribute");
+        };
+        assert_eq!("SourceMap", value.name());
+        assert_eq!(
+            Some(&SourceMap {
+                file_index,
+                lines: vec![7, 9]
+            }),
+            value.value::<SourceMap>()
+        );
+        assert!(value.value::<String>().is_none());
+
+        let mut bytes = Vec::new();
+        decoded.to_bytes(&mut bytes)?;
+        let mut expected_bytes = Vec::new();
+        attribute.to_bytes(&mut expected_bytes)?;
+        assert_eq!(expected_bytes, bytes);
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_unregistered() -> Result<()> {
+        let registry = registry();
+        let mut constant_pool = ConstantPool::default();
+        let name_index = constant_pool.add_utf8("ScalaSig")?;
+        let attribute = Attribute::Unknown {
+            name_index,
+            info: vec![5, 0, 0],
+        };
+        assert!(!registry.is_registered("ScalaSig"));
+        let decoded = registry.decode(&constant_pool, &attribute)?;
+        assert!(matches!(decoded, Cow::Borrowed(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_error() -> Result<()> {
+        let registry = registry();
+        let mut constant_pool = ConstantPool::default();
+        let name_index = constant_pool.add_utf8("SourceMap")?;
+        let attribute = Attribute::Unknown {
+            name_index,
+            info: vec![0, 42],
+        };
+        assert!(registry.decode(&constant_pool, &attribute).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode() -> Result<()> {
+        let registry = registry();
+        let mut constant_pool = ConstantPool::default();
+        let file_index = constant_pool.add_utf8("Main.kt")?;
+        let source_map = SourceMap {
+            file_index,
+            lines: vec![3],
+        };
+        let attribute = registry.encode(&mut constant_pool, "SourceMap", source_map)?;
+        assert_eq!("Custom", attribute.name());
+
+        let mut bytes = Vec::new();
+        attribute.to_bytes(&mut bytes)?;
+        assert_eq!(vec![0, 2, 0, 0, 0, 4, 0, 1, 0, 3], bytes);
+        let mut bytes = Cursor::new(bytes);
+        let unknown = Attribute::from_bytes(&constant_pool, &mut bytes)?;
+        assert_eq!(
+            attribute,
+            registry.decode(&constant_pool, &unknown)?.into_owned()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_errors() {
+        let registry = registry();
+        let mut constant_pool = ConstantPool::default();
+        assert_eq!(
+            Err(InvalidCustomAttribute("ScalaSig".to_string())),
+            registry.encode(&mut constant_pool, "ScalaSig", vec![1u8])
+        );
+        assert_eq!(
+            Err(InvalidCustomAttribute("SourceMap".to_string())),
+            registry.encode(&mut constant_pool, "SourceMap", vec![1u8])
+        );
+    }
+
+    #[test]
+    fn test_custom_attribute() -> Result<()> {
+        let attribute =
+            CustomAttribute::new("Marker", 42u16, |value| Ok(value.to_be_bytes().to_vec()));
+        assert_eq!("Marker", attribute.name());
+        assert_eq!(Some(&42), attribute.value::<u16>());
+        assert_eq!(vec![0, 42], attribute.to_bytes()?);
+        assert_eq!(
+            "CustomAttribute { name: \"Marker\", value: 42, .. }",
+            format!("{attribute:?}")
+        );
+        assert_eq!(attribute, attribute.clone());
+        let other = CustomAttribute::new("Marker", 42u32, |value| Ok(value.to_be_bytes().to_vec()));
+        assert_ne!(attribute, other);
+        Ok(())
+    }
+
+    #[test]
+    fn test_registry_debug() {
+        assert_eq!(
+            "AttributeRegistry { codecs: [\"SourceMap\"] }",
+            format!("{:?}", registry())
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let attribute =
+            CustomAttribute::new("Marker", 42u16, |value| Ok(value.to_be_bytes().to_vec()));
+        let json = serde_json::to_string(&attribute).expect("serialize");
+        assert_eq!(r#"{"name":"Marker","info":[0,42]}"#, json);
+        let deserialized: CustomAttribute = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(Some(&vec![0u8, 42]), deserialized.value::<Vec<u8>>());
+        assert_eq!(Some(vec![0, 42]), deserialized.to_bytes().ok());
+    }
+}
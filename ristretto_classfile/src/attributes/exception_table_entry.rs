@@ -11,6 +11,7 @@ use std::ops::Range;
 ///
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.3>
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExceptionTableEntry {
     pub range_pc: Range<u16>,
     pub handler_pc: u16,
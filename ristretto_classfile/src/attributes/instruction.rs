@@ -1,4 +1,4 @@
-use crate::attributes::ArrayType;
+use crate::attributes::{offset_utils, ArrayType};
 use crate::error::Error::InvalidInstruction;
 use crate::error::Result;
 use crate::ConstantPool;
@@ -13,6 +13,7 @@ use std::io::Cursor;
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5>
 #[expect(non_camel_case_types)]
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Instruction {
     /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.nop>
     Nop,
@@ -1276,6 +1277,28 @@ impl Instruction {
         };
         Ok(value)
     }
+
+    /// Deserialize the bytes of a `Code` attribute into instructions; branch targets are
+    /// converted from byte offsets to instruction indexes.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not valid instructions or a branch target is not the
+    /// start of an instruction.
+    pub fn code_from_bytes(bytes: Vec<u8>) -> Result<Vec<Instruction>> {
+        let (_, code) = offset_utils::instructions_from_bytes(&mut Cursor::new(bytes))?;
+        Ok(code)
+    }
+
+    /// Serialize instructions to the bytes of a `Code` attribute; branch targets are converted
+    /// from instruction indexes to byte offsets.
+    ///
+    /// # Errors
+    /// Returns an error if a branch target is not a valid instruction index or the code is longer
+    /// than 65535 bytes.
+    pub fn code_to_bytes(code: &[Instruction]) -> Result<Vec<u8>> {
+        let (_, bytes) = offset_utils::instructions_to_bytes(code)?;
+        Ok(bytes)
+    }
 }
 
 impl fmt::Display for Instruction {
@@ -1534,9 +1557,39 @@ impl fmt::Display for Instruction {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::Error::InvalidInstructionOffset;
     use indoc::indoc;
     use std::io::Read;
 
+    #[test]
+    fn test_code_bytes() -> Result<()> {
+        let code = vec![
+            Instruction::Iload_0,
+            Instruction::Ifeq(4),
+            Instruction::Ldc_w(1),
+            Instruction::Goto(5),
+            Instruction::Nop,
+            Instruction::Return,
+        ];
+        let bytes = Instruction::code_to_bytes(&code)?;
+        assert_eq!(
+            vec![0x1a, 0x99, 0x00, 0x09, 0x13, 0x00, 0x01, 0xa7, 0x00, 0x04, 0x00, 0xb1],
+            bytes
+        );
+        assert_eq!(code, Instruction::code_from_bytes(bytes)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_code_from_bytes_invalid_branch() {
+        // goto into the middle of the goto instruction
+        let bytes = vec![0xa7, 0x00, 0x01];
+        assert_eq!(
+            Err(InvalidInstructionOffset(1)),
+            Instruction::code_from_bytes(bytes)
+        );
+    }
+
     #[test]
     fn test_invalid_instructions() -> Result<()> {
         for code in 203..253 {
@@ -0,0 +1,148 @@
+use crate::attributes::{offset_utils, Instruction};
+use crate::error::Error::InvalidInstructionOffset;
+use crate::error::Result;
+
+/// Byte offsets (`pc`) of the instructions of a `Code` attribute.
+///
+/// Instructions are modeled with instruction index based branch targets, while attributes such as
+/// the exception table, `LineNumberTable`, `LocalVariableTable` and `StackMapTable` refer to byte
+/// offsets.  `InstructionOffsets` converts between the two in both directions; the number of
+/// instructions and the code length are also accepted so that exclusive range ends (e.g.
+/// `end_pc`) can be converted.
+///
+/// # Examples
+///
+/// ```rust
+/// use ristretto_classfile::attributes::{Instruction, InstructionOffsets};
+///
+/// let code = vec![Instruction::Iconst_0, Instruction::Istore(1), Instruction::Return];
+/// let offsets = InstructionOffsets::new(&code)?;
+/// assert_eq!(1, offsets.byte_offset(1)?);
+/// assert_eq!(3, offsets.byte_offset(2)?);
+/// assert_eq!(2, offsets.instruction_index(3)?);
+/// assert_eq!(4, offsets.code_length());
+/// # Ok::<(), ristretto_classfile::Error>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InstructionOffsets {
+    offsets: Vec<u16>,
+    code_length: u16,
+}
+
+impl InstructionOffsets {
+    /// Compute the byte offset of each instruction.
+    ///
+    /// # Errors
+    /// If an instruction cannot be serialized, a branch target is not a valid instruction index or
+    /// the code is longer than 65535 bytes.
+    pub fn new(code: &[Instruction]) -> Result<Self> {
+        let (instruction_to_byte, bytes) = offset_utils::instructions_to_bytes(code)?;
+        let mut offsets = instruction_to_byte.into_values().collect::<Vec<_>>();
+        offsets.sort_unstable();
+        let code_length = u16::try_from(bytes.len())?;
+        Ok(InstructionOffsets {
+            offsets,
+            code_length,
+        })
+    }
+
+    /// Get the number of instructions.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Returns true if there are no instructions.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+
+    /// Get the length of the code in bytes.
+    #[must_use]
+    pub fn code_length(&self) -> u16 {
+        self.code_length
+    }
+
+    /// Get the byte offset of the instruction at an index; the number of instructions maps to the
+    /// code length.
+    ///
+    /// # Errors
+    /// If the index is greater than the number of instructions.
+    pub fn byte_offset(&self, index: usize) -> Result<u16> {
+        if index == self.offsets.len() {
+            return Ok(self.code_length);
+        }
+        self.offsets
+            .get(index)
+            .copied()
+            .ok_or(InvalidInstructionOffset(u32::try_from(index)?))
+    }
+
+    /// Get the index of the instruction at a byte offset; the code length maps to the number of
+    /// instructions.
+    ///
+    /// # Errors
+    /// If the byte offset is not the start of an instruction or the code length.
+    pub fn instruction_index(&self, byte_offset: u32) -> Result<usize> {
+        if byte_offset == u32::from(self.code_length) {
+            return Ok(self.offsets.len());
+        }
+        let offset = u16::try_from(byte_offset)?;
+        self.offsets
+            .binary_search(&offset)
+            .map_err(|_| InvalidInstructionOffset(byte_offset))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indexmap::IndexMap;
+
+    #[test]
+    fn test_instruction_offsets() -> Result<()> {
+        let code = vec![
+            Instruction::Iload_0,
+            Instruction::Lookupswitch {
+                default: 2,
+                pairs: IndexMap::from([(1, 2)]),
+            },
+            Instruction::Ldc_w(1),
+            Instruction::Return,
+        ];
+        let offsets = InstructionOffsets::new(&code)?;
+        // lookupswitch at byte 1: opcode, 2 bytes of padding, default, npairs and one pair
+        assert_eq!(4, offsets.len());
+        assert!(!offsets.is_empty());
+        assert_eq!(24, offsets.code_length());
+        for (index, byte_offset) in [(0, 0), (1, 1), (2, 20), (3, 23), (4, 24)] {
+            assert_eq!(byte_offset, offsets.byte_offset(index)?);
+            assert_eq!(index, offsets.instruction_index(u32::from(byte_offset))?);
+        }
+        assert_eq!(Err(InvalidInstructionOffset(5)), offsets.byte_offset(5));
+        assert_eq!(
+            Err(InvalidInstructionOffset(2)),
+            offsets.instruction_index(2)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_instruction_offsets_empty() -> Result<()> {
+        let offsets = InstructionOffsets::new(&[])?;
+        assert!(offsets.is_empty());
+        assert_eq!(0, offsets.byte_offset(0)?);
+        assert_eq!(0, offsets.instruction_index(0)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_instruction_offsets_invalid_branch_target() {
+        let code = vec![Instruction::Goto(42), Instruction::Return];
+        assert_eq!(
+            Err(InvalidInstructionOffset(42)),
+            InstructionOffsets::new(&code)
+        );
+    }
+}
@@ -0,0 +1,436 @@
+use crate::attributes::Instruction;
+use crate::constant_pool::ConstantPool;
+use std::fmt;
+use std::ops::Range;
+
+/// Predicate that determines if an instruction matches a pattern element.
+type Predicate<'a> = Box<dyn Fn(&Instruction, &ConstantPool) -> bool + Send + Sync + 'a>;
+
+/// An element of an [`InstructionPattern`].
+pub enum PatternElement<'a> {
+    /// Matches one instruction that satisfies a predicate
+    Instruction {
+        description: String,
+        predicate: Predicate<'a>,
+    },
+    /// Matches any one instruction; written `_` in [`pattern!`](crate::pattern)
+    Any,
+    /// Matches any sequence of zero or more instructions, preferring the shortest sequence;
+    /// written `..` in [`pattern!`](crate::pattern)
+    Gap,
+}
+
+impl<'a> PatternElement<'a> {
+    /// Create an element that matches one instruction that satisfies a predicate; the
+    /// description is used when the element is formatted.
+    pub fn new<S, F>(description: S, predicate: F) -> Self
+    where
+        S: AsRef<str>,
+        F: Fn(&Instruction, &ConstantPool) -> bool + Send + Sync + 'a,
+    {
+        PatternElement::Instruction {
+            description: description.as_ref().to_string(),
+            predicate: Box::new(predicate),
+        }
+    }
+}
+
+impl fmt::Debug for PatternElement<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl fmt::Display for PatternElement<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternElement::Instruction { description, .. } => write!(f, "{description}"),
+            PatternElement::Any => write!(f, "_"),
+            PatternElement::Gap => write!(f, ".."),
+        }
+    }
+}
+
+/// A pattern that matches sequences of instructions, such as call sites and idioms.
+///
+/// Patterns are usually created with the [`pattern!`](crate::pattern) macro, which takes a comma
+/// separated list of elements:
+///
+/// - an [`Instruction`] pattern without the `Instruction::` prefix, e.g. `Aload(_)` or
+///   `Invokevirtual(index)`, optionally followed by an `if` guard
+/// - `_`, which matches any one instruction
+/// - `..`, which matches any sequence of zero or more instructions
+///
+/// Guards can use the bindings of the instruction pattern and a constant pool, which is named by
+/// prefixing the elements with `name =>`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ristretto_classfile::attributes::Instruction;
+/// use ristretto_classfile::{pattern, ConstantPool};
+///
+/// let mut constant_pool = ConstantPool::default();
+/// let print_stream = constant_pool.add_class("java/io/PrintStream")?;
+/// let println = constant_pool.add_method_ref(print_stream, "println", "(Ljava/lang/String;)V")?;
+/// let code = vec![
+///     Instruction::Aload_0,
+///     Instruction::Aload_1,
+///     Instruction::Invokevirtual(println),
+///     Instruction::Return,
+/// ];
+///
+/// let pattern = pattern![constant_pool =>
+///     Aload_1 | Aload(_),
+///     Invokevirtual(index) if constant_pool.is_member_ref(*index, "java/io/PrintStream", "println"),
+/// ];
+/// assert_eq!(Some(1..3), pattern.find(&code, &constant_pool));
+/// # Ok::<(), ristretto_classfile::Error>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct InstructionPattern<'a> {
+    elements: Vec<PatternElement<'a>>,
+}
+
+impl<'a> InstructionPattern<'a> {
+    /// Create a pattern from a list of elements.
+    #[must_use]
+    pub fn new(elements: Vec<PatternElement<'a>>) -> Self {
+        InstructionPattern { elements }
+    }
+
+    /// Get the elements of the pattern.
+    #[must_use]
+    pub fn elements(&self) -> &[PatternElement<'a>] {
+        &self.elements
+    }
+
+    /// Match the pattern against the instructions starting at an index.  Returns the range of the
+    /// matched instructions, which is the shortest match when the pattern contains gaps.
+    #[must_use]
+    pub fn match_at(
+        &self,
+        code: &[Instruction],
+        constant_pool: &ConstantPool,
+        index: usize,
+    ) -> Option<Range<usize>> {
+        if index > code.len() {
+            return None;
+        }
+        let end = Self::match_elements(&self.elements, code, constant_pool, index)?;
+        Some(index..end)
+    }
+
+    /// Find the first match of the pattern in the instructions.
+    #[must_use]
+    pub fn find(&self, code: &[Instruction], constant_pool: &ConstantPool) -> Option<Range<usize>> {
+        (0..=code.len()).find_map(|index| self.match_at(code, constant_pool, index))
+    }
+
+    /// Find the non-overlapping matches of the pattern in the instructions, in order.
+    #[must_use]
+    pub fn find_all(
+        &self,
+        code: &[Instruction],
+        constant_pool: &ConstantPool,
+    ) -> Vec<Range<usize>> {
+        let mut matches = Vec::new();
+        let mut index = 0;
+        while index <= code.len() {
+            match self.match_at(code, constant_pool, index) {
+                Some(range) => {
+                    // Empty matches are reported once per index
+                    index = range.end.max(index + 1);
+                    matches.push(range);
+                }
+                None => index += 1,
+            }
+        }
+        matches
+    }
+
+    /// Match elements against the instructions starting at an index, returning the index after
+    /// the last matched instruction.
+    fn match_elements(
+        elements: &[PatternElement<'a>],
+        code: &[Instruction],
+        constant_pool: &ConstantPool,
+        index: usize,
+    ) -> Option<usize> {
+        let Some((element, elements)) = elements.split_first() else {
+            return Some(index);
+        };
+        match element {
+            PatternElement::Instruction { predicate, .. } => {
+                let instruction = code.get(index)?;
+                if !predicate(instruction, constant_pool) {
+                    return None;
+                }
+                Self::match_elements(elements, code, constant_pool, index + 1)
+            }
+            PatternElement::Any => {
+                if index >= code.len() {
+                    return None;
+                }
+                Self::match_elements(elements, code, constant_pool, index + 1)
+            }
+            PatternElement::Gap => (index..=code.len())
+                .find_map(|start| Self::match_elements(elements, code, constant_pool, start)),
+        }
+    }
+}
+
+impl fmt::Display for InstructionPattern<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let elements = self
+            .elements
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        write!(f, "[{}]", elements.join(", "))
+    }
+}
+
+/// Create an [`InstructionPattern`](crate::attributes::InstructionPattern).
+///
+/// Elements are [`Instruction`](crate::attributes::Instruction) patterns without the
+/// `Instruction::` prefix with optional `if` guards, `_` for any one instruction and `..` for any
+/// sequence of instructions.  Prefix the elements with `name =>` to use the constant pool in
+/// guards.
+///
+/// # Examples
+///
+/// ```rust
+/// use ristretto_classfile::attributes::Instruction;
+/// use ristretto_classfile::{pattern, ConstantPool};
+///
+/// let constant_pool = ConstantPool::default();
+/// let code = vec![Instruction::Iload_0, Instruction::Ifeq(3), Instruction::Nop, Instruction::Return];
+/// let pattern = pattern![Iload_0, Ifeq(target) if *target > 1, .., Return];
+/// assert_eq!(Some(0..4), pattern.find(&code, &constant_pool));
+/// ```
+#[macro_export]
+macro_rules! pattern {
+    (@elements $constant_pool:ident [$($element:expr,)*]) => {
+        $crate::attributes::InstructionPattern::new(vec![$($element),*])
+    };
+    (@elements $constant_pool:ident [$($element:expr,)*] _ $(, $($rest:tt)*)?) => {
+        $crate::pattern!(
+            @elements $constant_pool
+            [$($element,)* $crate::attributes::PatternElement::Any,]
+            $($($rest)*)?
+        )
+    };
+    (@elements $constant_pool:ident [$($element:expr,)*] .. $(, $($rest:tt)*)?) => {
+        $crate::pattern!(
+            @elements $constant_pool
+            [$($element,)* $crate::attributes::PatternElement::Gap,]
+            $($($rest)*)?
+        )
+    };
+    (
+        @elements $constant_pool:ident [$($element:expr,)*]
+        $($variant:ident $(($($arguments:tt)*))? $({$($fields:tt)*})?)|+
+        $(if $guard:expr)?
+        $(, $($rest:tt)*)?
+    ) => {
+        $crate::pattern!(
+            @elements $constant_pool
+            [
+                $($element,)*
+                $crate::attributes::PatternElement::new(
+                    {
+                        let alternatives: &[&str] = &[$(
+                            concat!(
+                                stringify!($variant)
+                                $(, "(", stringify!($($arguments)*), ")")?
+                                $(, " { ", stringify!($($fields)*), " }")?
+                            )
+                        ),+];
+                        [
+                            alternatives.join(" | ")
+                            $(, concat!("if ", stringify!($guard)).to_string())?
+                        ]
+                        .join(" ")
+                    },
+                    move |instruction: &$crate::attributes::Instruction,
+                          $constant_pool: &$crate::ConstantPool| {
+                        let _ = $constant_pool;
+                        matches!(
+                            instruction,
+                            $($crate::attributes::Instruction::$variant
+                                $(($($arguments)*))? $({$($fields)*})?)|+
+                            $(if $guard)?
+                        )
+                    },
+                ),
+            ]
+            $($($rest)*)?
+        )
+    };
+    ($constant_pool:ident => $($elements:tt)*) => {
+        $crate::pattern!(@elements $constant_pool [] $($elements)*)
+    };
+    ($($elements:tt)*) => {
+        $crate::pattern!(@elements constant_pool [] $($elements)*)
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Result;
+
+    fn constant_pool() -> Result<(ConstantPool, u16, u16)> {
+        let mut constant_pool = ConstantPool::default();
+        let print_stream = constant_pool.add_class("java/io/PrintStream")?;
+        let println =
+            constant_pool.add_method_ref(print_stream, "println", "(Ljava/lang/String;)V")?;
+        let system = constant_pool.add_class("java/lang/System")?;
+        let out = constant_pool.add_field_ref(system, "out", "Ljava/io/PrintStream;")?;
+        Ok((constant_pool, println, out))
+    }
+
+    #[test]
+    fn test_match_at() -> Result<()> {
+        let (constant_pool, _, _) = constant_pool()?;
+        let code = vec![
+            Instruction::Iconst_1,
+            Instruction::Istore_0,
+            Instruction::Return,
+        ];
+        let pattern = pattern![Iconst_1, Istore_0];
+        assert_eq!(Some(0..2), pattern.match_at(&code, &constant_pool, 0));
+        assert_eq!(None, pattern.match_at(&code, &constant_pool, 1));
+        assert_eq!(None, pattern.match_at(&code, &constant_pool, 3));
+        assert_eq!(None, pattern.match_at(&code, &constant_pool, 4));
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_with_constant_pool_guard() -> Result<()> {
+        let (constant_pool, println, out) = constant_pool()?;
+        let code = vec![
+            Instruction::Getstatic(out),
+            Instruction::Aload_0,
+            Instruction::Invokevirtual(println),
+            Instruction::Return,
+        ];
+        let pattern = pattern![constant_pool =>
+            Getstatic(index) if constant_pool.is_member_ref(*index, "java/lang/System", "out"),
+            _,
+            Invokevirtual(index) if constant_pool.is_member_ref(*index, "java/io/PrintStream", "println"),
+        ];
+        assert_eq!(Some(0..3), pattern.find(&code, &constant_pool));
+
+        let pattern = pattern![constant_pool =>
+            Invokevirtual(index) if constant_pool.is_member_ref(*index, "java/io/PrintStream", "print"),
+        ];
+        assert_eq!(None, pattern.find(&code, &constant_pool));
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_with_captured_guard() -> Result<()> {
+        let (constant_pool, _, _) = constant_pool()?;
+        let code = vec![Instruction::Bipush(3), Instruction::Bipush(42)];
+        let value = 42;
+        let pattern = pattern![Bipush(operand) if *operand == value];
+        assert_eq!(Some(1..2), pattern.find(&code, &constant_pool));
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_alternatives_and_fields() -> Result<()> {
+        let (constant_pool, _, _) = constant_pool()?;
+        let code = vec![
+            Instruction::Iload(4),
+            Instruction::Tableswitch {
+                default: 1,
+                low: 0,
+                high: 0,
+                offsets: vec![1],
+            },
+            Instruction::Return,
+        ];
+        let pattern = pattern![Iload_0 | Iload(_), Tableswitch { low: 0, .. }];
+        assert_eq!(Some(0..2), pattern.find(&code, &constant_pool));
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_gap() -> Result<()> {
+        let (constant_pool, _, _) = constant_pool()?;
+        let code = vec![
+            Instruction::Aload_0,
+            Instruction::Nop,
+            Instruction::Nop,
+            Instruction::Areturn,
+            Instruction::Aload_0,
+            Instruction::Areturn,
+        ];
+        let pattern = pattern![Aload_0, .., Areturn];
+        assert_eq!(Some(0..4), pattern.find(&code, &constant_pool));
+        assert_eq!(vec![0..4, 4..6], pattern.find_all(&code, &constant_pool));
+
+        let pattern = pattern![Aload_0, .., Ireturn];
+        assert_eq!(None, pattern.find(&code, &constant_pool));
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_all() -> Result<()> {
+        let (constant_pool, _, _) = constant_pool()?;
+        let code = vec![
+            Instruction::Nop,
+            Instruction::Nop,
+            Instruction::Nop,
+            Instruction::Return,
+        ];
+        let pattern = pattern![Nop, Nop];
+        assert_eq!(vec![0..2], pattern.find_all(&code, &constant_pool));
+        let pattern = pattern![Nop, _];
+        assert_eq!(vec![0..2, 2..4], pattern.find_all(&code, &constant_pool));
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_pattern() -> Result<()> {
+        let (constant_pool, _, _) = constant_pool()?;
+        let code = vec![Instruction::Return];
+        let pattern = InstructionPattern::default();
+        assert_eq!(Some(0..0), pattern.find(&code, &constant_pool));
+        assert_eq!(vec![0..0, 1..1], pattern.find_all(&code, &constant_pool));
+        Ok(())
+    }
+
+    #[test]
+    fn test_pattern_element_new() -> Result<()> {
+        let (constant_pool, _, _) = constant_pool()?;
+        let code = vec![Instruction::Iconst_0, Instruction::Ireturn];
+        let pattern = InstructionPattern::new(vec![
+            PatternElement::Any,
+            PatternElement::new("return", |instruction, _| {
+                matches!(instruction, Instruction::Ireturn | Instruction::Return)
+            }),
+        ]);
+        assert_eq!(2, pattern.elements().len());
+        assert_eq!(Some(0..2), pattern.find(&code, &constant_pool));
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_string() {
+        let pattern = pattern![Aload(_), _, .., Invokevirtual(index) if *index == 1];
+        assert_eq!(
+            "[Aload(_), _, .., Invokevirtual(index) if *index == 1]",
+            pattern.to_string()
+        );
+        assert_eq!("Aload(_)", format!("{:?}", pattern.elements()[0]));
+        let pattern = pattern![Iload_0 | Iload(_), Tableswitch { low: 0, .. }];
+        assert_eq!(
+            "[Iload_0 | Iload(_), Tableswitch { low: 0, .. }]",
+            pattern.to_string()
+        );
+    }
+}
@@ -1,14 +1,19 @@
 mod annotation;
 mod annotation_element;
+mod annotation_value;
 mod annotation_value_pair;
 mod array_type;
 mod attribute;
 mod bootstrap_method;
+mod code_editor;
+mod custom_attribute;
 mod exception_table_entry;
 mod exports;
 mod exports_flags;
 mod inner_class;
 mod instruction;
+mod instruction_offsets;
+mod instruction_pattern;
 mod line_number;
 mod local_variable_table;
 mod local_variable_target;
@@ -16,7 +21,7 @@ mod local_variable_type_table;
 mod method_parameter;
 mod module_access_flags;
 mod nested_class_access_flags;
-mod offset_utils;
+pub(crate) mod offset_utils;
 mod opens;
 mod opens_flags;
 mod parameter_annotation;
@@ -32,15 +37,20 @@ mod verification_type;
 
 pub use annotation::Annotation;
 pub use annotation_element::AnnotationElement;
+pub use annotation_value::AnnotationValue;
 pub use annotation_value_pair::AnnotationValuePair;
 pub use array_type::ArrayType;
 pub use attribute::Attribute;
 pub use bootstrap_method::BootstrapMethod;
+pub use code_editor::CodeEditor;
+pub use custom_attribute::{AttributeRegistry, CustomAttribute};
 pub use exception_table_entry::ExceptionTableEntry;
 pub use exports::Exports;
 pub use exports_flags::ExportsFlags;
 pub use inner_class::InnerClass;
 pub use instruction::Instruction;
+pub use instruction_offsets::InstructionOffsets;
+pub use instruction_pattern::{InstructionPattern, PatternElement};
 pub use line_number::LineNumber;
 pub use local_variable_table::LocalVariableTable;
 pub use local_variable_target::LocalVariableTarget;
@@ -9,6 +9,7 @@ bitflags! {
     ///
     /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.25>
     #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct ModuleAccessFlags: u16 {
         /// Indicates that this module is open.
         const OPEN = 0x0020;
@@ -9,6 +9,7 @@ bitflags! {
     ///
     /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.6-300-D.1-D.1>
     #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct NestedClassAccessFlags: u16 {
         /// Declared public; may be accessed from outside its package.
         const PUBLIC = 0x0001;
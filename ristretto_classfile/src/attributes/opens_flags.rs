@@ -9,6 +9,7 @@ bitflags! {
     ///
     /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.25>
     #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct OpensFlags: u16 {
         /// Indicates that this opening was not explicitly or implicitly declared in the source of
         /// the module declaration.
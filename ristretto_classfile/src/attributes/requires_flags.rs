@@ -9,6 +9,7 @@ bitflags! {
     ///
     /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.25>
     #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct RequiresFlags: u16 {
         /// Indicates that any module which depends on the current module, implicitly declares a
         /// dependence on the module indicated by this entry.
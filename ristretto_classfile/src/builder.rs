@@ -0,0 +1,205 @@
+use crate::attributes::Instruction;
+use crate::base_type::BaseType;
+use crate::constant::Constant;
+use crate::constant_pool::ConstantPool;
+use crate::error::Result;
+use crate::field_type::FieldType;
+use crate::reference_kind::ReferenceKind;
+use std::collections::HashMap;
+
+/// Constant pool that reuses constants that have already been added.
+#[derive(Debug, Default)]
+pub(crate) struct Constants {
+    pub(crate) constant_pool: ConstantPool,
+    utf8: HashMap<String, u16>,
+    classes: HashMap<String, u16>,
+    modules: HashMap<String, u16>,
+    packages: HashMap<String, u16>,
+    strings: HashMap<String, u16>,
+    name_and_types: HashMap<(u16, u16), u16>,
+    field_refs: HashMap<(u16, u16), u16>,
+    method_refs: HashMap<(u16, u16), u16>,
+    method_handles: HashMap<(u8, u16), u16>,
+}
+
+impl Constants {
+    pub(crate) fn utf8(&mut self, value: &str) -> Result<u16> {
+        if let Some(index) = self.utf8.get(value) {
+            return Ok(*index);
+        }
+        let index = self.constant_pool.add_utf8(value)?;
+        self.utf8.insert(value.to_string(), index);
+        Ok(index)
+    }
+
+    pub(crate) fn optional_utf8(&mut self, value: Option<&String>) -> Result<u16> {
+        match value {
+            Some(value) => self.utf8(value),
+            None => Ok(0),
+        }
+    }
+
+    pub(crate) fn class(&mut self, name: &str) -> Result<u16> {
+        if let Some(index) = self.classes.get(name) {
+            return Ok(*index);
+        }
+        let name_index = self.utf8(name)?;
+        let index = self.constant_pool.add(Constant::Class(name_index))?;
+        self.classes.insert(name.to_string(), index);
+        Ok(index)
+    }
+
+    pub(crate) fn module(&mut self, name: &str) -> Result<u16> {
+        if let Some(index) = self.modules.get(name) {
+            return Ok(*index);
+        }
+        let name_index = self.utf8(name)?;
+        let index = self.constant_pool.add(Constant::Module(name_index))?;
+        self.modules.insert(name.to_string(), index);
+        Ok(index)
+    }
+
+    pub(crate) fn modules(&mut self, names: &[String]) -> Result<Vec<u16>> {
+        names.iter().map(|name| self.module(name)).collect()
+    }
+
+    pub(crate) fn package(&mut self, name: &str) -> Result<u16> {
+        if let Some(index) = self.packages.get(name) {
+            return Ok(*index);
+        }
+        let name_index = self.utf8(name)?;
+        let index = self.constant_pool.add(Constant::Package(name_index))?;
+        self.packages.insert(name.to_string(), index);
+        Ok(index)
+    }
+
+    pub(crate) fn string(&mut self, value: &str) -> Result<u16> {
+        if let Some(index) = self.strings.get(value) {
+            return Ok(*index);
+        }
+        let string_index = self.utf8(value)?;
+        let index = self.constant_pool.add(Constant::String(string_index))?;
+        self.strings.insert(value.to_string(), index);
+        Ok(index)
+    }
+
+    pub(crate) fn name_and_type(&mut self, name: &str, descriptor: &str) -> Result<u16> {
+        let name_index = self.utf8(name)?;
+        let descriptor_index = self.utf8(descriptor)?;
+        let key = (name_index, descriptor_index);
+        if let Some(index) = self.name_and_types.get(&key) {
+            return Ok(*index);
+        }
+        let index = self.constant_pool.add(Constant::NameAndType {
+            name_index,
+            descriptor_index,
+        })?;
+        self.name_and_types.insert(key, index);
+        Ok(index)
+    }
+
+    pub(crate) fn field_ref(&mut self, class: &str, name: &str, descriptor: &str) -> Result<u16> {
+        let class_index = self.class(class)?;
+        let name_and_type_index = self.name_and_type(name, descriptor)?;
+        let key = (class_index, name_and_type_index);
+        if let Some(index) = self.field_refs.get(&key) {
+            return Ok(*index);
+        }
+        let index = self.constant_pool.add(Constant::FieldRef {
+            class_index,
+            name_and_type_index,
+        })?;
+        self.field_refs.insert(key, index);
+        Ok(index)
+    }
+
+    pub(crate) fn method_ref(&mut self, class: &str, name: &str, descriptor: &str) -> Result<u16> {
+        let class_index = self.class(class)?;
+        let name_and_type_index = self.name_and_type(name, descriptor)?;
+        let key = (class_index, name_and_type_index);
+        if let Some(index) = self.method_refs.get(&key) {
+            return Ok(*index);
+        }
+        let index = self.constant_pool.add(Constant::MethodRef {
+            class_index,
+            name_and_type_index,
+        })?;
+        self.method_refs.insert(key, index);
+        Ok(index)
+    }
+
+    pub(crate) fn method_handle(
+        &mut self,
+        reference_kind: ReferenceKind,
+        reference_index: u16,
+    ) -> Result<u16> {
+        let key = (reference_kind.kind(), reference_index);
+        if let Some(index) = self.method_handles.get(&key) {
+            return Ok(*index);
+        }
+        let index = self
+            .constant_pool
+            .add_method_handle(reference_kind, reference_index)?;
+        self.method_handles.insert(key, index);
+        Ok(index)
+    }
+}
+
+/// Convert a binary package or class name to its internal form.
+pub(crate) fn internal_name<S: AsRef<str>>(name: S) -> String {
+    name.as_ref().replace('.', "/")
+}
+
+/// Get the instruction that loads a local variable of the field type.
+pub(crate) fn load_instruction(field_type: &FieldType, slot: u8) -> Instruction {
+    match field_type {
+        FieldType::Base(BaseType::Long) => Instruction::Lload(slot),
+        FieldType::Base(BaseType::Float) => Instruction::Fload(slot),
+        FieldType::Base(BaseType::Double) => Instruction::Dload(slot),
+        FieldType::Base(_) => Instruction::Iload(slot),
+        FieldType::Object(_) | FieldType::Array(_) => Instruction::Aload(slot),
+    }
+}
+
+/// Get the instruction that returns a value of the field type.
+pub(crate) fn return_instruction(field_type: &FieldType) -> Instruction {
+    match field_type {
+        FieldType::Base(BaseType::Long) => Instruction::Lreturn,
+        FieldType::Base(BaseType::Float) => Instruction::Freturn,
+        FieldType::Base(BaseType::Double) => Instruction::Dreturn,
+        FieldType::Base(_) => Instruction::Ireturn,
+        FieldType::Object(_) | FieldType::Array(_) => Instruction::Areturn,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_constants_are_reused() -> Result<()> {
+        let mut constants = Constants::default();
+        let field_ref = constants.field_ref("Foo", "x", "I")?;
+        assert_eq!(field_ref, constants.field_ref("Foo", "x", "I")?);
+        let method_ref = constants.method_ref("Foo", "x", "()I")?;
+        assert_eq!(method_ref, constants.method_ref("Foo", "x", "()I")?);
+        let method_handle = constants.method_handle(ReferenceKind::GetField, field_ref)?;
+        assert_eq!(
+            method_handle,
+            constants.method_handle(ReferenceKind::GetField, field_ref)?
+        );
+        let string = constants.string("x")?;
+        assert_eq!(string, constants.string("x")?);
+
+        // Foo, x, I, ()I: 4 UTF-8 + 1 class + 2 name and type + 1 field + 1 method + 1 handle
+        // + 1 string
+        assert_eq!(11, constants.constant_pool.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_internal_name() {
+        assert_eq!("com/example/Foo", internal_name("com.example.Foo"));
+        assert_eq!("com/example/Foo", internal_name("com/example/Foo"));
+    }
+}
@@ -1,16 +1,32 @@
-use crate::attributes::Attribute;
+use crate::attributes::{Annotation, Attribute, AttributeRegistry, BootstrapMethod};
 use crate::class_access_flags::ClassAccessFlags;
 use crate::constant_pool::ConstantPool;
+use crate::dead_code::{self, DeadCode};
 use crate::display::indent_lines;
-use crate::error::Error::{InvalidMagicNumber, VerificationError};
+use crate::downgrade::{self, DowngradeIssue};
+use crate::error::Error::{InvalidConstantPoolIndex, InvalidMagicNumber, VerificationError};
 use crate::error::Result;
 use crate::field::Field;
+use crate::javap;
 use crate::method::Method;
-use crate::verifiers::verifier;
+use crate::metrics::{self, ClassMetrics};
+use crate::peephole;
+use crate::references;
+use crate::source_map::SourceMap;
+use crate::synthetic::{self, Accessor};
+use crate::verifiers::bytecode::class_resolver::{ClassResolver, ResolvedClass};
+use crate::verifiers::bytecode::diagnostics::{self, VerificationReport};
+use crate::verifiers::bytecode::{maximums, stack_map_table};
+use crate::verifiers::profile::{self, VerificationProfile};
+use crate::verifiers::{inner_classes, nestmates, verifier};
 use crate::version::Version;
+use crate::visitor::{self, ClassVisitor};
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use std::collections::HashMap;
 use std::fmt;
-use std::io::Cursor;
+use std::io::{Cursor, Read, Write};
+#[cfg(feature = "tokio")]
+use tokio::io::{AsyncRead, AsyncWrite};
 
 const MAGIC: u32 = 0xCAFE_BABE;
 
@@ -18,6 +34,7 @@ const MAGIC: u32 = 0xCAFE_BABE;
 ///
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.1>
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClassFile {
     pub version: Version,
     pub constant_pool: ConstantPool,
@@ -39,6 +56,14 @@ impl ClassFile {
         self.constant_pool.try_get_class(self.this_class)
     }
 
+    /// Get the runtime visible annotations of the class.
+    ///
+    /// # Errors
+    /// If an annotation attribute cannot be decoded.
+    pub fn annotations(&self) -> Result<Vec<Annotation>> {
+        Annotation::runtime_visible(&self.constant_pool, &self.attributes)
+    }
+
     /// Verify the `ClassFile`.
     ///
     /// # Errors
@@ -57,11 +82,444 @@ impl ClassFile {
         }
     }
 
+    /// Verify the `ClassFile` and check that it satisfies the version specific rules of a
+    /// [`VerificationProfile`]; e.g. that the attributes and instructions are supported by the
+    /// class file version.
+    ///
+    /// # Errors
+    /// Returns a `VerificationError` if the verification fails.
+    pub fn verify_with_profile(&self, profile: &VerificationProfile) -> Result<()> {
+        self.verify()?;
+        profile::verify(self, profile)
+    }
+
+    /// Get the name of the host of the nest that the class belongs to; a class that does not
+    /// declare a `NestHost` attribute is the host of its own nest.
+    ///
+    /// # Errors
+    /// Returns an error if the class name or nest host is not a valid class constant.
+    pub fn nest_host(&self) -> Result<&String> {
+        nestmates::nest_host(self)
+    }
+
+    /// Verify the nests declared by the class files and that private fields and methods of the
+    /// class files are only referenced by classes in the same nest.  Nest hosts and members that
+    /// are not one of the class files are not checked.
+    ///
+    /// # Errors
+    /// Returns a `VerificationError` if a nest host and member do not declare each other, or a
+    /// private member is referenced by a class that is not a nestmate.
+    pub fn verify_nestmates(class_files: &[&ClassFile]) -> Result<()> {
+        nestmates::verify(class_files)
+    }
+
+    /// Verify that the `InnerClasses` and `EnclosingMethod` attributes of the class files are
+    /// consistent; e.g. that an outer class and its member classes declare the same entry for each
+    /// member.  Classes that are not one of the class files are not checked.
+    ///
+    /// # Errors
+    /// Returns a `VerificationError` if the entries of an outer and inner class do not match, a
+    /// referenced nested class does not have an entry, or the `EnclosingMethod` attribute does not
+    /// match the kind of nested class.
+    pub fn verify_inner_classes(class_files: &[&ClassFile]) -> Result<()> {
+        inner_classes::verify(class_files)
+    }
+
+    /// Get the source map (SMAP) stored in the `SourceDebugExtension` attribute, if present.
+    ///
+    /// # Errors
+    /// Returns an error if the attribute does not contain a valid source map.
+    pub fn source_map(&self) -> Result<Option<SourceMap>> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::SourceDebugExtension {
+                    debug_extension, ..
+                } => Some(debug_extension.parse()),
+                _ => None,
+            })
+            .transpose()
+    }
+
+    /// Compute the `StackMapTable` attribute of every method from the method's instructions and
+    /// descriptor, replacing any existing frames.  Class files for Java 7 and later require
+    /// stack map frames to pass verification.
+    ///
+    /// Reference types are merged without class hierarchy information; when two different
+    /// classes meet at a branch target the frame records `java/lang/Object`.  Use
+    /// [`compute_stack_map_tables_with_resolver`](ClassFile::compute_stack_map_tables_with_resolver)
+    /// to merge reference types to their common super class.
+    ///
+    /// # Errors
+    /// Returns an error if the frames cannot be inferred; e.g. the code contains unreachable
+    /// instructions, subroutines (`jsr`/`ret`) or inconsistent operand stacks.
+    pub fn compute_stack_map_tables(&mut self) -> Result<()> {
+        self.compute_stack_map_tables_with_resolver(&HashMap::<String, ResolvedClass>::new())
+    }
+
+    /// Compute the `StackMapTable` attribute of every method, using the class hierarchy supplied
+    /// by the resolver to merge reference types to their most specific common super class.
+    /// Classes that cannot be resolved, and interfaces, merge to `java/lang/Object`.
+    ///
+    /// # Errors
+    /// Returns an error if the frames cannot be inferred; e.g. the code contains unreachable
+    /// instructions, subroutines (`jsr`/`ret`) or inconsistent operand stacks.
+    pub fn compute_stack_map_tables_with_resolver(
+        &mut self,
+        resolver: &dyn ClassResolver,
+    ) -> Result<()> {
+        self.decode_lazy_attributes()?;
+        stack_map_table::update(self, resolver)
+    }
+
+    /// Compute the `max_stack` and `max_locals` of every method from the method's instructions,
+    /// descriptor and exception table; the entry state of each exception handler, with the
+    /// thrown exception on the operand stack, is included in `max_stack`.
+    ///
+    /// # Errors
+    /// Returns an error if the frames cannot be inferred; e.g. the code contains subroutines
+    /// (`jsr`/`ret`) or inconsistent operand stacks.
+    pub fn compute_max_stack_and_locals(&mut self) -> Result<()> {
+        self.decode_lazy_attributes()?;
+        maximums::update(self)
+    }
+
+    /// Apply peephole optimizations to the code of every method, making the code smaller without
+    /// changing its behavior:
+    ///
+    /// - `nop`, `iinc` by zero, `goto` to the next instruction and `checkcast java/lang/Object`
+    ///   are removed
+    /// - a local variable loaded and stored back to the same local variable, a constant or local
+    ///   variable pushed and immediately popped, `dup; pop`, `dup2; pop2` and `swap; swap` are
+    ///   removed
+    /// - a `checkcast` of a value already cast to the same class or of `null` is removed
+    /// - a conditional branch on an `int` constant or `null` is replaced by a `goto` if the branch
+    ///   is always taken and removed otherwise
+    /// - unreachable instructions are removed
+    ///
+    /// Branch targets, the exception table and the `LineNumberTable`, `LocalVariableTable`,
+    /// `LocalVariableTypeTable`, `StackMapTable` and type annotation attributes are rewritten for
+    /// the new instruction offsets.  Methods with subroutines (`jsr`/`ret`) are not changed.
+    ///
+    /// # Errors
+    /// Returns an error if the class file contains an invalid `checkcast` class index or the
+    /// offsets of the optimized code cannot be rewritten.
+    pub fn optimize(&mut self) -> Result<()> {
+        self.decode_lazy_attributes()?;
+        peephole::optimize(self)
+    }
+
+    /// Remove the instructions of every method that cannot be reached from the start of the code
+    /// or from the handler of an exception table entry that protects a reachable instruction;
+    /// e.g. code left behind by instrumentation or other transformations.  Exception table
+    /// entries that only protect removed instructions and stack map frames of removed instructions
+    /// are removed, and the remaining offsets are rewritten as for
+    /// [`CodeEditor`](crate::attributes::CodeEditor).  Methods with subroutines (`jsr`/`ret`) are
+    /// not changed.  Returns a report of the instructions removed from each changed method.
+    ///
+    /// # Errors
+    /// Returns an error if the class file contains an unknown attribute, an invalid method
+    /// descriptor or stack map frame, or the offsets of the remaining code cannot be rewritten.
+    pub fn remove_dead_code(&mut self) -> Result<Vec<DeadCode>> {
+        self.decode_lazy_attributes()?;
+        dead_code::remove_dead_code(self)
+    }
+
+    /// Get the size and complexity statistics of the class: the size of the class file, the
+    /// composition of the constant pool, the size of the attributes and, for each method, the
+    /// bytecode size, maximum stack and locals and cyclomatic complexity.  Lazily read attributes
+    /// are decoded as needed and are not changed.
+    ///
+    /// # Errors
+    /// Returns an error if the class file cannot be serialized or contains an invalid constant or
+    /// attribute.
+    pub fn metrics(&self) -> Result<ClassMetrics> {
+        metrics::metrics(self)
+    }
+
+    /// Downgrade the class to an older version where possible.  Attributes that are not valid for
+    /// the version, such as `NestMembers`, `Record` and `PermittedSubclasses`, are removed; for
+    /// versions before Java 11, private methods are invoked with `invokespecial` and dynamic
+    /// constants are converted to synthetic static fields initialized by the static initializer.
+    /// Constructs that cannot be downgraded, such as records or `invokedynamic` instructions
+    /// bootstrapped by classes that do not exist in the version, are left unchanged and returned
+    /// as issues.  The class is not changed if the version is not older than the class version.
+    ///
+    /// # Errors
+    /// Returns an error if the class file contains an unknown attribute, an invalid constant or
+    /// descriptor, or the offsets of the rewritten code cannot be rewritten.
+    pub fn downgrade(&mut self, version: Version) -> Result<Vec<DowngradeIssue>> {
+        self.decode_lazy_attributes()?;
+        downgrade::downgrade(self, version)
+    }
+
+    /// Verify the code of every method and report, for each method, the control flow graph, the
+    /// frame inferred on entry to each basic block and the instruction and frame at which
+    /// verification failed.  Verification failures are reported in the returned reports rather
+    /// than as errors.
+    ///
+    /// # Errors
+    /// Returns an error if the class file contains an unknown attribute or a method name or
+    /// descriptor is not a valid constant.
+    pub fn verification_reports(
+        &self,
+        resolver: &dyn ClassResolver,
+    ) -> Result<Vec<VerificationReport>> {
+        let mut class_file = self.clone();
+        class_file.decode_lazy_attributes()?;
+        class_file
+            .methods
+            .iter()
+            .map(|method| diagnostics::verify_method(&class_file, method, resolver))
+            .collect()
+    }
+
+    /// Remove unreferenced constants from the constant pool and merge duplicate constants, then
+    /// update every constant pool index in the class file; i.e. in the class, fields, methods,
+    /// attributes and instructions, to refer to the compacted constant pool.  Returns the map of
+    /// original constant pool indexes to compacted indexes.
+    ///
+    /// # Errors
+    /// Returns an error if the class file references invalid constant pool entries, contains an
+    /// unknown attribute, or a compacted index for an `ldc` instruction does not fit in a byte.
+    pub fn compact_constant_pool(&mut self) -> Result<HashMap<u16, u16>> {
+        let mut class_file = self.clone();
+        class_file.decode_lazy_attributes()?;
+        let mut referenced = Vec::new();
+        references::for_each_reference(&mut class_file, &mut |index| {
+            referenced.push(*index);
+            Ok(())
+        })?;
+        let remap = class_file.constant_pool.compact(referenced)?;
+        references::for_each_reference(&mut class_file, &mut |index| {
+            if *index != 0 {
+                *index = *remap.get(index).ok_or(InvalidConstantPoolIndex(*index))?;
+            }
+            Ok(())
+        })?;
+        *self = class_file;
+        Ok(remap)
+    }
+
+    /// Add a bootstrap method to the `BootstrapMethods` attribute of the class, adding the
+    /// attribute if the class does not have one, and return the index of the bootstrap method.  An
+    /// identical bootstrap method that is already defined is reused.
+    ///
+    /// # Errors
+    /// Returns an error if there are more than 65,535 bootstrap methods or the attribute name
+    /// cannot be added to the constant pool.
+    pub fn add_bootstrap_method(&mut self, bootstrap_method: BootstrapMethod) -> Result<u16> {
+        let attribute = self
+            .attributes
+            .iter_mut()
+            .find(|attribute| matches!(attribute, Attribute::BootstrapMethods { .. }));
+        let Some(Attribute::BootstrapMethods { methods, .. }) = attribute else {
+            let name_index = self.constant_pool.add_utf8("BootstrapMethods")?;
+            self.attributes.push(Attribute::BootstrapMethods {
+                name_index,
+                methods: vec![bootstrap_method],
+            });
+            return Ok(0);
+        };
+        if let Some(index) = methods
+            .iter()
+            .position(|method| *method == bootstrap_method)
+        {
+            return Ok(u16::try_from(index)?);
+        }
+        let index = u16::try_from(methods.len())?;
+        methods.push(bootstrap_method);
+        Ok(index)
+    }
+
+    /// Add a dynamic constant that is resolved by the bootstrap method to the constant pool,
+    /// adding the bootstrap method to the `BootstrapMethods` attribute of the class.  Returns the
+    /// constant pool index to use with the `ldc`, `ldc_w` or `ldc2_w` instructions or as a static
+    /// argument of another bootstrap method.  Dynamic constants require Java 11 or later.
+    ///
+    /// # Errors
+    /// Returns an error if the bootstrap method or constants cannot be added.
+    pub fn add_dynamic<S: AsRef<str>>(
+        &mut self,
+        bootstrap_method: BootstrapMethod,
+        name: S,
+        descriptor: S,
+    ) -> Result<u16> {
+        let bootstrap_method_attr_index = self.add_bootstrap_method(bootstrap_method)?;
+        self.constant_pool
+            .add_dynamic(bootstrap_method_attr_index, name, descriptor)
+    }
+
+    /// Add an invoke dynamic constant that is linked by the bootstrap method to the constant pool,
+    /// adding the bootstrap method to the `BootstrapMethods` attribute of the class.  Returns the
+    /// constant pool index to use with the `invokedynamic` instruction.
+    ///
+    /// # Errors
+    /// Returns an error if the bootstrap method or constants cannot be added.
+    pub fn add_invoke_dynamic<S: AsRef<str>>(
+        &mut self,
+        bootstrap_method: BootstrapMethod,
+        name: S,
+        descriptor: S,
+    ) -> Result<u16> {
+        let bootstrap_method_attr_index = self.add_bootstrap_method(bootstrap_method)?;
+        self.constant_pool
+            .add_invoke_dynamic(bootstrap_method_attr_index, name, descriptor)
+    }
+
+    /// Add a bridge method with the descriptor that invokes the method of the class with the same
+    /// name and the target descriptor; e.g. for a method that overrides a method with a
+    /// covariant return type or the erasure of a generic method.  Reference parameters are cast
+    /// to the types of the target parameters.
+    ///
+    /// # Errors
+    /// Returns an error if the target method does not exist, the bridge method already exists or
+    /// a descriptor is invalid.
+    pub fn add_bridge_method<S: AsRef<str>>(
+        &mut self,
+        name: S,
+        descriptor: S,
+        target_descriptor: S,
+    ) -> Result<()> {
+        synthetic::add_bridge_method(
+            self,
+            name.as_ref(),
+            descriptor.as_ref(),
+            target_descriptor.as_ref(),
+        )
+    }
+
+    /// Add a static synthetic accessor method for a member of the class, which allows nested
+    /// classes to access private members of the class when targeting versions of Java before
+    /// nest-based access control.  Returns the name and descriptor of the accessor.
+    ///
+    /// # Errors
+    /// Returns an error if the member does not exist or a descriptor is invalid.
+    pub fn add_accessor(&mut self, accessor: &Accessor) -> Result<(String, String)> {
+        synthetic::add_accessor(self, accessor)
+    }
+
+    /// Walk the `ClassFile` with the visitor in a single pass, allowing the visitor to rewrite
+    /// constants, fields, methods, instructions and attributes.
+    ///
+    /// # Errors
+    /// Returns an error if the visitor returns an error, or replaces a `Long` or `Double` constant
+    /// with a constant that occupies a single constant pool index (or vice versa).
+    pub fn accept<V: ClassVisitor + ?Sized>(&mut self, visitor: &mut V) -> Result<()> {
+        self.decode_lazy_attributes()?;
+        visitor::accept(self, visitor)
+    }
+
+    /// Disassemble the `ClassFile` into the layout produced by `javap -v -p`, so that the output
+    /// can be diffed against the `OpenJDK` tooling.
+    ///
+    /// # Errors
+    /// Returns an error if the class file references invalid constant pool entries or contains
+    /// invalid descriptors.
+    pub fn to_javap_string(&self) -> Result<String> {
+        javap::disassemble(self)
+    }
+
+    /// Decode the [`Lazy`](Attribute::Lazy) attributes of the class, fields and methods read with
+    /// [`ClassFile::from_bytes_lazy`].
+    ///
+    /// # Errors
+    /// Returns an error if an attribute cannot be decoded.
+    pub fn decode_lazy_attributes(&mut self) -> Result<()> {
+        let constant_pool = &self.constant_pool;
+        let attributes = self
+            .fields
+            .iter_mut()
+            .flat_map(|field| field.attributes.iter_mut())
+            .chain(
+                self.methods
+                    .iter_mut()
+                    .flat_map(|method| method.attributes.iter_mut()),
+            )
+            .chain(self.attributes.iter_mut());
+        for attribute in attributes {
+            if let Attribute::Lazy { .. } = attribute {
+                *attribute = attribute.decode(constant_pool)?.into_owned();
+            }
+        }
+        Ok(())
+    }
+
+    /// Decode the [`Unknown`](Attribute::Unknown) attributes of the class, fields, methods and
+    /// method code that have a codec in the registry to [`Custom`](Attribute::Custom) attributes.
+    ///
+    /// # Errors
+    /// Returns an error if a codec cannot parse an attribute.
+    pub fn decode_custom_attributes(&mut self, registry: &AttributeRegistry) -> Result<()> {
+        let constant_pool = &self.constant_pool;
+        let attributes = self
+            .fields
+            .iter_mut()
+            .flat_map(|field| field.attributes.iter_mut())
+            .chain(
+                self.methods
+                    .iter_mut()
+                    .flat_map(|method| method.attributes.iter_mut()),
+            )
+            .chain(self.attributes.iter_mut());
+        for attribute in attributes {
+            if let Attribute::Code { attributes, .. } = attribute {
+                for attribute in attributes {
+                    *attribute = registry.decode(constant_pool, attribute)?.into_owned();
+                }
+            } else if let Attribute::Unknown { .. } = attribute {
+                *attribute = registry.decode(constant_pool, attribute)?.into_owned();
+            }
+        }
+        Ok(())
+    }
+
     /// Deserialize the `ClassFile` from bytes.
     ///
     /// # Errors
     /// Returns an error if the bytes are not a valid class file.
     pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<ClassFile> {
+        Self::read(bytes, false)
+    }
+
+    /// Deserialize the `ClassFile` from bytes without decoding the `Code` and annotation
+    /// attributes, which are read as [`Lazy`](Attribute::Lazy) attributes.  This is
+    /// significantly faster for tools that only need the names and descriptors of a class; the
+    /// attributes can be decoded on access with [`Attribute::decode`] or all at once with
+    /// [`ClassFile::decode_lazy_attributes`].
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid class file.
+    pub fn from_bytes_lazy(bytes: &mut Cursor<Vec<u8>>) -> Result<ClassFile> {
+        Self::read(bytes, true)
+    }
+
+    /// Deserialize the `ClassFile` from a reader; the reader is read to the end.
+    ///
+    /// # Errors
+    /// Returns an error if the reader cannot be read or the bytes are not a valid class file.
+    pub fn from_reader<R: Read>(reader: &mut R) -> Result<ClassFile> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Self::from_bytes(&mut Cursor::new(bytes))
+    }
+
+    /// Deserialize the `ClassFile` from an asynchronous reader; the reader is read to the end.
+    ///
+    /// # Errors
+    /// Returns an error if the reader cannot be read or the bytes are not a valid class file.
+    #[cfg(feature = "tokio")]
+    pub async fn from_async_reader<R: AsyncRead + Unpin>(reader: &mut R) -> Result<ClassFile> {
+        use tokio::io::AsyncReadExt;
+
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Self::from_bytes(&mut Cursor::new(bytes))
+    }
+
+    /// Deserialize the `ClassFile` from bytes; if `lazy` is true, the `Code` and annotation
+    /// attributes are not decoded.
+    fn read(bytes: &mut Cursor<Vec<u8>>, lazy: bool) -> Result<ClassFile> {
         let magic = bytes.read_u32::<BigEndian>()?;
         if magic != MAGIC {
             return Err(InvalidMagicNumber(magic));
@@ -82,21 +540,21 @@ impl ClassFile {
         let field_count = bytes.read_u16::<BigEndian>()?;
         let mut fields = Vec::with_capacity(field_count as usize);
         for _ in 0..field_count {
-            let field = Field::from_bytes(&constant_pool, bytes)?;
+            let field = Field::read(&constant_pool, bytes, lazy)?;
             fields.push(field);
         }
 
         let method_count = bytes.read_u16::<BigEndian>()?;
         let mut methods = Vec::with_capacity(method_count as usize);
         for _ in 0..method_count {
-            let method = Method::from_bytes(&constant_pool, bytes)?;
+            let method = Method::read(&constant_pool, bytes, lazy)?;
             methods.push(method);
         }
 
         let attribute_count = bytes.read_u16::<BigEndian>()?;
         let mut attributes = Vec::with_capacity(attribute_count as usize);
         for _ in 0..attribute_count {
-            let attribute = Attribute::from_bytes(&constant_pool, bytes)?;
+            let attribute = Attribute::read(&constant_pool, bytes, lazy)?;
             attributes.push(attribute);
         }
 
@@ -114,6 +572,33 @@ impl ClassFile {
         Ok(class_file)
     }
 
+    /// Serialize the `ClassFile` to a writer.
+    ///
+    /// # Errors
+    /// - If there are more than 65,534 interfaces, fields, methods, or attributes.
+    /// - If the writer cannot be written to.
+    pub fn to_writer<W: Write>(&self, writer: &mut W) -> Result<()> {
+        let mut bytes = Vec::new();
+        self.to_bytes(&mut bytes)?;
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+
+    /// Serialize the `ClassFile` to an asynchronous writer.
+    ///
+    /// # Errors
+    /// - If there are more than 65,534 interfaces, fields, methods, or attributes.
+    /// - If the writer cannot be written to.
+    #[cfg(feature = "tokio")]
+    pub async fn to_async_writer<W: AsyncWrite + Unpin>(&self, writer: &mut W) -> Result<()> {
+        use tokio::io::AsyncWriteExt;
+
+        let mut bytes = Vec::new();
+        self.to_bytes(&mut bytes)?;
+        writer.write_all(&bytes).await?;
+        Ok(())
+    }
+
     /// Serialize the `ClassFile` to bytes.
     ///
     /// # Errors
@@ -219,8 +704,9 @@ impl fmt::Display for ClassFile {
 mod test {
     use super::*;
     use crate::error::Result;
+    use crate::reference_kind::ReferenceKind;
     use crate::Constant;
-    use crate::Error::{InvalidConstantPoolIndexType, IoError};
+    use crate::Error::{InvalidConstantPoolIndexType, IoError, UnknownAttribute};
     use indoc::indoc;
 
     #[test]
@@ -260,6 +746,337 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_compute_stack_map_tables() -> Result<()> {
+        let class_bytes = include_bytes!("../../classes/Expressions.class");
+        let expected = ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))?;
+        let mut class_file = expected.clone();
+        for method in &mut class_file.methods {
+            for attribute in &mut method.attributes {
+                if let Attribute::Code { attributes, .. } = attribute {
+                    attributes
+                        .retain(|attribute| !matches!(attribute, Attribute::StackMapTable { .. }));
+                }
+            }
+        }
+        assert_ne!(expected, class_file);
+
+        class_file.compute_stack_map_tables()?;
+        assert_eq!(expected, class_file);
+        Ok(())
+    }
+
+    #[test]
+    fn test_verification_reports() -> Result<()> {
+        let class_bytes = include_bytes!("../../classes/Expressions.class");
+        let class_file = ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))?;
+        let reports = class_file.verification_reports(&HashMap::<String, ResolvedClass>::new())?;
+        assert_eq!(class_file.methods.len(), reports.len());
+        assert!(reports.iter().all(VerificationReport::is_verified));
+        assert!(reports.iter().all(|report| !report.blocks.is_empty()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_constant_pool() -> Result<()> {
+        let class_bytes = include_bytes!("../../classes/Simple.class");
+        let class_file = ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))?;
+        let mut expected = class_file.clone();
+        expected.compact_constant_pool()?;
+        assert!(expected.constant_pool.len() < class_file.constant_pool.len());
+        assert!(expected.verify().is_ok());
+        let mut bytes = Vec::new();
+        expected.to_bytes(&mut bytes)?;
+        assert_eq!(expected, ClassFile::from_bytes(&mut Cursor::new(bytes))?);
+
+        let mut class_file = class_file.clone();
+        class_file.constant_pool.add_utf8("unused")?;
+        let class_index = class_file.constant_pool.add_class("Simple")?;
+        class_file.this_class = class_index;
+        let remap = class_file.compact_constant_pool()?;
+        assert_eq!(Some(&expected.this_class), remap.get(&class_index));
+        assert_eq!(expected, class_file);
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_constant_pool_unchanged() -> Result<()> {
+        let classes: [&[u8]; 4] = [
+            include_bytes!("../../classes/Annotations.class"),
+            include_bytes!("../../classes/Constants.class"),
+            include_bytes!("../../classes/Expressions.class"),
+            include_bytes!("../../classes/JDBC.class"),
+        ];
+        for class_bytes in classes {
+            let expected = ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))?;
+            let mut class_file = expected.clone();
+            class_file.compact_constant_pool()?;
+            assert_eq!(expected, class_file);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_constant_pool_unknown_attribute() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let this_class = constant_pool.add_class("Test")?;
+        let name_index = constant_pool.add_utf8("Custom")?;
+        let mut class_file = ClassFile {
+            constant_pool,
+            this_class,
+            attributes: vec![Attribute::Unknown {
+                name_index,
+                info: vec![],
+            }],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Err(UnknownAttribute("Custom".to_string())),
+            class_file.compact_constant_pool()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_bootstrap_method() -> Result<()> {
+        let mut class_file = ClassFile::default();
+        let first = BootstrapMethod {
+            bootstrap_method_ref: 1,
+            arguments: vec![2],
+        };
+        let second = BootstrapMethod {
+            bootstrap_method_ref: 1,
+            arguments: vec![3],
+        };
+        assert_eq!(0, class_file.add_bootstrap_method(first.clone())?);
+        assert_eq!(1, class_file.add_bootstrap_method(second.clone())?);
+        assert_eq!(0, class_file.add_bootstrap_method(first.clone())?);
+        assert_eq!(1, class_file.attributes.len());
+        let Some(Attribute::BootstrapMethods {
+            name_index,
+            methods,
+        }) = class_file.attributes.first()
+        else {
+            panic!("expected BootstrapMethods attribute");
+        };
+        assert_eq!(
+            "BootstrapMethods",
+            class_file.constant_pool.try_get_utf8(*name_index)?
+        );
+        assert_eq!(&vec![first, second], methods);
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_dynamic() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let this_class = constant_pool.add_class("Foo")?;
+        let super_class = constant_pool.add_class("java/lang/Object")?;
+        let class_index = constant_pool.add_class("java/lang/invoke/ConstantBootstraps")?;
+        let method_index = constant_pool.add_method_ref(
+            class_index,
+            "nullConstant",
+            "(Ljava/lang/invoke/MethodHandles$Lookup;Ljava/lang/String;Ljava/lang/Class;)\
+                Ljava/lang/Object;",
+        )?;
+        let bootstrap_method_ref =
+            constant_pool.add_method_handle(ReferenceKind::InvokeStatic, method_index)?;
+        let mut class_file = ClassFile {
+            version: Version::Java11 { minor: 0 },
+            constant_pool,
+            this_class,
+            super_class,
+            ..Default::default()
+        };
+        let bootstrap_method = BootstrapMethod {
+            bootstrap_method_ref,
+            arguments: vec![],
+        };
+        let index = class_file.add_dynamic(bootstrap_method, "_", "Ljava/lang/String;")?;
+        let (bootstrap_method_attr_index, name_and_type_index) =
+            class_file.constant_pool.try_get_dynamic(index)?;
+        assert_eq!(0, *bootstrap_method_attr_index);
+        let (name_index, descriptor_index) = class_file
+            .constant_pool
+            .try_get_name_and_type(*name_and_type_index)?;
+        assert_eq!("_", class_file.constant_pool.try_get_utf8(*name_index)?);
+        assert_eq!(
+            "Ljava/lang/String;",
+            class_file.constant_pool.try_get_utf8(*descriptor_index)?
+        );
+        assert_eq!(
+            "Dynamic #0:_:Ljava/lang/String;",
+            class_file.constant_pool.try_get_formatted_string(index)?
+        );
+        class_file.verify()
+    }
+
+    #[test]
+    fn test_add_invoke_dynamic() -> Result<()> {
+        let mut class_file = ClassFile::default();
+        class_file.add_bootstrap_method(BootstrapMethod {
+            bootstrap_method_ref: 1,
+            arguments: vec![],
+        })?;
+        let bootstrap_method = BootstrapMethod {
+            bootstrap_method_ref: 2,
+            arguments: vec![],
+        };
+        let index =
+            class_file.add_invoke_dynamic(bootstrap_method, "get", "()Ljava/lang/Object;")?;
+        let (bootstrap_method_attr_index, name_and_type_index) =
+            class_file.constant_pool.try_get_invoke_dynamic(index)?;
+        assert_eq!(1, *bootstrap_method_attr_index);
+        let (name_index, descriptor_index) = class_file
+            .constant_pool
+            .try_get_name_and_type(*name_and_type_index)?;
+        assert_eq!("get", class_file.constant_pool.try_get_utf8(*name_index)?);
+        assert_eq!(
+            "()Ljava/lang/Object;",
+            class_file.constant_pool.try_get_utf8(*descriptor_index)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bytes_lazy() -> Result<()> {
+        for class_bytes in [
+            include_bytes!("../../classes/Annotations.class").to_vec(),
+            include_bytes!("../../classes/Expressions.class").to_vec(),
+            include_bytes!("../../classes/Simple.class").to_vec(),
+        ] {
+            let expected = ClassFile::from_bytes(&mut Cursor::new(class_bytes.clone()))?;
+            let mut class_file = ClassFile::from_bytes_lazy(&mut Cursor::new(class_bytes.clone()))?;
+            assert_ne!(expected, class_file);
+            assert_eq!(expected.class_name()?, class_file.class_name()?);
+            assert_eq!(expected.to_javap_string()?, class_file.to_javap_string()?);
+
+            let mut bytes = Vec::new();
+            class_file.to_bytes(&mut bytes)?;
+            assert_eq!(class_bytes, bytes);
+
+            class_file.decode_lazy_attributes()?;
+            assert_eq!(expected, class_file);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bytes_lazy_attributes() -> Result<()> {
+        let class_bytes = include_bytes!("../../classes/Annotations.class").to_vec();
+        let class_file = ClassFile::from_bytes_lazy(&mut Cursor::new(class_bytes))?;
+        let names = class_file
+            .methods
+            .iter()
+            .flat_map(|method| method.attributes.iter())
+            .map(Attribute::name)
+            .collect::<Vec<_>>();
+        assert!(names.contains(&"Lazy"));
+        assert!(!names.contains(&"Code"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_custom_attributes() -> Result<()> {
+        let class_bytes = include_bytes!("../../classes/Simple.class").to_vec();
+        let mut class_file = ClassFile::from_bytes(&mut Cursor::new(class_bytes))?;
+        let name_index = class_file.constant_pool.add_utf8("Checksum")?;
+        class_file.attributes.push(Attribute::Unknown {
+            name_index,
+            info: vec![0, 0, 0, 42],
+        });
+        let mut expected_bytes = Vec::new();
+        class_file.to_bytes(&mut expected_bytes)?;
+
+        let mut registry = AttributeRegistry::new();
+        registry.register(
+            "Checksum",
+            |_constant_pool, bytes| Ok(u32::from_be_bytes(bytes.try_into().unwrap_or_default())),
+            |checksum| Ok(checksum.to_be_bytes().to_vec()),
+        );
+        let mut class_file = ClassFile::from_bytes(&mut Cursor::new(expected_bytes.clone()))?;
+        class_file.decode_custom_attributes(&registry)?;
+        let Some(Attribute::Custom { value, .. }) = class_file.attributes.last() else {
+            panic!("expected custom attribute");
+        };
+        assert_eq!(Some(&42), value.value::<u32>());
+
+        let mut bytes = Vec::new();
+        class_file.to_bytes(&mut bytes)?;
+        assert_eq!(expected_bytes, bytes);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() -> Result<()> {
+        for class_bytes in [
+            include_bytes!("../../classes/Annotations.class").to_vec(),
+            include_bytes!("../../classes/Expressions.class").to_vec(),
+            include_bytes!("../../classes/Simple.class").to_vec(),
+        ] {
+            let expected = ClassFile::from_bytes(&mut Cursor::new(class_bytes.clone()))?;
+            let json = serde_json::to_string(&expected).map_err(std::io::Error::from)?;
+            let class_file: ClassFile =
+                serde_json::from_str(&json).map_err(std::io::Error::from)?;
+            assert_eq!(expected, class_file);
+
+            let mut bytes = Vec::new();
+            class_file.to_bytes(&mut bytes)?;
+            assert_eq!(class_bytes, bytes);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotations() -> Result<()> {
+        let class_bytes = include_bytes!("../../classes/Annotations.class").to_vec();
+        for class_file in [
+            ClassFile::from_bytes(&mut Cursor::new(class_bytes.clone()))?,
+            ClassFile::from_bytes_lazy(&mut Cursor::new(class_bytes))?,
+        ] {
+            let constant_pool = &class_file.constant_pool;
+            let annotations = class_file.annotations()?;
+            assert_eq!(1, annotations.len());
+            assert_eq!(
+                "Ljava/lang/Deprecated;",
+                annotations[0].type_name(constant_pool)?
+            );
+
+            let method = class_file
+                .methods
+                .iter()
+                .find(|method| {
+                    constant_pool
+                        .try_get_utf8(method.name_index)
+                        .map(String::as_str)
+                        == Ok("method_annotation")
+                })
+                .expect("method_annotation");
+            let annotations = method.annotations(constant_pool)?;
+            assert_eq!(1, annotations.len());
+            assert_eq!(
+                "Ljava/lang/Deprecated;",
+                annotations[0].type_name(constant_pool)?
+            );
+            assert_eq!(None, annotations[0].value(constant_pool, "since")?);
+
+            let field = class_file
+                .fields
+                .iter()
+                .find(|field| {
+                    constant_pool
+                        .try_get_utf8(field.name_index)
+                        .map(String::as_str)
+                        == Ok("field_annotation")
+                })
+                .expect("field_annotation");
+            assert_eq!(1, field.annotations(constant_pool)?.len());
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_verify() -> Result<()> {
         let class_bytes = include_bytes!("../../classes/Simple.class");
@@ -340,6 +1157,51 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_minimum_to_javap_string() -> Result<()> {
+        let class_bytes = include_bytes!("../../classes/Minimum.class");
+        let class_file = ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))?;
+        let expected = indoc! {r#"
+              Compiled from "Minimum.java"
+            public class Minimum
+              minor version: 0
+              major version: 52
+              flags: (0x0021) ACC_PUBLIC, ACC_SUPER
+              this_class: #7                          // Minimum
+              super_class: #2                         // java/lang/Object
+              interfaces: 0, fields: 0, methods: 1, attributes: 1
+            Constant pool:
+               #1 = Methodref          #2.#3          // java/lang/Object."<init>":()V
+               #2 = Class              #4             // java/lang/Object
+               #3 = NameAndType        #5:#6          // "<init>":()V
+               #4 = Utf8               java/lang/Object
+               #5 = Utf8               <init>
+               #6 = Utf8               ()V
+               #7 = Class              #8             // Minimum
+               #8 = Utf8               Minimum
+               #9 = Utf8               Code
+              #10 = Utf8               LineNumberTable
+              #11 = Utf8               SourceFile
+              #12 = Utf8               Minimum.java
+            {
+              public Minimum();
+                descriptor: ()V
+                flags: (0x0001) ACC_PUBLIC
+                Code:
+                  stack=1, locals=1, args_size=1
+                     0: aload_0
+                     1: invokespecial #1                  // Method java/lang/Object."<init>":()V
+                     4: return
+                  LineNumberTable:
+                    line 1: 0
+            }
+            SourceFile: "Minimum.java"
+        "#};
+
+        assert_eq!(expected, class_file.to_javap_string()?);
+        Ok(())
+    }
+
     #[test]
     fn test_minimum_serialization() -> Result<()> {
         let class_bytes = include_bytes!("../../classes/Minimum.class");
@@ -378,6 +1240,33 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_reader_writer_serialization() -> Result<()> {
+        let class_bytes = include_bytes!("../../classes/Simple.class").to_vec();
+        let class_file = ClassFile::from_reader(&mut class_bytes.as_slice())?;
+        let mut bytes = Vec::new();
+        class_file.to_writer(&mut bytes)?;
+        assert_eq!(class_bytes, bytes);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_reader_invalid() {
+        let mut bytes: &[u8] = &[0xCA, 0xFE];
+        assert!(ClassFile::from_reader(&mut bytes).is_err());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_async_reader_writer_serialization() -> Result<()> {
+        let class_bytes = include_bytes!("../../classes/Simple.class").to_vec();
+        let class_file = ClassFile::from_async_reader(&mut class_bytes.as_slice()).await?;
+        let mut bytes = Vec::new();
+        class_file.to_async_writer(&mut bytes).await?;
+        assert_eq!(class_bytes, bytes);
+        Ok(())
+    }
+
     #[test]
     fn test_from_bytes_invalid() {
         let bytes = vec![
@@ -0,0 +1,537 @@
+use crate::attributes::{Attribute, Instruction};
+use crate::builder::internal_name;
+use crate::class_access_flags::ClassAccessFlags;
+use crate::class_file::ClassFile;
+use crate::constant_pool::ConstantPool;
+use crate::error::Error::InvalidClass;
+use crate::error::Result;
+use crate::field::Field;
+use crate::field_access_flags::FieldAccessFlags;
+use crate::field_type::FieldType;
+use crate::method::Method;
+use crate::method_access_flags::MethodAccessFlags;
+use crate::method_descriptor::MethodDescriptor;
+use crate::version::Version;
+
+/// Super class of classes that do not extend another class.
+const OBJECT: &str = "java/lang/Object";
+const VERSION_50_0: Version = Version::Java6 { minor: 0 };
+
+/// Field declared with a [`ClassFileBuilder`].
+#[derive(Clone, Debug, PartialEq)]
+struct FieldDeclaration {
+    access_flags: FieldAccessFlags,
+    name: String,
+    field_type: FieldType,
+}
+
+/// Method declared with a [`ClassFileBuilder`].
+#[derive(Clone, Debug, PartialEq)]
+struct MethodDeclaration {
+    access_flags: MethodAccessFlags,
+    name: String,
+    descriptor: String,
+    code: Vec<Instruction>,
+}
+
+/// Builder for class files.
+///
+/// The builder manages the constant pool entries of the class, its super class, interfaces,
+/// fields and methods; existing entries are reused.  Instructions refer to the constant pool by
+/// index, so constants used by method code are added with [`constant_pool`](Self::constant_pool)
+/// before the method is declared.  The `max_stack`, `max_locals` and `StackMapTable` of each
+/// method are computed and the class file is verified when it is built.  Class names may be
+/// specified in either their binary (e.g. `com.example.Foo`) or internal (e.g. `com/example/Foo`)
+/// form.
+///
+/// # Examples
+///
+/// ```rust
+/// use ristretto_classfile::attributes::Instruction;
+/// use ristretto_classfile::{
+///     BaseType, ClassFileBuilder, FieldAccessFlags, FieldType, MethodAccessFlags, Result,
+/// };
+///
+/// fn main() -> Result<()> {
+///     let mut builder = ClassFileBuilder::new("com.example.Counter").public();
+///     let constant_pool = builder.constant_pool();
+///     let class_index = constant_pool.add_class("com/example/Counter")?;
+///     let count = constant_pool.add_field_ref(class_index, "count", "I")?;
+///     let class_file = builder
+///         .field(FieldAccessFlags::PRIVATE, "count", FieldType::Base(BaseType::Int))
+///         .method(
+///             MethodAccessFlags::PUBLIC,
+///             "count",
+///             "()I",
+///             vec![
+///                 Instruction::Aload_0,
+///                 Instruction::Getfield(count),
+///                 Instruction::Ireturn,
+///             ],
+///         )
+///         .build()?;
+///     assert_eq!("com/example/Counter", class_file.class_name()?);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ClassFileBuilder {
+    java_version: Version,
+    name: String,
+    access_flags: ClassAccessFlags,
+    super_class: Option<String>,
+    interfaces: Vec<String>,
+    fields: Vec<FieldDeclaration>,
+    methods: Vec<MethodDeclaration>,
+    source_file: Option<String>,
+    constant_pool: ConstantPool,
+}
+
+impl ClassFileBuilder {
+    /// Create a new builder for the named class; the class extends `java.lang.Object` unless
+    /// another super class is specified.
+    #[must_use]
+    pub fn new<S: AsRef<str>>(name: S) -> Self {
+        ClassFileBuilder {
+            java_version: Version::Java21 { minor: 0 },
+            name: internal_name(name),
+            access_flags: ClassAccessFlags::empty(),
+            super_class: None,
+            interfaces: Vec::new(),
+            fields: Vec::new(),
+            methods: Vec::new(),
+            source_file: None,
+            constant_pool: ConstantPool::default(),
+        }
+    }
+
+    /// Set the class file version; defaults to Java 21.
+    #[must_use]
+    pub fn java_version(mut self, java_version: Version) -> Self {
+        self.java_version = java_version;
+        self
+    }
+
+    /// Set the class access flags; [`ClassAccessFlags::SUPER`] is always set for classes and
+    /// [`ClassAccessFlags::ABSTRACT`] for interfaces.
+    #[must_use]
+    pub fn access_flags(mut self, access_flags: ClassAccessFlags) -> Self {
+        self.access_flags = access_flags;
+        self
+    }
+
+    /// Make the class public.
+    #[must_use]
+    pub fn public(mut self) -> Self {
+        self.access_flags |= ClassAccessFlags::PUBLIC;
+        self
+    }
+
+    /// Make the class an interface.
+    #[must_use]
+    pub fn interface(mut self) -> Self {
+        self.access_flags |= ClassAccessFlags::INTERFACE | ClassAccessFlags::ABSTRACT;
+        self
+    }
+
+    /// Set the super class; defaults to `java.lang.Object`.
+    #[must_use]
+    pub fn extends<S: AsRef<str>>(mut self, super_class: S) -> Self {
+        self.super_class = Some(internal_name(super_class));
+        self
+    }
+
+    /// Add an interface that the class implements, or that the interface extends.
+    #[must_use]
+    pub fn implements<S: AsRef<str>>(mut self, interface: S) -> Self {
+        self.interfaces.push(internal_name(interface));
+        self
+    }
+
+    /// Add a field.
+    #[must_use]
+    pub fn field<S: AsRef<str>>(
+        mut self,
+        access_flags: FieldAccessFlags,
+        name: S,
+        field_type: FieldType,
+    ) -> Self {
+        self.fields.push(FieldDeclaration {
+            access_flags,
+            name: name.as_ref().to_string(),
+            field_type,
+        });
+        self
+    }
+
+    /// Add a method with its code; abstract and native methods have no code.
+    #[must_use]
+    pub fn method<N: AsRef<str>, D: AsRef<str>>(
+        mut self,
+        access_flags: MethodAccessFlags,
+        name: N,
+        descriptor: D,
+        code: Vec<Instruction>,
+    ) -> Self {
+        self.methods.push(MethodDeclaration {
+            access_flags,
+            name: name.as_ref().to_string(),
+            descriptor: descriptor.as_ref().to_string(),
+            code,
+        });
+        self
+    }
+
+    /// Set the name of the source file that the class was compiled from, e.g. `Foo.java`.
+    #[must_use]
+    pub fn source_file<S: AsRef<str>>(mut self, source_file: S) -> Self {
+        self.source_file = Some(source_file.as_ref().to_string());
+        self
+    }
+
+    /// Get the constant pool of the class, to add the constants that method code refers to.
+    pub fn constant_pool(&mut self) -> &mut ConstantPool {
+        &mut self.constant_pool
+    }
+
+    /// Build and verify the class file.
+    ///
+    /// # Errors
+    /// - If the class name is empty.
+    /// - If a field or method is declared more than once or has an invalid descriptor.
+    /// - If an abstract or native method has code, or another method has no code.
+    /// - If the constant pool exceeds 65,534 constants.
+    /// - If the stack map frames cannot be computed or the class file cannot be verified.
+    pub fn build(self) -> Result<ClassFile> {
+        self.validate()?;
+
+        let mut constant_pool = self.constant_pool;
+        let this_class = class(&mut constant_pool, &self.name)?;
+        let super_class = match &self.super_class {
+            Some(super_class) => class(&mut constant_pool, super_class)?,
+            None if self.name == OBJECT => 0,
+            None => class(&mut constant_pool, OBJECT)?,
+        };
+        let mut interfaces = Vec::new();
+        for interface in &self.interfaces {
+            interfaces.push(class(&mut constant_pool, interface)?);
+        }
+
+        let mut fields = Vec::new();
+        for field in &self.fields {
+            fields.push(Field {
+                access_flags: field.access_flags,
+                name_index: utf8(&mut constant_pool, &field.name)?,
+                descriptor_index: utf8(&mut constant_pool, &field.field_type.descriptor())?,
+                field_type: field.field_type.clone(),
+                attributes: Vec::new(),
+            });
+        }
+
+        let mut methods = Vec::new();
+        for method in &self.methods {
+            let mut attributes = Vec::new();
+            if !method.code.is_empty() {
+                attributes.push(Attribute::Code {
+                    name_index: utf8(&mut constant_pool, "Code")?,
+                    max_stack: 0,
+                    max_locals: 0,
+                    code: method.code.clone(),
+                    exception_table: Vec::new(),
+                    attributes: Vec::new(),
+                });
+            }
+            methods.push(Method {
+                access_flags: method.access_flags,
+                name_index: utf8(&mut constant_pool, &method.name)?,
+                descriptor_index: utf8(&mut constant_pool, &method.descriptor)?,
+                attributes,
+            });
+        }
+
+        let mut attributes = Vec::new();
+        if let Some(source_file) = &self.source_file {
+            attributes.push(Attribute::SourceFile {
+                name_index: utf8(&mut constant_pool, "SourceFile")?,
+                source_file_index: utf8(&mut constant_pool, source_file)?,
+            });
+        }
+
+        let access_flags = if self.access_flags.contains(ClassAccessFlags::INTERFACE) {
+            self.access_flags | ClassAccessFlags::ABSTRACT
+        } else {
+            self.access_flags | ClassAccessFlags::SUPER
+        };
+        let mut class_file = ClassFile {
+            version: self.java_version,
+            constant_pool,
+            access_flags,
+            this_class,
+            super_class,
+            interfaces,
+            fields,
+            methods,
+            attributes,
+        };
+        class_file.compute_max_stack_and_locals()?;
+        if class_file.version >= VERSION_50_0 {
+            class_file.compute_stack_map_tables()?;
+        }
+        class_file.verify()?;
+        Ok(class_file)
+    }
+
+    /// Validate the class declaration.
+    fn validate(&self) -> Result<()> {
+        if self.name.is_empty() {
+            return Err(InvalidClass("class name is empty".to_string()));
+        }
+
+        for (index, field) in self.fields.iter().enumerate() {
+            if self.fields[..index]
+                .iter()
+                .any(|other| other.name == field.name)
+            {
+                return Err(InvalidClass(format!(
+                    "field {} is declared more than once",
+                    field.name
+                )));
+            }
+        }
+
+        for (index, method) in self.methods.iter().enumerate() {
+            MethodDescriptor::parse(&method.descriptor)?;
+            if self.methods[..index]
+                .iter()
+                .any(|other| other.name == method.name && other.descriptor == method.descriptor)
+            {
+                return Err(InvalidClass(format!(
+                    "method {}{} is declared more than once",
+                    method.name, method.descriptor
+                )));
+            }
+            let has_code = !method.code.is_empty();
+            let requires_code = !method
+                .access_flags
+                .intersects(MethodAccessFlags::ABSTRACT | MethodAccessFlags::NATIVE);
+            if has_code != requires_code {
+                let reason = if has_code {
+                    "abstract or native method cannot have code"
+                } else {
+                    "method requires code"
+                };
+                return Err(InvalidClass(format!(
+                    "{reason}: {}{}",
+                    method.name, method.descriptor
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Get the index of a UTF-8 constant, reusing an existing constant.
+fn utf8(constant_pool: &mut ConstantPool, value: &str) -> Result<u16> {
+    match constant_pool.find_utf8(value) {
+        Some(index) => Ok(index),
+        None => constant_pool.add_utf8(value),
+    }
+}
+
+/// Get the index of a class constant, reusing an existing constant.
+fn class(constant_pool: &mut ConstantPool, name: &str) -> Result<u16> {
+    match constant_pool.find_class(name) {
+        Some(index) => Ok(index),
+        None => constant_pool.add_class(name),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::base_type::BaseType;
+    use crate::Error::InvalidConstantPoolIndex;
+
+    #[test]
+    fn test_build() -> Result<()> {
+        let mut builder = ClassFileBuilder::new("com.example.Foo")
+            .public()
+            .implements("java.lang.Runnable")
+            .source_file("Foo.java");
+        let constant_pool = builder.constant_pool();
+        let object_class = constant_pool.add_class("java/lang/Object")?;
+        let object_constructor = constant_pool.add_method_ref(object_class, "<init>", "()V")?;
+        let class_file = builder
+            .field(
+                FieldAccessFlags::PRIVATE,
+                "value",
+                FieldType::Base(BaseType::Long),
+            )
+            .method(
+                MethodAccessFlags::PUBLIC,
+                "<init>",
+                "()V",
+                vec![
+                    Instruction::Aload_0,
+                    Instruction::Invokespecial(object_constructor),
+                    Instruction::Return,
+                ],
+            )
+            .method(
+                MethodAccessFlags::PUBLIC,
+                "run",
+                "()V",
+                vec![Instruction::Return],
+            )
+            .build()?;
+
+        assert_eq!(Version::Java21 { minor: 0 }, class_file.version);
+        assert_eq!(
+            ClassAccessFlags::PUBLIC | ClassAccessFlags::SUPER,
+            class_file.access_flags
+        );
+        assert_eq!("com/example/Foo", class_file.class_name()?);
+        let constant_pool = &class_file.constant_pool;
+        assert_eq!(
+            "java/lang/Object",
+            constant_pool.try_get_class(class_file.super_class)?
+        );
+        assert_eq!(
+            Some(class_file.super_class),
+            constant_pool.find_class("java/lang/Object")
+        );
+        assert_eq!(
+            vec![constant_pool.find_class("java/lang/Runnable")],
+            class_file
+                .interfaces
+                .iter()
+                .map(|index| Some(*index))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(1, class_file.fields.len());
+        assert_eq!(2, class_file.methods.len());
+        let Some(Attribute::Code {
+            max_stack,
+            max_locals,
+            ..
+        }) = class_file.methods[0].attributes.first()
+        else {
+            panic!("missing Code attribute");
+        };
+        assert_eq!((1, 1), (*max_stack, *max_locals));
+        assert!(matches!(
+            class_file.attributes.as_slice(),
+            [Attribute::SourceFile { .. }]
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_interface() -> Result<()> {
+        let class_file = ClassFileBuilder::new("Shape")
+            .public()
+            .interface()
+            .method(
+                MethodAccessFlags::PUBLIC | MethodAccessFlags::ABSTRACT,
+                "area",
+                "()D",
+                Vec::new(),
+            )
+            .build()?;
+        assert_eq!(
+            ClassAccessFlags::PUBLIC | ClassAccessFlags::INTERFACE | ClassAccessFlags::ABSTRACT,
+            class_file.access_flags
+        );
+        assert!(class_file.methods[0].attributes.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_stack_map_table() -> Result<()> {
+        let class_file = ClassFileBuilder::new("Foo")
+            .method(
+                MethodAccessFlags::STATIC,
+                "sign",
+                "(I)I",
+                vec![
+                    Instruction::Iload_0,
+                    Instruction::Ifge(4),
+                    Instruction::Iconst_m1,
+                    Instruction::Ireturn,
+                    Instruction::Iconst_1,
+                    Instruction::Ireturn,
+                ],
+            )
+            .build()?;
+        let Some(Attribute::Code { attributes, .. }) = class_file.methods[0].attributes.first()
+        else {
+            panic!("missing Code attribute");
+        };
+        assert!(matches!(
+            attributes.as_slice(),
+            [Attribute::StackMapTable { .. }]
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_object() -> Result<()> {
+        let class_file = ClassFileBuilder::new("java/lang/Object").build()?;
+        assert_eq!(0, class_file.super_class);
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_errors() {
+        let builders = [
+            ClassFileBuilder::new(""),
+            ClassFileBuilder::new("Foo")
+                .field(
+                    FieldAccessFlags::empty(),
+                    "x",
+                    FieldType::Base(BaseType::Int),
+                )
+                .field(
+                    FieldAccessFlags::empty(),
+                    "x",
+                    FieldType::Base(BaseType::Long),
+                ),
+            ClassFileBuilder::new("Foo")
+                .method(
+                    MethodAccessFlags::STATIC,
+                    "x",
+                    "()V",
+                    vec![Instruction::Return],
+                )
+                .method(
+                    MethodAccessFlags::STATIC,
+                    "x",
+                    "()V",
+                    vec![Instruction::Return],
+                ),
+            ClassFileBuilder::new("Foo").method(
+                MethodAccessFlags::ABSTRACT,
+                "x",
+                "()V",
+                vec![Instruction::Return],
+            ),
+            ClassFileBuilder::new("Foo").method(MethodAccessFlags::STATIC, "x", "()V", Vec::new()),
+        ];
+        for builder in builders {
+            assert!(matches!(builder.build(), Err(InvalidClass(_))));
+        }
+    }
+
+    #[test]
+    fn test_build_invalid_constant() {
+        let result = ClassFileBuilder::new("Foo")
+            .method(
+                MethodAccessFlags::STATIC,
+                "x",
+                "()V",
+                vec![Instruction::Invokestatic(100)],
+            )
+            .build();
+        assert_eq!(Err(InvalidConstantPoolIndex(100)), result);
+    }
+}
@@ -1,8 +1,11 @@
+use crate::class_file::ClassFile;
 use crate::constant::Constant;
 use crate::error::Result;
+use crate::references;
 use crate::Error::{InvalidConstantPoolIndex, InvalidConstantPoolIndexType};
 use crate::ReferenceKind;
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use std::collections::HashMap;
 use std::io::Cursor;
 use std::{fmt, io};
 
@@ -10,6 +13,11 @@ use std::{fmt, io};
 ///
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.4>
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(from = "Vec<Constant>", into = "Vec<Constant>")
+)]
 pub struct ConstantPool {
     constants: Vec<ConstantEntry>,
 }
@@ -71,6 +79,17 @@ impl ConstantPool {
         }
     }
 
+    /// Get a mutable reference to a constant in the pool by index; indexes are 1-based.
+    /// Returns None if the index is out of bounds.  A `Long` or `Double` constant occupies two
+    /// indexes, so it must only be replaced by another `Long` or `Double` constant.
+    #[must_use]
+    pub fn get_mut(&mut self, index: u16) -> Option<&mut Constant> {
+        match self.constants.get_mut(index as usize) {
+            Some(ConstantEntry::Constant(constant)) => Some(constant),
+            _ => None,
+        }
+    }
+
     /// Get the number of constants in the pool.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -83,9 +102,10 @@ impl ConstantPool {
         self.len() == 0
     }
 
-    /// Get an iterator over the constants in the pool.
+    /// Get an iterator over the indexes and constants in the pool; the second index occupied by a
+    /// `Long` or `Double` constant is skipped.
     #[must_use]
-    pub fn iter(&self) -> ConstantPoolIterator {
+    pub fn iter(&self) -> ConstantPoolIterator<'_> {
         ConstantPoolIterator::new(self)
     }
 
@@ -405,6 +425,167 @@ impl ConstantPool {
         }
     }
 
+    /// Get the class name, member name and descriptor of a field, method or interface method
+    /// reference constant from the pool by index; indexes are 1-based.
+    ///
+    /// # Errors
+    /// Returns an error if the index is out of bounds or the constant is not a field, method or
+    /// interface method reference constant.
+    pub fn try_get_member_ref(&self, index: u16) -> Result<(&String, &String, &String)> {
+        let (Constant::FieldRef {
+            class_index,
+            name_and_type_index,
+        }
+        | Constant::MethodRef {
+            class_index,
+            name_and_type_index,
+        }
+        | Constant::InterfaceMethodRef {
+            class_index,
+            name_and_type_index,
+        }) = self.try_get(index)?
+        else {
+            return Err(InvalidConstantPoolIndexType(index));
+        };
+        let class_name = self.try_get_class(*class_index)?;
+        let (name_index, descriptor_index) = self.try_get_name_and_type(*name_and_type_index)?;
+        let name = self.try_get_utf8(*name_index)?;
+        let descriptor = self.try_get_utf8(*descriptor_index)?;
+        Ok((class_name, name, descriptor))
+    }
+
+    /// Determine if a constant is a field, method or interface method reference to a member of a
+    /// class with a name.
+    #[must_use]
+    pub fn is_member_ref<C: AsRef<str>, N: AsRef<str>>(
+        &self,
+        index: u16,
+        class_name: C,
+        name: N,
+    ) -> bool {
+        self.try_get_member_ref(index)
+            .is_ok_and(|(member_class_name, member_name, _)| {
+                member_class_name == class_name.as_ref() && member_name == name.as_ref()
+            })
+    }
+
+    /// Find the index of the first UTF-8 constant in the pool with a value.
+    #[must_use]
+    pub fn find_utf8<S: AsRef<str>>(&self, value: S) -> Option<u16> {
+        let value = value.as_ref();
+        self.iter().find_map(|(index, constant)| match constant {
+            Constant::Utf8(utf8) if utf8 == value => Some(index),
+            _ => None,
+        })
+    }
+
+    /// Find the index of the first class constant in the pool with a name.
+    #[must_use]
+    pub fn find_class<S: AsRef<str>>(&self, name: S) -> Option<u16> {
+        let name = name.as_ref();
+        self.iter().find_map(|(index, constant)| match constant {
+            Constant::Class(name_index) if self.try_get_utf8(*name_index).ok()? == name => {
+                Some(index)
+            }
+            _ => None,
+        })
+    }
+
+    /// Find the index of the first name and type constant in the pool with a name and descriptor.
+    #[must_use]
+    pub fn find_name_and_type<N: AsRef<str>, D: AsRef<str>>(
+        &self,
+        name: N,
+        descriptor: D,
+    ) -> Option<u16> {
+        let (name, descriptor) = (name.as_ref(), descriptor.as_ref());
+        self.iter().find_map(|(index, constant)| match constant {
+            Constant::NameAndType {
+                name_index,
+                descriptor_index,
+            } if self.try_get_utf8(*name_index).ok()? == name
+                && self.try_get_utf8(*descriptor_index).ok()? == descriptor =>
+            {
+                Some(index)
+            }
+            _ => None,
+        })
+    }
+
+    /// Find the index of the first field reference constant in the pool to a field of a class.
+    #[must_use]
+    pub fn find_field_ref<C: AsRef<str>, N: AsRef<str>, D: AsRef<str>>(
+        &self,
+        class_name: C,
+        name: N,
+        descriptor: D,
+    ) -> Option<u16> {
+        self.find_member_ref(
+            |constant| matches!(constant, Constant::FieldRef { .. }),
+            class_name.as_ref(),
+            name.as_ref(),
+            descriptor.as_ref(),
+        )
+    }
+
+    /// Find the index of the first method reference constant in the pool to a method of a class.
+    #[must_use]
+    pub fn find_method_ref<C: AsRef<str>, N: AsRef<str>, D: AsRef<str>>(
+        &self,
+        class_name: C,
+        name: N,
+        descriptor: D,
+    ) -> Option<u16> {
+        self.find_member_ref(
+            |constant| matches!(constant, Constant::MethodRef { .. }),
+            class_name.as_ref(),
+            name.as_ref(),
+            descriptor.as_ref(),
+        )
+    }
+
+    /// Find the index of the first interface method reference constant in the pool to a method of
+    /// an interface.
+    #[must_use]
+    pub fn find_interface_method_ref<C: AsRef<str>, N: AsRef<str>, D: AsRef<str>>(
+        &self,
+        class_name: C,
+        name: N,
+        descriptor: D,
+    ) -> Option<u16> {
+        self.find_member_ref(
+            |constant| matches!(constant, Constant::InterfaceMethodRef { .. }),
+            class_name.as_ref(),
+            name.as_ref(),
+            descriptor.as_ref(),
+        )
+    }
+
+    /// Find the index of the first member reference constant of a kind in the pool to a member of
+    /// a class.
+    fn find_member_ref<F>(
+        &self,
+        is_kind: F,
+        class_name: &str,
+        name: &str,
+        descriptor: &str,
+    ) -> Option<u16>
+    where
+        F: Fn(&Constant) -> bool,
+    {
+        self.iter().find_map(|(index, constant)| {
+            if !is_kind(constant) {
+                return None;
+            }
+            let (member_class_name, member_name, member_descriptor) =
+                self.try_get_member_ref(index).ok()?;
+            (member_class_name == class_name
+                && member_name == name
+                && member_descriptor == descriptor)
+                .then_some(index)
+        })
+    }
+
     /// Add a method handle constant to the pool.
     ///
     /// # Errors
@@ -668,6 +849,191 @@ impl ConstantPool {
         };
         Ok(value)
     }
+
+    /// Compact the pool; constants that are not reachable from the `referenced` indexes are
+    /// removed, equal constants are merged and the remaining constants are renumbered in their
+    /// original order.  References between constants are updated.  Index 0 is ignored since it
+    /// represents the absence of a reference.
+    ///
+    /// Returns a table mapping each old index that is still reachable to its new index; because
+    /// the order of the constants is preserved, new indexes are never greater than old indexes.
+    ///
+    /// # Errors
+    /// Returns an error if a referenced index is not a valid constant.
+    pub fn compact<I>(&mut self, referenced: I) -> Result<HashMap<u16, u16>>
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        let used = self.reachable(referenced)?;
+        let used_indexes: Vec<u16> = (1..=u16::try_from(self.len())?)
+            .filter(|index| used[usize::from(*index)])
+            .collect();
+
+        // Merge equal constants into the first occurrence; constants are compared after their
+        // references are merged, so this is repeated until no more constants are merged.
+        let mut canonical: Vec<u16> = (0..=u16::try_from(self.len())?).collect();
+        loop {
+            let mut changed = false;
+            let mut first_indexes: HashMap<Vec<u8>, u16> = HashMap::new();
+            for index in &used_indexes {
+                let mut constant = self.try_get(*index)?.clone();
+                for_each_reference(&mut constant, |reference| {
+                    *reference = canonical[usize::from(*reference)];
+                });
+                let mut key = Vec::new();
+                constant.to_bytes(&mut key)?;
+                let first_index = *first_indexes.entry(key).or_insert(*index);
+                if canonical[usize::from(*index)] != first_index {
+                    canonical[usize::from(*index)] = first_index;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        let mut remap = HashMap::with_capacity(used_indexes.len());
+        let mut constants = ConstantPool::new();
+        for index in &used_indexes {
+            if canonical[usize::from(*index)] == *index {
+                let new_index = constants.add(self.try_get(*index)?.clone())?;
+                remap.insert(*index, new_index);
+            }
+        }
+        for index in &used_indexes {
+            let new_index = remap[&canonical[usize::from(*index)]];
+            remap.insert(*index, new_index);
+        }
+        for constant_entry in &mut constants.constants {
+            if let ConstantEntry::Constant(constant) = constant_entry {
+                for_each_reference(constant, |reference| *reference = remap[reference]);
+            }
+        }
+
+        *self = constants;
+        Ok(remap)
+    }
+
+    /// Mark the constants reachable from the `referenced` indexes, either directly or through
+    /// references between constants; the result is indexed by constant pool index.
+    ///
+    /// # Errors
+    /// Returns an error if a referenced index is not a valid constant.
+    fn reachable<I>(&self, referenced: I) -> Result<Vec<bool>>
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        let mut used = vec![false; self.constants.len()];
+        let mut pending: Vec<u16> = referenced.into_iter().filter(|index| *index != 0).collect();
+        while let Some(index) = pending.pop() {
+            let mut constant = self.try_get(index)?.clone();
+            if used[usize::from(index)] {
+                continue;
+            }
+            used[usize::from(index)] = true;
+            for_each_reference(&mut constant, |reference| pending.push(*reference));
+        }
+        Ok(used)
+    }
+
+    /// Get the indexes of the constants that are not referenced by the class file; i.e. by the
+    /// class, fields, methods, attributes and instructions, either directly or through other
+    /// referenced constants.  These are the constants that [`ClassFile::compact_constant_pool`]
+    /// removes; the pool is expected to be the constant pool of the class file.
+    ///
+    /// # Errors
+    /// Returns an error if the class file references invalid constant pool entries or contains an
+    /// unknown attribute.
+    pub fn unused_entries(&self, class_file: &ClassFile) -> Result<Vec<u16>> {
+        let mut class_file = class_file.clone();
+        class_file.decode_lazy_attributes()?;
+        let mut referenced = Vec::new();
+        references::for_each_reference(&mut class_file, &mut |index| {
+            referenced.push(*index);
+            Ok(())
+        })?;
+        let used = self.reachable(referenced)?;
+        let unused = self
+            .constants
+            .iter()
+            .enumerate()
+            .filter(|(index, entry)| matches!(entry, ConstantEntry::Constant(_)) && !used[*index])
+            .map(|(index, _)| u16::try_from(index))
+            .collect::<std::result::Result<Vec<u16>, _>>()?;
+        Ok(unused)
+    }
+
+    /// Get the indexes of the constants that are not referenced by the class file, as returned by
+    /// [`ConstantPool::unused_entries`], along with the number of bytes each constant occupies in
+    /// the serialized class file.
+    ///
+    /// # Errors
+    /// Returns an error if the class file references invalid constant pool entries or contains an
+    /// unknown attribute.
+    pub fn unused_entries_with_sizes(&self, class_file: &ClassFile) -> Result<Vec<(u16, usize)>> {
+        let mut sizes = Vec::new();
+        for index in self.unused_entries(class_file)? {
+            let mut bytes = Vec::new();
+            self.try_get(index)?.to_bytes(&mut bytes)?;
+            sizes.push((index, bytes.len()));
+        }
+        Ok(sizes)
+    }
+}
+
+/// Call the function with a mutable reference to each constant pool index referenced by the
+/// constant.
+fn for_each_reference<F>(constant: &mut Constant, mut function: F)
+where
+    F: FnMut(&mut u16),
+{
+    match constant {
+        Constant::Utf8(_)
+        | Constant::Integer(_)
+        | Constant::Float(_)
+        | Constant::Long(_)
+        | Constant::Double(_) => {}
+        Constant::Class(index)
+        | Constant::String(index)
+        | Constant::MethodType(index)
+        | Constant::Module(index)
+        | Constant::Package(index)
+        | Constant::MethodHandle {
+            reference_index: index,
+            ..
+        }
+        | Constant::Dynamic {
+            name_and_type_index: index,
+            ..
+        }
+        | Constant::InvokeDynamic {
+            name_and_type_index: index,
+            ..
+        } => function(index),
+        Constant::FieldRef {
+            class_index,
+            name_and_type_index,
+        }
+        | Constant::MethodRef {
+            class_index,
+            name_and_type_index,
+        }
+        | Constant::InterfaceMethodRef {
+            class_index,
+            name_and_type_index,
+        } => {
+            function(class_index);
+            function(name_and_type_index);
+        }
+        Constant::NameAndType {
+            name_index,
+            descriptor_index,
+        } => {
+            function(name_index);
+            function(descriptor_index);
+        }
+    }
 }
 
 impl Default for ConstantPool {
@@ -676,6 +1042,32 @@ impl Default for ConstantPool {
     }
 }
 
+impl From<Vec<Constant>> for ConstantPool {
+    /// Create a constant pool from the constants; placeholders for 8 byte constants are added
+    /// automatically.
+    fn from(constants: Vec<Constant>) -> Self {
+        let mut constant_pool = ConstantPool::new();
+        for constant in constants {
+            constant_pool.push(constant);
+        }
+        constant_pool
+    }
+}
+
+impl From<ConstantPool> for Vec<Constant> {
+    /// Get the constants of the constant pool without placeholders.
+    fn from(constant_pool: ConstantPool) -> Self {
+        constant_pool
+            .constants
+            .into_iter()
+            .filter_map(|entry| match entry {
+                ConstantEntry::Constant(constant) => Some(constant),
+                ConstantEntry::Placeholder => None,
+            })
+            .collect()
+    }
+}
+
 /// All 8 byte constants (double and long) take up two entries in the constant pool; a placeholder
 /// is used to facilitate efficient indexed access of constants in the pool. See the JVM spec for:
 /// <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.4.5>
@@ -715,14 +1107,15 @@ impl<'a> ConstantPoolIterator<'a> {
 }
 
 impl<'a> Iterator for ConstantPoolIterator<'a> {
-    type Item = &'a Constant;
+    type Item = (u16, &'a Constant);
 
     fn next(&mut self) -> Option<Self::Item> {
         while self.index < self.constant_pool.constants.len() {
             match &self.constant_pool.constants[self.index] {
                 ConstantEntry::Constant(constant) => {
+                    let index = u16::try_from(self.index).ok()?;
                     self.index += 1;
-                    return Some(constant);
+                    return Some((index, constant));
                 }
                 ConstantEntry::Placeholder => {
                     self.index += 1;
@@ -734,7 +1127,7 @@ impl<'a> Iterator for ConstantPoolIterator<'a> {
 }
 
 impl<'a> IntoIterator for &'a ConstantPool {
-    type Item = &'a Constant;
+    type Item = (u16, &'a Constant);
     type IntoIter = ConstantPoolIterator<'a>;
 
     fn into_iter(self) -> Self::IntoIter {
@@ -764,7 +1157,7 @@ impl fmt::Display for ConstantPool {
 mod test {
     use super::*;
     use crate::constant::Constant;
-    use crate::Error::IoError;
+    use crate::Error::{InvalidConstantPoolIndex, IoError};
     use std::fmt::Debug;
 
     #[test]
@@ -776,6 +1169,36 @@ mod test {
         assert_eq!("", ConstantEntry::Placeholder.to_string());
     }
 
+    #[test]
+    fn test_from_constants() {
+        let constants = vec![
+            Constant::Long(42),
+            Constant::Utf8("foo".to_string()),
+            Constant::Integer(3),
+        ];
+        let constant_pool = ConstantPool::from(constants.clone());
+        assert_eq!(4, constant_pool.len());
+        assert_eq!(
+            Some(&Constant::Utf8("foo".to_string())),
+            constant_pool.get(3)
+        );
+        assert_eq!(constants, Vec::<Constant>::from(constant_pool));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        constant_pool.push(Constant::Double(1.5));
+        constant_pool.push(Constant::Utf8("foo".to_string()));
+        let json = serde_json::to_string(&constant_pool).map_err(std::io::Error::from)?;
+        assert_eq!(r#"[{"Double":1.5},{"Utf8":"foo"}]"#, json);
+        let deserialized: ConstantPool =
+            serde_json::from_str(&json).map_err(std::io::Error::from)?;
+        assert_eq!(constant_pool, deserialized);
+        Ok(())
+    }
+
     #[test]
     fn test_get_zero_none() {
         let constant_pool = ConstantPool::default();
@@ -790,6 +1213,19 @@ mod test {
         assert!(constant_pool.get(1).is_some());
     }
 
+    #[test]
+    fn test_get_mut() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        assert!(constant_pool.get_mut(0).is_none());
+        let index = constant_pool.add_long(42)?;
+        assert!(constant_pool.get_mut(index + 1).is_none());
+        if let Some(constant) = constant_pool.get_mut(index) {
+            *constant = Constant::Long(3);
+        }
+        assert_eq!(Some(&Constant::Long(3)), constant_pool.get(index));
+        Ok(())
+    }
+
     #[test]
     fn test_try_get_zero_error() {
         let constant_pool = ConstantPool::default();
@@ -851,10 +1287,14 @@ mod test {
         constant_pool.push(Constant::Utf8("foo".to_string()));
         constant_pool.push(Constant::Integer(42));
         constant_pool.push(Constant::Long(1_234_567_890));
+        constant_pool.push(Constant::Double(1.5));
+        constant_pool.push(Constant::Integer(7));
         let mut iter = constant_pool.iter();
-        assert_eq!(Some(&Constant::Utf8("foo".to_string())), iter.next());
-        assert_eq!(Some(&Constant::Integer(42)), iter.next());
-        assert_eq!(Some(&Constant::Long(1_234_567_890)), iter.next());
+        assert_eq!(Some((1, &Constant::Utf8("foo".to_string()))), iter.next());
+        assert_eq!(Some((2, &Constant::Integer(42))), iter.next());
+        assert_eq!(Some((3, &Constant::Long(1_234_567_890))), iter.next());
+        assert_eq!(Some((5, &Constant::Double(1.5))), iter.next());
+        assert_eq!(Some((7, &Constant::Integer(7))), iter.next());
         assert_eq!(None, iter.next());
     }
 
@@ -862,11 +1302,76 @@ mod test {
     fn test_into_iter() {
         let mut constant_pool = ConstantPool::default();
         constant_pool.push(Constant::Utf8("foo".to_string()));
-        for constant in &constant_pool {
+        for (index, constant) in &constant_pool {
+            assert_eq!(1, index);
             assert_eq!(Constant::Utf8("foo".to_string()), *constant);
         }
     }
 
+    #[test]
+    fn test_find_utf8() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        constant_pool.add_long(42)?;
+        let index = constant_pool.add_utf8("foo")?;
+        constant_pool.add_utf8("foo")?;
+        assert_eq!(Some(index), constant_pool.find_utf8("foo"));
+        assert_eq!(None, constant_pool.find_utf8("bar"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_class() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        constant_pool.add_utf8("java/lang/String")?;
+        let index = constant_pool.add_class("java/lang/String")?;
+        assert_eq!(Some(index), constant_pool.find_class("java/lang/String"));
+        assert_eq!(None, constant_pool.find_class("java/lang/Object"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_name_and_type() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let index = constant_pool.add_name_and_type("length", "()I")?;
+        assert_eq!(
+            Some(index),
+            constant_pool.find_name_and_type("length", "()I")
+        );
+        assert_eq!(None, constant_pool.find_name_and_type("length", "()J"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_member_refs() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let class_index = constant_pool.add_class("Foo")?;
+        let field_index = constant_pool.add_field_ref(class_index, "bar", "I")?;
+        let method_index = constant_pool.add_method_ref(class_index, "bar", "()I")?;
+        let interface_method_index =
+            constant_pool.add_interface_method_ref(class_index, "baz", "()V")?;
+
+        assert_eq!(
+            Some(field_index),
+            constant_pool.find_field_ref("Foo", "bar", "I")
+        );
+        assert_eq!(None, constant_pool.find_field_ref("Foo", "bar", "()I"));
+        assert_eq!(
+            Some(method_index),
+            constant_pool.find_method_ref("Foo", "bar", "()I")
+        );
+        assert_eq!(None, constant_pool.find_method_ref("Foo", "baz", "()V"));
+        assert_eq!(None, constant_pool.find_method_ref("Bar", "bar", "()I"));
+        assert_eq!(
+            Some(interface_method_index),
+            constant_pool.find_interface_method_ref("Foo", "baz", "()V")
+        );
+        assert_eq!(
+            None,
+            constant_pool.find_interface_method_ref("Foo", "bar", "()I")
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_double() {
         let mut constant_pool = ConstantPool::default();
@@ -1186,6 +1691,50 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_try_get_member_ref() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let class_index = constant_pool.add_class("Foo")?;
+        let field_index = constant_pool.add_field_ref(class_index, "x", "I")?;
+        let method_index = constant_pool.add_method_ref(class_index, "y", "()V")?;
+        let interface_method_index =
+            constant_pool.add_interface_method_ref(class_index, "z", "()I")?;
+        assert_eq!(
+            ("Foo", "x", "I"),
+            tuple(constant_pool.try_get_member_ref(field_index)?)
+        );
+        assert_eq!(
+            ("Foo", "y", "()V"),
+            tuple(constant_pool.try_get_member_ref(method_index)?)
+        );
+        assert_eq!(
+            ("Foo", "z", "()I"),
+            tuple(constant_pool.try_get_member_ref(interface_method_index)?)
+        );
+        assert_eq!(
+            Err(InvalidConstantPoolIndexType(class_index)),
+            constant_pool.try_get_member_ref(class_index)
+        );
+        Ok(())
+    }
+
+    fn tuple<'a>(value: (&'a String, &'a String, &'a String)) -> (&'a str, &'a str, &'a str) {
+        (value.0, value.1, value.2)
+    }
+
+    #[test]
+    fn test_is_member_ref() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let class_index = constant_pool.add_class("java/io/PrintStream")?;
+        let index =
+            constant_pool.add_method_ref(class_index, "println", "(Ljava/lang/String;)V")?;
+        assert!(constant_pool.is_member_ref(index, "java/io/PrintStream", "println"));
+        assert!(!constant_pool.is_member_ref(index, "java/io/PrintStream", "print"));
+        assert!(!constant_pool.is_member_ref(index, "java/lang/Object", "println"));
+        assert!(!constant_pool.is_member_ref(class_index, "java/io/PrintStream", "println"));
+        Ok(())
+    }
+
     #[test]
     fn test_try_get_formatted_string_method_ref() -> Result<()> {
         let mut constant_pool = ConstantPool::default();
@@ -1456,4 +2005,107 @@ mod test {
             ConstantPool::from_bytes(&mut bytes)
         );
     }
+
+    #[test]
+    fn test_unused_entries() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let unused_index = constant_pool.add_utf8("unused")?;
+        let long_index = constant_pool.add_long(42)?;
+        let this_class = constant_pool.add_class("Foo")?;
+        let super_class = constant_pool.add_class("java/lang/Object")?;
+        let unused_class_index = constant_pool.add_class("Bar")?;
+        let class_file = ClassFile {
+            constant_pool: constant_pool.clone(),
+            this_class,
+            super_class,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            vec![
+                unused_index,
+                long_index,
+                unused_class_index - 1,
+                unused_class_index
+            ],
+            constant_pool.unused_entries(&class_file)?
+        );
+        assert_eq!(
+            vec![
+                (unused_index, 9),
+                (long_index, 9),
+                (unused_class_index - 1, 6),
+                (unused_class_index, 3)
+            ],
+            constant_pool.unused_entries_with_sizes(&class_file)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_unused_entries_none() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let this_class = constant_pool.add_class("Foo")?;
+        let class_file = ClassFile {
+            constant_pool: constant_pool.clone(),
+            this_class,
+            ..Default::default()
+        };
+        assert!(constant_pool.unused_entries(&class_file)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_removes_unreferenced() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        constant_pool.add_utf8("unused")?;
+        let long_index = constant_pool.add_long(42)?;
+        let class_index = constant_pool.add_class("Foo")?;
+        let remap = constant_pool.compact([class_index, 0])?;
+
+        assert_eq!(2, constant_pool.len());
+        assert_eq!(
+            Some(&Constant::Utf8("Foo".to_string())),
+            constant_pool.get(1)
+        );
+        assert_eq!(Some(&Constant::Class(1)), constant_pool.get(2));
+        assert_eq!(HashMap::from([(4, 1), (5, 2)]), remap);
+        assert!(!remap.contains_key(&long_index));
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_merges_duplicates() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let long_index = constant_pool.add_long(42)?;
+        let class_index = constant_pool.add_class("Foo")?;
+        let duplicate_class_index = constant_pool.add_class("Foo")?;
+        let method_index = constant_pool.add_method_ref(duplicate_class_index, "foo", "()V")?;
+        let remap = constant_pool.compact([
+            long_index,
+            class_index,
+            duplicate_class_index,
+            method_index,
+        ])?;
+
+        let mut expected = ConstantPool::default();
+        expected.add_long(42)?;
+        let expected_class_index = expected.add_class("Foo")?;
+        let expected_method_index = expected.add_method_ref(expected_class_index, "foo", "()V")?;
+        assert_eq!(expected, constant_pool);
+        assert_eq!(Some(&1), remap.get(&long_index));
+        assert_eq!(Some(&expected_class_index), remap.get(&class_index));
+        assert_eq!(
+            Some(&expected_class_index),
+            remap.get(&duplicate_class_index)
+        );
+        assert_eq!(Some(&expected_method_index), remap.get(&method_index));
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_invalid_index() {
+        let mut constant_pool = ConstantPool::default();
+        assert_eq!(Err(InvalidConstantPoolIndex(1)), constant_pool.compact([1]));
+    }
 }
@@ -0,0 +1,565 @@
+use crate::attributes::{
+    Attribute, CodeEditor, ExceptionTableEntry, Instruction, InstructionOffsets, StackFrame,
+    VerificationType,
+};
+use crate::base_type::BaseType;
+use crate::class_file::ClassFile;
+use crate::constant_pool::ConstantPool;
+use crate::error::Error::{InvalidInstructionOffset, InvalidStackFrameType};
+use crate::error::Result;
+use crate::field_type::FieldType;
+use crate::method::Method;
+use crate::method_access_flags::MethodAccessFlags;
+use crate::method_descriptor::MethodDescriptor;
+use crate::verifiers::bytecode::{inference, stack_map_table};
+use std::ops::Range;
+
+/// Instructions removed from the code of a method by
+/// [`remove_dead_code`](ClassFile::remove_dead_code).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeadCode {
+    /// Method the instructions were removed from, e.g. `Foo.bar(I)V`
+    pub method: String,
+    /// Ranges of the removed instructions, as instruction indexes of the original code
+    pub instructions: Vec<Range<usize>>,
+    /// Number of exception table entries removed
+    pub exception_table_entries: usize,
+    /// Number of stack map frames removed
+    pub stack_frames: usize,
+}
+
+/// A stack map frame with the locals and stack declared at an instruction rather than relative to
+/// the previous frame.
+#[derive(Debug)]
+struct DeclaredFrame {
+    index: usize,
+    locals: Vec<VerificationType>,
+    stack: Vec<VerificationType>,
+}
+
+/// Remove the unreachable instructions of every method; see [`ClassFile::remove_dead_code`].
+pub(crate) fn remove_dead_code(class_file: &mut ClassFile) -> Result<Vec<DeadCode>> {
+    let mut removed = Vec::new();
+    for method_index in 0..class_file.methods.len() {
+        let method = &class_file.methods[method_index];
+        let Some((_, code, exception_table)) = inference::code(method) else {
+            continue;
+        };
+        let Some(reachable) = reachable(code, exception_table) else {
+            continue;
+        };
+        let ranges = unreachable_ranges(&reachable);
+        if ranges.is_empty() {
+            continue;
+        }
+
+        let constant_pool = &class_file.constant_pool;
+        let class_name = class_file.class_name()?;
+        let method_name = constant_pool.try_get_utf8(method.name_index)?;
+        let method_descriptor = constant_pool.try_get_utf8(method.descriptor_index)?;
+        let method_label = format!("{class_name}.{method_name}{method_descriptor}");
+        let old_offsets = InstructionOffsets::new(code)?;
+        let stack_frames = stack_frames(method).cloned();
+
+        // Frames are relative to the previous frame, so the frames are decoded before any frame
+        // is removed and encoded again once the code has been edited
+        let declared_frames = match &stack_frames {
+            Some(stack_frames) => {
+                let initial_locals = initial_locals(class_file, method_index)?;
+                let frames = declared_frames(initial_locals.clone(), stack_frames)?;
+                Some((initial_locals, frames))
+            }
+            None => None,
+        };
+
+        let method = &mut class_file.methods[method_index];
+        let mut exception_table_entries = 0;
+        let mut new_offsets = None;
+        for attribute in &mut method.attributes {
+            let Attribute::Code {
+                exception_table,
+                attributes,
+                ..
+            } = attribute
+            else {
+                continue;
+            };
+            exception_table_entries = exception_table.len();
+            for attribute in attributes {
+                if let Attribute::StackMapTable { frames, .. } = attribute {
+                    frames.clear();
+                }
+            }
+
+            let mut editor = CodeEditor::new(attribute)?;
+            // Ranges are deleted from the end of the code so that the earlier ranges are unchanged
+            for range in ranges.iter().rev() {
+                editor.delete(range.clone())?;
+            }
+            exception_table_entries -= editor.exception_table().len();
+            new_offsets = Some(InstructionOffsets::new(editor.code())?);
+            break;
+        }
+
+        let mut removed_frames = 0;
+        if let (Some((initial_locals, frames)), Some(new_offsets)) = (declared_frames, new_offsets)
+        {
+            let frame_count = frames.len();
+            let mut retained = Vec::with_capacity(frame_count);
+            for mut frame in frames {
+                if !reachable
+                    .get(frame.index)
+                    .is_some_and(|reachable| *reachable)
+                {
+                    continue;
+                }
+                frame.index = new_index(&reachable, frame.index);
+                for value in frame.locals.iter_mut().chain(frame.stack.iter_mut()) {
+                    if let VerificationType::Uninitialized { offset } = value {
+                        let index = old_offsets.instruction_index(u32::from(*offset))?;
+                        if !reachable.get(index).is_some_and(|reachable| *reachable) {
+                            return Err(InvalidInstructionOffset(u32::from(*offset)));
+                        }
+                        *offset = new_offsets.byte_offset(new_index(&reachable, index))?;
+                    }
+                }
+                retained.push(frame);
+            }
+            removed_frames = frame_count - retained.len();
+            let stack_frames = encode_frames(&new_offsets, &initial_locals, &retained)?;
+            set_stack_frames(&mut class_file.methods[method_index], stack_frames);
+        }
+
+        removed.push(DeadCode {
+            method: method_label,
+            instructions: ranges,
+            exception_table_entries,
+            stack_frames: removed_frames,
+        });
+    }
+    Ok(removed)
+}
+
+/// Determine which instructions are reachable from the start of the code or from the handler of
+/// an exception table entry that protects a reachable instruction.  Returns `None` if the
+/// successors of an instruction cannot be determined.
+pub(crate) fn reachable(
+    code: &[Instruction],
+    exception_table: &[ExceptionTableEntry],
+) -> Option<Vec<bool>> {
+    let mut reachable = vec![false; code.len()];
+    let mut pending = if code.is_empty() { Vec::new() } else { vec![0] };
+    loop {
+        while let Some(index) = pending.pop() {
+            if reachable[index] {
+                continue;
+            }
+            reachable[index] = true;
+            pending.extend(inference::successors(code, index).ok()?);
+        }
+        for entry in exception_table {
+            let handler = usize::from(entry.handler_pc);
+            let range = usize::from(entry.range_pc.start)..usize::from(entry.range_pc.end);
+            if handler < code.len()
+                && !reachable[handler]
+                && reachable
+                    .get(range)
+                    .is_some_and(|range| range.contains(&true))
+            {
+                pending.push(handler);
+            }
+        }
+        if pending.is_empty() {
+            return Some(reachable);
+        }
+    }
+}
+
+/// Get the ranges of consecutive unreachable instructions in ascending order.
+pub(crate) fn unreachable_ranges(reachable: &[bool]) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = None;
+    for (index, reachable) in reachable.iter().enumerate() {
+        match (start, *reachable) {
+            (None, false) => start = Some(index),
+            (Some(range_start), true) => {
+                ranges.push(range_start..index);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = start {
+        ranges.push(start..reachable.len());
+    }
+    ranges
+}
+
+/// Get the index of a reachable instruction once the unreachable instructions are removed.
+fn new_index(reachable: &[bool], index: usize) -> usize {
+    reachable[..index]
+        .iter()
+        .filter(|reachable| **reachable)
+        .count()
+}
+
+/// Get the frames of the `StackMapTable` attribute of a method, if any.
+fn stack_frames(method: &Method) -> Option<&Vec<StackFrame>> {
+    method.attributes.iter().find_map(|attribute| {
+        let Attribute::Code { attributes, .. } = attribute else {
+            return None;
+        };
+        attributes.iter().find_map(|attribute| match attribute {
+            Attribute::StackMapTable { frames, .. } => Some(frames),
+            _ => None,
+        })
+    })
+}
+
+/// Replace the frames of the `StackMapTable` attribute of a method; the attribute is removed if
+/// there are no frames.
+fn set_stack_frames(method: &mut Method, stack_frames: Vec<StackFrame>) {
+    for attribute in &mut method.attributes {
+        let Attribute::Code { attributes, .. } = attribute else {
+            continue;
+        };
+        if stack_frames.is_empty() {
+            attributes.retain(|attribute| !matches!(attribute, Attribute::StackMapTable { .. }));
+            return;
+        }
+        for attribute in attributes {
+            if let Attribute::StackMapTable { frames, .. } = attribute {
+                *frames = stack_frames;
+                return;
+            }
+        }
+    }
+}
+
+/// Get the locals of the implicit frame on method entry, as encoded in a stack map frame.  Class
+/// constants are added for parameter types that do not have one.
+fn initial_locals(
+    class_file: &mut ClassFile,
+    method_index: usize,
+) -> Result<Vec<VerificationType>> {
+    let this_class = class_file.this_class;
+    let class_name = class_file.class_name()?.clone();
+    let method = &class_file.methods[method_index];
+    let access_flags = method.access_flags;
+    let constant_pool = &mut class_file.constant_pool;
+    let method_name = constant_pool.try_get_utf8(method.name_index)?;
+    let is_constructor = method_name == "<init>";
+    let method_descriptor =
+        MethodDescriptor::parse(constant_pool.try_get_utf8(method.descriptor_index)?)?;
+
+    let mut locals = Vec::new();
+    if !access_flags.contains(MethodAccessFlags::STATIC) {
+        if is_constructor && class_name != "java/lang/Object" {
+            locals.push(VerificationType::UninitializedThis);
+        } else {
+            locals.push(VerificationType::Object {
+                cpool_index: this_class,
+            });
+        }
+    }
+    for parameter in &method_descriptor.parameters {
+        locals.push(parameter_type(constant_pool, parameter)?);
+    }
+    Ok(locals)
+}
+
+/// Get the verification type of a method parameter.
+fn parameter_type(
+    constant_pool: &mut ConstantPool,
+    field_type: &FieldType,
+) -> Result<VerificationType> {
+    let verification_type = match field_type {
+        FieldType::Base(BaseType::Double) => VerificationType::Double,
+        FieldType::Base(BaseType::Float) => VerificationType::Float,
+        FieldType::Base(BaseType::Long) => VerificationType::Long,
+        FieldType::Base(_) => VerificationType::Integer,
+        FieldType::Object(class_name) => VerificationType::Object {
+            cpool_index: stack_map_table::class_index(constant_pool, class_name)?,
+        },
+        FieldType::Array(_) => VerificationType::Object {
+            cpool_index: stack_map_table::class_index(constant_pool, &field_type.descriptor())?,
+        },
+    };
+    Ok(verification_type)
+}
+
+/// Decode stack map frames into the locals and stack declared at each frame's instruction.
+fn declared_frames(
+    initial_locals: Vec<VerificationType>,
+    stack_frames: &[StackFrame],
+) -> Result<Vec<DeclaredFrame>> {
+    let mut locals = initial_locals;
+    let mut offset: Option<usize> = None;
+    let mut frames = Vec::with_capacity(stack_frames.len());
+    for stack_frame in stack_frames {
+        let delta = usize::from(stack_frame.offset_delta());
+        let index = offset.map_or(delta, |offset| offset + delta + 1);
+        offset = Some(index);
+        let stack = match stack_frame {
+            StackFrame::SameFrame { .. } | StackFrame::SameFrameExtended { .. } => Vec::new(),
+            StackFrame::SameLocals1StackItemFrame { stack, .. }
+            | StackFrame::SameLocals1StackItemFrameExtended { stack, .. } => stack.clone(),
+            StackFrame::ChopFrame { frame_type, .. } => {
+                let chopped = usize::from(251 - frame_type);
+                if chopped > locals.len() {
+                    return Err(InvalidStackFrameType(*frame_type));
+                }
+                locals.truncate(locals.len() - chopped);
+                Vec::new()
+            }
+            StackFrame::AppendFrame {
+                locals: appended, ..
+            } => {
+                locals.extend(appended.iter().cloned());
+                Vec::new()
+            }
+            StackFrame::FullFrame {
+                locals: full_locals,
+                stack,
+                ..
+            } => {
+                locals.clone_from(full_locals);
+                stack.clone()
+            }
+        };
+        frames.push(DeclaredFrame {
+            index,
+            locals: locals.clone(),
+            stack,
+        });
+    }
+    Ok(frames)
+}
+
+/// Encode declared frames as stack map frames, each relative to the previous frame, using the
+/// most compact frame type that the byte offset delta allows.
+fn encode_frames(
+    offsets: &InstructionOffsets,
+    initial_locals: &[VerificationType],
+    frames: &[DeclaredFrame],
+) -> Result<Vec<StackFrame>> {
+    let mut previous_locals = initial_locals;
+    let mut previous: Option<(usize, u16)> = None;
+    let mut stack_frames = Vec::with_capacity(frames.len());
+    for frame in frames {
+        let byte_offset = offsets.byte_offset(frame.index)?;
+        let (offset_delta, byte_offset_delta) = match previous {
+            None => (u16::try_from(frame.index)?, byte_offset),
+            Some((previous_index, previous_byte_offset)) => (
+                u16::try_from(frame.index - previous_index - 1)?,
+                byte_offset - previous_byte_offset - 1,
+            ),
+        };
+        let locals = &frame.locals;
+        let stack = &frame.stack;
+
+        let stack_frame = if locals == previous_locals && stack.is_empty() {
+            if byte_offset_delta <= 63 {
+                StackFrame::SameFrame {
+                    frame_type: u8::try_from(offset_delta)?,
+                }
+            } else {
+                StackFrame::SameFrameExtended {
+                    frame_type: 251,
+                    offset_delta,
+                }
+            }
+        } else if locals == previous_locals && stack.len() == 1 {
+            if byte_offset_delta <= 63 {
+                StackFrame::SameLocals1StackItemFrame {
+                    frame_type: u8::try_from(offset_delta + 64)?,
+                    stack: stack.clone(),
+                }
+            } else {
+                StackFrame::SameLocals1StackItemFrameExtended {
+                    frame_type: 247,
+                    offset_delta,
+                    stack: stack.clone(),
+                }
+            }
+        } else if stack.is_empty()
+            && locals.len() < previous_locals.len()
+            && previous_locals.len() - locals.len() <= 3
+            && previous_locals.starts_with(locals)
+        {
+            let chopped = u8::try_from(previous_locals.len() - locals.len())?;
+            StackFrame::ChopFrame {
+                frame_type: 251 - chopped,
+                offset_delta,
+            }
+        } else if stack.is_empty()
+            && locals.len() > previous_locals.len()
+            && locals.len() - previous_locals.len() <= 3
+            && locals.starts_with(previous_locals)
+        {
+            let appended = locals[previous_locals.len()..].to_vec();
+            StackFrame::AppendFrame {
+                frame_type: 251 + u8::try_from(appended.len())?,
+                offset_delta,
+                locals: appended,
+            }
+        } else {
+            StackFrame::FullFrame {
+                frame_type: 255,
+                offset_delta,
+                locals: locals.clone(),
+                stack: stack.clone(),
+            }
+        };
+        stack_frames.push(stack_frame);
+        previous_locals = locals;
+        previous = Some((frame.index, byte_offset));
+    }
+    Ok(stack_frames)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::version::Version;
+    use std::io::Cursor;
+
+    fn class_file(
+        code: Vec<Instruction>,
+        exception_table: Vec<ExceptionTableEntry>,
+    ) -> Result<ClassFile> {
+        let mut class_file = crate::test::class_file("Foo", Version::Java8 { minor: 0 })?;
+        let constant_pool = &mut class_file.constant_pool;
+        let code_index = constant_pool.add_utf8("Code")?;
+        let stack_map_table_index = constant_pool.add_utf8("StackMapTable")?;
+        let name_index = constant_pool.add_utf8("test")?;
+        let descriptor_index = constant_pool.add_utf8("()V")?;
+        let method = Method {
+            access_flags: MethodAccessFlags::STATIC,
+            name_index,
+            descriptor_index,
+            attributes: vec![Attribute::Code {
+                name_index: code_index,
+                max_stack: 1,
+                max_locals: 2,
+                code,
+                exception_table,
+                attributes: vec![Attribute::StackMapTable {
+                    name_index: stack_map_table_index,
+                    frames: vec![
+                        StackFrame::AppendFrame {
+                            frame_type: 252,
+                            offset_delta: 3,
+                            locals: vec![VerificationType::Integer],
+                        },
+                        StackFrame::SameFrame { frame_type: 1 },
+                    ],
+                }],
+            }],
+        };
+        class_file.methods.push(method);
+        Ok(class_file)
+    }
+
+    #[test]
+    fn test_unreachable_ranges() {
+        let reachable = [true, false, false, true, false];
+        assert_eq!(vec![1..3, 4..5], unreachable_ranges(&reachable));
+    }
+
+    #[test]
+    fn test_reachable_exception_handler() {
+        let code = vec![
+            Instruction::Iconst_0,
+            Instruction::Ireturn,
+            Instruction::Pop,
+            Instruction::Iconst_1,
+            Instruction::Ireturn,
+        ];
+        let exception_table = vec![ExceptionTableEntry {
+            range_pc: 0..1,
+            handler_pc: 2,
+            catch_type: 0,
+        }];
+        assert_eq!(Some(vec![true; 5]), reachable(&code, &exception_table));
+        assert_eq!(
+            Some(vec![true, true, false, false, false]),
+            reachable(&code, &[])
+        );
+    }
+
+    #[test]
+    fn test_remove_dead_code() -> Result<()> {
+        let code = vec![
+            Instruction::Iconst_0,
+            Instruction::Istore_0,
+            Instruction::Goto(5),
+            Instruction::Iconst_1,
+            Instruction::Istore_1,
+            Instruction::Return,
+        ];
+        let exception_table = vec![ExceptionTableEntry {
+            range_pc: 3..5,
+            handler_pc: 5,
+            catch_type: 0,
+        }];
+        let mut class_file = class_file(code, exception_table)?;
+
+        let removed = class_file.remove_dead_code()?;
+        assert_eq!(
+            vec![DeadCode {
+                method: "Foo.test()V".to_string(),
+                instructions: vec![Range { start: 3, end: 5 }],
+                exception_table_entries: 1,
+                stack_frames: 1,
+            }],
+            removed
+        );
+
+        let Attribute::Code {
+            code,
+            exception_table,
+            attributes,
+            ..
+        } = &class_file.methods[0].attributes[0]
+        else {
+            unreachable!()
+        };
+        assert_eq!(
+            &vec![
+                Instruction::Iconst_0,
+                Instruction::Istore_0,
+                Instruction::Goto(3),
+                Instruction::Return,
+            ],
+            code
+        );
+        assert!(exception_table.is_empty());
+        let Attribute::StackMapTable { frames, .. } = &attributes[0] else {
+            unreachable!()
+        };
+        assert_eq!(
+            &vec![StackFrame::AppendFrame {
+                frame_type: 252,
+                offset_delta: 3,
+                locals: vec![VerificationType::Integer],
+            }],
+            frames
+        );
+
+        let mut bytes = Vec::new();
+        class_file.to_bytes(&mut bytes)?;
+        assert_eq!(class_file, ClassFile::from_bytes(&mut Cursor::new(bytes))?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_dead_code_unchanged() -> Result<()> {
+        let class_bytes = include_bytes!("../../classes/Expressions.class");
+        let expected = ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))?;
+        let mut class_file = expected.clone();
+        assert!(class_file.remove_dead_code()?.is_empty());
+        assert_eq!(expected, class_file);
+        Ok(())
+    }
+}
@@ -0,0 +1,443 @@
+use crate::attributes::{
+    Attribute, InstructionOffsets, LineNumber, LocalVariableTable, LocalVariableTypeTable,
+};
+use crate::constant_pool::ConstantPool;
+use crate::error::Error::{InvalidCodeAttribute, InvalidInstructionOffset};
+use crate::error::Result;
+use std::ops::Range;
+
+/// Builder for the `LineNumberTable` attribute of a `Code` attribute.
+///
+/// Line numbers are recorded against instruction indexes so that they can be added while code is
+/// being generated; they are validated against the code when added to the `Code` attribute.
+///
+/// # Examples
+///
+/// ```rust
+/// use ristretto_classfile::attributes::{Attribute, Instruction};
+/// use ristretto_classfile::{ConstantPool, LineNumberTableBuilder, Result};
+///
+/// fn main() -> Result<()> {
+///     let mut constant_pool = ConstantPool::default();
+///     let mut code = Attribute::Code {
+///         name_index: constant_pool.add_utf8("Code")?,
+///         max_stack: 1,
+///         max_locals: 0,
+///         code: vec![Instruction::Iconst_0, Instruction::Pop, Instruction::Return],
+///         exception_table: Vec::new(),
+///         attributes: Vec::new(),
+///     };
+///     LineNumberTableBuilder::new()
+///         .line(0, 10)
+///         .line(2, 11)
+///         .add_to(&mut constant_pool, &mut code)?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LineNumberTableBuilder {
+    line_numbers: Vec<(usize, u16)>,
+}
+
+impl LineNumberTableBuilder {
+    /// Create a new, empty builder.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that the instruction at `index` starts source line `line_number`.
+    #[must_use]
+    pub fn line(mut self, index: usize, line_number: u16) -> Self {
+        self.line_numbers.push((index, line_number));
+        self
+    }
+
+    /// Add the `LineNumberTable` to a `Code` attribute, replacing an existing table.  Nothing is
+    /// added if no lines have been recorded.
+    ///
+    /// # Errors
+    /// - If the attribute is not a `Code` attribute.
+    /// - If an instruction index is not within the code.
+    pub fn add_to(
+        &self,
+        constant_pool: &mut ConstantPool,
+        attribute: &mut Attribute,
+    ) -> Result<()> {
+        let Attribute::Code {
+            code, attributes, ..
+        } = attribute
+        else {
+            return Err(InvalidCodeAttribute(attribute.name().to_string()));
+        };
+        if self.line_numbers.is_empty() {
+            return Ok(());
+        }
+
+        let mut line_numbers = Vec::with_capacity(self.line_numbers.len());
+        for (index, line_number) in &self.line_numbers {
+            if *index >= code.len() {
+                return Err(InvalidInstructionOffset(u32::try_from(*index)?));
+            }
+            line_numbers.push(LineNumber {
+                start_pc: u16::try_from(*index)?,
+                line_number: *line_number,
+            });
+        }
+        line_numbers.sort_by_key(|line_number| line_number.start_pc);
+
+        attributes.retain(|attribute| !matches!(attribute, Attribute::LineNumberTable { .. }));
+        attributes.push(Attribute::LineNumberTable {
+            name_index: constant_pool.add_utf8("LineNumberTable")?,
+            line_numbers,
+        });
+        Ok(())
+    }
+}
+
+/// Local variable recorded by a [`LocalVariableTableBuilder`].
+#[derive(Clone, Debug, PartialEq)]
+struct Variable {
+    range: Range<usize>,
+    index: u16,
+    name: String,
+    descriptor: String,
+}
+
+/// Builder for the `LocalVariableTable` and `LocalVariableTypeTable` attributes of a `Code`
+/// attribute.
+///
+/// Variables are recorded against a range of instruction indexes and a local variable slot; the
+/// ranges are converted to the byte offsets required by the attributes when they are added to the
+/// `Code` attribute.
+///
+/// # Examples
+///
+/// ```rust
+/// use ristretto_classfile::attributes::{Attribute, Instruction};
+/// use ristretto_classfile::{ConstantPool, LocalVariableTableBuilder, Result};
+///
+/// fn main() -> Result<()> {
+///     let mut constant_pool = ConstantPool::default();
+///     let mut code = Attribute::Code {
+///         name_index: constant_pool.add_utf8("Code")?,
+///         max_stack: 1,
+///         max_locals: 1,
+///         code: vec![Instruction::Aload_0, Instruction::Areturn],
+///         exception_table: Vec::new(),
+///         attributes: Vec::new(),
+///     };
+///     LocalVariableTableBuilder::new()
+///         .variable(0..2, 0, "values", "Ljava/util/List;")
+///         .variable_type(0..2, 0, "values", "Ljava/util/List<Ljava/lang/String;>;")
+///         .add_to(&mut constant_pool, &mut code)?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LocalVariableTableBuilder {
+    variables: Vec<Variable>,
+    variable_types: Vec<Variable>,
+}
+
+impl LocalVariableTableBuilder {
+    /// Create a new, empty builder.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a local variable with a field descriptor that is live in local variable slot
+    /// `index` for the instructions in `range`.
+    #[must_use]
+    pub fn variable<S: AsRef<str>>(
+        mut self,
+        range: Range<usize>,
+        index: u16,
+        name: S,
+        descriptor: S,
+    ) -> Self {
+        self.variables.push(Variable {
+            range,
+            index,
+            name: name.as_ref().to_string(),
+            descriptor: descriptor.as_ref().to_string(),
+        });
+        self
+    }
+
+    /// Record the generic signature of a local variable that is live in local variable slot
+    /// `index` for the instructions in `range`.
+    #[must_use]
+    pub fn variable_type<S: AsRef<str>>(
+        mut self,
+        range: Range<usize>,
+        index: u16,
+        name: S,
+        signature: S,
+    ) -> Self {
+        self.variable_types.push(Variable {
+            range,
+            index,
+            name: name.as_ref().to_string(),
+            descriptor: signature.as_ref().to_string(),
+        });
+        self
+    }
+
+    /// Add the `LocalVariableTable` and `LocalVariableTypeTable` to a `Code` attribute, replacing
+    /// existing tables.  A table is not added if no variables have been recorded for it.
+    ///
+    /// # Errors
+    /// - If the attribute is not a `Code` attribute.
+    /// - If a range is empty or not within the code.
+    /// - If the code cannot be serialized.
+    pub fn add_to(
+        &self,
+        constant_pool: &mut ConstantPool,
+        attribute: &mut Attribute,
+    ) -> Result<()> {
+        let Attribute::Code {
+            code, attributes, ..
+        } = attribute
+        else {
+            return Err(InvalidCodeAttribute(attribute.name().to_string()));
+        };
+        let offsets = InstructionOffsets::new(code)?;
+
+        if !self.variables.is_empty() {
+            let mut variables = Vec::with_capacity(self.variables.len());
+            for variable in &self.variables {
+                let (start_pc, length) = byte_range(&offsets, &variable.range)?;
+                variables.push(LocalVariableTable {
+                    start_pc,
+                    length,
+                    name_index: constant_pool.add_utf8(&variable.name)?,
+                    descriptor_index: constant_pool.add_utf8(&variable.descriptor)?,
+                    index: variable.index,
+                });
+            }
+            attributes
+                .retain(|attribute| !matches!(attribute, Attribute::LocalVariableTable { .. }));
+            attributes.push(Attribute::LocalVariableTable {
+                name_index: constant_pool.add_utf8("LocalVariableTable")?,
+                variables,
+            });
+        }
+
+        if !self.variable_types.is_empty() {
+            let mut variable_types = Vec::with_capacity(self.variable_types.len());
+            for variable in &self.variable_types {
+                let (start_pc, length) = byte_range(&offsets, &variable.range)?;
+                variable_types.push(LocalVariableTypeTable {
+                    start_pc,
+                    length,
+                    name_index: constant_pool.add_utf8(&variable.name)?,
+                    signature_index: constant_pool.add_utf8(&variable.descriptor)?,
+                    index: variable.index,
+                });
+            }
+            attributes
+                .retain(|attribute| !matches!(attribute, Attribute::LocalVariableTypeTable { .. }));
+            attributes.push(Attribute::LocalVariableTypeTable {
+                name_index: constant_pool.add_utf8("LocalVariableTypeTable")?,
+                variable_types,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Convert a range of instruction indexes to a byte offset and length.
+fn byte_range(offsets: &InstructionOffsets, range: &Range<usize>) -> Result<(u16, u16)> {
+    if range.start >= range.end || range.end > offsets.len() {
+        return Err(InvalidInstructionOffset(u32::try_from(range.end)?));
+    }
+    let start_pc = offsets.byte_offset(range.start)?;
+    let end_pc = offsets.byte_offset(range.end)?;
+    Ok((start_pc, end_pc - start_pc))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::attributes::Instruction;
+
+    fn code_attribute(constant_pool: &mut ConstantPool) -> Result<Attribute> {
+        Ok(Attribute::Code {
+            name_index: constant_pool.add_utf8("Code")?,
+            max_stack: 1,
+            max_locals: 2,
+            code: vec![
+                Instruction::Iconst_0,
+                Instruction::Istore(1),
+                Instruction::Iload_1,
+                Instruction::Ireturn,
+            ],
+            exception_table: Vec::new(),
+            attributes: Vec::new(),
+        })
+    }
+
+    fn code_attributes(attribute: &Attribute) -> &Vec<Attribute> {
+        let Attribute::Code { attributes, .. } = attribute else {
+            panic!("expected Code attribute");
+        };
+        attributes
+    }
+
+    #[test]
+    fn test_line_number_table() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let mut attribute = code_attribute(&mut constant_pool)?;
+        LineNumberTableBuilder::new()
+            .line(2, 11)
+            .line(0, 10)
+            .add_to(&mut constant_pool, &mut attribute)?;
+
+        let attributes = code_attributes(&attribute);
+        assert_eq!(1, attributes.len());
+        let Attribute::LineNumberTable { line_numbers, .. } = &attributes[0] else {
+            panic!("expected LineNumberTable attribute");
+        };
+        let expected = vec![
+            LineNumber {
+                start_pc: 0,
+                line_number: 10,
+            },
+            LineNumber {
+                start_pc: 2,
+                line_number: 11,
+            },
+        ];
+        assert_eq!(&expected, line_numbers);
+        Ok(())
+    }
+
+    #[test]
+    fn test_line_number_table_replaces_existing() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let mut attribute = code_attribute(&mut constant_pool)?;
+        LineNumberTableBuilder::new()
+            .line(0, 1)
+            .add_to(&mut constant_pool, &mut attribute)?;
+        LineNumberTableBuilder::new()
+            .line(0, 2)
+            .add_to(&mut constant_pool, &mut attribute)?;
+
+        let attributes = code_attributes(&attribute);
+        assert_eq!(1, attributes.len());
+        let Attribute::LineNumberTable { line_numbers, .. } = &attributes[0] else {
+            panic!("expected LineNumberTable attribute");
+        };
+        assert_eq!(2, line_numbers[0].line_number);
+        Ok(())
+    }
+
+    #[test]
+    fn test_line_number_table_invalid_index() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let mut attribute = code_attribute(&mut constant_pool)?;
+        let result = LineNumberTableBuilder::new()
+            .line(4, 10)
+            .add_to(&mut constant_pool, &mut attribute);
+        assert!(matches!(result, Err(InvalidInstructionOffset(4))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_not_code_attribute() {
+        let mut constant_pool = ConstantPool::default();
+        let mut attribute = Attribute::Synthetic { name_index: 1 };
+        let result = LineNumberTableBuilder::new()
+            .line(0, 1)
+            .add_to(&mut constant_pool, &mut attribute);
+        assert!(matches!(result, Err(InvalidCodeAttribute(_))));
+        let result = LocalVariableTableBuilder::new().add_to(&mut constant_pool, &mut attribute);
+        assert!(matches!(result, Err(InvalidCodeAttribute(_))));
+    }
+
+    #[test]
+    fn test_local_variable_table() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let mut attribute = code_attribute(&mut constant_pool)?;
+        LocalVariableTableBuilder::new()
+            .variable(2..4, 1, "value", "I")
+            .add_to(&mut constant_pool, &mut attribute)?;
+
+        let attributes = code_attributes(&attribute);
+        assert_eq!(1, attributes.len());
+        let Attribute::LocalVariableTable { variables, .. } = &attributes[0] else {
+            panic!("expected LocalVariableTable attribute");
+        };
+        let variable = &variables[0];
+        // iconst_0 (1 byte) and istore 1 (2 bytes) precede iload_1
+        assert_eq!(3, variable.start_pc);
+        assert_eq!(2, variable.length);
+        assert_eq!(1, variable.index);
+        assert_eq!("value", constant_pool.try_get_utf8(variable.name_index)?);
+        assert_eq!("I", constant_pool.try_get_utf8(variable.descriptor_index)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_local_variable_type_table() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let mut attribute = code_attribute(&mut constant_pool)?;
+        LocalVariableTableBuilder::new()
+            .variable(0..4, 0, "this", "Ljava/util/List;")
+            .variable_type(0..4, 0, "this", "Ljava/util/List<TT;>;")
+            .add_to(&mut constant_pool, &mut attribute)?;
+
+        let attributes = code_attributes(&attribute);
+        assert_eq!(2, attributes.len());
+        let Attribute::LocalVariableTypeTable { variable_types, .. } = &attributes[1] else {
+            panic!("expected LocalVariableTypeTable attribute");
+        };
+        let variable_type = &variable_types[0];
+        assert_eq!(0, variable_type.start_pc);
+        assert_eq!(5, variable_type.length);
+        assert_eq!(
+            "Ljava/util/List<TT;>;",
+            constant_pool.try_get_utf8(variable_type.signature_index)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_local_variable_table_invalid_range() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let mut attribute = code_attribute(&mut constant_pool)?;
+        let result = LocalVariableTableBuilder::new()
+            .variable(0..5, 0, "value", "I")
+            .add_to(&mut constant_pool, &mut attribute);
+        assert!(matches!(result, Err(InvalidInstructionOffset(5))));
+        let result = LocalVariableTableBuilder::new()
+            .variable(2..2, 0, "value", "I")
+            .add_to(&mut constant_pool, &mut attribute);
+        assert!(matches!(result, Err(InvalidInstructionOffset(2))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_local_variable_table_serializes() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let mut attribute = code_attribute(&mut constant_pool)?;
+        LineNumberTableBuilder::new()
+            .line(0, 1)
+            .line(2, 2)
+            .add_to(&mut constant_pool, &mut attribute)?;
+        LocalVariableTableBuilder::new()
+            .variable(2..4, 1, "value", "I")
+            .add_to(&mut constant_pool, &mut attribute)?;
+
+        let mut bytes = Vec::new();
+        attribute.to_bytes(&mut bytes)?;
+        let mut cursor = std::io::Cursor::new(bytes);
+        assert_eq!(
+            attribute,
+            Attribute::from_bytes(&constant_pool, &mut cursor)?
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,908 @@
+use crate::attributes::{Attribute, BootstrapMethod, CodeEditor, Instruction};
+use crate::base_type::BaseType;
+use crate::class_access_flags::ClassAccessFlags;
+use crate::class_file::ClassFile;
+use crate::constant::Constant;
+use crate::constant_pool::ConstantPool;
+use crate::error::Result;
+use crate::field::Field;
+use crate::field_access_flags::FieldAccessFlags;
+use crate::field_type::FieldType;
+use crate::method::Method;
+use crate::method_access_flags::MethodAccessFlags;
+use crate::version::Version;
+use std::collections::{HashMap, HashSet};
+
+const VERSION_51_0: Version = Version::Java7 { minor: 0 };
+const VERSION_52_0: Version = Version::Java8 { minor: 0 };
+const VERSION_53_0: Version = Version::Java9 { minor: 0 };
+const VERSION_55_0: Version = Version::Java11 { minor: 0 };
+const VERSION_60_0: Version = Version::Java16 { minor: 0 };
+
+/// Classes that provide bootstrap methods and the first version of Java that includes them.
+const BOOTSTRAP_CLASSES: [(&str, Version); 5] = [
+    ("java/lang/invoke/LambdaMetafactory", VERSION_52_0),
+    ("java/lang/invoke/StringConcatFactory", VERSION_53_0),
+    ("java/lang/invoke/ConstantBootstraps", VERSION_55_0),
+    ("java/lang/runtime/ObjectMethods", VERSION_60_0),
+    (
+        "java/lang/runtime/SwitchBootstraps",
+        Version::Java21 { minor: 0 },
+    ),
+];
+
+/// Maximum operand stack used by the code that initializes a dynamic constant: the bootstrap
+/// method handle, the argument array twice, the array index and a `long` or `double` argument.
+const INITIALIZER_MAX_STACK: u16 = 6;
+
+/// Construct that could not be downgraded by [`downgrade`](ClassFile::downgrade).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DowngradeIssue {
+    /// Class or method containing the construct, e.g. `Foo` or `Foo.bar(I)V`
+    pub location: String,
+    /// Description of the construct
+    pub description: String,
+}
+
+/// Downgrade the class to an older version; see [`ClassFile::downgrade`].
+pub(crate) fn downgrade(
+    class_file: &mut ClassFile,
+    version: Version,
+) -> Result<Vec<DowngradeIssue>> {
+    let mut issues = Vec::new();
+    if version >= class_file.version {
+        return Ok(issues);
+    }
+
+    check_class(class_file, &version, &mut issues)?;
+    if version < VERSION_55_0 {
+        invoke_private_methods(class_file)?;
+        convert_dynamic_constants(class_file, &version, &mut issues)?;
+    }
+    check_code(class_file, &version, &mut issues)?;
+    remove_attributes(class_file, &version);
+    class_file.version = version;
+    Ok(issues)
+}
+
+/// Report class declarations that are not supported by the version.
+fn check_class(
+    class_file: &ClassFile,
+    version: &Version,
+    issues: &mut Vec<DowngradeIssue>,
+) -> Result<()> {
+    let constant_pool = &class_file.constant_pool;
+    let class_name = class_file.class_name()?;
+    if class_file.access_flags.contains(ClassAccessFlags::MODULE) && *version < VERSION_53_0 {
+        issues.push(issue(class_name, "module declarations require Java 9"));
+    }
+    if class_file.super_class != 0
+        && constant_pool.try_get_class(class_file.super_class)? == "java/lang/Record"
+        && *version < VERSION_60_0
+    {
+        issues.push(issue(
+            class_name,
+            "records extend java/lang/Record, which requires Java 16",
+        ));
+    }
+
+    if !class_file
+        .access_flags
+        .contains(ClassAccessFlags::INTERFACE)
+    {
+        return Ok(());
+    }
+    for method in &class_file.methods {
+        let name = constant_pool.try_get_utf8(method.name_index)?;
+        if method.access_flags.contains(MethodAccessFlags::ABSTRACT) || name == "<clinit>" {
+            continue;
+        }
+        if *version < VERSION_52_0 {
+            issues.push(issue(
+                method_label(class_file, method)?,
+                "default and static interface methods require Java 8",
+            ));
+        } else if method.access_flags.contains(MethodAccessFlags::PRIVATE)
+            && *version < VERSION_53_0
+        {
+            issues.push(issue(
+                method_label(class_file, method)?,
+                "private interface methods require Java 9",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Invoke the private methods of the class with `invokespecial`; `invokevirtual` and
+/// `invokeinterface` may only invoke private methods when nestmates are supported (Java 11).
+fn invoke_private_methods(class_file: &mut ClassFile) -> Result<()> {
+    let constant_pool = &class_file.constant_pool;
+    let class_name = class_file.class_name()?;
+    let mut private_methods = HashSet::new();
+    for method in &class_file.methods {
+        if method.access_flags.contains(MethodAccessFlags::PRIVATE)
+            && !method.access_flags.contains(MethodAccessFlags::STATIC)
+        {
+            let name = constant_pool.try_get_utf8(method.name_index)?;
+            let descriptor = constant_pool.try_get_utf8(method.descriptor_index)?;
+            private_methods.insert((name, descriptor));
+        }
+    }
+
+    let mut replacements = Vec::new();
+    for (method_index, method) in class_file.methods.iter().enumerate() {
+        let Some(Attribute::Code { code, .. }) = code_attribute(method) else {
+            continue;
+        };
+        for (index, instruction) in code.iter().enumerate() {
+            let (method_ref, (class_index, name_and_type_index)) = match instruction {
+                Instruction::Invokevirtual(method_ref) => {
+                    (*method_ref, constant_pool.try_get_method_ref(*method_ref)?)
+                }
+                Instruction::Invokeinterface(method_ref, _) => (
+                    *method_ref,
+                    constant_pool.try_get_interface_method_ref(*method_ref)?,
+                ),
+                _ => continue,
+            };
+            if constant_pool.try_get_class(*class_index)? != class_name {
+                continue;
+            }
+            let (name_index, descriptor_index) =
+                constant_pool.try_get_name_and_type(*name_and_type_index)?;
+            let name = constant_pool.try_get_utf8(*name_index)?;
+            let descriptor = constant_pool.try_get_utf8(*descriptor_index)?;
+            if private_methods.contains(&(name, descriptor)) {
+                replacements.push((method_index, index, method_ref));
+            }
+        }
+    }
+
+    for (method_index, index, method_ref) in replacements {
+        let Some(attribute) = code_attribute_mut(&mut class_file.methods[method_index]) else {
+            continue;
+        };
+        let mut editor = CodeEditor::new(attribute)?;
+        editor.replace(index..=index, vec![Instruction::Invokespecial(method_ref)])?;
+    }
+    Ok(())
+}
+
+/// Dynamic constant that is converted to a static field.
+struct DynamicConstant {
+    index: u16,
+    name: String,
+    field_type: FieldType,
+    bootstrap_method: BootstrapMethod,
+}
+
+/// Convert the dynamic constants of the class to synthetic static fields that are initialized by
+/// invoking the bootstrap method from the static initializer, and load the fields instead of the
+/// constants.  Dynamic constants require Java 11, while the initializer requires Java 7.
+fn convert_dynamic_constants(
+    class_file: &mut ClassFile,
+    version: &Version,
+    issues: &mut Vec<DowngradeIssue>,
+) -> Result<()> {
+    let dynamic_constants = dynamic_constants(class_file, version, issues)?;
+    if dynamic_constants.is_empty() {
+        return Ok(());
+    }
+
+    let mut initializer = Vec::new();
+    let mut field_refs = HashMap::new();
+    for (number, dynamic_constant) in dynamic_constants.iter().enumerate() {
+        let field_name = format!("$condy${number}");
+        let descriptor = dynamic_constant.field_type.descriptor();
+        let constant_pool = &mut class_file.constant_pool;
+        class_file.fields.push(Field {
+            access_flags: FieldAccessFlags::PRIVATE
+                | FieldAccessFlags::STATIC
+                | FieldAccessFlags::FINAL
+                | FieldAccessFlags::SYNTHETIC,
+            name_index: constant_pool.add_utf8(&field_name)?,
+            descriptor_index: constant_pool.add_utf8(&descriptor)?,
+            field_type: dynamic_constant.field_type.clone(),
+            attributes: Vec::new(),
+        });
+        let field_ref =
+            constant_pool.add_field_ref(class_file.this_class, &field_name, &descriptor)?;
+        initializer.extend(initialize(constant_pool, dynamic_constant, field_ref)?);
+        field_refs.insert(dynamic_constant.index, field_ref);
+    }
+
+    for method in &mut class_file.methods {
+        load_fields(method, &field_refs)?;
+    }
+    add_initializer(class_file, initializer)?;
+
+    // The constants are no longer referenced, but must be replaced as dynamic constants are not
+    // valid in older class files
+    for dynamic_constant in dynamic_constants {
+        if let Some(constant) = class_file.constant_pool.get_mut(dynamic_constant.index) {
+            *constant = Constant::Utf8(dynamic_constant.name);
+        }
+    }
+    Ok(())
+}
+
+/// Get the dynamic constants of the class that can be converted to static fields, reporting the
+/// dynamic constants that cannot be converted.
+fn dynamic_constants(
+    class_file: &ClassFile,
+    version: &Version,
+    issues: &mut Vec<DowngradeIssue>,
+) -> Result<Vec<DynamicConstant>> {
+    let constant_pool = &class_file.constant_pool;
+    let class_name = class_file.class_name()?;
+    let bootstrap_methods = bootstrap_methods(class_file);
+    let bootstrap_arguments = bootstrap_methods
+        .iter()
+        .flat_map(|bootstrap_method| bootstrap_method.arguments.iter().copied())
+        .collect::<HashSet<_>>();
+
+    let mut dynamic_constants = Vec::new();
+    for index in 1..=u16::try_from(constant_pool.len())? {
+        let Some(Constant::Dynamic {
+            bootstrap_method_attr_index,
+            name_and_type_index,
+        }) = constant_pool.get(index)
+        else {
+            continue;
+        };
+        let (name_index, descriptor_index) =
+            constant_pool.try_get_name_and_type(*name_and_type_index)?;
+        let name = constant_pool.try_get_utf8(*name_index)?;
+        let descriptor = constant_pool.try_get_utf8(*descriptor_index)?;
+        let Some(bootstrap_method) =
+            bootstrap_methods.get(usize::from(*bootstrap_method_attr_index))
+        else {
+            issues.push(issue(
+                class_name,
+                &format!("dynamic constant {name} has an invalid bootstrap method index"),
+            ));
+            continue;
+        };
+
+        let reason =
+            if *version < VERSION_51_0 {
+                Some("requires method handles (Java 7) to be initialized")
+            } else if bootstrap_arguments.contains(&index) {
+                Some("is a bootstrap method argument")
+            } else if bootstrap_method.arguments.iter().any(|argument| {
+                matches!(constant_pool.get(*argument), Some(Constant::Dynamic { .. }))
+            }) {
+                Some("has a dynamic constant bootstrap method argument")
+            } else {
+                None
+            };
+        if let Some(reason) = reason {
+            issues.push(issue(
+                class_name,
+                &format!("dynamic constant {name} {reason}"),
+            ));
+            continue;
+        }
+
+        dynamic_constants.push(DynamicConstant {
+            index,
+            name: name.clone(),
+            field_type: FieldType::parse(descriptor)?,
+            bootstrap_method: bootstrap_method.clone(),
+        });
+    }
+    Ok(dynamic_constants)
+}
+
+/// Get the instructions that resolve a dynamic constant by invoking the bootstrap method with the
+/// lookup of the class, the name and type of the constant and the static arguments, and store the
+/// constant in a static field.
+fn initialize(
+    constant_pool: &mut ConstantPool,
+    dynamic_constant: &DynamicConstant,
+    field_ref: u16,
+) -> Result<Vec<Instruction>> {
+    let bootstrap_method = &dynamic_constant.bootstrap_method;
+    let object_class = constant_pool.add_class("java/lang/Object")?;
+    let lookup_class = constant_pool.add_class("java/lang/invoke/MethodHandles")?;
+    let lookup = constant_pool.add_method_ref(
+        lookup_class,
+        "lookup",
+        "()Ljava/lang/invoke/MethodHandles$Lookup;",
+    )?;
+    let method_handle_class = constant_pool.add_class("java/lang/invoke/MethodHandle")?;
+    let invoke_with_arguments = constant_pool.add_method_ref(
+        method_handle_class,
+        "invokeWithArguments",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )?;
+    let name = constant_pool.add_string(&dynamic_constant.name)?;
+
+    let mut arguments = vec![
+        vec![Instruction::Invokestatic(lookup)],
+        vec![Instruction::Ldc_w(name)],
+        load_class(constant_pool, &dynamic_constant.field_type)?,
+    ];
+    for argument in &bootstrap_method.arguments {
+        arguments.push(load_argument(constant_pool, *argument)?);
+    }
+
+    let mut code = vec![
+        Instruction::Ldc_w(bootstrap_method.bootstrap_method_ref),
+        int_constant(arguments.len())?,
+        Instruction::Anewarray(object_class),
+    ];
+    for (index, argument) in arguments.into_iter().enumerate() {
+        code.push(Instruction::Dup);
+        code.push(int_constant(index)?);
+        code.extend(argument);
+        code.push(Instruction::Aastore);
+    }
+    code.push(Instruction::Invokevirtual(invoke_with_arguments));
+    code.extend(convert_result(constant_pool, &dynamic_constant.field_type)?);
+    code.push(Instruction::Putstatic(field_ref));
+    Ok(code)
+}
+
+/// Get the instructions that push the `Class` of a field type.
+fn load_class(
+    constant_pool: &mut ConstantPool,
+    field_type: &FieldType,
+) -> Result<Vec<Instruction>> {
+    let instruction = match field_type {
+        FieldType::Base(base_type) => {
+            let wrapper_class = constant_pool.add_class(wrapper_class(base_type))?;
+            let field_ref =
+                constant_pool.add_field_ref(wrapper_class, "TYPE", "Ljava/lang/Class;")?;
+            Instruction::Getstatic(field_ref)
+        }
+        FieldType::Object(_) | FieldType::Array(_) => {
+            Instruction::Ldc_w(constant_pool.add_class(field_type.class_name())?)
+        }
+    };
+    Ok(vec![instruction])
+}
+
+/// Get the instructions that push a static argument of a bootstrap method as an object.
+fn load_argument(constant_pool: &mut ConstantPool, argument: u16) -> Result<Vec<Instruction>> {
+    let (load, base_type) = match constant_pool.try_get(argument)? {
+        Constant::Integer(_) => (Instruction::Ldc_w(argument), BaseType::Int),
+        Constant::Float(_) => (Instruction::Ldc_w(argument), BaseType::Float),
+        Constant::Long(_) => (Instruction::Ldc2_w(argument), BaseType::Long),
+        Constant::Double(_) => (Instruction::Ldc2_w(argument), BaseType::Double),
+        _ => return Ok(vec![Instruction::Ldc_w(argument)]),
+    };
+    let wrapper_class = wrapper_class(&base_type);
+    let class_index = constant_pool.add_class(wrapper_class)?;
+    let descriptor = format!("({})L{wrapper_class};", base_type.code());
+    let value_of = constant_pool.add_method_ref(class_index, "valueOf", &descriptor)?;
+    Ok(vec![load, Instruction::Invokestatic(value_of)])
+}
+
+/// Get the instructions that convert the object returned by the bootstrap method to the field
+/// type.
+fn convert_result(
+    constant_pool: &mut ConstantPool,
+    field_type: &FieldType,
+) -> Result<Vec<Instruction>> {
+    let FieldType::Base(base_type) = field_type else {
+        let class_index = constant_pool.add_class(field_type.class_name())?;
+        return Ok(vec![Instruction::Checkcast(class_index)]);
+    };
+    let class_index = constant_pool.add_class(wrapper_class(base_type))?;
+    let method_name = format!("{}Value", base_type.class_name());
+    let descriptor = format!("(){}", base_type.code());
+    let method_ref = constant_pool.add_method_ref(class_index, &method_name, &descriptor)?;
+    Ok(vec![
+        Instruction::Checkcast(class_index),
+        Instruction::Invokevirtual(method_ref),
+    ])
+}
+
+/// Get the class that wraps values of a base type.
+fn wrapper_class(base_type: &BaseType) -> &'static str {
+    match base_type {
+        BaseType::Boolean => "java/lang/Boolean",
+        BaseType::Byte => "java/lang/Byte",
+        BaseType::Char => "java/lang/Character",
+        BaseType::Double => "java/lang/Double",
+        BaseType::Float => "java/lang/Float",
+        BaseType::Int => "java/lang/Integer",
+        BaseType::Long => "java/lang/Long",
+        BaseType::Short => "java/lang/Short",
+    }
+}
+
+/// Get the instruction that pushes an `int` constant.
+fn int_constant(value: usize) -> Result<Instruction> {
+    let instruction = match value {
+        0 => Instruction::Iconst_0,
+        1 => Instruction::Iconst_1,
+        2 => Instruction::Iconst_2,
+        3 => Instruction::Iconst_3,
+        4 => Instruction::Iconst_4,
+        5 => Instruction::Iconst_5,
+        _ => Instruction::Sipush(i16::try_from(value)?),
+    };
+    Ok(instruction)
+}
+
+/// Replace the `ldc`, `ldc_w` and `ldc2_w` instructions that load converted dynamic constants
+/// with `getstatic` instructions that load the fields.
+fn load_fields(method: &mut Method, field_refs: &HashMap<u16, u16>) -> Result<()> {
+    let Some(attribute) = code_attribute_mut(method) else {
+        return Ok(());
+    };
+    let mut editor = CodeEditor::new(attribute)?;
+    let mut replacements = Vec::new();
+    for (index, instruction) in editor.code().iter().enumerate() {
+        let constant_index = match instruction {
+            Instruction::Ldc(constant_index) => u16::from(*constant_index),
+            Instruction::Ldc_w(constant_index) | Instruction::Ldc2_w(constant_index) => {
+                *constant_index
+            }
+            _ => continue,
+        };
+        if let Some(field_ref) = field_refs.get(&constant_index) {
+            replacements.push((index, *field_ref));
+        }
+    }
+    for (index, field_ref) in replacements {
+        editor.replace(index..=index, vec![Instruction::Getstatic(field_ref)])?;
+    }
+    Ok(())
+}
+
+/// Insert the initializer at the start of the static initializer, adding a static initializer if
+/// the class does not have one.
+fn add_initializer(class_file: &mut ClassFile, initializer: Vec<Instruction>) -> Result<()> {
+    let constant_pool = &class_file.constant_pool;
+    let mut static_initializer = None;
+    for (index, method) in class_file.methods.iter().enumerate() {
+        if constant_pool.try_get_utf8(method.name_index)? == "<clinit>" {
+            static_initializer = Some(index);
+        }
+    }
+
+    let Some(index) = static_initializer else {
+        let constant_pool = &mut class_file.constant_pool;
+        let mut code = initializer;
+        code.push(Instruction::Return);
+        class_file.methods.push(Method {
+            access_flags: MethodAccessFlags::STATIC,
+            name_index: constant_pool.add_utf8("<clinit>")?,
+            descriptor_index: constant_pool.add_utf8("()V")?,
+            attributes: vec![Attribute::Code {
+                name_index: constant_pool.add_utf8("Code")?,
+                max_stack: INITIALIZER_MAX_STACK,
+                max_locals: 0,
+                code,
+                exception_table: Vec::new(),
+                attributes: Vec::new(),
+            }],
+        });
+        return Ok(());
+    };
+
+    let Some(attribute) = code_attribute_mut(&mut class_file.methods[index]) else {
+        return Ok(());
+    };
+    CodeEditor::new(attribute)?.insert(0, initializer)?;
+    if let Attribute::Code { max_stack, .. } = attribute {
+        *max_stack = (*max_stack).max(INITIALIZER_MAX_STACK);
+    }
+    Ok(())
+}
+
+/// Report instructions that are not supported by the version.
+fn check_code(
+    class_file: &ClassFile,
+    version: &Version,
+    issues: &mut Vec<DowngradeIssue>,
+) -> Result<()> {
+    let constant_pool = &class_file.constant_pool;
+    let bootstrap_methods = bootstrap_methods(class_file);
+    for method in &class_file.methods {
+        let Some(Attribute::Code { code, .. }) = code_attribute(method) else {
+            continue;
+        };
+        for instruction in code {
+            let description = match instruction {
+                Instruction::Invokedynamic(_) if *version < VERSION_51_0 => {
+                    "invokedynamic requires Java 7".to_string()
+                }
+                Instruction::Invokedynamic(index) => {
+                    let Some(Constant::InvokeDynamic {
+                        bootstrap_method_attr_index,
+                        ..
+                    }) = constant_pool.get(*index)
+                    else {
+                        continue;
+                    };
+                    let Some(bootstrap_method) =
+                        bootstrap_methods.get(usize::from(*bootstrap_method_attr_index))
+                    else {
+                        continue;
+                    };
+                    let class_name = bootstrap_class(constant_pool, bootstrap_method)?;
+                    let Some((_, required)) = BOOTSTRAP_CLASSES
+                        .iter()
+                        .find(|(name, required)| *name == class_name && version < required)
+                    else {
+                        continue;
+                    };
+                    format!(
+                        "invokedynamic bootstrapped by {class_name} requires Java {}",
+                        required.java()
+                    )
+                }
+                Instruction::Ldc(index) if *version < VERSION_51_0 => {
+                    match constant_pool.get(u16::from(*index)) {
+                        Some(Constant::MethodHandle { .. } | Constant::MethodType(_)) => {
+                            "method handle and method type constants require Java 7".to_string()
+                        }
+                        _ => continue,
+                    }
+                }
+                Instruction::Ldc_w(index) if *version < VERSION_51_0 => {
+                    match constant_pool.get(*index) {
+                        Some(Constant::MethodHandle { .. } | Constant::MethodType(_)) => {
+                            "method handle and method type constants require Java 7".to_string()
+                        }
+                        _ => continue,
+                    }
+                }
+                _ => continue,
+            };
+            issues.push(issue(method_label(class_file, method)?, &description));
+        }
+    }
+    Ok(())
+}
+
+/// Get the name of the class that declares a bootstrap method.
+fn bootstrap_class<'a>(
+    constant_pool: &'a ConstantPool,
+    bootstrap_method: &BootstrapMethod,
+) -> Result<&'a String> {
+    let (_, reference_index) =
+        constant_pool.try_get_method_handle(bootstrap_method.bootstrap_method_ref)?;
+    let class_index = match constant_pool.try_get(*reference_index)? {
+        Constant::InterfaceMethodRef { class_index, .. } => class_index,
+        _ => constant_pool.try_get_method_ref(*reference_index)?.0,
+    };
+    constant_pool.try_get_class(*class_index)
+}
+
+/// Remove the attributes of the class, fields, methods and code that are not supported by the
+/// version; e.g. `NestHost`, `NestMembers`, `Record` and `PermittedSubclasses`.
+fn remove_attributes(class_file: &mut ClassFile, version: &Version) {
+    let valid = |attribute: &Attribute| attribute.valid_for_version(version);
+    class_file.attributes.retain(valid);
+    for field in &mut class_file.fields {
+        field.attributes.retain(valid);
+    }
+    for method in &mut class_file.methods {
+        method.attributes.retain(valid);
+        if let Some(Attribute::Code { attributes, .. }) = code_attribute_mut(method) {
+            attributes.retain(valid);
+        }
+    }
+}
+
+/// Get the bootstrap methods of the class.
+fn bootstrap_methods(class_file: &ClassFile) -> Vec<BootstrapMethod> {
+    class_file
+        .attributes
+        .iter()
+        .find_map(|attribute| match attribute {
+            Attribute::BootstrapMethods { methods, .. } => Some(methods.clone()),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+/// Get the `Code` attribute of a method.
+fn code_attribute(method: &Method) -> Option<&Attribute> {
+    method
+        .attributes
+        .iter()
+        .find(|attribute| matches!(attribute, Attribute::Code { .. }))
+}
+
+/// Get the mutable `Code` attribute of a method.
+fn code_attribute_mut(method: &mut Method) -> Option<&mut Attribute> {
+    method
+        .attributes
+        .iter_mut()
+        .find(|attribute| matches!(attribute, Attribute::Code { .. }))
+}
+
+/// Get the label of a method, e.g. `Foo.bar(I)V`.
+fn method_label(class_file: &ClassFile, method: &Method) -> Result<String> {
+    let constant_pool = &class_file.constant_pool;
+    let class_name = class_file.class_name()?;
+    let name = constant_pool.try_get_utf8(method.name_index)?;
+    let descriptor = constant_pool.try_get_utf8(method.descriptor_index)?;
+    Ok(format!("{class_name}.{name}{descriptor}"))
+}
+
+/// Create an issue.
+fn issue<S: AsRef<str>>(location: S, description: &str) -> DowngradeIssue {
+    DowngradeIssue {
+        location: location.as_ref().to_string(),
+        description: description.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::record_builder::RecordBuilder;
+    use crate::reference_kind::ReferenceKind;
+
+    fn add_method(
+        class_file: &mut ClassFile,
+        access_flags: MethodAccessFlags,
+        name: &str,
+        descriptor: &str,
+        code: Vec<Instruction>,
+    ) -> Result<()> {
+        let constant_pool = &mut class_file.constant_pool;
+        let method = Method {
+            access_flags,
+            name_index: constant_pool.add_utf8(name)?,
+            descriptor_index: constant_pool.add_utf8(descriptor)?,
+            attributes: vec![Attribute::Code {
+                name_index: constant_pool.add_utf8("Code")?,
+                max_stack: 2,
+                max_locals: 1,
+                code,
+                exception_table: Vec::new(),
+                attributes: Vec::new(),
+            }],
+        };
+        class_file.methods.push(method);
+        Ok(())
+    }
+
+    fn method_code<'a>(class_file: &'a ClassFile, name: &str) -> Result<&'a Vec<Instruction>> {
+        for method in &class_file.methods {
+            if class_file.constant_pool.try_get_utf8(method.name_index)? == name {
+                if let Some(Attribute::Code { code, .. }) = code_attribute(method) {
+                    return Ok(code);
+                }
+            }
+        }
+        panic!("expected method {name}");
+    }
+
+    fn condy_class_file() -> Result<(ClassFile, u16)> {
+        let mut class_file = crate::test::class_file("Foo", Version::Java11 { minor: 0 })?;
+        let constant_pool = &mut class_file.constant_pool;
+        let bootstraps = constant_pool.add_class("java/lang/invoke/ConstantBootstraps")?;
+        let invoke = constant_pool.add_method_ref(
+            bootstraps,
+            "invoke",
+            "(Ljava/lang/invoke/MethodHandles$Lookup;Ljava/lang/String;Ljava/lang/Class;Ljava/lang/invoke/MethodHandle;[Ljava/lang/Object;)Ljava/lang/Object;",
+        )?;
+        let bootstrap_method_ref =
+            constant_pool.add_method_handle(ReferenceKind::InvokeStatic, invoke)?;
+        let integer = constant_pool.add_class("java/lang/Integer")?;
+        let value_of =
+            constant_pool.add_method_ref(integer, "valueOf", "(I)Ljava/lang/Integer;")?;
+        let value_of_handle =
+            constant_pool.add_method_handle(ReferenceKind::InvokeStatic, value_of)?;
+        let argument = constant_pool.add_integer(42)?;
+        let dynamic = class_file.add_dynamic(
+            BootstrapMethod {
+                bootstrap_method_ref,
+                arguments: vec![value_of_handle, argument],
+            },
+            "answer",
+            "Ljava/lang/Integer;",
+        )?;
+        add_method(
+            &mut class_file,
+            MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+            "answer",
+            "()Ljava/lang/Object;",
+            vec![Instruction::Ldc_w(dynamic), Instruction::Areturn],
+        )?;
+        Ok((class_file, dynamic))
+    }
+
+    #[test]
+    fn test_downgrade_newer_version() -> Result<()> {
+        let mut class_file = crate::test::class_file("Foo", Version::Java8 { minor: 0 })?;
+        let expected = class_file.clone();
+        assert!(class_file
+            .downgrade(Version::Java11 { minor: 0 })?
+            .is_empty());
+        assert!(class_file
+            .downgrade(Version::Java8 { minor: 0 })?
+            .is_empty());
+        assert_eq!(expected, class_file);
+        Ok(())
+    }
+
+    #[test]
+    fn test_downgrade_nest_and_sealed_attributes() -> Result<()> {
+        let mut class_file = crate::test::class_file("Foo", Version::Java17 { minor: 0 })?;
+        let member = class_file.constant_pool.add_class("Foo$Bar")?;
+        let nest_members = class_file.constant_pool.add_utf8("NestMembers")?;
+        let permitted_subclasses = class_file.constant_pool.add_utf8("PermittedSubclasses")?;
+        let source_file = class_file.constant_pool.add_utf8("SourceFile")?;
+        let source_file_index = class_file.constant_pool.add_utf8("Foo.java")?;
+        class_file.attributes = vec![
+            Attribute::NestMembers {
+                name_index: nest_members,
+                class_indexes: vec![member],
+            },
+            Attribute::PermittedSubclasses {
+                name_index: permitted_subclasses,
+                class_indexes: vec![member],
+            },
+            Attribute::SourceFile {
+                name_index: source_file,
+                source_file_index,
+            },
+        ];
+
+        let issues = class_file.downgrade(Version::Java8 { minor: 0 })?;
+        assert!(issues.is_empty());
+        assert_eq!(Version::Java8 { minor: 0 }, class_file.version);
+        assert_eq!(
+            vec![Attribute::SourceFile {
+                name_index: source_file,
+                source_file_index,
+            }],
+            class_file.attributes
+        );
+        class_file.verify()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_downgrade_record() -> Result<()> {
+        let mut class_file = RecordBuilder::new("com.example.Point")
+            .component("x", FieldType::Base(BaseType::Int))
+            .build()?;
+        let issues = class_file.downgrade(Version::Java8 { minor: 0 })?;
+
+        assert!(issues
+            .iter()
+            .any(|issue| issue.location == "com/example/Point"
+                && issue.description.contains("java/lang/Record")));
+        assert!(issues.iter().any(|issue| issue
+            .description
+            .contains("java/lang/runtime/ObjectMethods")));
+        assert!(!class_file
+            .attributes
+            .iter()
+            .any(|attribute| matches!(attribute, Attribute::Record { .. })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_downgrade_private_method_invocation() -> Result<()> {
+        let mut class_file = crate::test::class_file("Foo", Version::Java11 { minor: 0 })?;
+        let this_class = class_file.this_class;
+        let secret = class_file
+            .constant_pool
+            .add_method_ref(this_class, "secret", "()V")?;
+        add_method(
+            &mut class_file,
+            MethodAccessFlags::PRIVATE,
+            "secret",
+            "()V",
+            vec![Instruction::Return],
+        )?;
+        add_method(
+            &mut class_file,
+            MethodAccessFlags::PUBLIC,
+            "run",
+            "()V",
+            vec![
+                Instruction::Aload_0,
+                Instruction::Invokevirtual(secret),
+                Instruction::Return,
+            ],
+        )?;
+
+        assert!(class_file
+            .downgrade(Version::Java8 { minor: 0 })?
+            .is_empty());
+        assert_eq!(
+            &vec![
+                Instruction::Aload_0,
+                Instruction::Invokespecial(secret),
+                Instruction::Return,
+            ],
+            method_code(&class_file, "run")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_downgrade_dynamic_constant() -> Result<()> {
+        let (mut class_file, dynamic) = condy_class_file()?;
+        let issues = class_file.downgrade(Version::Java8 { minor: 0 })?;
+
+        assert!(issues.is_empty());
+        assert_eq!(
+            Some(&Constant::Utf8("answer".to_string())),
+            class_file.constant_pool.get(dynamic)
+        );
+        let field = &class_file.fields[0];
+        assert_eq!(
+            "$condy$0",
+            class_file.constant_pool.try_get_utf8(field.name_index)?
+        );
+        assert_eq!(
+            FieldAccessFlags::PRIVATE
+                | FieldAccessFlags::STATIC
+                | FieldAccessFlags::FINAL
+                | FieldAccessFlags::SYNTHETIC,
+            field.access_flags
+        );
+        assert!(matches!(
+            method_code(&class_file, "answer")?.as_slice(),
+            [Instruction::Getstatic(_), Instruction::Areturn]
+        ));
+        let initializer = method_code(&class_file, "<clinit>")?;
+        assert!(matches!(
+            initializer.first(),
+            Some(Instruction::Ldc_w(index))
+                if matches!(class_file.constant_pool.get(*index), Some(Constant::MethodHandle { .. }))
+        ));
+        assert!(matches!(
+            initializer.as_slice(),
+            [.., Instruction::Putstatic(_), Instruction::Return]
+        ));
+        class_file.verify()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_downgrade_java6_issues() -> Result<()> {
+        let (mut class_file, _) = condy_class_file()?;
+        let issues = class_file.downgrade(Version::Java6 { minor: 0 })?;
+
+        assert_eq!(
+            vec![DowngradeIssue {
+                location: "Foo".to_string(),
+                description:
+                    "dynamic constant answer requires method handles (Java 7) to be initialized"
+                        .to_string(),
+            }],
+            issues
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_downgrade_default_interface_method() -> Result<()> {
+        let mut class_file = crate::test::class_file("Foo", Version::Java8 { minor: 0 })?;
+        class_file.access_flags =
+            ClassAccessFlags::PUBLIC | ClassAccessFlags::INTERFACE | ClassAccessFlags::ABSTRACT;
+        add_method(
+            &mut class_file,
+            MethodAccessFlags::PUBLIC,
+            "run",
+            "()V",
+            vec![Instruction::Return],
+        )?;
+
+        let issues = class_file.downgrade(Version::Java7 { minor: 0 })?;
+        assert_eq!(
+            vec![DowngradeIssue {
+                location: "Foo.run()V".to_string(),
+                description: "default and static interface methods require Java 8".to_string(),
+            }],
+            issues
+        );
+        Ok(())
+    }
+}
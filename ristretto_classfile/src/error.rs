@@ -28,9 +28,15 @@ pub enum Error {
     /// Invalid bootstrap method index
     #[error("Invalid bootstrap method index {0}")]
     InvalidBootstrapMethodIndex(usize),
+    /// Invalid class
+    #[error("Invalid class: {0}")]
+    InvalidClass(String),
     /// Invalid class access flags
     #[error("Invalid class access flags: {0}")]
     InvalidClassAccessFlags(u16),
+    /// Attribute is not a `Code` attribute
+    #[error("Invalid code attribute: {0}")]
+    InvalidCodeAttribute(String),
     /// Invalid constant pool index
     #[error("Invalid constant pool index {0}")]
     InvalidConstantPoolIndex(u16),
@@ -40,6 +46,12 @@ pub enum Error {
     /// Invalid constant tag
     #[error("Invalid constant tag: {0}")]
     InvalidConstantTag(u8),
+    /// Custom attribute without a codec or with a value of a different type than its codec
+    #[error("Invalid custom attribute: {0}")]
+    InvalidCustomAttribute(String),
+    /// Invalid exception table declaration
+    #[error("Invalid exception table: {0}")]
+    InvalidExceptionTable(String),
     /// Invalid field access flags
     #[error("Invalid field access flags: {0}")]
     InvalidFieldAccessFlags(u16),
@@ -49,21 +61,45 @@ pub enum Error {
     /// Invalid field type descriptor
     #[error("Invalid field type descriptor {0}")]
     InvalidFieldTypeDescriptor(String),
+    /// Invalid `InnerClasses` or `EnclosingMethod` declaration
+    #[error("Invalid inner classes: {0}")]
+    InvalidInnerClasses(String),
     /// Invalid instruction
     #[error("Invalid instruction: {0}")]
     InvalidInstruction(u8),
     /// Invalid instruction offset
     #[error("Invalid instruction offset: {0}")]
     InvalidInstructionOffset(u32),
+    /// Invalid lambda declaration
+    #[error("Invalid lambda: {0}")]
+    InvalidLambda(String),
     /// Invalid magic number when reading a class file
     #[error("Invalid magic number: {0}")]
     InvalidMagicNumber(u32),
     /// Invalid method access flags
     #[error("Invalid method access flags: {0}")]
     InvalidMethodAccessFlags(u16),
+    /// Invalid method descriptor
+    #[error("Invalid method descriptor {0}")]
+    InvalidMethodDescriptor(String),
+    /// Invalid module descriptor
+    #[error("Invalid module descriptor: {0}")]
+    InvalidModuleDescriptor(String),
+    /// Invalid nest declaration
+    #[error("Invalid nest: {0}")]
+    InvalidNest(String),
+    /// Invalid record class declaration
+    #[error("Invalid record: {0}")]
+    InvalidRecord(String),
     /// Error when creating a reference kind
     #[error("Invalid reference kind: {0}")]
     InvalidReferenceKind(u8),
+    /// Invalid generic signature
+    #[error("Invalid signature {0}")]
+    InvalidSignature(String),
+    /// Invalid `SourceDebugExtension` source map
+    #[error("Invalid source map: {0}")]
+    InvalidSourceMap(String),
     /// Invalid stack frame type
     #[error("Invalid stack frame type: {0}")]
     InvalidStackFrameType(u8),
@@ -82,6 +118,15 @@ pub enum Error {
     /// Invalid wide instruction
     #[error("Invalid wide instruction: {0}")]
     InvalidWideInstruction(u8),
+    /// Member that already exists in a class
+    #[error("Member conflict: {0}")]
+    MemberConflict(String),
+    /// Member not found in a class
+    #[error("Member not found: {0}")]
+    MemberNotFound(String),
+    /// Constant pool references cannot be determined for an unknown attribute
+    #[error("Cannot remap constant pool references in unknown attribute {0}")]
+    UnknownAttribute(String),
     /// IO error
     #[error("IO error: {0}")]
     IoError(String),
@@ -0,0 +1,316 @@
+use crate::attributes::{Attribute, ExceptionTableEntry, Instruction};
+use crate::error::Error::{InvalidCodeAttribute, InvalidExceptionTable, InvalidInstructionOffset};
+use crate::error::Result;
+use std::collections::HashMap;
+
+/// Exception handler recorded by an [`ExceptionTableBuilder`].
+#[derive(Clone, Debug, PartialEq)]
+struct Handler {
+    start: String,
+    end: String,
+    target: String,
+    catch_type: u16,
+}
+
+/// Builder for the exception table of a `Code` attribute.
+///
+/// Exception handlers are declared with symbolic labels for the start (inclusive) and end
+/// (exclusive) of the protected range and the start of the handler, so that handlers can be
+/// declared before the code they refer to has been generated.  Labels are bound to instruction
+/// indexes as the code is generated and resolved when the exception table is built; the ranges are
+/// validated against the code.  Handlers are added in the order they are declared, which is the
+/// order in which the JVM searches for a matching handler.
+///
+/// # Examples
+///
+/// ```rust
+/// use ristretto_classfile::attributes::{Attribute, Instruction};
+/// use ristretto_classfile::{ConstantPool, ExceptionTableBuilder, Result};
+///
+/// fn main() -> Result<()> {
+///     let mut constant_pool = ConstantPool::default();
+///     let catch_type = constant_pool.add_class("java/lang/Exception")?;
+///     let builder = ExceptionTableBuilder::new().handler("try", "end_try", "catch", catch_type);
+///
+///     let mut code = Vec::new();
+///     let builder = builder.label("try", code.len());
+///     code.push(Instruction::Aconst_null);
+///     code.push(Instruction::Athrow);
+///     let builder = builder.label("end_try", code.len()).label("catch", code.len());
+///     code.push(Instruction::Pop);
+///     code.push(Instruction::Return);
+///
+///     let mut attribute = Attribute::Code {
+///         name_index: constant_pool.add_utf8("Code")?,
+///         max_stack: 1,
+///         max_locals: 0,
+///         code,
+///         exception_table: Vec::new(),
+///         attributes: Vec::new(),
+///     };
+///     builder.add_to(&mut attribute)?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExceptionTableBuilder {
+    labels: Vec<(String, usize)>,
+    handlers: Vec<Handler>,
+}
+
+impl ExceptionTableBuilder {
+    /// Create a new, empty builder.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bind the label to the instruction at `index`; an index equal to the number of instructions
+    /// refers to the end of the code.
+    #[must_use]
+    pub fn label<S: AsRef<str>>(mut self, label: S, index: usize) -> Self {
+        self.labels.push((label.as_ref().to_string(), index));
+        self
+    }
+
+    /// Declare a handler that starts at the `handler` label for exceptions thrown by the
+    /// instructions from the `start` label (inclusive) to the `end` label (exclusive).  The catch
+    /// type is the constant pool index of the class of exceptions that are handled, or 0 to handle
+    /// all exceptions; e.g. for a `finally` block.
+    #[must_use]
+    pub fn handler<S: AsRef<str>>(mut self, start: S, end: S, handler: S, catch_type: u16) -> Self {
+        self.handlers.push(Handler {
+            start: start.as_ref().to_string(),
+            end: end.as_ref().to_string(),
+            target: handler.as_ref().to_string(),
+            catch_type,
+        });
+        self
+    }
+
+    /// Resolve the labels of the handlers against the code and return the exception table entries.
+    ///
+    /// # Errors
+    /// - If a label is bound more than once or a handler refers to a label that is not bound.
+    /// - If the start of a protected range is not before the end of the range.
+    /// - If a range or handler is not within the code.
+    pub fn build(&self, code: &[Instruction]) -> Result<Vec<ExceptionTableEntry>> {
+        let mut labels = HashMap::with_capacity(self.labels.len());
+        for (label, index) in &self.labels {
+            if labels.insert(label.as_str(), *index).is_some() {
+                return Err(InvalidExceptionTable(format!(
+                    "label {label} is bound more than once"
+                )));
+            }
+        }
+        let resolve = |label: &String| {
+            labels
+                .get(label.as_str())
+                .copied()
+                .ok_or_else(|| InvalidExceptionTable(format!("label {label} is not bound")))
+        };
+
+        let mut exception_table = Vec::with_capacity(self.handlers.len());
+        for handler in &self.handlers {
+            let start = resolve(&handler.start)?;
+            let end = resolve(&handler.end)?;
+            let handler_index = resolve(&handler.target)?;
+            if start >= end {
+                return Err(InvalidExceptionTable(format!(
+                    "range {} ({start}) must start before {} ({end})",
+                    handler.start, handler.end
+                )));
+            }
+            if end > code.len() {
+                return Err(InvalidInstructionOffset(u32::try_from(end)?));
+            }
+            if handler_index >= code.len() {
+                return Err(InvalidInstructionOffset(u32::try_from(handler_index)?));
+            }
+            exception_table.push(ExceptionTableEntry {
+                range_pc: u16::try_from(start)?..u16::try_from(end)?,
+                handler_pc: u16::try_from(handler_index)?,
+                catch_type: handler.catch_type,
+            });
+        }
+        Ok(exception_table)
+    }
+
+    /// Add the handlers to the exception table of a `Code` attribute, after any existing entries.
+    ///
+    /// # Errors
+    /// - If the attribute is not a `Code` attribute.
+    /// - If the handlers cannot be resolved against the code; see [`ExceptionTableBuilder::build`].
+    pub fn add_to(&self, attribute: &mut Attribute) -> Result<()> {
+        let Attribute::Code {
+            code,
+            exception_table,
+            ..
+        } = attribute
+        else {
+            return Err(InvalidCodeAttribute(attribute.name().to_string()));
+        };
+        let entries = self.build(code)?;
+        exception_table.extend(entries);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn code() -> Vec<Instruction> {
+        vec![
+            Instruction::Aconst_null,
+            Instruction::Athrow,
+            Instruction::Pop,
+            Instruction::Return,
+        ]
+    }
+
+    fn message(result: Result<Vec<ExceptionTableEntry>>) -> String {
+        match result {
+            Err(InvalidExceptionTable(message)) => message,
+            result => format!("{result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_build() -> Result<()> {
+        let builder = ExceptionTableBuilder::new()
+            .handler("try", "end_try", "catch", 3)
+            .handler("try", "catch", "finally", 0)
+            .label("try", 0)
+            .label("end_try", 2)
+            .label("catch", 2)
+            .label("finally", 3);
+        assert_eq!(
+            vec![
+                ExceptionTableEntry {
+                    range_pc: 0..2,
+                    handler_pc: 2,
+                    catch_type: 3,
+                },
+                ExceptionTableEntry {
+                    range_pc: 0..2,
+                    handler_pc: 3,
+                    catch_type: 0,
+                },
+            ],
+            builder.build(&code())?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_range_end_of_code() -> Result<()> {
+        let builder = ExceptionTableBuilder::new()
+            .handler("start", "end", "start", 0)
+            .label("start", 0)
+            .label("end", 4);
+        assert_eq!(0..4, builder.build(&code())?[0].range_pc);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unbound_label() {
+        let builder = ExceptionTableBuilder::new()
+            .handler("start", "end", "catch", 0)
+            .label("start", 0)
+            .label("end", 1);
+        assert_eq!("label catch is not bound", message(builder.build(&code())));
+    }
+
+    #[test]
+    fn test_duplicate_label() {
+        let builder = ExceptionTableBuilder::new()
+            .label("start", 0)
+            .label("start", 1);
+        assert_eq!(
+            "label start is bound more than once",
+            message(builder.build(&code()))
+        );
+    }
+
+    #[test]
+    fn test_unordered_range() {
+        let builder = ExceptionTableBuilder::new()
+            .handler("start", "end", "catch", 0)
+            .label("start", 2)
+            .label("end", 2)
+            .label("catch", 3);
+        assert_eq!(
+            "range start (2) must start before end (2)",
+            message(builder.build(&code()))
+        );
+    }
+
+    #[test]
+    fn test_outside_code() {
+        let builder = ExceptionTableBuilder::new()
+            .handler("start", "end", "catch", 0)
+            .label("start", 0)
+            .label("end", 5)
+            .label("catch", 3);
+        assert_eq!(Err(InvalidInstructionOffset(5)), builder.build(&code()));
+
+        let builder = ExceptionTableBuilder::new()
+            .handler("start", "end", "catch", 0)
+            .label("start", 0)
+            .label("end", 2)
+            .label("catch", 4);
+        assert_eq!(Err(InvalidInstructionOffset(4)), builder.build(&code()));
+    }
+
+    #[test]
+    fn test_add_to() -> Result<()> {
+        let existing = ExceptionTableEntry {
+            range_pc: 0..1,
+            handler_pc: 3,
+            catch_type: 0,
+        };
+        let mut attribute = Attribute::Code {
+            name_index: 1,
+            max_stack: 1,
+            max_locals: 0,
+            code: code(),
+            exception_table: vec![existing.clone()],
+            attributes: Vec::new(),
+        };
+        ExceptionTableBuilder::new()
+            .handler("start", "end", "catch", 2)
+            .label("start", 0)
+            .label("end", 2)
+            .label("catch", 2)
+            .add_to(&mut attribute)?;
+
+        let Attribute::Code {
+            exception_table, ..
+        } = attribute
+        else {
+            unreachable!()
+        };
+        assert_eq!(
+            vec![
+                existing,
+                ExceptionTableEntry {
+                    range_pc: 0..2,
+                    handler_pc: 2,
+                    catch_type: 2,
+                }
+            ],
+            exception_table
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_to_invalid_attribute() {
+        let mut attribute = Attribute::Synthetic { name_index: 1 };
+        assert_eq!(
+            Err(InvalidCodeAttribute("Synthetic".to_string())),
+            ExceptionTableBuilder::new().add_to(&mut attribute)
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::attributes::Attribute;
+use crate::attributes::{Annotation, Attribute};
 use crate::constant_pool::ConstantPool;
 use crate::display::indent_lines;
 use crate::error::Result;
@@ -12,6 +12,7 @@ use std::io::Cursor;
 ///
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.5>
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Field {
     pub access_flags: FieldAccessFlags,
     pub name_index: u16,
@@ -26,6 +27,16 @@ impl Field {
     /// # Errors
     /// Returns an error if the bytes do not represent a valid Field.
     pub fn from_bytes(constant_pool: &ConstantPool, bytes: &mut Cursor<Vec<u8>>) -> Result<Field> {
+        Self::read(constant_pool, bytes, false)
+    }
+
+    /// Deserialize the Field from bytes; if `lazy` is true, the annotation attributes are not
+    /// decoded.
+    pub(crate) fn read(
+        constant_pool: &ConstantPool,
+        bytes: &mut Cursor<Vec<u8>>,
+        lazy: bool,
+    ) -> Result<Field> {
         let access_flags = FieldAccessFlags::from_bytes(bytes)?;
         let name_index = bytes.read_u16::<BigEndian>()?;
         let descriptor_index = bytes.read_u16::<BigEndian>()?;
@@ -35,7 +46,7 @@ impl Field {
         let attribute_count = bytes.read_u16::<BigEndian>()?;
         let mut attributes = Vec::with_capacity(attribute_count as usize);
         for _ in 0..attribute_count {
-            let attribute = Attribute::from_bytes(constant_pool, bytes)?;
+            let attribute = Attribute::read(constant_pool, bytes, lazy)?;
             attributes.push(attribute);
         }
 
@@ -66,6 +77,14 @@ impl Field {
 
         Ok(())
     }
+
+    /// Get the runtime visible annotations of the field.
+    ///
+    /// # Errors
+    /// If an annotation attribute cannot be decoded.
+    pub fn annotations(&self, constant_pool: &ConstantPool) -> Result<Vec<Annotation>> {
+        Annotation::runtime_visible(constant_pool, &self.attributes)
+    }
 }
 
 impl fmt::Display for Field {
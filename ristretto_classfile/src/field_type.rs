@@ -7,6 +7,7 @@ use std::{fmt, io};
 ///
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.3.2>
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FieldType {
     Base(BaseType),
     Object(String),
@@ -50,6 +51,27 @@ impl FieldType {
         }
     }
 
+    /// Return the Java source representation of the `FieldType`; e.g. `int`, `java.lang.String`
+    /// or `java.lang.Object[][]`.
+    #[must_use]
+    pub fn to_java_source(&self) -> String {
+        match self {
+            FieldType::Base(base_type) => base_type.class_name().to_string(),
+            FieldType::Object(class_name) => class_name.replace('/', "."),
+            FieldType::Array(component_type) => format!("{}[]", component_type.to_java_source()),
+        }
+    }
+
+    /// Return the number of local variable slots, or operand stack words, used by a value of the
+    /// `FieldType`; `long` and `double` values use two, all other values use one.
+    #[must_use]
+    pub fn slot_size(&self) -> usize {
+        match self {
+            FieldType::Base(BaseType::Long | BaseType::Double) => 2,
+            _ => 1,
+        }
+    }
+
     /// Return the `FieldType` for a given code.
     ///
     /// # Errors
@@ -88,11 +110,7 @@ impl FieldType {
 
 impl fmt::Display for FieldType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            FieldType::Base(base_type) => write!(f, "{}", base_type.class_name()),
-            FieldType::Object(class_name) => write!(f, "{class_name}"),
-            FieldType::Array(component_type) => write!(f, "{component_type}[]"),
-        }
+        write!(f, "{}", self.to_java_source())
     }
 }
 
@@ -196,6 +214,16 @@ mod test {
         test_field_type(&field_type, "LFoo;", 'L', "Foo")
     }
 
+    #[test]
+    fn test_to_java_source() -> Result<()> {
+        let field_type = FieldType::parse(&"[[Ljava/util/Map$Entry;".to_string())?;
+        assert_eq!("java.util.Map$Entry[][]", field_type.to_java_source());
+        assert_eq!("java.util.Map$Entry[][]", field_type.to_string());
+        let field_type = FieldType::parse(&"Z".to_string())?;
+        assert_eq!("boolean", field_type.to_java_source());
+        Ok(())
+    }
+
     #[test]
     fn test_object_no_semicolon_invalid() {
         let descriptor = "Lfoo".to_string();
@@ -242,6 +270,21 @@ mod test {
         assert_eq!("[[I", field_type_multi_array.class_name());
     }
 
+    #[test]
+    fn test_slot_size() {
+        assert_eq!(1, FieldType::Base(BaseType::Int).slot_size());
+        assert_eq!(2, FieldType::Base(BaseType::Long).slot_size());
+        assert_eq!(2, FieldType::Base(BaseType::Double).slot_size());
+        assert_eq!(
+            1,
+            FieldType::Object("java/lang/Object".to_string()).slot_size()
+        );
+        assert_eq!(
+            1,
+            FieldType::Array(Box::new(FieldType::Base(BaseType::Long))).slot_size()
+        );
+    }
+
     #[test]
     fn test_class_name_object() {
         let field_type = FieldType::Object("java/lang/Object".to_string());
@@ -0,0 +1,658 @@
+use crate::attributes::{Attribute, InnerClass, NestedClassAccessFlags};
+use crate::builder::internal_name;
+use crate::class_access_flags::ClassAccessFlags;
+use crate::class_file::ClassFile;
+use crate::constant::Constant;
+use crate::error::Error::InvalidInnerClasses;
+use crate::error::Result;
+use crate::verifiers::inner_classes;
+use crate::version::Version;
+
+const VERSION_49_0: Version = Version::Java5_0 { minor: 0 };
+
+/// A nested class derived from the name of a class file.
+#[derive(Clone, Debug, PartialEq)]
+struct NestedClass {
+    /// The class that declares the nested class.
+    enclosing_class: String,
+    /// The simple name of the class; `None` for anonymous classes.
+    simple_name: Option<String>,
+    /// True for member classes and false for local and anonymous classes.
+    member: bool,
+}
+
+/// Builder for the `InnerClasses` and `EnclosingMethod` attributes of a set of related classes;
+/// e.g. an outer class and the classes nested in it.
+///
+/// The nested classes are derived from the binary names of the classes: a class named
+/// `Outer$Inner` is a member class of `Outer`, `Outer$1Local` is a local class and `Outer$1` is an
+/// anonymous class declared in `Outer`, provided `Outer` is one of the classes.  Each class gets an
+/// `InnerClasses` entry for itself if it is nested, for each of its member classes, for every
+/// nested class that it references and for the classes that enclose them; entries for classes
+/// that are not one of the classes are kept.  Local and anonymous classes also get an
+/// `EnclosingMethod` attribute (Java 5 and later), which is removed from the other classes.
+///
+/// The access flags of an entry are derived from the access flags of the nested class, which do
+/// not record whether a member class is `private`, `protected` or `static`; member interfaces,
+/// enums and records are implicitly `static`.  The flags can be declared explicitly for the other
+/// classes.  Class names may be specified in either their binary (e.g. `com.example.Outer$Inner`)
+/// or internal (e.g. `com/example/Outer$Inner`) form.
+///
+/// # Examples
+///
+/// ```rust
+/// use ristretto_classfile::attributes::NestedClassAccessFlags;
+/// use ristretto_classfile::{ClassFile, ConstantPool, InnerClassesBuilder, Result, Version};
+///
+/// fn class_file(name: &str) -> Result<ClassFile> {
+///     let mut constant_pool = ConstantPool::default();
+///     let this_class = constant_pool.add_class(name)?;
+///     let super_class = constant_pool.add_class("java/lang/Object")?;
+///     Ok(ClassFile {
+///         version: Version::Java8 { minor: 0 },
+///         constant_pool,
+///         this_class,
+///         super_class,
+///         ..Default::default()
+///     })
+/// }
+///
+/// fn main() -> Result<()> {
+///     let mut class_files = vec![
+///         class_file("com/example/Outer")?,
+///         class_file("com/example/Outer$Inner")?,
+///         class_file("com/example/Outer$1")?,
+///     ];
+///     InnerClassesBuilder::new()
+///         .access_flags("com.example.Outer$Inner", NestedClassAccessFlags::PRIVATE | NestedClassAccessFlags::STATIC)
+///         .enclosing_method("com.example.Outer$1", "run", "()V")
+///         .add_to(&mut class_files)?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InnerClassesBuilder {
+    access_flags: Vec<(String, NestedClassAccessFlags)>,
+    enclosing_methods: Vec<(String, String, String)>,
+}
+
+impl InnerClassesBuilder {
+    /// Create a new builder for the classes it is added to.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare the access flags of a nested class, instead of deriving them from the access flags
+    /// of the class.
+    #[must_use]
+    pub fn access_flags<S: AsRef<str>>(
+        mut self,
+        class_name: S,
+        access_flags: NestedClassAccessFlags,
+    ) -> Self {
+        self.access_flags
+            .push((internal_name(class_name), access_flags));
+        self
+    }
+
+    /// Declare the method that encloses a local or anonymous class.  Classes that are declared in
+    /// an initializer have an `EnclosingMethod` attribute that names only the enclosing class.
+    #[must_use]
+    pub fn enclosing_method<S: AsRef<str>>(
+        mut self,
+        class_name: S,
+        name: S,
+        descriptor: S,
+    ) -> Self {
+        self.enclosing_methods.push((
+            internal_name(class_name),
+            name.as_ref().to_string(),
+            descriptor.as_ref().to_string(),
+        ));
+        self
+    }
+
+    /// Add the `InnerClasses` and `EnclosingMethod` attributes to the classes; existing attributes
+    /// are replaced.  The attributes are verified after they are added.
+    ///
+    /// # Errors
+    /// - If a class is added more than once.
+    /// - If access flags or an enclosing method are declared for a class that is not one of the
+    ///   nested classes, or an enclosing method is declared for a member class or a class with a
+    ///   version that does not support the `EnclosingMethod` attribute.
+    /// - If the constant pool of a class exceeds 65,534 constants.
+    /// - If the `InnerClasses` entries of the classes are inconsistent.
+    pub fn add_to(&self, class_files: &mut [ClassFile]) -> Result<()> {
+        let mut names = Vec::with_capacity(class_files.len());
+        for class_file in class_files.iter() {
+            let name = class_file.class_name()?;
+            if names.contains(name) {
+                return Err(InvalidInnerClasses(format!(
+                    "class {name} is added more than once"
+                )));
+            }
+            names.push(name.clone());
+        }
+        let nested_classes: Vec<Option<NestedClass>> = names
+            .iter()
+            .map(|name| nested_class(name, &names))
+            .collect();
+        self.validate(class_files, &names, &nested_classes)?;
+
+        let mut access_flags = Vec::with_capacity(class_files.len());
+        for (class_file, name) in class_files.iter().zip(&names) {
+            access_flags.push(self.nested_access_flags(class_file, name));
+        }
+        let mut entries = Vec::with_capacity(class_files.len());
+        for (class_file, name) in class_files.iter().zip(&names) {
+            entries.push(required_entries(class_file, name, &names, &nested_classes)?);
+        }
+
+        for ((class_file, required), nested_class) in
+            class_files.iter_mut().zip(entries).zip(&nested_classes)
+        {
+            let mut classes = Vec::new();
+            for attribute in &class_file.attributes {
+                if let Attribute::InnerClasses {
+                    classes: existing, ..
+                } = attribute
+                {
+                    for inner_class in existing {
+                        let name = class_file
+                            .constant_pool
+                            .try_get_class(inner_class.class_info_index)?;
+                        if !names.contains(name) {
+                            classes.push(inner_class.clone());
+                        }
+                    }
+                }
+            }
+            for index in required {
+                let Some(nested) = &nested_classes[index] else {
+                    continue;
+                };
+                let constant_pool = &mut class_file.constant_pool;
+                classes.push(InnerClass {
+                    class_info_index: constant_pool.add_class(&names[index])?,
+                    outer_class_info_index: if nested.member {
+                        constant_pool.add_class(&nested.enclosing_class)?
+                    } else {
+                        0
+                    },
+                    name_index: match &nested.simple_name {
+                        Some(simple_name) => constant_pool.add_utf8(simple_name)?,
+                        None => 0,
+                    },
+                    access_flags: access_flags[index],
+                });
+            }
+            class_file
+                .attributes
+                .retain(|attribute| !matches!(attribute, Attribute::InnerClasses { .. }));
+            if !classes.is_empty() {
+                class_file.attributes.push(Attribute::InnerClasses {
+                    name_index: class_file.constant_pool.add_utf8("InnerClasses")?,
+                    classes,
+                });
+            }
+
+            class_file
+                .attributes
+                .retain(|attribute| !matches!(attribute, Attribute::EnclosingMethod { .. }));
+            if let Some(nested) = nested_class.as_ref().filter(|nested| !nested.member) {
+                if class_file.version >= VERSION_49_0 {
+                    self.add_enclosing_method(class_file, nested)?;
+                }
+            }
+        }
+
+        let class_files: Vec<&ClassFile> = class_files.iter().collect();
+        inner_classes::verify(&class_files)
+    }
+
+    /// Validate the declared access flags and enclosing methods.
+    fn validate(
+        &self,
+        class_files: &[ClassFile],
+        names: &[String],
+        nested_classes: &[Option<NestedClass>],
+    ) -> Result<()> {
+        let nested_class = |name: &String| {
+            names
+                .iter()
+                .position(|class_name| class_name == name)
+                .and_then(|index| nested_classes[index].as_ref().map(|nested| (index, nested)))
+                .ok_or_else(|| InvalidInnerClasses(format!("class {name} is not a nested class")))
+        };
+        for (name, _) in &self.access_flags {
+            nested_class(name)?;
+        }
+        for (name, _, _) in &self.enclosing_methods {
+            let (index, nested) = nested_class(name)?;
+            if nested.member {
+                return Err(InvalidInnerClasses(format!(
+                    "member class {name} does not have an enclosing method"
+                )));
+            }
+            let version = &class_files[index].version;
+            if *version < VERSION_49_0 {
+                return Err(InvalidInnerClasses(format!(
+                    "enclosing methods are not supported by {version} in class {name}"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the access flags of the `InnerClasses` entry of a class.
+    fn nested_access_flags(&self, class_file: &ClassFile, name: &String) -> NestedClassAccessFlags {
+        if let Some((_, access_flags)) = self
+            .access_flags
+            .iter()
+            .find(|(class_name, _)| class_name == name)
+        {
+            return *access_flags;
+        }
+        let mut access_flags =
+            NestedClassAccessFlags::from_bits_truncate(class_file.access_flags.bits());
+        let is_record = class_file
+            .attributes
+            .iter()
+            .any(|attribute| matches!(attribute, Attribute::Record { .. }));
+        if is_record
+            || class_file
+                .access_flags
+                .intersects(ClassAccessFlags::INTERFACE | ClassAccessFlags::ENUM)
+        {
+            access_flags |= NestedClassAccessFlags::STATIC;
+        }
+        access_flags
+    }
+
+    /// Add the `EnclosingMethod` attribute of a local or anonymous class.
+    fn add_enclosing_method(&self, class_file: &mut ClassFile, nested: &NestedClass) -> Result<()> {
+        let class_name = class_file.class_name()?.clone();
+        let constant_pool = &mut class_file.constant_pool;
+        let class_index = constant_pool.add_class(&nested.enclosing_class)?;
+        let method_index = match self
+            .enclosing_methods
+            .iter()
+            .find(|(name, _, _)| *name == class_name)
+        {
+            Some((_, name, descriptor)) => constant_pool.add_name_and_type(name, descriptor)?,
+            None => 0,
+        };
+        let name_index = constant_pool.add_utf8("EnclosingMethod")?;
+        class_file.attributes.push(Attribute::EnclosingMethod {
+            name_index,
+            class_index,
+            method_index,
+        });
+        Ok(())
+    }
+}
+
+/// Derive the nested class from the binary name of a class; the enclosing class is the longest
+/// prefix of the name before a `$` that is one of the names.  The simple name of a local class is
+/// preceded by digits, and the simple name of an anonymous class consists only of digits.
+fn nested_class(name: &str, names: &[String]) -> Option<NestedClass> {
+    for (index, _) in name.rmatch_indices('$') {
+        let enclosing_class = &name[..index];
+        let suffix = &name[index + 1..];
+        if suffix.is_empty() || !names.iter().any(|name| name == enclosing_class) {
+            continue;
+        }
+        let simple_name = suffix.trim_start_matches(|c: char| c.is_ascii_digit());
+        let member = simple_name.len() == suffix.len();
+        let simple_name = (!simple_name.is_empty()).then(|| simple_name.to_string());
+        return Some(NestedClass {
+            enclosing_class: enclosing_class.to_string(),
+            simple_name,
+            member,
+        });
+    }
+    None
+}
+
+/// Get the indexes of the nested classes that require an `InnerClasses` entry in a class: the
+/// class itself, its member classes and the nested classes it references, together with the
+/// member classes that enclose them.  The indexes are in the order of the classes.
+fn required_entries(
+    class_file: &ClassFile,
+    name: &String,
+    names: &[String],
+    nested_classes: &[Option<NestedClass>],
+) -> Result<Vec<usize>> {
+    let mut required = vec![false; names.len()];
+    for (index, nested) in nested_classes.iter().enumerate() {
+        let Some(nested) = nested else {
+            continue;
+        };
+        if &names[index] == name || (nested.member && &nested.enclosing_class == name) {
+            required[index] = true;
+        }
+    }
+    for (_, constant) in &class_file.constant_pool {
+        let Constant::Class(name_index) = constant else {
+            continue;
+        };
+        let class_name = class_file.constant_pool.try_get_utf8(*name_index)?;
+        if let Some(index) = names.iter().position(|name| name == class_name) {
+            required[index] = true;
+        }
+    }
+
+    let mut pending: Vec<usize> = (0..names.len()).filter(|index| required[*index]).collect();
+    while let Some(index) = pending.pop() {
+        let Some(nested) = nested_classes[index]
+            .as_ref()
+            .filter(|nested| nested.member)
+        else {
+            continue;
+        };
+        if let Some(outer) = names
+            .iter()
+            .position(|name| *name == nested.enclosing_class)
+        {
+            if !required[outer] {
+                required[outer] = true;
+                pending.push(outer);
+            }
+        }
+    }
+
+    let entries = (0..names.len())
+        .filter(|index| required[*index] && nested_classes[*index].is_some())
+        .collect();
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    type Entry = (String, Option<String>, Option<String>);
+
+    fn entries(class_file: &ClassFile) -> Result<Vec<Entry>> {
+        let entries = inner_classes::inner_classes(class_file)?
+            .into_iter()
+            .map(|entry| {
+                (
+                    entry.class_name.clone(),
+                    entry.outer_class_name.cloned(),
+                    entry.simple_name.cloned(),
+                )
+            })
+            .collect();
+        Ok(entries)
+    }
+
+    fn member(name: &str, outer: &str, simple_name: &str) -> Entry {
+        (
+            name.to_string(),
+            Some(outer.to_string()),
+            Some(simple_name.to_string()),
+        )
+    }
+
+    #[test]
+    fn test_nested_class() {
+        let names = vec!["Outer".to_string(), "Outer$Inner".to_string()];
+        assert_eq!(None, nested_class("Outer", &names));
+        assert_eq!(None, nested_class("Other$Inner", &names));
+        assert_eq!(None, nested_class("Outer$", &names));
+        assert_eq!(
+            Some(NestedClass {
+                enclosing_class: "Outer".to_string(),
+                simple_name: Some("Inner".to_string()),
+                member: true,
+            }),
+            nested_class("Outer$Inner", &names)
+        );
+        assert_eq!(
+            Some(NestedClass {
+                enclosing_class: "Outer$Inner".to_string(),
+                simple_name: Some("Deep$Er".to_string()),
+                member: true,
+            }),
+            nested_class("Outer$Inner$Deep$Er", &names)
+        );
+        assert_eq!(
+            Some(NestedClass {
+                enclosing_class: "Outer".to_string(),
+                simple_name: Some("Local".to_string()),
+                member: false,
+            }),
+            nested_class("Outer$1Local", &names)
+        );
+        assert_eq!(
+            Some(NestedClass {
+                enclosing_class: "Outer".to_string(),
+                simple_name: None,
+                member: false,
+            }),
+            nested_class("Outer$12", &names)
+        );
+    }
+
+    #[test]
+    fn test_add_to() -> Result<()> {
+        let mut enum_class =
+            crate::test::class_file("Outer$Inner$Kind", Version::Java8 { minor: 0 })?;
+        enum_class.access_flags =
+            ClassAccessFlags::PUBLIC | ClassAccessFlags::FINAL | ClassAccessFlags::ENUM;
+        let mut class_files = vec![
+            crate::test::class_file("Outer", Version::Java8 { minor: 0 })?,
+            crate::test::class_file("Outer$Inner", Version::Java8 { minor: 0 })?,
+            enum_class,
+            crate::test::class_file("Other", Version::Java8 { minor: 0 })?,
+        ];
+        class_files[3].constant_pool.add_class("Outer$Inner$Kind")?;
+        InnerClassesBuilder::new()
+            .access_flags("Outer$Inner", NestedClassAccessFlags::PRIVATE)
+            .add_to(&mut class_files)?;
+
+        assert_eq!(
+            vec![member("Outer$Inner", "Outer", "Inner")],
+            entries(&class_files[0])?
+        );
+        let expected = vec![
+            member("Outer$Inner", "Outer", "Inner"),
+            member("Outer$Inner$Kind", "Outer$Inner", "Kind"),
+        ];
+        assert_eq!(expected, entries(&class_files[1])?);
+        assert_eq!(expected, entries(&class_files[2])?);
+        assert_eq!(expected, entries(&class_files[3])?);
+
+        let flags = inner_classes::inner_classes(&class_files[2])?
+            .iter()
+            .map(|entry| entry.access_flags)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                NestedClassAccessFlags::PRIVATE,
+                NestedClassAccessFlags::PUBLIC
+                    | NestedClassAccessFlags::STATIC
+                    | NestedClassAccessFlags::FINAL
+                    | NestedClassAccessFlags::ENUM
+            ],
+            flags
+        );
+
+        let refs: Vec<&ClassFile> = class_files.iter().collect();
+        ClassFile::verify_inner_classes(&refs)?;
+        for class_file in &class_files {
+            class_file.verify()?;
+            let mut bytes = Vec::new();
+            class_file.to_bytes(&mut bytes)?;
+            let mut cursor = std::io::Cursor::new(bytes);
+            assert_eq!(*class_file, ClassFile::from_bytes(&mut cursor)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_to_local_and_anonymous_classes() -> Result<()> {
+        let mut class_files = vec![
+            crate::test::class_file("Outer", Version::Java8 { minor: 0 })?,
+            crate::test::class_file("Outer$1", Version::Java8 { minor: 0 })?,
+            crate::test::class_file("Outer$1Local", Version::Java8 { minor: 0 })?,
+        ];
+        InnerClassesBuilder::new()
+            .enclosing_method("Outer$1", "run", "()V")
+            .add_to(&mut class_files)?;
+
+        assert!(entries(&class_files[0])?.is_empty());
+        assert_eq!(
+            vec![("Outer$1".to_string(), None, None)],
+            entries(&class_files[1])?
+        );
+        assert_eq!(
+            vec![("Outer$1Local".to_string(), None, Some("Local".to_string()))],
+            entries(&class_files[2])?
+        );
+
+        let enclosing_method = |class_file: &ClassFile| -> Result<(String, u16)> {
+            for attribute in &class_file.attributes {
+                if let Attribute::EnclosingMethod {
+                    class_index,
+                    method_index,
+                    ..
+                } = attribute
+                {
+                    let class_name = class_file.constant_pool.try_get_class(*class_index)?;
+                    return Ok((class_name.clone(), *method_index));
+                }
+            }
+            Ok((String::new(), 0))
+        };
+        let (class_name, method_index) = enclosing_method(&class_files[1])?;
+        assert_eq!("Outer", class_name);
+        let (name_index, descriptor_index) = class_files[1]
+            .constant_pool
+            .try_get_name_and_type(method_index)?;
+        assert_eq!(
+            "run",
+            class_files[1].constant_pool.try_get_utf8(*name_index)?
+        );
+        assert_eq!(
+            "()V",
+            class_files[1]
+                .constant_pool
+                .try_get_utf8(*descriptor_index)?
+        );
+        assert_eq!(("Outer".to_string(), 0), enclosing_method(&class_files[2])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_to_replaces_entries() -> Result<()> {
+        let mut class_files = vec![
+            crate::test::class_file("Outer", Version::Java8 { minor: 0 })?,
+            crate::test::class_file("Outer$Inner", Version::Java8 { minor: 0 })?,
+        ];
+        InnerClassesBuilder::new().add_to(&mut class_files)?;
+        let constant_pool = &mut class_files[0].constant_pool;
+        let class_info_index = constant_pool.add_class("java/util/Map$Entry")?;
+        let outer_class_info_index = constant_pool.add_class("java/util/Map")?;
+        let name_index = constant_pool.add_utf8("Entry")?;
+        let Some(Attribute::InnerClasses { classes, .. }) = class_files[0].attributes.last_mut()
+        else {
+            unreachable!()
+        };
+        classes.push(InnerClass {
+            class_info_index,
+            outer_class_info_index,
+            name_index,
+            access_flags: NestedClassAccessFlags::PUBLIC
+                | NestedClassAccessFlags::STATIC
+                | NestedClassAccessFlags::INTERFACE
+                | NestedClassAccessFlags::ABSTRACT,
+        });
+
+        let constant_pool = &mut class_files[1].constant_pool;
+        let enclosing_method = Attribute::EnclosingMethod {
+            name_index: constant_pool.add_utf8("EnclosingMethod")?,
+            class_index: constant_pool.add_class("Outer")?,
+            method_index: 0,
+        };
+        class_files[1].attributes.push(enclosing_method);
+
+        InnerClassesBuilder::new()
+            .access_flags("Outer$Inner", NestedClassAccessFlags::STATIC)
+            .add_to(&mut class_files)?;
+        assert_eq!(
+            vec![
+                member("java/util/Map$Entry", "java/util/Map", "Entry"),
+                member("Outer$Inner", "Outer", "Inner"),
+            ],
+            entries(&class_files[0])?
+        );
+        let inner_classes_attributes = class_files[0]
+            .attributes
+            .iter()
+            .filter(|attribute| matches!(attribute, Attribute::InnerClasses { .. }))
+            .count();
+        assert_eq!(1, inner_classes_attributes);
+        assert!(!class_files[1]
+            .attributes
+            .iter()
+            .any(|attribute| matches!(attribute, Attribute::EnclosingMethod { .. })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_to_duplicate_class() -> Result<()> {
+        let mut class_files = vec![
+            crate::test::class_file("Outer", Version::Java8 { minor: 0 })?,
+            crate::test::class_file("Outer", Version::Java8 { minor: 0 })?,
+        ];
+        assert_eq!(
+            Err(InvalidInnerClasses(
+                "class Outer is added more than once".to_string()
+            )),
+            InnerClassesBuilder::new().add_to(&mut class_files)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_to_invalid_declarations() -> Result<()> {
+        let mut class_files = vec![
+            crate::test::class_file("Outer", Version::Java8 { minor: 0 })?,
+            crate::test::class_file("Outer$Inner", Version::Java8 { minor: 0 })?,
+        ];
+        assert_eq!(
+            Err(InvalidInnerClasses(
+                "class Outer is not a nested class".to_string()
+            )),
+            InnerClassesBuilder::new()
+                .access_flags("Outer", NestedClassAccessFlags::STATIC)
+                .add_to(&mut class_files)
+        );
+        assert_eq!(
+            Err(InvalidInnerClasses(
+                "member class Outer$Inner does not have an enclosing method".to_string()
+            )),
+            InnerClassesBuilder::new()
+                .enclosing_method("Outer$Inner", "run", "()V")
+                .add_to(&mut class_files)
+        );
+
+        let mut class_files = vec![
+            crate::test::class_file("Outer", Version::Java8 { minor: 0 })?,
+            crate::test::class_file("Outer$1", Version::Java8 { minor: 0 })?,
+        ];
+        class_files[1].version = Version::Java1_4 { minor: 0 };
+        assert_eq!(
+            Err(InvalidInnerClasses(
+                "enclosing methods are not supported by Java 1.4 in class Outer$1".to_string()
+            )),
+            InnerClassesBuilder::new()
+                .enclosing_method("Outer$1", "run", "()V")
+                .add_to(&mut class_files)
+        );
+        Ok(())
+    }
+}
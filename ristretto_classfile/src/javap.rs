@@ -0,0 +1,1461 @@
+//! Disassembly of class files in the layout produced by `javap -v -p`.
+//!
+//! The output mirrors the `OpenJDK` tool closely enough that the two can be diffed line by line.
+//! The `Classfile`, `Last modified` and checksum header lines are omitted as they describe the
+//! file on disk rather than the class, and member declarations are rendered from descriptors
+//! rather than from generic `Signature` attributes.
+
+use crate::attributes::NestedClassAccessFlags;
+use crate::attributes::{
+    Annotation, AnnotationElement, Attribute, ExceptionTableEntry, Instruction, StackFrame,
+    VerificationType,
+};
+use crate::class_access_flags::ClassAccessFlags;
+use crate::class_file::ClassFile;
+use crate::constant::Constant;
+use crate::constant_pool::ConstantPool;
+use crate::error::Result;
+use crate::field::Field;
+use crate::field_access_flags::FieldAccessFlags;
+use crate::field_type::FieldType;
+use crate::method::Method;
+use crate::method_access_flags::MethodAccessFlags;
+use crate::method_descriptor::MethodDescriptor;
+use crate::reference_kind::ReferenceKind;
+use byteorder::{BigEndian, ReadBytesExt};
+use std::fmt;
+use std::io::{Cursor, Read};
+
+/// Number of spaces per indentation level.
+const INDENT_WIDTH: usize = 2;
+/// Column, relative to the current indentation, at which `//` comments start.
+const COMMENT_COLUMN: usize = 40;
+
+/// Disassemble a class file into the textual layout used by `javap -v -p`.
+pub(crate) fn disassemble(class_file: &ClassFile) -> Result<String> {
+    let mut writer = Writer::new(class_file);
+    writer.write_class()?;
+    Ok(writer.finish())
+}
+
+/// Line oriented writer that tracks the indentation and comment column used by javap.
+struct Writer<'a> {
+    class_file: &'a ClassFile,
+    constant_pool: &'a ConstantPool,
+    indent: usize,
+    lines: Vec<String>,
+}
+
+impl<'a> Writer<'a> {
+    fn new(class_file: &'a ClassFile) -> Self {
+        Self {
+            class_file,
+            constant_pool: &class_file.constant_pool,
+            indent: 0,
+            lines: Vec::new(),
+        }
+    }
+
+    fn finish(self) -> String {
+        let mut output = self.lines.join("\n");
+        output.push('\n');
+        output
+    }
+
+    /// Write a line at the current indentation.
+    fn line<S: AsRef<str>>(&mut self, text: S) {
+        let indent = " ".repeat(self.indent * INDENT_WIDTH);
+        let line = format!("{indent}{}", text.as_ref());
+        self.lines.push(line.trim_end().to_string());
+    }
+
+    /// Write a line at the current indentation followed by a `//` comment aligned to the comment
+    /// column.
+    fn line_with_comment<S: AsRef<str>, C: AsRef<str>>(&mut self, text: S, comment: C) {
+        let indent = " ".repeat(self.indent * INDENT_WIDTH);
+        let mut line = format!("{indent}{}", text.as_ref());
+        let column = self.indent * INDENT_WIDTH + COMMENT_COLUMN;
+        let padding = column.saturating_sub(line.len()).max(1);
+        line.push_str(&" ".repeat(padding));
+        line.push_str("// ");
+        line.push_str(comment.as_ref());
+        self.lines.push(line.trim_end().to_string());
+    }
+
+    fn write_class(&mut self) -> Result<()> {
+        let class_file = self.class_file;
+        for attribute in &class_file.attributes {
+            if let Attribute::SourceFile {
+                source_file_index, ..
+            } = attribute
+            {
+                let source_file = self.constant_pool.try_get_utf8(*source_file_index)?;
+                self.line(format!("  Compiled from \"{source_file}\""));
+            }
+        }
+        let header = self.class_header()?;
+        self.line(header);
+
+        self.indent += 1;
+        self.line(format!("minor version: {}", class_file.version.minor()));
+        self.line(format!("major version: {}", class_file.version.major()));
+        self.line(format!("flags: {}", flags(&class_file.access_flags)));
+        let this_class = self.class_value(class_file.this_class)?;
+        self.line_with_comment(
+            format!("this_class: #{}", class_file.this_class),
+            this_class,
+        );
+        if class_file.super_class == 0 {
+            self.line("super_class: #0");
+        } else {
+            let super_class = self.class_value(class_file.super_class)?;
+            self.line_with_comment(
+                format!("super_class: #{}", class_file.super_class),
+                super_class,
+            );
+        }
+        self.line(format!(
+            "interfaces: {}, fields: {}, methods: {}, attributes: {}",
+            class_file.interfaces.len(),
+            class_file.fields.len(),
+            class_file.methods.len(),
+            class_file.attributes.len()
+        ));
+        self.indent -= 1;
+
+        self.write_constant_pool()?;
+
+        self.line("{");
+        self.indent += 1;
+        let mut first = true;
+        for field in &class_file.fields {
+            if !first {
+                self.line("");
+            }
+            first = false;
+            self.write_field(field)?;
+        }
+        for method in &class_file.methods {
+            if !first {
+                self.line("");
+            }
+            first = false;
+            self.write_method(method)?;
+        }
+        self.indent -= 1;
+        self.line("}");
+
+        self.write_attributes(&class_file.attributes)
+    }
+
+    /// Render the class declaration, e.g. `public class Foo extends Bar implements Baz`.
+    fn class_header(&self) -> Result<String> {
+        let class_file = self.class_file;
+        let access_flags = class_file.access_flags;
+        let is_interface = access_flags.contains(ClassAccessFlags::INTERFACE);
+        let mut header = Vec::new();
+        if access_flags.contains(ClassAccessFlags::PUBLIC) {
+            header.push("public");
+        }
+        if access_flags.contains(ClassAccessFlags::FINAL) {
+            header.push("final");
+        }
+        if access_flags.contains(ClassAccessFlags::ABSTRACT) && !is_interface {
+            header.push("abstract");
+        }
+        if access_flags.contains(ClassAccessFlags::MODULE) {
+            header.push("module");
+        } else if is_interface {
+            header.push("interface");
+        } else {
+            header.push("class");
+        }
+
+        let mut header = header.join(" ");
+        header.push(' ');
+        header.push_str(&java_name(class_file.class_name()?));
+
+        let interfaces = class_file
+            .interfaces
+            .iter()
+            .map(|index| Ok(java_name(self.constant_pool.try_get_class(*index)?)))
+            .collect::<Result<Vec<String>>>()?;
+        if is_interface {
+            if !interfaces.is_empty() {
+                header.push_str(" extends ");
+                header.push_str(&interfaces.join(", "));
+            }
+        } else {
+            if class_file.super_class != 0 {
+                let super_class = self.constant_pool.try_get_class(class_file.super_class)?;
+                if super_class != "java/lang/Object" {
+                    header.push_str(" extends ");
+                    header.push_str(&java_name(super_class));
+                }
+            }
+            if !interfaces.is_empty() {
+                header.push_str(" implements ");
+                header.push_str(&interfaces.join(", "));
+            }
+        }
+        Ok(header)
+    }
+
+    fn write_constant_pool(&mut self) -> Result<()> {
+        self.line("Constant pool:");
+        self.indent += 1;
+        let count = self.constant_pool.len() + 1;
+        let width = count.to_string().len() + 1;
+        for index in 1..=self.constant_pool.len() {
+            let index = u16::try_from(index)?;
+            let Some(constant) = self.constant_pool.get(index) else {
+                // Second slot of a long or double constant
+                continue;
+            };
+            let prefix = format!(
+                "{:>width$} = {:<18} ",
+                format!("#{index}"),
+                tag_name(constant)
+            );
+            match constant {
+                Constant::Utf8(value) => self.line(format!("{prefix}{}", escape(value))),
+                Constant::Integer(_)
+                | Constant::Float(_)
+                | Constant::Long(_)
+                | Constant::Double(_) => {
+                    self.line(format!("{prefix}{}", self.constant_value(index)?));
+                }
+                Constant::Class(name_index)
+                | Constant::String(name_index)
+                | Constant::Module(name_index)
+                | Constant::Package(name_index) => {
+                    let value = self.constant_value(index)?;
+                    self.line_with_comment(format!("{prefix}#{name_index}"), value);
+                }
+                Constant::MethodType(descriptor_index) => {
+                    let value = self.constant_value(index)?;
+                    self.line_with_comment(
+                        format!("{prefix}#{descriptor_index}"),
+                        format!(" {value}"),
+                    );
+                }
+                Constant::FieldRef {
+                    class_index,
+                    name_and_type_index,
+                }
+                | Constant::MethodRef {
+                    class_index,
+                    name_and_type_index,
+                }
+                | Constant::InterfaceMethodRef {
+                    class_index,
+                    name_and_type_index,
+                } => {
+                    let value = self.constant_value(index)?;
+                    self.line_with_comment(
+                        format!("{prefix}#{class_index}.#{name_and_type_index}"),
+                        value,
+                    );
+                }
+                Constant::NameAndType {
+                    name_index,
+                    descriptor_index,
+                } => {
+                    let value = self.constant_value(index)?;
+                    self.line_with_comment(
+                        format!("{prefix}#{name_index}:#{descriptor_index}"),
+                        value,
+                    );
+                }
+                Constant::MethodHandle {
+                    reference_kind,
+                    reference_index,
+                } => {
+                    let value = self.constant_value(index)?;
+                    self.line_with_comment(
+                        format!("{prefix}{}:#{reference_index}", reference_kind.kind()),
+                        value,
+                    );
+                }
+                Constant::Dynamic {
+                    bootstrap_method_attr_index,
+                    name_and_type_index,
+                }
+                | Constant::InvokeDynamic {
+                    bootstrap_method_attr_index,
+                    name_and_type_index,
+                } => {
+                    let value = self.constant_value(index)?;
+                    self.line_with_comment(
+                        format!("{prefix}#{bootstrap_method_attr_index}:#{name_and_type_index}"),
+                        value,
+                    );
+                }
+            }
+        }
+        self.indent -= 1;
+        Ok(())
+    }
+
+    fn write_field(&mut self, field: &Field) -> Result<()> {
+        let name = self.constant_pool.try_get_utf8(field.name_index)?;
+        let descriptor = self.constant_pool.try_get_utf8(field.descriptor_index)?;
+        let modifiers = field_modifiers(field.access_flags);
+        let field_type = FieldType::parse(descriptor)?.to_java_source();
+        self.line(format!("{modifiers}{field_type} {name};"));
+        self.indent += 1;
+        self.line(format!("descriptor: {descriptor}"));
+        self.line(format!("flags: {}", flags(&field.access_flags)));
+        self.write_attributes(&field.attributes)?;
+        self.indent -= 1;
+        Ok(())
+    }
+
+    fn write_method(&mut self, method: &Method) -> Result<()> {
+        let name = self.constant_pool.try_get_utf8(method.name_index)?;
+        let descriptor = self.constant_pool.try_get_utf8(method.descriptor_index)?;
+        let access_flags = method.access_flags;
+        let method_descriptor = MethodDescriptor::parse(descriptor)?;
+
+        let mut modifiers = method_modifiers(access_flags);
+        if self
+            .class_file
+            .access_flags
+            .contains(ClassAccessFlags::INTERFACE)
+            && !access_flags.intersects(
+                MethodAccessFlags::ABSTRACT
+                    | MethodAccessFlags::STATIC
+                    | MethodAccessFlags::PRIVATE,
+            )
+            && name != "<clinit>"
+        {
+            modifiers.push_str("default ");
+        }
+
+        let declaration = if name == "<clinit>" {
+            "static {}".to_string()
+        } else {
+            let mut parameters = method_descriptor
+                .parameters
+                .iter()
+                .map(FieldType::to_java_source)
+                .collect::<Vec<String>>();
+            if access_flags.contains(MethodAccessFlags::VARARGS) {
+                if let Some(last) = parameters.last_mut() {
+                    if let Some(component) = last.strip_suffix("[]") {
+                        *last = format!("{component}...");
+                    }
+                }
+            }
+            let parameters = parameters.join(", ");
+            if name == "<init>" {
+                let class_name = java_name(self.class_file.class_name()?);
+                format!("{modifiers}{class_name}({parameters})")
+            } else {
+                let return_type = method_descriptor
+                    .return_type
+                    .as_ref()
+                    .map_or("void".to_string(), FieldType::to_java_source);
+                format!("{modifiers}{return_type} {name}({parameters})")
+            }
+        };
+
+        let mut exceptions = Vec::new();
+        for attribute in &method.attributes {
+            if let Attribute::Exceptions {
+                exception_indexes, ..
+            } = attribute
+            {
+                for index in exception_indexes {
+                    exceptions.push(java_name(self.constant_pool.try_get_class(*index)?));
+                }
+            }
+        }
+        if exceptions.is_empty() {
+            self.line(format!("{declaration};"));
+        } else {
+            self.line(format!("{declaration} throws {};", exceptions.join(", ")));
+        }
+
+        self.indent += 1;
+        self.line(format!("descriptor: {descriptor}"));
+        self.line(format!("flags: {}", flags(&access_flags)));
+        let arguments_size = method_descriptor.parameters_size()
+            + usize::from(!access_flags.contains(MethodAccessFlags::STATIC));
+        for attribute in &method.attributes {
+            let attribute = attribute.decode(self.constant_pool)?;
+            let attribute = attribute.as_ref();
+            if let Attribute::Code { .. } = attribute {
+                self.write_code(attribute, arguments_size)?;
+            } else {
+                self.write_attribute(attribute)?;
+            }
+        }
+        self.indent -= 1;
+        Ok(())
+    }
+
+    /// Write a `Code` attribute.  The attribute is serialized and read back so that instruction,
+    /// exception table, line number and stack map offsets are reported as byte offsets, the same
+    /// as javap, rather than the logical instruction offsets used in memory.
+    fn write_code(&mut self, attribute: &Attribute, arguments_size: usize) -> Result<()> {
+        let mut bytes = Vec::new();
+        attribute.to_bytes(&mut bytes)?;
+        let mut bytes = Cursor::new(bytes);
+        let _name_index = bytes.read_u16::<BigEndian>()?;
+        let _length = bytes.read_u32::<BigEndian>()?;
+        let max_stack = bytes.read_u16::<BigEndian>()?;
+        let max_locals = bytes.read_u16::<BigEndian>()?;
+        let code_length = bytes.read_u32::<BigEndian>()? as usize;
+        let mut code = vec![0; code_length];
+        bytes.read_exact(&mut code)?;
+        let exception_table_length = bytes.read_u16::<BigEndian>()?;
+        let mut exception_table = Vec::with_capacity(exception_table_length as usize);
+        for _ in 0..exception_table_length {
+            exception_table.push(ExceptionTableEntry::from_bytes(&mut bytes)?);
+        }
+        let attributes_count = bytes.read_u16::<BigEndian>()?;
+        let mut attributes = Vec::with_capacity(attributes_count as usize);
+        for _ in 0..attributes_count {
+            attributes.push(Attribute::from_bytes(self.constant_pool, &mut bytes)?);
+        }
+
+        self.line("Code:");
+        self.indent += 1;
+        self.line(format!(
+            "stack={max_stack}, locals={max_locals}, args_size={arguments_size}"
+        ));
+        let mut code = Cursor::new(code);
+        while code.position() < code_length as u64 {
+            let pc = i32::try_from(code.position())?;
+            let instruction = Instruction::from_bytes(&mut code)?;
+            self.write_instruction(pc, &instruction)?;
+        }
+
+        if !exception_table.is_empty() {
+            self.line("Exception table:");
+            self.indent += 1;
+            self.line(" from    to  target type");
+            for entry in &exception_table {
+                let catch_type = if entry.catch_type == 0 {
+                    "any".to_string()
+                } else {
+                    format!("Class {}", self.class_value(entry.catch_type)?)
+                };
+                self.line(format!(
+                    " {:5} {:5} {:5}   {catch_type}",
+                    entry.range_pc.start, entry.range_pc.end, entry.handler_pc
+                ));
+            }
+            self.indent -= 1;
+        }
+
+        self.write_attributes(&attributes)?;
+        self.indent -= 1;
+        Ok(())
+    }
+
+    fn write_instruction(&mut self, pc: i32, instruction: &Instruction) -> Result<()> {
+        let text = instruction.to_string();
+        let (mnemonic, operands) = text.split_once(' ').unwrap_or((text.as_str(), ""));
+        let prefix = format!("{pc:>4}: {mnemonic:<13} ");
+        match instruction {
+            Instruction::Ldc(index) => {
+                let comment = self.instruction_comment(u16::from(*index))?;
+                self.line_with_comment(format!("{prefix}#{index}"), comment);
+            }
+            Instruction::Ldc_w(index)
+            | Instruction::Ldc2_w(index)
+            | Instruction::Getstatic(index)
+            | Instruction::Putstatic(index)
+            | Instruction::Getfield(index)
+            | Instruction::Putfield(index)
+            | Instruction::Invokevirtual(index)
+            | Instruction::Invokespecial(index)
+            | Instruction::Invokestatic(index)
+            | Instruction::New(index)
+            | Instruction::Anewarray(index)
+            | Instruction::Checkcast(index)
+            | Instruction::Instanceof(index) => {
+                let comment = self.instruction_comment(*index)?;
+                self.line_with_comment(format!("{prefix}#{index}"), comment);
+            }
+            Instruction::Invokeinterface(index, count)
+            | Instruction::Multianewarray(index, count) => {
+                let comment = self.instruction_comment(*index)?;
+                self.line_with_comment(format!("{prefix}#{index},  {count}"), comment);
+            }
+            Instruction::Invokedynamic(index) => {
+                let comment = self.instruction_comment(*index)?;
+                self.line_with_comment(format!("{prefix}#{index},  0"), comment);
+            }
+            Instruction::Newarray(array_type) => {
+                self.line(format!("{prefix} {array_type}"));
+            }
+            Instruction::Tableswitch {
+                default,
+                low,
+                high,
+                offsets,
+            } => {
+                self.line(format!("{prefix}{{ // {low} to {high}"));
+                for (value, offset) in (*low..=*high).zip(offsets) {
+                    self.line(format!("{value:>18}: {}", pc + offset));
+                }
+                self.line(format!("{:>18}: {}", "default", pc + default));
+                self.line("      }");
+            }
+            Instruction::Lookupswitch { default, pairs } => {
+                self.line(format!("{prefix}{{ // {}", pairs.len()));
+                for (value, offset) in pairs {
+                    self.line(format!("{value:>18}: {}", pc + offset));
+                }
+                self.line(format!("{:>18}: {}", "default", pc + default));
+                self.line("      }");
+            }
+            _ => self.line(format!("{prefix}{operands}")),
+        }
+        Ok(())
+    }
+
+    /// Comment for an instruction operand referencing the constant pool, e.g. `Method foo:()V`.
+    /// Members of this class are shown without the class name.
+    fn instruction_comment(&self, index: u16) -> Result<String> {
+        let constant = self.constant_pool.try_get(index)?;
+        let value = match constant {
+            Constant::FieldRef {
+                class_index,
+                name_and_type_index,
+            }
+            | Constant::MethodRef {
+                class_index,
+                name_and_type_index,
+            }
+            | Constant::InterfaceMethodRef {
+                class_index,
+                name_and_type_index,
+            } if *class_index == self.class_file.this_class => {
+                self.constant_value(*name_and_type_index)?
+            }
+            _ => self.constant_value(index)?,
+        };
+        Ok(format!("{} {value}", comment_tag_name(constant)))
+    }
+
+    fn write_attributes(&mut self, attributes: &[Attribute]) -> Result<()> {
+        for attribute in attributes {
+            self.write_attribute(attribute)?;
+        }
+        Ok(())
+    }
+
+    #[expect(clippy::too_many_lines)]
+    fn write_attribute(&mut self, attribute: &Attribute) -> Result<()> {
+        match attribute {
+            Attribute::Lazy { .. } => {
+                let attribute = attribute.decode(self.constant_pool)?;
+                self.write_attribute(&attribute)?;
+            }
+            Attribute::ConstantValue {
+                constant_value_index,
+                ..
+            } => {
+                let constant = self.constant_pool.try_get(*constant_value_index)?;
+                let value = self.constant_value(*constant_value_index)?;
+                self.line(format!(
+                    "ConstantValue: {} {value}",
+                    comment_tag_name(constant)
+                ));
+            }
+            Attribute::Code { .. } => self.write_code(attribute, 0)?,
+            Attribute::StackMapTable { frames, .. } => {
+                self.line(format!(
+                    "StackMapTable: number_of_entries = {}",
+                    frames.len()
+                ));
+                self.indent += 1;
+                for frame in frames {
+                    self.write_stack_frame(frame)?;
+                }
+                self.indent -= 1;
+            }
+            Attribute::Exceptions {
+                exception_indexes, ..
+            } => {
+                let exceptions = exception_indexes
+                    .iter()
+                    .map(|index| Ok(java_name(self.constant_pool.try_get_class(*index)?)))
+                    .collect::<Result<Vec<String>>>()?;
+                self.line("Exceptions:");
+                self.line(format!("  throws {}", exceptions.join(", ")));
+            }
+            Attribute::InnerClasses { classes, .. } => {
+                self.line("InnerClasses:");
+                self.indent += 1;
+                for inner_class in classes {
+                    let mut text = inner_class_modifiers(inner_class.access_flags);
+                    let mut comment = String::new();
+                    if inner_class.name_index != 0 {
+                        text = format!("{text}#{}= ", inner_class.name_index);
+                        let name = self.constant_pool.try_get_utf8(inner_class.name_index)?;
+                        comment = format!("{name}=");
+                    }
+                    text = format!("{text}#{}", inner_class.class_info_index);
+                    let class_info = self.constant_pool.try_get(inner_class.class_info_index)?;
+                    let class_value = self.constant_value(inner_class.class_info_index)?;
+                    comment = format!("{comment}{} {class_value}", comment_tag_name(class_info));
+                    if inner_class.outer_class_info_index != 0 {
+                        text = format!("{text} of #{}", inner_class.outer_class_info_index);
+                        let outer_class = self
+                            .constant_pool
+                            .try_get(inner_class.outer_class_info_index)?;
+                        let outer_value =
+                            self.constant_value(inner_class.outer_class_info_index)?;
+                        comment = format!(
+                            "{comment} of {} {outer_value}",
+                            comment_tag_name(outer_class)
+                        );
+                    }
+                    text.push(';');
+                    self.line_with_comment(text, comment);
+                }
+                self.indent -= 1;
+            }
+            Attribute::EnclosingMethod {
+                class_index,
+                method_index,
+                ..
+            } => {
+                let mut comment = java_name(self.constant_pool.try_get_class(*class_index)?);
+                if *method_index != 0 {
+                    let (name_index, _) =
+                        self.constant_pool.try_get_name_and_type(*method_index)?;
+                    let name = self.constant_pool.try_get_utf8(*name_index)?;
+                    comment = format!("{comment}.{name}");
+                }
+                self.line_with_comment(
+                    format!("EnclosingMethod: #{class_index}.#{method_index}"),
+                    comment,
+                );
+            }
+            Attribute::Synthetic { .. } => self.line("Synthetic: true"),
+            Attribute::Signature {
+                signature_index, ..
+            } => {
+                let signature = self.constant_pool.try_get_utf8(*signature_index)?;
+                self.line_with_comment(format!("Signature: #{signature_index}"), signature);
+            }
+            Attribute::SourceFile {
+                source_file_index, ..
+            } => {
+                let source_file = self.constant_pool.try_get_utf8(*source_file_index)?;
+                self.line(format!("SourceFile: \"{source_file}\""));
+            }
+            Attribute::SourceDebugExtension {
+                debug_extension, ..
+            } => {
+                self.line("SourceDebugExtension:");
+                self.indent += 1;
+                for line in debug_extension.lines() {
+                    self.line(line);
+                }
+                self.indent -= 1;
+            }
+            Attribute::LineNumberTable { line_numbers, .. } => {
+                self.line("LineNumberTable:");
+                self.indent += 1;
+                for line_number in line_numbers {
+                    self.line(format!(
+                        "line {}: {}",
+                        line_number.line_number, line_number.start_pc
+                    ));
+                }
+                self.indent -= 1;
+            }
+            Attribute::LocalVariableTable { variables, .. } => {
+                self.line("LocalVariableTable:");
+                self.indent += 1;
+                self.line("Start  Length  Slot  Name   Signature");
+                for variable in variables {
+                    let name = self.constant_pool.try_get_utf8(variable.name_index)?;
+                    let descriptor = self.constant_pool.try_get_utf8(variable.descriptor_index)?;
+                    self.line(format!(
+                        "{:5} {:7} {:5} {name:>5}   {descriptor}",
+                        variable.start_pc, variable.length, variable.index
+                    ));
+                }
+                self.indent -= 1;
+            }
+            Attribute::LocalVariableTypeTable { variable_types, .. } => {
+                self.line("LocalVariableTypeTable:");
+                self.indent += 1;
+                self.line("Start  Length  Slot  Name   Signature");
+                for variable_type in variable_types {
+                    let name = self.constant_pool.try_get_utf8(variable_type.name_index)?;
+                    let signature = self
+                        .constant_pool
+                        .try_get_utf8(variable_type.signature_index)?;
+                    self.line(format!(
+                        "{:5} {:7} {:5} {name:>5}   {signature}",
+                        variable_type.start_pc, variable_type.length, variable_type.index
+                    ));
+                }
+                self.indent -= 1;
+            }
+            Attribute::Deprecated { .. } => self.line("Deprecated: true"),
+            Attribute::RuntimeVisibleAnnotations { annotations, .. } => {
+                self.line("RuntimeVisibleAnnotations:");
+                self.write_annotations(annotations)?;
+            }
+            Attribute::RuntimeInvisibleAnnotations { annotations, .. } => {
+                self.line("RuntimeInvisibleAnnotations:");
+                self.write_annotations(annotations)?;
+            }
+            Attribute::RuntimeVisibleParameterAnnotations {
+                parameter_annotations,
+                ..
+            } => {
+                self.line("RuntimeVisibleParameterAnnotations:");
+                self.indent += 1;
+                for (index, parameter_annotation) in parameter_annotations.iter().enumerate() {
+                    self.line(format!("parameter {index}:"));
+                    self.write_annotations(&parameter_annotation.annotations)?;
+                }
+                self.indent -= 1;
+            }
+            Attribute::RuntimeInvisibleParameterAnnotations {
+                parameter_annotations,
+                ..
+            } => {
+                self.line("RuntimeInvisibleParameterAnnotations:");
+                self.indent += 1;
+                for (index, parameter_annotation) in parameter_annotations.iter().enumerate() {
+                    self.line(format!("parameter {index}:"));
+                    self.write_annotations(&parameter_annotation.annotations)?;
+                }
+                self.indent -= 1;
+            }
+            Attribute::AnnotationDefault { element, .. } => {
+                self.line("AnnotationDefault:");
+                self.indent += 1;
+                self.line(format!("default_value: {}", element_value_raw(element)));
+                self.indent += 1;
+                for line in self.element_value_lines(element)? {
+                    self.line(line);
+                }
+                self.indent -= 2;
+            }
+            Attribute::BootstrapMethods { methods, .. } => {
+                self.line("BootstrapMethods:");
+                self.indent += 1;
+                for (index, method) in methods.iter().enumerate() {
+                    let reference = method.bootstrap_method_ref;
+                    let value = self.constant_value(reference)?;
+                    self.line(format!("{index}: #{reference} {value}"));
+                    self.indent += 1;
+                    self.line("Method arguments:");
+                    self.indent += 1;
+                    for argument in &method.arguments {
+                        let value = self.constant_value(*argument)?;
+                        self.line(format!("#{argument} {value}"));
+                    }
+                    self.indent -= 2;
+                }
+                self.indent -= 1;
+            }
+            Attribute::MethodParameters { parameters, .. } => {
+                self.line("MethodParameters:");
+                self.indent += 1;
+                self.line(format!("{:<30} Flags", "Name"));
+                for parameter in parameters {
+                    let name = if parameter.name_index == 0 {
+                        "<no name>"
+                    } else {
+                        self.constant_pool.try_get_utf8(parameter.name_index)?
+                    };
+                    let mut flags = Vec::new();
+                    if parameter.access_flags.contains(MethodAccessFlags::FINAL) {
+                        flags.push("final");
+                    }
+                    if parameter
+                        .access_flags
+                        .contains(MethodAccessFlags::SYNTHETIC)
+                    {
+                        flags.push("synthetic");
+                    }
+                    self.line(format!("{name:<30} {}", flags.join(" ")));
+                }
+                self.indent -= 1;
+            }
+            Attribute::NestHost {
+                host_class_index, ..
+            } => {
+                let host_class = self.class_value(*host_class_index)?;
+                self.line(format!("NestHost: class {host_class}"));
+            }
+            Attribute::NestMembers { class_indexes, .. } => {
+                self.line("NestMembers:");
+                self.write_class_list(class_indexes)?;
+            }
+            Attribute::PermittedSubclasses { class_indexes, .. } => {
+                self.line("PermittedSubclasses:");
+                self.write_class_list(class_indexes)?;
+            }
+            Attribute::Record { records, .. } => {
+                self.line("Record:");
+                self.indent += 1;
+                for record in records {
+                    let name = self.constant_pool.try_get_utf8(record.name_index)?;
+                    let descriptor = self.constant_pool.try_get_utf8(record.descriptor_index)?;
+                    let record_type = FieldType::parse(descriptor)?.to_java_source();
+                    self.line(format!("{record_type} {name};"));
+                    self.indent += 1;
+                    self.line(format!("descriptor: {descriptor}"));
+                    self.write_attributes(&record.attributes)?;
+                    self.indent -= 1;
+                    self.line("");
+                }
+                self.indent -= 1;
+            }
+            Attribute::ModuleMainClass {
+                main_class_index, ..
+            } => {
+                let main_class = self.class_value(*main_class_index)?;
+                self.line_with_comment(format!("ModuleMainClass: #{main_class_index}"), main_class);
+            }
+            Attribute::ModulePackages {
+                package_indexes, ..
+            } => {
+                self.line("ModulePackages:");
+                self.indent += 1;
+                for package_index in package_indexes {
+                    let package = self.constant_value(*package_index)?;
+                    self.line_with_comment(format!("#{package_index}"), package);
+                }
+                self.indent -= 1;
+            }
+            Attribute::Module { name_index, .. }
+            | Attribute::RuntimeVisibleTypeAnnotations { name_index, .. }
+            | Attribute::RuntimeInvisibleTypeAnnotations { name_index, .. }
+            | Attribute::Unknown { name_index, .. }
+            | Attribute::Custom { name_index, .. } => {
+                let name = self.constant_pool.try_get_utf8(*name_index)?;
+                self.line(format!("{name}:"));
+                self.indent += 1;
+                for line in attribute.to_string().lines() {
+                    self.line(line);
+                }
+                self.indent -= 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_class_list(&mut self, class_indexes: &[u16]) -> Result<()> {
+        self.indent += 1;
+        for class_index in class_indexes {
+            let class_name = self.class_value(*class_index)?;
+            self.line(class_name);
+        }
+        self.indent -= 1;
+        Ok(())
+    }
+
+    fn write_stack_frame(&mut self, frame: &StackFrame) -> Result<()> {
+        let frame_type = frame.frame_type();
+        match frame {
+            StackFrame::SameFrame { .. } => {
+                self.line(format!("frame_type = {frame_type} /* same */"));
+            }
+            StackFrame::SameLocals1StackItemFrame { stack, .. } => {
+                self.line(format!(
+                    "frame_type = {frame_type} /* same_locals_1_stack_item */"
+                ));
+                self.indent += 1;
+                self.line(format!("stack = {}", self.verification_types(stack)?));
+                self.indent -= 1;
+            }
+            StackFrame::SameLocals1StackItemFrameExtended {
+                offset_delta,
+                stack,
+                ..
+            } => {
+                self.line(format!(
+                    "frame_type = {frame_type} /* same_locals_1_stack_item_frame_extended */"
+                ));
+                self.indent += 1;
+                self.line(format!("offset_delta = {offset_delta}"));
+                self.line(format!("stack = {}", self.verification_types(stack)?));
+                self.indent -= 1;
+            }
+            StackFrame::ChopFrame { offset_delta, .. } => {
+                self.line(format!("frame_type = {frame_type} /* chop */"));
+                self.indent += 1;
+                self.line(format!("offset_delta = {offset_delta}"));
+                self.indent -= 1;
+            }
+            StackFrame::SameFrameExtended { offset_delta, .. } => {
+                self.line(format!(
+                    "frame_type = {frame_type} /* same_frame_extended */"
+                ));
+                self.indent += 1;
+                self.line(format!("offset_delta = {offset_delta}"));
+                self.indent -= 1;
+            }
+            StackFrame::AppendFrame {
+                offset_delta,
+                locals,
+                ..
+            } => {
+                self.line(format!("frame_type = {frame_type} /* append */"));
+                self.indent += 1;
+                self.line(format!("offset_delta = {offset_delta}"));
+                self.line(format!("locals = {}", self.verification_types(locals)?));
+                self.indent -= 1;
+            }
+            StackFrame::FullFrame {
+                offset_delta,
+                locals,
+                stack,
+                ..
+            } => {
+                self.line(format!("frame_type = {frame_type} /* full_frame */"));
+                self.indent += 1;
+                self.line(format!("offset_delta = {offset_delta}"));
+                self.line(format!("locals = {}", self.verification_types(locals)?));
+                self.line(format!("stack = {}", self.verification_types(stack)?));
+                self.indent -= 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Render verification types as `[ int, class java/lang/String ]`, or `[]` when empty.
+    fn verification_types(&self, verification_types: &[VerificationType]) -> Result<String> {
+        if verification_types.is_empty() {
+            return Ok("[]".to_string());
+        }
+        let verification_types = verification_types
+            .iter()
+            .map(|verification_type| {
+                let value = match verification_type {
+                    VerificationType::Object { cpool_index } => {
+                        format!("class {}", self.class_value(*cpool_index)?)
+                    }
+                    VerificationType::UninitializedThis => "this".to_string(),
+                    _ => verification_type.to_string(),
+                };
+                Ok(value)
+            })
+            .collect::<Result<Vec<String>>>()?;
+        Ok(format!("[ {} ]", verification_types.join(", ")))
+    }
+
+    fn write_annotations(&mut self, annotations: &[Annotation]) -> Result<()> {
+        self.indent += 1;
+        for (index, annotation) in annotations.iter().enumerate() {
+            self.line(format!("{index}: {}", annotation_raw(annotation)));
+            self.indent += 1;
+            for line in self.annotation_lines(annotation)? {
+                self.line(line);
+            }
+            self.indent -= 1;
+        }
+        self.indent -= 1;
+        Ok(())
+    }
+
+    /// Render an annotation with its indexes resolved, e.g.
+    /// ```text
+    /// java.lang.annotation.Retention(
+    ///   value=Ljava/lang/annotation/RetentionPolicy;.RUNTIME
+    /// )
+    /// ```
+    fn annotation_lines(&self, annotation: &Annotation) -> Result<Vec<String>> {
+        let descriptor = self.constant_pool.try_get_utf8(annotation.type_index)?;
+        let type_name = FieldType::parse(descriptor)?.to_java_source();
+        if annotation.elements.is_empty() {
+            return Ok(vec![type_name]);
+        }
+
+        let mut lines = vec![format!("{type_name}(")];
+        for element in &annotation.elements {
+            let name = self.constant_pool.try_get_utf8(element.name_index)?;
+            let mut value_lines = self.element_value_lines(&element.value)?.into_iter();
+            let first = value_lines.next().unwrap_or_default();
+            lines.push(format!("  {name}={first}"));
+            lines.extend(value_lines.map(|line| format!("  {line}")));
+        }
+        lines.push(")".to_string());
+        Ok(lines)
+    }
+
+    fn element_value_lines(&self, element: &AnnotationElement) -> Result<Vec<String>> {
+        let value = match element {
+            AnnotationElement::Byte { const_value_index } => {
+                format!("(byte) {}", self.constant_value(*const_value_index)?)
+            }
+            AnnotationElement::Char { const_value_index } => {
+                let value = self.constant_pool.try_get_integer(*const_value_index)?;
+                let value = u32::try_from(*value)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .unwrap_or_default();
+                format!("'{}'", escape(&value.to_string()))
+            }
+            AnnotationElement::Short { const_value_index } => {
+                format!("(short) {}", self.constant_value(*const_value_index)?)
+            }
+            AnnotationElement::Boolean { const_value_index } => {
+                let value = self.constant_pool.try_get_integer(*const_value_index)?;
+                (*value != 0).to_string()
+            }
+            AnnotationElement::Double { const_value_index }
+            | AnnotationElement::Float { const_value_index }
+            | AnnotationElement::Int { const_value_index }
+            | AnnotationElement::Long { const_value_index } => {
+                self.constant_value(*const_value_index)?
+            }
+            AnnotationElement::String { const_value_index } => {
+                let value = self.constant_pool.try_get_utf8(*const_value_index)?;
+                format!("\"{}\"", escape(value))
+            }
+            AnnotationElement::Enum {
+                type_name_index,
+                const_name_index,
+            } => {
+                let type_name = self.constant_pool.try_get_utf8(*type_name_index)?;
+                let const_name = self.constant_pool.try_get_utf8(*const_name_index)?;
+                format!("{type_name}.{const_name}")
+            }
+            AnnotationElement::Class { class_info_index } => {
+                let class_info = self.constant_pool.try_get_utf8(*class_info_index)?;
+                format!("class {class_info}")
+            }
+            AnnotationElement::Annotation { annotation } => {
+                let mut lines = self.annotation_lines(annotation)?;
+                if let Some(first) = lines.first_mut() {
+                    first.insert(0, '@');
+                }
+                return Ok(lines);
+            }
+            AnnotationElement::Array { values } => {
+                // Multi-line values, such as nested annotations, continue on the line where the
+                // previous value ends.
+                let mut lines = vec!["[".to_string()];
+                for (index, value) in values.iter().enumerate() {
+                    let mut value_lines = self.element_value_lines(value)?.into_iter();
+                    if let Some(last) = lines.last_mut() {
+                        if index > 0 {
+                            last.push(',');
+                        }
+                        last.push_str(&value_lines.next().unwrap_or_default());
+                    }
+                    lines.extend(value_lines);
+                }
+                if let Some(last) = lines.last_mut() {
+                    last.push(']');
+                }
+                return Ok(lines);
+            }
+        };
+        Ok(vec![value])
+    }
+
+    /// Class name for comments; array classes and other names that are not valid identifiers
+    /// are quoted.
+    fn class_value(&self, index: u16) -> Result<String> {
+        let class_name = self.constant_pool.try_get_class(index)?;
+        Ok(check_name(class_name))
+    }
+
+    /// The value of a constant as shown in javap comments.
+    fn constant_value(&self, index: u16) -> Result<String> {
+        let value = match self.constant_pool.try_get(index)? {
+            Constant::Utf8(value) => escape(value),
+            Constant::Integer(value) => value.to_string(),
+            Constant::Float(value) => format!("{}f", java_float(*value)),
+            Constant::Long(value) => format!("{value}l"),
+            Constant::Double(value) => format!("{}d", java_double(*value)),
+            Constant::Class(_) => self.class_value(index)?,
+            Constant::String(string_index) => {
+                escape(self.constant_pool.try_get_utf8(*string_index)?)
+            }
+            Constant::FieldRef {
+                class_index,
+                name_and_type_index,
+            }
+            | Constant::MethodRef {
+                class_index,
+                name_and_type_index,
+            }
+            | Constant::InterfaceMethodRef {
+                class_index,
+                name_and_type_index,
+            } => {
+                let class_name = self.class_value(*class_index)?;
+                let name_and_type = self.constant_value(*name_and_type_index)?;
+                format!("{class_name}.{name_and_type}")
+            }
+            Constant::NameAndType {
+                name_index,
+                descriptor_index,
+            } => {
+                let name = self.constant_pool.try_get_utf8(*name_index)?;
+                let descriptor = self.constant_pool.try_get_utf8(*descriptor_index)?;
+                format!("{}:{descriptor}", check_name(name))
+            }
+            Constant::MethodHandle {
+                reference_kind,
+                reference_index,
+            } => {
+                let reference = self.constant_value(*reference_index)?;
+                format!("{} {reference}", reference_kind_name(reference_kind))
+            }
+            Constant::MethodType(descriptor_index) => {
+                self.constant_pool.try_get_utf8(*descriptor_index)?.clone()
+            }
+            Constant::Dynamic {
+                bootstrap_method_attr_index,
+                name_and_type_index,
+            }
+            | Constant::InvokeDynamic {
+                bootstrap_method_attr_index,
+                name_and_type_index,
+            } => {
+                let name_and_type = self.constant_value(*name_and_type_index)?;
+                format!("#{bootstrap_method_attr_index}:{name_and_type}")
+            }
+            Constant::Module(name_index) | Constant::Package(name_index) => {
+                check_name(self.constant_pool.try_get_utf8(*name_index)?)
+            }
+        };
+        Ok(value)
+    }
+}
+
+/// Render access flags as `(0x0021) ACC_PUBLIC, ACC_SUPER`; javap uses lowercase hex digits.
+fn flags<F: fmt::Display>(access_flags: &F) -> String {
+    let access_flags = access_flags.to_string();
+    match access_flags.split_once(')') {
+        Some((bits, names)) => format!("{}){names}", bits.to_lowercase()),
+        None => access_flags,
+    }
+}
+
+/// Tag name used in the constant pool listing.
+fn tag_name(constant: &Constant) -> &'static str {
+    match constant {
+        Constant::Utf8(_) => "Utf8",
+        Constant::Integer(_) => "Integer",
+        Constant::Float(_) => "Float",
+        Constant::Long(_) => "Long",
+        Constant::Double(_) => "Double",
+        Constant::Class(_) => "Class",
+        Constant::String(_) => "String",
+        Constant::FieldRef { .. } => "Fieldref",
+        Constant::MethodRef { .. } => "Methodref",
+        Constant::InterfaceMethodRef { .. } => "InterfaceMethodref",
+        Constant::NameAndType { .. } => "NameAndType",
+        Constant::MethodHandle { .. } => "MethodHandle",
+        Constant::MethodType(_) => "MethodType",
+        Constant::Dynamic { .. } => "Dynamic",
+        Constant::InvokeDynamic { .. } => "InvokeDynamic",
+        Constant::Module(_) => "Module",
+        Constant::Package(_) => "Package",
+    }
+}
+
+/// Tag name used when a constant is referenced from an instruction or attribute.
+fn comment_tag_name(constant: &Constant) -> &'static str {
+    match constant {
+        Constant::Utf8(_) => "asciz",
+        Constant::Integer(_) => "int",
+        Constant::Float(_) => "float",
+        Constant::Long(_) => "long",
+        Constant::Double(_) => "double",
+        Constant::Class(_) => "class",
+        Constant::String(_) => "String",
+        Constant::FieldRef { .. } => "Field",
+        Constant::MethodRef { .. } => "Method",
+        Constant::InterfaceMethodRef { .. } => "InterfaceMethod",
+        Constant::NameAndType { .. } => "NameAndType",
+        Constant::MethodHandle { .. } => "MethodHandle",
+        Constant::MethodType(_) => "MethodType",
+        Constant::Dynamic { .. } => "Dynamic",
+        Constant::InvokeDynamic { .. } => "InvokeDynamic",
+        Constant::Module(_) => "Module",
+        Constant::Package(_) => "Package",
+    }
+}
+
+fn reference_kind_name(reference_kind: &ReferenceKind) -> &'static str {
+    match reference_kind {
+        ReferenceKind::GetField => "REF_getField",
+        ReferenceKind::GetStatic => "REF_getStatic",
+        ReferenceKind::PutField => "REF_putField",
+        ReferenceKind::PutStatic => "REF_putStatic",
+        ReferenceKind::InvokeVirtual => "REF_invokeVirtual",
+        ReferenceKind::InvokeStatic => "REF_invokeStatic",
+        ReferenceKind::InvokeSpecial => "REF_invokeSpecial",
+        ReferenceKind::NewInvokeSpecial => "REF_newInvokeSpecial",
+        ReferenceKind::InvokeInterface => "REF_invokeInterface",
+    }
+}
+
+fn field_modifiers(access_flags: FieldAccessFlags) -> String {
+    let modifiers = [
+        (FieldAccessFlags::PUBLIC, "public "),
+        (FieldAccessFlags::PRIVATE, "private "),
+        (FieldAccessFlags::PROTECTED, "protected "),
+        (FieldAccessFlags::STATIC, "static "),
+        (FieldAccessFlags::FINAL, "final "),
+        (FieldAccessFlags::VOLATILE, "volatile "),
+        (FieldAccessFlags::TRANSIENT, "transient "),
+    ];
+    modifiers
+        .iter()
+        .filter(|(flag, _)| access_flags.contains(*flag))
+        .map(|(_, modifier)| *modifier)
+        .collect()
+}
+
+fn method_modifiers(access_flags: MethodAccessFlags) -> String {
+    let modifiers = [
+        (MethodAccessFlags::PUBLIC, "public "),
+        (MethodAccessFlags::PRIVATE, "private "),
+        (MethodAccessFlags::PROTECTED, "protected "),
+        (MethodAccessFlags::STATIC, "static "),
+        (MethodAccessFlags::FINAL, "final "),
+        (MethodAccessFlags::SYNCHRONIZED, "synchronized "),
+        (MethodAccessFlags::NATIVE, "native "),
+        (MethodAccessFlags::ABSTRACT, "abstract "),
+        (MethodAccessFlags::STRICT, "strictfp "),
+    ];
+    modifiers
+        .iter()
+        .filter(|(flag, _)| access_flags.contains(*flag))
+        .map(|(_, modifier)| *modifier)
+        .collect()
+}
+
+fn inner_class_modifiers(access_flags: NestedClassAccessFlags) -> String {
+    let mut modifiers = [
+        (NestedClassAccessFlags::PUBLIC, "public "),
+        (NestedClassAccessFlags::PRIVATE, "private "),
+        (NestedClassAccessFlags::PROTECTED, "protected "),
+        (NestedClassAccessFlags::STATIC, "static "),
+        (NestedClassAccessFlags::FINAL, "final "),
+        (NestedClassAccessFlags::ABSTRACT, "abstract "),
+    ]
+    .to_vec();
+    if access_flags.contains(NestedClassAccessFlags::INTERFACE) {
+        modifiers.retain(|(flag, _)| *flag != NestedClassAccessFlags::ABSTRACT);
+    }
+    modifiers
+        .iter()
+        .filter(|(flag, _)| access_flags.contains(*flag))
+        .map(|(_, modifier)| *modifier)
+        .collect()
+}
+
+/// Render an annotation with unresolved constant pool indexes, e.g. `#12(#7=e#13.#14)`.
+fn annotation_raw(annotation: &Annotation) -> String {
+    let elements = annotation
+        .elements
+        .iter()
+        .map(|element| {
+            format!(
+                "#{}={}",
+                element.name_index,
+                element_value_raw(&element.value)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(",");
+    format!("#{}({elements})", annotation.type_index)
+}
+
+fn element_value_raw(element: &AnnotationElement) -> String {
+    let tag = char::from(element.tag());
+    match element {
+        AnnotationElement::Byte { const_value_index }
+        | AnnotationElement::Char { const_value_index }
+        | AnnotationElement::Double { const_value_index }
+        | AnnotationElement::Float { const_value_index }
+        | AnnotationElement::Int { const_value_index }
+        | AnnotationElement::Long { const_value_index }
+        | AnnotationElement::Short { const_value_index }
+        | AnnotationElement::Boolean { const_value_index }
+        | AnnotationElement::String { const_value_index } => {
+            format!("{tag}#{const_value_index}")
+        }
+        AnnotationElement::Enum {
+            type_name_index,
+            const_name_index,
+        } => format!("{tag}#{type_name_index}.#{const_name_index}"),
+        AnnotationElement::Class { class_info_index } => format!("{tag}#{class_info_index}"),
+        AnnotationElement::Annotation { annotation } => {
+            format!("{tag}{}", annotation_raw(annotation))
+        }
+        AnnotationElement::Array { values } => {
+            let values = values
+                .iter()
+                .map(element_value_raw)
+                .collect::<Vec<String>>()
+                .join(",");
+            format!("[{values}]")
+        }
+    }
+}
+
+/// Convert an internal class name (`java/lang/Object`) to a Java name (`java.lang.Object`).
+fn java_name(name: &str) -> String {
+    name.replace('/', ".")
+}
+
+/// Quote names that are not a sequence of valid Java identifiers separated by `/`, such as
+/// `"<init>"` or `"[Ljava/lang/String;"`.
+fn check_name(name: &str) -> String {
+    let mut previous = '/';
+    for character in name.chars() {
+        let valid = if previous == '/' {
+            character.is_alphabetic() || character == '$' || character == '_'
+        } else {
+            character == '/' || character.is_alphanumeric() || character == '$' || character == '_'
+        };
+        if !valid {
+            return format!("\"{}\"", escape(name));
+        }
+        previous = character;
+    }
+    if name.is_empty() {
+        return "\"\"".to_string();
+    }
+    name.to_string()
+}
+
+/// Escape control characters and quotes the same way javap does.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\u{8}' => escaped.push_str("\\b"),
+            '\u{c}' => escaped.push_str("\\f"),
+            '"' => escaped.push_str("\\\""),
+            '\'' => escaped.push_str("\\'"),
+            '\\' => escaped.push_str("\\\\"),
+            character if character.is_control() => {
+                escaped = format!("{escaped}\\u{:04x}", u32::from(character));
+            }
+            character => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+/// Format a float the same way as Java's `Float.toString`.
+fn java_float(value: f32) -> String {
+    if value.is_nan() {
+        return "NaN".to_string();
+    }
+    if value.is_infinite() {
+        let sign = if value.is_sign_negative() { "-" } else { "" };
+        return format!("{sign}Infinity");
+    }
+    java_decimal(&format!("{value:e}"), f64::from(value.abs()))
+}
+
+/// Format a double the same way as Java's `Double.toString`.
+fn java_double(value: f64) -> String {
+    if value.is_nan() {
+        return "NaN".to_string();
+    }
+    if value.is_infinite() {
+        let sign = if value.is_sign_negative() { "-" } else { "" };
+        return format!("{sign}Infinity");
+    }
+    java_decimal(&format!("{value:e}"), value.abs())
+}
+
+/// Convert the shortest round-trip scientific representation of a finite value (e.g.
+/// `-1.25e-5`) to Java's notation: plain decimal for magnitudes in `[10^-3, 10^7)` and
+/// `d.dddE±n` otherwise.
+fn java_decimal(scientific: &str, magnitude: f64) -> String {
+    let (sign, scientific) = match scientific.strip_prefix('-') {
+        Some(scientific) => ("-", scientific),
+        None => ("", scientific),
+    };
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((scientific, "0"));
+    let exponent = exponent.parse::<i32>().unwrap_or_default();
+    let digits = mantissa.replace('.', "");
+
+    if magnitude == 0.0 {
+        return format!("{sign}0.0");
+    }
+    if (1.0e-3..1.0e7).contains(&magnitude) {
+        let integer_digits = exponent + 1;
+        let value = if integer_digits <= 0 {
+            let zeros = "0".repeat(usize::try_from(-integer_digits).unwrap_or_default());
+            format!("0.{zeros}{digits}")
+        } else {
+            let integer_digits = usize::try_from(integer_digits).unwrap_or_default();
+            if integer_digits >= digits.len() {
+                let zeros = "0".repeat(integer_digits - digits.len());
+                format!("{digits}{zeros}.0")
+            } else {
+                let (integer, fraction) = digits.split_at(integer_digits);
+                format!("{integer}.{fraction}")
+            }
+        };
+        return format!("{sign}{value}");
+    }
+
+    let (first, rest) = digits.split_at(1);
+    let rest = if rest.is_empty() { "0" } else { rest };
+    format!("{sign}{first}.{rest}E{exponent}")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_java_float() {
+        assert_eq!("8.0", java_float(8.0));
+        assert_eq!("1.0E-5", java_float(1.0e-5));
+        assert_eq!("0.001", java_float(0.001));
+        assert_eq!("1.0E7", java_float(1.0e7));
+        assert_eq!("-0.0", java_float(-0.0));
+        assert_eq!("NaN", java_float(f32::NAN));
+        assert_eq!("-Infinity", java_float(f32::NEG_INFINITY));
+    }
+
+    #[test]
+    fn test_java_double() {
+        assert_eq!("10.0", java_double(10.0));
+        assert_eq!("2.5", java_double(2.5));
+        assert_eq!("1.0E10", java_double(1.0e10));
+        assert_eq!("1.2345E-4", java_double(1.2345e-4));
+        assert_eq!("1234567.5", java_double(1_234_567.5));
+    }
+
+    #[test]
+    fn test_check_name() {
+        assert_eq!("java/lang/Object", check_name("java/lang/Object"));
+        assert_eq!("Features$1", check_name("Features$1"));
+        assert_eq!("\"<init>\"", check_name("<init>"));
+        assert_eq!("\"[[I\"", check_name("[[I"));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            "a\\tb\\n\\\"c\\\" \\'d\\' \\\\ \\u0001",
+            escape("a\tb\n\"c\" 'd' \\ \u{1}")
+        );
+    }
+}
@@ -0,0 +1,469 @@
+use crate::attributes::BootstrapMethod;
+use crate::builder::internal_name;
+use crate::class_file::ClassFile;
+use crate::error::Error::InvalidLambda;
+use crate::error::Result;
+use crate::field_type::FieldType;
+use crate::method_descriptor::MethodDescriptor;
+use crate::reference_kind::ReferenceKind;
+
+/// Class that bootstraps lambda expressions and method references.
+const LAMBDA_METAFACTORY: &str = "java/lang/invoke/LambdaMetafactory";
+const METAFACTORY_DESCRIPTOR: &str = "(Ljava/lang/invoke/MethodHandles$Lookup;\
+    Ljava/lang/String;Ljava/lang/invoke/MethodType;Ljava/lang/invoke/MethodType;\
+    Ljava/lang/invoke/MethodHandle;Ljava/lang/invoke/MethodType;)Ljava/lang/invoke/CallSite;";
+
+/// Method that implements a lambda.
+#[derive(Clone, Debug)]
+struct Implementation {
+    reference_kind: ReferenceKind,
+    owner: String,
+    name: String,
+    descriptor: String,
+}
+
+/// Helper that links a lambda expression or method reference with
+/// `java.lang.invoke.LambdaMetafactory.metafactory`, matching the class files generated by
+/// `javac`.
+///
+/// The functional interface method is implemented by a method handle to the implementation
+/// method.  The leading implementation parameters (including the receiver of instance methods)
+/// that are not supplied by the functional interface method are captured when the
+/// `invokedynamic` instruction is executed, and must be pushed onto the operand stack before it.
+/// Class names may be specified in either their binary (e.g. `java.util.function.Supplier`) or
+/// internal (e.g. `java/util/function/Supplier`) form.
+///
+/// # Examples
+///
+/// ```rust
+/// use ristretto_classfile::attributes::Instruction;
+/// use ristretto_classfile::{ClassFile, ConstantPool, LambdaMetafactory, ReferenceKind, Result};
+///
+/// fn main() -> Result<()> {
+///     let mut constant_pool = ConstantPool::default();
+///     let this_class = constant_pool.add_class("Foo")?;
+///     let mut class_file = ClassFile {
+///         constant_pool,
+///         this_class,
+///         ..Default::default()
+///     };
+///
+///     // Supplier<String> supplier = () -> lambda$main$0();
+///     let supplier = "java.util.function.Supplier";
+///     let index = LambdaMetafactory::new(supplier, "get", "()Ljava/lang/Object;")
+///         .implementation(
+///             ReferenceKind::InvokeStatic,
+///             "Foo",
+///             "lambda$main$0",
+///             "()Ljava/lang/String;",
+///         )
+///         .instantiated_method_type("()Ljava/lang/String;")
+///         .add_to(&mut class_file)?;
+///     let instruction = Instruction::Invokedynamic(index);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct LambdaMetafactory {
+    interface: String,
+    method_name: String,
+    method_descriptor: String,
+    instantiated_method_descriptor: Option<String>,
+    implementation: Option<Implementation>,
+    interface_owner: bool,
+}
+
+impl LambdaMetafactory {
+    /// Create a new helper for a lambda of the functional interface; the method name and the
+    /// erased method descriptor identify the abstract method of the interface.
+    pub fn new<S: AsRef<str>>(interface: S, method_name: S, method_descriptor: S) -> Self {
+        Self {
+            interface: internal_name(interface),
+            method_name: method_name.as_ref().to_string(),
+            method_descriptor: method_descriptor.as_ref().to_string(),
+            instantiated_method_descriptor: None,
+            implementation: None,
+            interface_owner: false,
+        }
+    }
+
+    /// Set the method that implements the lambda; the reference kind must be one of the invoke
+    /// reference kinds.
+    #[must_use]
+    pub fn implementation<S: AsRef<str>>(
+        mut self,
+        reference_kind: ReferenceKind,
+        owner: S,
+        name: S,
+        descriptor: S,
+    ) -> Self {
+        self.implementation = Some(Implementation {
+            reference_kind,
+            owner: internal_name(owner),
+            name: name.as_ref().to_string(),
+            descriptor: descriptor.as_ref().to_string(),
+        });
+        self
+    }
+
+    /// Set whether the owner of the implementation method is an interface; e.g. for the private
+    /// static methods generated for lambdas in interfaces.  Implementation methods invoked with
+    /// [`ReferenceKind::InvokeInterface`] are always owned by an interface.
+    #[must_use]
+    pub fn interface_owner(mut self, interface_owner: bool) -> Self {
+        self.interface_owner = interface_owner;
+        self
+    }
+
+    /// Set the method descriptor the functional interface method is specialized to; defaults to
+    /// the erased method descriptor.
+    #[must_use]
+    pub fn instantiated_method_type<S: AsRef<str>>(mut self, descriptor: S) -> Self {
+        self.instantiated_method_descriptor = Some(descriptor.as_ref().to_string());
+        self
+    }
+
+    /// Add the bootstrap method and the invoke dynamic constant of the lambda to the class and
+    /// return the constant pool index to use with the `invokedynamic` instruction.
+    ///
+    /// # Errors
+    /// Returns an error if the implementation method is not set, the reference kind does not
+    /// invoke a method, a descriptor is invalid, the implementation method does not accept the
+    /// parameters of the functional interface method, or the constants cannot be added.
+    pub fn add_to(&self, class_file: &mut ClassFile) -> Result<u16> {
+        let Some(implementation) = &self.implementation else {
+            return Err(InvalidLambda("implementation method not set".to_string()));
+        };
+        let captured = self.captured(implementation)?;
+        let instantiated_method_descriptor = self
+            .instantiated_method_descriptor
+            .as_ref()
+            .unwrap_or(&self.method_descriptor);
+
+        let constant_pool = &mut class_file.constant_pool;
+        let lambda_metafactory = constant_pool.add_class(LAMBDA_METAFACTORY)?;
+        let metafactory = constant_pool.add_method_ref(
+            lambda_metafactory,
+            "metafactory",
+            METAFACTORY_DESCRIPTOR,
+        )?;
+        let bootstrap_method_ref =
+            constant_pool.add_method_handle(ReferenceKind::InvokeStatic, metafactory)?;
+        let method_type = constant_pool.add_method_type(&self.method_descriptor)?;
+        let owner = constant_pool.add_class(&implementation.owner)?;
+        let implementation_ref = if self.interface_owner
+            || implementation.reference_kind == ReferenceKind::InvokeInterface
+        {
+            constant_pool.add_interface_method_ref(
+                owner,
+                &implementation.name,
+                &implementation.descriptor,
+            )?
+        } else {
+            constant_pool.add_method_ref(owner, &implementation.name, &implementation.descriptor)?
+        };
+        let implementation_handle = constant_pool
+            .add_method_handle(implementation.reference_kind.clone(), implementation_ref)?;
+        let instantiated_method_type =
+            constant_pool.add_method_type(instantiated_method_descriptor)?;
+        let bootstrap_method = BootstrapMethod {
+            bootstrap_method_ref,
+            arguments: vec![method_type, implementation_handle, instantiated_method_type],
+        };
+
+        let descriptor = MethodDescriptor {
+            parameters: captured,
+            return_type: Some(FieldType::Object(self.interface.clone())),
+        };
+        class_file.add_invoke_dynamic(
+            bootstrap_method,
+            self.method_name.as_str(),
+            descriptor.descriptor().as_str(),
+        )
+    }
+
+    /// Determine the types of the implementation parameters that are captured; the receiver of
+    /// an instance method is the first parameter of the implementation method.
+    fn captured(&self, implementation: &Implementation) -> Result<Vec<FieldType>> {
+        let mut parameters = match implementation.reference_kind {
+            ReferenceKind::InvokeStatic | ReferenceKind::NewInvokeSpecial => Vec::new(),
+            ReferenceKind::InvokeVirtual
+            | ReferenceKind::InvokeSpecial
+            | ReferenceKind::InvokeInterface => {
+                vec![FieldType::Object(implementation.owner.clone())]
+            }
+            ref reference_kind => {
+                return Err(InvalidLambda(format!(
+                    "{reference_kind} does not invoke a method"
+                )));
+            }
+        };
+        parameters.extend(MethodDescriptor::parse(&implementation.descriptor)?.parameters);
+        let arity = MethodDescriptor::parse(&self.method_descriptor)?.arity();
+        let Some(captured) = parameters.len().checked_sub(arity) else {
+            return Err(InvalidLambda(format!(
+                "{}.{}{} does not accept the parameters of {}.{}{}",
+                implementation.owner,
+                implementation.name,
+                implementation.descriptor,
+                self.interface,
+                self.method_name,
+                self.method_descriptor
+            )));
+        };
+        parameters.truncate(captured);
+        Ok(parameters)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::attributes::{Attribute, Instruction};
+    use crate::method::Method;
+    use crate::method_access_flags::MethodAccessFlags;
+    use crate::version::Version;
+
+    fn invoke_dynamic(class_file: &ClassFile, index: u16) -> Result<(u16, String, String)> {
+        let constant_pool = &class_file.constant_pool;
+        let (bootstrap_method_attr_index, name_and_type_index) =
+            constant_pool.try_get_invoke_dynamic(index)?;
+        let (name_index, descriptor_index) =
+            constant_pool.try_get_name_and_type(*name_and_type_index)?;
+        Ok((
+            *bootstrap_method_attr_index,
+            constant_pool.try_get_utf8(*name_index)?.clone(),
+            constant_pool.try_get_utf8(*descriptor_index)?.clone(),
+        ))
+    }
+
+    fn bootstrap_method(class_file: &ClassFile, index: u16) -> &BootstrapMethod {
+        let Some(Attribute::BootstrapMethods { methods, .. }) = class_file
+            .attributes
+            .iter()
+            .find(|attribute| matches!(attribute, Attribute::BootstrapMethods { .. }))
+        else {
+            panic!("expected BootstrapMethods attribute");
+        };
+        &methods[usize::from(index)]
+    }
+
+    fn method(
+        class_file: &mut ClassFile,
+        name: &str,
+        descriptor: &str,
+        max_locals: u16,
+        code: Vec<Instruction>,
+    ) -> Result<Method> {
+        let code_index = class_file.constant_pool.add_utf8("Code")?;
+        Ok(Method {
+            access_flags: MethodAccessFlags::PRIVATE | MethodAccessFlags::STATIC,
+            name_index: class_file.constant_pool.add_utf8(name)?,
+            descriptor_index: class_file.constant_pool.add_utf8(descriptor)?,
+            attributes: vec![Attribute::Code {
+                name_index: code_index,
+                max_stack: 1,
+                max_locals,
+                code,
+                exception_table: vec![],
+                attributes: vec![],
+            }],
+        })
+    }
+
+    #[test]
+    fn test_lambda() -> Result<()> {
+        let mut class_file = crate::test::class_file("Foo", Version::Java8 { minor: 0 })?;
+        let index =
+            LambdaMetafactory::new("java.util.function.Supplier", "get", "()Ljava/lang/Object;")
+                .implementation(
+                    ReferenceKind::InvokeStatic,
+                    "Foo",
+                    "lambda$supplier$0",
+                    "()Ljava/lang/String;",
+                )
+                .instantiated_method_type("()Ljava/lang/String;")
+                .add_to(&mut class_file)?;
+
+        let (bootstrap_method_attr_index, name, descriptor) = invoke_dynamic(&class_file, index)?;
+        assert_eq!(0, bootstrap_method_attr_index);
+        assert_eq!("get", name);
+        assert_eq!("()Ljava/util/function/Supplier;", descriptor);
+
+        let bootstrap_method = bootstrap_method(&class_file, bootstrap_method_attr_index).clone();
+        let constant_pool = &class_file.constant_pool;
+        let (reference_kind, metafactory) =
+            constant_pool.try_get_method_handle(bootstrap_method.bootstrap_method_ref)?;
+        assert_eq!(&ReferenceKind::InvokeStatic, reference_kind);
+        let (class_index, _) = constant_pool.try_get_method_ref(*metafactory)?;
+        assert_eq!(
+            LAMBDA_METAFACTORY,
+            constant_pool.try_get_class(*class_index)?
+        );
+        let [method_type, implementation, instantiated_method_type] =
+            bootstrap_method.arguments[..]
+        else {
+            panic!("expected 3 bootstrap method arguments");
+        };
+        let method_type = constant_pool.try_get_method_type(method_type)?;
+        assert_eq!(
+            "()Ljava/lang/Object;",
+            constant_pool.try_get_utf8(*method_type)?
+        );
+        let (reference_kind, _) = constant_pool.try_get_method_handle(implementation)?;
+        assert_eq!(&ReferenceKind::InvokeStatic, reference_kind);
+        let instantiated_method_type =
+            constant_pool.try_get_method_type(instantiated_method_type)?;
+        assert_eq!(
+            "()Ljava/lang/String;",
+            constant_pool.try_get_utf8(*instantiated_method_type)?
+        );
+
+        let string = class_file.constant_pool.add_string("foo")?;
+        let lambda = method(
+            &mut class_file,
+            "lambda$supplier$0",
+            "()Ljava/lang/String;",
+            0,
+            vec![
+                Instruction::Ldc(u8::try_from(string)?),
+                Instruction::Areturn,
+            ],
+        )?;
+        let supplier = method(
+            &mut class_file,
+            "supplier",
+            "()Ljava/util/function/Supplier;",
+            0,
+            vec![Instruction::Invokedynamic(index), Instruction::Areturn],
+        )?;
+        class_file.methods = vec![lambda, supplier];
+        class_file.verify()
+    }
+
+    #[test]
+    fn test_lambda_captured() -> Result<()> {
+        let mut class_file = crate::test::class_file("Foo", Version::Java8 { minor: 0 })?;
+        let index = LambdaMetafactory::new(
+            "java/util/function/Function",
+            "apply",
+            "(Ljava/lang/Object;)Ljava/lang/Object;",
+        )
+        .implementation(
+            ReferenceKind::InvokeStatic,
+            "Foo",
+            "lambda$function$0",
+            "(ILjava/lang/String;)Ljava/lang/String;",
+        )
+        .instantiated_method_type("(Ljava/lang/String;)Ljava/lang/String;")
+        .add_to(&mut class_file)?;
+        let (_, name, descriptor) = invoke_dynamic(&class_file, index)?;
+        assert_eq!("apply", name);
+        assert_eq!("(I)Ljava/util/function/Function;", descriptor);
+        Ok(())
+    }
+
+    #[test]
+    fn test_method_reference() -> Result<()> {
+        let mut class_file = crate::test::class_file("Foo", Version::Java8 { minor: 0 })?;
+        // Function<String, Integer> function = String::length;
+        let unbound = LambdaMetafactory::new(
+            "java/util/function/Function",
+            "apply",
+            "(Ljava/lang/Object;)Ljava/lang/Object;",
+        )
+        .implementation(
+            ReferenceKind::InvokeVirtual,
+            "java/lang/String",
+            "length",
+            "()I",
+        )
+        .instantiated_method_type("(Ljava/lang/String;)Ljava/lang/Integer;")
+        .add_to(&mut class_file)?;
+        // Supplier<Integer> supplier = string::length;
+        let bound =
+            LambdaMetafactory::new("java/util/function/Supplier", "get", "()Ljava/lang/Object;")
+                .implementation(
+                    ReferenceKind::InvokeVirtual,
+                    "java/lang/String",
+                    "length",
+                    "()I",
+                )
+                .instantiated_method_type("()Ljava/lang/Integer;")
+                .add_to(&mut class_file)?;
+
+        let (unbound_bootstrap_method, _, descriptor) = invoke_dynamic(&class_file, unbound)?;
+        assert_eq!("()Ljava/util/function/Function;", descriptor);
+        let (bound_bootstrap_method, _, descriptor) = invoke_dynamic(&class_file, bound)?;
+        assert_eq!(
+            "(Ljava/lang/String;)Ljava/util/function/Supplier;",
+            descriptor
+        );
+        assert_eq!(0, unbound_bootstrap_method);
+        assert_eq!(1, bound_bootstrap_method);
+        Ok(())
+    }
+
+    #[test]
+    fn test_interface_owner() -> Result<()> {
+        let mut class_file = crate::test::class_file("Foo", Version::Java8 { minor: 0 })?;
+        let index = LambdaMetafactory::new("java/lang/Runnable", "run", "()V")
+            .implementation(ReferenceKind::InvokeStatic, "Foo", "lambda$run$0", "()V")
+            .interface_owner(true)
+            .add_to(&mut class_file)?;
+        let (bootstrap_method_attr_index, _, descriptor) = invoke_dynamic(&class_file, index)?;
+        assert_eq!("()Ljava/lang/Runnable;", descriptor);
+        let bootstrap_method = bootstrap_method(&class_file, bootstrap_method_attr_index);
+        let (_, reference_index) = class_file
+            .constant_pool
+            .try_get_method_handle(bootstrap_method.arguments[1])?;
+        assert!(class_file
+            .constant_pool
+            .try_get_interface_method_ref(*reference_index)
+            .is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_implementation_not_set() -> Result<()> {
+        let mut class_file = crate::test::class_file("Foo", Version::Java8 { minor: 0 })?;
+        let result =
+            LambdaMetafactory::new("java/lang/Runnable", "run", "()V").add_to(&mut class_file);
+        assert!(matches!(result, Err(InvalidLambda(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_reference_kind() -> Result<()> {
+        let mut class_file = crate::test::class_file("Foo", Version::Java8 { minor: 0 })?;
+        let result = LambdaMetafactory::new("java/lang/Runnable", "run", "()V")
+            .implementation(ReferenceKind::GetField, "Foo", "bar", "()V")
+            .add_to(&mut class_file);
+        assert!(matches!(result, Err(InvalidLambda(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_parameters() -> Result<()> {
+        let mut class_file = crate::test::class_file("Foo", Version::Java8 { minor: 0 })?;
+        let result = LambdaMetafactory::new(
+            "java/util/function/Function",
+            "apply",
+            "(Ljava/lang/Object;)Ljava/lang/Object;",
+        )
+        .implementation(
+            ReferenceKind::InvokeStatic,
+            "Foo",
+            "lambda$function$0",
+            "()Ljava/lang/Object;",
+        )
+        .add_to(&mut class_file);
+        assert_eq!(
+            Err("Invalid lambda: Foo.lambda$function$0()Ljava/lang/Object; does not accept the \
+                parameters of java/util/function/Function.apply(Ljava/lang/Object;)Ljava/lang/Object;"
+                .to_string()),
+            result.map_err(|error| error.to_string())
+        );
+        Ok(())
+    }
+}
@@ -31,6 +31,15 @@
 //! }
 //! ```
 //!
+//! ## Feature flags
+//!
+//! The following features are available:
+//!
+//! | Name    | Description                                                         | Default? |
+//! |---------|---------------------------------------------------------------------|----------|
+//! | `serde` | Enables serialization and deserialization of class files with serde | No       |
+//! | `tokio` | Enables reading and writing class files with tokio async I/O        | No       |
+//!
 //! ## Safety
 //!
 //! This crate uses `#![forbid(unsafe_code)]` to ensure everything is implemented in 100% safe Rust.
@@ -45,32 +54,77 @@ extern crate core;
 
 pub mod attributes;
 mod base_type;
+mod builder;
 mod class_access_flags;
 mod class_file;
+mod class_file_builder;
 mod constant;
 mod constant_pool;
+mod dead_code;
+mod debug_info_builder;
 mod display;
+mod downgrade;
 mod error;
+mod exception_table_builder;
 mod field;
 mod field_access_flags;
 mod field_type;
+mod inner_classes_builder;
+mod javap;
+mod lambda_metafactory;
+mod merger;
 mod method;
 mod method_access_flags;
+mod method_descriptor;
+mod metrics;
+mod module_info_builder;
 pub mod mutf8;
+mod nest_builder;
+mod peephole;
+mod record_builder;
 mod reference_kind;
+mod references;
+mod remapper;
+pub mod signature;
+mod source_map;
+mod synthetic;
+#[cfg(test)]
+pub(crate) mod test;
 mod verifiers;
 mod version;
+mod visitor;
 
 pub use base_type::BaseType;
 pub use class_access_flags::ClassAccessFlags;
 pub use class_file::ClassFile;
+pub use class_file_builder::ClassFileBuilder;
 pub use constant::Constant;
 pub use constant_pool::ConstantPool;
+pub use dead_code::DeadCode;
+pub use debug_info_builder::{LineNumberTableBuilder, LocalVariableTableBuilder};
+pub use downgrade::DowngradeIssue;
 pub use error::{Error, Result};
+pub use exception_table_builder::ExceptionTableBuilder;
 pub use field::Field;
 pub use field_access_flags::FieldAccessFlags;
 pub use field_type::FieldType;
+pub use inner_classes_builder::InnerClassesBuilder;
+pub use lambda_metafactory::LambdaMetafactory;
+pub use merger::{ClassMerger, MemberKind, MergeConflict, MergedMember};
 pub use method::Method;
 pub use method_access_flags::MethodAccessFlags;
+pub use method_descriptor::MethodDescriptor;
+pub use metrics::{AttributeMetrics, ClassMetrics, ConstantPoolMetrics, MethodMetrics};
+pub use module_info_builder::ModuleInfoBuilder;
+pub use nest_builder::NestBuilder;
+pub use record_builder::RecordBuilder;
 pub use reference_kind::ReferenceKind;
+pub use remapper::Remapper;
+pub use source_map::{LineMapping, SourceFile, SourceMap, SourceMapBuilder, Stratum};
+pub use synthetic::Accessor;
+pub use verifiers::bytecode::class_resolver::{ClassResolver, ResolvedClass};
+pub use verifiers::bytecode::diagnostics::{BasicBlock, VerificationFailure, VerificationReport};
+pub use verifiers::bytecode::inference::{Frame, Type as VerificationType};
+pub use verifiers::profile::VerificationProfile;
 pub use version::{Version, JAVA_PREVIEW_MINOR_VERSION};
+pub use visitor::{ClassVisitor, MethodVisitor};
@@ -0,0 +1,859 @@
+use crate::attributes::{Attribute, BootstrapMethod, CodeEditor, Instruction};
+use crate::class_file::ClassFile;
+use crate::constant::Constant;
+use crate::constant_pool::ConstantPool;
+use crate::error::Result;
+use crate::method::Method;
+use crate::references::{for_each_field_reference, for_each_method_reference};
+use crate::remapper::Remapper;
+use crate::Error::{InvalidBootstrapMethodIndex, MemberConflict};
+use std::collections::{HashMap, HashSet};
+
+/// Suffix added to the name of a member renamed to resolve a name clash
+const RENAME_SUFFIX: &str = "$merged";
+
+/// Resolution of a member of the source class with the same name and descriptor as a member of
+/// the target class.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MergeConflict {
+    /// Return a [`MemberConflict`](crate::Error::MemberConflict) error
+    #[default]
+    Error,
+    /// Keep the member of the target class; references to the member by the copied members refer
+    /// to the member of the target class
+    Keep,
+    /// Replace the member of the target class with the member of the source class
+    Replace,
+    /// Copy the member of the source class with a name that does not clash; e.g. `foo$merged`
+    Rename,
+}
+
+/// Kind of a class member.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MemberKind {
+    Field,
+    Method,
+}
+
+/// A member copied by a [`ClassMerger`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MergedMember {
+    pub kind: MemberKind,
+    pub name: String,
+    pub descriptor: String,
+    /// Name of the member in the target class; differs from `name` if the member was renamed
+    pub target_name: String,
+    /// Returns true if the member replaced a member of the target class
+    pub replaced: bool,
+}
+
+/// Copies fields and methods from a source class into a target class; e.g. to patch a class with
+/// the members of a mixin class.
+///
+/// References to the source class by the copied members are rewritten to refer to the target
+/// class, and the constants that the members depend on, including bootstrap methods, are copied
+/// into the constant pool of the target class.  Members with the same name and descriptor as a
+/// member of the target class are resolved with the [`MergeConflict`] strategy.  Constructors and
+/// static initializers are not copied.  The constant pool of the target class is compacted and the
+/// target class is verified after the members are copied.
+///
+/// # Examples
+///
+/// ```rust
+/// use ristretto_classfile::{
+///     ClassFile, ClassMerger, ConstantPool, Field, FieldAccessFlags, FieldType, MergeConflict,
+///     Version,
+/// };
+///
+/// let mut constant_pool = ConstantPool::default();
+/// let this_class = constant_pool.add_class("Mixin")?;
+/// let super_class = constant_pool.add_class("java/lang/Object")?;
+/// let name_index = constant_pool.add_utf8("count")?;
+/// let descriptor_index = constant_pool.add_utf8("I")?;
+/// let mixin = ClassFile {
+///     version: Version::Java8 { minor: 0 },
+///     constant_pool,
+///     this_class,
+///     super_class,
+///     fields: vec![Field {
+///         access_flags: FieldAccessFlags::PRIVATE,
+///         name_index,
+///         descriptor_index,
+///         field_type: FieldType::parse(&"I".to_string())?,
+///         attributes: Vec::new(),
+///     }],
+///     ..Default::default()
+/// };
+///
+/// let mut constant_pool = ConstantPool::default();
+/// let this_class = constant_pool.add_class("Foo")?;
+/// let super_class = constant_pool.add_class("java/lang/Object")?;
+/// let mut class_file = ClassFile {
+///     version: Version::Java8 { minor: 0 },
+///     constant_pool,
+///     this_class,
+///     super_class,
+///     ..Default::default()
+/// };
+///
+/// let merger = ClassMerger::new().conflict(MergeConflict::Rename);
+/// let merged = merger.merge(&mut class_file, &mixin)?;
+/// assert_eq!("count", merged[0].target_name);
+/// assert_eq!(1, class_file.fields.len());
+/// # Ok::<(), ristretto_classfile::Error>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClassMerger {
+    conflict: MergeConflict,
+    fields: HashSet<String>,
+    methods: HashSet<(String, String)>,
+}
+
+impl ClassMerger {
+    /// Create a new merger that copies every field and method and returns an error for name
+    /// clashes.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the resolution of name clashes.
+    #[must_use]
+    pub fn conflict(mut self, conflict: MergeConflict) -> Self {
+        self.conflict = conflict;
+        self
+    }
+
+    /// Copy the field with the given name.  If any fields or methods are selected, only the
+    /// selected members are copied.
+    #[must_use]
+    pub fn field<S: AsRef<str>>(mut self, name: S) -> Self {
+        self.fields.insert(name.as_ref().to_string());
+        self
+    }
+
+    /// Copy the method with the given name and descriptor.  If any fields or methods are
+    /// selected, only the selected members are copied.
+    #[must_use]
+    pub fn method<S: AsRef<str>>(mut self, name: S, descriptor: S) -> Self {
+        self.methods
+            .insert((name.as_ref().to_string(), descriptor.as_ref().to_string()));
+        self
+    }
+
+    fn is_selected(&self, kind: MemberKind, name: &str, descriptor: &str) -> bool {
+        if self.fields.is_empty() && self.methods.is_empty() {
+            return true;
+        }
+        match kind {
+            MemberKind::Field => self.fields.contains(name),
+            MemberKind::Method => self
+                .methods
+                .contains(&(name.to_string(), descriptor.to_string())),
+        }
+    }
+
+    /// Copy the members of the source class into the target class and return the copied members.
+    ///
+    /// # Errors
+    /// Returns an error if a member clashes with a member of the target class and the conflict
+    /// resolution is [`MergeConflict::Error`], if either class contains an invalid constant pool
+    /// reference or an unknown attribute, or if the merged class cannot be verified.
+    pub fn merge(&self, target: &mut ClassFile, source: &ClassFile) -> Result<Vec<MergedMember>> {
+        target.decode_lazy_attributes()?;
+        let source_class = source.class_name()?.clone();
+        let target_class = target.class_name()?.clone();
+
+        let target_fields = members(&target.constant_pool, &target.fields, |field| {
+            (field.name_index, field.descriptor_index)
+        })?;
+        let source_fields = members(&source.constant_pool, &source.fields, |field| {
+            (field.name_index, field.descriptor_index)
+        })?;
+        let mut resolved = self.resolve(
+            MemberKind::Field,
+            &target_class,
+            &target_fields,
+            source_fields,
+        )?;
+        let target_methods = members(&target.constant_pool, &target.methods, |method| {
+            (method.name_index, method.descriptor_index)
+        })?;
+        let source_methods = members(&source.constant_pool, &source.methods, |method| {
+            (method.name_index, method.descriptor_index)
+        })?;
+        resolved.extend(self.resolve(
+            MemberKind::Method,
+            &target_class,
+            &target_methods,
+            source_methods,
+        )?);
+
+        let mut remapper = Remapper::new().class(&source_class, &target_class);
+        for Merge { member, .. } in &resolved {
+            if member.target_name == member.name {
+                continue;
+            }
+            remapper = match member.kind {
+                MemberKind::Field => {
+                    remapper.field(&source_class, &member.name, &member.target_name)
+                }
+                MemberKind::Method => remapper.method(
+                    &source_class,
+                    &member.name,
+                    &member.descriptor,
+                    &member.target_name,
+                ),
+            };
+        }
+        let mut source = source.clone();
+        remapper.remap(&mut source)?;
+
+        let mut importer = Importer {
+            source: &source.constant_pool,
+            source_bootstrap_methods: bootstrap_methods(&source.attributes),
+            target: &mut target.constant_pool,
+            bootstrap_methods_offset: bootstrap_methods(&target.attributes).len(),
+            bootstrap_methods: Vec::new(),
+            constants: HashMap::new(),
+            bootstrap_method_indexes: HashMap::new(),
+        };
+        let mut import = |index: &mut u16| -> Result<()> {
+            *index = importer.import(*index)?;
+            Ok(())
+        };
+        let mut ldc_instructions = Vec::new();
+        let mut members = Vec::new();
+        for Merge {
+            source_index,
+            replace,
+            member,
+        } in resolved
+        {
+            if member.kind == MemberKind::Field {
+                let mut field = source.fields[source_index].clone();
+                for_each_field_reference(&source.constant_pool, &mut field, &mut import)?;
+                if let Some(index) = replace {
+                    target.fields[index] = field;
+                } else {
+                    target.fields.push(field);
+                }
+            } else {
+                let mut method = source.methods[source_index].clone();
+                let positions = widen_ldc(&mut method);
+                for_each_method_reference(&source.constant_pool, &mut method, &mut import)?;
+                let index = if let Some(index) = replace {
+                    target.methods[index] = method;
+                    index
+                } else {
+                    target.methods.push(method);
+                    target.methods.len() - 1
+                };
+                ldc_instructions.push((index, positions));
+            }
+            members.push(member);
+        }
+        let bootstrap_methods = importer.bootstrap_methods;
+
+        add_bootstrap_methods(target, bootstrap_methods)?;
+        target.compact_constant_pool()?;
+        for (index, positions) in ldc_instructions {
+            narrow_ldc(&mut target.methods[index], &positions)?;
+        }
+        target.verify()?;
+        Ok(members)
+    }
+
+    /// Resolve the fields or methods of the source class that are copied.  Constructors and static
+    /// initializers are not copied.
+    fn resolve(
+        &self,
+        kind: MemberKind,
+        target_class: &str,
+        target_members: &[(String, String)],
+        source_members: Vec<(String, String)>,
+    ) -> Result<Vec<Merge>> {
+        let mut names = target_members
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect::<HashSet<_>>();
+        let mut resolved = Vec::new();
+        for (source_index, (name, descriptor)) in source_members.into_iter().enumerate() {
+            if name == "<init>" || name == "<clinit>" || !self.is_selected(kind, &name, &descriptor)
+            {
+                continue;
+            }
+            let existing = target_members
+                .iter()
+                .position(|(target_name, target_descriptor)| {
+                    *target_name == name && *target_descriptor == descriptor
+                });
+            let (target_name, replace) = match (existing, self.conflict) {
+                (None, _) => (name.clone(), None),
+                (Some(_), MergeConflict::Error) => {
+                    let separator = if kind == MemberKind::Field { ":" } else { "" };
+                    return Err(MemberConflict(format!(
+                        "{target_class}.{name}{separator}{descriptor}"
+                    )));
+                }
+                (Some(_), MergeConflict::Keep) => continue,
+                (Some(index), MergeConflict::Replace) => (name.clone(), Some(index)),
+                (Some(_), MergeConflict::Rename) => (unique_name(&name, &names), None),
+            };
+            names.insert(target_name.clone());
+            resolved.push(Merge {
+                source_index,
+                replace,
+                member: MergedMember {
+                    kind,
+                    name,
+                    descriptor,
+                    target_name,
+                    replaced: replace.is_some(),
+                },
+            });
+        }
+        Ok(resolved)
+    }
+}
+
+/// A member of the source class to copy into the target class.
+#[derive(Debug)]
+struct Merge {
+    source_index: usize,
+    /// Index of the member of the target class to replace
+    replace: Option<usize>,
+    member: MergedMember,
+}
+
+/// Get the names and descriptors of fields or methods.
+fn members<T>(
+    constant_pool: &ConstantPool,
+    members: &[T],
+    indexes: impl Fn(&T) -> (u16, u16),
+) -> Result<Vec<(String, String)>> {
+    members
+        .iter()
+        .map(|member| {
+            let (name_index, descriptor_index) = indexes(member);
+            Ok((
+                constant_pool.try_get_utf8(name_index)?.clone(),
+                constant_pool.try_get_utf8(descriptor_index)?.clone(),
+            ))
+        })
+        .collect()
+}
+
+/// Get a name that is not in use by adding a suffix to a name.
+fn unique_name(name: &str, names: &HashSet<String>) -> String {
+    let mut unique_name = format!("{name}{RENAME_SUFFIX}");
+    let mut count = 1;
+    while names.contains(&unique_name) {
+        unique_name = format!("{name}{RENAME_SUFFIX}{count}");
+        count += 1;
+    }
+    unique_name
+}
+
+fn bootstrap_methods(attributes: &[Attribute]) -> &[BootstrapMethod] {
+    attributes
+        .iter()
+        .find_map(|attribute| match attribute {
+            Attribute::BootstrapMethods { methods, .. } => Some(methods.as_slice()),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+/// Add bootstrap methods to the `BootstrapMethods` attribute of a class, adding the attribute if
+/// the class does not have one.
+fn add_bootstrap_methods(
+    class_file: &mut ClassFile,
+    bootstrap_methods: Vec<BootstrapMethod>,
+) -> Result<()> {
+    if bootstrap_methods.is_empty() {
+        return Ok(());
+    }
+    let attribute = class_file
+        .attributes
+        .iter_mut()
+        .find(|attribute| matches!(attribute, Attribute::BootstrapMethods { .. }));
+    if let Some(Attribute::BootstrapMethods { methods, .. }) = attribute {
+        methods.extend(bootstrap_methods);
+    } else {
+        let name_index = class_file.constant_pool.add_utf8("BootstrapMethods")?;
+        class_file.attributes.push(Attribute::BootstrapMethods {
+            name_index,
+            methods: bootstrap_methods,
+        });
+    }
+    Ok(())
+}
+
+/// Copies constants and the bootstrap methods they refer to from a source constant pool to a
+/// target constant pool.  Constants are copied once; duplicate constants are merged when the
+/// target constant pool is compacted.
+struct Importer<'a> {
+    source: &'a ConstantPool,
+    source_bootstrap_methods: &'a [BootstrapMethod],
+    target: &'a mut ConstantPool,
+    /// Number of bootstrap methods of the target class before the import
+    bootstrap_methods_offset: usize,
+    bootstrap_methods: Vec<BootstrapMethod>,
+    constants: HashMap<u16, u16>,
+    bootstrap_method_indexes: HashMap<u16, u16>,
+}
+
+impl Importer<'_> {
+    /// Copy a constant and the constants it refers to; returns the index in the target constant
+    /// pool.
+    fn import(&mut self, index: u16) -> Result<u16> {
+        if index == 0 {
+            return Ok(0);
+        }
+        if let Some(target_index) = self.constants.get(&index) {
+            return Ok(*target_index);
+        }
+        let constant = match self.source.try_get(index)?.clone() {
+            Constant::Class(name_index) => Constant::Class(self.import(name_index)?),
+            Constant::String(string_index) => Constant::String(self.import(string_index)?),
+            Constant::FieldRef {
+                class_index,
+                name_and_type_index,
+            } => Constant::FieldRef {
+                class_index: self.import(class_index)?,
+                name_and_type_index: self.import(name_and_type_index)?,
+            },
+            Constant::MethodRef {
+                class_index,
+                name_and_type_index,
+            } => Constant::MethodRef {
+                class_index: self.import(class_index)?,
+                name_and_type_index: self.import(name_and_type_index)?,
+            },
+            Constant::InterfaceMethodRef {
+                class_index,
+                name_and_type_index,
+            } => Constant::InterfaceMethodRef {
+                class_index: self.import(class_index)?,
+                name_and_type_index: self.import(name_and_type_index)?,
+            },
+            Constant::NameAndType {
+                name_index,
+                descriptor_index,
+            } => Constant::NameAndType {
+                name_index: self.import(name_index)?,
+                descriptor_index: self.import(descriptor_index)?,
+            },
+            Constant::MethodHandle {
+                reference_kind,
+                reference_index,
+            } => Constant::MethodHandle {
+                reference_kind,
+                reference_index: self.import(reference_index)?,
+            },
+            Constant::MethodType(descriptor_index) => {
+                Constant::MethodType(self.import(descriptor_index)?)
+            }
+            Constant::Dynamic {
+                bootstrap_method_attr_index,
+                name_and_type_index,
+            } => Constant::Dynamic {
+                bootstrap_method_attr_index: self
+                    .import_bootstrap_method(bootstrap_method_attr_index)?,
+                name_and_type_index: self.import(name_and_type_index)?,
+            },
+            Constant::InvokeDynamic {
+                bootstrap_method_attr_index,
+                name_and_type_index,
+            } => Constant::InvokeDynamic {
+                bootstrap_method_attr_index: self
+                    .import_bootstrap_method(bootstrap_method_attr_index)?,
+                name_and_type_index: self.import(name_and_type_index)?,
+            },
+            Constant::Module(name_index) => Constant::Module(self.import(name_index)?),
+            Constant::Package(name_index) => Constant::Package(self.import(name_index)?),
+            constant => constant,
+        };
+        let target_index = self.target.add(constant)?;
+        self.constants.insert(index, target_index);
+        Ok(target_index)
+    }
+
+    /// Copy a bootstrap method; returns the index in the bootstrap methods of the target class.
+    fn import_bootstrap_method(&mut self, index: u16) -> Result<u16> {
+        if let Some(target_index) = self.bootstrap_method_indexes.get(&index) {
+            return Ok(*target_index);
+        }
+        let bootstrap_method = self
+            .source_bootstrap_methods
+            .get(usize::from(index))
+            .ok_or(InvalidBootstrapMethodIndex(usize::from(index)))?;
+        let mut arguments = Vec::with_capacity(bootstrap_method.arguments.len());
+        for argument in &bootstrap_method.arguments {
+            arguments.push(self.import(*argument)?);
+        }
+        let bootstrap_method = BootstrapMethod {
+            bootstrap_method_ref: self.import(bootstrap_method.bootstrap_method_ref)?,
+            arguments,
+        };
+        let target_index =
+            u16::try_from(self.bootstrap_methods_offset + self.bootstrap_methods.len())?;
+        self.bootstrap_methods.push(bootstrap_method);
+        self.bootstrap_method_indexes.insert(index, target_index);
+        Ok(target_index)
+    }
+}
+
+/// Replace the `ldc` instructions of a method with `ldc_w`, since the index of the imported
+/// constant may not fit in a byte until the constant pool is compacted; returns the instruction
+/// indexes of the replaced instructions.
+fn widen_ldc(method: &mut Method) -> Vec<usize> {
+    let mut positions = Vec::new();
+    for attribute in &mut method.attributes {
+        if let Attribute::Code { code, .. } = attribute {
+            for (position, instruction) in code.iter_mut().enumerate() {
+                if let Instruction::Ldc(index) = instruction {
+                    *instruction = Instruction::Ldc_w(u16::from(*index));
+                    positions.push(position);
+                }
+            }
+        }
+    }
+    positions
+}
+
+/// Restore the `ldc` instructions replaced by [`widen_ldc`].  Instructions with constant indexes
+/// that do not fit in a byte remain `ldc_w` instructions, and the offsets of the instructions
+/// that follow are updated.
+fn narrow_ldc(method: &mut Method, positions: &[usize]) -> Result<()> {
+    let Some(attribute) = method
+        .attributes
+        .iter_mut()
+        .find(|attribute| matches!(attribute, Attribute::Code { .. }))
+    else {
+        return Ok(());
+    };
+    let Attribute::Code { code, .. } = attribute else {
+        return Ok(());
+    };
+    let mut wide_instructions = Vec::new();
+    for position in positions {
+        if let Some(Instruction::Ldc_w(index)) = code.get(*position) {
+            let index = *index;
+            // Restore the original instruction length before any instructions are widened
+            code[*position] = Instruction::Ldc(u8::try_from(index).unwrap_or_default());
+            if index > u16::from(u8::MAX) {
+                wide_instructions.push((*position, index));
+            }
+        }
+    }
+    if wide_instructions.is_empty() {
+        return Ok(());
+    }
+
+    let mut editor = CodeEditor::new(attribute)?;
+    for (position, index) in wide_instructions {
+        editor.replace(position..=position, vec![Instruction::Ldc_w(index)])?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::reference_kind::ReferenceKind;
+    use crate::{MethodAccessFlags, Version};
+    use std::io::Cursor;
+
+    fn class_file(class_bytes: &[u8]) -> Result<ClassFile> {
+        ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))
+    }
+
+    fn simple() -> Result<ClassFile> {
+        class_file(include_bytes!("../../classes/Simple.class"))
+    }
+
+    fn minimum() -> Result<ClassFile> {
+        class_file(include_bytes!("../../classes/Minimum.class"))
+    }
+
+    fn method_names(class_file: &ClassFile) -> Result<Vec<&String>> {
+        class_file
+            .methods
+            .iter()
+            .map(|method| class_file.constant_pool.try_get_utf8(method.name_index))
+            .collect()
+    }
+
+    fn code(method: &Method) -> &Vec<Instruction> {
+        method
+            .attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::Code { code, .. } => Some(code),
+                _ => None,
+            })
+            .expect("code")
+    }
+
+    /// Create a class with a static method `test` with the given code.
+    fn code_class_file(
+        mut constant_pool: ConstantPool,
+        class_name: &str,
+        code: Vec<Instruction>,
+        attributes: Vec<Attribute>,
+    ) -> Result<ClassFile> {
+        let this_class = constant_pool.add_class(class_name)?;
+        let super_class = constant_pool.add_class("java/lang/Object")?;
+        let code_index = constant_pool.add_utf8("Code")?;
+        let name_index = constant_pool.add_utf8("test")?;
+        let descriptor_index = constant_pool.add_utf8("()V")?;
+        Ok(ClassFile {
+            version: Version::Java8 { minor: 0 },
+            constant_pool,
+            this_class,
+            super_class,
+            methods: vec![Method {
+                access_flags: MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                name_index,
+                descriptor_index,
+                attributes: vec![Attribute::Code {
+                    name_index: code_index,
+                    max_stack: 1,
+                    max_locals: 0,
+                    code,
+                    exception_table: Vec::new(),
+                    attributes: Vec::new(),
+                }],
+            }],
+            attributes,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_merge() -> Result<()> {
+        let mut class_file = minimum()?;
+        let members = ClassMerger::new().merge(&mut class_file, &simple()?)?;
+
+        let field_count = members
+            .iter()
+            .filter(|member| member.kind == MemberKind::Field)
+            .count();
+        assert_eq!(class_file.fields.len(), field_count);
+        assert_eq!(14, field_count);
+        assert!(members
+            .iter()
+            .all(|member| member.name == member.target_name));
+        assert_eq!(
+            vec![
+                "<init>",
+                "getAnswer",
+                "getPublicValue",
+                "setPublicValue",
+                "getProtectedValue",
+                "getDefaultValue",
+                "getPrivateValue",
+                "main"
+            ],
+            method_names(&class_file)?
+        );
+
+        // References to the source class refer to the target class
+        let method = &class_file.methods[2];
+        let Some(Instruction::Getfield(index)) = code(method).get(1) else {
+            panic!("Expected getfield instruction");
+        };
+        let (class_index, _) = class_file.constant_pool.try_get_field_ref(*index)?;
+        assert_eq!(
+            "Minimum",
+            class_file.constant_pool.try_get_class(*class_index)?
+        );
+
+        let mut bytes = Vec::new();
+        class_file.to_bytes(&mut bytes)?;
+        let class_file = ClassFile::from_bytes(&mut Cursor::new(bytes))?;
+        assert_eq!(8, class_file.methods.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_conflict_error() -> Result<()> {
+        let mut class_file = simple()?;
+        let result = ClassMerger::new().merge(&mut class_file, &simple()?);
+        assert_eq!(Err(MemberConflict("Simple.BOOLEAN:Z".to_string())), result);
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_conflict_keep() -> Result<()> {
+        let mut class_file = simple()?;
+        let merger = ClassMerger::new().conflict(MergeConflict::Keep);
+        assert!(merger.merge(&mut class_file, &simple()?)?.is_empty());
+        assert_eq!(simple()?.methods.len(), class_file.methods.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_conflict_replace() -> Result<()> {
+        let mut class_file = simple()?;
+        let merger = ClassMerger::new()
+            .conflict(MergeConflict::Replace)
+            .method("getAnswer", "()I");
+        let members = merger.merge(&mut class_file, &simple()?)?;
+        assert_eq!(1, members.len());
+        assert!(members[0].replaced);
+        assert_eq!(simple()?.methods.len(), class_file.methods.len());
+        class_file.verify()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_conflict_rename() -> Result<()> {
+        let mut class_file = simple()?;
+        let merger = ClassMerger::new()
+            .conflict(MergeConflict::Rename)
+            .field("publicValue")
+            .method("getPublicValue", "()I");
+        let members = merger.merge(&mut class_file, &simple()?)?;
+        let target_names = members
+            .iter()
+            .map(|member| member.target_name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec!["publicValue$merged", "getPublicValue$merged"],
+            target_names
+        );
+
+        // The renamed method refers to the renamed field
+        let method = class_file.methods.last().expect("method");
+        let Some(Instruction::Getfield(index)) = code(method).get(1) else {
+            panic!("Expected getfield instruction");
+        };
+        let (_, name_and_type_index) = class_file.constant_pool.try_get_field_ref(*index)?;
+        let (name_index, _) = class_file
+            .constant_pool
+            .try_get_name_and_type(*name_and_type_index)?;
+        assert_eq!(
+            "publicValue$merged",
+            class_file.constant_pool.try_get_utf8(*name_index)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_unique_name() {
+        let names = HashSet::from(["foo$merged".to_string(), "foo$merged1".to_string()]);
+        assert_eq!("bar$merged", unique_name("bar", &names));
+        assert_eq!("foo$merged2", unique_name("foo", &names));
+    }
+
+    #[test]
+    fn test_merge_bootstrap_methods() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let factory = constant_pool.add_class("java/lang/invoke/LambdaMetafactory")?;
+        let method_ref = constant_pool.add_method_ref(
+            factory,
+            "metafactory",
+            "(Ljava/lang/invoke/MethodHandles$Lookup;Ljava/lang/String;Ljava/lang/invoke/MethodType;Ljava/lang/invoke/MethodType;Ljava/lang/invoke/MethodHandle;Ljava/lang/invoke/MethodType;)Ljava/lang/invoke/CallSite;",
+        )?;
+        let method_handle =
+            constant_pool.add_method_handle(ReferenceKind::InvokeStatic, method_ref)?;
+        let method_type = constant_pool.add_method_type("()V")?;
+        let invoke_dynamic =
+            constant_pool.add_invoke_dynamic(0, "run", "()Ljava/lang/Runnable;")?;
+        let attribute_name_index = constant_pool.add_utf8("BootstrapMethods")?;
+        let source = code_class_file(
+            constant_pool,
+            "Mixin",
+            vec![
+                Instruction::Invokedynamic(invoke_dynamic),
+                Instruction::Pop,
+                Instruction::Return,
+            ],
+            vec![Attribute::BootstrapMethods {
+                name_index: attribute_name_index,
+                methods: vec![BootstrapMethod {
+                    bootstrap_method_ref: method_handle,
+                    arguments: vec![method_type],
+                }],
+            }],
+        )?;
+        let mut class_file = minimum()?;
+        ClassMerger::new().merge(&mut class_file, &source)?;
+
+        let Some(Instruction::Invokedynamic(index)) = code(&class_file.methods[1]).first() else {
+            panic!("Expected invokedynamic instruction");
+        };
+        let (bootstrap_method_index, _) =
+            class_file.constant_pool.try_get_invoke_dynamic(*index)?;
+        let bootstrap_methods = bootstrap_methods(&class_file.attributes);
+        let bootstrap_method = &bootstrap_methods[usize::from(*bootstrap_method_index)];
+        let (_, reference_index) = class_file
+            .constant_pool
+            .try_get_method_handle(bootstrap_method.bootstrap_method_ref)?;
+        let (_, name_and_type_index) = class_file
+            .constant_pool
+            .try_get_method_ref(*reference_index)?;
+        let (name_index, _) = class_file
+            .constant_pool
+            .try_get_name_and_type(*name_and_type_index)?;
+        assert_eq!(
+            "metafactory",
+            class_file.constant_pool.try_get_utf8(*name_index)?
+        );
+        let method_type = bootstrap_method.arguments[0];
+        let descriptor_index = class_file.constant_pool.try_get_method_type(method_type)?;
+        assert_eq!(
+            "()V",
+            class_file.constant_pool.try_get_utf8(*descriptor_index)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_ldc() -> Result<()> {
+        // The target class uses more than 256 constants, so the imported string constant does not
+        // fit in the index of an ldc instruction
+        let mut constant_pool = ConstantPool::default();
+        let mut instructions = Vec::new();
+        for value in 0..300 {
+            instructions.push(Instruction::Ldc_w(
+                constant_pool.add_string(value.to_string())?,
+            ));
+            instructions.push(Instruction::Pop);
+        }
+        instructions.push(Instruction::Return);
+        let mut target = code_class_file(constant_pool, "Target", instructions, Vec::new())?;
+
+        let mut constant_pool = ConstantPool::default();
+        let string = u8::try_from(constant_pool.add_string("foo")?)?;
+        let source = code_class_file(
+            constant_pool,
+            "Mixin",
+            vec![
+                Instruction::Ldc(string),
+                Instruction::Pop,
+                Instruction::Return,
+            ],
+            Vec::new(),
+        )?;
+
+        let merger = ClassMerger::new().conflict(MergeConflict::Rename);
+        merger.merge(&mut target, &source)?;
+        let Some(Instruction::Ldc_w(index)) = code(&target.methods[1]).first() else {
+            panic!("Expected ldc_w instruction");
+        };
+        assert_eq!("foo", target.constant_pool.try_get_string(*index)?);
+
+        // An ldc instruction that fits is restored
+        let mut target = minimum()?;
+        merger.merge(&mut target, &source)?;
+        assert!(matches!(
+            code(&target.methods[1]).first(),
+            Some(Instruction::Ldc(_))
+        ));
+        Ok(())
+    }
+}
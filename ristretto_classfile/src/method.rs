@@ -1,9 +1,14 @@
-use crate::attributes::Attribute;
+use crate::attributes::{Annotation, Attribute};
+use crate::class_file::ClassFile;
 use crate::constant_pool::ConstantPool;
 use crate::display::indent_lines;
 use crate::error::Result;
 use crate::method_access_flags::MethodAccessFlags;
+use crate::verifiers::bytecode::class_resolver::{ClassResolver, ResolvedClass};
+use crate::verifiers::bytecode::inference::{self, Frame};
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fmt;
 use std::io::Cursor;
 
@@ -11,6 +16,7 @@ use std::io::Cursor;
 ///
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.6>
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Method {
     pub access_flags: MethodAccessFlags,
     pub name_index: u16,
@@ -24,6 +30,16 @@ impl Method {
     /// # Errors
     /// Returns an error if the bytes do not represent a valid Method.
     pub fn from_bytes(constant_pool: &ConstantPool, bytes: &mut Cursor<Vec<u8>>) -> Result<Method> {
+        Self::read(constant_pool, bytes, false)
+    }
+
+    /// Deserialize the `Method` from bytes; if `lazy` is true, the `Code` and annotation attributes
+    /// are not decoded.
+    pub(crate) fn read(
+        constant_pool: &ConstantPool,
+        bytes: &mut Cursor<Vec<u8>>,
+        lazy: bool,
+    ) -> Result<Method> {
         let access_flags = MethodAccessFlags::from_bytes(bytes)?;
         let name_index = bytes.read_u16::<BigEndian>()?;
         let descriptor_index = bytes.read_u16::<BigEndian>()?;
@@ -31,7 +47,7 @@ impl Method {
         let attribute_count = bytes.read_u16::<BigEndian>()?;
         let mut attributes = Vec::with_capacity(attribute_count as usize);
         for _ in 0..attribute_count {
-            let attribute = Attribute::from_bytes(constant_pool, bytes)?;
+            let attribute = Attribute::read(constant_pool, bytes, lazy)?;
             attributes.push(attribute);
         }
 
@@ -60,6 +76,68 @@ impl Method {
         }
         Ok(())
     }
+
+    /// Get the runtime visible annotations of the method.
+    ///
+    /// # Errors
+    /// If an annotation attribute cannot be decoded.
+    pub fn annotations(&self, constant_pool: &ConstantPool) -> Result<Vec<Annotation>> {
+        Annotation::runtime_visible(constant_pool, &self.attributes)
+    }
+
+    /// Infer the types of the local variables and operand stack before each instruction of the
+    /// method, as computed by the bytecode verifier.  The result contains one entry per
+    /// instruction index; `None` for unreachable instructions.  Use
+    /// [`InstructionOffsets`](crate::attributes::InstructionOffsets) to find the instruction
+    /// index of a byte offset (`pc`).  Methods without a `Code` attribute return no frames.
+    ///
+    /// Reference types are merged without class hierarchy information; when two different
+    /// classes meet at a branch target the frame records `java/lang/Object`.  Use
+    /// [`analyze_with_resolver`](Method::analyze_with_resolver) to merge reference types to their
+    /// common super class.
+    ///
+    /// # Errors
+    /// Returns an error if the method references invalid constants, the operand stack underflows
+    /// or has inconsistent types at a merge point, or the method uses subroutines (`jsr`/`ret`).
+    pub fn analyze(&self, class_file: &ClassFile) -> Result<Vec<Option<Frame>>> {
+        self.analyze_with_resolver(class_file, &HashMap::<String, ResolvedClass>::new())
+    }
+
+    /// Infer the types of the local variables and operand stack before each instruction of the
+    /// method, using the class hierarchy supplied by the resolver to merge reference types.
+    ///
+    /// # Errors
+    /// Returns an error if the method references invalid constants, the operand stack underflows
+    /// or has inconsistent types at a merge point, or the method uses subroutines (`jsr`/`ret`).
+    pub fn analyze_with_resolver(
+        &self,
+        class_file: &ClassFile,
+        resolver: &dyn ClassResolver,
+    ) -> Result<Vec<Option<Frame>>> {
+        if !self
+            .attributes
+            .iter()
+            .any(|attribute| matches!(attribute, Attribute::Lazy { .. }))
+        {
+            return inference::infer(class_file, self, resolver);
+        }
+        let attributes = self
+            .attributes
+            .iter()
+            .map(|attribute| {
+                attribute
+                    .decode(&class_file.constant_pool)
+                    .map(Cow::into_owned)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let method = Method {
+            access_flags: self.access_flags,
+            name_index: self.name_index,
+            descriptor_index: self.descriptor_index,
+            attributes,
+        };
+        inference::infer(class_file, &method, resolver)
+    }
 }
 
 impl fmt::Display for Method {
@@ -78,7 +156,8 @@ impl fmt::Display for Method {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::attributes::Attribute;
+    use crate::attributes::{Attribute, Instruction};
+    use crate::verifiers::bytecode::inference::Type as VerificationType;
     use indoc::indoc;
 
     #[test]
@@ -108,6 +187,94 @@ mod test {
         assert_eq!(expected, method.to_string());
     }
 
+    #[test]
+    fn test_analyze() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let this_class = constant_pool.add_class("Foo")?;
+        let code_index = constant_pool.add_utf8("Code")?;
+        let method = Method {
+            access_flags: MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+            name_index: constant_pool.add_utf8("foo")?,
+            descriptor_index: constant_pool.add_utf8("(Z)I")?,
+            attributes: vec![Attribute::Code {
+                name_index: code_index,
+                max_stack: 1,
+                max_locals: 1,
+                code: vec![
+                    Instruction::Iload_0,
+                    Instruction::Ifeq(4),
+                    Instruction::Iconst_1,
+                    Instruction::Ireturn,
+                    Instruction::Iconst_0,
+                    Instruction::Ireturn,
+                    Instruction::Nop,
+                ],
+                exception_table: Vec::new(),
+                attributes: Vec::new(),
+            }],
+        };
+        let class_file = ClassFile {
+            constant_pool,
+            this_class,
+            methods: vec![method.clone()],
+            ..Default::default()
+        };
+
+        let frames = method.analyze(&class_file)?;
+        assert_eq!(7, frames.len());
+        let frame = |locals: Vec<VerificationType>, stack: Vec<VerificationType>| {
+            Some(Frame { locals, stack })
+        };
+        assert_eq!(frame(vec![VerificationType::Integer], vec![]), frames[0]);
+        assert_eq!(
+            frame(
+                vec![VerificationType::Integer],
+                vec![VerificationType::Integer]
+            ),
+            frames[1]
+        );
+        assert_eq!(frame(vec![VerificationType::Integer], vec![]), frames[4]);
+        assert_eq!(None, frames[6]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze_lazy() -> Result<()> {
+        let bytes = include_bytes!("../../classes/Simple.class").to_vec();
+        let class_file = ClassFile::from_bytes_lazy(&mut Cursor::new(bytes))?;
+        let method = &class_file.methods[0];
+        assert_eq!(
+            "<init>",
+            class_file.constant_pool.try_get_utf8(method.name_index)?
+        );
+        let frames = method.analyze(&class_file)?;
+        assert!(!frames.is_empty());
+        assert_eq!(
+            Some(&vec![VerificationType::UninitializedThis]),
+            frames[0].as_ref().map(|frame| &frame.locals)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze_without_code() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let this_class = constant_pool.add_class("Foo")?;
+        let method = Method {
+            access_flags: MethodAccessFlags::PUBLIC | MethodAccessFlags::ABSTRACT,
+            name_index: constant_pool.add_utf8("foo")?,
+            descriptor_index: constant_pool.add_utf8("()V")?,
+            attributes: Vec::new(),
+        };
+        let class_file = ClassFile {
+            constant_pool,
+            this_class,
+            ..Default::default()
+        };
+        assert!(method.analyze(&class_file)?.is_empty());
+        Ok(())
+    }
+
     #[test]
     fn test_serialization() -> Result<()> {
         let mut constant_pool = ConstantPool::default();
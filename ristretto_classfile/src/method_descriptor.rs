@@ -0,0 +1,211 @@
+use crate::error::Result;
+use crate::field_type::FieldType;
+use crate::Error::InvalidMethodDescriptor;
+use std::fmt;
+
+/// Implementation of `MethodDescriptor`.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.3.3>
+#[derive(Clone, Debug, PartialEq)]
+pub struct MethodDescriptor {
+    /// The parameter types, in declaration order.
+    pub parameters: Vec<FieldType>,
+    /// The return type; `None` represents `void`.
+    pub return_type: Option<FieldType>,
+}
+
+impl MethodDescriptor {
+    /// Return the number of parameters.
+    #[must_use]
+    pub fn arity(&self) -> usize {
+        self.parameters.len()
+    }
+
+    /// Return the number of local variable slots used by the parameters, not including `this` for
+    /// instance methods.
+    #[must_use]
+    pub fn parameters_size(&self) -> usize {
+        self.parameters.iter().map(FieldType::slot_size).sum()
+    }
+
+    /// Return the number of operand stack words used by the return value; 0 for `void`.
+    #[must_use]
+    pub fn return_size(&self) -> usize {
+        self.return_type.as_ref().map_or(0, FieldType::slot_size)
+    }
+
+    /// Return the descriptor for the `MethodDescriptor`.
+    #[must_use]
+    pub fn descriptor(&self) -> String {
+        let parameters: String = self.parameters.iter().map(FieldType::descriptor).collect();
+        let return_type = self
+            .return_type
+            .as_ref()
+            .map_or("V".to_string(), FieldType::descriptor);
+        format!("({parameters}){return_type}")
+    }
+
+    /// Return the Java source representation of a method with the name and this descriptor; e.g.
+    /// `void foo(int, java.lang.String[])`.  Descriptors do not record whether a method has
+    /// variable arity, so variable arity parameters are represented as arrays.
+    #[must_use]
+    pub fn to_java_source(&self, name: &str) -> String {
+        format!(
+            "{} {name}({})",
+            self.return_type_source(),
+            self.parameters_source()
+        )
+    }
+
+    /// Return the Java source representation of the parameters; e.g. `int, java.lang.String`.
+    fn parameters_source(&self) -> String {
+        self.parameters
+            .iter()
+            .map(FieldType::to_java_source)
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    /// Return the Java source representation of the return type; e.g. `void`.
+    fn return_type_source(&self) -> String {
+        self.return_type
+            .as_ref()
+            .map_or("void".to_string(), FieldType::to_java_source)
+    }
+
+    /// Parse a method descriptor; e.g. `(Ljava/lang/String;I)V`.
+    ///
+    /// # Errors
+    /// Returns an error if the descriptor is invalid.
+    pub fn parse(descriptor: &str) -> Result<MethodDescriptor> {
+        let invalid = || InvalidMethodDescriptor(descriptor.to_string());
+        let (parameters, return_type) = descriptor
+            .strip_prefix('(')
+            .and_then(|descriptor| descriptor.split_once(')'))
+            .ok_or_else(invalid)?;
+
+        let mut parameter_types = Vec::new();
+        let mut chars = parameters.char_indices().peekable();
+        while let Some((start, _)) = chars.peek().copied() {
+            let mut end = start;
+            while let Some((index, character)) = chars.next() {
+                end = index + character.len_utf8();
+                match character {
+                    '[' => {}
+                    'L' => {
+                        for (index, character) in chars.by_ref() {
+                            end = index + character.len_utf8();
+                            if character == ';' {
+                                break;
+                            }
+                        }
+                        break;
+                    }
+                    _ => break,
+                }
+            }
+            let parameter =
+                FieldType::parse(&parameters[start..end].to_string()).map_err(|_| invalid())?;
+            parameter_types.push(parameter);
+        }
+
+        let return_type = if return_type == "V" {
+            None
+        } else {
+            Some(FieldType::parse(&return_type.to_string()).map_err(|_| invalid())?)
+        };
+        Ok(MethodDescriptor {
+            parameters: parameter_types,
+            return_type,
+        })
+    }
+}
+
+impl fmt::Display for MethodDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "({}) -> {}",
+            self.parameters_source(),
+            self.return_type_source()
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::BaseType;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let descriptor = MethodDescriptor::parse("(I[[JLjava/lang/String;D)V")?;
+        assert_eq!(
+            vec![
+                FieldType::Base(BaseType::Int),
+                FieldType::Array(Box::new(FieldType::Array(Box::new(FieldType::Base(
+                    BaseType::Long
+                ))))),
+                FieldType::Object("java/lang/String".to_string()),
+                FieldType::Base(BaseType::Double),
+            ],
+            descriptor.parameters
+        );
+        assert!(descriptor.return_type.is_none());
+        assert_eq!(4, descriptor.arity());
+        assert_eq!(5, descriptor.parameters_size());
+        assert_eq!(0, descriptor.return_size());
+        assert_eq!("(I[[JLjava/lang/String;D)V", descriptor.descriptor());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_return_type() -> Result<()> {
+        let descriptor = MethodDescriptor::parse("()[Ljava/lang/Object;")?;
+        assert!(descriptor.parameters.is_empty());
+        assert_eq!(
+            Some(FieldType::Array(Box::new(FieldType::Object(
+                "java/lang/Object".to_string()
+            )))),
+            descriptor.return_type
+        );
+        assert_eq!(0, descriptor.arity());
+        assert_eq!(0, descriptor.parameters_size());
+        assert_eq!(1, descriptor.return_size());
+
+        let descriptor = MethodDescriptor::parse("(JJ)J")?;
+        assert_eq!(4, descriptor.parameters_size());
+        assert_eq!(2, descriptor.return_size());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for descriptor in ["", "I", "(I", "()", "(V)V", "(Ljava/lang/String)V", "()Q"] {
+            assert_eq!(
+                Err(InvalidMethodDescriptor(descriptor.to_string())),
+                MethodDescriptor::parse(descriptor),
+                "{descriptor}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_string() -> Result<()> {
+        let descriptor = MethodDescriptor::parse("(Ljava/lang/String;I)V")?;
+        assert_eq!("(java.lang.String, int) -> void", descriptor.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_java_source() -> Result<()> {
+        let descriptor = MethodDescriptor::parse("(I[Ljava/lang/String;)V")?;
+        assert_eq!(
+            "void foo(int, java.lang.String[])",
+            descriptor.to_java_source("foo")
+        );
+        let descriptor = MethodDescriptor::parse("()[[J")?;
+        assert_eq!("long[][] bar()", descriptor.to_java_source("bar"));
+        Ok(())
+    }
+}
@@ -0,0 +1,424 @@
+use crate::attributes::{Attribute, Instruction};
+use crate::class_file::ClassFile;
+use crate::constant::Constant;
+use crate::constant_pool::ConstantPool;
+use crate::error::Result;
+use crate::method::Method;
+use crate::version::Version;
+use std::collections::BTreeMap;
+
+/// Size and composition statistics of a class; see [`ClassFile::metrics`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClassMetrics {
+    /// Internal name of the class, e.g. `java/lang/Object`
+    pub class_name: String,
+    /// Version of the class file
+    pub version: Version,
+    /// Size of the serialized class file in bytes
+    pub size: usize,
+    /// Statistics of the constant pool
+    pub constant_pool: ConstantPoolMetrics,
+    /// Number of fields
+    pub fields: usize,
+    /// Statistics of each method, in declaration order
+    pub methods: Vec<MethodMetrics>,
+    /// Statistics of the class, field, method and code attributes, by attribute name
+    pub attributes: Vec<AttributeMetrics>,
+}
+
+impl ClassMetrics {
+    /// Get the total length of the bytecode of all methods in bytes.
+    #[must_use]
+    pub fn code_length(&self) -> usize {
+        self.methods.iter().map(|method| method.code_length).sum()
+    }
+
+    /// Get the highest cyclomatic complexity of the methods.
+    #[must_use]
+    pub fn max_cyclomatic_complexity(&self) -> usize {
+        self.methods
+            .iter()
+            .map(|method| method.cyclomatic_complexity)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+/// Size and composition statistics of a constant pool.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConstantPoolMetrics {
+    /// Number of constants; `Long` and `Double` constants are counted once
+    pub constants: usize,
+    /// Size of the serialized constant pool in bytes, including the count
+    pub size: usize,
+    /// Size of the modified UTF-8 encoded `Utf8` constants in bytes
+    pub utf8_size: usize,
+    /// Number of constants of each kind, by kind name, e.g. `Utf8` or `MethodRef`
+    pub kinds: BTreeMap<String, usize>,
+}
+
+/// Size and complexity statistics of a method.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MethodMetrics {
+    /// Name of the method, e.g. `<init>`
+    pub name: String,
+    /// Descriptor of the method, e.g. `(I)V`
+    pub descriptor: String,
+    /// Length of the bytecode in bytes; zero for abstract and native methods
+    pub code_length: usize,
+    /// Number of instructions
+    pub instructions: usize,
+    /// Maximum depth of the operand stack
+    pub max_stack: u16,
+    /// Number of local variable slots
+    pub max_locals: u16,
+    /// Number of exception table entries
+    pub exception_handlers: usize,
+    /// Cyclomatic complexity: one plus the number of conditional branches, `switch` cases and
+    /// exception handlers; zero for abstract and native methods
+    pub cyclomatic_complexity: usize,
+    /// Statistics of the method and code attributes, by attribute name
+    pub attributes: Vec<AttributeMetrics>,
+}
+
+/// Statistics of the attributes with a name.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AttributeMetrics {
+    /// Name of the attribute, e.g. `LineNumberTable`
+    pub name: String,
+    /// Number of attributes
+    pub count: usize,
+    /// Size of the serialized attributes in bytes, including the name index and length; the size
+    /// of `Code` attributes includes the attributes of the code
+    pub size: usize,
+}
+
+/// Get the metrics of a class; see [`ClassFile::metrics`].
+pub(crate) fn metrics(class_file: &ClassFile) -> Result<ClassMetrics> {
+    let constant_pool = &class_file.constant_pool;
+    let mut bytes = Vec::new();
+    class_file.to_bytes(&mut bytes)?;
+
+    let mut attributes = BTreeMap::new();
+    for attribute in class_file
+        .attributes
+        .iter()
+        .chain(class_file.fields.iter().flat_map(|field| &field.attributes))
+    {
+        add_attribute(constant_pool, &mut attributes, attribute)?;
+    }
+    let mut methods = Vec::with_capacity(class_file.methods.len());
+    for method in &class_file.methods {
+        let method_metrics = method_metrics(constant_pool, method)?;
+        for attribute in &method_metrics.attributes {
+            let metrics =
+                attributes
+                    .entry(attribute.name.clone())
+                    .or_insert_with(|| AttributeMetrics {
+                        name: attribute.name.clone(),
+                        ..Default::default()
+                    });
+            metrics.count += attribute.count;
+            metrics.size += attribute.size;
+        }
+        methods.push(method_metrics);
+    }
+
+    Ok(ClassMetrics {
+        class_name: class_file.class_name()?.clone(),
+        version: class_file.version.clone(),
+        size: bytes.len(),
+        constant_pool: constant_pool_metrics(constant_pool)?,
+        fields: class_file.fields.len(),
+        methods,
+        attributes: attributes.into_values().collect(),
+    })
+}
+
+/// Get the metrics of a constant pool.
+fn constant_pool_metrics(constant_pool: &ConstantPool) -> Result<ConstantPoolMetrics> {
+    let mut bytes = Vec::new();
+    constant_pool.to_bytes(&mut bytes)?;
+    let mut metrics = ConstantPoolMetrics {
+        size: bytes.len(),
+        ..Default::default()
+    };
+    for (_, constant) in constant_pool {
+        metrics.constants += 1;
+        *metrics
+            .kinds
+            .entry(constant_kind(constant).to_string())
+            .or_default() += 1;
+        if let Constant::Utf8(value) = constant {
+            metrics.utf8_size += crate::mutf8::to_bytes(value)?.len();
+        }
+    }
+    Ok(metrics)
+}
+
+/// Get the name of the kind of constant.
+fn constant_kind(constant: &Constant) -> &'static str {
+    match constant {
+        Constant::Utf8(_) => "Utf8",
+        Constant::Integer(_) => "Integer",
+        Constant::Float(_) => "Float",
+        Constant::Long(_) => "Long",
+        Constant::Double(_) => "Double",
+        Constant::Class(_) => "Class",
+        Constant::String(_) => "String",
+        Constant::FieldRef { .. } => "FieldRef",
+        Constant::MethodRef { .. } => "MethodRef",
+        Constant::InterfaceMethodRef { .. } => "InterfaceMethodRef",
+        Constant::NameAndType { .. } => "NameAndType",
+        Constant::MethodHandle { .. } => "MethodHandle",
+        Constant::MethodType(_) => "MethodType",
+        Constant::Dynamic { .. } => "Dynamic",
+        Constant::InvokeDynamic { .. } => "InvokeDynamic",
+        Constant::Module(_) => "Module",
+        Constant::Package(_) => "Package",
+    }
+}
+
+/// Get the metrics of a method, decoding a lazily read `Code` attribute.
+fn method_metrics(constant_pool: &ConstantPool, method: &Method) -> Result<MethodMetrics> {
+    let mut metrics = MethodMetrics {
+        name: constant_pool.try_get_utf8(method.name_index)?.clone(),
+        descriptor: constant_pool.try_get_utf8(method.descriptor_index)?.clone(),
+        ..Default::default()
+    };
+    let mut attributes = BTreeMap::new();
+    for attribute in &method.attributes {
+        add_attribute(constant_pool, &mut attributes, attribute)?;
+        let attribute = attribute.decode(constant_pool)?;
+        let Attribute::Code {
+            max_stack,
+            max_locals,
+            code,
+            exception_table,
+            attributes: code_attributes,
+            ..
+        } = attribute.as_ref()
+        else {
+            continue;
+        };
+        metrics.code_length = Instruction::code_to_bytes(code)?.len();
+        metrics.instructions = code.len();
+        metrics.max_stack = *max_stack;
+        metrics.max_locals = *max_locals;
+        metrics.exception_handlers = exception_table.len();
+        metrics.cyclomatic_complexity = cyclomatic_complexity(code) + exception_table.len();
+        for code_attribute in code_attributes {
+            add_attribute(constant_pool, &mut attributes, code_attribute)?;
+        }
+    }
+    metrics.attributes = attributes.into_values().collect();
+    Ok(metrics)
+}
+
+/// Get the cyclomatic complexity of the code, excluding exception handlers.
+fn cyclomatic_complexity(code: &[Instruction]) -> usize {
+    let decisions: usize = code
+        .iter()
+        .map(|instruction| match instruction {
+            Instruction::Ifeq(_)
+            | Instruction::Ifne(_)
+            | Instruction::Iflt(_)
+            | Instruction::Ifge(_)
+            | Instruction::Ifgt(_)
+            | Instruction::Ifle(_)
+            | Instruction::If_icmpeq(_)
+            | Instruction::If_icmpne(_)
+            | Instruction::If_icmplt(_)
+            | Instruction::If_icmpge(_)
+            | Instruction::If_icmpgt(_)
+            | Instruction::If_icmple(_)
+            | Instruction::If_acmpeq(_)
+            | Instruction::If_acmpne(_)
+            | Instruction::Ifnull(_)
+            | Instruction::Ifnonnull(_) => 1,
+            Instruction::Tableswitch { offsets, .. } => offsets.len(),
+            Instruction::Lookupswitch { pairs, .. } => pairs.len(),
+            _ => 0,
+        })
+        .sum();
+    decisions + 1
+}
+
+/// Add an attribute to the metrics of the attributes with the same name.
+fn add_attribute(
+    constant_pool: &ConstantPool,
+    attributes: &mut BTreeMap<String, AttributeMetrics>,
+    attribute: &Attribute,
+) -> Result<()> {
+    let name = match attribute {
+        Attribute::Lazy { name_index, .. }
+        | Attribute::Unknown { name_index, .. }
+        | Attribute::Custom { name_index, .. } => constant_pool.try_get_utf8(*name_index)?.clone(),
+        attribute => attribute.name().to_string(),
+    };
+    let mut bytes = Vec::new();
+    attribute.to_bytes(&mut bytes)?;
+    let metrics = attributes
+        .entry(name.clone())
+        .or_insert_with(|| AttributeMetrics {
+            name,
+            ..Default::default()
+        });
+    metrics.count += 1;
+    metrics.size += bytes.len();
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::class_access_flags::ClassAccessFlags;
+    use crate::method_access_flags::MethodAccessFlags;
+    use indexmap::IndexMap;
+    use std::io::Cursor;
+
+    fn class_file() -> Result<ClassFile> {
+        let mut class_file = crate::test::class_file("Foo", Version::Java8 { minor: 0 })?;
+        class_file.access_flags = ClassAccessFlags::PUBLIC | ClassAccessFlags::SUPER;
+
+        let constant_pool = &mut class_file.constant_pool;
+        let code_name_index = constant_pool.add_utf8("Code")?;
+        let line_number_table = constant_pool.add_utf8("LineNumberTable")?;
+        let code = vec![
+            Instruction::Iload_0,
+            Instruction::Ifeq(6),
+            Instruction::Iload_0,
+            Instruction::Lookupswitch {
+                default: 3,
+                pairs: IndexMap::from([(1, 1), (2, 3)]),
+            },
+            Instruction::Iconst_1,
+            Instruction::Ireturn,
+            Instruction::Iconst_0,
+            Instruction::Ireturn,
+        ];
+        class_file.methods.push(Method {
+            access_flags: MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+            name_index: constant_pool.add_utf8("test")?,
+            descriptor_index: constant_pool.add_utf8("(I)I")?,
+            attributes: vec![Attribute::Code {
+                name_index: code_name_index,
+                max_stack: 1,
+                max_locals: 1,
+                code,
+                exception_table: Vec::new(),
+                attributes: vec![Attribute::LineNumberTable {
+                    name_index: line_number_table,
+                    line_numbers: Vec::new(),
+                }],
+            }],
+        });
+        class_file.methods.push(Method {
+            access_flags: MethodAccessFlags::PUBLIC | MethodAccessFlags::ABSTRACT,
+            name_index: constant_pool.add_utf8("run")?,
+            descriptor_index: constant_pool.add_utf8("()V")?,
+            attributes: Vec::new(),
+        });
+        Ok(class_file)
+    }
+
+    #[test]
+    fn test_class_metrics() -> Result<()> {
+        let class_file = class_file()?;
+        let mut bytes = Vec::new();
+        class_file.to_bytes(&mut bytes)?;
+        let metrics = class_file.metrics()?;
+
+        assert_eq!("Foo", metrics.class_name);
+        assert_eq!(Version::Java8 { minor: 0 }, metrics.version);
+        assert_eq!(bytes.len(), metrics.size);
+        assert_eq!(0, metrics.fields);
+        assert_eq!(2, metrics.methods.len());
+        assert_eq!(metrics.methods[0].code_length, metrics.code_length());
+        assert_eq!(4, metrics.max_cyclomatic_complexity());
+        assert_eq!(
+            vec!["Code", "LineNumberTable"],
+            metrics
+                .attributes
+                .iter()
+                .map(|attribute| attribute.name.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            AttributeMetrics {
+                name: "LineNumberTable".to_string(),
+                count: 1,
+                size: 8,
+            },
+            metrics.attributes[1]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_constant_pool_metrics() -> Result<()> {
+        let mut class_file = class_file()?;
+        class_file.constant_pool.add_long(42)?;
+        let metrics = class_file.metrics()?;
+        let constant_pool = &metrics.constant_pool;
+
+        let mut bytes = Vec::new();
+        class_file.constant_pool.to_bytes(&mut bytes)?;
+        assert_eq!(bytes.len(), constant_pool.size);
+        assert_eq!(
+            class_file.constant_pool.iter().count(),
+            constant_pool.constants
+        );
+        assert_eq!(Some(&2), constant_pool.kinds.get("Class"));
+        assert_eq!(Some(&1), constant_pool.kinds.get("Long"));
+        assert_eq!(Some(&8), constant_pool.kinds.get("Utf8"));
+        assert_eq!(
+            "Foojava/lang/ObjectCodeLineNumberTabletest(I)Irun()V".len(),
+            constant_pool.utf8_size
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_method_metrics() -> Result<()> {
+        let class_file = class_file()?;
+        let metrics = class_file.metrics()?;
+
+        let test = &metrics.methods[0];
+        assert_eq!("test", test.name);
+        assert_eq!("(I)I", test.descriptor);
+        assert_eq!(8, test.instructions);
+        assert_eq!(1, test.max_stack);
+        assert_eq!(1, test.max_locals);
+        assert_eq!(0, test.exception_handlers);
+        // one plus the ifeq and the two lookupswitch cases
+        assert_eq!(4, test.cyclomatic_complexity);
+        assert_eq!(2, test.attributes.len());
+
+        let run = &metrics.methods[1];
+        assert_eq!(
+            MethodMetrics {
+                name: "run".to_string(),
+                descriptor: "()V".to_string(),
+                ..Default::default()
+            },
+            *run
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_metrics() -> Result<()> {
+        let class_file = class_file()?;
+        let mut bytes = Vec::new();
+        class_file.to_bytes(&mut bytes)?;
+        let lazy_class_file = ClassFile::from_bytes_lazy(&mut Cursor::new(bytes))?;
+
+        assert_eq!(class_file.metrics()?, lazy_class_file.metrics()?);
+        Ok(())
+    }
+}
@@ -0,0 +1,642 @@
+use crate::attributes::{
+    Attribute, Exports, ExportsFlags, ModuleAccessFlags, Opens, OpensFlags, Provides, Requires,
+    RequiresFlags,
+};
+use crate::builder::{internal_name, Constants};
+use crate::class_access_flags::ClassAccessFlags;
+use crate::class_file::ClassFile;
+use crate::error::Error::InvalidModuleDescriptor;
+use crate::error::Result;
+use crate::version::Version;
+
+/// Module that is implicitly required by every other module.
+const JAVA_BASE: &str = "java.base";
+
+/// Builder for `module-info` class files.
+///
+/// Module names are specified in their dotted form (e.g. `java.base`), and package and class names
+/// may be specified in either their binary (e.g. `com.example.Main`) or internal (e.g.
+/// `com/example/Main`) form.  The `ModulePackages` attribute is derived from the packages that are
+/// exported, opened, contain service implementations or the main class, and any packages added with
+/// [`package`](ModuleInfoBuilder::package).  If the module does not require `java.base`, the
+/// mandated dependency is added.
+///
+/// # Examples
+///
+/// ```rust
+/// use ristretto_classfile::attributes::{ExportsFlags, RequiresFlags};
+/// use ristretto_classfile::{ModuleInfoBuilder, Result};
+///
+/// fn main() -> Result<()> {
+///     let class_file = ModuleInfoBuilder::new("com.example")
+///         .version("1.0")
+///         .requires("java.logging", RequiresFlags::TRANSITIVE)
+///         .exports("com.example.api", ExportsFlags::empty())
+///         .uses("com.example.spi.Plugin")
+///         .provides("com.example.spi.Plugin", &["com.example.internal.DefaultPlugin"])
+///         .main_class("com.example.Main")
+///         .build()?;
+///     assert_eq!("module-info", class_file.class_name()?);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ModuleInfoBuilder {
+    java_version: Version,
+    name: String,
+    flags: ModuleAccessFlags,
+    version: Option<String>,
+    requires: Vec<(String, RequiresFlags, Option<String>)>,
+    exports: Vec<(String, ExportsFlags, Vec<String>)>,
+    opens: Vec<(String, OpensFlags, Vec<String>)>,
+    uses: Vec<String>,
+    provides: Vec<(String, Vec<String>)>,
+    packages: Vec<String>,
+    main_class: Option<String>,
+}
+
+impl ModuleInfoBuilder {
+    /// Create a new builder for the named module.
+    #[must_use]
+    pub fn new<S: AsRef<str>>(name: S) -> Self {
+        ModuleInfoBuilder {
+            java_version: Version::Java9 { minor: 0 },
+            name: name.as_ref().to_string(),
+            flags: ModuleAccessFlags::empty(),
+            version: None,
+            requires: Vec::new(),
+            exports: Vec::new(),
+            opens: Vec::new(),
+            uses: Vec::new(),
+            provides: Vec::new(),
+            packages: Vec::new(),
+            main_class: None,
+        }
+    }
+
+    /// Set the class file version; defaults to Java 9, the first version that supports modules.
+    #[must_use]
+    pub fn java_version(mut self, java_version: Version) -> Self {
+        self.java_version = java_version;
+        self
+    }
+
+    /// Set the module flags (e.g. [`ModuleAccessFlags::OPEN`]).
+    #[must_use]
+    pub fn flags(mut self, flags: ModuleAccessFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Set the version of the module.
+    #[must_use]
+    pub fn version<S: AsRef<str>>(mut self, version: S) -> Self {
+        self.version = Some(version.as_ref().to_string());
+        self
+    }
+
+    /// Add a dependency on a module.
+    #[must_use]
+    pub fn requires<S: AsRef<str>>(mut self, module: S, flags: RequiresFlags) -> Self {
+        self.requires
+            .push((module.as_ref().to_string(), flags, None));
+        self
+    }
+
+    /// Add a dependency on a module, recording the version of the module that was compiled against.
+    #[must_use]
+    pub fn requires_version<S: AsRef<str>, V: AsRef<str>>(
+        mut self,
+        module: S,
+        flags: RequiresFlags,
+        version: V,
+    ) -> Self {
+        self.requires.push((
+            module.as_ref().to_string(),
+            flags,
+            Some(version.as_ref().to_string()),
+        ));
+        self
+    }
+
+    /// Export a package to all modules.
+    #[must_use]
+    pub fn exports<S: AsRef<str>>(self, package: S, flags: ExportsFlags) -> Self {
+        self.exports_to::<S, &str>(package, flags, &[])
+    }
+
+    /// Export a package to the listed modules only; if `to` is empty, the package is exported to
+    /// all modules.
+    #[must_use]
+    pub fn exports_to<S: AsRef<str>, T: AsRef<str>>(
+        mut self,
+        package: S,
+        flags: ExportsFlags,
+        to: &[T],
+    ) -> Self {
+        let to = to
+            .iter()
+            .map(|module| module.as_ref().to_string())
+            .collect();
+        self.exports.push((internal_name(package), flags, to));
+        self
+    }
+
+    /// Open a package to all modules for reflection.
+    #[must_use]
+    pub fn opens<S: AsRef<str>>(self, package: S, flags: OpensFlags) -> Self {
+        self.opens_to::<S, &str>(package, flags, &[])
+    }
+
+    /// Open a package to the listed modules only for reflection; if `to` is empty, the package is
+    /// opened to all modules.
+    #[must_use]
+    pub fn opens_to<S: AsRef<str>, T: AsRef<str>>(
+        mut self,
+        package: S,
+        flags: OpensFlags,
+        to: &[T],
+    ) -> Self {
+        let to = to
+            .iter()
+            .map(|module| module.as_ref().to_string())
+            .collect();
+        self.opens.push((internal_name(package), flags, to));
+        self
+    }
+
+    /// Add a service interface that the module uses.
+    #[must_use]
+    pub fn uses<S: AsRef<str>>(mut self, service: S) -> Self {
+        self.uses.push(internal_name(service));
+        self
+    }
+
+    /// Add implementations of a service interface that the module provides.
+    #[must_use]
+    pub fn provides<S: AsRef<str>, T: AsRef<str>>(mut self, service: S, with: &[T]) -> Self {
+        let with = with.iter().map(internal_name).collect();
+        self.provides.push((internal_name(service), with));
+        self
+    }
+
+    /// Add a package of the module that is not exported or opened.
+    #[must_use]
+    pub fn package<S: AsRef<str>>(mut self, package: S) -> Self {
+        self.packages.push(internal_name(package));
+        self
+    }
+
+    /// Set the main class of the module.
+    #[must_use]
+    pub fn main_class<S: AsRef<str>>(mut self, main_class: S) -> Self {
+        self.main_class = Some(internal_name(main_class));
+        self
+    }
+
+    /// Build and verify the `module-info` class file.
+    ///
+    /// # Errors
+    /// - If a name is empty, or a module, package or service is declared more than once.
+    /// - If a service is provided without any implementations.
+    /// - If the constant pool exceeds 65,534 constants.
+    /// - If the class file cannot be verified.
+    pub fn build(&self) -> Result<ClassFile> {
+        self.validate()?;
+
+        let mut constants = Constants::default();
+        let this_class = constants.class("module-info")?;
+        let module_name_index = constants.module(&self.name)?;
+        let version_index = constants.optional_utf8(self.version.as_ref())?;
+
+        let mut requires = Vec::new();
+        if self.name != JAVA_BASE && !self.requires.iter().any(|(name, ..)| name == JAVA_BASE) {
+            requires.push(Requires {
+                index: constants.module(JAVA_BASE)?,
+                flags: RequiresFlags::MANDATED,
+                version_index: 0,
+            });
+        }
+        for (module, flags, version) in &self.requires {
+            requires.push(Requires {
+                index: constants.module(module)?,
+                flags: *flags,
+                version_index: constants.optional_utf8(version.as_ref())?,
+            });
+        }
+
+        let mut exports = Vec::new();
+        for (package, flags, to) in &self.exports {
+            exports.push(Exports {
+                index: constants.package(package)?,
+                flags: *flags,
+                to_index: constants.modules(to)?,
+            });
+        }
+
+        let mut opens = Vec::new();
+        for (package, flags, to) in &self.opens {
+            opens.push(Opens {
+                index: constants.package(package)?,
+                flags: *flags,
+                to_index: constants.modules(to)?,
+            });
+        }
+
+        let mut uses = Vec::new();
+        for service in &self.uses {
+            uses.push(constants.class(service)?);
+        }
+
+        let mut provides = Vec::new();
+        for (service, with) in &self.provides {
+            let index = constants.class(service)?;
+            let mut with_index = Vec::new();
+            for implementation in with {
+                with_index.push(constants.class(implementation)?);
+            }
+            provides.push(Provides { index, with_index });
+        }
+
+        let mut attributes = vec![Attribute::Module {
+            name_index: constants.utf8("Module")?,
+            module_name_index,
+            flags: self.flags,
+            version_index,
+            requires,
+            exports,
+            opens,
+            uses,
+            provides,
+        }];
+
+        let packages = self.module_packages();
+        if !packages.is_empty() {
+            let mut package_indexes = Vec::new();
+            for package in &packages {
+                package_indexes.push(constants.package(package)?);
+            }
+            attributes.push(Attribute::ModulePackages {
+                name_index: constants.utf8("ModulePackages")?,
+                package_indexes,
+            });
+        }
+
+        if let Some(main_class) = &self.main_class {
+            attributes.push(Attribute::ModuleMainClass {
+                name_index: constants.utf8("ModuleMainClass")?,
+                main_class_index: constants.class(main_class)?,
+            });
+        }
+
+        let class_file = ClassFile {
+            version: self.java_version.clone(),
+            constant_pool: constants.constant_pool,
+            access_flags: ClassAccessFlags::MODULE,
+            this_class,
+            attributes,
+            ..Default::default()
+        };
+        class_file.verify()?;
+        Ok(class_file)
+    }
+
+    /// Validate the module declaration.
+    fn validate(&self) -> Result<()> {
+        if self.name.is_empty() {
+            return Err(InvalidModuleDescriptor("module name is empty".to_string()));
+        }
+        if self.requires.iter().any(|(name, ..)| *name == self.name) {
+            return Err(InvalidModuleDescriptor(format!(
+                "module {} requires itself",
+                self.name
+            )));
+        }
+        unique("requires", self.requires.iter().map(|(name, ..)| name))?;
+        unique("exports", self.exports.iter().map(|(name, ..)| name))?;
+        unique("opens", self.opens.iter().map(|(name, ..)| name))?;
+        unique("uses", self.uses.iter())?;
+        unique("provides", self.provides.iter().map(|(name, _)| name))?;
+        for (service, with) in &self.provides {
+            if with.is_empty() {
+                return Err(InvalidModuleDescriptor(format!(
+                    "provides {service} without implementations"
+                )));
+            }
+            unique("provides with", with.iter())?;
+        }
+        if self.main_class.as_ref().is_some_and(String::is_empty) {
+            return Err(InvalidModuleDescriptor("main class is empty".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Get the packages of the module in declaration order.
+    fn module_packages(&self) -> Vec<String> {
+        let exports = self.exports.iter().map(|(package, ..)| package.clone());
+        let opens = self.opens.iter().map(|(package, ..)| package.clone());
+        let implementations = self.provides.iter().flat_map(|(_, with)| {
+            with.iter()
+                .filter_map(|class_name| package_name(class_name))
+        });
+        let main_class = self
+            .main_class
+            .iter()
+            .filter_map(|class_name| package_name(class_name));
+
+        let mut packages = Vec::new();
+        for package in exports
+            .chain(opens)
+            .chain(implementations)
+            .chain(main_class)
+            .chain(self.packages.iter().cloned())
+        {
+            if !packages.contains(&package) {
+                packages.push(package);
+            }
+        }
+        packages
+    }
+}
+
+/// Get the package of a class in internal form; classes in the unnamed package return `None`.
+fn package_name(class_name: &str) -> Option<String> {
+    class_name
+        .rsplit_once('/')
+        .map(|(package, _)| package.to_string())
+}
+
+/// Verify that the names of a module directive are not empty and are unique.
+fn unique<'a>(directive: &str, names: impl Iterator<Item = &'a String>) -> Result<()> {
+    let mut seen = Vec::new();
+    for name in names {
+        if name.is_empty() {
+            return Err(InvalidModuleDescriptor(format!(
+                "{directive} name is empty"
+            )));
+        }
+        if seen.contains(&name) {
+            return Err(InvalidModuleDescriptor(format!(
+                "{directive} {name} is declared more than once"
+            )));
+        }
+        seen.push(name);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Cursor;
+
+    fn module_attribute(class_file: &ClassFile) -> &Attribute {
+        class_file
+            .attributes
+            .iter()
+            .find(|attribute| matches!(attribute, Attribute::Module { .. }))
+            .expect("module attribute")
+    }
+
+    #[test]
+    fn test_build_minimal() -> Result<()> {
+        let class_file = ModuleInfoBuilder::new("com.example").build()?;
+        let constant_pool = &class_file.constant_pool;
+        assert_eq!(Version::Java9 { minor: 0 }, class_file.version);
+        assert_eq!(ClassAccessFlags::MODULE, class_file.access_flags);
+        assert_eq!("module-info", class_file.class_name()?);
+        assert_eq!(0, class_file.super_class);
+        assert_eq!(1, class_file.attributes.len());
+
+        let Attribute::Module {
+            module_name_index,
+            version_index,
+            requires,
+            ..
+        } = module_attribute(&class_file)
+        else {
+            unreachable!("expected module attribute");
+        };
+        assert_eq!(
+            "com.example",
+            constant_pool.try_get_module(*module_name_index)?
+        );
+        assert_eq!(0, *version_index);
+        assert_eq!(1, requires.len());
+        assert_eq!(
+            "java.base",
+            constant_pool.try_get_module(requires[0].index)?
+        );
+        assert_eq!(RequiresFlags::MANDATED, requires[0].flags);
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_java_base() -> Result<()> {
+        let class_file = ModuleInfoBuilder::new("java.base").build()?;
+        let Attribute::Module { requires, .. } = module_attribute(&class_file) else {
+            unreachable!("expected module attribute");
+        };
+        assert!(requires.is_empty());
+        Ok(())
+    }
+
+    fn example_module() -> Result<ClassFile> {
+        ModuleInfoBuilder::new("com.example")
+            .java_version(Version::Java21 { minor: 0 })
+            .flags(ModuleAccessFlags::OPEN)
+            .version("1.0")
+            .requires_version("java.base", RequiresFlags::MANDATED, "21")
+            .requires("java.logging", RequiresFlags::TRANSITIVE)
+            .exports("com.example.api", ExportsFlags::empty())
+            .exports_to("com.example.spi", ExportsFlags::empty(), &["com.other"])
+            .opens_to("com/example/model", OpensFlags::empty(), &["com.other"])
+            .uses("com.example.spi.Plugin")
+            .provides(
+                "com.example.spi.Plugin",
+                &["com.example.internal.DefaultPlugin"],
+            )
+            .package("com.example.util")
+            .main_class("com.example.Main")
+            .build()
+    }
+
+    #[test]
+    fn test_build() -> Result<()> {
+        let class_file = example_module()?;
+        let constant_pool = &class_file.constant_pool;
+        assert_eq!(Version::Java21 { minor: 0 }, class_file.version);
+        assert_eq!(3, class_file.attributes.len());
+
+        let Attribute::Module {
+            flags,
+            version_index,
+            ..
+        } = module_attribute(&class_file)
+        else {
+            unreachable!("expected module attribute");
+        };
+        assert_eq!(ModuleAccessFlags::OPEN, *flags);
+        assert_eq!("1.0", constant_pool.try_get_utf8(*version_index)?);
+
+        let Some(Attribute::ModulePackages {
+            package_indexes, ..
+        }) = class_file.attributes.get(1)
+        else {
+            unreachable!("expected module packages attribute");
+        };
+        let packages = package_indexes
+            .iter()
+            .map(|index| constant_pool.try_get_package(*index).cloned())
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(
+            vec![
+                "com/example/api",
+                "com/example/spi",
+                "com/example/model",
+                "com/example/internal",
+                "com/example",
+                "com/example/util",
+            ],
+            packages
+        );
+
+        let Some(Attribute::ModuleMainClass {
+            main_class_index, ..
+        }) = class_file.attributes.get(2)
+        else {
+            unreachable!("expected module main class attribute");
+        };
+        assert_eq!(
+            "com/example/Main",
+            constant_pool.try_get_class(*main_class_index)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_requires() -> Result<()> {
+        let class_file = example_module()?;
+        let constant_pool = &class_file.constant_pool;
+        let Attribute::Module { requires, .. } = module_attribute(&class_file) else {
+            unreachable!("expected module attribute");
+        };
+        assert_eq!(2, requires.len());
+        assert_eq!(
+            "java.base",
+            constant_pool.try_get_module(requires[0].index)?
+        );
+        assert_eq!("21", constant_pool.try_get_utf8(requires[0].version_index)?);
+        assert_eq!(
+            "java.logging",
+            constant_pool.try_get_module(requires[1].index)?
+        );
+        assert_eq!(RequiresFlags::TRANSITIVE, requires[1].flags);
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_exports_and_opens() -> Result<()> {
+        let class_file = example_module()?;
+        let constant_pool = &class_file.constant_pool;
+        let Attribute::Module { exports, opens, .. } = module_attribute(&class_file) else {
+            unreachable!("expected module attribute");
+        };
+        assert_eq!(2, exports.len());
+        assert_eq!(
+            "com/example/api",
+            constant_pool.try_get_package(exports[0].index)?
+        );
+        assert!(exports[0].to_index.is_empty());
+        assert_eq!(
+            "com.other",
+            constant_pool.try_get_module(exports[1].to_index[0])?
+        );
+        assert_eq!(
+            "com/example/model",
+            constant_pool.try_get_package(opens[0].index)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_uses_and_provides() -> Result<()> {
+        let class_file = example_module()?;
+        let constant_pool = &class_file.constant_pool;
+        let Attribute::Module { uses, provides, .. } = module_attribute(&class_file) else {
+            unreachable!("expected module attribute");
+        };
+        assert_eq!(
+            "com/example/spi/Plugin",
+            constant_pool.try_get_class(uses[0])?
+        );
+        assert_eq!(uses[0], provides[0].index);
+        assert_eq!(
+            "com/example/internal/DefaultPlugin",
+            constant_pool.try_get_class(provides[0].with_index[0])?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_serialization() -> Result<()> {
+        let class_file = ModuleInfoBuilder::new("com.example")
+            .exports("com.example.api", ExportsFlags::empty())
+            .main_class("com.example.Main")
+            .build()?;
+        let mut bytes = Vec::new();
+        class_file.to_bytes(&mut bytes)?;
+        let mut bytes = Cursor::new(bytes);
+        assert_eq!(class_file, ClassFile::from_bytes(&mut bytes)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_empty_name() {
+        let result = ModuleInfoBuilder::new("").build();
+        assert_eq!(
+            Err(InvalidModuleDescriptor("module name is empty".to_string())),
+            result
+        );
+    }
+
+    #[test]
+    fn test_build_requires_self() {
+        let result = ModuleInfoBuilder::new("com.example")
+            .requires("com.example", RequiresFlags::empty())
+            .build();
+        assert_eq!(
+            Err(InvalidModuleDescriptor(
+                "module com.example requires itself".to_string()
+            )),
+            result
+        );
+    }
+
+    #[test]
+    fn test_build_duplicate_exports() {
+        let result = ModuleInfoBuilder::new("com.example")
+            .exports("com.example.api", ExportsFlags::empty())
+            .exports("com/example/api", ExportsFlags::empty())
+            .build();
+        assert_eq!(
+            Err(InvalidModuleDescriptor(
+                "exports com/example/api is declared more than once".to_string()
+            )),
+            result
+        );
+    }
+
+    #[test]
+    fn test_build_provides_without_implementations() {
+        let result = ModuleInfoBuilder::new("com.example")
+            .provides::<_, &str>("com.example.spi.Plugin", &[])
+            .build();
+        assert_eq!(
+            Err(InvalidModuleDescriptor(
+                "provides com/example/spi/Plugin without implementations".to_string()
+            )),
+            result
+        );
+    }
+}
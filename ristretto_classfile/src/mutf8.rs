@@ -6,7 +6,11 @@
 use crate::Error::FromUtf8Error;
 use crate::Result;
 
-/// Converts a Rust string to a Java Modified UTF-8 byte array.
+/// Replacement for malformed sequences and unpaired surrogates when decoding.
+const REPLACEMENT_CHARACTER: char = '\u{FFFD}';
+
+/// Converts a Rust string to a Java Modified UTF-8 byte array; `U+0000` is encoded with two bytes
+/// and supplementary characters are encoded as surrogate pairs.
 ///
 /// # Errors
 /// Should not occur; reserved for future use.
@@ -22,16 +26,11 @@ pub fn to_bytes<S: AsRef<str>>(data: S) -> Result<Vec<u8>> {
                 encoded.push(0xC0 | u8::try_from((ch as u32) >> 6)?);
                 encoded.push(0x80 | u8::try_from((ch as u32) & 0x3F)?);
             }
-            '\u{0800}'..='\u{FFFF}' => {
-                encoded.push(0xE0 | u8::try_from((ch as u32) >> 12)?);
-                encoded.push(0x80 | u8::try_from(((ch as u32) >> 6) & 0x3F)?);
-                encoded.push(0x80 | u8::try_from((ch as u32) & 0x3F)?);
-            }
+            '\u{0800}'..='\u{FFFF}' => encode_unit(ch as u32, &mut encoded)?,
             _ => {
-                let mut buf = [0u8; 4];
-                let encoded_bytes = ch.encode_utf8(&mut buf);
-                for b in encoded_bytes.bytes() {
-                    encoded.push(b);
+                let mut units = [0u16; 2];
+                for unit in ch.encode_utf16(&mut units) {
+                    encode_unit(u32::from(*unit), &mut encoded)?;
                 }
             }
         }
@@ -40,62 +39,257 @@ pub fn to_bytes<S: AsRef<str>>(data: S) -> Result<Vec<u8>> {
     Ok(encoded)
 }
 
+/// Encode a UTF-16 code unit of `U+0800` or greater with three bytes.
+fn encode_unit(unit: u32, encoded: &mut Vec<u8>) -> Result<()> {
+    encoded.push(0xE0 | u8::try_from(unit >> 12)?);
+    encoded.push(0x80 | u8::try_from((unit >> 6) & 0x3F)?);
+    encoded.push(0x80 | u8::try_from(unit & 0x3F)?);
+    Ok(())
+}
+
 /// Converts a Java Modified UTF-8 byte array to a Rust string.
 ///
+/// Surrogate pairs are decoded to supplementary characters and unpaired surrogates, which cannot
+/// be represented by a Rust string, are replaced with `U+FFFD`.  Four byte UTF-8 sequences are
+/// accepted for compatibility; use [`validate`] to check that bytes are strictly Modified UTF-8.
+///
 /// # Errors
-/// Should not occur; reserved for future use.
-#[expect(clippy::similar_names)]
+/// If the bytes contain a malformed or truncated sequence.
 pub fn from_bytes<V: AsRef<[u8]>>(bytes: V) -> Result<String> {
     let bytes = bytes.as_ref();
     let mut decoded = String::with_capacity(bytes.len());
-    let mut i = 0;
-
-    while i < bytes.len() {
-        let byte1 = bytes[i];
-        if byte1 & 0x80 == 0 {
-            decoded.push(byte1 as char);
-            i += 1;
-        } else if byte1 & 0xE0 == 0xC0 {
-            if i + 1 >= bytes.len() {
-                return Err(FromUtf8Error("Invalid UTF-8 byte sequence".to_string()));
-            }
-            let byte2 = bytes[i + 1];
-            if byte1 == 0xC0 && byte2 == 0x80 {
-                decoded.push('\u{0000}');
-            } else {
-                let ch = (u32::from(byte1 & 0x1F) << 6) | u32::from(byte2 & 0x3F);
-                decoded.push(char::from_u32(ch).unwrap_or_default());
+    let mut high_surrogate = None;
+    let consumed = decode(bytes, 0, false, &mut high_surrogate, &mut decoded)?;
+    if consumed < bytes.len() {
+        return Err(invalid_sequence(consumed));
+    }
+    if high_surrogate.is_some() {
+        decoded.push(REPLACEMENT_CHARACTER);
+    }
+    Ok(decoded)
+}
+
+/// Converts a Java Modified UTF-8 byte array to a Rust string, replacing malformed sequences and
+/// unpaired surrogates with `U+FFFD`.
+#[must_use]
+pub fn decode_lossy<V: AsRef<[u8]>>(bytes: V) -> String {
+    let mut decoder = Decoder::lossy();
+    let mut value = decoder.decode(bytes).unwrap_or_default();
+    value.push_str(&decoder.finish().unwrap_or_default());
+    value
+}
+
+/// Verify that a byte array is well-formed Java Modified UTF-8.
+///
+/// In addition to the checks performed by [`from_bytes`], the bytes may not contain a `0x00` byte,
+/// four byte sequences, overlong encodings (other than `U+0000`) or unpaired surrogates.
+///
+/// # Errors
+/// If the bytes are not well-formed; the error contains the offset of the invalid sequence.
+pub fn validate<V: AsRef<[u8]>>(bytes: V) -> Result<()> {
+    let bytes = bytes.as_ref();
+    let mut offset = 0;
+    let mut high_surrogate = false;
+
+    while offset < bytes.len() {
+        let (value, length) = match next_sequence(&bytes[offset..]) {
+            Sequence::Unit(value, length) => (value, length),
+            Sequence::Incomplete | Sequence::Invalid(_) => return Err(invalid_sequence(offset)),
+        };
+        let overlong = match length {
+            1 => value == 0,
+            2 => value < 0x80 && value != 0,
+            3 => value < 0x800,
+            _ => true,
+        };
+        let low_surrogate = (0xDC00..=0xDFFF).contains(&value);
+        if overlong || high_surrogate != low_surrogate {
+            return Err(invalid_sequence(offset));
+        }
+        high_surrogate = (0xD800..=0xDBFF).contains(&value);
+        offset += length;
+    }
+
+    if high_surrogate {
+        return Err(invalid_sequence(offset));
+    }
+    Ok(())
+}
+
+/// Incremental Java Modified UTF-8 decoder.
+///
+/// Bytes may be supplied in chunks of any size; sequences and surrogate pairs that span chunks
+/// are buffered until they are complete.
+///
+/// # Examples
+///
+/// ```rust
+/// use ristretto_classfile::mutf8::Decoder;
+///
+/// let mut decoder = Decoder::new();
+/// let mut decoded = decoder.decode([0x41, 0xED, 0xA0])?;
+/// decoded.push_str(&decoder.decode([0xBD, 0xED, 0xB8, 0x80])?);
+/// decoded.push_str(&decoder.finish()?);
+/// assert_eq!("A\u{1F600}", decoded);
+/// # Ok::<(), ristretto_classfile::Error>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Decoder {
+    lossy: bool,
+    pending: Vec<u8>,
+    position: usize,
+    high_surrogate: Option<u32>,
+}
+
+impl Decoder {
+    /// Create a decoder that returns an error for malformed sequences.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a decoder that replaces malformed sequences with `U+FFFD`.
+    #[must_use]
+    pub fn lossy() -> Self {
+        Self {
+            lossy: true,
+            ..Self::default()
+        }
+    }
+
+    /// Decode the next chunk of bytes, returning the characters that are complete.
+    ///
+    /// # Errors
+    /// If the decoder is not lossy and the bytes contain a malformed sequence.
+    pub fn decode<V: AsRef<[u8]>>(&mut self, bytes: V) -> Result<String> {
+        self.pending.extend_from_slice(bytes.as_ref());
+        let mut decoded = String::with_capacity(self.pending.len());
+        let consumed = decode(
+            &self.pending,
+            self.position,
+            self.lossy,
+            &mut self.high_surrogate,
+            &mut decoded,
+        )?;
+        self.pending.drain(..consumed);
+        self.position += consumed;
+        Ok(decoded)
+    }
+
+    /// Finish decoding, returning any characters that are still buffered.
+    ///
+    /// # Errors
+    /// If the decoder is not lossy and the bytes ended with a truncated sequence.
+    pub fn finish(&mut self) -> Result<String> {
+        let mut decoded = String::new();
+        if self.high_surrogate.take().is_some() {
+            decoded.push(REPLACEMENT_CHARACTER);
+        }
+        if !self.pending.is_empty() {
+            if !self.lossy {
+                return Err(invalid_sequence(self.position));
             }
-            i += 2;
-        } else if byte1 & 0xF0 == 0xE0 {
-            if i + 2 >= bytes.len() {
-                return Err(FromUtf8Error("Invalid UTF-8 byte sequence".to_string()));
+            self.position += self.pending.len();
+            self.pending.clear();
+            decoded.push(REPLACEMENT_CHARACTER);
+        }
+        Ok(decoded)
+    }
+}
+
+/// Sequence of bytes encoding a character or UTF-16 code unit.
+enum Sequence {
+    /// Decoded value and the length of the sequence
+    Unit(u32, usize),
+    /// The bytes end before the sequence is complete
+    Incomplete,
+    /// Malformed sequence of the given length
+    Invalid(usize),
+}
+
+/// Get the next sequence from a non-empty byte slice.
+fn next_sequence(bytes: &[u8]) -> Sequence {
+    let lead = bytes[0];
+    let (length, mut value) = match lead {
+        0x00..=0x7F => return Sequence::Unit(u32::from(lead), 1),
+        0xC0..=0xDF => (2, u32::from(lead & 0x1F)),
+        0xE0..=0xEF => (3, u32::from(lead & 0x0F)),
+        0xF0..=0xF7 => (4, u32::from(lead & 0x07)),
+        _ => return Sequence::Invalid(1),
+    };
+    for index in 1..length {
+        let Some(byte) = bytes.get(index) else {
+            return Sequence::Incomplete;
+        };
+        if byte & 0xC0 != 0x80 {
+            return Sequence::Invalid(index);
+        }
+        value = (value << 6) | u32::from(byte & 0x3F);
+    }
+    Sequence::Unit(value, length)
+}
+
+/// Decode complete sequences, returning the number of bytes consumed; a trailing incomplete
+/// sequence is not consumed.  `position` is the offset of the bytes used for error messages.
+fn decode(
+    bytes: &[u8],
+    position: usize,
+    lossy: bool,
+    high_surrogate: &mut Option<u32>,
+    decoded: &mut String,
+) -> Result<usize> {
+    let mut offset = 0;
+
+    while offset < bytes.len() {
+        match next_sequence(&bytes[offset..]) {
+            Sequence::Unit(value, length) => {
+                offset += length;
+                if (0xD800..=0xDBFF).contains(&value) {
+                    if high_surrogate.replace(value).is_some() {
+                        decoded.push(REPLACEMENT_CHARACTER);
+                    }
+                    continue;
+                }
+                let value = match high_surrogate.take() {
+                    Some(high) if (0xDC00..=0xDFFF).contains(&value) => {
+                        0x10000 + ((high - 0xD800) << 10) + (value - 0xDC00)
+                    }
+                    Some(_) => {
+                        decoded.push(REPLACEMENT_CHARACTER);
+                        value
+                    }
+                    None => value,
+                };
+                match char::from_u32(value) {
+                    Some(ch) => decoded.push(ch),
+                    None if lossy || (0xDC00..=0xDFFF).contains(&value) => {
+                        decoded.push(REPLACEMENT_CHARACTER);
+                    }
+                    None => return Err(invalid_sequence(position + offset - length)),
+                }
             }
-            let byte2 = bytes[i + 1];
-            let byte3 = bytes[i + 2];
-            let ch = (u32::from(byte1 & 0x0F) << 12)
-                | (u32::from(byte2 & 0x3F) << 6)
-                | u32::from(byte3 & 0x3F);
-            decoded.push(char::from_u32(ch).unwrap_or_default());
-            i += 3;
-        } else {
-            if i + 3 >= bytes.len() {
-                return Err(FromUtf8Error("Invalid UTF-8 byte sequence".to_string()));
+            Sequence::Incomplete => break,
+            Sequence::Invalid(length) => {
+                if !lossy {
+                    return Err(invalid_sequence(position + offset));
+                }
+                if high_surrogate.take().is_some() {
+                    decoded.push(REPLACEMENT_CHARACTER);
+                }
+                decoded.push(REPLACEMENT_CHARACTER);
+                offset += length;
             }
-            // Handle surrogate pairs and supplementary characters
-            let byte2 = bytes[i + 1];
-            let byte3 = bytes[i + 2];
-            let byte4 = bytes[i + 3];
-            let ch = (u32::from(byte1 & 0x07) << 18)
-                | (u32::from(byte2 & 0x3F) << 12)
-                | (u32::from(byte3 & 0x3F) << 6)
-                | u32::from(byte4 & 0x3F);
-            decoded.push(char::from_u32(ch).unwrap_or_default());
-            i += 4;
         }
     }
 
-    Ok(decoded)
+    Ok(offset)
+}
+
+/// Error for a malformed sequence at a byte offset.
+fn invalid_sequence(offset: usize) -> crate::Error {
+    FromUtf8Error(format!(
+        "Invalid modified UTF-8 byte sequence at offset {offset}"
+    ))
 }
 
 #[cfg(test)]
@@ -114,8 +308,10 @@ mod tests {
                 let mutf8_encoded_bytes = to_bytes(&s)?;
                 match i {
                     0 => assert_eq!(mutf8_encoded_bytes, vec![0xC0, 0x80]),
+                    0x1_0000.. => assert_eq!(6, mutf8_encoded_bytes.len()),
                     _ => assert_eq!(rust_encoded_bytes, mutf8_encoded_bytes),
                 }
+                validate(&mutf8_encoded_bytes)?;
 
                 let rust_encoded_result = String::from_utf8(rust_encoded_bytes)?;
                 let mutf8_encoded_result = from_bytes(mutf8_encoded_bytes.as_slice())?;
@@ -143,7 +339,7 @@ mod tests {
             0xDF, 0xBF, // '\u{07FF}'
             0xE0, 0xA0, 0x80, // '\u{0800}'
             0xEF, 0xBF, 0xBF, // '\u{FFFF}'
-            0xF0, 0x90, 0x80, 0x80, // '\u{10000}'
+            0xED, 0xA0, 0x80, 0xED, 0xB0, 0x80, // '\u{10000}'
         ];
         assert_eq!(to_bytes(data)?, expected);
         Ok(())
@@ -173,4 +369,86 @@ mod tests {
         assert!(from_bytes([0x56, 0xa8]).is_err());
         assert!(from_bytes([0x7e, 0xff, 0xff, 0x2a]).is_err());
     }
+
+    #[test]
+    fn test_from_bytes_surrogate_pair() -> Result<()> {
+        let bytes = [0xED, 0xA0, 0xBD, 0xED, 0xB8, 0x80];
+        assert_eq!("\u{1F600}", from_bytes(bytes)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bytes_unpaired_surrogate() -> Result<()> {
+        assert_eq!("\u{FFFD}a", from_bytes([0xED, 0xA0, 0xBD, 0x61])?);
+        assert_eq!("a\u{FFFD}", from_bytes([0x61, 0xED, 0xB8, 0x80])?);
+        assert_eq!("\u{FFFD}", from_bytes([0xED, 0xA0, 0xBD])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bytes_invalid_continuation() {
+        let result = from_bytes([0x41, 0xC2, 0x41]);
+        assert!(matches!(result, Err(FromUtf8Error(message)) if message.ends_with("offset 1")));
+    }
+
+    #[test]
+    fn test_decode_lossy() {
+        assert_eq!("abc", decode_lossy(b"abc"));
+        assert_eq!("a\u{FFFD}b", decode_lossy([0x61, 0xFF, 0x62]));
+        assert_eq!("a\u{FFFD}b", decode_lossy([0x61, 0xE0, 0xA0, 0x62]));
+        assert_eq!("a\u{FFFD}", decode_lossy([0x61, 0xE0, 0xA0]));
+        assert_eq!("\u{FFFD}\u{FFFD}", decode_lossy([0xED, 0xA0, 0xBD, 0x80]));
+        assert_eq!(
+            "\u{1F600}",
+            decode_lossy([0xED, 0xA0, 0xBD, 0xED, 0xB8, 0x80])
+        );
+    }
+
+    #[test]
+    fn test_validate() -> Result<()> {
+        validate([])?;
+        validate(to_bytes("\u{0000}a\u{00E9}\u{20AC}\u{1F600}")?)?;
+        assert!(validate([0x00]).is_err());
+        assert!(validate([0xC1, 0x81]).is_err());
+        assert!(validate([0xE0, 0x81, 0x81]).is_err());
+        assert!(validate([0xF0, 0x9F, 0x98, 0x80]).is_err());
+        assert!(validate([0xED, 0xA0, 0xBD]).is_err());
+        assert!(validate([0xED, 0xB8, 0x80]).is_err());
+        assert!(validate([0xED, 0xA0, 0xBD, 0x61]).is_err());
+        assert!(validate([0xC2]).is_err());
+        assert!(validate([0x80]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_decoder() -> Result<()> {
+        let bytes = to_bytes("a\u{00E9}\u{20AC}\u{1F600}\u{0000}")?;
+        for chunk_size in 1..=bytes.len() {
+            let mut decoder = Decoder::new();
+            let mut value = String::new();
+            for chunk in bytes.chunks(chunk_size) {
+                value.push_str(&decoder.decode(chunk)?);
+            }
+            value.push_str(&decoder.finish()?);
+            assert_eq!("a\u{00E9}\u{20AC}\u{1F600}\u{0000}", value);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_decoder_errors() -> Result<()> {
+        let mut decoder = Decoder::new();
+        assert_eq!("ab", decoder.decode([0x61, 0x62, 0xE0])?);
+        let result = decoder.decode([0x41]);
+        assert!(matches!(result, Err(FromUtf8Error(message)) if message.ends_with("offset 2")));
+
+        let mut decoder = Decoder::new();
+        assert_eq!("a", decoder.decode([0x61, 0xE0, 0xA0])?);
+        assert!(decoder.finish().is_err());
+
+        let mut decoder = Decoder::lossy();
+        assert_eq!("a", decoder.decode([0x61, 0xE0, 0xA0])?);
+        assert_eq!("\u{FFFD}", decoder.finish()?);
+        Ok(())
+    }
 }
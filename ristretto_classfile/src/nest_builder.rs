@@ -0,0 +1,337 @@
+use crate::attributes::Attribute;
+use crate::builder::internal_name;
+use crate::class_file::ClassFile;
+use crate::error::Error::InvalidNest;
+use crate::error::Result;
+use crate::verifiers::nestmates;
+use crate::version::Version;
+
+const VERSION_55_0: Version = Version::Java11 { minor: 0 };
+
+/// Builder for the `NestHost` and `NestMembers` attributes of a nest of classes.
+///
+/// Classes in the same nest, e.g. an outer class and its inner classes, may access each other's
+/// private members without synthetic accessor methods.  The host class lists every member in its
+/// `NestMembers` attribute and each member names the host in its `NestHost` attribute; members
+/// that the host already declares are kept.  Classes that are not generated together with the
+/// host can be added to the nest by name.  Nests require Java 11 or later.  Class names may be
+/// specified in either their binary (e.g. `com.example.Outer$Inner`) or internal (e.g.
+/// `com/example/Outer$Inner`) form.
+///
+/// # Examples
+///
+/// ```rust
+/// use ristretto_classfile::{ClassFile, ConstantPool, NestBuilder, Result, Version};
+///
+/// fn class_file(name: &str) -> Result<ClassFile> {
+///     let mut constant_pool = ConstantPool::default();
+///     let this_class = constant_pool.add_class(name)?;
+///     let super_class = constant_pool.add_class("java/lang/Object")?;
+///     Ok(ClassFile {
+///         version: Version::Java11 { minor: 0 },
+///         constant_pool,
+///         this_class,
+///         super_class,
+///         ..Default::default()
+///     })
+/// }
+///
+/// fn main() -> Result<()> {
+///     let mut host = class_file("com/example/Outer")?;
+///     let mut members = vec![class_file("com/example/Outer$Inner")?];
+///     NestBuilder::new()
+///         .member("com.example.Outer$Other")
+///         .add_to(&mut host, &mut members)?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NestBuilder {
+    members: Vec<String>,
+}
+
+impl NestBuilder {
+    /// Create a new builder for a nest of the host and the classes it is added to.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a nest member by name; e.g. a class that is not generated with the host.  The member
+    /// must declare the host in its own `NestHost` attribute.
+    #[must_use]
+    pub fn member<S: AsRef<str>>(mut self, name: S) -> Self {
+        self.members.push(internal_name(name));
+        self
+    }
+
+    /// Add the host and member classes to a nest; the `NestMembers` attribute of the host and the
+    /// `NestHost` attribute of each member are replaced.  The nest is verified after the
+    /// attributes are added.
+    ///
+    /// # Errors
+    /// - If a class version does not support nests.
+    /// - If the host is a member of another nest, or a member is the host or a member of another
+    ///   nest.
+    /// - If a class is added to the nest more than once.
+    /// - If the constant pool of a class exceeds 65,534 constants.
+    /// - If a class references a private member of a class that is not a nestmate.
+    pub fn add_to(&self, host: &mut ClassFile, members: &mut [ClassFile]) -> Result<()> {
+        self.validate(host, members)?;
+
+        let host_name = host.class_name()?.clone();
+        let mut member_names: Vec<String> = nestmates::nest_members(host)?
+            .into_iter()
+            .cloned()
+            .collect();
+        for member in members.iter() {
+            let member_name = member.class_name()?;
+            if !member_names.contains(member_name) {
+                member_names.push(member_name.clone());
+            }
+        }
+        for member_name in &self.members {
+            if !member_names.contains(member_name) {
+                member_names.push(member_name.clone());
+            }
+        }
+
+        let mut class_indexes = Vec::with_capacity(member_names.len());
+        for member_name in &member_names {
+            class_indexes.push(host.constant_pool.add_class(member_name)?);
+        }
+        host.attributes
+            .retain(|attribute| !matches!(attribute, Attribute::NestMembers { .. }));
+        host.attributes.push(Attribute::NestMembers {
+            name_index: host.constant_pool.add_utf8("NestMembers")?,
+            class_indexes,
+        });
+
+        for member in members.iter_mut() {
+            let host_class_index = member.constant_pool.add_class(&host_name)?;
+            member
+                .attributes
+                .retain(|attribute| !matches!(attribute, Attribute::NestHost { .. }));
+            member.attributes.push(Attribute::NestHost {
+                name_index: member.constant_pool.add_utf8("NestHost")?,
+                host_class_index,
+            });
+        }
+
+        let mut class_files = vec![&*host];
+        class_files.extend(members.iter());
+        nestmates::verify(&class_files)
+    }
+
+    /// Validate the nest declaration.
+    fn validate(&self, host: &ClassFile, members: &[ClassFile]) -> Result<()> {
+        for class_file in std::iter::once(host).chain(members) {
+            if class_file.version < VERSION_55_0 {
+                return Err(InvalidNest(format!(
+                    "nests are not supported by {} in class {}",
+                    class_file.version,
+                    class_file.class_name()?
+                )));
+            }
+        }
+
+        let host_name = host.class_name()?;
+        let host_nest = nestmates::nest_host(host)?;
+        if host_nest != host_name {
+            return Err(InvalidNest(format!(
+                "host {host_name} is a member of the nest of {host_nest}"
+            )));
+        }
+
+        let mut names = vec![host_name];
+        for member in members {
+            let member_name = member.class_name()?;
+            if names.contains(&member_name) {
+                return Err(InvalidNest(format!(
+                    "class {member_name} is added to the nest more than once"
+                )));
+            }
+            let member_nest = nestmates::nest_host(member)?;
+            if member_nest != member_name && member_nest != host_name {
+                return Err(InvalidNest(format!(
+                    "member {member_name} is a member of the nest of {member_nest}"
+                )));
+            }
+            if !nestmates::nest_members(member)?.is_empty() {
+                return Err(InvalidNest(format!(
+                    "member {member_name} is the host of another nest"
+                )));
+            }
+            names.push(member_name);
+        }
+        for member_name in &self.members {
+            if names.contains(&member_name) {
+                return Err(InvalidNest(format!(
+                    "class {member_name} is added to the nest more than once"
+                )));
+            }
+            names.push(member_name);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::method::Method;
+    use crate::method_access_flags::MethodAccessFlags;
+    use crate::Error::VerificationError;
+
+    #[test]
+    fn test_add_to() -> Result<()> {
+        let mut host = crate::test::class_file("Outer", VERSION_55_0)?;
+        let mut members = vec![
+            crate::test::class_file("Outer$A", VERSION_55_0)?,
+            crate::test::class_file("Outer$B", VERSION_55_0)?,
+        ];
+        NestBuilder::new()
+            .member("Outer.C")
+            .add_to(&mut host, &mut members)?;
+
+        assert_eq!(
+            vec!["Outer$A", "Outer$B", "Outer/C"],
+            nestmates::nest_members(&host)?
+        );
+        for member in &members {
+            assert_eq!("Outer", nestmates::nest_host(member)?);
+            member.verify()?;
+        }
+        host.verify()?;
+
+        let mut bytes = Vec::new();
+        host.to_bytes(&mut bytes)?;
+        let mut cursor = std::io::Cursor::new(bytes);
+        assert_eq!(host, ClassFile::from_bytes(&mut cursor)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_to_keeps_existing_members() -> Result<()> {
+        let mut host = crate::test::class_file("Outer", VERSION_55_0)?;
+        NestBuilder::new()
+            .member("Outer$A")
+            .add_to(&mut host, &mut [])?;
+        let mut members = vec![
+            crate::test::class_file("Outer$A", VERSION_55_0)?,
+            crate::test::class_file("Outer$B", VERSION_55_0)?,
+        ];
+        NestBuilder::new().add_to(&mut host, &mut members)?;
+
+        assert_eq!(vec!["Outer$A", "Outer$B"], nestmates::nest_members(&host)?);
+        let nest_members = host
+            .attributes
+            .iter()
+            .filter(|attribute| matches!(attribute, Attribute::NestMembers { .. }))
+            .count();
+        assert_eq!(1, nest_members);
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_to_unsupported_version() -> Result<()> {
+        let mut host = crate::test::class_file("Outer", VERSION_55_0)?;
+        host.version = Version::Java8 { minor: 0 };
+        let result = NestBuilder::new().add_to(
+            &mut host,
+            &mut [crate::test::class_file("Outer$A", VERSION_55_0)?],
+        );
+        assert_eq!(
+            Err(InvalidNest(
+                "nests are not supported by Java 8 in class Outer".to_string()
+            )),
+            result
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_to_duplicate_member() -> Result<()> {
+        let mut host = crate::test::class_file("Outer", VERSION_55_0)?;
+        let result = NestBuilder::new().member("Outer$A").add_to(
+            &mut host,
+            &mut [crate::test::class_file("Outer$A", VERSION_55_0)?],
+        );
+        assert_eq!(
+            Err(InvalidNest(
+                "class Outer$A is added to the nest more than once".to_string()
+            )),
+            result
+        );
+
+        let result = NestBuilder::new().add_to(
+            &mut host,
+            &mut [crate::test::class_file("Outer", VERSION_55_0)?],
+        );
+        assert_eq!(
+            Err(InvalidNest(
+                "class Outer is added to the nest more than once".to_string()
+            )),
+            result
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_to_member_of_another_nest() -> Result<()> {
+        let mut other = crate::test::class_file("Other", VERSION_55_0)?;
+        let mut members = vec![crate::test::class_file("Outer$A", VERSION_55_0)?];
+        NestBuilder::new().add_to(&mut other, &mut members)?;
+
+        let mut host = crate::test::class_file("Outer", VERSION_55_0)?;
+        let result = NestBuilder::new().add_to(&mut host, &mut members);
+        assert_eq!(
+            Err(InvalidNest(
+                "member Outer$A is a member of the nest of Other".to_string()
+            )),
+            result
+        );
+
+        let result = NestBuilder::new().add_to(&mut members[0], &mut []);
+        assert_eq!(
+            Err(InvalidNest(
+                "host Outer$A is a member of the nest of Other".to_string()
+            )),
+            result
+        );
+
+        let result = NestBuilder::new().add_to(&mut host, &mut [other]);
+        assert_eq!(
+            Err(InvalidNest(
+                "member Other is the host of another nest".to_string()
+            )),
+            result
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_to_private_access() -> Result<()> {
+        let mut member = crate::test::class_file("Outer$A", VERSION_55_0)?;
+        member.methods.push(Method {
+            access_flags: MethodAccessFlags::PRIVATE | MethodAccessFlags::ABSTRACT,
+            name_index: member.constant_pool.add_utf8("secret")?,
+            descriptor_index: member.constant_pool.add_utf8("()V")?,
+            attributes: Vec::new(),
+        });
+        let mut caller = crate::test::class_file("Outer$B", VERSION_55_0)?;
+        let class_index = caller.constant_pool.add_class("Outer$A")?;
+        caller
+            .constant_pool
+            .add_method_ref(class_index, "secret", "()V")?;
+
+        assert!(matches!(
+            ClassFile::verify_nestmates(&[&member, &caller]),
+            Err(VerificationError { .. })
+        ));
+        let mut host = crate::test::class_file("Outer", VERSION_55_0)?;
+        let mut members = vec![member, caller];
+        NestBuilder::new().add_to(&mut host, &mut members)?;
+        ClassFile::verify_nestmates(&[&host, &members[0], &members[1]])
+    }
+}
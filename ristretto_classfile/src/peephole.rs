@@ -0,0 +1,590 @@
+use crate::attributes::{Attribute, CodeEditor, ExceptionTableEntry, Instruction};
+use crate::class_file::ClassFile;
+use crate::constant_pool::ConstantPool;
+use crate::dead_code::{reachable, unreachable_ranges};
+use crate::error::Result;
+use crate::verifiers::bytecode::inference;
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+/// A replacement of a range of instructions.
+type Rewrite = (RangeInclusive<usize>, Vec<Instruction>);
+
+/// Apply peephole rewrites to the code of every method; see [`ClassFile::optimize`].
+pub(crate) fn optimize(class_file: &mut ClassFile) -> Result<()> {
+    let constant_pool = &class_file.constant_pool;
+    for method in &mut class_file.methods {
+        for attribute in &mut method.attributes {
+            if matches!(attribute, Attribute::Code { .. }) {
+                optimize_code(constant_pool, attribute)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Apply rewrites and remove unreachable instructions until the code no longer changes.  Code
+/// with subroutines (`jsr`/`ret`) or invalid branch targets is left unchanged.
+fn optimize_code(constant_pool: &ConstantPool, attribute: &mut Attribute) -> Result<()> {
+    let mut editor = CodeEditor::new(attribute)?;
+    loop {
+        let Some(targets) = branch_targets(editor.code(), editor.exception_table()) else {
+            return Ok(());
+        };
+        if let Some((range, instructions)) = rewrite(constant_pool, editor.code(), &targets)? {
+            editor.replace(range, instructions)?;
+            continue;
+        }
+        let Some(reachable) = reachable(editor.code(), editor.exception_table()) else {
+            return Ok(());
+        };
+        if reachable.iter().all(|reachable| *reachable) {
+            return Ok(());
+        }
+        // Ranges are deleted from the end of the code so that the earlier ranges are unchanged
+        for range in unreachable_ranges(&reachable).into_iter().rev() {
+            editor.delete(range)?;
+        }
+    }
+}
+
+/// Get the indexes of the instructions that are the target of a branch or exception handler.
+/// Returns `None` if the successors of an instruction cannot be determined.
+fn branch_targets(
+    code: &[Instruction],
+    exception_table: &[ExceptionTableEntry],
+) -> Option<HashSet<usize>> {
+    let mut targets = HashSet::new();
+    for index in 0..code.len() {
+        let successors = inference::successors(code, index).ok()?;
+        if successors != [index + 1] {
+            targets.extend(successors);
+        }
+    }
+    targets.extend(
+        exception_table
+            .iter()
+            .map(|entry| usize::from(entry.handler_pc)),
+    );
+    Some(targets)
+}
+
+/// Find the first rewrite that applies to the code.  The second instruction of a rewritten pair
+/// must not be a branch target, since a branch to it would skip the first instruction.
+fn rewrite(
+    constant_pool: &ConstantPool,
+    code: &[Instruction],
+    targets: &HashSet<usize>,
+) -> Result<Option<Rewrite>> {
+    for (index, instruction) in code.iter().enumerate() {
+        // Branches to a deleted instruction refer to the next instruction, so the last
+        // instruction is never deleted
+        let Some(next) = code.get(index + 1) else {
+            break;
+        };
+        if is_redundant(constant_pool, instruction, index)? {
+            return Ok(Some((index..=index, Vec::new())));
+        }
+        if targets.contains(&(index + 1)) {
+            continue;
+        }
+        if let Some(instructions) = rewrite_pair(instruction, next, index) {
+            return Ok(Some((index..=index + 1, instructions)));
+        }
+    }
+    Ok(None)
+}
+
+/// Returns true if the instruction has no effect.
+fn is_redundant(
+    constant_pool: &ConstantPool,
+    instruction: &Instruction,
+    index: usize,
+) -> Result<bool> {
+    let redundant = match instruction {
+        Instruction::Nop | Instruction::Iinc(_, 0) | Instruction::Iinc_w(_, 0) => true,
+        Instruction::Goto(target) => usize::from(*target) == index + 1,
+        Instruction::Goto_w(target) => usize::try_from(*target) == Ok(index + 1),
+        Instruction::Checkcast(class_index) => {
+            constant_pool.try_get_class(*class_index)? == "java/lang/Object"
+        }
+        _ => false,
+    };
+    Ok(redundant)
+}
+
+/// Get the instructions that replace a pair of instructions starting at `index`, if any.
+fn rewrite_pair(
+    first: &Instruction,
+    second: &Instruction,
+    index: usize,
+) -> Option<Vec<Instruction>> {
+    if let (Some(load), Some(store)) = (load_local(first), store_local(second)) {
+        // Storing the value of a local variable to the same local variable
+        return (load == store).then(Vec::new);
+    }
+    match (first, second) {
+        (Instruction::Dup, Instruction::Pop)
+        | (Instruction::Dup2, Instruction::Pop2)
+        | (Instruction::Swap, Instruction::Swap) => Some(Vec::new()),
+        (_, Instruction::Pop) if pushed_slots(first) == Some(1) => Some(Vec::new()),
+        (_, Instruction::Pop2) if pushed_slots(first) == Some(2) => Some(Vec::new()),
+        (Instruction::Checkcast(first_class), Instruction::Checkcast(second_class))
+            if first_class == second_class =>
+        {
+            Some(vec![first.clone()])
+        }
+        (Instruction::Aconst_null, Instruction::Checkcast(_)) => Some(vec![first.clone()]),
+        (Instruction::Aconst_null, Instruction::Ifnull(target)) => {
+            constant_branch(true, *target, index)
+        }
+        (Instruction::Aconst_null, Instruction::Ifnonnull(target)) => {
+            constant_branch(false, *target, index)
+        }
+        _ => {
+            let value = int_constant(first)?;
+            let (taken, target) = match second {
+                Instruction::Ifeq(target) => (value == 0, target),
+                Instruction::Ifne(target) => (value != 0, target),
+                Instruction::Iflt(target) => (value < 0, target),
+                Instruction::Ifge(target) => (value >= 0, target),
+                Instruction::Ifgt(target) => (value > 0, target),
+                Instruction::Ifle(target) => (value <= 0, target),
+                _ => return None,
+            };
+            constant_branch(taken, *target, index)
+        }
+    }
+}
+
+/// Get the replacement for a constant followed by a conditional branch at `index`; a branch that
+/// is always taken becomes a `goto` and a branch that is never taken is removed.
+fn constant_branch(taken: bool, target: u16, index: usize) -> Option<Vec<Instruction>> {
+    if !taken {
+        return Some(Vec::new());
+    }
+    // The pair is replaced by a single instruction, so targets after the pair move back by one
+    let target = usize::from(target);
+    let target = if target <= index {
+        target
+    } else if target >= index + 2 {
+        target - 1
+    } else {
+        return None;
+    };
+    Some(vec![Instruction::Goto(u16::try_from(target).ok()?)])
+}
+
+/// Get the number of operand stack slots pushed by an instruction that only pushes a constant or
+/// a local variable.
+fn pushed_slots(instruction: &Instruction) -> Option<u8> {
+    if int_constant(instruction).is_some() {
+        return Some(1);
+    }
+    if let Some((local_type, _)) = load_local(instruction) {
+        return Some(local_type.slots());
+    }
+    match instruction {
+        Instruction::Aconst_null
+        | Instruction::Fconst_0
+        | Instruction::Fconst_1
+        | Instruction::Fconst_2 => Some(1),
+        Instruction::Lconst_0
+        | Instruction::Lconst_1
+        | Instruction::Dconst_0
+        | Instruction::Dconst_1 => Some(2),
+        _ => None,
+    }
+}
+
+/// Get the value pushed by an `int` constant instruction.
+fn int_constant(instruction: &Instruction) -> Option<i32> {
+    let value = match instruction {
+        Instruction::Iconst_m1 => -1,
+        Instruction::Iconst_0 => 0,
+        Instruction::Iconst_1 => 1,
+        Instruction::Iconst_2 => 2,
+        Instruction::Iconst_3 => 3,
+        Instruction::Iconst_4 => 4,
+        Instruction::Iconst_5 => 5,
+        Instruction::Bipush(value) => i32::from(*value),
+        Instruction::Sipush(value) => i32::from(*value),
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Type of a local variable accessed by a load or store instruction.
+#[derive(Debug, PartialEq)]
+enum LocalType {
+    Int,
+    Long,
+    Float,
+    Double,
+    Reference,
+}
+
+impl LocalType {
+    /// Get the number of operand stack slots used by a value of the type.
+    fn slots(&self) -> u8 {
+        match self {
+            LocalType::Long | LocalType::Double => 2,
+            LocalType::Int | LocalType::Float | LocalType::Reference => 1,
+        }
+    }
+}
+
+/// Get the type and index of the local variable loaded by an instruction.
+fn load_local(instruction: &Instruction) -> Option<(LocalType, u16)> {
+    let local = match instruction {
+        Instruction::Iload(index) => (LocalType::Int, u16::from(*index)),
+        Instruction::Iload_w(index) => (LocalType::Int, *index),
+        Instruction::Iload_0 => (LocalType::Int, 0),
+        Instruction::Iload_1 => (LocalType::Int, 1),
+        Instruction::Iload_2 => (LocalType::Int, 2),
+        Instruction::Iload_3 => (LocalType::Int, 3),
+        Instruction::Lload(index) => (LocalType::Long, u16::from(*index)),
+        Instruction::Lload_w(index) => (LocalType::Long, *index),
+        Instruction::Lload_0 => (LocalType::Long, 0),
+        Instruction::Lload_1 => (LocalType::Long, 1),
+        Instruction::Lload_2 => (LocalType::Long, 2),
+        Instruction::Lload_3 => (LocalType::Long, 3),
+        Instruction::Fload(index) => (LocalType::Float, u16::from(*index)),
+        Instruction::Fload_w(index) => (LocalType::Float, *index),
+        Instruction::Fload_0 => (LocalType::Float, 0),
+        Instruction::Fload_1 => (LocalType::Float, 1),
+        Instruction::Fload_2 => (LocalType::Float, 2),
+        Instruction::Fload_3 => (LocalType::Float, 3),
+        Instruction::Dload(index) => (LocalType::Double, u16::from(*index)),
+        Instruction::Dload_w(index) => (LocalType::Double, *index),
+        Instruction::Dload_0 => (LocalType::Double, 0),
+        Instruction::Dload_1 => (LocalType::Double, 1),
+        Instruction::Dload_2 => (LocalType::Double, 2),
+        Instruction::Dload_3 => (LocalType::Double, 3),
+        Instruction::Aload(index) => (LocalType::Reference, u16::from(*index)),
+        Instruction::Aload_w(index) => (LocalType::Reference, *index),
+        Instruction::Aload_0 => (LocalType::Reference, 0),
+        Instruction::Aload_1 => (LocalType::Reference, 1),
+        Instruction::Aload_2 => (LocalType::Reference, 2),
+        Instruction::Aload_3 => (LocalType::Reference, 3),
+        _ => return None,
+    };
+    Some(local)
+}
+
+/// Get the type and index of the local variable stored by an instruction.
+fn store_local(instruction: &Instruction) -> Option<(LocalType, u16)> {
+    let local = match instruction {
+        Instruction::Istore(index) => (LocalType::Int, u16::from(*index)),
+        Instruction::Istore_w(index) => (LocalType::Int, *index),
+        Instruction::Istore_0 => (LocalType::Int, 0),
+        Instruction::Istore_1 => (LocalType::Int, 1),
+        Instruction::Istore_2 => (LocalType::Int, 2),
+        Instruction::Istore_3 => (LocalType::Int, 3),
+        Instruction::Lstore(index) => (LocalType::Long, u16::from(*index)),
+        Instruction::Lstore_w(index) => (LocalType::Long, *index),
+        Instruction::Lstore_0 => (LocalType::Long, 0),
+        Instruction::Lstore_1 => (LocalType::Long, 1),
+        Instruction::Lstore_2 => (LocalType::Long, 2),
+        Instruction::Lstore_3 => (LocalType::Long, 3),
+        Instruction::Fstore(index) => (LocalType::Float, u16::from(*index)),
+        Instruction::Fstore_w(index) => (LocalType::Float, *index),
+        Instruction::Fstore_0 => (LocalType::Float, 0),
+        Instruction::Fstore_1 => (LocalType::Float, 1),
+        Instruction::Fstore_2 => (LocalType::Float, 2),
+        Instruction::Fstore_3 => (LocalType::Float, 3),
+        Instruction::Dstore(index) => (LocalType::Double, u16::from(*index)),
+        Instruction::Dstore_w(index) => (LocalType::Double, *index),
+        Instruction::Dstore_0 => (LocalType::Double, 0),
+        Instruction::Dstore_1 => (LocalType::Double, 1),
+        Instruction::Dstore_2 => (LocalType::Double, 2),
+        Instruction::Dstore_3 => (LocalType::Double, 3),
+        Instruction::Astore(index) => (LocalType::Reference, u16::from(*index)),
+        Instruction::Astore_w(index) => (LocalType::Reference, *index),
+        Instruction::Astore_0 => (LocalType::Reference, 0),
+        Instruction::Astore_1 => (LocalType::Reference, 1),
+        Instruction::Astore_2 => (LocalType::Reference, 2),
+        Instruction::Astore_3 => (LocalType::Reference, 3),
+        _ => return None,
+    };
+    Some(local)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::attributes::{LineNumber, StackFrame};
+    use crate::method::Method;
+    use crate::method_access_flags::MethodAccessFlags;
+    use std::io::Cursor;
+
+    const OBJECT: u16 = 2;
+    const STRING: u16 = 4;
+
+    fn optimize_instructions(code: Vec<Instruction>) -> Result<Vec<Instruction>> {
+        let mut constant_pool = ConstantPool::default();
+        assert_eq!(OBJECT, constant_pool.add_class("java/lang/Object")?);
+        assert_eq!(STRING, constant_pool.add_class("java/lang/String")?);
+        let mut attribute = Attribute::Code {
+            name_index: 0,
+            max_stack: 2,
+            max_locals: 2,
+            code,
+            exception_table: Vec::new(),
+            attributes: Vec::new(),
+        };
+        optimize_code(&constant_pool, &mut attribute)?;
+        let Attribute::Code { code, .. } = attribute else {
+            unreachable!()
+        };
+        Ok(code)
+    }
+
+    #[test]
+    fn test_remove_redundant_instructions() -> Result<()> {
+        let code = vec![
+            Instruction::Nop,
+            Instruction::Iinc(1, 0),
+            Instruction::Goto(3),
+            Instruction::Aload_0,
+            Instruction::Checkcast(OBJECT),
+            Instruction::Areturn,
+        ];
+        assert_eq!(
+            vec![Instruction::Aload_0, Instruction::Areturn],
+            optimize_instructions(code)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_redundant_pairs() -> Result<()> {
+        let code = vec![
+            Instruction::Iload_1,
+            Instruction::Istore(1),
+            Instruction::Aload_0,
+            Instruction::Dup,
+            Instruction::Pop,
+            Instruction::Checkcast(STRING),
+            Instruction::Checkcast(STRING),
+            Instruction::Aconst_null,
+            Instruction::Checkcast(STRING),
+            Instruction::Swap,
+            Instruction::Swap,
+            Instruction::Pop,
+            Instruction::Lload_0,
+            Instruction::Pop2,
+            Instruction::Areturn,
+        ];
+        assert_eq!(
+            vec![
+                Instruction::Aload_0,
+                Instruction::Checkcast(STRING),
+                Instruction::Areturn,
+            ],
+            optimize_instructions(code)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_store_different_local() -> Result<()> {
+        let code = vec![
+            Instruction::Iload_0,
+            Instruction::Istore_1,
+            Instruction::Lload(2),
+            Instruction::Dstore(2),
+            Instruction::Return,
+        ];
+        assert_eq!(code.clone(), optimize_instructions(code)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_constant_branch_taken() -> Result<()> {
+        // iconst_0; ifeq is always taken and the instructions skipped by the branch are removed
+        let code = vec![
+            Instruction::Iconst_0,
+            Instruction::Ifeq(4),
+            Instruction::Iconst_1,
+            Instruction::Ireturn,
+            Instruction::Iconst_2,
+            Instruction::Ireturn,
+        ];
+        assert_eq!(
+            vec![Instruction::Iconst_2, Instruction::Ireturn],
+            optimize_instructions(code)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_constant_branch_not_taken() -> Result<()> {
+        let code = vec![
+            Instruction::Aconst_null,
+            Instruction::Ifnonnull(4),
+            Instruction::Bipush(-1),
+            Instruction::Iflt(5),
+            Instruction::Iconst_1,
+            Instruction::Iconst_2,
+            Instruction::Ireturn,
+        ];
+        assert_eq!(
+            vec![Instruction::Iconst_2, Instruction::Ireturn],
+            optimize_instructions(code)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_branch_target_not_rewritten() -> Result<()> {
+        // The istore is a branch target, so the iload/istore pair is not removed
+        let code = vec![
+            Instruction::Iload_0,
+            Instruction::Ifeq(3),
+            Instruction::Iload_1,
+            Instruction::Istore_1,
+            Instruction::Iload_1,
+            Instruction::Ireturn,
+        ];
+        assert_eq!(code.clone(), optimize_instructions(code)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_subroutines_unchanged() -> Result<()> {
+        let code = vec![
+            Instruction::Nop,
+            Instruction::Jsr(3),
+            Instruction::Return,
+            Instruction::Astore_1,
+            Instruction::Ret(1),
+        ];
+        assert_eq!(code.clone(), optimize_instructions(code)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_offsets_rewritten() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let code_index = constant_pool.add_utf8("Code")?;
+        let line_number_table_index = constant_pool.add_utf8("LineNumberTable")?;
+        let stack_map_table_index = constant_pool.add_utf8("StackMapTable")?;
+        let mut attribute = Attribute::Code {
+            name_index: code_index,
+            max_stack: 1,
+            max_locals: 1,
+            code: vec![
+                Instruction::Nop,
+                Instruction::Iload_0,
+                Instruction::Ifeq(4),
+                Instruction::Nop,
+                Instruction::Return,
+            ],
+            exception_table: Vec::new(),
+            attributes: vec![
+                Attribute::LineNumberTable {
+                    name_index: line_number_table_index,
+                    line_numbers: vec![
+                        LineNumber {
+                            start_pc: 0,
+                            line_number: 1,
+                        },
+                        LineNumber {
+                            start_pc: 4,
+                            line_number: 2,
+                        },
+                    ],
+                },
+                Attribute::StackMapTable {
+                    name_index: stack_map_table_index,
+                    frames: vec![StackFrame::SameFrame { frame_type: 4 }],
+                },
+            ],
+        };
+        optimize_code(&constant_pool, &mut attribute)?;
+        let Attribute::Code {
+            code, attributes, ..
+        } = attribute
+        else {
+            unreachable!()
+        };
+        assert_eq!(
+            vec![
+                Instruction::Iload_0,
+                Instruction::Ifeq(2),
+                Instruction::Return
+            ],
+            code
+        );
+        assert_eq!(
+            vec![
+                Attribute::LineNumberTable {
+                    name_index: line_number_table_index,
+                    line_numbers: vec![
+                        LineNumber {
+                            start_pc: 0,
+                            line_number: 1,
+                        },
+                        LineNumber {
+                            start_pc: 2,
+                            line_number: 2,
+                        },
+                    ],
+                },
+                Attribute::StackMapTable {
+                    name_index: stack_map_table_index,
+                    frames: vec![StackFrame::SameFrame { frame_type: 2 }],
+                },
+            ],
+            attributes
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_optimize_class_file() -> Result<()> {
+        let class_bytes = include_bytes!("../../classes/Expressions.class");
+        let expected = ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))?;
+        let mut class_file = expected.clone();
+        class_file.optimize()?;
+        class_file.verify()?;
+        // javac does not generate code that these rewrites apply to
+        assert_eq!(expected, class_file);
+        Ok(())
+    }
+
+    #[test]
+    fn test_optimize_method() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let this_class = constant_pool.add_class("Foo")?;
+        let code_index = constant_pool.add_utf8("Code")?;
+        let name_index = constant_pool.add_utf8("test")?;
+        let descriptor_index = constant_pool.add_utf8("()V")?;
+        let method = Method {
+            access_flags: MethodAccessFlags::STATIC,
+            name_index,
+            descriptor_index,
+            attributes: vec![Attribute::Code {
+                name_index: code_index,
+                max_stack: 0,
+                max_locals: 0,
+                code: vec![Instruction::Nop, Instruction::Return],
+                exception_table: Vec::new(),
+                attributes: Vec::new(),
+            }],
+        };
+        let mut class_file = ClassFile {
+            constant_pool,
+            this_class,
+            methods: vec![method],
+            ..Default::default()
+        };
+        class_file.optimize()?;
+        let Some((_, code, _)) = inference::code(&class_file.methods[0]) else {
+            unreachable!()
+        };
+        assert_eq!(&vec![Instruction::Return], code);
+        Ok(())
+    }
+}
@@ -0,0 +1,620 @@
+use crate::attributes::{Attribute, BootstrapMethod, Instruction, Record};
+use crate::builder::{internal_name, load_instruction, return_instruction, Constants};
+use crate::class_access_flags::ClassAccessFlags;
+use crate::class_file::ClassFile;
+use crate::constant::Constant;
+use crate::error::Error::InvalidRecord;
+use crate::error::Result;
+use crate::field::Field;
+use crate::field_access_flags::FieldAccessFlags;
+use crate::field_type::FieldType;
+use crate::method::Method;
+use crate::method_access_flags::MethodAccessFlags;
+use crate::reference_kind::ReferenceKind;
+use crate::version::Version;
+
+/// Super class of every record class.
+const RECORD: &str = "java/lang/Record";
+
+/// Class that bootstraps the `toString`, `hashCode` and `equals` methods of record classes.
+const OBJECT_METHODS: &str = "java/lang/runtime/ObjectMethods";
+const OBJECT_METHODS_BOOTSTRAP_DESCRIPTOR: &str = "(Ljava/lang/invoke/MethodHandles$Lookup;\
+    Ljava/lang/String;Ljava/lang/invoke/TypeDescriptor;Ljava/lang/Class;Ljava/lang/String;\
+    [Ljava/lang/invoke/MethodHandle;)Ljava/lang/Object;";
+
+/// Names of `java.lang.Object` methods that cannot be used as record component names.
+///
+/// See: <https://docs.oracle.com/javase/specs/jls/se23/html/jls-8.html#jls-8.10.1>
+const RESERVED_COMPONENT_NAMES: [&str; 8] = [
+    "clone",
+    "finalize",
+    "getClass",
+    "hashCode",
+    "notify",
+    "notifyAll",
+    "toString",
+    "wait",
+];
+
+/// Class access flags that cannot be set on a record class.
+const INVALID_ACCESS_FLAGS: ClassAccessFlags = ClassAccessFlags::INTERFACE
+    .union(ClassAccessFlags::ABSTRACT)
+    .union(ClassAccessFlags::ANNOTATION)
+    .union(ClassAccessFlags::ENUM)
+    .union(ClassAccessFlags::MODULE);
+
+/// Builder for record classes.
+///
+/// The generated class extends `java.lang.Record` and contains a `Record` attribute describing
+/// the components, a private final field for each component, the canonical constructor, an
+/// accessor method for each component and `toString`, `hashCode` and `equals` methods that are
+/// bootstrapped with `java.lang.runtime.ObjectMethods`, matching the class files generated by
+/// `javac`.  Class names may be specified in either their binary (e.g. `com.example.Point`) or
+/// internal (e.g. `com/example/Point`) form.
+///
+/// # Examples
+///
+/// ```rust
+/// use ristretto_classfile::{BaseType, FieldType, RecordBuilder, Result};
+///
+/// fn main() -> Result<()> {
+///     let class_file = RecordBuilder::new("com.example.Point")
+///         .component("x", FieldType::Base(BaseType::Int))
+///         .component("y", FieldType::Base(BaseType::Int))
+///         .build()?;
+///     assert_eq!("com/example/Point", class_file.class_name()?);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RecordBuilder {
+    java_version: Version,
+    name: String,
+    access_flags: ClassAccessFlags,
+    interfaces: Vec<String>,
+    components: Vec<(String, FieldType)>,
+}
+
+impl RecordBuilder {
+    /// Create a new builder for the named public record class.
+    #[must_use]
+    pub fn new<S: AsRef<str>>(name: S) -> Self {
+        RecordBuilder {
+            java_version: Version::Java16 { minor: 0 },
+            name: internal_name(name),
+            access_flags: ClassAccessFlags::PUBLIC,
+            interfaces: Vec::new(),
+            components: Vec::new(),
+        }
+    }
+
+    /// Set the class file version; defaults to Java 16, the first version that supports records.
+    #[must_use]
+    pub fn java_version(mut self, java_version: Version) -> Self {
+        self.java_version = java_version;
+        self
+    }
+
+    /// Set the class access flags; [`ClassAccessFlags::FINAL`] and [`ClassAccessFlags::SUPER`]
+    /// are always set.
+    #[must_use]
+    pub fn access_flags(mut self, access_flags: ClassAccessFlags) -> Self {
+        self.access_flags = access_flags;
+        self
+    }
+
+    /// Add an interface that the record implements.
+    #[must_use]
+    pub fn interface<S: AsRef<str>>(mut self, interface: S) -> Self {
+        self.interfaces.push(internal_name(interface));
+        self
+    }
+
+    /// Add a record component; components are added to the canonical constructor in the order
+    /// they are declared.
+    #[must_use]
+    pub fn component<S: AsRef<str>>(mut self, name: S, field_type: FieldType) -> Self {
+        self.components
+            .push((name.as_ref().to_string(), field_type));
+        self
+    }
+
+    /// Build and verify the record class file.
+    ///
+    /// # Errors
+    /// - If the class version does not support records.
+    /// - If the class name is empty or the access flags are not valid for a record class.
+    /// - If a component name is empty, reserved or declared more than once.
+    /// - If the components require more than 254 local variable slots in the canonical
+    ///   constructor.
+    /// - If the constant pool exceeds 65,534 constants.
+    /// - If the class file cannot be verified.
+    pub fn build(&self) -> Result<ClassFile> {
+        self.validate()?;
+
+        let mut constants = Constants::default();
+        let this_class = constants.class(&self.name)?;
+        let super_class = constants.class(RECORD)?;
+        let mut interfaces = Vec::new();
+        for interface in &self.interfaces {
+            interfaces.push(constants.class(interface)?);
+        }
+
+        let mut fields = Vec::new();
+        let mut records = Vec::new();
+        for (name, field_type) in &self.components {
+            let name_index = constants.utf8(name)?;
+            let descriptor_index = constants.utf8(&field_type.descriptor())?;
+            fields.push(Field {
+                access_flags: FieldAccessFlags::PRIVATE | FieldAccessFlags::FINAL,
+                name_index,
+                descriptor_index,
+                field_type: field_type.clone(),
+                attributes: Vec::new(),
+            });
+            records.push(Record {
+                name_index,
+                descriptor_index,
+                attributes: Vec::new(),
+            });
+        }
+        let record = Attribute::Record {
+            name_index: constants.utf8("Record")?,
+            records,
+        };
+        if !record.valid_for_version(&self.java_version) {
+            return Err(InvalidRecord(format!(
+                "records are not supported by {}",
+                self.java_version
+            )));
+        }
+
+        let mut methods = vec![self.canonical_constructor(&mut constants)?];
+        methods.extend(self.object_methods(&mut constants)?);
+        for (name, field_type) in &self.components {
+            methods.push(self.accessor(&mut constants, name, field_type)?);
+        }
+
+        let bootstrap_methods = Attribute::BootstrapMethods {
+            name_index: constants.utf8("BootstrapMethods")?,
+            methods: vec![self.object_methods_bootstrap(&mut constants)?],
+        };
+
+        let class_file = ClassFile {
+            version: self.java_version.clone(),
+            constant_pool: constants.constant_pool,
+            access_flags: self.access_flags | ClassAccessFlags::FINAL | ClassAccessFlags::SUPER,
+            this_class,
+            super_class,
+            interfaces,
+            fields,
+            methods,
+            attributes: vec![record, bootstrap_methods],
+        };
+        class_file.verify()?;
+        Ok(class_file)
+    }
+
+    /// Validate the record declaration.
+    fn validate(&self) -> Result<()> {
+        if self.name.is_empty() {
+            return Err(InvalidRecord("class name is empty".to_string()));
+        }
+        if self.access_flags.intersects(INVALID_ACCESS_FLAGS) {
+            return Err(InvalidRecord(format!(
+                "invalid access flags {}",
+                self.access_flags
+            )));
+        }
+
+        let mut names = Vec::new();
+        let mut slots = 1;
+        for (name, field_type) in &self.components {
+            if name.is_empty() {
+                return Err(InvalidRecord("component name is empty".to_string()));
+            }
+            if RESERVED_COMPONENT_NAMES.contains(&name.as_str()) {
+                return Err(InvalidRecord(format!("component name {name} is reserved")));
+            }
+            if names.contains(&name) {
+                return Err(InvalidRecord(format!(
+                    "component {name} is declared more than once"
+                )));
+            }
+            names.push(name);
+            slots += field_type.slot_size();
+        }
+        if slots > usize::from(u8::MAX) {
+            return Err(InvalidRecord(format!(
+                "components require {slots} local variable slots; the maximum is 255"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Get the method descriptor of the canonical constructor.
+    fn constructor_descriptor(&self) -> String {
+        let parameters = self
+            .components
+            .iter()
+            .map(|(_, field_type)| field_type.descriptor())
+            .collect::<String>();
+        format!("({parameters})V")
+    }
+
+    /// Create the canonical constructor, which assigns each parameter to the component field.
+    fn canonical_constructor(&self, constants: &mut Constants) -> Result<Method> {
+        let record_constructor = constants.method_ref(RECORD, "<init>", "()V")?;
+        let mut code = vec![
+            Instruction::Aload_0,
+            Instruction::Invokespecial(record_constructor),
+        ];
+        let mut slot = 1;
+        let mut max_stack = 1;
+        for (name, field_type) in &self.components {
+            let field = constants.field_ref(&self.name, name, &field_type.descriptor())?;
+            code.push(Instruction::Aload_0);
+            code.push(load_instruction(field_type, u8::try_from(slot)?));
+            code.push(Instruction::Putfield(field));
+            slot += field_type.slot_size();
+            max_stack = max_stack.max(1 + field_type.slot_size());
+        }
+        code.push(Instruction::Return);
+
+        let access_flags = if self.access_flags.contains(ClassAccessFlags::PUBLIC) {
+            MethodAccessFlags::PUBLIC
+        } else {
+            MethodAccessFlags::empty()
+        };
+        let descriptor = self.constructor_descriptor();
+        method(
+            constants,
+            access_flags,
+            "<init>",
+            &descriptor,
+            (u16::try_from(max_stack)?, u16::try_from(slot)?),
+            code,
+        )
+    }
+
+    /// Create the `toString`, `hashCode` and `equals` methods, which are implemented with
+    /// `invokedynamic` instructions that are linked by the bootstrap method of the class.
+    fn object_methods(&self, constants: &mut Constants) -> Result<Vec<Method>> {
+        let this = format!("L{};", self.name);
+        let access_flags = MethodAccessFlags::PUBLIC | MethodAccessFlags::FINAL;
+
+        let to_string = invoke_dynamic(
+            constants,
+            "toString",
+            &format!("({this})Ljava/lang/String;"),
+        )?;
+        let to_string = method(
+            constants,
+            access_flags,
+            "toString",
+            "()Ljava/lang/String;",
+            (1, 1),
+            vec![
+                Instruction::Aload_0,
+                Instruction::Invokedynamic(to_string),
+                Instruction::Areturn,
+            ],
+        )?;
+
+        let hash_code = invoke_dynamic(constants, "hashCode", &format!("({this})I"))?;
+        let hash_code = method(
+            constants,
+            access_flags,
+            "hashCode",
+            "()I",
+            (1, 1),
+            vec![
+                Instruction::Aload_0,
+                Instruction::Invokedynamic(hash_code),
+                Instruction::Ireturn,
+            ],
+        )?;
+
+        let equals = invoke_dynamic(constants, "equals", &format!("({this}Ljava/lang/Object;)Z"))?;
+        let equals = method(
+            constants,
+            access_flags,
+            "equals",
+            "(Ljava/lang/Object;)Z",
+            (2, 2),
+            vec![
+                Instruction::Aload_0,
+                Instruction::Aload_1,
+                Instruction::Invokedynamic(equals),
+                Instruction::Ireturn,
+            ],
+        )?;
+
+        Ok(vec![to_string, hash_code, equals])
+    }
+
+    /// Create the bootstrap method that links the `toString`, `hashCode` and `equals` methods;
+    /// the arguments are the record class, the component names separated by `;` and a getter
+    /// method handle for each component field.
+    fn object_methods_bootstrap(&self, constants: &mut Constants) -> Result<BootstrapMethod> {
+        let bootstrap = constants.method_ref(
+            OBJECT_METHODS,
+            "bootstrap",
+            OBJECT_METHODS_BOOTSTRAP_DESCRIPTOR,
+        )?;
+        let bootstrap_method_ref =
+            constants.method_handle(ReferenceKind::InvokeStatic, bootstrap)?;
+
+        let names = self
+            .components
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>()
+            .join(";");
+        let mut arguments = vec![constants.class(&self.name)?, constants.string(&names)?];
+        for (name, field_type) in &self.components {
+            let field = constants.field_ref(&self.name, name, &field_type.descriptor())?;
+            arguments.push(constants.method_handle(ReferenceKind::GetField, field)?);
+        }
+
+        Ok(BootstrapMethod {
+            bootstrap_method_ref,
+            arguments,
+        })
+    }
+
+    /// Create the accessor method of a component, which returns the value of the component field.
+    fn accessor(
+        &self,
+        constants: &mut Constants,
+        name: &str,
+        field_type: &FieldType,
+    ) -> Result<Method> {
+        let descriptor = field_type.descriptor();
+        let field = constants.field_ref(&self.name, name, &descriptor)?;
+        let max_stack = u16::try_from(field_type.slot_size())?;
+        method(
+            constants,
+            MethodAccessFlags::PUBLIC,
+            name,
+            &format!("(){descriptor}"),
+            (max_stack, 1),
+            vec![
+                Instruction::Aload_0,
+                Instruction::Getfield(field),
+                return_instruction(field_type),
+            ],
+        )
+    }
+}
+
+/// Create a method with a `Code` attribute; `limits` is the maximum stack depth and number of
+/// local variables of the code.
+fn method(
+    constants: &mut Constants,
+    access_flags: MethodAccessFlags,
+    name: &str,
+    descriptor: &str,
+    limits: (u16, u16),
+    code: Vec<Instruction>,
+) -> Result<Method> {
+    let (max_stack, max_locals) = limits;
+    Ok(Method {
+        access_flags,
+        name_index: constants.utf8(name)?,
+        descriptor_index: constants.utf8(descriptor)?,
+        attributes: vec![Attribute::Code {
+            name_index: constants.utf8("Code")?,
+            max_stack,
+            max_locals,
+            code,
+            exception_table: Vec::new(),
+            attributes: Vec::new(),
+        }],
+    })
+}
+
+/// Add an invoke dynamic constant that is linked by the first bootstrap method of the class.
+fn invoke_dynamic(constants: &mut Constants, name: &str, descriptor: &str) -> Result<u16> {
+    let name_and_type_index = constants.name_and_type(name, descriptor)?;
+    constants.constant_pool.add(Constant::InvokeDynamic {
+        bootstrap_method_attr_index: 0,
+        name_and_type_index,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::base_type::BaseType;
+    use std::io::Cursor;
+
+    fn point() -> RecordBuilder {
+        RecordBuilder::new("com.example.Point")
+            .component("x", FieldType::Base(BaseType::Int))
+            .component("y", FieldType::Base(BaseType::Long))
+            .component("label", FieldType::Object("java/lang/String".to_string()))
+    }
+
+    fn method_names(class_file: &ClassFile) -> Result<Vec<(String, String)>> {
+        let constant_pool = &class_file.constant_pool;
+        class_file
+            .methods
+            .iter()
+            .map(|method| {
+                let name = constant_pool.try_get_utf8(method.name_index)?.clone();
+                let descriptor = constant_pool.try_get_utf8(method.descriptor_index)?.clone();
+                Ok((name, descriptor))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_build() -> Result<()> {
+        let class_file = point().interface("java.io.Serializable").build()?;
+        let constant_pool = &class_file.constant_pool;
+
+        assert_eq!(Version::Java16 { minor: 0 }, class_file.version);
+        assert_eq!(
+            ClassAccessFlags::PUBLIC | ClassAccessFlags::FINAL | ClassAccessFlags::SUPER,
+            class_file.access_flags
+        );
+        assert_eq!("com/example/Point", class_file.class_name()?);
+        assert_eq!(RECORD, constant_pool.try_get_class(class_file.super_class)?);
+        assert_eq!(1, class_file.interfaces.len());
+        assert_eq!(3, class_file.fields.len());
+        assert!(
+            class_file
+                .fields
+                .iter()
+                .all(|field| field.access_flags
+                    == FieldAccessFlags::PRIVATE | FieldAccessFlags::FINAL)
+        );
+
+        let expected = [
+            ("<init>", "(IJLjava/lang/String;)V"),
+            ("toString", "()Ljava/lang/String;"),
+            ("hashCode", "()I"),
+            ("equals", "(Ljava/lang/Object;)Z"),
+            ("x", "()I"),
+            ("y", "()J"),
+            ("label", "()Ljava/lang/String;"),
+        ]
+        .map(|(name, descriptor)| (name.to_string(), descriptor.to_string()));
+        assert_eq!(expected.to_vec(), method_names(&class_file)?);
+
+        let Some(Attribute::Record { records, .. }) = class_file.attributes.first() else {
+            panic!("expected Record attribute");
+        };
+        let components = records
+            .iter()
+            .map(|record| constant_pool.try_get_utf8(record.name_index))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(vec!["x", "y", "label"], components);
+
+        let Some(Attribute::BootstrapMethods { methods, .. }) = class_file.attributes.get(1) else {
+            panic!("expected BootstrapMethods attribute");
+        };
+        assert_eq!(5, methods[0].arguments.len());
+        assert_eq!(
+            "x;y;label",
+            constant_pool.try_get_string(methods[0].arguments[1])?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_canonical_constructor() -> Result<()> {
+        let class_file = point().build()?;
+        let Some(Attribute::Code {
+            max_stack,
+            max_locals,
+            code,
+            ..
+        }) = class_file.methods[0].attributes.first()
+        else {
+            panic!("expected Code attribute");
+        };
+        assert_eq!(3, *max_stack);
+        assert_eq!(5, *max_locals);
+        let loads = code
+            .iter()
+            .filter(|instruction| {
+                matches!(
+                    instruction,
+                    Instruction::Iload(1) | Instruction::Lload(2) | Instruction::Aload(4)
+                )
+            })
+            .count();
+        assert_eq!(3, loads);
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_bytes() -> Result<()> {
+        let class_file = point().build()?;
+        let mut bytes = Vec::new();
+        class_file.to_bytes(&mut bytes)?;
+        let mut bytes = Cursor::new(bytes);
+        assert_eq!(class_file, ClassFile::from_bytes(&mut bytes)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_record() -> Result<()> {
+        let class_file = RecordBuilder::new("Empty")
+            .access_flags(ClassAccessFlags::empty())
+            .build()?;
+        assert_eq!(
+            ClassAccessFlags::FINAL | ClassAccessFlags::SUPER,
+            class_file.access_flags
+        );
+        assert_eq!(
+            MethodAccessFlags::empty(),
+            class_file.methods[0].access_flags
+        );
+        assert_eq!(4, class_file.methods.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_version() {
+        let result = point().java_version(Version::Java15 { minor: 0 }).build();
+        assert_eq!(
+            Err(InvalidRecord(
+                "records are not supported by Java 15".to_string()
+            )),
+            result
+        );
+    }
+
+    #[test]
+    fn test_invalid_access_flags() {
+        let result = point().access_flags(ClassAccessFlags::ABSTRACT).build();
+        assert!(matches!(result, Err(InvalidRecord(_))));
+    }
+
+    #[test]
+    fn test_invalid_components() {
+        let int = FieldType::Base(BaseType::Int);
+        let empty = RecordBuilder::new("Foo").component("", int.clone()).build();
+        assert_eq!(
+            Err(InvalidRecord("component name is empty".to_string())),
+            empty
+        );
+        let reserved = RecordBuilder::new("Foo")
+            .component("hashCode", int.clone())
+            .build();
+        assert_eq!(
+            Err(InvalidRecord(
+                "component name hashCode is reserved".to_string()
+            )),
+            reserved
+        );
+        let duplicate = RecordBuilder::new("Foo")
+            .component("x", int.clone())
+            .component("x", int)
+            .build();
+        assert_eq!(
+            Err(InvalidRecord(
+                "component x is declared more than once".to_string()
+            )),
+            duplicate
+        );
+    }
+
+    #[test]
+    fn test_too_many_slots() {
+        let mut builder = RecordBuilder::new("Foo");
+        for i in 0..128 {
+            builder = builder.component(format!("c{i}"), FieldType::Base(BaseType::Long));
+        }
+        let result = builder.build();
+        assert_eq!(
+            Err(InvalidRecord(
+                "components require 257 local variable slots; the maximum is 255".to_string()
+            )),
+            result
+        );
+    }
+}
@@ -0,0 +1,440 @@
+use crate::attributes::{
+    Annotation, AnnotationElement, Attribute, Instruction, StackFrame, VerificationType,
+};
+use crate::class_file::ClassFile;
+use crate::constant_pool::ConstantPool;
+use crate::error::Result;
+use crate::field::Field;
+use crate::method::Method;
+use crate::Error::UnknownAttribute;
+
+/// Call the function with a mutable reference to every constant pool index referenced by the
+/// class file outside of the constant pool; i.e. by the class, fields, methods, attributes and
+/// instructions.  Indexes of 0, which represent the absence of a reference, are included.
+///
+/// # Errors
+/// Returns an error if the class file contains an unknown attribute, since the constant pool
+/// references within an unknown attribute cannot be determined, or if the function returns an
+/// error.
+pub(crate) fn for_each_reference<F>(class_file: &mut ClassFile, function: &mut F) -> Result<()>
+where
+    F: FnMut(&mut u16) -> Result<()>,
+{
+    function(&mut class_file.this_class)?;
+    function(&mut class_file.super_class)?;
+    for interface in &mut class_file.interfaces {
+        function(interface)?;
+    }
+    for field in &mut class_file.fields {
+        for_each_field_reference(&class_file.constant_pool, field, function)?;
+    }
+    for method in &mut class_file.methods {
+        for_each_method_reference(&class_file.constant_pool, method, function)?;
+    }
+    attributes(
+        &class_file.constant_pool,
+        &mut class_file.attributes,
+        function,
+    )
+}
+
+/// Call the function with a mutable reference to every constant pool index referenced by a field
+/// and its attributes.
+///
+/// # Errors
+/// Returns an error if the field contains an unknown attribute, or if the function returns an
+/// error.
+pub(crate) fn for_each_field_reference<F>(
+    constant_pool: &ConstantPool,
+    field: &mut Field,
+    function: &mut F,
+) -> Result<()>
+where
+    F: FnMut(&mut u16) -> Result<()>,
+{
+    function(&mut field.name_index)?;
+    function(&mut field.descriptor_index)?;
+    attributes(constant_pool, &mut field.attributes, function)
+}
+
+/// Call the function with a mutable reference to every constant pool index referenced by a
+/// method, its attributes and its instructions.
+///
+/// # Errors
+/// Returns an error if the method contains an unknown attribute, or if the function returns an
+/// error.
+pub(crate) fn for_each_method_reference<F>(
+    constant_pool: &ConstantPool,
+    method: &mut Method,
+    function: &mut F,
+) -> Result<()>
+where
+    F: FnMut(&mut u16) -> Result<()>,
+{
+    function(&mut method.name_index)?;
+    function(&mut method.descriptor_index)?;
+    attributes(constant_pool, &mut method.attributes, function)
+}
+
+fn attributes<F>(
+    constant_pool: &ConstantPool,
+    attributes: &mut [Attribute],
+    function: &mut F,
+) -> Result<()>
+where
+    F: FnMut(&mut u16) -> Result<()>,
+{
+    for attribute in attributes {
+        self::attribute(constant_pool, attribute, function)?;
+    }
+    Ok(())
+}
+
+#[expect(clippy::too_many_lines)]
+fn attribute<F>(
+    constant_pool: &ConstantPool,
+    attribute: &mut Attribute,
+    function: &mut F,
+) -> Result<()>
+where
+    F: FnMut(&mut u16) -> Result<()>,
+{
+    match attribute {
+        Attribute::ConstantValue {
+            name_index,
+            constant_value_index,
+        } => {
+            function(name_index)?;
+            function(constant_value_index)?;
+        }
+        Attribute::Code {
+            name_index,
+            code,
+            exception_table,
+            attributes,
+            ..
+        } => {
+            function(name_index)?;
+            for instruction in code {
+                self::instruction(instruction, function)?;
+            }
+            for entry in exception_table {
+                function(&mut entry.catch_type)?;
+            }
+            self::attributes(constant_pool, attributes, function)?;
+        }
+        Attribute::StackMapTable { name_index, frames } => {
+            function(name_index)?;
+            for frame in frames {
+                let verification_types: Vec<&mut VerificationType> = match frame {
+                    StackFrame::SameFrame { .. }
+                    | StackFrame::ChopFrame { .. }
+                    | StackFrame::SameFrameExtended { .. } => Vec::new(),
+                    StackFrame::SameLocals1StackItemFrame { stack, .. }
+                    | StackFrame::SameLocals1StackItemFrameExtended { stack, .. } => {
+                        stack.iter_mut().collect()
+                    }
+                    StackFrame::AppendFrame { locals, .. } => locals.iter_mut().collect(),
+                    StackFrame::FullFrame { locals, stack, .. } => {
+                        locals.iter_mut().chain(stack.iter_mut()).collect()
+                    }
+                };
+                for verification_type in verification_types {
+                    if let VerificationType::Object { cpool_index } = verification_type {
+                        function(cpool_index)?;
+                    }
+                }
+            }
+        }
+        Attribute::Exceptions {
+            name_index,
+            exception_indexes: indexes,
+        }
+        | Attribute::ModulePackages {
+            name_index,
+            package_indexes: indexes,
+        }
+        | Attribute::NestMembers {
+            name_index,
+            class_indexes: indexes,
+        }
+        | Attribute::PermittedSubclasses {
+            name_index,
+            class_indexes: indexes,
+        } => {
+            function(name_index)?;
+            for index in indexes {
+                function(index)?;
+            }
+        }
+        Attribute::InnerClasses {
+            name_index,
+            classes,
+        } => {
+            function(name_index)?;
+            for class in classes {
+                function(&mut class.class_info_index)?;
+                function(&mut class.outer_class_info_index)?;
+                function(&mut class.name_index)?;
+            }
+        }
+        Attribute::EnclosingMethod {
+            name_index,
+            class_index,
+            method_index,
+        } => {
+            function(name_index)?;
+            function(class_index)?;
+            function(method_index)?;
+        }
+        Attribute::Synthetic { name_index }
+        | Attribute::Deprecated { name_index }
+        | Attribute::SourceDebugExtension { name_index, .. }
+        | Attribute::LineNumberTable { name_index, .. } => function(name_index)?,
+        Attribute::Signature {
+            name_index,
+            signature_index: index,
+        }
+        | Attribute::SourceFile {
+            name_index,
+            source_file_index: index,
+        }
+        | Attribute::ModuleMainClass {
+            name_index,
+            main_class_index: index,
+        }
+        | Attribute::NestHost {
+            name_index,
+            host_class_index: index,
+        } => {
+            function(name_index)?;
+            function(index)?;
+        }
+        Attribute::LocalVariableTable {
+            name_index,
+            variables,
+        } => {
+            function(name_index)?;
+            for variable in variables {
+                function(&mut variable.name_index)?;
+                function(&mut variable.descriptor_index)?;
+            }
+        }
+        Attribute::LocalVariableTypeTable {
+            name_index,
+            variable_types,
+        } => {
+            function(name_index)?;
+            for variable_type in variable_types {
+                function(&mut variable_type.name_index)?;
+                function(&mut variable_type.signature_index)?;
+            }
+        }
+        Attribute::RuntimeVisibleAnnotations {
+            name_index,
+            annotations,
+        }
+        | Attribute::RuntimeInvisibleAnnotations {
+            name_index,
+            annotations,
+        } => {
+            function(name_index)?;
+            for annotation in annotations {
+                self::annotation(annotation, function)?;
+            }
+        }
+        Attribute::RuntimeVisibleParameterAnnotations {
+            name_index,
+            parameter_annotations,
+        }
+        | Attribute::RuntimeInvisibleParameterAnnotations {
+            name_index,
+            parameter_annotations,
+        } => {
+            function(name_index)?;
+            for parameter_annotation in parameter_annotations {
+                for annotation in &mut parameter_annotation.annotations {
+                    self::annotation(annotation, function)?;
+                }
+            }
+        }
+        Attribute::RuntimeVisibleTypeAnnotations {
+            name_index,
+            type_annotations,
+        }
+        | Attribute::RuntimeInvisibleTypeAnnotations {
+            name_index,
+            type_annotations,
+        } => {
+            function(name_index)?;
+            for type_annotation in type_annotations {
+                function(&mut type_annotation.type_index)?;
+                for element in &mut type_annotation.elements {
+                    function(&mut element.name_index)?;
+                    annotation_element(&mut element.value, function)?;
+                }
+            }
+        }
+        Attribute::AnnotationDefault {
+            name_index,
+            element,
+        } => {
+            function(name_index)?;
+            annotation_element(element, function)?;
+        }
+        Attribute::BootstrapMethods {
+            name_index,
+            methods,
+        } => {
+            function(name_index)?;
+            for method in methods {
+                function(&mut method.bootstrap_method_ref)?;
+                for argument in &mut method.arguments {
+                    function(argument)?;
+                }
+            }
+        }
+        Attribute::MethodParameters {
+            name_index,
+            parameters,
+        } => {
+            function(name_index)?;
+            for parameter in parameters {
+                function(&mut parameter.name_index)?;
+            }
+        }
+        Attribute::Module {
+            name_index,
+            module_name_index,
+            version_index,
+            requires,
+            exports,
+            opens,
+            uses,
+            provides,
+            ..
+        } => {
+            function(name_index)?;
+            function(module_name_index)?;
+            function(version_index)?;
+            for require in requires {
+                function(&mut require.index)?;
+                function(&mut require.version_index)?;
+            }
+            for export in exports {
+                function(&mut export.index)?;
+                for index in &mut export.to_index {
+                    function(index)?;
+                }
+            }
+            for open in opens {
+                function(&mut open.index)?;
+                for index in &mut open.to_index {
+                    function(index)?;
+                }
+            }
+            for index in uses {
+                function(index)?;
+            }
+            for provide in provides {
+                function(&mut provide.index)?;
+                for index in &mut provide.with_index {
+                    function(index)?;
+                }
+            }
+        }
+        Attribute::Record {
+            name_index,
+            records,
+        } => {
+            function(name_index)?;
+            for record in records {
+                function(&mut record.name_index)?;
+                function(&mut record.descriptor_index)?;
+                self::attributes(constant_pool, &mut record.attributes, function)?;
+            }
+        }
+        Attribute::Lazy { name_index, .. }
+        | Attribute::Unknown { name_index, .. }
+        | Attribute::Custom { name_index, .. } => {
+            let name = constant_pool.try_get_utf8(*name_index)?;
+            return Err(UnknownAttribute(name.clone()));
+        }
+    }
+    Ok(())
+}
+
+fn annotation<F>(annotation: &mut Annotation, function: &mut F) -> Result<()>
+where
+    F: FnMut(&mut u16) -> Result<()>,
+{
+    function(&mut annotation.type_index)?;
+    for element in &mut annotation.elements {
+        function(&mut element.name_index)?;
+        annotation_element(&mut element.value, function)?;
+    }
+    Ok(())
+}
+
+fn annotation_element<F>(element: &mut AnnotationElement, function: &mut F) -> Result<()>
+where
+    F: FnMut(&mut u16) -> Result<()>,
+{
+    match element {
+        AnnotationElement::Byte { const_value_index }
+        | AnnotationElement::Char { const_value_index }
+        | AnnotationElement::Double { const_value_index }
+        | AnnotationElement::Float { const_value_index }
+        | AnnotationElement::Int { const_value_index }
+        | AnnotationElement::Long { const_value_index }
+        | AnnotationElement::Short { const_value_index }
+        | AnnotationElement::Boolean { const_value_index }
+        | AnnotationElement::String { const_value_index } => function(const_value_index)?,
+        AnnotationElement::Enum {
+            type_name_index,
+            const_name_index,
+        } => {
+            function(type_name_index)?;
+            function(const_name_index)?;
+        }
+        AnnotationElement::Class { class_info_index } => function(class_info_index)?,
+        AnnotationElement::Annotation { annotation } => self::annotation(annotation, function)?,
+        AnnotationElement::Array { values } => {
+            for value in values {
+                annotation_element(value, function)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn instruction<F>(instruction: &mut Instruction, function: &mut F) -> Result<()>
+where
+    F: FnMut(&mut u16) -> Result<()>,
+{
+    match instruction {
+        Instruction::Ldc(index) => {
+            let mut wide_index = u16::from(*index);
+            function(&mut wide_index)?;
+            *index = u8::try_from(wide_index)?;
+        }
+        Instruction::Ldc_w(index)
+        | Instruction::Ldc2_w(index)
+        | Instruction::Getstatic(index)
+        | Instruction::Putstatic(index)
+        | Instruction::Getfield(index)
+        | Instruction::Putfield(index)
+        | Instruction::Invokevirtual(index)
+        | Instruction::Invokespecial(index)
+        | Instruction::Invokestatic(index)
+        | Instruction::Invokeinterface(index, _)
+        | Instruction::Invokedynamic(index)
+        | Instruction::New(index)
+        | Instruction::Anewarray(index)
+        | Instruction::Checkcast(index)
+        | Instruction::Instanceof(index)
+        | Instruction::Multianewarray(index, _) => function(index)?,
+        _ => {}
+    }
+    Ok(())
+}
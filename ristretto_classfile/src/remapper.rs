@@ -0,0 +1,966 @@
+use crate::attributes::{AnnotationElement, AnnotationValuePair, Attribute, InnerClass};
+use crate::class_file::ClassFile;
+use crate::constant::Constant;
+use crate::constant_pool::ConstantPool;
+use crate::error::Result;
+use crate::field_type::FieldType;
+use crate::method_descriptor::MethodDescriptor;
+use crate::signature::{
+    ClassSignature, ClassTypeSignature, MethodSignature, SimpleClassTypeSignature, TypeArgument,
+    TypeParameter, TypeSignature,
+};
+use crate::Error::UnknownAttribute;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+/// Renames classes, fields and methods throughout a class file; e.g. to shade or obfuscate
+/// classes.
+///
+/// Every occurrence of a renamed class or member is rewritten: class constants, field and method
+/// references, descriptors, generic signatures, annotations, inner classes, the enclosing method,
+/// record components and the method types and handles used as bootstrap method arguments.
+/// String constants are not changed.
+///
+/// Members are identified by the class that declares or is referenced as the owner of the member
+/// and by their original names; a reference to an inherited member through a subclass must be
+/// mapped with the subclass as the owner.  Class names use the internal form, e.g.
+/// `java/lang/String`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ristretto_classfile::{ClassFile, ConstantPool, Remapper};
+///
+/// let mut constant_pool = ConstantPool::default();
+/// let this_class = constant_pool.add_class("com/example/Foo")?;
+/// let mut class_file = ClassFile {
+///     constant_pool,
+///     this_class,
+///     ..Default::default()
+/// };
+///
+/// let remapper = Remapper::new().package("com/example/", "shaded/com/example/");
+/// remapper.remap(&mut class_file)?;
+/// assert_eq!("shaded/com/example/Foo", class_file.class_name()?);
+/// # Ok::<(), ristretto_classfile::Error>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Remapper {
+    classes: HashMap<String, String>,
+    packages: Vec<(String, String)>,
+    fields: HashMap<(String, String), String>,
+    methods: HashMap<(String, String, String), String>,
+}
+
+impl Remapper {
+    /// Create a new remapper that does not rename anything.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rename a class.
+    #[must_use]
+    pub fn class<S: AsRef<str>>(mut self, name: S, new_name: S) -> Self {
+        self.classes
+            .insert(name.as_ref().to_string(), new_name.as_ref().to_string());
+        self
+    }
+
+    /// Move the classes with names that start with a package prefix (e.g. `com/example/`) to a new
+    /// package prefix.  Classes renamed with [`class`](Remapper::class) are not moved; when
+    /// prefixes overlap the longest prefix is used.
+    #[must_use]
+    pub fn package<S: AsRef<str>>(mut self, prefix: S, new_prefix: S) -> Self {
+        self.packages
+            .push((prefix.as_ref().to_string(), new_prefix.as_ref().to_string()));
+        self.packages
+            .sort_by_key(|(prefix, _)| Reverse(prefix.len()));
+        self
+    }
+
+    /// Rename a field of the `owner` class.
+    #[must_use]
+    pub fn field<S: AsRef<str>>(mut self, owner: S, name: S, new_name: S) -> Self {
+        self.fields.insert(
+            (owner.as_ref().to_string(), name.as_ref().to_string()),
+            new_name.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Rename a method of the `owner` class with the given descriptor.
+    #[must_use]
+    pub fn method<S: AsRef<str>>(mut self, owner: S, name: S, descriptor: S, new_name: S) -> Self {
+        self.methods.insert(
+            (
+                owner.as_ref().to_string(),
+                name.as_ref().to_string(),
+                descriptor.as_ref().to_string(),
+            ),
+            new_name.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Get the new name of a class; array classes (e.g. `[Lcom/example/Foo;`) are mapped by
+    /// component type.
+    #[must_use]
+    pub fn map_class(&self, name: &str) -> String {
+        if name.starts_with('[') {
+            return self
+                .map_descriptor(name)
+                .unwrap_or_else(|_| name.to_string());
+        }
+        if let Some(new_name) = self.classes.get(name) {
+            return new_name.clone();
+        }
+        for (prefix, new_prefix) in &self.packages {
+            if let Some(class_name) = name.strip_prefix(prefix.as_str()) {
+                return format!("{new_prefix}{class_name}");
+            }
+        }
+        name.to_string()
+    }
+
+    /// Get the new name of a field of the `owner` class.
+    #[must_use]
+    pub fn map_field_name(&self, owner: &str, name: &str) -> String {
+        self.fields
+            .get(&(owner.to_string(), name.to_string()))
+            .cloned()
+            .unwrap_or_else(|| name.to_string())
+    }
+
+    /// Get the new name of a method of the `owner` class with the given descriptor.
+    #[must_use]
+    pub fn map_method_name(&self, owner: &str, name: &str, descriptor: &str) -> String {
+        self.methods
+            .get(&(owner.to_string(), name.to_string(), descriptor.to_string()))
+            .cloned()
+            .unwrap_or_else(|| name.to_string())
+    }
+
+    /// Map the classes of a field descriptor; e.g. `[Lcom/example/Foo;`.
+    ///
+    /// # Errors
+    /// Returns an error if the descriptor is invalid.
+    pub fn map_descriptor(&self, descriptor: &str) -> Result<String> {
+        let field_type = FieldType::parse(&descriptor.to_string())?;
+        Ok(self.map_field_type(&field_type).descriptor())
+    }
+
+    /// Map the classes of a method descriptor; e.g. `(Lcom/example/Foo;)V`.
+    ///
+    /// # Errors
+    /// Returns an error if the descriptor is invalid.
+    pub fn map_method_descriptor(&self, descriptor: &str) -> Result<String> {
+        let method_descriptor = MethodDescriptor::parse(descriptor)?;
+        let method_descriptor = MethodDescriptor {
+            parameters: method_descriptor
+                .parameters
+                .iter()
+                .map(|parameter| self.map_field_type(parameter))
+                .collect(),
+            return_type: method_descriptor
+                .return_type
+                .as_ref()
+                .map(|return_type| self.map_field_type(return_type)),
+        };
+        Ok(method_descriptor.descriptor())
+    }
+
+    fn map_field_type(&self, field_type: &FieldType) -> FieldType {
+        match field_type {
+            FieldType::Base(_) => field_type.clone(),
+            FieldType::Object(class_name) => FieldType::Object(self.map_class(class_name)),
+            FieldType::Array(component_type) => {
+                FieldType::Array(Box::new(self.map_field_type(component_type)))
+            }
+        }
+    }
+
+    fn map_type_signature(&self, signature: &TypeSignature) -> TypeSignature {
+        match signature {
+            TypeSignature::Base(_) | TypeSignature::TypeVariable(_) => signature.clone(),
+            TypeSignature::Class(class_type) => {
+                TypeSignature::Class(self.map_class_type_signature(class_type))
+            }
+            TypeSignature::Array(component_type) => {
+                TypeSignature::Array(Box::new(self.map_type_signature(component_type)))
+            }
+        }
+    }
+
+    /// Map a class type signature.  If the new name has the same number of nested classes, the
+    /// type arguments of each class are kept; otherwise the type arguments of the innermost class
+    /// are applied to the new class.
+    fn map_class_type_signature(&self, signature: &ClassTypeSignature) -> ClassTypeSignature {
+        let mut type_arguments = signature
+            .classes
+            .iter()
+            .map(|class| {
+                class
+                    .type_arguments
+                    .iter()
+                    .map(|type_argument| self.map_type_argument(type_argument))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let class_name = self.map_class(&signature.class_name());
+        let (package, simple_name) = class_name.rsplit_once('/').unwrap_or(("", &class_name));
+        let names = simple_name.split('$').collect::<Vec<_>>();
+        let classes = if names.len() == type_arguments.len() {
+            names
+                .into_iter()
+                .zip(type_arguments)
+                .map(|(name, type_arguments)| SimpleClassTypeSignature {
+                    name: name.to_string(),
+                    type_arguments,
+                })
+                .collect()
+        } else {
+            vec![SimpleClassTypeSignature {
+                name: simple_name.to_string(),
+                type_arguments: type_arguments.pop().unwrap_or_default(),
+            }]
+        };
+        ClassTypeSignature {
+            package: package.to_string(),
+            classes,
+        }
+    }
+
+    fn map_type_argument(&self, type_argument: &TypeArgument) -> TypeArgument {
+        match type_argument {
+            TypeArgument::Wildcard => TypeArgument::Wildcard,
+            TypeArgument::Exact(signature) => {
+                TypeArgument::Exact(self.map_type_signature(signature))
+            }
+            TypeArgument::Extends(signature) => {
+                TypeArgument::Extends(self.map_type_signature(signature))
+            }
+            TypeArgument::Super(signature) => {
+                TypeArgument::Super(self.map_type_signature(signature))
+            }
+        }
+    }
+
+    fn map_type_parameters(&self, type_parameters: &[TypeParameter]) -> Vec<TypeParameter> {
+        type_parameters
+            .iter()
+            .map(|type_parameter| TypeParameter {
+                name: type_parameter.name.clone(),
+                class_bound: type_parameter
+                    .class_bound
+                    .as_ref()
+                    .map(|bound| self.map_type_signature(bound)),
+                interface_bounds: type_parameter
+                    .interface_bounds
+                    .iter()
+                    .map(|bound| self.map_type_signature(bound))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Map the classes of a generic signature of the given kind.
+    fn map_signature(&self, kind: SignatureKind, signature: &str) -> Result<String> {
+        let signature = match kind {
+            SignatureKind::Class => {
+                let signature = ClassSignature::parse(signature)?;
+                ClassSignature {
+                    type_parameters: self.map_type_parameters(&signature.type_parameters),
+                    super_class: self.map_class_type_signature(&signature.super_class),
+                    interfaces: signature
+                        .interfaces
+                        .iter()
+                        .map(|interface| self.map_class_type_signature(interface))
+                        .collect(),
+                }
+                .to_string()
+            }
+            SignatureKind::Field => {
+                let signature = TypeSignature::parse(signature)?;
+                self.map_type_signature(&signature).to_string()
+            }
+            SignatureKind::Method => {
+                let signature = MethodSignature::parse(signature)?;
+                let map = |signature: &TypeSignature| self.map_type_signature(signature);
+                MethodSignature {
+                    type_parameters: self.map_type_parameters(&signature.type_parameters),
+                    parameters: signature.parameters.iter().map(map).collect(),
+                    result: signature.result.as_ref().map(map),
+                    throws: signature.throws.iter().map(map).collect(),
+                }
+                .to_string()
+            }
+        };
+        Ok(signature)
+    }
+
+    /// Rename the classes and members of a class file.  The constant pool is compacted afterward
+    /// so that the original names are not left behind as unreferenced constants.
+    ///
+    /// # Errors
+    /// Returns an error if the class file contains an invalid constant pool reference, descriptor
+    /// or signature, or an unknown attribute, since the references within an unknown attribute
+    /// cannot be renamed.
+    pub fn remap(&self, class_file: &mut ClassFile) -> Result<()> {
+        class_file.decode_lazy_attributes()?;
+        let original = class_file.constant_pool.clone();
+        let this_class = original.try_get_class(class_file.this_class)?.clone();
+        let mut constants = Constants::new(&mut class_file.constant_pool)?;
+        self.remap_constants(&original, &mut constants)?;
+
+        let context = Context {
+            remapper: self,
+            original: &original,
+            this_class: &this_class,
+        };
+        for field in &mut class_file.fields {
+            let name = original.try_get_utf8(field.name_index)?;
+            let descriptor = original.try_get_utf8(field.descriptor_index)?;
+            field.name_index = constants.utf8(&self.map_field_name(&this_class, name))?;
+            let descriptor = self.map_descriptor(descriptor)?;
+            field.descriptor_index = constants.utf8(&descriptor)?;
+            field.field_type = FieldType::parse(&descriptor)?;
+            context.attributes(&mut constants, SignatureKind::Field, &mut field.attributes)?;
+        }
+        for method in &mut class_file.methods {
+            let name = original.try_get_utf8(method.name_index)?;
+            let descriptor = original.try_get_utf8(method.descriptor_index)?;
+            method.name_index =
+                constants.utf8(&self.map_method_name(&this_class, name, descriptor))?;
+            method.descriptor_index = constants.utf8(&self.map_method_descriptor(descriptor)?)?;
+            context.attributes(
+                &mut constants,
+                SignatureKind::Method,
+                &mut method.attributes,
+            )?;
+        }
+        context.attributes(
+            &mut constants,
+            SignatureKind::Class,
+            &mut class_file.attributes,
+        )?;
+
+        class_file.compact_constant_pool()?;
+        Ok(())
+    }
+
+    /// Rename the classes and member references of the constant pool.  Class, method type and
+    /// descriptor constants are updated in place; member references refer to a new name and type
+    /// constant since name and type constants may be shared by references to different classes.
+    fn remap_constants(
+        &self,
+        original: &ConstantPool,
+        constants: &mut Constants<'_>,
+    ) -> Result<()> {
+        for index in 1..=u16::try_from(original.len())? {
+            let Some(constant) = original.get(index) else {
+                continue;
+            };
+            let constant = match constant {
+                Constant::Class(name_index) => {
+                    let name = original.try_get_utf8(*name_index)?;
+                    Constant::Class(constants.utf8(&self.map_class(name))?)
+                }
+                Constant::FieldRef {
+                    class_index,
+                    name_and_type_index,
+                } => {
+                    let owner = original.try_get_class(*class_index)?;
+                    let (name, descriptor) = name_and_type(original, *name_and_type_index)?;
+                    let name = self.map_field_name(owner, name);
+                    let descriptor = self.map_descriptor(descriptor)?;
+                    Constant::FieldRef {
+                        class_index: *class_index,
+                        name_and_type_index: constants.name_and_type(&name, &descriptor)?,
+                    }
+                }
+                Constant::MethodRef {
+                    class_index,
+                    name_and_type_index,
+                } => {
+                    let owner = original.try_get_class(*class_index)?;
+                    let (name, descriptor) = name_and_type(original, *name_and_type_index)?;
+                    let name = self.map_method_name(owner, name, descriptor);
+                    let descriptor = self.map_method_descriptor(descriptor)?;
+                    Constant::MethodRef {
+                        class_index: *class_index,
+                        name_and_type_index: constants.name_and_type(&name, &descriptor)?,
+                    }
+                }
+                Constant::InterfaceMethodRef {
+                    class_index,
+                    name_and_type_index,
+                } => {
+                    let owner = original.try_get_class(*class_index)?;
+                    let (name, descriptor) = name_and_type(original, *name_and_type_index)?;
+                    let name = self.map_method_name(owner, name, descriptor);
+                    let descriptor = self.map_method_descriptor(descriptor)?;
+                    Constant::InterfaceMethodRef {
+                        class_index: *class_index,
+                        name_and_type_index: constants.name_and_type(&name, &descriptor)?,
+                    }
+                }
+                Constant::MethodType(descriptor_index) => {
+                    let descriptor = original.try_get_utf8(*descriptor_index)?;
+                    Constant::MethodType(constants.utf8(&self.map_method_descriptor(descriptor)?)?)
+                }
+                Constant::Dynamic {
+                    bootstrap_method_attr_index,
+                    name_and_type_index,
+                } => {
+                    let (name, descriptor) = name_and_type(original, *name_and_type_index)?;
+                    let descriptor = self.map_descriptor(descriptor)?;
+                    Constant::Dynamic {
+                        bootstrap_method_attr_index: *bootstrap_method_attr_index,
+                        name_and_type_index: constants.name_and_type(name, &descriptor)?,
+                    }
+                }
+                Constant::InvokeDynamic {
+                    bootstrap_method_attr_index,
+                    name_and_type_index,
+                } => {
+                    let (name, descriptor) = name_and_type(original, *name_and_type_index)?;
+                    let descriptor = self.map_method_descriptor(descriptor)?;
+                    Constant::InvokeDynamic {
+                        bootstrap_method_attr_index: *bootstrap_method_attr_index,
+                        name_and_type_index: constants.name_and_type(name, &descriptor)?,
+                    }
+                }
+                _ => continue,
+            };
+            if let Some(entry) = constants.constant_pool.get_mut(index) {
+                *entry = constant;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The kind of generic signature of a `Signature` attribute, determined by the structure that the
+/// attribute belongs to.
+#[derive(Clone, Copy, Debug)]
+enum SignatureKind {
+    Class,
+    Field,
+    Method,
+}
+
+/// Get the name and descriptor of a name and type constant.
+fn name_and_type(constant_pool: &ConstantPool, index: u16) -> Result<(&String, &String)> {
+    let (name_index, descriptor_index) = constant_pool.try_get_name_and_type(index)?;
+    Ok((
+        constant_pool.try_get_utf8(*name_index)?,
+        constant_pool.try_get_utf8(*descriptor_index)?,
+    ))
+}
+
+/// Adds constants to a constant pool, reusing existing constants with the same value.
+struct Constants<'a> {
+    constant_pool: &'a mut ConstantPool,
+    utf8: HashMap<String, u16>,
+    name_and_types: HashMap<(u16, u16), u16>,
+}
+
+impl<'a> Constants<'a> {
+    fn new(constant_pool: &'a mut ConstantPool) -> Result<Self> {
+        let mut utf8 = HashMap::new();
+        let mut name_and_types = HashMap::new();
+        for index in 1..=u16::try_from(constant_pool.len())? {
+            match constant_pool.get(index) {
+                Some(Constant::Utf8(value)) => {
+                    utf8.entry(value.clone()).or_insert(index);
+                }
+                Some(Constant::NameAndType {
+                    name_index,
+                    descriptor_index,
+                }) => {
+                    name_and_types
+                        .entry((*name_index, *descriptor_index))
+                        .or_insert(index);
+                }
+                _ => {}
+            }
+        }
+        Ok(Self {
+            constant_pool,
+            utf8,
+            name_and_types,
+        })
+    }
+
+    fn utf8(&mut self, value: &str) -> Result<u16> {
+        if let Some(index) = self.utf8.get(value) {
+            return Ok(*index);
+        }
+        let index = self.constant_pool.add_utf8(value)?;
+        self.utf8.insert(value.to_string(), index);
+        Ok(index)
+    }
+
+    fn name_and_type(&mut self, name: &str, descriptor: &str) -> Result<u16> {
+        let key = (self.utf8(name)?, self.utf8(descriptor)?);
+        if let Some(index) = self.name_and_types.get(&key) {
+            return Ok(*index);
+        }
+        let index = self.constant_pool.add(Constant::NameAndType {
+            name_index: key.0,
+            descriptor_index: key.1,
+        })?;
+        self.name_and_types.insert(key, index);
+        Ok(index)
+    }
+}
+
+/// Renames the classes and members referenced by attributes.
+struct Context<'a> {
+    remapper: &'a Remapper,
+    original: &'a ConstantPool,
+    this_class: &'a str,
+}
+
+impl Context<'_> {
+    fn attributes(
+        &self,
+        constants: &mut Constants<'_>,
+        kind: SignatureKind,
+        attributes: &mut [Attribute],
+    ) -> Result<()> {
+        for attribute in attributes {
+            self.attribute(constants, kind, attribute)?;
+        }
+        Ok(())
+    }
+
+    #[expect(clippy::too_many_lines)]
+    fn attribute(
+        &self,
+        constants: &mut Constants<'_>,
+        kind: SignatureKind,
+        attribute: &mut Attribute,
+    ) -> Result<()> {
+        let remapper = self.remapper;
+        let original = self.original;
+        match attribute {
+            Attribute::Code { attributes, .. } => {
+                self.attributes(constants, kind, attributes)?;
+            }
+            Attribute::Signature {
+                signature_index, ..
+            } => {
+                let signature = original.try_get_utf8(*signature_index)?;
+                *signature_index = constants.utf8(&remapper.map_signature(kind, signature)?)?;
+            }
+            Attribute::LocalVariableTable { variables, .. } => {
+                for variable in variables {
+                    let descriptor = original.try_get_utf8(variable.descriptor_index)?;
+                    variable.descriptor_index =
+                        constants.utf8(&remapper.map_descriptor(descriptor)?)?;
+                }
+            }
+            Attribute::LocalVariableTypeTable { variable_types, .. } => {
+                for variable_type in variable_types {
+                    let signature = original.try_get_utf8(variable_type.signature_index)?;
+                    let signature = remapper.map_signature(SignatureKind::Field, signature)?;
+                    variable_type.signature_index = constants.utf8(&signature)?;
+                }
+            }
+            Attribute::RuntimeVisibleAnnotations { annotations, .. }
+            | Attribute::RuntimeInvisibleAnnotations { annotations, .. } => {
+                for annotation in annotations {
+                    self.annotation(
+                        constants,
+                        &mut annotation.type_index,
+                        &mut annotation.elements,
+                    )?;
+                }
+            }
+            Attribute::RuntimeVisibleParameterAnnotations {
+                parameter_annotations,
+                ..
+            }
+            | Attribute::RuntimeInvisibleParameterAnnotations {
+                parameter_annotations,
+                ..
+            } => {
+                for parameter_annotation in parameter_annotations {
+                    for annotation in &mut parameter_annotation.annotations {
+                        self.annotation(
+                            constants,
+                            &mut annotation.type_index,
+                            &mut annotation.elements,
+                        )?;
+                    }
+                }
+            }
+            Attribute::RuntimeVisibleTypeAnnotations {
+                type_annotations, ..
+            }
+            | Attribute::RuntimeInvisibleTypeAnnotations {
+                type_annotations, ..
+            } => {
+                for type_annotation in type_annotations {
+                    self.annotation(
+                        constants,
+                        &mut type_annotation.type_index,
+                        &mut type_annotation.elements,
+                    )?;
+                }
+            }
+            Attribute::AnnotationDefault { element, .. } => {
+                self.annotation_element(constants, element)?;
+            }
+            Attribute::InnerClasses { classes, .. } => {
+                for class in classes {
+                    self.inner_class(constants, class)?;
+                }
+            }
+            Attribute::EnclosingMethod {
+                class_index,
+                method_index,
+                ..
+            } if *method_index != 0 => {
+                let owner = original.try_get_class(*class_index)?;
+                let (name, descriptor) = name_and_type(original, *method_index)?;
+                let name = remapper.map_method_name(owner, name, descriptor);
+                let descriptor = remapper.map_method_descriptor(descriptor)?;
+                *method_index = constants.name_and_type(&name, &descriptor)?;
+            }
+            Attribute::Record { records, .. } => {
+                for record in records {
+                    let name = original.try_get_utf8(record.name_index)?;
+                    let descriptor = original.try_get_utf8(record.descriptor_index)?;
+                    record.name_index =
+                        constants.utf8(&remapper.map_field_name(self.this_class, name))?;
+                    record.descriptor_index =
+                        constants.utf8(&remapper.map_descriptor(descriptor)?)?;
+                    self.attributes(constants, SignatureKind::Field, &mut record.attributes)?;
+                }
+            }
+            Attribute::Lazy { name_index, .. }
+            | Attribute::Unknown { name_index, .. }
+            | Attribute::Custom { name_index, .. } => {
+                let name = original.try_get_utf8(*name_index)?;
+                return Err(UnknownAttribute(name.clone()));
+            }
+            // The remaining attributes only refer to class constants, which are renamed in the
+            // constant pool, or to names that are not renamed
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Rename the type and elements of an annotation or type annotation.
+    fn annotation(
+        &self,
+        constants: &mut Constants<'_>,
+        type_index: &mut u16,
+        elements: &mut [AnnotationValuePair],
+    ) -> Result<()> {
+        let type_name = self.original.try_get_utf8(*type_index)?;
+        let owner = FieldType::parse(type_name)?.class_name();
+        *type_index = constants.utf8(&self.remapper.map_descriptor(type_name)?)?;
+        for pair in elements {
+            let name = self.original.try_get_utf8(pair.name_index)?;
+            pair.name_index = constants.utf8(&self.annotation_element_name(&owner, name))?;
+            self.annotation_element(constants, &mut pair.value)?;
+        }
+        Ok(())
+    }
+
+    /// Get the new name of an annotation element; elements are the methods of the annotation
+    /// interface, which have no parameters.
+    fn annotation_element_name(&self, owner: &str, name: &str) -> String {
+        self.remapper
+            .methods
+            .iter()
+            .find(|((method_owner, method_name, descriptor), _)| {
+                method_owner == owner && method_name == name && descriptor.starts_with("()")
+            })
+            .map_or_else(|| name.to_string(), |(_, new_name)| new_name.clone())
+    }
+
+    fn annotation_element(
+        &self,
+        constants: &mut Constants<'_>,
+        element: &mut AnnotationElement,
+    ) -> Result<()> {
+        let remapper = self.remapper;
+        let original = self.original;
+        match element {
+            AnnotationElement::Enum {
+                type_name_index,
+                const_name_index,
+            } => {
+                let type_name = original.try_get_utf8(*type_name_index)?;
+                let owner = FieldType::parse(type_name)?.class_name();
+                let const_name = original.try_get_utf8(*const_name_index)?;
+                *type_name_index = constants.utf8(&remapper.map_descriptor(type_name)?)?;
+                *const_name_index = constants.utf8(&remapper.map_field_name(&owner, const_name))?;
+            }
+            AnnotationElement::Class { class_info_index } => {
+                // The class is a return descriptor, so `void` is allowed
+                let descriptor = original.try_get_utf8(*class_info_index)?;
+                if descriptor != "V" {
+                    *class_info_index = constants.utf8(&remapper.map_descriptor(descriptor)?)?;
+                }
+            }
+            AnnotationElement::Annotation { annotation } => {
+                self.annotation(
+                    constants,
+                    &mut annotation.type_index,
+                    &mut annotation.elements,
+                )?;
+            }
+            AnnotationElement::Array { values } => {
+                for value in values {
+                    self.annotation_element(constants, value)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Rename the simple name of an inner class to match the new name of the class.
+    fn inner_class(&self, constants: &mut Constants<'_>, class: &mut InnerClass) -> Result<()> {
+        if class.name_index == 0 {
+            return Ok(());
+        }
+        let class_name = self.original.try_get_class(class.class_info_index)?;
+        let new_class_name = self.remapper.map_class(class_name);
+        if new_class_name == *class_name {
+            return Ok(());
+        }
+        let outer_class_name = if class.outer_class_info_index == 0 {
+            None
+        } else {
+            let outer_class_name = self.original.try_get_class(class.outer_class_info_index)?;
+            Some(self.remapper.map_class(outer_class_name))
+        };
+        let simple_name = outer_class_name
+            .and_then(|outer_class_name| {
+                new_class_name
+                    .strip_prefix(&format!("{outer_class_name}$"))
+                    .map(ToString::to_string)
+            })
+            .unwrap_or_else(|| {
+                let name = new_class_name.rsplit('/').next().unwrap_or(&new_class_name);
+                name.rsplit('$').next().unwrap_or(name).to_string()
+            });
+        class.name_index = constants.utf8(&simple_name)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::attributes::Instruction;
+    use crate::{Method, MethodAccessFlags};
+    use std::io::Cursor;
+
+    fn utf8_constants(class_file: &ClassFile) -> Vec<&String> {
+        class_file
+            .constant_pool
+            .iter()
+            .filter_map(|(_, constant)| match constant {
+                Constant::Utf8(value) => Some(value),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_map_class() {
+        let remapper = Remapper::new()
+            .class("com/example/Foo", "a/A")
+            .package("com/", "shaded/com/")
+            .package("com/example/", "shaded/example/");
+        assert_eq!("a/A", remapper.map_class("com/example/Foo"));
+        assert_eq!("shaded/example/Bar", remapper.map_class("com/example/Bar"));
+        assert_eq!("shaded/com/Baz", remapper.map_class("com/Baz"));
+        assert_eq!("java/lang/Object", remapper.map_class("java/lang/Object"));
+        assert_eq!("[[La/A;", remapper.map_class("[[Lcom/example/Foo;"));
+        assert_eq!("[I", remapper.map_class("[I"));
+    }
+
+    #[test]
+    fn test_map_descriptors() -> Result<()> {
+        let remapper = Remapper::new().class("Foo", "a/A");
+        assert_eq!("[La/A;", remapper.map_descriptor("[LFoo;")?);
+        assert_eq!(
+            "(ILa/A;[La/A;)La/A;",
+            remapper.map_method_descriptor("(ILFoo;[LFoo;)LFoo;")?
+        );
+        assert_eq!("()V", remapper.map_method_descriptor("()V")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_member_names() {
+        let remapper = Remapper::new()
+            .field("Foo", "value", "a")
+            .method("Foo", "get", "()I", "b");
+        assert_eq!("a", remapper.map_field_name("Foo", "value"));
+        assert_eq!("value", remapper.map_field_name("Bar", "value"));
+        assert_eq!("b", remapper.map_method_name("Foo", "get", "()I"));
+        assert_eq!("get", remapper.map_method_name("Foo", "get", "()J"));
+    }
+
+    #[test]
+    fn test_map_signatures() -> Result<()> {
+        let remapper = Remapper::new()
+            .class("com/example/Foo", "a/A")
+            .class("com/example/Outer$Inner", "a/B$C");
+        assert_eq!(
+            "<T:La/A;>Ljava/lang/Object;Ljava/util/List<La/A;>;",
+            remapper.map_signature(
+                SignatureKind::Class,
+                "<T:Lcom/example/Foo;>Ljava/lang/Object;Ljava/util/List<Lcom/example/Foo;>;"
+            )?
+        );
+        assert_eq!(
+            "La/B<La/A;>.C<TT;>;",
+            remapper.map_signature(
+                SignatureKind::Field,
+                "Lcom/example/Outer<Lcom/example/Foo;>.Inner<TT;>;"
+            )?
+        );
+        assert_eq!(
+            "<T:Ljava/lang/Object;>(Ljava/util/Map<-La/A;*>;)[La/A;^La/A;",
+            remapper.map_signature(
+                SignatureKind::Method,
+                "<T:Ljava/lang/Object;>(Ljava/util/Map<-Lcom/example/Foo;*>;)[Lcom/example/Foo;^Lcom/example/Foo;"
+            )?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_remap() -> Result<()> {
+        let class_bytes = include_bytes!("../../classes/Child.class");
+        let mut class_file = ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))?;
+        let remapper = Remapper::new()
+            .class("Child", "shaded/Child")
+            .class("Parent", "shaded/Parent")
+            .field("Child", "three", "a");
+        remapper.remap(&mut class_file)?;
+
+        assert_eq!("shaded/Child", class_file.class_name()?);
+        let constant_pool = &class_file.constant_pool;
+        assert_eq!(
+            "shaded/Parent",
+            constant_pool.try_get_class(class_file.super_class)?
+        );
+        let field = &class_file.fields[0];
+        assert_eq!("a", constant_pool.try_get_utf8(field.name_index)?);
+        let utf8_constants = utf8_constants(&class_file);
+        assert!(!utf8_constants.contains(&&"three".to_string()));
+        assert!(!utf8_constants.contains(&&"Parent".to_string()));
+        assert!(utf8_constants.contains(&&"<init>".to_string()));
+        class_file.verify()?;
+
+        let mut bytes = Vec::new();
+        class_file.to_bytes(&mut bytes)?;
+        let class_file = ClassFile::from_bytes(&mut Cursor::new(bytes))?;
+        assert_eq!("shaded/Child", class_file.class_name()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_remap_shared_name_and_type() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let this_class = constant_pool.add_class("Foo")?;
+        let foo_value = constant_pool.add_field_ref(this_class, "value", "I")?;
+        let bar_class = constant_pool.add_class("Bar")?;
+        let bar_value = constant_pool.add_field_ref(bar_class, "value", "I")?;
+        let code_index = constant_pool.add_utf8("Code")?;
+        let name_index = constant_pool.add_utf8("test")?;
+        let descriptor_index = constant_pool.add_utf8("()V")?;
+        let method = Method {
+            access_flags: MethodAccessFlags::STATIC,
+            name_index,
+            descriptor_index,
+            attributes: vec![Attribute::Code {
+                name_index: code_index,
+                max_stack: 2,
+                max_locals: 0,
+                code: vec![
+                    Instruction::Getstatic(foo_value),
+                    Instruction::Getstatic(bar_value),
+                    Instruction::Return,
+                ],
+                exception_table: vec![],
+                attributes: vec![],
+            }],
+        };
+        let mut class_file = ClassFile {
+            constant_pool,
+            this_class,
+            methods: vec![method],
+            ..Default::default()
+        };
+        let remapper = Remapper::new().field("Foo", "value", "a");
+        remapper.remap(&mut class_file)?;
+
+        let Some(Attribute::Code { code, .. }) = class_file.methods[0].attributes.first() else {
+            panic!("Expected Code attribute");
+        };
+        let field_names = code
+            .iter()
+            .filter_map(|instruction| match instruction {
+                Instruction::Getstatic(index) => Some(*index),
+                _ => None,
+            })
+            .map(|index| {
+                let constant_pool = &class_file.constant_pool;
+                let (_, name_and_type_index) = constant_pool.try_get_field_ref(index)?;
+                Ok(name_and_type(constant_pool, *name_and_type_index)?
+                    .0
+                    .clone())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(vec!["a".to_string(), "value".to_string()], field_names);
+        Ok(())
+    }
+
+    #[test]
+    fn test_remap_annotations() -> Result<()> {
+        let class_bytes = include_bytes!("../../classes/Annotations.class");
+        let mut class_file = ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))?;
+        let remapper = Remapper::new().class("java/lang/Deprecated", "shaded/Deprecated");
+        remapper.remap(&mut class_file)?;
+
+        let utf8_constants = utf8_constants(&class_file);
+        assert!(utf8_constants.contains(&&"Lshaded/Deprecated;".to_string()));
+        assert!(!utf8_constants.contains(&&"Ljava/lang/Deprecated;".to_string()));
+        class_file.verify()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_remap_unknown_attribute() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let this_class = constant_pool.add_class("Foo")?;
+        let name_index = constant_pool.add_utf8("Custom")?;
+        let mut class_file = ClassFile {
+            constant_pool,
+            this_class,
+            attributes: vec![Attribute::Unknown {
+                name_index,
+                info: vec![],
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            Err(UnknownAttribute("Custom".to_string())),
+            Remapper::new().remap(&mut class_file)
+        );
+        Ok(())
+    }
+}
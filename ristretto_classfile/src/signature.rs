@@ -0,0 +1,915 @@
+use crate::base_type::BaseType;
+use crate::error::Result;
+use crate::Error::InvalidSignature;
+use std::fmt;
+
+/// Implementation of a `JavaTypeSignature`; the generic type of a field, parameter or return
+/// value.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.9.1>
+#[derive(Clone, Debug, PartialEq)]
+pub enum TypeSignature {
+    Base(BaseType),
+    Class(ClassTypeSignature),
+    TypeVariable(String),
+    Array(Box<TypeSignature>),
+}
+
+impl TypeSignature {
+    /// Parse a `JavaTypeSignature`; e.g. the value of a field `Signature` attribute.
+    ///
+    /// # Errors
+    /// Returns an error if the signature is invalid.
+    pub fn parse(signature: &str) -> Result<TypeSignature> {
+        let mut parser = Parser::new(signature);
+        let type_signature = parser.type_signature()?;
+        parser.end()?;
+        Ok(type_signature)
+    }
+
+    /// Return the Java source representation of the type; e.g.
+    /// `java.util.Map<java.lang.String, java.util.List<java.lang.Integer>>`.
+    #[must_use]
+    pub fn to_java_source(&self) -> String {
+        match self {
+            TypeSignature::Base(base_type) => base_type.class_name().to_string(),
+            TypeSignature::Class(class_type) => class_type.to_java_source(),
+            TypeSignature::TypeVariable(name) => name.clone(),
+            TypeSignature::Array(component_type) => {
+                format!("{}[]", component_type.to_java_source())
+            }
+        }
+    }
+}
+
+impl fmt::Display for TypeSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeSignature::Base(base_type) => write!(f, "{}", base_type.code()),
+            TypeSignature::Class(class_type) => write!(f, "{class_type}"),
+            TypeSignature::TypeVariable(name) => write!(f, "T{name};"),
+            TypeSignature::Array(component_type) => write!(f, "[{component_type}"),
+        }
+    }
+}
+
+/// Implementation of a `ClassTypeSignature`; a class type with optional type arguments for the
+/// class and each of its enclosing classes.
+///
+/// The package is the binary package name without a trailing `/` (e.g. `java/util`) and is
+/// empty for the unnamed package.  Classes are ordered from the outermost to the innermost class.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.9.1>
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClassTypeSignature {
+    pub package: String,
+    pub classes: Vec<SimpleClassTypeSignature>,
+}
+
+impl ClassTypeSignature {
+    /// Return the binary class name without type arguments; e.g. `java/util/Map$Entry`.
+    #[must_use]
+    pub fn class_name(&self) -> String {
+        let class_name = self
+            .classes
+            .iter()
+            .map(|class| class.name.as_str())
+            .collect::<Vec<_>>()
+            .join("$");
+        if self.package.is_empty() {
+            class_name
+        } else {
+            format!("{}/{class_name}", self.package)
+        }
+    }
+
+    /// Return the Java source representation of the class type; e.g.
+    /// `java.util.Map<K, V>.Entry<K, V>`.
+    #[must_use]
+    pub fn to_java_source(&self) -> String {
+        let classes = self
+            .classes
+            .iter()
+            .map(SimpleClassTypeSignature::to_java_source)
+            .collect::<Vec<_>>()
+            .join(".");
+        if self.package.is_empty() {
+            classes
+        } else {
+            format!("{}.{classes}", self.package.replace('/', "."))
+        }
+    }
+
+    /// Returns true if the class type is `java.lang.Object`.
+    fn is_object(&self) -> bool {
+        self.package == "java/lang"
+            && self.classes.len() == 1
+            && self.classes[0].name == "Object"
+            && self.classes[0].type_arguments.is_empty()
+    }
+}
+
+impl fmt::Display for ClassTypeSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "L")?;
+        if !self.package.is_empty() {
+            write!(f, "{}/", self.package)?;
+        }
+        for (index, class) in self.classes.iter().enumerate() {
+            if index > 0 {
+                write!(f, ".")?;
+            }
+            write!(f, "{class}")?;
+        }
+        write!(f, ";")
+    }
+}
+
+/// Implementation of a `SimpleClassTypeSignature`; a class name with its type arguments.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.9.1>
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimpleClassTypeSignature {
+    pub name: String,
+    pub type_arguments: Vec<TypeArgument>,
+}
+
+impl SimpleClassTypeSignature {
+    /// Return the Java source representation of the class name and type arguments; e.g.
+    /// `List<? extends T>`.
+    #[must_use]
+    pub fn to_java_source(&self) -> String {
+        if self.type_arguments.is_empty() {
+            return self.name.clone();
+        }
+        let type_arguments = self
+            .type_arguments
+            .iter()
+            .map(TypeArgument::to_java_source)
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("{}<{type_arguments}>", self.name)
+    }
+}
+
+impl fmt::Display for SimpleClassTypeSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.type_arguments.is_empty() {
+            write!(f, "<")?;
+            for type_argument in &self.type_arguments {
+                write!(f, "{type_argument}")?;
+            }
+            write!(f, ">")?;
+        }
+        Ok(())
+    }
+}
+
+/// Implementation of a `TypeArgument`.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.9.1>
+#[derive(Clone, Debug, PartialEq)]
+pub enum TypeArgument {
+    /// An unbounded wildcard; `?`
+    Wildcard,
+    /// An exact type; `T`
+    Exact(TypeSignature),
+    /// An upper bounded wildcard; `? extends T`
+    Extends(TypeSignature),
+    /// A lower bounded wildcard; `? super T`
+    Super(TypeSignature),
+}
+
+impl TypeArgument {
+    /// Return the Java source representation of the type argument; e.g. `? super T`.
+    #[must_use]
+    pub fn to_java_source(&self) -> String {
+        match self {
+            TypeArgument::Wildcard => "?".to_string(),
+            TypeArgument::Exact(type_signature) => type_signature.to_java_source(),
+            TypeArgument::Extends(type_signature) => {
+                format!("? extends {}", type_signature.to_java_source())
+            }
+            TypeArgument::Super(type_signature) => {
+                format!("? super {}", type_signature.to_java_source())
+            }
+        }
+    }
+}
+
+impl fmt::Display for TypeArgument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeArgument::Wildcard => write!(f, "*"),
+            TypeArgument::Exact(type_signature) => write!(f, "{type_signature}"),
+            TypeArgument::Extends(type_signature) => write!(f, "+{type_signature}"),
+            TypeArgument::Super(type_signature) => write!(f, "-{type_signature}"),
+        }
+    }
+}
+
+/// Implementation of a `TypeParameter`; a type variable declaration with its bounds.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.9.1>
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypeParameter {
+    pub name: String,
+    pub class_bound: Option<TypeSignature>,
+    pub interface_bounds: Vec<TypeSignature>,
+}
+
+impl TypeParameter {
+    /// Return the Java source representation of the type parameter; e.g.
+    /// `T extends java.lang.Number & java.lang.Comparable<T>`.  A `java.lang.Object` bound is
+    /// omitted when it is the only bound.
+    #[must_use]
+    pub fn to_java_source(&self) -> String {
+        let mut bounds = Vec::new();
+        if let Some(class_bound) = &self.class_bound {
+            let is_object =
+                matches!(class_bound, TypeSignature::Class(class_type) if class_type.is_object());
+            if !is_object || !self.interface_bounds.is_empty() {
+                bounds.push(class_bound.to_java_source());
+            }
+        }
+        bounds.extend(
+            self.interface_bounds
+                .iter()
+                .map(TypeSignature::to_java_source),
+        );
+        if bounds.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{} extends {}", self.name, bounds.join(" & "))
+        }
+    }
+}
+
+impl fmt::Display for TypeParameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:", self.name)?;
+        if let Some(class_bound) = &self.class_bound {
+            write!(f, "{class_bound}")?;
+        }
+        for interface_bound in &self.interface_bounds {
+            write!(f, ":{interface_bound}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Implementation of a `ClassSignature`; the value of a class `Signature` attribute.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.9.1>
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClassSignature {
+    pub type_parameters: Vec<TypeParameter>,
+    pub super_class: ClassTypeSignature,
+    pub interfaces: Vec<ClassTypeSignature>,
+}
+
+impl ClassSignature {
+    /// Parse a `ClassSignature`.
+    ///
+    /// # Errors
+    /// Returns an error if the signature is invalid.
+    pub fn parse(signature: &str) -> Result<ClassSignature> {
+        let mut parser = Parser::new(signature);
+        let type_parameters = parser.type_parameters()?;
+        let super_class = parser.class_type_signature()?;
+        let mut interfaces = Vec::new();
+        while !parser.is_empty() {
+            interfaces.push(parser.class_type_signature()?);
+        }
+        Ok(ClassSignature {
+            type_parameters,
+            super_class,
+            interfaces,
+        })
+    }
+
+    /// Return the Java source representation of a class with the name and this signature; e.g.
+    /// `Foo<T> extends Bar<T> implements java.lang.Comparable<Foo<T>>`.  A `java.lang.Object`
+    /// super class is omitted.
+    #[must_use]
+    pub fn to_java_source(&self, name: &str) -> String {
+        let mut source = format!("{name}{}", type_parameters_source(&self.type_parameters));
+        if !self.super_class.is_object() {
+            source.push_str(" extends ");
+            source.push_str(&self.super_class.to_java_source());
+        }
+        if !self.interfaces.is_empty() {
+            let interfaces = self
+                .interfaces
+                .iter()
+                .map(ClassTypeSignature::to_java_source)
+                .collect::<Vec<_>>()
+                .join(", ");
+            source.push_str(" implements ");
+            source.push_str(&interfaces);
+        }
+        source
+    }
+}
+
+impl fmt::Display for ClassSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_type_parameters(f, &self.type_parameters)?;
+        write!(f, "{}", self.super_class)?;
+        for interface in &self.interfaces {
+            write!(f, "{interface}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Implementation of a `MethodSignature`; the value of a method `Signature` attribute.  A `None`
+/// result represents `void`.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.9.1>
+#[derive(Clone, Debug, PartialEq)]
+pub struct MethodSignature {
+    pub type_parameters: Vec<TypeParameter>,
+    pub parameters: Vec<TypeSignature>,
+    pub result: Option<TypeSignature>,
+    pub throws: Vec<TypeSignature>,
+}
+
+impl MethodSignature {
+    /// Parse a `MethodSignature`.
+    ///
+    /// # Errors
+    /// Returns an error if the signature is invalid.
+    pub fn parse(signature: &str) -> Result<MethodSignature> {
+        let mut parser = Parser::new(signature);
+        let type_parameters = parser.type_parameters()?;
+        parser.expect('(')?;
+        let mut parameters = Vec::new();
+        while !parser.consume(')') {
+            parameters.push(parser.type_signature()?);
+        }
+        let result = if parser.consume('V') {
+            None
+        } else {
+            Some(parser.type_signature()?)
+        };
+        let mut throws = Vec::new();
+        while parser.consume('^') {
+            let throws_signature = match parser.peek() {
+                Some('T') => parser.type_signature()?,
+                _ => TypeSignature::Class(parser.class_type_signature()?),
+            };
+            throws.push(throws_signature);
+        }
+        parser.end()?;
+        Ok(MethodSignature {
+            type_parameters,
+            parameters,
+            result,
+            throws,
+        })
+    }
+
+    /// Return the Java source representation of a method with the name and this signature; e.g.
+    /// `<T> java.util.List<T> foo(int, T[]) throws java.io.IOException`.  Signatures do not
+    /// record whether a method has variable arity, so variable arity parameters are represented
+    /// as arrays.
+    #[must_use]
+    pub fn to_java_source(&self, name: &str) -> String {
+        let mut type_parameters = type_parameters_source(&self.type_parameters);
+        if !type_parameters.is_empty() {
+            type_parameters.push(' ');
+        }
+        let result = self
+            .result
+            .as_ref()
+            .map_or("void".to_string(), TypeSignature::to_java_source);
+        let parameters = self
+            .parameters
+            .iter()
+            .map(TypeSignature::to_java_source)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let mut source = format!("{type_parameters}{result} {name}({parameters})");
+        if !self.throws.is_empty() {
+            let throws = self
+                .throws
+                .iter()
+                .map(TypeSignature::to_java_source)
+                .collect::<Vec<_>>()
+                .join(", ");
+            source.push_str(" throws ");
+            source.push_str(&throws);
+        }
+        source
+    }
+}
+
+impl fmt::Display for MethodSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_type_parameters(f, &self.type_parameters)?;
+        write!(f, "(")?;
+        for parameter in &self.parameters {
+            write!(f, "{parameter}")?;
+        }
+        write!(f, ")")?;
+        match &self.result {
+            Some(result) => write!(f, "{result}")?,
+            None => write!(f, "V")?,
+        }
+        for throws in &self.throws {
+            write!(f, "^{throws}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Return the Java source representation of type parameters; e.g. `<K, V>`.  Returns an empty
+/// string if there are no type parameters.
+fn type_parameters_source(type_parameters: &[TypeParameter]) -> String {
+    if type_parameters.is_empty() {
+        return String::new();
+    }
+    let type_parameters = type_parameters
+        .iter()
+        .map(TypeParameter::to_java_source)
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("<{type_parameters}>")
+}
+
+fn write_type_parameters(
+    f: &mut fmt::Formatter<'_>,
+    type_parameters: &[TypeParameter],
+) -> fmt::Result {
+    if type_parameters.is_empty() {
+        return Ok(());
+    }
+    write!(f, "<")?;
+    for type_parameter in type_parameters {
+        write!(f, "{type_parameter}")?;
+    }
+    write!(f, ">")
+}
+
+/// Recursive descent parser for the signature grammar.
+struct Parser<'a> {
+    signature: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(signature: &'a str) -> Self {
+        Parser {
+            signature,
+            position: 0,
+        }
+    }
+
+    fn error(&self) -> crate::Error {
+        InvalidSignature(self.signature.to_string())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position >= self.signature.len()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.signature[self.position..].chars().next()
+    }
+
+    fn next(&mut self) -> Result<char> {
+        let character = self.peek().ok_or_else(|| self.error())?;
+        self.position += character.len_utf8();
+        Ok(character)
+    }
+
+    /// Consume the character if it is next; returns true if the character was consumed.
+    fn consume(&mut self, character: char) -> bool {
+        if self.peek() == Some(character) {
+            self.position += character.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, character: char) -> Result<()> {
+        if self.consume(character) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn end(&self) -> Result<()> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    /// Parse an identifier; identifiers may not contain any of `. ; [ / < > :`.
+    fn identifier(&mut self) -> Result<&'a str> {
+        let start = self.position;
+        while let Some(character) = self.peek() {
+            if matches!(character, '.' | ';' | '[' | '/' | '<' | '>' | ':') {
+                break;
+            }
+            self.position += character.len_utf8();
+        }
+        if self.position == start {
+            return Err(self.error());
+        }
+        Ok(&self.signature[start..self.position])
+    }
+
+    fn type_signature(&mut self) -> Result<TypeSignature> {
+        let type_signature = match self.peek() {
+            Some('L') => TypeSignature::Class(self.class_type_signature()?),
+            Some('T') => {
+                self.position += 1;
+                let name = self.identifier()?.to_string();
+                self.expect(';')?;
+                TypeSignature::TypeVariable(name)
+            }
+            Some('[') => {
+                self.position += 1;
+                TypeSignature::Array(Box::new(self.type_signature()?))
+            }
+            _ => {
+                let code = self.next()?;
+                let base_type = BaseType::parse(code).map_err(|_| self.error())?;
+                TypeSignature::Base(base_type)
+            }
+        };
+        Ok(type_signature)
+    }
+
+    fn reference_type_signature(&mut self) -> Result<TypeSignature> {
+        match self.peek() {
+            Some('L' | 'T' | '[') => self.type_signature(),
+            _ => Err(self.error()),
+        }
+    }
+
+    fn class_type_signature(&mut self) -> Result<ClassTypeSignature> {
+        self.expect('L')?;
+        let mut package = Vec::new();
+        let mut name = self.identifier()?;
+        while self.consume('/') {
+            package.push(name);
+            name = self.identifier()?;
+        }
+
+        let mut classes = vec![SimpleClassTypeSignature {
+            name: name.to_string(),
+            type_arguments: self.type_arguments()?,
+        }];
+        while self.consume('.') {
+            classes.push(SimpleClassTypeSignature {
+                name: self.identifier()?.to_string(),
+                type_arguments: self.type_arguments()?,
+            });
+        }
+        self.expect(';')?;
+        Ok(ClassTypeSignature {
+            package: package.join("/"),
+            classes,
+        })
+    }
+
+    fn type_arguments(&mut self) -> Result<Vec<TypeArgument>> {
+        let mut type_arguments = Vec::new();
+        if !self.consume('<') {
+            return Ok(type_arguments);
+        }
+        while !self.consume('>') {
+            let type_argument = match self.peek() {
+                Some('*') => {
+                    self.position += 1;
+                    TypeArgument::Wildcard
+                }
+                Some('+') => {
+                    self.position += 1;
+                    TypeArgument::Extends(self.reference_type_signature()?)
+                }
+                Some('-') => {
+                    self.position += 1;
+                    TypeArgument::Super(self.reference_type_signature()?)
+                }
+                _ => TypeArgument::Exact(self.reference_type_signature()?),
+            };
+            type_arguments.push(type_argument);
+        }
+        if type_arguments.is_empty() {
+            return Err(self.error());
+        }
+        Ok(type_arguments)
+    }
+
+    fn type_parameters(&mut self) -> Result<Vec<TypeParameter>> {
+        let mut type_parameters = Vec::new();
+        if !self.consume('<') {
+            return Ok(type_parameters);
+        }
+        while !self.consume('>') {
+            let name = self.identifier()?.to_string();
+            self.expect(':')?;
+            let class_bound = match self.peek() {
+                Some(':') => None,
+                _ => Some(self.reference_type_signature()?),
+            };
+            let mut interface_bounds = Vec::new();
+            while self.consume(':') {
+                interface_bounds.push(self.reference_type_signature()?);
+            }
+            type_parameters.push(TypeParameter {
+                name,
+                class_bound,
+                interface_bounds,
+            });
+        }
+        if type_parameters.is_empty() {
+            return Err(self.error());
+        }
+        Ok(type_parameters)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn class_type(package: &str, name: &str, type_arguments: Vec<TypeArgument>) -> TypeSignature {
+        TypeSignature::Class(ClassTypeSignature {
+            package: package.to_string(),
+            classes: vec![SimpleClassTypeSignature {
+                name: name.to_string(),
+                type_arguments,
+            }],
+        })
+    }
+
+    #[test]
+    fn test_type_signature_base() -> Result<()> {
+        let signature = TypeSignature::parse("I")?;
+        assert_eq!(TypeSignature::Base(BaseType::Int), signature);
+        assert_eq!("I", signature.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_type_signature_type_variable() -> Result<()> {
+        let signature = TypeSignature::parse("TT;")?;
+        assert_eq!(TypeSignature::TypeVariable("T".to_string()), signature);
+        assert_eq!("TT;", signature.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_type_signature_array() -> Result<()> {
+        let signature = TypeSignature::parse("[[TE;")?;
+        assert_eq!(
+            TypeSignature::Array(Box::new(TypeSignature::Array(Box::new(
+                TypeSignature::TypeVariable("E".to_string())
+            )))),
+            signature
+        );
+        assert_eq!("[[TE;", signature.to_string());
+        assert_eq!("E[][]", signature.to_java_source());
+        Ok(())
+    }
+
+    #[test]
+    fn test_type_signature_class() -> Result<()> {
+        let value = "Ljava/util/Map<Ljava/lang/String;+Ljava/lang/Number;>;";
+        let signature = TypeSignature::parse(value)?;
+        assert_eq!(
+            class_type(
+                "java/util",
+                "Map",
+                vec![
+                    TypeArgument::Exact(class_type("java/lang", "String", Vec::new())),
+                    TypeArgument::Extends(class_type("java/lang", "Number", Vec::new())),
+                ]
+            ),
+            signature
+        );
+        assert_eq!(value, signature.to_string());
+        assert_eq!(
+            "java.util.Map<java.lang.String, ? extends java.lang.Number>",
+            signature.to_java_source()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_type_signature_wildcards() -> Result<()> {
+        let value = "Ljava/util/function/Function<-TT;*>;";
+        let signature = TypeSignature::parse(value)?;
+        assert_eq!(
+            class_type(
+                "java/util/function",
+                "Function",
+                vec![
+                    TypeArgument::Super(TypeSignature::TypeVariable("T".to_string())),
+                    TypeArgument::Wildcard,
+                ]
+            ),
+            signature
+        );
+        assert_eq!(value, signature.to_string());
+        assert_eq!(
+            "java.util.function.Function<? super T, ?>",
+            signature.to_java_source()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_type_signature_inner_class() -> Result<()> {
+        let value = "LOuter<TT;>.Inner<Ljava/lang/String;>;";
+        let TypeSignature::Class(class_type_signature) = TypeSignature::parse(value)? else {
+            panic!("expected class type signature");
+        };
+        assert_eq!("", class_type_signature.package);
+        assert_eq!(2, class_type_signature.classes.len());
+        assert_eq!("Outer$Inner", class_type_signature.class_name());
+        assert_eq!(value, class_type_signature.to_string());
+        assert_eq!(
+            "Outer<T>.Inner<java.lang.String>",
+            class_type_signature.to_java_source()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_class_name() -> Result<()> {
+        let TypeSignature::Class(class_type_signature) =
+            TypeSignature::parse("Ljava/util/Map<TK;TV;>.Entry<TK;TV;>;")?
+        else {
+            panic!("expected class type signature");
+        };
+        assert_eq!("java/util/Map$Entry", class_type_signature.class_name());
+        Ok(())
+    }
+
+    #[test]
+    fn test_type_signature_invalid() {
+        for value in [
+            "",
+            "V",
+            "Q",
+            "Ljava/lang/String",
+            "Ljava/util/List<>;",
+            "Ljava/util/List<I>;",
+            "TT",
+            "T;",
+            "II",
+            "L;",
+        ] {
+            assert_eq!(
+                Err(InvalidSignature(value.to_string())),
+                TypeSignature::parse(value),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_class_signature() -> Result<()> {
+        let value = "<K:Ljava/lang/Object;V::Ljava/lang/Comparable<TV;>;>Ljava/util/AbstractMap<TK;TV;>;Ljava/io/Serializable;";
+        let signature = ClassSignature::parse(value)?;
+        assert_eq!(
+            vec![
+                TypeParameter {
+                    name: "K".to_string(),
+                    class_bound: Some(class_type("java/lang", "Object", Vec::new())),
+                    interface_bounds: Vec::new(),
+                },
+                TypeParameter {
+                    name: "V".to_string(),
+                    class_bound: None,
+                    interface_bounds: vec![class_type(
+                        "java/lang",
+                        "Comparable",
+                        vec![TypeArgument::Exact(TypeSignature::TypeVariable(
+                            "V".to_string()
+                        ))]
+                    )],
+                },
+            ],
+            signature.type_parameters
+        );
+        assert_eq!("java/util/AbstractMap", signature.super_class.class_name());
+        assert_eq!(1, signature.interfaces.len());
+        assert_eq!("java/io/Serializable", signature.interfaces[0].class_name());
+        assert_eq!(value, signature.to_string());
+        assert_eq!(
+            "Foo<K, V extends java.lang.Comparable<V>> extends java.util.AbstractMap<K, V> \
+             implements java.io.Serializable",
+            signature.to_java_source("Foo")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_class_signature_without_type_parameters() -> Result<()> {
+        let value = "Ljava/lang/Object;Ljava/lang/Comparable<LFoo;>;";
+        let signature = ClassSignature::parse(value)?;
+        assert!(signature.type_parameters.is_empty());
+        assert_eq!(value, signature.to_string());
+        assert_eq!(
+            "Foo implements java.lang.Comparable<Foo>",
+            signature.to_java_source("Foo")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_type_parameter_to_java_source() -> Result<()> {
+        let signature = ClassSignature::parse(
+            "<T:Ljava/lang/Object;:Ljava/lang/Runnable;U:Ljava/lang/Number;>Ljava/lang/Object;",
+        )?;
+        assert_eq!(
+            "Foo<T extends java.lang.Object & java.lang.Runnable, U extends java.lang.Number>",
+            signature.to_java_source("Foo")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_class_signature_invalid() {
+        for value in ["", "<>Ljava/lang/Object;", "<T>Ljava/lang/Object;", "I"] {
+            assert_eq!(
+                Err(InvalidSignature(value.to_string())),
+                ClassSignature::parse(value),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_method_signature() -> Result<()> {
+        let value =
+            "<T:Ljava/lang/Exception;>(Ljava/util/List<+TT;>;[I)TT;^TT;^Ljava/io/IOException;";
+        let signature = MethodSignature::parse(value)?;
+        assert_eq!(1, signature.type_parameters.len());
+        assert_eq!(
+            vec![
+                class_type(
+                    "java/util",
+                    "List",
+                    vec![TypeArgument::Extends(TypeSignature::TypeVariable(
+                        "T".to_string()
+                    ))]
+                ),
+                TypeSignature::Array(Box::new(TypeSignature::Base(BaseType::Int))),
+            ],
+            signature.parameters
+        );
+        assert_eq!(
+            Some(TypeSignature::TypeVariable("T".to_string())),
+            signature.result
+        );
+        assert_eq!(
+            vec![
+                TypeSignature::TypeVariable("T".to_string()),
+                class_type("java/io", "IOException", Vec::new()),
+            ],
+            signature.throws
+        );
+        assert_eq!(value, signature.to_string());
+        assert_eq!(
+            "<T extends java.lang.Exception> T foo(java.util.List<? extends T>, int[]) throws T, \
+             java.io.IOException",
+            signature.to_java_source("foo")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_method_signature_void() -> Result<()> {
+        let value = "()V";
+        let signature = MethodSignature::parse(value)?;
+        assert!(signature.parameters.is_empty());
+        assert_eq!(None, signature.result);
+        assert_eq!(value, signature.to_string());
+        assert_eq!("void run()", signature.to_java_source("run"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_method_signature_invalid() {
+        for value in ["", "V", "(", "()", "()VV", "(V)V", "()V^I", "()V^"] {
+            assert_eq!(
+                Err(InvalidSignature(value.to_string())),
+                MethodSignature::parse(value),
+                "{value}"
+            );
+        }
+    }
+}
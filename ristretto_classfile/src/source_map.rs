@@ -0,0 +1,708 @@
+use crate::attributes::Attribute;
+use crate::class_file::ClassFile;
+use crate::error::Error::InvalidSourceMap;
+use crate::error::Result;
+use crate::version::Version;
+use std::fmt;
+use std::str::{FromStr, Lines};
+
+const VERSION_49_0: Version = Version::Java5_0 { minor: 0 };
+/// Stratum of the Java source lines of a class; it may be the default stratum without being
+/// declared.
+const JAVA_STRATUM: &str = "Java";
+
+/// Source file of a stratum of a [`SourceMap`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceFile {
+    id: u32,
+    name: String,
+    path: Option<String>,
+}
+
+impl SourceFile {
+    /// Create a source file with an identifier that is unique within its stratum, a name and an
+    /// optional path, e.g. `pages/hello.jsp`.
+    #[must_use]
+    pub fn new<S: AsRef<str>>(id: u32, name: S, path: Option<&str>) -> Self {
+        SourceFile {
+            id,
+            name: name.as_ref().to_string(),
+            path: path.map(ToString::to_string),
+        }
+    }
+
+    /// Get the identifier of the file within its stratum.
+    #[must_use]
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Get the name of the file.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the path of the file, if declared.
+    #[must_use]
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+}
+
+/// Mapping of a range of input (source) lines to output (class file) lines.
+///
+/// Each of the `repeat_count` input lines starting at `input_start_line` maps to
+/// `output_line_increment` output lines, starting at `output_start_line`.
+///
+/// See: <https://jcp.org/en/jsr/detail?id=45>
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LineMapping {
+    pub file_id: u32,
+    pub input_start_line: u32,
+    pub repeat_count: u32,
+    pub output_start_line: u32,
+    pub output_line_increment: u32,
+}
+
+impl LineMapping {
+    /// Create a mapping of a single input line of a file to a single output line.
+    #[must_use]
+    pub fn new(file_id: u32, input_line: u32, output_line: u32) -> Self {
+        LineMapping {
+            file_id,
+            input_start_line: input_line,
+            repeat_count: 1,
+            output_start_line: output_line,
+            output_line_increment: 1,
+        }
+    }
+
+    /// Get the input line that an output line maps to, if the output line is in this mapping.
+    #[must_use]
+    pub fn input_line(&self, output_line: u32) -> Option<u32> {
+        if self.output_line_increment == 0 || output_line < self.output_start_line {
+            return None;
+        }
+        let offset = (output_line - self.output_start_line) / self.output_line_increment;
+        if offset >= self.repeat_count {
+            return None;
+        }
+        self.input_start_line.checked_add(offset)
+    }
+}
+
+/// Stratum of a [`SourceMap`]; a view of the lines of a class in terms of one source language,
+/// e.g. `JSP`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stratum {
+    name: String,
+    files: Vec<SourceFile>,
+    lines: Vec<LineMapping>,
+}
+
+impl Stratum {
+    fn new<S: AsRef<str>>(name: S) -> Self {
+        Stratum {
+            name: name.as_ref().to_string(),
+            files: Vec::new(),
+            lines: Vec::new(),
+        }
+    }
+
+    /// Get the name of the stratum.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the source files of the stratum.
+    #[must_use]
+    pub fn files(&self) -> &[SourceFile] {
+        &self.files
+    }
+
+    /// Get a source file of the stratum by identifier.
+    #[must_use]
+    pub fn file(&self, id: u32) -> Option<&SourceFile> {
+        self.files.iter().find(|file| file.id == id)
+    }
+
+    /// Get the line mappings of the stratum.
+    #[must_use]
+    pub fn lines(&self) -> &[LineMapping] {
+        &self.lines
+    }
+}
+
+/// Source map (SMAP) of a class, stored in the `SourceDebugExtension` attribute.
+///
+/// Source maps describe how the lines of a class generated from another language, e.g. a JSP page
+/// or a template, map back to the lines of the original sources, so that debuggers and stack traces
+/// can refer to the original sources.  Source maps are parsed from and displayed as the SMAP text
+/// format; embedded source maps are not supported.
+///
+/// See: <https://jcp.org/en/jsr/detail?id=45>
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceMap {
+    output_file_name: String,
+    default_stratum: String,
+    strata: Vec<Stratum>,
+}
+
+impl SourceMap {
+    /// Get the name of the generated source file, e.g. `hello_jsp.java`.
+    #[must_use]
+    pub fn output_file_name(&self) -> &str {
+        &self.output_file_name
+    }
+
+    /// Get the name of the stratum used when no stratum is requested.
+    #[must_use]
+    pub fn default_stratum(&self) -> &str {
+        &self.default_stratum
+    }
+
+    /// Get the strata of the source map.
+    #[must_use]
+    pub fn strata(&self) -> &[Stratum] {
+        &self.strata
+    }
+
+    /// Get a stratum by name.
+    #[must_use]
+    pub fn stratum(&self, name: &str) -> Option<&Stratum> {
+        self.strata.iter().find(|stratum| stratum.name == name)
+    }
+
+    /// Get the source file and input line that an output line of the class maps to in a
+    /// stratum; the default stratum is used if no stratum is specified.
+    #[must_use]
+    pub fn input_line(
+        &self,
+        stratum: Option<&str>,
+        output_line: u32,
+    ) -> Option<(&SourceFile, u32)> {
+        let stratum = self.stratum(stratum.unwrap_or(&self.default_stratum))?;
+        stratum.lines.iter().find_map(|mapping| {
+            let line = mapping.input_line(output_line)?;
+            Some((stratum.file(mapping.file_id)?, line))
+        })
+    }
+
+    /// Verify that the default stratum is declared, the names of the strata and the identifiers of
+    /// their files are unique, and every line mapping refers to a declared file.
+    fn verify(&self) -> Result<()> {
+        if self.default_stratum != JAVA_STRATUM && self.stratum(&self.default_stratum).is_none() {
+            return Err(InvalidSourceMap(format!(
+                "default stratum {} is not declared",
+                self.default_stratum
+            )));
+        }
+        for (index, stratum) in self.strata.iter().enumerate() {
+            if self.strata[..index]
+                .iter()
+                .any(|other| other.name == stratum.name)
+            {
+                return Err(InvalidSourceMap(format!(
+                    "stratum {} is declared more than once",
+                    stratum.name
+                )));
+            }
+            for (index, file) in stratum.files.iter().enumerate() {
+                if stratum.files[..index]
+                    .iter()
+                    .any(|other| other.id == file.id)
+                {
+                    return Err(InvalidSourceMap(format!(
+                        "file {} is declared more than once in stratum {}",
+                        file.id, stratum.name
+                    )));
+                }
+            }
+            for mapping in &stratum.lines {
+                if stratum.file(mapping.file_id).is_none() {
+                    return Err(InvalidSourceMap(format!(
+                        "file {} is not declared in stratum {}",
+                        mapping.file_id, stratum.name
+                    )));
+                }
+                if mapping.repeat_count == 0 {
+                    return Err(InvalidSourceMap(format!(
+                        "line {} of file {} has a repeat count of 0",
+                        mapping.input_start_line, mapping.file_id
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Section of the SMAP text being parsed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Section {
+    Files,
+    Lines,
+    Ignored,
+}
+
+/// Get the stratum that a file or line section belongs to.
+fn current_stratum(strata: &mut [Stratum]) -> Result<&mut Stratum> {
+    strata
+        .last_mut()
+        .ok_or_else(|| InvalidSourceMap("section declared before a stratum".to_string()))
+}
+
+/// Parse a file info line, e.g. `1 hello.jsp`, or `+ 1 hello.jsp` followed by the path line.
+fn parse_file(line: &str, lines: &mut Lines<'_>) -> Result<SourceFile> {
+    let invalid = || InvalidSourceMap(format!("invalid file info: {line}"));
+    let (file_info, has_path) = match line.strip_prefix('+') {
+        Some(file_info) => (file_info.trim_start(), true),
+        None => (line, false),
+    };
+    let (id, name) = file_info.split_once(' ').ok_or_else(invalid)?;
+    let id = id.parse::<u32>().map_err(|_| invalid())?;
+    let path = if has_path {
+        Some(lines.next().ok_or_else(invalid)?)
+    } else {
+        None
+    };
+    Ok(SourceFile::new(id, name, path))
+}
+
+/// Parse a line info line, `InputStartLine[#LineFileID][,RepeatCount]:OutputStartLine[,OutputLineIncrement]`;
+/// the file identifier defaults to the identifier of the previous line info.
+fn parse_line_mapping(line: &str, file_id: &mut u32) -> Result<LineMapping> {
+    let invalid = || InvalidSourceMap(format!("invalid line info: {line}"));
+    let number = |value: &str| value.parse::<u32>().map_err(|_| invalid());
+    let (input, output) = line.split_once(':').ok_or_else(invalid)?;
+    let (input, repeat_count) = match input.split_once(',') {
+        Some((input, repeat_count)) => (input, number(repeat_count)?),
+        None => (input, 1),
+    };
+    let input_start_line = match input.split_once('#') {
+        Some((input_start_line, line_file_id)) => {
+            *file_id = number(line_file_id)?;
+            number(input_start_line)?
+        }
+        None => number(input)?,
+    };
+    let (output_start_line, output_line_increment) = match output.split_once(',') {
+        Some((output_start_line, increment)) => (number(output_start_line)?, number(increment)?),
+        None => (number(output)?, 1),
+    };
+    Ok(LineMapping {
+        file_id: *file_id,
+        input_start_line,
+        repeat_count,
+        output_start_line,
+        output_line_increment,
+    })
+}
+
+impl FromStr for SourceMap {
+    type Err = crate::Error;
+
+    /// Parse a source map from the SMAP text format.
+    fn from_str(value: &str) -> Result<Self> {
+        let mut lines = value.lines();
+        if lines.next() != Some("SMAP") {
+            return Err(InvalidSourceMap("missing SMAP header".to_string()));
+        }
+        let missing = |name: &str| InvalidSourceMap(format!("missing {name}"));
+        let output_file_name = lines.next().ok_or_else(|| missing("output file name"))?;
+        let default_stratum = lines.next().ok_or_else(|| missing("default stratum"))?;
+
+        let mut strata = Vec::new();
+        let mut section = Section::Ignored;
+        let mut file_id = 0;
+        let mut ended = false;
+        while let Some(line) = lines.next() {
+            if let Some(section_name) = line.strip_prefix('*') {
+                section = match section_name.chars().next() {
+                    Some('S') => {
+                        strata.push(Stratum::new(section_name[1..].trim()));
+                        file_id = 0;
+                        Section::Ignored
+                    }
+                    Some('F') => Section::Files,
+                    Some('L') => Section::Lines,
+                    Some('E') => {
+                        ended = true;
+                        break;
+                    }
+                    Some('O' | 'C') => {
+                        return Err(InvalidSourceMap(
+                            "embedded source maps are not supported".to_string(),
+                        ));
+                    }
+                    // Vendor and unknown sections are ignored
+                    _ => Section::Ignored,
+                };
+                continue;
+            }
+            match section {
+                Section::Files => {
+                    let file = parse_file(line, &mut lines)?;
+                    current_stratum(&mut strata)?.files.push(file);
+                }
+                Section::Lines => {
+                    let mapping = parse_line_mapping(line, &mut file_id)?;
+                    current_stratum(&mut strata)?.lines.push(mapping);
+                }
+                Section::Ignored => {}
+            }
+        }
+        if !ended {
+            return Err(missing("end section"));
+        }
+
+        let source_map = SourceMap {
+            output_file_name: output_file_name.to_string(),
+            default_stratum: default_stratum.to_string(),
+            strata,
+        };
+        source_map.verify()?;
+        Ok(source_map)
+    }
+}
+
+impl fmt::Display for SourceMap {
+    /// Format the source map in the SMAP text format.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "SMAP")?;
+        writeln!(f, "{}", self.output_file_name)?;
+        writeln!(f, "{}", self.default_stratum)?;
+        for stratum in &self.strata {
+            writeln!(f, "*S {}", stratum.name)?;
+            if !stratum.files.is_empty() {
+                writeln!(f, "*F")?;
+                for file in &stratum.files {
+                    match &file.path {
+                        Some(path) => writeln!(f, "+ {} {}\n{path}", file.id, file.name)?,
+                        None => writeln!(f, "{} {}", file.id, file.name)?,
+                    }
+                }
+            }
+            if !stratum.lines.is_empty() {
+                writeln!(f, "*L")?;
+                let mut file_id = 0;
+                for mapping in &stratum.lines {
+                    write!(f, "{}", mapping.input_start_line)?;
+                    if mapping.file_id != file_id {
+                        write!(f, "#{}", mapping.file_id)?;
+                        file_id = mapping.file_id;
+                    }
+                    if mapping.repeat_count != 1 {
+                        write!(f, ",{}", mapping.repeat_count)?;
+                    }
+                    write!(f, ":{}", mapping.output_start_line)?;
+                    if mapping.output_line_increment != 1 {
+                        write!(f, ",{}", mapping.output_line_increment)?;
+                    }
+                    writeln!(f)?;
+                }
+            }
+        }
+        writeln!(f, "*E")
+    }
+}
+
+/// Builder for a [`SourceMap`] and the `SourceDebugExtension` attribute that stores it.
+///
+/// Files and line mappings are added to strata by name; a stratum is declared when it is first
+/// used.  The `SourceDebugExtension` attribute requires Java 5 or later.
+///
+/// # Examples
+///
+/// ```rust
+/// use ristretto_classfile::{ClassFile, ConstantPool, Result, SourceMapBuilder, Version};
+///
+/// fn main() -> Result<()> {
+///     let mut constant_pool = ConstantPool::default();
+///     let this_class = constant_pool.add_class("hello_jsp")?;
+///     let mut class_file = ClassFile {
+///         version: Version::Java21 { minor: 0 },
+///         constant_pool,
+///         this_class,
+///         ..Default::default()
+///     };
+///     SourceMapBuilder::new("hello_jsp.java", "JSP")
+///         .file("JSP", 1, "hello.jsp", Some("pages/hello.jsp"))
+///         .line("JSP", 1, 3, 42)
+///         .add_to(&mut class_file)?;
+///
+///     let source_map = class_file.source_map()?.expect("source map");
+///     let (file, line) = source_map.input_line(None, 42).expect("line");
+///     assert_eq!(("hello.jsp", 3), (file.name(), line));
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceMapBuilder {
+    output_file_name: String,
+    default_stratum: String,
+    strata: Vec<Stratum>,
+}
+
+impl SourceMapBuilder {
+    /// Create a builder for the source map of a generated source file, e.g. `hello_jsp.java`,
+    /// with the stratum used when no stratum is requested.
+    #[must_use]
+    pub fn new<O: AsRef<str>, S: AsRef<str>>(output_file_name: O, default_stratum: S) -> Self {
+        SourceMapBuilder {
+            output_file_name: output_file_name.as_ref().to_string(),
+            default_stratum: default_stratum.as_ref().to_string(),
+            strata: Vec::new(),
+        }
+    }
+
+    /// Get a stratum by name, declaring it if necessary.
+    fn stratum(&mut self, name: &str) -> &mut Stratum {
+        let index = if let Some(index) = self.strata.iter().position(|stratum| stratum.name == name)
+        {
+            index
+        } else {
+            self.strata.push(Stratum::new(name));
+            self.strata.len() - 1
+        };
+        &mut self.strata[index]
+    }
+
+    /// Add a source file to a stratum; the identifier is used by the line mappings of the file.
+    #[must_use]
+    pub fn file<S: AsRef<str>, N: AsRef<str>>(
+        mut self,
+        stratum: S,
+        id: u32,
+        name: N,
+        path: Option<&str>,
+    ) -> Self {
+        let file = SourceFile::new(id, name, path);
+        self.stratum(stratum.as_ref()).files.push(file);
+        self
+    }
+
+    /// Map an input line of a file of a stratum to an output line of the class.
+    #[must_use]
+    pub fn line<S: AsRef<str>>(
+        self,
+        stratum: S,
+        file_id: u32,
+        input_line: u32,
+        output_line: u32,
+    ) -> Self {
+        self.lines(stratum, LineMapping::new(file_id, input_line, output_line))
+    }
+
+    /// Add a mapping of a range of input lines to output lines of the class to a stratum.
+    #[must_use]
+    pub fn lines<S: AsRef<str>>(mut self, stratum: S, mapping: LineMapping) -> Self {
+        self.stratum(stratum.as_ref()).lines.push(mapping);
+        self
+    }
+
+    /// Build the source map.
+    ///
+    /// # Errors
+    /// - If the default stratum is not declared.
+    /// - If a file identifier is declared more than once in a stratum.
+    /// - If a line mapping refers to a file that is not declared or has a repeat count of 0.
+    pub fn build(&self) -> Result<SourceMap> {
+        let source_map = SourceMap {
+            output_file_name: self.output_file_name.clone(),
+            default_stratum: self.default_stratum.clone(),
+            strata: self.strata.clone(),
+        };
+        source_map.verify()?;
+        Ok(source_map)
+    }
+
+    /// Add the source map to a class file as its `SourceDebugExtension` attribute, replacing an
+    /// existing attribute.
+    ///
+    /// # Errors
+    /// - If the class file version is earlier than Java 5.
+    /// - If the source map is invalid; see [`build`](SourceMapBuilder::build).
+    pub fn add_to(&self, class_file: &mut ClassFile) -> Result<()> {
+        if class_file.version < VERSION_49_0 {
+            return Err(InvalidSourceMap(format!(
+                "the SourceDebugExtension attribute requires Java 5 or later; found {}",
+                class_file.version
+            )));
+        }
+        let debug_extension = self.build()?.to_string();
+        class_file
+            .attributes
+            .retain(|attribute| !matches!(attribute, Attribute::SourceDebugExtension { .. }));
+        class_file.attributes.push(Attribute::SourceDebugExtension {
+            name_index: class_file.constant_pool.add_utf8("SourceDebugExtension")?,
+            debug_extension,
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ClassFileBuilder;
+
+    const SMAP: &str = "SMAP\nhello_jsp.java\nJSP\n*S JSP\n*F\n+ 0 hello.jsp\npages/hello.jsp\n1 footer.jsp\n*L\n1,5:10,2\n7:20\n3#1:30\n*E\n";
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let source_map: SourceMap = SMAP.parse()?;
+        assert_eq!("hello_jsp.java", source_map.output_file_name());
+        assert_eq!("JSP", source_map.default_stratum());
+        let stratum = source_map.stratum("JSP").expect("stratum");
+        assert_eq!(
+            &[
+                SourceFile::new(0, "hello.jsp", Some("pages/hello.jsp")),
+                SourceFile::new(1, "footer.jsp", None)
+            ],
+            stratum.files()
+        );
+        assert_eq!(
+            &[
+                LineMapping {
+                    file_id: 0,
+                    input_start_line: 1,
+                    repeat_count: 5,
+                    output_start_line: 10,
+                    output_line_increment: 2,
+                },
+                LineMapping::new(0, 7, 20),
+                LineMapping::new(1, 3, 30),
+            ],
+            stratum.lines()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_display_round_trip() -> Result<()> {
+        let source_map: SourceMap = SMAP.parse()?;
+        assert_eq!(SMAP, source_map.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_ignores_vendor_sections() -> Result<()> {
+        let smap = "SMAP\r\nFoo.java\r\nJava\r\n*S Foo\r\n*V\r\nvendor data\r\n*F\r\n1 Foo.foo\r\n*L\r\n2#1:4\r\n*E\r\n";
+        let source_map: SourceMap = smap.parse()?;
+        assert_eq!("Java", source_map.default_stratum());
+        let (file, line) = source_map.input_line(Some("Foo"), 4).expect("line");
+        assert_eq!(("Foo.foo", 2), (file.name(), line));
+        assert_eq!(None, source_map.input_line(None, 4));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let invalid = [
+            "",
+            "SMAP\nhello_jsp.java\n",
+            "SMAP\nhello_jsp.java\nJSP\n*S JSP\n*S JSP\n*E\n",
+            "SMAP\nhello_jsp.java\nJSP\n*S JSP\n*F\n1 hello.jsp\n",
+            "SMAP\nhello_jsp.java\nJSP\n*F\n1 hello.jsp\n*E\n",
+            "SMAP\nhello_jsp.java\nJSP\n*S JSP\n*F\n1 hello.jsp\n*L\n1#2:3\n*E\n",
+            "SMAP\nhello_jsp.java\nJSP\n*S JSP\n*F\n1 hello.jsp\n*L\n1#1:x\n*E\n",
+            "SMAP\nhello_jsp.java\nJSP\n*S JSP\n*F\n+ 1 hello.jsp\n",
+            "SMAP\nhello_jsp.java\nJSP\n*S JSP\n*F\n1 a.jsp\n1 b.jsp\n*E\n",
+            "SMAP\nhello_jsp.java\nJava\n*O JSP\n*E\n",
+            "SMAP\nhello_jsp.java\nTemplate\n*E\n",
+        ];
+        for smap in invalid {
+            assert!(
+                matches!(smap.parse::<SourceMap>(), Err(InvalidSourceMap(_))),
+                "{smap:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_input_line() -> Result<()> {
+        let source_map: SourceMap = SMAP.parse()?;
+        let input_line = |output_line| {
+            source_map
+                .input_line(None, output_line)
+                .map(|(file, line)| (file.name().to_string(), line))
+        };
+        assert_eq!(None, input_line(9));
+        assert_eq!(Some(("hello.jsp".to_string(), 1)), input_line(10));
+        assert_eq!(Some(("hello.jsp".to_string(), 1)), input_line(11));
+        assert_eq!(Some(("hello.jsp".to_string(), 5)), input_line(19));
+        assert_eq!(Some(("hello.jsp".to_string(), 7)), input_line(20));
+        assert_eq!(None, input_line(21));
+        assert_eq!(Some(("footer.jsp".to_string(), 3)), input_line(30));
+        assert_eq!(None, source_map.input_line(Some("Kotlin"), 10));
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder() -> Result<()> {
+        let source_map = SourceMapBuilder::new("hello_jsp.java", "JSP")
+            .file("JSP", 0, "hello.jsp", Some("pages/hello.jsp"))
+            .file("JSP", 1, "footer.jsp", None)
+            .lines(
+                "JSP",
+                LineMapping {
+                    file_id: 0,
+                    input_start_line: 1,
+                    repeat_count: 5,
+                    output_start_line: 10,
+                    output_line_increment: 2,
+                },
+            )
+            .line("JSP", 0, 7, 20)
+            .line("JSP", 1, 3, 30)
+            .build()?;
+        assert_eq!(SMAP, source_map.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_undeclared_file() {
+        let result = SourceMapBuilder::new("hello_jsp.java", "JSP")
+            .line("JSP", 1, 1, 1)
+            .build();
+        assert!(matches!(result, Err(InvalidSourceMap(_))));
+    }
+
+    #[test]
+    fn test_add_to() -> Result<()> {
+        let mut class_file = ClassFileBuilder::new("hello_jsp").build()?;
+        let builder = SourceMapBuilder::new("hello_jsp.java", "JSP")
+            .file("JSP", 1, "hello.jsp", None)
+            .line("JSP", 1, 3, 42);
+        builder.add_to(&mut class_file)?;
+        builder.add_to(&mut class_file)?;
+        assert_eq!(1, class_file.attributes.len());
+        assert_eq!(Some(builder.build()?), class_file.source_map()?);
+
+        let mut bytes = Vec::new();
+        class_file.to_bytes(&mut bytes)?;
+        let class_file = ClassFile::from_bytes(&mut std::io::Cursor::new(bytes))?;
+        assert_eq!(Some(builder.build()?), class_file.source_map()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_to_java_1_4() -> Result<()> {
+        let mut class_file = ClassFileBuilder::new("hello_jsp")
+            .java_version(Version::Java1_4 { minor: 0 })
+            .build()?;
+        let result = SourceMapBuilder::new("hello_jsp.java", "Java").add_to(&mut class_file);
+        assert!(matches!(result, Err(InvalidSourceMap(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_source_map_none() -> Result<()> {
+        let class_file = ClassFileBuilder::new("hello_jsp").build()?;
+        assert_eq!(None, class_file.source_map()?);
+        Ok(())
+    }
+}
@@ -0,0 +1,561 @@
+use crate::attributes::{Attribute, Instruction};
+use crate::builder::{load_instruction, return_instruction};
+use crate::class_access_flags::ClassAccessFlags;
+use crate::class_file::ClassFile;
+use crate::error::Error::{MemberConflict, MemberNotFound};
+use crate::error::Result;
+use crate::field_access_flags::FieldAccessFlags;
+use crate::field_type::FieldType;
+use crate::method::Method;
+use crate::method_access_flags::MethodAccessFlags;
+use crate::method_descriptor::MethodDescriptor;
+
+/// Member of a class that a synthetic accessor method provides access to.
+///
+/// Before Java 11 introduced nest-based access control, `javac` generated static accessor
+/// methods named `access$000`, `access$100`, etc. in the enclosing class so that nested classes
+/// could access its private members.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Accessor {
+    /// Read the value of a field.
+    GetField { name: String },
+    /// Write the value of a field; the accessor returns the value that was written.
+    PutField { name: String },
+    /// Invoke a method.
+    Invoke { name: String, descriptor: String },
+}
+
+/// Add a bridge method that invokes the method with the target descriptor.
+pub(crate) fn add_bridge_method(
+    class_file: &mut ClassFile,
+    name: &str,
+    descriptor: &str,
+    target_descriptor: &str,
+) -> Result<()> {
+    let class_name = class_file.class_name()?.clone();
+    if find_method(class_file, name, descriptor)?.is_some() {
+        return Err(MemberConflict(format!("{class_name}.{name}{descriptor}")));
+    }
+    let Some(target) = find_method(class_file, name, target_descriptor)? else {
+        return Err(MemberNotFound(format!(
+            "{class_name}.{name}{target_descriptor}"
+        )));
+    };
+    let access_flags = (target.access_flags
+        & (MethodAccessFlags::PUBLIC | MethodAccessFlags::PROTECTED))
+        | MethodAccessFlags::SYNTHETIC
+        | MethodAccessFlags::BRIDGE;
+    let bridge = MethodDescriptor::parse(descriptor)?;
+    let target = MethodDescriptor::parse(target_descriptor)?;
+
+    let constant_pool = &mut class_file.constant_pool;
+    let mut code = vec![Instruction::Aload_0];
+    let mut slot = 1;
+    for (parameter, target_parameter) in bridge.parameters.iter().zip(&target.parameters) {
+        code.push(load_instruction(parameter, u8::try_from(slot)?));
+        if parameter != target_parameter {
+            if let FieldType::Object(_) | FieldType::Array(_) = target_parameter {
+                let class_index = constant_pool.add_class(target_parameter.class_name())?;
+                code.push(Instruction::Checkcast(class_index));
+            }
+        }
+        slot += parameter.slot_size();
+    }
+    let this_class = class_file.this_class;
+    if class_file
+        .access_flags
+        .contains(ClassAccessFlags::INTERFACE)
+    {
+        let method_index =
+            constant_pool.add_interface_method_ref(this_class, name, target_descriptor)?;
+        let count = u8::try_from(slot)?;
+        code.push(Instruction::Invokeinterface(method_index, count));
+    } else {
+        let method_index = constant_pool.add_method_ref(this_class, name, target_descriptor)?;
+        code.push(Instruction::Invokevirtual(method_index));
+    }
+    code.push(return_type_instruction(bridge.return_type.as_ref()));
+
+    let max_stack = slot.max(bridge.return_size()).max(target.return_size());
+    let method = method(
+        class_file,
+        access_flags,
+        name,
+        descriptor,
+        (max_stack, slot),
+        code,
+    )?;
+    class_file.methods.push(method);
+    Ok(())
+}
+
+/// Parameters, return type, maximum stack depth and code of an accessor method.
+struct AccessorCode {
+    parameters: Vec<FieldType>,
+    return_type: Option<FieldType>,
+    max_stack: usize,
+    code: Vec<Instruction>,
+}
+
+/// Add a static accessor method for a member of the class; returns the name and descriptor of
+/// the accessor.
+pub(crate) fn add_accessor(
+    class_file: &mut ClassFile,
+    accessor: &Accessor,
+) -> Result<(String, String)> {
+    let AccessorCode {
+        parameters,
+        return_type,
+        max_stack,
+        code,
+    } = match accessor {
+        Accessor::GetField { name } => field_getter(class_file, name)?,
+        Accessor::PutField { name } => field_setter(class_file, name)?,
+        Accessor::Invoke { name, descriptor } => method_invoker(class_file, name, descriptor)?,
+    };
+
+    let name = accessor_name(class_file)?;
+    let descriptor = MethodDescriptor {
+        parameters,
+        return_type,
+    };
+    let max_locals = descriptor.parameters_size();
+    let descriptor = descriptor.descriptor();
+    let method = method(
+        class_file,
+        MethodAccessFlags::STATIC | MethodAccessFlags::SYNTHETIC,
+        &name,
+        &descriptor,
+        (max_stack, max_locals),
+        code,
+    )?;
+    class_file.methods.push(method);
+    Ok((name, descriptor))
+}
+
+/// Create the code of an accessor that returns the value of a field.
+fn field_getter(class_file: &mut ClassFile, name: &str) -> Result<AccessorCode> {
+    let (field_type, is_static, field_index) = field_ref(class_file, name)?;
+    let (parameters, mut code) = if is_static {
+        (Vec::new(), vec![Instruction::Getstatic(field_index)])
+    } else {
+        (
+            vec![FieldType::Object(class_file.class_name()?.clone())],
+            vec![Instruction::Aload_0, Instruction::Getfield(field_index)],
+        )
+    };
+    code.push(return_instruction(&field_type));
+    Ok(AccessorCode {
+        parameters,
+        max_stack: field_type.slot_size(),
+        return_type: Some(field_type),
+        code,
+    })
+}
+
+/// Create the code of an accessor that sets the value of a field and returns the value.
+fn field_setter(class_file: &mut ClassFile, name: &str) -> Result<AccessorCode> {
+    let (field_type, is_static, field_index) = field_ref(class_file, name)?;
+    let category2 = field_type.slot_size() == 2;
+    let (parameters, mut code) = if is_static {
+        let duplicate = if category2 {
+            Instruction::Dup2
+        } else {
+            Instruction::Dup
+        };
+        (
+            vec![field_type.clone()],
+            vec![
+                load_instruction(&field_type, 0),
+                duplicate,
+                Instruction::Putstatic(field_index),
+            ],
+        )
+    } else {
+        let duplicate = if category2 {
+            Instruction::Dup2_x1
+        } else {
+            Instruction::Dup_x1
+        };
+        (
+            vec![
+                FieldType::Object(class_file.class_name()?.clone()),
+                field_type.clone(),
+            ],
+            vec![
+                Instruction::Aload_0,
+                load_instruction(&field_type, 1),
+                duplicate,
+                Instruction::Putfield(field_index),
+            ],
+        )
+    };
+    code.push(return_instruction(&field_type));
+    Ok(AccessorCode {
+        parameters,
+        max_stack: field_type.slot_size() * 2 + usize::from(!is_static),
+        return_type: Some(field_type),
+        code,
+    })
+}
+
+/// Create the code of an accessor that invokes a method and returns the result.
+fn method_invoker(
+    class_file: &mut ClassFile,
+    name: &str,
+    descriptor: &str,
+) -> Result<AccessorCode> {
+    let class_name = class_file.class_name()?.clone();
+    let Some(method) = find_method(class_file, name, descriptor)? else {
+        return Err(MemberNotFound(format!("{class_name}.{name}{descriptor}")));
+    };
+    let is_static = method.access_flags.contains(MethodAccessFlags::STATIC);
+    let method_descriptor = MethodDescriptor::parse(descriptor)?;
+    let this_class = class_file.this_class;
+    let interface = class_file
+        .access_flags
+        .contains(ClassAccessFlags::INTERFACE);
+    let constant_pool = &mut class_file.constant_pool;
+    let method_index = if interface {
+        constant_pool.add_interface_method_ref(this_class, name, descriptor)?
+    } else {
+        constant_pool.add_method_ref(this_class, name, descriptor)?
+    };
+
+    let mut parameters = method_descriptor.parameters;
+    if !is_static {
+        parameters.insert(0, FieldType::Object(class_name));
+    }
+    let mut code = Vec::new();
+    let mut slot = 0;
+    for parameter in &parameters {
+        code.push(load_instruction(parameter, u8::try_from(slot)?));
+        slot += parameter.slot_size();
+    }
+    if is_static {
+        code.push(Instruction::Invokestatic(method_index));
+    } else {
+        code.push(Instruction::Invokespecial(method_index));
+    }
+    let return_type = method_descriptor.return_type;
+    code.push(return_type_instruction(return_type.as_ref()));
+    let return_size = return_type.as_ref().map_or(0, FieldType::slot_size);
+    Ok(AccessorCode {
+        parameters,
+        return_type,
+        max_stack: slot.max(return_size),
+        code,
+    })
+}
+
+/// Find a method of the class by name and descriptor.
+fn find_method<'a>(
+    class_file: &'a ClassFile,
+    name: &str,
+    descriptor: &str,
+) -> Result<Option<&'a Method>> {
+    let constant_pool = &class_file.constant_pool;
+    for method in &class_file.methods {
+        if constant_pool.try_get_utf8(method.name_index)? == name
+            && constant_pool.try_get_utf8(method.descriptor_index)? == descriptor
+        {
+            return Ok(Some(method));
+        }
+    }
+    Ok(None)
+}
+
+/// Find a field of the class by name and add a field reference to it; returns the type of the
+/// field, whether it is static and the index of the field reference.
+fn field_ref(class_file: &mut ClassFile, name: &str) -> Result<(FieldType, bool, u16)> {
+    let constant_pool = &class_file.constant_pool;
+    let mut found = None;
+    for field in &class_file.fields {
+        if constant_pool.try_get_utf8(field.name_index)? == name {
+            let is_static = field.access_flags.contains(FieldAccessFlags::STATIC);
+            found = Some((field.field_type.clone(), is_static));
+            break;
+        }
+    }
+    let Some((field_type, is_static)) = found else {
+        let class_name = class_file.class_name()?;
+        return Err(MemberNotFound(format!("{class_name}.{name}")));
+    };
+    let this_class = class_file.this_class;
+    let field_index = class_file.constant_pool.add_field_ref(
+        this_class,
+        name,
+        field_type.descriptor().as_str(),
+    )?;
+    Ok((field_type, is_static, field_index))
+}
+
+/// Get the next unused accessor name; accessors are numbered `access$000`, `access$100`, etc.
+/// like the accessors generated by `javac`.
+fn accessor_name(class_file: &ClassFile) -> Result<String> {
+    let constant_pool = &class_file.constant_pool;
+    let mut names = Vec::with_capacity(class_file.methods.len());
+    for method in &class_file.methods {
+        names.push(constant_pool.try_get_utf8(method.name_index)?);
+    }
+    let mut number: usize = 0;
+    loop {
+        let name = format!("access${:03}", number * 100);
+        if !names.contains(&&name) {
+            return Ok(name);
+        }
+        number += 1;
+    }
+}
+
+/// Get the instruction that returns a value of the return type; `None` for `void`.
+fn return_type_instruction(return_type: Option<&FieldType>) -> Instruction {
+    match return_type {
+        Some(field_type) => return_instruction(field_type),
+        None => Instruction::Return,
+    }
+}
+
+/// Create a method with a `Code` attribute; `limits` is the maximum stack depth and number of
+/// local variables of the code.
+fn method(
+    class_file: &mut ClassFile,
+    access_flags: MethodAccessFlags,
+    name: &str,
+    descriptor: &str,
+    limits: (usize, usize),
+    code: Vec<Instruction>,
+) -> Result<Method> {
+    let (max_stack, max_locals) = limits;
+    let constant_pool = &mut class_file.constant_pool;
+    Ok(Method {
+        access_flags,
+        name_index: constant_pool.add_utf8(name)?,
+        descriptor_index: constant_pool.add_utf8(descriptor)?,
+        attributes: vec![Attribute::Code {
+            name_index: constant_pool.add_utf8("Code")?,
+            max_stack: u16::try_from(max_stack)?,
+            max_locals: u16::try_from(max_locals)?,
+            code,
+            exception_table: Vec::new(),
+            attributes: Vec::new(),
+        }],
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::base_type::BaseType;
+    use crate::field::Field;
+    use crate::version::Version;
+
+    fn class_file() -> Result<ClassFile> {
+        let mut class_file = crate::test::class_file("Foo", Version::Java8 { minor: 0 })?;
+        class_file.access_flags = ClassAccessFlags::PUBLIC | ClassAccessFlags::SUPER;
+        for (name, field_type, access_flags) in [
+            (
+                "count",
+                FieldType::Base(BaseType::Int),
+                FieldAccessFlags::PRIVATE,
+            ),
+            (
+                "total",
+                FieldType::Base(BaseType::Long),
+                FieldAccessFlags::PRIVATE | FieldAccessFlags::STATIC,
+            ),
+        ] {
+            let field = Field {
+                access_flags,
+                name_index: class_file.constant_pool.add_utf8(name)?,
+                descriptor_index: class_file.constant_pool.add_utf8(field_type.descriptor())?,
+                field_type,
+                attributes: Vec::new(),
+            };
+            class_file.fields.push(field);
+        }
+        let string = class_file.constant_pool.add_string("foo")?;
+        let get = method(
+            &mut class_file,
+            MethodAccessFlags::PUBLIC,
+            "get",
+            "()Ljava/lang/String;",
+            (1, 1),
+            vec![
+                Instruction::Ldc(u8::try_from(string)?),
+                Instruction::Areturn,
+            ],
+        )?;
+        let put = method(
+            &mut class_file,
+            MethodAccessFlags::PUBLIC,
+            "put",
+            "(Ljava/lang/String;[I)V",
+            (0, 3),
+            vec![Instruction::Return],
+        )?;
+        let secret = method(
+            &mut class_file,
+            MethodAccessFlags::PRIVATE,
+            "secret",
+            "(IJ)J",
+            (2, 4),
+            vec![Instruction::Lload_2, Instruction::Lreturn],
+        )?;
+        let static_secret = method(
+            &mut class_file,
+            MethodAccessFlags::PRIVATE | MethodAccessFlags::STATIC,
+            "staticSecret",
+            "()V",
+            (0, 0),
+            vec![Instruction::Return],
+        )?;
+        class_file.methods = vec![get, put, secret, static_secret];
+        Ok(class_file)
+    }
+
+    fn method_code(
+        class_file: &ClassFile,
+        name: &str,
+        descriptor: &str,
+    ) -> Result<(u16, u16, Vec<String>)> {
+        let Some(method) = find_method(class_file, name, descriptor)? else {
+            panic!("expected method {name}{descriptor}");
+        };
+        let Some(Attribute::Code {
+            max_stack,
+            max_locals,
+            code,
+            ..
+        }) = method.attributes.first()
+        else {
+            panic!("expected code attribute");
+        };
+        let code = code.iter().map(ToString::to_string).collect();
+        Ok((*max_stack, *max_locals, code))
+    }
+
+    #[test]
+    fn test_bridge_method() -> Result<()> {
+        let mut class_file = class_file()?;
+        class_file.add_bridge_method("get", "()Ljava/lang/Object;", "()Ljava/lang/String;")?;
+        class_file.add_bridge_method(
+            "put",
+            "(Ljava/lang/Object;Ljava/lang/Object;)V",
+            "(Ljava/lang/String;[I)V",
+        )?;
+
+        let Some(bridge) = find_method(&class_file, "get", "()Ljava/lang/Object;")? else {
+            panic!("expected bridge method");
+        };
+        assert_eq!(
+            MethodAccessFlags::PUBLIC | MethodAccessFlags::SYNTHETIC | MethodAccessFlags::BRIDGE,
+            bridge.access_flags
+        );
+        let (max_stack, max_locals, code) = method_code(
+            &class_file,
+            "put",
+            "(Ljava/lang/Object;Ljava/lang/Object;)V",
+        )?;
+        assert_eq!((3, 3), (max_stack, max_locals));
+        assert_eq!(7, code.len());
+        assert!(code[2].starts_with("checkcast"));
+        assert!(code[4].starts_with("checkcast"));
+        assert!(code[5].starts_with("invokevirtual"));
+        class_file.verify()
+    }
+
+    #[test]
+    fn test_bridge_method_errors() -> Result<()> {
+        let mut class_file = class_file()?;
+        assert_eq!(
+            Err(MemberNotFound("Foo.get()Ljava/lang/Integer;".to_string())),
+            class_file.add_bridge_method("get", "()Ljava/lang/Object;", "()Ljava/lang/Integer;")
+        );
+        assert_eq!(
+            Err(MemberConflict("Foo.get()Ljava/lang/String;".to_string())),
+            class_file.add_bridge_method("get", "()Ljava/lang/String;", "()Ljava/lang/String;")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_accessors() -> Result<()> {
+        let mut class_file = class_file()?;
+        let get_count = class_file.add_accessor(&Accessor::GetField {
+            name: "count".to_string(),
+        })?;
+        let put_count = class_file.add_accessor(&Accessor::PutField {
+            name: "count".to_string(),
+        })?;
+        let get_total = class_file.add_accessor(&Accessor::GetField {
+            name: "total".to_string(),
+        })?;
+        let put_total = class_file.add_accessor(&Accessor::PutField {
+            name: "total".to_string(),
+        })?;
+        let secret = class_file.add_accessor(&Accessor::Invoke {
+            name: "secret".to_string(),
+            descriptor: "(IJ)J".to_string(),
+        })?;
+        let static_secret = class_file.add_accessor(&Accessor::Invoke {
+            name: "staticSecret".to_string(),
+            descriptor: "()V".to_string(),
+        })?;
+
+        assert_eq!(
+            vec![
+                ("access$000".to_string(), "(LFoo;)I".to_string()),
+                ("access$100".to_string(), "(LFoo;I)I".to_string()),
+                ("access$200".to_string(), "()J".to_string()),
+                ("access$300".to_string(), "(J)J".to_string()),
+                ("access$400".to_string(), "(LFoo;IJ)J".to_string()),
+                ("access$500".to_string(), "()V".to_string()),
+            ],
+            vec![
+                get_count,
+                put_count,
+                get_total,
+                put_total,
+                secret,
+                static_secret
+            ]
+        );
+        let (max_stack, max_locals, code) = method_code(&class_file, "access$100", "(LFoo;I)I")?;
+        assert_eq!((3, 2), (max_stack, max_locals));
+        assert_eq!("dup_x1", code[2]);
+        let (max_stack, max_locals, code) = method_code(&class_file, "access$300", "(J)J")?;
+        assert_eq!((4, 2), (max_stack, max_locals));
+        assert_eq!("dup2", code[1]);
+        let (max_stack, max_locals, code) = method_code(&class_file, "access$400", "(LFoo;IJ)J")?;
+        assert_eq!((4, 4), (max_stack, max_locals));
+        assert!(code[3].starts_with("invokespecial"));
+        let Some(accessor) = find_method(&class_file, "access$000", "(LFoo;)I")? else {
+            panic!("expected accessor");
+        };
+        assert_eq!(
+            MethodAccessFlags::STATIC | MethodAccessFlags::SYNTHETIC,
+            accessor.access_flags
+        );
+        class_file.verify()
+    }
+
+    #[test]
+    fn test_accessor_not_found() -> Result<()> {
+        let mut class_file = class_file()?;
+        assert_eq!(
+            Err(MemberNotFound("Foo.missing".to_string())),
+            class_file.add_accessor(&Accessor::GetField {
+                name: "missing".to_string(),
+            })
+        );
+        assert_eq!(
+            Err(MemberNotFound("Foo.missing()V".to_string())),
+            class_file.add_accessor(&Accessor::Invoke {
+                name: "missing".to_string(),
+                descriptor: "()V".to_string(),
+            })
+        );
+        Ok(())
+    }
+}
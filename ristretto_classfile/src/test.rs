@@ -0,0 +1,15 @@
+use crate::{ClassFile, ConstantPool, Result, Version};
+
+/// Get a class file for testing with the given name and version that extends `java/lang/Object`.
+pub(crate) fn class_file(name: &str, version: Version) -> Result<ClassFile> {
+    let mut constant_pool = ConstantPool::default();
+    let this_class = constant_pool.add_class(name)?;
+    let super_class = constant_pool.add_class("java/lang/Object")?;
+    Ok(ClassFile {
+        version,
+        constant_pool,
+        this_class,
+        super_class,
+        ..Default::default()
+    })
+}
@@ -0,0 +1,231 @@
+use crate::class_access_flags::ClassAccessFlags;
+use crate::class_file::ClassFile;
+use crate::Result;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
+const JAVA_LANG_OBJECT: &str = "java/lang/Object";
+
+/// Class hierarchy information for a class that is referenced by the code being verified.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResolvedClass {
+    pub access_flags: ClassAccessFlags,
+    /// Internal name of the super class; `None` for `java/lang/Object`
+    pub super_class: Option<String>,
+    /// Internal names of the direct super interfaces
+    pub interfaces: Vec<String>,
+}
+
+impl ResolvedClass {
+    /// Get the class hierarchy information declared by a class file.
+    ///
+    /// # Errors
+    /// If the super class or an interface is not a valid class constant.
+    pub fn from_class_file(class_file: &ClassFile) -> Result<ResolvedClass> {
+        let constant_pool = &class_file.constant_pool;
+        let super_class = if class_file.super_class == 0 {
+            None
+        } else {
+            Some(constant_pool.try_get_class(class_file.super_class)?.clone())
+        };
+        let interfaces = class_file
+            .interfaces
+            .iter()
+            .map(|index| constant_pool.try_get_class(*index).cloned())
+            .collect::<Result<Vec<_>>>()?;
+        Ok(ResolvedClass {
+            access_flags: class_file.access_flags,
+            super_class,
+            interfaces,
+        })
+    }
+
+    /// Returns true if the class is an interface.
+    #[must_use]
+    pub fn is_interface(&self) -> bool {
+        self.access_flags.contains(ClassAccessFlags::INTERFACE)
+    }
+}
+
+/// Resolves the class hierarchy of classes referenced by the code being verified.
+///
+/// Without hierarchy information, distinct reference types are merged to `java/lang/Object`;
+/// embedders that can load classes (e.g. a class loader or VM) implement this trait so that
+/// reference types are merged to their most specific common super class instead.
+pub trait ClassResolver {
+    /// Resolve a class by its internal name (e.g. `java/lang/String`); returns `None` if the
+    /// class is unknown.
+    fn resolve(&self, class_name: &str) -> Option<ResolvedClass>;
+}
+
+impl<S: BuildHasher> ClassResolver for HashMap<String, ResolvedClass, S> {
+    fn resolve(&self, class_name: &str) -> Option<ResolvedClass> {
+        self.get(class_name).cloned()
+    }
+}
+
+/// Resolver for the class being verified; the class is resolved from its class file and all other
+/// classes are resolved by the embedder supplied resolver.
+pub(crate) struct ClassFileResolver<'a> {
+    class_name: &'a str,
+    class: ResolvedClass,
+    resolver: &'a dyn ClassResolver,
+}
+
+impl<'a> ClassFileResolver<'a> {
+    pub(crate) fn new(class_file: &'a ClassFile, resolver: &'a dyn ClassResolver) -> Result<Self> {
+        Ok(ClassFileResolver {
+            class_name: class_file.class_name()?,
+            class: ResolvedClass::from_class_file(class_file)?,
+            resolver,
+        })
+    }
+}
+
+impl ClassResolver for ClassFileResolver<'_> {
+    fn resolve(&self, class_name: &str) -> Option<ResolvedClass> {
+        if class_name == self.class_name {
+            Some(self.class.clone())
+        } else {
+            self.resolver.resolve(class_name)
+        }
+    }
+}
+
+/// Get the most specific common super class of two classes.  As with the JVM verifier,
+/// interfaces are treated as `java/lang/Object`; if either class cannot be resolved,
+/// `java/lang/Object` is returned.  Super classes that cannot be resolved end the hierarchy of a
+/// class, so the result is a common super class, but may not be the most specific one.
+pub(crate) fn common_super_class(resolver: &dyn ClassResolver, left: &str, right: &str) -> String {
+    if left == right {
+        return left.to_string();
+    }
+
+    let mut ancestors: Vec<String> = Vec::new();
+    let mut current = Some(left.to_string());
+    while let Some(class_name) = current {
+        if ancestors.contains(&class_name) {
+            break;
+        }
+        let class = resolver.resolve(&class_name);
+        if ancestors.is_empty() && class.as_ref().is_none_or(ResolvedClass::is_interface) {
+            return JAVA_LANG_OBJECT.to_string();
+        }
+        current = class.and_then(|class| class.super_class);
+        ancestors.push(class_name);
+    }
+
+    let mut visited: Vec<String> = Vec::new();
+    let mut current = Some(right.to_string());
+    while let Some(class_name) = current {
+        if visited.contains(&class_name) {
+            break;
+        }
+        let class = resolver.resolve(&class_name);
+        if visited.is_empty() && class.as_ref().is_none_or(ResolvedClass::is_interface) {
+            return JAVA_LANG_OBJECT.to_string();
+        }
+        if ancestors.contains(&class_name) {
+            return class_name;
+        }
+        current = class.and_then(|class| class.super_class);
+        visited.push(class_name);
+    }
+    JAVA_LANG_OBJECT.to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::constant_pool::ConstantPool;
+
+    fn class(super_class: &str) -> ResolvedClass {
+        ResolvedClass {
+            access_flags: ClassAccessFlags::PUBLIC,
+            super_class: Some(super_class.to_string()),
+            interfaces: Vec::new(),
+        }
+    }
+
+    fn resolver() -> HashMap<String, ResolvedClass> {
+        let mut classes = HashMap::new();
+        classes.insert(JAVA_LANG_OBJECT.to_string(), ResolvedClass::default());
+        classes.insert("java/lang/Number".to_string(), class(JAVA_LANG_OBJECT));
+        classes.insert("java/lang/Integer".to_string(), class("java/lang/Number"));
+        classes.insert("java/lang/Long".to_string(), class("java/lang/Number"));
+        classes.insert("java/lang/String".to_string(), class(JAVA_LANG_OBJECT));
+        classes.insert(
+            "java/lang/Comparable".to_string(),
+            ResolvedClass {
+                access_flags: ClassAccessFlags::INTERFACE | ClassAccessFlags::ABSTRACT,
+                super_class: Some(JAVA_LANG_OBJECT.to_string()),
+                interfaces: Vec::new(),
+            },
+        );
+        classes
+    }
+
+    #[test]
+    fn test_common_super_class() {
+        let resolver = resolver();
+        let cases = [
+            (
+                "java/lang/Integer",
+                "java/lang/Integer",
+                "java/lang/Integer",
+            ),
+            ("java/lang/Integer", "java/lang/Long", "java/lang/Number"),
+            ("java/lang/Integer", "java/lang/Number", "java/lang/Number"),
+            ("java/lang/Number", "java/lang/Integer", "java/lang/Number"),
+            ("java/lang/Integer", "java/lang/String", JAVA_LANG_OBJECT),
+            (
+                "java/lang/Integer",
+                "java/lang/Comparable",
+                JAVA_LANG_OBJECT,
+            ),
+            ("java/lang/Integer", "foo/Unknown", JAVA_LANG_OBJECT),
+            ("foo/Unknown", "java/lang/Integer", JAVA_LANG_OBJECT),
+        ];
+        for (left, right, expected) in cases {
+            assert_eq!(expected, common_super_class(&resolver, left, right));
+        }
+    }
+
+    #[test]
+    fn test_common_super_class_cycle() {
+        let mut resolver = HashMap::new();
+        resolver.insert("A".to_string(), class("B"));
+        resolver.insert("B".to_string(), class("A"));
+        resolver.insert("C".to_string(), class("C"));
+        assert_eq!(JAVA_LANG_OBJECT, common_super_class(&resolver, "A", "C"));
+        assert_eq!("A", common_super_class(&resolver, "B", "A"));
+    }
+
+    #[test]
+    fn test_from_class_file() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let this_class = constant_pool.add_class("Foo")?;
+        let super_class = constant_pool.add_class("Bar")?;
+        let interface = constant_pool.add_class("java/io/Serializable")?;
+        let class_file = ClassFile {
+            constant_pool,
+            access_flags: ClassAccessFlags::PUBLIC,
+            this_class,
+            super_class,
+            interfaces: vec![interface],
+            ..Default::default()
+        };
+        let expected = ResolvedClass {
+            access_flags: ClassAccessFlags::PUBLIC,
+            super_class: Some("Bar".to_string()),
+            interfaces: vec!["java/io/Serializable".to_string()],
+        };
+        assert_eq!(expected, ResolvedClass::from_class_file(&class_file)?);
+
+        let resolver = HashMap::new();
+        let class_file_resolver = ClassFileResolver::new(&class_file, &resolver)?;
+        assert_eq!(Some(expected), class_file_resolver.resolve("Foo"));
+        assert_eq!(None, class_file_resolver.resolve("Bar"));
+        Ok(())
+    }
+}
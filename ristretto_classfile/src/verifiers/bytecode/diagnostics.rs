@@ -0,0 +1,449 @@
+use crate::attributes::Instruction;
+use crate::class_file::ClassFile;
+use crate::display::indent_lines;
+use crate::method::Method;
+use crate::verifiers::bytecode::class_resolver::ClassResolver;
+use crate::verifiers::bytecode::inference::{self, Frame};
+use crate::{Error, Result};
+use std::fmt;
+
+/// A sequence of instructions that is only entered at its first instruction and only branches at
+/// its last instruction.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BasicBlock {
+    /// Index of the first instruction of the block
+    pub start: usize,
+    pub instructions: Vec<Instruction>,
+    /// Indexes of the blocks that may execute after the block
+    pub successors: Vec<usize>,
+    /// Indexes of the blocks that handle exceptions thrown by the block
+    pub handlers: Vec<usize>,
+    /// Frame inferred on entry to the block; `None` if the block is unreachable or was not
+    /// reached before verification failed.
+    pub frame: Option<Frame>,
+}
+
+/// The instruction and frame at which verification failed.
+#[derive(Debug, PartialEq)]
+pub struct VerificationFailure {
+    /// Index of the instruction being verified; `None` if verification failed before the first
+    /// instruction, e.g. for an invalid method descriptor.
+    pub index: Option<usize>,
+    pub instruction: Option<Instruction>,
+    /// Frame inferred before the instruction
+    pub frame: Option<Frame>,
+    pub error: Error,
+}
+
+/// Diagnostics for the verification of a method: the control flow graph of the method, the
+/// frame inferred on entry to each basic block and the failure, if any.
+#[derive(Debug, PartialEq)]
+pub struct VerificationReport {
+    /// Method being verified, e.g. `Foo.bar(I)V`
+    pub method: String,
+    pub blocks: Vec<BasicBlock>,
+    pub failure: Option<VerificationFailure>,
+}
+
+impl VerificationReport {
+    /// Returns true if the method was verified without a failure.
+    #[must_use]
+    pub fn is_verified(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+impl fmt::Display for VerificationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.method)?;
+        for (block_index, block) in self.blocks.iter().enumerate() {
+            let end = block.start + block.instructions.len();
+            write!(f, "block {block_index} [{}..{end})", block.start)?;
+            if !block.successors.is_empty() {
+                write!(f, " -> {}", block_list(&block.successors))?;
+            }
+            if !block.handlers.is_empty() {
+                write!(f, " handlers {}", block_list(&block.handlers))?;
+            }
+            writeln!(f)?;
+            match &block.frame {
+                Some(frame) => writeln!(f, "{}", indent_lines(&frame_string(frame), "  "))?,
+                None => writeln!(f, "  unreachable")?,
+            }
+            for (offset, instruction) in block.instructions.iter().enumerate() {
+                writeln!(f, "  {:>4}: {instruction}", block.start + offset)?;
+            }
+        }
+
+        match &self.failure {
+            None => writeln!(f, "verified"),
+            Some(failure) => {
+                match (failure.index, &failure.instruction) {
+                    (Some(index), Some(instruction)) => {
+                        writeln!(f, "failed at instruction {index} ({instruction})")?;
+                    }
+                    (Some(index), None) => writeln!(f, "failed at instruction {index}")?,
+                    _ => writeln!(f, "failed")?,
+                }
+                if let Some(frame) = &failure.frame {
+                    writeln!(f, "{}", indent_lines(&frame_string(frame), "  "))?;
+                }
+                writeln!(f, "  {}", failure.error)
+            }
+        }
+    }
+}
+
+fn block_list(blocks: &[usize]) -> String {
+    blocks
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn frame_string(frame: &Frame) -> String {
+    let types = |types: &[inference::Type]| {
+        types
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    format!(
+        "locals: [{}]\nstack: [{}]",
+        types(&frame.locals),
+        types(&frame.stack)
+    )
+}
+
+/// Verify a method, collecting the control flow graph and inferred frames of the method along
+/// with the instruction and frame at which verification failed.  Unlike
+/// [`infer`](inference::infer), a verification failure is reported in the returned
+/// [`VerificationReport`] rather than as an error.
+///
+/// # Errors
+/// Returns an error if the class or method name or the method descriptor is not a valid constant.
+pub fn verify_method(
+    class_file: &ClassFile,
+    method: &Method,
+    resolver: &dyn ClassResolver,
+) -> Result<VerificationReport> {
+    let constant_pool = &class_file.constant_pool;
+    let class_name = class_file.class_name()?;
+    let method_name = constant_pool.try_get_utf8(method.name_index)?;
+    let method_descriptor = constant_pool.try_get_utf8(method.descriptor_index)?;
+
+    let mut frames = Vec::new();
+    let failure = inference::infer_frames(class_file, method, resolver, &mut frames)
+        .err()
+        .map(|(index, error)| {
+            let instruction = index
+                .and_then(|index| inference::code(method)?.1.get(index))
+                .cloned();
+            let frame = index.and_then(|index| frames.get(index).cloned().flatten());
+            VerificationFailure {
+                index,
+                instruction,
+                frame,
+                error,
+            }
+        });
+    let blocks = match inference::code(method) {
+        Some((_max_locals, code, exception_table)) => {
+            let handlers = exception_table
+                .iter()
+                .map(|entry| {
+                    (
+                        usize::from(entry.range_pc.start),
+                        usize::from(entry.range_pc.end),
+                        usize::from(entry.handler_pc),
+                    )
+                })
+                .collect::<Vec<_>>();
+            basic_blocks(code, &handlers, &frames)
+        }
+        None => Vec::new(),
+    };
+
+    Ok(VerificationReport {
+        method: format!("{class_name}.{method_name}{method_descriptor}"),
+        blocks,
+        failure,
+    })
+}
+
+/// Split the instructions into basic blocks.  Exception handlers are `(start, end, handler)`
+/// instruction index ranges; `frames` contains the inferred frame before each instruction.
+fn basic_blocks(
+    code: &[Instruction],
+    handlers: &[(usize, usize, usize)],
+    frames: &[Option<Frame>],
+) -> Vec<BasicBlock> {
+    if code.is_empty() {
+        return Vec::new();
+    }
+
+    // Instructions that fail to branch (e.g. subroutines) end a block without successors; the
+    // failure itself is reported by the frame inference.
+    let successors = (0..code.len())
+        .map(|index| inference::successors(code, index).unwrap_or_default())
+        .collect::<Vec<_>>();
+    let mut leaders = vec![false; code.len() + 1];
+    leaders[0] = true;
+    for (index, targets) in successors.iter().enumerate() {
+        if targets.as_slice() != [index + 1] {
+            leaders[index + 1] = true;
+            for target in targets {
+                leaders[*target] = true;
+            }
+        }
+    }
+    for (start, end, handler) in handlers {
+        for index in [*start, *end, *handler] {
+            if let Some(leader) = leaders.get_mut(index) {
+                *leader = true;
+            }
+        }
+    }
+    let starts = (0..code.len())
+        .filter(|index| leaders[*index])
+        .collect::<Vec<_>>();
+    let block_index = |index: usize| starts.partition_point(|start| *start <= index) - 1;
+
+    starts
+        .iter()
+        .enumerate()
+        .map(|(position, start)| {
+            let start = *start;
+            let end = starts.get(position + 1).copied().unwrap_or(code.len());
+            let mut block_successors = Vec::new();
+            for target in &successors[end - 1] {
+                let successor = block_index(*target);
+                if !block_successors.contains(&successor) {
+                    block_successors.push(successor);
+                }
+            }
+            let mut block_handlers = Vec::new();
+            for (handler_start, handler_end, handler) in handlers {
+                if *handler >= code.len() || *handler_end <= start || *handler_start >= end {
+                    continue;
+                }
+                let handler = block_index(*handler);
+                if !block_handlers.contains(&handler) {
+                    block_handlers.push(handler);
+                }
+            }
+            BasicBlock {
+                start,
+                instructions: code[start..end].to_vec(),
+                successors: block_successors,
+                handlers: block_handlers,
+                frame: frames.get(start).cloned().flatten(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::attributes::{Attribute, ExceptionTableEntry};
+    use crate::constant_pool::ConstantPool;
+    use crate::method_access_flags::MethodAccessFlags;
+    use crate::verifiers::bytecode::class_resolver::ResolvedClass;
+    use crate::verifiers::bytecode::inference::Type;
+    use std::collections::HashMap;
+
+    fn class_file_with_method(
+        descriptor: &str,
+        max_locals: u16,
+        code: Vec<Instruction>,
+        exception_table: Vec<ExceptionTableEntry>,
+    ) -> Result<(ClassFile, Method)> {
+        let mut constant_pool = ConstantPool::default();
+        let this_class = constant_pool.add_class("Foo")?;
+        let code_index = constant_pool.add_utf8("Code")?;
+        let name_index = constant_pool.add_utf8("test")?;
+        let descriptor_index = constant_pool.add_utf8(descriptor)?;
+        let method = Method {
+            access_flags: MethodAccessFlags::STATIC,
+            name_index,
+            descriptor_index,
+            attributes: vec![Attribute::Code {
+                name_index: code_index,
+                max_stack: 2,
+                max_locals,
+                code,
+                exception_table,
+                attributes: Vec::new(),
+            }],
+        };
+        let class_file = ClassFile {
+            constant_pool,
+            this_class,
+            methods: vec![method.clone()],
+            ..Default::default()
+        };
+        Ok((class_file, method))
+    }
+
+    #[test]
+    fn test_verify_method() -> Result<()> {
+        let (class_file, method) = class_file_with_method(
+            "(I)I",
+            1,
+            vec![
+                Instruction::Iload_0,
+                Instruction::Ifeq(4),
+                Instruction::Iconst_1,
+                Instruction::Ireturn,
+                Instruction::Iconst_0,
+                Instruction::Ireturn,
+            ],
+            Vec::new(),
+        )?;
+        let report = verify_method(
+            &class_file,
+            &method,
+            &HashMap::<String, ResolvedClass>::new(),
+        )?;
+
+        assert!(report.is_verified());
+        assert_eq!("Foo.test(I)I", report.method);
+        assert_eq!(3, report.blocks.len());
+        assert_eq!(vec![1, 2], report.blocks[0].successors);
+        assert!(report.blocks[1].successors.is_empty());
+        assert_eq!(4, report.blocks[2].start);
+        assert_eq!(
+            Some(Frame {
+                locals: vec![Type::Integer],
+                stack: Vec::new(),
+            }),
+            report.blocks[2].frame
+        );
+        let expected = indoc::indoc! {"
+            Foo.test(I)I
+            block 0 [0..2) -> 1, 2
+              locals: [int]
+              stack: []
+                 0: iload_0
+                 1: ifeq 4
+            block 1 [2..4)
+              locals: [int]
+              stack: []
+                 2: iconst_1
+                 3: ireturn
+            block 2 [4..6)
+              locals: [int]
+              stack: []
+                 4: iconst_0
+                 5: ireturn
+            verified
+        "};
+        assert_eq!(expected, report.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_method_failure() -> Result<()> {
+        let (class_file, method) = class_file_with_method(
+            "(I)V",
+            1,
+            vec![
+                Instruction::Iload_0,
+                Instruction::Ifeq(3),
+                Instruction::Iconst_1,
+                Instruction::Return,
+            ],
+            Vec::new(),
+        )?;
+        let report = verify_method(
+            &class_file,
+            &method,
+            &HashMap::<String, ResolvedClass>::new(),
+        )?;
+
+        assert!(!report.is_verified());
+        let Some(failure) = &report.failure else {
+            panic!("expected verification failure");
+        };
+        assert_eq!(Some(2), failure.index);
+        assert_eq!(Some(Instruction::Iconst_1), failure.instruction);
+        assert_eq!(
+            Some(Frame {
+                locals: vec![Type::Integer],
+                stack: Vec::new(),
+            }),
+            failure.frame
+        );
+        assert!(report
+            .to_string()
+            .contains("failed at instruction 2 (iconst_1)"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_method_handlers() -> Result<()> {
+        let (class_file, method) = class_file_with_method(
+            "()V",
+            0,
+            vec![
+                Instruction::Aconst_null,
+                Instruction::Athrow,
+                Instruction::Pop,
+                Instruction::Return,
+            ],
+            vec![ExceptionTableEntry {
+                range_pc: 0..2,
+                handler_pc: 2,
+                catch_type: 0,
+            }],
+        )?;
+        let report = verify_method(
+            &class_file,
+            &method,
+            &HashMap::<String, ResolvedClass>::new(),
+        )?;
+
+        assert!(report.is_verified());
+        assert_eq!(2, report.blocks.len());
+        assert!(report.blocks[0].successors.is_empty());
+        assert_eq!(vec![1], report.blocks[0].handlers);
+        assert_eq!(
+            Some(Frame {
+                locals: Vec::new(),
+                stack: vec![Type::Object("java/lang/Throwable".to_string())],
+            }),
+            report.blocks[1].frame
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_method_no_code() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let this_class = constant_pool.add_class("Foo")?;
+        let name_index = constant_pool.add_utf8("test")?;
+        let descriptor_index = constant_pool.add_utf8("()V")?;
+        let method = Method {
+            access_flags: MethodAccessFlags::ABSTRACT,
+            name_index,
+            descriptor_index,
+            attributes: Vec::new(),
+        };
+        let class_file = ClassFile {
+            constant_pool,
+            this_class,
+            ..Default::default()
+        };
+        let report = verify_method(
+            &class_file,
+            &method,
+            &HashMap::<String, ResolvedClass>::new(),
+        )?;
+        assert!(report.is_verified());
+        assert!(report.blocks.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,1178 @@
+use crate::attributes::{ArrayType, Attribute, ExceptionTableEntry, Instruction};
+use crate::base_type::BaseType;
+use crate::class_file::ClassFile;
+use crate::constant::Constant;
+use crate::constant_pool::ConstantPool;
+use crate::field_type::FieldType;
+use crate::method::Method;
+use crate::method_access_flags::MethodAccessFlags;
+use crate::method_descriptor::MethodDescriptor;
+use crate::verifiers::bytecode::class_resolver::{
+    common_super_class, ClassFileResolver, ClassResolver,
+};
+use crate::Error::VerificationError;
+use crate::{Error, Result};
+use std::fmt;
+
+const JAVA_LANG_OBJECT: &str = "java/lang/Object";
+const JAVA_LANG_THROWABLE: &str = "java/lang/Throwable";
+
+/// The type of a local variable or operand stack entry inferred by the verifier.
+///
+/// Reference types are tracked by class name (e.g. `java/lang/String` or `[I` for arrays) rather
+/// than by constant pool index so that types can be merged without a constant pool.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Type {
+    Top,
+    Integer,
+    Float,
+    Long,
+    Double,
+    Null,
+    UninitializedThis,
+    /// An object created by the `new` instruction at the given instruction index that has not
+    /// been initialized yet.
+    Uninitialized(u16),
+    Object(String),
+}
+
+impl Type {
+    /// Returns true if the type occupies two local variable slots or stack words.
+    #[must_use]
+    pub fn is_category2(&self) -> bool {
+        matches!(self, Type::Long | Type::Double)
+    }
+
+    /// Get the type used to represent values of the given field type.
+    #[must_use]
+    pub fn from_field_type(field_type: &FieldType) -> Type {
+        match field_type {
+            FieldType::Base(BaseType::Double) => Type::Double,
+            FieldType::Base(BaseType::Float) => Type::Float,
+            FieldType::Base(BaseType::Long) => Type::Long,
+            FieldType::Base(_) => Type::Integer,
+            FieldType::Object(class_name) => Type::Object(class_name.clone()),
+            FieldType::Array(_) => Type::Object(field_type.descriptor()),
+        }
+    }
+
+    /// Merge two types into the most specific type that both are assignable to.  Reference types
+    /// are merged to their common super class using the class hierarchy supplied by the resolver;
+    /// classes that cannot be resolved merge to `java/lang/Object`.  Incompatible types merge to
+    /// `Top`.
+    #[must_use]
+    pub fn merge(&self, other: &Type, resolver: &dyn ClassResolver) -> Type {
+        if self == other {
+            return self.clone();
+        }
+        match (self, other) {
+            (Type::Null, Type::Object(_)) => other.clone(),
+            (Type::Object(_), Type::Null) => self.clone(),
+            (Type::Object(left), Type::Object(right)) => {
+                Type::Object(merge_class_names(resolver, left, right))
+            }
+            _ => Type::Top,
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Top => write!(f, "top"),
+            Type::Integer => write!(f, "int"),
+            Type::Float => write!(f, "float"),
+            Type::Long => write!(f, "long"),
+            Type::Double => write!(f, "double"),
+            Type::Null => write!(f, "null"),
+            Type::UninitializedThis => write!(f, "uninitializedThis"),
+            Type::Uninitialized(index) => write!(f, "uninitialized({index})"),
+            Type::Object(class_name) => write!(f, "{class_name}"),
+        }
+    }
+}
+
+/// Merge two reference class names; arrays of references merge component wise.
+fn merge_class_names(resolver: &dyn ClassResolver, left: &str, right: &str) -> String {
+    if left == right {
+        return left.to_string();
+    }
+    match (array_component(left), array_component(right)) {
+        (Some(left), Some(right)) => {
+            let component = merge_class_names(resolver, &left, &right);
+            if component.starts_with('[') {
+                format!("[{component}")
+            } else {
+                format!("[L{component};")
+            }
+        }
+        (None, None) if !left.starts_with('[') && !right.starts_with('[') => {
+            common_super_class(resolver, left, right)
+        }
+        _ => JAVA_LANG_OBJECT.to_string(),
+    }
+}
+
+/// Get the class name of the components of a reference array; `None` if the class name is not
+/// an array or is an array of primitives.
+fn array_component(class_name: &str) -> Option<String> {
+    let component = class_name.strip_prefix('[')?;
+    if component.starts_with('[') {
+        Some(component.to_string())
+    } else {
+        component
+            .strip_prefix('L')
+            .and_then(|component| component.strip_suffix(';'))
+            .map(ToString::to_string)
+    }
+}
+
+/// The types of the local variables and operand stack before an instruction is executed.
+///
+/// Locals contain one entry per slot; `Long` and `Double` are followed by a `Top` entry for the
+/// second slot.  The stack contains one entry per value regardless of the value category.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Frame {
+    pub locals: Vec<Type>,
+    pub stack: Vec<Type>,
+}
+
+impl Frame {
+    /// Merge another frame into this frame; returns true if this frame changed.
+    fn merge(
+        &mut self,
+        other: &Frame,
+        resolver: &dyn ClassResolver,
+    ) -> std::result::Result<bool, String> {
+        if self.stack.len() != other.stack.len() {
+            return Err(format!(
+                "inconsistent stack height {} != {}",
+                self.stack.len(),
+                other.stack.len()
+            ));
+        }
+
+        let mut changed = false;
+        for (local, other_local) in self.locals.iter_mut().zip(&other.locals) {
+            let merged = local.merge(other_local, resolver);
+            if merged != *local {
+                *local = merged;
+                changed = true;
+            }
+        }
+        for (value, other_value) in self.stack.iter_mut().zip(&other.stack) {
+            let merged = value.merge(other_value, resolver);
+            if merged == Type::Top {
+                return Err(format!(
+                    "incompatible stack types {value} and {other_value}"
+                ));
+            }
+            if merged != *value {
+                *value = merged;
+                changed = true;
+            }
+        }
+        Ok(changed)
+    }
+}
+
+/// Infer the frame before each instruction of a method using abstract interpretation of the
+/// method's instructions, starting from the types declared by the method descriptor and
+/// following branches and exception handlers until a fixed point is reached.
+///
+/// The result contains one entry per instruction; `None` for unreachable instructions.  Methods
+/// without a `Code` attribute return no frames.  The resolver supplies the class hierarchy used to
+/// merge reference types at branch targets and exception handlers.
+///
+/// # Errors
+/// Returns an error if the method references invalid constants, the operand stack underflows or
+/// has inconsistent types at a merge point, or the method uses subroutines (`jsr`/`ret`).
+pub fn infer(
+    class_file: &ClassFile,
+    method: &Method,
+    resolver: &dyn ClassResolver,
+) -> Result<Vec<Option<Frame>>> {
+    let mut frames = Vec::new();
+    infer_frames(class_file, method, resolver, &mut frames).map_err(|(_index, error)| error)?;
+    Ok(frames)
+}
+
+/// Infer the frames of a method into `frames`.  If inference fails, `frames` contains the frames
+/// inferred before the failure and the error is returned with the index of the instruction that
+/// was being executed; `None` if the failure occurred before any instruction was executed.
+pub(crate) fn infer_frames(
+    class_file: &ClassFile,
+    method: &Method,
+    resolver: &dyn ClassResolver,
+    frames: &mut Vec<Option<Frame>>,
+) -> std::result::Result<(), (Option<usize>, Error)> {
+    frames.clear();
+    let Some((max_locals, code, exception_table)) = code(method) else {
+        return Ok(());
+    };
+
+    let initial_frame = || -> Result<(Inference<'_>, Frame)> {
+        let constant_pool = &class_file.constant_pool;
+        let class_name = class_file.class_name()?;
+        let method_name = constant_pool.try_get_utf8(method.name_index)?;
+        let method_descriptor = constant_pool.try_get_utf8(method.descriptor_index)?;
+        let inference = Inference {
+            constant_pool,
+            class_name,
+            resolver: ClassFileResolver::new(class_file, resolver)?,
+            context: format!("{class_name}.{method_name}{method_descriptor}"),
+            code,
+            exception_table,
+        };
+        let initial_frame = inference.initial_frame(
+            method.access_flags,
+            method_name,
+            method_descriptor,
+            max_locals,
+        )?;
+        Ok((inference, initial_frame))
+    };
+    let (inference, initial_frame) = initial_frame().map_err(|error| (None, error))?;
+    inference.run(initial_frame, frames)
+}
+
+/// Get the `max_locals`, instructions and exception table of a method; `None` if the method does
+/// not have a `Code` attribute.
+pub(crate) fn code(method: &Method) -> Option<(u16, &Vec<Instruction>, &Vec<ExceptionTableEntry>)> {
+    method
+        .attributes
+        .iter()
+        .find_map(|attribute| match attribute {
+            Attribute::Code {
+                max_locals,
+                code,
+                exception_table,
+                ..
+            } => Some((*max_locals, code, exception_table)),
+            _ => None,
+        })
+}
+
+/// Get the indexes of the instructions that may execute after the instruction at `index`,
+/// excluding exception handlers.
+///
+/// # Errors
+/// Returns an error message if the instruction is a subroutine instruction (`jsr`/`ret`) or
+/// branches outside of the code.
+pub(crate) fn successors(
+    code: &[Instruction],
+    index: usize,
+) -> std::result::Result<Vec<usize>, String> {
+    let relative_target = |offset: i32| -> std::result::Result<usize, String> {
+        let target = i64::try_from(index).map_err(|error| error.to_string())? + i64::from(offset);
+        usize::try_from(target).map_err(|_| format!("invalid switch target {target}"))
+    };
+    let Some(instruction) = code.get(index) else {
+        return Err(format!("invalid instruction index {index}"));
+    };
+    let successors = match instruction {
+        Instruction::Ifeq(target)
+        | Instruction::Ifne(target)
+        | Instruction::Iflt(target)
+        | Instruction::Ifge(target)
+        | Instruction::Ifgt(target)
+        | Instruction::Ifle(target)
+        | Instruction::Ifnull(target)
+        | Instruction::Ifnonnull(target)
+        | Instruction::If_icmpeq(target)
+        | Instruction::If_icmpne(target)
+        | Instruction::If_icmplt(target)
+        | Instruction::If_icmpge(target)
+        | Instruction::If_icmpgt(target)
+        | Instruction::If_icmple(target)
+        | Instruction::If_acmpeq(target)
+        | Instruction::If_acmpne(target) => vec![index + 1, usize::from(*target)],
+        Instruction::Goto(target) => vec![usize::from(*target)],
+        Instruction::Goto_w(target) => {
+            let target =
+                usize::try_from(*target).map_err(|_| format!("invalid branch target {target}"))?;
+            vec![target]
+        }
+        Instruction::Jsr(_)
+        | Instruction::Jsr_w(_)
+        | Instruction::Ret(_)
+        | Instruction::Ret_w(_) => {
+            return Err(format!(
+                "subroutine instruction {instruction} is not supported"
+            ));
+        }
+        Instruction::Tableswitch {
+            default, offsets, ..
+        } => {
+            let mut successors = Vec::with_capacity(offsets.len() + 1);
+            successors.push(relative_target(*default)?);
+            for offset in offsets {
+                successors.push(relative_target(*offset)?);
+            }
+            successors
+        }
+        Instruction::Lookupswitch { default, pairs } => {
+            let mut successors = Vec::with_capacity(pairs.len() + 1);
+            successors.push(relative_target(*default)?);
+            for offset in pairs.values() {
+                successors.push(relative_target(*offset)?);
+            }
+            successors
+        }
+        Instruction::Ireturn
+        | Instruction::Lreturn
+        | Instruction::Freturn
+        | Instruction::Dreturn
+        | Instruction::Areturn
+        | Instruction::Athrow
+        | Instruction::Return => Vec::new(),
+        _ => vec![index + 1],
+    };
+
+    if let Some(successor) = successors
+        .iter()
+        .find(|successor| **successor >= code.len())
+    {
+        return Err(format!(
+            "instruction {index} branches to invalid instruction {successor}"
+        ));
+    }
+    Ok(successors)
+}
+
+struct Inference<'a> {
+    constant_pool: &'a ConstantPool,
+    class_name: &'a str,
+    resolver: ClassFileResolver<'a>,
+    context: String,
+    code: &'a [Instruction],
+    exception_table: &'a [ExceptionTableEntry],
+}
+
+impl Inference<'_> {
+    fn error<S: AsRef<str>>(&self, message: S) -> Error {
+        VerificationError {
+            context: self.context.clone(),
+            message: message.as_ref().to_string(),
+        }
+    }
+
+    /// Create the frame on method entry from the method descriptor.
+    fn initial_frame(
+        &self,
+        access_flags: MethodAccessFlags,
+        method_name: &str,
+        method_descriptor: &str,
+        max_locals: u16,
+    ) -> Result<Frame> {
+        let mut locals = Vec::new();
+        if !access_flags.contains(MethodAccessFlags::STATIC) {
+            if method_name == "<init>" && self.class_name != JAVA_LANG_OBJECT {
+                locals.push(Type::UninitializedThis);
+            } else {
+                locals.push(Type::Object(self.class_name.to_string()));
+            }
+        }
+
+        let method_descriptor = MethodDescriptor::parse(method_descriptor)?;
+        for parameter in &method_descriptor.parameters {
+            let parameter = Type::from_field_type(parameter);
+            let category2 = parameter.is_category2();
+            locals.push(parameter);
+            if category2 {
+                locals.push(Type::Top);
+            }
+        }
+
+        let max_locals = usize::from(max_locals);
+        if locals.len() > max_locals {
+            return Err(self.error(format!(
+                "parameters require {} locals; max_locals is {max_locals}",
+                locals.len()
+            )));
+        }
+        locals.resize(max_locals, Type::Top);
+        Ok(Frame {
+            locals,
+            stack: Vec::new(),
+        })
+    }
+
+    /// Propagate frames through the instructions until no frame changes.
+    fn run(
+        &self,
+        initial_frame: Frame,
+        frames: &mut Vec<Option<Frame>>,
+    ) -> std::result::Result<(), (Option<usize>, Error)> {
+        *frames = vec![None; self.code.len()];
+        if self.code.is_empty() {
+            return Ok(());
+        }
+
+        let mut pending = vec![0];
+        frames[0] = Some(initial_frame);
+        while let Some(index) = pending.pop() {
+            self.step(frames, &mut pending, index)
+                .map_err(|error| (Some(index), error))?;
+        }
+        Ok(())
+    }
+
+    /// Execute the instruction at `index` and propagate the resulting frame to the successors and
+    /// exception handlers of the instruction.
+    fn step(
+        &self,
+        frames: &mut [Option<Frame>],
+        pending: &mut Vec<usize>,
+        index: usize,
+    ) -> Result<()> {
+        let Some(frame) = frames[index].clone() else {
+            return Ok(());
+        };
+        let next_frame = self.execute(index, &frame)?;
+        let successors = successors(self.code, index).map_err(|message| self.error(message))?;
+
+        for entry in self.exception_table {
+            let start = usize::from(entry.range_pc.start);
+            let end = usize::from(entry.range_pc.end);
+            if index < start || index >= end {
+                continue;
+            }
+            let exception = if entry.catch_type == 0 {
+                JAVA_LANG_THROWABLE.to_string()
+            } else {
+                self.constant_pool.try_get_class(entry.catch_type)?.clone()
+            };
+            let handler = usize::from(entry.handler_pc);
+            // The exception may be thrown before or after the instruction updates the
+            // locals, so the handler must accept both.
+            for locals in [&frame.locals, &next_frame.locals] {
+                let handler_frame = Frame {
+                    locals: locals.clone(),
+                    stack: vec![Type::Object(exception.clone())],
+                };
+                self.propagate(frames, pending, handler, &handler_frame)?;
+            }
+        }
+
+        for successor in successors {
+            self.propagate(frames, pending, successor, &next_frame)?;
+        }
+        Ok(())
+    }
+
+    fn propagate(
+        &self,
+        frames: &mut [Option<Frame>],
+        pending: &mut Vec<usize>,
+        index: usize,
+        frame: &Frame,
+    ) -> Result<()> {
+        let Some(target) = frames.get_mut(index) else {
+            return Err(self.error(format!("invalid instruction index {index}")));
+        };
+        if let Some(target) = target {
+            let changed = target
+                .merge(frame, &self.resolver)
+                .map_err(|message| self.error(format!("instruction {index}: {message}")))?;
+            if changed && !pending.contains(&index) {
+                pending.push(index);
+            }
+        } else {
+            *target = Some(frame.clone());
+            pending.push(index);
+        }
+        Ok(())
+    }
+
+    /// Execute the instruction at `index` with the given frame; returns the frame after the
+    /// instruction.
+    #[expect(clippy::too_many_lines)]
+    fn execute(&self, index: usize, frame: &Frame) -> Result<Frame> {
+        let mut frame = frame.clone();
+        let instruction = &self.code[index];
+        match instruction {
+            Instruction::Nop
+            | Instruction::Iinc(..)
+            | Instruction::Iinc_w(..)
+            | Instruction::Goto(_)
+            | Instruction::Goto_w(_)
+            | Instruction::Return => {}
+            Instruction::Aconst_null => frame.stack.push(Type::Null),
+            Instruction::Ldc(constant_index) => {
+                let value = self.constant_type(u16::from(*constant_index))?;
+                frame.stack.push(value);
+            }
+            Instruction::Ldc_w(constant_index) | Instruction::Ldc2_w(constant_index) => {
+                let value = self.constant_type(*constant_index)?;
+                frame.stack.push(value);
+            }
+            Instruction::Iconst_m1
+            | Instruction::Iconst_0
+            | Instruction::Iconst_1
+            | Instruction::Iconst_2
+            | Instruction::Iconst_3
+            | Instruction::Iconst_4
+            | Instruction::Iconst_5
+            | Instruction::Bipush(_)
+            | Instruction::Sipush(_)
+            | Instruction::Iload(_)
+            | Instruction::Iload_w(_)
+            | Instruction::Iload_0
+            | Instruction::Iload_1
+            | Instruction::Iload_2
+            | Instruction::Iload_3 => frame.stack.push(Type::Integer),
+            Instruction::Lconst_0
+            | Instruction::Lconst_1
+            | Instruction::Lload(_)
+            | Instruction::Lload_w(_)
+            | Instruction::Lload_0
+            | Instruction::Lload_1
+            | Instruction::Lload_2
+            | Instruction::Lload_3 => frame.stack.push(Type::Long),
+            Instruction::Fconst_0
+            | Instruction::Fconst_1
+            | Instruction::Fconst_2
+            | Instruction::Fload(_)
+            | Instruction::Fload_w(_)
+            | Instruction::Fload_0
+            | Instruction::Fload_1
+            | Instruction::Fload_2
+            | Instruction::Fload_3 => frame.stack.push(Type::Float),
+            Instruction::Dconst_0
+            | Instruction::Dconst_1
+            | Instruction::Dload(_)
+            | Instruction::Dload_w(_)
+            | Instruction::Dload_0
+            | Instruction::Dload_1
+            | Instruction::Dload_2
+            | Instruction::Dload_3 => frame.stack.push(Type::Double),
+            Instruction::Aload(local) => self.load(&mut frame, usize::from(*local))?,
+            Instruction::Aload_w(local) => self.load(&mut frame, usize::from(*local))?,
+            Instruction::Aload_0 => self.load(&mut frame, 0)?,
+            Instruction::Aload_1 => self.load(&mut frame, 1)?,
+            Instruction::Aload_2 => self.load(&mut frame, 2)?,
+            Instruction::Aload_3 => self.load(&mut frame, 3)?,
+            Instruction::Aaload => {
+                self.pop(&mut frame)?;
+                let array = self.pop(&mut frame)?;
+                let component = match array {
+                    Type::Object(class_name) => match array_component(&class_name) {
+                        Some(component) => Type::Object(component),
+                        None => Type::Object(JAVA_LANG_OBJECT.to_string()),
+                    },
+                    Type::Null => Type::Null,
+                    value => return Err(self.error(format!("aaload on {value}"))),
+                };
+                frame.stack.push(component);
+            }
+            Instruction::Istore(local)
+            | Instruction::Lstore(local)
+            | Instruction::Fstore(local)
+            | Instruction::Dstore(local)
+            | Instruction::Astore(local) => self.store(&mut frame, usize::from(*local))?,
+            Instruction::Istore_w(local)
+            | Instruction::Lstore_w(local)
+            | Instruction::Fstore_w(local)
+            | Instruction::Dstore_w(local)
+            | Instruction::Astore_w(local) => self.store(&mut frame, usize::from(*local))?,
+            Instruction::Istore_0
+            | Instruction::Lstore_0
+            | Instruction::Fstore_0
+            | Instruction::Dstore_0
+            | Instruction::Astore_0 => self.store(&mut frame, 0)?,
+            Instruction::Istore_1
+            | Instruction::Lstore_1
+            | Instruction::Fstore_1
+            | Instruction::Dstore_1
+            | Instruction::Astore_1 => self.store(&mut frame, 1)?,
+            Instruction::Istore_2
+            | Instruction::Lstore_2
+            | Instruction::Fstore_2
+            | Instruction::Dstore_2
+            | Instruction::Astore_2 => self.store(&mut frame, 2)?,
+            Instruction::Istore_3
+            | Instruction::Lstore_3
+            | Instruction::Fstore_3
+            | Instruction::Dstore_3
+            | Instruction::Astore_3 => self.store(&mut frame, 3)?,
+            Instruction::Iastore
+            | Instruction::Lastore
+            | Instruction::Fastore
+            | Instruction::Dastore
+            | Instruction::Aastore
+            | Instruction::Bastore
+            | Instruction::Castore
+            | Instruction::Sastore => self.pop_values(&mut frame, 3)?,
+            Instruction::Pop => {
+                self.pop_words(&mut frame, 1)?;
+            }
+            Instruction::Pop2 => {
+                self.pop_words(&mut frame, 2)?;
+            }
+            Instruction::Dup => self.duplicate(&mut frame, 1, 0)?,
+            Instruction::Dup_x1 => self.duplicate(&mut frame, 1, 1)?,
+            Instruction::Dup_x2 => self.duplicate(&mut frame, 1, 2)?,
+            Instruction::Dup2 => self.duplicate(&mut frame, 2, 0)?,
+            Instruction::Dup2_x1 => self.duplicate(&mut frame, 2, 1)?,
+            Instruction::Dup2_x2 => self.duplicate(&mut frame, 2, 2)?,
+            Instruction::Swap => {
+                let first = self.pop_words(&mut frame, 1)?;
+                let second = self.pop_words(&mut frame, 1)?;
+                frame.stack.extend(first);
+                frame.stack.extend(second);
+            }
+            Instruction::Iaload
+            | Instruction::Baload
+            | Instruction::Caload
+            | Instruction::Saload
+            | Instruction::Iadd
+            | Instruction::Isub
+            | Instruction::Imul
+            | Instruction::Idiv
+            | Instruction::Irem
+            | Instruction::Ishl
+            | Instruction::Ishr
+            | Instruction::Iushr
+            | Instruction::Iand
+            | Instruction::Ior
+            | Instruction::Ixor
+            | Instruction::Lcmp
+            | Instruction::Fcmpl
+            | Instruction::Fcmpg
+            | Instruction::Dcmpl
+            | Instruction::Dcmpg => {
+                self.pop_values(&mut frame, 2)?;
+                frame.stack.push(Type::Integer);
+            }
+            Instruction::Laload
+            | Instruction::Ladd
+            | Instruction::Lsub
+            | Instruction::Lmul
+            | Instruction::Ldiv
+            | Instruction::Lrem
+            | Instruction::Lshl
+            | Instruction::Lshr
+            | Instruction::Lushr
+            | Instruction::Land
+            | Instruction::Lor
+            | Instruction::Lxor => {
+                self.pop_values(&mut frame, 2)?;
+                frame.stack.push(Type::Long);
+            }
+            Instruction::Faload
+            | Instruction::Fadd
+            | Instruction::Fsub
+            | Instruction::Fmul
+            | Instruction::Fdiv
+            | Instruction::Frem => {
+                self.pop_values(&mut frame, 2)?;
+                frame.stack.push(Type::Float);
+            }
+            Instruction::Daload
+            | Instruction::Dadd
+            | Instruction::Dsub
+            | Instruction::Dmul
+            | Instruction::Ddiv
+            | Instruction::Drem => {
+                self.pop_values(&mut frame, 2)?;
+                frame.stack.push(Type::Double);
+            }
+            Instruction::Ineg
+            | Instruction::L2i
+            | Instruction::F2i
+            | Instruction::D2i
+            | Instruction::I2b
+            | Instruction::I2c
+            | Instruction::I2s
+            | Instruction::Arraylength
+            | Instruction::Instanceof(_) => {
+                self.pop(&mut frame)?;
+                frame.stack.push(Type::Integer);
+            }
+            Instruction::Lneg | Instruction::I2l | Instruction::F2l | Instruction::D2l => {
+                self.pop(&mut frame)?;
+                frame.stack.push(Type::Long);
+            }
+            Instruction::Fneg | Instruction::I2f | Instruction::L2f | Instruction::D2f => {
+                self.pop(&mut frame)?;
+                frame.stack.push(Type::Float);
+            }
+            Instruction::Dneg | Instruction::I2d | Instruction::L2d | Instruction::F2d => {
+                self.pop(&mut frame)?;
+                frame.stack.push(Type::Double);
+            }
+            Instruction::Ifeq(_)
+            | Instruction::Ifne(_)
+            | Instruction::Iflt(_)
+            | Instruction::Ifge(_)
+            | Instruction::Ifgt(_)
+            | Instruction::Ifle(_)
+            | Instruction::Ifnull(_)
+            | Instruction::Ifnonnull(_)
+            | Instruction::Tableswitch { .. }
+            | Instruction::Lookupswitch { .. }
+            | Instruction::Ireturn
+            | Instruction::Lreturn
+            | Instruction::Freturn
+            | Instruction::Dreturn
+            | Instruction::Areturn
+            | Instruction::Athrow
+            | Instruction::Putstatic(_)
+            | Instruction::Monitorenter
+            | Instruction::Monitorexit => {
+                self.pop(&mut frame)?;
+            }
+            Instruction::If_icmpeq(_)
+            | Instruction::If_icmpne(_)
+            | Instruction::If_icmplt(_)
+            | Instruction::If_icmpge(_)
+            | Instruction::If_icmpgt(_)
+            | Instruction::If_icmple(_)
+            | Instruction::If_acmpeq(_)
+            | Instruction::If_acmpne(_)
+            | Instruction::Putfield(_) => self.pop_values(&mut frame, 2)?,
+            Instruction::Jsr(_)
+            | Instruction::Jsr_w(_)
+            | Instruction::Ret(_)
+            | Instruction::Ret_w(_) => {
+                return Err(self.error(format!(
+                    "subroutine instruction {instruction} is not supported"
+                )));
+            }
+            Instruction::Getstatic(field_index) => {
+                let field_type = self.field_type(*field_index)?;
+                frame.stack.push(field_type);
+            }
+            Instruction::Getfield(field_index) => {
+                self.pop(&mut frame)?;
+                let field_type = self.field_type(*field_index)?;
+                frame.stack.push(field_type);
+            }
+            Instruction::Invokevirtual(method_index)
+            | Instruction::Invokeinterface(method_index, _) => {
+                let (_name, descriptor) = self.method_name_and_type(*method_index)?;
+                self.invoke(&mut frame, descriptor, true)?;
+            }
+            Instruction::Invokestatic(method_index) => {
+                let (_name, descriptor) = self.method_name_and_type(*method_index)?;
+                self.invoke(&mut frame, descriptor, false)?;
+            }
+            Instruction::Invokespecial(method_index) => {
+                let (name, descriptor) = self.method_name_and_type(*method_index)?;
+                if name == "<init>" {
+                    self.invoke_initializer(&mut frame, descriptor)?;
+                } else {
+                    self.invoke(&mut frame, descriptor, true)?;
+                }
+            }
+            Instruction::Invokedynamic(invoke_dynamic_index) => {
+                let name_and_type_index = match self.constant_pool.try_get(*invoke_dynamic_index)? {
+                    Constant::InvokeDynamic {
+                        name_and_type_index,
+                        ..
+                    } => *name_and_type_index,
+                    _ => {
+                        return Err(self.error(format!(
+                            "invalid invokedynamic constant #{invoke_dynamic_index}"
+                        )))
+                    }
+                };
+                let (_name, descriptor) = self.name_and_type(name_and_type_index)?;
+                self.invoke(&mut frame, descriptor, false)?;
+            }
+            Instruction::New(_) => {
+                let index = u16::try_from(index)?;
+                frame.stack.push(Type::Uninitialized(index));
+            }
+            Instruction::Newarray(array_type) => {
+                self.pop(&mut frame)?;
+                let class_name = match array_type {
+                    ArrayType::Boolean => "[Z",
+                    ArrayType::Char => "[C",
+                    ArrayType::Float => "[F",
+                    ArrayType::Double => "[D",
+                    ArrayType::Byte => "[B",
+                    ArrayType::Short => "[S",
+                    ArrayType::Int => "[I",
+                    ArrayType::Long => "[J",
+                };
+                frame.stack.push(Type::Object(class_name.to_string()));
+            }
+            Instruction::Anewarray(class_index) => {
+                self.pop(&mut frame)?;
+                let class_name = self.constant_pool.try_get_class(*class_index)?;
+                let array_class_name = if class_name.starts_with('[') {
+                    format!("[{class_name}")
+                } else {
+                    format!("[L{class_name};")
+                };
+                frame.stack.push(Type::Object(array_class_name));
+            }
+            Instruction::Checkcast(class_index) => {
+                self.pop(&mut frame)?;
+                let class_name = self.constant_pool.try_get_class(*class_index)?;
+                frame.stack.push(Type::Object(class_name.clone()));
+            }
+            Instruction::Multianewarray(class_index, dimensions) => {
+                self.pop_values(&mut frame, usize::from(*dimensions))?;
+                let class_name = self.constant_pool.try_get_class(*class_index)?;
+                frame.stack.push(Type::Object(class_name.clone()));
+            }
+            Instruction::Wide
+            | Instruction::Breakpoint
+            | Instruction::Impdep1
+            | Instruction::Impdep2 => {
+                return Err(self.error(format!("unexpected instruction {instruction}")));
+            }
+        }
+
+        Ok(frame)
+    }
+
+    fn pop(&self, frame: &mut Frame) -> Result<Type> {
+        frame
+            .stack
+            .pop()
+            .ok_or_else(|| self.error("operand stack underflow"))
+    }
+
+    fn pop_values(&self, frame: &mut Frame, count: usize) -> Result<()> {
+        for _ in 0..count {
+            self.pop(frame)?;
+        }
+        Ok(())
+    }
+
+    /// Pop values totalling the given number of stack words without splitting a category 2
+    /// value; the values are returned in stack order.
+    fn pop_words(&self, frame: &mut Frame, words: usize) -> Result<Vec<Type>> {
+        let mut values = Vec::new();
+        let mut popped = 0;
+        while popped < words {
+            let value = self.pop(frame)?;
+            popped += if value.is_category2() { 2 } else { 1 };
+            values.insert(0, value);
+        }
+        if popped != words {
+            return Err(self.error("operation splits a category 2 value"));
+        }
+        Ok(values)
+    }
+
+    /// Duplicate the top `words` stack words and insert them `below` words down the stack.
+    fn duplicate(&self, frame: &mut Frame, words: usize, below: usize) -> Result<()> {
+        let values = self.pop_words(frame, words)?;
+        let skipped = self.pop_words(frame, below)?;
+        frame.stack.extend(values.iter().cloned());
+        frame.stack.extend(skipped);
+        frame.stack.extend(values);
+        Ok(())
+    }
+
+    fn load(&self, frame: &mut Frame, local: usize) -> Result<()> {
+        let value = frame
+            .locals
+            .get(local)
+            .cloned()
+            .ok_or_else(|| self.error(format!("invalid local variable {local}")))?;
+        frame.stack.push(value);
+        Ok(())
+    }
+
+    fn store(&self, frame: &mut Frame, local: usize) -> Result<()> {
+        let value = self.pop(frame)?;
+        let slots = if value.is_category2() { 2 } else { 1 };
+        if local + slots > frame.locals.len() {
+            return Err(self.error(format!("invalid local variable {local}")));
+        }
+        // Overwriting the second slot of a category 2 value invalidates the value
+        if local > 0 && frame.locals[local - 1].is_category2() {
+            frame.locals[local - 1] = Type::Top;
+        }
+        frame.locals[local] = value;
+        if slots == 2 {
+            frame.locals[local + 1] = Type::Top;
+        }
+        Ok(())
+    }
+
+    fn invoke(&self, frame: &mut Frame, descriptor: &str, has_receiver: bool) -> Result<()> {
+        let descriptor = MethodDescriptor::parse(descriptor)?;
+        self.pop_values(frame, descriptor.arity())?;
+        if has_receiver {
+            self.pop(frame)?;
+        }
+        if let Some(return_type) = &descriptor.return_type {
+            frame.stack.push(Type::from_field_type(return_type));
+        }
+        Ok(())
+    }
+
+    /// Invoke an instance initialization method; every reference to the uninitialized receiver
+    /// in the frame becomes an instance of the initialized class.
+    fn invoke_initializer(&self, frame: &mut Frame, descriptor: &str) -> Result<()> {
+        let descriptor = MethodDescriptor::parse(descriptor)?;
+        self.pop_values(frame, descriptor.arity())?;
+        let receiver = self.pop(frame)?;
+        let initialized = match &receiver {
+            Type::UninitializedThis => Type::Object(self.class_name.to_string()),
+            Type::Uninitialized(new_index) => {
+                let Some(Instruction::New(class_index)) = self.code.get(usize::from(*new_index))
+                else {
+                    return Err(self.error(format!("invalid new instruction {new_index}")));
+                };
+                let class_name = self.constant_pool.try_get_class(*class_index)?;
+                Type::Object(class_name.clone())
+            }
+            value => return Err(self.error(format!("<init> invoked on {value}"))),
+        };
+        for value in frame.locals.iter_mut().chain(frame.stack.iter_mut()) {
+            if *value == receiver {
+                *value = initialized.clone();
+            }
+        }
+        Ok(())
+    }
+
+    fn constant_type(&self, constant_index: u16) -> Result<Type> {
+        let value = match self.constant_pool.try_get(constant_index)? {
+            Constant::Integer(_) => Type::Integer,
+            Constant::Float(_) => Type::Float,
+            Constant::Long(_) => Type::Long,
+            Constant::Double(_) => Type::Double,
+            Constant::String(_) => Type::Object("java/lang/String".to_string()),
+            Constant::Class(_) => Type::Object("java/lang/Class".to_string()),
+            Constant::MethodType(_) => Type::Object("java/lang/invoke/MethodType".to_string()),
+            Constant::MethodHandle { .. } => {
+                Type::Object("java/lang/invoke/MethodHandle".to_string())
+            }
+            Constant::Dynamic {
+                name_and_type_index,
+                ..
+            } => {
+                let (_name, descriptor) = self.name_and_type(*name_and_type_index)?;
+                let field_type = FieldType::parse(descriptor)?;
+                Type::from_field_type(&field_type)
+            }
+            _ => {
+                return Err(self.error(format!("invalid loadable constant #{constant_index}")));
+            }
+        };
+        Ok(value)
+    }
+
+    fn field_type(&self, field_index: u16) -> Result<Type> {
+        let (_class_index, name_and_type_index) =
+            self.constant_pool.try_get_field_ref(field_index)?;
+        let (_name, descriptor) = self.name_and_type(*name_and_type_index)?;
+        let field_type = FieldType::parse(descriptor)?;
+        Ok(Type::from_field_type(&field_type))
+    }
+
+    fn method_name_and_type(&self, method_index: u16) -> Result<(&String, &String)> {
+        match self.constant_pool.try_get(method_index)? {
+            Constant::MethodRef {
+                name_and_type_index,
+                ..
+            }
+            | Constant::InterfaceMethodRef {
+                name_and_type_index,
+                ..
+            } => self.name_and_type(*name_and_type_index),
+            _ => Err(self.error(format!("invalid method constant #{method_index}"))),
+        }
+    }
+
+    fn name_and_type(&self, name_and_type_index: u16) -> Result<(&String, &String)> {
+        let (name_index, descriptor_index) = self
+            .constant_pool
+            .try_get_name_and_type(name_and_type_index)?;
+        let name = self.constant_pool.try_get_utf8(*name_index)?;
+        let descriptor = self.constant_pool.try_get_utf8(*descriptor_index)?;
+        Ok((name, descriptor))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::class_access_flags::ClassAccessFlags;
+    use crate::verifiers::bytecode::class_resolver::ResolvedClass;
+    use std::collections::HashMap;
+    use std::io::Cursor;
+
+    /// Resolver without any classes; distinct reference types merge to `java/lang/Object`.
+    fn unresolved() -> HashMap<String, ResolvedClass> {
+        HashMap::new()
+    }
+
+    #[test]
+    fn test_is_category2() {
+        assert!(Type::Long.is_category2());
+        assert!(Type::Double.is_category2());
+        assert!(!Type::Integer.is_category2());
+        assert!(!Type::Object("java/lang/String".to_string()).is_category2());
+    }
+
+    #[test]
+    fn test_from_field_type() {
+        assert_eq!(
+            Type::Integer,
+            Type::from_field_type(&FieldType::Base(BaseType::Boolean))
+        );
+        assert_eq!(
+            Type::Long,
+            Type::from_field_type(&FieldType::Base(BaseType::Long))
+        );
+        assert_eq!(
+            Type::Object("java/lang/String".to_string()),
+            Type::from_field_type(&FieldType::Object("java/lang/String".to_string()))
+        );
+        assert_eq!(
+            Type::Object("[I".to_string()),
+            Type::from_field_type(&FieldType::Array(Box::new(FieldType::Base(BaseType::Int))))
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let resolver = unresolved();
+        let string = Type::Object("java/lang/String".to_string());
+        let integer = Type::Object("java/lang/Integer".to_string());
+        assert_eq!(
+            Type::Integer,
+            Type::Integer.merge(&Type::Integer, &resolver)
+        );
+        assert_eq!(Type::Top, Type::Integer.merge(&Type::Float, &resolver));
+        assert_eq!(Type::Top, Type::Integer.merge(&string, &resolver));
+        assert_eq!(string, Type::Null.merge(&string, &resolver));
+        assert_eq!(string, string.merge(&Type::Null, &resolver));
+        assert_eq!(
+            Type::Object(JAVA_LANG_OBJECT.to_string()),
+            string.merge(&integer, &resolver)
+        );
+    }
+
+    #[test]
+    fn test_merge_class_names() {
+        let resolver = unresolved();
+        assert_eq!("[I", merge_class_names(&resolver, "[I", "[I"));
+        assert_eq!(JAVA_LANG_OBJECT, merge_class_names(&resolver, "[I", "[J"));
+        assert_eq!(
+            "[Ljava/lang/Object;",
+            merge_class_names(&resolver, "[Ljava/lang/String;", "[Ljava/lang/Integer;")
+        );
+        assert_eq!(
+            "[[Ljava/lang/Object;",
+            merge_class_names(&resolver, "[[Ljava/lang/String;", "[[Ljava/lang/Integer;")
+        );
+        assert_eq!(
+            "[Ljava/lang/Object;",
+            merge_class_names(&resolver, "[[I", "[[J")
+        );
+    }
+
+    #[test]
+    fn test_merge_class_names_with_hierarchy() {
+        let number = ResolvedClass {
+            access_flags: ClassAccessFlags::PUBLIC | ClassAccessFlags::ABSTRACT,
+            super_class: Some(JAVA_LANG_OBJECT.to_string()),
+            interfaces: Vec::new(),
+        };
+        let integer = ResolvedClass {
+            access_flags: ClassAccessFlags::PUBLIC | ClassAccessFlags::FINAL,
+            super_class: Some("java/lang/Number".to_string()),
+            interfaces: Vec::new(),
+        };
+        let mut resolver = HashMap::new();
+        resolver.insert("java/lang/Number".to_string(), number);
+        resolver.insert("java/lang/Integer".to_string(), integer.clone());
+        resolver.insert("java/lang/Long".to_string(), integer);
+
+        assert_eq!(
+            "java/lang/Number",
+            merge_class_names(&resolver, "java/lang/Integer", "java/lang/Long")
+        );
+        assert_eq!(
+            "[Ljava/lang/Number;",
+            merge_class_names(&resolver, "[Ljava/lang/Integer;", "[Ljava/lang/Long;")
+        );
+        assert_eq!(
+            JAVA_LANG_OBJECT,
+            merge_class_names(&resolver, "java/lang/Integer", "[Ljava/lang/Long;")
+        );
+    }
+
+    #[test]
+    fn test_array_component() {
+        assert_eq!(None, array_component("java/lang/String"));
+        assert_eq!(None, array_component("[I"));
+        assert_eq!(Some("[I".to_string()), array_component("[[I"));
+        assert_eq!(
+            Some("java/lang/String".to_string()),
+            array_component("[Ljava/lang/String;")
+        );
+    }
+
+    #[test]
+    fn test_frame_merge() {
+        let resolver = unresolved();
+        let mut frame = Frame {
+            locals: vec![Type::Integer, Type::Object("java/lang/String".to_string())],
+            stack: vec![Type::Null],
+        };
+        let other = Frame {
+            locals: vec![Type::Integer, Type::Float],
+            stack: vec![Type::Object("java/lang/String".to_string())],
+        };
+        assert_eq!(Ok(true), frame.merge(&other, &resolver));
+        assert_eq!(vec![Type::Integer, Type::Top], frame.locals);
+        assert_eq!(
+            vec![Type::Object("java/lang/String".to_string())],
+            frame.stack
+        );
+        assert_eq!(Ok(false), frame.merge(&other, &resolver));
+    }
+
+    #[test]
+    fn test_frame_merge_stack_height_mismatch() {
+        let resolver = unresolved();
+        let mut frame = Frame {
+            locals: Vec::new(),
+            stack: vec![Type::Integer],
+        };
+        let other = Frame {
+            locals: Vec::new(),
+            stack: Vec::new(),
+        };
+        assert!(frame.merge(&other, &resolver).is_err());
+    }
+
+    #[test]
+    fn test_infer() -> Result<()> {
+        let class_bytes = include_bytes!("../../../../classes/Simple.class");
+        let class_file = ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))?;
+        let constructor = &class_file.methods[0];
+        let frames = infer(&class_file, constructor, &unresolved())?;
+
+        assert!(!frames.is_empty());
+        assert_eq!(
+            Some(Frame {
+                locals: vec![Type::UninitializedThis],
+                stack: Vec::new(),
+            }),
+            frames[0]
+        );
+        assert!(frames.iter().all(Option::is_some));
+        Ok(())
+    }
+
+    #[test]
+    fn test_infer_no_code() -> Result<()> {
+        let class_file = ClassFile::default();
+        let method = Method::default();
+        assert!(infer(&class_file, &method, &unresolved())?.is_empty());
+        Ok(())
+    }
+}
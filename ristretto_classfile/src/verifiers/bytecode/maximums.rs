@@ -0,0 +1,279 @@
+use crate::attributes::{Attribute, Instruction};
+use crate::class_file::ClassFile;
+use crate::field_type::FieldType;
+use crate::method::Method;
+use crate::method_access_flags::MethodAccessFlags;
+use crate::method_descriptor::MethodDescriptor;
+use crate::verifiers::bytecode::class_resolver::ResolvedClass;
+use crate::verifiers::bytecode::inference::{self, Frame};
+use crate::Result;
+use std::collections::HashMap;
+
+/// Compute the `max_stack` and `max_locals` of every method with a `Code` attribute.
+///
+/// `max_locals` covers the method parameters and every local variable accessed by the
+/// instructions.  `max_stack` is the deepest operand stack of the frames inferred for the method;
+/// the frames include the entry state of each exception handler, where the operand stack holds
+/// the thrown exception, so handlers that are only reachable through the exception table are
+/// accounted for.
+///
+/// # Errors
+/// Returns an error if the frames cannot be inferred for a method.
+pub fn update(class_file: &mut ClassFile) -> Result<()> {
+    for method_index in 0..class_file.methods.len() {
+        let method = &class_file.methods[method_index];
+        let Some((_max_locals, code, _exception_table)) = inference::code(method) else {
+            continue;
+        };
+        let descriptor = class_file
+            .constant_pool
+            .try_get_utf8(method.descriptor_index)?;
+        let max_locals = max_locals(method.access_flags, descriptor, code)?;
+
+        // Frames are inferred with the computed max_locals so that every accessed local variable
+        // is available
+        let mut method = method.clone();
+        set_maximums(&mut method, None, max_locals);
+        let frames = inference::infer(
+            class_file,
+            &method,
+            &HashMap::<String, ResolvedClass>::new(),
+        )?;
+        let max_stack = max_stack(&frames)?;
+
+        set_maximums(
+            &mut class_file.methods[method_index],
+            Some(max_stack),
+            max_locals,
+        );
+    }
+    Ok(())
+}
+
+fn set_maximums(method: &mut Method, stack: Option<u16>, locals: u16) {
+    for attribute in &mut method.attributes {
+        if let Attribute::Code {
+            max_stack,
+            max_locals,
+            ..
+        } = attribute
+        {
+            if let Some(stack) = stack {
+                *max_stack = stack;
+            }
+            *max_locals = locals;
+        }
+    }
+}
+
+/// Get the number of local variable slots used by the parameters and instructions of a method.
+fn max_locals(
+    access_flags: MethodAccessFlags,
+    descriptor: &str,
+    code: &[Instruction],
+) -> Result<u16> {
+    let descriptor = MethodDescriptor::parse(descriptor)?;
+    let mut max_locals = usize::from(!access_flags.contains(MethodAccessFlags::STATIC));
+    max_locals += descriptor
+        .parameters
+        .iter()
+        .map(FieldType::slot_size)
+        .sum::<usize>();
+    for instruction in code {
+        if let Some((local, slots)) = local_variable(instruction) {
+            max_locals = max_locals.max(local + slots);
+        }
+    }
+    Ok(u16::try_from(max_locals)?)
+}
+
+/// Get the operand stack words required by the deepest inferred frame.
+fn max_stack(frames: &[Option<Frame>]) -> Result<u16> {
+    let max_stack = frames
+        .iter()
+        .flatten()
+        .map(|frame| {
+            frame
+                .stack
+                .iter()
+                .map(|value| if value.is_category2() { 2 } else { 1 })
+                .sum::<usize>()
+        })
+        .max()
+        .unwrap_or_default();
+    Ok(u16::try_from(max_stack)?)
+}
+
+/// Get the local variable index and number of slots accessed by an instruction.
+fn local_variable(instruction: &Instruction) -> Option<(usize, usize)> {
+    let local = match instruction {
+        Instruction::Iload(local)
+        | Instruction::Fload(local)
+        | Instruction::Aload(local)
+        | Instruction::Istore(local)
+        | Instruction::Fstore(local)
+        | Instruction::Astore(local)
+        | Instruction::Iinc(local, _)
+        | Instruction::Ret(local) => (usize::from(*local), 1),
+        Instruction::Lload(local)
+        | Instruction::Dload(local)
+        | Instruction::Lstore(local)
+        | Instruction::Dstore(local) => (usize::from(*local), 2),
+        Instruction::Iload_w(local)
+        | Instruction::Fload_w(local)
+        | Instruction::Aload_w(local)
+        | Instruction::Istore_w(local)
+        | Instruction::Fstore_w(local)
+        | Instruction::Astore_w(local)
+        | Instruction::Iinc_w(local, _)
+        | Instruction::Ret_w(local) => (usize::from(*local), 1),
+        Instruction::Lload_w(local)
+        | Instruction::Dload_w(local)
+        | Instruction::Lstore_w(local)
+        | Instruction::Dstore_w(local) => (usize::from(*local), 2),
+        Instruction::Iload_0
+        | Instruction::Fload_0
+        | Instruction::Aload_0
+        | Instruction::Istore_0
+        | Instruction::Fstore_0
+        | Instruction::Astore_0 => (0, 1),
+        Instruction::Iload_1
+        | Instruction::Fload_1
+        | Instruction::Aload_1
+        | Instruction::Istore_1
+        | Instruction::Fstore_1
+        | Instruction::Astore_1 => (1, 1),
+        Instruction::Iload_2
+        | Instruction::Fload_2
+        | Instruction::Aload_2
+        | Instruction::Istore_2
+        | Instruction::Fstore_2
+        | Instruction::Astore_2 => (2, 1),
+        Instruction::Iload_3
+        | Instruction::Fload_3
+        | Instruction::Aload_3
+        | Instruction::Istore_3
+        | Instruction::Fstore_3
+        | Instruction::Astore_3 => (3, 1),
+        Instruction::Lload_0
+        | Instruction::Dload_0
+        | Instruction::Lstore_0
+        | Instruction::Dstore_0 => (0, 2),
+        Instruction::Lload_1
+        | Instruction::Dload_1
+        | Instruction::Lstore_1
+        | Instruction::Dstore_1 => (1, 2),
+        Instruction::Lload_2
+        | Instruction::Dload_2
+        | Instruction::Lstore_2
+        | Instruction::Dstore_2 => (2, 2),
+        Instruction::Lload_3
+        | Instruction::Dload_3
+        | Instruction::Lstore_3
+        | Instruction::Dstore_3 => (3, 2),
+        _ => return None,
+    };
+    Some(local)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::attributes::ExceptionTableEntry;
+    use crate::constant_pool::ConstantPool;
+    use std::io::Cursor;
+
+    fn maximums(class_file: &ClassFile, method_index: usize) -> Option<(u16, u16)> {
+        class_file.methods[method_index]
+            .attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::Code {
+                    max_stack,
+                    max_locals,
+                    ..
+                } => Some((*max_stack, *max_locals)),
+                _ => None,
+            })
+    }
+
+    #[test]
+    fn test_update() -> Result<()> {
+        let class_bytes = include_bytes!("../../../../classes/Expressions.class");
+        let expected = ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))?;
+        let mut class_file = expected.clone();
+        for method in &mut class_file.methods {
+            set_maximums(method, Some(0), 0);
+        }
+        update(&mut class_file)?;
+        for method_index in 0..expected.methods.len() {
+            assert_eq!(
+                maximums(&expected, method_index),
+                maximums(&class_file, method_index)
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_exception_handler() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let this_class = constant_pool.add_class("Foo")?;
+        let code_index = constant_pool.add_utf8("Code")?;
+        let name_index = constant_pool.add_utf8("test")?;
+        let descriptor_index = constant_pool.add_utf8("(J)V")?;
+        // The handler is only reachable through the exception table; the thrown exception is the
+        // deepest operand stack of the method.
+        let method = Method {
+            access_flags: MethodAccessFlags::STATIC,
+            name_index,
+            descriptor_index,
+            attributes: vec![Attribute::Code {
+                name_index: code_index,
+                max_stack: 0,
+                max_locals: 0,
+                code: vec![
+                    Instruction::Return,
+                    Instruction::Astore_3,
+                    Instruction::Return,
+                ],
+                exception_table: vec![ExceptionTableEntry {
+                    range_pc: 0..1,
+                    handler_pc: 1,
+                    catch_type: 0,
+                }],
+                attributes: Vec::new(),
+            }],
+        };
+        let mut class_file = ClassFile {
+            constant_pool,
+            this_class,
+            methods: vec![method],
+            ..Default::default()
+        };
+        update(&mut class_file)?;
+        assert_eq!(Some((1, 4)), maximums(&class_file, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_locals() -> Result<()> {
+        assert_eq!(
+            3,
+            max_locals(MethodAccessFlags::empty(), "(J)V", &[Instruction::Return])?
+        );
+        assert_eq!(
+            2,
+            max_locals(MethodAccessFlags::STATIC, "(J)V", &[Instruction::Return])?
+        );
+        assert_eq!(
+            7,
+            max_locals(
+                MethodAccessFlags::STATIC,
+                "()V",
+                &[Instruction::Dstore(5), Instruction::Return]
+            )?
+        );
+        Ok(())
+    }
+}
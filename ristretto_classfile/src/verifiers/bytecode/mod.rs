@@ -0,0 +1,5 @@
+pub mod class_resolver;
+pub mod diagnostics;
+pub mod inference;
+pub mod maximums;
+pub mod stack_map_table;
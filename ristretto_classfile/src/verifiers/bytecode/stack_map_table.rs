@@ -0,0 +1,434 @@
+use crate::attributes::{
+    Attribute, ExceptionTableEntry, Instruction, InstructionOffsets, StackFrame, VerificationType,
+};
+use crate::class_file::ClassFile;
+use crate::constant::Constant;
+use crate::constant_pool::ConstantPool;
+use crate::verifiers::bytecode::class_resolver::ClassResolver;
+use crate::verifiers::bytecode::inference::{self, Frame, Type};
+use crate::Error::VerificationError;
+use crate::Result;
+use std::collections::BTreeSet;
+
+/// Compute the `StackMapTable` attribute of every method with a `Code` attribute, replacing any
+/// existing `StackMapTable`.  Methods that do not require stack map frames have the attribute
+/// removed.  Reference types are merged using the class hierarchy supplied by the resolver.
+///
+/// # Errors
+/// Returns an error if the frames cannot be inferred for a method.
+pub fn update(class_file: &mut ClassFile, resolver: &dyn ClassResolver) -> Result<()> {
+    for method_index in 0..class_file.methods.len() {
+        let method = &class_file.methods[method_index];
+        let frames = inference::infer(class_file, method, resolver)?;
+        if frames.is_empty() {
+            continue;
+        }
+        let Some((code, exception_table)) =
+            method
+                .attributes
+                .iter()
+                .find_map(|attribute| match attribute {
+                    Attribute::Code {
+                        code,
+                        exception_table,
+                        ..
+                    } => Some((code.clone(), exception_table)),
+                    _ => None,
+                })
+        else {
+            continue;
+        };
+
+        let class_name = class_file.class_name()?;
+        let method_name = class_file.constant_pool.try_get_utf8(method.name_index)?;
+        let context = format!("{class_name}.{method_name}");
+        let initial_frame = frames[0].clone().unwrap_or(Frame {
+            locals: Vec::new(),
+            stack: Vec::new(),
+        });
+        let mut required_frames = Vec::new();
+        for index in frame_indexes(&code, exception_table) {
+            let Some(frame) = frames.get(index).cloned().flatten() else {
+                return Err(VerificationError {
+                    context,
+                    message: format!("unreachable code at instruction {index}"),
+                });
+            };
+            required_frames.push((index, frame));
+        }
+
+        let constant_pool = &mut class_file.constant_pool;
+        let stack_frames = stack_frames(constant_pool, &code, &initial_frame, &required_frames)?;
+        let name_index = if stack_frames.is_empty() {
+            0
+        } else {
+            utf8_index(constant_pool, "StackMapTable")?
+        };
+
+        let method = &mut class_file.methods[method_index];
+        for attribute in &mut method.attributes {
+            let Attribute::Code { attributes, .. } = attribute else {
+                continue;
+            };
+            attributes.retain(|attribute| !matches!(attribute, Attribute::StackMapTable { .. }));
+            if !stack_frames.is_empty() {
+                attributes.push(Attribute::StackMapTable {
+                    name_index,
+                    frames: stack_frames.clone(),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Get the indexes of the instructions that require a stack map frame: branch targets,
+/// exception handlers and instructions that follow an unconditional control transfer.
+pub(crate) fn frame_indexes(
+    code: &[Instruction],
+    exception_table: &[ExceptionTableEntry],
+) -> BTreeSet<usize> {
+    let mut indexes = BTreeSet::new();
+    for (index, instruction) in code.iter().enumerate() {
+        let unconditional = match instruction {
+            Instruction::Ifeq(target)
+            | Instruction::Ifne(target)
+            | Instruction::Iflt(target)
+            | Instruction::Ifge(target)
+            | Instruction::Ifgt(target)
+            | Instruction::Ifle(target)
+            | Instruction::If_icmpeq(target)
+            | Instruction::If_icmpne(target)
+            | Instruction::If_icmplt(target)
+            | Instruction::If_icmpge(target)
+            | Instruction::If_icmpgt(target)
+            | Instruction::If_icmple(target)
+            | Instruction::If_acmpeq(target)
+            | Instruction::If_acmpne(target)
+            | Instruction::Ifnull(target)
+            | Instruction::Ifnonnull(target) => {
+                indexes.insert(usize::from(*target));
+                false
+            }
+            Instruction::Goto(target) => {
+                indexes.insert(usize::from(*target));
+                true
+            }
+            Instruction::Goto_w(target) => {
+                if let Ok(target) = usize::try_from(*target) {
+                    indexes.insert(target);
+                }
+                true
+            }
+            Instruction::Tableswitch {
+                default, offsets, ..
+            } => {
+                for offset in offsets.iter().chain([default]) {
+                    if let Some(target) = relative_target(index, *offset) {
+                        indexes.insert(target);
+                    }
+                }
+                true
+            }
+            Instruction::Lookupswitch { default, pairs } => {
+                for offset in pairs.values().chain([default]) {
+                    if let Some(target) = relative_target(index, *offset) {
+                        indexes.insert(target);
+                    }
+                }
+                true
+            }
+            Instruction::Ireturn
+            | Instruction::Lreturn
+            | Instruction::Freturn
+            | Instruction::Dreturn
+            | Instruction::Areturn
+            | Instruction::Return
+            | Instruction::Athrow => true,
+            _ => false,
+        };
+        if unconditional && index + 1 < code.len() {
+            indexes.insert(index + 1);
+        }
+    }
+
+    for entry in exception_table {
+        indexes.insert(usize::from(entry.handler_pc));
+    }
+    indexes
+}
+
+fn relative_target(index: usize, offset: i32) -> Option<usize> {
+    let offset = isize::try_from(offset).ok()?;
+    index.checked_add_signed(offset)
+}
+
+/// Encode the frames as stack map frames, each relative to the previous frame, using the most
+/// compact frame type.  Offsets are instruction indexes; the compact frame types are only used
+/// when the byte offset delta also fits the frame type so that the frames can be serialized.
+fn stack_frames(
+    constant_pool: &mut ConstantPool,
+    code: &[Instruction],
+    initial_frame: &Frame,
+    frames: &[(usize, Frame)],
+) -> Result<Vec<StackFrame>> {
+    let mut encoder = Encoder {
+        constant_pool,
+        offsets: InstructionOffsets::new(code)?,
+    };
+    let mut previous_locals = compact_locals(&initial_frame.locals);
+    let mut previous_index: Option<u16> = None;
+    let mut stack_frames = Vec::with_capacity(frames.len());
+    for (index, frame) in frames {
+        let index = u16::try_from(*index)?;
+        let locals = compact_locals(&frame.locals);
+        let stack = &frame.stack;
+        let (offset_delta, byte_offset_delta) = match previous_index {
+            None => (index, encoder.byte_offset(index)?),
+            Some(previous_index) => (
+                index - previous_index - 1,
+                encoder.byte_offset(index)? - encoder.byte_offset(previous_index)? - 1,
+            ),
+        };
+
+        let stack_frame = if locals == previous_locals && stack.is_empty() {
+            if byte_offset_delta <= 63 {
+                StackFrame::SameFrame {
+                    frame_type: u8::try_from(offset_delta)?,
+                }
+            } else {
+                StackFrame::SameFrameExtended {
+                    frame_type: 251,
+                    offset_delta,
+                }
+            }
+        } else if locals == previous_locals && stack.len() == 1 {
+            if byte_offset_delta <= 63 {
+                StackFrame::SameLocals1StackItemFrame {
+                    frame_type: u8::try_from(offset_delta + 64)?,
+                    stack: encoder.verification_types(stack)?,
+                }
+            } else {
+                StackFrame::SameLocals1StackItemFrameExtended {
+                    frame_type: 247,
+                    offset_delta,
+                    stack: encoder.verification_types(stack)?,
+                }
+            }
+        } else if stack.is_empty()
+            && locals.len() < previous_locals.len()
+            && previous_locals.len() - locals.len() <= 3
+            && previous_locals.starts_with(&locals)
+        {
+            let chopped = u8::try_from(previous_locals.len() - locals.len())?;
+            StackFrame::ChopFrame {
+                frame_type: 251 - chopped,
+                offset_delta,
+            }
+        } else if stack.is_empty()
+            && locals.len() > previous_locals.len()
+            && locals.len() - previous_locals.len() <= 3
+            && locals.starts_with(&previous_locals)
+        {
+            let appended = encoder.verification_types(&locals[previous_locals.len()..])?;
+            StackFrame::AppendFrame {
+                frame_type: 251 + u8::try_from(appended.len())?,
+                offset_delta,
+                locals: appended,
+            }
+        } else {
+            StackFrame::FullFrame {
+                frame_type: 255,
+                offset_delta,
+                locals: encoder.verification_types(&locals)?,
+                stack: encoder.verification_types(stack)?,
+            }
+        };
+        stack_frames.push(stack_frame);
+        previous_locals = locals;
+        previous_index = Some(index);
+    }
+    Ok(stack_frames)
+}
+
+/// Get the locals as encoded in a stack map frame; the second slot of category 2 values is
+/// implicit and trailing `Top` values are omitted.
+fn compact_locals(locals: &[Type]) -> Vec<Type> {
+    let mut compact_locals = Vec::with_capacity(locals.len());
+    let mut index = 0;
+    while index < locals.len() {
+        let local = &locals[index];
+        compact_locals.push(local.clone());
+        index += if local.is_category2() { 2 } else { 1 };
+    }
+    while compact_locals.last() == Some(&Type::Top) {
+        compact_locals.pop();
+    }
+    compact_locals
+}
+
+/// Encodes inferred types as verification types, adding class constants as needed.
+struct Encoder<'a> {
+    constant_pool: &'a mut ConstantPool,
+    offsets: InstructionOffsets,
+}
+
+impl Encoder<'_> {
+    fn byte_offset(&self, index: u16) -> Result<u16> {
+        self.offsets.byte_offset(usize::from(index))
+    }
+
+    fn verification_types(&mut self, values: &[Type]) -> Result<Vec<VerificationType>> {
+        values
+            .iter()
+            .map(|value| self.verification_type(value))
+            .collect()
+    }
+
+    fn verification_type(&mut self, value: &Type) -> Result<VerificationType> {
+        let verification_type = match value {
+            Type::Top => VerificationType::Top,
+            Type::Integer => VerificationType::Integer,
+            Type::Float => VerificationType::Float,
+            Type::Long => VerificationType::Long,
+            Type::Double => VerificationType::Double,
+            Type::Null => VerificationType::Null,
+            Type::UninitializedThis => VerificationType::UninitializedThis,
+            Type::Uninitialized(index) => VerificationType::Uninitialized {
+                offset: self.byte_offset(*index)?,
+            },
+            Type::Object(class_name) => VerificationType::Object {
+                cpool_index: class_index(self.constant_pool, class_name)?,
+            },
+        };
+        Ok(verification_type)
+    }
+}
+
+/// Get the index of the class constant with the given name, adding it if it does not exist.
+pub(crate) fn class_index(constant_pool: &mut ConstantPool, class_name: &str) -> Result<u16> {
+    for index in 1..=u16::try_from(constant_pool.len())? {
+        if let Some(Constant::Class(name_index)) = constant_pool.get(index) {
+            if constant_pool.try_get_utf8(*name_index)? == class_name {
+                return Ok(index);
+            }
+        }
+    }
+    constant_pool.add_class(class_name)
+}
+
+/// Get the index of the utf8 constant with the given value, adding it if it does not exist.
+fn utf8_index(constant_pool: &mut ConstantPool, value: &str) -> Result<u16> {
+    for index in 1..=u16::try_from(constant_pool.len())? {
+        if let Some(Constant::Utf8(utf8)) = constant_pool.get(index) {
+            if utf8 == value {
+                return Ok(index);
+            }
+        }
+    }
+    constant_pool.add_utf8(value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_frame_indexes() {
+        let code = vec![
+            Instruction::Iload_0,
+            Instruction::Ifeq(4),
+            Instruction::Iconst_1,
+            Instruction::Ireturn,
+            Instruction::Iconst_0,
+            Instruction::Ireturn,
+        ];
+        let indexes = frame_indexes(&code, &[]);
+        assert_eq!(BTreeSet::from([4]), indexes);
+    }
+
+    #[test]
+    fn test_frame_indexes_switch_and_handlers() {
+        let code = vec![
+            Instruction::Iload_0,
+            Instruction::Lookupswitch {
+                default: 3,
+                pairs: [(1, 2)].into_iter().collect(),
+            },
+            Instruction::Return,
+            Instruction::Return,
+            Instruction::Return,
+        ];
+        let exception_table = vec![ExceptionTableEntry {
+            range_pc: 0..2,
+            handler_pc: 4,
+            catch_type: 0,
+        }];
+        let indexes = frame_indexes(&code, &exception_table);
+        assert_eq!(BTreeSet::from([2, 3, 4]), indexes);
+    }
+
+    #[test]
+    fn test_stack_frames() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let code = vec![
+            Instruction::Iload_0,
+            Instruction::Ifeq(4),
+            Instruction::Aconst_null,
+            Instruction::Goto(5),
+            Instruction::Ldc(1),
+            Instruction::Areturn,
+        ];
+        let initial_frame = Frame {
+            locals: vec![Type::Integer],
+            stack: Vec::new(),
+        };
+        let string = Type::Object("java/lang/String".to_string());
+        let frames = vec![
+            (4, initial_frame.clone()),
+            (
+                5,
+                Frame {
+                    locals: vec![Type::Integer],
+                    stack: vec![string],
+                },
+            ),
+        ];
+        let stack_frames = stack_frames(&mut constant_pool, &code, &initial_frame, &frames)?;
+        let class_index = class_index(&mut constant_pool, "java/lang/String")?;
+
+        assert_eq!(
+            vec![
+                StackFrame::SameFrame { frame_type: 4 },
+                StackFrame::SameLocals1StackItemFrame {
+                    frame_type: 64,
+                    stack: vec![VerificationType::Object {
+                        cpool_index: class_index
+                    }],
+                },
+            ],
+            stack_frames
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_class_index() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let index = constant_pool.add_class("Foo")?;
+        assert_eq!(index, class_index(&mut constant_pool, "Foo")?);
+        let length = constant_pool.len();
+        let bar_index = class_index(&mut constant_pool, "Bar")?;
+        assert_ne!(index, bar_index);
+        assert!(constant_pool.len() > length);
+        Ok(())
+    }
+
+    #[test]
+    fn test_utf8_index() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let index = constant_pool.add_utf8("StackMapTable")?;
+        assert_eq!(index, utf8_index(&mut constant_pool, "StackMapTable")?);
+        Ok(())
+    }
+}
@@ -21,7 +21,7 @@ pub fn verify(class_file: &ClassFile) -> Result<()> {
 
 /// Verify the `ClassFile` `ConstantPool` for version specific constants.
 fn verify_version_constants(class_file: &ClassFile) -> Result<()> {
-    for constant in &class_file.constant_pool {
+    for (_, constant) in &class_file.constant_pool {
         if !constant.valid_for_version(&class_file.version) {
             let tag = constant.tag();
             return Err(InvalidVersionConstant(tag));
@@ -35,7 +35,7 @@ fn verify_version_constants(class_file: &ClassFile) -> Result<()> {
 #[expect(clippy::too_many_lines)]
 fn verify_constant_indexes(class_file: &ClassFile) -> Result<()> {
     let constant_pool = &class_file.constant_pool;
-    for (index, constant) in constant_pool.iter().enumerate() {
+    for (index, (_, constant)) in constant_pool.iter().enumerate() {
         let index = u16::try_from(index)?;
         match constant {
             Constant::Class(name_index)
@@ -0,0 +1,397 @@
+use crate::attributes::{Attribute, NestedClassAccessFlags};
+use crate::class_file::ClassFile;
+use crate::constant::Constant;
+use crate::version::Version;
+use crate::Error::VerificationError;
+use crate::Result;
+use std::collections::HashMap;
+
+const VERSION_49_0: Version = Version::Java5_0 { minor: 0 };
+
+/// An entry of an `InnerClasses` attribute with the constant pool indexes resolved.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct InnerClassEntry<'a> {
+    pub(crate) class_name: &'a String,
+    pub(crate) outer_class_name: Option<&'a String>,
+    pub(crate) simple_name: Option<&'a String>,
+    pub(crate) access_flags: NestedClassAccessFlags,
+}
+
+/// Get the entries of the `InnerClasses` attributes of a class.
+pub(crate) fn inner_classes(class_file: &ClassFile) -> Result<Vec<InnerClassEntry<'_>>> {
+    let constant_pool = &class_file.constant_pool;
+    let mut entries = Vec::new();
+    for attribute in &class_file.attributes {
+        let Attribute::InnerClasses { classes, .. } = attribute else {
+            continue;
+        };
+        for inner_class in classes {
+            let outer_class_name = match inner_class.outer_class_info_index {
+                0 => None,
+                index => Some(constant_pool.try_get_class(index)?),
+            };
+            let simple_name = match inner_class.name_index {
+                0 => None,
+                index => Some(constant_pool.try_get_utf8(index)?),
+            };
+            entries.push(InnerClassEntry {
+                class_name: constant_pool.try_get_class(inner_class.class_info_index)?,
+                outer_class_name,
+                simple_name,
+                access_flags: inner_class.access_flags,
+            });
+        }
+    }
+    Ok(entries)
+}
+
+/// Get the entry that a class declares for itself in its `InnerClasses` attribute; only nested
+/// classes declare such an entry.
+fn own_entry(class_file: &ClassFile) -> Result<Option<InnerClassEntry<'_>>> {
+    let class_name = class_file.class_name()?;
+    let entry = inner_classes(class_file)?
+        .into_iter()
+        .find(|entry| entry.class_name == class_name);
+    Ok(entry)
+}
+
+/// Verify that the `InnerClasses` and `EnclosingMethod` attributes of the class files are
+/// consistent with each other.  An entry for a nested class must match the entry that the nested
+/// class declares for itself, the outer class of a member class must declare an entry for the
+/// member, and a class must declare an entry for every nested class that it references.  Local and
+/// anonymous classes must declare an `EnclosingMethod` attribute, and other classes must not.
+/// Classes that are not one of the class files cannot be checked and are assumed to be valid.
+pub(crate) fn verify(class_files: &[&ClassFile]) -> Result<()> {
+    let mut classes = HashMap::new();
+    for class_file in class_files {
+        classes.insert(class_file.class_name()?.as_str(), *class_file);
+    }
+
+    for class_file in class_files {
+        let class_name = class_file.class_name()?;
+        let inner_classes_attributes = class_file
+            .attributes
+            .iter()
+            .filter(|attribute| matches!(attribute, Attribute::InnerClasses { .. }))
+            .count();
+        if inner_classes_attributes > 1 {
+            return Err(error(
+                class_name,
+                "more than one InnerClasses attribute is declared".to_string(),
+            ));
+        }
+
+        let entries = inner_classes(class_file)?;
+        for (index, entry) in entries.iter().enumerate() {
+            let name = entry.class_name;
+            if entries[..index]
+                .iter()
+                .any(|previous| previous.class_name == name)
+            {
+                return Err(error(
+                    class_name,
+                    format!("more than one InnerClasses entry is declared for {name}"),
+                ));
+            }
+            if name == class_name {
+                continue;
+            }
+            let Some(inner) = classes.get(name.as_str()) else {
+                continue;
+            };
+            match own_entry(inner)? {
+                Some(inner_entry) if inner_entry == *entry => {}
+                Some(_) => {
+                    return Err(error(
+                        class_name,
+                        format!("InnerClasses entry for {name} does not match the entry of {name}"),
+                    ));
+                }
+                None => {
+                    return Err(error(
+                        class_name,
+                        format!("InnerClasses entry for {name} is not declared by {name}"),
+                    ));
+                }
+            }
+        }
+
+        let class_entry = entries.iter().find(|entry| entry.class_name == class_name);
+        if let Some(outer_name) = class_entry.and_then(|entry| entry.outer_class_name) {
+            if let Some(outer) = classes.get(outer_name.as_str()) {
+                if !inner_classes(outer)?
+                    .iter()
+                    .any(|entry| entry.class_name == class_name)
+                {
+                    return Err(error(
+                        class_name,
+                        format!(
+                            "outer class {outer_name} does not declare an InnerClasses entry for \
+                             {class_name}"
+                        ),
+                    ));
+                }
+            }
+        }
+
+        verify_enclosing_method(class_file, class_entry)?;
+
+        for (_, constant) in &class_file.constant_pool {
+            let Constant::Class(name_index) = constant else {
+                continue;
+            };
+            let name = class_file.constant_pool.try_get_utf8(*name_index)?;
+            let Some(referenced) = classes.get(name.as_str()) else {
+                continue;
+            };
+            if own_entry(referenced)?.is_some()
+                && !entries.iter().any(|entry| entry.class_name == name)
+            {
+                return Err(error(
+                    class_name,
+                    format!("referenced nested class {name} does not have an InnerClasses entry"),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Verify that a class declares an `EnclosingMethod` attribute if and only if it is a local or
+/// anonymous class.
+fn verify_enclosing_method(
+    class_file: &ClassFile,
+    class_entry: Option<&InnerClassEntry<'_>>,
+) -> Result<()> {
+    if class_file.version < VERSION_49_0 {
+        return Ok(());
+    }
+    let is_local = class_entry.is_some_and(|entry| entry.outer_class_name.is_none());
+    let has_enclosing_method = class_file
+        .attributes
+        .iter()
+        .any(|attribute| matches!(attribute, Attribute::EnclosingMethod { .. }));
+    if is_local && !has_enclosing_method {
+        return Err(error(
+            class_file.class_name()?,
+            "local or anonymous class does not declare an EnclosingMethod attribute".to_string(),
+        ));
+    }
+    if !is_local && has_enclosing_method {
+        return Err(error(
+            class_file.class_name()?,
+            "EnclosingMethod attribute is declared by a class that is not a local or \
+             anonymous class"
+                .to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn error(class_name: &str, message: String) -> crate::Error {
+    VerificationError {
+        context: class_name.to_string(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::attributes::InnerClass;
+
+    fn add_entry(
+        class_file: &mut ClassFile,
+        name: &str,
+        outer_name: Option<&str>,
+        simple_name: Option<&str>,
+        access_flags: NestedClassAccessFlags,
+    ) -> Result<()> {
+        let constant_pool = &mut class_file.constant_pool;
+        let inner_class = InnerClass {
+            class_info_index: constant_pool.add_class(name)?,
+            outer_class_info_index: match outer_name {
+                Some(outer_name) => constant_pool.add_class(outer_name)?,
+                None => 0,
+            },
+            name_index: match simple_name {
+                Some(simple_name) => constant_pool.add_utf8(simple_name)?,
+                None => 0,
+            },
+            access_flags,
+        };
+        for attribute in &mut class_file.attributes {
+            if let Attribute::InnerClasses { classes, .. } = attribute {
+                classes.push(inner_class);
+                return Ok(());
+            }
+        }
+        class_file.attributes.push(Attribute::InnerClasses {
+            name_index: constant_pool.add_utf8("InnerClasses")?,
+            classes: vec![inner_class],
+        });
+        Ok(())
+    }
+
+    fn member_classes() -> Result<(ClassFile, ClassFile)> {
+        let mut outer = crate::test::class_file("Outer", Version::Java8 { minor: 0 })?;
+        let mut inner = crate::test::class_file("Outer$Inner", Version::Java8 { minor: 0 })?;
+        for class_file in [&mut outer, &mut inner] {
+            add_entry(
+                class_file,
+                "Outer$Inner",
+                Some("Outer"),
+                Some("Inner"),
+                NestedClassAccessFlags::STATIC,
+            )?;
+        }
+        Ok((outer, inner))
+    }
+
+    fn message(result: Result<()>) -> String {
+        match result {
+            Err(VerificationError { message, .. }) => message,
+            result => format!("{result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_inner_classes() -> Result<()> {
+        let (outer, _) = member_classes()?;
+        let entries = inner_classes(&outer)?;
+        assert_eq!(1, entries.len());
+        assert_eq!("Outer$Inner", entries[0].class_name);
+        assert_eq!(Some(&"Outer".to_string()), entries[0].outer_class_name);
+        assert_eq!(Some(&"Inner".to_string()), entries[0].simple_name);
+        assert_eq!(NestedClassAccessFlags::STATIC, entries[0].access_flags);
+        assert_eq!(None, own_entry(&outer)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify() -> Result<()> {
+        let (outer, inner) = member_classes()?;
+        verify(&[&outer, &inner])
+    }
+
+    #[test]
+    fn test_verify_mismatched_entry() -> Result<()> {
+        let mut outer = crate::test::class_file("Outer", Version::Java8 { minor: 0 })?;
+        let mut inner = crate::test::class_file("Outer$Inner", Version::Java8 { minor: 0 })?;
+        add_entry(
+            &mut outer,
+            "Outer$Inner",
+            Some("Outer"),
+            Some("Inner"),
+            NestedClassAccessFlags::PRIVATE,
+        )?;
+        add_entry(
+            &mut inner,
+            "Outer$Inner",
+            Some("Outer"),
+            Some("Inner"),
+            NestedClassAccessFlags::empty(),
+        )?;
+        assert_eq!(
+            "InnerClasses entry for Outer$Inner does not match the entry of Outer$Inner",
+            message(verify(&[&outer, &inner]))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_entry_not_declared_by_inner_class() -> Result<()> {
+        let (outer, mut inner) = member_classes()?;
+        inner.attributes.clear();
+        assert_eq!(
+            "InnerClasses entry for Outer$Inner is not declared by Outer$Inner",
+            message(verify(&[&outer, &inner]))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_member_not_declared_by_outer_class() -> Result<()> {
+        let (mut outer, inner) = member_classes()?;
+        outer.attributes.clear();
+        assert_eq!(
+            "outer class Outer does not declare an InnerClasses entry for Outer$Inner",
+            message(verify(&[&inner, &outer]))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_duplicate_entry() -> Result<()> {
+        let (mut outer, inner) = member_classes()?;
+        add_entry(
+            &mut outer,
+            "Outer$Inner",
+            Some("Outer"),
+            Some("Inner"),
+            NestedClassAccessFlags::STATIC,
+        )?;
+        assert_eq!(
+            "more than one InnerClasses entry is declared for Outer$Inner",
+            message(verify(&[&outer, &inner]))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_referenced_class_without_entry() -> Result<()> {
+        let (_, inner) = member_classes()?;
+        let mut other = crate::test::class_file("Other", Version::Java8 { minor: 0 })?;
+        other.constant_pool.add_class("Outer$Inner")?;
+        assert_eq!(
+            "referenced nested class Outer$Inner does not have an InnerClasses entry",
+            message(verify(&[&inner, &other]))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_enclosing_method() -> Result<()> {
+        let mut local = crate::test::class_file("Outer$1", Version::Java8 { minor: 0 })?;
+        add_entry(
+            &mut local,
+            "Outer$1",
+            None,
+            None,
+            NestedClassAccessFlags::empty(),
+        )?;
+        assert_eq!(
+            "local or anonymous class does not declare an EnclosingMethod attribute",
+            message(verify(&[&local]))
+        );
+
+        let class_index = local.constant_pool.add_class("Outer")?;
+        local.attributes.push(Attribute::EnclosingMethod {
+            name_index: local.constant_pool.add_utf8("EnclosingMethod")?,
+            class_index,
+            method_index: 0,
+        });
+        verify(&[&local])?;
+
+        local.version = Version::Java1_4 { minor: 0 };
+        local.attributes.truncate(1);
+        verify(&[&local])
+    }
+
+    #[test]
+    fn test_verify_enclosing_method_of_member_class() -> Result<()> {
+        let (outer, mut inner) = member_classes()?;
+        let class_index = inner.constant_pool.add_class("Outer")?;
+        inner.attributes.push(Attribute::EnclosingMethod {
+            name_index: inner.constant_pool.add_utf8("EnclosingMethod")?,
+            class_index,
+            method_index: 0,
+        });
+        assert_eq!(
+            "EnclosingMethod attribute is declared by a class that is not a local or anonymous \
+             class",
+            message(verify(&[&outer, &inner]))
+        );
+        Ok(())
+    }
+}
@@ -1,8 +1,12 @@
+pub mod bytecode;
 pub mod class_access_flags;
 pub mod constant_pool;
 pub mod field_access_flags;
 pub mod fields;
+pub mod inner_classes;
 pub mod interfaces;
 pub mod method_access_flags;
 pub mod methods;
+pub mod nestmates;
+pub mod profile;
 pub mod verifier;
@@ -0,0 +1,304 @@
+use crate::attributes::Attribute;
+use crate::class_file::ClassFile;
+use crate::constant::Constant;
+use crate::field_access_flags::FieldAccessFlags;
+use crate::method_access_flags::MethodAccessFlags;
+use crate::version::Version;
+use crate::Error::VerificationError;
+use crate::Result;
+use std::collections::HashMap;
+
+const VERSION_55_0: Version = Version::Java11 { minor: 0 };
+
+/// Get the name of the nest host of a class.  Classes without a `NestHost` attribute, and classes
+/// with a version that does not support nests, are the hosts of their own nest.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-5.html#jvms-5.4.4>
+pub(crate) fn nest_host(class_file: &ClassFile) -> Result<&String> {
+    if class_file.version >= VERSION_55_0 {
+        for attribute in &class_file.attributes {
+            if let Attribute::NestHost {
+                host_class_index, ..
+            } = attribute
+            {
+                return class_file.constant_pool.try_get_class(*host_class_index);
+            }
+        }
+    }
+    class_file.class_name()
+}
+
+/// Get the names of the classes declared in the `NestMembers` attribute of a class.
+pub(crate) fn nest_members(class_file: &ClassFile) -> Result<Vec<&String>> {
+    let mut members = Vec::new();
+    if class_file.version < VERSION_55_0 {
+        return Ok(members);
+    }
+    for attribute in &class_file.attributes {
+        if let Attribute::NestMembers { class_indexes, .. } = attribute {
+            for class_index in class_indexes {
+                members.push(class_file.constant_pool.try_get_class(*class_index)?);
+            }
+        }
+    }
+    Ok(members)
+}
+
+/// Verify the nests declared by the class files, and that private fields and methods of the class
+/// files are only referenced by their nestmates.  Hosts and members that are not one of the class
+/// files cannot be checked and are assumed to be valid.
+pub(crate) fn verify(class_files: &[&ClassFile]) -> Result<()> {
+    let mut classes = HashMap::new();
+    for class_file in class_files {
+        classes.insert(class_file.class_name()?.as_str(), *class_file);
+    }
+
+    for class_file in class_files {
+        let class_name = class_file.class_name()?;
+        let host_name = nest_host(class_file)?;
+        if let Some(host) = classes.get(host_name.as_str()) {
+            if host_name != class_name && !nest_members(host)?.contains(&class_name) {
+                return Err(error(
+                    class_name,
+                    format!("nest host {host_name} does not declare {class_name} as a member"),
+                ));
+            }
+        }
+        for member_name in nest_members(class_file)? {
+            if let Some(member) = classes.get(member_name.as_str()) {
+                if nest_host(member)? != class_name {
+                    return Err(error(
+                        class_name,
+                        format!(
+                            "nest member {member_name} does not declare {class_name} as its \
+                             host"
+                        ),
+                    ));
+                }
+            }
+        }
+        verify_private_access(class_file, host_name, &classes)?;
+    }
+    Ok(())
+}
+
+/// Verify that the private members referenced by a class are declared by a nestmate.
+fn verify_private_access(
+    class_file: &ClassFile,
+    host_name: &String,
+    classes: &HashMap<&str, &ClassFile>,
+) -> Result<()> {
+    let class_name = class_file.class_name()?;
+    let constant_pool = &class_file.constant_pool;
+    for index in 1..=u16::try_from(constant_pool.len())? {
+        let is_field = match constant_pool.get(index) {
+            Some(Constant::FieldRef { .. }) => true,
+            Some(Constant::MethodRef { .. } | Constant::InterfaceMethodRef { .. }) => false,
+            _ => continue,
+        };
+        let (member_class_name, name, descriptor) = constant_pool.try_get_member_ref(index)?;
+        if member_class_name == class_name {
+            continue;
+        }
+        let Some(member_class) = classes.get(member_class_name.as_str()) else {
+            continue;
+        };
+        if !is_private(member_class, is_field, name, descriptor)?
+            || nest_host(member_class)? == host_name
+        {
+            continue;
+        }
+        return Err(error(
+            class_name,
+            format!(
+                "cannot access private member {member_class_name}.{name}{descriptor} of a class \
+                 that is not a nestmate"
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns true if the class declares a private field or method with the name and descriptor.
+fn is_private(
+    class_file: &ClassFile,
+    is_field: bool,
+    name: &str,
+    descriptor: &str,
+) -> Result<bool> {
+    let constant_pool = &class_file.constant_pool;
+    if is_field {
+        for field in &class_file.fields {
+            if constant_pool.try_get_utf8(field.name_index)? == name
+                && constant_pool.try_get_utf8(field.descriptor_index)? == descriptor
+            {
+                return Ok(field.access_flags.contains(FieldAccessFlags::PRIVATE));
+            }
+        }
+    } else {
+        for method in &class_file.methods {
+            if constant_pool.try_get_utf8(method.name_index)? == name
+                && constant_pool.try_get_utf8(method.descriptor_index)? == descriptor
+            {
+                return Ok(method.access_flags.contains(MethodAccessFlags::PRIVATE));
+            }
+        }
+    }
+    Ok(false)
+}
+
+fn error(class_name: &str, message: String) -> crate::Error {
+    VerificationError {
+        context: class_name.to_string(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::attributes::{Attribute, Instruction};
+    use crate::field::Field;
+    use crate::field_type::FieldType;
+    use crate::method::Method;
+
+    fn add_private_field(class_file: &mut ClassFile, name: &str) -> Result<()> {
+        let constant_pool = &mut class_file.constant_pool;
+        class_file.fields.push(Field {
+            access_flags: FieldAccessFlags::PRIVATE,
+            name_index: constant_pool.add_utf8(name)?,
+            descriptor_index: constant_pool.add_utf8("I")?,
+            field_type: FieldType::parse(&"I".to_string())?,
+            attributes: Vec::new(),
+        });
+        Ok(())
+    }
+
+    fn add_field_access(class_file: &mut ClassFile, class_name: &str, name: &str) -> Result<()> {
+        let constant_pool = &mut class_file.constant_pool;
+        let class_index = constant_pool.add_class(class_name)?;
+        let field_index = constant_pool.add_field_ref(class_index, name, "I")?;
+        class_file.methods.push(Method {
+            access_flags: MethodAccessFlags::STATIC,
+            name_index: constant_pool.add_utf8("get")?,
+            descriptor_index: constant_pool.add_utf8("()I")?,
+            attributes: vec![Attribute::Code {
+                name_index: constant_pool.add_utf8("Code")?,
+                max_stack: 1,
+                max_locals: 0,
+                code: vec![Instruction::Getstatic(field_index), Instruction::Ireturn],
+                exception_table: Vec::new(),
+                attributes: Vec::new(),
+            }],
+        });
+        Ok(())
+    }
+
+    fn declare_nest(host: &mut ClassFile, member: &mut ClassFile) -> Result<()> {
+        let member_name = member.class_name()?.clone();
+        let host_name = host.class_name()?.clone();
+        host.attributes.push(Attribute::NestMembers {
+            name_index: host.constant_pool.add_utf8("NestMembers")?,
+            class_indexes: vec![host.constant_pool.add_class(member_name)?],
+        });
+        member.attributes.push(Attribute::NestHost {
+            name_index: member.constant_pool.add_utf8("NestHost")?,
+            host_class_index: member.constant_pool.add_class(host_name)?,
+        });
+        Ok(())
+    }
+
+    #[test]
+    fn test_nest_host_and_members() -> Result<()> {
+        let mut host = crate::test::class_file("Outer", VERSION_55_0)?;
+        let mut member = crate::test::class_file("Outer$Inner", VERSION_55_0)?;
+        assert_eq!("Outer", nest_host(&host)?);
+        assert_eq!("Outer$Inner", nest_host(&member)?);
+
+        declare_nest(&mut host, &mut member)?;
+        assert_eq!("Outer", nest_host(&host)?);
+        assert_eq!("Outer", nest_host(&member)?);
+        assert_eq!(vec!["Outer$Inner"], nest_members(&host)?);
+        assert!(nest_members(&member)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_nest_host_unsupported_version() -> Result<()> {
+        let mut host = crate::test::class_file("Outer", VERSION_55_0)?;
+        let mut member = crate::test::class_file("Outer$Inner", VERSION_55_0)?;
+        declare_nest(&mut host, &mut member)?;
+        host.version = Version::Java10 { minor: 0 };
+        member.version = Version::Java10 { minor: 0 };
+        assert_eq!("Outer$Inner", nest_host(&member)?);
+        assert!(nest_members(&host)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_private_access_between_nestmates() -> Result<()> {
+        let mut host = crate::test::class_file("Outer", VERSION_55_0)?;
+        let mut member = crate::test::class_file("Outer$Inner", VERSION_55_0)?;
+        add_private_field(&mut host, "secret")?;
+        add_field_access(&mut member, "Outer", "secret")?;
+        declare_nest(&mut host, &mut member)?;
+        verify(&[&host, &member])
+    }
+
+    #[test]
+    fn test_verify_private_access_without_nest() -> Result<()> {
+        let mut host = crate::test::class_file("Outer", VERSION_55_0)?;
+        let mut member = crate::test::class_file("Outer$Inner", VERSION_55_0)?;
+        add_private_field(&mut host, "secret")?;
+        add_field_access(&mut member, "Outer", "secret")?;
+        assert_eq!(
+            Err(VerificationError {
+                context: "Outer$Inner".to_string(),
+                message: "cannot access private member Outer.secretI of a class that is not a \
+                          nestmate"
+                    .to_string(),
+            }),
+            verify(&[&host, &member])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_private_access_unknown_class() -> Result<()> {
+        let mut member = crate::test::class_file("Outer$Inner", VERSION_55_0)?;
+        add_field_access(&mut member, "Outer", "secret")?;
+        verify(&[&member])
+    }
+
+    #[test]
+    fn test_verify_member_not_declared_by_host() -> Result<()> {
+        let mut host = crate::test::class_file("Outer", VERSION_55_0)?;
+        let mut member = crate::test::class_file("Outer$Inner", VERSION_55_0)?;
+        declare_nest(&mut host, &mut member)?;
+        host.attributes.clear();
+        assert_eq!(
+            Err(VerificationError {
+                context: "Outer$Inner".to_string(),
+                message: "nest host Outer does not declare Outer$Inner as a member".to_string(),
+            }),
+            verify(&[&host, &member])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_host_not_declared_by_member() -> Result<()> {
+        let mut host = crate::test::class_file("Outer", VERSION_55_0)?;
+        let mut member = crate::test::class_file("Outer$Inner", VERSION_55_0)?;
+        declare_nest(&mut host, &mut member)?;
+        member.attributes.clear();
+        assert_eq!(
+            Err(VerificationError {
+                context: "Outer".to_string(),
+                message: "nest member Outer$Inner does not declare Outer as its host".to_string(),
+            }),
+            verify(&[&host, &member])
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,371 @@
+use crate::attributes::{Attribute, Instruction};
+use crate::class_file::ClassFile;
+use crate::verifiers::bytecode::stack_map_table::frame_indexes;
+use crate::version::Version;
+use crate::Error::VerificationError;
+use crate::Result;
+
+const VERSION_50_0: Version = Version::Java6 { minor: 0 };
+const VERSION_51_0: Version = Version::Java7 { minor: 0 };
+
+/// Attributes that were preview features before they became part of the platform, and the first
+/// major version in which they may be used by class files that enable preview features.
+const PREVIEW_ATTRIBUTES: [(&str, u16); 2] = [("Record", 58), ("PermittedSubclasses", 59)];
+
+/// Version specific rules that a class file is verified against.
+///
+/// The profile version is the latest class file version supported by the target runtime; newer
+/// class files, and class files that enable the preview features of a different release, are
+/// rejected.  Attributes and instructions are checked against the version of the class file; e.g.
+/// `invokedynamic` requires Java 7 and subroutines (`jsr`/`ret`) are not allowed from Java 7.  In
+/// strict mode, methods of Java 6 and later class files that require stack map frames must have a
+/// `StackMapTable` attribute; without strict mode the JVM falls back to type inference for Java 6
+/// class files.
+///
+/// # Examples
+///
+/// ```rust
+/// use ristretto_classfile::{ClassFile, ConstantPool, Result, VerificationProfile, Version};
+///
+/// fn main() -> Result<()> {
+///     let mut constant_pool = ConstantPool::default();
+///     let this_class = constant_pool.add_class("Foo")?;
+///     let super_class = constant_pool.add_class("java/lang/Object")?;
+///     let class_file = ClassFile {
+///         version: Version::Java17 { minor: 0 },
+///         constant_pool,
+///         this_class,
+///         super_class,
+///         ..Default::default()
+///     };
+///
+///     class_file.verify_with_profile(&VerificationProfile::new(Version::Java21 { minor: 0 }))?;
+///     assert!(class_file
+///         .verify_with_profile(&VerificationProfile::new(Version::Java11 { minor: 0 }))
+///         .is_err());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct VerificationProfile {
+    version: Version,
+    strict: bool,
+}
+
+impl VerificationProfile {
+    /// Create a new profile for a target runtime that supports class files up to the version.
+    #[must_use]
+    pub fn new(version: Version) -> Self {
+        VerificationProfile {
+            version,
+            strict: false,
+        }
+    }
+
+    /// Set strict mode; defaults to `false`.
+    #[must_use]
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Get the latest class file version supported by the profile.
+    #[must_use]
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    /// Returns true if strict mode is enabled.
+    #[must_use]
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+}
+
+/// Verify the `ClassFile` against the version specific rules of the profile.
+pub(crate) fn verify(class_file: &ClassFile, profile: &VerificationProfile) -> Result<()> {
+    let class_name = class_file.class_name()?;
+    let error = |message: String| VerificationError {
+        context: class_name.clone(),
+        message,
+    };
+    let version = &class_file.version;
+    if version.major() > profile.version.major() {
+        return Err(error(format!(
+            "class file version {} is not supported by {}",
+            version.major(),
+            profile.version
+        )));
+    }
+    if version.is_preview() && version.major() != profile.version.major() {
+        return Err(error(format!(
+            "preview features of {version} are not supported by {}",
+            profile.version
+        )));
+    }
+
+    verify_attributes(version, &class_file.attributes).map_err(error)?;
+    for field in &class_file.fields {
+        verify_attributes(version, &field.attributes).map_err(error)?;
+    }
+    for method in &class_file.methods {
+        verify_attributes(version, &method.attributes).map_err(error)?;
+        let method_name = class_file.constant_pool.try_get_utf8(method.name_index)?;
+        for attribute in &method.attributes {
+            let Attribute::Code {
+                code,
+                exception_table,
+                attributes,
+                ..
+            } = attribute
+            else {
+                continue;
+            };
+            verify_attributes(version, attributes).map_err(error)?;
+            verify_instructions(version, code)
+                .map_err(|message| error(format!("method {method_name}: {message}")))?;
+            if profile.strict
+                && *version >= VERSION_50_0
+                && !frame_indexes(code, exception_table).is_empty()
+                && !attributes
+                    .iter()
+                    .any(|attribute| matches!(attribute, Attribute::StackMapTable { .. }))
+            {
+                return Err(error(format!(
+                    "method {method_name} requires a StackMapTable attribute"
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Verify that the attributes are supported by the class file version.
+fn verify_attributes(
+    version: &Version,
+    attributes: &[Attribute],
+) -> std::result::Result<(), String> {
+    for attribute in attributes {
+        if attribute.valid_for_version(version) {
+            continue;
+        }
+        let name = attribute.name();
+        let preview = PREVIEW_ATTRIBUTES
+            .iter()
+            .find(|(preview_name, _)| *preview_name == name);
+        match preview {
+            Some((_, major)) if version.major() >= *major => {
+                if !version.is_preview() {
+                    return Err(format!(
+                        "attribute {name} requires preview features to be enabled in {version}"
+                    ));
+                }
+            }
+            _ => return Err(format!("attribute {name} is not supported by {version}")),
+        }
+    }
+    Ok(())
+}
+
+/// Verify that the instructions are supported by the class file version.
+fn verify_instructions(version: &Version, code: &[Instruction]) -> std::result::Result<(), String> {
+    for instruction in code {
+        let unsupported = match instruction {
+            Instruction::Invokedynamic(_) if *version < VERSION_51_0 => "invokedynamic",
+            Instruction::Jsr(_) if *version >= VERSION_51_0 => "jsr",
+            Instruction::Jsr_w(_) if *version >= VERSION_51_0 => "jsr_w",
+            Instruction::Ret(_) | Instruction::Ret_w(_) if *version >= VERSION_51_0 => "ret",
+            _ => continue,
+        };
+        return Err(format!(
+            "instruction {unsupported} is not supported by {version}"
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::method::Method;
+    use crate::method_access_flags::MethodAccessFlags;
+    use crate::version::JAVA_PREVIEW_MINOR_VERSION;
+
+    fn add_method(class_file: &mut ClassFile, code: Vec<Instruction>) -> Result<()> {
+        let constant_pool = &mut class_file.constant_pool;
+        class_file.methods.push(Method {
+            access_flags: MethodAccessFlags::STATIC,
+            name_index: constant_pool.add_utf8("foo")?,
+            descriptor_index: constant_pool.add_utf8("()V")?,
+            attributes: vec![Attribute::Code {
+                name_index: constant_pool.add_utf8("Code")?,
+                max_stack: 1,
+                max_locals: 0,
+                code,
+                exception_table: Vec::new(),
+                attributes: Vec::new(),
+            }],
+        });
+        Ok(())
+    }
+
+    fn profile() -> VerificationProfile {
+        VerificationProfile::new(Version::Java21 { minor: 0 })
+    }
+
+    fn message(result: Result<()>) -> String {
+        match result {
+            Err(VerificationError { message, .. }) => message,
+            result => format!("{result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_profile() {
+        let profile = profile().strict(true);
+        assert_eq!(&Version::Java21 { minor: 0 }, profile.version());
+        assert!(profile.is_strict());
+        assert!(!VerificationProfile::new(Version::Java8 { minor: 0 }).is_strict());
+    }
+
+    #[test]
+    fn test_verify() -> Result<()> {
+        let class_file = crate::test::class_file("Foo", Version::Java17 { minor: 0 })?;
+        verify(&class_file, &profile())
+    }
+
+    #[test]
+    fn test_unsupported_version() -> Result<()> {
+        let class_file = crate::test::class_file("Foo", Version::Java21 { minor: 0 })?;
+        let profile = VerificationProfile::new(Version::Java17 { minor: 0 });
+        assert_eq!(
+            "class file version 65 is not supported by Java 17",
+            message(verify(&class_file, &profile))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_preview_version() -> Result<()> {
+        let class_file = crate::test::class_file(
+            "Foo",
+            Version::Java17 {
+                minor: JAVA_PREVIEW_MINOR_VERSION,
+            },
+        )?;
+        verify(
+            &class_file,
+            &VerificationProfile::new(Version::Java17 { minor: 0 }),
+        )?;
+        assert_eq!(
+            "preview features of Java 17 are not supported by Java 21",
+            message(verify(&class_file, &profile()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_attribute() -> Result<()> {
+        let mut class_file = crate::test::class_file("Foo", Version::Java8 { minor: 0 })?;
+        let name_index = class_file.constant_pool.add_utf8("NestMembers")?;
+        class_file.attributes.push(Attribute::NestMembers {
+            name_index,
+            class_indexes: Vec::new(),
+        });
+        assert_eq!(
+            "attribute NestMembers is not supported by Java 8",
+            message(verify(&class_file, &profile()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_preview_attribute() -> Result<()> {
+        let mut class_file = crate::test::class_file("Foo", Version::Java15 { minor: 0 })?;
+        let name_index = class_file.constant_pool.add_utf8("Record")?;
+        class_file.attributes.push(Attribute::Record {
+            name_index,
+            records: Vec::new(),
+        });
+        let profile = VerificationProfile::new(Version::Java15 { minor: 0 });
+        assert_eq!(
+            "attribute Record requires preview features to be enabled in Java 15",
+            message(verify(&class_file, &profile))
+        );
+
+        class_file.version = Version::Java15 {
+            minor: JAVA_PREVIEW_MINOR_VERSION,
+        };
+        verify(&class_file, &profile)?;
+
+        class_file.version = Version::Java13 {
+            minor: JAVA_PREVIEW_MINOR_VERSION,
+        };
+        let profile = VerificationProfile::new(Version::Java13 { minor: 0 });
+        assert_eq!(
+            "attribute Record is not supported by Java 13",
+            message(verify(&class_file, &profile))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_invokedynamic() -> Result<()> {
+        let mut class_file = crate::test::class_file("Foo", Version::Java6 { minor: 0 })?;
+        add_method(
+            &mut class_file,
+            vec![Instruction::Invokedynamic(1), Instruction::Return],
+        )?;
+        assert_eq!(
+            "method foo: instruction invokedynamic is not supported by Java 6",
+            message(verify(&class_file, &profile()))
+        );
+        class_file.version = Version::Java7 { minor: 0 };
+        verify(&class_file, &profile())
+    }
+
+    #[test]
+    fn test_subroutines() -> Result<()> {
+        let mut class_file = crate::test::class_file("Foo", Version::Java7 { minor: 0 })?;
+        add_method(&mut class_file, vec![Instruction::Ret(0)])?;
+        assert_eq!(
+            "method foo: instruction ret is not supported by Java 7",
+            message(verify(&class_file, &profile()))
+        );
+        class_file.version = Version::Java6 { minor: 0 };
+        verify(&class_file, &profile())
+    }
+
+    #[test]
+    fn test_strict_stack_map_table() -> Result<()> {
+        let mut class_file = crate::test::class_file("Foo", Version::Java6 { minor: 0 })?;
+        add_method(
+            &mut class_file,
+            vec![
+                Instruction::Iconst_0,
+                Instruction::Ifeq(2),
+                Instruction::Return,
+            ],
+        )?;
+        verify(&class_file, &profile())?;
+        assert_eq!(
+            "method foo requires a StackMapTable attribute",
+            message(verify(&class_file, &profile().strict(true)))
+        );
+
+        class_file.compute_stack_map_tables()?;
+        verify(&class_file, &profile().strict(true))?;
+
+        class_file.version = Version::Java5_0 { minor: 0 };
+        class_file.methods[0].attributes = Vec::new();
+        add_method(
+            &mut class_file,
+            vec![
+                Instruction::Iconst_0,
+                Instruction::Ifeq(2),
+                Instruction::Return,
+            ],
+        )?;
+        verify(&class_file, &profile().strict(true))
+    }
+}
@@ -10,6 +10,7 @@ pub const JAVA_PREVIEW_MINOR_VERSION: u16 = 65535;
 ///
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.1>
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Version {
     Java1_0_2 { minor: u16 },
     Java1_1 { minor: u16 },
@@ -0,0 +1,392 @@
+use crate::attributes::{Attribute, Instruction};
+use crate::class_file::ClassFile;
+use crate::constant::Constant;
+use crate::constant_pool::ConstantPool;
+use crate::error::Result;
+use crate::field::Field;
+use crate::method::Method;
+use crate::Error::InvalidConstantPoolIndexType;
+
+/// Visitor for a [`ClassFile`], in the style of the ASM `ClassVisitor`.  The visitor is driven by
+/// [`ClassFile::accept`] which walks the class file in a single pass, in the order the methods
+/// are declared here, passing mutable references so that the visitor can rewrite what it visits.
+/// Every method has a default implementation that does nothing, so a visitor only implements
+/// the methods it is interested in.
+pub trait ClassVisitor {
+    /// Visit the class before the constants and members are visited; fields, methods and
+    /// attributes may be added or removed.
+    ///
+    /// # Errors
+    /// Returns an error if the class cannot be visited.
+    fn visit_class(&mut self, _class_file: &mut ClassFile) -> Result<()> {
+        Ok(())
+    }
+
+    /// Visit a constant in the constant pool.  A `Long` or `Double` constant occupies two indexes,
+    /// so it may only be replaced by another `Long` or `Double` constant.
+    ///
+    /// # Errors
+    /// Returns an error if the constant cannot be visited.
+    fn visit_constant(&mut self, _index: u16, _constant: &mut Constant) -> Result<()> {
+        Ok(())
+    }
+
+    /// Visit a field.
+    ///
+    /// # Errors
+    /// Returns an error if the field cannot be visited.
+    fn visit_field(&mut self, _constant_pool: &mut ConstantPool, _field: &mut Field) -> Result<()> {
+        Ok(())
+    }
+
+    /// Visit a method; returns a [`MethodVisitor`] to visit the code and attributes of the method,
+    /// or `None` to skip them.
+    ///
+    /// # Errors
+    /// Returns an error if the method cannot be visited.
+    fn visit_method(
+        &mut self,
+        _constant_pool: &mut ConstantPool,
+        _method: &mut Method,
+    ) -> Result<Option<Box<dyn MethodVisitor + '_>>> {
+        Ok(None)
+    }
+
+    /// Visit the attributes of the class; attributes may be added or removed.
+    ///
+    /// # Errors
+    /// Returns an error if the attributes cannot be visited.
+    fn visit_attributes(
+        &mut self,
+        _constant_pool: &mut ConstantPool,
+        _attributes: &mut Vec<Attribute>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Visit the class after the constants and members have been visited.
+    ///
+    /// # Errors
+    /// Returns an error if the class cannot be visited.
+    fn visit_end(&mut self, _class_file: &mut ClassFile) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Visitor for a [`Method`], in the style of the ASM `MethodVisitor`; created by
+/// [`ClassVisitor::visit_method`].
+pub trait MethodVisitor {
+    /// Visit an instruction of the `Code` attribute; `index` is the index of the instruction in
+    /// the code, which is the offset used by branch instructions, exception tables and code
+    /// attributes.  Replacing an instruction does not move the instructions around it.
+    ///
+    /// # Errors
+    /// Returns an error if the instruction cannot be visited.
+    fn visit_instruction(
+        &mut self,
+        _constant_pool: &mut ConstantPool,
+        _index: usize,
+        _instruction: &mut Instruction,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Visit the attributes of the `Code` attribute (e.g. `LineNumberTable` or `StackMapTable`);
+    /// attributes may be added or removed.
+    ///
+    /// # Errors
+    /// Returns an error if the attributes cannot be visited.
+    fn visit_code_attributes(
+        &mut self,
+        _constant_pool: &mut ConstantPool,
+        _attributes: &mut Vec<Attribute>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Visit the attributes of the method, including the `Code` attribute after its instructions
+    /// have been visited; attributes may be added or removed.
+    ///
+    /// # Errors
+    /// Returns an error if the attributes cannot be visited.
+    fn visit_attributes(
+        &mut self,
+        _constant_pool: &mut ConstantPool,
+        _attributes: &mut Vec<Attribute>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Visit the method after the code and attributes have been visited.
+    ///
+    /// # Errors
+    /// Returns an error if the method cannot be visited.
+    fn visit_end(&mut self, _constant_pool: &mut ConstantPool, _method: &mut Method) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Walk the class file with the visitor.
+///
+/// # Errors
+/// Returns an error if the visitor returns an error, or replaces a `Long` or `Double` constant
+/// with a constant that occupies a single index (or vice versa).
+pub(crate) fn accept<V>(class_file: &mut ClassFile, visitor: &mut V) -> Result<()>
+where
+    V: ClassVisitor + ?Sized,
+{
+    visitor.visit_class(class_file)?;
+
+    let constant_pool = &mut class_file.constant_pool;
+    for index in 1..=u16::try_from(constant_pool.len())? {
+        if let Some(constant) = constant_pool.get_mut(index) {
+            let is_wide = is_wide(constant);
+            visitor.visit_constant(index, constant)?;
+            if is_wide != self::is_wide(constant) {
+                return Err(InvalidConstantPoolIndexType(index));
+            }
+        }
+    }
+
+    for field in &mut class_file.fields {
+        visitor.visit_field(constant_pool, field)?;
+    }
+    for method in &mut class_file.methods {
+        if let Some(mut method_visitor) = visitor.visit_method(constant_pool, method)? {
+            accept_method(constant_pool, method, method_visitor.as_mut())?;
+        }
+    }
+    visitor.visit_attributes(constant_pool, &mut class_file.attributes)?;
+    visitor.visit_end(class_file)
+}
+
+/// Walk the method with the visitor.
+fn accept_method(
+    constant_pool: &mut ConstantPool,
+    method: &mut Method,
+    visitor: &mut dyn MethodVisitor,
+) -> Result<()> {
+    for attribute in &mut method.attributes {
+        if let Attribute::Code {
+            code, attributes, ..
+        } = attribute
+        {
+            for (index, instruction) in code.iter_mut().enumerate() {
+                visitor.visit_instruction(constant_pool, index, instruction)?;
+            }
+            visitor.visit_code_attributes(constant_pool, attributes)?;
+        }
+    }
+    visitor.visit_attributes(constant_pool, &mut method.attributes)?;
+    visitor.visit_end(constant_pool, method)
+}
+
+/// Check if the constant occupies two indexes in the constant pool.
+fn is_wide(constant: &Constant) -> bool {
+    matches!(constant, Constant::Long(_) | Constant::Double(_))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Cursor;
+
+    fn hello_world() -> Result<ClassFile> {
+        let class_bytes = include_bytes!("../../classes/HelloWorld.class");
+        ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))
+    }
+
+    #[derive(Default)]
+    struct NoopVisitor;
+
+    impl ClassVisitor for NoopVisitor {}
+
+    #[test]
+    fn test_noop_visitor() -> Result<()> {
+        let expected = hello_world()?;
+        let mut class_file = expected.clone();
+        class_file.accept(&mut NoopVisitor)?;
+        assert_eq!(expected, class_file);
+        Ok(())
+    }
+
+    #[derive(Default)]
+    struct Counter {
+        constants: usize,
+        methods: Vec<String>,
+        instructions: usize,
+        code_attributes: usize,
+        ended: bool,
+    }
+
+    impl ClassVisitor for Counter {
+        fn visit_constant(&mut self, _index: u16, _constant: &mut Constant) -> Result<()> {
+            self.constants += 1;
+            Ok(())
+        }
+
+        fn visit_method(
+            &mut self,
+            constant_pool: &mut ConstantPool,
+            method: &mut Method,
+        ) -> Result<Option<Box<dyn MethodVisitor + '_>>> {
+            let name = constant_pool.try_get_utf8(method.name_index)?;
+            self.methods.push(name.clone());
+            Ok(Some(Box::new(CounterMethodVisitor(self))))
+        }
+
+        fn visit_end(&mut self, _class_file: &mut ClassFile) -> Result<()> {
+            self.ended = true;
+            Ok(())
+        }
+    }
+
+    struct CounterMethodVisitor<'a>(&'a mut Counter);
+
+    impl MethodVisitor for CounterMethodVisitor<'_> {
+        fn visit_instruction(
+            &mut self,
+            _constant_pool: &mut ConstantPool,
+            _index: usize,
+            _instruction: &mut Instruction,
+        ) -> Result<()> {
+            self.0.instructions += 1;
+            Ok(())
+        }
+
+        fn visit_code_attributes(
+            &mut self,
+            _constant_pool: &mut ConstantPool,
+            attributes: &mut Vec<Attribute>,
+        ) -> Result<()> {
+            self.0.code_attributes += attributes.len();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_visit_order() -> Result<()> {
+        let mut class_file = hello_world()?;
+        let mut counter = Counter::default();
+        class_file.accept(&mut counter)?;
+
+        let constants = class_file.constant_pool.iter().count();
+        let instructions: usize = class_file
+            .methods
+            .iter()
+            .flat_map(|method| &method.attributes)
+            .map(|attribute| match attribute {
+                Attribute::Code { code, .. } => code.len(),
+                _ => 0,
+            })
+            .sum();
+        assert_eq!(constants, counter.constants);
+        assert_eq!(vec!["<init>", "main"], counter.methods);
+        assert_eq!(instructions, counter.instructions);
+        assert!(counter.code_attributes > 0);
+        assert!(counter.ended);
+        Ok(())
+    }
+
+    struct Instrumenter;
+
+    impl ClassVisitor for Instrumenter {
+        fn visit_constant(&mut self, _index: u16, constant: &mut Constant) -> Result<()> {
+            if let Constant::Utf8(value) = constant {
+                if value == "Hello " {
+                    *value = "Goodbye ".to_string();
+                }
+            }
+            Ok(())
+        }
+
+        fn visit_method(
+            &mut self,
+            _constant_pool: &mut ConstantPool,
+            _method: &mut Method,
+        ) -> Result<Option<Box<dyn MethodVisitor + '_>>> {
+            Ok(Some(Box::new(StripLineNumbers)))
+        }
+
+        fn visit_attributes(
+            &mut self,
+            constant_pool: &mut ConstantPool,
+            attributes: &mut Vec<Attribute>,
+        ) -> Result<()> {
+            let name_index = constant_pool.add_utf8("Deprecated")?;
+            attributes.push(Attribute::Deprecated { name_index });
+            Ok(())
+        }
+    }
+
+    struct StripLineNumbers;
+
+    impl MethodVisitor for StripLineNumbers {
+        fn visit_code_attributes(
+            &mut self,
+            _constant_pool: &mut ConstantPool,
+            attributes: &mut Vec<Attribute>,
+        ) -> Result<()> {
+            attributes.retain(|attribute| !matches!(attribute, Attribute::LineNumberTable { .. }));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_instrument() -> Result<()> {
+        let mut class_file = hello_world()?;
+        class_file.accept(&mut Instrumenter)?;
+
+        let constants: Vec<&Constant> = class_file
+            .constant_pool
+            .iter()
+            .map(|(_, constant)| constant)
+            .collect();
+        assert!(constants.contains(&&Constant::Utf8("Goodbye ".to_string())));
+        assert!(!constants.contains(&&Constant::Utf8("Hello ".to_string())));
+        for method in &class_file.methods {
+            for attribute in &method.attributes {
+                if let Attribute::Code { attributes, .. } = attribute {
+                    assert!(!attributes
+                        .iter()
+                        .any(|attribute| matches!(attribute, Attribute::LineNumberTable { .. })));
+                }
+            }
+        }
+        assert!(class_file
+            .attributes
+            .iter()
+            .any(|attribute| matches!(attribute, Attribute::Deprecated { .. })));
+
+        let mut bytes = Vec::new();
+        class_file.to_bytes(&mut bytes)?;
+        assert_eq!(class_file, ClassFile::from_bytes(&mut Cursor::new(bytes))?);
+        Ok(())
+    }
+
+    struct ReplaceLong;
+
+    impl ClassVisitor for ReplaceLong {
+        fn visit_constant(&mut self, _index: u16, constant: &mut Constant) -> Result<()> {
+            if let Constant::Long(value) = constant {
+                *constant = Constant::Integer(i32::try_from(*value)?);
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_replace_wide_constant_error() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let index = constant_pool.add_long(42)?;
+        let mut class_file = ClassFile {
+            constant_pool,
+            ..Default::default()
+        };
+        assert_eq!(
+            Err(InvalidConstantPoolIndexType(index)),
+            class_file.accept(&mut ReplaceLong)
+        );
+        Ok(())
+    }
+}
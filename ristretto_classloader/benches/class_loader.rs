@@ -1,10 +1,19 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use ristretto_classloader::{runtime, ClassLoader, Result};
+use ristretto_classloader::{runtime, ClassLoader, ClassPath, ClassPathEntry, Result};
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::runtime::Runtime;
+use zip::write::SimpleFileOptions;
+
+/// Number of jars in the class path of the jar benchmarks.
+const JAR_COUNT: usize = 200;
+/// Number of entries in each jar of the jar benchmarks.
+const ENTRY_COUNT: usize = 500;
 
 fn benchmarks(criterion: &mut Criterion) {
     bench_lifecycle(criterion).ok();
+    bench_jars(criterion).ok();
 }
 
 fn bench_lifecycle(criterion: &mut Criterion) -> Result<()> {
@@ -40,6 +49,56 @@ fn bench_lifecycle(criterion: &mut Criterion) -> Result<()> {
     Ok(())
 }
 
+/// Benchmark loading a class from the last jar of a class path of many large jars with a new class
+/// loader, which opens every jar of the class path.
+fn bench_jars(criterion: &mut Criterion) -> Result<()> {
+    let runtime = Runtime::new()?;
+    let temp_dir = tempfile::tempdir()?;
+    let class_path = write_jars(temp_dir.path())?;
+
+    criterion.bench_function("load_jar_class_path", |bencher| {
+        bencher.iter(|| {
+            runtime.block_on(async {
+                let entries = class_path.iter().map(ClassPathEntry::new).collect();
+                let class_loader = ClassLoader::new("jars", ClassPath::new(entries));
+                class_loader
+                    .load("HelloWorld")
+                    .await
+                    .expect("Failed to load class");
+            });
+        });
+    });
+
+    Ok(())
+}
+
+/// Write the jars of the jar benchmarks; the last jar contains the `HelloWorld` class.
+fn write_jars(directory: &Path) -> Result<Vec<String>> {
+    let classes_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("classes");
+    let class_bytes = std::fs::read(classes_dir.join("HelloWorld.class"))?;
+    let mut class_path = Vec::new();
+    for jar_index in 0..JAR_COUNT {
+        let path = directory.join(format!("jar{jar_index}.jar"));
+        let mut jar = zip::ZipWriter::new(std::fs::File::create(&path)?);
+        for entry_index in 0..ENTRY_COUNT {
+            let name = format!("com/example/jar{jar_index}/Class{entry_index}.class");
+            jar.start_file(name, SimpleFileOptions::default())?;
+            for line in 0..64 {
+                writeln!(jar, "jar {jar_index} entry {entry_index} line {line}")?;
+            }
+        }
+        if jar_index == JAR_COUNT - 1 {
+            jar.start_file("HelloWorld.class", SimpleFileOptions::default())?;
+            jar.write_all(&class_bytes)?;
+        }
+        jar.finish()?;
+        class_path.push(path.to_string_lossy().to_string());
+    }
+    Ok(class_path)
+}
+
 async fn default_class_loader() -> Result<Arc<ClassLoader>> {
     let (_java_home, _version, class_loader) = runtime::default_class_loader().await?;
     let class_loader = Arc::new(class_loader);
@@ -1,5 +1,5 @@
-use crate::Error::{FieldNotFound, MethodNotFound, PoisonedLock};
-use crate::{Field, Method, Result};
+use crate::Error::{FieldNotFound, InvalidStateTransition, MethodNotFound, PoisonedLock};
+use crate::{ClassState, Field, Method, Result};
 use indexmap::IndexMap;
 use ristretto_classfile::attributes::Attribute;
 use ristretto_classfile::{
@@ -8,6 +8,12 @@ use ristretto_classfile::{
 use std::collections::HashMap;
 use std::fmt::{Debug, Display};
 use std::sync::{Arc, RwLock};
+use tokio::sync::Notify;
+
+/// Separator between the name of a hidden class and the suffix that makes the name unique, e.g.
+/// `Foo$$Lambda+0x0000000000000001`; hidden class names are not valid binary names, so a hidden
+/// class cannot be found by name.
+pub(crate) const HIDDEN_CLASS_SEPARATOR: &str = "+0x";
 
 /// A representation of a Java class.
 #[derive(Debug)]
@@ -19,6 +25,8 @@ pub struct Class {
     interfaces: Arc<RwLock<Vec<Arc<Class>>>>,
     fields: IndexMap<String, Arc<Field>>,
     methods: HashMap<String, Arc<Method>>,
+    state: Arc<RwLock<ClassState>>,
+    state_changed: Arc<Notify>,
 }
 
 impl Class {
@@ -44,6 +52,8 @@ impl Class {
             interfaces: Arc::new(RwLock::new(Vec::new())),
             fields: IndexMap::new(),
             methods,
+            state: Arc::new(RwLock::new(ClassState::Loaded)),
+            state_changed: Arc::new(Notify::new()),
         })
     }
 
@@ -89,6 +99,8 @@ impl Class {
             interfaces: Arc::new(RwLock::new(Vec::new())),
             fields,
             methods,
+            state: Arc::new(RwLock::new(ClassState::Loaded)),
+            state_changed: Arc::new(Notify::new()),
         })
     }
 
@@ -98,6 +110,14 @@ impl Class {
         &self.name
     }
 
+    /// Check if the class is a hidden class, i.e. a class defined from bytes that cannot be found
+    /// by name, such as a lambda proxy class.
+    /// See: <https://openjdk.org/jeps/371>
+    #[must_use]
+    pub fn is_hidden(&self) -> bool {
+        self.name.contains(HIDDEN_CLASS_SEPARATOR)
+    }
+
     /// Get the raw component name for an array class.
     #[must_use]
     pub fn array_component_type(&self) -> &str {
@@ -224,6 +244,68 @@ impl Class {
         Ok(())
     }
 
+    /// Get the lifecycle state of the class.
+    ///
+    /// # Errors
+    /// if the state lock is poisoned.
+    pub fn state(&self) -> Result<ClassState> {
+        let state_guard = self
+            .state
+            .read()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        Ok(state_guard.clone())
+    }
+
+    /// Transition the class from the expected state to the next state.  Returns `false` without
+    /// changing the state if the class is not in the expected state, e.g. because another thread
+    /// transitioned it first.
+    ///
+    /// # Errors
+    /// if the transition from the expected state to the next state is not valid or the state lock
+    /// is poisoned.
+    pub fn transition(&self, expected: &ClassState, next: ClassState) -> Result<bool> {
+        if !expected.can_transition_to(&next) {
+            return Err(InvalidStateTransition {
+                class_name: self.name.clone(),
+                from: expected.to_string(),
+                to: next.to_string(),
+            });
+        }
+        let mut state_guard = self
+            .state
+            .write()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        if *state_guard != *expected {
+            return Ok(false);
+        }
+        *state_guard = next;
+        drop(state_guard);
+        self.state_changed.notify_waiters();
+        Ok(true)
+    }
+
+    /// Wait until the class is not being initialized by another thread and get its state; the
+    /// state of a class that is being initialized by the given thread is returned immediately.
+    ///
+    /// # Errors
+    /// if the state lock is poisoned.
+    pub async fn wait_for_initialization(&self, thread_id: u64) -> Result<ClassState> {
+        loop {
+            // Register for the notification before reading the state, so that a transition
+            // between reading the state and waiting is not missed
+            let mut state_changed = std::pin::pin!(self.state_changed.notified());
+            state_changed.as_mut().enable();
+            match self.state()? {
+                ClassState::Initializing(initializing_thread_id)
+                    if initializing_thread_id != thread_id =>
+                {
+                    state_changed.await;
+                }
+                state => return Ok(state),
+            }
+        }
+    }
+
     /// Get the constant pool
     #[must_use]
     pub fn constant_pool(&self) -> &ConstantPool {
@@ -670,6 +752,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_state_transitions() -> Result<()> {
+        let class = simple_class()?;
+        assert_eq!(ClassState::Loaded, class.state()?);
+        assert!(class.transition(&ClassState::Loaded, ClassState::Linked)?);
+        assert!(!class.transition(&ClassState::Loaded, ClassState::Linked)?);
+        assert!(class.transition(&ClassState::Linked, ClassState::Initializing(1))?);
+        assert!(class.transition(&ClassState::Initializing(1), ClassState::Initialized)?);
+        assert_eq!(ClassState::Initialized, class.state()?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_initialization() -> Result<()> {
+        let class = Arc::new(simple_class()?);
+        assert!(class.transition(&ClassState::Loaded, ClassState::Linked)?);
+        assert!(class.transition(&ClassState::Linked, ClassState::Initializing(1))?);
+        assert_eq!(
+            ClassState::Initializing(1),
+            class.wait_for_initialization(1).await?
+        );
+
+        let waiting_class = Arc::clone(&class);
+        let waiter = tokio::spawn(async move { waiting_class.wait_for_initialization(2).await });
+        tokio::task::yield_now().await;
+        assert!(!waiter.is_finished());
+        assert!(class.transition(&ClassState::Initializing(1), ClassState::Initialized)?);
+        let state = waiter.await.map_err(std::io::Error::from)??;
+        assert_eq!(ClassState::Initialized, state);
+        Ok(())
+    }
+
+    #[test]
+    fn test_state_invalid_transition() -> Result<()> {
+        let class = simple_class()?;
+        let result = class.transition(&ClassState::Loaded, ClassState::Initialized);
+        assert!(matches!(
+            result,
+            Err(Error::InvalidStateTransition { class_name, .. }) if class_name == "Simple"
+        ));
+        assert_eq!(ClassState::Loaded, class.state()?);
+        Ok(())
+    }
+
     #[test]
     fn test_constant_pool() -> Result<()> {
         let class = Class::new_named("[Z")?;
@@ -0,0 +1,417 @@
+use crate::Error::ParseError;
+use crate::Result;
+use ristretto_classfile::attributes::Attribute;
+use ristretto_classfile::{ClassAccessFlags, ConstantPool};
+use std::collections::BTreeMap;
+use std::io::{Cursor, Read};
+
+/// Magic number at the start of every class file.
+const MAGIC: u32 = 0xCAFE_BABE;
+
+/// Header of a class file: the class declaration and class level annotations.
+///
+/// Headers are read without decoding the fields, methods or code of the class so that large class
+/// paths can be scanned quickly.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClassHeader {
+    name: String,
+    access_flags: ClassAccessFlags,
+    super_class: Option<String>,
+    interfaces: Vec<String>,
+    annotations: Vec<String>,
+}
+
+impl ClassHeader {
+    /// Read the header of a class file.
+    ///
+    /// # Errors
+    /// if the bytes are not a valid class file.
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self> {
+        let mut bytes = Cursor::new(bytes);
+        if read_u32(&mut bytes)? != MAGIC {
+            return Err(ParseError("Invalid class file magic number".to_string()));
+        }
+        // Skip the minor and major versions
+        read_u32(&mut bytes)?;
+        let constant_pool = ConstantPool::from_bytes(&mut bytes)?;
+        let access_flags = ClassAccessFlags::from_bytes(&mut bytes)?;
+        let name = constant_pool.try_get_class(read_u16(&mut bytes)?)?.clone();
+        let super_class = match read_u16(&mut bytes)? {
+            0 => None,
+            index => Some(constant_pool.try_get_class(index)?.clone()),
+        };
+        let interfaces_count = read_u16(&mut bytes)?;
+        let mut interfaces = Vec::with_capacity(usize::from(interfaces_count));
+        for _ in 0..interfaces_count {
+            interfaces.push(constant_pool.try_get_class(read_u16(&mut bytes)?)?.clone());
+        }
+
+        // Skip the fields and methods
+        for _ in 0..2 {
+            let members_count = read_u16(&mut bytes)?;
+            for _ in 0..members_count {
+                // Skip the access flags, name index and descriptor index
+                skip(&mut bytes, 6)?;
+                skip_attributes(&mut bytes)?;
+            }
+        }
+
+        let mut annotations = Vec::new();
+        let attributes_count = read_u16(&mut bytes)?;
+        for _ in 0..attributes_count {
+            let attribute = Attribute::from_bytes(&constant_pool, &mut bytes)?;
+            let (Attribute::RuntimeVisibleAnnotations {
+                annotations: values,
+                ..
+            }
+            | Attribute::RuntimeInvisibleAnnotations {
+                annotations: values,
+                ..
+            }) = attribute
+            else {
+                continue;
+            };
+            for annotation in values {
+                let descriptor = constant_pool.try_get_utf8(annotation.type_index)?;
+                let type_name = descriptor
+                    .strip_prefix('L')
+                    .and_then(|name| name.strip_suffix(';'))
+                    .ok_or_else(|| {
+                        ParseError(format!("Invalid annotation descriptor: {descriptor}"))
+                    })?;
+                annotations.push(type_name.to_string());
+            }
+        }
+
+        Ok(Self {
+            name,
+            access_flags,
+            super_class,
+            interfaces,
+            annotations,
+        })
+    }
+
+    /// Get the internal name of the class (e.g. `java/lang/String`).
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the access flags of the class.
+    #[must_use]
+    pub fn access_flags(&self) -> &ClassAccessFlags {
+        &self.access_flags
+    }
+
+    /// Get the internal name of the super class; `None` for `java/lang/Object` and modules.
+    #[must_use]
+    pub fn super_class(&self) -> Option<&str> {
+        self.super_class.as_deref()
+    }
+
+    /// Get the internal names of the interfaces implemented by the class.
+    #[must_use]
+    pub fn interfaces(&self) -> &[String] {
+        &self.interfaces
+    }
+
+    /// Get the internal names of the visible and invisible annotations of the class.
+    #[must_use]
+    pub fn annotations(&self) -> &[String] {
+        &self.annotations
+    }
+}
+
+/// Read a big endian `u16`.
+fn read_u16(bytes: &mut Cursor<Vec<u8>>) -> Result<u16> {
+    let mut buffer = [0u8; 2];
+    bytes.read_exact(&mut buffer)?;
+    Ok(u16::from_be_bytes(buffer))
+}
+
+/// Read a big endian `u32`.
+fn read_u32(bytes: &mut Cursor<Vec<u8>>) -> Result<u32> {
+    let mut buffer = [0u8; 4];
+    bytes.read_exact(&mut buffer)?;
+    Ok(u32::from_be_bytes(buffer))
+}
+
+/// Skip a number of bytes.
+fn skip(bytes: &mut Cursor<Vec<u8>>, length: u64) -> Result<()> {
+    let position = bytes.position() + length;
+    if position > bytes.get_ref().len() as u64 {
+        return Err(ParseError("Unexpected end of class file".to_string()));
+    }
+    bytes.set_position(position);
+    Ok(())
+}
+
+/// Skip the attributes of a field or method.
+fn skip_attributes(bytes: &mut Cursor<Vec<u8>>) -> Result<()> {
+    let attributes_count = read_u16(bytes)?;
+    for _ in 0..attributes_count {
+        // Skip the attribute name index
+        skip(bytes, 2)?;
+        let length = read_u32(bytes)?;
+        skip(bytes, u64::from(length))?;
+    }
+    Ok(())
+}
+
+/// Index of the classes in a class path.
+///
+/// Classes are indexed by their internal name (e.g. `java/util/List`); queries accept either the
+/// internal or binary (e.g. `java.util.List`) form.  When a class is found in more than one class
+/// path entry, the first entry is indexed, matching the order used when loading classes.  Headers
+/// are only available when the index was created with
+/// [`ClassPath::scan_headers`](crate::ClassPath::scan_headers).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClassIndex {
+    classes: BTreeMap<String, Option<ClassHeader>>,
+}
+
+impl ClassIndex {
+    /// Add a class name to the index.
+    pub(crate) fn insert_name(&mut self, name: String) {
+        self.classes.entry(name).or_insert(None);
+    }
+
+    /// Add a class header to the index.
+    pub(crate) fn insert_header(&mut self, header: ClassHeader) {
+        self.classes
+            .entry(header.name.clone())
+            .or_insert(Some(header));
+    }
+
+    /// Get the number of classes.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.classes.len()
+    }
+
+    /// Returns true if the index does not contain any classes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty()
+    }
+
+    /// Returns true if the index contains the class.
+    #[must_use]
+    pub fn contains<S: AsRef<str>>(&self, name: S) -> bool {
+        self.classes.contains_key(&internal_name(name))
+    }
+
+    /// Get the header of a class.
+    #[must_use]
+    pub fn header<S: AsRef<str>>(&self, name: S) -> Option<&ClassHeader> {
+        self.classes.get(&internal_name(name))?.as_ref()
+    }
+
+    /// Returns an iterator over the class names, in sorted order.
+    pub fn class_names(&self) -> impl Iterator<Item = &str> {
+        self.classes.keys().map(String::as_str)
+    }
+
+    /// Returns an iterator over the class headers, in sorted order.
+    pub fn headers(&self) -> impl Iterator<Item = &ClassHeader> {
+        self.classes.values().flatten()
+    }
+
+    /// Get the names of the classes that start with a prefix (e.g. for completion).
+    #[must_use]
+    pub fn starting_with<S: AsRef<str>>(&self, prefix: S) -> Vec<&str> {
+        let prefix = internal_name(prefix);
+        self.classes
+            .range(prefix.clone()..)
+            .map(|(name, _)| name.as_str())
+            .take_while(|name| name.starts_with(&prefix))
+            .collect()
+    }
+
+    /// Get the names of the classes in a package, excluding classes in sub-packages; the empty
+    /// string is the unnamed package.
+    #[must_use]
+    pub fn package<S: AsRef<str>>(&self, package: S) -> Vec<&str> {
+        let package = internal_name(package);
+        self.class_names()
+            .filter(|name| match name.rsplit_once('/') {
+                Some((name_package, _)) => name_package == package,
+                None => package.is_empty(),
+            })
+            .collect()
+    }
+
+    /// Get the names of the classes that match a glob pattern.  `?` matches a single character
+    /// and `*` matches any characters within a package, while `**` also matches across packages;
+    /// e.g. `java.util.*` matches `java/util/List` but not `java/util/concurrent/Future`.
+    #[must_use]
+    pub fn glob<S: AsRef<str>>(&self, pattern: S) -> Vec<&str> {
+        let pattern = internal_name(pattern);
+        self.class_names()
+            .filter(|name| glob_matches(pattern.as_bytes(), name.as_bytes()))
+            .collect()
+    }
+
+    /// Get the headers of the classes with an annotation.
+    #[must_use]
+    pub fn annotated_with<S: AsRef<str>>(&self, annotation: S) -> Vec<&ClassHeader> {
+        let annotation = internal_name(annotation);
+        self.headers()
+            .filter(|header| header.annotations.contains(&annotation))
+            .collect()
+    }
+}
+
+/// Convert a binary class or package name to an internal name.
+fn internal_name<S: AsRef<str>>(name: S) -> String {
+    name.as_ref().replace('.', "/")
+}
+
+/// Returns true if a name matches a glob pattern.
+fn glob_matches(pattern: &[u8], name: &[u8]) -> bool {
+    match pattern {
+        [] => name.is_empty(),
+        [b'*', b'*', rest @ ..] => (0..=name.len()).any(|index| glob_matches(rest, &name[index..])),
+        [b'*', rest @ ..] => {
+            let segment = name
+                .iter()
+                .position(|&byte| byte == b'/')
+                .unwrap_or(name.len());
+            (0..=segment).any(|index| glob_matches(rest, &name[index..]))
+        }
+        [b'?', rest @ ..] => {
+            matches!(name, [byte, ..] if *byte != b'/') && glob_matches(rest, &name[1..])
+        }
+        [byte, rest @ ..] => name.first() == Some(byte) && glob_matches(rest, &name[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ristretto_classfile::attributes::Annotation;
+    use ristretto_classfile::ClassFile;
+    use std::path::PathBuf;
+
+    fn index() -> ClassIndex {
+        let mut index = ClassIndex::default();
+        for name in [
+            "Main",
+            "java/util/List",
+            "java/util/Map",
+            "java/util/Map$Entry",
+            "java/util/concurrent/Future",
+        ] {
+            index.insert_name(name.to_string());
+        }
+        index
+    }
+
+    #[test]
+    fn test_header_from_bytes() -> Result<()> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let class_file = cargo_manifest
+            .join("..")
+            .join("classes")
+            .join("HelloWorld.class");
+        let header = ClassHeader::from_bytes(std::fs::read(class_file)?)?;
+        assert_eq!("HelloWorld", header.name());
+        assert!(header.access_flags().contains(ClassAccessFlags::PUBLIC));
+        assert_eq!(Some("java/lang/Object"), header.super_class());
+        assert!(header.interfaces().is_empty());
+        assert!(header.annotations().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_header_annotations() -> Result<()> {
+        let mut constant_pool = ConstantPool::new();
+        let this_class = constant_pool.add_class("Foo")?;
+        let super_class = constant_pool.add_class("java/lang/Object")?;
+        let interface = constant_pool.add_class("java/io/Serializable")?;
+        let name_index = constant_pool.add_utf8("RuntimeVisibleAnnotations")?;
+        let type_index = constant_pool.add_utf8("Ljava/lang/Deprecated;")?;
+        let class_file = ClassFile {
+            constant_pool,
+            this_class,
+            super_class,
+            interfaces: vec![interface],
+            attributes: vec![Attribute::RuntimeVisibleAnnotations {
+                name_index,
+                annotations: vec![Annotation {
+                    type_index,
+                    elements: Vec::new(),
+                }],
+            }],
+            ..Default::default()
+        };
+        let mut bytes = Vec::new();
+        class_file.to_bytes(&mut bytes)?;
+
+        let header = ClassHeader::from_bytes(bytes)?;
+        assert_eq!("Foo", header.name());
+        assert_eq!(["java/io/Serializable"], header.interfaces());
+        assert_eq!(["java/lang/Deprecated"], header.annotations());
+
+        let mut index = ClassIndex::default();
+        index.insert_header(header.clone());
+        assert_eq!(Some(&header), index.header("Foo"));
+        assert_eq!(vec![&header], index.annotated_with("java.lang.Deprecated"));
+        assert!(index
+            .annotated_with("java.lang.FunctionalInterface")
+            .is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_header_invalid() {
+        let result = ClassHeader::from_bytes(vec![0xCA, 0xFE, 0xBA, 0xBF]);
+        assert!(matches!(result, Err(ParseError(_))));
+        assert!(ClassHeader::from_bytes(vec![0xCA, 0xFE]).is_err());
+    }
+
+    #[test]
+    fn test_contains() {
+        let index = index();
+        assert_eq!(5, index.len());
+        assert!(!index.is_empty());
+        assert!(index.contains("java.util.List"));
+        assert!(index.contains("java/util/List"));
+        assert!(!index.contains("java.util.Set"));
+        assert_eq!(None, index.header("java.util.List"));
+    }
+
+    #[test]
+    fn test_starting_with() {
+        let index = index();
+        assert_eq!(
+            vec!["java/util/Map", "java/util/Map$Entry"],
+            index.starting_with("java.util.M")
+        );
+        assert!(index.starting_with("javax").is_empty());
+    }
+
+    #[test]
+    fn test_package() {
+        let index = index();
+        assert_eq!(
+            vec!["java/util/List", "java/util/Map", "java/util/Map$Entry"],
+            index.package("java.util")
+        );
+        assert_eq!(vec!["Main"], index.package(""));
+    }
+
+    #[test]
+    fn test_glob() {
+        let index = index();
+        assert_eq!(
+            vec!["java/util/List", "java/util/Map", "java/util/Map$Entry"],
+            index.glob("java.util.*")
+        );
+        assert_eq!(5 - 1, index.glob("java/**").len());
+        assert_eq!(vec!["java/util/Map"], index.glob("java/util/M?p"));
+        assert_eq!(vec!["java/util/concurrent/Future"], index.glob("**/F*"));
+        assert!(index.glob("java/*").is_empty());
+    }
+}
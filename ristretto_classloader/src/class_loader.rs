@@ -1,10 +1,18 @@
-use crate::Error::ClassNotFound;
+use crate::class::HIDDEN_CLASS_SEPARATOR;
+use crate::class_watcher::ClassWatcher;
+use crate::Error::{ClassNotFound, DuplicateClassDefinition, WrongClassName};
 use crate::{Class, ClassPath, Result};
+use ristretto_classfile::{ClassFile, Constant};
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::io::Cursor;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+/// Source of the unique suffixes of hidden class names.
+static HIDDEN_CLASS_ID: AtomicU64 = AtomicU64::new(1);
+
 /// Implementation of a Java class loader.
 ///
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-5.html>
@@ -14,6 +22,8 @@ pub struct ClassLoader {
     class_path: ClassPath,
     parent: Arc<Option<ClassLoader>>,
     classes: Arc<RwLock<HashMap<String, Arc<Class>>>>,
+    watcher: Arc<RwLock<Option<ClassWatcher>>>,
+    generation: Arc<AtomicU64>,
 }
 
 impl ClassLoader {
@@ -24,6 +34,8 @@ impl ClassLoader {
             class_path,
             parent: Arc::new(None),
             classes: Arc::new(RwLock::new(HashMap::new())),
+            watcher: Arc::new(RwLock::new(None)),
+            generation: Arc::new(AtomicU64::new(0)),
         }
     }
 
@@ -71,37 +83,302 @@ impl ClassLoader {
     ) -> Result<(Arc<Class>, bool)> {
         let class_name = class_name.as_ref().to_string().replace('.', "/");
         let class_name = class_name.as_str();
-        {
-            let classes = self.classes.read().await;
-            if let Some(class) = classes.get(class_name) {
-                return Ok((Arc::clone(class), true));
+        if let Some(class) = self.find_loaded(class_name).await {
+            return Ok((class, true));
+        }
+
+        // Delegate to the parent class loaders first; the class is defined by the first class
+        // loader that has already loaded it or can read it from its class path.
+        for class_loader in self.class_loaders() {
+            let result = if let Some(class) = class_loader.find_loaded(class_name).await {
+                (class, true)
+            } else if let Ok(class_file) = class_loader.class_path().read_class(class_name).await {
+                let mut classes = class_loader.classes.write().await;
+                Self::define(&mut classes, class_name, class_file)?
+            } else {
+                continue;
+            };
+
+            // Record this class loader as an initiating loader of the class
+            if !class_loader.ptr_eq(self) {
+                let mut classes = self.classes.write().await;
+                classes
+                    .entry(class_name.to_string())
+                    .or_insert_with(|| result.0.clone());
             }
+            return Ok(result);
         }
 
-        // Convert hierarchy of class loaders to a flat list so that we can iterate over them from
-        // the boot class loader to the current class loader.
+        Err(ClassNotFound(class_name.to_string()))
+    }
+
+    /// Load a class by name without an async runtime.
+    ///
+    /// # Errors
+    /// if the class file cannot be read.
+    ///
+    /// # Panics
+    /// if called from within an async execution context.
+    pub fn load_blocking<S: AsRef<str>>(&self, name: S) -> Result<Arc<Class>> {
+        self.load_with_status_blocking(name).map(|(class, _)| class)
+    }
+
+    /// Load a class by name without an async runtime, with a boolean status indicating if the
+    /// class was loaded previously.
+    ///
+    /// # Errors
+    /// if the class file cannot be read.
+    ///
+    /// # Panics
+    /// if called from within an async execution context.
+    pub fn load_with_status_blocking<S: AsRef<str>>(
+        &self,
+        class_name: S,
+    ) -> Result<(Arc<Class>, bool)> {
+        let class_name = class_name.as_ref().to_string().replace('.', "/");
+        let class_name = class_name.as_str();
+        if let Some(class) = self.find_loaded_blocking(class_name) {
+            return Ok((class, true));
+        }
+
+        for class_loader in self.class_loaders() {
+            let result = if let Some(class) = class_loader.find_loaded_blocking(class_name) {
+                (class, true)
+            } else if let Ok(class_file) = class_loader.class_path().read_class_blocking(class_name)
+            {
+                let mut classes = class_loader.classes.blocking_write();
+                Self::define(&mut classes, class_name, class_file)?
+            } else {
+                continue;
+            };
+
+            if !class_loader.ptr_eq(self) {
+                let mut classes = self.classes.blocking_write();
+                classes
+                    .entry(class_name.to_string())
+                    .or_insert_with(|| result.0.clone());
+            }
+            return Ok(result);
+        }
+
+        Err(ClassNotFound(class_name.to_string()))
+    }
+
+    /// Get a class that has been loaded by this class loader, either as the defining loader or as
+    /// an initiating loader; the class is not loaded if it has not been loaded.
+    pub async fn find_loaded<S: AsRef<str>>(&self, class_name: S) -> Option<Arc<Class>> {
+        let class_name = class_name.as_ref().replace('.', "/");
+        let classes = self.classes.read().await;
+        classes.get(&class_name).cloned()
+    }
+
+    /// Get a class that has been loaded by this class loader without an async runtime.
+    ///
+    /// # Panics
+    /// if called from within an async execution context.
+    pub fn find_loaded_blocking<S: AsRef<str>>(&self, class_name: S) -> Option<Arc<Class>> {
+        let class_name = class_name.as_ref().replace('.', "/");
+        let classes = self.classes.blocking_read();
+        classes.get(&class_name).cloned()
+    }
+
+    /// Check if two class loaders are the same instance; clones of a class loader share their
+    /// loaded classes and are the same instance.
+    #[must_use]
+    pub fn ptr_eq(&self, other: &ClassLoader) -> bool {
+        Arc::ptr_eq(&self.classes, &other.classes)
+    }
+
+    /// Watch the directories of the class path for class files that are added, changed or removed;
+    /// the changes are picked up by [`ClassLoader::reload`].  Watching is opt-in because every
+    /// reload walks the watched directories.
+    ///
+    /// # Errors
+    /// if the class files of a directory cannot be read.
+    pub async fn watch(&self) -> Result<()> {
+        let watcher = ClassWatcher::new(&self.class_path)?;
+        *self.watcher.write().await = Some(watcher);
+        Ok(())
+    }
+
+    /// Invalidate the classes whose class files changed in the watched directories of this class
+    /// loader and its parents since they were last checked; the classes are invalidated in the
+    /// class loader that watches the directory and in the class loaders below it, down to this
+    /// class loader.  Returns the names of the invalidated classes.
+    ///
+    /// Class loaders only know their parent, so other class loaders below the watching class
+    /// loader (e.g. siblings of this class loader) are not invalidated and keep the classes they
+    /// have already loaded; pass the returned names to [`ClassLoader::invalidate`] of those class
+    /// loaders to reload the classes there as well.
+    ///
+    /// # Errors
+    /// if the class files of a watched directory cannot be read.
+    pub async fn reload(&self) -> Result<Vec<String>> {
+        let class_loaders = self.class_loaders();
+        let mut class_names = Vec::new();
+        for (index, class_loader) in class_loaders.iter().enumerate() {
+            let changes = match class_loader.watcher.write().await.as_mut() {
+                Some(watcher) => watcher.changes()?,
+                None => continue,
+            };
+            if changes.is_empty() {
+                continue;
+            }
+            for class_loader in &class_loaders[index..] {
+                class_loader.invalidate(&changes).await;
+            }
+            class_names.extend(changes);
+        }
+        Ok(class_names)
+    }
+
+    /// Invalidate loaded classes so that they are read from the class path again the next time
+    /// they are loaded, and advance the generation of the class loader.  Classes that are already
+    /// in use keep their current definition.  Returns the new generation.
+    pub async fn invalidate<S: AsRef<str>>(&self, class_names: &[S]) -> u64 {
+        let mut classes = self.classes.write().await;
+        for class_name in class_names {
+            classes.remove(&class_name.as_ref().replace('.', "/"));
+        }
+        self.generation.fetch_add(1, Ordering::AcqRel) + 1
+    }
+
+    /// Get the generation of the class loader; the generation advances every time classes are
+    /// invalidated, so classes and other values derived from an earlier generation may be stale.
+    #[must_use]
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    /// Get the hierarchy of class loaders from the boot class loader to this class loader.
+    pub(crate) fn class_loaders(&self) -> Vec<&ClassLoader> {
         let mut class_loader = self;
         let mut class_loaders = vec![class_loader];
         while let Some(parent) = class_loader.parent() {
             class_loader = parent;
             class_loaders.push(parent);
         }
+        class_loaders.reverse();
+        class_loaders
+    }
 
-        for class_loader in class_loaders.into_iter().rev() {
-            let class_path = class_loader.class_path();
-            if let Ok(class_file) = class_path.read_class(class_name).await {
-                let mut classes = self.classes.write().await;
-                // Check if the class was loaded while waiting for the lock.
-                if let Some(class) = classes.get(class_name) {
-                    return Ok((class.clone(), true));
-                }
-                let class = Arc::new(Class::from(class_file)?);
-                classes.insert(class_name.to_string(), class.clone());
-                return Ok((class, false));
+    /// Define a class read from the class path, unless it was loaded while waiting for the lock.
+    fn define(
+        classes: &mut HashMap<String, Arc<Class>>,
+        class_name: &str,
+        class_file: ClassFile,
+    ) -> Result<(Arc<Class>, bool)> {
+        if let Some(class) = classes.get(class_name) {
+            return Ok((class.clone(), true));
+        }
+        let class = Arc::new(Class::from(class_file)?);
+        classes.insert(class_name.to_string(), class.clone());
+        Ok((class, false))
+    }
+
+    /// Define a class from the bytes of a class file, e.g. a class generated at runtime; the class
+    /// is defined by this class loader and can then be loaded by name.
+    ///
+    /// # Errors
+    /// if the bytes are not a valid class file, the class file defines a class with a different
+    /// name, or the class has already been loaded by this class loader.
+    pub async fn define_class<S: AsRef<str>>(&self, name: S, bytes: &[u8]) -> Result<Arc<Class>> {
+        let class_file = Self::read_class_file(Some(name.as_ref()), bytes)?;
+        let mut classes = self.classes.write().await;
+        Self::define_new(&mut classes, class_file)
+    }
+
+    /// Define a class from the bytes of a class file without an async runtime.
+    ///
+    /// # Errors
+    /// if the bytes are not a valid class file, the class file defines a class with a different
+    /// name, or the class has already been loaded by this class loader.
+    ///
+    /// # Panics
+    /// if called from within an async execution context.
+    pub fn define_class_blocking<S: AsRef<str>>(
+        &self,
+        name: S,
+        bytes: &[u8],
+    ) -> Result<Arc<Class>> {
+        let class_file = Self::read_class_file(Some(name.as_ref()), bytes)?;
+        let mut classes = self.classes.blocking_write();
+        Self::define_new(&mut classes, class_file)
+    }
+
+    /// Define a hidden class from the bytes of a class file, e.g. a lambda proxy class.  The class
+    /// is renamed to `<name>+0x<id>` so that it never conflicts with, and cannot be found by, the
+    /// name in the class file; references to the class in its own constant pool use the new name.
+    /// See: <https://openjdk.org/jeps/371>
+    ///
+    /// # Errors
+    /// if the bytes are not a valid class file.
+    pub async fn define_hidden_class(&self, bytes: &[u8]) -> Result<Arc<Class>> {
+        let class_file = Self::hidden_class_file(bytes)?;
+        let mut classes = self.classes.write().await;
+        Self::define_new(&mut classes, class_file)
+    }
+
+    /// Define a hidden class from the bytes of a class file without an async runtime.
+    ///
+    /// # Errors
+    /// if the bytes are not a valid class file.
+    ///
+    /// # Panics
+    /// if called from within an async execution context.
+    pub fn define_hidden_class_blocking(&self, bytes: &[u8]) -> Result<Arc<Class>> {
+        let class_file = Self::hidden_class_file(bytes)?;
+        let mut classes = self.classes.blocking_write();
+        Self::define_new(&mut classes, class_file)
+    }
+
+    /// Read and verify a class file, checking the class name if one is expected.
+    fn read_class_file(expected: Option<&str>, bytes: &[u8]) -> Result<ClassFile> {
+        let class_file = ClassFile::from_bytes(&mut Cursor::new(bytes.to_vec()))?;
+        class_file.verify()?;
+        if let Some(expected) = expected {
+            let expected = expected.replace('.', "/");
+            let class_name = class_file.class_name()?;
+            if *class_name != expected {
+                return Err(WrongClassName {
+                    class_name: class_name.clone(),
+                    expected,
+                });
             }
         }
+        Ok(class_file)
+    }
+
+    /// Read a class file and give the class a unique hidden class name.
+    fn hidden_class_file(bytes: &[u8]) -> Result<ClassFile> {
+        let mut class_file = Self::read_class_file(None, bytes)?;
+        let id = HIDDEN_CLASS_ID.fetch_add(1, Ordering::Relaxed);
+        let name = format!(
+            "{}{HIDDEN_CLASS_SEPARATOR}{id:016x}",
+            class_file.class_name()?
+        );
+        let name_index = class_file.constant_pool.add_utf8(name)?;
+        if let Some(Constant::Class(class_name_index)) =
+            class_file.constant_pool.get_mut(class_file.this_class)
+        {
+            *class_name_index = name_index;
+        }
+        Ok(class_file)
+    }
 
-        Err(ClassNotFound(class_name.to_string()))
+    /// Define a class from bytes; unlike classes read from the class path, defining a class that
+    /// has already been loaded is an error.
+    fn define_new(
+        classes: &mut HashMap<String, Arc<Class>>,
+        class_file: ClassFile,
+    ) -> Result<Arc<Class>> {
+        let class_name = class_file.class_name()?.clone();
+        if classes.contains_key(&class_name) {
+            return Err(DuplicateClassDefinition(class_name));
+        }
+        let class = Arc::new(Class::from(class_file)?);
+        classes.insert(class_name, class.clone());
+        Ok(class)
     }
 
     /// Register a class with the class loader.
@@ -114,6 +391,20 @@ impl ClassLoader {
         classes.insert(class_name, class);
         Ok(())
     }
+
+    /// Register a class with the class loader without an async runtime.
+    ///
+    /// # Errors
+    /// if the class cannot be registered.
+    ///
+    /// # Panics
+    /// if called from within an async execution context.
+    pub fn register_blocking(&self, class: Arc<Class>) -> Result<()> {
+        let mut classes = self.classes.blocking_write();
+        let class_name = class.name().to_string();
+        classes.insert(class_name, class);
+        Ok(())
+    }
 }
 
 impl Clone for ClassLoader {
@@ -124,6 +415,8 @@ impl Clone for ClassLoader {
             class_path: self.class_path.clone(),
             parent: Arc::clone(&self.parent),
             classes: Arc::clone(&self.classes),
+            watcher: Arc::clone(&self.watcher),
+            generation: Arc::clone(&self.generation),
         }
     }
 }
@@ -214,6 +507,30 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_load_class_blocking() -> Result<()> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_directory = cargo_manifest.join("..").join("classes");
+        let class_path = ClassPath::from(classes_directory.to_string_lossy());
+        let boot_class_loader = ClassLoader::new("boot", class_path);
+        let mut class_loader = ClassLoader::new("test", ClassPath::from("foo"));
+        class_loader.set_parent(Some(boot_class_loader));
+
+        let (class, previously_loaded) = class_loader.load_with_status_blocking("HelloWorld")?;
+        assert_eq!("HelloWorld", class.name());
+        assert!(!previously_loaded);
+        let (_class, previously_loaded) = class_loader.load_with_status_blocking("HelloWorld")?;
+        assert!(previously_loaded);
+
+        let result = class_loader.load_blocking("Foo");
+        assert!(matches!(result, Err(ClassNotFound(_))));
+
+        let class = Arc::new(Class::new_named("Foo")?);
+        class_loader.register_blocking(class)?;
+        assert_eq!("Foo", class_loader.load_blocking("Foo")?.name());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_load_class_more_than_once() -> Result<()> {
         let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -254,6 +571,62 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_load_class_defined_by_parent() -> Result<()> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_directory = cargo_manifest.join("..").join("classes");
+        let class_path = ClassPath::from(classes_directory.to_string_lossy());
+        let boot_class_loader = ClassLoader::new("boot", class_path);
+        let mut class_loader = ClassLoader::new("test", ClassPath::from("foo"));
+        class_loader.set_parent(Some(boot_class_loader.clone()));
+
+        let (class, previously_loaded) = class_loader.load_with_status("HelloWorld").await?;
+        assert!(!previously_loaded);
+        let boot_class = boot_class_loader
+            .find_loaded("HelloWorld")
+            .await
+            .expect("defined by boot");
+        assert!(Arc::ptr_eq(&class, &boot_class));
+        let (boot_class, previously_loaded) =
+            boot_class_loader.load_with_status("HelloWorld").await?;
+        assert!(previously_loaded);
+        assert!(Arc::ptr_eq(&class, &boot_class));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_load_class_registered_with_parent() -> Result<()> {
+        let boot_class_loader = ClassLoader::new("boot", ClassPath::from("foo"));
+        let mut class_loader = ClassLoader::new("test", ClassPath::from("bar"));
+        class_loader.set_parent(Some(boot_class_loader.clone()));
+        assert!(class_loader.find_loaded("Foo").await.is_none());
+
+        let foo = Arc::new(Class::new_named("Foo")?);
+        boot_class_loader.register(foo.clone()).await?;
+        let (class, previously_loaded) = class_loader.load_with_status("Foo").await?;
+        assert!(previously_loaded);
+        assert!(Arc::ptr_eq(&foo, &class));
+        assert!(class_loader.find_loaded("Foo").await.is_some());
+
+        let bar = Arc::new(Class::new_named("Bar")?);
+        class_loader.register(bar).await?;
+        assert!(boot_class_loader.find_loaded("Bar").await.is_none());
+        assert!(matches!(
+            boot_class_loader.load("Bar").await,
+            Err(ClassNotFound(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ptr_eq() {
+        let class_loader = ClassLoader::new("test", ClassPath::from("."));
+        let other = ClassLoader::new("test", ClassPath::from("."));
+        assert!(class_loader.ptr_eq(&class_loader.clone()));
+        assert!(!class_loader.ptr_eq(&other));
+        assert_eq!(class_loader, other);
+    }
+
     #[tokio::test]
     async fn test_load_class_not_found() {
         let class_path = ClassPath::from(".");
@@ -300,4 +673,134 @@ mod tests {
         );
         Ok(())
     }
+
+    /// Get the bytes of a class file for a class with a name.
+    fn class_bytes(name: &str) -> Result<Vec<u8>> {
+        let class_file = ristretto_classfile::ClassFileBuilder::new(name).build()?;
+        let mut bytes = Vec::new();
+        class_file.to_bytes(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    #[tokio::test]
+    async fn test_define_class() -> Result<()> {
+        let class_loader = ClassLoader::new("test", ClassPath::from("foo"));
+        let class = class_loader
+            .define_class("foo.Bar", &class_bytes("foo/Bar")?)
+            .await?;
+        assert_eq!("foo/Bar", class.name());
+        assert!(!class.is_hidden());
+        let loaded = class_loader.load("foo.Bar").await?;
+        assert!(Arc::ptr_eq(&class, &loaded));
+
+        let result = class_loader
+            .define_class("foo/Bar", &class_bytes("foo/Bar")?)
+            .await;
+        assert!(matches!(result, Err(DuplicateClassDefinition(_))));
+        let result = class_loader
+            .define_class("foo/Baz", &class_bytes("foo/Bar")?)
+            .await;
+        assert!(matches!(result, Err(WrongClassName { .. })));
+        let result = class_loader.define_class("foo/Baz", &[0xCA, 0xFE]).await;
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_define_class_blocking() -> Result<()> {
+        let class_loader = ClassLoader::new("test", ClassPath::from("foo"));
+        let class = class_loader.define_class_blocking("Foo", &class_bytes("Foo")?)?;
+        assert!(Arc::ptr_eq(&class, &class_loader.load_blocking("Foo")?));
+        let hidden = class_loader.define_hidden_class_blocking(&class_bytes("Foo")?)?;
+        assert!(hidden.is_hidden());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_define_hidden_class() -> Result<()> {
+        let class_loader = ClassLoader::new("test", ClassPath::from("foo"));
+        let bytes = class_bytes("Foo$$Lambda")?;
+        let first = class_loader.define_hidden_class(&bytes).await?;
+        let second = class_loader.define_hidden_class(&bytes).await?;
+        assert!(first.is_hidden());
+        assert!(first.name().starts_with("Foo$$Lambda+0x"));
+        assert_ne!(first.name(), second.name());
+        assert_eq!(first.name(), first.class_file().class_name()?);
+        assert!(class_loader.find_loaded("Foo$$Lambda").await.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reload() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let directory = temp_dir.path().join("classes");
+        std::fs::create_dir_all(&directory)?;
+        std::fs::write(directory.join("Foo.class"), class_bytes("Foo")?)?;
+        let parent = ClassLoader::new("parent", ClassPath::from(directory.to_string_lossy()));
+        let mut class_loader = ClassLoader::new("child", ClassPath::from("foo"));
+        class_loader.set_parent(Some(parent.clone()));
+        parent.watch().await?;
+
+        let class = class_loader.load("Foo").await?;
+        assert!(class_loader.reload().await?.is_empty());
+        assert_eq!(0, class_loader.generation());
+
+        let file = std::fs::File::options()
+            .write(true)
+            .open(directory.join("Foo.class"))?;
+        file.set_modified(std::time::SystemTime::now() + std::time::Duration::from_secs(60))?;
+        assert_eq!(vec!["Foo"], class_loader.reload().await?);
+        assert_eq!(1, parent.generation());
+        assert_eq!(1, class_loader.generation());
+        assert!(parent.find_loaded("Foo").await.is_none());
+        assert!(class_loader.find_loaded("Foo").await.is_none());
+        let reloaded = class_loader.load("Foo").await?;
+        assert!(!Arc::ptr_eq(&class, &reloaded));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reload_sibling() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let directory = temp_dir.path().join("classes");
+        std::fs::create_dir_all(&directory)?;
+        std::fs::write(directory.join("Foo.class"), class_bytes("Foo")?)?;
+        let parent = ClassLoader::new("parent", ClassPath::from(directory.to_string_lossy()));
+        let mut class_loader = ClassLoader::new("child", ClassPath::from("foo"));
+        class_loader.set_parent(Some(parent.clone()));
+        let mut sibling = ClassLoader::new("sibling", ClassPath::from("foo"));
+        sibling.set_parent(Some(parent.clone()));
+        parent.watch().await?;
+
+        let class = sibling.load("Foo").await?;
+        let file = std::fs::File::options()
+            .write(true)
+            .open(directory.join("Foo.class"))?;
+        file.set_modified(std::time::SystemTime::now() + std::time::Duration::from_secs(60))?;
+        let class_names = class_loader.reload().await?;
+        assert_eq!(vec!["Foo"], class_names);
+        assert!(parent.find_loaded("Foo").await.is_none());
+
+        // The sibling is not invalidated by the reload and keeps the stale class
+        assert_eq!(0, sibling.generation());
+        assert!(Arc::ptr_eq(&class, &sibling.load("Foo").await?));
+        assert!(sibling.reload().await?.is_empty());
+
+        assert_eq!(1, sibling.invalidate(&class_names).await);
+        let reloaded = sibling.load("Foo").await?;
+        assert!(!Arc::ptr_eq(&class, &reloaded));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invalidate() -> Result<()> {
+        let class_loader = ClassLoader::new("test", ClassPath::from("foo"));
+        class_loader
+            .define_class("foo.Bar", &class_bytes("foo/Bar")?)
+            .await?;
+        assert_eq!(1, class_loader.invalidate(&["foo.Bar"]).await);
+        assert!(class_loader.find_loaded("foo/Bar").await.is_none());
+        assert_eq!(1, class_loader.clone().generation());
+        Ok(())
+    }
 }
@@ -1,8 +1,11 @@
 use crate::class_path_entry::ClassPathEntry;
-use crate::Error::ClassNotFound;
-use crate::Result;
+use crate::Error::{ClassNotFound, SecurityError};
+use crate::{ClassIndex, Result};
 use ristretto_classfile::ClassFile;
+use std::collections::HashSet;
 use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
 use tracing::{info, instrument};
 
 /// Represents a class path.
@@ -21,7 +24,9 @@ impl ClassPath {
         ClassPath { class_path }
     }
 
-    /// Creates a new `ClassPath` from a string.
+    /// Creates a new `ClassPath` from a string.  An element whose last component is `*`, e.g.
+    /// `lib/*`, is expanded to the jars in the directory in name order, like `java -cp 'lib/*'`;
+    /// subdirectories are not searched, and a directory that does not exist expands to no entries.
     ///
     /// # Errors
     /// if the class path is not valid.
@@ -34,11 +39,75 @@ impl ClassPath {
             let path = path
                 .replace("http//", "http://")
                 .replace("https//", "https://");
+            #[cfg(feature = "url")]
+            let path = path.as_str();
+            if let Some(directory) = wildcard_directory(path) {
+                for jar in wildcard_jars(directory) {
+                    class_path_entries.push(ClassPathEntry::new(jar.to_string_lossy()));
+                }
+                continue;
+            }
             class_path_entries.push(ClassPathEntry::new(path));
         }
         ClassPath::new(class_path_entries)
     }
 
+    /// Creates a new `ClassPath` from a module path.  Each element of the module path is a
+    /// module (a jar, jmod or exploded module directory) or a directory of modules, such as
+    /// `$JAVA_HOME/jmods`; the modules in a directory are added in name order.
+    ///
+    /// # Errors
+    /// if an element of the module path does not exist or a directory of modules cannot be read.
+    pub fn from_module_path<S: AsRef<str>>(module_path: S) -> Result<Self> {
+        let mut class_path_entries = Vec::new();
+        for path in module_path.as_ref().split(':') {
+            let path = PathBuf::from(path);
+            if !fs::metadata(&path)?.is_dir() || is_exploded_module(&path) {
+                class_path_entries.push(ClassPathEntry::new(path.to_string_lossy()));
+                continue;
+            }
+
+            let mut modules = Vec::new();
+            for entry in fs::read_dir(&path)? {
+                let path = entry?.path();
+                let is_archive = path.is_file()
+                    && path
+                        .extension()
+                        .is_some_and(|extension| extension == "jar" || extension == "jmod");
+                if is_archive || is_exploded_module(&path) {
+                    modules.push(path);
+                }
+            }
+            modules.sort();
+            for module in modules {
+                class_path_entries.push(ClassPathEntry::new(module.to_string_lossy()));
+            }
+        }
+        Ok(ClassPath::new(class_path_entries))
+    }
+
+    /// Get the class path with the `Class-Path` manifest attributes of its jars resolved, as
+    /// `HotSpot` does for the application class path: the entries that a jar declares are inserted
+    /// after the jar, recursively, and an entry that is already on the class path is not repeated.
+    ///
+    /// # Errors
+    /// if the manifest of a jar cannot be read.
+    pub async fn with_manifest_class_path(&self) -> Result<Self> {
+        let mut visited = HashSet::new();
+        let mut class_path_entries = Vec::with_capacity(self.class_path.len());
+        for class_path_entry in self.iter() {
+            if !visited.insert(class_path_entry.key()) {
+                continue;
+            }
+            class_path_entries.push(class_path_entry.clone());
+            let manifest_class_path = class_path_entry
+                .resolve_manifest_class_path(&mut visited)
+                .await?;
+            class_path_entries.extend(manifest_class_path);
+        }
+        Ok(ClassPath::new(class_path_entries))
+    }
+
     /// Returns an iterator over the class path.
     pub fn iter(&self) -> impl Iterator<Item = &ClassPathEntry> {
         self.class_path.iter()
@@ -53,9 +122,40 @@ impl ClassPath {
         let name = name.as_ref();
 
         for class_path_entry in self.iter() {
-            if let Ok(class_file) = class_path_entry.read_class(name).await {
-                info!("load class {name} source: {}", class_path_entry.name());
-                return Ok(class_file);
+            match class_path_entry.read_class(name).await {
+                Ok(class_file) => {
+                    info!("load class {name} source: {}", class_path_entry.name());
+                    return Ok(class_file);
+                }
+                // A class that fails jar verification must not be loaded from a later entry
+                Err(error @ SecurityError(_)) => return Err(error),
+                Err(_) => {}
+            }
+        }
+
+        Err(ClassNotFound(name.to_string()))
+    }
+
+    /// Read a class from the class path without an async runtime.  Jars referenced by url are
+    /// skipped unless they have been downloaded by a previous async read.
+    ///
+    /// # Errors
+    /// if the class file is not found or cannot be read.
+    ///
+    /// # Panics
+    /// if a jar is read from within an async execution context.
+    #[instrument(level = "trace", fields(name = ?name.as_ref()), skip(self))]
+    pub fn read_class_blocking<S: AsRef<str>>(&self, name: S) -> Result<ClassFile> {
+        let name = name.as_ref();
+
+        for class_path_entry in self.iter() {
+            match class_path_entry.read_class_blocking(name) {
+                Ok(class_file) => {
+                    info!("load class {name} source: {}", class_path_entry.name());
+                    return Ok(class_file);
+                }
+                Err(error @ SecurityError(_)) => return Err(error),
+                Err(_) => {}
             }
         }
 
@@ -75,6 +175,129 @@ impl ClassPath {
         classes.sort();
         Ok(classes)
     }
+
+    /// Get the class names in the class path without an async runtime.
+    ///
+    /// # Errors
+    /// if the class names cannot be read or a jar is a url that has not been downloaded.
+    ///
+    /// # Panics
+    /// if a jar is read from within an async execution context.
+    pub fn class_names_blocking(&self) -> Result<Vec<String>> {
+        let mut classes = Vec::new();
+        for class_path_entry in self.iter() {
+            let class_names = class_path_entry.class_names_blocking()?;
+            classes.extend(class_names);
+        }
+        classes.sort();
+        Ok(classes)
+    }
+}
+
+/// Implementation of class path scanning.
+impl ClassPath {
+    /// Create an index of the names of the classes in the class path without reading the classes.
+    ///
+    /// # Errors
+    /// if the class names cannot be read.
+    pub async fn scan(&self) -> Result<ClassIndex> {
+        let mut index = ClassIndex::default();
+        for class_path_entry in self.iter() {
+            for class_name in class_path_entry.class_names().await? {
+                index.insert_name(class_name);
+            }
+        }
+        Ok(index)
+    }
+
+    /// Create an index of the names of the classes in the class path without an async runtime.
+    ///
+    /// # Errors
+    /// if the class names cannot be read or a jar is a url that has not been downloaded.
+    ///
+    /// # Panics
+    /// if a jar is read from within an async execution context.
+    pub fn scan_blocking(&self) -> Result<ClassIndex> {
+        let mut index = ClassIndex::default();
+        for class_path_entry in self.iter() {
+            for class_name in class_path_entry.class_names_blocking()? {
+                index.insert_name(class_name);
+            }
+        }
+        Ok(index)
+    }
+
+    /// Create an index of the classes in the class path that includes the header (access flags,
+    /// super class, interfaces and annotations) of each class.
+    ///
+    /// # Errors
+    /// if a class file cannot be read.
+    pub async fn scan_headers(&self) -> Result<ClassIndex> {
+        let mut index = ClassIndex::default();
+        for class_path_entry in self.iter() {
+            for header in class_path_entry.class_headers().await? {
+                index.insert_header(header);
+            }
+        }
+        Ok(index)
+    }
+
+    /// Create an index of the classes in the class path that includes the header of each class,
+    /// without an async runtime.
+    ///
+    /// # Errors
+    /// if a class file cannot be read or a jar is a url that has not been downloaded.
+    ///
+    /// # Panics
+    /// if a jar is read from within an async execution context.
+    pub fn scan_headers_blocking(&self) -> Result<ClassIndex> {
+        let mut index = ClassIndex::default();
+        for class_path_entry in self.iter() {
+            for header in class_path_entry.class_headers_blocking()? {
+                index.insert_header(header);
+            }
+        }
+        Ok(index)
+    }
+}
+
+/// Returns the directory of a class path wildcard, e.g. `lib` for `lib/*` and `.` for `*`, or
+/// `None` if the element is not a wildcard.
+fn wildcard_directory(path: &str) -> Option<&str> {
+    if path == "*" {
+        return Some(".");
+    }
+    path.strip_suffix("/*")
+        .or_else(|| path.strip_suffix("\\*"))
+        .filter(|directory| !directory.is_empty() && !directory.contains("!/"))
+}
+
+/// Returns the jars in a directory in name order; a jar is a file with a `jar` extension in any
+/// case, and hidden files are skipped.
+fn wildcard_jars(directory: &str) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return Vec::new();
+    };
+    let mut jars = entries
+        .filter_map(std::result::Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            let hidden = path
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+            let is_jar = path
+                .extension()
+                .is_some_and(|extension| extension.eq_ignore_ascii_case("jar"));
+            !hidden && is_jar && path.is_file()
+        })
+        .collect::<Vec<_>>();
+    jars.sort();
+    jars
+}
+
+/// Returns true if the path is a directory with a `module-info` class.
+fn is_exploded_module(path: &Path) -> bool {
+    path.join("module-info.class").is_file()
 }
 
 /// Into iterator for `ClassPath`.
@@ -103,6 +326,7 @@ impl Display for ClassPath {
 mod tests {
     use super::*;
     use crate::Result;
+    use std::io::Write;
     use std::path::PathBuf;
 
     #[test]
@@ -117,6 +341,75 @@ mod tests {
         assert_eq!(".:..", class_path.to_string());
     }
 
+    #[test]
+    fn test_from_wildcard() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let lib = temp_dir.path().join("lib");
+        fs::create_dir_all(lib.join("nested"))?;
+        for name in ["b.jar", "a.JAR", "c.txt", ".hidden.jar", "nested/d.jar"] {
+            fs::write(lib.join(name), [])?;
+        }
+
+        let lib = lib.to_string_lossy();
+        let class_path = ClassPath::from(format!("{lib}/*:."));
+        let names = class_path
+            .iter()
+            .map(|entry| entry.name().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                format!("{lib}/a.JAR"),
+                format!("{lib}/b.jar"),
+                ".".to_string()
+            ],
+            names
+        );
+        assert!(class_path
+            .iter()
+            .take(2)
+            .all(|entry| matches!(entry, ClassPathEntry::Jar(_))));
+
+        let missing = temp_dir.path().join("missing");
+        let class_path = ClassPath::from(format!("{}/*", missing.to_string_lossy()));
+        assert_eq!(0, class_path.iter().count());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_with_manifest_class_path() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let directory = temp_dir.path();
+        for (name, class_path) in [("app.jar", "a.jar b.jar"), ("a.jar", "b.jar app.jar")] {
+            let mut archive = zip::ZipWriter::new(fs::File::create(directory.join(name))?);
+            archive.start_file(
+                "META-INF/MANIFEST.MF",
+                zip::write::SimpleFileOptions::default(),
+            )?;
+            archive.write_all(format!("Class-Path: {class_path}\n").as_bytes())?;
+            archive.finish()?;
+        }
+        fs::copy(directory.join("a.jar"), directory.join("b.jar"))?;
+
+        let directory = directory.to_string_lossy();
+        let class_path = ClassPath::from(format!("{directory}/app.jar:{directory}/b.jar:."));
+        let class_path = class_path.with_manifest_class_path().await?;
+        assert_eq!(
+            format!("{directory}/app.jar:{directory}/a.jar:{directory}/b.jar:."),
+            class_path.to_string()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_wildcard_directory() {
+        assert_eq!(Some("."), wildcard_directory("*"));
+        assert_eq!(Some("lib"), wildcard_directory("lib/*"));
+        assert_eq!(Some("lib"), wildcard_directory("lib\\*"));
+        assert_eq!(None, wildcard_directory("lib"));
+        assert_eq!(None, wildcard_directory("lib/*.jar"));
+        assert_eq!(None, wildcard_directory("app.jar!/BOOT-INF/lib/*"));
+    }
+
     #[test]
     fn test_iter() {
         let class_path = ClassPath::from(".:..");
@@ -133,6 +426,50 @@ mod tests {
         assert_eq!("..", iter.next().expect("next").name());
     }
 
+    #[test]
+    fn test_from_module_path() -> Result<()> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_jar = cargo_manifest
+            .join("..")
+            .join("classes")
+            .join("classes.jar");
+        let temp_dir = tempfile::tempdir()?;
+        let modules = temp_dir.path().join("modules");
+        fs::create_dir(&modules)?;
+        fs::copy(&classes_jar, modules.join("b.jmod"))?;
+        fs::copy(&classes_jar, modules.join("a.jar"))?;
+        fs::write(modules.join("README.txt"), "not a module")?;
+        let exploded = modules.join("c");
+        fs::create_dir(&exploded)?;
+        fs::write(exploded.join("module-info.class"), [])?;
+
+        let module_path = format!(
+            "{}:{}",
+            modules.to_string_lossy(),
+            classes_jar.to_string_lossy()
+        );
+        let class_path = ClassPath::from_module_path(module_path)?;
+        let entries = class_path.iter().collect::<Vec<_>>();
+        assert_eq!(4, entries.len());
+        assert!(matches!(entries[0], ClassPathEntry::Jar(_)));
+        assert!(entries[0].name().ends_with("a.jar"));
+        assert!(matches!(entries[1], ClassPathEntry::Jmod(_)));
+        assert!(entries[1].name().ends_with("b.jmod"));
+        assert!(matches!(entries[2], ClassPathEntry::Directory(_)));
+        assert!(entries[2].name().ends_with('c'));
+        assert!(matches!(entries[3], ClassPathEntry::Jar(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_module_path_not_found() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let module_path = temp_dir.path().join("missing");
+        let result = ClassPath::from_module_path(module_path.to_string_lossy());
+        assert!(matches!(result, Err(crate::Error::IoError(_))));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_read_class() -> Result<()> {
         let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -168,6 +505,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_class_blocking() -> Result<()> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_directory = cargo_manifest.join("..").join("classes");
+        let classes_jar = cargo_manifest
+            .join("..")
+            .join("classes")
+            .join("classes.jar");
+        let class_path = ClassPath::from(format!(
+            "{}:{}",
+            classes_directory.to_string_lossy(),
+            classes_jar.to_string_lossy()
+        ));
+
+        let class_file = class_path.read_class_blocking("HelloWorld")?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+        let class_names = class_path.class_names_blocking()?;
+        assert!(class_names.contains(&"HelloWorld".to_string()));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_class_names() -> Result<()> {
         let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -196,4 +554,39 @@ mod tests {
         }
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_scan() -> Result<()> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_directory = cargo_manifest.join("..").join("classes");
+        let class_path = ClassPath::from(classes_directory.to_string_lossy());
+
+        let index = class_path.scan().await?;
+        assert!(index.contains("HelloWorld"));
+        assert_eq!(None, index.header("HelloWorld"));
+
+        let index = class_path.scan_headers().await?;
+        let header = index.header("HelloWorld").expect("HelloWorld header");
+        assert_eq!(Some("java/lang/Object"), header.super_class());
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_blocking() -> Result<()> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_directory = cargo_manifest.join("..").join("classes");
+        let classes_jar = classes_directory.join("classes.jar");
+        let class_path = ClassPath::from(format!(
+            "{}:{}",
+            classes_directory.to_string_lossy(),
+            classes_jar.to_string_lossy()
+        ));
+
+        let index = class_path.scan_blocking()?;
+        assert!(index.contains("HelloWorld"));
+        let index = class_path.scan_headers_blocking()?;
+        assert!(index.header("HelloWorld").is_some());
+        assert_eq!(vec!["HelloWorld"], index.glob("Hello*"));
+        Ok(())
+    }
 }
@@ -1,8 +1,10 @@
 use crate::Error::ClassNotFound;
-use crate::Result;
+use crate::{ClassHeader, Result};
 use ristretto_classfile::ClassFile;
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::path::PathBuf;
+use std::time::SystemTime;
 use std::{fs, io};
 use tracing::instrument;
 use walkdir::WalkDir;
@@ -46,32 +48,81 @@ impl Directory {
             return Err(ClassNotFound(name.to_string()));
         }
 
-        let bytes = fs::read(path)?;
-        let mut cursor = io::Cursor::new(bytes);
-        let class_file = ClassFile::from_bytes(&mut cursor)?;
+        let mut file = fs::File::open(path)?;
+        let class_file = ClassFile::from_reader(&mut file)?;
         Ok(class_file)
     }
 
+    /// Read a file from the directory; returns `None` if the file does not exist.
+    ///
+    /// # Errors
+    /// if the file cannot be read.
+    pub fn read_file<S: AsRef<str>>(&self, name: S) -> Result<Option<Vec<u8>>> {
+        let path = self.path.join(name.as_ref());
+        if !path.is_file() {
+            return Ok(None);
+        }
+        Ok(Some(fs::read(path)?))
+    }
+
     /// Get the class names in the directory.
+    pub fn class_names(&self) -> Vec<String> {
+        self.class_files()
+            .filter_map(|entry| self.class_name(&entry))
+            .collect()
+    }
+
+    /// Get the modification times of the class files in the directory, by class name.
     ///
     /// # Errors
-    /// if the class names cannot be read.
-    #[expect(clippy::unused_async)]
-    pub async fn class_names(&self) -> Result<Vec<String>> {
-        let path = self.path.clone();
-        let mut classes = Vec::new();
-        for entry in WalkDir::new(path)
+    /// if the metadata of a class file cannot be read.
+    pub fn class_modified_times(&self) -> Result<HashMap<String, SystemTime>> {
+        let mut modified_times = HashMap::new();
+        for entry in self.class_files() {
+            let Some(class_name) = self.class_name(&entry) else {
+                continue;
+            };
+            let modified = entry.metadata().map_err(io::Error::from)?.modified()?;
+            modified_times.insert(class_name, modified);
+        }
+        Ok(modified_times)
+    }
+
+    /// Get the class name of a class file in the directory.
+    fn class_name(&self, entry: &walkdir::DirEntry) -> Option<String> {
+        let relative_path = entry.path().strip_prefix(&self.path).ok()?;
+        let class_name = relative_path
+            .with_extension("")
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        Some(class_name)
+    }
+
+    /// Get the headers of the classes in the directory.
+    ///
+    /// # Errors
+    /// if a class file cannot be read.
+    pub fn class_headers(&self) -> Result<Vec<ClassHeader>> {
+        let mut headers = Vec::new();
+        for entry in self.class_files() {
+            let bytes = fs::read(entry.path())?;
+            headers.push(ClassHeader::from_bytes(bytes)?);
+        }
+        Ok(headers)
+    }
+
+    /// Get the class files in the directory and its subdirectories.
+    fn class_files(&self) -> impl Iterator<Item = walkdir::DirEntry> {
+        WalkDir::new(&self.path)
             .follow_links(true)
             .into_iter()
             .filter_map(Result::ok)
-        {
-            let file_name = entry.file_name().to_string_lossy();
-            if file_name.ends_with(".class") {
-                let class_name = file_name.replace(".class", "");
-                classes.push(class_name);
-            }
-        }
-        Ok(classes)
+            .filter(|entry| {
+                entry.file_type().is_file()
+                    && entry.file_name().to_string_lossy().ends_with(".class")
+            })
     }
 }
 
@@ -136,13 +187,49 @@ mod tests {
         assert!(matches!(result, Err(ClassNotFound(_))));
     }
 
-    #[tokio::test]
-    async fn test_class_names() -> Result<()> {
+    #[test]
+    fn test_class_names() {
         let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
         let classes_directory = cargo_manifest.join("..").join("classes");
         let directory = Directory::new(classes_directory.to_string_lossy());
-        let class_names = directory.class_names().await?;
+        let class_names = directory.class_names();
         assert!(class_names.contains(&"HelloWorld".to_string()));
+    }
+
+    #[test]
+    fn test_class_names_packages() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let package = temp_dir.path().join("com").join("example");
+        fs::create_dir_all(&package)?;
+        fs::write(package.join("Foo.class"), [])?;
+        let directory = Directory::new(temp_dir.path().to_string_lossy());
+        assert_eq!(vec!["com/example/Foo".to_string()], directory.class_names());
+        Ok(())
+    }
+
+    #[test]
+    fn test_class_modified_times() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let package = temp_dir.path().join("com").join("example");
+        fs::create_dir_all(&package)?;
+        fs::write(package.join("Foo.class"), [])?;
+        let directory = Directory::new(temp_dir.path().to_string_lossy());
+        let modified_times = directory.class_modified_times()?;
+        assert_eq!(
+            Some(&fs::metadata(package.join("Foo.class"))?.modified()?),
+            modified_times.get("com/example/Foo")
+        );
+        assert_eq!(1, modified_times.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_class_headers() -> Result<()> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_directory = cargo_manifest.join("..").join("classes");
+        let directory = Directory::new(classes_directory.to_string_lossy());
+        let headers = directory.class_headers()?;
+        assert!(headers.iter().any(|header| header.name() == "HelloWorld"));
         Ok(())
     }
 }
@@ -1,16 +1,22 @@
-use crate::class_path_entry::manifest::Manifest;
+use crate::class_path_entry::manifest::{
+    Manifest, CLASS_PATH, SPRING_BOOT_CLASSES, SPRING_BOOT_CLASSPATH_INDEX, SPRING_BOOT_LIB,
+};
+#[cfg(feature = "signed-jars")]
+use crate::class_path_entry::signature::{CodeSigner, JarSignatures};
+use crate::class_path_entry::zip_archive::{ReadAt, ZipArchive};
+use crate::class_path_entry::ClassPathEntry;
 use crate::Error::{ArchiveError, ClassNotFound, FileNotFound, ParseError};
-use crate::Result;
+use crate::{ClassHeader, Result};
 use reqwest::Client;
-use ristretto_classfile::ClassFile;
+use ristretto_classfile::{ClassAccessFlags, ClassFile};
 use std::fmt::Debug;
-use std::path::PathBuf;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use std::{fs, io};
-use tokio::sync::RwLock;
+use tokio::sync::{RwLock, RwLockReadGuard};
 use tracing::instrument;
-use zip::ZipArchive;
 
 /// A jar or zip in the class path.
 /// See: <https://docs.oracle.com/en/java/javase/22/docs/specs/jar/jar.html>
@@ -18,6 +24,7 @@ use zip::ZipArchive;
 pub struct Jar {
     name: String,
     archive: Arc<RwLock<Archive>>,
+    prefix: String,
 }
 
 /// Implement the `Jar` struct.
@@ -30,6 +37,7 @@ impl Jar {
         Self {
             name: path.to_string(),
             archive: Arc::new(RwLock::new(archive)),
+            prefix: String::new(),
         }
     }
 
@@ -42,6 +50,7 @@ impl Jar {
         Self {
             name: url.to_string(),
             archive: Arc::new(RwLock::new(archive)),
+            prefix: String::new(),
         }
     }
 
@@ -52,6 +61,32 @@ impl Jar {
         Self {
             name: name.as_ref().to_string(),
             archive: Arc::new(RwLock::new(archive)),
+            prefix: String::new(),
+        }
+    }
+
+    /// Create a jar from an entry of this jar, e.g. `BOOT-INF/lib/foo.jar`; the name of the jar is
+    /// `<name>!/<entry>`.  The nested jar is read from this jar without being extracted, and a
+    /// nested jar that is stored without compression shares the bytes of this jar.  An entry that
+    /// ends with `/` is a directory of this jar that classes are read from, e.g.
+    /// `BOOT-INF/classes/`, and an empty entry is the root directory of this jar.
+    pub fn nested<S: AsRef<str>>(&self, entry: S) -> Self {
+        let entry = entry.as_ref();
+        let name = format!("{}!/{entry}", self.name);
+        let entry = format!("{}{entry}", self.prefix);
+        if entry.is_empty() || entry.ends_with('/') {
+            return Self {
+                name,
+                archive: Arc::clone(&self.archive),
+                prefix: entry,
+            };
+        }
+
+        let archive = Archive::from_parent(Arc::clone(&self.archive), entry);
+        Self {
+            name,
+            archive: Arc::new(RwLock::new(archive)),
+            prefix: String::new(),
         }
     }
 
@@ -65,13 +100,110 @@ impl Jar {
     /// # Errors
     /// if the manifest cannot be read.
     pub async fn manifest(&self) -> Result<Manifest> {
-        let file_name = "META-INF/MANIFEST.MF";
-        let Some(file) = self.read_file(file_name).await? else {
-            return Err(FileNotFound(file_name.to_string()));
+        read_archive(&self.archive).await?.manifest()
+    }
+
+    /// Get the manifest of the jar without an async runtime.
+    ///
+    /// # Errors
+    /// if the manifest cannot be read or the jar is a url that has not been downloaded.
+    ///
+    /// # Panics
+    /// if called from within an async execution context.
+    pub fn manifest_blocking(&self) -> Result<Manifest> {
+        read_archive_blocking(&self.archive)?.manifest()
+    }
+
+    /// Get the nested class path of a Spring Boot executable jar; the class path is the classes
+    /// directory followed by the jars of the lib directory, in the order of the class path index
+    /// if the jar has one.  Jars whose manifest does not declare a classes or lib directory do not
+    /// have a nested class path.
+    ///
+    /// # Errors
+    /// if the class path index or the entries of the jar cannot be read.
+    pub async fn nested_class_path(&self, manifest: &Manifest) -> Result<Vec<ClassPathEntry>> {
+        let entries = read_archive(&self.archive)
+            .await?
+            .nested_class_path(&self.prefix, manifest)?;
+        let class_path = entries
+            .into_iter()
+            .map(|entry| ClassPathEntry::NestedJar(self.nested(entry)))
+            .collect();
+        Ok(class_path)
+    }
+
+    /// Get the class path that the `Class-Path` attribute of the manifest declares, resolved
+    /// relative to the directory of the jar.  The attribute is a space separated list of relative
+    /// or `file:` urls; entries that do not exist are skipped, and nested jars and jars without a
+    /// manifest do not declare a class path.
+    ///
+    /// # Errors
+    /// if the manifest cannot be read.
+    pub async fn manifest_class_path(&self) -> Result<Vec<String>> {
+        if self.is_nested() {
+            return Ok(Vec::new());
+        }
+        let manifest = match self.manifest().await {
+            Ok(manifest) => manifest,
+            Err(FileNotFound(_)) => return Ok(Vec::new()),
+            Err(error) => return Err(error),
         };
-        let file = String::from_utf8(file).map_err(|error| ParseError(error.to_string()))?;
-        let manifest = Manifest::from_str(file.as_str())?;
-        Ok(manifest)
+        let Some(class_path) = manifest.attribute(CLASS_PATH) else {
+            return Ok(Vec::new());
+        };
+
+        let mut entries = Vec::new();
+        for url in class_path.split_whitespace() {
+            let url = decode_url(url);
+            if self.name.starts_with("https://") || self.name.starts_with("http://") {
+                if url.contains("://") {
+                    entries.push(url);
+                } else if let Some((base, _)) = self.name.rsplit_once('/') {
+                    entries.push(format!("{base}/{url}"));
+                }
+                continue;
+            }
+
+            let path = url.strip_prefix("file:").unwrap_or(&url);
+            let path = if path.starts_with("///") {
+                &path[2..]
+            } else {
+                path
+            };
+            let path = Path::new(&self.name)
+                .parent()
+                .unwrap_or(Path::new(""))
+                .join(path);
+            if path.exists() {
+                entries.push(path.to_string_lossy().to_string());
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Returns true if the jar is inside another jar.
+    fn is_nested(&self) -> bool {
+        self.name.contains("!/")
+    }
+
+    /// Get the signers of a file in the jar, e.g. `com/example/Foo.class`; files that are not
+    /// signed have no signers.  The signatures of a signed jar are verified when the jar is first
+    /// read, and every file that is read afterwards is checked against its manifest digest.
+    ///
+    /// # Errors
+    /// if the signatures of the jar cannot be read or verified.
+    #[cfg(feature = "signed-jars")]
+    pub async fn signers<S: AsRef<str>>(&self, name: S) -> Result<Vec<CodeSigner>> {
+        let name = format!("{}{}", self.prefix, name.as_ref());
+        read_archive(&self.archive).await?.signers(&name)
+    }
+
+    /// Get the names of the files in the jar; directories are excluded.
+    ///
+    /// # Errors
+    /// if the file names cannot be read.
+    pub async fn file_names(&self) -> Result<Vec<String>> {
+        read_archive(&self.archive).await?.file_names(&self.prefix)
     }
 
     /// Read a file from the jar.
@@ -80,8 +212,21 @@ impl Jar {
     /// if the file is not found or cannot be read.
     #[instrument(level = "trace", fields(name = ?name.as_ref()), skip(self))]
     pub async fn read_file<S: AsRef<str>>(&self, name: S) -> Result<Option<Vec<u8>>> {
-        let mut archive = self.archive.write().await;
-        archive.load_file(name.as_ref()).await
+        let name = format!("{}{}", self.prefix, name.as_ref());
+        read_archive(&self.archive).await?.load_file(&name)
+    }
+
+    /// Read a file from the jar without an async runtime.
+    ///
+    /// # Errors
+    /// if the file cannot be read or the jar is a url that has not been downloaded.
+    ///
+    /// # Panics
+    /// if called from within an async execution context.
+    #[instrument(level = "trace", fields(name = ?name.as_ref()), skip(self))]
+    pub fn read_file_blocking<S: AsRef<str>>(&self, name: S) -> Result<Option<Vec<u8>>> {
+        let name = format!("{}{}", self.prefix, name.as_ref());
+        read_archive_blocking(&self.archive)?.load_file(&name)
     }
 
     /// Read a class from the jar.
@@ -90,18 +235,22 @@ impl Jar {
     /// if the class file is not found or cannot be read.
     #[instrument(level = "trace", fields(name = ?name.as_ref()), skip(self))]
     pub async fn read_class<S: AsRef<str>>(&self, name: S) -> Result<ClassFile> {
-        let name = name.as_ref();
-        let mut archive = self.archive.write().await;
-        let class_file = if archive.is_module().await? {
-            let name = format!("classes/{name}");
-            archive.load_class_file(name.as_str()).await?
-        } else {
-            archive.load_class_file(name).await?
-        };
-        let Some(class_file) = class_file else {
-            return Err(ClassNotFound(name.to_string()));
-        };
-        Ok(class_file)
+        read_archive(&self.archive)
+            .await?
+            .read_class(&self.prefix, name.as_ref())
+    }
+
+    /// Read a class from the jar without an async runtime.
+    ///
+    /// # Errors
+    /// if the class file is not found, cannot be read or the jar is a url that has not been
+    /// downloaded.
+    ///
+    /// # Panics
+    /// if called from within an async execution context.
+    #[instrument(level = "trace", fields(name = ?name.as_ref()), skip(self))]
+    pub fn read_class_blocking<S: AsRef<str>>(&self, name: S) -> Result<ClassFile> {
+        read_archive_blocking(&self.archive)?.read_class(&self.prefix, name.as_ref())
     }
 
     /// Get the class names in the jar.
@@ -109,26 +258,79 @@ impl Jar {
     /// # Errors
     /// if the class names cannot be read.
     pub async fn class_names(&self) -> Result<Vec<String>> {
-        let mut archive = self.archive.write().await;
-        let is_module = archive.is_module().await?;
-        let zip_archive = archive.zip_archive().await?;
-        let mut classes = Vec::new();
-        for i in 0..zip_archive.len() {
-            let file = zip_archive.by_index(i)?;
-            let file_name = file.name();
-            if file_name.ends_with("class") {
-                let mut class_name = file_name.replace(".class", "");
-                if is_module {
-                    class_name = class_name
-                        .strip_prefix("classes/")
-                        .unwrap_or_default()
-                        .to_string();
-                }
-                classes.push(class_name);
-            }
-        }
-        Ok(classes)
+        read_archive(&self.archive).await?.class_names(&self.prefix)
+    }
+
+    /// Get the class names in the jar without an async runtime.
+    ///
+    /// # Errors
+    /// if the class names cannot be read or the jar is a url that has not been downloaded.
+    ///
+    /// # Panics
+    /// if called from within an async execution context.
+    pub fn class_names_blocking(&self) -> Result<Vec<String>> {
+        read_archive_blocking(&self.archive)?.class_names(&self.prefix)
+    }
+
+    /// Get the headers of the classes in the jar.
+    ///
+    /// # Errors
+    /// if a class file cannot be read.
+    pub async fn class_headers(&self) -> Result<Vec<ClassHeader>> {
+        read_archive(&self.archive)
+            .await?
+            .class_headers(&self.prefix)
     }
+
+    /// Get the headers of the classes in the jar without an async runtime.
+    ///
+    /// # Errors
+    /// if a class file cannot be read or the jar is a url that has not been downloaded.
+    ///
+    /// # Panics
+    /// if called from within an async execution context.
+    pub fn class_headers_blocking(&self) -> Result<Vec<ClassHeader>> {
+        read_archive_blocking(&self.archive)?.class_headers(&self.prefix)
+    }
+}
+
+/// Get a shared reference to an archive, downloading and opening the archive first if it has not
+/// been opened.  Entries are read through the shared reference, so an archive can be read by
+/// multiple tasks at once.
+///
+/// # Errors
+/// if the archive cannot be downloaded or opened.
+async fn read_archive(archive: &RwLock<Archive>) -> Result<RwLockReadGuard<'_, Archive>> {
+    let reader = archive.read().await;
+    if reader.zip_archive.is_some() {
+        return Ok(reader);
+    }
+    drop(reader);
+
+    let mut writer = archive.write().await;
+    writer.download().await?;
+    writer.open()?;
+    Ok(writer.downgrade())
+}
+
+/// Get a shared reference to an archive without an async runtime, opening the archive first if it
+/// has not been opened.
+///
+/// # Errors
+/// if the archive cannot be opened or is a url that has not been downloaded.
+///
+/// # Panics
+/// if called from within an async execution context.
+fn read_archive_blocking(archive: &RwLock<Archive>) -> Result<RwLockReadGuard<'_, Archive>> {
+    let reader = archive.blocking_read();
+    if reader.zip_archive.is_some() {
+        return Ok(reader);
+    }
+    drop(reader);
+
+    let mut writer = archive.blocking_write();
+    writer.open()?;
+    Ok(writer.downgrade())
 }
 
 /// Implement the `PartialEq` trait for `Jar`.
@@ -139,15 +341,123 @@ impl PartialEq for Jar {
     }
 }
 
+/// The bytes of an archive; a nested archive that is stored without compression is a range of the
+/// bytes of its parent archive.
+#[derive(Clone, Debug)]
+struct ArchiveBytes {
+    bytes: Arc<Vec<u8>>,
+    range: Range<usize>,
+}
+
+/// Implement the `ArchiveBytes` struct.
+impl ArchiveBytes {
+    /// Create new archive bytes.
+    fn new(bytes: Vec<u8>) -> Self {
+        let range = 0..bytes.len();
+        Self {
+            bytes: Arc::new(bytes),
+            range,
+        }
+    }
+
+    /// Get a range of the bytes without copying them.
+    fn slice(&self, range: Range<usize>) -> Self {
+        let start = self.range.start + range.start;
+        let end = self.range.start + range.end;
+        Self {
+            bytes: Arc::clone(&self.bytes),
+            range: start..end,
+        }
+    }
+}
+
+impl AsRef<[u8]> for ArchiveBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes[self.range.clone()]
+    }
+}
+
+/// The reader of an archive.  An archive that is read from a path is not read into memory; only
+/// the central directory is read when the archive is opened, and entries are read from the file
+/// at their positions when they are loaded.
+#[derive(Debug)]
+enum ArchiveReader {
+    Bytes(ArchiveBytes),
+    File(fs::File),
+}
+
+impl ReadAt for ArchiveReader {
+    fn length(&self) -> io::Result<u64> {
+        match self {
+            ArchiveReader::Bytes(bytes) => bytes.length(),
+            ArchiveReader::File(file) => Ok(file.metadata()?.len()),
+        }
+    }
+
+    fn read_exact_at(&self, buffer: &mut [u8], position: u64) -> io::Result<()> {
+        match self {
+            ArchiveReader::Bytes(bytes) => bytes.read_exact_at(buffer, position),
+            ArchiveReader::File(file) => read_file_at(file, buffer, position),
+        }
+    }
+}
+
+/// Read the exact number of bytes required to fill the buffer at a position of a file, without
+/// moving the cursor of the file.
+///
+/// # Errors
+/// if the bytes cannot be read.
+#[cfg(unix)]
+fn read_file_at(file: &fs::File, buffer: &mut [u8], position: u64) -> io::Result<()> {
+    std::os::unix::fs::FileExt::read_exact_at(file, buffer, position)
+}
+
+/// Read the exact number of bytes required to fill the buffer at a position of a file, without
+/// moving the cursor of the file.
+///
+/// # Errors
+/// if the bytes cannot be read.
+#[cfg(windows)]
+fn read_file_at(file: &fs::File, mut buffer: &mut [u8], mut position: u64) -> io::Result<()> {
+    use std::os::windows::fs::FileExt;
+    while !buffer.is_empty() {
+        match file.seek_read(buffer, position) {
+            Ok(0) => return Err(io::Error::from(io::ErrorKind::UnexpectedEof)),
+            Ok(length) => {
+                buffer = &mut buffer[length..];
+                position += length as u64;
+            }
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(())
+}
+
+/// Read the exact number of bytes required to fill the buffer at a position of a file; targets
+/// without positional reads seek the file, and are single threaded.
+///
+/// # Errors
+/// if the bytes cannot be read.
+#[cfg(not(any(unix, windows)))]
+fn read_file_at(mut file: &fs::File, buffer: &mut [u8], position: u64) -> io::Result<()> {
+    use std::io::{Read, Seek, SeekFrom};
+    file.seek(SeekFrom::Start(position))?;
+    file.read_exact(buffer)
+}
+
 /// The source of the archive.
 #[expect(clippy::struct_field_names)]
 #[derive(Debug)]
 struct Archive {
     path: Option<PathBuf>,
     url: Option<String>,
-    bytes: Option<Arc<Vec<u8>>>,
-    zip_archive: Option<ZipArchive<io::Cursor<Vec<u8>>>>,
-    is_module: Option<bool>,
+    parent: Option<(Arc<RwLock<Archive>>, String)>,
+    bytes: Option<ArchiveBytes>,
+    zip_archive: Option<ZipArchive<ArchiveReader>>,
+    is_module: OnceLock<bool>,
+    #[cfg(feature = "signed-jars")]
+    signatures: OnceLock<Option<JarSignatures>>,
 }
 
 /// Implement the `Archive` enum.
@@ -158,9 +468,12 @@ impl Archive {
         Self {
             path,
             url: None,
+            parent: None,
             bytes: None,
             zip_archive: None,
-            is_module: None,
+            is_module: OnceLock::new(),
+            #[cfg(feature = "signed-jars")]
+            signatures: OnceLock::new(),
         }
     }
 
@@ -170,9 +483,12 @@ impl Archive {
         Self {
             path: None,
             url: Some(url),
+            parent: None,
             bytes: None,
             zip_archive: None,
-            is_module: None,
+            is_module: OnceLock::new(),
+            #[cfg(feature = "signed-jars")]
+            signatures: OnceLock::new(),
         }
     }
 
@@ -184,94 +500,350 @@ impl Archive {
         Self {
             path: None,
             url: None,
-            bytes: Some(Arc::new(bytes)),
+            parent: None,
+            bytes: Some(ArchiveBytes::new(bytes)),
             zip_archive: None,
-            is_module: None,
+            is_module: OnceLock::new(),
+            #[cfg(feature = "signed-jars")]
+            signatures: OnceLock::new(),
         }
     }
 
-    /// Create a new archive source from a path.
+    /// Create a new archive source from an entry of a parent archive.
+    fn from_parent(parent: Arc<RwLock<Archive>>, entry: String) -> Self {
+        Self {
+            path: None,
+            url: None,
+            parent: Some((parent, entry)),
+            bytes: None,
+            zip_archive: None,
+            is_module: OnceLock::new(),
+            #[cfg(feature = "signed-jars")]
+            signatures: OnceLock::new(),
+        }
+    }
+
+    /// Download the archive if the source is a url, or read it from its parent archive if the
+    /// source is an entry of a parent archive; other sources are read on demand.
     ///
     /// # Errors
-    /// if the archive cannot be read.
-    async fn zip_archive(&mut self) -> Result<&mut ZipArchive<io::Cursor<Vec<u8>>>> {
-        if let Some(ref mut zip_archive) = self.zip_archive {
-            return Ok(zip_archive);
+    /// if the archive cannot be downloaded.
+    async fn download(&mut self) -> Result<()> {
+        if self.zip_archive.is_some() || self.bytes.is_some() {
+            return Ok(());
         }
-
-        if let Some(path) = &self.path {
-            let bytes = fs::read(path)?;
-            let cursor = io::Cursor::new(bytes);
-            let archive = ZipArchive::new(cursor)?;
-            self.zip_archive = Some(archive);
-        } else if let Some(url) = &self.url {
+        if let Some(url) = &self.url {
             let client = Client::new();
             let bytes = client.get(url).send().await?.bytes().await?.to_vec();
-            let cursor = io::Cursor::new(bytes);
-            let archive = ZipArchive::new(cursor)?;
-            self.zip_archive = Some(archive);
-        } else if let Some(bytes) = &self.bytes {
-            let bytes = bytes.to_vec();
-            let cursor = io::Cursor::new(bytes);
-            let archive = ZipArchive::new(cursor)?;
-            self.bytes = None;
-            self.zip_archive = Some(archive);
-        }
-
-        if let Some(ref mut zip_archive) = self.zip_archive {
-            Ok(zip_archive)
+            self.bytes = Some(ArchiveBytes::new(bytes));
+        } else if let Some((parent, entry)) = &self.parent {
+            let parent = Box::pin(read_archive(parent)).await?;
+            self.bytes = Some(parent.entry_bytes(entry)?);
+        }
+        Ok(())
+    }
+
+    /// Open the zip archive from the path, bytes or parent archive if it has not been opened.
+    /// Opening the archive reads the central directory, which records the offset of every entry;
+    /// entries are read when they are loaded.
+    ///
+    /// # Errors
+    /// if the archive cannot be read or is a url that has not been downloaded.
+    fn open(&mut self) -> Result<()> {
+        if self.zip_archive.is_some() {
+            return Ok(());
+        }
+
+        if let (None, Some((parent, entry))) = (&self.bytes, &self.parent) {
+            let bytes = read_archive_blocking(parent)?.entry_bytes(entry)?;
+            self.bytes = Some(bytes);
+        }
+
+        if let Some(bytes) = &self.bytes {
+            let reader = ArchiveReader::Bytes(bytes.clone());
+            self.zip_archive = Some(ZipArchive::new(reader)?);
+        } else if let Some(path) = &self.path {
+            let reader = ArchiveReader::File(fs::File::open(path)?);
+            self.zip_archive = Some(ZipArchive::new(reader)?);
+        } else if let Some(url) = &self.url {
+            return Err(ArchiveError(format!(
+                "Archive has not been downloaded: {url}"
+            )));
         } else {
-            Err(ArchiveError("No archive source provided".to_string()))
+            return Err(ArchiveError("No archive source provided".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Get the zip archive.
+    ///
+    /// # Errors
+    /// if the archive has not been opened.
+    fn zip_archive(&self) -> Result<&ZipArchive<ArchiveReader>> {
+        self.zip_archive
+            .as_ref()
+            .ok_or_else(|| ArchiveError("Archive has not been opened".to_string()))
+    }
+
+    /// Get the bytes of an entry of the archive; the bytes of an entry that is stored without
+    /// compression are shared with the archive instead of being copied if the archive is in
+    /// memory.
+    ///
+    /// # Errors
+    /// if the entry is not found, cannot be read or fails its checksum.
+    fn entry_bytes(&self, name: &str) -> Result<ArchiveBytes> {
+        if let Some(bytes) = &self.bytes {
+            let zip_archive = self.zip_archive()?;
+            if let Some(range) = zip_archive.stored_range(name)? {
+                let start = usize::try_from(range.start)?;
+                let end = usize::try_from(range.end)?;
+                let entry_bytes = bytes.slice(start..end);
+                zip_archive.verify_checksum(name, entry_bytes.as_ref())?;
+                return Ok(entry_bytes);
+            }
+        }
+
+        let Some(bytes) = self.zip_archive()?.read_file(name)? else {
+            return Err(FileNotFound(name.to_string()));
+        };
+        Ok(ArchiveBytes::new(bytes))
+    }
+
+    /// Get the manifest of the archive.
+    ///
+    /// # Errors
+    /// if the manifest cannot be read.
+    fn manifest(&self) -> Result<Manifest> {
+        let file_name = "META-INF/MANIFEST.MF";
+        let Some(file) = self.read_entry(file_name)? else {
+            return Err(FileNotFound(file_name.to_string()));
+        };
+        let file = String::from_utf8(file).map_err(|error| ParseError(error.to_string()))?;
+        let manifest = Manifest::from_str(file.as_str())?;
+        Ok(manifest)
+    }
+
+    /// Get the entries of the nested class path that a Spring Boot manifest declares, relative to
+    /// the prefix.
+    ///
+    /// # Errors
+    /// if the class path index or the entries of the archive cannot be read.
+    fn nested_class_path(&self, prefix: &str, manifest: &Manifest) -> Result<Vec<String>> {
+        let mut class_path = Vec::new();
+        if let Some(classes) = manifest.attribute(SPRING_BOOT_CLASSES) {
+            class_path.push(directory(classes));
         }
+        let Some(lib) = manifest.attribute(SPRING_BOOT_LIB).map(directory) else {
+            return Ok(class_path);
+        };
+
+        let index = match manifest.attribute(SPRING_BOOT_CLASSPATH_INDEX) {
+            Some(index) => self.load_file(&format!("{prefix}{index}"))?,
+            None => None,
+        };
+        if let Some(index) = index {
+            let index = String::from_utf8(index).map_err(|error| ParseError(error.to_string()))?;
+            for line in index.lines() {
+                let Some(entry) = line.trim().strip_prefix('-') else {
+                    continue;
+                };
+                let entry = entry.trim().trim_matches('"');
+                if entry.starts_with(&lib) {
+                    class_path.push(entry.to_string());
+                } else {
+                    class_path.push(format!("{lib}{entry}"));
+                }
+            }
+            return Ok(class_path);
+        }
+
+        let lib_prefix = format!("{prefix}{lib}");
+        let mut jars = Vec::new();
+        for file_name in self.zip_archive()?.file_names() {
+            let Some(jar_name) = file_name.strip_prefix(&lib_prefix) else {
+                continue;
+            };
+            if !jar_name.contains('/')
+                && Path::new(jar_name)
+                    .extension()
+                    .is_some_and(|extension| extension == "jar")
+            {
+                jars.push(format!("{lib}{jar_name}"));
+            }
+        }
+        jars.sort();
+        class_path.extend(jars);
+        Ok(class_path)
     }
 
+    /// Get the names of the files in the archive, or in the prefix directory if there is one,
+    /// relative to the prefix.
+    ///
+    /// # Errors
+    /// if the archive cannot be read.
+    fn file_names(&self, prefix: &str) -> Result<Vec<String>> {
+        let file_names = self
+            .zip_archive()?
+            .file_names()
+            .filter(|file_name| !file_name.ends_with('/'))
+            .filter_map(|file_name| file_name.strip_prefix(prefix))
+            .map(ToString::to_string)
+            .collect();
+        Ok(file_names)
+    }
+
+    /// Read a class from the archive; classes are read from the prefix directory if there is one,
+    /// otherwise classes of modules are read from the `classes` directory.
+    ///
+    /// # Errors
+    /// if the class file is not found or cannot be read.
+    fn read_class(&self, prefix: &str, name: &str) -> Result<ClassFile> {
+        let class_file = if !prefix.is_empty() {
+            let name = format!("{prefix}{name}");
+            self.load_class_file(name.as_str())?
+        } else if self.is_module()? {
+            let name = format!("classes/{name}");
+            self.load_class_file(name.as_str())?
+        } else {
+            self.load_class_file(name)?
+        };
+        let Some(class_file) = class_file else {
+            return Err(ClassNotFound(name.to_string()));
+        };
+        Ok(class_file)
+    }
+
+    /// Get the class names in the archive, or in the prefix directory if there is one.
+    ///
+    /// # Errors
+    /// if the class names cannot be read.
+    fn class_names(&self, prefix: &str) -> Result<Vec<String>> {
+        let prefix = if prefix.is_empty() && self.is_module()? {
+            "classes/"
+        } else {
+            prefix
+        };
+        let zip_archive = self.zip_archive()?;
+        let mut classes = Vec::new();
+        for file_name in zip_archive.file_names() {
+            if file_name.ends_with("class") {
+                if let Some(class_name) = file_name.strip_prefix(prefix) {
+                    classes.push(class_name.replace(".class", ""));
+                }
+            }
+        }
+        Ok(classes)
+    }
+
+    /// Get the headers of the classes in the archive, or in the prefix directory if there is one;
+    /// module descriptors are excluded.
+    ///
+    /// # Errors
+    /// if a class file cannot be read.
+    fn class_headers(&self, prefix: &str) -> Result<Vec<ClassHeader>> {
+        let zip_archive = self.zip_archive()?;
+        let file_names = zip_archive
+            .file_names()
+            .filter(|file_name| {
+                file_name.starts_with(prefix)
+                    && Path::new(file_name)
+                        .extension()
+                        .is_some_and(|extension| extension == "class")
+            })
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        let mut headers = Vec::new();
+        for file_name in file_names {
+            let Some(bytes) = zip_archive.read_file(&file_name)? else {
+                continue;
+            };
+            let header = ClassHeader::from_bytes(bytes)?;
+            if !header.access_flags().contains(ClassAccessFlags::MODULE) {
+                headers.push(header);
+            }
+        }
+        Ok(headers)
+    }
     /// Load class file from a jar.
     ///
     /// # Errors
     /// if the jar cannot be read or the class file cannot be loaded.
     #[instrument(level = "trace")]
-    async fn load_class_file(&mut self, class_name: &str) -> Result<Option<ClassFile>> {
+    fn load_class_file(&self, class_name: &str) -> Result<Option<ClassFile>> {
         let class_file_name = format!("{class_name}.class");
-        let file = self.load_file(&class_file_name).await?;
-        if let Some(bytes) = file {
-            let mut cursor = io::Cursor::new(bytes);
-            let class_file = ClassFile::from_bytes(&mut cursor)?;
-            class_file.verify()?;
-            return Ok(Some(class_file));
-        }
-        Ok(None)
+        let Some(bytes) = self.load_file(&class_file_name)? else {
+            return Ok(None);
+        };
+        let class_file = ClassFile::from_bytes(&mut io::Cursor::new(bytes))?;
+        class_file.verify()?;
+        Ok(Some(class_file))
     }
 
-    /// Load file from a jar.
+    /// Load file from a jar; the file is verified against its manifest digest if the jar is
+    /// signed.
     ///
     /// # Errors
-    /// if the jar cannot be read or the class file cannot be loaded.
+    /// if the jar cannot be read, the file cannot be loaded or the file fails verification.
     #[instrument(level = "trace")]
-    async fn load_file(&mut self, file_name: &str) -> Result<Option<Vec<u8>>> {
-        let zip_archive = self.zip_archive().await?;
-        if let Some(index) = zip_archive.index_for_name(file_name) {
-            let mut file = zip_archive.by_index(index)?;
-            let file_size = usize::try_from(file.size())?;
-            let mut bytes = Vec::with_capacity(file_size);
-            io::copy(&mut file, &mut bytes)?;
-            return Ok(Some(bytes));
+    fn load_file(&self, file_name: &str) -> Result<Option<Vec<u8>>> {
+        let bytes = self.read_entry(file_name)?;
+        #[cfg(feature = "signed-jars")]
+        if let (Some(bytes), Some(signatures)) = (&bytes, self.signatures()?) {
+            signatures.verify(file_name, bytes)?;
+        }
+        Ok(bytes)
+    }
+
+    /// Get the signatures of the archive, reading them on first use; returns `None` if the archive
+    /// is not signed.
+    ///
+    /// # Errors
+    /// if the signatures cannot be read or verified.
+    #[cfg(feature = "signed-jars")]
+    fn signatures(&self) -> Result<Option<&JarSignatures>> {
+        if self.signatures.get().is_none() {
+            let file_names = self
+                .zip_archive()?
+                .file_names()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+            let signatures = JarSignatures::read(&file_names, |name| self.read_entry(name))?;
+            let _ = self.signatures.set(signatures);
         }
-        Ok(None)
+        Ok(self.signatures.get().and_then(Option::as_ref))
+    }
+
+    /// Get the signers of a file in the archive.
+    ///
+    /// # Errors
+    /// if the signatures cannot be read or verified.
+    #[cfg(feature = "signed-jars")]
+    fn signers(&self, file_name: &str) -> Result<Vec<CodeSigner>> {
+        let signers = self
+            .signatures()?
+            .map(|signatures| signatures.signers(file_name))
+            .unwrap_or_default();
+        Ok(signers)
+    }
+
+    /// Read an entry of the archive without verifying it.
+    ///
+    /// # Errors
+    /// if the archive or the entry cannot be read.
+    fn read_entry(&self, file_name: &str) -> Result<Option<Vec<u8>>> {
+        self.zip_archive()?.read_file(file_name)
     }
 
     /// Check if the archive is a module.
     ///
     /// # Errors
     /// if the module information cannot be read.
-    async fn is_module(&mut self) -> Result<bool> {
-        if let Some(is_module) = self.is_module {
-            Ok(is_module)
-        } else {
-            let module_info = self.load_class_file("classes/module-info").await?;
-            let is_module = module_info.is_some();
-            self.is_module = Some(is_module);
-            Ok(is_module)
+    fn is_module(&self) -> Result<bool> {
+        if let Some(is_module) = self.is_module.get() {
+            return Ok(*is_module);
         }
+        let module_info = self.load_class_file("classes/module-info")?;
+        Ok(*self.is_module.get_or_init(|| module_info.is_some()))
     }
 }
 
@@ -281,8 +853,39 @@ impl Clone for Jar {
         Self {
             name: self.name.clone(),
             archive: Arc::clone(&self.archive),
+            prefix: self.prefix.clone(),
+        }
+    }
+}
+
+/// Get the name of a directory entry, which ends with `/`.
+fn directory(name: &str) -> String {
+    let name = name.trim_start_matches('/');
+    if name.ends_with('/') {
+        name.to_string()
+    } else {
+        format!("{name}/")
+    }
+}
+
+/// Decode the percent-encoded bytes of a url, e.g. `my%20lib.jar`; invalid escapes are kept.
+fn decode_url(url: &str) -> String {
+    let bytes = url.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            let hex = url.get(index + 1..index + 3).unwrap_or_default();
+            if let Ok(byte) = u8::from_str_radix(hex, 16) {
+                decoded.push(byte);
+                index += 3;
+                continue;
+            }
         }
+        decoded.push(bytes[index]);
+        index += 1;
     }
+    String::from_utf8_lossy(&decoded).to_string()
 }
 
 #[cfg(test)]
@@ -293,6 +896,7 @@ mod tests {
     use std::io::Write;
     use std::path::PathBuf;
     use zip::write::SimpleFileOptions;
+    use zip::CompressionMethod;
 
     #[test]
     fn test_new() {
@@ -435,19 +1039,312 @@ mod tests {
         Ok(())
     }
 
-    #[tokio::test]
-    async fn test_archive_zip_archive_error() {
+    #[test]
+    fn test_archive_zip_archive_error() {
         let mut archive = Archive {
             path: None,
             url: None,
+            parent: None,
             bytes: None,
             zip_archive: None,
-            is_module: None,
+            is_module: OnceLock::new(),
+            #[cfg(feature = "signed-jars")]
+            signatures: OnceLock::new(),
         };
-        let result = archive.zip_archive().await;
+        let result = archive.open();
+        assert!(matches!(result, Err(ArchiveError(_))));
+    }
+
+    #[test]
+    fn test_archive_not_downloaded_error() {
+        let mut archive = Archive::from_url("https://localhost/classes.jar");
+        let result = archive.open();
         assert!(matches!(result, Err(ArchiveError(_))));
     }
 
+    #[test]
+    fn test_read_class_blocking() -> Result<()> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_jar = cargo_manifest
+            .join("..")
+            .join("classes")
+            .join("classes.jar");
+        let jar = Jar::new(classes_jar.to_string_lossy());
+        let class_file = jar.read_class_blocking("HelloWorld")?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+        let result = jar.read_class_blocking("Foo");
+        assert!(matches!(result, Err(ClassNotFound(_))));
+
+        let manifest = jar.manifest_blocking()?;
+        assert_eq!(Some("HelloWorld"), manifest.attribute(MAIN_CLASS));
+        let class_names = jar.class_names_blocking()?;
+        assert!(class_names.contains(&"HelloWorld".to_string()));
+        assert!(jar.read_file_blocking("HelloWorld.class")?.is_some());
+        let headers = jar.class_headers_blocking()?;
+        assert!(headers.iter().any(|header| header.name() == "HelloWorld"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_class_headers() -> Result<()> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_jar = cargo_manifest
+            .join("..")
+            .join("classes")
+            .join("classes.jar");
+        let jar = Jar::new(classes_jar.to_string_lossy());
+        let headers = jar.class_headers().await?;
+        let header = headers
+            .iter()
+            .find(|header| header.name() == "HelloWorld")
+            .expect("HelloWorld header");
+        assert_eq!(Some("java/lang/Object"), header.super_class());
+        Ok(())
+    }
+
+    /// Create a Spring Boot executable jar with `HelloWorld` in the classes directory and in a
+    /// stored and a deflated nested jar.
+    fn boot_jar(manifest: &str, class_path_index: Option<&str>) -> Result<Vec<u8>> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let hello_world = cargo_manifest
+            .join("..")
+            .join("classes")
+            .join("HelloWorld.class");
+        let class_bytes = fs::read(hello_world)?;
+
+        let mut nested_jar = zip::ZipWriter::new(io::Cursor::new(Vec::new()));
+        nested_jar.start_file("HelloWorld.class", SimpleFileOptions::default())?;
+        nested_jar.write_all(&class_bytes)?;
+        let nested_jar = nested_jar.finish()?.into_inner();
+
+        let mut boot_jar = zip::ZipWriter::new(io::Cursor::new(Vec::new()));
+        boot_jar.start_file("META-INF/MANIFEST.MF", SimpleFileOptions::default())?;
+        boot_jar.write_all(manifest.as_bytes())?;
+        boot_jar.start_file(
+            "BOOT-INF/classes/HelloWorld.class",
+            SimpleFileOptions::default(),
+        )?;
+        boot_jar.write_all(&class_bytes)?;
+        let stored = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+        boot_jar.start_file("BOOT-INF/lib/stored.jar", stored)?;
+        boot_jar.write_all(&nested_jar)?;
+        boot_jar.start_file("BOOT-INF/lib/deflated.jar", SimpleFileOptions::default())?;
+        boot_jar.write_all(&nested_jar)?;
+        if let Some(class_path_index) = class_path_index {
+            boot_jar.start_file("BOOT-INF/classpath.idx", SimpleFileOptions::default())?;
+            boot_jar.write_all(class_path_index.as_bytes())?;
+        }
+        Ok(boot_jar.finish()?.into_inner())
+    }
+
+    const BOOT_MANIFEST: &str = "Manifest-Version: 1.0\n\
+        Main-Class: org.springframework.boot.loader.launch.JarLauncher\n\
+        Start-Class: HelloWorld\n\
+        Spring-Boot-Classes: BOOT-INF/classes/\n\
+        Spring-Boot-Lib: BOOT-INF/lib/\n";
+
+    #[tokio::test]
+    async fn test_nested_stored_jar() -> Result<()> {
+        let jar = Jar::from_bytes("boot.jar", boot_jar(BOOT_MANIFEST, None)?);
+        let nested_jar = jar.nested("BOOT-INF/lib/stored.jar");
+        assert_eq!("boot.jar!/BOOT-INF/lib/stored.jar", nested_jar.name());
+        let class_file = nested_jar.read_class("HelloWorld").await?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+
+        // The stored jar is a range of the bytes of the boot jar
+        let parent = jar.archive.read().await;
+        let archive = nested_jar.archive.read().await;
+        let (Some(parent_bytes), Some(bytes)) = (&parent.bytes, &archive.bytes) else {
+            panic!("archive bytes not read");
+        };
+        assert!(Arc::ptr_eq(&parent_bytes.bytes, &bytes.bytes));
+        assert!(bytes.range.start > 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_nested_deflated_jar() -> Result<()> {
+        let jar = Jar::from_bytes("boot.jar", boot_jar(BOOT_MANIFEST, None)?);
+        let nested_jar = jar.nested("BOOT-INF/lib/deflated.jar");
+        let class_names = nested_jar.class_names().await?;
+        assert_eq!(vec!["HelloWorld".to_string()], class_names);
+        let result = nested_jar.read_class("Foo").await;
+        assert!(matches!(result, Err(ClassNotFound(_))));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_class_from_file() -> Result<()> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_jar = cargo_manifest
+            .join("..")
+            .join("classes")
+            .join("classes.jar");
+        let jar = Jar::new(classes_jar.to_string_lossy());
+        let class_file = jar.read_class("HelloWorld").await?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+
+        // Archives that are read from a path are not read into memory
+        let archive = jar.archive.read().await;
+        assert!(archive.bytes.is_none());
+        assert!(archive.zip_archive.is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_nested_stored_jar_from_file() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let boot_jar_path = temp_dir.path().join("boot.jar");
+        fs::write(&boot_jar_path, boot_jar(BOOT_MANIFEST, None)?)?;
+        let jar = Jar::new(boot_jar_path.to_string_lossy());
+        let nested_jar = jar.nested("BOOT-INF/lib/stored.jar");
+        let class_file = nested_jar.read_class("HelloWorld").await?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_nested_directory() -> Result<()> {
+        let jar = Jar::from_bytes("boot.jar", boot_jar(BOOT_MANIFEST, None)?);
+        let classes = jar.nested("BOOT-INF/classes/");
+        assert_eq!("boot.jar!/BOOT-INF/classes/", classes.name());
+        let class_file = classes.read_class("HelloWorld").await?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+        assert_eq!(vec!["HelloWorld".to_string()], classes.class_names().await?);
+        assert_eq!(1, classes.class_headers().await?.len());
+        assert!(classes.read_file("HelloWorld.class").await?.is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_nested_missing_entry() -> Result<()> {
+        let jar = Jar::from_bytes("boot.jar", boot_jar(BOOT_MANIFEST, None)?);
+        let nested_jar = jar.nested("BOOT-INF/lib/missing.jar");
+        let result = nested_jar.read_class("HelloWorld").await;
+        assert!(matches!(result, Err(FileNotFound(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_nested_blocking() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let jar_path = temp_dir.path().join("boot.jar");
+        fs::write(&jar_path, boot_jar(BOOT_MANIFEST, None)?)?;
+        let jar = Jar::new(jar_path.to_string_lossy());
+        for entry in ["BOOT-INF/lib/stored.jar", "BOOT-INF/lib/deflated.jar"] {
+            let nested_jar = jar.nested(entry);
+            let class_file = nested_jar.read_class_blocking("HelloWorld")?;
+            assert_eq!("HelloWorld", class_file.class_name()?);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_nested_class_path() -> Result<()> {
+        let jar = Jar::from_bytes("boot.jar", boot_jar(BOOT_MANIFEST, None)?);
+        let manifest = jar.manifest().await?;
+        let names = jar
+            .nested_class_path(&manifest)
+            .await?
+            .iter()
+            .map(|entry| entry.name().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "boot.jar!/BOOT-INF/classes/",
+                "boot.jar!/BOOT-INF/lib/deflated.jar",
+                "boot.jar!/BOOT-INF/lib/stored.jar",
+            ],
+            names
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_nested_class_path_index() -> Result<()> {
+        let manifest =
+            format!("{BOOT_MANIFEST}Spring-Boot-Classpath-Index: BOOT-INF/classpath.idx\n");
+        let class_path_index = "- \"BOOT-INF/lib/stored.jar\"\n- \"deflated.jar\"\n";
+        let jar = Jar::from_bytes("boot.jar", boot_jar(&manifest, Some(class_path_index))?);
+        let manifest = jar.manifest().await?;
+        let names = jar
+            .nested_class_path(&manifest)
+            .await?
+            .iter()
+            .map(|entry| entry.name().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "boot.jar!/BOOT-INF/classes/",
+                "boot.jar!/BOOT-INF/lib/stored.jar",
+                "boot.jar!/BOOT-INF/lib/deflated.jar",
+            ],
+            names
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_nested_class_path_not_boot_jar() -> Result<()> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_jar = cargo_manifest
+            .join("..")
+            .join("classes")
+            .join("classes.jar");
+        let jar = Jar::new(classes_jar.to_string_lossy());
+        let manifest = jar.manifest().await?;
+        assert!(jar.nested_class_path(&manifest).await?.is_empty());
+        Ok(())
+    }
+
+    /// Create a jar with more than 65,535 entries, which requires a ZIP64 end of central
+    /// directory record, and a `HelloWorld` class with ZIP64 extended information.
+    fn zip64_jar() -> Result<Vec<u8>> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let hello_world = cargo_manifest
+            .join("..")
+            .join("classes")
+            .join("HelloWorld.class");
+        let stored = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+        let mut archive = zip::ZipWriter::new(io::Cursor::new(Vec::new()));
+        for i in 0..=u16::MAX {
+            archive.start_file(format!("resources/{i}.txt"), stored)?;
+        }
+        archive.start_file("HelloWorld.class", stored.large_file(true))?;
+        archive.write_all(&fs::read(hello_world)?)?;
+        Ok(archive.finish()?.into_inner())
+    }
+
+    #[tokio::test]
+    async fn test_zip64() -> Result<()> {
+        let bytes = zip64_jar()?;
+        let jar = Jar::from_bytes("zip64.jar", bytes.clone());
+        let class_file = jar.read_class("HelloWorld").await?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+        assert_eq!(vec!["HelloWorld".to_string()], jar.class_names().await?);
+        assert_eq!(usize::from(u16::MAX) + 2, jar.file_names().await?.len());
+
+        // A ZIP64 jar nested in a jar
+        let stored = SimpleFileOptions::default()
+            .compression_method(CompressionMethod::Stored)
+            .large_file(true);
+        let mut archive = zip::ZipWriter::new(io::Cursor::new(Vec::new()));
+        archive.start_file("lib/zip64.jar", stored)?;
+        archive.write_all(&bytes)?;
+        let outer_jar = Jar::from_bytes("outer.jar", archive.finish()?.into_inner());
+        let nested_jar = outer_jar.nested("lib/zip64.jar");
+        let class_file = nested_jar.read_class("HelloWorld").await?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+
+        // A ZIP64 archive preceded by other data, e.g. the header of a jmod
+        let mut prefixed_bytes = vec![b'J', b'M', 0x01, 0x00];
+        prefixed_bytes.extend(bytes);
+        let jar = Jar::from_bytes("zip64.jmod", prefixed_bytes);
+        let class_file = jar.read_class("HelloWorld").await?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+        Ok(())
+    }
+
     #[cfg(feature = "url")]
     #[tokio::test]
     async fn test_from_url_read_class() -> Result<()> {
@@ -0,0 +1,281 @@
+use crate::class_path_entry::jar::Jar;
+use crate::{ClassHeader, Result};
+use ristretto_classfile::ClassFile;
+use std::fmt::{Debug, Display};
+use tracing::instrument;
+
+/// A section of a jmod file.
+/// See: <https://openjdk.org/jeps/261#Packaging:-JMOD-files>
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum JmodSection {
+    /// Native commands
+    Bin,
+    /// Class files and resources
+    Classes,
+    /// Configuration files, e.g. `security/java.security`
+    Conf,
+    /// Header files
+    Include,
+    /// Legal notices
+    Legal,
+    /// Native libraries
+    Lib,
+    /// Man pages
+    Man,
+}
+
+impl JmodSection {
+    /// Get the directory of the section in the jmod file.
+    #[must_use]
+    pub fn directory(&self) -> &'static str {
+        match self {
+            JmodSection::Bin => "bin/",
+            JmodSection::Classes => "classes/",
+            JmodSection::Conf => "conf/",
+            JmodSection::Include => "include/",
+            JmodSection::Legal => "legal/",
+            JmodSection::Lib => "lib/",
+            JmodSection::Man => "man/",
+        }
+    }
+}
+
+impl Display for JmodSection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.directory().trim_end_matches('/'))
+    }
+}
+
+/// A jmod file in the class path; jmod files package a module for linking into a runtime image,
+/// e.g. `$JAVA_HOME/jmods/java.base.jmod`.  A jmod file is a zip archive preceded by a `JM` magic
+/// number and version, with class files in the `classes` section and native libraries and
+/// configuration files in the `lib` and `conf` sections.
+/// See: <https://openjdk.org/jeps/261#Packaging:-JMOD-files>
+#[derive(Clone, Debug, PartialEq)]
+pub struct Jmod {
+    name: String,
+    jar: Jar,
+    classes: Jar,
+}
+
+/// Implement the `Jmod` struct.
+impl Jmod {
+    /// Create a new jmod from a path.
+    pub fn new<S: AsRef<str>>(path: S) -> Self {
+        Self::from_jar(Jar::new(path))
+    }
+
+    /// Create a new jmod from bytes.
+    pub fn from_bytes<S: AsRef<str>>(name: S, bytes: Vec<u8>) -> Self {
+        Self::from_jar(Jar::from_bytes(name, bytes))
+    }
+
+    /// Create a new jmod from the jar that reads its archive.
+    fn from_jar(jar: Jar) -> Self {
+        let classes = jar.nested(JmodSection::Classes.directory());
+        Self {
+            name: jar.name().clone(),
+            jar,
+            classes,
+        }
+    }
+
+    /// Get the name of the jmod.
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    /// Read a class from the `classes` section of the jmod.
+    ///
+    /// # Errors
+    /// if the class file is not found or cannot be read.
+    #[instrument(level = "trace", fields(name = ?name.as_ref()), skip(self))]
+    pub async fn read_class<S: AsRef<str>>(&self, name: S) -> Result<ClassFile> {
+        self.classes.read_class(name).await
+    }
+
+    /// Read a class from the `classes` section of the jmod without an async runtime.
+    ///
+    /// # Errors
+    /// if the class file is not found or cannot be read.
+    ///
+    /// # Panics
+    /// if called from within an async execution context.
+    #[instrument(level = "trace", fields(name = ?name.as_ref()), skip(self))]
+    pub fn read_class_blocking<S: AsRef<str>>(&self, name: S) -> Result<ClassFile> {
+        self.classes.read_class_blocking(name)
+    }
+
+    /// Read a resource from the `classes` section of the jmod.
+    ///
+    /// # Errors
+    /// if the file cannot be read.
+    #[instrument(level = "trace", fields(name = ?name.as_ref()), skip(self))]
+    pub async fn read_resource<S: AsRef<str>>(&self, name: S) -> Result<Option<Vec<u8>>> {
+        self.classes.read_file(name).await
+    }
+
+    /// Get the class names in the jmod.
+    ///
+    /// # Errors
+    /// if the class names cannot be read.
+    pub async fn class_names(&self) -> Result<Vec<String>> {
+        self.classes.class_names().await
+    }
+
+    /// Get the class names in the jmod without an async runtime.
+    ///
+    /// # Errors
+    /// if the class names cannot be read.
+    ///
+    /// # Panics
+    /// if called from within an async execution context.
+    pub fn class_names_blocking(&self) -> Result<Vec<String>> {
+        self.classes.class_names_blocking()
+    }
+
+    /// Get the headers of the classes in the jmod; the module descriptor is excluded.
+    ///
+    /// # Errors
+    /// if a class file cannot be read.
+    pub async fn class_headers(&self) -> Result<Vec<ClassHeader>> {
+        self.classes.class_headers().await
+    }
+
+    /// Get the headers of the classes in the jmod without an async runtime.
+    ///
+    /// # Errors
+    /// if a class file cannot be read.
+    ///
+    /// # Panics
+    /// if called from within an async execution context.
+    pub fn class_headers_blocking(&self) -> Result<Vec<ClassHeader>> {
+        self.classes.class_headers_blocking()
+    }
+
+    /// Get the names of the files in a section of the jmod, relative to the section.
+    ///
+    /// # Errors
+    /// if the file names cannot be read.
+    pub async fn file_names(&self, section: JmodSection) -> Result<Vec<String>> {
+        self.jar.nested(section.directory()).file_names().await
+    }
+
+    /// Read a file from a section of the jmod, e.g. `security/java.security` from the `conf`
+    /// section; returns `None` if the file does not exist.
+    ///
+    /// # Errors
+    /// if the file cannot be read.
+    #[instrument(level = "trace", fields(name = ?name.as_ref()), skip(self))]
+    pub async fn read_file<S: AsRef<str>>(
+        &self,
+        section: JmodSection,
+        name: S,
+    ) -> Result<Option<Vec<u8>>> {
+        let name = format!("{}{}", section.directory(), name.as_ref());
+        self.jar.read_file(name).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error::ClassNotFound;
+    use ristretto_classfile::attributes::ExportsFlags;
+    use ristretto_classfile::ModuleInfoBuilder;
+    use std::io::{Cursor, Write};
+    use std::path::PathBuf;
+    use zip::write::SimpleFileOptions;
+
+    /// Create a jmod with a module descriptor and `HelloWorld` class, a native library and a
+    /// configuration file.
+    fn jmod_bytes() -> Result<Vec<u8>> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let hello_world = cargo_manifest
+            .join("..")
+            .join("classes")
+            .join("HelloWorld.class");
+        let module_info = ModuleInfoBuilder::new("hello")
+            .exports("hello", ExportsFlags::empty())
+            .build()?;
+        let mut module_info_bytes = Vec::new();
+        module_info.to_bytes(&mut module_info_bytes)?;
+
+        let mut bytes = vec![b'J', b'M', 0x01, 0x00];
+        let mut archive = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default();
+        archive.start_file("classes/module-info.class", options)?;
+        archive.write_all(&module_info_bytes)?;
+        archive.start_file("classes/HelloWorld.class", options)?;
+        archive.write_all(&std::fs::read(hello_world)?)?;
+        archive.start_file("lib/libhello.so", options)?;
+        archive.write_all(b"library")?;
+        archive.start_file("conf/hello.properties", options)?;
+        archive.write_all(b"greeting=hello")?;
+        bytes.extend(archive.finish()?.into_inner());
+        Ok(bytes)
+    }
+
+    #[test]
+    fn test_section() {
+        assert_eq!("classes/", JmodSection::Classes.directory());
+        assert_eq!("lib", JmodSection::Lib.to_string());
+        assert_eq!("conf", JmodSection::Conf.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_read_class() -> Result<()> {
+        let jmod = Jmod::from_bytes("hello.jmod", jmod_bytes()?);
+        assert_eq!("hello.jmod", jmod.name());
+        let class_file = jmod.read_class("HelloWorld").await?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+        let module_info = jmod.read_class("module-info").await?;
+        assert_eq!("module-info", module_info.class_name()?);
+        assert!(jmod.read_resource("HelloWorld.class").await?.is_some());
+        let result = jmod.read_class("Foo").await;
+        assert!(matches!(result, Err(ClassNotFound(_))));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_class_names_and_headers() -> Result<()> {
+        let jmod = Jmod::from_bytes("hello.jmod", jmod_bytes()?);
+        let mut class_names = jmod.class_names().await?;
+        class_names.sort();
+        assert_eq!(vec!["HelloWorld", "module-info"], class_names);
+        let headers = jmod.class_headers().await?;
+        assert_eq!(1, headers.len());
+        assert_eq!("HelloWorld", headers[0].name());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sections() -> Result<()> {
+        let jmod = Jmod::from_bytes("hello.jmod", jmod_bytes()?);
+        assert_eq!(
+            vec!["libhello.so"],
+            jmod.file_names(JmodSection::Lib).await?
+        );
+        assert_eq!(
+            Some(b"greeting=hello".to_vec()),
+            jmod.read_file(JmodSection::Conf, "hello.properties")
+                .await?
+        );
+        assert_eq!(None, jmod.read_file(JmodSection::Conf, "missing").await?);
+        assert!(jmod.file_names(JmodSection::Bin).await?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_blocking() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let path = temp_dir.path().join("hello.jmod");
+        std::fs::write(&path, jmod_bytes()?)?;
+        let jmod = Jmod::new(path.to_string_lossy());
+        let class_file = jmod.read_class_blocking("HelloWorld")?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+        assert_eq!(2, jmod.class_names_blocking()?.len());
+        assert_eq!(1, jmod.class_headers_blocking()?.len());
+        Ok(())
+    }
+}
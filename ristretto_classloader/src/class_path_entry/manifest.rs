@@ -9,6 +9,11 @@ use std::str::FromStr;
 
 pub const MANIFEST_VERSION: &str = "Manifest-Version";
 pub const MAIN_CLASS: &str = "Main-Class";
+pub const CLASS_PATH: &str = "Class-Path";
+pub const START_CLASS: &str = "Start-Class";
+pub const SPRING_BOOT_CLASSES: &str = "Spring-Boot-Classes";
+pub const SPRING_BOOT_LIB: &str = "Spring-Boot-Lib";
+pub const SPRING_BOOT_CLASSPATH_INDEX: &str = "Spring-Boot-Classpath-Index";
 
 /// Represents a Jar manifest.
 /// See: <https://docs.oracle.com/en/java/javase/22/docs/specs/jar/jar.html#jar-manifest>
@@ -86,7 +91,20 @@ impl<'de> Deserialize<'de> for Manifest {
                 let mut sections = IndexMap::new();
                 let mut current_section = None;
 
+                // A line that starts with a space continues the value of the previous line; long
+                // values such as `Class-Path` are wrapped at 72 bytes
+                let mut lines: Vec<String> = Vec::new();
                 for line in v.lines() {
+                    if let Some(continuation) = line.strip_prefix(' ') {
+                        if let Some(previous) = lines.last_mut() {
+                            previous.push_str(continuation);
+                            continue;
+                        }
+                    }
+                    lines.push(line.to_string());
+                }
+
+                for line in &lines {
                     let trimmed = line.trim();
                     if trimmed.is_empty() {
                         continue;
@@ -154,6 +172,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_continuation_lines() -> Result<()> {
+        let manifest = Manifest::from_str(indoc! {r"
+            Manifest-Version: 1.0
+            Class-Path: lib/a.jar lib/b
+             .jar lib/c.jar
+            Main-Class: com.example.Main
+        "})?;
+        assert_eq!(
+            Some("lib/a.jar lib/b.jar lib/c.jar"),
+            manifest.attribute(CLASS_PATH)
+        );
+        assert_eq!(Some("com.example.Main"), manifest.attribute(MAIN_CLASS));
+        Ok(())
+    }
+
     #[test]
     fn test_serde() -> Result<()> {
         let manifest = Manifest::from_str(EXPECTED)?;
@@ -1,7 +1,14 @@
 mod directory;
 mod jar;
+mod jmod;
 pub mod manifest;
 mod model;
+#[cfg(feature = "signed-jars")]
+mod signature;
+mod zip_archive;
 
+pub use jmod::JmodSection;
 pub use manifest::Manifest;
 pub use model::ClassPathEntry;
+#[cfg(feature = "signed-jars")]
+pub use signature::CodeSigner;
@@ -1,7 +1,11 @@
 use crate::class_path_entry::directory::Directory;
 use crate::class_path_entry::jar::Jar;
-use crate::Result;
+use crate::class_path_entry::jmod::Jmod;
+#[cfg(feature = "signed-jars")]
+use crate::class_path_entry::signature::CodeSigner;
+use crate::{ClassHeader, Result};
 use ristretto_classfile::ClassFile;
+use std::collections::HashSet;
 use std::fmt::Debug;
 use std::path::PathBuf;
 use tracing::instrument;
@@ -11,19 +15,38 @@ use tracing::instrument;
 pub enum ClassPathEntry {
     Directory(Directory),
     Jar(Jar),
+    /// A jar or directory inside another jar, e.g. `app.jar!/BOOT-INF/lib/foo.jar`.
+    NestedJar(Jar),
+    Jmod(Jmod),
 }
 
 /// Implementation for `ClassPathEntry`.
 impl ClassPathEntry {
-    /// Create a new class path entry.
+    /// Create a new class path entry; entries of a jar are separated from the jar path by `!/`,
+    /// e.g. `app.jar!/BOOT-INF/lib/foo.jar` or `app.jar!/BOOT-INF/classes/`.
     pub fn new<S: AsRef<str>>(path: S) -> Self {
         let path = path.as_ref();
+        if let Some((jar_path, entry)) = path.rsplit_once("!/") {
+            if let ClassPathEntry::Jar(jar) | ClassPathEntry::NestedJar(jar) =
+                ClassPathEntry::new(jar_path)
+            {
+                return ClassPathEntry::NestedJar(jar.nested(entry));
+            }
+        }
+
         #[cfg(feature = "url")]
         if path.starts_with("https://") || path.starts_with("http://") {
             return ClassPathEntry::Jar(Jar::from_url(path));
         }
 
-        if PathBuf::from(path).is_file() {
+        let path_buf = PathBuf::from(path);
+        if path_buf.is_file() {
+            if path_buf
+                .extension()
+                .is_some_and(|extension| extension == "jmod")
+            {
+                return ClassPathEntry::Jmod(Jmod::new(path));
+            }
             ClassPathEntry::Jar(Jar::new(path))
         } else {
             ClassPathEntry::Directory(Directory::new(path))
@@ -35,7 +58,8 @@ impl ClassPathEntry {
     pub fn name(&self) -> &String {
         match self {
             ClassPathEntry::Directory(directory) => directory.name(),
-            ClassPathEntry::Jar(jar) => jar.name(),
+            ClassPathEntry::Jar(jar) | ClassPathEntry::NestedJar(jar) => jar.name(),
+            ClassPathEntry::Jmod(jmod) => jmod.name(),
         }
     }
 
@@ -47,7 +71,115 @@ impl ClassPathEntry {
     pub async fn read_class<S: AsRef<str>>(&self, name: S) -> Result<ClassFile> {
         match self {
             ClassPathEntry::Directory(directory) => directory.read_class(name),
-            ClassPathEntry::Jar(jar) => jar.read_class(name).await,
+            ClassPathEntry::Jar(jar) | ClassPathEntry::NestedJar(jar) => jar.read_class(name).await,
+            ClassPathEntry::Jmod(jmod) => jmod.read_class(name).await,
+        }
+    }
+
+    /// Read a class from the class path entry without an async runtime.
+    ///
+    /// # Errors
+    /// if the class file cannot be read or the entry is a url that has not been downloaded.
+    ///
+    /// # Panics
+    /// if a jar is read from within an async execution context.
+    #[instrument(level = "trace", fields(name = ?name.as_ref()), skip(self))]
+    pub fn read_class_blocking<S: AsRef<str>>(&self, name: S) -> Result<ClassFile> {
+        match self {
+            ClassPathEntry::Directory(directory) => directory.read_class(name),
+            ClassPathEntry::Jar(jar) | ClassPathEntry::NestedJar(jar) => {
+                jar.read_class_blocking(name)
+            }
+            ClassPathEntry::Jmod(jmod) => jmod.read_class_blocking(name),
+        }
+    }
+
+    /// Read a file, such as a resource or a `META-INF/services` file, from the class path entry;
+    /// returns `None` if the file does not exist.
+    ///
+    /// # Errors
+    /// if the file cannot be read.
+    #[instrument(level = "trace", fields(name = ?name.as_ref()), skip(self))]
+    pub async fn read_file<S: AsRef<str>>(&self, name: S) -> Result<Option<Vec<u8>>> {
+        match self {
+            ClassPathEntry::Directory(directory) => directory.read_file(name),
+            ClassPathEntry::Jar(jar) | ClassPathEntry::NestedJar(jar) => jar.read_file(name).await,
+            ClassPathEntry::Jmod(jmod) => jmod.read_resource(name).await,
+        }
+    }
+
+    /// Get the signers of a file in the class path entry, e.g. `com/example/Foo.class`; only
+    /// files of signed jars have signers.
+    ///
+    /// # Errors
+    /// if the signatures of a jar cannot be read or verified.
+    #[cfg(feature = "signed-jars")]
+    pub async fn signers<S: AsRef<str>>(&self, name: S) -> Result<Vec<CodeSigner>> {
+        match self {
+            ClassPathEntry::Jar(jar) | ClassPathEntry::NestedJar(jar) => jar.signers(name).await,
+            ClassPathEntry::Directory(_) | ClassPathEntry::Jmod(_) => Ok(Vec::new()),
+        }
+    }
+
+    /// Get the class path that the `Class-Path` manifest attribute of a jar declares, followed
+    /// transitively in the order that `HotSpot` searches it: the entries of each jar come directly
+    /// after the jar.  Each jar or directory appears once and this entry is not included, so jars
+    /// that refer to each other do not recurse.
+    ///
+    /// # Errors
+    /// if the manifest of a jar cannot be read.
+    pub async fn manifest_class_path(&self) -> Result<Vec<ClassPathEntry>> {
+        let mut visited = HashSet::from([self.key()]);
+        self.resolve_manifest_class_path(&mut visited).await
+    }
+
+    /// Get the class path that the `Class-Path` manifest attribute of a jar declares, followed
+    /// transitively; entries that have been visited are skipped.
+    ///
+    /// # Errors
+    /// if the manifest of a jar cannot be read.
+    pub(crate) async fn resolve_manifest_class_path(
+        &self,
+        visited: &mut HashSet<String>,
+    ) -> Result<Vec<ClassPathEntry>> {
+        let mut class_path = Vec::new();
+        let mut pending = self.declared_class_path().await?;
+        pending.reverse();
+        while let Some(entry) = pending.pop() {
+            if !visited.insert(entry.key()) {
+                continue;
+            }
+            let declared_class_path = entry.declared_class_path().await?;
+            pending.extend(declared_class_path.into_iter().rev());
+            class_path.push(entry);
+        }
+        Ok(class_path)
+    }
+
+    /// Get the entries that the `Class-Path` manifest attribute of a jar declares directly.
+    ///
+    /// # Errors
+    /// if the manifest cannot be read.
+    async fn declared_class_path(&self) -> Result<Vec<ClassPathEntry>> {
+        let ClassPathEntry::Jar(jar) = self else {
+            return Ok(Vec::new());
+        };
+        let class_path = jar
+            .manifest_class_path()
+            .await?
+            .into_iter()
+            .map(ClassPathEntry::new)
+            .collect();
+        Ok(class_path)
+    }
+
+    /// Get the key that identifies the file of the class path entry; paths are canonicalized so
+    /// that different relative paths to the same jar are the same entry.
+    pub(crate) fn key(&self) -> String {
+        let name = self.name();
+        match std::fs::canonicalize(name) {
+            Ok(path) => path.to_string_lossy().to_string(),
+            Err(_) => name.clone(),
         }
     }
 
@@ -57,8 +189,53 @@ impl ClassPathEntry {
     /// if the class names cannot be read.
     pub async fn class_names(&self) -> Result<Vec<String>> {
         match self {
-            ClassPathEntry::Directory(directory) => directory.class_names().await,
-            ClassPathEntry::Jar(jar) => jar.class_names().await,
+            ClassPathEntry::Directory(directory) => Ok(directory.class_names()),
+            ClassPathEntry::Jar(jar) | ClassPathEntry::NestedJar(jar) => jar.class_names().await,
+            ClassPathEntry::Jmod(jmod) => jmod.class_names().await,
+        }
+    }
+
+    /// Get the headers of the classes in the class path entry.
+    ///
+    /// # Errors
+    /// if a class file cannot be read.
+    pub async fn class_headers(&self) -> Result<Vec<ClassHeader>> {
+        match self {
+            ClassPathEntry::Directory(directory) => directory.class_headers(),
+            ClassPathEntry::Jar(jar) | ClassPathEntry::NestedJar(jar) => jar.class_headers().await,
+            ClassPathEntry::Jmod(jmod) => jmod.class_headers().await,
+        }
+    }
+
+    /// Get the headers of the classes in the class path entry without an async runtime.
+    ///
+    /// # Errors
+    /// if a class file cannot be read or the entry is a url that has not been downloaded.
+    ///
+    /// # Panics
+    /// if a jar is read from within an async execution context.
+    pub fn class_headers_blocking(&self) -> Result<Vec<ClassHeader>> {
+        match self {
+            ClassPathEntry::Directory(directory) => directory.class_headers(),
+            ClassPathEntry::Jar(jar) | ClassPathEntry::NestedJar(jar) => {
+                jar.class_headers_blocking()
+            }
+            ClassPathEntry::Jmod(jmod) => jmod.class_headers_blocking(),
+        }
+    }
+
+    /// Get the class names in the class path entry without an async runtime.
+    ///
+    /// # Errors
+    /// if the class names cannot be read or the entry is a url that has not been downloaded.
+    ///
+    /// # Panics
+    /// if a jar is read from within an async execution context.
+    pub fn class_names_blocking(&self) -> Result<Vec<String>> {
+        match self {
+            ClassPathEntry::Directory(directory) => Ok(directory.class_names()),
+            ClassPathEntry::Jar(jar) | ClassPathEntry::NestedJar(jar) => jar.class_names_blocking(),
+            ClassPathEntry::Jmod(jmod) => jmod.class_names_blocking(),
         }
     }
 }
@@ -74,6 +251,8 @@ struct Manifest {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::io::Write;
+    use std::path::Path;
 
     //
     // Directory Tests
@@ -108,6 +287,18 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_class_blocking_directory() -> Result<()> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_directory = cargo_manifest.join("..").join("classes");
+        let class_path_entry = ClassPathEntry::new(classes_directory.to_string_lossy());
+        let class_file = class_path_entry.read_class_blocking("HelloWorld")?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+        let class_names = class_path_entry.class_names_blocking()?;
+        assert!(class_names.contains(&"HelloWorld".to_string()));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_class_names_directory() -> Result<()> {
         let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -170,6 +361,77 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_new_nested_jar() -> Result<()> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_jar = cargo_manifest
+            .join("..")
+            .join("classes")
+            .join("classes.jar");
+        let classes_jar = classes_jar.to_string_lossy();
+        let path = format!("{classes_jar}!/");
+        let class_path_entry = ClassPathEntry::new(&path);
+        assert!(matches!(class_path_entry, ClassPathEntry::NestedJar(_)));
+        assert_eq!(&path, class_path_entry.name());
+        let class_file = class_path_entry.read_class("HelloWorld").await?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+
+        let class_path_entry = ClassPathEntry::new(format!("{classes_jar}!/lib/foo.jar"));
+        assert!(matches!(class_path_entry, ClassPathEntry::NestedJar(_)));
+        assert!(class_path_entry.read_class("HelloWorld").await.is_err());
+        Ok(())
+    }
+
+    /// Write a jar whose manifest declares a class path.
+    fn write_jar(path: &Path, class_path: &str) -> Result<()> {
+        let mut archive = zip::ZipWriter::new(std::fs::File::create(path)?);
+        archive.start_file(
+            "META-INF/MANIFEST.MF",
+            zip::write::SimpleFileOptions::default(),
+        )?;
+        write!(archive, "Manifest-Version: 1.0\nClass-Path: {class_path}\n")?;
+        archive.finish()?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_manifest_class_path() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let directory = temp_dir.path();
+        std::fs::create_dir_all(directory.join("lib").join("classes"))?;
+        write_jar(
+            &directory.join("app.jar"),
+            "lib/a.jar lib/classes/ lib/missing.jar",
+        )?;
+        write_jar(&directory.join("lib").join("a.jar"), "b.jar")?;
+        // b.jar refers back to app.jar and a.jar
+        write_jar(&directory.join("lib").join("b.jar"), "../app.jar a.jar")?;
+
+        let class_path_entry = ClassPathEntry::new(directory.join("app.jar").to_string_lossy());
+        let class_path = class_path_entry.manifest_class_path().await?;
+        let names = class_path
+            .iter()
+            .map(|entry| {
+                Path::new(entry.name())
+                    .strip_prefix(directory)
+                    .map(|path| path.to_string_lossy().replace('\\', "/"))
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["lib/a.jar", "lib/b.jar", "lib/classes"], names);
+        assert!(matches!(class_path[2], ClassPathEntry::Directory(_)));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_manifest_class_path_without_manifest() -> Result<()> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_directory = cargo_manifest.join("..").join("classes");
+        let class_path_entry = ClassPathEntry::new(classes_directory.to_string_lossy());
+        assert!(class_path_entry.manifest_class_path().await?.is_empty());
+        Ok(())
+    }
+
     //
     // Url Tests
     //
@@ -0,0 +1,622 @@
+use crate::Error::SecurityError;
+use crate::Result;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use cms::cert::x509::der::asn1::{ObjectIdentifier, OctetString};
+use cms::cert::x509::der::{Decode, Encode};
+use cms::cert::x509::ext::pkix::SubjectKeyIdentifier;
+use cms::cert::x509::Certificate;
+use cms::cert::CertificateChoices;
+use cms::content_info::ContentInfo;
+use cms::signed_data::{SignedData, SignerIdentifier, SignerInfo};
+use p256::ecdsa::signature::hazmat::PrehashVerifier;
+use rsa::pkcs8::DecodePublicKey;
+use rsa::{Pkcs1v15Sign, RsaPublicKey};
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use std::collections::HashMap;
+use std::fmt::{Display, Write};
+
+const MANIFEST: &str = "META-INF/MANIFEST.MF";
+const SIGNATURE_BLOCK_EXTENSIONS: [&str; 3] = ["RSA", "EC", "DSA"];
+const ID_SHA_1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.14.3.2.26");
+const ID_SHA_256: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.1");
+const ID_SHA_384: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.2");
+const ID_SHA_512: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.3");
+const ID_MESSAGE_DIGEST: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.4");
+const ID_SUBJECT_KEY_IDENTIFIER: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.29.14");
+
+/// The signer of entries of a signed jar, identified by the certificate chain of the signature
+/// block file, e.g. `META-INF/SIGNER.RSA`.  The chain is not validated against trusted
+/// certificates; deciding which signers to trust is left to the embedder.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CodeSigner {
+    subject: String,
+    issuer: String,
+    serial_number: String,
+    certificate_chain: Vec<Vec<u8>>,
+}
+
+impl CodeSigner {
+    /// Get the subject of the signer certificate, e.g. `CN=Example`.
+    #[must_use]
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    /// Get the issuer of the signer certificate.
+    #[must_use]
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    /// Get the serial number of the signer certificate as hexadecimal.
+    #[must_use]
+    pub fn serial_number(&self) -> &str {
+        &self.serial_number
+    }
+
+    /// Get the DER encoded certificates of the signer, starting with the signer certificate and
+    /// followed by its issuers that are included in the signature block.
+    #[must_use]
+    pub fn certificate_chain(&self) -> &[Vec<u8>] {
+        &self.certificate_chain
+    }
+}
+
+impl Display for CodeSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.subject)
+    }
+}
+
+/// Digest algorithms of jar manifests and signature files.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum DigestAlgorithm {
+    Sha1,
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl DigestAlgorithm {
+    /// Get the algorithm of a digest attribute prefix, e.g. `SHA-256`; weak algorithms such as MD5
+    /// are not supported, and digests that use them are ignored.
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_uppercase().as_str() {
+            "SHA1" | "SHA-1" => Some(Self::Sha1),
+            "SHA-256" => Some(Self::Sha256),
+            "SHA-384" => Some(Self::Sha384),
+            "SHA-512" => Some(Self::Sha512),
+            _ => None,
+        }
+    }
+
+    /// Get the algorithm of an object identifier.
+    fn from_oid(oid: &ObjectIdentifier) -> Option<Self> {
+        match *oid {
+            ID_SHA_1 => Some(Self::Sha1),
+            ID_SHA_256 => Some(Self::Sha256),
+            ID_SHA_384 => Some(Self::Sha384),
+            ID_SHA_512 => Some(Self::Sha512),
+            _ => None,
+        }
+    }
+
+    /// Compute the digest of bytes.
+    fn digest(self, bytes: &[u8]) -> Vec<u8> {
+        match self {
+            Self::Sha1 => Sha1::digest(bytes).to_vec(),
+            Self::Sha256 => Sha256::digest(bytes).to_vec(),
+            Self::Sha384 => Sha384::digest(bytes).to_vec(),
+            Self::Sha512 => Sha512::digest(bytes).to_vec(),
+        }
+    }
+
+    /// Get the RSA PKCS #1 v1.5 signature scheme of the algorithm.
+    fn pkcs1v15(self) -> Pkcs1v15Sign {
+        match self {
+            Self::Sha1 => Pkcs1v15Sign::new::<Sha1>(),
+            Self::Sha256 => Pkcs1v15Sign::new::<Sha256>(),
+            Self::Sha384 => Pkcs1v15Sign::new::<Sha384>(),
+            Self::Sha512 => Pkcs1v15Sign::new::<Sha512>(),
+        }
+    }
+}
+
+impl Display for DigestAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Sha1 => "SHA-1",
+            Self::Sha256 => "SHA-256",
+            Self::Sha384 => "SHA-384",
+            Self::Sha512 => "SHA-512",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A section of a manifest or signature file.  Digests of a section are computed over its raw
+/// bytes, including the blank line that ends the section.
+#[derive(Debug)]
+struct Section<'a> {
+    bytes: &'a [u8],
+    attributes: Vec<(String, String)>,
+}
+
+impl Section<'_> {
+    /// Get the value of an attribute; attribute names are case-insensitive.
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Get the digests of the attributes whose names end with a suffix, e.g. `-Digest` for
+    /// `SHA-256-Digest`; digests of unsupported algorithms are ignored.
+    fn digests(&self, suffix: &str) -> Vec<(DigestAlgorithm, Vec<u8>)> {
+        self.attributes
+            .iter()
+            .filter_map(|(key, value)| {
+                let split = key.len().checked_sub(suffix.len())?;
+                let (algorithm, key_suffix) = (key.get(..split)?, key.get(split..)?);
+                if !key_suffix.eq_ignore_ascii_case(suffix) {
+                    return None;
+                }
+                let algorithm = DigestAlgorithm::from_name(algorithm)?;
+                let digest = STANDARD.decode(value.trim()).ok()?;
+                Some((algorithm, digest))
+            })
+            .collect()
+    }
+
+    /// Check the digests of the attributes whose names end with a suffix against bytes; returns
+    /// `None` if there are no digests of supported algorithms.
+    fn digests_match(&self, suffix: &str, bytes: &[u8]) -> Option<bool> {
+        let digests = self.digests(suffix);
+        if digests.is_empty() {
+            return None;
+        }
+        let matches = digests
+            .iter()
+            .all(|(algorithm, digest)| algorithm.digest(bytes) == *digest);
+        Some(matches)
+    }
+}
+
+/// Split a manifest or signature file into sections; the first section holds the main
+/// attributes.  Lines end with `\r\n`, `\n` or `\r`, and a line that starts with a space continues
+/// the previous line.
+fn sections(bytes: &[u8]) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    let mut start = None;
+    let mut lines: Vec<String> = Vec::new();
+    let mut position = 0;
+    while position < bytes.len() {
+        let line_start = position;
+        while position < bytes.len() && bytes[position] != b'\n' && bytes[position] != b'\r' {
+            position += 1;
+        }
+        let line = &bytes[line_start..position];
+        if bytes.get(position) == Some(&b'\r') {
+            position += 1;
+        }
+        if bytes.get(position) == Some(&b'\n') {
+            position += 1;
+        }
+
+        if line.is_empty() {
+            if let Some(section_start) = start.take() {
+                sections.push(section(&bytes[section_start..position], &lines));
+                lines.clear();
+            }
+            continue;
+        }
+        start.get_or_insert(line_start);
+        let line = String::from_utf8_lossy(line);
+        match (line.strip_prefix(' '), lines.last_mut()) {
+            (Some(continuation), Some(previous)) => previous.push_str(continuation),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    if let Some(section_start) = start {
+        sections.push(section(&bytes[section_start..], &lines));
+    }
+    sections
+}
+
+/// Create a section from its raw bytes and logical lines.
+fn section<'a>(bytes: &'a [u8], lines: &[String]) -> Section<'a> {
+    let attributes = lines
+        .iter()
+        .filter_map(|line| line.split_once(": "))
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+    Section { bytes, attributes }
+}
+
+/// The signatures of a signed jar: the manifest digests of its entries, and the signers of the
+/// entries whose signature file digests match the manifest.
+#[derive(Debug, Default)]
+pub(crate) struct JarSignatures {
+    digests: HashMap<String, Vec<(DigestAlgorithm, Vec<u8>)>>,
+    signers: HashMap<String, Vec<CodeSigner>>,
+}
+
+impl JarSignatures {
+    /// Read the signatures of a jar from its manifest and the signature files in `META-INF`;
+    /// returns `None` if the jar is not signed.  The signature block of every signature file is
+    /// verified, and the signature file digests are checked against the manifest.
+    ///
+    /// # Errors
+    /// if a signature block cannot be verified or a signature file digest does not match the
+    /// manifest.
+    pub(crate) fn read<F>(file_names: &[String], mut read_file: F) -> Result<Option<Self>>
+    where
+        F: FnMut(&str) -> Result<Option<Vec<u8>>>,
+    {
+        let mut signature_files = file_names
+            .iter()
+            .filter_map(|file_name| {
+                let name = file_name.strip_prefix("META-INF/")?;
+                let (base_name, extension) = name.rsplit_once('.')?;
+                let is_signature_file = !base_name.contains('/')
+                    && !base_name.is_empty()
+                    && extension.eq_ignore_ascii_case("SF");
+                is_signature_file.then_some((file_name.as_str(), base_name))
+            })
+            .collect::<Vec<_>>();
+        if signature_files.is_empty() {
+            return Ok(None);
+        }
+        signature_files.sort_unstable();
+        let Some(manifest) = read_file(MANIFEST)? else {
+            return Ok(None);
+        };
+        let manifest_sections = sections(&manifest);
+        let named_sections = manifest_sections
+            .iter()
+            .skip(1)
+            .filter_map(|section| section.attribute("Name").map(|name| (name, section)))
+            .collect::<HashMap<_, _>>();
+
+        let mut signatures = JarSignatures::default();
+        let mut signed = false;
+        for (signature_file, base_name) in signature_files {
+            let Some(block_file) = signature_block_file(file_names, base_name) else {
+                continue;
+            };
+            let (Some(signature), Some(block)) =
+                (read_file(signature_file)?, read_file(block_file)?)
+            else {
+                continue;
+            };
+            let signers = verify_signature_block(&signature, &block).map_err(|error| {
+                SecurityError(format!(
+                    "Cannot verify signature block file {block_file}: {error}"
+                ))
+            })?;
+            signed = true;
+
+            let signature_sections = sections(&signature);
+            let Some(main_attributes) = signature_sections.first() else {
+                continue;
+            };
+            let manifest_signed =
+                main_attributes.digests_match("-Digest-Manifest", &manifest) == Some(true);
+            if !manifest_signed {
+                let main_section = manifest_sections.first().map_or(&[][..], |main| main.bytes);
+                if main_attributes.digests_match("-Digest-Manifest-Main-Attributes", main_section)
+                    == Some(false)
+                {
+                    return Err(SecurityError(format!(
+                        "Invalid signature file digest for manifest main attributes in \
+                         {signature_file}"
+                    )));
+                }
+            }
+
+            for section in signature_sections.iter().skip(1) {
+                let Some(name) = section.attribute("Name") else {
+                    continue;
+                };
+                if !manifest_signed {
+                    let Some(manifest_section) = named_sections.get(name) else {
+                        continue;
+                    };
+                    match section.digests_match("-Digest", manifest_section.bytes) {
+                        Some(true) => {}
+                        Some(false) => {
+                            return Err(SecurityError(format!(
+                                "Invalid signature file digest for {name} in {signature_file}"
+                            )));
+                        }
+                        None => continue,
+                    }
+                }
+                signatures
+                    .signers
+                    .entry(name.to_string())
+                    .or_default()
+                    .extend(signers.iter().cloned());
+            }
+        }
+        if !signed {
+            return Ok(None);
+        }
+
+        for (name, section) in named_sections {
+            let digests = section.digests("-Digest");
+            if !digests.is_empty() {
+                signatures.digests.insert(name.to_string(), digests);
+            }
+        }
+        Ok(Some(signatures))
+    }
+
+    /// Verify the contents of an entry against its manifest digests; entries without a manifest
+    /// digest are not signed and are not checked.
+    ///
+    /// # Errors
+    /// if a digest of the entry does not match the manifest.
+    pub(crate) fn verify(&self, name: &str, bytes: &[u8]) -> Result<()> {
+        let Some(digests) = self.digests.get(name) else {
+            return Ok(());
+        };
+        for (algorithm, digest) in digests {
+            if algorithm.digest(bytes) != *digest {
+                return Err(SecurityError(format!(
+                    "{algorithm} digest error for {name}"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the signers of an entry.
+    pub(crate) fn signers(&self, name: &str) -> Vec<CodeSigner> {
+        self.signers.get(name).cloned().unwrap_or_default()
+    }
+}
+
+/// Get the signature block file of a signature file, e.g. `META-INF/SIGNER.RSA` for
+/// `META-INF/SIGNER.SF`.
+fn signature_block_file<'a>(file_names: &'a [String], base_name: &str) -> Option<&'a str> {
+    file_names
+        .iter()
+        .find(|file_name| {
+            file_name
+                .strip_prefix("META-INF/")
+                .and_then(|name| name.rsplit_once('.'))
+                .is_some_and(|(name, extension)| {
+                    name == base_name
+                        && SIGNATURE_BLOCK_EXTENSIONS
+                            .iter()
+                            .any(|block| extension.eq_ignore_ascii_case(block))
+                })
+        })
+        .map(String::as_str)
+}
+
+/// Verify a PKCS #7 signature block over the contents of a signature file, returning the signers.
+///
+/// # Errors
+/// if the signature block cannot be decoded, uses an unsupported algorithm or does not verify.
+fn verify_signature_block(
+    signature_file: &[u8],
+    block: &[u8],
+) -> std::result::Result<Vec<CodeSigner>, String> {
+    let content_info = ContentInfo::from_der(block).map_err(|error| error.to_string())?;
+    let signed_data = content_info
+        .content
+        .decode_as::<SignedData>()
+        .map_err(|error| error.to_string())?;
+    let certificates = signed_data
+        .certificates
+        .as_ref()
+        .map(|certificates| {
+            certificates
+                .0
+                .iter()
+                .filter_map(|certificate| match certificate {
+                    CertificateChoices::Certificate(certificate) => Some(certificate),
+                    CertificateChoices::Other(_) => None,
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let mut signers = Vec::new();
+    for signer_info in signed_data.signer_infos.0.iter() {
+        let certificate = certificates
+            .iter()
+            .find(|certificate| is_signer(certificate, &signer_info.sid))
+            .ok_or("signer certificate not found")?;
+        verify_signer(signature_file, signer_info, certificate)?;
+        signers.push(code_signer(certificate, &certificates)?);
+    }
+    if signers.is_empty() {
+        return Err("no signers".to_string());
+    }
+    Ok(signers)
+}
+
+/// Check if a certificate is identified by a signer identifier.
+fn is_signer(certificate: &Certificate, sid: &SignerIdentifier) -> bool {
+    let tbs_certificate = &certificate.tbs_certificate;
+    match sid {
+        SignerIdentifier::IssuerAndSerialNumber(issuer_and_serial_number) => {
+            tbs_certificate.issuer == issuer_and_serial_number.issuer
+                && tbs_certificate.serial_number == issuer_and_serial_number.serial_number
+        }
+        SignerIdentifier::SubjectKeyIdentifier(subject_key_identifier) => tbs_certificate
+            .extensions
+            .iter()
+            .flatten()
+            .filter(|extension| extension.extn_id == ID_SUBJECT_KEY_IDENTIFIER)
+            .filter_map(|extension| {
+                SubjectKeyIdentifier::from_der(extension.extn_value.as_bytes()).ok()
+            })
+            .any(|identifier| identifier == *subject_key_identifier),
+    }
+}
+
+/// Verify the signature of a signer over the contents of a signature file.  When the signer has
+/// signed attributes, the signature is over the attributes, and the message digest attribute
+/// must match the signature file.
+///
+/// # Errors
+/// if the signature uses an unsupported algorithm or does not verify.
+fn verify_signer(
+    signature_file: &[u8],
+    signer_info: &SignerInfo,
+    certificate: &Certificate,
+) -> std::result::Result<(), String> {
+    let algorithm = DigestAlgorithm::from_oid(&signer_info.digest_alg.oid).ok_or_else(|| {
+        format!(
+            "unsupported digest algorithm {}",
+            signer_info.digest_alg.oid
+        )
+    })?;
+    let signed_bytes = match &signer_info.signed_attrs {
+        Some(signed_attributes) => {
+            let message_digest = signed_attributes
+                .iter()
+                .find(|attribute| attribute.oid == ID_MESSAGE_DIGEST)
+                .and_then(|attribute| attribute.values.iter().next())
+                .and_then(|value| value.decode_as::<OctetString>().ok())
+                .ok_or("message digest attribute not found")?;
+            if message_digest.as_bytes() != algorithm.digest(signature_file).as_slice() {
+                return Err("message digest does not match the signature file".to_string());
+            }
+            signed_attributes
+                .to_der()
+                .map_err(|error| error.to_string())?
+        }
+        None => signature_file.to_vec(),
+    };
+    let digest = algorithm.digest(&signed_bytes);
+    let signature = signer_info.signature.as_bytes();
+    let public_key = certificate
+        .tbs_certificate
+        .subject_public_key_info
+        .to_der()
+        .map_err(|error| error.to_string())?;
+
+    if let Ok(key) = RsaPublicKey::from_public_key_der(&public_key) {
+        return key
+            .verify(algorithm.pkcs1v15(), &digest, signature)
+            .map_err(|error| error.to_string());
+    }
+    if let Ok(key) = p256::ecdsa::VerifyingKey::from_public_key_der(&public_key) {
+        let signature =
+            p256::ecdsa::Signature::from_der(signature).map_err(|error| error.to_string())?;
+        return key
+            .verify_prehash(&digest, &signature)
+            .map_err(|error| error.to_string());
+    }
+    if let Ok(key) = p384::ecdsa::VerifyingKey::from_public_key_der(&public_key) {
+        let signature =
+            p384::ecdsa::Signature::from_der(signature).map_err(|error| error.to_string())?;
+        return key
+            .verify_prehash(&digest, &signature)
+            .map_err(|error| error.to_string());
+    }
+    Err(format!(
+        "unsupported public key algorithm {}",
+        certificate
+            .tbs_certificate
+            .subject_public_key_info
+            .algorithm
+            .oid
+    ))
+}
+
+/// Create the code signer of a certificate, following its issuers through the certificates of the
+/// signature block.
+///
+/// # Errors
+/// if a certificate cannot be encoded.
+fn code_signer(
+    certificate: &Certificate,
+    certificates: &[&Certificate],
+) -> std::result::Result<CodeSigner, String> {
+    let tbs_certificate = &certificate.tbs_certificate;
+    let mut certificate_chain = vec![certificate.to_der().map_err(|error| error.to_string())?];
+    let mut current = certificate;
+    while current.tbs_certificate.issuer != current.tbs_certificate.subject
+        && certificate_chain.len() <= certificates.len()
+    {
+        let Some(issuer) = certificates
+            .iter()
+            .find(|issuer| issuer.tbs_certificate.subject == current.tbs_certificate.issuer)
+        else {
+            break;
+        };
+        certificate_chain.push(issuer.to_der().map_err(|error| error.to_string())?);
+        current = issuer;
+    }
+    let serial_number = tbs_certificate.serial_number.as_bytes().iter().fold(
+        String::new(),
+        |mut serial_number, byte| {
+            let _ = write!(serial_number, "{byte:02x}");
+            serial_number
+        },
+    );
+    Ok(CodeSigner {
+        subject: tbs_certificate.subject.to_string(),
+        issuer: tbs_certificate.issuer.to_string(),
+        serial_number,
+        certificate_chain,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections() {
+        let manifest = b"Manifest-Version: 1.0\r\n\r\nName: a/very/long/na\r\n me.class\r\nSHA-256-Digest: AAAA\r\n\r\nName: b.class\nSHA1-Digest: AAAA\n";
+        let sections = sections(manifest);
+        assert_eq!(3, sections.len());
+        assert_eq!(b"Manifest-Version: 1.0\r\n\r\n", sections[0].bytes);
+        assert_eq!(
+            Some("a/very/long/name.class"),
+            sections[1].attribute("name")
+        );
+        assert!(sections[1].bytes.ends_with(b"AAAA\r\n\r\n"));
+        assert_eq!(Some("b.class"), sections[2].attribute("Name"));
+        assert_eq!(
+            vec![(DigestAlgorithm::Sha1, vec![0, 0, 0])],
+            sections[2].digests("-Digest")
+        );
+    }
+
+    #[test]
+    fn test_digest_algorithm() {
+        assert_eq!(
+            Some(DigestAlgorithm::Sha1),
+            DigestAlgorithm::from_name("SHA1")
+        );
+        assert_eq!(
+            Some(DigestAlgorithm::Sha256),
+            DigestAlgorithm::from_name("sha-256")
+        );
+        assert_eq!(None, DigestAlgorithm::from_name("MD5"));
+        assert_eq!(
+            Some(DigestAlgorithm::Sha512),
+            DigestAlgorithm::from_oid(&ID_SHA_512)
+        );
+        assert_eq!(32, DigestAlgorithm::Sha256.digest(b"").len());
+    }
+
+    #[test]
+    fn test_unsigned() -> Result<()> {
+        let file_names = vec![MANIFEST.to_string(), "Foo.class".to_string()];
+        let signatures = JarSignatures::read(&file_names, |_| Ok(None))?;
+        assert!(signatures.is_none());
+        Ok(())
+    }
+}
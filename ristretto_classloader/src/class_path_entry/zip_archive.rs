@@ -0,0 +1,623 @@
+use crate::Error::ArchiveError;
+use crate::Result;
+use flate2::read::DeflateDecoder;
+use flate2::Crc;
+use indexmap::IndexMap;
+use std::io;
+use std::io::Read;
+use std::ops::Range;
+use std::sync::OnceLock;
+
+const LOCAL_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+const LOCAL_HEADER_SIZE: usize = 30;
+const CENTRAL_HEADER_SIGNATURE: u32 = 0x0201_4b50;
+const CENTRAL_HEADER_SIZE: usize = 46;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0605_4b50;
+const END_OF_CENTRAL_DIRECTORY_SIZE: usize = 22;
+const ZIP64_LOCATOR_SIGNATURE: u32 = 0x0706_4b50;
+const ZIP64_LOCATOR_SIZE: usize = 20;
+const ZIP64_END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0606_4b50;
+const ZIP64_END_OF_CENTRAL_DIRECTORY_SIZE: usize = 56;
+const ZIP64_EXTRA_FIELD: u16 = 0x0001;
+const MAX_COMMENT_SIZE: usize = 0xFFFF;
+/// The maximum number of bytes preallocated for a decompressed entry; the size that the central
+/// directory records is not trusted beyond this, and larger entries grow as they are read.
+const MAX_PREALLOCATION_SIZE: u64 = 0x10_0000;
+
+const STORED: u16 = 0;
+const DEFLATED: u16 = 8;
+const ENCRYPTED: u16 = 0x0001;
+
+/// A source of bytes that is read at a position instead of from a cursor, so that entries of an
+/// archive can be read concurrently through a shared reference.
+pub(crate) trait ReadAt {
+    /// Get the length of the source.
+    ///
+    /// # Errors
+    /// if the length cannot be read.
+    fn length(&self) -> io::Result<u64>;
+
+    /// Read the exact number of bytes required to fill the buffer at a position of the source.
+    ///
+    /// # Errors
+    /// if the bytes cannot be read.
+    fn read_exact_at(&self, buffer: &mut [u8], position: u64) -> io::Result<()>;
+}
+
+impl<T: AsRef<[u8]>> ReadAt for T {
+    fn length(&self) -> io::Result<u64> {
+        Ok(self.as_ref().len() as u64)
+    }
+
+    fn read_exact_at(&self, buffer: &mut [u8], position: u64) -> io::Result<()> {
+        let bytes = usize::try_from(position)
+            .ok()
+            .and_then(|start| Some(start..start.checked_add(buffer.len())?))
+            .and_then(|range| self.as_ref().get(range))
+            .ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))?;
+        buffer.copy_from_slice(bytes);
+        Ok(())
+    }
+}
+
+/// A zip archive that is read on demand.  Opening the archive reads only the central directory;
+/// the offset of the data of an entry is read from its local header when the entry is first read
+/// and cached, and entries are decompressed when they are read.  Entries are read at their
+/// positions, so an archive can be read by multiple threads at once.
+/// See: <https://pkware.cachefly.net/webdocs/casestudies/APPNOTE.TXT>
+#[derive(Debug)]
+pub(crate) struct ZipArchive<R: ReadAt> {
+    reader: R,
+    entries: IndexMap<String, ZipEntry>,
+}
+
+/// An entry of the central directory of a zip archive.
+#[derive(Debug)]
+struct ZipEntry {
+    flags: u16,
+    compression_method: u16,
+    crc32: u32,
+    compressed_size: u64,
+    size: u64,
+    header_offset: u64,
+    data_offset: OnceLock<u64>,
+}
+
+/// Implement the `ZipArchive` struct.
+impl<R: ReadAt> ZipArchive<R> {
+    /// Open a zip archive, reading its central directory.
+    ///
+    /// # Errors
+    /// if the central directory cannot be read or is invalid.
+    pub(crate) fn new(reader: R) -> Result<Self> {
+        let length = reader.length()?;
+        let (directory_start, archive_offset, entry_count, directory_size) =
+            find_central_directory(&reader, length)?;
+        if directory_start
+            .checked_add(directory_size)
+            .is_none_or(|directory_end| directory_end > length)
+        {
+            return Err(ArchiveError(
+                "Invalid archive: central directory extends past the end of the archive"
+                    .to_string(),
+            ));
+        }
+        let directory = read_at(&reader, directory_start, usize::try_from(directory_size)?)?;
+
+        let capacity = usize::try_from(entry_count)?.min(directory.len() / CENTRAL_HEADER_SIZE);
+        let mut entries = IndexMap::with_capacity(capacity);
+        let mut position = 0;
+        for _ in 0..entry_count {
+            let (name, entry, size) = read_central_header(&directory, position, archive_offset)?;
+            if entry.header_offset.saturating_add(entry.compressed_size) > directory_start {
+                return Err(ArchiveError(format!(
+                    "Invalid archive: entry extends into the central directory: {name}"
+                )));
+            }
+            entries.entry(name).or_insert(entry);
+            position += size;
+        }
+
+        Ok(Self { reader, entries })
+    }
+
+    /// Get the names of the entries in the archive, in the order of the central directory.
+    pub(crate) fn file_names(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    /// Get the range of the data of an entry that is stored without compression, or `None` if
+    /// the entry does not exist or is compressed.  The data is not read, so the caller must
+    /// [verify the checksum](ZipArchive::verify_checksum) of the data.
+    ///
+    /// # Errors
+    /// if the local header of the entry cannot be read, or the data of the entry does not match
+    /// its sizes or extends past the end of the archive.
+    pub(crate) fn stored_range(&self, name: &str) -> Result<Option<Range<u64>>> {
+        let Some(entry) = self.entries.get(name) else {
+            return Ok(None);
+        };
+        if entry.compression_method != STORED || entry.flags & ENCRYPTED != 0 {
+            return Ok(None);
+        }
+        entry.verify_stored_size(name)?;
+        let start = entry.data_offset(&self.reader)?;
+        let length = self.reader.length()?;
+        match start.checked_add(entry.size) {
+            Some(end) if end <= length => Ok(Some(start..end)),
+            _ => Err(ArchiveError(format!(
+                "Invalid archive: entry extends past the end of the archive: {name}"
+            ))),
+        }
+    }
+
+    /// Verify the checksum of the data of an entry.
+    ///
+    /// # Errors
+    /// if the entry does not exist or the checksum of the data does not match the entry.
+    pub(crate) fn verify_checksum(&self, name: &str, bytes: &[u8]) -> Result<()> {
+        let Some(entry) = self.entries.get(name) else {
+            return Err(ArchiveError(format!("Entry not found: {name}")));
+        };
+        let mut crc = Crc::new();
+        crc.update(bytes);
+        if crc.sum() != entry.crc32 {
+            return Err(ArchiveError(format!("Invalid checksum: {name}")));
+        }
+        Ok(())
+    }
+
+    /// Read and decompress an entry, or return `None` if the entry does not exist.
+    ///
+    /// # Errors
+    /// if the entry cannot be read, is encrypted or compressed with an unsupported method, or
+    /// fails its checksum.
+    pub(crate) fn read_file(&self, name: &str) -> Result<Option<Vec<u8>>> {
+        let Some(entry) = self.entries.get(name) else {
+            return Ok(None);
+        };
+        if entry.flags & ENCRYPTED != 0 {
+            return Err(ArchiveError(format!("Encrypted entry: {name}")));
+        }
+        let data_offset = entry.data_offset(&self.reader)?;
+        let data = read_at(
+            &self.reader,
+            data_offset,
+            usize::try_from(entry.compressed_size)?,
+        )?;
+        let bytes = match entry.compression_method {
+            STORED => {
+                entry.verify_stored_size(name)?;
+                data
+            }
+            DEFLATED => {
+                let capacity = entry.size.min(MAX_PREALLOCATION_SIZE);
+                let mut bytes = Vec::with_capacity(usize::try_from(capacity)?);
+                DeflateDecoder::new(data.as_slice())
+                    .take(entry.size)
+                    .read_to_end(&mut bytes)?;
+                bytes
+            }
+            method => {
+                return Err(ArchiveError(format!(
+                    "Unsupported compression method {method}: {name}"
+                )))
+            }
+        };
+        self.verify_checksum(name, &bytes)?;
+        Ok(Some(bytes))
+    }
+}
+
+/// Implement the `ZipEntry` struct.
+impl ZipEntry {
+    /// Verify that the size of an entry that is stored without compression is its compressed
+    /// size.
+    ///
+    /// # Errors
+    /// if the sizes differ.
+    fn verify_stored_size(&self, name: &str) -> Result<()> {
+        if self.size != self.compressed_size {
+            return Err(ArchiveError(format!(
+                "Invalid archive: stored entry size does not match its compressed size: {name}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Get the offset of the data of the entry, reading the local header of the entry on first
+    /// use.
+    ///
+    /// # Errors
+    /// if the local header cannot be read or is invalid.
+    fn data_offset<R: ReadAt>(&self, reader: &R) -> Result<u64> {
+        if let Some(data_offset) = self.data_offset.get() {
+            return Ok(*data_offset);
+        }
+        let header = read_at(reader, self.header_offset, LOCAL_HEADER_SIZE)?;
+        if u32_at(&header, 0)? != LOCAL_HEADER_SIGNATURE {
+            return Err(ArchiveError(
+                "Invalid archive: invalid local header".to_string(),
+            ));
+        }
+        let name_length = u64::from(u16_at(&header, 26)?);
+        let extra_length = u64::from(u16_at(&header, 28)?);
+        let data_offset =
+            self.header_offset + LOCAL_HEADER_SIZE as u64 + name_length + extra_length;
+        Ok(*self.data_offset.get_or_init(|| data_offset))
+    }
+}
+
+/// Find the central directory of an archive; returns the position of the central directory, the
+/// offset of the archive in the file, the number of entries and the size of the central
+/// directory.  Data may be prepended to an archive, e.g. a launcher script, in which case the
+/// offsets that the archive records are relative to the start of the archive.
+///
+/// # Errors
+/// if the end of central directory record cannot be found or is invalid.
+fn find_central_directory<R: ReadAt>(reader: &R, length: u64) -> Result<(u64, u64, u64, u64)> {
+    let tail_length = length.min((END_OF_CENTRAL_DIRECTORY_SIZE + MAX_COMMENT_SIZE) as u64);
+    let tail_start = length - tail_length;
+    let tail = read_at(reader, tail_start, usize::try_from(tail_length)?)?;
+    let position = (0..=tail.len().saturating_sub(END_OF_CENTRAL_DIRECTORY_SIZE))
+        .rev()
+        .find(|&position| {
+            u32_at(&tail, position).ok() == Some(END_OF_CENTRAL_DIRECTORY_SIGNATURE)
+                && u16_at(&tail, position + 20).is_ok_and(|comment_length| {
+                    position + END_OF_CENTRAL_DIRECTORY_SIZE + usize::from(comment_length)
+                        <= tail.len()
+                })
+        })
+        .ok_or_else(|| ArchiveError("Invalid archive: central directory not found".to_string()))?;
+    let record_position = tail_start + position as u64;
+
+    let entry_count = u64::from(u16_at(&tail, position + 10)?);
+    let directory_size = u64::from(u32_at(&tail, position + 12)?);
+    let directory_offset = u64::from(u32_at(&tail, position + 16)?);
+    if entry_count == 0xFFFF || directory_size == 0xFFFF_FFFF || directory_offset == 0xFFFF_FFFF {
+        if let Some(zip64) = find_zip64_central_directory(reader, record_position)? {
+            return Ok(zip64);
+        }
+    }
+
+    let directory_start = record_position
+        .checked_sub(directory_size)
+        .ok_or_else(|| ArchiveError("Invalid archive: invalid central directory".to_string()))?;
+    let archive_offset = directory_start
+        .checked_sub(directory_offset)
+        .ok_or_else(|| ArchiveError("Invalid archive: invalid central directory".to_string()))?;
+    Ok((directory_start, archive_offset, entry_count, directory_size))
+}
+
+/// Find the zip64 central directory of an archive with more than 65535 entries or larger than
+/// 4 GiB, or `None` if the archive does not have a zip64 locator.
+///
+/// # Errors
+/// if the zip64 end of central directory record cannot be read or is invalid.
+fn find_zip64_central_directory<R: ReadAt>(
+    reader: &R,
+    record_position: u64,
+) -> Result<Option<(u64, u64, u64, u64)>> {
+    let Some(locator_position) = record_position.checked_sub(ZIP64_LOCATOR_SIZE as u64) else {
+        return Ok(None);
+    };
+    let locator = read_at(reader, locator_position, ZIP64_LOCATOR_SIZE)?;
+    if u32_at(&locator, 0)? != ZIP64_LOCATOR_SIGNATURE {
+        return Ok(None);
+    }
+    // The record usually directly precedes the locator; if it is not at the position that the
+    // locator records, data is prepended to the archive
+    let recorded_position = u64_at(&locator, 8)?;
+    let mut archive_offset = 0;
+    let mut record = read_at(
+        reader,
+        recorded_position,
+        ZIP64_END_OF_CENTRAL_DIRECTORY_SIZE,
+    )?;
+    if u32_at(&record, 0).ok() != Some(ZIP64_END_OF_CENTRAL_DIRECTORY_SIGNATURE) {
+        let invalid =
+            || ArchiveError("Invalid archive: invalid zip64 central directory".to_string());
+        let position = locator_position
+            .checked_sub(ZIP64_END_OF_CENTRAL_DIRECTORY_SIZE as u64)
+            .ok_or_else(invalid)?;
+        archive_offset = position
+            .checked_sub(recorded_position)
+            .ok_or_else(invalid)?;
+        record = read_at(reader, position, ZIP64_END_OF_CENTRAL_DIRECTORY_SIZE)?;
+        if u32_at(&record, 0)? != ZIP64_END_OF_CENTRAL_DIRECTORY_SIGNATURE {
+            return Err(invalid());
+        }
+    }
+    let entry_count = u64_at(&record, 32)?;
+    let directory_size = u64_at(&record, 40)?;
+    let directory_start = u64_at(&record, 48)?
+        .checked_add(archive_offset)
+        .ok_or_else(|| {
+            ArchiveError("Invalid archive: invalid zip64 central directory".to_string())
+        })?;
+    Ok(Some((
+        directory_start,
+        archive_offset,
+        entry_count,
+        directory_size,
+    )))
+}
+
+/// Read the central directory header at a position of the central directory; returns the name of
+/// the entry, the entry and the size of the header.
+///
+/// # Errors
+/// if the header is invalid.
+fn read_central_header(
+    directory: &[u8],
+    position: usize,
+    archive_offset: u64,
+) -> Result<(String, ZipEntry, usize)> {
+    if u32_at(directory, position)? != CENTRAL_HEADER_SIGNATURE {
+        return Err(ArchiveError(
+            "Invalid archive: invalid central directory header".to_string(),
+        ));
+    }
+    let name_length = usize::from(u16_at(directory, position + 28)?);
+    let extra_length = usize::from(u16_at(directory, position + 30)?);
+    let comment_length = usize::from(u16_at(directory, position + 32)?);
+    let name_start = position + CENTRAL_HEADER_SIZE;
+    let extra_start = name_start + name_length;
+    let name = bytes_at(directory, name_start, name_length)?;
+    let extra = bytes_at(directory, extra_start, extra_length)?;
+
+    let mut entry = ZipEntry {
+        flags: u16_at(directory, position + 8)?,
+        compression_method: u16_at(directory, position + 10)?,
+        crc32: u32_at(directory, position + 16)?,
+        compressed_size: u64::from(u32_at(directory, position + 20)?),
+        size: u64::from(u32_at(directory, position + 24)?),
+        header_offset: u64::from(u32_at(directory, position + 42)?),
+        data_offset: OnceLock::new(),
+    };
+    read_zip64_extra_field(&mut entry, extra)?;
+    entry.header_offset += archive_offset;
+
+    let name = String::from_utf8_lossy(name).to_string();
+    let size = CENTRAL_HEADER_SIZE + name_length + extra_length + comment_length;
+    Ok((name, entry, size))
+}
+
+/// Read the sizes and offset of an entry from the zip64 extended information extra field; the
+/// field contains only the values that do not fit in the central directory header.
+///
+/// # Errors
+/// if the extra field is invalid.
+fn read_zip64_extra_field(entry: &mut ZipEntry, extra: &[u8]) -> Result<()> {
+    let mut position = 0;
+    while position + 4 <= extra.len() {
+        let id = u16_at(extra, position)?;
+        let length = usize::from(u16_at(extra, position + 2)?);
+        let field = bytes_at(extra, position + 4, length)?;
+        position += 4 + length;
+        if id != ZIP64_EXTRA_FIELD {
+            continue;
+        }
+
+        let mut offset = 0;
+        for value in [
+            &mut entry.size,
+            &mut entry.compressed_size,
+            &mut entry.header_offset,
+        ] {
+            if *value == 0xFFFF_FFFF {
+                *value = u64_at(field, offset)?;
+                offset += 8;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Read bytes at a position of a reader.
+///
+/// # Errors
+/// if the bytes cannot be read.
+fn read_at<R: ReadAt>(reader: &R, position: u64, length: usize) -> Result<Vec<u8>> {
+    let mut bytes = vec![0; length];
+    reader.read_exact_at(&mut bytes, position)?;
+    Ok(bytes)
+}
+
+/// Get bytes at a position of a buffer.
+///
+/// # Errors
+/// if the buffer is too short.
+fn bytes_at(bytes: &[u8], position: usize, length: usize) -> Result<&[u8]> {
+    bytes
+        .get(position..position + length)
+        .ok_or_else(|| ArchiveError("Invalid archive: unexpected end of data".to_string()))
+}
+
+/// Get a little endian `u16` at a position of a buffer.
+///
+/// # Errors
+/// if the buffer is too short.
+fn u16_at(bytes: &[u8], position: usize) -> Result<u16> {
+    let bytes = bytes_at(bytes, position, 2)?;
+    Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+/// Get a little endian `u32` at a position of a buffer.
+///
+/// # Errors
+/// if the buffer is too short.
+fn u32_at(bytes: &[u8], position: usize) -> Result<u32> {
+    let bytes = bytes_at(bytes, position, 4)?;
+    Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Get a little endian `u64` at a position of a buffer.
+///
+/// # Errors
+/// if the buffer is too short.
+fn u64_at(bytes: &[u8], position: usize) -> Result<u64> {
+    let bytes = bytes_at(bytes, position, 8)?;
+    let mut value = [0; 8];
+    value.copy_from_slice(bytes);
+    Ok(u64::from_le_bytes(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Write};
+    use zip::write::SimpleFileOptions;
+    use zip::CompressionMethod;
+
+    fn archive() -> Result<Vec<u8>> {
+        let mut archive = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        archive.start_file("deflated.txt", SimpleFileOptions::default())?;
+        archive.write_all(b"deflated deflated deflated")?;
+        let stored = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+        archive.start_file("stored.txt", stored)?;
+        archive.write_all(b"stored")?;
+        archive.add_directory("directory/", SimpleFileOptions::default())?;
+        archive.set_comment("comment");
+        Ok(archive.finish()?.into_inner())
+    }
+
+    #[test]
+    fn test_read_file() -> Result<()> {
+        let bytes = archive()?;
+        let archive = ZipArchive::new(bytes.as_slice())?;
+        assert_eq!(
+            vec!["deflated.txt", "stored.txt", "directory/"],
+            archive.file_names().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some(b"deflated deflated deflated".to_vec()),
+            archive.read_file("deflated.txt")?
+        );
+        assert_eq!(Some(b"stored".to_vec()), archive.read_file("stored.txt")?);
+        assert_eq!(None, archive.read_file("missing.txt")?);
+
+        let range = archive.stored_range("stored.txt")?.unwrap_or_default();
+        let range = usize::try_from(range.start)?..usize::try_from(range.end)?;
+        assert_eq!(b"stored", &bytes[range]);
+        assert_eq!(None, archive.stored_range("deflated.txt")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_prepended_data() -> Result<()> {
+        let mut bytes = b"#!/bin/sh\nexec java -jar \"$0\" \"$@\"\n".to_vec();
+        bytes.extend(archive()?);
+        let archive = ZipArchive::new(bytes)?;
+        assert_eq!(Some(b"stored".to_vec()), archive.read_file("stored.txt")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_archive() {
+        let result = ZipArchive::new(b"not an archive".to_vec());
+        assert!(matches!(result, Err(ArchiveError(_))));
+        let result = ZipArchive::new(Vec::new());
+        assert!(matches!(result, Err(ArchiveError(_))));
+    }
+
+    /// Create an archive with only a zip64 end of central directory record that records a central
+    /// directory of the given size and offset.
+    fn zip64_archive(directory_size: u64, directory_offset: u64) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend(ZIP64_END_OF_CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes());
+        bytes.extend(44u64.to_le_bytes());
+        bytes.extend([45, 0, 45, 0]);
+        bytes.extend([0; 8]);
+        bytes.extend(1u64.to_le_bytes());
+        bytes.extend(1u64.to_le_bytes());
+        bytes.extend(directory_size.to_le_bytes());
+        bytes.extend(directory_offset.to_le_bytes());
+        bytes.extend(ZIP64_LOCATOR_SIGNATURE.to_le_bytes());
+        bytes.extend(0u32.to_le_bytes());
+        bytes.extend(0u64.to_le_bytes());
+        bytes.extend(1u32.to_le_bytes());
+        bytes.extend(END_OF_CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes());
+        bytes.extend([0; 4]);
+        bytes.extend([0xFF; 12]);
+        bytes.extend([0; 2]);
+        bytes
+    }
+
+    #[test]
+    fn test_invalid_zip64_central_directory() {
+        let result = ZipArchive::new(zip64_archive(u64::MAX, 0));
+        assert!(matches!(result, Err(ArchiveError(_))));
+        let result = ZipArchive::new(zip64_archive(0x10_0000, 0));
+        assert!(matches!(result, Err(ArchiveError(_))));
+        let result = ZipArchive::new(zip64_archive(0, u64::MAX));
+        assert!(matches!(result, Err(ArchiveError(_))));
+    }
+
+    /// Find the position of the local or central header of an entry in an archive.
+    fn header_position(
+        bytes: &[u8],
+        signature: u32,
+        header_size: usize,
+        name: &str,
+    ) -> Result<usize> {
+        bytes
+            .windows(header_size + name.len())
+            .position(|window| {
+                window.starts_with(&signature.to_le_bytes()) && window.ends_with(name.as_bytes())
+            })
+            .ok_or_else(|| ArchiveError(format!("Header not found: {name}")))
+    }
+
+    #[test]
+    fn test_malformed_stored_entry() -> Result<()> {
+        let mut bytes = archive()?;
+        let position = header_position(
+            &bytes,
+            CENTRAL_HEADER_SIGNATURE,
+            CENTRAL_HEADER_SIZE,
+            "stored.txt",
+        )?;
+        // Record an uncompressed size that is larger than the stored data
+        bytes[position + 24..position + 28].copy_from_slice(&0xFFFFu32.to_le_bytes());
+        let archive = ZipArchive::new(bytes)?;
+        let result = archive.stored_range("stored.txt");
+        assert!(matches!(result, Err(ArchiveError(_))));
+        let result = archive.read_file("stored.txt");
+        assert!(matches!(result, Err(ArchiveError(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_stored_entry_past_end_of_archive() -> Result<()> {
+        let mut bytes = archive()?;
+        let position = header_position(
+            &bytes,
+            LOCAL_HEADER_SIGNATURE,
+            LOCAL_HEADER_SIZE,
+            "stored.txt",
+        )?;
+        // Record an extra field length that moves the data past the end of the archive
+        bytes[position + 28..position + 30].copy_from_slice(&0xFFFFu16.to_le_bytes());
+        let archive = ZipArchive::new(bytes)?;
+        let result = archive.stored_range("stored.txt");
+        assert!(matches!(result, Err(ArchiveError(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_checksum() -> Result<()> {
+        let mut bytes = archive()?;
+        let range = ZipArchive::new(bytes.as_slice())?
+            .stored_range("stored.txt")?
+            .unwrap_or_default();
+        bytes[usize::try_from(range.start)?] = b'S';
+        let archive = ZipArchive::new(bytes.as_slice())?;
+        let result = archive.read_file("stored.txt");
+        assert!(matches!(result, Err(ArchiveError(_))));
+        let range = usize::try_from(range.start)?..usize::try_from(range.end)?;
+        let result = archive.verify_checksum("stored.txt", &bytes[range]);
+        assert!(matches!(result, Err(ArchiveError(_))));
+        Ok(())
+    }
+}
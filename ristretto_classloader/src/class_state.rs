@@ -0,0 +1,126 @@
+use std::fmt::Display;
+
+/// The lifecycle state of a class.  A class is loaded, then linked (its super class and
+/// interfaces are resolved, verified and prepared) and then initialized.  Linking and
+/// initialization failures are recorded so that later attempts fail with the same error instead
+/// of repeating the work.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-5.html>
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ClassState {
+    /// The class has been created from a class file but has not been linked
+    #[default]
+    Loaded,
+    /// The class has been linked and is ready to be initialized
+    Linked,
+    /// Linking the class failed with the error message
+    LinkageFailed(String),
+    /// The class initializer is running on the thread with the identifier; the class is usable by
+    /// the initializing thread, and other threads wait until the initialization completes
+    Initializing(u64),
+    /// The class initializer completed successfully
+    Initialized,
+    /// The class initializer failed with the error message
+    InitializationFailed(String),
+}
+
+impl ClassState {
+    /// Check if the state can transition to the next state.
+    #[must_use]
+    pub fn can_transition_to(&self, next: &ClassState) -> bool {
+        matches!(
+            (self, next),
+            (
+                ClassState::Loaded,
+                ClassState::Linked | ClassState::LinkageFailed(_)
+            ) | (ClassState::Linked, ClassState::Initializing(_))
+                | (
+                    ClassState::Initializing(_),
+                    ClassState::Initialized | ClassState::InitializationFailed(_)
+                )
+        )
+    }
+
+    /// Check if the class has been linked successfully; classes that are initializing or have been
+    /// initialized, successfully or not, have been linked.
+    #[must_use]
+    pub fn is_linked(&self) -> bool {
+        matches!(
+            self,
+            ClassState::Linked
+                | ClassState::Initializing(_)
+                | ClassState::Initialized
+                | ClassState::InitializationFailed(_)
+        )
+    }
+}
+
+impl Display for ClassState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClassState::Loaded => write!(f, "loaded"),
+            ClassState::Linked => write!(f, "linked"),
+            ClassState::LinkageFailed(message) => write!(f, "linkage failed: {message}"),
+            ClassState::Initializing(thread_id) => write!(f, "initializing on thread {thread_id}"),
+            ClassState::Initialized => write!(f, "initialized"),
+            ClassState::InitializationFailed(message) => {
+                write!(f, "initialization failed: {message}")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        assert_eq!(ClassState::Loaded, ClassState::default());
+    }
+
+    #[test]
+    fn test_can_transition_to() {
+        let failed = || ClassState::LinkageFailed("error".to_string());
+        assert!(ClassState::Loaded.can_transition_to(&ClassState::Linked));
+        assert!(ClassState::Loaded.can_transition_to(&failed()));
+        assert!(ClassState::Linked.can_transition_to(&ClassState::Initializing(1)));
+        assert!(ClassState::Initializing(1).can_transition_to(&ClassState::Initialized));
+        assert!(ClassState::Initializing(1)
+            .can_transition_to(&ClassState::InitializationFailed("error".to_string())));
+
+        assert!(!ClassState::Loaded.can_transition_to(&ClassState::Initializing(1)));
+        assert!(!ClassState::Linked.can_transition_to(&ClassState::Loaded));
+        assert!(!failed().can_transition_to(&ClassState::Linked));
+        assert!(!ClassState::Initialized.can_transition_to(&ClassState::Initializing(1)));
+    }
+
+    #[test]
+    fn test_is_linked() {
+        assert!(!ClassState::Loaded.is_linked());
+        assert!(!ClassState::LinkageFailed("error".to_string()).is_linked());
+        assert!(ClassState::Linked.is_linked());
+        assert!(ClassState::Initializing(1).is_linked());
+        assert!(ClassState::Initialized.is_linked());
+        assert!(ClassState::InitializationFailed("error".to_string()).is_linked());
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!("loaded", ClassState::Loaded.to_string());
+        assert_eq!("linked", ClassState::Linked.to_string());
+        assert_eq!(
+            "linkage failed: error",
+            ClassState::LinkageFailed("error".to_string()).to_string()
+        );
+        assert_eq!(
+            "initializing on thread 1",
+            ClassState::Initializing(1).to_string()
+        );
+        assert_eq!("initialized", ClassState::Initialized.to_string());
+        assert_eq!(
+            "initialization failed: error",
+            ClassState::InitializationFailed("error".to_string()).to_string()
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{ClassPath, ClassPathEntry, Result};
+use std::collections::HashMap;
+use std::time::SystemTime;
+
+/// Watches the directories of a class path for class files that are added, changed or removed,
+/// e.g. by an incremental compiler.  Directories are polled by comparing the modification times
+/// of their class files with those recorded by the previous poll; jars are not watched.
+#[derive(Debug)]
+pub(crate) struct ClassWatcher {
+    directories: Vec<ClassPathEntry>,
+    modified_times: HashMap<String, SystemTime>,
+}
+
+impl ClassWatcher {
+    /// Create a watcher for the directories of a class path, recording the current modification
+    /// times of their class files.
+    ///
+    /// # Errors
+    /// if the class files of a directory cannot be read.
+    pub(crate) fn new(class_path: &ClassPath) -> Result<Self> {
+        let directories = class_path
+            .iter()
+            .filter(|entry| matches!(entry, ClassPathEntry::Directory(_)))
+            .cloned()
+            .collect();
+        let mut watcher = Self {
+            directories,
+            modified_times: HashMap::new(),
+        };
+        watcher.modified_times = watcher.scan()?;
+        Ok(watcher)
+    }
+
+    /// Get the names of the classes that were added, changed or removed since the previous poll,
+    /// sorted by name.
+    ///
+    /// # Errors
+    /// if the class files of a directory cannot be read.
+    pub(crate) fn changes(&mut self) -> Result<Vec<String>> {
+        let modified_times = self.scan()?;
+        let mut class_names = modified_times
+            .iter()
+            .filter(|(class_name, modified)| self.modified_times.get(*class_name) != Some(modified))
+            .map(|(class_name, _)| class_name.clone())
+            .collect::<Vec<_>>();
+        class_names.extend(
+            self.modified_times
+                .keys()
+                .filter(|class_name| !modified_times.contains_key(*class_name))
+                .cloned(),
+        );
+        class_names.sort();
+        self.modified_times = modified_times;
+        Ok(class_names)
+    }
+
+    /// Get the modification times of the class files in the watched directories; a class in more
+    /// than one directory is read from the first, so only its first class file is recorded.
+    fn scan(&self) -> Result<HashMap<String, SystemTime>> {
+        let mut modified_times = HashMap::new();
+        for entry in &self.directories {
+            let ClassPathEntry::Directory(directory) = entry else {
+                continue;
+            };
+            for (class_name, modified) in directory.class_modified_times()? {
+                modified_times.entry(class_name).or_insert(modified);
+            }
+        }
+        Ok(modified_times)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::time::Duration;
+
+    #[test]
+    fn test_changes() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let directory = temp_dir.path();
+        fs::write(directory.join("Foo.class"), [])?;
+        fs::write(directory.join("Bar.class"), [])?;
+        let class_path = ClassPath::from(directory.to_string_lossy());
+        let mut watcher = ClassWatcher::new(&class_path)?;
+        assert!(watcher.changes()?.is_empty());
+
+        let modified = SystemTime::now() + Duration::from_secs(60);
+        fs::File::options()
+            .write(true)
+            .open(directory.join("Foo.class"))?
+            .set_modified(modified)?;
+        fs::remove_file(directory.join("Bar.class"))?;
+        fs::write(directory.join("Baz.class"), [])?;
+        assert_eq!(vec!["Bar", "Baz", "Foo"], watcher.changes()?);
+        assert!(watcher.changes()?.is_empty());
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use crate::header::Header;
 use crate::Error::PoisonedLock;
 use crate::Result;
 use std::fmt;
@@ -7,6 +8,7 @@ use std::sync::{Arc, RwLock};
 /// A concurrent vector.
 pub struct ConcurrentVec<T: Clone + Debug + PartialEq> {
     inner: Arc<RwLock<Vec<T>>>,
+    header: Arc<Header>,
 }
 
 impl<T: Clone + Debug + PartialEq> ConcurrentVec<T> {
@@ -27,6 +29,7 @@ impl<T: Clone + Debug + PartialEq> ConcurrentVec<T> {
     pub fn from(values: Vec<T>) -> Self {
         ConcurrentVec {
             inner: Arc::new(RwLock::new(values)),
+            header: Arc::new(Header::new()),
         }
     }
 
@@ -156,6 +159,16 @@ impl<T: Clone + Debug + PartialEq> ConcurrentVec<T> {
         let vec = self.to_vec()?;
         Ok(Self::from(vec))
     }
+
+    /// Get a pointer that identifies the vector; clones of the vector share the same identity.
+    pub(crate) fn identity(&self) -> *const () {
+        Arc::as_ptr(&self.inner).cast()
+    }
+
+    /// Get the identity hash code of the vector; clones of the vector share the same hash code.
+    pub(crate) fn identity_hash_code(&self) -> i32 {
+        self.header.identity_hash_code()
+    }
 }
 
 impl<T: Clone + Debug + PartialEq> Clone for ConcurrentVec<T> {
@@ -163,6 +176,7 @@ impl<T: Clone + Debug + PartialEq> Clone for ConcurrentVec<T> {
     fn clone(&self) -> Self {
         ConcurrentVec {
             inner: Arc::clone(&self.inner),
+            header: Arc::clone(&self.header),
         }
     }
 }
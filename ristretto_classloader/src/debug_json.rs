@@ -0,0 +1,139 @@
+use crate::{Object, Reference, Result, Value};
+use ristretto_classfile::{BaseType, FieldType};
+use serde_json::{json, Map};
+use std::collections::HashMap;
+
+/// Renders values as JSON for debugging.  Every object and array that is rendered is assigned an
+/// `id`; subsequent references to the same instance are rendered as a `ref` to the `id` so that
+/// cyclic object graphs can be rendered.
+#[derive(Debug, Default)]
+pub(crate) struct DebugJson {
+    ids: HashMap<*const (), usize>,
+}
+
+impl DebugJson {
+    /// Render a value; references are expanded up to `depth` levels.
+    pub(crate) fn value(
+        &mut self,
+        value: &Value,
+        field_type: Option<&FieldType>,
+        depth: usize,
+    ) -> Result<serde_json::Value> {
+        let json = match value {
+            Value::Int(value) => match field_type {
+                Some(FieldType::Base(BaseType::Boolean)) => json!(*value != 0),
+                Some(FieldType::Base(BaseType::Char)) => {
+                    let character = u32::try_from(*value)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .unwrap_or(char::REPLACEMENT_CHARACTER);
+                    json!(character.to_string())
+                }
+                _ => json!(value),
+            },
+            Value::Long(value) => json!(value),
+            Value::Float(value) => float(f64::from(*value)),
+            Value::Double(value) => float(*value),
+            Value::Object(Some(reference)) => self.reference(reference, depth)?,
+            Value::Object(None) | Value::Unused => serde_json::Value::Null,
+        };
+        Ok(json)
+    }
+
+    /// Render a reference; references are expanded up to `depth` levels.
+    pub(crate) fn reference(
+        &mut self,
+        reference: &Reference,
+        depth: usize,
+    ) -> Result<serde_json::Value> {
+        let class_name = reference.class_name();
+        let identity = reference.identity();
+        if let Some(id) = self.ids.get(&identity) {
+            return Ok(json!({ "class": class_name, "ref": id }));
+        }
+
+        let mut json = Map::new();
+        json.insert("class".to_string(), json!(class_name));
+        json.insert(
+            "identity_hash".to_string(),
+            json!(reference.identity_hash_code()),
+        );
+        if depth == 0 {
+            json.insert("truncated".to_string(), json!(true));
+            return Ok(serde_json::Value::Object(json));
+        }
+
+        let id = self.ids.len() + 1;
+        self.ids.insert(identity, id);
+        json.insert("id".to_string(), json!(id));
+        let elements = match reference {
+            Reference::ByteArray(array) => Some(json!(array.to_vec()?)),
+            Reference::CharArray(array) => Some(json!(array.to_vec()?)),
+            Reference::ShortArray(array) => Some(json!(array.to_vec()?)),
+            Reference::IntArray(array) => Some(json!(array.to_vec()?)),
+            Reference::LongArray(array) => Some(json!(array.to_vec()?)),
+            Reference::FloatArray(array) => Some(serde_json::Value::Array(
+                array
+                    .to_vec()?
+                    .into_iter()
+                    .map(|value| float(f64::from(value)))
+                    .collect(),
+            )),
+            Reference::DoubleArray(array) => Some(serde_json::Value::Array(
+                array.to_vec()?.into_iter().map(float).collect(),
+            )),
+            Reference::Array(_, array) => {
+                let mut elements = Vec::new();
+                for element in array.to_vec()? {
+                    let element = match element {
+                        Some(reference) => self.reference(&reference, depth - 1)?,
+                        None => serde_json::Value::Null,
+                    };
+                    elements.push(element);
+                }
+                Some(serde_json::Value::Array(elements))
+            }
+            Reference::Object(object) => {
+                self.object(object, depth, &mut json)?;
+                None
+            }
+        };
+        if let Some(elements) = elements {
+            json.insert("elements".to_string(), elements);
+        }
+        Ok(serde_json::Value::Object(json))
+    }
+
+    /// Render the fields of an object.
+    fn object(
+        &mut self,
+        object: &Object,
+        depth: usize,
+        json: &mut Map<String, serde_json::Value>,
+    ) -> Result<()> {
+        if object.class().name() == "java/lang/String" {
+            let value: String = object.clone().try_into()?;
+            json.insert("string".to_string(), json!(value));
+        }
+
+        let mut fields = object.fields();
+        fields.sort_by(|a, b| a.name().cmp(b.name()));
+        let mut values = Map::new();
+        for field in fields {
+            let value = self.value(&field.value()?, Some(field.field_type()), depth - 1)?;
+            values.insert(field.name().to_string(), value);
+        }
+        json.insert("fields".to_string(), serde_json::Value::Object(values));
+        Ok(())
+    }
+}
+
+/// Render a floating point value; values that cannot be represented in JSON (`NaN` and the
+/// infinities) are rendered as strings.
+fn float(value: f64) -> serde_json::Value {
+    if value.is_finite() {
+        json!(value)
+    } else {
+        json!(value.to_string())
+    }
+}
@@ -13,6 +13,9 @@ pub enum Error {
     /// A class was not found
     #[error("Class not found: {0}")]
     ClassNotFound(String),
+    /// A class was defined more than once by the same class loader
+    #[error("Duplicate class definition: {0}")]
+    DuplicateClassDefinition(String),
     /// Specified field not found
     #[error("Field not found: {class_name}.{field_name}")]
     FieldNotFound {
@@ -28,6 +31,13 @@ pub enum Error {
     /// An error occurred while parsing a method descriptor
     #[error("Invalid method descriptor: {0}")]
     InvalidMethodDescriptor(String),
+    /// Invalid class state transition
+    #[error("Invalid state transition for class {class_name}: {from} -> {to}")]
+    InvalidStateTransition {
+        class_name: String,
+        from: String,
+        to: String,
+    },
     /// Invalid value type
     #[error("Invalid value type: {0}")]
     InvalidValueType(String),
@@ -50,6 +60,12 @@ pub enum Error {
     /// An error occurred while performing a request
     #[error(transparent)]
     RequestError(#[from] reqwest::Error),
+    /// A Java runtime is not installed and cannot be downloaded
+    #[error("Java runtime not found: {0}")]
+    RuntimeNotFound(String),
+    /// A signed jar failed verification, e.g. an entry does not match its manifest digest
+    #[error("Security error: {0}")]
+    SecurityError(String),
     /// Error serializing or deserializing data
     #[error("Serde error: {0}")]
     SerdeError(String),
@@ -59,6 +75,12 @@ pub enum Error {
     /// The requested version is not supported
     #[error("Unsupported version: {0}")]
     UnsupportedVersion(String),
+    /// A class file defines a class with a different name than the requested class
+    #[error("Wrong class name: {class_name} (expected: {expected})")]
+    WrongClassName {
+        class_name: String,
+        expected: String,
+    },
     /// An error while reading a jar or module
     #[error(transparent)]
     ZipError(#[from] zip::result::ZipError),
@@ -0,0 +1,80 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::OnceLock;
+
+/// Seed for the identity hash code generator; must not be zero.
+const IDENTITY_HASH_SEED: u32 = 0x9E37_79B9;
+
+/// State of the identity hash code generator shared by all objects.
+static IDENTITY_HASH_STATE: AtomicU32 = AtomicU32::new(IDENTITY_HASH_SEED);
+
+/// Header shared by all references to an object or array.
+///
+/// The identity hash code is generated and stored on first request rather than being derived from
+/// the location or contents of the object, so that the hash code does not change when the object
+/// is modified or relocated, and is not reused by a different object.
+#[derive(Debug, Default)]
+pub(crate) struct Header {
+    identity_hash_code: OnceLock<i32>,
+}
+
+impl Header {
+    /// Create a new header.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the identity hash code, generating it on first request.
+    pub(crate) fn identity_hash_code(&self) -> i32 {
+        *self.identity_hash_code.get_or_init(next_identity_hash_code)
+    }
+}
+
+/// Generate the next identity hash code using a Marsaglia xor-shift generator.  Like the hash codes
+/// generated by `HotSpot`, the hash code is a non-zero, non-negative 31-bit value.
+fn next_identity_hash_code() -> i32 {
+    loop {
+        let state = IDENTITY_HASH_STATE
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |state| {
+                Some(xor_shift(state))
+            })
+            .unwrap_or(IDENTITY_HASH_SEED);
+        let hash_code = xor_shift(state) & 0x7FFF_FFFF;
+        if hash_code != 0 {
+            return i32::try_from(hash_code).unwrap_or(i32::MAX);
+        }
+    }
+}
+
+/// Advance the xor-shift generator state.
+fn xor_shift(mut state: u32) -> u32 {
+    state ^= state << 13;
+    state ^= state >> 17;
+    state ^= state << 5;
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identity_hash_code_stable() {
+        let header = Header::new();
+        let hash_code = header.identity_hash_code();
+        assert!(hash_code > 0);
+        assert_eq!(hash_code, header.identity_hash_code());
+    }
+
+    #[test]
+    fn test_identity_hash_code_unique() {
+        let first = Header::new();
+        let second = Header::new();
+        assert_ne!(first.identity_hash_code(), second.identity_hash_code());
+    }
+
+    #[test]
+    fn test_xor_shift() {
+        assert_ne!(0, xor_shift(IDENTITY_HASH_SEED));
+        assert_ne!(IDENTITY_HASH_SEED, xor_shift(IDENTITY_HASH_SEED));
+    }
+}
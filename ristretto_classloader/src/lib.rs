@@ -40,9 +40,10 @@
 //!
 //! The following features are available:
 //!
-//! | Name      | Description                    | Default? |
-//! |-----------|--------------------------------|----------|
-//! | `url`     | Enables url class path entries | No       |
+//! | Name          | Description                         | Default? |
+//! |---------------|-------------------------------------|----------|
+//! | `signed-jars` | Enables verification of signed jars | No       |
+//! | `url`         | Enables url class path entries      | No       |
 //!
 //! ## Safety
 //!
@@ -56,28 +57,46 @@
 #![deny(clippy::unwrap_used)]
 
 mod class;
+mod class_index;
 mod class_loader;
 mod class_path;
 mod class_path_entry;
+mod class_state;
+mod class_watcher;
 mod concurrent_vec;
+mod debug_json;
 mod error;
 mod field;
+mod header;
 mod method;
+mod module_graph;
 mod object;
+mod quickened;
 mod reference;
+mod resources;
 pub mod runtime;
+mod services;
+#[cfg(feature = "signed-jars")]
+mod signers;
 mod value;
 
 pub use class::Class;
+pub use class_index::{ClassHeader, ClassIndex};
 pub use class_loader::ClassLoader;
 pub use class_path::ClassPath;
-pub use class_path_entry::{manifest, ClassPathEntry, Manifest};
+#[cfg(feature = "signed-jars")]
+pub use class_path_entry::CodeSigner;
+pub use class_path_entry::{manifest, ClassPathEntry, JmodSection, Manifest};
+pub use class_state::ClassState;
 pub use concurrent_vec::ConcurrentVec;
 pub use error::{Error, Result};
 pub use field::Field;
 pub use method::Method;
-pub use object::Object;
+pub use module_graph::{package_name, Module, ModuleGraph};
+pub use object::{Object, WeakObject};
+pub use quickened::{QuickInstruction, QuickenedCode};
 pub use reference::Reference;
+pub use resources::ResourceUrl;
 pub use ristretto_classfile::{BaseType, FieldAccessFlags, FieldType, MethodAccessFlags};
 pub use runtime::DEFAULT_JAVA_VERSION;
 pub use value::Value;
@@ -1,7 +1,8 @@
+use crate::quickened::QuickenedCode;
 use crate::Error::InvalidMethodDescriptor;
 use crate::Result;
 use ristretto_classfile::attributes::{Attribute, ExceptionTableEntry, Instruction, LineNumber};
-use ristretto_classfile::{BaseType, ClassFile, FieldType, MethodAccessFlags};
+use ristretto_classfile::{ClassFile, FieldType, MethodAccessFlags, MethodDescriptor};
 use std::fmt::Display;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -14,6 +15,7 @@ pub struct Method {
     max_stack: usize,
     max_locals: usize,
     code: Vec<Instruction>,
+    quickened: QuickenedCode,
     line_numbers: Vec<LineNumber>,
     exception_table: Vec<ExceptionTableEntry>,
     attributes: Vec<Attribute>,
@@ -63,6 +65,7 @@ impl Method {
         };
 
         let (parameters, return_type) = Method::parse_descriptor(descriptor.as_ref())?;
+        let quickened = QuickenedCode::new(code.len());
         Ok(Self {
             access_flags: definition.access_flags,
             name: name.to_string(),
@@ -72,6 +75,7 @@ impl Method {
             max_stack,
             max_locals,
             code,
+            quickened,
             line_numbers,
             exception_table,
             attributes: definition.attributes.clone(),
@@ -144,6 +148,12 @@ impl Method {
         &self.code
     }
 
+    /// Get the quickened instructions of the code.
+    #[must_use]
+    pub fn quickened(&self) -> &QuickenedCode {
+        &self.quickened
+    }
+
     /// Get the line number for a given program counter.
     ///
     /// # Errors
@@ -182,65 +192,9 @@ impl Method {
     /// # Errors
     /// if the descriptor cannot be parsed
     pub fn parse_descriptor(descriptor: &str) -> Result<(Vec<FieldType>, Option<FieldType>)> {
-        let mut chars = descriptor.chars().peekable();
-        let mut parameters = Vec::new();
-        let mut return_type = None;
-
-        if chars.next() != Some('(') {
-            return Err(InvalidMethodDescriptor(descriptor.to_string()));
-        }
-
-        while let Some(&ch) = chars.peek() {
-            if ch == ')' {
-                chars.next();
-                break;
-            }
-            parameters.push(Self::parse_field_type(descriptor, &mut chars)?);
-        }
-
-        match chars.next() {
-            Some('V') => {}
-            Some(ch) => {
-                return_type = Some(Self::parse_field_type(
-                    descriptor,
-                    &mut std::iter::once(ch).chain(chars),
-                )?);
-            }
-            None => return Err(InvalidMethodDescriptor(descriptor.to_string())),
-        }
-
-        Ok((parameters, return_type))
-    }
-
-    /// Parse the field type.
-    ///
-    /// # Errors
-    /// if the field type cannot be parsed
-    fn parse_field_type<I>(descriptor: &str, chars: &mut I) -> Result<FieldType>
-    where
-        I: Iterator<Item = char>,
-    {
-        match chars.next() {
-            Some('L') => {
-                let mut class_name = String::new();
-                for ch in chars.by_ref() {
-                    if ch == ';' {
-                        break;
-                    }
-                    class_name.push(ch);
-                }
-                Ok(FieldType::Object(class_name))
-            }
-            Some('[') => {
-                let component_type = Self::parse_field_type(descriptor, chars)?;
-                Ok(FieldType::Array(Box::new(component_type)))
-            }
-            Some(value) => {
-                let base_type = BaseType::parse(value)?;
-                Ok(FieldType::Base(base_type))
-            }
-            None => Err(InvalidMethodDescriptor(descriptor.to_string())),
-        }
+        let method_descriptor = MethodDescriptor::parse(descriptor)
+            .map_err(|_| InvalidMethodDescriptor(descriptor.to_string()))?;
+        Ok((method_descriptor.parameters, method_descriptor.return_type))
     }
 }
 
@@ -263,7 +217,7 @@ impl Display for Method {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ristretto_classfile::ConstantPool;
+    use ristretto_classfile::{BaseType, ConstantPool};
 
     #[test]
     fn test_method() -> Result<()> {
@@ -362,44 +316,6 @@ mod tests {
         ));
     }
 
-    #[test]
-    fn test_parse_field_type() -> Result<()> {
-        assert_eq!(
-            Method::parse_field_type("", &mut "I".chars())?,
-            FieldType::Base(BaseType::Int)
-        );
-        assert_eq!(
-            Method::parse_field_type("", &mut "J".chars())?,
-            FieldType::Base(BaseType::Long)
-        );
-        assert_eq!(
-            Method::parse_field_type("", &mut "S".chars())?,
-            FieldType::Base(BaseType::Short)
-        );
-        assert_eq!(
-            Method::parse_field_type("", &mut "Z".chars())?,
-            FieldType::Base(BaseType::Boolean)
-        );
-        assert_eq!(
-            Method::parse_field_type("", &mut "Ljava.lang.String;".chars())?,
-            FieldType::Object("java.lang.String".to_string())
-        );
-        assert_eq!(
-            Method::parse_field_type("", &mut "[Ljava.lang.String;".chars())?,
-            FieldType::Array(Box::new(FieldType::Object("java.lang.String".to_string())))
-        );
-        Ok(())
-    }
-
-    #[test]
-    fn test_parse_field_type_invalid() {
-        let descriptor = String::new();
-        assert!(matches!(
-            Method::parse_field_type(&descriptor, &mut descriptor.chars()),
-            Err(InvalidMethodDescriptor(_))
-        ));
-    }
-
     #[test]
     fn test_to_string() {
         let method = Method {
@@ -411,6 +327,7 @@ mod tests {
             max_stack: 1,
             max_locals: 2,
             code: Vec::new(),
+            quickened: QuickenedCode::new(0),
             line_numbers: Vec::new(),
             exception_table: Vec::new(),
             attributes: Vec::new(),
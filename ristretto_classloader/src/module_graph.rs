@@ -0,0 +1,366 @@
+use crate::{ClassPath, Result};
+use ristretto_classfile::attributes::{Attribute, ModuleAccessFlags};
+use ristretto_classfile::ClassFile;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A named module read from a `module-info` class.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Module {
+    name: String,
+    open: bool,
+    requires: BTreeSet<String>,
+    exports: BTreeMap<String, Vec<String>>,
+    opens: BTreeMap<String, Vec<String>>,
+    provides: BTreeMap<String, Vec<String>>,
+    packages: BTreeSet<String>,
+}
+
+impl Module {
+    /// Create a module from a `module-info` class file.
+    ///
+    /// # Errors
+    /// if the class file does not have a `Module` attribute or the attribute references invalid
+    /// constants.
+    pub fn from_class_file(class_file: &ClassFile) -> Result<Self> {
+        let constant_pool = &class_file.constant_pool;
+        let mut module = None;
+        let mut packages = BTreeSet::new();
+        for attribute in &class_file.attributes {
+            match attribute.decode(constant_pool)?.as_ref() {
+                Attribute::Module {
+                    module_name_index,
+                    flags,
+                    requires,
+                    exports,
+                    opens,
+                    provides,
+                    ..
+                } => {
+                    let mut value = Module {
+                        name: constant_pool.try_get_module(*module_name_index)?.clone(),
+                        open: flags.contains(ModuleAccessFlags::OPEN),
+                        ..Default::default()
+                    };
+                    for requires in requires {
+                        let name = constant_pool.try_get_module(requires.index)?;
+                        value.requires.insert(name.clone());
+                    }
+                    for exports in exports {
+                        let package = constant_pool.try_get_package(exports.index)?;
+                        let targets = module_names(class_file, &exports.to_index)?;
+                        value.exports.insert(package.clone(), targets);
+                    }
+                    for opens in opens {
+                        let package = constant_pool.try_get_package(opens.index)?;
+                        let targets = module_names(class_file, &opens.to_index)?;
+                        value.opens.insert(package.clone(), targets);
+                    }
+                    for provides in provides {
+                        let service = constant_pool.try_get_class(provides.index)?;
+                        let mut providers = Vec::with_capacity(provides.with_index.len());
+                        for index in &provides.with_index {
+                            providers.push(constant_pool.try_get_class(*index)?.clone());
+                        }
+                        value.provides.insert(service.clone(), providers);
+                    }
+                    module = Some(value);
+                }
+                Attribute::ModulePackages {
+                    package_indexes, ..
+                } => {
+                    for index in package_indexes {
+                        packages.insert(constant_pool.try_get_package(*index)?.clone());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let Some(mut module) = module else {
+            return Err(crate::Error::ParseError(format!(
+                "{} does not have a Module attribute",
+                class_file.class_name()?
+            )));
+        };
+        // Without a ModulePackages attribute, only the exported and opened packages are known
+        packages.extend(module.exports.keys().cloned());
+        packages.extend(module.opens.keys().cloned());
+        module.packages = packages;
+        Ok(module)
+    }
+
+    /// Get the name of the module, e.g. `java.base`.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Determine if the module is an open module.
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Get the names of the modules required by the module.
+    #[must_use]
+    pub fn requires(&self) -> &BTreeSet<String> {
+        &self.requires
+    }
+
+    /// Get the service providers of the module by service interface, e.g.
+    /// `java/sql/Driver` to `[org/h2/Driver]`.
+    #[must_use]
+    pub fn provides(&self) -> &BTreeMap<String, Vec<String>> {
+        &self.provides
+    }
+
+    /// Get the packages of the module, e.g. `java/lang`.
+    #[must_use]
+    pub fn packages(&self) -> &BTreeSet<String> {
+        &self.packages
+    }
+
+    /// Determine if a package of the module is exported to a module; `None` is the unnamed
+    /// module, which is only exported to by unqualified exports.
+    #[must_use]
+    pub fn is_exported(&self, package: &str, module: Option<&str>) -> bool {
+        self.is_opened(package, module) || Self::is_visible(&self.exports, package, module)
+    }
+
+    /// Determine if a package of the module is open for deep reflection to a module; `None` is
+    /// the unnamed module, which is only opened to by unqualified opens.
+    #[must_use]
+    pub fn is_opened(&self, package: &str, module: Option<&str>) -> bool {
+        if self.open {
+            return self.packages.contains(package);
+        }
+        Self::is_visible(&self.opens, package, module)
+    }
+
+    /// Determine if a package is exported or opened to a module.
+    fn is_visible(
+        packages: &BTreeMap<String, Vec<String>>,
+        package: &str,
+        module: Option<&str>,
+    ) -> bool {
+        let Some(targets) = packages.get(package) else {
+            return false;
+        };
+        if targets.is_empty() {
+            return true;
+        }
+        module.is_some_and(|module| targets.iter().any(|target| target == module))
+    }
+}
+
+/// Get the names of the modules referenced by a list of module constants.
+fn module_names(class_file: &ClassFile, indexes: &[u16]) -> Result<Vec<String>> {
+    let mut names = Vec::with_capacity(indexes.len());
+    for index in indexes {
+        names.push(class_file.constant_pool.try_get_module(*index)?.clone());
+    }
+    Ok(names)
+}
+
+/// The named modules of a class path and the packages they contain.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ModuleGraph {
+    modules: BTreeMap<String, Module>,
+    packages: BTreeMap<String, String>,
+}
+
+impl ModuleGraph {
+    /// Add a module to the graph, replacing any module with the same name.
+    pub fn add(&mut self, module: Module) {
+        for package in &module.packages {
+            self.packages.insert(package.clone(), module.name.clone());
+        }
+        self.modules.insert(module.name.clone(), module);
+    }
+
+    /// Get a module by name.
+    #[must_use]
+    pub fn module(&self, name: &str) -> Option<&Module> {
+        self.modules.get(name)
+    }
+
+    /// Get the modules of the graph.
+    pub fn modules(&self) -> impl Iterator<Item = &Module> {
+        self.modules.values()
+    }
+
+    /// Get the module containing a package; packages may use `.` or `/` separators.
+    #[must_use]
+    pub fn package_module(&self, package: &str) -> Option<&Module> {
+        let package = package.replace('.', "/");
+        let name = self.packages.get(&package)?;
+        self.modules.get(name)
+    }
+
+    /// Get the module containing a class; class names may use `.` or `/` separators.  Returns
+    /// `None` for classes in the unnamed module.
+    #[must_use]
+    pub fn class_module(&self, class_name: &str) -> Option<&Module> {
+        self.package_module(package_name(class_name))
+    }
+}
+
+/// Get the package of a class name, e.g. `java/lang` for `java/lang/Object`; the package of a
+/// class in the unnamed package is empty.
+#[must_use]
+pub fn package_name(class_name: &str) -> &str {
+    class_name
+        .rfind(['/', '.'])
+        .map_or("", |index| &class_name[..index])
+}
+
+/// Implementation of module discovery.
+impl ClassPath {
+    /// Read the `module-info` class of each class path entry into a module graph; entries
+    /// without a `module-info` class, such as the Java 8 runtime, are skipped.
+    ///
+    /// # Errors
+    /// if a `module-info` class cannot be parsed.
+    pub async fn module_graph(&self) -> Result<ModuleGraph> {
+        let mut graph = ModuleGraph::default();
+        for class_path_entry in self.iter() {
+            if let Ok(class_file) = class_path_entry.read_class("module-info").await {
+                graph.add(Module::from_class_file(&class_file)?);
+            }
+        }
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ristretto_classfile::attributes::{ExportsFlags, OpensFlags, RequiresFlags};
+    use ristretto_classfile::ModuleInfoBuilder;
+
+    fn module() -> Result<Module> {
+        let class_file = ModuleInfoBuilder::new("java.base")
+            .requires("java.logging", RequiresFlags::empty())
+            .exports("java/lang", ExportsFlags::empty())
+            .exports_to(
+                "jdk/internal/misc",
+                ExportsFlags::empty(),
+                &["jdk.unsupported"],
+            )
+            .opens_to("java/io", OpensFlags::empty(), &["jdk.unsupported"])
+            .package("java/lang")
+            .package("java/io")
+            .package("jdk/internal/misc")
+            .package("sun/nio")
+            .build()?;
+        Module::from_class_file(&class_file)
+    }
+
+    #[test]
+    fn test_from_class_file() -> Result<()> {
+        let module = module()?;
+        assert_eq!("java.base", module.name());
+        assert!(!module.is_open());
+        assert!(module.requires().contains("java.logging"));
+        assert_eq!(4, module.packages().len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_provides() -> Result<()> {
+        let class_file = ModuleInfoBuilder::new("app")
+            .provides("java/sql/Driver", &["app/Driver", "app/OtherDriver"])
+            .package("app")
+            .build()?;
+        let module = Module::from_class_file(&class_file)?;
+        assert_eq!(
+            Some(&vec![
+                "app/Driver".to_string(),
+                "app/OtherDriver".to_string()
+            ]),
+            module.provides().get("java/sql/Driver")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_class_file_without_module() {
+        let class_file = ClassFile::default();
+        assert!(Module::from_class_file(&class_file).is_err());
+    }
+
+    #[test]
+    fn test_is_exported() -> Result<()> {
+        let module = module()?;
+        assert!(module.is_exported("java/lang", None));
+        assert!(module.is_exported("java/lang", Some("app")));
+        assert!(!module.is_exported("jdk/internal/misc", None));
+        assert!(!module.is_exported("jdk/internal/misc", Some("app")));
+        assert!(module.is_exported("jdk/internal/misc", Some("jdk.unsupported")));
+        assert!(module.is_exported("java/io", Some("jdk.unsupported")));
+        assert!(!module.is_exported("sun/nio", None));
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_opened() -> Result<()> {
+        let module = module()?;
+        assert!(!module.is_opened("java/lang", None));
+        assert!(!module.is_opened("java/io", None));
+        assert!(module.is_opened("java/io", Some("jdk.unsupported")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_module() -> Result<()> {
+        let class_file = ModuleInfoBuilder::new("app")
+            .flags(ModuleAccessFlags::OPEN)
+            .package("com/example")
+            .build()?;
+        let module = Module::from_class_file(&class_file)?;
+        assert!(module.is_open());
+        assert!(module.is_opened("com/example", None));
+        assert!(module.is_exported("com/example", Some("other")));
+        assert!(!module.is_opened("com/other", None));
+        Ok(())
+    }
+
+    #[test]
+    fn test_module_graph() -> Result<()> {
+        let mut graph = ModuleGraph::default();
+        graph.add(module()?);
+        assert_eq!(1, graph.modules().count());
+        assert!(graph.module("java.base").is_some());
+        assert!(graph.module("java.sql").is_none());
+        assert_eq!(
+            Some("java.base"),
+            graph.class_module("java.lang.Object").map(Module::name)
+        );
+        assert_eq!(
+            Some("java.base"),
+            graph
+                .class_module("jdk/internal/misc/Unsafe")
+                .map(Module::name)
+        );
+        assert!(graph.class_module("com/example/Main").is_none());
+        assert!(graph.class_module("Main").is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_package_name() {
+        assert_eq!("java/lang", package_name("java/lang/Object"));
+        assert_eq!("java.lang", package_name("java.lang.Object"));
+        assert_eq!("", package_name("Main"));
+    }
+
+    #[tokio::test]
+    async fn test_class_path_module_graph() -> Result<()> {
+        let cargo_manifest = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_directory = cargo_manifest.join("..").join("classes");
+        let class_path = ClassPath::from(classes_directory.to_string_lossy());
+        let graph = class_path.module_graph().await?;
+        assert_eq!(0, graph.modules().count());
+        Ok(())
+    }
+}
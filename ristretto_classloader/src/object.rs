@@ -1,10 +1,13 @@
+use crate::debug_json::DebugJson;
+use crate::header::Header;
 use crate::Error::{FieldNotFound, InvalidValueType, ParseError};
 use crate::Reference::{ByteArray, CharArray};
 use crate::{Class, Field, Reference, Result, Value};
+use indexmap::IndexMap;
 use ristretto_classfile::{mutf8, FieldAccessFlags, Version};
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 use std::fmt::{Debug, Display};
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 
 const JAVA_8: Version = Version::Java8 { minor: 0 };
 
@@ -12,7 +15,8 @@ const JAVA_8: Version = Version::Java8 { minor: 0 };
 #[derive(Clone)]
 pub struct Object {
     class: Arc<Class>,
-    fields: Arc<HashMap<String, Field>>,
+    fields: Arc<IndexMap<String, Field>>,
+    header: Arc<Header>,
 }
 
 impl Object {
@@ -21,7 +25,7 @@ impl Object {
     /// # Errors
     /// if the fields of the class cannot be read.
     pub fn new(class: Arc<Class>) -> Result<Self> {
-        let mut fields = HashMap::new();
+        let mut fields = IndexMap::new();
         let mut current_class = Some(class.clone());
         while let Some(class) = current_class {
             let class_file = class.class_file();
@@ -44,6 +48,7 @@ impl Object {
         Ok(Self {
             class,
             fields: Arc::new(fields),
+            header: Arc::new(Header::new()),
         })
     }
 
@@ -82,6 +87,37 @@ impl Object {
         Ok(field)
     }
 
+    /// Get the offset of a field; objects of the same class have the same field offsets, so the
+    /// offset can be cached to access the field of other objects of the class with
+    /// [`field_at_offset`](Object::field_at_offset).
+    ///
+    /// # Errors
+    /// if the field cannot be found.
+    pub fn field_offset<S: AsRef<str>>(&self, name: S) -> Result<usize> {
+        let name = name.as_ref();
+        let Some(offset) = self.fields.get_index_of(name) else {
+            return Err(FieldNotFound {
+                class_name: self.class.name().to_string(),
+                field_name: name.to_string(),
+            });
+        };
+        Ok(offset)
+    }
+
+    /// Get field by offset; see [`field_offset`](Object::field_offset).
+    ///
+    /// # Errors
+    /// if there is no field at the offset.
+    pub fn field_at_offset(&self, offset: usize) -> Result<&Field> {
+        let Some((_name, field)) = self.fields.get_index(offset) else {
+            return Err(FieldNotFound {
+                class_name: self.class.name().to_string(),
+                field_name: format!("offset {offset}"),
+            });
+        };
+        Ok(field)
+    }
+
     /// Get value for a field.
     ///
     /// # Errors
@@ -100,6 +136,45 @@ impl Object {
         field.set_value(value)
     }
 
+    /// Get a pointer that identifies the object; clones of the object share the same identity.
+    #[must_use]
+    pub fn identity(&self) -> *const () {
+        Arc::as_ptr(&self.fields).cast()
+    }
+
+    /// Create a weak reference to the object that does not keep the object alive.
+    #[must_use]
+    pub fn downgrade(&self) -> WeakObject {
+        WeakObject {
+            class: Arc::downgrade(&self.class),
+            fields: Arc::downgrade(&self.fields),
+            header: Arc::downgrade(&self.header),
+        }
+    }
+
+    /// Get the identity hash code of the object; clones of the object share the same hash code.
+    pub(crate) fn identity_hash_code(&self) -> i32 {
+        self.header.identity_hash_code()
+    }
+
+    /// Render the object graph as JSON for debugging, expanding references up to `depth` levels.
+    /// Objects and arrays are rendered with their class name, identity hash code and field values
+    /// or elements; each rendered instance is assigned an `id` and subsequent references to the
+    /// instance are rendered as a `ref` to the `id`, so cyclic object graphs can be rendered.
+    ///
+    /// # Errors
+    /// if a field value cannot be read.
+    pub fn to_debug_json(&self, depth: usize) -> Result<String> {
+        let json = DebugJson::default().reference(&Reference::Object(self.clone()), depth)?;
+        Ok(format!("{json:#}"))
+    }
+
+    /// Check if two objects are the same instance (reference equality).
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Object) -> bool {
+        Arc::ptr_eq(&self.class, &other.class) && Arc::ptr_eq(&self.fields, &other.fields)
+    }
+
     /// Check if the object is an instance of the given class and return the "value".
     ///
     /// # Errors
@@ -120,8 +195,8 @@ impl Object {
         &self,
         other: &Object,
         visited: &mut HashSet<(
-            (*const Class, *const HashMap<String, Field>),
-            (*const Class, *const HashMap<String, Field>),
+            (*const Class, *const IndexMap<String, Field>),
+            (*const Class, *const IndexMap<String, Field>),
         )>,
     ) -> bool {
         // Optimization for the case where the two objects are the same reference.
@@ -213,7 +288,7 @@ impl Object {
     /// # Errors
     /// if the fields cannot be cloned.
     pub fn deep_clone(&self) -> Result<Self> {
-        let mut fields = HashMap::new();
+        let mut fields = IndexMap::with_capacity(self.fields.len());
         for (name, field) in self.fields.iter() {
             let field = field.deep_clone()?;
             fields.insert(name.clone(), field);
@@ -221,6 +296,34 @@ impl Object {
         Ok(Self {
             class: self.class.clone(),
             fields: Arc::new(fields),
+            header: Arc::new(Header::new()),
+        })
+    }
+}
+
+/// A weak reference to an [`Object`]; the identity of the object is not reused while a weak
+/// reference to it exists.
+#[derive(Clone, Debug)]
+pub struct WeakObject {
+    class: Weak<Class>,
+    fields: Weak<IndexMap<String, Field>>,
+    header: Weak<Header>,
+}
+
+impl WeakObject {
+    /// Get a pointer that identifies the object; see [`Object::identity`].
+    #[must_use]
+    pub fn identity(&self) -> *const () {
+        self.fields.as_ptr().cast()
+    }
+
+    /// Get the object if it is still alive.
+    #[must_use]
+    pub fn upgrade(&self) -> Option<Object> {
+        Some(Object {
+            class: self.class.upgrade()?,
+            fields: self.fields.upgrade()?,
+            header: self.header.upgrade()?,
         })
     }
 }
@@ -573,6 +676,23 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_field_offset() -> Result<()> {
+        let class = string_class().await?;
+        let object = Object::new(class.clone())?;
+        let other = Object::new(class)?;
+        other.set_value("hash", Value::Int(42))?;
+        let offset = object.field_offset("hash")?;
+        assert_eq!(Value::Int(0), object.field_at_offset(offset)?.value()?);
+        assert_eq!(Value::Int(42), other.field_at_offset(offset)?.value()?);
+        assert!(matches!(
+            object.field_offset("foo"),
+            Err(FieldNotFound { field_name, .. }) if field_name == "foo"
+        ));
+        assert!(object.field_at_offset(usize::MAX).is_err());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_clone() -> Result<()> {
         let class_name = "java.lang.Integer";
@@ -614,6 +734,83 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_to_debug_json() -> Result<()> {
+        let class = load_class("java.lang.Integer").await?;
+        let object = Object::new(class)?;
+        object.set_value("value", Value::Int(42))?;
+        let reference = Reference::Object(object.clone());
+        let json: serde_json::Value =
+            serde_json::from_str(&object.to_debug_json(1)?).expect("json");
+        let expected = serde_json::json!({
+            "class": "java/lang/Integer",
+            "fields": { "value": 42 },
+            "id": 1,
+            "identity_hash": reference.identity_hash_code(),
+        });
+        assert_eq!(expected, json);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_to_debug_json_cycle() -> Result<()> {
+        let class = load_class("java.lang.Throwable").await?;
+        let object = Object::new(class)?;
+        object.set_value(
+            "cause",
+            Value::Object(Some(Reference::Object(object.clone()))),
+        )?;
+        let json: serde_json::Value =
+            serde_json::from_str(&object.to_debug_json(8)?).expect("json");
+        let expected = serde_json::json!({ "class": "java/lang/Throwable", "ref": 1 });
+        assert_eq!(expected, json["fields"]["cause"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_to_debug_json_truncated() -> Result<()> {
+        let class = load_class("java.lang.Throwable").await?;
+        let object = Object::new(class.clone())?;
+        let cause = Object::new(class)?;
+        object.set_value("cause", Value::Object(Some(Reference::Object(cause))))?;
+        let json: serde_json::Value =
+            serde_json::from_str(&object.to_debug_json(1)?).expect("json");
+        assert_eq!(
+            serde_json::json!(true),
+            json["fields"]["cause"]["truncated"]
+        );
+        assert!(json["fields"]["cause"].get("fields").is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_ptr_eq() -> Result<()> {
+        let class_name = "java.lang.Integer";
+        let class = load_class(class_name).await?;
+        let object1 = Object::new(class.clone())?;
+        object1.set_value("value", Value::Int(42))?;
+        let object2 = Object::new(class)?;
+        object2.set_value("value", Value::Int(42))?;
+        assert!(object1.ptr_eq(&object1.clone()));
+        assert!(!object1.ptr_eq(&object2));
+        assert_eq!(object1, object2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_downgrade() -> Result<()> {
+        let class = load_class("java.lang.Integer").await?;
+        let object = Object::new(class)?;
+        let weak_object = object.downgrade();
+        assert_eq!(object.identity(), weak_object.identity());
+        assert!(weak_object
+            .upgrade()
+            .is_some_and(|upgraded| upgraded.ptr_eq(&object)));
+        drop(object);
+        assert!(weak_object.upgrade().is_none());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_eq_same_references() -> Result<()> {
         let class_name = "java.lang.Integer";
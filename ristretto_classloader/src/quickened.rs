@@ -0,0 +1,166 @@
+use crate::{Class, Field, Method};
+use std::fmt;
+use std::fmt::{Debug, Formatter};
+use std::sync::{OnceLock, Weak};
+
+/// A "quick" variant of an instruction that carries the result of a successful resolution, so
+/// that subsequent executions of the instruction do not repeat the constant pool work.  Resolved
+/// classes, fields and methods are held weakly so that quickened code does not keep classes alive
+/// (a method can refer to its own class) or pin a class that was replaced; an instruction that
+/// refers to a dropped definition is resolved again.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se6/html/Quick.doc.html>
+#[derive(Clone, Debug)]
+pub enum QuickInstruction {
+    /// `getfield_quick` / `putfield_quick`; the class of the object that the field was resolved
+    /// for and the offset of the field in objects of that class (see
+    /// [`Object::field_offset`](crate::Object::field_offset)).  The field of objects of other
+    /// classes is resolved by name.
+    Field(Weak<Class>, usize),
+    /// `getstatic_quick` / `putstatic_quick`; the resolved static field of an initialized class.
+    StaticField(Weak<Field>),
+    /// `invokevirtual_quick`, `invokespecial_quick`, `invokestatic_quick` and
+    /// `invokeinterface_quick`; the resolved class and method.  Virtual and interface methods are
+    /// still selected using the class of the receiver.
+    Invoke(Weak<Class>, Weak<Method>),
+}
+
+/// Quick instructions are equal if they refer to the same definitions.
+impl PartialEq for QuickInstruction {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Field(class, offset), Self::Field(other_class, other_offset)) => {
+                class.ptr_eq(other_class) && offset == other_offset
+            }
+            (Self::StaticField(field), Self::StaticField(other_field)) => field.ptr_eq(other_field),
+            (Self::Invoke(class, method), Self::Invoke(other_class, other_method)) => {
+                class.ptr_eq(other_class) && method.ptr_eq(other_method)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Quickened instructions of a method, indexed by program counter.  Each instruction can only be
+/// quickened once; the first successful resolution wins.
+pub struct QuickenedCode {
+    instructions: Vec<OnceLock<QuickInstruction>>,
+}
+
+impl QuickenedCode {
+    /// Create a new table for code with the given number of instructions.
+    #[must_use]
+    pub fn new(length: usize) -> Self {
+        let mut instructions = Vec::with_capacity(length);
+        instructions.resize_with(length, OnceLock::new);
+        QuickenedCode { instructions }
+    }
+
+    /// Get the quickened instruction at the program counter, if the instruction has been
+    /// quickened.
+    #[must_use]
+    pub fn get(&self, program_counter: usize) -> Option<&QuickInstruction> {
+        self.instructions.get(program_counter)?.get()
+    }
+
+    /// Record the quickened instruction at the program counter.  Returns false if the program
+    /// counter is out of bounds or the instruction has already been quickened.
+    #[must_use]
+    pub fn set(&self, program_counter: usize, instruction: QuickInstruction) -> bool {
+        match self.instructions.get(program_counter) {
+            Some(cell) => cell.set(instruction).is_ok(),
+            None => false,
+        }
+    }
+
+    /// Get the number of quickened instructions.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.instructions
+            .iter()
+            .filter(|cell| cell.get().is_some())
+            .count()
+    }
+}
+
+/// Quickened instructions are a cache of resolution results; a clone starts without any quickened
+/// instructions so that it never refers to state resolved for the original.
+impl Clone for QuickenedCode {
+    fn clone(&self) -> Self {
+        Self::new(self.instructions.len())
+    }
+}
+
+/// Quickened instructions do not affect the behavior of a method, so they are ignored when
+/// comparing methods.
+impl PartialEq for QuickenedCode {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+/// Quickened instructions can refer to the class and method that contain them, so only the
+/// number of quickened instructions is formatted.
+impl Debug for QuickenedCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QuickenedCode")
+            .field("length", &self.instructions.len())
+            .field("quickened", &self.count())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(offset: usize) -> QuickInstruction {
+        QuickInstruction::Field(Weak::new(), offset)
+    }
+
+    #[test]
+    fn test_quicken() {
+        let quickened = QuickenedCode::new(2);
+        assert_eq!(None, quickened.get(0));
+        assert_eq!(0, quickened.count());
+
+        assert!(quickened.set(1, field(1)));
+        assert!(!quickened.set(1, field(2)));
+        assert!(!quickened.set(2, field(2)));
+        assert_eq!(Some(&field(1)), quickened.get(1));
+        assert_eq!(None, quickened.get(2));
+        assert_eq!(1, quickened.count());
+    }
+
+    #[test]
+    fn test_clone() {
+        let quickened = QuickenedCode::new(1);
+        assert!(quickened.set(0, field(0)));
+        let clone = quickened.clone();
+        assert_eq!(None, clone.get(0));
+        assert_eq!(quickened, clone);
+    }
+
+    #[test]
+    fn test_eq() {
+        let class = Weak::new();
+        let method = Weak::new();
+        assert_eq!(field(0), field(0));
+        assert_ne!(field(0), field(1));
+        assert_eq!(
+            QuickInstruction::Invoke(class.clone(), method.clone()),
+            QuickInstruction::Invoke(class.clone(), method)
+        );
+        assert_ne!(field(0), QuickInstruction::StaticField(Weak::new()));
+    }
+
+    #[test]
+    fn test_debug() {
+        let quickened = QuickenedCode::new(3);
+        assert!(quickened.set(0, field(0)));
+        assert_eq!(
+            "QuickenedCode { length: 3, quickened: 1 }",
+            format!("{quickened:?}")
+        );
+    }
+}
@@ -59,6 +59,38 @@ impl Reference {
         Ok(class)
     }
 
+    /// Get the identity hash code of the reference; i.e. the value returned by
+    /// `System.identityHashCode()`.
+    #[must_use]
+    pub fn identity_hash_code(&self) -> i32 {
+        match self {
+            Reference::ByteArray(value) => value.identity_hash_code(),
+            Reference::CharArray(value) => value.identity_hash_code(),
+            Reference::ShortArray(value) => value.identity_hash_code(),
+            Reference::IntArray(value) => value.identity_hash_code(),
+            Reference::LongArray(value) => value.identity_hash_code(),
+            Reference::FloatArray(value) => value.identity_hash_code(),
+            Reference::DoubleArray(value) => value.identity_hash_code(),
+            Reference::Array(_, value) => value.identity_hash_code(),
+            Reference::Object(value) => value.identity_hash_code(),
+        }
+    }
+
+    /// Get a pointer that identifies the referenced object or array.
+    pub(crate) fn identity(&self) -> *const () {
+        match self {
+            Reference::ByteArray(value) => value.identity(),
+            Reference::CharArray(value) => value.identity(),
+            Reference::ShortArray(value) => value.identity(),
+            Reference::IntArray(value) => value.identity(),
+            Reference::LongArray(value) => value.identity(),
+            Reference::FloatArray(value) => value.identity(),
+            Reference::DoubleArray(value) => value.identity(),
+            Reference::Array(_, value) => value.identity(),
+            Reference::Object(value) => value.identity(),
+        }
+    }
+
     /// Returns the reference as a `Vec<i8>`.
     ///
     /// # Errors
@@ -908,6 +940,37 @@ mod tests {
         assert!(matches!(result, Err(InvalidValueType(_))));
     }
 
+    #[test]
+    fn test_identity_hash_code() -> Result<()> {
+        let reference = Reference::from(vec![1i8]);
+        let hash_code = reference.identity_hash_code();
+        assert_eq!(hash_code, reference.clone().identity_hash_code());
+
+        let Reference::ByteArray(ref array) = reference else {
+            unreachable!("Expected byte array");
+        };
+        array.set(0, 2i8)?;
+        assert_eq!(hash_code, reference.identity_hash_code());
+        Ok(())
+    }
+
+    #[test]
+    fn test_identity_hash_code_deep_clone() -> Result<()> {
+        let reference = Reference::from(vec![1i8]);
+        let clone = reference.deep_clone()?;
+        assert_eq!(reference, clone);
+        assert_ne!(reference.identity_hash_code(), clone.identity_hash_code());
+        Ok(())
+    }
+
+    #[test]
+    fn test_identity_hash_code_equal_values() {
+        let first = Reference::from(vec![1i8]);
+        let second = Reference::from(vec![1i8]);
+        assert_eq!(first, second);
+        assert_ne!(first.identity_hash_code(), second.identity_hash_code());
+    }
+
     #[test]
     fn test_clone_byte_array() -> Result<()> {
         let reference = Reference::from(vec![1i8]);
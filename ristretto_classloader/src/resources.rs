@@ -0,0 +1,251 @@
+use crate::Error::FileNotFound;
+use crate::{ClassLoader, ClassPath, ClassPathEntry, Result};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+/// A resource found in a class path entry, e.g. `logback.xml` in `app.jar`.  The url of the
+/// resource uses the scheme that the JDK uses for the entry: `file:` for directories, `jar:` for
+/// jars and nested jars, and `jrt:` for the modules of the runtime image.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResourceUrl {
+    url: String,
+    name: String,
+    class_path_entry: ClassPathEntry,
+}
+
+impl ResourceUrl {
+    /// Create a resource url for a resource in a class path entry.
+    fn new(class_path_entry: &ClassPathEntry, name: &str) -> Self {
+        let url = match class_path_entry {
+            ClassPathEntry::Directory(directory) => {
+                let path = PathBuf::from(directory.name()).join(name);
+                format!("file:{}", absolute_path(&path))
+            }
+            ClassPathEntry::Jar(jar) | ClassPathEntry::NestedJar(jar) => {
+                let jar_name = jar.name();
+                let (path, entry) = match jar_name.split_once("!/") {
+                    Some((path, entry)) => (path, Some(entry)),
+                    None => (jar_name.as_str(), None),
+                };
+                let base = if path.starts_with("https://") || path.starts_with("http://") {
+                    path.to_string()
+                } else {
+                    format!("file:{}", absolute_path(Path::new(path)))
+                };
+                match entry {
+                    Some(entry) if entry.is_empty() || entry.ends_with('/') => {
+                        format!("jar:{base}!/{entry}{name}")
+                    }
+                    Some(entry) => format!("jar:{base}!/{entry}!/{name}"),
+                    None => format!("jar:{base}!/{name}"),
+                }
+            }
+            ClassPathEntry::Jmod(jmod) => {
+                let module = Path::new(jmod.name())
+                    .file_stem()
+                    .map(|module| module.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                format!("jrt:/{module}/{name}")
+            }
+        };
+        Self {
+            url,
+            name: name.to_string(),
+            class_path_entry: class_path_entry.clone(),
+        }
+    }
+
+    /// Get the url of the resource, e.g. `jar:file:/app/app.jar!/logback.xml`.
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Get the name of the resource relative to the class path entry, e.g. `logback.xml`.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the class path entry that contains the resource.
+    #[must_use]
+    pub fn class_path_entry(&self) -> &ClassPathEntry {
+        &self.class_path_entry
+    }
+
+    /// Read the contents of the resource.
+    ///
+    /// # Errors
+    /// if the resource no longer exists or cannot be read.
+    pub async fn read(&self) -> Result<Vec<u8>> {
+        self.class_path_entry
+            .read_file(&self.name)
+            .await?
+            .ok_or_else(|| FileNotFound(self.url.clone()))
+    }
+}
+
+impl Display for ResourceUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.url)
+    }
+}
+
+/// Implementation of resource lookup.
+impl ClassPath {
+    /// Find every resource with a name, e.g. `META-INF/spring.factories`, in class path order.
+    ///
+    /// # Errors
+    /// if a class path entry cannot be read.
+    pub async fn find_resources<S: AsRef<str>>(&self, name: S) -> Result<Vec<ResourceUrl>> {
+        let name = name.as_ref().trim_start_matches('/');
+        let mut resources = Vec::new();
+        for class_path_entry in self.iter() {
+            if class_path_entry.read_file(name).await?.is_some() {
+                resources.push(ResourceUrl::new(class_path_entry, name));
+            }
+        }
+        Ok(resources)
+    }
+}
+
+impl ClassLoader {
+    /// Find every resource with a name that is visible to this class loader, from the boot class
+    /// loader to this class loader; this matches the order of `ClassLoader.getResources`.
+    ///
+    /// # Errors
+    /// if a class path entry cannot be read.
+    pub async fn find_resources<S: AsRef<str>>(&self, name: S) -> Result<Vec<ResourceUrl>> {
+        let name = name.as_ref();
+        let mut resources = Vec::new();
+        for class_loader in self.class_loaders() {
+            for resource in class_loader.class_path().find_resources(name).await? {
+                if !resources.contains(&resource) {
+                    resources.push(resource);
+                }
+            }
+        }
+        Ok(resources)
+    }
+
+    /// Find the first resource with a name that is visible to this class loader; this matches
+    /// `ClassLoader.getResource`.
+    ///
+    /// # Errors
+    /// if a class path entry cannot be read.
+    pub async fn find_resource<S: AsRef<str>>(&self, name: S) -> Result<Option<ResourceUrl>> {
+        let resources = self.find_resources(name).await?;
+        Ok(resources.into_iter().next())
+    }
+}
+
+/// Get the absolute path of a file with `/` separators, as used in `file:` urls.
+fn absolute_path(path: &Path) -> String {
+    let path = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    let path = path.to_string_lossy().replace('\\', "/");
+    if path.starts_with('/') {
+        path
+    } else {
+        format!("/{path}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::io::{Cursor, Write};
+    use zip::write::SimpleFileOptions;
+
+    fn jar_bytes(files: &[(&str, &[u8])]) -> Result<Vec<u8>> {
+        let mut archive = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default();
+        for (name, bytes) in files {
+            archive.start_file(*name, options)?;
+            archive.write_all(bytes)?;
+        }
+        Ok(archive.finish()?.into_inner())
+    }
+
+    #[tokio::test]
+    async fn test_find_resources() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let directory = temp_dir.path().join("classes");
+        fs::create_dir_all(&directory)?;
+        fs::write(directory.join("logback.xml"), "directory")?;
+        let jar_path = temp_dir.path().join("app.jar");
+        fs::write(
+            &jar_path,
+            jar_bytes(&[("logback.xml", b"jar"), ("other.xml", b"other")])?,
+        )?;
+
+        let class_path = ClassPath::new(vec![
+            ClassPathEntry::new(directory.to_string_lossy()),
+            ClassPathEntry::new(jar_path.to_string_lossy()),
+        ]);
+        let resources = class_path.find_resources("/logback.xml").await?;
+        assert_eq!(2, resources.len());
+        assert!(resources[0].url().starts_with("file:/"));
+        assert!(resources[0].url().ends_with("/classes/logback.xml"));
+        assert_eq!(b"directory".to_vec(), resources[0].read().await?);
+        assert!(resources[1].url().starts_with("jar:file:/"));
+        assert!(resources[1].url().ends_with("/app.jar!/logback.xml"));
+        assert_eq!("logback.xml", resources[1].name());
+        assert_eq!(b"jar".to_vec(), resources[1].read().await?);
+
+        let resources = class_path.find_resources("other.xml").await?;
+        assert_eq!(1, resources.len());
+        assert!(class_path.find_resources("missing.xml").await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_find_resources_nested() -> Result<()> {
+        let nested = jar_bytes(&[("logback.xml", b"lib")])?;
+        let bytes = jar_bytes(&[
+            ("BOOT-INF/classes/logback.xml", b"classes"),
+            ("BOOT-INF/lib/lib.jar", &nested),
+        ])?;
+        let temp_dir = tempfile::tempdir()?;
+        let jar_path = temp_dir.path().join("app.jar");
+        fs::write(&jar_path, bytes)?;
+        let jar_path = jar_path.to_string_lossy();
+        let class_path = ClassPath::new(vec![
+            ClassPathEntry::new(format!("{jar_path}!/BOOT-INF/classes/")),
+            ClassPathEntry::new(format!("{jar_path}!/BOOT-INF/lib/lib.jar")),
+        ]);
+        let resources = class_path.find_resources("logback.xml").await?;
+        let urls = resources.iter().map(ResourceUrl::url).collect::<Vec<_>>();
+        assert!(urls[0].ends_with("/app.jar!/BOOT-INF/classes/logback.xml"));
+        assert!(urls[1].ends_with("/app.jar!/BOOT-INF/lib/lib.jar!/logback.xml"));
+        assert_eq!(b"lib".to_vec(), resources[1].read().await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_class_loader_find_resources() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let parent_directory = temp_dir.path().join("parent");
+        let child_directory = temp_dir.path().join("child");
+        for directory in [&parent_directory, &child_directory] {
+            fs::create_dir_all(directory)?;
+            fs::write(directory.join("logback.xml"), "logback")?;
+        }
+        let parent = ClassLoader::new(
+            "parent",
+            ClassPath::from(parent_directory.to_string_lossy()),
+        );
+        let mut class_loader =
+            ClassLoader::new("child", ClassPath::from(child_directory.to_string_lossy()));
+        class_loader.set_parent(Some(parent));
+
+        let resources = class_loader.find_resources("logback.xml").await?;
+        assert_eq!(2, resources.len());
+        assert!(resources[0].url().contains("/parent/"));
+        assert!(resources[1].url().contains("/child/"));
+        let resource = class_loader.find_resource("logback.xml").await?;
+        assert_eq!(Some(&resources[0]), resource.as_ref());
+        assert_eq!(None, class_loader.find_resource("missing.xml").await?);
+        Ok(())
+    }
+}
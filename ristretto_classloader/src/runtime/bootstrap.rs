@@ -1,14 +1,46 @@
+use crate::runtime::distribution;
+use crate::runtime::download::{self, ProgressCallback};
 use crate::runtime::util;
+use crate::runtime::Distribution;
 use crate::{ClassLoader, ClassPath, Error, Result};
 use flate2::bufread::GzDecoder;
 use ristretto_classfile::Error::IoError;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::LazyLock;
 use std::{env, io};
 use tar::Archive;
 use tracing::{debug, instrument, warn};
 
 pub const DEFAULT_JAVA_VERSION: &str = "21.0.6.7.1";
 
+/// Environment variable that disables runtime downloads when set to a value other than `0` or
+/// `false`.
+const OFFLINE_VARIABLE: &str = "RISTRETTO_OFFLINE";
+
+static OFFLINE: LazyLock<AtomicBool> = LazyLock::new(|| {
+    let offline = env::var(OFFLINE_VARIABLE)
+        .is_ok_and(|value| !matches!(value.to_ascii_lowercase().as_str(), "" | "0" | "false"));
+    if offline {
+        debug!("{OFFLINE_VARIABLE} environment variable found; runtime downloads are disabled");
+    }
+    AtomicBool::new(offline)
+});
+
+/// Check if offline mode is enabled.  In offline mode runtimes are never downloaded; a version is
+/// resolved from the runtimes already installed in the `.ristretto` directory, e.g. by a
+/// pre-seeded cache.  Offline mode is enabled by the `RISTRETTO_OFFLINE` environment variable or
+/// [`set_offline`].
+#[must_use]
+pub fn is_offline() -> bool {
+    OFFLINE.load(Ordering::Relaxed)
+}
+
+/// Enable or disable offline mode.
+pub fn set_offline(offline: bool) {
+    OFFLINE.store(offline, Ordering::Relaxed);
+}
+
 /// Get a class loader for the default Java runtime version. If the version is not installed, the
 /// archive will be downloaded and extracted.
 ///
@@ -18,6 +50,28 @@ pub async fn default_class_loader() -> Result<(PathBuf, String, ClassLoader)> {
     version_class_loader(DEFAULT_JAVA_VERSION).await
 }
 
+/// Get a class loader for a locally installed Java runtime, e.g. the value of `JAVA_HOME`.  The
+/// path can also be the root of a macOS JDK bundle, whose Java home is in `Contents/Home`.  No
+/// network access is required.
+///
+/// # Errors
+/// An error will be returned if the path is not a Java home or the class loader cannot be
+/// created.
+#[instrument(level = "debug", skip(path), fields(path = ?path.as_ref()))]
+pub async fn home_class_loader_from<P: AsRef<Path>>(
+    path: P,
+) -> Result<(PathBuf, String, ClassLoader)> {
+    let path = path.as_ref();
+    let java_home = Distribution::java_home(path);
+    if !java_home.join("release").is_file() && !java_home.join("version.txt").is_file() {
+        return Err(Error::RuntimeNotFound(format!(
+            "{} is not a Java home; no release or version.txt file found",
+            path.to_string_lossy()
+        )));
+    }
+    home_class_loader(&java_home).await
+}
+
 /// Get a class loader for the given Java home.
 ///
 /// # Errors
@@ -65,12 +119,53 @@ pub async fn home_class_loader(java_home: &PathBuf) -> Result<(PathBuf, String,
 
 /// Get a class loader for the given Java runtime version. If the version is not installed, the
 /// archive will be downloaded and extracted. A version can be a partial version, a major version,
-/// or a `*` to get the latest LTS release supported by the runtime.
+/// or a `*` to get the latest LTS release supported by the runtime.  The version can be prefixed
+/// with a [`Distribution`] to install, e.g. `temurin-21`; Corretto is installed by default.
 ///
 /// # Errors
 /// An error will be returned if the class loader cannot be created.
 #[instrument(level = "debug")]
 pub async fn version_class_loader(version: &str) -> Result<(PathBuf, String, ClassLoader)> {
+    version_class_loader_with_progress(version, None).await
+}
+
+/// Get a class loader for the given Java runtime version, as [`version_class_loader`] does,
+/// reporting the progress of the runtime download to a callback.
+///
+/// # Errors
+/// An error will be returned if the class loader cannot be created.
+#[instrument(level = "debug", skip(progress))]
+pub async fn version_class_loader_with_progress(
+    version: &str,
+    progress: Option<&ProgressCallback>,
+) -> Result<(PathBuf, String, ClassLoader)> {
+    let (distribution, version) = Distribution::parse_version(version);
+    install_class_loader(distribution, version, progress).await
+}
+
+/// Get a class loader for the given version of a Java runtime distribution. If the version is not
+/// installed, the archive will be downloaded and extracted.
+///
+/// # Errors
+/// An error will be returned if the class loader cannot be created.
+#[instrument(level = "debug")]
+pub async fn distribution_class_loader(
+    distribution: Distribution,
+    version: &str,
+) -> Result<(PathBuf, String, ClassLoader)> {
+    install_class_loader(distribution, version, None).await
+}
+
+/// Get a class loader for the given version of a Java runtime distribution, installing the runtime
+/// if it is not installed.
+///
+/// # Errors
+/// An error will be returned if the class loader cannot be created.
+async fn install_class_loader(
+    distribution: Distribution,
+    version: &str,
+    progress: Option<&ProgressCallback>,
+) -> Result<(PathBuf, String, ClassLoader)> {
     let current_dir = env::current_dir().unwrap_or_default();
 
     #[cfg(target_arch = "wasm32")]
@@ -79,17 +174,77 @@ pub async fn version_class_loader(version: &str) -> Result<(PathBuf, String, Cla
     let home_dir = home::home_dir().unwrap_or(current_dir);
 
     let base_path = home_dir.join(".ristretto");
-    let mut installation_dir = base_path.join(version);
-    if !installation_dir.exists() {
-        let (version, file_name, archive) = util::get_runtime_archive(version).await?;
-        installation_dir =
-            extract_archive(version.as_str(), file_name.as_str(), &archive, &base_path).await?;
+    let installation_dir =
+        install(&base_path, distribution, version, is_offline(), progress).await?;
+    let java_home = Distribution::java_home(&installation_dir);
+    home_class_loader(&java_home).await
+}
+
+/// Get the installation directory of a version of a distribution, downloading and extracting the
+/// runtime if it is not installed.  When offline, the latest installed runtime matching the
+/// version is used instead, e.g. `21.0.6.7.1` for `21`.  Runtimes that are installed by more than
+/// one process at the same time are downloaded once, and extracted to a temporary directory that is
+/// renamed to the installation directory, so a partially extracted runtime is never used.  A
+/// downloaded archive that cannot be extracted is removed, so that it is downloaded again.
+///
+/// # Errors
+/// An error will be returned if the runtime cannot be installed, or if it is not installed when
+/// offline.
+async fn install(
+    base_path: &PathBuf,
+    distribution: Distribution,
+    version: &str,
+    offline: bool,
+    progress: Option<&ProgressCallback>,
+) -> Result<PathBuf> {
+    let installation_dir = base_path.join(distribution.installation_name(version));
+    if installation_dir.exists() {
+        return Ok(installation_dir);
+    }
+    if offline {
+        return find_installation(base_path, distribution, version).ok_or_else(|| {
+            Error::RuntimeNotFound(format!(
+                "{} is not installed in {} and downloads are disabled in offline mode; install the \
+                 runtime or use a local Java home",
+                distribution.installation_name(version),
+                base_path.to_string_lossy()
+            ))
+        });
     }
 
-    #[cfg(target_os = "macos")]
-    let installation_dir = installation_dir.join("Contents").join("Home");
+    let (version, file_name, archive) = distribution.runtime_archive(version, progress).await?;
+    let installation_name = distribution.installation_name(&version);
+    let result = extract_archive(&installation_name, file_name.as_str(), &archive, base_path).await;
+    download::remove(&file_name);
+    result
+}
 
-    home_class_loader(&installation_dir).await
+/// Find the latest installed runtime of a distribution that matches a version; `*` matches any
+/// version.
+fn find_installation(
+    base_path: &Path,
+    distribution: Distribution,
+    version: &str,
+) -> Option<PathBuf> {
+    let prefix = if version == "*" {
+        distribution.installation_name("")
+    } else {
+        format!("{}.", distribution.installation_name(version))
+    };
+    let entries = std::fs::read_dir(base_path).ok()?;
+    entries
+        .filter_map(std::result::Result::ok)
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let (installed_distribution, installed_version) = Distribution::parse_version(&name);
+            let installed = installed_distribution == distribution
+                && name.starts_with(&prefix)
+                && installed_version.starts_with(|character: char| character.is_ascii_digit());
+            installed.then(|| (distribution::version_key(installed_version), entry.path()))
+        })
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, path)| path)
 }
 
 /// Get the class path for the given version.
@@ -97,31 +252,13 @@ pub async fn version_class_loader(version: &str) -> Result<(PathBuf, String, Cla
 /// # Errors
 /// An error will be returned if the class path cannot be determined.
 fn get_class_path(version: &str, installation_dir: &Path) -> Result<ClassPath> {
-    let class_path = if util::parse_major_version(version) <= 8 {
+    if util::parse_major_version(version) <= 8 {
         let rt_jar_path = installation_dir.join("jre").join("lib").join("rt.jar");
-        let class_path = rt_jar_path.to_string_lossy();
-        class_path.to_string()
+        Ok(ClassPath::from(rt_jar_path.to_string_lossy()))
     } else {
         let jmods_path = installation_dir.join("jmods");
-        let jmod_files = std::fs::read_dir(jmods_path)?
-            .filter_map(|entry| {
-                let entry = entry.ok()?;
-                let path = entry.path();
-                if path.extension()?.to_string_lossy() == "jmod" {
-                    Some(path)
-                } else {
-                    None
-                }
-            })
-            .collect::<Vec<_>>();
-        let mut class_paths = jmod_files
-            .iter()
-            .map(|path| path.to_string_lossy().to_string())
-            .collect::<Vec<_>>();
-        class_paths.sort_by(Ord::cmp);
-        class_paths.join(":")
-    };
-    Ok(ClassPath::from(class_path))
+        ClassPath::from_module_path(jmods_path.to_string_lossy())
+    }
 }
 
 /// Extract the archive to the installation directory.
@@ -222,6 +359,66 @@ async fn extract_archive(
 mod tests {
     use super::*;
 
+    /// Create a Java home with a release file and an empty `jmods` directory.
+    fn java_home(path: &Path, version: &str) -> Result<()> {
+        std::fs::create_dir_all(path.join("jmods"))?;
+        std::fs::write(
+            path.join("release"),
+            format!("JAVA_VERSION=\"{version}\"\n"),
+        )?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_home_class_loader_from() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let bundle_home = temp_dir.path().join("Contents").join("Home");
+        java_home(&bundle_home, "21.0.5")?;
+        let (java_home, java_version, class_loader) =
+            home_class_loader_from(temp_dir.path()).await?;
+        assert_eq!(bundle_home, java_home);
+        assert_eq!("21.0.5", java_version);
+        assert_eq!("bootstrap", class_loader.name());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_home_class_loader_from_not_found() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let result = home_class_loader_from(temp_dir.path()).await;
+        assert!(matches!(result, Err(Error::RuntimeNotFound(_))));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_install_offline() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let base_path = temp_dir.path().to_path_buf();
+        for name in [
+            "21.0.5.11.1",
+            "21.0.10.7.1",
+            "17.0.13.11.1",
+            "temurin-21.0.6+7",
+        ] {
+            std::fs::create_dir_all(base_path.join(name))?;
+        }
+        let corretto = Distribution::Corretto;
+        let installation_dir = install(&base_path, corretto, "21", true, None).await?;
+        assert_eq!(base_path.join("21.0.10.7.1"), installation_dir);
+        let installation_dir = install(&base_path, corretto, "17.0.13.11.1", true, None).await?;
+        assert_eq!(base_path.join("17.0.13.11.1"), installation_dir);
+        let installation_dir = install(&base_path, corretto, "*", true, None).await?;
+        assert_eq!(base_path.join("21.0.10.7.1"), installation_dir);
+        let installation_dir = install(&base_path, Distribution::Temurin, "21", true, None).await?;
+        assert_eq!(base_path.join("temurin-21.0.6+7"), installation_dir);
+
+        let result = install(&base_path, corretto, "11", true, None).await;
+        assert!(matches!(result, Err(Error::RuntimeNotFound(_))));
+        let result = install(&base_path, Distribution::Zulu, "*", true, None).await;
+        assert!(matches!(result, Err(Error::RuntimeNotFound(_))));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_class_loader_v8() -> Result<()> {
         let version = "8.442.06.1";
@@ -0,0 +1,464 @@
+use crate::runtime::download::{self, Checksum, ProgressCallback};
+use crate::runtime::models::{LibericaRelease, TemurinRelease, ZuluPackage};
+use crate::runtime::util;
+use crate::{Error, Result};
+use std::env::consts;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use tracing::instrument;
+
+/// A vendor distribution of the JDK that can be installed as the Java runtime.  A distribution is
+/// selected by prefixing the version with the name of the distribution, e.g. `temurin-21` or
+/// `zulu-17.0.13`; versions without a prefix use [`Distribution::Corretto`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Distribution {
+    /// Amazon Corretto
+    /// See: <https://aws.amazon.com/corretto/>
+    #[default]
+    Corretto,
+    /// Eclipse Temurin
+    /// See: <https://adoptium.net/>
+    Temurin,
+    /// Azul Zulu
+    /// See: <https://www.azul.com/downloads/>
+    Zulu,
+    /// Liberica JDK
+    /// See: <https://bell-sw.com/libericajdk/>
+    Liberica,
+    /// `GraalVM` Community Edition
+    /// See: <https://github.com/graalvm/graalvm-ce-builds>
+    GraalVm,
+}
+
+impl Distribution {
+    /// Get the name of the distribution, as used to prefix versions.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Distribution::Corretto => "corretto",
+            Distribution::Temurin => "temurin",
+            Distribution::Zulu => "zulu",
+            Distribution::Liberica => "liberica",
+            Distribution::GraalVm => "graalvm",
+        }
+    }
+
+    /// Split a version into its distribution and the version of the distribution, e.g.
+    /// `temurin-21` into `(Temurin, "21")`; a version without a distribution prefix is a
+    /// [`Distribution::Corretto`] version.
+    #[must_use]
+    pub fn parse_version(version: &str) -> (Distribution, &str) {
+        if let Some((name, distribution_version)) = version.split_once('-') {
+            if let Ok(distribution) = Distribution::from_str(name) {
+                return (distribution, distribution_version);
+            }
+        }
+        (Distribution::Corretto, version)
+    }
+
+    /// Get the name of the installation directory for a version of the distribution; Corretto
+    /// versions are installed by version alone, as they were before other distributions were
+    /// supported.
+    pub(crate) fn installation_name(self, version: &str) -> String {
+        match self {
+            Distribution::Corretto => version.to_string(),
+            _ => format!("{}-{version}", self.name()),
+        }
+    }
+
+    /// Get the Java home of an installation.  macOS archives package the JDK as a bundle with the
+    /// Java home in `Contents/Home`, either at the top level (e.g. Corretto and Temurin) or in a
+    /// `*.jdk` bundle next to links to its directories (e.g. Zulu).
+    pub(crate) fn java_home(installation_dir: &Path) -> PathBuf {
+        let bundle_home = installation_dir.join("Contents").join("Home");
+        if bundle_home.is_dir() {
+            return bundle_home;
+        }
+        if !installation_dir.join("release").is_file() {
+            if let Ok(entries) = std::fs::read_dir(installation_dir) {
+                for entry in entries.filter_map(std::result::Result::ok) {
+                    let bundle_home = entry.path().join("Contents").join("Home");
+                    if entry.file_name().to_string_lossy().ends_with(".jdk") && bundle_home.is_dir()
+                    {
+                        return bundle_home;
+                    }
+                }
+            }
+        }
+        installation_dir.to_path_buf()
+    }
+
+    /// Get the runtime archive of the distribution for a version, returning the full version, the
+    /// file name and the archive.  A partial or major version resolves to the latest matching
+    /// release, and `*` to the latest LTS release supported by the runtime.
+    ///
+    /// # Errors
+    /// An error will be returned if the request fails, if the version is not supported or if the
+    /// archive does not match its published checksum.
+    #[instrument(level = "debug", skip(progress))]
+    pub(crate) async fn runtime_archive(
+        self,
+        version: &str,
+        progress: Option<&ProgressCallback>,
+    ) -> Result<(String, String, Vec<u8>)> {
+        if self == Distribution::Corretto {
+            return util::get_runtime_archive(version, progress).await;
+        }
+        let version = if version == "*" {
+            util::DEFAULT_MAJOR_VERSION.to_string()
+        } else {
+            version.to_string()
+        };
+        let (version, file_name, url, checksum) = self.archive_url(&version).await?;
+        let archive = download::download(&url, &file_name, checksum.as_ref(), progress).await?;
+        Ok((version, file_name, archive))
+    }
+
+    /// Get the full version, file name, download url and published checksum of the latest release
+    /// of the distribution matching a version.
+    ///
+    /// # Errors
+    /// An error will be returned if the request fails or if the version is not supported.
+    async fn archive_url(
+        self,
+        version: &str,
+    ) -> Result<(String, String, String, Option<Checksum>)> {
+        let major_version = util::parse_major_version(version);
+        if major_version == 0 {
+            return Err(Error::UnsupportedVersion(version.to_string()));
+        }
+        let (os, arch) = self.platform();
+        let extension = if consts::OS == "windows" {
+            "zip"
+        } else {
+            "tar.gz"
+        };
+        let archive = match self {
+            Distribution::Corretto => None,
+            Distribution::Temurin => {
+                let url = format!(
+                    "https://api.adoptium.net/v3/assets/feature_releases/{major_version}/ga?architecture={arch}&heap_size=normal&image_type=jdk&jvm_impl=hotspot&os={os}&page_size=100&sort_order=DESC&vendor=eclipse"
+                );
+                let releases: Vec<TemurinRelease> = util::get_json(&url).await?;
+                releases
+                    .into_iter()
+                    .filter(|release| release.version_data.semver.starts_with(version))
+                    .find_map(|release| {
+                        let binary = release.binaries.into_iter().next()?;
+                        Some((
+                            release.version_data.semver,
+                            binary.package.name,
+                            binary.package.link,
+                            binary.package.checksum.map(Checksum::Sha256),
+                        ))
+                    })
+            }
+            Distribution::Zulu => {
+                let url = format!(
+                    "https://api.azul.com/metadata/v1/zulu/packages/?java_version={version}&os={os}&arch={arch}&archive_type={extension}&java_package_type=jdk&javafx_bundled=false&crac_supported=false&release_status=ga&availability_types=CA&latest=true&include_fields=sha256_hash&page_size=1"
+                );
+                let packages: Vec<ZuluPackage> = util::get_json(&url).await?;
+                packages.into_iter().next().map(|package| {
+                    let version = package
+                        .java_version
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(".");
+                    (
+                        version,
+                        package.name,
+                        package.download_url,
+                        package.sha256_hash.map(Checksum::Sha256),
+                    )
+                })
+            }
+            Distribution::Liberica => {
+                let url = format!(
+                    "https://api.bell-sw.com/v1/liberica/releases?version-feature={major_version}&bitness=64&os={os}&arch={arch}&package-type={extension}&bundle-type=jdk"
+                );
+                let mut releases: Vec<LibericaRelease> = util::get_json(&url).await?;
+                releases.sort_by_key(|release| std::cmp::Reverse(version_key(&release.version)));
+                releases
+                    .into_iter()
+                    .find(|release| release.ga && release.version.starts_with(version))
+                    .map(|release| {
+                        (
+                            release.version,
+                            release.filename,
+                            release.download_url,
+                            release.sha1.map(Checksum::Sha1),
+                        )
+                    })
+            }
+            Distribution::GraalVm => {
+                let suffix = format!("_{os}-{arch}_bin.{extension}");
+                graalvm_archive_url(version, &suffix).await?
+            }
+        };
+        archive.ok_or_else(|| Error::UnsupportedVersion(self.installation_name(version)))
+    }
+
+    /// Get the operating system and architecture names that the distribution uses for the current
+    /// platform.
+    fn platform(self) -> (&'static str, &'static str) {
+        let aarch64 = consts::ARCH == "aarch64";
+        let musl = cfg!(target_env = "musl");
+        match self {
+            Distribution::Corretto => {
+                let os = match consts::OS {
+                    "macos" => "macosx",
+                    "windows" => "windows",
+                    _ if musl => "alpine-linux",
+                    _ => "linux",
+                };
+                (os, if aarch64 { "aarch64" } else { "x64" })
+            }
+            Distribution::Temurin => {
+                let os = match consts::OS {
+                    "macos" => "mac",
+                    "windows" => "windows",
+                    _ if musl => "alpine-linux",
+                    _ => "linux",
+                };
+                (os, if aarch64 { "aarch64" } else { "x64" })
+            }
+            Distribution::Zulu => {
+                let os = match consts::OS {
+                    "macos" => "macos",
+                    "windows" => "windows",
+                    _ if musl => "linux-musl",
+                    _ => "linux-glibc",
+                };
+                (os, if aarch64 { "aarch64" } else { "x64" })
+            }
+            Distribution::Liberica => {
+                let os = match consts::OS {
+                    "macos" => "macos",
+                    "windows" => "windows",
+                    _ if musl => "linux-musl",
+                    _ => "linux",
+                };
+                (os, if aarch64 { "arm" } else { "x86" })
+            }
+            Distribution::GraalVm => {
+                let os = match consts::OS {
+                    "macos" => "macos",
+                    "windows" => "windows",
+                    _ => "linux",
+                };
+                (os, if aarch64 { "aarch64" } else { "x64" })
+            }
+        }
+    }
+}
+
+/// Get the full version, file name, download url and published checksum of the latest `GraalVM`
+/// release matching a version, whose archive name ends with a suffix.  `GraalVM` publishes the
+/// checksum of each archive as a separate `<archive>.sha256` release asset.
+///
+/// # Errors
+/// An error will be returned if the request fails.
+async fn graalvm_archive_url(
+    version: &str,
+    suffix: &str,
+) -> Result<Option<(String, String, String, Option<Checksum>)>> {
+    let releases = util::get_releases("graalvm/graalvm-ce-builds").await?;
+    let archive = releases
+        .into_iter()
+        .filter(|release| !release.prerelease)
+        .find_map(|release| {
+            let release_version = release.tag_name.strip_prefix("jdk-")?.to_string();
+            if !release_version.starts_with(version) {
+                return None;
+            }
+            let asset = release
+                .assets
+                .iter()
+                .find(|asset| asset.name.ends_with(suffix))?;
+            let checksum_name = format!("{}.sha256", asset.name);
+            let checksum_url = release
+                .assets
+                .iter()
+                .find(|asset| asset.name == checksum_name)
+                .map(|asset| asset.browser_download_url.clone());
+            Some((
+                release_version,
+                asset.name.clone(),
+                asset.browser_download_url.clone(),
+                checksum_url,
+            ))
+        });
+    let Some((release_version, file_name, url, checksum_url)) = archive else {
+        return Ok(None);
+    };
+    let checksum = match checksum_url {
+        // The checksum file contains the checksum, optionally followed by the file name
+        Some(checksum_url) => util::get_text(&checksum_url)
+            .await?
+            .split_whitespace()
+            .next()
+            .map(|checksum| Checksum::Sha256(checksum.to_string())),
+        None => None,
+    };
+    Ok(Some((release_version, file_name, url, checksum)))
+}
+
+/// Get a key that orders versions numerically, e.g. `21.0.10+7` after `21.0.9+11`.
+pub(crate) fn version_key(version: &str) -> Vec<u64> {
+    version
+        .split(|character: char| !character.is_ascii_digit())
+        .filter_map(|part| part.parse().ok())
+        .collect()
+}
+
+impl Display for Distribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Distribution {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        let distribution = match name.to_ascii_lowercase().as_str() {
+            "corretto" => Distribution::Corretto,
+            "temurin" => Distribution::Temurin,
+            "zulu" => Distribution::Zulu,
+            "liberica" => Distribution::Liberica,
+            "graalvm" => Distribution::GraalVm,
+            _ => return Err(Error::ParseError(format!("Unknown distribution: {name}"))),
+        };
+        Ok(distribution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DISTRIBUTIONS: [Distribution; 5] = [
+        Distribution::Corretto,
+        Distribution::Temurin,
+        Distribution::Zulu,
+        Distribution::Liberica,
+        Distribution::GraalVm,
+    ];
+
+    #[test]
+    fn test_name() -> Result<()> {
+        for distribution in DISTRIBUTIONS {
+            assert_eq!(distribution, Distribution::from_str(distribution.name())?);
+            assert_eq!(distribution.name(), distribution.to_string());
+        }
+        assert_eq!(Distribution::GraalVm, Distribution::from_str("GraalVM")?);
+        assert!(Distribution::from_str("foo").is_err());
+        assert_eq!(Distribution::Corretto, Distribution::default());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(
+            (Distribution::Temurin, "21"),
+            Distribution::parse_version("temurin-21")
+        );
+        assert_eq!(
+            (Distribution::Zulu, "17.0.13"),
+            Distribution::parse_version("zulu-17.0.13")
+        );
+        assert_eq!(
+            (Distribution::Corretto, "21.0.6.7.1"),
+            Distribution::parse_version("21.0.6.7.1")
+        );
+        assert_eq!(
+            (Distribution::Corretto, "foo-21"),
+            Distribution::parse_version("foo-21")
+        );
+    }
+
+    #[test]
+    fn test_installation_name() {
+        assert_eq!("21", Distribution::Corretto.installation_name("21"));
+        assert_eq!(
+            "liberica-21.0.5+11",
+            Distribution::Liberica.installation_name("21.0.5+11")
+        );
+    }
+
+    #[test]
+    fn test_java_home() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let installation_dir = temp_dir.path();
+        assert_eq!(installation_dir, Distribution::java_home(installation_dir));
+
+        let bundle_home = installation_dir
+            .join("zulu-21.jdk")
+            .join("Contents")
+            .join("Home");
+        std::fs::create_dir_all(&bundle_home)?;
+        assert_eq!(bundle_home, Distribution::java_home(installation_dir));
+
+        let bundle_home = installation_dir.join("Contents").join("Home");
+        std::fs::create_dir_all(&bundle_home)?;
+        assert_eq!(bundle_home, Distribution::java_home(installation_dir));
+        Ok(())
+    }
+
+    #[test]
+    fn test_version_key() {
+        assert!(version_key("21.0.10+7") > version_key("21.0.9+11"));
+        assert_eq!(vec![17, 0, 13, 12], version_key("17.0.13+12"));
+    }
+
+    #[test]
+    fn test_platform() {
+        for distribution in DISTRIBUTIONS {
+            let (os, arch) = distribution.platform();
+            assert!(!os.is_empty());
+            assert!(!arch.is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_archive_url_invalid() {
+        let result = Distribution::Temurin.archive_url("0").await;
+        assert!(matches!(result, Err(Error::UnsupportedVersion(_))));
+    }
+
+    #[tokio::test]
+    async fn test_archive_url_temurin() -> Result<()> {
+        let (version, file_name, url, checksum) = Distribution::Temurin.archive_url("21").await?;
+        assert!(matches!(checksum, Some(Checksum::Sha256(_))));
+        assert!(version.starts_with("21"));
+        assert!(url.ends_with(&file_name));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_archive_url_zulu() -> Result<()> {
+        let (version, file_name, url, _checksum) = Distribution::Zulu.archive_url("21").await?;
+        assert!(version.starts_with("21"));
+        assert!(url.ends_with(&file_name));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_archive_url_liberica() -> Result<()> {
+        let (version, file_name, url, _checksum) = Distribution::Liberica.archive_url("21").await?;
+        assert!(version.starts_with("21"));
+        assert!(url.ends_with(&file_name));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_archive_url_graalvm() -> Result<()> {
+        let (version, file_name, url, checksum) = Distribution::GraalVm.archive_url("21").await?;
+        assert!(matches!(checksum, Some(Checksum::Sha256(_))));
+        assert!(version.starts_with("21"));
+        assert!(url.ends_with(&file_name));
+        Ok(())
+    }
+}
@@ -0,0 +1,578 @@
+use crate::runtime::util;
+use crate::Error::ArchiveError;
+use crate::Result;
+#[cfg(not(target_arch = "wasm32"))]
+use fs4::fs_std::FileExt;
+use reqwest::header::{self, HeaderMap};
+use reqwest::{Client, Response, StatusCode};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use std::fmt::{self, Debug, Write as _};
+use std::fs;
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs::File;
+#[cfg(target_arch = "wasm32")]
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::io::AsyncWriteExt;
+use tracing::{debug, instrument, warn};
+
+/// Interval at which a locked download is checked while waiting for the lock.
+#[cfg(not(target_arch = "wasm32"))]
+const LOCK_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Callback that receives the progress of a runtime archive download.  Callbacks are compared by
+/// identity.
+#[derive(Clone)]
+pub struct ProgressCallback(Arc<dyn Fn(&DownloadProgress) + Send + Sync>);
+
+impl ProgressCallback {
+    /// Create a progress callback.  The callback is called from the task that downloads the
+    /// archive, after every chunk that is received.
+    pub fn new<F: Fn(&DownloadProgress) + Send + Sync + 'static>(callback: F) -> Self {
+        Self(Arc::new(callback))
+    }
+
+    /// Report the progress of a download to the callback.
+    fn report(&self, progress: &DownloadProgress) {
+        (self.0)(progress);
+    }
+}
+
+impl Debug for ProgressCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ProgressCallback")
+    }
+}
+
+impl PartialEq for ProgressCallback {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Progress of a runtime archive download.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DownloadProgress {
+    url: String,
+    downloaded: u64,
+    total: Option<u64>,
+}
+
+impl DownloadProgress {
+    /// Get the url of the archive.
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Get the number of bytes downloaded, including bytes downloaded before a download was
+    /// resumed.
+    #[must_use]
+    pub fn downloaded(&self) -> u64 {
+        self.downloaded
+    }
+
+    /// Get the size of the archive in bytes, if the server reported it.
+    #[must_use]
+    pub fn total(&self) -> Option<u64> {
+        self.total
+    }
+}
+
+/// A checksum that a distribution publishes for a runtime archive.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Checksum {
+    /// Hex encoded SHA-1 digest
+    Sha1(String),
+    /// Hex encoded SHA-256 digest
+    Sha256(String),
+}
+
+impl Checksum {
+    /// Verify an archive against the checksum.
+    ///
+    /// # Errors
+    /// An error will be returned if the digest of the archive does not match the checksum.
+    fn verify(&self, file_name: &str, archive: &[u8]) -> Result<()> {
+        let (expected, digest) = match self {
+            Checksum::Sha1(expected) => (expected, Sha1::digest(archive).to_vec()),
+            Checksum::Sha256(expected) => (expected, Sha256::digest(archive).to_vec()),
+        };
+        let actual = digest.iter().fold(String::new(), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        });
+        if actual.eq_ignore_ascii_case(expected.trim()) {
+            Ok(())
+        } else {
+            Err(ArchiveError(format!(
+                "Invalid checksum for {file_name}: expected {expected}, found {actual}"
+            )))
+        }
+    }
+}
+
+/// Get the directory that runtime archives are downloaded to.
+pub(crate) fn download_dir() -> PathBuf {
+    let current_dir = std::env::current_dir().unwrap_or_default();
+
+    #[cfg(target_arch = "wasm32")]
+    let home_dir = current_dir;
+    #[cfg(not(target_arch = "wasm32"))]
+    let home_dir = home::home_dir().unwrap_or(current_dir);
+
+    home_dir.join(".ristretto").join(".downloads")
+}
+
+/// Download an archive to the download directory and return its contents.  The archive is first
+/// written to `<file_name>.part`; a download that fails is resumed from the partial file with an
+/// HTTP range request the next time it is downloaded, provided that the server reported an `ETag`
+/// or `Last-Modified` validator that shows the archive has not changed.  The archive is verified
+/// against its published checksum, if the distribution publishes one.  Processes that download the
+/// same archive at the same time are serialized by a `<file_name>.lock` file, and a process that
+/// waited for the lock reuses the archive downloaded by the other process.  The downloaded archive
+/// is kept until [`remove`] is called.
+///
+/// # Errors
+/// An error will be returned if the request fails, the archive cannot be written or the archive
+/// does not match its checksum.
+#[instrument(level = "debug", skip(progress))]
+pub(crate) async fn download(
+    url: &str,
+    file_name: &str,
+    checksum: Option<&Checksum>,
+    progress: Option<&ProgressCallback>,
+) -> Result<Vec<u8>> {
+    download_to(url, file_name, &download_dir(), checksum, progress).await
+}
+
+/// Remove a downloaded archive, e.g. after it has been extracted or when it cannot be extracted.
+pub(crate) fn remove(file_name: &str) {
+    let path = download_dir().join(file_name);
+    if let Err(error) = fs::remove_file(&path) {
+        debug!("Failed to remove {}: {error}", path.to_string_lossy());
+    }
+}
+
+/// Download an archive to a directory and return its contents.
+///
+/// # Errors
+/// An error will be returned if the request fails, the archive cannot be written or the archive
+/// does not match its checksum.
+async fn download_to(
+    url: &str,
+    file_name: &str,
+    download_dir: &Path,
+    checksum: Option<&Checksum>,
+    progress: Option<&ProgressCallback>,
+) -> Result<Vec<u8>> {
+    #[cfg(target_arch = "wasm32")]
+    fs::create_dir_all(download_dir)?;
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::fs::create_dir_all(download_dir).await?;
+    let archive_path = download_dir.join(file_name);
+    let partial_path = download_dir.join(format!("{file_name}.part"));
+    let validator_path = download_dir.join(format!("{file_name}.part.validator"));
+    let _lock = DownloadLock::acquire(&download_dir.join(format!("{file_name}.lock"))).await?;
+    if archive_path.is_file() {
+        debug!(
+            "Using downloaded archive: {}",
+            archive_path.to_string_lossy()
+        );
+        let archive = read(&archive_path).await?;
+        match checksum.map_or(Ok(()), |checksum| checksum.verify(file_name, &archive)) {
+            Ok(()) => return Ok(archive),
+            Err(error) => {
+                warn!("Removing downloaded archive: {error}");
+                remove_file(&archive_path).await?;
+            }
+        }
+    }
+
+    let response = request(url, &partial_path, &validator_path).await?;
+    let resumed = response.status() == StatusCode::PARTIAL_CONTENT;
+    if !resumed {
+        // Record the validator of the archive, so that a download that fails can be resumed
+        // only if the archive has not changed
+        match validator(&response) {
+            Some(validator) => write(&validator_path, validator.as_bytes()).await?,
+            None => remove_file(&validator_path).await?,
+        }
+    }
+    write_response(url, response, &partial_path, resumed, progress).await?;
+
+    #[cfg(target_arch = "wasm32")]
+    fs::rename(&partial_path, &archive_path)?;
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::fs::rename(&partial_path, &archive_path).await?;
+    remove_file(&validator_path).await?;
+    let archive = read(&archive_path).await?;
+    if let Some(checksum) = checksum {
+        if let Err(error) = checksum.verify(file_name, &archive) {
+            remove_file(&archive_path).await?;
+            return Err(error);
+        }
+    }
+    Ok(archive)
+}
+
+/// Request an archive, resuming the download of the partial file with a range request if the
+/// validator of the partial file is known.  The server returns the full archive if the archive has
+/// changed since the partial file was downloaded.
+///
+/// # Errors
+/// An error will be returned if the request fails.
+async fn request(url: &str, partial_path: &Path, validator_path: &Path) -> Result<Response> {
+    let offset = metadata_length(partial_path).await;
+    let validator = read(validator_path)
+        .await
+        .ok()
+        .and_then(|validator| String::from_utf8(validator).ok());
+    let mut request = Client::new().get(url).headers(util::headers());
+    if let (true, Some(validator)) = (offset > 0, validator) {
+        debug!("Resuming download of {url} at {offset} bytes");
+        request = request
+            .header(header::RANGE, format!("bytes={offset}-"))
+            .header(header::IF_RANGE, validator);
+    }
+    let mut response = request.send().await?;
+    let restart = match response.status() {
+        // The partial file is larger than the archive
+        StatusCode::RANGE_NOT_SATISFIABLE => true,
+        // The range does not start at the end of the partial file, so the response cannot be
+        // appended to it
+        StatusCode::PARTIAL_CONTENT => {
+            let start = content_range_start(response.headers());
+            if start == Some(offset) {
+                false
+            } else {
+                warn!("Ignoring partial content of {url} at {start:?}; expected {offset} bytes");
+                true
+            }
+        }
+        _ => false,
+    };
+    if restart {
+        remove_file(partial_path).await?;
+        response = Client::new()
+            .get(url)
+            .headers(util::headers())
+            .send()
+            .await?;
+    }
+    Ok(response.error_for_status()?)
+}
+
+/// Get the first byte position of a `Content-Range` header; e.g. `100` for
+/// `bytes 100-199/200`.
+fn content_range_start(headers: &HeaderMap) -> Option<u64> {
+    let content_range = headers.get(header::CONTENT_RANGE)?.to_str().ok()?;
+    let (start, _end) = content_range
+        .strip_prefix("bytes ")?
+        .split_once('/')?
+        .0
+        .split_once('-')?;
+    start.trim().parse().ok()
+}
+
+/// Get the validator of a response that a range request can be made conditional on; a strong
+/// `ETag`, or the `Last-Modified` date.
+fn validator(response: &Response) -> Option<String> {
+    let headers = response.headers();
+    headers
+        .get(header::ETAG)
+        .and_then(|etag| etag.to_str().ok())
+        .filter(|etag| !etag.starts_with("W/"))
+        .or_else(|| {
+            headers
+                .get(header::LAST_MODIFIED)
+                .and_then(|last_modified| last_modified.to_str().ok())
+        })
+        .map(ToString::to_string)
+}
+
+/// Write the body of a response to the partial file, appending to the file if the download was
+/// resumed, and report the progress of the download.
+///
+/// # Errors
+/// An error will be returned if the response cannot be read or the file cannot be written.
+async fn write_response(
+    url: &str,
+    mut response: Response,
+    partial_path: &Path,
+    resumed: bool,
+    progress_callback: Option<&ProgressCallback>,
+) -> Result<()> {
+    let mut downloaded = if resumed {
+        metadata_length(partial_path).await
+    } else {
+        0
+    };
+    #[cfg(target_arch = "wasm32")]
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(resumed)
+        .truncate(!resumed)
+        .open(partial_path)?;
+    #[cfg(not(target_arch = "wasm32"))]
+    let mut file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(resumed)
+        .truncate(!resumed)
+        .open(partial_path)
+        .await?;
+    let mut progress = DownloadProgress {
+        url: url.to_string(),
+        downloaded,
+        total: response.content_length().map(|length| length + downloaded),
+    };
+    debug!("Downloading archive: {url}");
+    if let Some(callback) = progress_callback {
+        callback.report(&progress);
+    }
+    while let Some(chunk) = response.chunk().await? {
+        #[cfg(target_arch = "wasm32")]
+        file.write_all(&chunk)?;
+        #[cfg(not(target_arch = "wasm32"))]
+        file.write_all(&chunk).await?;
+        downloaded += chunk.len() as u64;
+        progress.downloaded = downloaded;
+        if let Some(callback) = progress_callback {
+            callback.report(&progress);
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    file.flush()?;
+    #[cfg(not(target_arch = "wasm32"))]
+    file.flush().await?;
+    Ok(())
+}
+
+/// Read a file.
+///
+/// # Errors
+/// An error will be returned if the file cannot be read.
+async fn read(path: &Path) -> Result<Vec<u8>> {
+    #[cfg(target_arch = "wasm32")]
+    let bytes = fs::read(path)?;
+    #[cfg(not(target_arch = "wasm32"))]
+    let bytes = tokio::fs::read(path).await?;
+    Ok(bytes)
+}
+
+/// Write a file.
+///
+/// # Errors
+/// An error will be returned if the file cannot be written.
+async fn write(path: &Path, bytes: &[u8]) -> Result<()> {
+    #[cfg(target_arch = "wasm32")]
+    fs::write(path, bytes)?;
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::fs::write(path, bytes).await?;
+    Ok(())
+}
+
+/// Remove a file if it exists.
+///
+/// # Errors
+/// An error will be returned if the file exists and cannot be removed.
+async fn remove_file(path: &Path) -> Result<()> {
+    #[cfg(target_arch = "wasm32")]
+    let result = fs::remove_file(path);
+    #[cfg(not(target_arch = "wasm32"))]
+    let result = tokio::fs::remove_file(path).await;
+    match result {
+        Err(error) if error.kind() != std::io::ErrorKind::NotFound => Err(error.into()),
+        _ => Ok(()),
+    }
+}
+
+/// Get the length of a file, or zero if the file does not exist.
+async fn metadata_length(path: &Path) -> u64 {
+    #[cfg(target_arch = "wasm32")]
+    let metadata = fs::metadata(path);
+    #[cfg(not(target_arch = "wasm32"))]
+    let metadata = tokio::fs::metadata(path).await;
+    metadata.map_or(0, |metadata| metadata.len())
+}
+
+/// A lock that serializes the processes that download an archive; an exclusive advisory lock on
+/// a lock file.  The operating system releases the lock when the lock is dropped or the process
+/// that holds it exits, so a lock is never abandoned.  The lock file is not removed, as a process
+/// that waits for the lock may have opened it already.  File locks are not supported on wasm,
+/// where the lock does not serialize downloads.
+#[derive(Debug)]
+struct DownloadLock {
+    #[cfg(not(target_arch = "wasm32"))]
+    _file: File,
+}
+
+impl DownloadLock {
+    /// Acquire a lock, waiting while another process holds it.
+    ///
+    /// # Errors
+    /// An error will be returned if the lock file cannot be created or locked.
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn acquire(path: &Path) -> Result<Self> {
+        let file = File::options()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        while !file.try_lock_exclusive()? {
+            tokio::time::sleep(LOCK_POLL_INTERVAL).await;
+        }
+        Ok(Self { _file: file })
+    }
+
+    /// Acquire a lock; file locks are not supported on wasm.
+    ///
+    /// # Errors
+    /// An error will be returned if the lock file cannot be created.
+    #[cfg(target_arch = "wasm32")]
+    #[expect(clippy::unused_async)]
+    async fn acquire(_path: &Path) -> Result<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+    use std::sync::Mutex;
+
+    #[tokio::test]
+    async fn test_lock() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let lock_path = temp_dir.path().join("archive.lock");
+        let lock = DownloadLock::acquire(&lock_path).await?;
+        assert!(lock_path.is_file());
+
+        let waiter = {
+            let lock_path = lock_path.clone();
+            tokio::spawn(async move { DownloadLock::acquire(&lock_path).await })
+        };
+        tokio::time::sleep(LOCK_POLL_INTERVAL * 2).await;
+        assert!(!waiter.is_finished());
+        drop(lock);
+        let lock = waiter
+            .await
+            .map_err(|error| Error::ArchiveError(error.to_string()))??;
+        drop(lock);
+        assert!(lock_path.is_file());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_lock_file_without_lock() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let lock_path = temp_dir.path().join("archive.lock");
+
+        // A lock file that is not locked, e.g. left by a process that exited, does not block
+        fs::write(&lock_path, format!("{}\n", u32::MAX))?;
+        let lock = tokio::time::timeout(LOCK_POLL_INTERVAL, DownloadLock::acquire(&lock_path))
+            .await
+            .map_err(|error| Error::ArchiveError(error.to_string()))??;
+        drop(lock);
+        Ok(())
+    }
+
+    #[test]
+    fn test_content_range_start() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(None, content_range_start(&headers));
+        headers.insert(
+            header::CONTENT_RANGE,
+            header::HeaderValue::from_static("bytes 100-199/200"),
+        );
+        assert_eq!(Some(100), content_range_start(&headers));
+        headers.insert(
+            header::CONTENT_RANGE,
+            header::HeaderValue::from_static("bytes 0-199/*"),
+        );
+        assert_eq!(Some(0), content_range_start(&headers));
+        headers.insert(
+            header::CONTENT_RANGE,
+            header::HeaderValue::from_static("bytes */200"),
+        );
+        assert_eq!(None, content_range_start(&headers));
+    }
+
+    #[tokio::test]
+    async fn test_download_existing_archive() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        fs::write(temp_dir.path().join("archive.tar.gz"), b"archive")?;
+        let checksum = Checksum::Sha256("00".to_string());
+        let archive = download_to(
+            "https://localhost/archive.tar.gz",
+            "archive.tar.gz",
+            temp_dir.path(),
+            None,
+            None,
+        )
+        .await?;
+        assert_eq!(b"archive".to_vec(), archive);
+
+        // An archive that does not match its checksum is removed and downloaded again
+        let result = download_to(
+            "https://localhost/archive.tar.gz",
+            "archive.tar.gz",
+            temp_dir.path(),
+            Some(&checksum),
+            None,
+        )
+        .await;
+        assert!(result.is_err());
+        assert!(!temp_dir.path().join("archive.tar.gz").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_checksum() -> Result<()> {
+        Checksum::Sha256(
+            "E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855".to_string(),
+        )
+        .verify("archive", b"")?;
+        Checksum::Sha1("da39a3ee5e6b4b0d3255bfef95601890afd80709".to_string())
+            .verify("archive", b"")?;
+        let result = Checksum::Sha256("00".to_string()).verify("archive", b"");
+        assert!(matches!(result, Err(Error::ArchiveError(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_progress_callback() {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let callback_reports = Arc::clone(&reports);
+        let callback = ProgressCallback::new(move |progress: &DownloadProgress| {
+            if let Ok(mut reports) = callback_reports.lock() {
+                reports.push(progress.clone());
+            }
+        });
+        assert_eq!(callback, callback.clone());
+        assert_ne!(callback, ProgressCallback::new(|_| {}));
+        let progress = DownloadProgress {
+            url: "https://localhost/archive.tar.gz".to_string(),
+            downloaded: 10,
+            total: Some(20),
+        };
+        callback.report(&progress);
+
+        let reports = reports
+            .lock()
+            .map(|reports| reports.clone())
+            .unwrap_or_default();
+        assert_eq!(vec![progress], reports);
+        assert_eq!(10, reports[0].downloaded());
+        assert_eq!(Some(20), reports[0].total());
+    }
+}
@@ -1,7 +1,13 @@
 mod bootstrap;
+mod distribution;
+mod download;
 mod models;
 mod util;
 
 pub use bootstrap::{
-    default_class_loader, home_class_loader, version_class_loader, DEFAULT_JAVA_VERSION,
+    default_class_loader, distribution_class_loader, home_class_loader, home_class_loader_from,
+    is_offline, set_offline, version_class_loader, version_class_loader_with_progress,
+    DEFAULT_JAVA_VERSION,
 };
+pub use distribution::Distribution;
+pub use download::{DownloadProgress, ProgressCallback};
@@ -12,6 +12,7 @@ pub(crate) struct Release {
     pub name: String,
     pub draft: bool,
     pub prerelease: bool,
+    pub body: Option<String>,
     pub assets: Vec<Asset>,
 }
 
@@ -28,3 +29,53 @@ pub(crate) struct Asset {
     pub size: i64,
     pub browser_download_url: String,
 }
+
+/// Represents an Eclipse Temurin release from the Adoptium API
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct TemurinRelease {
+    pub release_name: String,
+    pub binaries: Vec<TemurinBinary>,
+    pub version_data: TemurinVersion,
+}
+
+/// Represents a binary of an Eclipse Temurin release
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct TemurinBinary {
+    pub package: TemurinPackage,
+}
+
+/// Represents the package of an Eclipse Temurin binary
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct TemurinPackage {
+    pub name: String,
+    pub link: String,
+    pub checksum: Option<String>,
+}
+
+/// Represents the version of an Eclipse Temurin release
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct TemurinVersion {
+    pub openjdk_version: String,
+    pub semver: String,
+}
+
+/// Represents an Azul Zulu package from the Azul metadata API
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ZuluPackage {
+    pub name: String,
+    pub download_url: String,
+    pub java_version: Vec<u64>,
+    pub sha256_hash: Option<String>,
+}
+
+/// Represents a Liberica JDK release from the Liberica API
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LibericaRelease {
+    pub filename: String,
+    pub download_url: String,
+    pub version: String,
+    #[serde(rename = "GA")]
+    pub ga: bool,
+    pub sha1: Option<String>,
+}
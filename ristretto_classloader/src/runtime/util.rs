@@ -1,13 +1,15 @@
+use crate::runtime::download::{self, Checksum, ProgressCallback};
 use crate::runtime::models::Release;
 use crate::{Error, Result};
 use reqwest::header;
 use reqwest::Client;
+use serde::de::DeserializeOwned;
 use std::env;
 use std::env::consts;
 use std::sync::LazyLock;
-use tracing::{debug, instrument};
+use tracing::{debug, instrument, warn};
 
-const DEFAULT_MAJOR_VERSION: u64 = 21;
+pub(crate) const DEFAULT_MAJOR_VERSION: u64 = 21;
 const GITHUB_API_VERSION_HEADER: &str = "X-GitHub-Api-Version";
 const GITHUB_API_VERSION: &str = "2022-11-28";
 
@@ -35,8 +37,11 @@ static USER_AGENT: LazyLock<String> = LazyLock::new(|| {
 ///
 /// # Errors
 /// An error will be returned if the request fails or if the version requirement is not supported.
-#[instrument(level = "debug")]
-pub(crate) async fn get_runtime_archive(version: &str) -> Result<(String, String, Vec<u8>)> {
+#[instrument(level = "debug", skip(progress))]
+pub(crate) async fn get_runtime_archive(
+    version: &str,
+    progress: Option<&ProgressCallback>,
+) -> Result<(String, String, Vec<u8>)> {
     let version = if version == "*" {
         DEFAULT_MAJOR_VERSION.to_string()
     } else {
@@ -51,7 +56,7 @@ pub(crate) async fn get_runtime_archive(version: &str) -> Result<(String, String
 
     let version_parts = version.chars().filter(|&c| c == '.').count() + 1;
     if major_version == 8 && version_parts == 4 || version_parts == 5 {
-        let (file_name, archive) = download_archive(version).await?;
+        let (file_name, archive) = download_archive(version, progress).await?;
         return Ok((version.to_string(), file_name, archive));
     }
 
@@ -59,7 +64,7 @@ pub(crate) async fn get_runtime_archive(version: &str) -> Result<(String, String
     let release_versions = get_release_versions(major_version.as_str()).await?;
     for release_version in release_versions {
         if release_version.starts_with(version) {
-            let (file_name, archive) = download_archive(release_version.as_str()).await?;
+            let (file_name, archive) = download_archive(release_version.as_str(), progress).await?;
             return Ok((release_version, file_name, archive));
         }
     }
@@ -71,15 +76,11 @@ pub(crate) async fn get_runtime_archive(version: &str) -> Result<(String, String
 ///
 /// # Errors
 /// An error will be returned if the request fails
-#[instrument(level = "debug")]
-async fn download_archive(version: &str) -> Result<(String, Vec<u8>)> {
-    let client = Client::new();
-    let mut headers = header::HeaderMap::new();
-    headers.insert(
-        header::USER_AGENT,
-        header::HeaderValue::from_static(&USER_AGENT),
-    );
-
+#[instrument(level = "debug", skip(progress))]
+async fn download_archive(
+    version: &str,
+    progress: Option<&ProgressCallback>,
+) -> Result<(String, Vec<u8>)> {
     let (os, arch, extension) = match consts::OS {
         "macos" => {
             if consts::ARCH == "aarch64" {
@@ -113,45 +114,122 @@ async fn download_archive(version: &str) -> Result<(String, Vec<u8>)> {
 
     let file_name = format!("amazon-corretto-{version}-{os}-{arch}{extension}");
     let url = format!("https://corretto.aws/downloads/resources/{version}/{file_name}");
-    debug!("Downloading archive: {url}");
-    let response = client
-        .get(url)
-        .headers(headers)
+    let checksum = match get_checksum(version, &file_name).await {
+        Ok(checksum) => checksum,
+        Err(error) => {
+            warn!("Failed to get the checksum of {file_name}: {error}");
+            None
+        }
+    };
+    let archive = download::download(&url, &file_name, checksum.as_ref(), progress).await?;
+    Ok((file_name, archive))
+}
+
+/// Get the SHA-256 checksum of a Corretto runtime archive from the notes of its GitHub release,
+/// which list the checksum of each archive on the line that links to the archive.
+///
+/// # Errors
+/// An error will be returned if the request fails
+#[instrument(level = "debug")]
+async fn get_checksum(version: &str, file_name: &str) -> Result<Option<Checksum>> {
+    let major_version = parse_major_version(version);
+    let url = format!(
+        "https://api.github.com/repos/corretto/corretto-{major_version}/releases/tags/{version}"
+    );
+    let response = Client::new()
+        .get(&url)
+        .headers(github_headers()?)
         .send()
         .await?
         .error_for_status()?;
-    let archive = response.bytes().await?;
-    Ok((file_name, archive.to_vec()))
+    let release = response.json::<Release>().await?;
+    Ok(release
+        .body
+        .as_deref()
+        .and_then(|body| parse_checksum(body, file_name)))
 }
 
-/// Get the release versions for a given major version.
-/// Returns a vector of release versions sorted in descending order.
+/// Parse the SHA-256 checksum of an archive from release notes.
+fn parse_checksum(body: &str, file_name: &str) -> Option<Checksum> {
+    let line = body.lines().find(|line| line.contains(file_name))?;
+    line.split(|character: char| !character.is_ascii_hexdigit())
+        .find(|token| token.len() == 64)
+        .map(|checksum| Checksum::Sha256(checksum.to_string()))
+}
+
+/// Get text from a distribution API, e.g. a checksum file.
 ///
 /// # Errors
 /// An error will be returned if the request fails
 #[instrument(level = "debug")]
-async fn get_release_versions(major_version: &str) -> Result<Vec<String>> {
-    let url = format!("https://api.github.com/repos/corretto/corretto-{major_version}/releases");
-    let client = Client::new();
+pub(crate) async fn get_text(url: &str) -> Result<String> {
+    debug!("Getting: {url}");
+    let response = Client::new()
+        .get(url)
+        .headers(headers())
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(response.text().await?)
+}
+
+/// Get a JSON document from a distribution API.
+///
+/// # Errors
+/// An error will be returned if the request fails or the response cannot be deserialized
+#[instrument(level = "debug")]
+pub(crate) async fn get_json<T: DeserializeOwned>(url: &str) -> Result<T> {
+    debug!("Getting: {url}");
+    let response = Client::new()
+        .get(url)
+        .headers(headers())
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(response.json::<T>().await?)
+}
+
+/// Get the headers for requests to download sites and distribution APIs.
+pub(crate) fn headers() -> header::HeaderMap {
     let mut headers = header::HeaderMap::new();
     headers.insert(
         header::USER_AGENT,
         header::HeaderValue::from_static(&USER_AGENT),
     );
-    headers.insert(
-        GITHUB_API_VERSION_HEADER,
-        header::HeaderValue::from_static(GITHUB_API_VERSION),
-    );
-    if let Some(token) = &*GITHUB_TOKEN {
-        headers.append(
-            header::AUTHORIZATION,
-            format!("Bearer {token}")
-                .parse()
-                .map_err(|_| Error::ParseError("Bearer token".to_string()))?,
-        );
-    }
+    headers
+}
+
+/// Get the release versions for a given major version.
+/// Returns a vector of release versions sorted in descending order.
+///
+/// # Errors
+/// An error will be returned if the request fails
+#[instrument(level = "debug")]
+async fn get_release_versions(major_version: &str) -> Result<Vec<String>> {
+    let releases = get_releases(&format!("corretto/corretto-{major_version}")).await?;
+    let mut releases = releases
+        .into_iter()
+        .map(|release| release.name)
+        .collect::<Vec<_>>();
 
-    debug!("Getting release versions: {url}");
+    // Sort the releases in descending order
+    releases.sort_by(|a, b| b.cmp(a));
+    debug!("Release versions: {releases:?}");
+
+    Ok(releases)
+}
+
+/// Get the releases of a GitHub repository, e.g. `corretto/corretto-21`, newest first.
+///
+/// # Errors
+/// An error will be returned if the request fails
+#[instrument(level = "debug")]
+pub(crate) async fn get_releases(repository: &str) -> Result<Vec<Release>> {
+    let url = format!("https://api.github.com/repos/{repository}/releases");
+    let client = Client::new();
+    let headers = github_headers()?;
+
+    debug!("Getting releases: {url}");
     let mut page = 1;
     let mut releases = Vec::new();
 
@@ -167,19 +245,34 @@ async fn get_release_versions(major_version: &str) -> Result<Vec<String>> {
         if response_releases.is_empty() {
             break;
         }
-        for release in response_releases {
-            releases.push(release.name);
-        }
+        releases.extend(response_releases);
         page += 1;
     }
 
-    // Sort the releases in descending order
-    releases.sort_by(|a, b| b.cmp(a));
-    debug!("Release versions: {releases:?}");
-
     Ok(releases)
 }
 
+/// Get the headers for requests to the GitHub API.
+///
+/// # Errors
+/// An error will be returned if the `GITHUB_TOKEN` is not a valid header value
+fn github_headers() -> Result<header::HeaderMap> {
+    let mut headers = headers();
+    headers.insert(
+        GITHUB_API_VERSION_HEADER,
+        header::HeaderValue::from_static(GITHUB_API_VERSION),
+    );
+    if let Some(token) = &*GITHUB_TOKEN {
+        headers.append(
+            header::AUTHORIZATION,
+            format!("Bearer {token}")
+                .parse()
+                .map_err(|_| Error::ParseError("Bearer token".to_string()))?,
+        );
+    }
+    Ok(headers)
+}
+
 /// Parse the major version from a version string. Returns 0 if the major version cannot be parsed.
 pub(crate) fn parse_major_version(version: &str) -> u64 {
     let major_part = version.split('.').next().unwrap_or("0");
@@ -193,7 +286,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_runtime_archive_latest_exact() -> Result<()> {
         let expected_version = "11.0.26.4.1";
-        let (version, file_name, archive) = get_runtime_archive(expected_version).await?;
+        let (version, file_name, archive) = get_runtime_archive(expected_version, None).await?;
         assert_eq!(expected_version, version);
         assert!(file_name.contains(expected_version));
         assert!(!archive.is_empty());
@@ -203,7 +296,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_runtime_archive_partial_version() -> Result<()> {
         let partial_version = "8.422";
-        let (version, file_name, archive) = get_runtime_archive(partial_version).await?;
+        let (version, file_name, archive) = get_runtime_archive(partial_version, None).await?;
         assert!(version.starts_with(partial_version));
         assert!(file_name.contains(partial_version));
         assert!(!archive.is_empty());
@@ -213,7 +306,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_runtime_archive_latest_major_version() -> Result<()> {
         let major_version = "17";
-        let (version, file_name, archive) = get_runtime_archive(major_version).await?;
+        let (version, file_name, archive) = get_runtime_archive(major_version, None).await?;
         assert!(version.starts_with(major_version));
         assert!(file_name.contains(major_version));
         assert!(!archive.is_empty());
@@ -222,7 +315,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_runtime_archive_latest_lts() -> Result<()> {
-        let (version, _file_name, archive) = get_runtime_archive("*").await?;
+        let (version, _file_name, archive) = get_runtime_archive("*", None).await?;
         let expected_major_version = DEFAULT_MAJOR_VERSION.to_string();
         assert!(version.starts_with(expected_major_version.as_str()));
         assert!(!archive.is_empty());
@@ -231,20 +324,20 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_runtime_archive_unsupported_version() {
-        let result = get_runtime_archive("21.0.0.0.0").await;
+        let result = get_runtime_archive("21.0.0.0.0", None).await;
         assert!(matches!(result, Err(Error::RequestError(_))));
     }
 
     #[tokio::test]
     async fn test_get_runtime_archive_invalid() {
-        let result = get_runtime_archive("0").await;
+        let result = get_runtime_archive("0", None).await;
         assert!(matches!(result, Err(Error::UnsupportedVersion(_))));
     }
 
     #[tokio::test]
     async fn test_download_archive() -> Result<()> {
         let version = "21.0.6.7.1";
-        let (_file_name, archive) = download_archive(version).await?;
+        let (_file_name, archive) = download_archive(version, None).await?;
         assert!(!archive.is_empty());
         Ok(())
     }
@@ -258,6 +351,30 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_get_checksum() -> Result<()> {
+        let version = "21.0.6.7.1";
+        let file_name = format!("amazon-corretto-{version}-linux-x64.tar.gz");
+        let checksum = get_checksum(version, &file_name).await?;
+        assert!(matches!(checksum, Some(Checksum::Sha256(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_checksum() {
+        let body = "| Linux x64 | JDK | [amazon-corretto-21-linux-x64.tar.gz](https://corretto.aws/downloads/resources/21/amazon-corretto-21-linux-x64.tar.gz) | `d41d8cd98f00b204e9800998ecf8427e` / `e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855` |";
+        assert_eq!(
+            Some(Checksum::Sha256(
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855".to_string()
+            )),
+            parse_checksum(body, "amazon-corretto-21-linux-x64.tar.gz")
+        );
+        assert_eq!(
+            None,
+            parse_checksum(body, "amazon-corretto-21-linux-aarch64.tar.gz")
+        );
+    }
+
     #[test]
     fn test_parse_major_version() {
         assert_eq!(11, parse_major_version("11"));
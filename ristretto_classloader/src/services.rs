@@ -0,0 +1,173 @@
+use crate::module_graph::Module;
+use crate::Error::ParseError;
+use crate::{ClassLoader, ClassPath, Result};
+
+/// Directory of the provider configuration files of the class path.
+const SERVICES_DIRECTORY: &str = "META-INF/services";
+
+/// Implementation of service provider discovery.
+impl ClassPath {
+    /// Get the binary names of the providers of a service, e.g. `org.h2.Driver` for
+    /// `java.sql.Driver`, in class path order.  Providers of named modules are declared by the
+    /// `provides` directives of their `module-info` class, and providers of other class path
+    /// entries by a `META-INF/services/<service>` provider configuration file.
+    ///
+    /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/util/ServiceLoader.html>
+    ///
+    /// # Errors
+    /// if a `module-info` class or a provider configuration file cannot be read.
+    pub async fn services<S: AsRef<str>>(&self, service: S) -> Result<Vec<String>> {
+        let service = service.as_ref().replace('/', ".");
+        let mut providers = Vec::new();
+        for class_path_entry in self.iter() {
+            let entry_providers =
+                if let Ok(class_file) = class_path_entry.read_class("module-info").await {
+                    let module = Module::from_class_file(&class_file)?;
+                    let internal_name = service.replace('.', "/");
+                    module
+                        .provides()
+                        .get(&internal_name)
+                        .into_iter()
+                        .flatten()
+                        .map(|provider| provider.replace('/', "."))
+                        .collect()
+                } else {
+                    let file_name = format!("{SERVICES_DIRECTORY}/{service}");
+                    match class_path_entry.read_file(file_name).await? {
+                        Some(bytes) => parse_provider_configuration(&bytes)?,
+                        None => Vec::new(),
+                    }
+                };
+            for provider in entry_providers {
+                if !providers.contains(&provider) {
+                    providers.push(provider);
+                }
+            }
+        }
+        Ok(providers)
+    }
+}
+
+impl ClassLoader {
+    /// Get the binary names of the providers of a service that are visible to this class loader,
+    /// from the boot class loader to this class loader.
+    ///
+    /// # Errors
+    /// if a `module-info` class or a provider configuration file cannot be read.
+    pub async fn services<S: AsRef<str>>(&self, service: S) -> Result<Vec<String>> {
+        let service = service.as_ref();
+        let mut providers = Vec::new();
+        for class_loader in self.class_loaders() {
+            for provider in class_loader.class_path().services(service).await? {
+                if !providers.contains(&provider) {
+                    providers.push(provider);
+                }
+            }
+        }
+        Ok(providers)
+    }
+}
+
+/// Parse a provider configuration file; each line names a provider class, and `#` starts a
+/// comment.
+///
+/// # Errors
+/// if the file is not UTF-8.
+fn parse_provider_configuration(bytes: &[u8]) -> Result<Vec<String>> {
+    let configuration =
+        std::str::from_utf8(bytes).map_err(|error| ParseError(error.to_string()))?;
+    let mut providers = Vec::new();
+    for line in configuration.lines() {
+        let provider = line.split('#').next().unwrap_or_default().trim();
+        if !provider.is_empty() && !providers.iter().any(|name| name == provider) {
+            providers.push(provider.to_string());
+        }
+    }
+    Ok(providers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ClassPathEntry;
+    use ristretto_classfile::ModuleInfoBuilder;
+    use std::fs;
+
+    /// Create a class path of a directory with a provider configuration file and an exploded
+    /// module that provides the same service.
+    fn class_path(root: &std::path::Path) -> Result<ClassPath> {
+        let directory = root.join("classes");
+        let services = directory.join(SERVICES_DIRECTORY);
+        fs::create_dir_all(&services)?;
+        fs::write(
+            services.join("java.sql.Driver"),
+            "# JDBC drivers\norg.h2.Driver\n\n  com.example.Driver # example\norg.h2.Driver\n",
+        )?;
+
+        let module = root.join("module");
+        fs::create_dir_all(&module)?;
+        let module_info = ModuleInfoBuilder::new("app")
+            .provides("java/sql/Driver", &["app/Driver"])
+            .package("app")
+            .build()?;
+        let mut bytes = Vec::new();
+        module_info.to_bytes(&mut bytes)?;
+        fs::write(module.join("module-info.class"), bytes)?;
+        // Provider configuration files of named modules are ignored
+        fs::create_dir_all(module.join(SERVICES_DIRECTORY))?;
+        fs::write(
+            module.join(SERVICES_DIRECTORY).join("java.sql.Driver"),
+            "app.Ignored\n",
+        )?;
+
+        Ok(ClassPath::new(vec![
+            ClassPathEntry::new(directory.to_string_lossy()),
+            ClassPathEntry::new(module.to_string_lossy()),
+        ]))
+    }
+
+    #[test]
+    fn test_parse_provider_configuration() -> Result<()> {
+        let providers = parse_provider_configuration(b"# comment\na.B\r\n a.C # c\n\na.B\n")?;
+        assert_eq!(vec!["a.B", "a.C"], providers);
+        assert!(parse_provider_configuration(&[0xff]).is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_class_path_services() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let class_path = class_path(temp_dir.path())?;
+        let expected = vec!["org.h2.Driver", "com.example.Driver", "app.Driver"];
+        assert_eq!(expected, class_path.services("java.sql.Driver").await?);
+        assert_eq!(expected, class_path.services("java/sql/Driver").await?);
+        assert!(class_path.services("java.lang.Runnable").await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_class_loader_services() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let parent = ClassLoader::new("parent", class_path(temp_dir.path())?);
+        let services = temp_dir.path().join("child").join(SERVICES_DIRECTORY);
+        fs::create_dir_all(&services)?;
+        fs::write(
+            services.join("java.sql.Driver"),
+            "child.Driver\norg.h2.Driver\n",
+        )?;
+        let child_directory = temp_dir.path().join("child");
+        let mut class_loader =
+            ClassLoader::new("child", ClassPath::from(child_directory.to_string_lossy()));
+        class_loader.set_parent(Some(parent));
+        assert_eq!(
+            vec![
+                "org.h2.Driver",
+                "com.example.Driver",
+                "app.Driver",
+                "child.Driver"
+            ],
+            class_loader.services("java.sql.Driver").await?
+        );
+        Ok(())
+    }
+}
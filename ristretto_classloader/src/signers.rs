@@ -0,0 +1,183 @@
+use crate::Error::SecurityError;
+use crate::{ClassLoader, ClassPath, CodeSigner, Result};
+
+/// Implementation of signer lookup.
+impl ClassPath {
+    /// Get the signers of a class, e.g. `com.example.Foo`, from the first class path entry that
+    /// contains the class; classes that are not in a signed jar have no signers.  This matches
+    /// `Class.getSigners` for a class loaded from the class path.
+    ///
+    /// # Errors
+    /// if a class path entry cannot be read, or the class fails verification.
+    pub async fn class_signers<S: AsRef<str>>(&self, class_name: S) -> Result<Vec<CodeSigner>> {
+        let signers = self.find_class_signers(class_name.as_ref()).await?;
+        Ok(signers.unwrap_or_default())
+    }
+
+    /// Get the signers of a class, or `None` if the class is not in the class path.
+    ///
+    /// # Errors
+    /// if a class path entry cannot be read, or the class fails verification.
+    async fn find_class_signers(&self, class_name: &str) -> Result<Option<Vec<CodeSigner>>> {
+        let file_name = format!("{}.class", class_name.replace('.', "/"));
+        for class_path_entry in self.iter() {
+            match class_path_entry.read_file(&file_name).await {
+                Ok(Some(_)) => return class_path_entry.signers(&file_name).await.map(Some),
+                // A class that fails jar verification must not be loaded from a later entry
+                Err(error @ SecurityError(_)) => return Err(error),
+                Ok(None) | Err(_) => {}
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl ClassLoader {
+    /// Get the signers of a class that is visible to this class loader, from the first class
+    /// loader that contains the class, starting with the boot class loader; this is the class
+    /// loader that loads the class.
+    ///
+    /// # Errors
+    /// if a class path entry cannot be read, or the class fails verification.
+    pub async fn class_signers<S: AsRef<str>>(&self, class_name: S) -> Result<Vec<CodeSigner>> {
+        let class_name = class_name.as_ref();
+        for class_loader in self.class_loaders() {
+            let class_path = class_loader.class_path();
+            if let Some(signers) = class_path.find_class_signers(class_name).await? {
+                return Ok(signers);
+            }
+        }
+        Ok(Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ClassPathEntry;
+    use std::io::{Cursor, Read, Write};
+    use std::path::PathBuf;
+    use zip::write::SimpleFileOptions;
+
+    fn classes_jar(name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("classes")
+            .join(name)
+    }
+
+    /// Copy a jar, replacing the contents of an entry.
+    fn tamper(jar: &PathBuf, name: &str, bytes: &[u8]) -> Result<Vec<u8>> {
+        let mut archive = zip::ZipArchive::new(std::fs::File::open(jar)?)?;
+        let mut tampered = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for index in 0..archive.len() {
+            let mut file = archive.by_index(index)?;
+            let file_name = file.name().to_string();
+            let mut contents = Vec::new();
+            file.read_to_end(&mut contents)?;
+            tampered.start_file(file_name.as_str(), SimpleFileOptions::default())?;
+            if file_name == name {
+                tampered.write_all(bytes)?;
+            } else {
+                tampered.write_all(&contents)?;
+            }
+        }
+        Ok(tampered.finish()?.into_inner())
+    }
+
+    #[tokio::test]
+    async fn test_class_signers() -> Result<()> {
+        for (jar, subject) in [
+            ("signed.jar", "CN=Ristretto RSA"),
+            ("signed-ec.jar", "CN=Ristretto EC"),
+        ] {
+            let class_path = ClassPath::from(classes_jar(jar).to_string_lossy());
+            let signers = class_path.class_signers("HelloWorld").await?;
+            assert_eq!(1, signers.len());
+            assert_eq!(subject, signers[0].subject());
+            assert_eq!(subject, signers[0].issuer());
+            assert_eq!(1, signers[0].certificate_chain().len());
+
+            let class_file = class_path.read_class("HelloWorld").await?;
+            assert_eq!("HelloWorld", class_file.class_name()?);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_class_signers_unsigned() -> Result<()> {
+        let class_path = ClassPath::from(classes_jar("classes.jar").to_string_lossy());
+        assert!(class_path.class_signers("HelloWorld").await?.is_empty());
+        let class_path = ClassPath::from(classes_jar("").to_string_lossy());
+        assert!(class_path.class_signers("HelloWorld").await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_class_signers_skips_unreadable_entries() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let invalid_jar = temp_dir.path().join("invalid.jar");
+        std::fs::write(&invalid_jar, b"not a jar")?;
+        let class_path = ClassPath::new(vec![
+            ClassPathEntry::new(invalid_jar.to_string_lossy()),
+            ClassPathEntry::new(classes_jar("signed.jar").to_string_lossy()),
+        ]);
+        let signers = class_path.class_signers("HelloWorld").await?;
+        assert_eq!("CN=Ristretto RSA", signers[0].subject());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_class_loader_class_signers() -> Result<()> {
+        let parent = ClassLoader::new(
+            "parent",
+            ClassPath::from(classes_jar("signed.jar").to_string_lossy()),
+        );
+        let mut class_loader = ClassLoader::new(
+            "child",
+            ClassPath::from(classes_jar("classes.jar").to_string_lossy()),
+        );
+        class_loader.set_parent(Some(parent));
+        let signers = class_loader.class_signers("HelloWorld").await?;
+        assert_eq!("CN=Ristretto RSA", signers[0].subject());
+        assert!(class_loader.class_signers("Child").await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_tampered_class() -> Result<()> {
+        let jar = classes_jar("signed.jar");
+        let simple = std::fs::read(classes_jar("Simple.class"))?;
+        let bytes = tamper(&jar, "HelloWorld.class", &simple)?;
+        let temp_dir = tempfile::tempdir()?;
+        let tampered_jar = temp_dir.path().join("tampered.jar");
+        std::fs::write(&tampered_jar, bytes)?;
+
+        let class_path_entry = ClassPathEntry::new(tampered_jar.to_string_lossy());
+        let result = class_path_entry.read_class("HelloWorld").await;
+        assert!(matches!(result, Err(SecurityError(_))));
+        assert!(class_path_entry.read_class("Simple").await.is_ok());
+
+        let class_path = ClassPath::new(vec![
+            class_path_entry,
+            ClassPathEntry::new(classes_jar("classes.jar").to_string_lossy()),
+        ]);
+        let result = class_path.read_class("HelloWorld").await;
+        assert!(matches!(result, Err(SecurityError(_))));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_tampered_signature_file() -> Result<()> {
+        let jar = classes_jar("signed.jar");
+        let bytes = tamper(&jar, "META-INF/RSA.SF", b"Signature-Version: 1.0\r\n\r\n")?;
+        let temp_dir = tempfile::tempdir()?;
+        let tampered_jar = temp_dir.path().join("tampered.jar");
+        std::fs::write(&tampered_jar, bytes)?;
+
+        let class_path_entry = ClassPathEntry::new(tampered_jar.to_string_lossy());
+        let result = class_path_entry.read_class("HelloWorld").await;
+        assert!(matches!(result, Err(SecurityError(_))));
+        Ok(())
+    }
+}
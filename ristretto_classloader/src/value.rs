@@ -1,3 +1,4 @@
+use crate::debug_json::DebugJson;
 use crate::reference::Reference;
 use crate::Error::InvalidValueType;
 use crate::{Class, Object, Result};
@@ -96,6 +97,16 @@ impl Value {
 
         Ok(value)
     }
+
+    /// Render the value as JSON for debugging, expanding references up to `depth` levels; see
+    /// [`Object::to_debug_json`].
+    ///
+    /// # Errors
+    /// if a field value cannot be read.
+    pub fn to_debug_json(&self, depth: usize) -> Result<String> {
+        let json = DebugJson::default().value(self, None, depth)?;
+        Ok(format!("{json:#}"))
+    }
 }
 
 impl Display for Value {
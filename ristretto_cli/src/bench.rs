@@ -0,0 +1,605 @@
+use clap::{Args, ValueEnum};
+use ristretto_classfile::attributes::Attribute;
+use ristretto_classfile::{ClassFile, MethodAccessFlags};
+use ristretto_vm::Error::InternalError;
+use ristretto_vm::{Configuration, Result, Value, VM};
+use serde_json::json;
+use std::time::{Duration, Instant};
+
+/// Simple name of the annotation that marks a benchmark method; e.g.
+/// `org.openjdk.jmh.annotations.Benchmark`
+const BENCHMARK_ANNOTATION: &str = "Benchmark";
+/// Name prefix of the benchmark methods of a class without annotated benchmark methods
+const BENCHMARK_PREFIX: &str = "bench";
+
+/// Arguments of the `bench` command.
+#[derive(Clone, Debug, Args)]
+pub(crate) struct BenchArguments {
+    #[arg(help = "The class containing the benchmark methods")]
+    pub(crate) class: String,
+
+    #[arg(
+        long = "warmup-iterations",
+        value_name = "COUNT",
+        default_value_t = 3,
+        help = "Number of warmup iterations per fork; warmup results are not measured"
+    )]
+    pub(crate) warmup_iterations: usize,
+
+    #[arg(
+        long = "iterations",
+        value_name = "COUNT",
+        default_value_t = 5,
+        value_parser = clap::value_parser!(u64).range(1..),
+        help = "Number of measurement iterations per fork"
+    )]
+    pub(crate) iterations: u64,
+
+    #[arg(
+        long = "iteration-time",
+        value_name = "MILLISECONDS",
+        default_value_t = 1000,
+        help = "Minimum duration of each iteration; the benchmark method is invoked repeatedly until the duration elapses"
+    )]
+    pub(crate) iteration_time: u64,
+
+    #[arg(
+        long = "forks",
+        value_name = "COUNT",
+        default_value_t = 1,
+        value_parser = clap::value_parser!(u64).range(1..),
+        help = "Number of times to run each benchmark in a new VM"
+    )]
+    pub(crate) forks: u64,
+
+    #[arg(
+        long = "mode",
+        value_enum,
+        default_value_t = Mode::Throughput,
+        help = "Benchmark mode"
+    )]
+    pub(crate) mode: Mode,
+
+    #[arg(
+        long = "format",
+        value_enum,
+        default_value_t = ResultFormat::Text,
+        help = "Output format of the benchmark results"
+    )]
+    pub(crate) format: ResultFormat,
+}
+
+/// Benchmark mode; the score measured by each iteration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Mode {
+    /// Operations per second
+    #[value(name = "thrpt")]
+    Throughput,
+    /// Average time per operation in nanoseconds
+    #[value(name = "avgt")]
+    AverageTime,
+}
+
+impl Mode {
+    /// Short name of the mode.
+    fn name(self) -> &'static str {
+        match self {
+            Mode::Throughput => "thrpt",
+            Mode::AverageTime => "avgt",
+        }
+    }
+
+    /// Units of the score.
+    fn unit(self) -> &'static str {
+        match self {
+            Mode::Throughput => "ops/s",
+            Mode::AverageTime => "ns/op",
+        }
+    }
+
+    /// Score of an iteration that performed `operations` in `elapsed` time.
+    fn score(self, operations: u64, elapsed: Duration) -> f64 {
+        match self {
+            Mode::Throughput => operations as f64 / elapsed.as_secs_f64(),
+            Mode::AverageTime => elapsed.as_nanos() as f64 / operations as f64,
+        }
+    }
+}
+
+/// Output format of the benchmark results.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub(crate) enum ResultFormat {
+    Text,
+    Json,
+}
+
+/// A benchmark method of the benchmark class.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Benchmark {
+    name: String,
+    descriptor: String,
+    is_static: bool,
+}
+
+/// Statistics of the measurement iteration scores of a benchmark.
+#[derive(Clone, Debug, PartialEq)]
+struct Statistics {
+    count: usize,
+    mean: f64,
+    /// Half width of the 99.9% confidence interval of the mean; `None` for a single sample
+    error: Option<f64>,
+    standard_deviation: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Statistics {
+    fn new(samples: &[f64]) -> Self {
+        let count = samples.len();
+        let mean = samples.iter().sum::<f64>() / count as f64;
+        let (standard_deviation, error) = if count > 1 {
+            let variance = samples
+                .iter()
+                .map(|sample| (sample - mean).powi(2))
+                .sum::<f64>()
+                / (count - 1) as f64;
+            let standard_deviation = variance.sqrt();
+            let error = student_t_999(count - 1) * standard_deviation / (count as f64).sqrt();
+            (standard_deviation, Some(error))
+        } else {
+            (0.0, None)
+        };
+        Statistics {
+            count,
+            mean,
+            error,
+            standard_deviation,
+            min: samples.iter().copied().fold(f64::INFINITY, f64::min),
+            max: samples.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        }
+    }
+}
+
+/// Quantile of the two-sided 99.9% confidence interval of the Student's t-distribution.  Degrees
+/// of freedom between the tabulated values use the next lower tabulated value, which results in a
+/// slightly wider interval.
+fn student_t_999(degrees_of_freedom: usize) -> f64 {
+    const QUANTILES: [f64; 30] = [
+        636.619, 31.599, 12.924, 8.610, 6.869, 5.959, 5.408, 5.041, 4.781, 4.587, 4.437, 4.318,
+        4.221, 4.140, 4.073, 4.015, 3.965, 3.922, 3.883, 3.850, 3.819, 3.792, 3.768, 3.745, 3.725,
+        3.707, 3.690, 3.674, 3.659, 3.646,
+    ];
+    match degrees_of_freedom {
+        0 => f64::NAN,
+        1..=30 => QUANTILES[degrees_of_freedom - 1],
+        31..=39 => 3.646,
+        40..=59 => 3.551,
+        60..=119 => 3.460,
+        120..=999 => 3.373,
+        _ => 3.291,
+    }
+}
+
+/// The measured results of a benchmark.
+#[derive(Clone, Debug, PartialEq)]
+struct BenchmarkResult {
+    name: String,
+    samples: Vec<f64>,
+    statistics: Statistics,
+}
+
+/// Run the benchmark methods of a class.  Each fork of a benchmark runs in a new VM created from
+/// the configuration; progress is written to stderr and the results to stdout.
+///
+/// # Errors
+/// if the class has no benchmark methods, or a VM cannot be created or a benchmark method cannot
+/// be invoked
+pub(crate) async fn run(configuration: Configuration, arguments: &BenchArguments) -> Result<()> {
+    let vm = VM::new(configuration.clone()).await?;
+    let class = vm.class(&arguments.class).await?;
+    let benchmarks = benchmarks(class.class_file())?;
+    if benchmarks.is_empty() {
+        return Err(InternalError(format!(
+            "No benchmark methods found in {}",
+            arguments.class
+        )));
+    }
+    drop(vm);
+
+    let mut results = Vec::new();
+    for benchmark in &benchmarks {
+        let name = format!("{}.{}", arguments.class, benchmark.name);
+        eprintln!("# Benchmark: {name}");
+        let mut samples = Vec::new();
+        for fork in 1..=arguments.forks {
+            eprintln!("# Fork: {fork} of {}", arguments.forks);
+            let vm = VM::new(configuration.clone()).await?;
+            samples.extend(run_fork(&vm, arguments, benchmark).await?);
+        }
+        let statistics = Statistics::new(&samples);
+        results.push(BenchmarkResult {
+            name,
+            samples,
+            statistics,
+        });
+    }
+
+    let output = match arguments.format {
+        ResultFormat::Text => format_text(&results, arguments.mode),
+        ResultFormat::Json => format_json(&results, arguments),
+    };
+    print!("{output}");
+    Ok(())
+}
+
+/// Find the benchmark methods of a class; the methods without parameters annotated with a
+/// `Benchmark` annotation, or if there are none, the methods without parameters with names that
+/// start with `bench`.
+fn benchmarks(class_file: &ClassFile) -> Result<Vec<Benchmark>> {
+    let constant_pool = &class_file.constant_pool;
+    let mut annotated = Vec::new();
+    let mut named = Vec::new();
+    for method in &class_file.methods {
+        let name = constant_pool.try_get_utf8(method.name_index)?;
+        let descriptor = constant_pool.try_get_utf8(method.descriptor_index)?;
+        if name.starts_with('<')
+            || !descriptor.starts_with("()")
+            || method.access_flags.contains(MethodAccessFlags::ABSTRACT)
+        {
+            continue;
+        }
+        let benchmark = Benchmark {
+            name: name.clone(),
+            descriptor: descriptor.clone(),
+            is_static: method.access_flags.contains(MethodAccessFlags::STATIC),
+        };
+
+        let mut is_annotated = false;
+        for attribute in &method.attributes {
+            let (Attribute::RuntimeVisibleAnnotations { annotations, .. }
+            | Attribute::RuntimeInvisibleAnnotations { annotations, .. }) = attribute
+            else {
+                continue;
+            };
+            for annotation in annotations {
+                let type_name = annotation.type_name(constant_pool)?;
+                let simple_name = type_name
+                    .trim_end_matches(';')
+                    .rsplit(['/', '$'])
+                    .next()
+                    .unwrap_or_default();
+                is_annotated |= simple_name == BENCHMARK_ANNOTATION;
+            }
+        }
+
+        if is_annotated {
+            annotated.push(benchmark);
+        } else if name.starts_with(BENCHMARK_PREFIX) {
+            named.push(benchmark);
+        }
+    }
+
+    if annotated.is_empty() {
+        Ok(named)
+    } else {
+        Ok(annotated)
+    }
+}
+
+/// Run the warmup and measurement iterations of a benchmark, returning the measurement scores.
+/// Instance benchmark methods are invoked on an object created with the no argument constructor.
+async fn run_fork(vm: &VM, arguments: &BenchArguments, benchmark: &Benchmark) -> Result<Vec<f64>> {
+    let parameters = if benchmark.is_static {
+        Vec::new()
+    } else {
+        vec![
+            vm.object(&arguments.class, "()V", Vec::<Value>::new())
+                .await?,
+        ]
+    };
+    let mode = arguments.mode;
+    let unit = mode.unit();
+    let iteration_time = Duration::from_millis(arguments.iteration_time);
+
+    for iteration in 1..=arguments.warmup_iterations {
+        let score = run_iteration(
+            vm,
+            &arguments.class,
+            benchmark,
+            &parameters,
+            mode,
+            iteration_time,
+        )
+        .await?;
+        eprintln!("# Warmup Iteration {iteration:>3}: {score:.3} {unit}");
+    }
+    let mut scores = Vec::new();
+    for iteration in 1..=arguments.iterations {
+        let score = run_iteration(
+            vm,
+            &arguments.class,
+            benchmark,
+            &parameters,
+            mode,
+            iteration_time,
+        )
+        .await?;
+        eprintln!("Iteration {iteration:>3}: {score:.3} {unit}");
+        scores.push(score);
+    }
+    Ok(scores)
+}
+
+/// Invoke the benchmark method repeatedly until the iteration time elapses and return the score.
+async fn run_iteration(
+    vm: &VM,
+    class: &str,
+    benchmark: &Benchmark,
+    parameters: &[Value],
+    mode: Mode,
+    iteration_time: Duration,
+) -> Result<f64> {
+    let start = Instant::now();
+    let mut operations = 0;
+    loop {
+        vm.invoke(
+            class,
+            &benchmark.name,
+            &benchmark.descriptor,
+            parameters.to_vec(),
+        )
+        .await?;
+        operations += 1;
+        let elapsed = start.elapsed();
+        if elapsed >= iteration_time {
+            return Ok(mode.score(operations, elapsed));
+        }
+    }
+}
+
+/// Format the benchmark results as a table.
+fn format_text(results: &[BenchmarkResult], mode: Mode) -> String {
+    let rows = results
+        .iter()
+        .map(|result| {
+            let statistics = &result.statistics;
+            let error = statistics
+                .error
+                .map(|error| format!("± {error:.3}"))
+                .unwrap_or_default();
+            [
+                result.name.clone(),
+                mode.name().to_string(),
+                statistics.count.to_string(),
+                format!("{:.3}", statistics.mean),
+                error,
+                mode.unit().to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let header = ["Benchmark", "Mode", "Cnt", "Score", "Error", "Units"].map(ToString::to_string);
+    let mut widths = header.clone().map(|column| column.chars().count());
+    for row in &rows {
+        for (width, column) in widths.iter_mut().zip(row) {
+            *width = (*width).max(column.chars().count());
+        }
+    }
+
+    let mut output = String::new();
+    for row in std::iter::once(&header).chain(&rows) {
+        let [name, mode, count, score, error, unit] = row;
+        output.push_str(&format!(
+            "{name:<name_width$}  {mode:>mode_width$}  {count:>count_width$}  {score:>score_width$}  {error:<error_width$}  {unit}\n",
+            name_width = widths[0],
+            mode_width = widths[1],
+            count_width = widths[2],
+            score_width = widths[3],
+            error_width = widths[4],
+        ));
+    }
+    output
+}
+
+/// Format the benchmark results as JSON.
+fn format_json(results: &[BenchmarkResult], arguments: &BenchArguments) -> String {
+    let results = results
+        .iter()
+        .map(|result| {
+            let statistics = &result.statistics;
+            json!({
+                "benchmark": result.name,
+                "mode": arguments.mode.name(),
+                "forks": arguments.forks,
+                "warmup_iterations": arguments.warmup_iterations,
+                "iterations": arguments.iterations,
+                "iteration_time_ms": arguments.iteration_time,
+                "count": statistics.count,
+                "score": statistics.mean,
+                "error": statistics.error,
+                "standard_deviation": statistics.standard_deviation,
+                "min": statistics.min,
+                "max": statistics.max,
+                "unit": arguments.mode.unit(),
+                "samples": result.samples,
+            })
+        })
+        .collect::<Vec<_>>();
+    format!("{:#}\n", serde_json::Value::Array(results))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use ristretto_classfile::attributes::Annotation;
+    use ristretto_classfile::{ConstantPool, Method};
+
+    #[derive(Debug, Parser)]
+    struct Cli {
+        #[command(flatten)]
+        arguments: BenchArguments,
+    }
+
+    fn arguments() -> BenchArguments {
+        Cli::parse_from(["bench", "Fib"]).arguments
+    }
+
+    fn result(samples: Vec<f64>) -> BenchmarkResult {
+        BenchmarkResult {
+            name: "Fib.benchFib".to_string(),
+            statistics: Statistics::new(&samples),
+            samples,
+        }
+    }
+
+    #[test]
+    fn test_arguments_defaults() {
+        let arguments = arguments();
+        assert_eq!("Fib", arguments.class);
+        assert_eq!(3, arguments.warmup_iterations);
+        assert_eq!(5, arguments.iterations);
+        assert_eq!(1000, arguments.iteration_time);
+        assert_eq!(1, arguments.forks);
+        assert_eq!(Mode::Throughput, arguments.mode);
+        assert_eq!(ResultFormat::Text, arguments.format);
+    }
+
+    #[test]
+    fn test_arguments_invalid_forks() {
+        let result = Cli::try_parse_from(["bench", "Fib", "--forks", "0"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_mode_score() {
+        let elapsed = Duration::from_millis(500);
+        assert!((Mode::Throughput.score(100, elapsed) - 200.0).abs() < f64::EPSILON);
+        assert!((Mode::AverageTime.score(100, elapsed) - 5_000_000.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_statistics() {
+        let statistics = Statistics::new(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(5, statistics.count);
+        assert!((statistics.mean - 3.0).abs() < f64::EPSILON);
+        assert!((statistics.standard_deviation - 2.5_f64.sqrt()).abs() < 1e-9);
+        let error = statistics.error.expect("error");
+        assert!((error - 8.610 * 2.5_f64.sqrt() / 5_f64.sqrt()).abs() < 1e-9);
+        assert!((statistics.min - 1.0).abs() < f64::EPSILON);
+        assert!((statistics.max - 5.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_statistics_single_sample() {
+        let statistics = Statistics::new(&[42.0]);
+        assert_eq!(1, statistics.count);
+        assert!(statistics.error.is_none());
+        assert!(statistics.standard_deviation.abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_student_t_999() {
+        assert!((student_t_999(1) - 636.619).abs() < f64::EPSILON);
+        assert!((student_t_999(30) - 3.646).abs() < f64::EPSILON);
+        assert!((student_t_999(45) - 3.551).abs() < f64::EPSILON);
+        assert!((student_t_999(10_000) - 3.291).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_benchmarks() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let this_class = constant_pool.add_class("Fib")?;
+        let descriptor_index = constant_pool.add_utf8("()I")?;
+        let annotation_name_index = constant_pool.add_utf8("RuntimeVisibleAnnotations")?;
+        let annotation_type_index =
+            constant_pool.add_utf8("Lorg/openjdk/jmh/annotations/Benchmark;")?;
+        let method = |name_index, access_flags, attributes| Method {
+            access_flags,
+            name_index,
+            descriptor_index,
+            attributes,
+        };
+        let fib = constant_pool.add_utf8("fib")?;
+        let bench_helper = constant_pool.add_utf8("benchHelper")?;
+        let mut class_file = ClassFile {
+            constant_pool,
+            this_class,
+            methods: vec![
+                method(
+                    fib,
+                    MethodAccessFlags::PUBLIC,
+                    vec![Attribute::RuntimeVisibleAnnotations {
+                        name_index: annotation_name_index,
+                        annotations: vec![Annotation {
+                            type_index: annotation_type_index,
+                            elements: vec![],
+                        }],
+                    }],
+                ),
+                method(
+                    bench_helper,
+                    MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                    vec![],
+                ),
+            ],
+            ..Default::default()
+        };
+        let expected = vec![Benchmark {
+            name: "fib".to_string(),
+            descriptor: "()I".to_string(),
+            is_static: false,
+        }];
+        assert_eq!(expected, benchmarks(&class_file)?);
+
+        class_file.methods.remove(0);
+        let expected = vec![Benchmark {
+            name: "benchHelper".to_string(),
+            descriptor: "()I".to_string(),
+            is_static: true,
+        }];
+        assert_eq!(expected, benchmarks(&class_file)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_text() {
+        let output = format_text(&[result(vec![1.0, 2.0, 3.0])], Mode::Throughput);
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(2, lines.len());
+        assert!(lines[0].starts_with("Benchmark     "));
+        assert!(lines[1].starts_with("Fib.benchFib  thrpt    3  2.000  ± "));
+        assert!(lines[1].ends_with("ops/s"));
+    }
+
+    #[test]
+    fn test_format_json() -> serde_json::Result<()> {
+        let output = format_json(&[result(vec![1.0, 2.0, 3.0])], &arguments());
+        let value: serde_json::Value = serde_json::from_str(&output)?;
+        assert_eq!("Fib.benchFib", value[0]["benchmark"]);
+        assert_eq!("thrpt", value[0]["mode"]);
+        assert_eq!(Some(2.0), value[0]["score"].as_f64());
+        assert_eq!(Some(3), value[0]["samples"].as_array().map(Vec::len));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_run_no_benchmarks() -> Result<()> {
+        let cargo_manifest = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_jar = cargo_manifest
+            .join("..")
+            .join("classes")
+            .join("classes.jar");
+        let configuration = ristretto_vm::ConfigurationBuilder::new()
+            .class_path(ristretto_vm::ClassPath::from(
+                classes_jar.to_string_lossy().as_ref(),
+            ))
+            .build()?;
+        let result = run(
+            configuration,
+            &Cli::parse_from(["bench", "HelloWorld"]).arguments,
+        )
+        .await;
+        assert!(matches!(result, Err(InternalError(_))));
+        Ok(())
+    }
+}
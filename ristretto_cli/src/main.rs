@@ -1,14 +1,27 @@
 #![forbid(unsafe_code)]
 
+mod bench;
 mod logging;
+mod plan;
 mod version;
 
-use clap::{ArgGroup, Parser};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use bench::BenchArguments;
+use clap::{ArgGroup, Parser, Subcommand};
+use plan::PlanFormat;
+#[cfg(all(unix, not(target_arch = "wasm32")))]
+use ristretto_vm::AttachListener;
 use ristretto_vm::Error::{InternalError, Throwable};
-use ristretto_vm::{ClassPath, ConfigurationBuilder, Error, Reference, Result, Value, VM};
+use ristretto_vm::{
+    ClassPath, Configuration, ConfigurationBuilder, Error, ExecutionPlan, JavaThrowable, Result,
+    Value, VM,
+};
 use std::env;
 use std::env::consts::{ARCH, OS};
+use std::io::Read;
 use std::path::PathBuf;
+use std::sync::Arc;
 use tracing::debug;
 
 #[derive(Debug, Parser)]
@@ -16,13 +29,17 @@ use tracing::debug;
     name = "java",
     about = "Ristretto CLI",
     help_expected = true,
-    trailing_var_arg = true
+    trailing_var_arg = true,
+    args_conflicts_with_subcommands = true
 )]
 #[command(group(
     ArgGroup::new("execution")
     .args(&["mainclass", "jar"])
 ))]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     #[arg(help = "The main class to execute")]
     mainclass: Option<String>,
 
@@ -33,13 +50,30 @@ struct Cli {
     )]
     jar: Option<String>,
 
+    #[arg(
+        long = "class-bytes",
+        value_name = "FILE",
+        help = "Execute the class file read from FILE, or from stdin when FILE is -",
+        conflicts_with_all = ["jar", "eval_class"]
+    )]
+    class_bytes: Option<String>,
+
+    #[arg(
+        long = "eval-class",
+        value_name = "BASE64",
+        help = "Execute a base64 encoded class file",
+        conflicts_with = "jar"
+    )]
+    eval_class: Option<String>,
+
     #[arg(
         long = "classpath",
+        global = true,
         help = "Class search path of directories and zip/jar files"
     )]
     classpath: Option<String>,
 
-    #[arg(short = 'D', help = "Define a system property")]
+    #[arg(short = 'D', global = true, help = "Define a system property")]
     properties: Option<Vec<String>>,
 
     #[arg(help = "Additional parameters to pass to the main class")]
@@ -47,15 +81,105 @@ struct Cli {
 
     #[arg(
         long = "enable-preview",
+        global = true,
         help = "Allow classes to depend on preview features of this release"
     )]
     enable_preview: bool,
 
+    #[arg(
+        long = "dump-config",
+        value_name = "FORMAT",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "text",
+        help = "Print the resolved Java runtime, class loaders and main class and exit without running"
+    )]
+    dump_config: Option<PlanFormat>,
+
+    #[arg(
+        long = "dry-run",
+        help = "Resolve and print the execution plan, failing if the main class cannot be run, and exit without running"
+    )]
+    dry_run: bool,
+
+    #[arg(
+        long = "show-full-stacktraces",
+        global = true,
+        help = "Show every stack trace frame instead of eliding frames in common with the enclosing trace"
+    )]
+    show_full_stacktraces: bool,
+
+    #[arg(
+        long = "print-method-statistics",
+        help = "Print the registered, called and unlinked native methods when the VM exits"
+    )]
+    print_method_statistics: bool,
+
+    #[arg(
+        long = "attach-listener",
+        help = "Listen for attach requests from tools such as jcmd and jstack while the main class runs"
+    )]
+    attach_listener: bool,
+
+    #[arg(
+        long = "panic-on-unsatisfied-link",
+        global = true,
+        help = "Panic when an unimplemented native method is called instead of throwing UnsatisfiedLinkError"
+    )]
+    panic_on_unsatisfied_link: bool,
+
+    #[arg(
+        long = "add-exports",
+        value_name = "MODULE/PACKAGE=TARGET(,TARGET)*",
+        global = true,
+        help = "Export a package of a module to other modules; ALL-UNNAMED targets the class path"
+    )]
+    add_exports: Option<Vec<String>>,
+
+    #[arg(
+        long = "add-opens",
+        value_name = "MODULE/PACKAGE=TARGET(,TARGET)*",
+        global = true,
+        help = "Open a package of a module for deep reflection by other modules; ALL-UNNAMED targets the class path"
+    )]
+    add_opens: Option<Vec<String>>,
+
+    #[arg(
+        long = "illegal-access",
+        value_name = "permit|warn|deny",
+        global = true,
+        help = "Allow reflective access from the class path to packages of modules that are not exported or opened to it; defaults to permit for Java 9 to 15 and deny otherwise"
+    )]
+    illegal_access: Option<String>,
+
+    #[arg(
+        long = "enable-native-access",
+        value_name = "MODULE(,MODULE)*",
+        global = true,
+        help = "Enable calls of restricted methods, such as System.loadLibrary, by modules; ALL-UNNAMED enables the class path"
+    )]
+    enable_native_access: Option<Vec<String>>,
+
+    #[arg(
+        long = "illegal-native-access",
+        value_name = "allow|warn|deny",
+        global = true,
+        help = "Allow calls of restricted methods by modules that native access is not enabled for"
+    )]
+    illegal_native_access: Option<String>,
+
     /// Display the version of this tool
     #[arg(long)]
     version: bool,
 }
 
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run the benchmark methods of a class with warmup and measurement iterations in one or more
+    /// forked VMs
+    Bench(BenchArguments),
+}
+
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[cfg(target_arch = "wasm32")]
@@ -98,7 +222,15 @@ async fn common_main(cli: Cli) -> Result<()> {
         configuration_builder = configuration_builder.java_version(java_version);
     }
 
-    if let Some(main_class) = cli.mainclass {
+    let mut parameters = cli.parameters.unwrap_or_default();
+    if let Some(class_bytes) = main_class_bytes(cli.class_bytes, cli.eval_class)? {
+        configuration_builder = configuration_builder.main_class_bytes(class_bytes);
+        // The main class is defined from the class bytes, so the first positional argument is a
+        // parameter to pass to the main class
+        if let Some(parameter) = cli.mainclass {
+            parameters.insert(0, parameter);
+        }
+    } else if let Some(main_class) = cli.mainclass {
         configuration_builder = configuration_builder.main_class(main_class);
     } else if let Some(jar) = cli.jar {
         configuration_builder = configuration_builder.jar(PathBuf::from(jar));
@@ -121,86 +253,138 @@ async fn common_main(cli: Cli) -> Result<()> {
         configuration_builder = configuration_builder.preview_features();
     }
 
+    if cli.panic_on_unsatisfied_link {
+        configuration_builder = configuration_builder.panic_on_unsatisfied_link();
+    }
+
+    for value in cli.add_exports.unwrap_or_default() {
+        configuration_builder = configuration_builder.add_exports(value);
+    }
+    for value in cli.add_opens.unwrap_or_default() {
+        configuration_builder = configuration_builder.add_opens(value);
+    }
+    if let Some(illegal_access) = cli.illegal_access {
+        configuration_builder = configuration_builder.illegal_access(illegal_access.parse()?);
+    }
+    for value in cli.enable_native_access.unwrap_or_default() {
+        configuration_builder = configuration_builder.enable_native_access(value);
+    }
+    if let Some(illegal_native_access) = cli.illegal_native_access {
+        configuration_builder =
+            configuration_builder.illegal_native_access(illegal_native_access.parse()?);
+    }
+
     let configuration = configuration_builder.build()?;
+    if let Some(Command::Bench(arguments)) = &cli.command {
+        return match bench::run(configuration, arguments).await {
+            Ok(()) => Ok(()),
+            Err(error) => process_error(error, cli.show_full_stacktraces),
+        };
+    }
+
+    if cli.dry_run || cli.dump_config.is_some() {
+        return print_plan(&configuration, cli.dump_config, cli.dry_run).await;
+    }
+
     let vm = match VM::new(configuration).await {
         Ok(vm) => vm,
         Err(error) => {
-            return process_error(error);
+            return process_error(error, cli.show_full_stacktraces);
         }
     };
-    let parameters = cli.parameters.unwrap_or_default();
 
-    match vm.invoke_main(parameters).await {
+    let result = invoke_main(&vm, parameters, cli.attach_listener).await;
+    if cli.print_method_statistics {
+        let statistics = vm.method_registry().statistics();
+        eprint!("{statistics}");
+    }
+
+    match result {
         Ok(_) => Ok(()),
-        Err(error) => process_error(error),
+        Err(error) => process_error(error, cli.show_full_stacktraces),
     }
 }
 
-fn process_error(error: Error) -> Result<()> {
-    let Throwable(ref throwable) = error else {
+/// Print the execution plan of a configuration; a dry run fails if the main class cannot be run.
+async fn print_plan(
+    configuration: &Configuration,
+    format: Option<PlanFormat>,
+    dry_run: bool,
+) -> Result<()> {
+    let plan = ExecutionPlan::resolve(configuration).await?;
+    let format = format.unwrap_or(PlanFormat::Text);
+    print!("{}", plan::format(&plan, format));
+    if dry_run && !plan.is_runnable() {
+        let error = InternalError("The main class cannot be run".to_string());
         eprintln!("{error}");
         return Err(error);
-    };
+    }
+    Ok(())
+}
 
-    let mut throwable = throwable.clone();
-    let mut first_throwable = true;
-
-    loop {
-        let class = throwable.class();
-        let class_name = class.name();
-        let message: String = throwable
-            .value("detailMessage")
-            .and_then(|value| value.try_into())?;
-        let prelude = if first_throwable {
-            first_throwable = false;
-            "Exception"
-        } else {
-            "Caused by"
-        };
-        eprintln!("{prelude} {class_name}: {message}");
-        let Value::Object(Some(Reference::Array(_class, stack_trace))) =
-            throwable.value("backtrace")?
-        else {
-            return Err(error);
-        };
-        let stack_trace = stack_trace.to_vec()?;
-        for stack_trace_element in stack_trace {
-            let Some(Reference::Object(stack_trace_element)) = stack_trace_element else {
-                continue;
-            };
-            let class: String = stack_trace_element.value("declaringClass")?.try_into()?;
-            let method: String = stack_trace_element.value("methodName")?.try_into()?;
-
-            let mut source = String::new();
-            let file = stack_trace_element.value("fileName")?;
-            if let Value::Object(Some(ref _file_object)) = file {
-                source = file.try_into()?;
-            };
-            let line = stack_trace_element.value("lineNumber")?.to_int()?;
-            if line > 0 {
-                if source.is_empty() {
-                    source = format!("{line}");
-                } else {
-                    source = format!("{source}:{line}");
-                }
-            }
-            if source.is_empty() {
-                eprintln!("    at {class}.{method}");
-            } else {
-                eprintln!("    at {class}.{method}({source})");
-            }
+/// Invoke the main method of the VM; when requested, attach requests are handled while the main
+/// method runs.
+async fn invoke_main(
+    vm: &Arc<VM>,
+    parameters: Vec<String>,
+    attach_listener: bool,
+) -> Result<Option<Value>> {
+    #[cfg(all(unix, not(target_arch = "wasm32")))]
+    if attach_listener {
+        let listener = AttachListener::bind()?;
+        let main = vm.invoke_main(parameters);
+        tokio::pin!(main);
+        tokio::select! {
+            result = &mut main => return result,
+            Err(error) = listener.run(vm) => debug!("attach listener stopped: {error}"),
         }
+        return main.await;
+    }
+    #[cfg(not(all(unix, not(target_arch = "wasm32"))))]
+    if attach_listener {
+        debug!("attach listener is not supported on this platform");
+    }
+    vm.invoke_main(parameters).await
+}
 
-        let cause = throwable.value("cause")?;
-        let Value::Object(Some(Reference::Object(cause))) = cause else {
-            break;
-        };
-        if throwable == cause {
-            break;
+/// Get the bytes of the main class from a file (`-` for stdin) or from a base64 encoded string.
+fn main_class_bytes(
+    class_bytes: Option<String>,
+    eval_class: Option<String>,
+) -> Result<Option<Vec<u8>>> {
+    if let Some(file) = class_bytes {
+        let bytes = if file == "-" {
+            let mut bytes = Vec::new();
+            std::io::stdin()
+                .read_to_end(&mut bytes)
+                .map_err(|error| InternalError(format!("Unable to read stdin: {error}")))?;
+            bytes
         } else {
-            throwable = cause;
-        }
+            std::fs::read(&file)
+                .map_err(|error| InternalError(format!("Unable to read {file}: {error}")))?
+        };
+        return Ok(Some(bytes));
+    }
+
+    if let Some(encoded) = eval_class {
+        let bytes = STANDARD
+            .decode(encoded.trim())
+            .map_err(|error| InternalError(format!("Invalid base64 class: {error}")))?;
+        return Ok(Some(bytes));
     }
+
+    Ok(None)
+}
+
+fn process_error(error: Error, show_full_stack_traces: bool) -> Result<()> {
+    let Throwable(ref throwable) = error else {
+        eprintln!("{error}");
+        return Err(error);
+    };
+
+    let throwable = JavaThrowable::try_from(throwable)?;
+    let stack_trace = throwable.stack_trace_string(!show_full_stack_traces);
+    eprint!("Exception in thread \"main\" {stack_trace}");
     Err(error)
 }
 
@@ -216,4 +400,93 @@ mod tests {
         assert!(result.is_err());
         Ok(())
     }
+
+    #[test]
+    fn test_main_class_bytes_none() -> Result<()> {
+        assert!(main_class_bytes(None, None)?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_main_class_bytes_eval_class() -> Result<()> {
+        let bytes = main_class_bytes(None, Some("yv66vg==".to_string()))?;
+        assert_eq!(Some(vec![0xCA, 0xFE, 0xBA, 0xBE]), bytes);
+        Ok(())
+    }
+
+    #[test]
+    fn test_main_class_bytes_invalid_eval_class() {
+        let result = main_class_bytes(None, Some("not base64!".to_string()));
+        assert!(matches!(result, Err(InternalError(_))));
+    }
+
+    #[test]
+    fn test_main_class_bytes_file() -> Result<()> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let class_file = cargo_manifest
+            .join("..")
+            .join("classes")
+            .join("HelloWorld.class");
+        let bytes = main_class_bytes(Some(class_file.to_string_lossy().to_string()), None)?
+            .expect("class bytes");
+        assert!(bytes.starts_with(&[0xCA, 0xFE, 0xBA, 0xBE]));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_print_plan_dump_config() -> Result<()> {
+        let cli = Cli::parse_from(["java", "--dump-config=json"]);
+        assert_eq!(Some(PlanFormat::Json), cli.dump_config);
+
+        // Resolve a local Java home so that no runtime is downloaded
+        let java_home = tempfile::tempdir().map_err(|error| InternalError(error.to_string()))?;
+        std::fs::write(java_home.path().join("version.txt"), "8.0.452.09.1")
+            .map_err(|error| InternalError(error.to_string()))?;
+        let configuration = ConfigurationBuilder::new()
+            .java_home(java_home.path().to_path_buf())
+            .build()?;
+        print_plan(&configuration, cli.dump_config, cli.dry_run).await
+    }
+
+    #[tokio::test]
+    async fn test_common_main_dry_run_error() -> Result<()> {
+        let cli = Cli::parse_from(["java", "--dry-run", "Missing"]);
+        let result = common_main(cli).await;
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_dump_config_default_format() {
+        let cli = Cli::parse_from(["java", "--dump-config", "HelloWorld"]);
+        assert_eq!(Some(PlanFormat::Text), cli.dump_config);
+        assert_eq!(Some("HelloWorld".to_string()), cli.mainclass);
+    }
+
+    #[test]
+    fn test_bench_command() {
+        let cli = Cli::parse_from(["java", "bench", "Fib", "--forks", "2", "--classpath", "."]);
+        let Some(Command::Bench(arguments)) = cli.command else {
+            panic!("Expected bench command");
+        };
+        assert_eq!("Fib", arguments.class);
+        assert_eq!(2, arguments.forks);
+        assert_eq!(Some(".".to_string()), cli.classpath);
+    }
+
+    #[test]
+    fn test_bench_parameter() {
+        let cli = Cli::parse_from(["java", "HelloWorld", "bench"]);
+        assert!(cli.command.is_none());
+        assert_eq!(Some("HelloWorld".to_string()), cli.mainclass);
+        assert_eq!(Some(vec!["bench".to_string()]), cli.parameters);
+    }
+
+    #[test]
+    fn test_class_bytes_parameters() {
+        let cli = Cli::parse_from(["java", "--class-bytes", "-", "foo", "bar"]);
+        assert_eq!(Some("-".to_string()), cli.class_bytes);
+        assert_eq!(Some("foo".to_string()), cli.mainclass);
+        assert_eq!(Some(vec!["bar".to_string()]), cli.parameters);
+    }
 }
@@ -0,0 +1,94 @@
+use clap::ValueEnum;
+use ristretto_vm::ExecutionPlan;
+use serde_json::{json, Value};
+
+/// Output format of the execution plan.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub(crate) enum PlanFormat {
+    Text,
+    Json,
+}
+
+/// Format the execution plan.
+pub(crate) fn format(plan: &ExecutionPlan, format: PlanFormat) -> String {
+    match format {
+        PlanFormat::Text => plan.to_string(),
+        PlanFormat::Json => format!("{:#}\n", to_json(plan)),
+    }
+}
+
+/// Convert the execution plan to JSON.
+fn to_json(plan: &ExecutionPlan) -> Value {
+    let class_loaders = plan
+        .class_loaders
+        .iter()
+        .map(|class_loader| {
+            json!({
+                "name": class_loader.name,
+                "class_path": class_loader.class_path,
+            })
+        })
+        .collect::<Vec<_>>();
+    let main_class = plan.main_class.as_ref().map(|main_class| {
+        json!({
+            "name": main_class.name,
+            "source": main_class.source,
+            "main_method": main_class.main_method,
+        })
+    });
+    json!({
+        "java_home": plan.java_home.to_string_lossy(),
+        "java_version": plan.java_version,
+        "class_file_version": plan.class_file_version,
+        "preview_features": plan.preview_features,
+        "class_loaders": class_loaders,
+        "main_class": main_class,
+        "system_properties": plan.system_properties,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ristretto_vm::{ClassLoaderPlan, MainClassPlan};
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+
+    fn plan() -> ExecutionPlan {
+        ExecutionPlan {
+            java_home: PathBuf::from("/java"),
+            java_version: "21.0.5".to_string(),
+            class_file_version: "Java 21".to_string(),
+            preview_features: false,
+            class_loaders: vec![ClassLoaderPlan {
+                name: "system".to_string(),
+                class_path: vec![".".to_string()],
+            }],
+            main_class: Some(MainClassPlan {
+                name: "HelloWorld".to_string(),
+                source: None,
+                main_method: false,
+            }),
+            system_properties: BTreeMap::from([("foo".to_string(), "bar".to_string())]),
+        }
+    }
+
+    #[test]
+    fn test_format_text() {
+        let plan = plan();
+        assert_eq!(plan.to_string(), format(&plan, PlanFormat::Text));
+    }
+
+    #[test]
+    fn test_format_json() -> serde_json::Result<()> {
+        let value: Value = serde_json::from_str(&format(&plan(), PlanFormat::Json))?;
+        assert_eq!("21.0.5", value["java_version"]);
+        assert_eq!("system", value["class_loaders"][0]["name"]);
+        assert_eq!(".", value["class_loaders"][0]["class_path"][0]);
+        assert_eq!("HelloWorld", value["main_class"]["name"]);
+        assert!(value["main_class"]["source"].is_null());
+        assert_eq!(Some(false), value["main_class"]["main_method"].as_bool());
+        assert_eq!("bar", value["system_properties"]["foo"]);
+        Ok(())
+    }
+}
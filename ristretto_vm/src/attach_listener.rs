@@ -0,0 +1,766 @@
+//! Attach listener that allows tools to attach to a running VM; the counterpart of
+//! `sun.tools.attach.VirtualMachineImpl` on Linux and macOS.
+//!
+//! The listener accepts connections on the unix domain socket `.java_pid<pid>` in the temporary
+//! directory, where the attach API looks for the socket of the process it attaches to.  A request
+//! is a sequence of null terminated strings: the protocol version (`1`), the command and three
+//! arguments.  The response is the status of the command on the first line (`0` on success),
+//! followed by the output of the command.  The following commands are supported:
+//!
+//! * `jcmd`: a diagnostic command of the `jcmd` tool; see [`DIAGNOSTIC_COMMANDS`].
+//! * `load`: load a Java agent (`load instrument false <jar>[=<options>]`) by defining the classes
+//!   of the agent jar and invoking the `agentmain` method of its `Agent-Class`.  Native agent
+//!   libraries are not supported, and the agent is not passed an `Instrumentation` instance.
+//! * `properties`: the system properties in the `java.util.Properties` text format.
+//! * `threaddump`: the stack traces of the Java threads, taken at a safepoint.
+//!
+//! Only processes that run as the same user as the VM may attach.  The socket is created in a
+//! directory that only the current user can access, and is only moved to its public path once its
+//! permissions are restricted to the current user.
+
+use crate::java_object::JavaObject;
+use crate::native_methods::properties;
+use crate::Error::InternalError;
+use crate::{Result, StackTraceElement, Thread, VM};
+use rand::Rng;
+use ristretto_classloader::{Class, ClassPathEntry, Value};
+use std::collections::BTreeMap;
+use std::fs::{DirBuilder, Permissions};
+use std::os::unix::fs::{DirBuilderExt, MetadataExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{UnixListener, UnixStream};
+use tracing::debug;
+
+/// Version of the attach protocol supported by the listener
+const PROTOCOL_VERSION: &str = "1";
+/// Number of strings in a request; the protocol version, the command and three arguments
+const REQUEST_STRINGS: usize = 5;
+/// Maximum length of a request in bytes
+const MAX_REQUEST_LENGTH: usize = 4096;
+/// Time a connection has to send its request; connections are handled one at a time, so a
+/// connection that does not send a request would otherwise block the listener
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+/// Time to wait for the Java threads to stop at a safepoint for a thread dump
+const SAFEPOINT_TIMEOUT: Duration = Duration::from_secs(10);
+/// Status of a command that completed
+const STATUS_OK: i32 = 0;
+/// Status of a command that failed; `JNI_ERR`
+const STATUS_ERROR: i32 = -1;
+/// Status of a request with an unsupported protocol version; `ATTACH_ERROR_BADVERSION`
+const STATUS_BAD_VERSION: i32 = 101;
+/// Manifest attribute that names the class of a Java agent that is loaded into a running VM
+const AGENT_CLASS: &str = "Agent-Class";
+/// Diagnostic commands supported by the `jcmd` command and their descriptions
+const DIAGNOSTIC_COMMANDS: [(&str, &str); 4] = [
+    ("help", "List the available diagnostic commands"),
+    ("Thread.print", "Print the stack traces of the Java threads"),
+    (
+        "VM.native_methods",
+        "Print the registered, called and unlinked native methods",
+    ),
+    ("VM.system_properties", "Print the system properties"),
+];
+
+/// Listener for the attach requests of tools such as `jcmd`, `jstack` and the
+/// `com.sun.tools.attach` API.  The socket is removed when the listener is dropped.
+#[derive(Debug)]
+pub struct AttachListener {
+    path: PathBuf,
+    listener: UnixListener,
+}
+
+impl AttachListener {
+    /// Get the path of the socket that tools connect to when attaching to the current process.
+    #[must_use]
+    pub fn default_path() -> PathBuf {
+        std::env::temp_dir().join(format!(".java_pid{}", std::process::id()))
+    }
+
+    /// Bind the listener to the socket of the current process; see
+    /// [`AttachListener::default_path`].
+    ///
+    /// # Errors
+    /// if the socket cannot be created
+    pub fn bind() -> Result<Self> {
+        Self::bind_path(Self::default_path())
+    }
+
+    /// Bind the listener to the socket at the path; a socket left at the path (e.g. by an exited
+    /// process with the same process id) is replaced.  The socket is only accessible by the
+    /// current user; it is bound in a private directory next to the path, where other users
+    /// cannot connect to it before its permissions are restricted, and then moved to the path.
+    ///
+    /// # Errors
+    /// if the socket cannot be created
+    pub fn bind_path<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file_name = path
+            .file_name()
+            .ok_or_else(|| InternalError(format!("invalid socket path: {}", path.display())))?;
+        let private_dir = path.with_file_name(format!(
+            "{}.{:016x}",
+            file_name.to_string_lossy(),
+            rand::thread_rng().gen::<u64>()
+        ));
+        DirBuilder::new()
+            .mode(0o700)
+            .create(&private_dir)
+            .map_err(|error| InternalError(error.to_string()))?;
+        let result = Self::bind_private(&private_dir.join(file_name), &path);
+        let _ = std::fs::remove_dir_all(&private_dir);
+        let listener = result?;
+        Ok(AttachListener { path, listener })
+    }
+
+    /// Bind a socket in a private directory, restrict its permissions to the current user and
+    /// move it to the path.
+    fn bind_private(private_path: &Path, path: &Path) -> Result<UnixListener> {
+        let listener =
+            UnixListener::bind(private_path).map_err(|error| InternalError(error.to_string()))?;
+        std::fs::set_permissions(private_path, Permissions::from_mode(0o600))
+            .map_err(|error| InternalError(error.to_string()))?;
+        std::fs::rename(private_path, path).map_err(|error| InternalError(error.to_string()))?;
+        Ok(listener)
+    }
+
+    /// Get the path of the socket
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Accept and handle attach requests until a connection cannot be accepted.
+    ///
+    /// # Errors
+    /// if a connection cannot be accepted
+    pub async fn run(&self, vm: &Arc<VM>) -> Result<()> {
+        loop {
+            self.accept(vm).await?;
+        }
+    }
+
+    /// Accept a connection and handle its request.  Failures handling the request are logged and
+    /// reported to the tool, but are not returned; a connection that does not send its request
+    /// within [`REQUEST_TIMEOUT`] is closed.
+    ///
+    /// # Errors
+    /// if a connection cannot be accepted
+    pub async fn accept(&self, vm: &Arc<VM>) -> Result<()> {
+        let (stream, _address) = self
+            .listener
+            .accept()
+            .await
+            .map_err(|error| InternalError(error.to_string()))?;
+        if let Err(error) = self.handle(vm, stream).await {
+            debug!("attach request failed: {error}");
+        }
+        Ok(())
+    }
+
+    /// Handle the request of a connection from a process of the same user.
+    async fn handle(&self, vm: &Arc<VM>, mut stream: UnixStream) -> Result<()> {
+        let owner = std::fs::metadata(&self.path)
+            .map_err(|error| InternalError(error.to_string()))?
+            .uid();
+        let peer = stream
+            .peer_cred()
+            .map_err(|error| InternalError(error.to_string()))?
+            .uid();
+        if peer != owner {
+            return Err(InternalError(format!(
+                "attach by user {peer} rejected; the VM is owned by user {owner}"
+            )));
+        }
+
+        let request = tokio::time::timeout(REQUEST_TIMEOUT, read_request(&mut stream))
+            .await
+            .map_err(|_| {
+                InternalError(format!(
+                    "attach request not received within {}s",
+                    REQUEST_TIMEOUT.as_secs()
+                ))
+            })??;
+        let (status, output) = execute(vm, &request).await;
+        stream
+            .write_all(format!("{status}\n{output}").as_bytes())
+            .await
+            .map_err(|error| InternalError(error.to_string()))?;
+        stream
+            .shutdown()
+            .await
+            .map_err(|error| InternalError(error.to_string()))?;
+        Ok(())
+    }
+}
+
+impl Drop for AttachListener {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Read the strings of a request; a connection that is closed before the request is complete
+/// sends the strings that were written.
+async fn read_request(stream: &mut UnixStream) -> Result<Vec<String>> {
+    let mut bytes = Vec::new();
+    let mut buffer = [0u8; 256];
+    while bytes.iter().filter(|byte| **byte == 0).count() < REQUEST_STRINGS {
+        let length = stream
+            .read(&mut buffer)
+            .await
+            .map_err(|error| InternalError(error.to_string()))?;
+        if length == 0 {
+            break;
+        }
+        bytes.extend_from_slice(&buffer[..length]);
+        if bytes.len() > MAX_REQUEST_LENGTH {
+            return Err(InternalError(format!(
+                "attach request exceeds {MAX_REQUEST_LENGTH} bytes"
+            )));
+        }
+    }
+    Ok(parse_request(&bytes))
+}
+
+/// Split a request into its null terminated strings.
+fn parse_request(bytes: &[u8]) -> Vec<String> {
+    bytes
+        .split(|byte| *byte == 0)
+        .take(REQUEST_STRINGS)
+        .map(|string| String::from_utf8_lossy(string).to_string())
+        .collect()
+}
+
+/// Execute a request and return the status and output of the command.
+pub(crate) async fn execute(vm: &Arc<VM>, request: &[String]) -> (i32, String) {
+    let version = request.first().map_or("", String::as_str);
+    if version != PROTOCOL_VERSION {
+        return (STATUS_BAD_VERSION, String::new());
+    }
+    let command = request.get(1).map_or("", String::as_str);
+    let arguments = request.get(2..).unwrap_or_default();
+    let result = match command {
+        "jcmd" => diagnostic_command(vm, arguments).await,
+        "load" => load_agent(vm, arguments).await,
+        "properties" => system_properties(vm),
+        "threaddump" => thread_dump(vm).await,
+        _ => return (STATUS_ERROR, format!("Operation {command} not recognized!")),
+    };
+    match result {
+        Ok(output) => (STATUS_OK, output),
+        Err(error) => (STATUS_ERROR, error.to_string()),
+    }
+}
+
+/// Execute a diagnostic command; the first argument is the command line of the `jcmd` tool, e.g.
+/// `Thread.print -l`.  Options of the commands are ignored.
+async fn diagnostic_command(vm: &Arc<VM>, arguments: &[String]) -> Result<String> {
+    let command_line = arguments.first().map_or("", String::as_str);
+    let command = command_line.split_whitespace().next().unwrap_or_default();
+    match command {
+        "help" => {
+            let mut output = String::from("The following commands are available:\n");
+            for (command, description) in DIAGNOSTIC_COMMANDS {
+                output.push_str(&format!("{command:<24}{description}\n"));
+            }
+            Ok(output)
+        }
+        "Thread.print" => thread_dump(vm).await,
+        "VM.native_methods" => Ok(vm.method_registry().statistics().to_string()),
+        "VM.system_properties" => system_properties(vm),
+        _ => Err(InternalError(format!(
+            "Unknown diagnostic command: {command}"
+        ))),
+    }
+}
+
+/// Load a Java agent; the options are the path of the agent jar, optionally followed by `=` and
+/// the options passed to the agent.
+async fn load_agent(vm: &Arc<VM>, arguments: &[String]) -> Result<String> {
+    let library = arguments.first().map_or("", String::as_str);
+    if library != "instrument" {
+        return Err(InternalError(format!(
+            "{library} was not loaded; only Java agents are supported"
+        )));
+    }
+    let options = arguments.get(2).map_or("", String::as_str);
+    let (jar_path, agent_options) = options.split_once('=').unwrap_or((options, ""));
+    let ClassPathEntry::Jar(jar) = ClassPathEntry::new(jar_path) else {
+        return Err(InternalError(format!("agent jar {jar_path} not found")));
+    };
+    let manifest = jar.manifest().await?;
+    let Some(agent_class_name) = manifest.attribute(AGENT_CLASS) else {
+        return Err(InternalError(format!(
+            "agent jar {jar_path} does not declare an {AGENT_CLASS} attribute"
+        )));
+    };
+
+    // Define the classes of the agent; classes that are already loaded are not replaced
+    let thread = vm.attach_listener_thread().await?;
+    for class_name in jar.class_names().await? {
+        if class_name.ends_with("module-info") {
+            continue;
+        }
+        let class_loader_lock = vm.class_loader();
+        let class_loader = class_loader_lock.read().await;
+        if class_loader.find_loaded(&class_name).await.is_some() {
+            continue;
+        }
+        let class_file = jar.read_class(&class_name).await?;
+        class_loader
+            .register(Arc::new(Class::from(class_file)?))
+            .await?;
+    }
+
+    let agent_class = thread.class(agent_class_name).await?;
+    let agent_options = agent_options.to_object(vm).await?;
+    if let Some(method) = agent_class.method("agentmain", "(Ljava/lang/String;)V") {
+        thread
+            .execute(&agent_class, &method, vec![agent_options])
+            .await?;
+    } else {
+        let method = agent_class.try_get_method(
+            "agentmain",
+            "(Ljava/lang/String;Ljava/lang/instrument/Instrumentation;)V",
+        )?;
+        thread
+            .execute(
+                &agent_class,
+                &method,
+                vec![agent_options, Value::Object(None)],
+            )
+            .await?;
+    }
+    Ok("return code: 0\n".to_string())
+}
+
+/// Get the system properties in the `java.util.Properties` text format; properties set in the
+/// configuration override the default properties.
+fn system_properties(vm: &VM) -> Result<String> {
+    let mut system_properties: BTreeMap<String, String> = properties::system_properties(vm)?
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect();
+    if let Some(main_class) = vm.main_class() {
+        system_properties.insert("sun.java.command".to_string(), main_class.clone());
+    }
+    system_properties.extend(
+        vm.system_properties()
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone())),
+    );
+
+    let mut output = String::new();
+    for (key, value) in &system_properties {
+        output.push_str(&escape_property(key, true));
+        output.push('=');
+        output.push_str(&escape_property(value, false));
+        output.push('\n');
+    }
+    Ok(output)
+}
+
+/// Escape a property key or value the same way as `java.util.Properties.store()`.
+fn escape_property(value: &str, is_key: bool) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for (index, character) in value.chars().enumerate() {
+        match character {
+            ' ' if is_key || index == 0 => escaped.push_str("\\ "),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\x0c' => escaped.push_str("\\f"),
+            '\\' | '=' | ':' | '#' | '!' => {
+                escaped.push('\\');
+                escaped.push(character);
+            }
+            ' '..='~' => escaped.push(character),
+            _ => {
+                let mut units = [0u16; 2];
+                for unit in character.encode_utf16(&mut units) {
+                    escaped.push_str(&format!("\\u{unit:04X}"));
+                }
+            }
+        }
+    }
+    escaped
+}
+
+/// Get the stack traces of the Java threads; the threads are stopped at a safepoint while the
+/// stack traces are taken.  The dump fails if the threads do not reach the safepoint within
+/// [`SAFEPOINT_TIMEOUT`], e.g. because a thread is blocked outside a safe region.
+async fn thread_dump(vm: &VM) -> Result<String> {
+    let _safepoint = tokio::time::timeout(SAFEPOINT_TIMEOUT, vm.safepoint(None))
+        .await
+        .map_err(|_| {
+            InternalError(format!(
+                "threads did not reach a safepoint within {}s",
+                SAFEPOINT_TIMEOUT.as_secs()
+            ))
+        })?;
+    let mut threads = vm.threads();
+    threads.sort_by_key(|thread| thread.id());
+
+    let vm_version = env!("CARGO_PKG_VERSION");
+    let java_version = vm.java_version();
+    let mut output = format!("Full thread dump ristretto {vm_version} (Java {java_version}):\n");
+    for thread in threads {
+        let name = thread.name().await;
+        let id = thread.id();
+        output.push_str(&format!("\n\"{name}\" #{id}\n"));
+        for element in stack_trace(&thread).await? {
+            output.push_str(&format!("\tat {element}\n"));
+        }
+    }
+    Ok(output)
+}
+
+/// Get the stack trace of a thread; the innermost frame is first.
+async fn stack_trace(thread: &Thread) -> Result<Vec<StackTraceElement>> {
+    let mut stack_trace = Vec::new();
+    for frame in thread.frames().await?.iter().rev() {
+        let class = frame.class();
+        let method = frame.method();
+        let line_number = method.line_number(frame.program_counter());
+        stack_trace.push(StackTraceElement::new(
+            class.name(),
+            method.name(),
+            class.source_file().map(ToString::to_string),
+            i32::try_from(line_number)?,
+        ));
+    }
+    Ok(stack_trace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ristretto_classfile::attributes::Instruction;
+    use ristretto_classfile::{
+        ClassFile, ClassFileBuilder, FieldAccessFlags, FieldType, MethodAccessFlags, Version,
+    };
+    use ristretto_classloader::Object;
+    use std::io::Write;
+    use std::os::unix::fs::FileTypeExt;
+    use zip::write::SimpleFileOptions;
+
+    fn request(command: &str, arguments: &[&str]) -> Vec<String> {
+        let mut request = vec![PROTOCOL_VERSION.to_string(), command.to_string()];
+        request.extend(arguments.iter().map(ToString::to_string));
+        request
+    }
+
+    #[test]
+    fn test_parse_request() {
+        let bytes = b"1\0properties\0\0\0\0";
+        assert_eq!(request("properties", &["", "", ""]), parse_request(bytes));
+        let bytes = b"1\0load\0instrument\0false\0agent.jar=options\0";
+        assert_eq!(
+            request("load", &["instrument", "false", "agent.jar=options"]),
+            parse_request(bytes)
+        );
+    }
+
+    #[test]
+    fn test_escape_property() {
+        assert_eq!("java.home", escape_property("java.home", true));
+        assert_eq!("a\\ key", escape_property("a key", true));
+        assert_eq!("\\ a value", escape_property(" a value", false));
+        assert_eq!("C\\:\\\\temp", escape_property("C:\\temp", false));
+        assert_eq!("\\n\\t\\=\\#\\!", escape_property("\n\t=#!", false));
+        assert_eq!(
+            "caf\\u00E9 \\uD83D\\uDE00",
+            escape_property("café 😀", false)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_bad_version() -> Result<()> {
+        let (vm, _thread) = crate::test::thread().await?;
+        let request = vec!["2".to_string(), "properties".to_string()];
+        assert_eq!(
+            (STATUS_BAD_VERSION, String::new()),
+            execute(&vm, &request).await
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_execute_unknown_command() -> Result<()> {
+        let (vm, _thread) = crate::test::thread().await?;
+        assert_eq!(
+            (STATUS_ERROR, "Operation foo not recognized!".to_string()),
+            execute(&vm, &request("foo", &["", "", ""])).await
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_execute_properties() -> Result<()> {
+        let (vm, _thread) = crate::test::thread().await?;
+        let (status, output) = execute(&vm, &request("properties", &["", "", ""])).await;
+        assert_eq!(STATUS_OK, status);
+        let java_version = format!("java.version={}\n", vm.java_version());
+        assert!(output.contains(&java_version));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_execute_thread_dump() -> Result<()> {
+        let (vm, thread) = crate::test::thread().await?;
+        let (status, output) = execute(&vm, &request("threaddump", &["", "", ""])).await;
+        assert_eq!(STATUS_OK, status);
+        assert!(output.starts_with("Full thread dump ristretto"));
+        let name = thread.name().await;
+        assert!(output.contains(&format!("\"{name}\" #{}\n", thread.id())));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_execute_diagnostic_commands() -> Result<()> {
+        let (vm, _thread) = crate::test::thread().await?;
+        let (status, output) = execute(&vm, &request("jcmd", &["help", "", ""])).await;
+        assert_eq!(STATUS_OK, status);
+        for (command, _description) in DIAGNOSTIC_COMMANDS {
+            assert!(output.contains(command));
+        }
+
+        let (status, output) = execute(&vm, &request("jcmd", &["VM.native_methods", "", ""])).await;
+        assert_eq!(STATUS_OK, status);
+        assert_eq!(vm.method_registry().statistics().to_string(), output);
+
+        let (status, output) = execute(&vm, &request("jcmd", &["Thread.print -l", "", ""])).await;
+        assert_eq!(STATUS_OK, status);
+        assert!(output.starts_with("Full thread dump ristretto"));
+
+        let (status, output) = execute(&vm, &request("jcmd", &["VM.foo", "", ""])).await;
+        assert_eq!(STATUS_ERROR, status);
+        assert!(output.contains("Unknown diagnostic command: VM.foo"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_execute_load_native_agent() -> Result<()> {
+        let (vm, _thread) = crate::test::thread().await?;
+        let (status, output) = execute(&vm, &request("load", &["libagent", "false", ""])).await;
+        assert_eq!(STATUS_ERROR, status);
+        assert!(output.contains("libagent was not loaded"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_execute_load_missing_jar() -> Result<()> {
+        let (vm, _thread) = crate::test::thread().await?;
+        let (status, output) = execute(
+            &vm,
+            &request("load", &["instrument", "false", "missing.jar=options"]),
+        )
+        .await;
+        assert_eq!(STATUS_ERROR, status);
+        assert!(output.contains("agent jar missing.jar not found"));
+        Ok(())
+    }
+
+    /// Class with a `main` method that sleeps until `agentThread` is set by the agent
+    fn main_class() -> Result<ClassFile> {
+        let mut builder = ClassFileBuilder::new("Main")
+            .java_version(Version::Java8 { minor: 0 })
+            .public();
+        let constant_pool = builder.constant_pool();
+        let class_index = constant_pool.add_class("Main")?;
+        let agent_thread =
+            constant_pool.add_field_ref(class_index, "agentThread", "Ljava/lang/Thread;")?;
+        let thread_class = constant_pool.add_class("java/lang/Thread")?;
+        let sleep = constant_pool.add_method_ref(thread_class, "sleep", "(J)V")?;
+        let class_file = builder
+            .field(
+                FieldAccessFlags::PUBLIC | FieldAccessFlags::STATIC,
+                "agentThread",
+                FieldType::Object("java/lang/Thread".to_string()),
+            )
+            .method(
+                MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                "main",
+                "()V",
+                vec![
+                    Instruction::Getstatic(agent_thread),
+                    Instruction::Ifnonnull(5),
+                    Instruction::Lconst_1,
+                    Instruction::Invokestatic(sleep),
+                    Instruction::Goto(0),
+                    Instruction::Return,
+                ],
+            )
+            .build()?;
+        Ok(class_file)
+    }
+
+    /// Agent that stores the thread that executes `agentmain` in `Main.agentThread`
+    fn agent_jar(path: &Path) -> Result<()> {
+        let mut builder = ClassFileBuilder::new("Agent")
+            .java_version(Version::Java8 { minor: 0 })
+            .public();
+        let constant_pool = builder.constant_pool();
+        let main_class = constant_pool.add_class("Main")?;
+        let agent_thread =
+            constant_pool.add_field_ref(main_class, "agentThread", "Ljava/lang/Thread;")?;
+        let thread_class = constant_pool.add_class("java/lang/Thread")?;
+        let current_thread =
+            constant_pool.add_method_ref(thread_class, "currentThread", "()Ljava/lang/Thread;")?;
+        let class_file = builder
+            .method(
+                MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                "agentmain",
+                "(Ljava/lang/String;)V",
+                vec![
+                    Instruction::Invokestatic(current_thread),
+                    Instruction::Putstatic(agent_thread),
+                    Instruction::Return,
+                ],
+            )
+            .build()?;
+        let mut class_bytes = Vec::new();
+        class_file.to_bytes(&mut class_bytes)?;
+
+        let file = std::fs::File::create(path).map_err(|error| InternalError(error.to_string()))?;
+        let mut archive = zip::ZipWriter::new(file);
+        let options = SimpleFileOptions::default();
+        archive
+            .start_file("META-INF/MANIFEST.MF", options)
+            .map_err(|error| InternalError(error.to_string()))?;
+        archive
+            .write_all(b"Manifest-Version: 1.0\r\nAgent-Class: Agent\r\n\r\n")
+            .map_err(|error| InternalError(error.to_string()))?;
+        archive
+            .start_file("Agent.class", options)
+            .map_err(|error| InternalError(error.to_string()))?;
+        archive
+            .write_all(&class_bytes)
+            .map_err(|error| InternalError(error.to_string()))?;
+        archive
+            .finish()
+            .map_err(|error| InternalError(error.to_string()))?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_execute_load_agent_while_main_is_running() -> Result<()> {
+        let (vm, _thread) = crate::test::thread().await?;
+        let temp_dir = tempfile::tempdir().map_err(|error| InternalError(error.to_string()))?;
+        let jar_path = temp_dir.path().join("agent.jar");
+        agent_jar(&jar_path)?;
+        let main_class = Arc::new(Class::from(main_class()?)?);
+        vm.class_loader().read().await.register(main_class).await?;
+
+        let main_thread = vm.primordial_thread()?;
+        let main_class = main_thread.class("Main").await?;
+        let main_method = main_class.try_get_method("main", "()V")?;
+        let main = main_thread.execute(&main_class, &main_method, Vec::<Value>::new());
+        let load = async {
+            // Wait until main is sleeping in its loop
+            while main_thread.frames().await?.is_empty() {
+                tokio::task::yield_now().await;
+            }
+            let options = format!("{}=options", jar_path.display());
+            let response = execute(&vm, &request("load", &["instrument", "false", &options])).await;
+            let main_frames = main_thread.frames().await?;
+            Ok::<_, crate::Error>((response, main_frames))
+        };
+        let (main_result, load_result) = tokio::join!(main, load);
+        main_result?;
+        let ((status, output), main_frames) = load_result?;
+        assert_eq!(
+            (STATUS_OK, "return code: 0\n".to_string()),
+            (status, output)
+        );
+
+        // The agent did not run on the main thread, whose frames are left as they were
+        assert_eq!(1, main_frames.len());
+        assert_eq!("main", main_frames[0].method().name());
+        assert!(main_thread.frames().await?.is_empty());
+
+        let attach_thread = vm.attach_listener_thread().await?;
+        assert_ne!(main_thread.id(), attach_thread.id());
+        assert_eq!("Attach Listener", attach_thread.name().await);
+        assert!(attach_thread.frames().await?.is_empty());
+        let agent_thread: Object = main_class
+            .static_field("agentThread")?
+            .value()?
+            .try_into()?;
+        let attach_java_thread: Object = attach_thread.java_object().await.try_into()?;
+        assert!(agent_thread.ptr_eq(&attach_java_thread));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_attach() -> Result<()> {
+        let (vm, _thread) = crate::test::thread().await?;
+        let path = std::env::temp_dir().join(format!(".java_pid{}_test", std::process::id()));
+        let listener = AttachListener::bind_path(&path)?;
+        assert_eq!(path, listener.path());
+
+        let client = async {
+            let mut stream = UnixStream::connect(&path)
+                .await
+                .map_err(|error| InternalError(error.to_string()))?;
+            stream
+                .write_all(b"1\0properties\0\0\0\0")
+                .await
+                .map_err(|error| InternalError(error.to_string()))?;
+            let mut response = String::new();
+            stream
+                .read_to_string(&mut response)
+                .await
+                .map_err(|error| InternalError(error.to_string()))?;
+            Ok::<String, crate::Error>(response)
+        };
+        let (accepted, response) = tokio::join!(listener.accept(&vm), client);
+        accepted?;
+        let response = response?;
+        assert!(response.starts_with("0\n"));
+        assert!(response.contains("java.version="));
+
+        drop(listener);
+        assert!(!path.exists());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_bind_permissions() -> Result<()> {
+        let path = std::env::temp_dir().join(format!(".java_pid{}_mode", std::process::id()));
+        std::fs::write(&path, b"stale").map_err(|error| InternalError(error.to_string()))?;
+        let listener = AttachListener::bind_path(&path)?;
+        let metadata =
+            std::fs::metadata(&path).map_err(|error| InternalError(error.to_string()))?;
+        assert!(metadata.file_type().is_socket());
+        assert_eq!(0o600, metadata.permissions().mode() & 0o777);
+
+        // The private directory that the socket was bound in is removed
+        let prefix = format!(".java_pid{}_mode.", std::process::id());
+        let entries = std::fs::read_dir(std::env::temp_dir())
+            .map_err(|error| InternalError(error.to_string()))?;
+        assert!(!entries
+            .filter_map(std::result::Result::ok)
+            .any(|entry| entry.file_name().to_string_lossy().starts_with(&prefix)));
+        drop(listener);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout() -> Result<()> {
+        let (vm, _thread) = crate::test::thread().await?;
+        let path = std::env::temp_dir().join(format!(".java_pid{}_idle", std::process::id()));
+        let listener = AttachListener::bind_path(&path)?;
+        let _idle = UnixStream::connect(&path)
+            .await
+            .map_err(|error| InternalError(error.to_string()))?;
+        // A connection that does not send a request does not block the listener
+        tokio::time::timeout(REQUEST_TIMEOUT * 2, listener.accept(&vm))
+            .await
+            .map_err(|error| InternalError(error.to_string()))??;
+        Ok(())
+    }
+}
@@ -0,0 +1,257 @@
+//! Synchronous facade over the asynchronous [`VM`](crate::VM) for embedders that do not use async
+//! Rust.  The facade owns a single threaded tokio runtime that drives the VM; every method blocks
+//! the calling thread until the VM completes the operation.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use ristretto_vm::blocking::VM;
+//! use ristretto_vm::{ClassPath, ConfigurationBuilder, Result};
+//!
+//! fn main() -> Result<()> {
+//!     let configuration = ConfigurationBuilder::new()
+//!         .class_path(ClassPath::from("classes"))
+//!         .main_class("HelloWorld")
+//!         .build()?;
+//!     let vm = VM::new(configuration)?;
+//!     let _result = vm.invoke_main(vec!["world"])?;
+//!     vm.shutdown();
+//!     Ok(())
+//! }
+//! ```
+
+use crate::rust_value::RustValue;
+use crate::Error::InternalError;
+use crate::{Configuration, ConfigurationBuilder, Result};
+use ristretto_classloader::{Class, Value};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::runtime::{Builder, Runtime};
+
+/// Time to wait for background tasks to complete when the VM is shut down.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Blocking Java Virtual Machine
+#[derive(Debug)]
+pub struct VM {
+    // The VM is declared before the runtime so that it is dropped first, while the runtime that
+    // drives it is still available.
+    vm: Arc<crate::VM>,
+    runtime: Runtime,
+}
+
+/// Blocking VM
+impl VM {
+    /// Create a new VM
+    ///
+    /// # Errors
+    /// if the runtime or the VM cannot be created
+    pub fn new(configuration: Configuration) -> Result<Self> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|error| InternalError(error.to_string()))?;
+        let vm = runtime.block_on(crate::VM::new(configuration))?;
+        Ok(VM { vm, runtime })
+    }
+
+    /// Create a new VM with the default configuration
+    ///
+    /// # Errors
+    /// if the runtime or the VM cannot be created
+    pub fn with_default_configuration() -> Result<Self> {
+        let configuration = ConfigurationBuilder::default().build()?;
+        VM::new(configuration)
+    }
+
+    /// Get the asynchronous VM; e.g. to call methods that are not exposed by the blocking VM
+    /// together with [`block_on`](VM::block_on).
+    #[must_use]
+    pub fn vm(&self) -> &Arc<crate::VM> {
+        &self.vm
+    }
+
+    /// Get the configuration
+    #[must_use]
+    pub fn configuration(&self) -> &Configuration {
+        self.vm.configuration()
+    }
+
+    /// Get the main class
+    #[must_use]
+    pub fn main_class(&self) -> Option<&String> {
+        self.vm.main_class()
+    }
+
+    /// Get the java version
+    #[must_use]
+    pub fn java_version(&self) -> &str {
+        self.vm.java_version()
+    }
+
+    /// Run a future on the runtime of the VM, blocking until it completes.
+    ///
+    /// # Panics
+    /// if called from within an asynchronous execution context
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Load a class (e.g. "java.lang.Object").
+    ///
+    /// # Errors
+    /// if the class cannot be loaded
+    pub fn class<S: AsRef<str>>(&self, class_name: S) -> Result<Arc<Class>> {
+        self.block_on(self.vm.class(class_name))
+    }
+
+    /// Invoke the main method of the main class associated with the VM. The main method must have
+    /// the signature `public static void main(String[] args)`.
+    ///
+    /// # Errors
+    /// * if the main class is not specified
+    /// * if the main class does not specify a main method
+    /// * if the main method cannot be invoked
+    pub fn invoke_main<S: AsRef<str>>(&self, parameters: Vec<S>) -> Result<Option<Value>> {
+        self.block_on(self.vm.invoke_main(parameters))
+    }
+
+    /// Invoke a method.  To invoke a method on an object reference, the object reference must be
+    /// the first parameter in the parameters vector.
+    ///
+    /// # Errors
+    /// if the method cannot be invoked
+    pub fn invoke<C, M, D>(
+        &self,
+        class: C,
+        method: M,
+        descriptor: D,
+        parameters: Vec<impl RustValue>,
+    ) -> Result<Option<Value>>
+    where
+        C: AsRef<str>,
+        M: AsRef<str>,
+        D: AsRef<str>,
+    {
+        self.block_on(self.vm.invoke(class, method, descriptor, parameters))
+    }
+
+    /// Invoke a method.  To invoke a method on an object reference, the object reference must be
+    /// the first parameter in the parameters vector.
+    ///
+    /// # Errors
+    /// if the method cannot be invoked
+    pub fn try_invoke<C, M, D>(
+        &self,
+        class: C,
+        method: M,
+        descriptor: D,
+        parameters: Vec<impl RustValue>,
+    ) -> Result<Value>
+    where
+        C: AsRef<str>,
+        M: AsRef<str>,
+        D: AsRef<str>,
+    {
+        self.block_on(self.vm.try_invoke(class, method, descriptor, parameters))
+    }
+
+    /// Create a new VM Object by invoking the constructor of the specified class.
+    ///
+    /// # Errors
+    /// if the object cannot be created
+    pub fn object<C, M>(
+        &self,
+        class_name: C,
+        descriptor: M,
+        parameters: Vec<impl RustValue>,
+    ) -> Result<Value>
+    where
+        C: AsRef<str>,
+        M: AsRef<str>,
+    {
+        self.block_on(self.vm.object(class_name, descriptor, parameters))
+    }
+
+    /// Shut down the VM and its runtime, waiting a bounded time for blocking operations that are
+    /// still running to complete.  Dropping the VM also shuts it down, but waits for all blocking
+    /// operations to complete.
+    pub fn shutdown(self) {
+        let VM { vm, runtime } = self;
+        drop(vm);
+        runtime.shutdown_timeout(SHUTDOWN_TIMEOUT);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ristretto_classloader::{ClassPath, DEFAULT_JAVA_VERSION};
+    use std::path::PathBuf;
+
+    fn test_vm() -> Result<VM> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_jar_path = cargo_manifest
+            .join("..")
+            .join("classes")
+            .join("classes.jar");
+        let configuration = ConfigurationBuilder::new()
+            .class_path(ClassPath::from(classes_jar_path.to_string_lossy()))
+            .main_class("HelloWorld")
+            .build()?;
+        VM::new(configuration)
+    }
+
+    #[test]
+    fn test_vm_new() -> Result<()> {
+        let vm = test_vm()?;
+        assert_eq!(DEFAULT_JAVA_VERSION, vm.java_version());
+        assert_eq!(Some(&"HelloWorld".to_string()), vm.main_class());
+        assert!(vm
+            .configuration()
+            .class_path()
+            .to_string()
+            .contains("classes.jar"));
+        vm.shutdown();
+        Ok(())
+    }
+
+    #[test]
+    fn test_class() -> Result<()> {
+        let vm = test_vm()?;
+        let class = vm.class("java.lang.Object")?;
+        assert_eq!("java/lang/Object", class.name());
+        Ok(())
+    }
+
+    #[test]
+    fn test_invoke() -> Result<()> {
+        let vm = test_vm()?;
+        let value = vm.try_invoke(
+            "java.lang.Integer",
+            "parseInt",
+            "(Ljava/lang/String;)I",
+            vec!["42"],
+        )?;
+        assert_eq!(Value::Int(42), value);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invoke_main() -> Result<()> {
+        let vm = test_vm()?;
+        let result = vm.invoke_main(vec!["world"])?;
+        assert!(result.is_none());
+        vm.shutdown();
+        Ok(())
+    }
+
+    #[test]
+    fn test_block_on() -> Result<()> {
+        let vm = test_vm()?;
+        let class = vm.block_on(vm.vm().class("java.lang.String"))?;
+        assert_eq!("java/lang/String", class.name());
+        Ok(())
+    }
+}
@@ -1,20 +1,59 @@
+use crate::module_access::{IllegalAccess, ModuleOverride};
+use crate::native_access::IllegalNativeAccess;
+use crate::native_methods::MethodOverride;
+use crate::scheduler::{default_scheduler, Scheduler};
 use crate::Error::InternalError;
 use crate::Result;
+use ristretto_classloader::runtime::ProgressCallback;
 use ristretto_classloader::{ClassPath, DEFAULT_JAVA_VERSION};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::string::ToString;
+use std::sync::Arc;
 
 /// Configuration
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct Configuration {
     class_path: ClassPath,
     main_class: Option<String>,
+    main_class_bytes: Option<Vec<u8>>,
     jar: Option<PathBuf>,
     java_home: Option<PathBuf>,
     java_version: Option<String>,
     system_properties: HashMap<String, String>,
     preview_features: bool,
+    method_overrides: HashMap<String, MethodOverride>,
+    panic_on_unsatisfied_link: bool,
+    add_exports: Vec<ModuleOverride>,
+    add_opens: Vec<ModuleOverride>,
+    illegal_access: Option<IllegalAccess>,
+    enable_native_access: Vec<String>,
+    illegal_native_access: IllegalNativeAccess,
+    scheduler: Arc<dyn Scheduler>,
+    download_progress: Option<ProgressCallback>,
+}
+
+/// Configurations are equal if their settings are equal and they use the same scheduler.
+impl PartialEq for Configuration {
+    fn eq(&self, other: &Self) -> bool {
+        self.class_path == other.class_path
+            && self.main_class == other.main_class
+            && self.main_class_bytes == other.main_class_bytes
+            && self.jar == other.jar
+            && self.java_home == other.java_home
+            && self.java_version == other.java_version
+            && self.system_properties == other.system_properties
+            && self.preview_features == other.preview_features
+            && self.method_overrides == other.method_overrides
+            && self.panic_on_unsatisfied_link == other.panic_on_unsatisfied_link
+            && self.add_exports == other.add_exports
+            && self.add_opens == other.add_opens
+            && self.illegal_access == other.illegal_access
+            && self.enable_native_access == other.enable_native_access
+            && self.illegal_native_access == other.illegal_native_access
+            && Arc::ptr_eq(&self.scheduler, &other.scheduler)
+            && self.download_progress == other.download_progress
+    }
 }
 
 /// Configuration
@@ -31,6 +70,12 @@ impl Configuration {
         self.main_class.as_ref()
     }
 
+    /// Get the bytes of the main class file
+    #[must_use]
+    pub fn main_class_bytes(&self) -> Option<&[u8]> {
+        self.main_class_bytes.as_deref()
+    }
+
     /// Get the jar
     #[must_use]
     pub fn jar(&self) -> Option<&PathBuf> {
@@ -60,6 +105,64 @@ impl Configuration {
     pub fn preview_features(&self) -> bool {
         self.preview_features
     }
+
+    /// Get the method overrides; keyed by method signature (e.g. `java/lang/System.exit(I)V`)
+    #[must_use]
+    pub fn method_overrides(&self) -> &HashMap<String, MethodOverride> {
+        &self.method_overrides
+    }
+
+    /// Get the panic on unsatisfied link flag; when set, calling a native method that is not
+    /// implemented panics instead of throwing `java.lang.UnsatisfiedLinkError`
+    #[must_use]
+    pub fn panic_on_unsatisfied_link(&self) -> bool {
+        self.panic_on_unsatisfied_link
+    }
+
+    /// Get the `--add-exports` overrides, which export packages of named modules to other modules
+    #[must_use]
+    pub fn add_exports(&self) -> &[ModuleOverride] {
+        &self.add_exports
+    }
+
+    /// Get the `--add-opens` overrides, which open packages of named modules for deep reflection
+    /// by other modules
+    #[must_use]
+    pub fn add_opens(&self) -> &[ModuleOverride] {
+        &self.add_opens
+    }
+
+    /// Get the handling of reflective access by the unnamed module to packages of named modules
+    /// that are not exported or opened to it; `None` uses the default for the Java version
+    #[must_use]
+    pub fn illegal_access(&self) -> Option<IllegalAccess> {
+        self.illegal_access
+    }
+
+    /// Get the modules that native access is enabled for; `ALL-UNNAMED` is the class path
+    #[must_use]
+    pub fn enable_native_access(&self) -> &[String] {
+        &self.enable_native_access
+    }
+
+    /// Get the handling of restricted method calls by modules that native access is not enabled
+    /// for
+    #[must_use]
+    pub fn illegal_native_access(&self) -> IllegalNativeAccess {
+        self.illegal_native_access
+    }
+
+    /// Get the scheduler used to sleep, yield and run blocking operations for Java threads
+    #[must_use]
+    pub fn scheduler(&self) -> &Arc<dyn Scheduler> {
+        &self.scheduler
+    }
+
+    /// Get the callback that receives the progress of the Java runtime download
+    #[must_use]
+    pub fn download_progress(&self) -> Option<&ProgressCallback> {
+        self.download_progress.as_ref()
+    }
 }
 
 /// Configuration builder
@@ -67,11 +170,21 @@ impl Configuration {
 pub struct ConfigurationBuilder {
     class_path: Option<ClassPath>,
     main_class: Option<String>,
+    main_class_bytes: Option<Vec<u8>>,
     jar: Option<PathBuf>,
     java_home: Option<PathBuf>,
     java_version: Option<String>,
     system_properties: HashMap<String, String>,
     preview_features: bool,
+    method_overrides: HashMap<String, MethodOverride>,
+    panic_on_unsatisfied_link: bool,
+    add_exports: Vec<String>,
+    add_opens: Vec<String>,
+    illegal_access: Option<IllegalAccess>,
+    enable_native_access: Vec<String>,
+    illegal_native_access: IllegalNativeAccess,
+    scheduler: Option<Arc<dyn Scheduler>>,
+    download_progress: Option<ProgressCallback>,
 }
 
 /// Configuration builder
@@ -82,11 +195,21 @@ impl ConfigurationBuilder {
         ConfigurationBuilder {
             class_path: None,
             main_class: None,
+            main_class_bytes: None,
             jar: None,
             java_home: None,
             java_version: None,
             system_properties: HashMap::new(),
             preview_features: false,
+            method_overrides: HashMap::new(),
+            panic_on_unsatisfied_link: false,
+            add_exports: Vec::new(),
+            add_opens: Vec::new(),
+            illegal_access: None,
+            enable_native_access: Vec::new(),
+            illegal_native_access: IllegalNativeAccess::default(),
+            scheduler: None,
+            download_progress: None,
         }
     }
 
@@ -104,6 +227,14 @@ impl ConfigurationBuilder {
         self
     }
 
+    /// Set the bytes of the main class file to run; the class is defined from these bytes
+    /// instead of being loaded from the class path.
+    #[must_use]
+    pub fn main_class_bytes(mut self, main_class_bytes: Vec<u8>) -> Self {
+        self.main_class_bytes = Some(main_class_bytes);
+        self
+    }
+
     /// Set the jar
     #[must_use]
     pub fn jar(mut self, jar: PathBuf) -> Self {
@@ -152,10 +283,102 @@ impl ConfigurationBuilder {
         self
     }
 
+    /// Panic when a native method that is not implemented is called instead of throwing
+    /// `java.lang.UnsatisfiedLinkError`; useful when debugging missing native methods
+    #[must_use]
+    pub fn panic_on_unsatisfied_link(mut self) -> Self {
+        self.panic_on_unsatisfied_link = true;
+        self
+    }
+
+    /// Export a package of a named module to other modules, in the `--add-exports` form
+    /// `<module>/<package>=<target>(,<target>)*`; `ALL-UNNAMED` targets the class path
+    #[must_use]
+    pub fn add_exports<S: AsRef<str>>(mut self, value: S) -> Self {
+        self.add_exports.push(value.as_ref().to_string());
+        self
+    }
+
+    /// Open a package of a named module for deep reflection by other modules, in the
+    /// `--add-opens` form `<module>/<package>=<target>(,<target>)*`; `ALL-UNNAMED` targets the
+    /// class path
+    #[must_use]
+    pub fn add_opens<S: AsRef<str>>(mut self, value: S) -> Self {
+        self.add_opens.push(value.as_ref().to_string());
+        self
+    }
+
+    /// Set the handling of reflective access by the unnamed module to packages of named modules
+    /// that are not exported or opened to it; defaults to [`IllegalAccess::default_for`] the Java
+    /// version
+    #[must_use]
+    pub fn illegal_access(mut self, illegal_access: IllegalAccess) -> Self {
+        self.illegal_access = Some(illegal_access);
+        self
+    }
+
+    /// Enable native access for modules, in the `--enable-native-access` form
+    /// `<module>(,<module>)*`; `ALL-UNNAMED` enables native access for the class path
+    #[must_use]
+    pub fn enable_native_access<S: AsRef<str>>(mut self, value: S) -> Self {
+        self.enable_native_access.push(value.as_ref().to_string());
+        self
+    }
+
+    /// Set the handling of restricted method calls, such as `System.loadLibrary`, by modules
+    /// that native access is not enabled for; defaults to [`IllegalNativeAccess::Warn`]
+    #[must_use]
+    pub fn illegal_native_access(mut self, illegal_native_access: IllegalNativeAccess) -> Self {
+        self.illegal_native_access = illegal_native_access;
+        self
+    }
+
+    /// Set the scheduler used to sleep, yield and run blocking operations for Java threads;
+    /// defaults to the tokio scheduler, or the thread scheduler on wasm.  The scheduler does not
+    /// create Java threads.
+    #[must_use]
+    pub fn scheduler(mut self, scheduler: Arc<dyn Scheduler>) -> Self {
+        self.scheduler = Some(scheduler);
+        self
+    }
+
+    /// Set the callback that receives the progress of the Java runtime download, if the runtime
+    /// is not installed
+    #[must_use]
+    pub fn download_progress(mut self, download_progress: ProgressCallback) -> Self {
+        self.download_progress = Some(download_progress);
+        self
+    }
+
+    /// Override the implementation of a method; this replaces both Rust and Java byte code
+    /// implementations of the method, allowing embedders to disable or patch methods.
+    #[must_use]
+    pub fn method_override<C, M, D>(
+        mut self,
+        class_name: C,
+        method_name: M,
+        method_descriptor: D,
+        method_override: MethodOverride,
+    ) -> Self
+    where
+        C: AsRef<str>,
+        M: AsRef<str>,
+        D: AsRef<str>,
+    {
+        let class_name = class_name.as_ref().replace('.', "/");
+        let method_name = method_name.as_ref();
+        let method_descriptor = method_descriptor.as_ref();
+        let method_signature = format!("{class_name}.{method_name}{method_descriptor}");
+        self.method_overrides
+            .insert(method_signature, method_override);
+        self
+    }
+
     /// Build the configuration
     ///
     /// # Errors
-    /// An error will be returned if the configuration cannot be built.
+    /// An error will be returned if the configuration cannot be built or a module override is
+    /// invalid.
     pub fn build(self) -> Result<Configuration> {
         let class_path = if let Some(class_path) = self.class_path {
             class_path
@@ -177,14 +400,46 @@ impl ConfigurationBuilder {
             None
         };
 
+        let add_exports = self
+            .add_exports
+            .iter()
+            .map(|value| value.parse())
+            .collect::<Result<Vec<ModuleOverride>>>()?;
+        let add_opens = self
+            .add_opens
+            .iter()
+            .map(|value| value.parse())
+            .collect::<Result<Vec<ModuleOverride>>>()?;
+        let mut enable_native_access = Vec::new();
+        for value in &self.enable_native_access {
+            for module in value.split(',') {
+                if module.is_empty() {
+                    return Err(InternalError(format!(
+                        "Invalid native access modules: {value}; expected <module>(,<module>)*"
+                    )));
+                }
+                enable_native_access.push(module.to_string());
+            }
+        }
+
         Ok(Configuration {
             class_path,
             main_class: self.main_class,
+            main_class_bytes: self.main_class_bytes,
             jar: self.jar,
             java_home,
             java_version,
             system_properties: self.system_properties,
             preview_features: self.preview_features,
+            method_overrides: self.method_overrides,
+            panic_on_unsatisfied_link: self.panic_on_unsatisfied_link,
+            add_exports,
+            add_opens,
+            illegal_access: self.illegal_access,
+            enable_native_access,
+            illegal_native_access: self.illegal_native_access,
+            scheduler: self.scheduler.unwrap_or_else(default_scheduler),
+            download_progress: self.download_progress,
         })
     }
 }
@@ -200,21 +455,29 @@ impl Default for ConfigurationBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::scheduler::DeterministicScheduler;
 
     #[test]
     fn test_configuration_builder() -> Result<()> {
         let configuration = ConfigurationBuilder::new()
             .class_path(ClassPath::from(".."))
             .main_class("Foo")
+            .main_class_bytes(vec![0xCA, 0xFE, 0xBA, 0xBE])
             .jar(PathBuf::from("test.jar"))
             .java_version("21")
             .preview_features()
+            .panic_on_unsatisfied_link()
             .build()?;
         assert_eq!(&ClassPath::from(".."), configuration.class_path());
         assert_eq!(Some(&"Foo".to_string()), configuration.main_class());
+        assert_eq!(
+            Some([0xCA, 0xFE, 0xBA, 0xBE].as_slice()),
+            configuration.main_class_bytes()
+        );
         assert_eq!(Some(&PathBuf::from("test.jar")), configuration.jar());
         assert_eq!(Some(&"21".to_string()), configuration.java_version());
         assert!(configuration.preview_features());
+        assert!(configuration.panic_on_unsatisfied_link());
         Ok(())
     }
 
@@ -223,6 +486,7 @@ mod tests {
         let configuration = ConfigurationBuilder::new().build()?;
         assert_eq!(&ClassPath::from("."), configuration.class_path());
         assert!(configuration.main_class().is_none());
+        assert!(configuration.main_class_bytes().is_none());
         assert!(configuration.jar().is_none());
         assert_eq!(
             Some(&DEFAULT_JAVA_VERSION.to_string()),
@@ -230,6 +494,39 @@ mod tests {
         );
         assert!(configuration.system_properties().is_empty());
         assert!(!configuration.preview_features());
+        assert!(configuration.method_overrides().is_empty());
+        assert!(!configuration.panic_on_unsatisfied_link());
+        assert!(configuration.add_exports().is_empty());
+        assert!(configuration.add_opens().is_empty());
+        assert_eq!(None, configuration.illegal_access());
+        assert!(configuration.enable_native_access().is_empty());
+        assert_eq!(
+            IllegalNativeAccess::Warn,
+            configuration.illegal_native_access()
+        );
+        assert!(**configuration.scheduler() == *configuration.scheduler().clone());
+        assert!(configuration.download_progress().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_configuration_builder_scheduler() -> Result<()> {
+        let scheduler: Arc<dyn Scheduler> = Arc::new(DeterministicScheduler::new());
+        let configuration = ConfigurationBuilder::new()
+            .scheduler(scheduler.clone())
+            .build()?;
+        assert!(*scheduler == **configuration.scheduler());
+        Ok(())
+    }
+
+    #[test]
+    fn test_configuration_builder_download_progress() -> Result<()> {
+        let download_progress = ProgressCallback::new(|_| {});
+        let configuration = ConfigurationBuilder::new()
+            .download_progress(download_progress.clone())
+            .build()?;
+        assert_eq!(Some(&download_progress), configuration.download_progress());
+        assert_ne!(configuration, ConfigurationBuilder::new().build()?);
         Ok(())
     }
 
@@ -277,4 +574,82 @@ mod tests {
         assert_eq!(Some(&"2".to_string()), system_properties.get("b"));
         Ok(())
     }
+
+    #[test]
+    fn test_configuration_builder_method_override() -> Result<()> {
+        let configuration = ConfigurationBuilder::new()
+            .method_override(
+                "java.lang.System",
+                "exit",
+                "(I)V",
+                MethodOverride::UnsupportedOperation,
+            )
+            .build()?;
+
+        let method_overrides = configuration.method_overrides();
+        assert_eq!(
+            Some(&MethodOverride::UnsupportedOperation),
+            method_overrides.get("java/lang/System.exit(I)V")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_configuration_builder_module_access() -> Result<()> {
+        let configuration = ConfigurationBuilder::new()
+            .add_exports("java.base/jdk.internal.misc=ALL-UNNAMED")
+            .add_opens("java.base/java.lang=ALL-UNNAMED,java.sql")
+            .illegal_access(IllegalAccess::Warn)
+            .build()?;
+        assert_eq!(
+            &[ModuleOverride::new(
+                "java.base",
+                "jdk/internal/misc",
+                &["ALL-UNNAMED"]
+            )],
+            configuration.add_exports()
+        );
+        assert_eq!(
+            &[ModuleOverride::new(
+                "java.base",
+                "java/lang",
+                &["ALL-UNNAMED", "java.sql"]
+            )],
+            configuration.add_opens()
+        );
+        assert_eq!(Some(IllegalAccess::Warn), configuration.illegal_access());
+        Ok(())
+    }
+
+    #[test]
+    fn test_configuration_builder_native_access() -> Result<()> {
+        let configuration = ConfigurationBuilder::new()
+            .enable_native_access("ALL-UNNAMED,com.example")
+            .enable_native_access("java.desktop")
+            .illegal_native_access(IllegalNativeAccess::Deny)
+            .build()?;
+        assert_eq!(
+            &["ALL-UNNAMED", "com.example", "java.desktop"],
+            configuration.enable_native_access()
+        );
+        assert_eq!(
+            IllegalNativeAccess::Deny,
+            configuration.illegal_native_access()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_configuration_builder_invalid_native_access() {
+        let result = ConfigurationBuilder::new()
+            .enable_native_access("com.example,")
+            .build();
+        assert!(matches!(result, Err(InternalError(_))));
+    }
+
+    #[test]
+    fn test_configuration_builder_invalid_module_override() {
+        let result = ConfigurationBuilder::new().add_opens("java.base").build();
+        assert!(matches!(result, Err(InternalError(_))));
+    }
 }
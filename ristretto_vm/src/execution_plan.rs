@@ -0,0 +1,231 @@
+use crate::vm::{Resolution, VM};
+use crate::{Configuration, Result};
+use ristretto_classloader::Class;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+
+/// Source reported for a main class defined from class bytes rather than loaded from a class path
+const CLASS_BYTES_SOURCE: &str = "<class bytes>";
+
+/// The class path of a class loader in an [`ExecutionPlan`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClassLoaderPlan {
+    pub name: String,
+    pub class_path: Vec<String>,
+}
+
+/// The main class of an [`ExecutionPlan`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MainClassPlan {
+    pub name: String,
+    /// Class path entry the class is loaded from; `None` if the class cannot be found
+    pub source: Option<String>,
+    /// Returns true if the class has a `public static void main(String[])` method
+    pub main_method: bool,
+}
+
+/// The Java runtime, class loaders and main class that a VM would use for a configuration.  The
+/// plan is resolved without initializing a VM or running any Java code, so that launch problems
+/// (e.g. a wrong jar or a missing main class) can be diagnosed quickly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionPlan {
+    pub java_home: PathBuf,
+    pub java_version: String,
+    pub class_file_version: String,
+    pub preview_features: bool,
+    /// Class loaders from the bootstrap class loader to the class loader of the main class
+    pub class_loaders: Vec<ClassLoaderPlan>,
+    pub main_class: Option<MainClassPlan>,
+    pub system_properties: BTreeMap<String, String>,
+}
+
+impl ExecutionPlan {
+    /// Resolve the execution plan for a configuration.
+    ///
+    /// # Errors
+    /// if the Java runtime cannot be loaded or the main class cannot be determined
+    pub async fn resolve(configuration: &Configuration) -> Result<Self> {
+        let Resolution {
+            java_home,
+            java_version,
+            java_class_file_version,
+            class_loader,
+            main_class,
+            defined_main_class,
+            ..
+        } = VM::resolve(configuration).await?;
+
+        let mut class_loaders = vec![&class_loader];
+        while let Some(parent) = class_loaders.last().and_then(|loader| loader.parent()) {
+            class_loaders.push(parent);
+        }
+        class_loaders.reverse();
+
+        let main_class = if let Some(class) = defined_main_class {
+            Some(MainClassPlan {
+                name: class.name().replace('/', "."),
+                source: Some(CLASS_BYTES_SOURCE.to_string()),
+                main_method: class.main_method().is_some(),
+            })
+        } else if let Some(name) = main_class {
+            let class_name = name.replace('.', "/");
+            let mut main_class = MainClassPlan {
+                name,
+                source: None,
+                main_method: false,
+            };
+            'class_loaders: for class_loader in &class_loaders {
+                for class_path_entry in class_loader.class_path().iter() {
+                    if let Ok(class_file) = class_path_entry.read_class(&class_name).await {
+                        main_class.source = Some(class_path_entry.name().to_string());
+                        main_class.main_method = Class::from(class_file)?.main_method().is_some();
+                        break 'class_loaders;
+                    }
+                }
+            }
+            Some(main_class)
+        } else {
+            None
+        };
+
+        let class_loaders = class_loaders
+            .into_iter()
+            .map(|class_loader| ClassLoaderPlan {
+                name: class_loader.name().to_string(),
+                class_path: class_loader
+                    .class_path()
+                    .iter()
+                    .map(|entry| entry.name().to_string())
+                    .collect(),
+            })
+            .collect();
+
+        Ok(ExecutionPlan {
+            java_home,
+            java_version,
+            class_file_version: java_class_file_version.to_string(),
+            preview_features: configuration.preview_features(),
+            class_loaders,
+            main_class,
+            system_properties: configuration
+                .system_properties()
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+        })
+    }
+
+    /// Returns true if the plan has a main class that can be found and has a main method.
+    #[must_use]
+    pub fn is_runnable(&self) -> bool {
+        self.main_class
+            .as_ref()
+            .is_some_and(|main_class| main_class.source.is_some() && main_class.main_method)
+    }
+}
+
+impl fmt::Display for ExecutionPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "java home: {}", self.java_home.to_string_lossy())?;
+        writeln!(f, "java version: {}", self.java_version)?;
+        writeln!(f, "class file version: {}", self.class_file_version)?;
+        writeln!(f, "preview features: {}", self.preview_features)?;
+        writeln!(f, "class loaders:")?;
+        for class_loader in &self.class_loaders {
+            writeln!(f, "  {}:", class_loader.name)?;
+            for entry in &class_loader.class_path {
+                writeln!(f, "    {entry}")?;
+            }
+        }
+        match &self.main_class {
+            Some(main_class) => {
+                writeln!(f, "main class: {}", main_class.name)?;
+                let source = main_class.source.as_deref().unwrap_or("not found");
+                writeln!(f, "  source: {source}")?;
+                let main_method = if main_class.main_method {
+                    "found"
+                } else {
+                    "not found"
+                };
+                writeln!(f, "  main method: {main_method}")?;
+            }
+            None => writeln!(f, "main class: none")?,
+        }
+        writeln!(f, "system properties:")?;
+        for (key, value) in &self.system_properties {
+            writeln!(f, "  {key}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConfigurationBuilder;
+    use ristretto_classloader::ClassPath;
+
+    fn classes_jar_path() -> PathBuf {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        cargo_manifest
+            .join("..")
+            .join("classes")
+            .join("classes.jar")
+    }
+
+    #[tokio::test]
+    async fn test_resolve_jar() -> Result<()> {
+        let configuration = ConfigurationBuilder::new()
+            .jar(classes_jar_path())
+            .add_system_property("foo", "bar")
+            .build()?;
+        let plan = ExecutionPlan::resolve(&configuration).await?;
+        let class_loader_names = plan
+            .class_loaders
+            .iter()
+            .map(|class_loader| class_loader.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["bootstrap", "system", "jar"], class_loader_names);
+        let main_class = plan.main_class.as_ref().expect("main class");
+        assert_eq!("HelloWorld", main_class.name);
+        assert!(main_class
+            .source
+            .as_ref()
+            .is_some_and(|source| source.ends_with("classes.jar")));
+        assert!(main_class.main_method);
+        assert!(plan.is_runnable());
+        assert_eq!(Some(&"bar".to_string()), plan.system_properties.get("foo"));
+        let plan = plan.to_string();
+        assert!(plan.contains("main class: HelloWorld"));
+        assert!(plan.contains("  main method: found"));
+        assert!(plan.contains("  foo=bar"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_resolve_missing_main_class() -> Result<()> {
+        let class_path = ClassPath::from(classes_jar_path().to_string_lossy());
+        let configuration = ConfigurationBuilder::new()
+            .class_path(class_path)
+            .main_class("Missing")
+            .build()?;
+        let plan = ExecutionPlan::resolve(&configuration).await?;
+        let main_class = plan.main_class.as_ref().expect("main class");
+        assert_eq!("Missing", main_class.name);
+        assert!(main_class.source.is_none());
+        assert!(!plan.is_runnable());
+        assert!(plan.to_string().contains("  source: not found"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_resolve_no_main_class() -> Result<()> {
+        let configuration = ConfigurationBuilder::new().build()?;
+        let plan = ExecutionPlan::resolve(&configuration).await?;
+        assert!(plan.main_class.is_none());
+        assert!(!plan.is_runnable());
+        assert!(plan.to_string().contains("main class: none"));
+        Ok(())
+    }
+}
@@ -27,7 +27,7 @@ use crate::{LocalVariables, OperandStack, Result, Thread};
 use async_recursion::async_recursion;
 use byte_unit::{Byte, UnitType};
 use ristretto_classfile::attributes::Instruction;
-use ristretto_classloader::{Class, Method, Value};
+use ristretto_classloader::{Class, Method, QuickInstruction, Value};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Weak};
 use tracing::{debug, event_enabled, Level};
@@ -95,6 +95,24 @@ impl Frame {
         self.program_counter.load(Ordering::Relaxed)
     }
 
+    /// Get the quickened form of the instruction at the program counter, if the instruction has
+    /// been quickened.
+    #[inline]
+    pub(crate) fn quickened(&self) -> Option<&QuickInstruction> {
+        self.method.quickened().get(self.program_counter())
+    }
+
+    /// Quicken the instruction at the program counter; subsequent executions of the instruction
+    /// use the resolved form instead of resolving the constant pool entry again.
+    #[inline]
+    pub(crate) fn quicken(&self, instruction: QuickInstruction) {
+        // Another thread may have quickened the instruction first; both resolved the same entry.
+        let _ = self
+            .method
+            .quickened()
+            .set(self.program_counter(), instruction);
+    }
+
     /// Execute the method in this frame
     ///
     /// # Errors
@@ -108,8 +126,15 @@ impl Frame {
         let max_stack = self.method.max_stack();
         let stack = &mut OperandStack::with_max_size(max_stack);
         let code = self.method.code();
+        let thread = self.thread.upgrade();
 
         loop {
+            if let Some(thread) = &thread {
+                if thread.safepoint_requested() {
+                    thread.safepoint_poll().await;
+                }
+            }
+
             let program_counter = self.program_counter.load(Ordering::Relaxed);
             let Some(instruction) = code.get(program_counter) else {
                 return Err(InvalidProgramCounter(program_counter));
@@ -119,6 +144,9 @@ impl Frame {
                 self.debug_execute(locals, stack, instruction)?;
             }
 
+            #[cfg(feature = "race-detector")]
+            crate::race_detector::instrument(self, stack, instruction).await;
+
             let result = self.process(locals, stack, instruction).await;
             match result {
                 Ok(Continue) => {
@@ -389,8 +417,8 @@ impl Frame {
             Instruction::Return => r#return(),
             Instruction::Getstatic(index) => getstatic(self, stack, *index).await,
             Instruction::Putstatic(index) => putstatic(self, stack, *index).await,
-            Instruction::Getfield(index) => getfield(stack, &self.class, *index),
-            Instruction::Putfield(index) => putfield(stack, &self.class, *index),
+            Instruction::Getfield(index) => getfield(self, stack, *index),
+            Instruction::Putfield(index) => putfield(self, stack, *index),
             Instruction::Invokevirtual(index) => invokevirtual(self, stack, *index).await,
             Instruction::Invokespecial(index) => invokespecial(self, stack, *index).await,
             Instruction::Invokestatic(index) => invokestatic(self, stack, *index).await,
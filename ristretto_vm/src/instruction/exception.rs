@@ -1,8 +1,11 @@
 use crate::frame::{ExecutionResult, Frame};
 use crate::operand_stack::OperandStack;
-use crate::Error::{InternalError, JavaError, Throwable};
+use crate::Error::{
+    ClassFileError, ClassLoaderError, InternalError, JavaError, Throwable,
+    UnsupportedClassFileVersion,
+};
 use crate::{Error, Result, VM};
-use ristretto_classloader::{Object, Reference};
+use ristretto_classloader::{Object, Reference, Value};
 use std::sync::Arc;
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.athrow>
@@ -63,23 +66,101 @@ pub(crate) async fn process_throwable(
     Err(Throwable(throwable))
 }
 
-/// Convert native Rust errors to Java throwables.
+/// Convert native Rust errors to Java throwables.  The error is mapped to the most appropriate
+/// Java throwable class, and the Rust error source chain is preserved as the throwable cause
+/// chain.
 ///
 /// # Errors
 /// if the error cannot be converted to a throwable
 pub(crate) async fn convert_error_to_throwable(vm: Arc<VM>, error: Error) -> Result<Object> {
-    let (class_name, message) = match error {
-        JavaError(java_error) => {
-            let class_name = java_error.class_name().to_string();
-            let message = java_error.message();
-            (class_name, message)
+    let (class_name, message) = match &error {
+        Throwable(throwable) => return Ok(throwable.clone()),
+        _ => throwable_class_and_message(&error),
+    };
+    let throwable = new_throwable(&vm, class_name, message).await?;
+
+    let mut current_throwable = throwable.clone();
+    let mut source = std::error::Error::source(&error);
+    while let Some(error) = source {
+        let (class_name, message) = throwable_class_and_message(error);
+        let cause = new_throwable(&vm, class_name, message).await?;
+        current_throwable.set_value("cause", Value::from(cause.clone()))?;
+        current_throwable = cause;
+        source = error.source();
+    }
+
+    Ok(throwable)
+}
+
+/// Determine the Java throwable class name and message for a native Rust error.  Errors of the
+/// VM, class loader and class file crates are mapped to the corresponding Java throwable; other
+/// errors (e.g. I/O errors in the source chain) are mapped to `java.lang.InternalError`.
+fn throwable_class_and_message<'a>(
+    error: &'a (dyn std::error::Error + 'static),
+) -> (&'a str, String) {
+    if let Some(vm_error) = error.downcast_ref::<Error>() {
+        match vm_error {
+            JavaError(java_error) => return (java_error.class_name(), java_error.message()),
+            ClassFileError(_)
+            | ClassLoaderError(ristretto_classloader::Error::ClassFileError(_)) => {
+                return ("java.lang.ClassFormatError", error.to_string())
+            }
+            ClassLoaderError(class_loader_error) => {
+                if let Some(class_and_message) = class_loader_throwable(class_loader_error) {
+                    return class_and_message;
+                }
+            }
+            UnsupportedClassFileVersion(_) => {
+                return ("java.lang.UnsupportedClassVersionError", error.to_string())
+            }
+            _ => {}
+        }
+    } else if let Some(class_loader_error) = error.downcast_ref::<ristretto_classloader::Error>() {
+        if let ristretto_classloader::Error::ClassFileError(_) = class_loader_error {
+            return ("java.lang.ClassFormatError", error.to_string());
+        }
+        if let Some(class_and_message) = class_loader_throwable(class_loader_error) {
+            return class_and_message;
         }
-        Throwable(throwable) => return Ok(throwable),
-        _ => ("java.lang.InternalError".to_string(), format!("{error}")),
+    } else if error.is::<ristretto_classfile::Error>() {
+        return ("java.lang.ClassFormatError", error.to_string());
+    }
+    ("java.lang.InternalError", error.to_string())
+}
+
+/// Determine the Java throwable class name and message for a class loader error that corresponds
+/// to a linkage error.
+fn class_loader_throwable(error: &ristretto_classloader::Error) -> Option<(&str, String)> {
+    let class_and_message = match error {
+        ristretto_classloader::Error::ClassNotFound(class_name) => {
+            ("java.lang.NoClassDefFoundError", class_name.to_string())
+        }
+        ristretto_classloader::Error::FieldNotFound { field_name, .. } => {
+            ("java.lang.NoSuchFieldError", field_name.to_string())
+        }
+        ristretto_classloader::Error::IllegalAccessError(message) => {
+            ("java.lang.IllegalAccessError", message.to_string())
+        }
+        ristretto_classloader::Error::MethodNotFound {
+            class_name,
+            method_name,
+            method_descriptor,
+        } => (
+            "java.lang.NoSuchMethodError",
+            format!("'{class_name}.{method_name}{method_descriptor}'"),
+        ),
+        _ => return None,
     };
+    Some(class_and_message)
+}
 
+/// Create a new throwable with the specified class and message.
+///
+/// # Errors
+/// if the throwable cannot be created
+async fn new_throwable<S: AsRef<str>>(vm: &VM, class_name: &str, message: S) -> Result<Object> {
     let throwable = vm
-        .object(class_name, "Ljava/lang/String;", vec![message])
+        .object(class_name, "Ljava/lang/String;", vec![message.as_ref()])
         .await?;
     let throwable: Object = throwable.try_into()?;
     Ok(throwable)
@@ -106,4 +187,86 @@ mod test {
         assert!(result.is_err());
         Ok(())
     }
+
+    #[test]
+    fn test_throwable_class_and_message() {
+        let error = JavaError(crate::JavaError::NullPointerException("foo".to_string()));
+        assert_eq!(
+            ("java.lang.NullPointerException", "foo".to_string()),
+            throwable_class_and_message(&error)
+        );
+
+        let error = ClassLoaderError(ristretto_classloader::Error::ClassNotFound(
+            "Foo".to_string(),
+        ));
+        assert_eq!(
+            ("java.lang.NoClassDefFoundError", "Foo".to_string()),
+            throwable_class_and_message(&error)
+        );
+
+        let error = ClassLoaderError(ristretto_classloader::Error::MethodNotFound {
+            class_name: "Foo".to_string(),
+            method_name: "bar".to_string(),
+            method_descriptor: "()V".to_string(),
+        });
+        assert_eq!(
+            ("java.lang.NoSuchMethodError", "'Foo.bar()V'".to_string()),
+            throwable_class_and_message(&error)
+        );
+
+        let error = UnsupportedClassFileVersion(99);
+        assert_eq!(
+            (
+                "java.lang.UnsupportedClassVersionError",
+                "Unsupported class file version: 99".to_string()
+            ),
+            throwable_class_and_message(&error)
+        );
+
+        let error = InternalError("foo".to_string());
+        assert_eq!(
+            ("java.lang.InternalError", "Internal error: foo".to_string()),
+            throwable_class_and_message(&error)
+        );
+    }
+
+    #[test]
+    fn test_throwable_class_and_message_for_causes() {
+        let cause = ristretto_classloader::Error::ClassNotFound("Foo".to_string());
+        assert_eq!(
+            ("java.lang.NoClassDefFoundError", "Foo".to_string()),
+            throwable_class_and_message(&cause)
+        );
+
+        let cause = ristretto_classloader::Error::IllegalAccessError("foo".to_string());
+        assert_eq!(
+            ("java.lang.IllegalAccessError", "foo".to_string()),
+            throwable_class_and_message(&cause)
+        );
+
+        let cause = ristretto_classfile::Error::InvalidConstantPoolIndex(42);
+        assert_eq!(
+            ("java.lang.ClassFormatError", cause.to_string()),
+            throwable_class_and_message(&cause)
+        );
+
+        let cause = std::io::Error::other("foo");
+        assert_eq!(
+            ("java.lang.InternalError", "foo".to_string()),
+            throwable_class_and_message(&cause)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_convert_error_to_throwable() -> Result<()> {
+        let vm = VM::default().await?;
+        let error = ClassLoaderError(ristretto_classloader::Error::ClassNotFound(
+            "Foo".to_string(),
+        ));
+        let throwable = convert_error_to_throwable(vm, error).await?;
+        assert_eq!("java/lang/NoClassDefFoundError", throwable.class().name());
+        let message: String = throwable.value("detailMessage")?.try_into()?;
+        assert_eq!("Foo", message);
+        Ok(())
+    }
 }
@@ -1,27 +1,57 @@
-use crate::frame::ExecutionResult;
 use crate::frame::ExecutionResult::Continue;
+use crate::frame::{ExecutionResult, Frame};
 use crate::operand_stack::OperandStack;
 use crate::Error::InvalidStackValue;
 use crate::Result;
-use ristretto_classloader::{Class, Reference, Value};
+use ristretto_classloader::{Field, Object, QuickInstruction, Reference, Value};
 use std::sync::Arc;
 
+/// Get the field of the object referenced by the instruction at the program counter of the frame.
+/// The instruction is quickened on first resolution with the class of the object and the offset
+/// of the field, so that subsequent executions for objects of the same class do not look up the
+/// constant pool or the field name.
+#[inline]
+fn field<'a>(frame: &Frame, object: &'a Object, index: u16) -> Result<&'a Field> {
+    match frame.quickened() {
+        Some(QuickInstruction::Field(class, offset))
+            if std::ptr::eq(class.as_ptr(), Arc::as_ptr(object.class())) =>
+        {
+            Ok(object.field_at_offset(*offset)?)
+        }
+        Some(_) => Ok(object.field(field_name(frame, index)?)?),
+        None => {
+            let offset = object.field_offset(field_name(frame, index)?)?;
+            frame.quicken(QuickInstruction::Field(
+                Arc::downgrade(object.class()),
+                offset,
+            ));
+            Ok(object.field_at_offset(offset)?)
+        }
+    }
+}
+
+/// Get the name of the field referenced by the constant pool entry at the index.
+#[inline]
+fn field_name(frame: &Frame, index: u16) -> Result<&str> {
+    let constant_pool = frame.class().constant_pool();
+    let (_class_index, name_and_type_index) = constant_pool.try_get_field_ref(index)?;
+    let (name_index, _descriptor_index) =
+        constant_pool.try_get_name_and_type(*name_and_type_index)?;
+    let field_name = constant_pool.try_get_utf8(*name_index)?;
+    Ok(field_name)
+}
+
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.getfield>
 #[inline]
 pub(crate) fn getfield(
+    frame: &Frame,
     stack: &mut OperandStack,
-    class: &Arc<Class>,
     index: u16,
 ) -> Result<ExecutionResult> {
     let value = stack.pop()?;
     match value {
         Value::Object(Some(Reference::Object(object))) => {
-            let constant_pool = class.constant_pool();
-            let (_class_index, name_and_type_index) = constant_pool.try_get_field_ref(index)?;
-            let (name_index, _descriptor_index) =
-                constant_pool.try_get_name_and_type(*name_and_type_index)?;
-            let field_name = constant_pool.try_get_utf8(*name_index)?;
-            let value = object.value(field_name)?;
+            let value = field(frame, &object, index)?.value()?;
             stack.push(value)?;
             Ok(Continue)
         }
@@ -35,20 +65,15 @@ pub(crate) fn getfield(
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.putfield>
 #[inline]
 pub(crate) fn putfield(
+    frame: &Frame,
     stack: &mut OperandStack,
-    class: &Arc<Class>,
     index: u16,
 ) -> Result<ExecutionResult> {
     let value = stack.pop()?;
-    let mut object_value = stack.pop()?;
+    let object_value = stack.pop()?;
     match object_value {
-        Value::Object(Some(Reference::Object(ref mut object))) => {
-            let constant_pool = class.constant_pool();
-            let (_class_index, name_and_type_index) = constant_pool.try_get_field_ref(index)?;
-            let (name_index, _descriptor_index) =
-                constant_pool.try_get_name_and_type(*name_and_type_index)?;
-            let field_name = constant_pool.try_get_utf8(*name_index)?;
-            object.set_value(field_name, value)?;
+        Value::Object(Some(Reference::Object(ref object))) => {
+            field(frame, object, index)?.set_value(value)?;
             Ok(Continue)
         }
         _ => Err(InvalidStackValue {
@@ -61,10 +86,10 @@ pub(crate) fn putfield(
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::frame::Frame;
     use crate::instruction::{dup, new};
     use crate::thread::Thread;
     use crate::VM;
+    use ristretto_classfile::attributes::Instruction;
     use ristretto_classloader::Value;
     use std::sync::Arc;
 
@@ -85,7 +110,6 @@ mod test {
         let (_vm, _thread, frame, class_index, field_index) =
             test_class_field("Child", "zero", "I").await?;
         let stack = &mut OperandStack::with_max_size(4);
-        let class = frame.class();
         let result = new(&frame, stack, class_index).await?;
         assert_eq!(Continue, result);
 
@@ -96,10 +120,10 @@ mod test {
         assert_eq!(Continue, result);
 
         stack.push_int(42)?;
-        let result = putfield(stack, class, field_index)?;
+        let result = putfield(&frame, stack, field_index)?;
         assert_eq!(Continue, result);
 
-        let result = getfield(stack, class, field_index)?;
+        let result = getfield(&frame, stack, field_index)?;
         assert_eq!(Continue, result);
         let value = stack.pop()?;
         assert_eq!(Value::Int(42), value);
@@ -111,6 +135,100 @@ mod test {
         test_put_and_get_field().await
     }
 
+    #[tokio::test]
+    async fn test_getfield_quickened() -> Result<()> {
+        let (_vm, thread, mut frame) =
+            crate::test::frame_with_code(vec![Instruction::Getfield(0)]).await?;
+        let class = Arc::get_mut(frame.class_mut()).expect("class");
+        let constant_pool = class.constant_pool_mut();
+        let class_index = constant_pool.add_class("GrandParent")?;
+        let field_index = constant_pool.add_field_ref(class_index, "zero", "I")?;
+        let grand_parent_class = thread.class("GrandParent").await?;
+        let grand_parent = Object::new(grand_parent_class.clone())?;
+        grand_parent.set_value("zero", Value::Int(1))?;
+        let stack = &mut OperandStack::with_max_size(1);
+
+        stack.push(Value::from(grand_parent.clone()))?;
+        let result = getfield(&frame, stack, field_index)?;
+        assert_eq!(Continue, result);
+        assert_eq!(Value::Int(1), stack.pop()?);
+        let offset = grand_parent.field_offset("zero")?;
+        assert_eq!(
+            Some(&QuickInstruction::Field(
+                Arc::downgrade(&grand_parent_class),
+                offset
+            )),
+            frame.quickened()
+        );
+
+        // The quickened offset is used for objects of the class it was resolved for
+        let other_grand_parent = Object::new(grand_parent_class.clone())?;
+        other_grand_parent.set_value("zero", Value::Int(2))?;
+        stack.push(Value::from(other_grand_parent))?;
+        getfield(&frame, stack, field_index)?;
+        assert_eq!(Value::Int(2), stack.pop()?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_getfield_quickened_for_subclass_with_different_offset() -> Result<()> {
+        let (_vm, thread, mut frame) =
+            crate::test::frame_with_code(vec![Instruction::Getfield(0)]).await?;
+        let class = Arc::get_mut(frame.class_mut()).expect("class");
+        let constant_pool = class.constant_pool_mut();
+        let class_index = constant_pool.add_class("GrandParent")?;
+        let field_index = constant_pool.add_field_ref(class_index, "zero", "I")?;
+        let grand_parent = Object::new(thread.class("GrandParent").await?)?;
+        grand_parent.set_value("zero", Value::Int(1))?;
+        let child = Object::new(thread.class("Child").await?)?;
+        child.set_value("three", Value::Int(300))?;
+        child.set_value("zero", Value::Int(3))?;
+        let offset = grand_parent.field_offset("zero")?;
+        assert_ne!(offset, child.field_offset("zero")?);
+        let stack = &mut OperandStack::with_max_size(1);
+
+        stack.push(Value::from(grand_parent))?;
+        getfield(&frame, stack, field_index)?;
+        assert_eq!(Value::Int(1), stack.pop()?);
+
+        // The field of the subclass is resolved by name instead of using the quickened offset
+        stack.push(Value::from(child))?;
+        let result = getfield(&frame, stack, field_index)?;
+        assert_eq!(Continue, result);
+        assert_eq!(Value::Int(3), stack.pop()?);
+        assert!(matches!(
+            frame.quickened(),
+            Some(QuickInstruction::Field(_, quickened_offset)) if *quickened_offset == offset
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_putfield_quickened_for_subclass_with_different_offset() -> Result<()> {
+        let (_vm, thread, mut frame) =
+            crate::test::frame_with_code(vec![Instruction::Putfield(0)]).await?;
+        let class = Arc::get_mut(frame.class_mut()).expect("class");
+        let constant_pool = class.constant_pool_mut();
+        let class_index = constant_pool.add_class("GrandParent")?;
+        let field_index = constant_pool.add_field_ref(class_index, "zero", "I")?;
+        let grand_parent = Object::new(thread.class("GrandParent").await?)?;
+        let child = Object::new(thread.class("Child").await?)?;
+        let stack = &mut OperandStack::with_max_size(2);
+
+        stack.push(Value::from(grand_parent.clone()))?;
+        stack.push_int(1)?;
+        putfield(&frame, stack, field_index)?;
+        assert_eq!(Value::Int(1), grand_parent.value("zero")?);
+
+        stack.push(Value::from(child.clone()))?;
+        stack.push_int(3)?;
+        let result = putfield(&frame, stack, field_index)?;
+        assert_eq!(Continue, result);
+        assert_eq!(Value::Int(3), child.value("zero")?);
+        assert_eq!(Value::Int(0), child.value("three")?);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_getfield_field_not_found() -> Result<()> {
         let (_vm, _thread, frame, class_index, field_index) =
@@ -118,8 +236,7 @@ mod test {
         let stack = &mut OperandStack::with_max_size(1);
         let result = new(&frame, stack, class_index).await?;
         assert_eq!(Continue, result);
-        let class = frame.class();
-        let result = getfield(stack, class, field_index);
+        let result = getfield(&frame, stack, field_index);
         assert!(result.is_err());
         Ok(())
     }
@@ -128,9 +245,8 @@ mod test {
     async fn test_getfield_invalid_value() -> Result<()> {
         let (_vm, _thread, frame) = crate::test::frame().await?;
         let stack = &mut OperandStack::with_max_size(2);
-        let class = frame.class();
         stack.push_object(None)?;
-        let result = getfield(stack, class, 0);
+        let result = getfield(&frame, stack, 0);
         assert!(matches!(result, Err(InvalidStackValue {
             expected,
             actual
@@ -149,13 +265,12 @@ mod test {
         let (_vm, _thread, frame, class_index, field_index) =
             test_class_field("Child", "foo", "I").await?;
         let stack = &mut OperandStack::with_max_size(3);
-        let class = frame.class();
         let result = new(&frame, stack, class_index).await?;
         assert_eq!(Continue, result);
         let result = dup(stack)?;
         assert_eq!(Continue, result);
         stack.push_int(42)?;
-        let result = putfield(stack, class, field_index);
+        let result = putfield(&frame, stack, field_index);
         assert!(result.is_err());
         Ok(())
     }
@@ -164,10 +279,9 @@ mod test {
     async fn test_putfield_invalid_value() -> Result<()> {
         let (_vm, _thread, frame) = crate::test::frame().await?;
         let stack = &mut OperandStack::with_max_size(2);
-        let class = frame.class();
         stack.push_object(None)?;
         stack.push_int(42)?;
-        let result = putfield(stack, class, 0);
+        let result = putfield(&frame, stack, 0);
         assert!(matches!(result, Err(InvalidStackValue {
             expected,
             actual
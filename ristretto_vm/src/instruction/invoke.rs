@@ -7,7 +7,7 @@ use crate::{Error, Result};
 use ristretto_classfile::Constant;
 use ristretto_classfile::Error::InvalidConstantPoolIndexType;
 use ristretto_classloader::Error::MethodNotFound;
-use ristretto_classloader::{Class, Method, Reference, Value};
+use ristretto_classloader::{Class, ClassState, Method, QuickInstruction, Reference, Value};
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -18,6 +18,28 @@ enum InvocationType {
     Virtual,
 }
 
+/// Get the class and method of the quickened instruction at the program counter of the frame, if
+/// the instruction has been quickened and the class and method have not been dropped.
+#[inline]
+fn quickened(frame: &Frame) -> Option<(Arc<Class>, Arc<Method>)> {
+    match frame.quickened() {
+        Some(QuickInstruction::Invoke(class, method)) => {
+            Some((class.upgrade()?, method.upgrade()?))
+        }
+        _ => None,
+    }
+}
+
+/// Quicken the instruction at the program counter of the frame with the resolved class and
+/// method.
+#[inline]
+fn quicken(frame: &Frame, class: &Arc<Class>, method: &Arc<Method>) {
+    frame.quicken(QuickInstruction::Invoke(
+        Arc::downgrade(class),
+        Arc::downgrade(method),
+    ));
+}
+
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.invokevirtual>
 #[inline]
 pub(crate) async fn invokevirtual(
@@ -26,6 +48,9 @@ pub(crate) async fn invokevirtual(
     method_index: u16,
 ) -> Result<ExecutionResult> {
     let thread = frame.thread()?;
+    if let Some((class, method)) = quickened(frame) {
+        return invoke_method(&thread, stack, class, method, &InvocationType::Virtual).await;
+    }
     let constant_pool = frame.class().constant_pool();
     let (class_index, name_and_type_index) = constant_pool.try_get_method_ref(method_index)?;
     let class_name = constant_pool.try_get_class(*class_index)?;
@@ -35,6 +60,7 @@ pub(crate) async fn invokevirtual(
     let method_name = constant_pool.try_get_utf8(*name_index)?;
     let method_descriptor = constant_pool.try_get_utf8(*descriptor_index)?;
     let method = try_get_virtual_method(&class, method_name, method_descriptor)?;
+    quicken(frame, &class, &method);
 
     invoke_method(&thread, stack, class, method, &InvocationType::Virtual).await
 }
@@ -87,6 +113,9 @@ pub(crate) async fn invokespecial(
     method_index: u16,
 ) -> Result<ExecutionResult> {
     let thread = frame.thread()?;
+    if let Some((class, method)) = quickened(frame) {
+        return invoke_method(&thread, stack, class, method, &InvocationType::Special).await;
+    }
     let constant_pool = frame.class().constant_pool();
     let (class_index, name_and_type_index) = constant_pool.try_get_method_ref(method_index)?;
     let class_name = constant_pool.try_get_class(*class_index)?;
@@ -96,6 +125,7 @@ pub(crate) async fn invokespecial(
     let method_name = constant_pool.try_get_utf8(*name_index)?;
     let method_descriptor = constant_pool.try_get_utf8(*descriptor_index)?;
     let (method_class, method) = try_get_special_method(&class, method_name, method_descriptor)?;
+    quicken(frame, &method_class, &method);
 
     invoke_method(
         &thread,
@@ -149,6 +179,9 @@ pub(crate) async fn invokestatic(
     method_index: u16,
 ) -> Result<ExecutionResult> {
     let thread = frame.thread()?;
+    if let Some((class, method)) = quickened(frame) {
+        return invoke_method(&thread, stack, class, method, &InvocationType::Static).await;
+    }
     let constant_pool = frame.class().constant_pool();
     let constant = constant_pool.try_get(method_index)?;
     let (Constant::MethodRef {
@@ -169,6 +202,11 @@ pub(crate) async fn invokestatic(
     let method_name = constant_pool.try_get_utf8(*name_index)?;
     let method_descriptor = constant_pool.try_get_utf8(*descriptor_index)?;
     let method = class.try_get_method(method_name, method_descriptor)?;
+    // invokestatic triggers initialization of the class, so the instruction is only quickened once
+    // the class has been initialized.
+    if class.state()? == ClassState::Initialized {
+        quicken(frame, &class, &method);
+    }
 
     invoke_method(&thread, stack, class, method, &InvocationType::Static).await
 }
@@ -182,6 +220,9 @@ pub(crate) async fn invokeinterface(
     _count: u8,
 ) -> Result<ExecutionResult> {
     let thread = frame.thread()?;
+    if let Some((class, method)) = quickened(frame) {
+        return invoke_method(&thread, stack, class, method, &InvocationType::Interface).await;
+    }
     let constant_pool = frame.class().constant_pool();
     let (class_index, name_and_type_index) =
         constant_pool.try_get_interface_method_ref(method_index)?;
@@ -192,6 +233,7 @@ pub(crate) async fn invokeinterface(
     let method_name = constant_pool.try_get_utf8(*name_index)?;
     let method_descriptor = constant_pool.try_get_utf8(*descriptor_index)?;
     let method = try_get_virtual_method(&class, method_name, method_descriptor)?;
+    quicken(frame, &class, &method);
 
     invoke_method(&thread, stack, class, method, &InvocationType::Interface).await
 }
@@ -275,6 +317,8 @@ mod test {
     use super::*;
     use crate::Error::ClassLoaderError;
     use crate::VM;
+    use ristretto_classfile::attributes::Instruction;
+    use ristretto_classfile::{ClassFileBuilder, MethodAccessFlags, Version};
 
     #[tokio::test]
     async fn test_try_get_virtual_method_hierarchy() -> Result<()> {
@@ -337,4 +381,130 @@ mod test {
         ));
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_invokevirtual_quickened_with_receiver_of_another_class() -> Result<()> {
+        let (_vm, thread, mut frame) =
+            crate::test::frame_with_code(vec![Instruction::Invokevirtual(0)]).await?;
+        let class = Arc::get_mut(frame.class_mut()).expect("class");
+        let constant_pool = class.constant_pool_mut();
+        let class_index = constant_pool.add_class("java/lang/Number")?;
+        let method_index = constant_pool.add_method_ref(class_index, "intValue", "()I")?;
+        let integer = thread
+            .object("java.lang.Integer", "I", vec![Value::Int(42)])
+            .await?;
+        let long = thread
+            .object("java.lang.Long", "J", vec![Value::Long(7)])
+            .await?;
+        let stack = &mut OperandStack::with_max_size(1);
+
+        stack.push(integer)?;
+        let result = invokevirtual(&frame, stack, method_index).await?;
+        assert_eq!(Continue, result);
+        assert_eq!(Value::Int(42), stack.pop()?);
+        let number = thread.class("java.lang.Number").await?;
+        let int_value = number.try_get_method("intValue", "()I")?;
+        assert_eq!(
+            Some(&QuickInstruction::Invoke(
+                Arc::downgrade(&number),
+                Arc::downgrade(&int_value)
+            )),
+            frame.quickened()
+        );
+
+        // The quickened method is still selected using the class of the receiver
+        stack.push(long)?;
+        let result = invokevirtual(&frame, stack, method_index).await?;
+        assert_eq!(Continue, result);
+        assert_eq!(Value::Int(7), stack.pop()?);
+        Ok(())
+    }
+
+    /// Class with a static `value` method that returns the given value; the class initializer
+    /// invokes the method while the class is being initialized
+    fn value_class(name: &str, value: i8) -> Result<Arc<Class>> {
+        let mut builder = ClassFileBuilder::new(name)
+            .java_version(Version::Java8 { minor: 0 })
+            .public();
+        let constant_pool = builder.constant_pool();
+        let class_index = constant_pool.add_class(name)?;
+        let value_method = constant_pool.add_method_ref(class_index, "value", "()I")?;
+        let class_file = builder
+            .method(
+                MethodAccessFlags::STATIC,
+                "<clinit>",
+                "()V",
+                vec![
+                    Instruction::Invokestatic(value_method),
+                    Instruction::Pop,
+                    Instruction::Return,
+                ],
+            )
+            .method(
+                MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                "value",
+                "()I",
+                vec![Instruction::Bipush(value), Instruction::Ireturn],
+            )
+            .method(
+                MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                "invokeValue",
+                "()I",
+                vec![
+                    Instruction::Invokestatic(value_method),
+                    Instruction::Ireturn,
+                ],
+            )
+            .build()?;
+        Ok(Arc::new(Class::from(class_file)?))
+    }
+
+    #[tokio::test]
+    async fn test_invokestatic_not_quickened_while_initializing() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let class = thread.define_class(value_class("Value", 42)?).await?;
+        assert_eq!(ClassState::Initialized, class.state()?);
+        let class_initializer = class.class_initializer().expect("class initializer");
+        assert_eq!(0, class_initializer.quickened().count());
+
+        let method = class.try_get_method("invokeValue", "()I")?;
+        let result = thread.execute(&class, &method, Vec::<Value>::new()).await?;
+        assert_eq!(Some(Value::Int(42)), result);
+        assert_eq!(1, method.quickened().count());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invokestatic_quickened_class_replaced() -> Result<()> {
+        let (vm, thread, mut frame) =
+            crate::test::frame_with_code(vec![Instruction::Invokestatic(0)]).await?;
+        let class = Arc::get_mut(frame.class_mut()).expect("class");
+        let constant_pool = class.constant_pool_mut();
+        let class_index = constant_pool.add_class("Value")?;
+        let method_index = constant_pool.add_method_ref(class_index, "value", "()I")?;
+        thread.register_class(value_class("Value", 1)?).await?;
+        let stack = &mut OperandStack::with_max_size(1);
+
+        invokestatic(&frame, stack, method_index).await?;
+        assert_eq!(Value::Int(1), stack.pop()?);
+        assert!(frame.quickened().is_some());
+
+        // Replace the class; the quickened instruction no longer refers to a live class, so the
+        // instruction is resolved again
+        let class_loader_lock = vm.class_loader();
+        let class_loader = class_loader_lock.read().await;
+        class_loader.invalidate(&["Value"]).await;
+        class_loader.register(value_class("Value", 2)?).await?;
+        drop(class_loader);
+        assert!(matches!(
+            frame.quickened(),
+            Some(QuickInstruction::Invoke(class, method))
+                if class.upgrade().is_none() && method.upgrade().is_none()
+        ));
+
+        let result = invokestatic(&frame, stack, method_index).await?;
+        assert_eq!(Continue, result);
+        assert_eq!(Value::Int(2), stack.pop()?);
+        Ok(())
+    }
 }
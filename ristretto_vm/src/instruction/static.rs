@@ -3,6 +3,28 @@ use crate::frame::{ExecutionResult, Frame};
 use crate::operand_stack::OperandStack;
 use crate::Result;
 use ristretto_classfile::FieldType;
+use ristretto_classloader::{Class, ClassState, Field, QuickInstruction};
+use std::sync::Arc;
+
+/// Quicken the instruction at the program counter of the frame with the resolved static field.
+/// Static field instructions trigger initialization of the class that declares the field, so the
+/// instruction is only quickened once the class has been initialized.
+fn quicken(frame: &Frame, class: &Class, field: &Arc<Field>) -> Result<()> {
+    if class.state()? == ClassState::Initialized {
+        frame.quicken(QuickInstruction::StaticField(Arc::downgrade(field)));
+    }
+    Ok(())
+}
+
+/// Get the static field of the quickened instruction at the program counter of the frame, if the
+/// instruction has been quickened and the field has not been dropped.
+#[inline]
+fn quickened(frame: &Frame) -> Option<Arc<Field>> {
+    match frame.quickened() {
+        Some(QuickInstruction::StaticField(field)) => field.upgrade(),
+        _ => None,
+    }
+}
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.getstatic>
 #[inline]
@@ -11,6 +33,11 @@ pub(crate) async fn getstatic(
     stack: &mut OperandStack,
     index: u16,
 ) -> Result<ExecutionResult> {
+    if let Some(field) = quickened(frame) {
+        stack.push(field.value()?)?;
+        return Ok(Continue);
+    }
+
     let thread = frame.thread()?;
     let constant_pool = frame.class().constant_pool();
     let (class_index, name_and_type_index) = constant_pool.try_get_field_ref(index)?;
@@ -28,6 +55,7 @@ pub(crate) async fn getstatic(
         // https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-5.html#jvms-5.4.3
         thread.class(class_name).await?;
     }
+    quicken(frame, &class, &field)?;
     Ok(Continue)
 }
 
@@ -38,6 +66,11 @@ pub(crate) async fn putstatic(
     stack: &mut OperandStack,
     index: u16,
 ) -> Result<ExecutionResult> {
+    if let Some(field) = quickened(frame) {
+        field.set_value(stack.pop()?)?;
+        return Ok(Continue);
+    }
+
     let thread = frame.thread()?;
     let constant_pool = frame.class().constant_pool();
     let (class_index, name_and_type_index) = constant_pool.try_get_field_ref(index)?;
@@ -55,6 +88,7 @@ pub(crate) async fn putstatic(
         // https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-5.html#jvms-5.4.3
         thread.class(class_name).await?;
     }
+    quicken(frame, &class, &field)?;
     Ok(Continue)
 }
 
@@ -65,15 +99,27 @@ mod test {
     use crate::operand_stack::OperandStack;
     use crate::thread::Thread;
     use crate::VM;
+    use ristretto_classfile::attributes::Instruction;
+    use ristretto_classfile::{
+        BaseType, ClassFileBuilder, FieldAccessFlags, MethodAccessFlags, Version,
+    };
     use ristretto_classloader::Value;
-    use std::sync::Arc;
 
     async fn test_class_field(
         class_name: &str,
         field_name: &str,
         field_type: &str,
     ) -> Result<(Arc<VM>, Arc<Thread>, Frame, u16, u16)> {
-        let (vm, thread, mut frame) = crate::test::frame().await?;
+        test_class_field_with_code(class_name, field_name, field_type, Vec::new()).await
+    }
+
+    async fn test_class_field_with_code(
+        class_name: &str,
+        field_name: &str,
+        field_type: &str,
+        code: Vec<Instruction>,
+    ) -> Result<(Arc<VM>, Arc<Thread>, Frame, u16, u16)> {
+        let (vm, thread, mut frame) = crate::test::frame_with_code(code).await?;
         let class = frame.class_mut();
         let constant_pool = Arc::get_mut(class).expect("class").constant_pool_mut();
         let class_index = constant_pool.add_class(class_name)?;
@@ -119,6 +165,82 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_putstatic_quickened() -> Result<()> {
+        let (_vm, thread, frame, _class_index, field_index) =
+            test_class_field_with_code("Simple", "ANSWER", "I", vec![Instruction::Putstatic(0)])
+                .await?;
+        let stack = &mut OperandStack::with_max_size(1);
+        stack.push_int(3)?;
+        let result = putstatic(&frame, stack, field_index).await?;
+        assert_eq!(Continue, result);
+        let field = thread.class("Simple").await?.static_field("ANSWER")?;
+        assert_eq!(
+            Some(&QuickInstruction::StaticField(Arc::downgrade(&field))),
+            frame.quickened()
+        );
+
+        // The quickened instruction sets the resolved field without reading the constant pool, so
+        // the index of the instruction is not used
+        stack.push_int(7)?;
+        let result = putstatic(&frame, stack, 0).await?;
+        assert_eq!(Continue, result);
+        assert_eq!(Value::Int(7), field.value()?);
+        Ok(())
+    }
+
+    /// Class whose initializer writes and reads its own static field while the class is being
+    /// initialized
+    fn initializing_class() -> Result<Arc<Class>> {
+        let mut builder = ClassFileBuilder::new("Initializing")
+            .java_version(Version::Java8 { minor: 0 })
+            .public();
+        let constant_pool = builder.constant_pool();
+        let class_index = constant_pool.add_class("Initializing")?;
+        let value = constant_pool.add_field_ref(class_index, "VALUE", "I")?;
+        let class_file = builder
+            .field(
+                FieldAccessFlags::PUBLIC | FieldAccessFlags::STATIC,
+                "VALUE",
+                FieldType::Base(BaseType::Int),
+            )
+            .method(
+                MethodAccessFlags::STATIC,
+                "<clinit>",
+                "()V",
+                vec![
+                    Instruction::Bipush(42),
+                    Instruction::Putstatic(value),
+                    Instruction::Getstatic(value),
+                    Instruction::Pop,
+                    Instruction::Return,
+                ],
+            )
+            .method(
+                MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                "value",
+                "()I",
+                vec![Instruction::Getstatic(value), Instruction::Ireturn],
+            )
+            .build()?;
+        Ok(Arc::new(Class::from(class_file)?))
+    }
+
+    #[tokio::test]
+    async fn test_static_field_not_quickened_while_initializing() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let class = thread.define_class(initializing_class()?).await?;
+        assert_eq!(ClassState::Initialized, class.state()?);
+        let class_initializer = class.class_initializer().expect("class initializer");
+        assert_eq!(0, class_initializer.quickened().count());
+
+        let method = class.try_get_method("value", "()I")?;
+        let result = thread.execute(&class, &method, Vec::<Value>::new()).await?;
+        assert_eq!(Some(Value::Int(42)), result);
+        assert_eq!(1, method.quickened().count());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_putstatic_field_not_found() -> Result<()> {
         let (_vm, _thread, frame, _class_index, field_index) =
@@ -16,6 +16,10 @@ pub enum JavaError {
         source_class_name: String,
         target_class_name: String,
     },
+    /// `ClassCircularityError`
+    /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/ClassCircularityError.html>
+    #[error("{0}")]
+    ClassCircularityError(String),
     /// `ClassFormatError`
     /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/ClassFormatError.html>
     #[error("{0}")]
@@ -28,10 +32,26 @@ pub enum JavaError {
     /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/IllegalArgumentException.html>
     #[error("{0}")]
     IllegalArgumentException(String),
+    /// `IllegalAccessException`
+    /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/IllegalAccessException.html>
+    #[error("{0}")]
+    IllegalAccessException(String),
+    /// `IllegalCallerException`
+    /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/IllegalCallerException.html>
+    #[error("{0}")]
+    IllegalCallerException(String),
+    /// `InaccessibleObjectException`
+    /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/reflect/InaccessibleObjectException.html>
+    #[error("{0}")]
+    InaccessibleObjectException(String),
     /// `IndexOutOfBoundsException`
     /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/IndexOutOfBoundsException.html>
     #[error("Index: {index}, Size {size}")]
     IndexOutOfBoundsException { index: i32, size: i32 },
+    /// `LinkageError`
+    /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/LinkageError.html>
+    #[error("{0}")]
+    LinkageError(String),
     /// `NoClassDefFoundError`
     /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/NoClassDefFoundError.html>
     #[error("{0}")]
@@ -40,6 +60,18 @@ pub enum JavaError {
     /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/NullPointerException.html>
     #[error("{0}")]
     NullPointerException(String),
+    /// `UnsatisfiedLinkError` for a native method that is not linked to an implementation
+    /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/UnsatisfiedLinkError.html>
+    #[error("{}.{method_name}{method_descriptor}", class_name.replace('/', "."))]
+    UnsatisfiedLinkError {
+        class_name: String,
+        method_name: String,
+        method_descriptor: String,
+    },
+    /// `UnsupportedOperationException`
+    /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/UnsupportedOperationException.html>
+    #[error("{0}")]
+    UnsupportedOperationException(String),
 }
 
 impl JavaError {
@@ -52,12 +84,23 @@ impl JavaError {
             }
             JavaError::ArithmeticException(_) => "java.lang.ArithmeticException",
             JavaError::ClassCastException { .. } => "java.lang.ClassCastException",
+            JavaError::ClassCircularityError(_) => "java.lang.ClassCircularityError",
             JavaError::ClassFormatError(_) => "java.lang.ClassFormatError",
             JavaError::ClassNotFoundException(_) => "java.lang.ClassNotFoundException",
             JavaError::IllegalArgumentException(_) => "java.lang.IllegalArgumentException",
+            JavaError::IllegalAccessException(_) => "java.lang.IllegalAccessException",
+            JavaError::IllegalCallerException(_) => "java.lang.IllegalCallerException",
+            JavaError::InaccessibleObjectException(_) => {
+                "java.lang.reflect.InaccessibleObjectException"
+            }
             JavaError::IndexOutOfBoundsException { .. } => "java.lang.IndexOutOfBoundsException",
+            JavaError::LinkageError(_) => "java.lang.LinkageError",
             JavaError::NoClassDefFoundError(_) => "java.lang.NoClassDefFoundError",
             JavaError::NullPointerException(_) => "java.lang.NullPointerException",
+            JavaError::UnsatisfiedLinkError { .. } => "java.lang.UnsatisfiedLinkError",
+            JavaError::UnsupportedOperationException(_) => {
+                "java.lang.UnsupportedOperationException"
+            }
         }
     }
 
@@ -105,6 +148,13 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_class_circularity_error() {
+        let error = JavaError::ClassCircularityError("Foo".to_string());
+        assert_eq!(error.class_name(), "java.lang.ClassCircularityError");
+        assert_eq!(error.message(), "Foo");
+    }
+
     #[test]
     fn test_class_format_error() {
         let error = JavaError::ClassFormatError("invalid class format".to_string());
@@ -126,6 +176,30 @@ mod tests {
         assert_eq!(error.message(), "invalid argument");
     }
 
+    #[test]
+    fn test_illegal_access_exception() {
+        let error = JavaError::IllegalAccessException("access denied".to_string());
+        assert_eq!(error.class_name(), "java.lang.IllegalAccessException");
+        assert_eq!(error.message(), "access denied");
+    }
+
+    #[test]
+    fn test_illegal_caller_exception() {
+        let error = JavaError::IllegalCallerException("unnamed module".to_string());
+        assert_eq!(error.class_name(), "java.lang.IllegalCallerException");
+        assert_eq!(error.message(), "unnamed module");
+    }
+
+    #[test]
+    fn test_inaccessible_object_exception() {
+        let error = JavaError::InaccessibleObjectException("not opened".to_string());
+        assert_eq!(
+            error.class_name(),
+            "java.lang.reflect.InaccessibleObjectException"
+        );
+        assert_eq!(error.message(), "not opened");
+    }
+
     #[test]
     fn test_index_out_of_bounds_exception() {
         let error = JavaError::IndexOutOfBoundsException { index: 5, size: 3 };
@@ -133,6 +207,13 @@ mod tests {
         assert_eq!(error.message(), "Index: 5, Size 3");
     }
 
+    #[test]
+    fn test_linkage_error() {
+        let error = JavaError::LinkageError("duplicate class definition".to_string());
+        assert_eq!(error.class_name(), "java.lang.LinkageError");
+        assert_eq!(error.message(), "duplicate class definition");
+    }
+
     #[test]
     fn test_no_class_def_found_error() {
         let error = JavaError::NoClassDefFoundError("java.lang.String".to_string());
@@ -146,4 +227,25 @@ mod tests {
         assert_eq!(error.class_name(), "java.lang.NullPointerException");
         assert_eq!(error.message(), "null");
     }
+
+    #[test]
+    fn test_unsatisfied_link_error() {
+        let error = JavaError::UnsatisfiedLinkError {
+            class_name: "java/lang/Class".to_string(),
+            method_name: "isRecord0".to_string(),
+            method_descriptor: "()Z".to_string(),
+        };
+        assert_eq!(error.class_name(), "java.lang.UnsatisfiedLinkError");
+        assert_eq!(error.message(), "java.lang.Class.isRecord0()Z");
+    }
+
+    #[test]
+    fn test_unsupported_operation_exception() {
+        let error = JavaError::UnsupportedOperationException("foo".to_string());
+        assert_eq!(
+            error.class_name(),
+            "java.lang.UnsupportedOperationException"
+        );
+        assert_eq!(error.message(), "foo");
+    }
 }
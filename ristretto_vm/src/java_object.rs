@@ -222,10 +222,13 @@ async fn to_class_object(vm: &VM, class: &Arc<Class>) -> Result<Value> {
     let class_name = class.name().replace('/', ".");
     let name = class_name.to_object(vm).await?;
     object.set_value("name", name)?;
-    // TODO: a "null" class loader indicates a system class loader; this should be re-evaluated
-    // to support custom class loaders
+    // A "null" class loader indicates a class defined by the VM class loader
+    let class_loader = match vm.java_class_loader_object(class) {
+        Some(class_loader) => Value::from(class_loader),
+        None => Value::Object(None),
+    };
     let class_loader_field = object.field("classLoader")?;
-    class_loader_field.unsafe_set_value(Value::Object(None))?;
+    class_loader_field.unsafe_set_value(class_loader)?;
     let value = Value::from(object);
     Ok(value)
 }
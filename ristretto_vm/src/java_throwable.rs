@@ -0,0 +1,551 @@
+use crate::Result;
+use ristretto_classloader::{Object, Reference, Value};
+use std::fmt;
+
+/// A single frame of a Java stack trace.
+///
+/// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/StackTraceElement.html>
+#[derive(Clone, Debug, PartialEq)]
+pub struct StackTraceElement {
+    class_name: String,
+    method_name: String,
+    file_name: Option<String>,
+    line_number: i32,
+}
+
+impl StackTraceElement {
+    /// Create a new stack trace element.
+    #[must_use]
+    pub fn new<C, M>(
+        class_name: C,
+        method_name: M,
+        file_name: Option<String>,
+        line_number: i32,
+    ) -> Self
+    where
+        C: AsRef<str>,
+        M: AsRef<str>,
+    {
+        StackTraceElement {
+            class_name: class_name.as_ref().to_string(),
+            method_name: method_name.as_ref().to_string(),
+            file_name,
+            line_number,
+        }
+    }
+
+    /// Get the fully qualified name of the class containing the execution point.
+    #[must_use]
+    pub fn class_name(&self) -> &str {
+        &self.class_name
+    }
+
+    /// Get the name of the method containing the execution point.
+    #[must_use]
+    pub fn method_name(&self) -> &str {
+        &self.method_name
+    }
+
+    /// Get the name of the source file containing the execution point.
+    #[must_use]
+    pub fn file_name(&self) -> Option<&String> {
+        self.file_name.as_ref()
+    }
+
+    /// Get the line number of the source line containing the execution point; a value less than
+    /// or equal to zero indicates the line number is unavailable.
+    #[must_use]
+    pub fn line_number(&self) -> i32 {
+        self.line_number
+    }
+
+    /// Read a stack trace element from a `java.lang.StackTraceElement` object.
+    ///
+    /// # Errors
+    /// if the object is not a valid stack trace element
+    fn from_object(object: &Object) -> Result<Self> {
+        let class_name: String = object.value("declaringClass")?.try_into()?;
+        let method_name: String = object.value("methodName")?.try_into()?;
+        let file_name = string_value(object.value("fileName")?)?;
+        let line_number = object.value("lineNumber")?.to_int()?;
+        Ok(StackTraceElement {
+            class_name,
+            method_name,
+            file_name,
+            line_number,
+        })
+    }
+}
+
+impl fmt::Display for StackTraceElement {
+    /// Format the stack trace element the same way as `StackTraceElement.toString()`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let class_name = self.class_name.replace('/', ".");
+        write!(f, "{class_name}.{}(", self.method_name)?;
+        match (&self.file_name, self.line_number) {
+            (_, -2) => write!(f, "Native Method")?,
+            (Some(file_name), line_number) if line_number > 0 => {
+                write!(f, "{file_name}:{line_number}")?;
+            }
+            (Some(file_name), _) => write!(f, "{file_name}")?,
+            (None, _) => write!(f, "Unknown Source")?,
+        }
+        write!(f, ")")
+    }
+}
+
+/// A Rust representation of a `java.lang.Throwable` that provides structured access to the class
+/// name, message, stack trace, cause and suppressed throwables.
+///
+/// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/Throwable.html>
+#[derive(Clone, Debug, PartialEq)]
+pub struct JavaThrowable {
+    class_name: String,
+    message: Option<String>,
+    stack_trace: Vec<StackTraceElement>,
+    cause: Option<Box<JavaThrowable>>,
+    suppressed: Vec<JavaThrowable>,
+    circular_reference: bool,
+}
+
+impl JavaThrowable {
+    /// Get the fully qualified class name of the throwable (e.g. `java.lang.RuntimeException`).
+    #[must_use]
+    pub fn class_name(&self) -> &str {
+        &self.class_name
+    }
+
+    /// Get the detail message of the throwable.
+    #[must_use]
+    pub fn message(&self) -> Option<&String> {
+        self.message.as_ref()
+    }
+
+    /// Get the stack trace of the throwable.
+    #[must_use]
+    pub fn stack_trace(&self) -> &Vec<StackTraceElement> {
+        &self.stack_trace
+    }
+
+    /// Get the cause of the throwable.
+    #[must_use]
+    pub fn cause(&self) -> Option<&JavaThrowable> {
+        self.cause.as_deref()
+    }
+
+    /// Get the throwables that were suppressed in order to deliver this throwable.
+    #[must_use]
+    pub fn suppressed(&self) -> &Vec<JavaThrowable> {
+        &self.suppressed
+    }
+
+    /// Determine if this throwable is a reference to a throwable that was already encountered
+    /// while reading the enclosing cause and suppressed throwables.  Circular references only
+    /// contain the class name and message; the stack trace, cause and suppressed throwables are
+    /// available on the first occurrence.
+    #[must_use]
+    pub fn is_circular_reference(&self) -> bool {
+        self.circular_reference
+    }
+
+    /// Read a throwable, tracking the objects already visited so that circular cause or suppressed
+    /// references do not recurse forever.
+    ///
+    /// # Errors
+    /// if the object is not a valid throwable
+    fn from_object(object: &Object, visited: &mut Vec<Object>) -> Result<Self> {
+        let class_name = object.class().name().replace('/', ".");
+        let message = string_value(object.value("detailMessage")?)?;
+        if visited.iter().any(|visited| visited.ptr_eq(object)) {
+            return Ok(JavaThrowable {
+                class_name,
+                message,
+                stack_trace: Vec::new(),
+                cause: None,
+                suppressed: Vec::new(),
+                circular_reference: true,
+            });
+        }
+        visited.push(object.clone());
+        let stack_trace = Self::stack_trace_from_object(object)?;
+
+        let mut suppressed = Vec::new();
+        for suppressed_object in list_elements(&object.value("suppressedExceptions")?)? {
+            suppressed.push(Self::from_object(&suppressed_object, visited)?);
+        }
+
+        // A throwable without an initialized cause references itself
+        let cause = match object.value("cause")? {
+            Value::Object(Some(Reference::Object(cause))) if !cause.ptr_eq(object) => {
+                Some(Box::new(Self::from_object(&cause, visited)?))
+            }
+            _ => None,
+        };
+
+        Ok(JavaThrowable {
+            class_name,
+            message,
+            stack_trace,
+            cause,
+            suppressed,
+            circular_reference: false,
+        })
+    }
+
+    /// Get the stack trace formatted the same way as `Throwable.printStackTrace()`, including
+    /// suppressed throwables and causes.  When `elide_common_frames` is true, the frames an
+    /// enclosed throwable has in common with its enclosing throwable are replaced with
+    /// `... N more`; when false, every frame is included.
+    #[must_use]
+    pub fn stack_trace_string(&self, elide_common_frames: bool) -> String {
+        let mut output = String::new();
+        self.write_stack_trace(&mut output, &[], "", "", elide_common_frames);
+        output
+    }
+
+    /// Write this throwable, its suppressed throwables and its cause with the specified caption
+    /// (e.g. `Caused by: `) and line prefix.
+    ///
+    /// See: `java.lang.Throwable.printEnclosedStackTrace`
+    fn write_stack_trace(
+        &self,
+        output: &mut String,
+        enclosing_trace: &[StackTraceElement],
+        caption: &str,
+        prefix: &str,
+        elide_common_frames: bool,
+    ) {
+        if self.circular_reference {
+            output.push_str(&format!("{prefix}{caption}[CIRCULAR REFERENCE: {self}]\n"));
+            return;
+        }
+
+        let trace = &self.stack_trace;
+        let frames_in_common = if elide_common_frames {
+            trace
+                .iter()
+                .rev()
+                .zip(enclosing_trace.iter().rev())
+                .take_while(|(element, enclosing_element)| element == enclosing_element)
+                .count()
+        } else {
+            0
+        };
+
+        output.push_str(&format!("{prefix}{caption}{self}\n"));
+        for element in &trace[..trace.len() - frames_in_common] {
+            output.push_str(&format!("{prefix}\tat {element}\n"));
+        }
+        if frames_in_common != 0 {
+            output.push_str(&format!("{prefix}\t... {frames_in_common} more\n"));
+        }
+
+        let suppressed_prefix = format!("{prefix}\t");
+        for suppressed in &self.suppressed {
+            suppressed.write_stack_trace(
+                output,
+                trace,
+                "Suppressed: ",
+                &suppressed_prefix,
+                elide_common_frames,
+            );
+        }
+        if let Some(cause) = &self.cause {
+            cause.write_stack_trace(output, trace, "Caused by: ", prefix, elide_common_frames);
+        }
+    }
+
+    /// Read the stack trace of a throwable.  An explicitly assigned stack trace (e.g. via
+    /// `Throwable.setStackTrace`) takes precedence over the VM captured backtrace.
+    ///
+    /// # Errors
+    /// if the stack trace elements cannot be read
+    fn stack_trace_from_object(object: &Object) -> Result<Vec<StackTraceElement>> {
+        let mut elements = array_elements(&object.value("stackTrace")?)?;
+        if elements.is_empty() {
+            elements = array_elements(&object.value("backtrace")?)?;
+        }
+        elements
+            .iter()
+            .map(StackTraceElement::from_object)
+            .collect()
+    }
+}
+
+impl TryFrom<&Object> for JavaThrowable {
+    type Error = crate::Error;
+
+    fn try_from(object: &Object) -> Result<Self> {
+        let mut visited = Vec::new();
+        JavaThrowable::from_object(object, &mut visited)
+    }
+}
+
+impl fmt::Display for JavaThrowable {
+    /// Format the throwable the same way as `Throwable.toString()`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.message {
+            Some(message) => write!(f, "{}: {message}", self.class_name),
+            None => write!(f, "{}", self.class_name),
+        }
+    }
+}
+
+/// Convert a `java.lang.String` value to a Rust string; `null` values are returned as `None`.
+fn string_value(value: Value) -> Result<Option<String>> {
+    match value {
+        Value::Object(None) => Ok(None),
+        value => Ok(Some(value.try_into()?)),
+    }
+}
+
+/// Get the non-null object elements of an object array value.
+fn array_elements(value: &Value) -> Result<Vec<Object>> {
+    let Value::Object(Some(Reference::Array(_class, elements))) = value else {
+        return Ok(Vec::new());
+    };
+    let elements = elements
+        .to_vec()?
+        .into_iter()
+        .filter_map(|element| match element {
+            Some(Reference::Object(object)) => Some(object),
+            _ => None,
+        })
+        .collect();
+    Ok(elements)
+}
+
+/// Get the non-null object elements of a `java.util.ArrayList` value; any other list
+/// implementation (e.g. the empty list sentinel used by `Throwable`) is treated as empty.
+fn list_elements(value: &Value) -> Result<Vec<Object>> {
+    let Value::Object(Some(Reference::Object(list))) = value else {
+        return Ok(Vec::new());
+    };
+    if list.class().name() != "java/util/ArrayList" {
+        return Ok(Vec::new());
+    }
+    let size = usize::try_from(list.value("size")?.to_int()?)?;
+    let mut elements = array_elements(&list.value("elementData")?)?;
+    elements.truncate(size);
+    Ok(elements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rust_value::RustValue;
+    use crate::VM;
+    use indoc::indoc;
+
+    async fn throwable(vm: &VM, class_name: &str, message: &str) -> Result<Object> {
+        let throwable = vm
+            .object(class_name, "Ljava/lang/String;", vec![message])
+            .await?;
+        Ok(throwable.try_into()?)
+    }
+
+    fn new_throwable(
+        class_name: &str,
+        message: &str,
+        stack_trace: Vec<StackTraceElement>,
+        cause: Option<JavaThrowable>,
+    ) -> JavaThrowable {
+        JavaThrowable {
+            class_name: class_name.to_string(),
+            message: Some(message.to_string()),
+            stack_trace,
+            cause: cause.map(Box::new),
+            suppressed: Vec::new(),
+            circular_reference: false,
+        }
+    }
+
+    fn element(method_name: &str, line_number: i32) -> StackTraceElement {
+        StackTraceElement::new(
+            "Foo",
+            method_name,
+            Some("Foo.java".to_string()),
+            line_number,
+        )
+    }
+
+    #[test]
+    fn test_stack_trace_string_elides_common_frames() {
+        let cause = new_throwable(
+            "java.lang.IllegalStateException",
+            "bar",
+            vec![element("baz", 3), element("bar", 2), element("main", 1)],
+            None,
+        );
+        let throwable = new_throwable(
+            "java.lang.RuntimeException",
+            "foo",
+            vec![element("bar", 5), element("main", 1)],
+            Some(cause),
+        );
+        let expected = indoc! {"
+            java.lang.RuntimeException: foo
+            \tat Foo.bar(Foo.java:5)
+            \tat Foo.main(Foo.java:1)
+            Caused by: java.lang.IllegalStateException: bar
+            \tat Foo.baz(Foo.java:3)
+            \tat Foo.bar(Foo.java:2)
+            \t... 1 more
+        "};
+        assert_eq!(expected, throwable.stack_trace_string(true));
+    }
+
+    #[test]
+    fn test_stack_trace_string_full() {
+        let cause = new_throwable(
+            "java.lang.IllegalStateException",
+            "bar",
+            vec![element("bar", 2), element("main", 1)],
+            None,
+        );
+        let throwable = new_throwable(
+            "java.lang.RuntimeException",
+            "foo",
+            vec![element("main", 1)],
+            Some(cause),
+        );
+        let expected = indoc! {"
+            java.lang.RuntimeException: foo
+            \tat Foo.main(Foo.java:1)
+            Caused by: java.lang.IllegalStateException: bar
+            \tat Foo.bar(Foo.java:2)
+            \tat Foo.main(Foo.java:1)
+        "};
+        assert_eq!(expected, throwable.stack_trace_string(false));
+    }
+
+    #[test]
+    fn test_stack_trace_element_to_string() {
+        let element = StackTraceElement::new("Foo", "bar", Some("Foo.java".to_string()), 42);
+        assert_eq!("Foo.bar(Foo.java:42)", element.to_string());
+        let element = StackTraceElement::new("Foo", "bar", Some("Foo.java".to_string()), -1);
+        assert_eq!("Foo.bar(Foo.java)", element.to_string());
+        let element = StackTraceElement::new("Foo", "bar", None, -1);
+        assert_eq!("Foo.bar(Unknown Source)", element.to_string());
+        let element = StackTraceElement::new("java/lang/Foo", "bar", None, -2);
+        assert_eq!("java.lang.Foo.bar(Native Method)", element.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_try_from_object() -> Result<()> {
+        let vm = VM::default().await?;
+        let object = throwable(&vm, "java.lang.RuntimeException", "foo").await?;
+        let throwable = JavaThrowable::try_from(&object)?;
+        assert_eq!("java.lang.RuntimeException", throwable.class_name());
+        assert_eq!(Some(&"foo".to_string()), throwable.message());
+        assert!(throwable.cause().is_none());
+        assert!(throwable.suppressed().is_empty());
+        assert_eq!("java.lang.RuntimeException: foo", throwable.to_string());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_try_from_object_with_cause() -> Result<()> {
+        let vm = VM::default().await?;
+        let cause = throwable(&vm, "java.lang.IllegalStateException", "bar").await?;
+        let object: Object = vm
+            .object(
+                "java.lang.RuntimeException",
+                "Ljava/lang/String;Ljava/lang/Throwable;",
+                vec!["foo".to_value(), Value::from(cause)],
+            )
+            .await?
+            .try_into()?;
+        let throwable = JavaThrowable::try_from(&object)?;
+        let cause = throwable.cause().expect("cause");
+        assert_eq!("java.lang.IllegalStateException", cause.class_name());
+        assert_eq!(Some(&"bar".to_string()), cause.message());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_try_from_object_circular_cause() -> Result<()> {
+        let vm = VM::default().await?;
+        let first = throwable(&vm, "java.lang.RuntimeException", "first").await?;
+        let second = throwable(&vm, "java.lang.RuntimeException", "second").await?;
+        first.set_value("cause", Value::from(second.clone()))?;
+        second.set_value("cause", Value::from(first.clone()))?;
+        let throwable = JavaThrowable::try_from(&first)?;
+        assert!(!throwable.is_circular_reference());
+        let cause = throwable.cause().expect("cause");
+        assert_eq!(Some(&"second".to_string()), cause.message());
+        assert!(!cause.is_circular_reference());
+        let circular_cause = cause.cause().expect("circular cause");
+        assert_eq!(Some(&"first".to_string()), circular_cause.message());
+        assert!(circular_cause.is_circular_reference());
+        assert!(circular_cause.cause().is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_try_from_object_with_suppressed() -> Result<()> {
+        let vm = VM::default().await?;
+        let object = throwable(&vm, "java.lang.RuntimeException", "foo").await?;
+        let suppressed = throwable(&vm, "java.lang.IllegalStateException", "bar").await?;
+        vm.invoke(
+            "java.lang.Throwable",
+            "addSuppressed",
+            "(Ljava/lang/Throwable;)V",
+            vec![Value::from(object.clone()), Value::from(suppressed)],
+        )
+        .await?;
+        let throwable = JavaThrowable::try_from(&object)?;
+        let suppressed = throwable.suppressed();
+        assert_eq!(1, suppressed.len());
+        assert_eq!(
+            "java.lang.IllegalStateException: bar",
+            suppressed[0].to_string()
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_stack_trace_string_suppressed_circular_reference() -> Result<()> {
+        let vm = VM::default().await?;
+        let object = throwable(&vm, "java.lang.RuntimeException", "foo").await?;
+        let suppressed = throwable(&vm, "java.lang.IllegalStateException", "bar").await?;
+        let cause = throwable(&vm, "java.lang.IllegalArgumentException", "baz").await?;
+        vm.invoke(
+            "java.lang.Throwable",
+            "addSuppressed",
+            "(Ljava/lang/Throwable;)V",
+            vec![Value::from(object.clone()), Value::from(suppressed)],
+        )
+        .await?;
+        vm.invoke(
+            "java.lang.Throwable",
+            "initCause",
+            "(Ljava/lang/Throwable;)Ljava/lang/Throwable;",
+            vec![Value::from(object.clone()), Value::from(cause.clone())],
+        )
+        .await?;
+        vm.invoke(
+            "java.lang.Throwable",
+            "addSuppressed",
+            "(Ljava/lang/Throwable;)V",
+            vec![Value::from(cause), Value::from(object.clone())],
+        )
+        .await?;
+
+        let output = JavaThrowable::try_from(&object)?.stack_trace_string(false);
+        let lines = output
+            .lines()
+            .filter(|line| !line.trim_start().starts_with("at "))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "java.lang.RuntimeException: foo",
+                "\tSuppressed: java.lang.IllegalStateException: bar",
+                "Caused by: java.lang.IllegalArgumentException: baz",
+                "\tSuppressed: [CIRCULAR REFERENCE: java.lang.RuntimeException: foo]",
+            ],
+            lines
+        );
+        Ok(())
+    }
+}
@@ -35,28 +35,61 @@
 // #![deny(clippy::pedantic)]
 #![deny(clippy::unwrap_in_result)]
 #![deny(clippy::unwrap_used)]
+#[cfg(all(unix, not(target_arch = "wasm32")))]
+mod attach_listener;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod blocking;
 mod configuration;
 mod error;
+mod execution_plan;
 mod frame;
 mod instruction;
 mod java_error;
 mod java_object;
+mod java_throwable;
 mod local_variables;
+mod module_access;
+mod native_access;
 mod native_methods;
 mod operand_stack;
 mod parameters;
+mod perf_data;
+#[cfg(feature = "race-detector")]
+mod race_detector;
 mod rust_value;
+mod safepoint;
+mod scheduler;
 #[cfg(test)]
 pub(crate) mod test;
 mod thread;
 mod vm;
 
+#[cfg(all(unix, not(target_arch = "wasm32")))]
+pub use attach_listener::AttachListener;
 pub use configuration::{Configuration, ConfigurationBuilder};
 pub use error::{Error, Result};
+pub use execution_plan::{ClassLoaderPlan, ExecutionPlan, MainClassPlan};
 pub(crate) use frame::Frame;
 pub use java_error::JavaError;
+pub use java_throwable::{JavaThrowable, StackTraceElement};
 pub(crate) use local_variables::LocalVariables;
+pub use module_access::{IllegalAccess, ModuleOverride, ALL_UNNAMED};
+pub use native_access::{IllegalNativeAccess, NativeAccess, RestrictedCall};
+pub use native_methods::{MethodOverride, MethodRegistry, MethodStatistics, RustMethod};
 pub(crate) use operand_stack::OperandStack;
+pub use parameters::Parameters;
+pub use perf_data::{PerfData, PerfValue};
+#[cfg(feature = "race-detector")]
+pub use race_detector::{AccessKind, DataRace, FieldAccess, RaceDetector};
+pub use ristretto_classloader::runtime::{DownloadProgress, ProgressCallback};
 pub use ristretto_classloader::{Class, ClassPath, Reference, Value, DEFAULT_JAVA_VERSION};
-pub(crate) use thread::Thread;
+pub use rust_value::RustValue;
+pub use safepoint::{SafepointGuard, Safepoints};
+#[cfg(not(target_arch = "wasm32"))]
+pub use scheduler::TokioScheduler;
+pub use scheduler::{
+    default_scheduler, BlockingFunction, DeterministicScheduler, Scheduler, SchedulerFuture,
+    ThreadScheduler,
+};
+pub use thread::Thread;
 pub use vm::VM;
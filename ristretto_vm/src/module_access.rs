@@ -0,0 +1,489 @@
+//! Enforcement of the exports and opens of named modules on reflective access.
+//!
+//! Code in a named module may access the public members of the packages that another module
+//! exports to it, and may use deep reflection (e.g. `setAccessible(true)`) on the packages that
+//! another module opens to it.  Code on the class path is in the unnamed module, which is only
+//! granted unqualified exports and opens.  `--add-exports` and `--add-opens` overrides grant
+//! additional access, with `ALL-UNNAMED` naming the unnamed module.  Access by the unnamed module
+//! that would otherwise be denied is governed by the `--illegal-access` mode.
+
+use crate::Error::InternalError;
+use crate::{Configuration, Result};
+use ristretto_classloader::{package_name, Module, ModuleGraph};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::warn;
+
+/// Module name that `--add-exports` and `--add-opens` targets use for the unnamed module.
+pub const ALL_UNNAMED: &str = "ALL-UNNAMED";
+
+/// Handling of reflective access by the unnamed module to packages that are not exported or
+/// opened to it; see `--illegal-access`.  The default depends on the Java version; see
+/// [`IllegalAccess::default_for`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IllegalAccess {
+    /// Allow the access and log a warning for the first illegal access.  Unlike the JDK, which
+    /// only opens the packages of the runtime image that existed in JDK 8, access to any package
+    /// is allowed.
+    Permit,
+    /// Allow the access and log a warning for each illegal access
+    Warn,
+    /// Deny the access
+    Deny,
+}
+
+impl IllegalAccess {
+    /// Get the default mode for a Java major version; Java 9 through 15 permit illegal access,
+    /// while Java 16 denies it by default (JEP 396) and later versions always deny it.  In the
+    /// JDK, the permitted access is limited to the packages that existed in JDK 8.
+    #[must_use]
+    pub fn default_for(java_major_version: u16) -> Self {
+        if (9..=15).contains(&java_major_version) {
+            IllegalAccess::Permit
+        } else {
+            IllegalAccess::Deny
+        }
+    }
+}
+
+impl FromStr for IllegalAccess {
+    type Err = crate::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "permit" => Ok(IllegalAccess::Permit),
+            "warn" => Ok(IllegalAccess::Warn),
+            "deny" => Ok(IllegalAccess::Deny),
+            _ => Err(InternalError(format!(
+                "Invalid illegal access mode: {value}; expected permit, warn or deny"
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for IllegalAccess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IllegalAccess::Permit => write!(f, "permit"),
+            IllegalAccess::Warn => write!(f, "warn"),
+            IllegalAccess::Deny => write!(f, "deny"),
+        }
+    }
+}
+
+/// An `--add-exports` or `--add-opens` override, e.g. `java.base/java.lang=ALL-UNNAMED`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModuleOverride {
+    module: String,
+    package: String,
+    targets: Vec<String>,
+}
+
+impl ModuleOverride {
+    /// Create an override of a package of a module for the target modules.
+    #[must_use]
+    pub fn new<M, P, T>(module: M, package: P, targets: &[T]) -> Self
+    where
+        M: AsRef<str>,
+        P: AsRef<str>,
+        T: AsRef<str>,
+    {
+        ModuleOverride {
+            module: module.as_ref().to_string(),
+            package: package.as_ref().replace('.', "/"),
+            targets: targets
+                .iter()
+                .map(|target| target.as_ref().to_string())
+                .collect(),
+        }
+    }
+
+    /// Get the name of the source module, e.g. `java.base`.
+    #[must_use]
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
+    /// Get the internal name of the package, e.g. `java/lang`.
+    #[must_use]
+    pub fn package(&self) -> &str {
+        &self.package
+    }
+
+    /// Get the target modules; [`ALL_UNNAMED`] is the unnamed module.
+    #[must_use]
+    pub fn targets(&self) -> &[String] {
+        &self.targets
+    }
+
+    /// Determine if the override grants access to a package of a module to a module; `None` is
+    /// the unnamed module.
+    fn grants(&self, module: &str, package: &str, to: Option<&str>) -> bool {
+        self.module == module
+            && self.package == package
+            && self.targets.iter().any(|target| {
+                Some(target.as_str()) == to || (to.is_none() && target == ALL_UNNAMED)
+            })
+    }
+}
+
+impl FromStr for ModuleOverride {
+    type Err = crate::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let invalid = || {
+            InternalError(format!(
+                "Invalid module override: {value}; expected <module>/<package>=<target>(,<target>)*"
+            ))
+        };
+        let (source, targets) = value.split_once('=').ok_or_else(invalid)?;
+        let (module, package) = source.split_once('/').ok_or_else(invalid)?;
+        let targets = targets.split(',').collect::<Vec<_>>();
+        if module.is_empty() || package.is_empty() || targets.iter().any(|target| target.is_empty())
+        {
+            return Err(invalid());
+        }
+        Ok(ModuleOverride::new(module, package, &targets))
+    }
+}
+
+impl fmt::Display for ModuleOverride {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{}={}",
+            self.module,
+            self.package.replace('/', "."),
+            self.targets.join(",")
+        )
+    }
+}
+
+/// Kind of reflective access to a class of a module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Access {
+    /// Access to public members of public classes; requires the package to be exported
+    Public,
+    /// Access to any member, e.g. with `setAccessible(true)`; requires the package to be opened
+    Deep,
+}
+
+/// Checks reflective access between modules.
+#[derive(Debug)]
+pub(crate) struct ModuleAccess {
+    graph: ModuleGraph,
+    add_exports: Vec<ModuleOverride>,
+    add_opens: Vec<ModuleOverride>,
+    illegal_access: IllegalAccess,
+    warned: AtomicBool,
+}
+
+impl ModuleAccess {
+    /// Create a module access checker for a module graph with the overrides and illegal access
+    /// mode of a configuration; if the configuration does not set the illegal access mode, the
+    /// default for the Java major version is used.
+    pub(crate) fn new(
+        graph: ModuleGraph,
+        configuration: &Configuration,
+        java_major_version: u16,
+    ) -> Self {
+        ModuleAccess {
+            graph,
+            add_exports: configuration.add_exports().to_vec(),
+            add_opens: configuration.add_opens().to_vec(),
+            illegal_access: configuration
+                .illegal_access()
+                .unwrap_or_else(|| IllegalAccess::default_for(java_major_version)),
+            warned: AtomicBool::new(false),
+        }
+    }
+
+    /// Get the name of the named module of a class; `None` is the unnamed module.
+    pub(crate) fn class_module(&self, class_name: &str) -> Option<&str> {
+        self.graph.class_module(class_name).map(Module::name)
+    }
+
+    /// Check if code in the caller class may access the target class; class names may use `.` or
+    /// `/` separators.
+    ///
+    /// # Errors
+    /// if the access is denied, with the reason in the form used by the JDK, e.g.
+    /// `module java.base does not "opens java.lang" to unnamed module`.
+    pub(crate) fn check(
+        &self,
+        caller: &str,
+        target: &str,
+        access: Access,
+    ) -> std::result::Result<(), String> {
+        let Some(target_module) = self.graph.class_module(target) else {
+            return Ok(());
+        };
+        let caller_module = self.graph.class_module(caller).map(Module::name);
+        if caller_module == Some(target_module.name()) {
+            return Ok(());
+        }
+
+        let package = package_name(target).replace('.', "/");
+        if self.is_allowed(target_module, &package, caller_module, access) {
+            return Ok(());
+        }
+
+        let directive = match access {
+            Access::Public => "exports",
+            Access::Deep => "opens",
+        };
+        let caller_name = caller_module.map_or_else(
+            || "unnamed module".to_string(),
+            |module| format!("module {module}"),
+        );
+        let reason = format!(
+            "module {} does not \"{directive} {}\" to {caller_name}",
+            target_module.name(),
+            package.replace('/', ".")
+        );
+        if caller_module.is_some() {
+            return Err(reason);
+        }
+
+        match self.illegal_access {
+            IllegalAccess::Permit => {
+                if !self.warned.swap(true, Ordering::Relaxed) {
+                    warn!("Illegal reflective access by {caller} to {target}: {reason}");
+                }
+                Ok(())
+            }
+            IllegalAccess::Warn => {
+                warn!("Illegal reflective access by {caller} to {target}: {reason}");
+                Ok(())
+            }
+            IllegalAccess::Deny => Err(reason),
+        }
+    }
+
+    /// Determine if a module or the overrides grant access to a package of the module.
+    fn is_allowed(
+        &self,
+        module: &Module,
+        package: &str,
+        caller_module: Option<&str>,
+        access: Access,
+    ) -> bool {
+        let opened = module.is_opened(package, caller_module)
+            || self
+                .add_opens
+                .iter()
+                .any(|value| value.grants(module.name(), package, caller_module));
+        match access {
+            Access::Deep => opened,
+            Access::Public => {
+                opened
+                    || module.is_exported(package, caller_module)
+                    || self
+                        .add_exports
+                        .iter()
+                        .any(|value| value.grants(module.name(), package, caller_module))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConfigurationBuilder;
+    use ristretto_classfile::attributes::{ExportsFlags, OpensFlags, RequiresFlags};
+    use ristretto_classfile::ModuleInfoBuilder;
+
+    fn module_graph() -> Result<ModuleGraph> {
+        let mut graph = ModuleGraph::default();
+        let java_base = ModuleInfoBuilder::new("java.base")
+            .exports("java/lang", ExportsFlags::empty())
+            .exports_to("jdk/internal/misc", ExportsFlags::empty(), &["java.sql"])
+            .opens_to("java/io", OpensFlags::empty(), &["java.sql"])
+            .package("java/lang")
+            .package("java/io")
+            .package("jdk/internal/misc")
+            .build()?;
+        graph.add(Module::from_class_file(&java_base)?);
+        let java_sql = ModuleInfoBuilder::new("java.sql")
+            .requires("java.base", RequiresFlags::MANDATED)
+            .exports("java/sql", ExportsFlags::empty())
+            .build()?;
+        graph.add(Module::from_class_file(&java_sql)?);
+        Ok(graph)
+    }
+
+    fn module_access(builder: ConfigurationBuilder) -> Result<ModuleAccess> {
+        let configuration = builder.build()?;
+        Ok(ModuleAccess::new(module_graph()?, &configuration, 21))
+    }
+
+    #[test]
+    fn test_illegal_access_from_str() -> Result<()> {
+        assert_eq!(IllegalAccess::Permit, "permit".parse()?);
+        assert_eq!(IllegalAccess::Warn, "warn".parse()?);
+        assert_eq!(IllegalAccess::Deny, "deny".parse()?);
+        assert!("debug".parse::<IllegalAccess>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_illegal_access_display() {
+        assert_eq!("permit", IllegalAccess::Permit.to_string());
+        assert_eq!("warn", IllegalAccess::Warn.to_string());
+        assert_eq!("deny", IllegalAccess::Deny.to_string());
+    }
+
+    #[test]
+    fn test_illegal_access_default_for() {
+        assert_eq!(IllegalAccess::Deny, IllegalAccess::default_for(8));
+        assert_eq!(IllegalAccess::Permit, IllegalAccess::default_for(9));
+        assert_eq!(IllegalAccess::Permit, IllegalAccess::default_for(11));
+        assert_eq!(IllegalAccess::Permit, IllegalAccess::default_for(15));
+        assert_eq!(IllegalAccess::Deny, IllegalAccess::default_for(16));
+        assert_eq!(IllegalAccess::Deny, IllegalAccess::default_for(17));
+        assert_eq!(IllegalAccess::Deny, IllegalAccess::default_for(21));
+    }
+
+    #[test]
+    fn test_module_override_from_str() -> Result<()> {
+        let value: ModuleOverride = "java.base/java.lang=ALL-UNNAMED,java.sql".parse()?;
+        assert_eq!("java.base", value.module());
+        assert_eq!("java/lang", value.package());
+        assert_eq!(&["ALL-UNNAMED", "java.sql"], value.targets());
+        assert_eq!(
+            "java.base/java.lang=ALL-UNNAMED,java.sql",
+            value.to_string()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_module_override_from_str_invalid() {
+        for value in [
+            "",
+            "java.base",
+            "java.base/java.lang",
+            "java.base=ALL-UNNAMED",
+            "/java.lang=ALL-UNNAMED",
+            "java.base/=ALL-UNNAMED",
+            "java.base/java.lang=",
+            "java.base/java.lang=a,,b",
+        ] {
+            assert!(value.parse::<ModuleOverride>().is_err(), "{value}");
+        }
+    }
+
+    #[test]
+    fn test_check_unnamed_target() -> Result<()> {
+        let module_access = module_access(ConfigurationBuilder::new())?;
+        assert!(module_access
+            .check("java.lang.String", "com.example.Main", Access::Deep)
+            .is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_same_module() -> Result<()> {
+        let module_access = module_access(ConfigurationBuilder::new())?;
+        assert!(module_access
+            .check("java/lang/String", "jdk/internal/misc/Unsafe", Access::Deep)
+            .is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_exported() -> Result<()> {
+        let module_access = module_access(ConfigurationBuilder::new())?;
+        assert!(module_access
+            .check("Main", "java.lang.String", Access::Public)
+            .is_ok());
+        assert_eq!(
+            Err(
+                "module java.base does not \"exports jdk.internal.misc\" to unnamed module"
+                    .to_string()
+            ),
+            module_access.check("Main", "jdk.internal.misc.Unsafe", Access::Public)
+        );
+        assert!(module_access
+            .check(
+                "java.sql.Driver",
+                "jdk.internal.misc.Unsafe",
+                Access::Public
+            )
+            .is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_opened() -> Result<()> {
+        let module_access = module_access(ConfigurationBuilder::new())?;
+        assert_eq!(
+            Err("module java.base does not \"opens java.lang\" to unnamed module".to_string()),
+            module_access.check("Main", "java.lang.String", Access::Deep)
+        );
+        assert_eq!(
+            Err("module java.base does not \"opens java.lang\" to module java.sql".to_string()),
+            module_access.check("java.sql.Driver", "java.lang.String", Access::Deep)
+        );
+        assert!(module_access
+            .check("java.sql.Driver", "java.io.File", Access::Deep)
+            .is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_overrides() -> Result<()> {
+        let module_access = module_access(
+            ConfigurationBuilder::new()
+                .add_exports("java.base/jdk.internal.misc=ALL-UNNAMED")
+                .add_opens("java.base/java.lang=java.sql"),
+        )?;
+        assert!(module_access
+            .check("Main", "jdk.internal.misc.Unsafe", Access::Public)
+            .is_ok());
+        assert!(module_access
+            .check("Main", "jdk.internal.misc.Unsafe", Access::Deep)
+            .is_err());
+        assert!(module_access
+            .check("java.sql.Driver", "java.lang.String", Access::Deep)
+            .is_ok());
+        assert!(module_access
+            .check("Main", "java.lang.String", Access::Deep)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_illegal_access_default() -> Result<()> {
+        let configuration = ConfigurationBuilder::new().build()?;
+        let module_access = ModuleAccess::new(module_graph()?, &configuration, 11);
+        assert!(module_access
+            .check("Main", "java.lang.String", Access::Deep)
+            .is_ok());
+        let module_access = ModuleAccess::new(module_graph()?, &configuration, 17);
+        assert!(module_access
+            .check("Main", "java.lang.String", Access::Deep)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_illegal_access() -> Result<()> {
+        for illegal_access in [IllegalAccess::Permit, IllegalAccess::Warn] {
+            let module_access =
+                module_access(ConfigurationBuilder::new().illegal_access(illegal_access))?;
+            assert!(module_access
+                .check("Main", "java.lang.String", Access::Deep)
+                .is_ok());
+            assert!(module_access
+                .check("Main", "java.lang.String", Access::Deep)
+                .is_ok());
+            assert!(module_access
+                .check("java.sql.Driver", "java.lang.String", Access::Deep)
+                .is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,390 @@
+//! Restrictions on native access by Java code.
+//!
+//! Loading JNI libraries, linking foreign functions and allocating memory with `sun.misc.Unsafe`
+//! are restricted operations: they can crash the VM or break the integrity of the Java heap.
+//! Following JEP 472, a restricted method may be called freely by the modules that native access
+//! is enabled for with `--enable-native-access`, where `ALL-UNNAMED` names the class path.  Calls by
+//! other modules are governed by the `--illegal-native-access` mode.  The system modules of the
+//! Java runtime always have native access.  Every restricted call is recorded, so embedders can
+//! audit which modules and classes use native code.
+
+use crate::module_access::ALL_UNNAMED;
+use crate::thread::Thread;
+use crate::Error::{InternalError, PoisonedLock};
+use crate::JavaError::IllegalCallerException;
+use crate::{Configuration, Result};
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::RwLock;
+use tracing::warn;
+
+/// Restricted methods, as the class and method names of the frame that is entered when the
+/// method is called.
+const RESTRICTED_METHODS: &[(&str, &str)] = &[
+    ("java/lang/System", "load"),
+    ("java/lang/System", "loadLibrary"),
+    ("java/lang/Runtime", "load"),
+    ("java/lang/Runtime", "loadLibrary"),
+    ("java/lang/foreign/SymbolLookup", "libraryLookup"),
+    ("jdk/internal/foreign/abi/AbstractLinker", "downcallHandle"),
+    ("jdk/internal/foreign/abi/AbstractLinker", "upcallStub"),
+    ("sun/misc/Unsafe", "allocateMemory"),
+    ("sun/misc/Unsafe", "reallocateMemory"),
+    ("sun/misc/Unsafe", "freeMemory"),
+];
+
+/// Handling of restricted method calls by modules that native access is not enabled for; see
+/// `--illegal-native-access`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IllegalNativeAccess {
+    /// Allow the call
+    Allow,
+    /// Allow the call and log a warning for the first call by each module
+    #[default]
+    Warn,
+    /// Deny the call with `java.lang.IllegalCallerException`
+    Deny,
+}
+
+impl FromStr for IllegalNativeAccess {
+    type Err = crate::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "allow" => Ok(IllegalNativeAccess::Allow),
+            "warn" => Ok(IllegalNativeAccess::Warn),
+            "deny" => Ok(IllegalNativeAccess::Deny),
+            _ => Err(InternalError(format!(
+                "Invalid illegal native access mode: {value}; expected allow, warn or deny"
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for IllegalNativeAccess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IllegalNativeAccess::Allow => write!(f, "allow"),
+            IllegalNativeAccess::Warn => write!(f, "warn"),
+            IllegalNativeAccess::Deny => write!(f, "deny"),
+        }
+    }
+}
+
+/// A call of a restricted method, e.g. `java.lang.System::loadLibrary`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RestrictedCall {
+    module: Option<String>,
+    caller: String,
+    method: String,
+}
+
+impl RestrictedCall {
+    /// Create a restricted call of a method by a class of a module; `None` is the unnamed module.
+    #[must_use]
+    pub fn new<C, M>(module: Option<&str>, caller: C, method: M) -> Self
+    where
+        C: AsRef<str>,
+        M: AsRef<str>,
+    {
+        RestrictedCall {
+            module: module.map(ToString::to_string),
+            caller: caller.as_ref().replace('/', "."),
+            method: method.as_ref().replace('/', "."),
+        }
+    }
+
+    /// Get the name of the module of the caller; `None` is the unnamed module.
+    #[must_use]
+    pub fn module(&self) -> Option<&str> {
+        self.module.as_deref()
+    }
+
+    /// Get the name of the calling class, e.g. `com.example.Main`.
+    #[must_use]
+    pub fn caller(&self) -> &str {
+        &self.caller
+    }
+
+    /// Get the restricted method, e.g. `java.lang.System::loadLibrary`.
+    #[must_use]
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+
+    /// Get the module description used by the JDK, e.g. `module java.desktop`.
+    fn module_description(&self) -> String {
+        self.module.as_ref().map_or_else(
+            || "unnamed module".to_string(),
+            |module| format!("module {module}"),
+        )
+    }
+}
+
+impl fmt::Display for RestrictedCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} has been called by {} in {}",
+            self.method,
+            self.caller,
+            self.module_description()
+        )
+    }
+}
+
+/// Find the restricted method call of a thread from the class and method names of its frames,
+/// ordered from the oldest frame.  The outermost restricted method is the one called by
+/// application code; its caller is the frame before it.
+fn restricted_call<'a, I>(frames: I) -> Option<(&'a str, String)>
+where
+    I: IntoIterator<Item = (&'a str, &'a str)>,
+{
+    let mut caller = None;
+    for (class_name, method_name) in frames {
+        if RESTRICTED_METHODS.contains(&(class_name, method_name)) {
+            return caller.map(|caller| (caller, format!("{class_name}::{method_name}")));
+        }
+        caller = Some(class_name);
+    }
+    None
+}
+
+/// Tracks and checks the calls of restricted methods.
+#[derive(Debug)]
+pub struct NativeAccess {
+    enable_native_access: Vec<String>,
+    illegal_native_access: IllegalNativeAccess,
+    calls: RwLock<Vec<RestrictedCall>>,
+    warned: RwLock<HashSet<Option<String>>>,
+}
+
+impl NativeAccess {
+    /// Create a native access checker with the enabled modules and illegal native access mode of
+    /// a configuration.
+    pub(crate) fn new(configuration: &Configuration) -> Self {
+        NativeAccess {
+            enable_native_access: configuration.enable_native_access().to_vec(),
+            illegal_native_access: configuration.illegal_native_access(),
+            calls: RwLock::new(Vec::new()),
+            warned: RwLock::new(HashSet::new()),
+        }
+    }
+
+    /// Determine if native access is enabled for a module; `None` is the unnamed module.
+    #[must_use]
+    pub fn is_enabled(&self, module: Option<&str>) -> bool {
+        let name = module.unwrap_or(ALL_UNNAMED);
+        self.enable_native_access.iter().any(|value| value == name)
+    }
+
+    /// Get the distinct restricted method calls made so far, in the order they were first made.
+    ///
+    /// # Errors
+    /// if the lock is poisoned
+    pub fn calls(&self) -> Result<Vec<RestrictedCall>> {
+        let calls = self
+            .calls
+            .read()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        Ok(calls.clone())
+    }
+
+    /// Record a restricted method call and check if it is permitted; `system` is set for callers
+    /// in the system modules of the Java runtime.
+    ///
+    /// # Errors
+    /// if the call is denied, with the reason in the form used by the JDK, e.g.
+    /// `Illegal native access from: unnamed module`.
+    pub(crate) fn check(&self, call: RestrictedCall, system: bool) -> Result<()> {
+        {
+            let mut calls = self
+                .calls
+                .write()
+                .map_err(|error| PoisonedLock(error.to_string()))?;
+            if !calls.contains(&call) {
+                calls.push(call.clone());
+            }
+        }
+        if system || self.is_enabled(call.module()) {
+            return Ok(());
+        }
+
+        match self.illegal_native_access {
+            IllegalNativeAccess::Allow => Ok(()),
+            IllegalNativeAccess::Warn => {
+                let first = self
+                    .warned
+                    .write()
+                    .map_err(|error| PoisonedLock(error.to_string()))?
+                    .insert(call.module.clone());
+                if first {
+                    let module = call.module().unwrap_or(ALL_UNNAMED);
+                    warn!(
+                        "A restricted method has been called: {call}; use \
+                         --enable-native-access={module} to avoid a warning for callers in this \
+                         module"
+                    );
+                }
+                Ok(())
+            }
+            IllegalNativeAccess::Deny => Err(IllegalCallerException(format!(
+                "Illegal native access from: {}",
+                call.module_description()
+            ))
+            .into()),
+        }
+    }
+}
+
+/// Check the restricted method call that the current native method of a thread is executing
+/// for, if any; the caller is found from the frames of the thread.
+///
+/// # Errors
+/// if the call is denied or the frames of the thread cannot be accessed
+pub(crate) async fn check_restricted_call(thread: &Thread) -> Result<()> {
+    let frames = thread.frames().await?;
+    let Some((caller, method)) = restricted_call(
+        frames
+            .iter()
+            .map(|frame| (frame.class().name(), frame.method().name())),
+    ) else {
+        return Ok(());
+    };
+    let vm = thread.vm()?;
+    let module = vm.module_access().await?.class_module(caller);
+    let call = RestrictedCall::new(module, caller, method);
+    vm.native_access().check(call, module.is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConfigurationBuilder;
+
+    fn native_access(builder: ConfigurationBuilder) -> Result<NativeAccess> {
+        let configuration = builder.build()?;
+        Ok(NativeAccess::new(&configuration))
+    }
+
+    fn call() -> RestrictedCall {
+        RestrictedCall::new(None, "com/example/Main", "java/lang/System::loadLibrary")
+    }
+
+    #[test]
+    fn test_illegal_native_access_from_str() -> Result<()> {
+        assert_eq!(IllegalNativeAccess::Allow, "allow".parse()?);
+        assert_eq!(IllegalNativeAccess::Warn, "warn".parse()?);
+        assert_eq!(IllegalNativeAccess::Deny, "deny".parse()?);
+        assert!("permit".parse::<IllegalNativeAccess>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_illegal_native_access_display() {
+        assert_eq!("allow", IllegalNativeAccess::Allow.to_string());
+        assert_eq!("warn", IllegalNativeAccess::Warn.to_string());
+        assert_eq!("deny", IllegalNativeAccess::Deny.to_string());
+        assert_eq!(IllegalNativeAccess::Warn, IllegalNativeAccess::default());
+    }
+
+    #[test]
+    fn test_restricted_call_display() {
+        assert_eq!(
+            "java.lang.System::loadLibrary has been called by com.example.Main in unnamed module",
+            call().to_string()
+        );
+        let call = RestrictedCall::new(
+            Some("java.desktop"),
+            "java/awt/Toolkit",
+            "java/lang/System::loadLibrary",
+        );
+        assert_eq!(Some("java.desktop"), call.module());
+        assert_eq!("java.awt.Toolkit", call.caller());
+        assert_eq!("java.lang.System::loadLibrary", call.method());
+        assert_eq!(
+            "java.lang.System::loadLibrary has been called by java.awt.Toolkit in module java.desktop",
+            call.to_string()
+        );
+    }
+
+    #[test]
+    fn test_restricted_call() {
+        let frames = [
+            ("com/example/Main", "main"),
+            ("java/lang/System", "loadLibrary"),
+            ("java/lang/Runtime", "loadLibrary"),
+            ("jdk/internal/loader/NativeLibraries", "load"),
+        ];
+        assert_eq!(
+            Some((
+                "com/example/Main",
+                "java/lang/System::loadLibrary".to_string()
+            )),
+            restricted_call(frames)
+        );
+    }
+
+    #[test]
+    fn test_restricted_call_none() {
+        let frames = [
+            ("com/example/Main", "main"),
+            ("jdk/internal/loader/BootLoader", "loadLibrary"),
+        ];
+        assert_eq!(None, restricted_call(frames));
+        assert_eq!(None, restricted_call([("java/lang/System", "loadLibrary")]));
+    }
+
+    #[test]
+    fn test_check_warn() -> Result<()> {
+        let native_access = native_access(ConfigurationBuilder::new())?;
+        native_access.check(call(), false)?;
+        native_access.check(call(), false)?;
+        assert_eq!(vec![call()], native_access.calls()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_deny() -> Result<()> {
+        let native_access = native_access(
+            ConfigurationBuilder::new().illegal_native_access(IllegalNativeAccess::Deny),
+        )?;
+        let result = native_access.check(call(), false);
+        assert!(matches!(
+            result,
+            Err(crate::Error::JavaError(IllegalCallerException(message)))
+                if message == "Illegal native access from: unnamed module"
+        ));
+        assert_eq!(vec![call()], native_access.calls()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_deny_system_module() -> Result<()> {
+        let native_access = native_access(
+            ConfigurationBuilder::new().illegal_native_access(IllegalNativeAccess::Deny),
+        )?;
+        let call = RestrictedCall::new(
+            Some("java.desktop"),
+            "java/awt/Toolkit",
+            "java/lang/System::loadLibrary",
+        );
+        native_access.check(call, true)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_enabled() -> Result<()> {
+        let native_access = native_access(
+            ConfigurationBuilder::new()
+                .enable_native_access("com.example,ALL-UNNAMED")
+                .illegal_native_access(IllegalNativeAccess::Deny),
+        )?;
+        assert!(native_access.is_enabled(None));
+        assert!(native_access.is_enabled(Some("com.example")));
+        assert!(!native_access.is_enabled(Some("com.other")));
+        native_access.check(call(), false)?;
+        Ok(())
+    }
+}
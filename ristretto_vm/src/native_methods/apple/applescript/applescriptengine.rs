@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -43,7 +43,7 @@ async fn create_context_from(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("apple.applescript.AppleScriptEngine.createContextFrom(Ljava/lang/Object;)J")
+    unsatisfied_link(CLASS_NAME, "createContextFrom", "(Ljava/lang/Object;)J")
 }
 
 #[async_recursion(?Send)]
@@ -51,17 +51,17 @@ async fn create_object_from(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("apple.applescript.AppleScriptEngine.createObjectFrom(J)Ljava/lang/Object;")
+    unsatisfied_link(CLASS_NAME, "createObjectFrom", "(J)Ljava/lang/Object;")
 }
 
 #[async_recursion(?Send)]
 async fn dispose_context(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("apple.applescript.AppleScriptEngine.disposeContext(J)V")
+    unsatisfied_link(CLASS_NAME, "disposeContext", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn eval_script(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("apple.applescript.AppleScriptEngine.evalScript(Ljava/lang/String;J)J")
+    unsatisfied_link(CLASS_NAME, "evalScript", "(Ljava/lang/String;J)J")
 }
 
 #[async_recursion(?Send)]
@@ -69,7 +69,7 @@ async fn eval_script_from_url(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("apple.applescript.AppleScriptEngine.evalScriptFromURL(Ljava/lang/String;J)J")
+    unsatisfied_link(CLASS_NAME, "evalScriptFromURL", "(Ljava/lang/String;J)J")
 }
 
 #[async_recursion(?Send)]
@@ -82,48 +82,63 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.applescript.AppleScriptEngine.createContextFrom(Ljava/lang/Object;)J"
-    )]
     async fn test_create_context_from() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_context_from(thread, Parameters::default()).await;
+        let result = create_context_from(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/applescript/AppleScriptEngine",
+            "createContextFrom",
+            "(Ljava/lang/Object;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.applescript.AppleScriptEngine.createObjectFrom(J)Ljava/lang/Object;"
-    )]
     async fn test_create_object_from() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_object_from(thread, Parameters::default()).await;
+        let result = create_object_from(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/applescript/AppleScriptEngine",
+            "createObjectFrom",
+            "(J)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.applescript.AppleScriptEngine.disposeContext(J)V"
-    )]
     async fn test_dispose_context() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = dispose_context(thread, Parameters::default()).await;
+        let result = dispose_context(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/applescript/AppleScriptEngine",
+            "disposeContext",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.applescript.AppleScriptEngine.evalScript(Ljava/lang/String;J)J"
-    )]
     async fn test_eval_script() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = eval_script(thread, Parameters::default()).await;
+        let result = eval_script(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/applescript/AppleScriptEngine",
+            "evalScript",
+            "(Ljava/lang/String;J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.applescript.AppleScriptEngine.evalScriptFromURL(Ljava/lang/String;J)J"
-    )]
     async fn test_eval_script_from_url() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = eval_script_from_url(thread, Parameters::default()).await;
+        let result = eval_script_from_url(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/applescript/AppleScriptEngine",
+            "evalScriptFromURL",
+            "(Ljava/lang/String;J)J",
+        );
     }
 
     #[tokio::test]
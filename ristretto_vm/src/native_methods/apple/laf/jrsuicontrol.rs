@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -69,12 +69,12 @@ async fn dispose_cf_dictionary(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("apple.laf.JRSUIControl.disposeCFDictionary(J)V")
+    unsatisfied_link(CLASS_NAME, "disposeCFDictionary", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn get_cf_dictionary(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("apple.laf.JRSUIControl.getCFDictionary(Z)J")
+    unsatisfied_link(CLASS_NAME, "getCFDictionary", "(Z)J")
 }
 
 #[async_recursion(?Send)]
@@ -82,7 +82,7 @@ async fn get_native_hit_part(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("apple.laf.JRSUIControl.getNativeHitPart(JJJDDDDDD)I")
+    unsatisfied_link(CLASS_NAME, "getNativeHitPart", "(JJJDDDDDD)I")
 }
 
 #[async_recursion(?Send)]
@@ -90,7 +90,7 @@ async fn get_native_part_bounds(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("apple.laf.JRSUIControl.getNativePartBounds([DJJJDDDDI)V")
+    unsatisfied_link(CLASS_NAME, "getNativePartBounds", "([DJJJDDDDI)V")
 }
 
 #[async_recursion(?Send)]
@@ -98,17 +98,21 @@ async fn get_native_scroll_bar_offset_change(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("apple.laf.JRSUIControl.getNativeScrollBarOffsetChange(JJJDDDDIII)D")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getNativeScrollBarOffsetChange",
+        "(JJJDDDDIII)D",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_ptr_of_buffer(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("apple.laf.JRSUIControl.getPtrOfBuffer(Ljava/nio/ByteBuffer;)J")
+    unsatisfied_link(CLASS_NAME, "getPtrOfBuffer", "(Ljava/nio/ByteBuffer;)J")
 }
 
 #[async_recursion(?Send)]
 async fn init_native_jrsui(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("apple.laf.JRSUIControl.initNativeJRSUI()I")
+    unsatisfied_link(CLASS_NAME, "initNativeJRSUI", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -116,7 +120,7 @@ async fn paint_changes_image(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("apple.laf.JRSUIControl.paintChangesImage([IIIJJJDDDDJ)I")
+    unsatisfied_link(CLASS_NAME, "paintChangesImage", "([IIIJJJDDDDJ)I")
 }
 
 #[async_recursion(?Send)]
@@ -124,12 +128,12 @@ async fn paint_changes_to_cg_context(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("apple.laf.JRSUIControl.paintChangesToCGContext(JJJJDDDDJ)I")
+    unsatisfied_link(CLASS_NAME, "paintChangesToCGContext", "(JJJJDDDDJ)I")
 }
 
 #[async_recursion(?Send)]
 async fn paint_image(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("apple.laf.JRSUIControl.paintImage([IIIJJJDDDD)I")
+    unsatisfied_link(CLASS_NAME, "paintImage", "([IIIJJJDDDD)I")
 }
 
 #[async_recursion(?Send)]
@@ -137,12 +141,12 @@ async fn paint_to_cg_context(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("apple.laf.JRSUIControl.paintToCGContext(JJJJDDDD)I")
+    unsatisfied_link(CLASS_NAME, "paintToCGContext", "(JJJJDDDD)I")
 }
 
 #[async_recursion(?Send)]
 async fn sync_changes(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("apple.laf.JRSUIControl.syncChanges(JJ)I")
+    unsatisfied_link(CLASS_NAME, "syncChanges", "(JJ)I")
 }
 
 #[cfg(test)]
@@ -150,104 +154,146 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.laf.JRSUIControl.disposeCFDictionary(J)V"
-    )]
     async fn test_dispose_cf_dictionary() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = dispose_cf_dictionary(thread, Parameters::default()).await;
+        let result = dispose_cf_dictionary(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/laf/JRSUIControl",
+            "disposeCFDictionary",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: apple.laf.JRSUIControl.getCFDictionary(Z)J")]
     async fn test_get_cf_dictionary() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_cf_dictionary(thread, Parameters::default()).await;
+        let result = get_cf_dictionary(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/laf/JRSUIControl",
+            "getCFDictionary",
+            "(Z)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.laf.JRSUIControl.getNativeHitPart(JJJDDDDDD)I"
-    )]
     async fn test_get_native_hit_part() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_native_hit_part(thread, Parameters::default()).await;
+        let result = get_native_hit_part(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/laf/JRSUIControl",
+            "getNativeHitPart",
+            "(JJJDDDDDD)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.laf.JRSUIControl.getNativePartBounds([DJJJDDDDI)V"
-    )]
     async fn test_get_native_part_bounds() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_native_part_bounds(thread, Parameters::default()).await;
+        let result = get_native_part_bounds(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/laf/JRSUIControl",
+            "getNativePartBounds",
+            "([DJJJDDDDI)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.laf.JRSUIControl.getNativeScrollBarOffsetChange(JJJDDDDIII)D"
-    )]
     async fn test_get_native_scroll_bar_offset_change() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_native_scroll_bar_offset_change(thread, Parameters::default()).await;
+        let result = get_native_scroll_bar_offset_change(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/laf/JRSUIControl",
+            "getNativeScrollBarOffsetChange",
+            "(JJJDDDDIII)D",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.laf.JRSUIControl.getPtrOfBuffer(Ljava/nio/ByteBuffer;)J"
-    )]
     async fn test_get_ptr_of_buffer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_ptr_of_buffer(thread, Parameters::default()).await;
+        let result = get_ptr_of_buffer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/laf/JRSUIControl",
+            "getPtrOfBuffer",
+            "(Ljava/nio/ByteBuffer;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: apple.laf.JRSUIControl.initNativeJRSUI()I")]
     async fn test_init_native_jrsui() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_native_jrsui(thread, Parameters::default()).await;
+        let result = init_native_jrsui(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/laf/JRSUIControl",
+            "initNativeJRSUI",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.laf.JRSUIControl.paintChangesImage([IIIJJJDDDDJ)I"
-    )]
     async fn test_paint_changes_image() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = paint_changes_image(thread, Parameters::default()).await;
+        let result = paint_changes_image(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/laf/JRSUIControl",
+            "paintChangesImage",
+            "([IIIJJJDDDDJ)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.laf.JRSUIControl.paintChangesToCGContext(JJJJDDDDJ)I"
-    )]
     async fn test_paint_changes_to_cg_context() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = paint_changes_to_cg_context(thread, Parameters::default()).await;
+        let result = paint_changes_to_cg_context(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/laf/JRSUIControl",
+            "paintChangesToCGContext",
+            "(JJJJDDDDJ)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.laf.JRSUIControl.paintImage([IIIJJJDDDD)I"
-    )]
     async fn test_paint_image() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = paint_image(thread, Parameters::default()).await;
+        let result = paint_image(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/laf/JRSUIControl",
+            "paintImage",
+            "([IIIJJJDDDD)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.laf.JRSUIControl.paintToCGContext(JJJJDDDD)I"
-    )]
     async fn test_paint_to_cg_context() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = paint_to_cg_context(thread, Parameters::default()).await;
+        let result = paint_to_cg_context(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/laf/JRSUIControl",
+            "paintToCGContext",
+            "(JJJJDDDD)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: apple.laf.JRSUIControl.syncChanges(JJ)I")]
     async fn test_sync_changes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = sync_changes(thread, Parameters::default()).await;
+        let result = sync_changes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/laf/JRSUIControl",
+            "syncChanges",
+            "(JJ)I",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -19,12 +19,12 @@ async fn begin_native_focus(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("apple.laf.JRSUIFocus.beginNativeFocus(JI)I")
+    unsatisfied_link(CLASS_NAME, "beginNativeFocus", "(JI)I")
 }
 
 #[async_recursion(?Send)]
 async fn end_native_focus(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("apple.laf.JRSUIFocus.endNativeFocus(J)I")
+    unsatisfied_link(CLASS_NAME, "endNativeFocus", "(J)I")
 }
 
 #[cfg(test)]
@@ -32,16 +32,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: apple.laf.JRSUIFocus.beginNativeFocus(JI)I")]
     async fn test_begin_native_focus() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = begin_native_focus(thread, Parameters::default()).await;
+        let result = begin_native_focus(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/laf/JRSUIFocus",
+            "beginNativeFocus",
+            "(JI)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: apple.laf.JRSUIFocus.endNativeFocus(J)I")]
     async fn test_end_native_focus() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = end_native_focus(thread, Parameters::default()).await;
+        let result = end_native_focus(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/laf/JRSUIFocus",
+            "endNativeFocus",
+            "(J)I",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,7 +23,7 @@ async fn should_use_scroll_to_click(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("apple.laf.JRSUIUtils$ScrollBar.shouldUseScrollToClick()Z")
+    unsatisfied_link(CLASS_NAME, "shouldUseScrollToClick", "()Z")
 }
 
 #[cfg(test)]
@@ -31,11 +31,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.laf.JRSUIUtils$ScrollBar.shouldUseScrollToClick()Z"
-    )]
     async fn test_should_use_scroll_to_click() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = should_use_scroll_to_click(thread, Parameters::default()).await;
+        let result = should_use_scroll_to_click(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/laf/JRSUIUtils$ScrollBar",
+            "shouldUseScrollToClick",
+            "()Z",
+        );
     }
 }
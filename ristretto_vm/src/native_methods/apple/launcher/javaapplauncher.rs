@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -29,7 +29,11 @@ async fn native_convert_and_release(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("apple.launcher.JavaAppLauncher.nativeConvertAndRelease(J)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeConvertAndRelease",
+        "(J)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -37,7 +41,11 @@ async fn native_invoke_non_public(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("apple.launcher.JavaAppLauncher.nativeInvokeNonPublic(Ljava/lang/Class;Ljava/lang/reflect/Method;[Ljava/lang/String;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeInvokeNonPublic",
+        "(Ljava/lang/Class;Ljava/lang/reflect/Method;[Ljava/lang/String;)V",
+    )
 }
 
 #[cfg(test)]
@@ -45,20 +53,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.launcher.JavaAppLauncher.nativeConvertAndRelease(J)Ljava/lang/Object;"
-    )]
     async fn test_native_convert_and_release() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_convert_and_release(thread, Parameters::default()).await;
+        let result = native_convert_and_release(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/launcher/JavaAppLauncher",
+            "nativeConvertAndRelease",
+            "(J)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.launcher.JavaAppLauncher.nativeInvokeNonPublic(Ljava/lang/Class;Ljava/lang/reflect/Method;[Ljava/lang/String;)V"
-    )]
     async fn test_native_invoke_non_public() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_invoke_non_public(thread, Parameters::default()).await;
+        let result = native_invoke_non_public(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/launcher/JavaAppLauncher",
+            "nativeInvokeNonPublic",
+            "(Ljava/lang/Class;Ljava/lang/reflect/Method;[Ljava/lang/String;)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_21};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_21};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -52,7 +52,11 @@ async fn add_item_to_keychain(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("apple.security.KeychainStore._addItemToKeychain(Ljava/lang/String;Z[B[C)J")
+    unsatisfied_link(
+        CLASS_NAME,
+        "_addItemToKeychain",
+        "(Ljava/lang/String;Z[B[C)J",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -60,7 +64,7 @@ async fn get_encoded_key_data(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("apple.security.KeychainStore._getEncodedKeyData(J[C)[B")
+    unsatisfied_link(CLASS_NAME, "_getEncodedKeyData", "(J[C)[B")
 }
 
 #[async_recursion(?Send)]
@@ -68,7 +72,7 @@ async fn release_keychain_item_ref(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("apple.security.KeychainStore._releaseKeychainItemRef(J)V")
+    unsatisfied_link(CLASS_NAME, "_releaseKeychainItemRef", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -76,12 +80,12 @@ async fn remove_item_from_keychain(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("apple.security.KeychainStore._removeItemFromKeychain(J)I")
+    unsatisfied_link(CLASS_NAME, "_removeItemFromKeychain", "(J)I")
 }
 
 #[async_recursion(?Send)]
 async fn scan_keychain(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("apple.security.KeychainStore._scanKeychain(Ljava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "_scanKeychain", "(Ljava/lang/String;)V")
 }
 
 #[cfg(test)]
@@ -89,47 +93,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.security.KeychainStore._addItemToKeychain(Ljava/lang/String;Z[B[C)J"
-    )]
     async fn test_add_item_to_keychain() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = add_item_to_keychain(thread, Parameters::default()).await;
+        let result = add_item_to_keychain(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/security/KeychainStore",
+            "_addItemToKeychain",
+            "(Ljava/lang/String;Z[B[C)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.security.KeychainStore._getEncodedKeyData(J[C)[B"
-    )]
     async fn test_get_encoded_key_data() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_encoded_key_data(thread, Parameters::default()).await;
+        let result = get_encoded_key_data(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/security/KeychainStore",
+            "_getEncodedKeyData",
+            "(J[C)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.security.KeychainStore._releaseKeychainItemRef(J)V"
-    )]
     async fn test_release_keychain_item_ref() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = release_keychain_item_ref(thread, Parameters::default()).await;
+        let result = release_keychain_item_ref(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/security/KeychainStore",
+            "_releaseKeychainItemRef",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.security.KeychainStore._removeItemFromKeychain(J)I"
-    )]
     async fn test_remove_item_from_keychain() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = remove_item_from_keychain(thread, Parameters::default()).await;
+        let result = remove_item_from_keychain(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/security/KeychainStore",
+            "_removeItemFromKeychain",
+            "(J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: apple.security.KeychainStore._scanKeychain(Ljava/lang/String;)V"
-    )]
     async fn test_scan_keychain() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = scan_keychain(thread, Parameters::default()).await;
+        let result = scan_keychain(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "apple/security/KeychainStore",
+            "_scanKeychain",
+            "(Ljava/lang/String;)V",
+        );
     }
 }
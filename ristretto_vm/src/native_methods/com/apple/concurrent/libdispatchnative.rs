@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -59,7 +59,7 @@ async fn native_create_concurrent_queue(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.concurrent.LibDispatchNative.nativeCreateConcurrentQueue(I)J")
+    unsatisfied_link(CLASS_NAME, "nativeCreateConcurrentQueue", "(I)J")
 }
 
 #[async_recursion(?Send)]
@@ -67,7 +67,11 @@ async fn native_create_serial_queue(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.concurrent.LibDispatchNative.nativeCreateSerialQueue(Ljava/lang/String;)J")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeCreateSerialQueue",
+        "(Ljava/lang/String;)J",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -75,7 +79,7 @@ async fn native_execute_async(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.concurrent.LibDispatchNative.nativeExecuteAsync(JLjava/lang/Runnable;)V")
+    unsatisfied_link(CLASS_NAME, "nativeExecuteAsync", "(JLjava/lang/Runnable;)V")
 }
 
 #[async_recursion(?Send)]
@@ -83,7 +87,7 @@ async fn native_execute_sync(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.concurrent.LibDispatchNative.nativeExecuteSync(JLjava/lang/Runnable;)V")
+    unsatisfied_link(CLASS_NAME, "nativeExecuteSync", "(JLjava/lang/Runnable;)V")
 }
 
 #[async_recursion(?Send)]
@@ -91,7 +95,7 @@ async fn native_get_main_queue(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.concurrent.LibDispatchNative.nativeGetMainQueue()J")
+    unsatisfied_link(CLASS_NAME, "nativeGetMainQueue", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -99,7 +103,7 @@ async fn native_is_dispatch_supported(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.concurrent.LibDispatchNative.nativeIsDispatchSupported()Z")
+    unsatisfied_link(CLASS_NAME, "nativeIsDispatchSupported", "()Z")
 }
 
 #[async_recursion(?Send)]
@@ -107,7 +111,7 @@ async fn native_release_queue(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.concurrent.LibDispatchNative.nativeReleaseQueue(J)V")
+    unsatisfied_link(CLASS_NAME, "nativeReleaseQueue", "(J)V")
 }
 
 #[cfg(test)]
@@ -115,65 +119,86 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.concurrent.LibDispatchNative.nativeCreateConcurrentQueue(I)J"
-    )]
     async fn test_native_create_concurrent_queue() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_create_concurrent_queue(thread, Parameters::default()).await;
+        let result = native_create_concurrent_queue(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/concurrent/LibDispatchNative",
+            "nativeCreateConcurrentQueue",
+            "(I)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.concurrent.LibDispatchNative.nativeCreateSerialQueue(Ljava/lang/String;)J"
-    )]
     async fn test_native_create_serial_queue() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_create_serial_queue(thread, Parameters::default()).await;
+        let result = native_create_serial_queue(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/concurrent/LibDispatchNative",
+            "nativeCreateSerialQueue",
+            "(Ljava/lang/String;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.concurrent.LibDispatchNative.nativeExecuteAsync(JLjava/lang/Runnable;)V"
-    )]
     async fn test_native_execute_async() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_execute_async(thread, Parameters::default()).await;
+        let result = native_execute_async(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/concurrent/LibDispatchNative",
+            "nativeExecuteAsync",
+            "(JLjava/lang/Runnable;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.concurrent.LibDispatchNative.nativeExecuteSync(JLjava/lang/Runnable;)V"
-    )]
     async fn test_native_execute_sync() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_execute_sync(thread, Parameters::default()).await;
+        let result = native_execute_sync(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/concurrent/LibDispatchNative",
+            "nativeExecuteSync",
+            "(JLjava/lang/Runnable;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.concurrent.LibDispatchNative.nativeGetMainQueue()J"
-    )]
     async fn test_native_get_main_queue() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_main_queue(thread, Parameters::default()).await;
+        let result = native_get_main_queue(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/concurrent/LibDispatchNative",
+            "nativeGetMainQueue",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.concurrent.LibDispatchNative.nativeIsDispatchSupported()Z"
-    )]
     async fn test_native_is_dispatch_supported() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_is_dispatch_supported(thread, Parameters::default()).await;
+        let result = native_is_dispatch_supported(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/concurrent/LibDispatchNative",
+            "nativeIsDispatchSupported",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.concurrent.LibDispatchNative.nativeReleaseQueue(J)V"
-    )]
     async fn test_native_release_queue() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_release_queue(thread, Parameters::default()).await;
+        let result = native_release_queue(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/concurrent/LibDispatchNative",
+            "nativeReleaseQueue",
+            "(J)V",
+        );
     }
 }
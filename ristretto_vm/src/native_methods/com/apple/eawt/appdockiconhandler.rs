@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -50,7 +50,7 @@ async fn native_get_dock_icon_image(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.eawt._AppDockIconHandler.nativeGetDockIconImage()J")
+    unsatisfied_link(CLASS_NAME, "nativeGetDockIconImage", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -58,7 +58,11 @@ async fn native_set_dock_icon_badge(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.eawt._AppDockIconHandler.nativeSetDockIconBadge(Ljava/lang/String;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeSetDockIconBadge",
+        "(Ljava/lang/String;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -66,7 +70,7 @@ async fn native_set_dock_icon_image(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.eawt._AppDockIconHandler.nativeSetDockIconImage(J)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetDockIconImage", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -74,7 +78,7 @@ async fn native_set_dock_icon_progress(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.eawt._AppDockIconHandler.nativeSetDockIconProgress(I)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetDockIconProgress", "(I)V")
 }
 
 #[async_recursion(?Send)]
@@ -82,7 +86,7 @@ async fn native_set_dock_menu(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.eawt._AppDockIconHandler.nativeSetDockMenu(J)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetDockMenu", "(J)V")
 }
 
 #[cfg(test)]
@@ -90,47 +94,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.eawt._AppDockIconHandler.nativeGetDockIconImage()J"
-    )]
     async fn test_native_get_dock_icon_image() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_dock_icon_image(thread, Parameters::default()).await;
+        let result = native_get_dock_icon_image(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/eawt/_AppDockIconHandler",
+            "nativeGetDockIconImage",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.eawt._AppDockIconHandler.nativeSetDockIconBadge(Ljava/lang/String;)V"
-    )]
     async fn test_native_set_dock_icon_badge() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_dock_icon_badge(thread, Parameters::default()).await;
+        let result = native_set_dock_icon_badge(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/eawt/_AppDockIconHandler",
+            "nativeSetDockIconBadge",
+            "(Ljava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.eawt._AppDockIconHandler.nativeSetDockIconImage(J)V"
-    )]
     async fn test_native_set_dock_icon_image() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_dock_icon_image(thread, Parameters::default()).await;
+        let result = native_set_dock_icon_image(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/eawt/_AppDockIconHandler",
+            "nativeSetDockIconImage",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.eawt._AppDockIconHandler.nativeSetDockIconProgress(I)V"
-    )]
     async fn test_native_set_dock_icon_progress() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_dock_icon_progress(thread, Parameters::default()).await;
+        let result = native_set_dock_icon_progress(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/eawt/_AppDockIconHandler",
+            "nativeSetDockIconProgress",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.eawt._AppDockIconHandler.nativeSetDockMenu(J)V"
-    )]
     async fn test_native_set_dock_menu() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_dock_menu(thread, Parameters::default()).await;
+        let result = native_set_dock_menu(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/eawt/_AppDockIconHandler",
+            "nativeSetDockMenu",
+            "(J)V",
+        );
     }
 }
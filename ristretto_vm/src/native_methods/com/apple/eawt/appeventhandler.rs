@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -35,7 +35,7 @@ async fn native_open_cocoa_about_window(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.eawt._AppEventHandler.nativeOpenCocoaAboutWindow()V")
+    unsatisfied_link(CLASS_NAME, "nativeOpenCocoaAboutWindow", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -43,7 +43,7 @@ async fn native_register_for_notification(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.eawt._AppEventHandler.nativeRegisterForNotification(I)V")
+    unsatisfied_link(CLASS_NAME, "nativeRegisterForNotification", "(I)V")
 }
 
 #[async_recursion(?Send)]
@@ -51,7 +51,7 @@ async fn native_reply_to_app_should_terminate(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.eawt._AppEventHandler.nativeReplyToAppShouldTerminate(Z)V")
+    unsatisfied_link(CLASS_NAME, "nativeReplyToAppShouldTerminate", "(Z)V")
 }
 
 #[cfg(test)]
@@ -59,29 +59,38 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.eawt._AppEventHandler.nativeOpenCocoaAboutWindow()V"
-    )]
     async fn test_native_open_cocoa_about_window() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_open_cocoa_about_window(thread, Parameters::default()).await;
+        let result = native_open_cocoa_about_window(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/eawt/_AppEventHandler",
+            "nativeOpenCocoaAboutWindow",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.eawt._AppEventHandler.nativeRegisterForNotification(I)V"
-    )]
     async fn test_native_register_for_notification() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_register_for_notification(thread, Parameters::default()).await;
+        let result = native_register_for_notification(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/eawt/_AppEventHandler",
+            "nativeRegisterForNotification",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.eawt._AppEventHandler.nativeReplyToAppShouldTerminate(Z)V"
-    )]
     async fn test_native_reply_to_app_should_terminate() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_reply_to_app_should_terminate(thread, Parameters::default()).await;
+        let result = native_reply_to_app_should_terminate(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/eawt/_AppEventHandler",
+            "nativeReplyToAppShouldTerminate",
+            "(Z)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_17};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_17};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -38,7 +38,7 @@ async fn native_activate_default_menu_bar(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.eawt._AppMenuBarHandler.nativeActivateDefaultMenuBar(J)V")
+    unsatisfied_link(CLASS_NAME, "nativeActivateDefaultMenuBar", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -46,7 +46,7 @@ async fn native_set_default_menu_bar(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.eawt._AppMenuBarHandler.nativeSetDefaultMenuBar(J)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetDefaultMenuBar", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -54,7 +54,7 @@ async fn native_set_menu_state(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.eawt._AppMenuBarHandler.nativeSetMenuState(IZZ)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetMenuState", "(IZZ)V")
 }
 
 #[cfg(test)]
@@ -62,29 +62,38 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.eawt._AppMenuBarHandler.nativeActivateDefaultMenuBar(J)V"
-    )]
     async fn test_native_activate_default_menu_bar() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_activate_default_menu_bar(thread, Parameters::default()).await;
+        let result = native_activate_default_menu_bar(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/eawt/_AppMenuBarHandler",
+            "nativeActivateDefaultMenuBar",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.eawt._AppMenuBarHandler.nativeSetDefaultMenuBar(J)V"
-    )]
     async fn test_native_set_default_menu_bar() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_default_menu_bar(thread, Parameters::default()).await;
+        let result = native_set_default_menu_bar(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/eawt/_AppMenuBarHandler",
+            "nativeSetDefaultMenuBar",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.eawt._AppMenuBarHandler.nativeSetMenuState(IZZ)V"
-    )]
     async fn test_native_set_menu_state() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_menu_state(thread, Parameters::default()).await;
+        let result = native_set_menu_state(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/eawt/_AppMenuBarHandler",
+            "nativeSetMenuState",
+            "(IZZ)V",
+        );
     }
 }
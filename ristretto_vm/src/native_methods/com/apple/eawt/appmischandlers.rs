@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -47,7 +47,7 @@ async fn native_disable_sudden_termination(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.eawt._AppMiscHandlers.nativeDisableSuddenTermination()V")
+    unsatisfied_link(CLASS_NAME, "nativeDisableSuddenTermination", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -55,7 +55,7 @@ async fn native_enable_sudden_termination(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.eawt._AppMiscHandlers.nativeEnableSuddenTermination()V")
+    unsatisfied_link(CLASS_NAME, "nativeEnableSuddenTermination", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -63,7 +63,7 @@ async fn native_open_help_viewer(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.eawt._AppMiscHandlers.nativeOpenHelpViewer()V")
+    unsatisfied_link(CLASS_NAME, "nativeOpenHelpViewer", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -71,7 +71,7 @@ async fn native_request_activation(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.eawt._AppMiscHandlers.nativeRequestActivation(Z)V")
+    unsatisfied_link(CLASS_NAME, "nativeRequestActivation", "(Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -79,7 +79,7 @@ async fn native_request_user_attention(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.eawt._AppMiscHandlers.nativeRequestUserAttention(Z)V")
+    unsatisfied_link(CLASS_NAME, "nativeRequestUserAttention", "(Z)V")
 }
 
 #[cfg(test)]
@@ -87,47 +87,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.eawt._AppMiscHandlers.nativeDisableSuddenTermination()V"
-    )]
     async fn test_native_disable_sudden_termination() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_disable_sudden_termination(thread, Parameters::default()).await;
+        let result = native_disable_sudden_termination(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/eawt/_AppMiscHandlers",
+            "nativeDisableSuddenTermination",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.eawt._AppMiscHandlers.nativeEnableSuddenTermination()V"
-    )]
     async fn test_native_enable_sudden_termination() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_enable_sudden_termination(thread, Parameters::default()).await;
+        let result = native_enable_sudden_termination(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/eawt/_AppMiscHandlers",
+            "nativeEnableSuddenTermination",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.eawt._AppMiscHandlers.nativeOpenHelpViewer()V"
-    )]
     async fn test_native_open_help_viewer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_open_help_viewer(thread, Parameters::default()).await;
+        let result = native_open_help_viewer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/eawt/_AppMiscHandlers",
+            "nativeOpenHelpViewer",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.eawt._AppMiscHandlers.nativeRequestActivation(Z)V"
-    )]
     async fn test_native_request_activation() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_request_activation(thread, Parameters::default()).await;
+        let result = native_request_activation(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/eawt/_AppMiscHandlers",
+            "nativeRequestActivation",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.eawt._AppMiscHandlers.nativeRequestUserAttention(Z)V"
-    )]
     async fn test_native_request_user_attention() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_request_user_attention(thread, Parameters::default()).await;
+        let result = native_request_user_attention(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/eawt/_AppMiscHandlers",
+            "nativeRequestUserAttention",
+            "(Z)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -42,7 +42,11 @@ async fn get_native_display_name(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.laf.AquaFileView.getNativeDisplayName([BZ)Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getNativeDisplayName",
+        "([BZ)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -50,7 +54,7 @@ async fn get_native_ls_info(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.laf.AquaFileView.getNativeLSInfo([BZ)I")
+    unsatisfied_link(CLASS_NAME, "getNativeLSInfo", "([BZ)I")
 }
 
 #[async_recursion(?Send)]
@@ -58,7 +62,7 @@ async fn get_native_machine_name(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.laf.AquaFileView.getNativeMachineName()Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getNativeMachineName", "()Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
@@ -66,7 +70,11 @@ async fn get_native_path_for_resolved_alias(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.laf.AquaFileView.getNativePathForResolvedAlias([BZ)Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getNativePathForResolvedAlias",
+        "([BZ)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -74,7 +82,11 @@ async fn get_native_path_to_shared_jdk_bundle(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.laf.AquaFileView.getNativePathToSharedJDKBundle()Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getNativePathToSharedJDKBundle",
+        "()Ljava/lang/String;",
+    )
 }
 
 #[cfg(test)]
@@ -82,47 +94,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.laf.AquaFileView.getNativeDisplayName([BZ)Ljava/lang/String;"
-    )]
     async fn test_get_native_display_name() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_native_display_name(thread, Parameters::default()).await;
+        let result = get_native_display_name(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/laf/AquaFileView",
+            "getNativeDisplayName",
+            "([BZ)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.laf.AquaFileView.getNativeLSInfo([BZ)I"
-    )]
     async fn test_get_native_ls_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_native_ls_info(thread, Parameters::default()).await;
+        let result = get_native_ls_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/laf/AquaFileView",
+            "getNativeLSInfo",
+            "([BZ)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.laf.AquaFileView.getNativeMachineName()Ljava/lang/String;"
-    )]
     async fn test_get_native_machine_name() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_native_machine_name(thread, Parameters::default()).await;
+        let result = get_native_machine_name(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/laf/AquaFileView",
+            "getNativeMachineName",
+            "()Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.laf.AquaFileView.getNativePathForResolvedAlias([BZ)Ljava/lang/String;"
-    )]
     async fn test_get_native_path_for_resolved_alias() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_native_path_for_resolved_alias(thread, Parameters::default()).await;
+        let result = get_native_path_for_resolved_alias(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/laf/AquaFileView",
+            "getNativePathForResolvedAlias",
+            "([BZ)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.laf.AquaFileView.getNativePathToSharedJDKBundle()Ljava/lang/String;"
-    )]
     async fn test_get_native_path_to_shared_jdk_bundle() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_native_path_to_shared_jdk_bundle(thread, Parameters::default()).await;
+        let result = get_native_path_to_shared_jdk_bundle(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/laf/AquaFileView",
+            "getNativePathToSharedJDKBundle",
+            "()Ljava/lang/String;",
+        );
     }
 }
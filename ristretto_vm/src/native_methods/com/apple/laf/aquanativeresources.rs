@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,7 +23,7 @@ async fn get_window_background_color(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.laf.AquaNativeResources.getWindowBackgroundColor()J")
+    unsatisfied_link(CLASS_NAME, "getWindowBackgroundColor", "()J")
 }
 
 #[cfg(test)]
@@ -31,11 +31,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.laf.AquaNativeResources.getWindowBackgroundColor()J"
-    )]
     async fn test_get_window_background_color() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_window_background_color(thread, Parameters::default()).await;
+        let result = get_window_background_color(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/laf/AquaNativeResources",
+            "getWindowBackgroundColor",
+            "()J",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -29,7 +29,11 @@ async fn add_menu_listeners(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.laf.ScreenMenu.addMenuListeners(Lcom/apple/laf/ScreenMenu;J)J")
+    unsatisfied_link(
+        CLASS_NAME,
+        "addMenuListeners",
+        "(Lcom/apple/laf/ScreenMenu;J)J",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -37,7 +41,7 @@ async fn remove_menu_listeners(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.laf.ScreenMenu.removeMenuListeners(J)V")
+    unsatisfied_link(CLASS_NAME, "removeMenuListeners", "(J)V")
 }
 
 #[cfg(test)]
@@ -45,20 +49,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.laf.ScreenMenu.addMenuListeners(Lcom/apple/laf/ScreenMenu;J)J"
-    )]
     async fn test_add_menu_listeners() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = add_menu_listeners(thread, Parameters::default()).await;
+        let result = add_menu_listeners(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/laf/ScreenMenu",
+            "addMenuListeners",
+            "(Lcom/apple/laf/ScreenMenu;J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.laf.ScreenMenu.removeMenuListeners(J)V"
-    )]
     async fn test_remove_menu_listeners() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = remove_menu_listeners(thread, Parameters::default()).await;
+        let result = remove_menu_listeners(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/laf/ScreenMenu",
+            "removeMenuListeners",
+            "(J)V",
+        );
     }
 }
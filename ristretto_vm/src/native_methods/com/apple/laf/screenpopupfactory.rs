@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,7 +23,11 @@ async fn get_heavy_weight_popup(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.apple.laf.ScreenPopupFactory._getHeavyWeightPopup(Ljava/awt/Component;Ljava/awt/Component;II)Ljavax/swing/Popup;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "_getHeavyWeightPopup",
+        "(Ljava/awt/Component;Ljava/awt/Component;II)Ljavax/swing/Popup;",
+    )
 }
 
 #[cfg(test)]
@@ -31,11 +35,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.apple.laf.ScreenPopupFactory._getHeavyWeightPopup(Ljava/awt/Component;Ljava/awt/Component;II)Ljavax/swing/Popup;"
-    )]
     async fn test_get_heavy_weight_popup() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_heavy_weight_popup(thread, Parameters::default()).await;
+        let result = get_heavy_weight_popup(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/apple/laf/ScreenPopupFactory",
+            "_getHeavyWeightPopup",
+            "(Ljava/awt/Component;Ljava/awt/Component;II)Ljavax/swing/Popup;",
+        );
     }
 }
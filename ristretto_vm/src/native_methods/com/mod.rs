@@ -1,2 +1,3 @@
+#[cfg(feature = "apple")]
 pub(crate) mod apple;
 pub(crate) mod sun;
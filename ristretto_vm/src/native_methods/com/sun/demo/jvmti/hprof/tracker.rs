@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -38,12 +38,16 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn native_call_site(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.demo.jvmti.hprof.Tracker.nativeCallSite(Ljava/lang/Object;II)V")
+    unsatisfied_link(CLASS_NAME, "nativeCallSite", "(Ljava/lang/Object;II)V")
 }
 
 #[async_recursion(?Send)]
 async fn native_new_array(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.demo.jvmti.hprof.Tracker.nativeNewArray(Ljava/lang/Object;Ljava/lang/Object;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeNewArray",
+        "(Ljava/lang/Object;Ljava/lang/Object;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -51,8 +55,10 @@ async fn native_object_init(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!(
-        "com.sun.demo.jvmti.hprof.Tracker.nativeObjectInit(Ljava/lang/Object;Ljava/lang/Object;)V"
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeObjectInit",
+        "(Ljava/lang/Object;Ljava/lang/Object;)V",
     )
 }
 
@@ -61,7 +67,7 @@ async fn native_return_site(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.demo.jvmti.hprof.Tracker.nativeReturnSite(Ljava/lang/Object;II)V")
+    unsatisfied_link(CLASS_NAME, "nativeReturnSite", "(Ljava/lang/Object;II)V")
 }
 
 #[cfg(test)]
@@ -69,38 +75,50 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.demo.jvmti.hprof.Tracker.nativeCallSite(Ljava/lang/Object;II)V"
-    )]
     async fn test_native_call_site() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_call_site(thread, Parameters::default()).await;
+        let result = native_call_site(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/demo/jvmti/hprof/Tracker",
+            "nativeCallSite",
+            "(Ljava/lang/Object;II)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.demo.jvmti.hprof.Tracker.nativeNewArray(Ljava/lang/Object;Ljava/lang/Object;)V"
-    )]
     async fn test_native_new_array() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_new_array(thread, Parameters::default()).await;
+        let result = native_new_array(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/demo/jvmti/hprof/Tracker",
+            "nativeNewArray",
+            "(Ljava/lang/Object;Ljava/lang/Object;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.demo.jvmti.hprof.Tracker.nativeObjectInit(Ljava/lang/Object;Ljava/lang/Object;)V"
-    )]
     async fn test_native_object_init() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_object_init(thread, Parameters::default()).await;
+        let result = native_object_init(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/demo/jvmti/hprof/Tracker",
+            "nativeObjectInit",
+            "(Ljava/lang/Object;Ljava/lang/Object;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.demo.jvmti.hprof.Tracker.nativeReturnSite(Ljava/lang/Object;II)V"
-    )]
     async fn test_native_return_site() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_return_site(thread, Parameters::default()).await;
+        let result = native_return_site(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/demo/jvmti/hprof/Tracker",
+            "nativeReturnSite",
+            "(Ljava/lang/Object;II)V",
+        );
     }
 }
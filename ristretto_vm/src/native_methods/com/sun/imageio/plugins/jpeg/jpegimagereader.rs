@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -43,7 +43,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn abort_read(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.imageio.plugins.jpeg.JPEGImageReader.abortRead(J)V")
+    unsatisfied_link(CLASS_NAME, "abortRead", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -51,12 +51,12 @@ async fn clear_native_read_abort_flag(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.imageio.plugins.jpeg.JPEGImageReader.clearNativeReadAbortFlag(J)V")
+    unsatisfied_link(CLASS_NAME, "clearNativeReadAbortFlag", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn dispose_reader(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.imageio.plugins.jpeg.JPEGImageReader.disposeReader(J)V")
+    unsatisfied_link(CLASS_NAME, "disposeReader", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -64,22 +64,26 @@ async fn init_jpeg_image_reader(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.imageio.plugins.jpeg.JPEGImageReader.initJPEGImageReader()J")
+    unsatisfied_link(CLASS_NAME, "initJPEGImageReader", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn init_reader_i_ds(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.imageio.plugins.jpeg.JPEGImageReader.initReaderIDs(Ljava/lang/Class;Ljava/lang/Class;Ljava/lang/Class;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "initReaderIDs",
+        "(Ljava/lang/Class;Ljava/lang/Class;Ljava/lang/Class;)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn read_image(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.imageio.plugins.jpeg.JPEGImageReader.readImage(IJ[BI[I[IIIIIII[Ljavax/imageio/plugins/jpeg/JPEGQTable;[Ljavax/imageio/plugins/jpeg/JPEGHuffmanTable;[Ljavax/imageio/plugins/jpeg/JPEGHuffmanTable;IIZ)Z")
+    unsatisfied_link(CLASS_NAME, "readImage", "(IJ[BI[I[IIIIIII[Ljavax/imageio/plugins/jpeg/JPEGQTable;[Ljavax/imageio/plugins/jpeg/JPEGHuffmanTable;[Ljavax/imageio/plugins/jpeg/JPEGHuffmanTable;IIZ)Z")
 }
 
 #[async_recursion(?Send)]
 async fn read_image_header(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.imageio.plugins.jpeg.JPEGImageReader.readImageHeader(JZZ)Z")
+    unsatisfied_link(CLASS_NAME, "readImageHeader", "(JZZ)Z")
 }
 
 #[async_recursion(?Send)]
@@ -87,12 +91,12 @@ async fn reset_library_state(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.imageio.plugins.jpeg.JPEGImageReader.resetLibraryState(J)V")
+    unsatisfied_link(CLASS_NAME, "resetLibraryState", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn reset_reader(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.imageio.plugins.jpeg.JPEGImageReader.resetReader(J)V")
+    unsatisfied_link(CLASS_NAME, "resetReader", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -100,12 +104,12 @@ async fn set_out_color_space(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.imageio.plugins.jpeg.JPEGImageReader.setOutColorSpace(JI)V")
+    unsatisfied_link(CLASS_NAME, "setOutColorSpace", "(JI)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_source(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.imageio.plugins.jpeg.JPEGImageReader.setSource(J)V")
+    unsatisfied_link(CLASS_NAME, "setSource", "(J)V")
 }
 
 #[cfg(test)]
@@ -113,101 +117,129 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.imageio.plugins.jpeg.JPEGImageReader.abortRead(J)V"
-    )]
     async fn test_abort_read() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = abort_read(thread, Parameters::default()).await;
+        let result = abort_read(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/imageio/plugins/jpeg/JPEGImageReader",
+            "abortRead",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.imageio.plugins.jpeg.JPEGImageReader.clearNativeReadAbortFlag(J)V"
-    )]
     async fn test_clear_native_read_abort_flag() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = clear_native_read_abort_flag(thread, Parameters::default()).await;
+        let result = clear_native_read_abort_flag(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/imageio/plugins/jpeg/JPEGImageReader",
+            "clearNativeReadAbortFlag",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.imageio.plugins.jpeg.JPEGImageReader.disposeReader(J)V"
-    )]
     async fn test_dispose_reader() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = dispose_reader(thread, Parameters::default()).await;
+        let result = dispose_reader(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/imageio/plugins/jpeg/JPEGImageReader",
+            "disposeReader",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.imageio.plugins.jpeg.JPEGImageReader.initJPEGImageReader()J"
-    )]
     async fn test_init_jpeg_image_reader() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_jpeg_image_reader(thread, Parameters::default()).await;
+        let result = init_jpeg_image_reader(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/imageio/plugins/jpeg/JPEGImageReader",
+            "initJPEGImageReader",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.imageio.plugins.jpeg.JPEGImageReader.initReaderIDs(Ljava/lang/Class;Ljava/lang/Class;Ljava/lang/Class;)V"
-    )]
     async fn test_init_reader_i_ds() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_reader_i_ds(thread, Parameters::default()).await;
+        let result = init_reader_i_ds(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/imageio/plugins/jpeg/JPEGImageReader",
+            "initReaderIDs",
+            "(Ljava/lang/Class;Ljava/lang/Class;Ljava/lang/Class;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.imageio.plugins.jpeg.JPEGImageReader.readImage(IJ[BI[I[IIIIIII[Ljavax/imageio/plugins/jpeg/JPEGQTable;[Ljavax/imageio/plugins/jpeg/JPEGHuffmanTable;[Ljavax/imageio/plugins/jpeg/JPEGHuffmanTable;IIZ)Z"
-    )]
     async fn test_read_image() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = read_image(thread, Parameters::default()).await;
+        let result = read_image(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "com/sun/imageio/plugins/jpeg/JPEGImageReader", "readImage", "(IJ[BI[I[IIIIIII[Ljavax/imageio/plugins/jpeg/JPEGQTable;[Ljavax/imageio/plugins/jpeg/JPEGHuffmanTable;[Ljavax/imageio/plugins/jpeg/JPEGHuffmanTable;IIZ)Z");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.imageio.plugins.jpeg.JPEGImageReader.readImageHeader(JZZ)Z"
-    )]
     async fn test_read_image_header() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = read_image_header(thread, Parameters::default()).await;
+        let result = read_image_header(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/imageio/plugins/jpeg/JPEGImageReader",
+            "readImageHeader",
+            "(JZZ)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.imageio.plugins.jpeg.JPEGImageReader.resetLibraryState(J)V"
-    )]
     async fn test_reset_library_state() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = reset_library_state(thread, Parameters::default()).await;
+        let result = reset_library_state(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/imageio/plugins/jpeg/JPEGImageReader",
+            "resetLibraryState",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.imageio.plugins.jpeg.JPEGImageReader.resetReader(J)V"
-    )]
     async fn test_reset_reader() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = reset_reader(thread, Parameters::default()).await;
+        let result = reset_reader(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/imageio/plugins/jpeg/JPEGImageReader",
+            "resetReader",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.imageio.plugins.jpeg.JPEGImageReader.setOutColorSpace(JI)V"
-    )]
     async fn test_set_out_color_space() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_out_color_space(thread, Parameters::default()).await;
+        let result = set_out_color_space(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/imageio/plugins/jpeg/JPEGImageReader",
+            "setOutColorSpace",
+            "(JI)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.imageio.plugins.jpeg.JPEGImageReader.setSource(J)V"
-    )]
     async fn test_set_source() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_source(thread, Parameters::default()).await;
+        let result = set_source(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/imageio/plugins/jpeg/JPEGImageReader",
+            "setSource",
+            "(J)V",
+        );
     }
 }
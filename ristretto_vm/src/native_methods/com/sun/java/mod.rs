@@ -1,2 +1,3 @@
+#[cfg(feature = "awt")]
 pub(crate) mod swing;
 pub(crate) mod util;
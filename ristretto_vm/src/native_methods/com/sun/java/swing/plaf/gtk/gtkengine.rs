@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -138,7 +138,7 @@ async fn native_finish_painting(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKEngine.nativeFinishPainting([III)I")
+    unsatisfied_link(CLASS_NAME, "nativeFinishPainting", "([III)I")
 }
 
 #[async_recursion(?Send)]
@@ -146,7 +146,7 @@ async fn native_set_range_value(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKEngine.nativeSetRangeValue(IDDDD)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetRangeValue", "(IDDDD)V")
 }
 
 #[async_recursion(?Send)]
@@ -154,7 +154,7 @@ async fn native_start_painting(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKEngine.nativeStartPainting(II)V")
+    unsatisfied_link(CLASS_NAME, "nativeStartPainting", "(II)V")
 }
 
 #[async_recursion(?Send)]
@@ -162,7 +162,7 @@ async fn native_get_gtk_setting(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKEngine.nativeGetGTKSetting(I)Ljava/lang/Object;")
+    unsatisfied_link(CLASS_NAME, "nativeGetGTKSetting", "(I)Ljava/lang/Object;")
 }
 
 #[async_recursion(?Send)]
@@ -170,7 +170,11 @@ async fn native_paint_arrow(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintArrow(IIILjava/lang/String;IIIII)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativePaintArrow",
+        "(IIILjava/lang/String;IIIII)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -178,12 +182,16 @@ async fn native_paint_background(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintBackground(IIIIII)V")
+    unsatisfied_link(CLASS_NAME, "nativePaintBackground", "(IIIIII)V")
 }
 
 #[async_recursion(?Send)]
 async fn native_paint_box(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintBox(IIILjava/lang/String;IIIIII)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativePaintBox",
+        "(IIILjava/lang/String;IIIIII)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -191,7 +199,11 @@ async fn native_paint_box_gap(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintBoxGap(IIILjava/lang/String;IIIIIII)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativePaintBoxGap",
+        "(IIILjava/lang/String;IIIIIII)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -199,7 +211,11 @@ async fn native_paint_check(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintCheck(IILjava/lang/String;IIII)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativePaintCheck",
+        "(IILjava/lang/String;IIII)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -207,7 +223,11 @@ async fn native_paint_expander(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintExpander(IILjava/lang/String;IIIII)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativePaintExpander",
+        "(IILjava/lang/String;IIIII)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -215,7 +235,11 @@ async fn native_paint_extension(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintExtension(IIILjava/lang/String;IIIII)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativePaintExtension",
+        "(IIILjava/lang/String;IIIII)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -223,7 +247,11 @@ async fn native_paint_flat_box(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintFlatBox(IIILjava/lang/String;IIIIZ)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativePaintFlatBox",
+        "(IIILjava/lang/String;IIIIZ)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -231,7 +259,11 @@ async fn native_paint_focus(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintFocus(IILjava/lang/String;IIII)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativePaintFocus",
+        "(IILjava/lang/String;IIII)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -239,7 +271,11 @@ async fn native_paint_handle(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintHandle(IIILjava/lang/String;IIIII)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativePaintHandle",
+        "(IIILjava/lang/String;IIIII)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -247,7 +283,11 @@ async fn native_paint_hline(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintHline(IILjava/lang/String;IIII)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativePaintHline",
+        "(IILjava/lang/String;IIII)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -255,7 +295,11 @@ async fn native_paint_option(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintOption(IILjava/lang/String;IIII)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativePaintOption",
+        "(IILjava/lang/String;IIII)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -263,7 +307,11 @@ async fn native_paint_shadow(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintShadow(IIILjava/lang/String;IIIIII)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativePaintShadow",
+        "(IIILjava/lang/String;IIIIII)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -271,7 +319,11 @@ async fn native_paint_slider(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintSlider(IIILjava/lang/String;IIIIIZ)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativePaintSlider",
+        "(IIILjava/lang/String;IIIIIZ)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -279,7 +331,11 @@ async fn native_paint_vline(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintVline(IILjava/lang/String;IIII)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativePaintVline",
+        "(IILjava/lang/String;IIII)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -287,7 +343,7 @@ async fn native_switch_theme(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKEngine.nativeSwitchTheme()V")
+    unsatisfied_link(CLASS_NAME, "nativeSwitchTheme", "()V")
 }
 
 #[cfg(test)]
@@ -295,182 +351,242 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKEngine.nativeFinishPainting([III)I"
-    )]
     async fn test_native_finish_painting() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_finish_painting(thread, Parameters::default()).await;
+        let result = native_finish_painting(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKEngine",
+            "nativeFinishPainting",
+            "([III)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKEngine.nativeSetRangeValue(IDDDD)V"
-    )]
     async fn test_native_set_range_value() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_range_value(thread, Parameters::default()).await;
+        let result = native_set_range_value(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKEngine",
+            "nativeSetRangeValue",
+            "(IDDDD)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKEngine.nativeStartPainting(II)V"
-    )]
     async fn test_native_start_painting() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_start_painting(thread, Parameters::default()).await;
+        let result = native_start_painting(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKEngine",
+            "nativeStartPainting",
+            "(II)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKEngine.nativeGetGTKSetting(I)Ljava/lang/Object;"
-    )]
     async fn test_native_get_gtk_setting() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_gtk_setting(thread, Parameters::default()).await;
+        let result = native_get_gtk_setting(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKEngine",
+            "nativeGetGTKSetting",
+            "(I)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintArrow(IIILjava/lang/String;IIIII)V"
-    )]
     async fn test_native_paint_arrow() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_paint_arrow(thread, Parameters::default()).await;
+        let result = native_paint_arrow(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKEngine",
+            "nativePaintArrow",
+            "(IIILjava/lang/String;IIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintBackground(IIIIII)V"
-    )]
     async fn test_native_paint_background() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_paint_background(thread, Parameters::default()).await;
+        let result = native_paint_background(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKEngine",
+            "nativePaintBackground",
+            "(IIIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintBox(IIILjava/lang/String;IIIIII)V"
-    )]
     async fn test_native_paint_box() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_paint_box(thread, Parameters::default()).await;
+        let result = native_paint_box(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKEngine",
+            "nativePaintBox",
+            "(IIILjava/lang/String;IIIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintBoxGap(IIILjava/lang/String;IIIIIII)V"
-    )]
     async fn test_native_paint_box_gap() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_paint_box_gap(thread, Parameters::default()).await;
+        let result = native_paint_box_gap(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKEngine",
+            "nativePaintBoxGap",
+            "(IIILjava/lang/String;IIIIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintCheck(IILjava/lang/String;IIII)V"
-    )]
     async fn test_native_paint_check() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_paint_check(thread, Parameters::default()).await;
+        let result = native_paint_check(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKEngine",
+            "nativePaintCheck",
+            "(IILjava/lang/String;IIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintExpander(IILjava/lang/String;IIIII)V"
-    )]
     async fn test_native_paint_expander() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_paint_expander(thread, Parameters::default()).await;
+        let result = native_paint_expander(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKEngine",
+            "nativePaintExpander",
+            "(IILjava/lang/String;IIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintExtension(IIILjava/lang/String;IIIII)V"
-    )]
     async fn test_native_paint_extension() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_paint_extension(thread, Parameters::default()).await;
+        let result = native_paint_extension(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKEngine",
+            "nativePaintExtension",
+            "(IIILjava/lang/String;IIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintFlatBox(IIILjava/lang/String;IIIIZ)V"
-    )]
     async fn test_native_paint_flat_box() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_paint_flat_box(thread, Parameters::default()).await;
+        let result = native_paint_flat_box(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKEngine",
+            "nativePaintFlatBox",
+            "(IIILjava/lang/String;IIIIZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintFocus(IILjava/lang/String;IIII)V"
-    )]
     async fn test_native_paint_focus() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_paint_focus(thread, Parameters::default()).await;
+        let result = native_paint_focus(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKEngine",
+            "nativePaintFocus",
+            "(IILjava/lang/String;IIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintHandle(IIILjava/lang/String;IIIII)V"
-    )]
     async fn test_native_paint_handle() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_paint_handle(thread, Parameters::default()).await;
+        let result = native_paint_handle(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKEngine",
+            "nativePaintHandle",
+            "(IIILjava/lang/String;IIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintHline(IILjava/lang/String;IIII)V"
-    )]
     async fn test_native_paint_hline() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_paint_hline(thread, Parameters::default()).await;
+        let result = native_paint_hline(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKEngine",
+            "nativePaintHline",
+            "(IILjava/lang/String;IIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintOption(IILjava/lang/String;IIII)V"
-    )]
     async fn test_native_paint_option() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_paint_option(thread, Parameters::default()).await;
+        let result = native_paint_option(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKEngine",
+            "nativePaintOption",
+            "(IILjava/lang/String;IIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintShadow(IIILjava/lang/String;IIIIII)V"
-    )]
     async fn test_native_paint_shadow() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_paint_shadow(thread, Parameters::default()).await;
+        let result = native_paint_shadow(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKEngine",
+            "nativePaintShadow",
+            "(IIILjava/lang/String;IIIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintSlider(IIILjava/lang/String;IIIIIZ)V"
-    )]
     async fn test_native_paint_slider() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_paint_slider(thread, Parameters::default()).await;
+        let result = native_paint_slider(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKEngine",
+            "nativePaintSlider",
+            "(IIILjava/lang/String;IIIIIZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKEngine.nativePaintVline(IILjava/lang/String;IIII)V"
-    )]
     async fn test_native_paint_vline() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_paint_vline(thread, Parameters::default()).await;
+        let result = native_paint_vline(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKEngine",
+            "nativePaintVline",
+            "(IILjava/lang/String;IIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKEngine.nativeSwitchTheme()V"
-    )]
     async fn test_native_switch_theme() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_switch_theme(thread, Parameters::default()).await;
+        let result = native_switch_theme(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKEngine",
+            "nativeSwitchTheme",
+            "()V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -47,7 +47,11 @@ async fn native_get_class_value(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKStyle.nativeGetClassValue(ILjava/lang/String;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeGetClassValue",
+        "(ILjava/lang/String;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -55,7 +59,7 @@ async fn native_get_color_for_state(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKStyle.nativeGetColorForState(III)I")
+    unsatisfied_link(CLASS_NAME, "nativeGetColorForState", "(III)I")
 }
 
 #[async_recursion(?Send)]
@@ -63,7 +67,11 @@ async fn native_get_pango_font_name(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKStyle.nativeGetPangoFontName(I)Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeGetPangoFontName",
+        "(I)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -71,7 +79,7 @@ async fn native_get_x_thickness(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKStyle.nativeGetXThickness(I)I")
+    unsatisfied_link(CLASS_NAME, "nativeGetXThickness", "(I)I")
 }
 
 #[async_recursion(?Send)]
@@ -79,7 +87,7 @@ async fn native_get_y_thickness(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.java.swing.plaf.gtk.GTKStyle.nativeGetYThickness(I)I")
+    unsatisfied_link(CLASS_NAME, "nativeGetYThickness", "(I)I")
 }
 
 #[cfg(test)]
@@ -87,47 +95,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKStyle.nativeGetClassValue(ILjava/lang/String;)Ljava/lang/Object;"
-    )]
     async fn test_native_get_class_value() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_class_value(thread, Parameters::default()).await;
+        let result = native_get_class_value(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKStyle",
+            "nativeGetClassValue",
+            "(ILjava/lang/String;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKStyle.nativeGetColorForState(III)I"
-    )]
     async fn test_native_get_color_for_state() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_color_for_state(thread, Parameters::default()).await;
+        let result = native_get_color_for_state(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKStyle",
+            "nativeGetColorForState",
+            "(III)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKStyle.nativeGetPangoFontName(I)Ljava/lang/String;"
-    )]
     async fn test_native_get_pango_font_name() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_pango_font_name(thread, Parameters::default()).await;
+        let result = native_get_pango_font_name(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKStyle",
+            "nativeGetPangoFontName",
+            "(I)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKStyle.nativeGetXThickness(I)I"
-    )]
     async fn test_native_get_x_thickness() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_x_thickness(thread, Parameters::default()).await;
+        let result = native_get_x_thickness(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKStyle",
+            "nativeGetXThickness",
+            "(I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.swing.plaf.gtk.GTKStyle.nativeGetYThickness(I)I"
-    )]
     async fn test_native_get_y_thickness() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_y_thickness(thread, Parameters::default()).await;
+        let result = native_get_y_thickness(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/swing/plaf/gtk/GTKStyle",
+            "nativeGetYThickness",
+            "(I)I",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -43,22 +43,26 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn finish(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.java.util.jar.pack.NativeUnpack.finish()J")
+    unsatisfied_link(CLASS_NAME, "finish", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn get_next_file(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.java.util.jar.pack.NativeUnpack.getNextFile([Ljava/lang/Object;)Z")
+    unsatisfied_link(CLASS_NAME, "getNextFile", "([Ljava/lang/Object;)Z")
 }
 
 #[async_recursion(?Send)]
 async fn get_option(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.java.util.jar.pack.NativeUnpack.getOption(Ljava/lang/String;)Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getOption",
+        "(Ljava/lang/String;)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_unused_input(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.java.util.jar.pack.NativeUnpack.getUnusedInput()Ljava/nio/ByteBuffer;")
+    unsatisfied_link(CLASS_NAME, "getUnusedInput", "()Ljava/nio/ByteBuffer;")
 }
 
 #[async_recursion(?Send)]
@@ -68,14 +72,16 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn set_option(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!(
-        "com.sun.java.util.jar.pack.NativeUnpack.setOption(Ljava/lang/String;Ljava/lang/String;)Z"
+    unsatisfied_link(
+        CLASS_NAME,
+        "setOption",
+        "(Ljava/lang/String;Ljava/lang/String;)Z",
     )
 }
 
 #[async_recursion(?Send)]
 async fn start(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.java.util.jar.pack.NativeUnpack.start(Ljava/nio/ByteBuffer;J)J")
+    unsatisfied_link(CLASS_NAME, "start", "(Ljava/nio/ByteBuffer;J)J")
 }
 
 #[cfg(test)]
@@ -83,39 +89,51 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.util.jar.pack.NativeUnpack.finish()J"
-    )]
     async fn test_finish() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = finish(thread, Parameters::default()).await;
+        let result = finish(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/util/jar/pack/NativeUnpack",
+            "finish",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.util.jar.pack.NativeUnpack.getNextFile([Ljava/lang/Object;)Z"
-    )]
     async fn test_get_next_file() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_next_file(thread, Parameters::default()).await;
+        let result = get_next_file(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/util/jar/pack/NativeUnpack",
+            "getNextFile",
+            "([Ljava/lang/Object;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.util.jar.pack.NativeUnpack.getOption(Ljava/lang/String;)Ljava/lang/String;"
-    )]
     async fn test_get_option() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_option(thread, Parameters::default()).await;
+        let result = get_option(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/util/jar/pack/NativeUnpack",
+            "getOption",
+            "(Ljava/lang/String;)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.util.jar.pack.NativeUnpack.getUnusedInput()Ljava/nio/ByteBuffer;"
-    )]
     async fn test_get_unused_input() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_unused_input(thread, Parameters::default()).await;
+        let result = get_unused_input(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/util/jar/pack/NativeUnpack",
+            "getUnusedInput",
+            "()Ljava/nio/ByteBuffer;",
+        );
     }
 
     #[tokio::test]
@@ -127,20 +145,26 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.util.jar.pack.NativeUnpack.setOption(Ljava/lang/String;Ljava/lang/String;)Z"
-    )]
     async fn test_set_option() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_option(thread, Parameters::default()).await;
+        let result = set_option(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/util/jar/pack/NativeUnpack",
+            "setOption",
+            "(Ljava/lang/String;Ljava/lang/String;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.java.util.jar.pack.NativeUnpack.start(Ljava/nio/ByteBuffer;J)J"
-    )]
     async fn test_start() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = start(thread, Parameters::default()).await;
+        let result = start(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/java/util/jar/pack/NativeUnpack",
+            "start",
+            "(Ljava/nio/ByteBuffer;J)J",
+        );
     }
 }
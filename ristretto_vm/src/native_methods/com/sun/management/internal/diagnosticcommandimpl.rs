@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -41,7 +41,11 @@ async fn execute_diagnostic_command(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.DiagnosticCommandImpl.executeDiagnosticCommand(Ljava/lang/String;)Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "executeDiagnosticCommand",
+        "(Ljava/lang/String;)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -49,7 +53,11 @@ async fn get_diagnostic_command_info(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.DiagnosticCommandImpl.getDiagnosticCommandInfo([Ljava/lang/String;)[Lcom/sun/management/internal/DiagnosticCommandInfo;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getDiagnosticCommandInfo",
+        "([Ljava/lang/String;)[Lcom/sun/management/internal/DiagnosticCommandInfo;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -57,7 +65,7 @@ async fn get_diagnostic_commands(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.DiagnosticCommandImpl.getDiagnosticCommands()[Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getDiagnosticCommands", "()[Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
@@ -65,7 +73,7 @@ async fn set_notification_enabled(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.DiagnosticCommandImpl.setNotificationEnabled(Z)V")
+    unsatisfied_link(CLASS_NAME, "setNotificationEnabled", "(Z)V")
 }
 
 #[cfg(test)]
@@ -73,38 +81,50 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.DiagnosticCommandImpl.executeDiagnosticCommand(Ljava/lang/String;)Ljava/lang/String;"
-    )]
     async fn test_execute_diagnostic_command() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = execute_diagnostic_command(thread, Parameters::default()).await;
+        let result = execute_diagnostic_command(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/DiagnosticCommandImpl",
+            "executeDiagnosticCommand",
+            "(Ljava/lang/String;)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.DiagnosticCommandImpl.getDiagnosticCommandInfo([Ljava/lang/String;)[Lcom/sun/management/internal/DiagnosticCommandInfo;"
-    )]
     async fn test_get_diagnostic_command_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_diagnostic_command_info(thread, Parameters::default()).await;
+        let result = get_diagnostic_command_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/DiagnosticCommandImpl",
+            "getDiagnosticCommandInfo",
+            "([Ljava/lang/String;)[Lcom/sun/management/internal/DiagnosticCommandInfo;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.DiagnosticCommandImpl.getDiagnosticCommands()[Ljava/lang/String;"
-    )]
     async fn test_get_diagnostic_commands() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_diagnostic_commands(thread, Parameters::default()).await;
+        let result = get_diagnostic_commands(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/DiagnosticCommandImpl",
+            "getDiagnosticCommands",
+            "()[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.DiagnosticCommandImpl.setNotificationEnabled(Z)V"
-    )]
     async fn test_set_notification_enabled() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_notification_enabled(thread, Parameters::default()).await;
+        let result = set_notification_enabled(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/DiagnosticCommandImpl",
+            "setNotificationEnabled",
+            "(Z)V",
+        );
     }
 }
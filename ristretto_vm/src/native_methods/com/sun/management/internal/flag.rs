@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -60,12 +60,16 @@ async fn get_all_flag_names(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.Flag.getAllFlagNames()[Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getAllFlagNames", "()[Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
 async fn get_flags(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.Flag.getFlags([Ljava/lang/String;[Lcom/sun/management/internal/Flag;I)I")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getFlags",
+        "([Ljava/lang/String;[Lcom/sun/management/internal/Flag;I)I",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -73,32 +77,36 @@ async fn get_internal_flag_count(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.Flag.getInternalFlagCount()I")
+    unsatisfied_link(CLASS_NAME, "getInternalFlagCount", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn initialize(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.Flag.initialize()V")
+    unsatisfied_link(CLASS_NAME, "initialize", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn set_boolean_value(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.Flag.setBooleanValue(Ljava/lang/String;Z)V")
+    unsatisfied_link(CLASS_NAME, "setBooleanValue", "(Ljava/lang/String;Z)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_double_value(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.Flag.setDoubleValue(Ljava/lang/String;D)V")
+    unsatisfied_link(CLASS_NAME, "setDoubleValue", "(Ljava/lang/String;D)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_long_value(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.Flag.setLongValue(Ljava/lang/String;J)V")
+    unsatisfied_link(CLASS_NAME, "setLongValue", "(Ljava/lang/String;J)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_string_value(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.Flag.setStringValue(Ljava/lang/String;Ljava/lang/String;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "setStringValue",
+        "(Ljava/lang/String;Ljava/lang/String;)V",
+    )
 }
 
 #[cfg(test)]
@@ -106,74 +114,98 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.Flag.getAllFlagNames()[Ljava/lang/String;"
-    )]
     async fn test_get_all_flag_names() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_all_flag_names(thread, Parameters::default()).await;
+        let result = get_all_flag_names(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/Flag",
+            "getAllFlagNames",
+            "()[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.Flag.getFlags([Ljava/lang/String;[Lcom/sun/management/internal/Flag;I)I"
-    )]
     async fn test_get_flags() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_flags(thread, Parameters::default()).await;
+        let result = get_flags(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/Flag",
+            "getFlags",
+            "([Ljava/lang/String;[Lcom/sun/management/internal/Flag;I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.Flag.getInternalFlagCount()I"
-    )]
     async fn test_get_internal_flag_count() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_internal_flag_count(thread, Parameters::default()).await;
+        let result = get_internal_flag_count(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/Flag",
+            "getInternalFlagCount",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.Flag.initialize()V"
-    )]
     async fn test_initialize() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = initialize(thread, Parameters::default()).await;
+        let result = initialize(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/Flag",
+            "initialize",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.Flag.setBooleanValue(Ljava/lang/String;Z)V"
-    )]
     async fn test_set_boolean_value() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_boolean_value(thread, Parameters::default()).await;
+        let result = set_boolean_value(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/Flag",
+            "setBooleanValue",
+            "(Ljava/lang/String;Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.Flag.setDoubleValue(Ljava/lang/String;D)V"
-    )]
     async fn test_set_double_value() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_double_value(thread, Parameters::default()).await;
+        let result = set_double_value(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/Flag",
+            "setDoubleValue",
+            "(Ljava/lang/String;D)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.Flag.setLongValue(Ljava/lang/String;J)V"
-    )]
     async fn test_set_long_value() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_long_value(thread, Parameters::default()).await;
+        let result = set_long_value(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/Flag",
+            "setLongValue",
+            "(Ljava/lang/String;J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.Flag.setStringValue(Ljava/lang/String;Ljava/lang/String;)V"
-    )]
     async fn test_set_string_value() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_string_value(thread, Parameters::default()).await;
+        let result = set_string_value(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/Flag",
+            "setStringValue",
+            "(Ljava/lang/String;Ljava/lang/String;)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,7 +23,11 @@ async fn set_notification_enabled(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.GarbageCollectorExtImpl.setNotificationEnabled(Lcom/sun/management/GarbageCollectorMXBean;Z)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "setNotificationEnabled",
+        "(Lcom/sun/management/GarbageCollectorMXBean;Z)V",
+    )
 }
 
 #[cfg(test)]
@@ -31,11 +35,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.GarbageCollectorExtImpl.setNotificationEnabled(Lcom/sun/management/GarbageCollectorMXBean;Z)V"
-    )]
     async fn test_set_notification_enabled() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_notification_enabled(thread, Parameters::default()).await;
+        let result = set_notification_enabled(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/GarbageCollectorExtImpl",
+            "setNotificationEnabled",
+            "(Lcom/sun/management/GarbageCollectorMXBean;Z)V",
+        );
     }
 }
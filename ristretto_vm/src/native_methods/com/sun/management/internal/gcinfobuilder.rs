@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -30,7 +30,11 @@ async fn fill_gc_attribute_info(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.GcInfoBuilder.fillGcAttributeInfo(Ljava/lang/management/GarbageCollectorMXBean;I[Ljava/lang/String;[C[Ljava/lang/String;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "fillGcAttributeInfo",
+        "(Ljava/lang/management/GarbageCollectorMXBean;I[Ljava/lang/String;[C[Ljava/lang/String;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -38,7 +42,7 @@ async fn get_last_gc_info_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.GcInfoBuilder.getLastGcInfo0(Ljava/lang/management/GarbageCollectorMXBean;I[Ljava/lang/Object;[C[Ljava/lang/management/MemoryUsage;[Ljava/lang/management/MemoryUsage;)Lcom/sun/management/GcInfo;")
+    unsatisfied_link(CLASS_NAME, "getLastGcInfo0", "(Ljava/lang/management/GarbageCollectorMXBean;I[Ljava/lang/Object;[C[Ljava/lang/management/MemoryUsage;[Ljava/lang/management/MemoryUsage;)Lcom/sun/management/GcInfo;")
 }
 
 #[async_recursion(?Send)]
@@ -46,7 +50,11 @@ async fn get_num_gc_ext_attributes(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.GcInfoBuilder.getNumGcExtAttributes(Ljava/lang/management/GarbageCollectorMXBean;)I")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getNumGcExtAttributes",
+        "(Ljava/lang/management/GarbageCollectorMXBean;)I",
+    )
 }
 
 #[cfg(test)]
@@ -54,29 +62,28 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.GcInfoBuilder.fillGcAttributeInfo(Ljava/lang/management/GarbageCollectorMXBean;I[Ljava/lang/String;[C[Ljava/lang/String;)V"
-    )]
     async fn test_fill_gc_attribute_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fill_gc_attribute_info(thread, Parameters::default()).await;
+        let result = fill_gc_attribute_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "com/sun/management/internal/GcInfoBuilder", "fillGcAttributeInfo", "(Ljava/lang/management/GarbageCollectorMXBean;I[Ljava/lang/String;[C[Ljava/lang/String;)V");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.GcInfoBuilder.getLastGcInfo0(Ljava/lang/management/GarbageCollectorMXBean;I[Ljava/lang/Object;[C[Ljava/lang/management/MemoryUsage;[Ljava/lang/management/MemoryUsage;)Lcom/sun/management/GcInfo;"
-    )]
     async fn test_get_last_gc_info_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_last_gc_info_0(thread, Parameters::default()).await;
+        let result = get_last_gc_info_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "com/sun/management/internal/GcInfoBuilder", "getLastGcInfo0", "(Ljava/lang/management/GarbageCollectorMXBean;I[Ljava/lang/Object;[C[Ljava/lang/management/MemoryUsage;[Ljava/lang/management/MemoryUsage;)Lcom/sun/management/GcInfo;");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.GcInfoBuilder.getNumGcExtAttributes(Ljava/lang/management/GarbageCollectorMXBean;)I"
-    )]
     async fn test_get_num_gc_ext_attributes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_num_gc_ext_attributes(thread, Parameters::default()).await;
+        let result = get_num_gc_ext_attributes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/GcInfoBuilder",
+            "getNumGcExtAttributes",
+            "(Ljava/lang/management/GarbageCollectorMXBean;)I",
+        );
     }
 }
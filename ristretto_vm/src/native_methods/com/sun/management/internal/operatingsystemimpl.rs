@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -120,12 +120,12 @@ async fn get_committed_virtual_memory_size_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.OperatingSystemImpl.getCommittedVirtualMemorySize0()J")
+    unsatisfied_link(CLASS_NAME, "getCommittedVirtualMemorySize0", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn get_cpu_load_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.OperatingSystemImpl.getCpuLoad0()D")
+    unsatisfied_link(CLASS_NAME, "getCpuLoad0", "()D")
 }
 
 #[async_recursion(?Send)]
@@ -133,7 +133,7 @@ async fn get_free_physical_memory_size_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.OperatingSystemImpl.getFreePhysicalMemorySize0()J")
+    unsatisfied_link(CLASS_NAME, "getFreePhysicalMemorySize0", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -141,7 +141,7 @@ async fn get_free_memory_size_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.OperatingSystemImpl.getFreeMemorySize0()J")
+    unsatisfied_link(CLASS_NAME, "getFreeMemorySize0", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -149,7 +149,7 @@ async fn get_free_swap_space_size_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.OperatingSystemImpl.getFreeSwapSpaceSize0()J")
+    unsatisfied_link(CLASS_NAME, "getFreeSwapSpaceSize0", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -157,7 +157,7 @@ async fn get_host_configured_cpu_count_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.OperatingSystemImpl.getHostConfiguredCpuCount0()I")
+    unsatisfied_link(CLASS_NAME, "getHostConfiguredCpuCount0", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -165,7 +165,7 @@ async fn get_host_online_cpu_count_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.OperatingSystemImpl.getHostOnlineCpuCount0()I")
+    unsatisfied_link(CLASS_NAME, "getHostOnlineCpuCount0", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -173,7 +173,7 @@ async fn get_host_total_cpu_ticks_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.OperatingSystemImpl.getHostTotalCpuTicks0()J")
+    unsatisfied_link(CLASS_NAME, "getHostTotalCpuTicks0", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -181,7 +181,7 @@ async fn get_max_file_descriptor_count_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.OperatingSystemImpl.getMaxFileDescriptorCount0()J")
+    unsatisfied_link(CLASS_NAME, "getMaxFileDescriptorCount0", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -189,7 +189,7 @@ async fn get_open_file_descriptor_count_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.OperatingSystemImpl.getOpenFileDescriptorCount0()J")
+    unsatisfied_link(CLASS_NAME, "getOpenFileDescriptorCount0", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -197,7 +197,7 @@ async fn get_process_cpu_load_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.OperatingSystemImpl.getProcessCpuLoad0()D")
+    unsatisfied_link(CLASS_NAME, "getProcessCpuLoad0", "()D")
 }
 
 #[async_recursion(?Send)]
@@ -205,7 +205,7 @@ async fn get_process_cpu_time_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.OperatingSystemImpl.getProcessCpuTime0()J")
+    unsatisfied_link(CLASS_NAME, "getProcessCpuTime0", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -213,7 +213,7 @@ async fn get_single_cpu_load_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.OperatingSystemImpl.getSingleCpuLoad0(I)D")
+    unsatisfied_link(CLASS_NAME, "getSingleCpuLoad0", "(I)D")
 }
 
 #[async_recursion(?Send)]
@@ -221,7 +221,7 @@ async fn get_system_cpu_load_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.OperatingSystemImpl.getSystemCpuLoad0()D")
+    unsatisfied_link(CLASS_NAME, "getSystemCpuLoad0", "()D")
 }
 
 #[async_recursion(?Send)]
@@ -229,7 +229,7 @@ async fn get_total_memory_size_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.OperatingSystemImpl.getTotalMemorySize0()J")
+    unsatisfied_link(CLASS_NAME, "getTotalMemorySize0", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -237,7 +237,7 @@ async fn get_total_physical_memory_size_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.OperatingSystemImpl.getTotalPhysicalMemorySize0()J")
+    unsatisfied_link(CLASS_NAME, "getTotalPhysicalMemorySize0", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -245,7 +245,7 @@ async fn get_total_swap_space_size_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.management.internal.OperatingSystemImpl.getTotalSwapSpaceSize0()J")
+    unsatisfied_link(CLASS_NAME, "getTotalSwapSpaceSize0", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -258,156 +258,207 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.OperatingSystemImpl.getCommittedVirtualMemorySize0()J"
-    )]
     async fn test_get_committed_virtual_memory_size_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_committed_virtual_memory_size_0(thread, Parameters::default()).await;
+        let result = get_committed_virtual_memory_size_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/OperatingSystemImpl",
+            "getCommittedVirtualMemorySize0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.OperatingSystemImpl.getCpuLoad0()D"
-    )]
     async fn test_get_cpu_load_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_cpu_load_0(thread, Parameters::default()).await;
+        let result = get_cpu_load_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/OperatingSystemImpl",
+            "getCpuLoad0",
+            "()D",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.OperatingSystemImpl.getFreePhysicalMemorySize0()J"
-    )]
     async fn test_get_free_physical_memory_size_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_free_physical_memory_size_0(thread, Parameters::default()).await;
+        let result = get_free_physical_memory_size_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/OperatingSystemImpl",
+            "getFreePhysicalMemorySize0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.OperatingSystemImpl.getFreeMemorySize0()J"
-    )]
     async fn test_get_free_memory_size_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_free_memory_size_0(thread, Parameters::default()).await;
+        let result = get_free_memory_size_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/OperatingSystemImpl",
+            "getFreeMemorySize0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.OperatingSystemImpl.getFreeSwapSpaceSize0()J"
-    )]
     async fn test_get_free_swap_space_size_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_free_swap_space_size_0(thread, Parameters::default()).await;
+        let result = get_free_swap_space_size_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/OperatingSystemImpl",
+            "getFreeSwapSpaceSize0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.OperatingSystemImpl.getHostConfiguredCpuCount0()I"
-    )]
     async fn test_get_host_configured_cpu_count_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_host_configured_cpu_count_0(thread, Parameters::default()).await;
+        let result = get_host_configured_cpu_count_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/OperatingSystemImpl",
+            "getHostConfiguredCpuCount0",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.OperatingSystemImpl.getHostOnlineCpuCount0()I"
-    )]
     async fn test_get_host_online_cpu_count_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_host_online_cpu_count_0(thread, Parameters::default()).await;
+        let result = get_host_online_cpu_count_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/OperatingSystemImpl",
+            "getHostOnlineCpuCount0",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.OperatingSystemImpl.getHostTotalCpuTicks0()J"
-    )]
     async fn test_get_host_total_cpu_ticks_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_host_total_cpu_ticks_0(thread, Parameters::default()).await;
+        let result = get_host_total_cpu_ticks_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/OperatingSystemImpl",
+            "getHostTotalCpuTicks0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.OperatingSystemImpl.getMaxFileDescriptorCount0()J"
-    )]
     async fn test_get_max_file_descriptor_count_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_max_file_descriptor_count_0(thread, Parameters::default()).await;
+        let result = get_max_file_descriptor_count_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/OperatingSystemImpl",
+            "getMaxFileDescriptorCount0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.OperatingSystemImpl.getOpenFileDescriptorCount0()J"
-    )]
     async fn test_get_open_file_descriptor_count_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_open_file_descriptor_count_0(thread, Parameters::default()).await;
+        let result = get_open_file_descriptor_count_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/OperatingSystemImpl",
+            "getOpenFileDescriptorCount0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.OperatingSystemImpl.getProcessCpuLoad0()D"
-    )]
     async fn test_get_process_cpu_load_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_process_cpu_load_0(thread, Parameters::default()).await;
+        let result = get_process_cpu_load_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/OperatingSystemImpl",
+            "getProcessCpuLoad0",
+            "()D",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.OperatingSystemImpl.getProcessCpuTime0()J"
-    )]
     async fn test_get_process_cpu_time_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_process_cpu_time_0(thread, Parameters::default()).await;
+        let result = get_process_cpu_time_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/OperatingSystemImpl",
+            "getProcessCpuTime0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.OperatingSystemImpl.getSingleCpuLoad0(I)D"
-    )]
     async fn test_get_single_cpu_load_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_single_cpu_load_0(thread, Parameters::default()).await;
+        let result = get_single_cpu_load_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/OperatingSystemImpl",
+            "getSingleCpuLoad0",
+            "(I)D",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.OperatingSystemImpl.getSystemCpuLoad0()D"
-    )]
     async fn test_get_system_cpu_load_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_system_cpu_load_0(thread, Parameters::default()).await;
+        let result = get_system_cpu_load_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/OperatingSystemImpl",
+            "getSystemCpuLoad0",
+            "()D",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.OperatingSystemImpl.getTotalMemorySize0()J"
-    )]
     async fn test_get_total_memory_size_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_total_memory_size_0(thread, Parameters::default()).await;
+        let result = get_total_memory_size_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/OperatingSystemImpl",
+            "getTotalMemorySize0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.OperatingSystemImpl.getTotalPhysicalMemorySize0()J"
-    )]
     async fn test_get_total_physical_memory_size_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_total_physical_memory_size_0(thread, Parameters::default()).await;
+        let result = get_total_physical_memory_size_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/OperatingSystemImpl",
+            "getTotalPhysicalMemorySize0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.management.internal.OperatingSystemImpl.getTotalSwapSpaceSize0()J"
-    )]
     async fn test_get_total_swap_space_size_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_total_swap_space_size_0(thread, Parameters::default()).await;
+        let result = get_total_swap_space_size_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/management/internal/OperatingSystemImpl",
+            "getTotalSwapSpaceSize0",
+            "()J",
+        );
     }
 
     #[tokio::test]
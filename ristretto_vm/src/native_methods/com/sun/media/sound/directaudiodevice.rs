@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -49,22 +49,22 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn n_available(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.DirectAudioDevice.nAvailable(JZ)I")
+    unsatisfied_link(CLASS_NAME, "nAvailable", "(JZ)I")
 }
 
 #[async_recursion(?Send)]
 async fn n_close(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.DirectAudioDevice.nClose(JZ)V")
+    unsatisfied_link(CLASS_NAME, "nClose", "(JZ)V")
 }
 
 #[async_recursion(?Send)]
 async fn n_flush(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.DirectAudioDevice.nFlush(JZ)V")
+    unsatisfied_link(CLASS_NAME, "nFlush", "(JZ)V")
 }
 
 #[async_recursion(?Send)]
 async fn n_get_buffer_size(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.DirectAudioDevice.nGetBufferSize(JZ)I")
+    unsatisfied_link(CLASS_NAME, "nGetBufferSize", "(JZ)I")
 }
 
 #[async_recursion(?Send)]
@@ -72,12 +72,12 @@ async fn n_get_byte_position(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.DirectAudioDevice.nGetBytePosition(JZJ)J")
+    unsatisfied_link(CLASS_NAME, "nGetBytePosition", "(JZJ)J")
 }
 
 #[async_recursion(?Send)]
 async fn n_get_formats(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.DirectAudioDevice.nGetFormats(IIZLjava/util/Vector;)V")
+    unsatisfied_link(CLASS_NAME, "nGetFormats", "(IIZLjava/util/Vector;)V")
 }
 
 #[async_recursion(?Send)]
@@ -85,17 +85,17 @@ async fn n_is_still_draining(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.DirectAudioDevice.nIsStillDraining(JZ)Z")
+    unsatisfied_link(CLASS_NAME, "nIsStillDraining", "(JZ)Z")
 }
 
 #[async_recursion(?Send)]
 async fn n_open(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.DirectAudioDevice.nOpen(IIZIFIIIZZI)J")
+    unsatisfied_link(CLASS_NAME, "nOpen", "(IIZIFIIIZZI)J")
 }
 
 #[async_recursion(?Send)]
 async fn n_read(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.DirectAudioDevice.nRead(J[BIII)I")
+    unsatisfied_link(CLASS_NAME, "nRead", "(J[BIII)I")
 }
 
 #[async_recursion(?Send)]
@@ -103,12 +103,12 @@ async fn n_requires_servicing(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.DirectAudioDevice.nRequiresServicing(JZ)Z")
+    unsatisfied_link(CLASS_NAME, "nRequiresServicing", "(JZ)Z")
 }
 
 #[async_recursion(?Send)]
 async fn n_service(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.DirectAudioDevice.nService(JZ)V")
+    unsatisfied_link(CLASS_NAME, "nService", "(JZ)V")
 }
 
 #[async_recursion(?Send)]
@@ -116,22 +116,22 @@ async fn n_set_byte_position(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.DirectAudioDevice.nSetBytePosition(JZJ)V")
+    unsatisfied_link(CLASS_NAME, "nSetBytePosition", "(JZJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn n_start(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.DirectAudioDevice.nStart(JZ)V")
+    unsatisfied_link(CLASS_NAME, "nStart", "(JZ)V")
 }
 
 #[async_recursion(?Send)]
 async fn n_stop(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.DirectAudioDevice.nStop(JZ)V")
+    unsatisfied_link(CLASS_NAME, "nStop", "(JZ)V")
 }
 
 #[async_recursion(?Send)]
 async fn n_write(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.DirectAudioDevice.nWrite(J[BIIIFF)I")
+    unsatisfied_link(CLASS_NAME, "nWrite", "(J[BIIIFF)I")
 }
 
 #[cfg(test)]
@@ -139,137 +139,182 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.DirectAudioDevice.nAvailable(JZ)I"
-    )]
     async fn test_n_available() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_available(thread, Parameters::default()).await;
+        let result = n_available(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/DirectAudioDevice",
+            "nAvailable",
+            "(JZ)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.DirectAudioDevice.nClose(JZ)V"
-    )]
     async fn test_n_close() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_close(thread, Parameters::default()).await;
+        let result = n_close(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/DirectAudioDevice",
+            "nClose",
+            "(JZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.DirectAudioDevice.nFlush(JZ)V"
-    )]
     async fn test_n_flush() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_flush(thread, Parameters::default()).await;
+        let result = n_flush(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/DirectAudioDevice",
+            "nFlush",
+            "(JZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.DirectAudioDevice.nGetBufferSize(JZ)I"
-    )]
     async fn test_n_get_buffer_size() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_get_buffer_size(thread, Parameters::default()).await;
+        let result = n_get_buffer_size(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/DirectAudioDevice",
+            "nGetBufferSize",
+            "(JZ)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.DirectAudioDevice.nGetBytePosition(JZJ)J"
-    )]
     async fn test_n_get_byte_position() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_get_byte_position(thread, Parameters::default()).await;
+        let result = n_get_byte_position(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/DirectAudioDevice",
+            "nGetBytePosition",
+            "(JZJ)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.DirectAudioDevice.nGetFormats(IIZLjava/util/Vector;)V"
-    )]
     async fn test_n_get_formats() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_get_formats(thread, Parameters::default()).await;
+        let result = n_get_formats(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/DirectAudioDevice",
+            "nGetFormats",
+            "(IIZLjava/util/Vector;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.DirectAudioDevice.nIsStillDraining(JZ)Z"
-    )]
     async fn test_n_is_still_draining() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_is_still_draining(thread, Parameters::default()).await;
+        let result = n_is_still_draining(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/DirectAudioDevice",
+            "nIsStillDraining",
+            "(JZ)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.DirectAudioDevice.nOpen(IIZIFIIIZZI)J"
-    )]
     async fn test_n_open() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_open(thread, Parameters::default()).await;
+        let result = n_open(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/DirectAudioDevice",
+            "nOpen",
+            "(IIZIFIIIZZI)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.DirectAudioDevice.nRead(J[BIII)I"
-    )]
     async fn test_n_read() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_read(thread, Parameters::default()).await;
+        let result = n_read(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/DirectAudioDevice",
+            "nRead",
+            "(J[BIII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.DirectAudioDevice.nRequiresServicing(JZ)Z"
-    )]
     async fn test_n_requires_servicing() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_requires_servicing(thread, Parameters::default()).await;
+        let result = n_requires_servicing(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/DirectAudioDevice",
+            "nRequiresServicing",
+            "(JZ)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.DirectAudioDevice.nService(JZ)V"
-    )]
     async fn test_n_service() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_service(thread, Parameters::default()).await;
+        let result = n_service(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/DirectAudioDevice",
+            "nService",
+            "(JZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.DirectAudioDevice.nSetBytePosition(JZJ)V"
-    )]
     async fn test_n_set_byte_position() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_set_byte_position(thread, Parameters::default()).await;
+        let result = n_set_byte_position(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/DirectAudioDevice",
+            "nSetBytePosition",
+            "(JZJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.DirectAudioDevice.nStart(JZ)V"
-    )]
     async fn test_n_start() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_start(thread, Parameters::default()).await;
+        let result = n_start(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/DirectAudioDevice",
+            "nStart",
+            "(JZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.DirectAudioDevice.nStop(JZ)V"
-    )]
     async fn test_n_stop() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_stop(thread, Parameters::default()).await;
+        let result = n_stop(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/DirectAudioDevice",
+            "nStop",
+            "(JZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.DirectAudioDevice.nWrite(J[BIIIFF)I"
-    )]
     async fn test_n_write() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_write(thread, Parameters::default()).await;
+        let result = n_write(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/DirectAudioDevice",
+            "nWrite",
+            "(J[BIIIFF)I",
+        );
     }
 }
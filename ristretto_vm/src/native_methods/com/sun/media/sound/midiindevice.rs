@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -20,32 +20,32 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn n_close(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.MidiInDevice.nClose(J)V")
+    unsatisfied_link(CLASS_NAME, "nClose", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn n_get_messages(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.MidiInDevice.nGetMessages(J)V")
+    unsatisfied_link(CLASS_NAME, "nGetMessages", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn n_get_time_stamp(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.MidiInDevice.nGetTimeStamp(J)J")
+    unsatisfied_link(CLASS_NAME, "nGetTimeStamp", "(J)J")
 }
 
 #[async_recursion(?Send)]
 async fn n_open(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.MidiInDevice.nOpen(I)J")
+    unsatisfied_link(CLASS_NAME, "nOpen", "(I)J")
 }
 
 #[async_recursion(?Send)]
 async fn n_start(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.MidiInDevice.nStart(J)V")
+    unsatisfied_link(CLASS_NAME, "nStart", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn n_stop(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.MidiInDevice.nStop(J)V")
+    unsatisfied_link(CLASS_NAME, "nStop", "(J)V")
 }
 
 #[cfg(test)]
@@ -53,48 +53,74 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: com.sun.media.sound.MidiInDevice.nClose(J)V")]
     async fn test_n_close() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_close(thread, Parameters::default()).await;
+        let result = n_close(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/MidiInDevice",
+            "nClose",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.MidiInDevice.nGetMessages(J)V"
-    )]
     async fn test_n_get_messages() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_get_messages(thread, Parameters::default()).await;
+        let result = n_get_messages(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/MidiInDevice",
+            "nGetMessages",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.MidiInDevice.nGetTimeStamp(J)J"
-    )]
     async fn test_n_get_time_stamp() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_get_time_stamp(thread, Parameters::default()).await;
+        let result = n_get_time_stamp(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/MidiInDevice",
+            "nGetTimeStamp",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: com.sun.media.sound.MidiInDevice.nOpen(I)J")]
     async fn test_n_open() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_open(thread, Parameters::default()).await;
+        let result = n_open(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/MidiInDevice",
+            "nOpen",
+            "(I)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: com.sun.media.sound.MidiInDevice.nStart(J)V")]
     async fn test_n_start() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_start(thread, Parameters::default()).await;
+        let result = n_start(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/MidiInDevice",
+            "nStart",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: com.sun.media.sound.MidiInDevice.nStop(J)V")]
     async fn test_n_stop() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_stop(thread, Parameters::default()).await;
+        let result = n_stop(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/MidiInDevice",
+            "nStop",
+            "(J)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -29,17 +29,17 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn n_close(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.MidiOutDevice.nClose(J)V")
+    unsatisfied_link(CLASS_NAME, "nClose", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn n_get_time_stamp(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.MidiOutDevice.nGetTimeStamp(J)J")
+    unsatisfied_link(CLASS_NAME, "nGetTimeStamp", "(J)J")
 }
 
 #[async_recursion(?Send)]
 async fn n_open(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.MidiOutDevice.nOpen(I)J")
+    unsatisfied_link(CLASS_NAME, "nOpen", "(I)J")
 }
 
 #[async_recursion(?Send)]
@@ -47,7 +47,7 @@ async fn n_send_long_message(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.MidiOutDevice.nSendLongMessage(J[BIJ)V")
+    unsatisfied_link(CLASS_NAME, "nSendLongMessage", "(J[BIJ)V")
 }
 
 #[async_recursion(?Send)]
@@ -55,7 +55,7 @@ async fn n_send_short_message(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.MidiOutDevice.nSendShortMessage(JIJ)V")
+    unsatisfied_link(CLASS_NAME, "nSendShortMessage", "(JIJ)V")
 }
 
 #[cfg(test)]
@@ -63,43 +63,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: com.sun.media.sound.MidiOutDevice.nClose(J)V")]
     async fn test_n_close() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_close(thread, Parameters::default()).await;
+        let result = n_close(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/MidiOutDevice",
+            "nClose",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.MidiOutDevice.nGetTimeStamp(J)J"
-    )]
     async fn test_n_get_time_stamp() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_get_time_stamp(thread, Parameters::default()).await;
+        let result = n_get_time_stamp(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/MidiOutDevice",
+            "nGetTimeStamp",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: com.sun.media.sound.MidiOutDevice.nOpen(I)J")]
     async fn test_n_open() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_open(thread, Parameters::default()).await;
+        let result = n_open(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/MidiOutDevice",
+            "nOpen",
+            "(I)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.MidiOutDevice.nSendLongMessage(J[BIJ)V"
-    )]
     async fn test_n_send_long_message() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_send_long_message(thread, Parameters::default()).await;
+        let result = n_send_long_message(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/MidiOutDevice",
+            "nSendLongMessage",
+            "(J[BIJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.MidiOutDevice.nSendShortMessage(JIJ)V"
-    )]
     async fn test_n_send_short_message() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_send_short_message(thread, Parameters::default()).await;
+        let result = n_send_short_message(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/MidiOutDevice",
+            "nSendShortMessage",
+            "(JIJ)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -34,27 +34,27 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn n_get_description(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.MidiOutDeviceProvider.nGetDescription(I)Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "nGetDescription", "(I)Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
 async fn n_get_name(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.MidiOutDeviceProvider.nGetName(I)Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "nGetName", "(I)Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
 async fn n_get_num_devices(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.MidiOutDeviceProvider.nGetNumDevices()I")
+    unsatisfied_link(CLASS_NAME, "nGetNumDevices", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn n_get_vendor(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.MidiOutDeviceProvider.nGetVendor(I)Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "nGetVendor", "(I)Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
 async fn n_get_version(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.MidiOutDeviceProvider.nGetVersion(I)Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "nGetVersion", "(I)Ljava/lang/String;")
 }
 
 #[cfg(test)]
@@ -62,47 +62,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.MidiOutDeviceProvider.nGetDescription(I)Ljava/lang/String;"
-    )]
     async fn test_n_get_description() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_get_description(thread, Parameters::default()).await;
+        let result = n_get_description(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/MidiOutDeviceProvider",
+            "nGetDescription",
+            "(I)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.MidiOutDeviceProvider.nGetName(I)Ljava/lang/String;"
-    )]
     async fn test_n_get_name() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_get_name(thread, Parameters::default()).await;
+        let result = n_get_name(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/MidiOutDeviceProvider",
+            "nGetName",
+            "(I)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.MidiOutDeviceProvider.nGetNumDevices()I"
-    )]
     async fn test_n_get_num_devices() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_get_num_devices(thread, Parameters::default()).await;
+        let result = n_get_num_devices(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/MidiOutDeviceProvider",
+            "nGetNumDevices",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.MidiOutDeviceProvider.nGetVendor(I)Ljava/lang/String;"
-    )]
     async fn test_n_get_vendor() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_get_vendor(thread, Parameters::default()).await;
+        let result = n_get_vendor(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/MidiOutDeviceProvider",
+            "nGetVendor",
+            "(I)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.MidiOutDeviceProvider.nGetVersion(I)Ljava/lang/String;"
-    )]
     async fn test_n_get_version() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_get_version(thread, Parameters::default()).await;
+        let result = n_get_version(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/MidiOutDeviceProvider",
+            "nGetVersion",
+            "(I)Ljava/lang/String;",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -34,7 +34,7 @@ async fn n_get_extra_libraries(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.Platform.nGetExtraLibraries()Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "nGetExtraLibraries", "()Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
@@ -42,17 +42,17 @@ async fn n_get_library_for_feature(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.Platform.nGetLibraryForFeature(I)I")
+    unsatisfied_link(CLASS_NAME, "nGetLibraryForFeature", "(I)I")
 }
 
 #[async_recursion(?Send)]
 async fn n_is_big_endian(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.Platform.nIsBigEndian()Z")
+    unsatisfied_link(CLASS_NAME, "nIsBigEndian", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn n_is_signed_8(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.Platform.nIsSigned8()Z")
+    unsatisfied_link(CLASS_NAME, "nIsSigned8", "()Z")
 }
 
 #[cfg(test)]
@@ -60,34 +60,50 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.Platform.nGetExtraLibraries()Ljava/lang/String;"
-    )]
     async fn test_n_get_extra_libraries() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_get_extra_libraries(thread, Parameters::default()).await;
+        let result = n_get_extra_libraries(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/Platform",
+            "nGetExtraLibraries",
+            "()Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.Platform.nGetLibraryForFeature(I)I"
-    )]
     async fn test_n_get_library_for_feature() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_get_library_for_feature(thread, Parameters::default()).await;
+        let result = n_get_library_for_feature(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/Platform",
+            "nGetLibraryForFeature",
+            "(I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: com.sun.media.sound.Platform.nIsBigEndian()Z")]
     async fn test_n_is_big_endian() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_is_big_endian(thread, Parameters::default()).await;
+        let result = n_is_big_endian(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/Platform",
+            "nIsBigEndian",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: com.sun.media.sound.Platform.nIsSigned8()Z")]
     async fn test_n_is_signed_8() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_is_signed_8(thread, Parameters::default()).await;
+        let result = n_is_signed_8(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/Platform",
+            "nIsSigned8",
+            "()Z",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -54,7 +54,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn n_close(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.PortMixer.nClose(J)V")
+    unsatisfied_link(CLASS_NAME, "nClose", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -62,7 +62,7 @@ async fn n_control_get_float_value(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.PortMixer.nControlGetFloatValue(J)F")
+    unsatisfied_link(CLASS_NAME, "nControlGetFloatValue", "(J)F")
 }
 
 #[async_recursion(?Send)]
@@ -70,7 +70,7 @@ async fn n_control_get_int_value(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.PortMixer.nControlGetIntValue(J)I")
+    unsatisfied_link(CLASS_NAME, "nControlGetIntValue", "(J)I")
 }
 
 #[async_recursion(?Send)]
@@ -78,7 +78,7 @@ async fn n_control_set_float_value(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.PortMixer.nControlSetFloatValue(JF)V")
+    unsatisfied_link(CLASS_NAME, "nControlSetFloatValue", "(JF)V")
 }
 
 #[async_recursion(?Send)]
@@ -86,32 +86,32 @@ async fn n_control_set_int_value(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.PortMixer.nControlSetIntValue(JI)V")
+    unsatisfied_link(CLASS_NAME, "nControlSetIntValue", "(JI)V")
 }
 
 #[async_recursion(?Send)]
 async fn n_get_controls(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.PortMixer.nGetControls(JILjava/util/Vector;)V")
+    unsatisfied_link(CLASS_NAME, "nGetControls", "(JILjava/util/Vector;)V")
 }
 
 #[async_recursion(?Send)]
 async fn n_get_port_count(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.PortMixer.nGetPortCount(J)I")
+    unsatisfied_link(CLASS_NAME, "nGetPortCount", "(J)I")
 }
 
 #[async_recursion(?Send)]
 async fn n_get_port_name(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.PortMixer.nGetPortName(JI)Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "nGetPortName", "(JI)Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
 async fn n_get_port_type(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.PortMixer.nGetPortType(JI)I")
+    unsatisfied_link(CLASS_NAME, "nGetPortType", "(JI)I")
 }
 
 #[async_recursion(?Send)]
 async fn n_open(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.PortMixer.nOpen(I)J")
+    unsatisfied_link(CLASS_NAME, "nOpen", "(I)J")
 }
 
 #[cfg(test)]
@@ -119,88 +119,122 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: com.sun.media.sound.PortMixer.nClose(J)V")]
     async fn test_n_close() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_close(thread, Parameters::default()).await;
+        let result = n_close(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/PortMixer",
+            "nClose",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.PortMixer.nControlGetFloatValue(J)F"
-    )]
     async fn test_n_control_get_float_value() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_control_get_float_value(thread, Parameters::default()).await;
+        let result = n_control_get_float_value(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/PortMixer",
+            "nControlGetFloatValue",
+            "(J)F",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.PortMixer.nControlGetIntValue(J)I"
-    )]
     async fn test_n_control_get_int_value() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_control_get_int_value(thread, Parameters::default()).await;
+        let result = n_control_get_int_value(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/PortMixer",
+            "nControlGetIntValue",
+            "(J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.PortMixer.nControlSetFloatValue(JF)V"
-    )]
     async fn test_n_control_set_float_value() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_control_set_float_value(thread, Parameters::default()).await;
+        let result = n_control_set_float_value(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/PortMixer",
+            "nControlSetFloatValue",
+            "(JF)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.PortMixer.nControlSetIntValue(JI)V"
-    )]
     async fn test_n_control_set_int_value() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_control_set_int_value(thread, Parameters::default()).await;
+        let result = n_control_set_int_value(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/PortMixer",
+            "nControlSetIntValue",
+            "(JI)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.PortMixer.nGetControls(JILjava/util/Vector;)V"
-    )]
     async fn test_n_get_controls() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_get_controls(thread, Parameters::default()).await;
+        let result = n_get_controls(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/PortMixer",
+            "nGetControls",
+            "(JILjava/util/Vector;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.PortMixer.nGetPortCount(J)I"
-    )]
     async fn test_n_get_port_count() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_get_port_count(thread, Parameters::default()).await;
+        let result = n_get_port_count(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/PortMixer",
+            "nGetPortCount",
+            "(J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.PortMixer.nGetPortName(JI)Ljava/lang/String;"
-    )]
     async fn test_n_get_port_name() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_get_port_name(thread, Parameters::default()).await;
+        let result = n_get_port_name(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/PortMixer",
+            "nGetPortName",
+            "(JI)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.PortMixer.nGetPortType(JI)I"
-    )]
     async fn test_n_get_port_type() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_get_port_type(thread, Parameters::default()).await;
+        let result = n_get_port_type(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/PortMixer",
+            "nGetPortType",
+            "(JI)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: com.sun.media.sound.PortMixer.nOpen(I)J")]
     async fn test_n_open() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_open(thread, Parameters::default()).await;
+        let result = n_open(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/PortMixer",
+            "nOpen",
+            "(I)J",
+        );
     }
 }
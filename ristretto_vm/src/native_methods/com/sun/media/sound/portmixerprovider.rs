@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -21,7 +21,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn n_get_num_devices(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.PortMixerProvider.nGetNumDevices()I")
+    unsatisfied_link(CLASS_NAME, "nGetNumDevices", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -29,7 +29,11 @@ async fn n_new_port_mixer_info(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.media.sound.PortMixerProvider.nNewPortMixerInfo(I)Lcom/sun/media/sound/PortMixerProvider$PortMixerInfo;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nNewPortMixerInfo",
+        "(I)Lcom/sun/media/sound/PortMixerProvider$PortMixerInfo;",
+    )
 }
 
 #[cfg(test)]
@@ -37,20 +41,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.PortMixerProvider.nGetNumDevices()I"
-    )]
     async fn test_n_get_num_devices() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_get_num_devices(thread, Parameters::default()).await;
+        let result = n_get_num_devices(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/PortMixerProvider",
+            "nGetNumDevices",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.media.sound.PortMixerProvider.nNewPortMixerInfo(I)Lcom/sun/media/sound/PortMixerProvider$PortMixerInfo;"
-    )]
     async fn test_n_new_port_mixer_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = n_new_port_mixer_info(thread, Parameters::default()).await;
+        let result = n_new_port_mixer_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/media/sound/PortMixerProvider",
+            "nNewPortMixerInfo",
+            "(I)Lcom/sun/media/sound/PortMixerProvider$PortMixerInfo;",
+        );
     }
 }
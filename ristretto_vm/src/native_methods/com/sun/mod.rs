@@ -1,6 +1,10 @@
 pub(crate) mod demo;
+#[cfg(feature = "awt")]
 pub(crate) mod imageio;
 pub(crate) mod java;
+#[cfg(feature = "management")]
 pub(crate) mod management;
+#[cfg(feature = "sound")]
 pub(crate) mod media;
+#[cfg(feature = "security")]
 pub(crate) mod security;
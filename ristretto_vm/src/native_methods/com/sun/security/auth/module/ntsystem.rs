@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -21,7 +21,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn get_current(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.security.auth.module.NTSystem.getCurrent(Z)V")
+    unsatisfied_link(CLASS_NAME, "getCurrent", "(Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -29,7 +29,7 @@ async fn get_impersonation_token_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("com.sun.security.auth.module.NTSystem.getImpersonationToken0()J")
+    unsatisfied_link(CLASS_NAME, "getImpersonationToken0", "()J")
 }
 
 #[cfg(test)]
@@ -37,20 +37,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.security.auth.module.NTSystem.getCurrent(Z)V"
-    )]
     async fn test_get_current() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_current(thread, Parameters::default()).await;
+        let result = get_current(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/security/auth/module/NTSystem",
+            "getCurrent",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.security.auth.module.NTSystem.getImpersonationToken0()J"
-    )]
     async fn test_get_impersonation_token_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_impersonation_token_0(thread, Parameters::default()).await;
+        let result = get_impersonation_token_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/security/auth/module/NTSystem",
+            "getImpersonationToken0",
+            "()J",
+        );
     }
 }
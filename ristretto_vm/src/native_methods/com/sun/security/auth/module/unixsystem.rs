@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -15,7 +15,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn get_unix_info(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("com.sun.security.auth.module.UnixSystem.getUnixInfo()V")
+    unsatisfied_link(CLASS_NAME, "getUnixInfo", "()V")
 }
 
 #[cfg(test)]
@@ -23,11 +23,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: com.sun.security.auth.module.UnixSystem.getUnixInfo()V"
-    )]
     async fn test_get_unix_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_unix_info(thread, Parameters::default()).await;
+        let result = get_unix_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "com/sun/security/auth/module/UnixSystem",
+            "getUnixInfo",
+            "()V",
+        );
     }
 }
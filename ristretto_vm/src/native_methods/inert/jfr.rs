@@ -0,0 +1,210 @@
+use crate::native_methods::registry::MethodRegistry;
+use crate::parameters::Parameters;
+use crate::thread::Thread;
+use crate::Result;
+use async_recursion::async_recursion;
+use ristretto_classloader::{Object, Value};
+use std::sync::Arc;
+
+const EVENT_SETTINGS: &str = "jdk/jfr/EventSettings";
+const FLIGHT_RECORDER: &str = "jdk/jfr/FlightRecorder";
+const RECORDING: &str = "jdk/jfr/Recording";
+
+/// Register the inert implementations of `jdk.jfr.FlightRecorder`, `jdk.jfr.Recording` and
+/// `jdk.jfr.EventSettings`.
+pub(crate) fn register(registry: &mut MethodRegistry) {
+    registry.register(
+        EVENT_SETTINGS,
+        "with",
+        "(Ljava/lang/String;Ljava/lang/String;)Ljdk/jfr/EventSettings;",
+        event_settings_with,
+    );
+
+    registry.register(
+        FLIGHT_RECORDER,
+        "addListener",
+        "(Ljdk/jfr/FlightRecorderListener;)V",
+        no_op,
+    );
+    registry.register(
+        FLIGHT_RECORDER,
+        "addPeriodicEvent",
+        "(Ljava/lang/Class;Ljava/lang/Runnable;)V",
+        no_op,
+    );
+    registry.register(
+        FLIGHT_RECORDER,
+        "getEventTypes",
+        "()Ljava/util/List;",
+        empty_list,
+    );
+    registry.register(
+        FLIGHT_RECORDER,
+        "getFlightRecorder",
+        "()Ljdk/jfr/FlightRecorder;",
+        get_flight_recorder,
+    );
+    registry.register(
+        FLIGHT_RECORDER,
+        "getRecordings",
+        "()Ljava/util/List;",
+        empty_list,
+    );
+    registry.register(FLIGHT_RECORDER, "isAvailable", "()Z", r#false);
+    registry.register(FLIGHT_RECORDER, "isInitialized", "()Z", r#false);
+    registry.register(FLIGHT_RECORDER, "register", "(Ljava/lang/Class;)V", no_op);
+    registry.register(
+        FLIGHT_RECORDER,
+        "removeListener",
+        "(Ljdk/jfr/FlightRecorderListener;)Z",
+        r#false,
+    );
+    registry.register(
+        FLIGHT_RECORDER,
+        "removePeriodicEvent",
+        "(Ljava/lang/Runnable;)Z",
+        r#false,
+    );
+    registry.register(FLIGHT_RECORDER, "unregister", "(Ljava/lang/Class;)V", no_op);
+
+    registry.register(RECORDING, "<init>", "()V", no_op);
+    registry.register(RECORDING, "<init>", "(Ljava/util/Map;)V", no_op);
+    registry.register(RECORDING, "<init>", "(Ljdk/jfr/Configuration;)V", no_op);
+    registry.register(RECORDING, "close", "()V", no_op);
+    registry.register(
+        RECORDING,
+        "disable",
+        "(Ljava/lang/Class;)Ljdk/jfr/EventSettings;",
+        event_settings,
+    );
+    registry.register(
+        RECORDING,
+        "disable",
+        "(Ljava/lang/String;)Ljdk/jfr/EventSettings;",
+        event_settings,
+    );
+    registry.register(RECORDING, "dump", "(Ljava/nio/file/Path;)V", no_op);
+    registry.register(
+        RECORDING,
+        "enable",
+        "(Ljava/lang/Class;)Ljdk/jfr/EventSettings;",
+        event_settings,
+    );
+    registry.register(
+        RECORDING,
+        "enable",
+        "(Ljava/lang/String;)Ljdk/jfr/EventSettings;",
+        event_settings,
+    );
+    registry.register(RECORDING, "scheduleStart", "(Ljava/time/Duration;)V", no_op);
+    registry.register(
+        RECORDING,
+        "setDestination",
+        "(Ljava/nio/file/Path;)V",
+        no_op,
+    );
+    registry.register(RECORDING, "setDumpOnExit", "(Z)V", no_op);
+    registry.register(RECORDING, "setDuration", "(Ljava/time/Duration;)V", no_op);
+    registry.register(RECORDING, "setMaxAge", "(Ljava/time/Duration;)V", no_op);
+    registry.register(RECORDING, "setMaxSize", "(J)V", no_op);
+    registry.register(RECORDING, "setName", "(Ljava/lang/String;)V", no_op);
+    registry.register(RECORDING, "setSettings", "(Ljava/util/Map;)V", no_op);
+    registry.register(RECORDING, "setToDisk", "(Z)V", no_op);
+    registry.register(RECORDING, "start", "()V", no_op);
+    registry.register(RECORDING, "stop", "()Z", r#true);
+}
+
+#[async_recursion(?Send)]
+async fn no_op(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    Ok(None)
+}
+
+#[async_recursion(?Send)]
+async fn r#false(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    Ok(Some(Value::from(false)))
+}
+
+#[async_recursion(?Send)]
+async fn r#true(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    Ok(Some(Value::from(true)))
+}
+
+#[async_recursion(?Send)]
+async fn empty_list(thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    let class = thread.class("java/util/Collections").await?;
+    let method = class.try_get_method("emptyList", "()Ljava/util/List;")?;
+    thread.execute(&class, &method, Vec::<Value>::new()).await
+}
+
+/// Allocate an instance of the class without invoking a constructor; the instance is only used
+/// as a receiver for the inert methods registered above.
+async fn inert_object(thread: &Thread, class_name: &str) -> Result<Value> {
+    let class = thread.class(class_name).await?;
+    Ok(Value::from(Object::new(class)?))
+}
+
+#[async_recursion(?Send)]
+async fn get_flight_recorder(
+    thread: Arc<Thread>,
+    _parameters: Parameters,
+) -> Result<Option<Value>> {
+    let flight_recorder = inert_object(&thread, FLIGHT_RECORDER).await?;
+    Ok(Some(flight_recorder))
+}
+
+#[async_recursion(?Send)]
+async fn event_settings(thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    let event_settings = inert_object(&thread, EVENT_SETTINGS).await?;
+    Ok(Some(event_settings))
+}
+
+#[async_recursion(?Send)]
+async fn event_settings_with(
+    _thread: Arc<Thread>,
+    mut parameters: Parameters,
+) -> Result<Option<Value>> {
+    let _value = parameters.pop_reference()?;
+    let _name = parameters.pop_reference()?;
+    let event_settings = parameters.pop()?;
+    Ok(Some(event_settings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_flight_recorder() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let result = get_flight_recorder(thread, Parameters::default()).await?;
+        let Some(Value::Object(Some(reference))) = result else {
+            panic!("expected flight recorder");
+        };
+        assert_eq!(FLIGHT_RECORDER, reference.class_name());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_recording() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let result = no_op(thread.clone(), Parameters::default()).await?;
+        assert_eq!(None, result);
+        let result = r#true(thread, Parameters::default()).await?;
+        assert_eq!(Some(Value::from(true)), result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_event_settings_with() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let event_settings = inert_object(&thread, EVENT_SETTINGS).await?;
+        let parameters = Parameters::new(vec![
+            event_settings.clone(),
+            Value::Object(None),
+            Value::Object(None),
+        ]);
+        let result = event_settings_with(thread, parameters).await?;
+        assert_eq!(Some(event_settings), result);
+        Ok(())
+    }
+}
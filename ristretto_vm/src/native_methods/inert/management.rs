@@ -0,0 +1,265 @@
+use crate::java_object::JavaObject;
+use crate::native_methods::registry::MethodRegistry;
+use crate::parameters::Parameters;
+use crate::thread::Thread;
+use crate::Result;
+use async_recursion::async_recursion;
+use ristretto_classloader::{ConcurrentVec, Reference, Value};
+use std::sync::Arc;
+use sysinfo::System;
+
+const CLASS_LOADING_IMPL: &str = "sun/management/ClassLoadingImpl";
+const MEMORY_IMPL: &str = "sun/management/MemoryImpl";
+const VM_MANAGEMENT_IMPL: &str = "sun/management/VMManagementImpl";
+
+/// Version of the management interface reported to `sun.management.VMManagementImpl`
+const MANAGEMENT_VERSION: &str = "4.0";
+
+/// Register the inert implementations of the `sun.management` native methods that back the
+/// platform `MXBean`s returned by `java.lang.management.ManagementFactory`.
+#[expect(clippy::too_many_lines)]
+pub(crate) fn register(registry: &mut MethodRegistry) {
+    registry.register(CLASS_LOADING_IMPL, "setVerboseClass", "(Z)V", no_op);
+
+    registry.register(
+        MEMORY_IMPL,
+        "getMemoryManagers0",
+        "()[Ljava/lang/management/MemoryManagerMXBean;",
+        get_memory_managers_0,
+    );
+    registry.register(
+        MEMORY_IMPL,
+        "getMemoryPools0",
+        "()[Ljava/lang/management/MemoryPoolMXBean;",
+        get_memory_pools_0,
+    );
+    registry.register(
+        MEMORY_IMPL,
+        "getMemoryUsage0",
+        "(Z)Ljava/lang/management/MemoryUsage;",
+        get_memory_usage_0,
+    );
+    registry.register(MEMORY_IMPL, "setVerboseGC", "(Z)V", no_op);
+
+    registry.register(
+        VM_MANAGEMENT_IMPL,
+        "getAvailableProcessors",
+        "()I",
+        get_available_processors,
+    );
+    registry.register(
+        VM_MANAGEMENT_IMPL,
+        "getClassInitializationTime",
+        "()J",
+        long_zero,
+    );
+    registry.register(VM_MANAGEMENT_IMPL, "getClassLoadingTime", "()J", long_zero);
+    registry.register(
+        VM_MANAGEMENT_IMPL,
+        "getClassVerificationTime",
+        "()J",
+        long_zero,
+    );
+    registry.register(VM_MANAGEMENT_IMPL, "getDaemonThreadCount", "()I", int_zero);
+    registry.register(
+        VM_MANAGEMENT_IMPL,
+        "getInitializedClassCount",
+        "()J",
+        long_zero,
+    );
+    registry.register(VM_MANAGEMENT_IMPL, "getLiveThreadCount", "()I", int_zero);
+    registry.register(VM_MANAGEMENT_IMPL, "getLoadedClassSize", "()J", long_zero);
+    registry.register(VM_MANAGEMENT_IMPL, "getMethodDataSize", "()J", long_zero);
+    registry.register(VM_MANAGEMENT_IMPL, "getPeakThreadCount", "()I", int_zero);
+    registry.register(VM_MANAGEMENT_IMPL, "getProcessId", "()I", get_process_id);
+    registry.register(VM_MANAGEMENT_IMPL, "getSafepointCount", "()J", long_zero);
+    registry.register(VM_MANAGEMENT_IMPL, "getSafepointSyncTime", "()J", long_zero);
+    registry.register(VM_MANAGEMENT_IMPL, "getStartupTime", "()J", long_zero);
+    registry.register(
+        VM_MANAGEMENT_IMPL,
+        "getTotalApplicationNonStoppedTime",
+        "()J",
+        long_zero,
+    );
+    registry.register(VM_MANAGEMENT_IMPL, "getTotalClassCount", "()J", long_zero);
+    registry.register(VM_MANAGEMENT_IMPL, "getTotalCompileTime", "()J", long_zero);
+    registry.register(
+        VM_MANAGEMENT_IMPL,
+        "getTotalSafepointTime",
+        "()J",
+        long_zero,
+    );
+    registry.register(VM_MANAGEMENT_IMPL, "getTotalThreadCount", "()J", long_zero);
+    registry.register(
+        VM_MANAGEMENT_IMPL,
+        "getUnloadedClassCount",
+        "()J",
+        long_zero,
+    );
+    registry.register(VM_MANAGEMENT_IMPL, "getUnloadedClassSize", "()J", long_zero);
+    registry.register(VM_MANAGEMENT_IMPL, "getUptime0", "()J", long_zero);
+    registry.register(VM_MANAGEMENT_IMPL, "getVerboseClass", "()Z", r#false);
+    registry.register(VM_MANAGEMENT_IMPL, "getVerboseGC", "()Z", r#false);
+    registry.register(
+        VM_MANAGEMENT_IMPL,
+        "getVersion0",
+        "()Ljava/lang/String;",
+        get_version_0,
+    );
+    registry.register(
+        VM_MANAGEMENT_IMPL,
+        "getVmArguments0",
+        "()[Ljava/lang/String;",
+        get_vm_arguments_0,
+    );
+    registry.register(
+        VM_MANAGEMENT_IMPL,
+        "initOptionalSupportFields",
+        "()V",
+        no_op,
+    );
+    registry.register(
+        VM_MANAGEMENT_IMPL,
+        "isThreadAllocatedMemoryEnabled",
+        "()Z",
+        r#false,
+    );
+    registry.register(
+        VM_MANAGEMENT_IMPL,
+        "isThreadContentionMonitoringEnabled",
+        "()Z",
+        r#false,
+    );
+    registry.register(VM_MANAGEMENT_IMPL, "isThreadCpuTimeEnabled", "()Z", r#false);
+}
+
+#[async_recursion(?Send)]
+async fn no_op(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    Ok(None)
+}
+
+#[async_recursion(?Send)]
+async fn r#false(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    Ok(Some(Value::from(false)))
+}
+
+#[async_recursion(?Send)]
+async fn int_zero(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    Ok(Some(Value::Int(0)))
+}
+
+#[async_recursion(?Send)]
+async fn long_zero(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    Ok(Some(Value::Long(0)))
+}
+
+/// Create an empty array of the specified array class (e.g. `[Ljava/lang/String;`).
+async fn empty_array(thread: &Thread, class_name: &str) -> Result<Value> {
+    let class = thread.class(class_name).await?;
+    let array = Reference::Array(class, ConcurrentVec::from(Vec::new()));
+    Ok(Value::Object(Some(array)))
+}
+
+#[async_recursion(?Send)]
+async fn get_memory_managers_0(
+    thread: Arc<Thread>,
+    _parameters: Parameters,
+) -> Result<Option<Value>> {
+    let managers = empty_array(&thread, "[Ljava/lang/management/MemoryManagerMXBean;").await?;
+    Ok(Some(managers))
+}
+
+#[async_recursion(?Send)]
+async fn get_memory_pools_0(thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    let pools = empty_array(&thread, "[Ljava/lang/management/MemoryPoolMXBean;").await?;
+    Ok(Some(pools))
+}
+
+#[async_recursion(?Send)]
+async fn get_memory_usage_0(
+    thread: Arc<Thread>,
+    mut parameters: Parameters,
+) -> Result<Option<Value>> {
+    let _heap = parameters.pop_int()? != 0;
+    // init, used, committed, max; a max of -1 indicates that the maximum is undefined
+    let memory_usage = thread
+        .object(
+            "java/lang/management/MemoryUsage",
+            "JJJJ",
+            vec![
+                Value::Long(0),
+                Value::Long(0),
+                Value::Long(0),
+                Value::Long(-1),
+            ],
+        )
+        .await?;
+    Ok(Some(memory_usage))
+}
+
+#[async_recursion(?Send)]
+async fn get_available_processors(
+    _thread: Arc<Thread>,
+    _parameters: Parameters,
+) -> Result<Option<Value>> {
+    let sys = System::new_all();
+    let cpus = sys.physical_core_count().unwrap_or(1);
+    let cpus = i32::try_from(cpus)?;
+    Ok(Some(Value::Int(cpus)))
+}
+
+#[async_recursion(?Send)]
+async fn get_process_id(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    let pid = i32::try_from(std::process::id())?;
+    Ok(Some(Value::Int(pid)))
+}
+
+#[async_recursion(?Send)]
+async fn get_version_0(thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    let vm = thread.vm()?;
+    let version = MANAGEMENT_VERSION.to_object(&vm).await?;
+    Ok(Some(version))
+}
+
+#[async_recursion(?Send)]
+async fn get_vm_arguments_0(thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    let arguments = empty_array(&thread, "[Ljava/lang/String;").await?;
+    Ok(Some(arguments))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_memory_usage_0() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let parameters = Parameters::new(vec![Value::Int(1)]);
+        let result = get_memory_usage_0(thread, parameters).await?;
+        let Some(Value::Object(Some(reference))) = result else {
+            panic!("expected memory usage");
+        };
+        assert_eq!("java/lang/management/MemoryUsage", reference.class_name());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_process_id() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let result = get_process_id(thread, Parameters::default()).await?;
+        let expected = i32::try_from(std::process::id())?;
+        assert_eq!(Some(Value::Int(expected)), result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_vm_arguments_0() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let result = get_vm_arguments_0(thread, Parameters::default()).await?;
+        let Some(Value::Object(Some(Reference::Array(_class, arguments)))) = result else {
+            panic!("expected string array");
+        };
+        assert!(arguments.is_empty()?);
+        Ok(())
+    }
+}
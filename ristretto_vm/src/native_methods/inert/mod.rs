@@ -0,0 +1,9 @@
+//! Inert implementations of the Java Flight Recorder and management APIs that are registered when
+//! the `jfr` or `management` cargo features are disabled. Applications that optionally integrate
+//! monitoring (e.g. start a recording or look up an `MXBean`) continue to run; the calls succeed
+//! but do not record or report anything.
+
+#[cfg(not(feature = "jfr"))]
+pub(crate) mod jfr;
+#[cfg(not(feature = "management"))]
+pub(crate) mod management;
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -26,7 +26,11 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn native_set_source(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.awt.AWTEvent.nativeSetSource(Ljava/awt/peer/ComponentPeer;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeSetSource",
+        "(Ljava/awt/peer/ComponentPeer;)V",
+    )
 }
 
 #[cfg(test)]
@@ -42,11 +46,14 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.awt.AWTEvent.nativeSetSource(Ljava/awt/peer/ComponentPeer;)V"
-    )]
     async fn test_native_set_source() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_source(thread, Parameters::default()).await;
+        let result = native_set_source(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/awt/AWTEvent",
+            "nativeSetSource",
+            "(Ljava/awt/peer/ComponentPeer;)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -16,7 +16,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn finalize_impl(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.awt.Cursor.finalizeImpl(J)V")
+    unsatisfied_link(CLASS_NAME, "finalizeImpl", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -29,10 +29,10 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.awt.Cursor.finalizeImpl(J)V")]
     async fn test_finalize_impl() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = finalize_impl(thread, Parameters::default()).await;
+        let result = finalize_impl(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/awt/Cursor", "finalizeImpl", "(J)V");
     }
 
     #[tokio::test]
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -38,12 +38,12 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn close(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.awt.SplashScreen::_close(J)V");
+    unsatisfied_link(CLASS_NAME, "_close", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn get_bounds(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.awt.SplashScreen::_getBounds(J)Ljava/awt/Rectangle;");
+    unsatisfied_link(CLASS_NAME, "_getBounds", "(J)Ljava/awt/Rectangle;")
 }
 
 #[async_recursion(?Send)]
@@ -51,7 +51,7 @@ async fn get_image_file_name(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.awt.SplashScreen::_getImageFileName(J)Ljava/lang/String;");
+    unsatisfied_link(CLASS_NAME, "_getImageFileName", "(J)Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
@@ -59,32 +59,32 @@ async fn get_image_jar_name(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.awt.SplashScreen::_getImageJarName(J)Ljava/lang/String;");
+    unsatisfied_link(CLASS_NAME, "_getImageJarName", "(J)Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
 async fn get_instance(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.awt.SplashScreen::_getInstance()J");
+    unsatisfied_link(CLASS_NAME, "_getInstance", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn get_scale_factor(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.awt.SplashScreen::_getScaleFactor(J)F");
+    unsatisfied_link(CLASS_NAME, "_getScaleFactor", "(J)F")
 }
 
 #[async_recursion(?Send)]
 async fn is_visible(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.awt.SplashScreen::_isVisible(J)Z");
+    unsatisfied_link(CLASS_NAME, "_isVisible", "(J)Z")
 }
 
 #[async_recursion(?Send)]
 async fn set_image_data(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.awt.SplashScreen::_setImageData(J[B)Z");
+    unsatisfied_link(CLASS_NAME, "_setImageData", "(J[B)Z")
 }
 
 #[async_recursion(?Send)]
 async fn update(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.awt.SplashScreen::_update(J[IIIIII)V");
+    unsatisfied_link(CLASS_NAME, "_update", "(J[IIIIII)V")
 }
 
 #[cfg(test)]
@@ -92,71 +92,100 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.awt.SplashScreen::_close(J)V")]
     async fn test_close() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = close(thread, Parameters::default()).await;
+        let result = close(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/awt/SplashScreen", "_close", "(J)V");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.awt.SplashScreen::_getBounds(J)Ljava/awt/Rectangle;"
-    )]
     async fn test_get_bounds() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_bounds(thread, Parameters::default()).await;
+        let result = get_bounds(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/awt/SplashScreen",
+            "_getBounds",
+            "(J)Ljava/awt/Rectangle;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.awt.SplashScreen::_getImageFileName(J)Ljava/lang/String;"
-    )]
     async fn test_get_image_file_name() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_image_file_name(thread, Parameters::default()).await;
+        let result = get_image_file_name(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/awt/SplashScreen",
+            "_getImageFileName",
+            "(J)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.awt.SplashScreen::_getImageJarName(J)Ljava/lang/String;"
-    )]
     async fn test_get_image_jar_name() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_image_jar_name(thread, Parameters::default()).await;
+        let result = get_image_jar_name(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/awt/SplashScreen",
+            "_getImageJarName",
+            "(J)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.awt.SplashScreen::_getInstance()J")]
     async fn test_get_instance() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_instance(thread, Parameters::default()).await;
+        let result = get_instance(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/awt/SplashScreen",
+            "_getInstance",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.awt.SplashScreen::_getScaleFactor(J)F")]
     async fn test_get_scale_factor() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_scale_factor(thread, Parameters::default()).await;
+        let result = get_scale_factor(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/awt/SplashScreen",
+            "_getScaleFactor",
+            "(J)F",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.awt.SplashScreen::_isVisible(J)Z")]
     async fn test_is_visible() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_visible(thread, Parameters::default()).await;
+        let result = is_visible(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/awt/SplashScreen", "_isVisible", "(J)Z");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.awt.SplashScreen::_setImageData(J[B)Z")]
     async fn test_set_image_data() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_image_data(thread, Parameters::default()).await;
+        let result = set_image_data(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/awt/SplashScreen",
+            "_setImageData",
+            "(J[B)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.awt.SplashScreen::_update(J[IIIIII)V")]
     async fn test_update() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = update(thread, Parameters::default()).await;
+        let result = update(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/awt/SplashScreen",
+            "_update",
+            "(J[IIIIII)V",
+        );
     }
 }
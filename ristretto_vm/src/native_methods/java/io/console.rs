@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_17};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_17};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -20,17 +20,17 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn echo(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.Console.echo(Z)Z")
+    unsatisfied_link(CLASS_NAME, "echo", "(Z)Z")
 }
 
 #[async_recursion(?Send)]
 async fn encoding(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.Console.encoding()Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "encoding", "()Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
 async fn istty(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.Console.istty()Z")
+    unsatisfied_link(CLASS_NAME, "istty", "()Z")
 }
 
 #[cfg(test)]
@@ -38,23 +38,28 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.Console.echo(Z)Z")]
     async fn test_echo() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = echo(thread, Parameters::default()).await;
+        let result = echo(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/io/Console", "echo", "(Z)Z");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.Console.encoding()Ljava/lang/String;")]
     async fn test_encoding() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = encoding(thread, Parameters::default()).await;
+        let result = encoding(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/Console",
+            "encoding",
+            "()Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.Console.istty()Z")]
     async fn test_istty() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = istty(thread, Parameters::default()).await;
+        let result = istty(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/io/Console", "istty", "()Z");
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -15,7 +15,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn cleanup_close_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.FileCleanable.cleanupClose0(IJ)V")
+    unsatisfied_link(CLASS_NAME, "cleanupClose0", "(IJ)V")
 }
 
 #[cfg(test)]
@@ -23,9 +23,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.FileCleanable.cleanupClose0(IJ)V")]
     async fn test_cleanup_close_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = cleanup_close_0(thread, Parameters::default()).await;
+        let result = cleanup_close_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/FileCleanable",
+            "cleanupClose0",
+            "(IJ)V",
+        );
     }
 }
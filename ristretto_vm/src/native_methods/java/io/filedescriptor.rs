@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -27,7 +27,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn close_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.FileDescriptor.close0()V")
+    unsatisfied_link(CLASS_NAME, "close0", "()V")
 }
 
 #[expect(clippy::match_same_arms)]
@@ -71,7 +71,7 @@ async fn sync(thread: Arc<Thread>, parameters: Parameters) -> Result<Option<Valu
 
 #[async_recursion(?Send)]
 async fn sync_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.FileDescriptor.sync0()V")
+    unsatisfied_link(CLASS_NAME, "sync0", "()V")
 }
 
 #[cfg(test)]
@@ -79,10 +79,10 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.FileDescriptor.close0()V")]
     async fn test_close_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = close_0(thread, Parameters::default()).await;
+        let result = close_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/io/FileDescriptor", "close0", "()V");
     }
 
     #[tokio::test]
@@ -116,16 +116,16 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.FileDescriptor.sync0()V")]
     async fn test_sync() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = sync(thread, Parameters::default()).await;
+        let result = sync(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/io/FileDescriptor", "sync0", "()V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.FileDescriptor.sync0()V")]
     async fn test_sync_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = sync_0(thread, Parameters::default()).await;
+        let result = sync_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/io/FileDescriptor", "sync0", "()V");
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_17, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_17, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -27,12 +27,12 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn available_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.FileInputStream.available0()I")
+    unsatisfied_link(CLASS_NAME, "available0", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn close_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.FileInputStream.close0()V")
+    unsatisfied_link(CLASS_NAME, "close0", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -42,32 +42,32 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn length_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.FileInputStream.length0()J")
+    unsatisfied_link(CLASS_NAME, "length0", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn open_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.FileInputStream.open0(Ljava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "open0", "(Ljava/lang/String;)V")
 }
 
 #[async_recursion(?Send)]
 async fn position_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.FileInputStream.position0()J")
+    unsatisfied_link(CLASS_NAME, "position0", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn read_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.FileInputStream.read0()I")
+    unsatisfied_link(CLASS_NAME, "read0", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn read_bytes(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.FileInputStream.readBytes([BII)I")
+    unsatisfied_link(CLASS_NAME, "readBytes", "([BII)I")
 }
 
 #[async_recursion(?Send)]
 async fn skip_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.FileInputStream.skip0(J)J")
+    unsatisfied_link(CLASS_NAME, "skip0", "(J)J")
 }
 
 #[cfg(test)]
@@ -75,17 +75,22 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.FileInputStream.available0()I")]
     async fn test_available_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = available_0(thread, Parameters::default()).await;
+        let result = available_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/FileInputStream",
+            "available0",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.FileInputStream.close0()V")]
     async fn test_close_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = close_0(thread, Parameters::default()).await;
+        let result = close_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/io/FileInputStream", "close0", "()V");
     }
 
     #[tokio::test]
@@ -97,46 +102,54 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.FileInputStream.length0()J")]
     async fn test_length_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = length_0(thread, Parameters::default()).await;
+        let result = length_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/io/FileInputStream", "length0", "()J");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.FileInputStream.open0(Ljava/lang/String;)V"
-    )]
     async fn test_open_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = open_0(thread, Parameters::default()).await;
+        let result = open_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/FileInputStream",
+            "open0",
+            "(Ljava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.FileInputStream.position0()J")]
     async fn test_position_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = position_0(thread, Parameters::default()).await;
+        let result = position_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/io/FileInputStream", "position0", "()J");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.FileInputStream.read0()I")]
     async fn test_read_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = read_0(thread, Parameters::default()).await;
+        let result = read_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/io/FileInputStream", "read0", "()I");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.FileInputStream.readBytes([BII)I")]
     async fn test_read_bytes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = read_bytes(thread, Parameters::default()).await;
+        let result = read_bytes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/FileInputStream",
+            "readBytes",
+            "([BII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.FileInputStream.skip0(J)J")]
     async fn test_skip_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = skip_0(thread, Parameters::default()).await;
+        let result = skip_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/io/FileInputStream", "skip0", "(J)J");
     }
 }
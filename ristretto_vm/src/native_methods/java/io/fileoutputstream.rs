@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Error::InternalError;
@@ -24,7 +24,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn close_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.FileOutputStream.close0()V")
+    unsatisfied_link(CLASS_NAME, "close0", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -34,16 +34,16 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn open_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.FileOutputStream.open0(Ljava/lang/String;Z)V")
+    unsatisfied_link(CLASS_NAME, "open0", "(Ljava/lang/String;Z)V")
 }
 
 #[async_recursion(?Send)]
 async fn write(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.FileOutputStream.write(IZ)V")
+    unsatisfied_link(CLASS_NAME, "write", "(IZ)V")
 }
 
 #[async_recursion(?Send)]
-async fn write_bytes(_thread: Arc<Thread>, mut parameters: Parameters) -> Result<Option<Value>> {
+async fn write_bytes(thread: Arc<Thread>, mut parameters: Parameters) -> Result<Option<Value>> {
     let _append = parameters.pop_int()? != 0;
     let length = usize::try_from(parameters.pop_int()?)?;
     let offset = usize::try_from(parameters.pop_int()?)?;
@@ -51,32 +51,34 @@ async fn write_bytes(_thread: Arc<Thread>, mut parameters: Parameters) -> Result
     let file_output_stream = parameters.pop_object()?;
     let file_descriptor: Object = file_output_stream.value("fd")?.try_into()?;
     let handle = file_descriptor.value("handle")?.to_long()?;
+    let bytes = bytes[offset..offset + length].to_vec();
 
-    match handle {
+    let result = match handle {
         1 => {
-            let stdout = std::io::stdout();
-            let mut stdout = stdout.lock();
-            stdout
-                .write_all(&bytes[offset..offset + length])
-                .map_err(|error| InternalError(error.to_string()))?;
-            stdout
-                .flush()
-                .map_err(|error| InternalError(error.to_string()))?;
+            thread
+                .spawn_blocking(move || {
+                    let stdout = std::io::stdout();
+                    let mut stdout = stdout.lock();
+                    stdout.write_all(&bytes)?;
+                    stdout.flush()
+                })
+                .await?
         }
         2 => {
-            let stderr = std::io::stderr();
-            let mut stderr = stderr.lock();
-            stderr
-                .write_all(&bytes[offset..offset + length])
-                .map_err(|error| InternalError(error.to_string()))?;
-            stderr
-                .flush()
-                .map_err(|error| InternalError(error.to_string()))?;
+            thread
+                .spawn_blocking(move || {
+                    let stderr = std::io::stderr();
+                    let mut stderr = stderr.lock();
+                    stderr.write_all(&bytes)?;
+                    stderr.flush()
+                })
+                .await?
         }
         _ => {
             return Err(InternalError(format!("Invalid file handle: {handle}")));
         }
-    }
+    };
+    result.map_err(|error| InternalError(error.to_string()))?;
     Ok(None)
 }
 
@@ -85,10 +87,10 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.FileOutputStream.close0()V")]
     async fn test_close_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = close_0(thread, Parameters::default()).await;
+        let result = close_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/io/FileOutputStream", "close0", "()V");
     }
 
     #[tokio::test]
@@ -100,18 +102,21 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.FileOutputStream.open0(Ljava/lang/String;Z)V"
-    )]
     async fn test_open_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = open_0(thread, Parameters::default()).await;
+        let result = open_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/FileOutputStream",
+            "open0",
+            "(Ljava/lang/String;Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.FileOutputStream.write(IZ)V")]
     async fn test_write() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = write(thread, Parameters::default()).await;
+        let result = write(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/io/FileOutputStream", "write", "(IZ)V");
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -18,12 +18,12 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn bytes_to_doubles(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.ObjectInputStream.bytesToDoubles([BI[DII)V")
+    unsatisfied_link(CLASS_NAME, "bytesToDoubles", "([BI[DII)V")
 }
 
 #[async_recursion(?Send)]
 async fn bytes_to_floats(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.ObjectInputStream.bytesToFloats([BI[FII)V")
+    unsatisfied_link(CLASS_NAME, "bytesToFloats", "([BI[FII)V")
 }
 
 #[cfg(test)]
@@ -31,20 +31,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.ObjectInputStream.bytesToDoubles([BI[DII)V"
-    )]
     async fn test_bytes_to_doubles() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = bytes_to_doubles(thread, Parameters::default()).await;
+        let result = bytes_to_doubles(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/ObjectInputStream",
+            "bytesToDoubles",
+            "([BI[DII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.ObjectInputStream.bytesToFloats([BI[FII)V"
-    )]
     async fn test_bytes_to_floats() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = bytes_to_floats(thread, Parameters::default()).await;
+        let result = bytes_to_floats(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/ObjectInputStream",
+            "bytesToFloats",
+            "([BI[FII)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -18,12 +18,12 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn doubles_to_bytes(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.ObjectOutputStream.doublesToBytes([DI[BII)V")
+    unsatisfied_link(CLASS_NAME, "doublesToBytes", "([DI[BII)V")
 }
 
 #[async_recursion(?Send)]
 async fn floats_to_bytes(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.ObjectOutputStream.floatsToBytes([FI[BII)V")
+    unsatisfied_link(CLASS_NAME, "floatsToBytes", "([FI[BII)V")
 }
 
 #[cfg(test)]
@@ -31,20 +31,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.ObjectOutputStream.doublesToBytes([DI[BII)V"
-    )]
     async fn test_doubles_to_bytes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = doubles_to_bytes(thread, Parameters::default()).await;
+        let result = doubles_to_bytes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/ObjectOutputStream",
+            "doublesToBytes",
+            "([DI[BII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.ObjectOutputStream.floatsToBytes([FI[BII)V"
-    )]
     async fn test_floats_to_bytes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = floats_to_bytes(thread, Parameters::default()).await;
+        let result = floats_to_bytes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/ObjectOutputStream",
+            "floatsToBytes",
+            "([FI[BII)V",
+        );
     }
 }
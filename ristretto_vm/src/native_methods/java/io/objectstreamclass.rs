@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -24,7 +24,7 @@ async fn has_static_initializer(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.io.ObjectStreamClass.hasStaticInitializer(Ljava/lang/Class;)Z")
+    unsatisfied_link(CLASS_NAME, "hasStaticInitializer", "(Ljava/lang/Class;)Z")
 }
 
 #[async_recursion(?Send)]
@@ -37,12 +37,15 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.ObjectStreamClass.hasStaticInitializer(Ljava/lang/Class;)Z"
-    )]
     async fn test_has_static_initializer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = has_static_initializer(thread, Parameters::default()).await;
+        let result = has_static_initializer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/ObjectStreamClass",
+            "hasStaticInitializer",
+            "(Ljava/lang/Class;)Z",
+        );
     }
 
     #[tokio::test]
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_17, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_17, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -36,12 +36,12 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn close_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.RandomAccessFile.close0()V")
+    unsatisfied_link(CLASS_NAME, "close0", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn get_file_pointer(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.RandomAccessFile.getFilePointer()J")
+    unsatisfied_link(CLASS_NAME, "getFilePointer", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -51,62 +51,62 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn length(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.RandomAccessFile.length()J")
+    unsatisfied_link(CLASS_NAME, "length", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn length_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.RandomAccessFile.length0()J")
+    unsatisfied_link(CLASS_NAME, "length0", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn open_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.RandomAccessFile.open0(Ljava/lang/String;I)V")
+    unsatisfied_link(CLASS_NAME, "open0", "(Ljava/lang/String;I)V")
 }
 
 #[async_recursion(?Send)]
 async fn read_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.RandomAccessFile.read0()I")
+    unsatisfied_link(CLASS_NAME, "read0", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn read_bytes(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.RandomAccessFile.readBytes([BII)I")
+    unsatisfied_link(CLASS_NAME, "readBytes", "([BII)I")
 }
 
 #[async_recursion(?Send)]
 async fn read_bytes_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.RandomAccessFile.readBytes0([BII)I")
+    unsatisfied_link(CLASS_NAME, "readBytes0", "([BII)I")
 }
 
 #[async_recursion(?Send)]
 async fn seek_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.RandomAccessFile.seek0(J)V")
+    unsatisfied_link(CLASS_NAME, "seek0", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_length(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.RandomAccessFile.setLength(J)V")
+    unsatisfied_link(CLASS_NAME, "setLength", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_length_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.RandomAccessFile.setLength0(J)V")
+    unsatisfied_link(CLASS_NAME, "setLength0", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn write_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.RandomAccessFile.write0(I)V")
+    unsatisfied_link(CLASS_NAME, "write0", "(I)V")
 }
 
 #[async_recursion(?Send)]
 async fn write_bytes(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.RandomAccessFile.writeBytes([BII)V")
+    unsatisfied_link(CLASS_NAME, "writeBytes", "([BII)V")
 }
 
 #[async_recursion(?Send)]
 async fn write_bytes_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.RandomAccessFile.writeBytes0([BII)V")
+    unsatisfied_link(CLASS_NAME, "writeBytes0", "([BII)V")
 }
 
 #[cfg(test)]
@@ -114,59 +114,84 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.RandomAccessFile.close0()V")]
     async fn test_close_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = close_0(thread, Parameters::default()).await;
+        let result = close_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/io/RandomAccessFile", "close0", "()V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.RandomAccessFile.length()J")]
     async fn test_length() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = length(thread, Parameters::default()).await;
+        let result = length(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/io/RandomAccessFile", "length", "()J");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.RandomAccessFile.length0()J")]
     async fn test_length_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = length_0(thread, Parameters::default()).await;
+        let result = length_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/io/RandomAccessFile", "length0", "()J");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.RandomAccessFile.readBytes([BII)I")]
     async fn test_read_bytes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = read_bytes(thread, Parameters::default()).await;
+        let result = read_bytes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/RandomAccessFile",
+            "readBytes",
+            "([BII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.RandomAccessFile.readBytes0([BII)I")]
     async fn test_read_bytes_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = read_bytes_0(thread, Parameters::default()).await;
+        let result = read_bytes_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/RandomAccessFile",
+            "readBytes0",
+            "([BII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.RandomAccessFile.setLength(J)V")]
     async fn test_set_length() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_length(thread, Parameters::default()).await;
+        let result = set_length(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/RandomAccessFile",
+            "setLength",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.RandomAccessFile.setLength0(J)V")]
     async fn test_set_length_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_length_0(thread, Parameters::default()).await;
+        let result = set_length_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/RandomAccessFile",
+            "setLength0",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.RandomAccessFile.getFilePointer()J")]
     async fn test_get_file_pointer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_file_pointer(thread, Parameters::default()).await;
+        let result = get_file_pointer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/RandomAccessFile",
+            "getFilePointer",
+            "()J",
+        );
     }
 
     #[tokio::test]
@@ -178,46 +203,59 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.RandomAccessFile.open0(Ljava/lang/String;I)V"
-    )]
     async fn test_open_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = open_0(thread, Parameters::default()).await;
+        let result = open_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/RandomAccessFile",
+            "open0",
+            "(Ljava/lang/String;I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.RandomAccessFile.read0()I")]
     async fn test_read_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = read_0(thread, Parameters::default()).await;
+        let result = read_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/io/RandomAccessFile", "read0", "()I");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.RandomAccessFile.seek0(J)V")]
     async fn test_seek_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = seek_0(thread, Parameters::default()).await;
+        let result = seek_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/io/RandomAccessFile", "seek0", "(J)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.RandomAccessFile.write0(I)V")]
     async fn test_write_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = write_0(thread, Parameters::default()).await;
+        let result = write_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/io/RandomAccessFile", "write0", "(I)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.RandomAccessFile.writeBytes([BII)V")]
     async fn test_write_bytes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = write_bytes(thread, Parameters::default()).await;
+        let result = write_bytes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/RandomAccessFile",
+            "writeBytes",
+            "([BII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.RandomAccessFile.writeBytes0([BII)V")]
     async fn test_write_bytes_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = write_bytes_0(thread, Parameters::default()).await;
+        let result = write_bytes_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/RandomAccessFile",
+            "writeBytes0",
+            "([BII)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Error::InternalError;
@@ -6,7 +6,7 @@ use crate::Result;
 use async_recursion::async_recursion;
 use bitflags::bitflags;
 use ristretto_classloader::{Reference, Value};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 const CLASS_NAME: &str = "java/io/UnixFileSystem";
@@ -162,22 +162,26 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn canonicalize_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.UnixFileSystem.canonicalize0(Ljava/lang/String;)Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "canonicalize0",
+        "(Ljava/lang/String;)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn check_access(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.UnixFileSystem.checkAccess(Ljava/io/File;I)Z")
+    unsatisfied_link(CLASS_NAME, "checkAccess", "(Ljava/io/File;I)Z")
 }
 
 #[async_recursion(?Send)]
 async fn check_access_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.UnixFileSystem.checkAccess0(Ljava/io/File;I)Z")
+    unsatisfied_link(CLASS_NAME, "checkAccess0", "(Ljava/io/File;I)Z")
 }
 
 #[async_recursion(?Send)]
 async fn create_directory(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.UnixFileSystem.createDirectory(Ljava/io/File;)Z")
+    unsatisfied_link(CLASS_NAME, "createDirectory", "(Ljava/io/File;)Z")
 }
 
 #[async_recursion(?Send)]
@@ -185,7 +189,7 @@ async fn create_directory_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.io.UnixFileSystem.createDirectory0(Ljava/io/File;)Z")
+    unsatisfied_link(CLASS_NAME, "createDirectory0", "(Ljava/io/File;)Z")
 }
 
 #[async_recursion(?Send)]
@@ -193,7 +197,7 @@ async fn create_file_exclusively(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.io.UnixFileSystem.createFileExclusively(Ljava/lang/String;)Z")
+    unsatisfied_link(CLASS_NAME, "createFileExclusively", "(Ljava/lang/String;)Z")
 }
 
 #[async_recursion(?Send)]
@@ -201,17 +205,21 @@ async fn create_file_exclusively_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.io.UnixFileSystem.createFileExclusively0(Ljava/lang/String;)Z")
+    unsatisfied_link(
+        CLASS_NAME,
+        "createFileExclusively0",
+        "(Ljava/lang/String;)Z",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn delete_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.UnixFileSystem.delete0(Ljava/io/File;)Z")
+    unsatisfied_link(CLASS_NAME, "delete0", "(Ljava/io/File;)Z")
 }
 
 #[async_recursion(?Send)]
 async fn get_boolean_attributes_0(
-    _thread: Arc<Thread>,
+    thread: Arc<Thread>,
     mut parameters: Parameters,
 ) -> Result<Option<Value>> {
     let Some(Reference::Object(file)) = parameters.pop_reference()? else {
@@ -221,6 +229,14 @@ async fn get_boolean_attributes_0(
     };
     let path: String = file.value("path")?.try_into()?;
     let path = PathBuf::from(path);
+    let attributes = thread
+        .spawn_blocking(move || boolean_attributes(&path))
+        .await?;
+    Ok(Some(Value::Int(attributes.bits())))
+}
+
+/// Get the boolean attributes of a file.
+fn boolean_attributes(path: &Path) -> BooleanAttributeFlags {
     let mut attributes = if path.exists() {
         BooleanAttributeFlags::EXISTS
     } else {
@@ -239,7 +255,7 @@ async fn get_boolean_attributes_0(
     {
         attributes |= BooleanAttributeFlags::HIDDEN;
     }
-    Ok(Some(Value::Int(attributes.bits())))
+    attributes
 }
 
 #[async_recursion(?Send)]
@@ -247,7 +263,7 @@ async fn get_last_modified_time(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.io.UnixFileSystem.getLastModifiedTime(Ljava/io/File;)J")
+    unsatisfied_link(CLASS_NAME, "getLastModifiedTime", "(Ljava/io/File;)J")
 }
 
 #[async_recursion(?Send)]
@@ -255,7 +271,7 @@ async fn get_last_modified_time_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.io.UnixFileSystem.getLastModifiedTime0(Ljava/io/File;)J")
+    unsatisfied_link(CLASS_NAME, "getLastModifiedTime0", "(Ljava/io/File;)J")
 }
 
 #[async_recursion(?Send)]
@@ -265,12 +281,12 @@ async fn get_length(thread: Arc<Thread>, parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn get_length_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.UnixFileSystem.getLength0(Ljava/io/File;)J")
+    unsatisfied_link(CLASS_NAME, "getLength0", "(Ljava/io/File;)J")
 }
 
 #[async_recursion(?Send)]
 async fn get_name_max_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.UnixFileSystem.getNameMax0(Ljava/lang/String;)J")
+    unsatisfied_link(CLASS_NAME, "getNameMax0", "(Ljava/lang/String;)J")
 }
 
 #[async_recursion(?Send)]
@@ -280,7 +296,7 @@ async fn get_space(thread: Arc<Thread>, parameters: Parameters) -> Result<Option
 
 #[async_recursion(?Send)]
 async fn get_space_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.UnixFileSystem.getSpace0(Ljava/io/File;I)J")
+    unsatisfied_link(CLASS_NAME, "getSpace0", "(Ljava/io/File;I)J")
 }
 
 #[async_recursion(?Send)]
@@ -295,12 +311,12 @@ async fn list(thread: Arc<Thread>, parameters: Parameters) -> Result<Option<Valu
 
 #[async_recursion(?Send)]
 async fn list_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.UnixFileSystem.list0(Ljava/io/File;)[Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "list0", "(Ljava/io/File;)[Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
 async fn rename_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.UnixFileSystem.rename0(Ljava/io/File;Ljava/io/File;)Z")
+    unsatisfied_link(CLASS_NAME, "rename0", "(Ljava/io/File;Ljava/io/File;)Z")
 }
 
 #[async_recursion(?Send)]
@@ -316,7 +332,7 @@ async fn set_last_modified_time_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.io.UnixFileSystem.setLastModifiedTime0(Ljava/io/File;J)Z")
+    unsatisfied_link(CLASS_NAME, "setLastModifiedTime0", "(Ljava/io/File;J)Z")
 }
 
 #[async_recursion(?Send)]
@@ -326,7 +342,7 @@ async fn set_permission(thread: Arc<Thread>, parameters: Parameters) -> Result<O
 
 #[async_recursion(?Send)]
 async fn set_permission_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.UnixFileSystem.setPermission0(Ljava/io/File;IZZ)Z")
+    unsatisfied_link(CLASS_NAME, "setPermission0", "(Ljava/io/File;IZZ)Z")
 }
 
 #[async_recursion(?Send)]
@@ -336,146 +352,208 @@ async fn set_read_only(thread: Arc<Thread>, parameters: Parameters) -> Result<Op
 
 #[async_recursion(?Send)]
 async fn set_read_only_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.UnixFileSystem.setReadOnly0(Ljava/io/File;)Z")
+    unsatisfied_link(CLASS_NAME, "setReadOnly0", "(Ljava/io/File;)Z")
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_boolean_attributes() {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        assert_eq!(
+            BooleanAttributeFlags::EXISTS | BooleanAttributeFlags::DIRECTORY,
+            boolean_attributes(&cargo_manifest)
+        );
+        assert_eq!(
+            BooleanAttributeFlags::EXISTS | BooleanAttributeFlags::REGULAR,
+            boolean_attributes(&cargo_manifest.join("Cargo.toml"))
+        );
+        assert_eq!(
+            BooleanAttributeFlags::empty(),
+            boolean_attributes(&cargo_manifest.join("missing"))
+        );
+    }
+
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.canonicalize0(Ljava/lang/String;)Ljava/lang/String;"
-    )]
     async fn test_canonicalize_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = canonicalize_0(thread, Parameters::default()).await;
+        let result = canonicalize_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "canonicalize0",
+            "(Ljava/lang/String;)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.checkAccess(Ljava/io/File;I)Z"
-    )]
     async fn test_check_access() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = check_access(thread, Parameters::default()).await;
+        let result = check_access(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "checkAccess",
+            "(Ljava/io/File;I)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.checkAccess0(Ljava/io/File;I)Z"
-    )]
     async fn test_check_access_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = check_access_0(thread, Parameters::default()).await;
+        let result = check_access_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "checkAccess0",
+            "(Ljava/io/File;I)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.createDirectory(Ljava/io/File;)Z"
-    )]
     async fn test_create_directory() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_directory(thread, Parameters::default()).await;
+        let result = create_directory(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "createDirectory",
+            "(Ljava/io/File;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.createDirectory0(Ljava/io/File;)Z"
-    )]
     async fn test_create_directory_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_directory_0(thread, Parameters::default()).await;
+        let result = create_directory_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "createDirectory0",
+            "(Ljava/io/File;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.createFileExclusively(Ljava/lang/String;)Z"
-    )]
     async fn test_create_file_exclusively() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_file_exclusively(thread, Parameters::default()).await;
+        let result = create_file_exclusively(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "createFileExclusively",
+            "(Ljava/lang/String;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.createFileExclusively0(Ljava/lang/String;)Z"
-    )]
     async fn test_create_file_exclusively_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_file_exclusively_0(thread, Parameters::default()).await;
+        let result = create_file_exclusively_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "createFileExclusively0",
+            "(Ljava/lang/String;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.delete0(Ljava/io/File;)Z"
-    )]
     async fn test_delete_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = delete_0(thread, Parameters::default()).await;
+        let result = delete_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "delete0",
+            "(Ljava/io/File;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.getLastModifiedTime(Ljava/io/File;)J"
-    )]
     async fn test_get_last_modified_time() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_last_modified_time(thread, Parameters::default()).await;
+        let result = get_last_modified_time(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "getLastModifiedTime",
+            "(Ljava/io/File;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.getLastModifiedTime0(Ljava/io/File;)J"
-    )]
     async fn test_get_last_modified_time_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_last_modified_time_0(thread, Parameters::default()).await;
+        let result = get_last_modified_time_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "getLastModifiedTime0",
+            "(Ljava/io/File;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.getLength0(Ljava/io/File;)J"
-    )]
     async fn test_get_length() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_length(thread, Parameters::default()).await;
+        let result = get_length(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "getLength0",
+            "(Ljava/io/File;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.getLength0(Ljava/io/File;)J"
-    )]
     async fn test_get_length_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_length_0(thread, Parameters::default()).await;
+        let result = get_length_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "getLength0",
+            "(Ljava/io/File;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.getNameMax0(Ljava/lang/String;)J"
-    )]
     async fn test_get_name_max_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_name_max_0(thread, Parameters::default()).await;
+        let result = get_name_max_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "getNameMax0",
+            "(Ljava/lang/String;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.getSpace0(Ljava/io/File;I)J"
-    )]
     async fn test_get_space() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_space(thread, Parameters::default()).await;
+        let result = get_space(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "getSpace0",
+            "(Ljava/io/File;I)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.getSpace0(Ljava/io/File;I)J"
-    )]
     async fn test_get_space_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_space_0(thread, Parameters::default()).await;
+        let result = get_space_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "getSpace0",
+            "(Ljava/io/File;I)J",
+        );
     }
 
     #[tokio::test]
@@ -487,83 +565,110 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.list0(Ljava/io/File;)[Ljava/lang/String;"
-    )]
     async fn test_list() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = list(thread, Parameters::default()).await;
+        let result = list(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "list0",
+            "(Ljava/io/File;)[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.list0(Ljava/io/File;)[Ljava/lang/String;"
-    )]
     async fn test_list_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = list_0(thread, Parameters::default()).await;
+        let result = list_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "list0",
+            "(Ljava/io/File;)[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.rename0(Ljava/io/File;Ljava/io/File;)Z"
-    )]
     async fn test_rename_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = rename_0(thread, Parameters::default()).await;
+        let result = rename_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "rename0",
+            "(Ljava/io/File;Ljava/io/File;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.setLastModifiedTime0(Ljava/io/File;J)Z"
-    )]
     async fn test_set_last_modified_time() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_last_modified_time(thread, Parameters::default()).await;
+        let result = set_last_modified_time(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "setLastModifiedTime0",
+            "(Ljava/io/File;J)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.setLastModifiedTime0(Ljava/io/File;J)Z"
-    )]
     async fn test_set_last_modified_time_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_last_modified_time_0(thread, Parameters::default()).await;
+        let result = set_last_modified_time_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "setLastModifiedTime0",
+            "(Ljava/io/File;J)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.setPermission0(Ljava/io/File;IZZ)Z"
-    )]
     async fn test_set_permission() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_permission(thread, Parameters::default()).await;
+        let result = set_permission(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "setPermission0",
+            "(Ljava/io/File;IZZ)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.setPermission0(Ljava/io/File;IZZ)Z"
-    )]
     async fn test_set_permission_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_permission_0(thread, Parameters::default()).await;
+        let result = set_permission_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "setPermission0",
+            "(Ljava/io/File;IZZ)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.setReadOnly0(Ljava/io/File;)Z"
-    )]
     async fn test_set_read_only() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_read_only(thread, Parameters::default()).await;
+        let result = set_read_only(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "setReadOnly0",
+            "(Ljava/io/File;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.UnixFileSystem.setReadOnly0(Ljava/io/File;)Z"
-    )]
     async fn test_set_read_only_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_read_only_0(thread, Parameters::default()).await;
+        let result = set_read_only_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/UnixFileSystem",
+            "setReadOnly0",
+            "(Ljava/io/File;)Z",
+        );
     }
 }
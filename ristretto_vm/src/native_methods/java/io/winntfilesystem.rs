@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -109,7 +109,11 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn canonicalize_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.WinNTFileSystem.canonicalize0(Ljava/lang/String;)Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "canonicalize0",
+        "(Ljava/lang/String;)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -117,17 +121,21 @@ async fn canonicalize_with_prefix_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.io.WinNTFileSystem.canonicalizeWithPrefix0(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "canonicalizeWithPrefix0",
+        "(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn check_access(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.WinNTFileSystem.checkAccess(Ljava/io/File;I)Z")
+    unsatisfied_link(CLASS_NAME, "checkAccess", "(Ljava/io/File;I)Z")
 }
 
 #[async_recursion(?Send)]
 async fn create_directory(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.WinNTFileSystem.createDirectory(Ljava/io/File;)Z")
+    unsatisfied_link(CLASS_NAME, "createDirectory", "(Ljava/io/File;)Z")
 }
 
 #[async_recursion(?Send)]
@@ -135,12 +143,12 @@ async fn create_file_exclusively(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.io.WinNTFileSystem.createFileExclusively(Ljava/lang/String;)Z")
+    unsatisfied_link(CLASS_NAME, "createFileExclusively", "(Ljava/lang/String;)Z")
 }
 
 #[async_recursion(?Send)]
 async fn delete_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.WinNTFileSystem.delete0(Ljava/io/File;)Z")
+    unsatisfied_link(CLASS_NAME, "delete0", "(Ljava/io/File;)Z")
 }
 
 #[async_recursion(?Send)]
@@ -148,7 +156,7 @@ async fn get_boolean_attributes(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.io.WinNTFileSystem.getBooleanAttributes(Ljava/io/File;)I")
+    unsatisfied_link(CLASS_NAME, "getBooleanAttributes", "(Ljava/io/File;)I")
 }
 
 #[async_recursion(?Send)]
@@ -156,7 +164,7 @@ async fn get_drive_directory(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.io.WinNTFileSystem.getDriveDirectory(I)Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getDriveDirectory", "(I)Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
@@ -164,22 +172,22 @@ async fn get_last_modified_time(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.io.WinNTFileSystem.getLastModifiedTime(Ljava/io/File;)J")
+    unsatisfied_link(CLASS_NAME, "getLastModifiedTime", "(Ljava/io/File;)J")
 }
 
 #[async_recursion(?Send)]
 async fn get_length(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.WinNTFileSystem.getLength(Ljava/io/File;)J")
+    unsatisfied_link(CLASS_NAME, "getLength", "(Ljava/io/File;)J")
 }
 
 #[async_recursion(?Send)]
 async fn get_name_max_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.WinNTFileSystem.getNameMax0(Ljava/lang/String;)I")
+    unsatisfied_link(CLASS_NAME, "getNameMax0", "(Ljava/lang/String;)I")
 }
 
 #[async_recursion(?Send)]
 async fn get_space_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.WinNTFileSystem.getSpace0(Ljava/io/File;I)J")
+    unsatisfied_link(CLASS_NAME, "getSpace0", "(Ljava/io/File;I)J")
 }
 
 #[async_recursion(?Send)]
@@ -189,17 +197,17 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn list(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.WinNTFileSystem.list(Ljava/io/File;)[Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "list", "(Ljava/io/File;)[Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
 async fn list_roots_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.WinNTFileSystem.listRoots0()I")
+    unsatisfied_link(CLASS_NAME, "listRoots0", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn rename_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.WinNTFileSystem.rename0(Ljava/io/File;Ljava/io/File;)Z")
+    unsatisfied_link(CLASS_NAME, "rename0", "(Ljava/io/File;Ljava/io/File;)Z")
 }
 
 #[async_recursion(?Send)]
@@ -207,17 +215,17 @@ async fn set_last_modified_time(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.io.WinNTFileSystem.setLastModifiedTime(Ljava/io/File;J)Z")
+    unsatisfied_link(CLASS_NAME, "setLastModifiedTime", "(Ljava/io/File;J)Z")
 }
 
 #[async_recursion(?Send)]
 async fn set_permission(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.WinNTFileSystem.setPermission(Ljava/io/File;IZZ)Z")
+    unsatisfied_link(CLASS_NAME, "setPermission", "(Ljava/io/File;IZZ)Z")
 }
 
 #[async_recursion(?Send)]
 async fn set_read_only(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.io.WinNTFileSystem.setReadOnly(Ljava/io/File;)Z")
+    unsatisfied_link(CLASS_NAME, "setReadOnly", "(Ljava/io/File;)Z")
 }
 
 #[cfg(test)]
@@ -225,111 +233,147 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.WinNTFileSystem.canonicalize0(Ljava/lang/String;)Ljava/lang/String;"
-    )]
     async fn test_canonicalize_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = canonicalize_0(thread, Parameters::default()).await;
+        let result = canonicalize_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/WinNTFileSystem",
+            "canonicalize0",
+            "(Ljava/lang/String;)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.WinNTFileSystem.canonicalizeWithPrefix0(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String;"
-    )]
     async fn test_canonicalize_with_prefix_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = canonicalize_with_prefix_0(thread, Parameters::default()).await;
+        let result = canonicalize_with_prefix_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/WinNTFileSystem",
+            "canonicalizeWithPrefix0",
+            "(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.WinNTFileSystem.checkAccess(Ljava/io/File;I)Z"
-    )]
     async fn test_check_access() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = check_access(thread, Parameters::default()).await;
+        let result = check_access(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/WinNTFileSystem",
+            "checkAccess",
+            "(Ljava/io/File;I)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.WinNTFileSystem.createDirectory(Ljava/io/File;)Z"
-    )]
     async fn test_create_directory() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_directory(thread, Parameters::default()).await;
+        let result = create_directory(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/WinNTFileSystem",
+            "createDirectory",
+            "(Ljava/io/File;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.WinNTFileSystem.createFileExclusively(Ljava/lang/String;)Z"
-    )]
     async fn test_create_file_exclusively() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_file_exclusively(thread, Parameters::default()).await;
+        let result = create_file_exclusively(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/WinNTFileSystem",
+            "createFileExclusively",
+            "(Ljava/lang/String;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.WinNTFileSystem.delete0(Ljava/io/File;)Z"
-    )]
     async fn test_delete_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = delete_0(thread, Parameters::default()).await;
+        let result = delete_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/WinNTFileSystem",
+            "delete0",
+            "(Ljava/io/File;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.WinNTFileSystem.getBooleanAttributes(Ljava/io/File;)I"
-    )]
     async fn test_get_boolean_attributes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_boolean_attributes(thread, Parameters::default()).await;
+        let result = get_boolean_attributes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/WinNTFileSystem",
+            "getBooleanAttributes",
+            "(Ljava/io/File;)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.WinNTFileSystem.getDriveDirectory(I)Ljava/lang/String;"
-    )]
     async fn test_get_drive_directory() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_drive_directory(thread, Parameters::default()).await;
+        let result = get_drive_directory(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/WinNTFileSystem",
+            "getDriveDirectory",
+            "(I)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.WinNTFileSystem.getLastModifiedTime(Ljava/io/File;)J"
-    )]
     async fn test_get_last_modified_time() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_last_modified_time(thread, Parameters::default()).await;
+        let result = get_last_modified_time(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/WinNTFileSystem",
+            "getLastModifiedTime",
+            "(Ljava/io/File;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.WinNTFileSystem.getLength(Ljava/io/File;)J"
-    )]
     async fn test_get_length() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_length(thread, Parameters::default()).await;
+        let result = get_length(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/WinNTFileSystem",
+            "getLength",
+            "(Ljava/io/File;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.WinNTFileSystem.getNameMax0(Ljava/lang/String;)I"
-    )]
     async fn test_get_name_max_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_name_max_0(thread, Parameters::default()).await;
+        let result = get_name_max_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/WinNTFileSystem",
+            "getNameMax0",
+            "(Ljava/lang/String;)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.WinNTFileSystem.getSpace0(Ljava/io/File;I)J"
-    )]
     async fn test_get_space_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_space_0(thread, Parameters::default()).await;
+        let result = get_space_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/WinNTFileSystem",
+            "getSpace0",
+            "(Ljava/io/File;I)J",
+        );
     }
 
     #[tokio::test]
@@ -341,54 +385,74 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.WinNTFileSystem.list(Ljava/io/File;)[Ljava/lang/String;"
-    )]
     async fn test_list() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = list(thread, Parameters::default()).await;
+        let result = list(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/WinNTFileSystem",
+            "list",
+            "(Ljava/io/File;)[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.io.WinNTFileSystem.listRoots0()I")]
     async fn test_list_roots_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = list_roots_0(thread, Parameters::default()).await;
+        let result = list_roots_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/WinNTFileSystem",
+            "listRoots0",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.WinNTFileSystem.rename0(Ljava/io/File;Ljava/io/File;)Z"
-    )]
     async fn test_rename_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = rename_0(thread, Parameters::default()).await;
+        let result = rename_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/WinNTFileSystem",
+            "rename0",
+            "(Ljava/io/File;Ljava/io/File;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.WinNTFileSystem.setLastModifiedTime(Ljava/io/File;J)Z"
-    )]
     async fn test_set_last_modified_time() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_last_modified_time(thread, Parameters::default()).await;
+        let result = set_last_modified_time(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/WinNTFileSystem",
+            "setLastModifiedTime",
+            "(Ljava/io/File;J)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.WinNTFileSystem.setPermission(Ljava/io/File;IZZ)Z"
-    )]
     async fn test_set_permission() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_permission(thread, Parameters::default()).await;
+        let result = set_permission(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/WinNTFileSystem",
+            "setPermission",
+            "(Ljava/io/File;IZZ)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.io.WinNTFileSystem.setReadOnly(Ljava/io/File;)Z"
-    )]
     async fn test_set_read_only() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_read_only(thread, Parameters::default()).await;
+        let result = set_read_only(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/io/WinNTFileSystem",
+            "setReadOnly",
+            "(Ljava/io/File;)Z",
+        );
     }
 }
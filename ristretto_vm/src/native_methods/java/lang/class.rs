@@ -1,5 +1,7 @@
 use crate::java_object::JavaObject;
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17, JAVA_21, JAVA_8};
+use crate::native_methods::registry::{
+    unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17, JAVA_21, JAVA_8,
+};
 use crate::parameters::Parameters;
 use crate::rust_value::RustValue;
 use crate::thread::Thread;
@@ -212,11 +214,18 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
     );
 }
 
-async fn get_class(thread: &Thread, object: &Object) -> Result<Arc<Class>> {
+/// Get the class represented by a `java.lang.Class` object, or the class of any other object.
+pub(crate) async fn get_class(thread: &Thread, object: &Object) -> Result<Arc<Class>> {
     let class = object.class();
     if class.name() == "java/lang/Class" {
         let class_name: String = object.value("name")?.try_into()?;
-        let class = thread.class(class_name.as_str()).await?;
+        let class_loader = match object.value("classLoader")? {
+            Value::Object(Some(Reference::Object(class_loader))) => Some(class_loader),
+            _ => None,
+        };
+        let class = thread
+            .class_with_loader(class_loader.as_ref(), class_name.as_str())
+            .await?;
         return Ok(class);
     }
     Ok(Arc::clone(class))
@@ -234,14 +243,20 @@ async fn desired_assertion_status_0(
 async fn for_name_0(thread: Arc<Thread>, mut parameters: Parameters) -> Result<Option<Value>> {
     // TODO: Add support for unused parameters
     let _caller = parameters.pop_reference()?;
-    let _class_loader = parameters.pop_reference()?;
+    let class_loader = match parameters.pop_reference()? {
+        Some(Reference::Object(class_loader)) => Some(class_loader),
+        _ => None,
+    };
     let _initialize = parameters.pop_int()? != 0;
     let Ok(object) = parameters.pop_object() else {
         return Err(NullPointerException("className cannot be null".to_string()).into());
     };
     let class_name: String = object.try_into()?;
     let vm = thread.vm()?;
-    let class = match thread.class(&class_name).await {
+    let class = match thread
+        .class_with_loader(class_loader.as_ref(), &class_name)
+        .await
+    {
         Ok(class) => class,
         Err(_error) => {
             return Err(ClassNotFoundException(class_name).into());
@@ -305,7 +320,11 @@ async fn get_component_type(
 
 #[async_recursion(?Send)]
 async fn get_constant_pool(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Class.getConstantPool()Lsun/reflect/ConstantPool;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getConstantPool",
+        "()Lsun/reflect/ConstantPool;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -662,7 +681,7 @@ async fn get_generic_signature_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Class.getGenericSignature0()Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getGenericSignature0", "()Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
@@ -717,7 +736,7 @@ async fn get_name_0(thread: Arc<Thread>, mut parameters: Parameters) -> Result<O
 
 #[async_recursion(?Send)]
 async fn get_nest_host_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Class.getNestHost0()Ljava/lang/Class;")
+    unsatisfied_link(CLASS_NAME, "getNestHost0", "()Ljava/lang/Class;")
 }
 
 #[async_recursion(?Send)]
@@ -725,7 +744,7 @@ async fn get_nest_members_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Class.getNestMembers0()[Ljava/lang/Class;")
+    unsatisfied_link(CLASS_NAME, "getNestMembers0", "()[Ljava/lang/Class;")
 }
 
 #[async_recursion(?Send)]
@@ -757,7 +776,11 @@ async fn get_protection_domain_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Class.getProtectionDomain0()Ljava/security/ProtectionDomain;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getProtectionDomain0",
+        "()Ljava/security/ProtectionDomain;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -765,7 +788,7 @@ async fn get_raw_annotations(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Class.getRawAnnotations()[B")
+    unsatisfied_link(CLASS_NAME, "getRawAnnotations", "()[B")
 }
 
 #[async_recursion(?Send)]
@@ -773,7 +796,7 @@ async fn get_raw_type_annotations(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Class.getRawTypeAnnotations()[B")
+    unsatisfied_link(CLASS_NAME, "getRawTypeAnnotations", "()[B")
 }
 
 #[async_recursion(?Send)]
@@ -781,7 +804,11 @@ async fn get_record_components_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Class.getRecordComponents0()[Ljava/lang/reflect/RecordComponent;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getRecordComponents0",
+        "()[Ljava/lang/reflect/RecordComponent;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -871,9 +898,10 @@ async fn is_assignable_from(
 }
 
 #[async_recursion(?Send)]
-async fn is_hidden(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    // TODO: implement support for hidden classes
-    Ok(Some(Value::from(false)))
+async fn is_hidden(thread: Arc<Thread>, mut parameters: Parameters) -> Result<Option<Value>> {
+    let object = parameters.pop_object()?;
+    let class = get_class(&thread, &object).await?;
+    Ok(Some(Value::from(class.is_hidden())))
 }
 
 #[async_recursion(?Send)]
@@ -915,7 +943,7 @@ async fn is_primitive(thread: Arc<Thread>, mut parameters: Parameters) -> Result
 
 #[async_recursion(?Send)]
 async fn is_record_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Class.isRecord0()Z")
+    unsatisfied_link(CLASS_NAME, "isRecord0", "()Z")
 }
 
 #[async_recursion(?Send)]
@@ -1039,12 +1067,15 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Class.getConstantPool()Lsun/reflect/ConstantPool;"
-    )]
     async fn test_get_constant_pool() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_constant_pool(thread, Parameters::default()).await;
+        let result = get_constant_pool(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Class",
+            "getConstantPool",
+            "()Lsun/reflect/ConstantPool;",
+        );
     }
 
     #[tokio::test]
@@ -1277,12 +1308,15 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Class.getGenericSignature0()Ljava/lang/String;"
-    )]
     async fn test_get_generic_signature_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_generic_signature_0(thread, Parameters::default()).await;
+        let result = get_generic_signature_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Class",
+            "getGenericSignature0",
+            "()Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
@@ -1337,21 +1371,27 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Class.getNestHost0()Ljava/lang/Class;"
-    )]
     async fn test_get_nest_host_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_nest_host_0(thread, Parameters::default()).await;
+        let result = get_nest_host_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Class",
+            "getNestHost0",
+            "()Ljava/lang/Class;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Class.getNestMembers0()[Ljava/lang/Class;"
-    )]
     async fn test_get_nest_members_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_nest_members_0(thread, Parameters::default()).await;
+        let result = get_nest_members_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Class",
+            "getNestMembers0",
+            "()[Ljava/lang/Class;",
+        );
     }
 
     #[tokio::test]
@@ -1377,35 +1417,51 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Class.getProtectionDomain0()Ljava/security/ProtectionDomain;"
-    )]
     async fn test_get_protection_domain_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_protection_domain_0(thread, Parameters::default()).await;
+        let result = get_protection_domain_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Class",
+            "getProtectionDomain0",
+            "()Ljava/security/ProtectionDomain;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.Class.getRawAnnotations()[B")]
     async fn test_get_raw_annotations() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_raw_annotations(thread, Parameters::default()).await;
+        let result = get_raw_annotations(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Class",
+            "getRawAnnotations",
+            "()[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.Class.getRawTypeAnnotations()[B")]
     async fn test_get_raw_type_annotations() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_raw_type_annotations(thread, Parameters::default()).await;
+        let result = get_raw_type_annotations(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Class",
+            "getRawTypeAnnotations",
+            "()[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Class.getRecordComponents0()[Ljava/lang/reflect/RecordComponent;"
-    )]
     async fn test_get_record_components_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_record_components_0(thread, Parameters::default()).await;
+        let result = get_record_components_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Class",
+            "getRecordComponents0",
+            "()[Ljava/lang/reflect/RecordComponent;",
+        );
     }
 
     #[tokio::test]
@@ -1534,8 +1590,11 @@ mod tests {
 
     #[tokio::test]
     async fn test_is_hidden() -> Result<()> {
-        let (_vm, thread) = crate::test::thread().await?;
-        let result = is_hidden(thread, Parameters::default()).await?;
+        let (vm, thread) = crate::test::thread().await?;
+        let class = thread.class("java.lang.String").await?;
+        let class_object = class.to_object(&vm).await?;
+        let parameters = Parameters::new(vec![class_object]);
+        let result = is_hidden(thread, parameters).await?;
         assert_eq!(result, Some(Value::from(false)));
         Ok(())
     }
@@ -1611,10 +1670,10 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.Class.isRecord0()Z")]
     async fn test_is_record_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_record_0(thread, Parameters::default()).await;
+        let result = is_record_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/lang/Class", "isRecord0", "()Z");
     }
 
     #[tokio::test]
@@ -2,8 +2,10 @@ use crate::java_object::JavaObject;
 use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
-use crate::JavaError::{ClassFormatError, IndexOutOfBoundsException, NoClassDefFoundError};
-use crate::{Result, VM};
+use crate::JavaError::{
+    ClassFormatError, IndexOutOfBoundsException, LinkageError, NoClassDefFoundError,
+};
+use crate::Result;
 use async_recursion::async_recursion;
 use ristretto_classfile::ClassFile;
 use ristretto_classloader::{Class, Object, Reference, Value};
@@ -12,6 +14,9 @@ use std::sync::Arc;
 
 const CLASS_NAME: &str = "java/lang/ClassLoader";
 
+/// The `java.lang.invoke.MethodHandles.Lookup` flag for a hidden class.
+const HIDDEN_CLASS: i32 = 0x02;
+
 /// Register all native methods for `java.lang.ClassLoader`.
 pub(crate) fn register(registry: &mut MethodRegistry) {
     if registry.java_major_version() <= JAVA_8 {
@@ -79,16 +84,8 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
     );
 }
 
-/// Create a `java.lang.Class` object from a byte array.
-/// This method is used by the `defineClass0`, `defineClass1`, and `defineClass2` native methods.
-/// The `defineClass0` method is used by Java 8 and earlier versions.
-async fn class_object_from_bytes(
-    vm: &Arc<VM>,
-    source_file: Option<Reference>,
-    bytes: &[u8],
-    offset: i32,
-    length: i32,
-) -> Result<Object> {
+/// Get the bytes of a class file from a range of a byte array.
+fn class_bytes(bytes: &[u8], offset: i32, length: i32) -> Result<&[u8]> {
     let bytes_length = i32::try_from(bytes.len())?;
     if offset < 0 || length < 0 || offset + length > bytes_length {
         return Err(IndexOutOfBoundsException {
@@ -99,7 +96,19 @@ async fn class_object_from_bytes(
     }
     let offset = usize::try_from(offset)?;
     let length = usize::try_from(length)?;
-    let bytes = bytes[offset..offset + length].to_vec();
+    Ok(&bytes[offset..offset + length])
+}
+
+/// Create a class from a byte array.
+/// This method is used by the `defineClass0`, `defineClass1`, and `defineClass2` native methods.
+/// The `defineClass0` method is used by Java 8 and earlier versions.
+pub(crate) fn class_from_bytes(
+    source_file: Option<Reference>,
+    bytes: &[u8],
+    offset: i32,
+    length: i32,
+) -> Result<Arc<Class>> {
+    let bytes = class_bytes(bytes, offset, length)?.to_vec();
     let mut bytes = Cursor::new(bytes);
     let class_file = match ClassFile::from_bytes(&mut bytes) {
         Ok(class_file) => class_file,
@@ -116,10 +125,85 @@ async fn class_object_from_bytes(
         // TODO: implement setting the source file
     }
 
-    let class = Arc::new(Class::from(class_file)?);
-    let class = class.to_object(vm).await?;
-    let class: Object = class.try_into()?;
-    Ok(class)
+    Ok(Arc::new(Class::from(class_file)?))
+}
+
+/// Verify that a defined class has the name expected by the caller.
+pub(crate) fn check_class_name(
+    class: &Class,
+    expected_class_name: Option<Reference>,
+) -> Result<()> {
+    if let Some(expected_class_name) = expected_class_name {
+        let expected_class_name: String = expected_class_name.try_into()?;
+        let class_name = class.name();
+        if class_name != expected_class_name.replace('.', "/") {
+            return Err(NoClassDefFoundError(class_name.to_string()).into());
+        }
+    }
+    Ok(())
+}
+
+/// Define a class with the class loader backing a `java.lang.ClassLoader` object; a `null` class
+/// loader defines the class with the VM class loader.  Classes defined by a Java class loader are
+/// only known to that class loader, so class loaders that define classes with the same name are
+/// isolated from each other; the classes that they reference are resolved by the defining class
+/// loader.
+pub(crate) async fn define_class(
+    thread: &Arc<Thread>,
+    class_loader: Option<Object>,
+    class: Arc<Class>,
+) -> Result<Value> {
+    let vm = thread.vm()?;
+    let Some(class_loader_object) = class_loader else {
+        let class = thread.define_class(class).await?;
+        return class.to_object(&vm).await;
+    };
+    let class_loader = vm.java_class_loader(&class_loader_object).await?;
+    if class_loader.find_loaded(class.name()).await.is_some() {
+        return Err(LinkageError(format!(
+            "loader {} attempted duplicate class definition for {}",
+            class_loader.name(),
+            class.name().replace('/', ".")
+        ))
+        .into());
+    }
+    class_loader.register(class.clone()).await?;
+    vm.set_java_class_loader_object(&class, &class_loader_object);
+    let class = thread.initialize_class(class).await?;
+    class.to_object(&vm).await
+}
+
+/// Define a hidden class with the class loader backing a `java.lang.ClassLoader` object, or the
+/// VM class loader for a `null` class loader.  The hidden class is given a unique name, so it
+/// cannot conflict with, or be found by, the name in the class file.
+async fn define_hidden_class(
+    thread: &Arc<Thread>,
+    class_loader: Option<Object>,
+    bytes: &[u8],
+) -> Result<Value> {
+    let vm = thread.vm()?;
+    let result = if let Some(class_loader_object) = &class_loader {
+        let class_loader = vm.java_class_loader(class_loader_object).await?;
+        class_loader.define_hidden_class(bytes).await
+    } else {
+        let class_loader_lock = vm.class_loader();
+        let class_loader = class_loader_lock.read().await;
+        class_loader.define_hidden_class(bytes).await
+    };
+    let class = match result {
+        Ok(class) => class,
+        Err(ristretto_classloader::Error::ClassFileError(error)) => {
+            return Err(ClassFormatError(error.to_string()).into());
+        }
+        Err(error) => return Err(error.into()),
+    };
+    let class = if let Some(class_loader_object) = &class_loader {
+        vm.set_java_class_loader_object(&class, class_loader_object);
+        thread.initialize_class(class).await?
+    } else {
+        thread.define_class(class).await?
+    };
+    class.to_object(&vm).await
 }
 
 #[async_recursion(?Send)]
@@ -131,32 +215,37 @@ async fn define_class_0(thread: Arc<Thread>, mut parameters: Parameters) -> Resu
         let length = parameters.pop_int()?;
         let offset = parameters.pop_int()?;
         let bytes: Vec<u8> = parameters.pop()?.try_into()?;
-        let class = class_object_from_bytes(&vm, None, &bytes, offset, length).await?;
-        if let Some(expected_class_name) = parameters.pop_reference()? {
-            let expected_class_name: String = expected_class_name.try_into()?;
-            let class_name = class.class().name();
-            if class_name != expected_class_name {
-                return Err(NoClassDefFoundError(class_name.to_string()).into());
-            }
-        }
-        class
+        let class = class_from_bytes(None, &bytes, offset, length)?;
+        check_class_name(&class, parameters.pop_reference()?)?;
+        let class_loader = parameters.pop_object().ok();
+        define_class(&thread, class_loader, class).await?
     } else {
-        let _class_data = parameters.pop_reference()?;
-        let _flags = parameters.pop_int()?;
+        let class_data = parameters.pop_reference()?;
+        let flags = parameters.pop_int()?;
         let _initialize = parameters.pop_int()? != 0;
         let _protection_domain = parameters.pop_object()?;
         let length = parameters.pop_int()?;
         let offset = parameters.pop_int()?;
         let bytes: Vec<u8> = parameters.pop()?.try_into()?;
-        let _name: String = parameters.pop()?.try_into()?;
-        let _lookup: Arc<Class> = parameters.pop()?.try_into()?;
-        let class = class_object_from_bytes(&vm, None, &bytes, offset, length).await?;
-        let class_loader = parameters.pop()?;
-        class.set_value("classLoader", class_loader)?;
+        let name = parameters.pop_reference()?;
+        let _lookup = parameters.pop()?;
+        let class_loader = parameters.pop_object().ok();
+        let class = if flags & HIDDEN_CLASS == 0 {
+            let class = class_from_bytes(None, &bytes, offset, length)?;
+            check_class_name(&class, name)?;
+            define_class(&thread, class_loader, class).await?
+        } else {
+            let bytes = class_bytes(&bytes, offset, length)?;
+            define_hidden_class(&thread, class_loader, bytes).await?
+        };
+        if let Some(class_data) = class_data {
+            let class_object: Object = class.clone().try_into()?;
+            class_object.set_value("classData", Value::Object(Some(class_data)))?;
+        }
         class
     };
 
-    Ok(Some(Value::from(class)))
+    Ok(Some(class))
 }
 
 #[async_recursion(?Send)]
@@ -166,23 +255,11 @@ async fn define_class_1(thread: Arc<Thread>, mut parameters: Parameters) -> Resu
     let length = parameters.pop_int()?;
     let offset = parameters.pop_int()?;
     let bytes: Vec<u8> = parameters.pop()?.try_into()?;
-    let vm = thread.vm()?;
-    let class = class_object_from_bytes(&vm, source_file, &bytes, offset, length).await?;
-
-    if vm.java_major_version() <= JAVA_8 {
-        if let Some(expected_class_name) = parameters.pop_reference()? {
-            let expected_class_name: String = expected_class_name.try_into()?;
-            let class_name = class.class().name();
-            if class_name != expected_class_name {
-                return Err(NoClassDefFoundError(class_name.to_string()).into());
-            }
-        }
-    } else {
-        let class_loader = parameters.pop()?;
-        class.set_value("classLoader", class_loader)?;
-    }
-
-    Ok(Some(Value::from(class)))
+    let class = class_from_bytes(source_file, &bytes, offset, length)?;
+    check_class_name(&class, parameters.pop_reference()?)?;
+    let class_loader = parameters.pop_object().ok();
+    let class = define_class(&thread, class_loader, class).await?;
+    Ok(Some(class))
 }
 
 #[async_recursion(?Send)]
@@ -195,25 +272,16 @@ async fn define_class_2(thread: Arc<Thread>, mut parameters: Parameters) -> Resu
     let buffer: Vec<u8> = byte_buffer.value("hb")?.try_into()?;
     let buffer_offset = byte_buffer.value("offset")?.try_into()?;
     let bytes: Vec<u8> = buffer.into_iter().skip(buffer_offset).collect();
-    let vm = thread.vm()?;
-    let class = class_object_from_bytes(&vm, source_file, &bytes, offset, length).await?;
-
-    if vm.java_major_version() <= JAVA_8 {
-        if let Some(expected_class_name) = parameters.pop_reference()? {
-            let expected_class_name: String = expected_class_name.try_into()?;
-            let class_name = class.class().name();
-            if class_name != expected_class_name {
-                return Err(NoClassDefFoundError(class_name.to_string()).into());
-            }
-        }
-    } else {
-        let class_loader = parameters.pop()?;
-        class.set_value("classLoader", class_loader)?;
-    }
-
-    Ok(Some(Value::from(class)))
+    let class = class_from_bytes(source_file, &bytes, offset, length)?;
+    check_class_name(&class, parameters.pop_reference()?)?;
+    let class_loader = parameters.pop_object().ok();
+    let class = define_class(&thread, class_loader, class).await?;
+    Ok(Some(class))
 }
 
+/// Find a class with the bootstrap class loader.  The Java system class loader is `null` in
+/// Ristretto, so the bootstrap class loader is the VM class loader, which also loads the classes
+/// of the class path.
 #[async_recursion(?Send)]
 async fn find_bootstrap_class(
     thread: Arc<Thread>,
@@ -240,9 +308,19 @@ async fn find_loaded_class_0(
     mut parameters: Parameters,
 ) -> Result<Option<Value>> {
     let class_name: String = parameters.pop()?.try_into()?;
+    let class_loader = parameters.pop_object()?;
     let vm = thread.vm()?;
-    let Ok(class) = vm.class(class_name).await else {
-        return Ok(Some(Value::Object(None)));
+    // Classes defined by the Java class loader take precedence over classes of the VM class
+    // loader, which Ristretto does not expose to Java as a class loader object.
+    let class_loader = vm.java_class_loader(&class_loader).await?;
+    let class = match class_loader.find_loaded(&class_name).await {
+        Some(class) => class,
+        None => {
+            let Ok(class) = vm.class(class_name).await else {
+                return Ok(Some(Value::Object(None)));
+            };
+            class
+        }
     };
     let class = class.to_object(&vm).await?;
     Ok(Some(class))
@@ -280,6 +358,180 @@ async fn retrieve_directives(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::path::PathBuf;
+
+    fn minimum_class_bytes() -> Result<Vec<u8>> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let class_file = cargo_manifest
+            .join("..")
+            .join("classes")
+            .join("Minimum.class");
+        Ok(std::fs::read(class_file).map_err(ristretto_classloader::Error::from)?)
+    }
+
+    #[test]
+    fn test_class_from_bytes() -> Result<()> {
+        let bytes = minimum_class_bytes()?;
+        let length = i32::try_from(bytes.len())?;
+        let class = class_from_bytes(None, &bytes, 0, length)?;
+        assert_eq!("Minimum", class.name());
+        Ok(())
+    }
+
+    #[test]
+    fn test_class_from_bytes_index_out_of_bounds() -> Result<()> {
+        let bytes = minimum_class_bytes()?;
+        let length = i32::try_from(bytes.len())?;
+        let result = class_from_bytes(None, &bytes, 1, length);
+        assert!(matches!(
+            result,
+            Err(crate::Error::JavaError(IndexOutOfBoundsException { .. }))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_class_from_bytes_class_format_error() {
+        let bytes = [0xCA, 0xFE, 0xBA, 0xBE];
+        let result = class_from_bytes(None, &bytes, 0, 4);
+        assert!(matches!(
+            result,
+            Err(crate::Error::JavaError(ClassFormatError(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_check_class_name() -> Result<()> {
+        let (vm, _thread) = crate::test::thread().await?;
+        let class = Class::new_named("java/lang/Foo")?;
+        check_class_name(&class, None)?;
+        let Value::Object(name) = "java.lang.Foo".to_object(&vm).await? else {
+            panic!("expected string object");
+        };
+        check_class_name(&class, name)?;
+        let Value::Object(name) = "java.lang.Bar".to_object(&vm).await? else {
+            panic!("expected string object");
+        };
+        let result = check_class_name(&class, name);
+        assert!(matches!(
+            result,
+            Err(crate::Error::JavaError(NoClassDefFoundError(_)))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_define_class_with_class_loader() -> Result<()> {
+        let (vm, thread) = crate::test::thread().await?;
+        let class_loader_class = thread.class("java.lang.ClassLoader").await?;
+        let class_loader = Object::new(class_loader_class)?;
+        let bytes = minimum_class_bytes()?;
+        let length = i32::try_from(bytes.len())?;
+
+        let class = class_from_bytes(None, &bytes, 0, length)?;
+        let class_object = define_class(&thread, Some(class_loader.clone()), class.clone()).await?;
+        let class_object: Object = class_object.try_into()?;
+        let defining_class_loader: Object = class_object.value("classLoader")?.try_into()?;
+        assert!(defining_class_loader.ptr_eq(&class_loader));
+        let backing_class_loader = vm.java_class_loader(&class_loader).await?;
+        let defined_class = backing_class_loader.find_loaded("Minimum").await;
+        assert!(defined_class.is_some_and(|defined_class| Arc::ptr_eq(&defined_class, &class)));
+        let loaded_class = thread
+            .class_with_loader(Some(&class_loader), "Minimum")
+            .await?;
+        assert!(Arc::ptr_eq(&class, &loaded_class));
+        // The class is not defined with the VM class loader
+        assert!(!Arc::ptr_eq(&class, &thread.class("Minimum").await?));
+
+        let class = class_from_bytes(None, &bytes, 0, length)?;
+        let result = define_class(&thread, Some(class_loader), class).await;
+        assert!(matches!(
+            result,
+            Err(crate::Error::JavaError(LinkageError(_)))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_define_class_with_class_loaders_is_isolated() -> Result<()> {
+        let (vm, thread) = crate::test::thread().await?;
+        let class_loader_class = thread.class("java.lang.ClassLoader").await?;
+        let first_class_loader = Object::new(class_loader_class.clone())?;
+        let second_class_loader = Object::new(class_loader_class)?;
+        let bytes = minimum_class_bytes()?;
+        let length = i32::try_from(bytes.len())?;
+
+        let first_class = class_from_bytes(None, &bytes, 0, length)?;
+        define_class(
+            &thread,
+            Some(first_class_loader.clone()),
+            first_class.clone(),
+        )
+        .await?;
+        let second_class = class_from_bytes(None, &bytes, 0, length)?;
+        define_class(
+            &thread,
+            Some(second_class_loader.clone()),
+            second_class.clone(),
+        )
+        .await?;
+
+        let class = thread
+            .class_with_loader(Some(&first_class_loader), "Minimum")
+            .await?;
+        assert!(Arc::ptr_eq(&first_class, &class));
+        let class = thread
+            .class_with_loader(Some(&second_class_loader), "Minimum")
+            .await?;
+        assert!(Arc::ptr_eq(&second_class, &class));
+        assert!(vm
+            .java_class_loader_object(&first_class)
+            .is_some_and(|class_loader| class_loader.ptr_eq(&first_class_loader)));
+        assert!(vm
+            .java_class_loader_object(&second_class)
+            .is_some_and(|class_loader| class_loader.ptr_eq(&second_class_loader)));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_define_class_does_not_keep_class_loader_alive() -> Result<()> {
+        let (vm, thread) = crate::test::thread().await?;
+        let class_loader_class = thread.class("java.lang.ClassLoader").await?;
+        let class_loader = Object::new(class_loader_class)?;
+        let bytes = minimum_class_bytes()?;
+        let length = i32::try_from(bytes.len())?;
+
+        let class = class_from_bytes(None, &bytes, 0, length)?;
+        let class_object = define_class(&thread, Some(class_loader.clone()), class.clone()).await?;
+        assert!(vm.java_class_loader_object(&class).is_some());
+        drop(class_object);
+        drop(class_loader);
+        assert!(vm.java_class_loader_object(&class).is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_define_hidden_class() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let bytes = minimum_class_bytes()?;
+        let first: Object = define_hidden_class(&thread, None, &bytes)
+            .await?
+            .try_into()?;
+        let second: Object = define_hidden_class(&thread, None, &bytes)
+            .await?
+            .try_into()?;
+        let first_name: String = first.value("name")?.try_into()?;
+        let second_name: String = second.value("name")?.try_into()?;
+        assert!(first_name.starts_with("Minimum+0x"));
+        assert_ne!(first_name, second_name);
+
+        let result = define_hidden_class(&thread, None, &[0xCA, 0xFE]).await;
+        assert!(matches!(
+            result,
+            Err(crate::Error::JavaError(ClassFormatError(_)))
+        ));
+        Ok(())
+    }
 
     #[tokio::test]
     async fn test_find_builtin_lib() -> Result<()> {
@@ -1,4 +1,5 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_access::check_restricted_call;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,27 +24,29 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn find(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.ClassLoader$NativeLibrary.find(Ljava/lang/String;)J")
+    unsatisfied_link(CLASS_NAME, "find", "(Ljava/lang/String;)J")
 }
 
 #[async_recursion(?Send)]
 async fn find_entry(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.ClassLoader$NativeLibrary.findEntry(Ljava/lang/String;)J")
+    unsatisfied_link(CLASS_NAME, "findEntry", "(Ljava/lang/String;)J")
 }
 
 #[async_recursion(?Send)]
-async fn load(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.ClassLoader$NativeLibrary.load(Ljava/lang/String;Z)V")
+async fn load(thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    check_restricted_call(&thread).await?;
+    unsatisfied_link(CLASS_NAME, "load", "(Ljava/lang/String;Z)V")
 }
 
 #[async_recursion(?Send)]
-async fn load_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.ClassLoader$NativeLibrary.load0(Ljava/lang/String;ZZ)Z")
+async fn load_0(thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    check_restricted_call(&thread).await?;
+    unsatisfied_link(CLASS_NAME, "load0", "(Ljava/lang/String;ZZ)Z")
 }
 
 #[async_recursion(?Send)]
 async fn unload(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.ClassLoader$NativeLibrary.unload(Ljava/lang/String;ZJ)V")
+    unsatisfied_link(CLASS_NAME, "unload", "(Ljava/lang/String;ZJ)V")
 }
 
 #[cfg(test)]
@@ -51,47 +54,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.ClassLoader$NativeLibrary.find(Ljava/lang/String;)J"
-    )]
     async fn test_find() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = find(thread, Parameters::default()).await;
+        let result = find(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/ClassLoader$NativeLibrary",
+            "find",
+            "(Ljava/lang/String;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.ClassLoader$NativeLibrary.findEntry(Ljava/lang/String;)J"
-    )]
     async fn test_find_entry() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = find_entry(thread, Parameters::default()).await;
+        let result = find_entry(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/ClassLoader$NativeLibrary",
+            "findEntry",
+            "(Ljava/lang/String;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.ClassLoader$NativeLibrary.load(Ljava/lang/String;Z)V"
-    )]
     async fn test_load() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = load(thread, Parameters::default()).await;
+        let result = load(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/ClassLoader$NativeLibrary",
+            "load",
+            "(Ljava/lang/String;Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.ClassLoader$NativeLibrary.load0(Ljava/lang/String;ZZ)Z"
-    )]
     async fn test_load_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = load_0(thread, Parameters::default()).await;
+        let result = load_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/ClassLoader$NativeLibrary",
+            "load0",
+            "(Ljava/lang/String;ZZ)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.ClassLoader$NativeLibrary.unload(Ljava/lang/String;ZJ)V"
-    )]
     async fn test_unload() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = unload(thread, Parameters::default()).await;
+        let result = unload(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/ClassLoader$NativeLibrary",
+            "unload",
+            "(Ljava/lang/String;ZJ)V",
+        );
     }
 }
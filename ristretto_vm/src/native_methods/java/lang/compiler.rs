@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -36,27 +36,31 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn command(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Compiler.command(Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "command",
+        "(Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn compile_class(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Compiler.compileClass(Ljava/lang/Class;)Z")
+    unsatisfied_link(CLASS_NAME, "compileClass", "(Ljava/lang/Class;)Z")
 }
 
 #[async_recursion(?Send)]
 async fn compile_classes(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Compiler.compileClasses(Ljava/lang/String;)Z")
+    unsatisfied_link(CLASS_NAME, "compileClasses", "(Ljava/lang/String;)Z")
 }
 
 #[async_recursion(?Send)]
 async fn disable(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Compiler.disable()V")
+    unsatisfied_link(CLASS_NAME, "disable", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn enable(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Compiler.enable()V")
+    unsatisfied_link(CLASS_NAME, "enable", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -74,44 +78,53 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Compiler.command(Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_command() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = command(thread, Parameters::default()).await;
+        let result = command(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Compiler",
+            "command",
+            "(Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Compiler.compileClass(Ljava/lang/Class;)Z"
-    )]
     async fn test_compile_class() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = compile_class(thread, Parameters::default()).await;
+        let result = compile_class(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Compiler",
+            "compileClass",
+            "(Ljava/lang/Class;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Compiler.compileClasses(Ljava/lang/String;)Z"
-    )]
     async fn test_compile_classes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = compile_classes(thread, Parameters::default()).await;
+        let result = compile_classes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Compiler",
+            "compileClasses",
+            "(Ljava/lang/String;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.Compiler.disable()V")]
     async fn test_disable() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = disable(thread, Parameters::default()).await;
+        let result = disable(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/lang/Compiler", "disable", "()V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.Compiler.enable()V")]
     async fn test_enable() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = enable(thread, Parameters::default()).await;
+        let result = enable(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/lang/Compiler", "enable", "()V");
     }
 
     #[tokio::test]
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_17};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_17};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -65,42 +65,74 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn invoke(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.MethodHandle.invoke([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "invoke",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn invoke_basic(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.MethodHandle.invokeBasic([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "invokeBasic",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn invoke_exact(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.MethodHandle.invokeExact([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "invokeExact",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn link_to_interface(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.MethodHandle.linkToInterface([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "linkToInterface",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn link_to_native(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.MethodHandle.linkToNative([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "linkToNative",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn link_to_special(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.MethodHandle.linkToSpecial([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "linkToSpecial",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn link_to_static(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.MethodHandle.linkToStatic([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "linkToStatic",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn link_to_virtual(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.MethodHandle.linkToVirtual([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "linkToVirtual",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[cfg(test)]
@@ -108,74 +140,98 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.MethodHandle.invoke([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_invoke() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = invoke(thread, Parameters::default()).await;
+        let result = invoke(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/MethodHandle",
+            "invoke",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.MethodHandle.invokeBasic([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_invoke_basic() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = invoke_basic(thread, Parameters::default()).await;
+        let result = invoke_basic(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/MethodHandle",
+            "invokeBasic",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.MethodHandle.invokeExact([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_invoke_exact() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = invoke_exact(thread, Parameters::default()).await;
+        let result = invoke_exact(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/MethodHandle",
+            "invokeExact",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.MethodHandle.linkToInterface([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_link_to_interface() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = link_to_interface(thread, Parameters::default()).await;
+        let result = link_to_interface(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/MethodHandle",
+            "linkToInterface",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.MethodHandle.linkToNative([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_link_to_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = link_to_native(thread, Parameters::default()).await;
+        let result = link_to_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/MethodHandle",
+            "linkToNative",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.MethodHandle.linkToSpecial([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_link_to_special() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = link_to_special(thread, Parameters::default()).await;
+        let result = link_to_special(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/MethodHandle",
+            "linkToSpecial",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.MethodHandle.linkToStatic([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_link_to_static() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = link_to_static(thread, Parameters::default()).await;
+        let result = link_to_static(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/MethodHandle",
+            "linkToStatic",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.MethodHandle.linkToVirtual([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_link_to_virtual() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = link_to_virtual(thread, Parameters::default()).await;
+        let result = link_to_virtual(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/MethodHandle",
+            "linkToVirtual",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 }
@@ -1,13 +1,36 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17, JAVA_8};
+use crate::java_object::JavaObject;
+use crate::module_access::Access;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
+use crate::Error::InternalError;
+use crate::JavaError::IllegalAccessException;
 use crate::Result;
 use async_recursion::async_recursion;
-use ristretto_classloader::{Class, Object, Value};
+use ristretto_classfile::{ClassAccessFlags, FieldAccessFlags, MethodAccessFlags};
+use ristretto_classloader::{Class, Method, Object, Value};
 use std::sync::Arc;
 
 const CLASS_NAME: &str = "java/lang/invoke/MethodHandleNatives";
 
+/// `MemberName` flags; see `java.lang.invoke.MethodHandleNatives.Constants`
+const MN_IS_METHOD: i32 = 0x0001_0000;
+const MN_IS_CONSTRUCTOR: i32 = 0x0002_0000;
+const MN_IS_FIELD: i32 = 0x0004_0000;
+const MN_REFERENCE_KIND_SHIFT: i32 = 24;
+
+/// Method handle reference kinds; see `java.lang.invoke.MethodHandleNatives.Constants`
+const REF_GET_FIELD: i32 = 1;
+const REF_GET_STATIC: i32 = 2;
+const REF_INVOKE_VIRTUAL: i32 = 5;
+const REF_INVOKE_STATIC: i32 = 6;
+const REF_INVOKE_SPECIAL: i32 = 7;
+const REF_NEW_INVOKE_SPECIAL: i32 = 8;
+const REF_INVOKE_INTERFACE: i32 = 9;
+
+/// Lookup mode of a lookup with full privileges; see `java.lang.invoke.MethodHandles.Lookup`
+const LM_TRUSTED: i32 = -1;
+
 /// Register all native methods for `java.lang.invoke.MethodHandleNatives`.
 pub(crate) fn register(registry: &mut MethodRegistry) {
     if registry.java_major_version() <= JAVA_8 {
@@ -116,7 +139,11 @@ async fn clear_call_site_context(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.MethodHandleNatives.clearCallSiteContext(Ljava/lang/invoke/MethodHandleNatives$CallSiteContext;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "clearCallSiteContext",
+        "(Ljava/lang/invoke/MethodHandleNatives$CallSiteContext;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -124,17 +151,21 @@ async fn copy_out_bootstrap_arguments(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.MethodHandleNatives.copyOutBootstrapArguments(Ljava/lang/Class;[III[Ljava/lang/Object;IZLjava/lang/Object;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "copyOutBootstrapArguments",
+        "(Ljava/lang/Class;[III[Ljava/lang/Object;IZLjava/lang/Object;)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn expand(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.MethodHandleNatives.expand(Ljava/lang/invoke/MemberName;)V")
+    unsatisfied_link(CLASS_NAME, "expand", "(Ljava/lang/invoke/MemberName;)V")
 }
 
 #[async_recursion(?Send)]
 async fn get_constant(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.MethodHandleNatives.getConstant(I)I")
+    unsatisfied_link(CLASS_NAME, "getConstant", "(I)I")
 }
 
 #[async_recursion(?Send)]
@@ -142,30 +173,135 @@ async fn get_member_vm_info(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.MethodHandleNatives.getMemberVMInfo(Ljava/lang/invoke/MemberName;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getMemberVMInfo",
+        "(Ljava/lang/invoke/MemberName;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_members(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.MethodHandleNatives.getMembers(Ljava/lang/Class;Ljava/lang/String;Ljava/lang/String;ILjava/lang/Class;I[Ljava/lang/invoke/MemberName;)I")
+    unsatisfied_link(CLASS_NAME, "getMembers", "(Ljava/lang/Class;Ljava/lang/String;Ljava/lang/String;ILjava/lang/Class;I[Ljava/lang/invoke/MemberName;)I")
 }
 
 #[async_recursion(?Send)]
 async fn get_named_con(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.MethodHandleNatives.getNamedCon(I[Ljava/lang/Object;)I")
+    unsatisfied_link(CLASS_NAME, "getNamedCon", "(I[Ljava/lang/Object;)I")
 }
 
+/// Initialize a `MemberName` from a reflective `Method`, `Constructor` or `Field`; used by
+/// `Lookup.unreflect`, `Lookup.unreflectConstructor` and `Lookup.unreflectGetter`/`Setter` to
+/// convert reflection objects into method handles.
 #[async_recursion(?Send)]
-async fn init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+async fn init(thread: Arc<Thread>, mut parameters: Parameters) -> Result<Option<Value>> {
+    let reference = parameters.pop_object()?;
+    let member_self = parameters.pop_object()?;
+
+    let class_object = reference.value("clazz")?;
+    let declaring_class: Object = class_object.clone().try_into()?;
+    let class_name: String = declaring_class.value("name")?.try_into()?;
+    let class = thread.class(&class_name).await?;
+    let (name, descriptor, flags) = match reference.class().name() {
+        "java/lang/reflect/Method" => {
+            let method = reflected_method(&class, &reference)?;
+            let access_flags = *method.access_flags();
+            let reference_kind = if access_flags.contains(MethodAccessFlags::STATIC) {
+                REF_INVOKE_STATIC
+            } else if access_flags.contains(MethodAccessFlags::PRIVATE) {
+                REF_INVOKE_SPECIAL
+            } else if class.is_interface() {
+                REF_INVOKE_INTERFACE
+            } else {
+                REF_INVOKE_VIRTUAL
+            };
+            let flags = i32::from(access_flags.bits())
+                | MN_IS_METHOD
+                | (reference_kind << MN_REFERENCE_KIND_SHIFT);
+            (
+                method.name().to_string(),
+                method.descriptor().to_string(),
+                flags,
+            )
+        }
+        "java/lang/reflect/Constructor" => {
+            let method = reflected_method(&class, &reference)?;
+            let flags = i32::from(method.access_flags().bits())
+                | MN_IS_CONSTRUCTOR
+                | (REF_NEW_INVOKE_SPECIAL << MN_REFERENCE_KIND_SHIFT);
+            (
+                method.name().to_string(),
+                method.descriptor().to_string(),
+                flags,
+            )
+        }
+        "java/lang/reflect/Field" => {
+            let name: String = reference.value("name")?.try_into()?;
+            let Some(field) = class
+                .fields()
+                .into_iter()
+                .find(|field| field.name() == name)
+            else {
+                return Err(InternalError(format!(
+                    "field not found: {class_name}.{name}"
+                )));
+            };
+            let access_flags = *field.access_flags();
+            let reference_kind = if access_flags.contains(FieldAccessFlags::STATIC) {
+                REF_GET_STATIC
+            } else {
+                REF_GET_FIELD
+            };
+            let flags = i32::from(access_flags.bits())
+                | MN_IS_FIELD
+                | (reference_kind << MN_REFERENCE_KIND_SHIFT);
+            (name, field.field_type().descriptor(), flags)
+        }
+        reference_class => {
+            return Err(InternalError(format!(
+                "MethodHandleNatives.init: unsupported reference {reference_class}"
+            )));
+        }
+    };
+
+    let vm = thread.vm()?;
+    member_self.set_value("clazz", class_object)?;
+    member_self.set_value("name", name.to_object(&vm).await?)?;
+    member_self.set_value("type", descriptor.to_object(&vm).await?)?;
+    member_self.set_value("flags", Value::Int(flags))?;
+    if vm.java_major_version() > JAVA_8 {
+        let resolved_method_name_class =
+            thread.class("java.lang.invoke.ResolvedMethodName").await?;
+        let resolved_method_name = Object::new(resolved_method_name_class)?;
+        member_self.set_value("method", Value::from(resolved_method_name))?;
+    }
     Ok(None)
 }
 
+/// Get the method of a reflective `Method` or `Constructor` by slot.
+fn reflected_method(class: &Arc<Class>, reference: &Object) -> Result<Arc<Method>> {
+    let slot: i32 = reference.value("slot")?.try_into()?;
+    let slot = usize::try_from(slot)?;
+    class.methods().get(slot).cloned().ok_or_else(|| {
+        InternalError(format!(
+            "method slot {slot} not found in class {}",
+            class.name()
+        ))
+    })
+}
+
 #[async_recursion(?Send)]
 async fn object_field_offset(
-    _thread: Arc<Thread>,
-    _parameters: Parameters,
+    thread: Arc<Thread>,
+    mut parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.MethodHandleNatives.objectFieldOffset(Ljava/lang/invoke/MemberName;)J")
+    let member_self = parameters.pop_object()?;
+    let class_object: Object = member_self.value("clazz")?.try_into()?;
+    let class_name: String = class_object.value("name")?.try_into()?;
+    let class = thread.class(class_name).await?;
+    let name: String = member_self.value("name")?.try_into()?;
+    let offset = i64::try_from(class.field_offset(name)?)?;
+    Ok(Some(Value::Long(offset)))
 }
 
 #[async_recursion(?Send)]
@@ -176,13 +312,13 @@ async fn register_natives(_thread: Arc<Thread>, _parameters: Parameters) -> Resu
 async fn resolve(
     thread: Arc<Thread>,
     member_self: Object,
-    _caller: Option<Arc<Class>>,
-    _lookup_mode: i32,
+    caller: Option<Object>,
+    lookup_mode: i32,
     _speculative_resolve: bool,
 ) -> Result<Option<Value>> {
     let class_object: Object = member_self.value("clazz")?.try_into()?;
     let class_name: String = class_object.value("name")?.try_into()?;
-    let class = thread.class(class_name).await?;
+    let class = thread.class(&class_name).await?;
     let name: String = member_self.value("name")?.try_into()?;
 
     let method_type = member_self.value("type")?;
@@ -198,12 +334,34 @@ async fn resolve(
         .await?
         .try_into()?;
 
-    // Lookup modes:
-    // LM_MODULE = 16;
-    // LM_UNCONDITIONAL = 32;
-    // LM_TRUSTED = -1;
+    let method = class.try_get_method(&name, &descriptor)?;
+
+    // Lookups by trusted callers (LM_TRUSTED) are not subject to module access checks
+    if let Some(caller) = caller.filter(|_| lookup_mode != LM_TRUSTED) {
+        let caller_name: String = caller.value("name")?.try_into()?;
+        let access = if method.access_flags().contains(MethodAccessFlags::PUBLIC)
+            && class
+                .class_file()
+                .access_flags
+                .contains(ClassAccessFlags::PUBLIC)
+        {
+            Access::Public
+        } else {
+            Access::Deep
+        };
+        let vm = thread.vm()?;
+        if let Err(reason) = vm
+            .module_access()
+            .await?
+            .check(&caller_name, &class_name, access)
+        {
+            return Err(IllegalAccessException(format!(
+                "{class_name}.{name}{descriptor} is not accessible from {caller_name}: {reason}"
+            ))
+            .into());
+        }
+    }
 
-    let method = class.try_get_method(name, descriptor)?;
     let access_flags = i32::from(method.access_flags().bits());
     let flags: i32 = member_self.value("flags")?.try_into()?;
     let flags = flags | access_flags;
@@ -221,42 +379,24 @@ async fn resolve(
 
 #[async_recursion(?Send)]
 async fn resolve_0(thread: Arc<Thread>, mut parameters: Parameters) -> Result<Option<Value>> {
-    let caller = match parameters.pop_object() {
-        Ok(caller) => {
-            let caller: Arc<Class> = caller.try_into()?;
-            Some(caller)
-        }
-        Err(_) => None,
-    };
+    let caller = parameters.pop_object().ok();
     let member_self = parameters.pop_object()?;
-    resolve(thread, member_self, caller, -1, true).await
+    resolve(thread, member_self, caller, LM_TRUSTED, true).await
 }
 
 #[async_recursion(?Send)]
 async fn resolve_1(thread: Arc<Thread>, mut parameters: Parameters) -> Result<Option<Value>> {
     let speculative_resolve = parameters.pop_int()? != 0;
-    let caller = match parameters.pop_object() {
-        Ok(caller) => {
-            let caller: Arc<Class> = caller.try_into()?;
-            Some(caller)
-        }
-        Err(_) => None,
-    };
+    let caller = parameters.pop_object().ok();
     let member_self = parameters.pop_object()?;
-    resolve(thread, member_self, caller, -1, speculative_resolve).await
+    resolve(thread, member_self, caller, LM_TRUSTED, speculative_resolve).await
 }
 
 #[async_recursion(?Send)]
 async fn resolve_2(thread: Arc<Thread>, mut parameters: Parameters) -> Result<Option<Value>> {
     let speculative_resolve = parameters.pop_int()? != 0;
     let lookup_mode = parameters.pop_int()?;
-    let caller = match parameters.pop_object() {
-        Ok(caller) => {
-            let caller: Arc<Class> = caller.try_into()?;
-            Some(caller)
-        }
-        Err(_) => None,
-    };
+    let caller = parameters.pop_object().ok();
     let member_self = parameters.pop_object()?;
     resolve(
         thread,
@@ -273,7 +413,11 @@ async fn set_call_site_target_normal(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.MethodHandleNatives.setCallSiteTargetNormal(Ljava/lang/invoke/CallSite;Ljava/lang/invoke/MethodHandle;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "setCallSiteTargetNormal",
+        "(Ljava/lang/invoke/CallSite;Ljava/lang/invoke/MethodHandle;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -281,12 +425,20 @@ async fn set_call_site_target_volatile(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.MethodHandleNatives.setCallSiteTargetVolatile(Ljava/lang/invoke/CallSite;Ljava/lang/invoke/MethodHandle;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "setCallSiteTargetVolatile",
+        "(Ljava/lang/invoke/CallSite;Ljava/lang/invoke/MethodHandle;)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn static_field_base(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.MethodHandleNatives.staticFieldBase(Ljava/lang/invoke/MemberName;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "staticFieldBase",
+        "(Ljava/lang/invoke/MemberName;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -294,7 +446,11 @@ async fn static_field_offset(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.MethodHandleNatives.staticFieldOffset(Ljava/lang/invoke/MemberName;)J")
+    unsatisfied_link(
+        CLASS_NAME,
+        "staticFieldOffset",
+        "(Ljava/lang/invoke/MemberName;)J",
+    )
 }
 
 #[cfg(test)]
@@ -302,83 +458,193 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.MethodHandleNatives.clearCallSiteContext(Ljava/lang/invoke/MethodHandleNatives$CallSiteContext;)V"
-    )]
     async fn test_clear_call_site_context() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = clear_call_site_context(thread, Parameters::default()).await;
+        let result = clear_call_site_context(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/MethodHandleNatives",
+            "clearCallSiteContext",
+            "(Ljava/lang/invoke/MethodHandleNatives$CallSiteContext;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.MethodHandleNatives.copyOutBootstrapArguments(Ljava/lang/Class;[III[Ljava/lang/Object;IZLjava/lang/Object;)V"
-    )]
     async fn test_copy_out_bootstrap_arguments() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = copy_out_bootstrap_arguments(thread, Parameters::default()).await;
+        let result = copy_out_bootstrap_arguments(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/MethodHandleNatives",
+            "copyOutBootstrapArguments",
+            "(Ljava/lang/Class;[III[Ljava/lang/Object;IZLjava/lang/Object;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.MethodHandleNatives.expand(Ljava/lang/invoke/MemberName;)V"
-    )]
     async fn test_expand() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = expand(thread, Parameters::default()).await;
+        let result = expand(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/MethodHandleNatives",
+            "expand",
+            "(Ljava/lang/invoke/MemberName;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.MethodHandleNatives.getConstant(I)I"
-    )]
     async fn test_get_constant() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_constant(thread, Parameters::default()).await;
+        let result = get_constant(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/MethodHandleNatives",
+            "getConstant",
+            "(I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.MethodHandleNatives.getMemberVMInfo(Ljava/lang/invoke/MemberName;)Ljava/lang/Object;"
-    )]
     async fn test_get_member_vm_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_member_vm_info(thread, Parameters::default()).await;
+        let result = get_member_vm_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/MethodHandleNatives",
+            "getMemberVMInfo",
+            "(Ljava/lang/invoke/MemberName;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.MethodHandleNatives.getMembers(Ljava/lang/Class;Ljava/lang/String;Ljava/lang/String;ILjava/lang/Class;I[Ljava/lang/invoke/MemberName;)I"
-    )]
     async fn test_get_members() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_members(thread, Parameters::default()).await;
+        let result = get_members(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/lang/invoke/MethodHandleNatives", "getMembers", "(Ljava/lang/Class;Ljava/lang/String;Ljava/lang/String;ILjava/lang/Class;I[Ljava/lang/invoke/MemberName;)I");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.MethodHandleNatives.getNamedCon(I[Ljava/lang/Object;)I"
-    )]
     async fn test_get_named_con() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_named_con(thread, Parameters::default()).await;
+        let result = get_named_con(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/MethodHandleNatives",
+            "getNamedCon",
+            "(I[Ljava/lang/Object;)I",
+        );
     }
 
-    #[tokio::test]
-    async fn test_init() -> Result<()> {
-        let (_vm, thread) = crate::test::thread().await?;
-        let result = init(thread, Parameters::default()).await?;
+    async fn declared_member(
+        thread: &Arc<Thread>,
+        method_name: &str,
+        descriptor: &str,
+        parameters: Vec<Value>,
+    ) -> Result<Value> {
+        let vm = thread.vm()?;
+        let integer_class = thread.class("java.lang.Integer").await?;
+        let class_class = thread.class("java.lang.Class").await?;
+        let method = class_class.try_get_method(method_name, descriptor)?;
+        let mut parameters = parameters;
+        parameters.insert(0, integer_class.to_object(&vm).await?);
+        thread.try_execute(&class_class, &method, parameters).await
+    }
+
+    async fn init_member_name(thread: &Arc<Thread>, reference: Value) -> Result<Object> {
+        let member_name = thread
+            .object("java/lang/invoke/MemberName", "", Vec::<Value>::new())
+            .await?;
+        let parameters = Parameters::new(vec![member_name.clone(), reference]);
+        let result = init(thread.clone(), parameters).await?;
         assert_eq!(None, result);
+        Ok(member_name.try_into()?)
+    }
+
+    #[tokio::test]
+    async fn test_init_method() -> Result<()> {
+        let (vm, thread) = crate::test::thread().await?;
+        let class_array = thread.class("[Ljava/lang/Class;").await?;
+        let parameter_types = Value::try_from((class_array, Vec::<Value>::new()))?;
+        let method = declared_member(
+            &thread,
+            "getDeclaredMethod",
+            "(Ljava/lang/String;[Ljava/lang/Class;)Ljava/lang/reflect/Method;",
+            vec!["intValue".to_object(&vm).await?, parameter_types],
+        )
+        .await?;
+        let member_name = init_member_name(&thread, method).await?;
+
+        let name: String = member_name.value("name")?.try_into()?;
+        assert_eq!("intValue", name);
+        let descriptor: String = member_name.value("type")?.try_into()?;
+        assert_eq!("()I", descriptor);
+        let flags: i32 = member_name.value("flags")?.try_into()?;
+        assert_eq!(MN_IS_METHOD, flags & MN_IS_METHOD);
+        assert_eq!(REF_INVOKE_VIRTUAL, flags >> MN_REFERENCE_KIND_SHIFT);
         Ok(())
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.MethodHandleNatives.objectFieldOffset(Ljava/lang/invoke/MemberName;)J"
-    )]
-    async fn test_object_field_offset() {
-        let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = object_field_offset(thread, Parameters::default()).await;
+    async fn test_init_constructor() -> Result<()> {
+        let (vm, thread) = crate::test::thread().await?;
+        let class_array = thread.class("[Ljava/lang/Class;").await?;
+        let int_class = thread.class("int").await?;
+        let parameter_types =
+            Value::try_from((class_array, vec![int_class.to_object(&vm).await?]))?;
+        let constructor = declared_member(
+            &thread,
+            "getDeclaredConstructor",
+            "([Ljava/lang/Class;)Ljava/lang/reflect/Constructor;",
+            vec![parameter_types],
+        )
+        .await?;
+        let member_name = init_member_name(&thread, constructor).await?;
+
+        let name: String = member_name.value("name")?.try_into()?;
+        assert_eq!("<init>", name);
+        let descriptor: String = member_name.value("type")?.try_into()?;
+        assert_eq!("(I)V", descriptor);
+        let flags: i32 = member_name.value("flags")?.try_into()?;
+        assert_eq!(MN_IS_CONSTRUCTOR, flags & MN_IS_CONSTRUCTOR);
+        assert_eq!(REF_NEW_INVOKE_SPECIAL, flags >> MN_REFERENCE_KIND_SHIFT);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_init_field() -> Result<()> {
+        let (vm, thread) = crate::test::thread().await?;
+        let field = declared_member(
+            &thread,
+            "getDeclaredField",
+            "(Ljava/lang/String;)Ljava/lang/reflect/Field;",
+            vec!["value".to_object(&vm).await?],
+        )
+        .await?;
+        let member_name = init_member_name(&thread, field).await?;
+
+        let name: String = member_name.value("name")?.try_into()?;
+        assert_eq!("value", name);
+        let descriptor: String = member_name.value("type")?.try_into()?;
+        assert_eq!("I", descriptor);
+        let flags: i32 = member_name.value("flags")?.try_into()?;
+        assert_eq!(MN_IS_FIELD, flags & MN_IS_FIELD);
+        assert_eq!(REF_GET_FIELD, flags >> MN_REFERENCE_KIND_SHIFT);
+
+        let parameters = Parameters::new(vec![Value::from(member_name)]);
+        let offset = object_field_offset(thread.clone(), parameters).await?;
+        let integer_class = thread.class("java.lang.Integer").await?;
+        let expected = i64::try_from(integer_class.field_offset("value")?)?;
+        assert_eq!(Some(Value::Long(expected)), offset);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_init_invalid_reference() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let result = init(thread, Parameters::default()).await;
+        assert!(result.is_err());
+        Ok(())
     }
 
     #[tokio::test]
@@ -390,38 +656,50 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.MethodHandleNatives.setCallSiteTargetNormal(Ljava/lang/invoke/CallSite;Ljava/lang/invoke/MethodHandle;)V"
-    )]
     async fn test_set_call_site_target_normal() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_call_site_target_normal(thread, Parameters::default()).await;
+        let result = set_call_site_target_normal(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/MethodHandleNatives",
+            "setCallSiteTargetNormal",
+            "(Ljava/lang/invoke/CallSite;Ljava/lang/invoke/MethodHandle;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.MethodHandleNatives.setCallSiteTargetVolatile(Ljava/lang/invoke/CallSite;Ljava/lang/invoke/MethodHandle;)V"
-    )]
     async fn test_set_call_site_target_volatile() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_call_site_target_volatile(thread, Parameters::default()).await;
+        let result = set_call_site_target_volatile(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/MethodHandleNatives",
+            "setCallSiteTargetVolatile",
+            "(Ljava/lang/invoke/CallSite;Ljava/lang/invoke/MethodHandle;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.MethodHandleNatives.staticFieldBase(Ljava/lang/invoke/MemberName;)Ljava/lang/Object;"
-    )]
     async fn test_static_field_base() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = static_field_base(thread, Parameters::default()).await;
+        let result = static_field_base(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/MethodHandleNatives",
+            "staticFieldBase",
+            "(Ljava/lang/invoke/MemberName;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.MethodHandleNatives.staticFieldOffset(Ljava/lang/invoke/MemberName;)J"
-    )]
     async fn test_static_field_offset() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = static_field_offset(thread, Parameters::default()).await;
+        let result = static_field_offset(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/MethodHandleNatives",
+            "staticFieldOffset",
+            "(Ljava/lang/invoke/MemberName;)J",
+        );
     }
 }
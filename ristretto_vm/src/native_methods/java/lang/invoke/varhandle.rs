@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -199,7 +199,11 @@ async fn compare_and_exchange(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.compareAndExchange([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "compareAndExchange",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -207,7 +211,11 @@ async fn compare_and_exchange_acquire(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.compareAndExchangeAcquire([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "compareAndExchangeAcquire",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -215,27 +223,39 @@ async fn compare_and_exchange_release(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.compareAndExchangeRelease([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "compareAndExchangeRelease",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn compare_and_set(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.compareAndSet([Ljava/lang/Object;)Z")
+    unsatisfied_link(CLASS_NAME, "compareAndSet", "([Ljava/lang/Object;)Z")
 }
 
 #[async_recursion(?Send)]
 async fn get(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.get([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(CLASS_NAME, "get", "([Ljava/lang/Object;)Ljava/lang/Object;")
 }
 
 #[async_recursion(?Send)]
 async fn get_acquire(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.getAcquire([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getAcquire",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_and_add(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.getAndAdd([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getAndAdd",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -243,7 +263,11 @@ async fn get_and_add_acquire(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.getAndAddAcquire([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getAndAddAcquire",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -251,7 +275,11 @@ async fn get_and_add_release(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.getAndAddRelease([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getAndAddRelease",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -259,7 +287,11 @@ async fn get_and_bitwise_and(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.getAndBitwiseAnd([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getAndBitwiseAnd",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -267,8 +299,10 @@ async fn get_and_bitwise_and_acquire(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!(
-        "java.lang.invoke.VarHandle.getAndBitwiseAndAcquire([Ljava/lang/Object;)Ljava/lang/Object;"
+    unsatisfied_link(
+        CLASS_NAME,
+        "getAndBitwiseAndAcquire",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
     )
 }
 
@@ -277,8 +311,10 @@ async fn get_and_bitwise_and_release(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!(
-        "java.lang.invoke.VarHandle.getAndBitwiseAndRelease([Ljava/lang/Object;)Ljava/lang/Object;"
+    unsatisfied_link(
+        CLASS_NAME,
+        "getAndBitwiseAndRelease",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
     )
 }
 
@@ -287,7 +323,11 @@ async fn get_and_bitwise_or(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.getAndBitwiseOr([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getAndBitwiseOr",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -295,8 +335,10 @@ async fn get_and_bitwise_or_acquire(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!(
-        "java.lang.invoke.VarHandle.getAndBitwiseOrAcquire([Ljava/lang/Object;)Ljava/lang/Object;"
+    unsatisfied_link(
+        CLASS_NAME,
+        "getAndBitwiseOrAcquire",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
     )
 }
 
@@ -305,8 +347,10 @@ async fn get_and_bitwise_or_release(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!(
-        "java.lang.invoke.VarHandle.getAndBitwiseOrRelease([Ljava/lang/Object;)Ljava/lang/Object;"
+    unsatisfied_link(
+        CLASS_NAME,
+        "getAndBitwiseOrRelease",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
     )
 }
 
@@ -315,7 +359,11 @@ async fn get_and_bitwise_xor(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.getAndBitwiseXor([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getAndBitwiseXor",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -323,8 +371,10 @@ async fn get_and_bitwise_xor_acquire(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!(
-        "java.lang.invoke.VarHandle.getAndBitwiseXorAcquire([Ljava/lang/Object;)Ljava/lang/Object;"
+    unsatisfied_link(
+        CLASS_NAME,
+        "getAndBitwiseXorAcquire",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
     )
 }
 
@@ -333,14 +383,20 @@ async fn get_and_bitwise_xor_release(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!(
-        "java.lang.invoke.VarHandle.getAndBitwiseXorRelease([Ljava/lang/Object;)Ljava/lang/Object;"
+    unsatisfied_link(
+        CLASS_NAME,
+        "getAndBitwiseXorRelease",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
     )
 }
 
 #[async_recursion(?Send)]
 async fn get_and_set(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.getAndSet([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getAndSet",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -348,7 +404,11 @@ async fn get_and_set_acquire(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.getAndSetAcquire([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getAndSetAcquire",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -356,37 +416,49 @@ async fn get_and_set_release(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.getAndSetRelease([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getAndSetRelease",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_opaque(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.getOpaque([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getOpaque",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_volatile(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.getVolatile([Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getVolatile",
+        "([Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn set(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.set([Ljava/lang/Object;)V")
+    unsatisfied_link(CLASS_NAME, "set", "([Ljava/lang/Object;)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_opaque(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.setOpaque([Ljava/lang/Object;)V")
+    unsatisfied_link(CLASS_NAME, "setOpaque", "([Ljava/lang/Object;)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_release(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.setRelease([Ljava/lang/Object;)V")
+    unsatisfied_link(CLASS_NAME, "setRelease", "([Ljava/lang/Object;)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_volatile(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.setVolatile([Ljava/lang/Object;)V")
+    unsatisfied_link(CLASS_NAME, "setVolatile", "([Ljava/lang/Object;)V")
 }
 
 #[async_recursion(?Send)]
@@ -394,7 +466,7 @@ async fn weak_compare_and_set(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.weakCompareAndSet([Ljava/lang/Object;)Z")
+    unsatisfied_link(CLASS_NAME, "weakCompareAndSet", "([Ljava/lang/Object;)Z")
 }
 
 #[async_recursion(?Send)]
@@ -402,7 +474,11 @@ async fn weak_compare_and_set_acquire(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.weakCompareAndSetAcquire([Ljava/lang/Object;)Z")
+    unsatisfied_link(
+        CLASS_NAME,
+        "weakCompareAndSetAcquire",
+        "([Ljava/lang/Object;)Z",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -410,7 +486,11 @@ async fn weak_compare_and_set_plain(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.weakCompareAndSetPlain([Ljava/lang/Object;)Z")
+    unsatisfied_link(
+        CLASS_NAME,
+        "weakCompareAndSetPlain",
+        "([Ljava/lang/Object;)Z",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -418,7 +498,11 @@ async fn weak_compare_and_set_release(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.invoke.VarHandle.weakCompareAndSetRelease([Ljava/lang/Object;)Z")
+    unsatisfied_link(
+        CLASS_NAME,
+        "weakCompareAndSetRelease",
+        "([Ljava/lang/Object;)Z",
+    )
 }
 
 #[cfg(test)]
@@ -426,281 +510,374 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.compareAndExchange([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_compare_and_exchange() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = compare_and_exchange(thread, Parameters::default()).await;
+        let result = compare_and_exchange(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "compareAndExchange",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.compareAndExchangeAcquire([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_compare_and_exchange_acquire() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = compare_and_exchange_acquire(thread, Parameters::default()).await;
+        let result = compare_and_exchange_acquire(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "compareAndExchangeAcquire",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.compareAndExchangeRelease([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_compare_and_exchange_release() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = compare_and_exchange_release(thread, Parameters::default()).await;
+        let result = compare_and_exchange_release(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "compareAndExchangeRelease",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.compareAndSet([Ljava/lang/Object;)Z"
-    )]
     async fn test_compare_and_set() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = compare_and_set(thread, Parameters::default()).await;
+        let result = compare_and_set(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "compareAndSet",
+            "([Ljava/lang/Object;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.get([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_get() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get(thread, Parameters::default()).await;
+        let result = get(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "get",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.getAcquire([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_get_acquire() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_acquire(thread, Parameters::default()).await;
+        let result = get_acquire(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "getAcquire",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.getAndAdd([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_get_and_add() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_and_add(thread, Parameters::default()).await;
+        let result = get_and_add(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "getAndAdd",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.getAndAddAcquire([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_get_and_add_acquire() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_and_add_acquire(thread, Parameters::default()).await;
+        let result = get_and_add_acquire(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "getAndAddAcquire",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.getAndAddRelease([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_get_and_add_release() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_and_add_release(thread, Parameters::default()).await;
+        let result = get_and_add_release(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "getAndAddRelease",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.getAndBitwiseAnd([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_get_and_bitwise_and() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_and_bitwise_and(thread, Parameters::default()).await;
+        let result = get_and_bitwise_and(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "getAndBitwiseAnd",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.getAndBitwiseAndAcquire([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_get_and_bitwise_and_acquire() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_and_bitwise_and_acquire(thread, Parameters::default()).await;
+        let result = get_and_bitwise_and_acquire(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "getAndBitwiseAndAcquire",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.getAndBitwiseAndRelease([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_get_and_bitwise_and_release() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_and_bitwise_and_release(thread, Parameters::default()).await;
+        let result = get_and_bitwise_and_release(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "getAndBitwiseAndRelease",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.getAndBitwiseOr([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_get_and_bitwise_or() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_and_bitwise_or(thread, Parameters::default()).await;
+        let result = get_and_bitwise_or(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "getAndBitwiseOr",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.getAndBitwiseOrAcquire([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_get_and_bitwise_or_acquire() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_and_bitwise_or_acquire(thread, Parameters::default()).await;
+        let result = get_and_bitwise_or_acquire(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "getAndBitwiseOrAcquire",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.getAndBitwiseOrRelease([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_get_and_bitwise_or_release() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_and_bitwise_or_release(thread, Parameters::default()).await;
+        let result = get_and_bitwise_or_release(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "getAndBitwiseOrRelease",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.getAndBitwiseXor([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_get_and_bitwise_xor() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_and_bitwise_xor(thread, Parameters::default()).await;
+        let result = get_and_bitwise_xor(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "getAndBitwiseXor",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.getAndBitwiseXorAcquire([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_get_and_bitwise_xor_acquire() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_and_bitwise_xor_acquire(thread, Parameters::default()).await;
+        let result = get_and_bitwise_xor_acquire(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "getAndBitwiseXorAcquire",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.getAndBitwiseXorRelease([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_get_and_bitwise_xor_release() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_and_bitwise_xor_release(thread, Parameters::default()).await;
+        let result = get_and_bitwise_xor_release(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "getAndBitwiseXorRelease",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.getAndSet([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_get_and_set() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_and_set(thread, Parameters::default()).await;
+        let result = get_and_set(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "getAndSet",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.getAndSetAcquire([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_get_and_set_acquire() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_and_set_acquire(thread, Parameters::default()).await;
+        let result = get_and_set_acquire(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "getAndSetAcquire",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.getAndSetRelease([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_get_and_set_release() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_and_set_release(thread, Parameters::default()).await;
+        let result = get_and_set_release(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "getAndSetRelease",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.getOpaque([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_get_opaque() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_opaque(thread, Parameters::default()).await;
+        let result = get_opaque(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "getOpaque",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.getVolatile([Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_get_volatile() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_volatile(thread, Parameters::default()).await;
+        let result = get_volatile(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "getVolatile",
+            "([Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.set([Ljava/lang/Object;)V"
-    )]
     async fn test_set() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set(thread, Parameters::default()).await;
+        let result = set(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "set",
+            "([Ljava/lang/Object;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.setOpaque([Ljava/lang/Object;)V"
-    )]
     async fn test_set_opaque() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_opaque(thread, Parameters::default()).await;
+        let result = set_opaque(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "setOpaque",
+            "([Ljava/lang/Object;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.setRelease([Ljava/lang/Object;)V"
-    )]
     async fn test_set_release() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_release(thread, Parameters::default()).await;
+        let result = set_release(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "setRelease",
+            "([Ljava/lang/Object;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.setVolatile([Ljava/lang/Object;)V"
-    )]
     async fn test_set_volatile() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_volatile(thread, Parameters::default()).await;
+        let result = set_volatile(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "setVolatile",
+            "([Ljava/lang/Object;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.weakCompareAndSet([Ljava/lang/Object;)Z"
-    )]
     async fn test_weak_compare_and_set() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = weak_compare_and_set(thread, Parameters::default()).await;
+        let result = weak_compare_and_set(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "weakCompareAndSet",
+            "([Ljava/lang/Object;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.weakCompareAndSetAcquire([Ljava/lang/Object;)Z"
-    )]
     async fn test_weak_compare_and_set_acquire() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = weak_compare_and_set_acquire(thread, Parameters::default()).await;
+        let result = weak_compare_and_set_acquire(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "weakCompareAndSetAcquire",
+            "([Ljava/lang/Object;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.weakCompareAndSetPlain([Ljava/lang/Object;)Z"
-    )]
     async fn test_weak_compare_and_set_plain() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = weak_compare_and_set_plain(thread, Parameters::default()).await;
+        let result = weak_compare_and_set_plain(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "weakCompareAndSetPlain",
+            "([Ljava/lang/Object;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.invoke.VarHandle.weakCompareAndSetRelease([Ljava/lang/Object;)Z"
-    )]
     async fn test_weak_compare_and_set_release() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = weak_compare_and_set_release(thread, Parameters::default()).await;
+        let result = weak_compare_and_set_release(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/invoke/VarHandle",
+            "weakCompareAndSetRelease",
+            "([Ljava/lang/Object;)Z",
+        );
     }
 }
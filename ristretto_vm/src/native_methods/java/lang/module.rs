@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -54,7 +54,11 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn add_exports_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Module.addExports0(Ljava/lang/Module;Ljava/lang/String;Ljava/lang/Module;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "addExports0",
+        "(Ljava/lang/Module;Ljava/lang/String;Ljava/lang/Module;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -62,7 +66,11 @@ async fn add_exports_to_all_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Module.addExportsToAll0(Ljava/lang/Module;Ljava/lang/String;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "addExportsToAll0",
+        "(Ljava/lang/Module;Ljava/lang/String;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -70,17 +78,29 @@ async fn add_exports_to_all_unnamed_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Module.addExportsToAllUnnamed0(Ljava/lang/Module;Ljava/lang/String;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "addExportsToAllUnnamed0",
+        "(Ljava/lang/Module;Ljava/lang/String;)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn add_reads_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Module.addReads0(Ljava/lang/Module;Ljava/lang/Module;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "addReads0",
+        "(Ljava/lang/Module;Ljava/lang/Module;)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn define_module_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Module.defineModule0(Ljava/lang/Module;ZLjava/lang/String;Ljava/lang/String;[Ljava/lang/String;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "defineModule0",
+        "(Ljava/lang/Module;ZLjava/lang/String;Ljava/lang/String;[Ljava/lang/String;)V",
+    )
 }
 
 #[cfg(test)]
@@ -88,47 +108,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Module.addExports0(Ljava/lang/Module;Ljava/lang/String;Ljava/lang/Module;)V"
-    )]
     async fn test_add_exports_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = add_exports_0(thread, Parameters::default()).await;
+        let result = add_exports_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Module",
+            "addExports0",
+            "(Ljava/lang/Module;Ljava/lang/String;Ljava/lang/Module;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Module.addExportsToAll0(Ljava/lang/Module;Ljava/lang/String;)V"
-    )]
     async fn test_add_exports_to_all_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = add_exports_to_all_0(thread, Parameters::default()).await;
+        let result = add_exports_to_all_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Module",
+            "addExportsToAll0",
+            "(Ljava/lang/Module;Ljava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Module.addExportsToAllUnnamed0(Ljava/lang/Module;Ljava/lang/String;)V"
-    )]
     async fn test_add_exports_to_all_unnamed_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = add_exports_to_all_unnamed_0(thread, Parameters::default()).await;
+        let result = add_exports_to_all_unnamed_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Module",
+            "addExportsToAllUnnamed0",
+            "(Ljava/lang/Module;Ljava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Module.addReads0(Ljava/lang/Module;Ljava/lang/Module;)V"
-    )]
     async fn test_add_reads_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = add_reads_0(thread, Parameters::default()).await;
+        let result = add_reads_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Module",
+            "addReads0",
+            "(Ljava/lang/Module;Ljava/lang/Module;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Module.defineModule0(Ljava/lang/Module;ZLjava/lang/String;Ljava/lang/String;[Ljava/lang/String;)V"
-    )]
     async fn test_define_module_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = define_module_0(thread, Parameters::default()).await;
+        let result = define_module_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Module",
+            "defineModule0",
+            "(Ljava/lang/Module;ZLjava/lang/String;Ljava/lang/String;[Ljava/lang/String;)V",
+        );
     }
 }
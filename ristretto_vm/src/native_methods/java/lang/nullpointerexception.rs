@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,7 +23,7 @@ async fn get_extended_npe_message(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.NullPointerException.getExtendedNPEMessage()Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getExtendedNPEMessage", "()Ljava/lang/String;")
 }
 
 #[cfg(test)]
@@ -31,11 +31,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.NullPointerException.getExtendedNPEMessage()Ljava/lang/String;"
-    )]
     async fn test_get_extended_npe_message() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_extended_npe_message(thread, Parameters::default()).await;
+        let result = get_extended_npe_message(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/NullPointerException",
+            "getExtendedNPEMessage",
+            "()Ljava/lang/String;",
+        );
     }
 }
@@ -1,12 +1,11 @@
 use crate::java_object::JavaObject;
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Error::InternalError;
 use crate::Result;
 use async_recursion::async_recursion;
 use ristretto_classloader::{Reference, Value};
-use std::hash::{DefaultHasher, Hash, Hasher};
 use std::sync::Arc;
 
 const CLASS_NAME: &str = "java/lang/Object";
@@ -72,18 +71,12 @@ async fn hash_code(_thread: Arc<Thread>, mut parameters: Parameters) -> Result<O
 }
 
 pub(crate) fn object_hash_code(object: &Reference) -> i32 {
-    let value = format!("{object}");
-    let mut hasher = DefaultHasher::new();
-    value.hash(&mut hasher);
-    let hash_code = hasher.finish();
-    #[expect(clippy::cast_possible_truncation)]
-    let hash_code = hash_code as i32;
-    hash_code
+    object.identity_hash_code()
 }
 
 #[async_recursion(?Send)]
 async fn notify(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Object.notify()V")
+    unsatisfied_link(CLASS_NAME, "notify", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -98,12 +91,12 @@ async fn register_natives(_thread: Arc<Thread>, _parameters: Parameters) -> Resu
 
 #[async_recursion(?Send)]
 async fn wait(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Object.wait(J)V")
+    unsatisfied_link(CLASS_NAME, "wait", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn wait_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Object.wait0(J)V")
+    unsatisfied_link(CLASS_NAME, "wait0", "(J)V")
 }
 
 #[cfg(test)]
@@ -130,10 +123,10 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.Object.notify()V")]
     async fn test_notify() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = notify(thread, Parameters::default()).await;
+        let result = notify(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/lang/Object", "notify", "()V");
     }
 
     #[tokio::test]
@@ -153,16 +146,16 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.Object.wait(J)V")]
     async fn test_wait() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = wait(thread, Parameters::default()).await;
+        let result = wait(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/lang/Object", "wait", "(J)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.Object.wait0(J)V")]
     async fn test_wait_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = wait_0(thread, Parameters::default()).await;
+        let result = wait_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/lang/Object", "wait0", "(J)V");
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -29,7 +29,11 @@ async fn get_system_package_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Package.getSystemPackage0(Ljava/lang/String;)Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getSystemPackage0",
+        "(Ljava/lang/String;)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -37,7 +41,7 @@ async fn get_system_packages_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Package.getSystemPackages0()[Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getSystemPackages0", "()[Ljava/lang/String;")
 }
 
 #[cfg(test)]
@@ -45,20 +49,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Package.getSystemPackage0(Ljava/lang/String;)Ljava/lang/String;"
-    )]
     async fn test_get_system_package_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_system_package_0(thread, Parameters::default()).await;
+        let result = get_system_package_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Package",
+            "getSystemPackage0",
+            "(Ljava/lang/String;)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Package.getSystemPackages0()[Ljava/lang/String;"
-    )]
     async fn test_get_system_packages_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_system_packages_0(thread, Parameters::default()).await;
+        let result = get_system_packages_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Package",
+            "getSystemPackages0",
+            "()[Ljava/lang/String;",
+        );
     }
 }
@@ -168,24 +168,29 @@ async fn parent_0(_thread: Arc<Thread>, mut parameters: Parameters) -> Result<Op
 
 #[async_recursion(?Send)]
 async fn wait_for_process_exit_0(
-    _thread: Arc<Thread>,
+    thread: Arc<Thread>,
     mut parameters: Parameters,
 ) -> Result<Option<Value>> {
     let _start_time = parameters.pop_long()?;
     let pid = parameters.pop_long()?;
     let pid = usize::try_from(pid)?;
     let pid = Pid::from(pid);
-    let mut system = System::new_all();
-    system.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
-
-    let Some(process) = system.process(pid) else {
-        return Ok(Some(Value::Int(-1)));
-    };
-
-    process.wait();
-
-    // TODO: evaluate expected return value
-    Ok(Some(Value::Int(0)))
+    let exit_code = thread
+        .spawn_blocking(move || {
+            let mut system = System::new_all();
+            system.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+
+            let Some(process) = system.process(pid) else {
+                return -1;
+            };
+
+            process.wait();
+
+            // TODO: evaluate expected return value
+            0
+        })
+        .await?;
+    Ok(Some(Value::Int(exit_code)))
 }
 
 #[cfg(test)]
@@ -218,4 +223,31 @@ mod tests {
         assert!(run_time > 0);
         Ok(())
     }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_wait_for_process_exit_0_does_not_block_other_threads() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        // cat exits when its standard input is closed
+        let mut child = process::Command::new("cat")
+            .stdin(process::Stdio::piped())
+            .stdout(process::Stdio::null())
+            .spawn()
+            .map_err(|error| crate::Error::InternalError(error.to_string()))?;
+        let pid = Value::Long(i64::from(child.id()));
+        let stdin = child.stdin.take();
+
+        // The test runtime has a single worker; if waiting for the process blocked the worker,
+        // the standard input of the child would never be closed and the child would not exit.
+        let wait = wait_for_process_exit_0(thread, Parameters::new(vec![pid, Value::Long(0)]));
+        let close = async {
+            tokio::task::yield_now().await;
+            drop(stdin);
+        };
+        let (exit_code, ()) = tokio::join!(wait, close);
+        // Reap the child if waiting for it failed
+        let _ = child.wait();
+        assert_eq!(Some(Value::Int(0)), exit_code?);
+        Ok(())
+    }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -16,7 +16,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn info_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.ProcessHandleImpl$Info.info0(J)V")
+    unsatisfied_link(CLASS_NAME, "info0", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -29,10 +29,15 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.ProcessHandleImpl$Info.info0(J)V")]
     async fn test_info_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = info_0(thread, Parameters::default()).await;
+        let result = info_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/ProcessHandleImpl$Info",
+            "info0",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
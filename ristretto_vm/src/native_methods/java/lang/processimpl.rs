@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -21,7 +21,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn fork_and_exec(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.ProcessImpl.forkAndExec(I[B[B[BI[BI[B[IZ)I")
+    unsatisfied_link(CLASS_NAME, "forkAndExec", "(I[B[B[BI[BI[B[IZ)I")
 }
 
 #[async_recursion(?Send)]
@@ -34,12 +34,15 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.ProcessImpl.forkAndExec(I[B[B[BI[BI[B[IZ)I"
-    )]
     async fn test_fork_and_exec() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fork_and_exec(thread, Parameters::default()).await;
+        let result = fork_and_exec(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/ProcessImpl",
+            "forkAndExec",
+            "(I[B[B[BI[BI[B[IZ)I",
+        );
     }
 
     #[tokio::test]
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -20,7 +20,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn refers_to_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.ref.PhantomReference.refersTo0(Ljava/lang/Object;)Z")
+    unsatisfied_link(CLASS_NAME, "refersTo0", "(Ljava/lang/Object;)Z")
 }
 
 #[cfg(test)]
@@ -28,11 +28,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.ref.PhantomReference.refersTo0(Ljava/lang/Object;)Z"
-    )]
     async fn test_refers_to_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = refers_to_0(thread, Parameters::default()).await;
+        let result = refers_to_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/ref/PhantomReference",
+            "refersTo0",
+            "(Ljava/lang/Object;)Z",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_17};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_17};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -42,7 +42,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn clear_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.ref.Reference.clear0()V")
+    unsatisfied_link(CLASS_NAME, "clear0", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -50,7 +50,11 @@ async fn get_and_clear_reference_pending_list(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.ref.Reference.getAndClearReferencePendingList()Ljava/lang/ref/Reference;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getAndClearReferencePendingList",
+        "()Ljava/lang/ref/Reference;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -58,7 +62,7 @@ async fn has_reference_pending_list(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.ref.Reference.hasReferencePendingList()Z")
+    unsatisfied_link(CLASS_NAME, "hasReferencePendingList", "()Z")
 }
 
 #[async_recursion(?Send)]
@@ -79,7 +83,7 @@ async fn wait_for_reference_pending_list(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.ref.Reference.waitForReferencePendingList()V")
+    unsatisfied_link(CLASS_NAME, "waitForReferencePendingList", "()V")
 }
 
 #[cfg(test)]
@@ -87,36 +91,45 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.ref.Reference.clear0()V")]
     async fn test_clear_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = clear_0(thread, Parameters::default()).await;
+        let result = clear_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/lang/ref/Reference", "clear0", "()V");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.ref.Reference.getAndClearReferencePendingList()Ljava/lang/ref/Reference;"
-    )]
     async fn test_get_and_clear_reference_pending_list() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_and_clear_reference_pending_list(thread, Parameters::default()).await;
+        let result = get_and_clear_reference_pending_list(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/ref/Reference",
+            "getAndClearReferencePendingList",
+            "()Ljava/lang/ref/Reference;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.ref.Reference.hasReferencePendingList()Z"
-    )]
     async fn test_has_reference_pending_list() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = has_reference_pending_list(thread, Parameters::default()).await;
+        let result = has_reference_pending_list(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/ref/Reference",
+            "hasReferencePendingList",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.ref.Reference.waitForReferencePendingList()V"
-    )]
     async fn test_wait_for_reference_pending_list() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = wait_for_reference_pending_list(thread, Parameters::default()).await;
+        let result = wait_for_reference_pending_list(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/ref/Reference",
+            "waitForReferencePendingList",
+            "()V",
+        );
     }
 }
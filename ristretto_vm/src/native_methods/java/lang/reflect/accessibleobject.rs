@@ -0,0 +1,270 @@
+use crate::module_access::Access;
+use crate::native_methods::java::lang::class::get_class;
+use crate::native_methods::registry::MethodRegistry;
+use crate::parameters::Parameters;
+use crate::thread::Thread;
+use crate::JavaError::{IllegalCallerException, InaccessibleObjectException};
+use crate::Result;
+use async_recursion::async_recursion;
+use ristretto_classfile::{ClassAccessFlags, MethodAccessFlags};
+use ristretto_classloader::{Class, Value};
+use std::sync::Arc;
+
+const CLASS_NAME: &str = "java/lang/reflect/AccessibleObject";
+
+/// Register all native methods for `java.lang.reflect.AccessibleObject`.
+pub(crate) fn register(registry: &mut MethodRegistry) {
+    registry.register(
+        CLASS_NAME,
+        "checkCanSetAccessible",
+        "(Ljava/lang/Class;Ljava/lang/Class;Z)Z",
+        check_can_set_accessible,
+    );
+}
+
+/// Check if the caller may suppress the access checks of a field, method or constructor.  This
+/// follows `AccessibleObject.checkCanSetAccessible` using the module graph of the VM: code in
+/// `java.base` or in the module of the declaring class may suppress any access check; public
+/// members of public classes, and protected static members of public classes accessed from a
+/// subclass, require the package to be exported to the caller; all other members require the
+/// package to be opened to the caller.
+#[async_recursion(?Send)]
+async fn check_can_set_accessible(
+    thread: Arc<Thread>,
+    mut parameters: Parameters,
+) -> Result<Option<Value>> {
+    let throw_exception_if_denied = parameters.pop_int()? != 0;
+    let declaring_class = parameters.pop_object()?;
+    let caller = parameters.pop_object()?;
+    let member = parameters.pop_object()?;
+    let declaring_class_name: String = declaring_class.value("name")?.try_into()?;
+    let caller_name: String = caller.value("name")?.try_into()?;
+
+    // Reflection through a method handle must be checked against the caller of the method
+    // handle instead; see java.lang.invoke.MethodHandleImpl.BindCaller
+    if caller_name == "java.lang.invoke.MethodHandle" {
+        return Err(IllegalCallerException(caller_name).into());
+    }
+
+    let vm = thread.vm()?;
+    let module_access = vm.module_access().await?;
+    if module_access.class_module(&caller_name) == Some("java.base") {
+        return Ok(Some(Value::from(true)));
+    }
+
+    let caller = get_class(&thread, &caller).await?;
+    let class = get_class(&thread, &declaring_class).await?;
+    let modifiers: i32 = member.value("modifiers")?.try_into()?;
+    let public = i32::from(MethodAccessFlags::PUBLIC.bits());
+    let protected_static =
+        i32::from((MethodAccessFlags::PROTECTED | MethodAccessFlags::STATIC).bits());
+    let exported_member = modifiers & public != 0
+        || (modifiers & protected_static == protected_static && is_subclass_of(&caller, &class)?);
+    let access = if exported_member
+        && class
+            .class_file()
+            .access_flags
+            .contains(ClassAccessFlags::PUBLIC)
+    {
+        Access::Public
+    } else {
+        Access::Deep
+    };
+
+    let Err(reason) = module_access.check(&caller_name, &declaring_class_name, access) else {
+        return Ok(Some(Value::from(true)));
+    };
+    if !throw_exception_if_denied {
+        return Ok(Some(Value::from(false)));
+    }
+
+    let member_name = if member.class().name() == "java/lang/reflect/Constructor" {
+        declaring_class_name
+    } else {
+        let name: String = member.value("name")?.try_into()?;
+        format!("{declaring_class_name}.{name}")
+    };
+    Err(
+        InaccessibleObjectException(format!("Unable to make {member_name} accessible: {reason}"))
+            .into(),
+    )
+}
+
+/// Determine if a class is the same class as, or a subclass of, another class.
+fn is_subclass_of(class: &Arc<Class>, super_class: &Arc<Class>) -> Result<bool> {
+    let mut class = Some(class.clone());
+    while let Some(current_class) = class {
+        if current_class.name() == super_class.name() {
+            return Ok(true);
+        }
+        class = current_class.parent()?;
+    }
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::java_object::JavaObject;
+    use ristretto_classfile::attributes::Instruction;
+    use ristretto_classfile::{ClassFileBuilder, Version};
+
+    async fn check(
+        thread: &Arc<Thread>,
+        caller: &str,
+        field_name: &str,
+        throw: bool,
+    ) -> Result<Option<Value>> {
+        let vm = thread.vm()?;
+        let string_class = thread.class("java.lang.String").await?;
+        let class_class = thread.class("java.lang.Class").await?;
+        let method = class_class.try_get_method(
+            "getDeclaredField",
+            "(Ljava/lang/String;)Ljava/lang/reflect/Field;",
+        )?;
+        let string_class_object = string_class.to_object(&vm).await?;
+        let field = thread
+            .try_execute(
+                &class_class,
+                &method,
+                vec![
+                    string_class_object.clone(),
+                    field_name.to_object(&vm).await?,
+                ],
+            )
+            .await?;
+        let caller = thread.class(caller).await?.to_object(&vm).await?;
+        let parameters =
+            Parameters::new(vec![field, caller, string_class_object, Value::from(throw)]);
+        check_can_set_accessible(thread.clone(), parameters).await
+    }
+
+    #[tokio::test]
+    async fn test_check_can_set_accessible_exported() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let result = check(&thread, "HelloWorld", "CASE_INSENSITIVE_ORDER", true).await?;
+        assert_eq!(Some(Value::from(true)), result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_check_can_set_accessible_denied() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let result = check(&thread, "HelloWorld", "value", false).await?;
+        assert_eq!(Some(Value::from(false)), result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_check_can_set_accessible_exception() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let result = check(&thread, "HelloWorld", "value", true).await;
+        assert!(matches!(
+            result,
+            Err(crate::Error::JavaError(InaccessibleObjectException(message)))
+                if message == "Unable to make java.lang.String.value accessible: module java.base does not \"opens java.lang\" to unnamed module"
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_check_can_set_accessible_java_base_caller() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let result = check(&thread, "java.util.Objects", "value", true).await?;
+        assert_eq!(Some(Value::from(true)), result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_check_can_set_accessible_method_handle_caller() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let result = check(&thread, "java.lang.invoke.MethodHandle", "value", true).await;
+        assert!(matches!(
+            result,
+            Err(crate::Error::JavaError(IllegalCallerException(message)))
+                if message == "java.lang.invoke.MethodHandle"
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_is_subclass_of() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let child = thread.class("Child").await?;
+        let grand_parent = thread.class("GrandParent").await?;
+        assert!(is_subclass_of(&child, &grand_parent)?);
+        assert!(is_subclass_of(&child, &child)?);
+        assert!(!is_subclass_of(&grand_parent, &child)?);
+        Ok(())
+    }
+
+    /// Class path class with a `setAccessible` method that calls `setAccessible(true)` on a
+    /// declared field of `java.lang.String`
+    fn reflection_class() -> Result<Arc<Class>> {
+        let mut builder = ClassFileBuilder::new("Reflection")
+            .java_version(Version::Java8 { minor: 0 })
+            .public();
+        let constant_pool = builder.constant_pool();
+        let string_class = constant_pool.add_class("java/lang/String")?;
+        let class_class = constant_pool.add_class("java/lang/Class")?;
+        let get_declared_field = constant_pool.add_method_ref(
+            class_class,
+            "getDeclaredField",
+            "(Ljava/lang/String;)Ljava/lang/reflect/Field;",
+        )?;
+        let field_class = constant_pool.add_class("java/lang/reflect/Field")?;
+        let set_accessible = constant_pool.add_method_ref(field_class, "setAccessible", "(Z)V")?;
+        let class_file = builder
+            .method(
+                MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                "setAccessible",
+                "(Ljava/lang/String;)V",
+                vec![
+                    Instruction::Ldc_w(string_class),
+                    Instruction::Aload_0,
+                    Instruction::Invokevirtual(get_declared_field),
+                    Instruction::Iconst_1,
+                    Instruction::Invokevirtual(set_accessible),
+                    Instruction::Return,
+                ],
+            )
+            .build()?;
+        Ok(Arc::new(Class::from(class_file)?))
+    }
+
+    async fn set_accessible(thread: &Arc<Thread>, field_name: &str) -> Result<Option<Value>> {
+        let vm = thread.vm()?;
+        let class = thread.define_class(reflection_class()?).await?;
+        let method = class.try_get_method("setAccessible", "(Ljava/lang/String;)V")?;
+        thread
+            .execute(&class, &method, vec![field_name.to_object(&vm).await?])
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_set_accessible_from_class_path_exported() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let result = set_accessible(&thread, "CASE_INSENSITIVE_ORDER").await?;
+        assert_eq!(None, result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_accessible_from_class_path_denied() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let result = set_accessible(&thread, "value").await;
+        let Err(crate::Error::Throwable(throwable)) = result else {
+            panic!("expected InaccessibleObjectException; found {result:?}");
+        };
+        assert_eq!(
+            "java/lang/reflect/InaccessibleObjectException",
+            throwable.class().name()
+        );
+        let message: String = throwable.value("detailMessage")?.try_into()?;
+        assert_eq!(
+            "Unable to make java.lang.String.value accessible: module java.base does not \"opens java.lang\" to unnamed module",
+            message
+        );
+        Ok(())
+    }
+}
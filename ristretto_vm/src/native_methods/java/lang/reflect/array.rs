@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -81,57 +81,61 @@ fn get_class_name(value: Value) -> Result<String> {
 
 #[async_recursion(?Send)]
 async fn get(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.get(Ljava/lang/Object;I)Ljava/lang/Object;")
+    unsatisfied_link(CLASS_NAME, "get", "(Ljava/lang/Object;I)Ljava/lang/Object;")
 }
 
 #[async_recursion(?Send)]
 async fn get_boolean(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.getBoolean(Ljava/lang/Object;I)Z")
+    unsatisfied_link(CLASS_NAME, "getBoolean", "(Ljava/lang/Object;I)Z")
 }
 
 #[async_recursion(?Send)]
 async fn get_byte(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.getByte(Ljava/lang/Object;I)B")
+    unsatisfied_link(CLASS_NAME, "getByte", "(Ljava/lang/Object;I)B")
 }
 
 #[async_recursion(?Send)]
 async fn get_char(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.getChar(Ljava/lang/Object;I)C")
+    unsatisfied_link(CLASS_NAME, "getChar", "(Ljava/lang/Object;I)C")
 }
 
 #[async_recursion(?Send)]
 async fn get_double(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.getDouble(Ljava/lang/Object;I)D")
+    unsatisfied_link(CLASS_NAME, "getDouble", "(Ljava/lang/Object;I)D")
 }
 
 #[async_recursion(?Send)]
 async fn get_float(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.getFloat(Ljava/lang/Object;I)F")
+    unsatisfied_link(CLASS_NAME, "getFloat", "(Ljava/lang/Object;I)F")
 }
 
 #[async_recursion(?Send)]
 async fn get_int(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.getInt(Ljava/lang/Object;I)I")
+    unsatisfied_link(CLASS_NAME, "getInt", "(Ljava/lang/Object;I)I")
 }
 
 #[async_recursion(?Send)]
 async fn get_length(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.getLength(Ljava/lang/Object;)I")
+    unsatisfied_link(CLASS_NAME, "getLength", "(Ljava/lang/Object;)I")
 }
 
 #[async_recursion(?Send)]
 async fn get_long(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.getLong(Ljava/lang/Object;I)J")
+    unsatisfied_link(CLASS_NAME, "getLong", "(Ljava/lang/Object;I)J")
 }
 
 #[async_recursion(?Send)]
 async fn get_short(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.getShort(Ljava/lang/Object;I)S")
+    unsatisfied_link(CLASS_NAME, "getShort", "(Ljava/lang/Object;I)S")
 }
 
 #[async_recursion(?Send)]
 async fn multi_new_array(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.multiNewArray(Ljava/lang/Class;[I)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "multiNewArray",
+        "(Ljava/lang/Class;[I)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -160,47 +164,51 @@ async fn new_array(thread: Arc<Thread>, mut parameters: Parameters) -> Result<Op
 
 #[async_recursion(?Send)]
 async fn set(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.set(Ljava/lang/Object;ILjava/lang/Object;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "set",
+        "(Ljava/lang/Object;ILjava/lang/Object;)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn set_boolean(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.setBoolean(Ljava/lang/Object;IZ)V")
+    unsatisfied_link(CLASS_NAME, "setBoolean", "(Ljava/lang/Object;IZ)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_byte(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.setByte(Ljava/lang/Object;IB)V")
+    unsatisfied_link(CLASS_NAME, "setByte", "(Ljava/lang/Object;IB)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_char(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.setChar(Ljava/lang/Object;IC)V")
+    unsatisfied_link(CLASS_NAME, "setChar", "(Ljava/lang/Object;IC)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_double(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.setDouble(Ljava/lang/Object;ID)V")
+    unsatisfied_link(CLASS_NAME, "setDouble", "(Ljava/lang/Object;ID)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_float(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.setFloat(Ljava/lang/Object;IF)V")
+    unsatisfied_link(CLASS_NAME, "setFloat", "(Ljava/lang/Object;IF)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_int(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.setInt(Ljava/lang/Object;II)V")
+    unsatisfied_link(CLASS_NAME, "setInt", "(Ljava/lang/Object;II)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_long(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.setLong(Ljava/lang/Object;IJ)V")
+    unsatisfied_link(CLASS_NAME, "setLong", "(Ljava/lang/Object;IJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_short(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.setShort(Ljava/lang/Object;IS)V")
+    unsatisfied_link(CLASS_NAME, "setShort", "(Ljava/lang/Object;IS)V")
 }
 
 #[cfg(test)]
@@ -208,164 +216,242 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Array.get(Ljava/lang/Object;I)Ljava/lang/Object;"
-    )]
     async fn test_get() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get(thread, Parameters::default()).await;
+        let result = get(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Array",
+            "get",
+            "(Ljava/lang/Object;I)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Array.getBoolean(Ljava/lang/Object;I)Z"
-    )]
     async fn test_get_boolean() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_boolean(thread, Parameters::default()).await;
+        let result = get_boolean(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Array",
+            "getBoolean",
+            "(Ljava/lang/Object;I)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Array.getByte(Ljava/lang/Object;I)B"
-    )]
     async fn test_get_byte() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_byte(thread, Parameters::default()).await;
+        let result = get_byte(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Array",
+            "getByte",
+            "(Ljava/lang/Object;I)B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Array.getChar(Ljava/lang/Object;I)C"
-    )]
     async fn test_get_char() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_char(thread, Parameters::default()).await;
+        let result = get_char(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Array",
+            "getChar",
+            "(Ljava/lang/Object;I)C",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Array.getDouble(Ljava/lang/Object;I)D"
-    )]
     async fn test_get_double() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_double(thread, Parameters::default()).await;
+        let result = get_double(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Array",
+            "getDouble",
+            "(Ljava/lang/Object;I)D",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Array.getFloat(Ljava/lang/Object;I)F"
-    )]
     async fn test_get_float() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_float(thread, Parameters::default()).await;
+        let result = get_float(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Array",
+            "getFloat",
+            "(Ljava/lang/Object;I)F",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Array.getInt(Ljava/lang/Object;I)I"
-    )]
     async fn test_get_int() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_int(thread, Parameters::default()).await;
+        let result = get_int(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Array",
+            "getInt",
+            "(Ljava/lang/Object;I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Array.getLength(Ljava/lang/Object;)I"
-    )]
     async fn test_get_length() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_length(thread, Parameters::default()).await;
+        let result = get_length(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Array",
+            "getLength",
+            "(Ljava/lang/Object;)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Array.getLong(Ljava/lang/Object;I)J"
-    )]
     async fn test_get_long() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_long(thread, Parameters::default()).await;
+        let result = get_long(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Array",
+            "getLong",
+            "(Ljava/lang/Object;I)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Array.getShort(Ljava/lang/Object;I)S"
-    )]
     async fn test_get_short() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_short(thread, Parameters::default()).await;
+        let result = get_short(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Array",
+            "getShort",
+            "(Ljava/lang/Object;I)S",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Array.multiNewArray(Ljava/lang/Class;[I)Ljava/lang/Object;"
-    )]
     async fn test_multi_new_array() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = multi_new_array(thread, Parameters::default()).await;
+        let result = multi_new_array(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Array",
+            "multiNewArray",
+            "(Ljava/lang/Class;[I)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Array.set(Ljava/lang/Object;ILjava/lang/Object;)V"
-    )]
     async fn test_set() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set(thread, Parameters::default()).await;
+        let result = set(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Array",
+            "set",
+            "(Ljava/lang/Object;ILjava/lang/Object;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Array.setBoolean(Ljava/lang/Object;IZ)V"
-    )]
     async fn test_set_boolean() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_boolean(thread, Parameters::default()).await;
+        let result = set_boolean(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Array",
+            "setBoolean",
+            "(Ljava/lang/Object;IZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Array.setByte(Ljava/lang/Object;IB)V"
-    )]
     async fn test_set_byte() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_byte(thread, Parameters::default()).await;
+        let result = set_byte(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Array",
+            "setByte",
+            "(Ljava/lang/Object;IB)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Array.setChar(Ljava/lang/Object;IC)V"
-    )]
     async fn test_set_char() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_char(thread, Parameters::default()).await;
+        let result = set_char(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Array",
+            "setChar",
+            "(Ljava/lang/Object;IC)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Array.setDouble(Ljava/lang/Object;ID)V"
-    )]
     async fn test_set_double() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_double(thread, Parameters::default()).await;
+        let result = set_double(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Array",
+            "setDouble",
+            "(Ljava/lang/Object;ID)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Array.setFloat(Ljava/lang/Object;IF)V"
-    )]
     async fn test_set_float() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_float(thread, Parameters::default()).await;
+        let result = set_float(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Array",
+            "setFloat",
+            "(Ljava/lang/Object;IF)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Array.setInt(Ljava/lang/Object;II)V"
-    )]
     async fn test_set_int() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_int(thread, Parameters::default()).await;
+        let result = set_int(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Array",
+            "setInt",
+            "(Ljava/lang/Object;II)V",
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_long() {
+        let (_vm, thread) = crate::test::thread().await.expect("thread");
+        let result = set_long(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Array",
+            "setLong",
+            "(Ljava/lang/Object;IJ)V",
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_short() {
+        let (_vm, thread) = crate::test::thread().await.expect("thread");
+        let result = set_short(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Array",
+            "setShort",
+            "(Ljava/lang/Object;IS)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -26,7 +26,11 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn get_parameters_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Executable.getParameters0()[Ljava/lang/reflect/Parameter;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getParameters0",
+        "()[Ljava/lang/reflect/Parameter;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -34,7 +38,7 @@ async fn get_type_annotation_bytes_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Executable.getTypeAnnotationBytes0()[B")
+    unsatisfied_link(CLASS_NAME, "getTypeAnnotationBytes0", "()[B")
 }
 
 #[cfg(test)]
@@ -42,20 +46,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Executable.getParameters0()[Ljava/lang/reflect/Parameter;"
-    )]
     async fn test_get_parameters_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_parameters_0(thread, Parameters::default()).await;
+        let result = get_parameters_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Executable",
+            "getParameters0",
+            "()[Ljava/lang/reflect/Parameter;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Executable.getTypeAnnotationBytes0()[B"
-    )]
     async fn test_get_type_annotation_bytes_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_type_annotation_bytes_0(thread, Parameters::default()).await;
+        let result = get_type_annotation_bytes_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Executable",
+            "getTypeAnnotationBytes0",
+            "()[B",
+        );
     }
 }
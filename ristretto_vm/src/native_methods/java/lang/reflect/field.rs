@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,7 +23,7 @@ async fn get_type_annotation_bytes_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Field.getTypeAnnotationBytes0()[B")
+    unsatisfied_link(CLASS_NAME, "getTypeAnnotationBytes0", "()[B")
 }
 
 #[cfg(test)]
@@ -31,11 +31,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.reflect.Field.getTypeAnnotationBytes0()[B"
-    )]
     async fn test_get_type_annotation_bytes_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_type_annotation_bytes_0(thread, Parameters::default()).await;
+        let result = get_type_annotation_bytes_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/reflect/Field",
+            "getTypeAnnotationBytes0",
+            "()[B",
+        );
     }
 }
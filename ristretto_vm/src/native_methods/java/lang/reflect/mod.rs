@@ -1,3 +1,4 @@
+pub(crate) mod accessibleobject;
 pub(crate) mod array;
 pub(crate) mod executable;
 pub(crate) mod field;
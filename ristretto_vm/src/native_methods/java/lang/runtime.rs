@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -71,7 +71,7 @@ async fn run_finalization_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Runtime.runFinalization0()V")
+    unsatisfied_link(CLASS_NAME, "runFinalization0", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -79,7 +79,7 @@ async fn trace_instructions(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Runtime.traceInstructions(Z)V")
+    unsatisfied_link(CLASS_NAME, "traceInstructions", "(Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -87,7 +87,7 @@ async fn trace_method_calls(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Runtime.traceMethodCalls(Z)V")
+    unsatisfied_link(CLASS_NAME, "traceMethodCalls", "(Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -143,24 +143,39 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.Runtime.runFinalization0()V")]
     async fn test_run_finalization_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = run_finalization_0(thread, Parameters::default()).await;
+        let result = run_finalization_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Runtime",
+            "runFinalization0",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.Runtime.traceInstructions(Z)V")]
     async fn test_trace_instructions() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = trace_instructions(thread, Parameters::default()).await;
+        let result = trace_instructions(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Runtime",
+            "traceInstructions",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.Runtime.traceMethodCalls(Z)V")]
     async fn test_trace_method_calls() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = trace_method_calls(thread, Parameters::default()).await;
+        let result = trace_method_calls(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Runtime",
+            "traceMethodCalls",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -42,7 +42,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn class_depth(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.SecurityManager.classDepth(Ljava/lang/String;)I")
+    unsatisfied_link(CLASS_NAME, "classDepth", "(Ljava/lang/String;)I")
 }
 
 #[async_recursion(?Send)]
@@ -50,7 +50,7 @@ async fn class_loader_depth_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.SecurityManager.classLoaderDepth0()I")
+    unsatisfied_link(CLASS_NAME, "classLoaderDepth0", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -58,7 +58,11 @@ async fn current_class_loader_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.SecurityManager.currentClassLoader0()Ljava/lang/ClassLoader;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "currentClassLoader0",
+        "()Ljava/lang/ClassLoader;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -66,12 +70,12 @@ async fn current_loaded_class_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.SecurityManager.currentLoadedClass0()Ljava/lang/Class;")
+    unsatisfied_link(CLASS_NAME, "currentLoadedClass0", "()Ljava/lang/Class;")
 }
 
 #[async_recursion(?Send)]
 async fn get_class_context(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.SecurityManager.getClassContext()[Ljava/lang/Class;")
+    unsatisfied_link(CLASS_NAME, "getClassContext", "()[Ljava/lang/Class;")
 }
 
 #[cfg(test)]
@@ -79,47 +83,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.SecurityManager.classDepth(Ljava/lang/String;)I"
-    )]
     async fn test_class_depth() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = class_depth(thread, Parameters::default()).await;
+        let result = class_depth(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/SecurityManager",
+            "classDepth",
+            "(Ljava/lang/String;)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.SecurityManager.classLoaderDepth0()I"
-    )]
     async fn test_class_loader_depth_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = class_loader_depth_0(thread, Parameters::default()).await;
+        let result = class_loader_depth_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/SecurityManager",
+            "classLoaderDepth0",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.SecurityManager.currentClassLoader0()Ljava/lang/ClassLoader;"
-    )]
     async fn test_current_class_loader_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = current_class_loader_0(thread, Parameters::default()).await;
+        let result = current_class_loader_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/SecurityManager",
+            "currentClassLoader0",
+            "()Ljava/lang/ClassLoader;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.SecurityManager.currentLoadedClass0()Ljava/lang/Class;"
-    )]
     async fn test_current_loaded_class_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = current_loaded_class_0(thread, Parameters::default()).await;
+        let result = current_loaded_class_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/SecurityManager",
+            "currentLoadedClass0",
+            "()Ljava/lang/Class;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.SecurityManager.getClassContext()[Ljava/lang/Class;"
-    )]
     async fn test_get_class_context() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_class_context(thread, Parameters::default()).await;
+        let result = get_class_context(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/SecurityManager",
+            "getClassContext",
+            "()[Ljava/lang/Class;",
+        );
     }
 }
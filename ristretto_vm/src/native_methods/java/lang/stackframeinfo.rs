@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,7 +23,7 @@ async fn expand_stack_frame_info(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.StackFrameInfo.expandStackFrameInfo()V")
+    unsatisfied_link(CLASS_NAME, "expandStackFrameInfo", "()V")
 }
 
 #[cfg(test)]
@@ -31,11 +31,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.StackFrameInfo.expandStackFrameInfo()V"
-    )]
     async fn test_expand_stack_frame_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = expand_stack_frame_info(thread, Parameters::default()).await;
+        let result = expand_stack_frame_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/StackFrameInfo",
+            "expandStackFrameInfo",
+            "()V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,7 +23,7 @@ async fn check_stack_walk_modes(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.StackStreamFactory.checkStackWalkModes()Z")
+    unsatisfied_link(CLASS_NAME, "checkStackWalkModes", "()Z")
 }
 
 #[cfg(test)]
@@ -31,11 +31,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.StackStreamFactory.checkStackWalkModes()Z"
-    )]
     async fn test_check_stack_walk_modes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = check_stack_walk_modes(thread, Parameters::default()).await;
+        let result = check_stack_walk_modes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/StackStreamFactory",
+            "checkStackWalkModes",
+            "()Z",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_17, JAVA_21};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_17, JAVA_21};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -50,7 +50,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn call_stack_walk(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.StackStreamFactory$AbstractStackWalker.callStackWalk()Ljava/lang/Object;")
+    unsatisfied_link(CLASS_NAME, "callStackWalk", "()Ljava/lang/Object;")
 }
 
 #[async_recursion(?Send)]
@@ -58,12 +58,16 @@ async fn fetch_stack_frames(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.StackStreamFactory$AbstractStackWalker.fetchStackFrames()I")
+    unsatisfied_link(CLASS_NAME, "fetchStackFrames", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn set_continuation(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.StackStreamFactory$AbstractStackWalker.setContinuation()V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "setContinuation",
+        "(J[Ljava/lang/Object;Ljdk/internal/vm/Continuation;)V",
+    )
 }
 
 #[cfg(test)]
@@ -71,29 +75,38 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.StackStreamFactory$AbstractStackWalker.callStackWalk()Ljava/lang/Object;"
-    )]
     async fn test_call_stack_walk() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = call_stack_walk(thread, Parameters::default()).await;
+        let result = call_stack_walk(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/StackStreamFactory$AbstractStackWalker",
+            "callStackWalk",
+            "()Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.StackStreamFactory$AbstractStackWalker.fetchStackFrames()I"
-    )]
     async fn test_fetch_stack_frames() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fetch_stack_frames(thread, Parameters::default()).await;
+        let result = fetch_stack_frames(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/StackStreamFactory$AbstractStackWalker",
+            "fetchStackFrames",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.StackStreamFactory$AbstractStackWalker.setContinuation()V"
-    )]
     async fn test_set_continuation() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_continuation(thread, Parameters::default()).await;
+        let result = set_continuation(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/StackStreamFactory$AbstractStackWalker",
+            "setContinuation",
+            "(J[Ljava/lang/Object;Ljdk/internal/vm/Continuation;)V",
+        );
     }
 }
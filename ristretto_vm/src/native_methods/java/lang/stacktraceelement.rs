@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_17};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_17};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Error::InternalError;
@@ -40,7 +40,11 @@ async fn init_stack_trace_element(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.StackTraceElement.initStackTraceElement(Ljava/lang/StackTraceElement;Ljava/lang/StackFrameInfo;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "initStackTraceElement",
+        "(Ljava/lang/StackTraceElement;Ljava/lang/StackFrameInfo;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -69,11 +73,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.StackTraceElement.initStackTraceElement(Ljava/lang/StackTraceElement;Ljava/lang/StackFrameInfo;)V"
-    )]
     async fn test_init_stack_trace_element() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_stack_trace_element(thread, Parameters::default()).await;
+        let result = init_stack_trace_element(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/StackTraceElement",
+            "initStackTraceElement",
+            "(Ljava/lang/StackTraceElement;Ljava/lang/StackFrameInfo;)V",
+        );
     }
 }
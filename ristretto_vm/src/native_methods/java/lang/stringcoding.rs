@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -15,7 +15,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn err(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.StringCoding.err(Ljava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "err", "(Ljava/lang/String;)V")
 }
 
 #[cfg(test)]
@@ -23,11 +23,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.StringCoding.err(Ljava/lang/String;)V"
-    )]
     async fn test_err() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = err(thread, Parameters::default()).await;
+        let result = err(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/StringCoding",
+            "err",
+            "(Ljava/lang/String;)V",
+        );
     }
 }
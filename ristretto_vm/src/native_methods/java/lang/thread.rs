@@ -1,4 +1,6 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17, JAVA_21, JAVA_23};
+use crate::native_methods::registry::{
+    unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17, JAVA_21, JAVA_23,
+};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::JavaError::NullPointerException;
@@ -163,7 +165,11 @@ async fn current_thread(thread: Arc<Thread>, _parameters: Parameters) -> Result<
 
 #[async_recursion(?Send)]
 async fn dump_threads(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Thread.dumpThreads([Ljava/lang/Thread;)[[Ljava/lang/StackTraceElement;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "dumpThreads",
+        "([Ljava/lang/Thread;)[[Ljava/lang/StackTraceElement;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -171,7 +177,11 @@ async fn ensure_materialized_for_stack_walk(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Thread.ensureMaterializedForStackWalk(Ljava/lang/Object;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "ensureMaterializedForStackWalk",
+        "(Ljava/lang/Object;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -179,7 +189,11 @@ async fn find_scoped_value_bindings(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Thread.findScopedValueBindings()Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "findScopedValueBindings",
+        "()Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -195,22 +209,22 @@ async fn get_next_thread_id_offset(
 
 #[async_recursion(?Send)]
 async fn get_stack_trace_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Thread.getStackTrace0()Ljava/lang/Object;")
+    unsatisfied_link(CLASS_NAME, "getStackTrace0", "()Ljava/lang/Object;")
 }
 
 #[async_recursion(?Send)]
 async fn get_threads(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Thread.getThreads()[Ljava/lang/Thread;")
+    unsatisfied_link(CLASS_NAME, "getThreads", "()[Ljava/lang/Thread;")
 }
 
 #[async_recursion(?Send)]
 async fn holds_lock(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Thread.holdsLock(Ljava/lang/Object;)Z")
+    unsatisfied_link(CLASS_NAME, "holdsLock", "(Ljava/lang/Object;)Z")
 }
 
 #[async_recursion(?Send)]
 async fn interrupt_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Thread.interrupt0()V")
+    unsatisfied_link(CLASS_NAME, "interrupt0", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -223,7 +237,7 @@ async fn is_alive(thread: Arc<Thread>, _parameters: Parameters) -> Result<Option
 
 #[async_recursion(?Send)]
 async fn is_interrupted(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Thread.isInterrupted(Z)Z")
+    unsatisfied_link(CLASS_NAME, "isInterrupted", "(Z)Z")
 }
 
 #[async_recursion(?Send)]
@@ -233,7 +247,7 @@ async fn register_natives(_thread: Arc<Thread>, _parameters: Parameters) -> Resu
 
 #[async_recursion(?Send)]
 async fn resume_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Thread.resume0()V")
+    unsatisfied_link(CLASS_NAME, "resume0", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -241,7 +255,7 @@ async fn scoped_value_cache(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Thread.scopedValueCache()[Ljava/lang/Object;")
+    unsatisfied_link(CLASS_NAME, "scopedValueCache", "()[Ljava/lang/Object;")
 }
 
 #[async_recursion(?Send)]
@@ -249,7 +263,7 @@ async fn set_current_thread(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Thread.setCurrentThread(Ljava/lang/Thread;)V")
+    unsatisfied_link(CLASS_NAME, "setCurrentThread", "(Ljava/lang/Thread;)V")
 }
 
 #[async_recursion(?Send)]
@@ -265,7 +279,7 @@ async fn set_native_name(thread: Arc<Thread>, mut parameters: Parameters) -> Res
 #[async_recursion(?Send)]
 async fn set_priority_0(_thread: Arc<Thread>, mut parameters: Parameters) -> Result<Option<Value>> {
     let _new_priority = parameters.pop_int()?;
-    // TODO: implement priority if/when tokio supports it
+    // TODO: implement priority if/when the scheduler supports it
     Ok(None)
 }
 
@@ -274,18 +288,18 @@ async fn set_scoped_value_cache(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Thread.setScopedValueCache([Ljava/lang/Object;)V")
+    unsatisfied_link(CLASS_NAME, "setScopedValueCache", "([Ljava/lang/Object;)V")
 }
 
 #[async_recursion(?Send)]
-async fn sleep(_thread: Arc<Thread>, mut parameters: Parameters) -> Result<Option<Value>> {
+async fn sleep(thread: Arc<Thread>, mut parameters: Parameters) -> Result<Option<Value>> {
     let millis = parameters.pop_long()?;
     let millis = u64::try_from(millis)?;
     let duration = Duration::from_millis(millis);
-    #[cfg(not(target_arch = "wasm32"))]
-    tokio::time::sleep(duration).await;
-    #[cfg(target_arch = "wasm32")]
-    std::thread::sleep(duration);
+    let vm = thread.vm()?;
+    thread
+        .safe_region(vm.configuration().scheduler().sleep(duration))
+        .await;
     Ok(None)
 }
 
@@ -295,14 +309,14 @@ async fn sleep_0(thread: Arc<Thread>, parameters: Parameters) -> Result<Option<V
 }
 
 #[async_recursion(?Send)]
-async fn sleep_nanos_0(_thread: Arc<Thread>, mut parameters: Parameters) -> Result<Option<Value>> {
+async fn sleep_nanos_0(thread: Arc<Thread>, mut parameters: Parameters) -> Result<Option<Value>> {
     let nanos = parameters.pop_long()?;
     let nanos = u64::try_from(nanos)?;
     let duration = Duration::from_nanos(nanos);
-    #[cfg(not(target_arch = "wasm32"))]
-    tokio::time::sleep(duration).await;
-    #[cfg(target_arch = "wasm32")]
-    std::thread::sleep(duration);
+    let vm = thread.vm()?;
+    thread
+        .safe_region(vm.configuration().scheduler().sleep(duration))
+        .await;
     Ok(None)
 }
 
@@ -316,20 +330,20 @@ async fn start_0(thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<
 
 #[async_recursion(?Send)]
 async fn stop_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Thread.stop0(Ljava/lang/Object;)V")
+    unsatisfied_link(CLASS_NAME, "stop0", "(Ljava/lang/Object;)V")
 }
 
 #[async_recursion(?Send)]
 async fn suspend_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.Thread.suspend0()V")
+    unsatisfied_link(CLASS_NAME, "suspend0", "()V")
 }
 
 #[async_recursion(?Send)]
-async fn r#yield(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    #[cfg(not(target_arch = "wasm32"))]
-    tokio::task::yield_now().await;
-    #[cfg(target_arch = "wasm32")]
-    std::thread::yield_now();
+async fn r#yield(thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    let vm = thread.vm()?;
+    thread
+        .safe_region(vm.configuration().scheduler().yield_now())
+        .await;
     Ok(None)
 }
 
@@ -375,30 +389,39 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Thread.dumpThreads([Ljava/lang/Thread;)[[Ljava/lang/StackTraceElement;"
-    )]
     async fn test_dump_threads() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = dump_threads(thread, Parameters::default()).await;
+        let result = dump_threads(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Thread",
+            "dumpThreads",
+            "([Ljava/lang/Thread;)[[Ljava/lang/StackTraceElement;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Thread.ensureMaterializedForStackWalk(Ljava/lang/Object;)V"
-    )]
     async fn test_ensure_materialized_for_stack_walk() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ensure_materialized_for_stack_walk(thread, Parameters::default()).await;
+        let result = ensure_materialized_for_stack_walk(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Thread",
+            "ensureMaterializedForStackWalk",
+            "(Ljava/lang/Object;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Thread.findScopedValueBindings()Ljava/lang/Object;"
-    )]
     async fn test_find_scoped_value_bindings() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = find_scoped_value_bindings(thread, Parameters::default()).await;
+        let result = find_scoped_value_bindings(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Thread",
+            "findScopedValueBindings",
+            "()Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
@@ -411,44 +434,53 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Thread.getStackTrace0()Ljava/lang/Object;"
-    )]
     async fn test_get_stack_trace_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_stack_trace_0(thread, Parameters::default()).await;
+        let result = get_stack_trace_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Thread",
+            "getStackTrace0",
+            "()Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Thread.getThreads()[Ljava/lang/Thread;"
-    )]
     async fn test_get_threads() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_threads(thread, Parameters::default()).await;
+        let result = get_threads(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Thread",
+            "getThreads",
+            "()[Ljava/lang/Thread;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Thread.holdsLock(Ljava/lang/Object;)Z"
-    )]
     async fn test_holds_lock() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = holds_lock(thread, Parameters::default()).await;
+        let result = holds_lock(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Thread",
+            "holdsLock",
+            "(Ljava/lang/Object;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.Thread.interrupt0()V")]
     async fn test_interrupt_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = interrupt_0(thread, Parameters::default()).await;
+        let result = interrupt_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/lang/Thread", "interrupt0", "()V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.Thread.isInterrupted(Z)Z")]
     async fn test_is_interrupted() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_interrupted(thread, Parameters::default()).await;
+        let result = is_interrupted(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/lang/Thread", "isInterrupted", "(Z)Z");
     }
 
     #[tokio::test]
@@ -460,28 +492,34 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.Thread.resume0()V")]
     async fn test_resume_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = resume_0(thread, Parameters::default()).await;
+        let result = resume_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/lang/Thread", "resume0", "()V");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Thread.scopedValueCache()[Ljava/lang/Object;"
-    )]
     async fn test_scoped_value_cache() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = scoped_value_cache(thread, Parameters::default()).await;
+        let result = scoped_value_cache(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Thread",
+            "scopedValueCache",
+            "()[Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Thread.setCurrentThread(Ljava/lang/Thread;)V"
-    )]
     async fn test_set_current_thread() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_current_thread(thread, Parameters::default()).await;
+        let result = set_current_thread(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Thread",
+            "setCurrentThread",
+            "(Ljava/lang/Thread;)V",
+        );
     }
 
     #[tokio::test]
@@ -494,12 +532,15 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Thread.setScopedValueCache([Ljava/lang/Object;)V"
-    )]
     async fn test_set_scoped_value_cache() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_scoped_value_cache(thread, Parameters::default()).await;
+        let result = set_scoped_value_cache(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Thread",
+            "setScopedValueCache",
+            "([Ljava/lang/Object;)V",
+        );
     }
 
     #[tokio::test]
@@ -539,17 +580,22 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.Thread.stop0(Ljava/lang/Object;)V")]
     async fn test_stop_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = stop_0(thread, Parameters::default()).await;
+        let result = stop_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Thread",
+            "stop0",
+            "(Ljava/lang/Object;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.Thread.suspend0()V")]
     async fn test_suspend_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = suspend_0(thread, Parameters::default()).await;
+        let result = suspend_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/lang/Thread", "suspend0", "()V");
     }
 
     #[tokio::test]
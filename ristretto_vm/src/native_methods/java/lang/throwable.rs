@@ -1,5 +1,5 @@
 use crate::java_object::JavaObject;
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Error::InternalError;
@@ -49,12 +49,26 @@ async fn fill_in_stack_trace(
     let vm = thread.vm()?;
     let stack_element_class = thread.class("java/lang/StackTraceElement").await?;
     let stack_elements = ConcurrentVec::new();
-    for frame in thread.frames().await?.iter().rev() {
+    let frames = thread.frames().await?;
+
+    // Skip the fillInStackTrace frames and the constructor frames of the throwable class and its
+    // superclasses so that the stack trace starts where the throwable was created.
+    let mut skipped_frames = 0;
+    for frame in frames.iter().rev() {
         let class = frame.class();
-        let class_name = class.name();
-        if class_name == "java/lang/Throwable" {
-            continue;
+        let method_name = frame.method().name();
+        let fill_in_stack_trace_frame =
+            method_name == "fillInStackTrace" && class.name() == "java/lang/Throwable";
+        let constructor_frame = method_name == "<init>" && throwable.instance_of(class)?;
+        if !fill_in_stack_trace_frame && !constructor_frame {
+            break;
         }
+        skipped_frames += 1;
+    }
+
+    for frame in frames.iter().rev().skip(skipped_frames) {
+        let class = frame.class();
+        let class_name = class.name().replace('/', ".");
         let class_name = class_name.to_object(&vm).await?;
         let stack_element_object = Object::new(stack_element_class.clone())?;
         stack_element_object.set_value("declaringClass", class_name)?;
@@ -97,7 +111,7 @@ async fn get_stack_trace_depth(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Throwable.getStackTraceDepth()I")
+    unsatisfied_link(CLASS_NAME, "getStackTraceDepth", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -105,7 +119,11 @@ async fn get_stack_trace_element(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Throwable.getStackTraceElement(I)Ljava/lang/StackTraceElement;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getStackTraceElement",
+        "(I)Ljava/lang/StackTraceElement;",
+    )
 }
 
 #[cfg(test)]
@@ -113,18 +131,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.Throwable.getStackTraceDepth()I")]
     async fn test_get_stack_trace_depth() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_stack_trace_depth(thread, Parameters::default()).await;
+        let result = get_stack_trace_depth(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Throwable",
+            "getStackTraceDepth",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.Throwable.getStackTraceElement(I)Ljava/lang/StackTraceElement;"
-    )]
     async fn test_get_stack_trace_element() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_stack_trace_element(thread, Parameters::default()).await;
+        let result = get_stack_trace_element(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/Throwable",
+            "getStackTraceElement",
+            "(I)Ljava/lang/StackTraceElement;",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -28,17 +28,17 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn destroy_process(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.UNIXProcess.destroyProcess(IZ)V")
+    unsatisfied_link(CLASS_NAME, "destroyProcess", "(IZ)V")
 }
 
 #[async_recursion(?Send)]
 async fn fork_and_exec(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.UNIXProcess.forkAndExec(I[B[B[BI[BI[B[IZ)I")
+    unsatisfied_link(CLASS_NAME, "forkAndExec", "(I[B[B[BI[BI[B[IZ)I")
 }
 
 #[async_recursion(?Send)]
 async fn init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.UNIXProcess.init()V")
+    unsatisfied_link(CLASS_NAME, "init", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -46,7 +46,7 @@ async fn wait_for_process_exit(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.UNIXProcess.waitForProcessExit(I)I")
+    unsatisfied_link(CLASS_NAME, "waitForProcessExit", "(I)I")
 }
 
 #[cfg(test)]
@@ -54,32 +54,45 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.UNIXProcess.destroyProcess(IZ)V")]
     async fn test_destroy_process() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = destroy_process(thread, Parameters::default()).await;
+        let result = destroy_process(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/UNIXProcess",
+            "destroyProcess",
+            "(IZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.UNIXProcess.forkAndExec(I[B[B[BI[BI[B[IZ)I"
-    )]
     async fn test_fork_and_exec() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fork_and_exec(thread, Parameters::default()).await;
+        let result = fork_and_exec(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/UNIXProcess",
+            "forkAndExec",
+            "(I[B[B[BI[BI[B[IZ)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.UNIXProcess.init()V")]
     async fn test_init() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init(thread, Parameters::default()).await;
+        let result = init(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/lang/UNIXProcess", "init", "()V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.UNIXProcess.waitForProcessExit(I)I")]
     async fn test_wait_for_process_exit() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = wait_for_process_exit(thread, Parameters::default()).await;
+        let result = wait_for_process_exit(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/UNIXProcess",
+            "waitForProcessExit",
+            "(I)I",
+        );
     }
 }
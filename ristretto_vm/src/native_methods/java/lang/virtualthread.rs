@@ -1,4 +1,6 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_17, JAVA_21, JAVA_23};
+use crate::native_methods::registry::{
+    unsatisfied_link, MethodRegistry, JAVA_17, JAVA_21, JAVA_23,
+};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -72,12 +74,12 @@ async fn notify_jvmti_disable_suspend(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.VirtualThread.notifyJvmtiDisableSuspend(Z)V")
+    unsatisfied_link(CLASS_NAME, "notifyJvmtiDisableSuspend", "(Z)V")
 }
 
 #[async_recursion(?Send)]
 async fn notify_jvmti_end(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.lang.VirtualThread.notifyJvmtiEnd()V")
+    unsatisfied_link(CLASS_NAME, "notifyJvmtiEnd", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -85,7 +87,7 @@ async fn notify_jvmti_hide_frames(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.VirtualThread.notifyJvmtiHideFrames(Z)V")
+    unsatisfied_link(CLASS_NAME, "notifyJvmtiHideFrames", "(Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -93,7 +95,7 @@ async fn notify_jvmti_mount(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.VirtualThread.notifyJvmtiMount(Z)V")
+    unsatisfied_link(CLASS_NAME, "notifyJvmtiMount", "(Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -101,7 +103,7 @@ async fn notify_jvmti_mount_begin(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.VirtualThread.notifyJvmtiMountBegin(Z)V")
+    unsatisfied_link(CLASS_NAME, "notifyJvmtiMountBegin", "(Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -109,7 +111,7 @@ async fn notify_jvmti_mount_end(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.VirtualThread.notifyJvmtiMountEnd(Z)V")
+    unsatisfied_link(CLASS_NAME, "notifyJvmtiMountEnd", "(Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -117,7 +119,7 @@ async fn notify_jvmti_start(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.VirtualThread.notifyJvmtiStart()V")
+    unsatisfied_link(CLASS_NAME, "notifyJvmtiStart", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -125,7 +127,7 @@ async fn notify_jvmti_unmount(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.VirtualThread.notifyJvmtiUnmount(Z)V")
+    unsatisfied_link(CLASS_NAME, "notifyJvmtiUnmount", "(Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -133,7 +135,7 @@ async fn notify_jvmti_unmount_begin(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.VirtualThread.notifyJvmtiUnmountBegin(Z)V")
+    unsatisfied_link(CLASS_NAME, "notifyJvmtiUnmountBegin", "(Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -141,7 +143,7 @@ async fn notify_jvmti_unmount_end(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.VirtualThread.notifyJvmtiUnmountEnd(Z)V")
+    unsatisfied_link(CLASS_NAME, "notifyJvmtiUnmountEnd", "(Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -154,87 +156,123 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.VirtualThread.notifyJvmtiDisableSuspend(Z)V"
-    )]
     async fn test_notify_jvmti_disable_suspend() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = notify_jvmti_disable_suspend(thread, Parameters::default()).await;
+        let result = notify_jvmti_disable_suspend(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/VirtualThread",
+            "notifyJvmtiDisableSuspend",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.VirtualThread.notifyJvmtiEnd()V")]
     async fn test_notify_jvmti_end() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = notify_jvmti_end(thread, Parameters::default()).await;
+        let result = notify_jvmti_end(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/VirtualThread",
+            "notifyJvmtiEnd",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.VirtualThread.notifyJvmtiHideFrames(Z)V"
-    )]
     async fn test_notify_jvmti_hide_frames() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = notify_jvmti_hide_frames(thread, Parameters::default()).await;
+        let result = notify_jvmti_hide_frames(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/VirtualThread",
+            "notifyJvmtiHideFrames",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.VirtualThread.notifyJvmtiMount(Z)V")]
     async fn test_notify_jvmti_mount() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = notify_jvmti_mount(thread, Parameters::default()).await;
+        let result = notify_jvmti_mount(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/VirtualThread",
+            "notifyJvmtiMount",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.VirtualThread.notifyJvmtiMountBegin(Z)V"
-    )]
     async fn test_notify_jvmti_mount_begin() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = notify_jvmti_mount_begin(thread, Parameters::default()).await;
+        let result = notify_jvmti_mount_begin(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/VirtualThread",
+            "notifyJvmtiMountBegin",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.VirtualThread.notifyJvmtiMountEnd(Z)V"
-    )]
     async fn test_notify_jvmti_mount_end() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = notify_jvmti_mount_end(thread, Parameters::default()).await;
+        let result = notify_jvmti_mount_end(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/VirtualThread",
+            "notifyJvmtiMountEnd",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.lang.VirtualThread.notifyJvmtiStart()V")]
     async fn test_notify_jvmti_start() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = notify_jvmti_start(thread, Parameters::default()).await;
+        let result = notify_jvmti_start(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/VirtualThread",
+            "notifyJvmtiStart",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.VirtualThread.notifyJvmtiUnmount(Z)V"
-    )]
     async fn test_notify_jvmti_unmount() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = notify_jvmti_unmount(thread, Parameters::default()).await;
+        let result = notify_jvmti_unmount(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/VirtualThread",
+            "notifyJvmtiUnmount",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.VirtualThread.notifyJvmtiUnmountBegin(Z)V"
-    )]
     async fn test_notify_jvmti_unmount_begin() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = notify_jvmti_unmount_begin(thread, Parameters::default()).await;
+        let result = notify_jvmti_unmount_begin(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/VirtualThread",
+            "notifyJvmtiUnmountBegin",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.lang.VirtualThread.notifyJvmtiUnmountEnd(Z)V"
-    )]
     async fn test_notify_jvmti_unmount_end() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = notify_jvmti_unmount_end(thread, Parameters::default()).await;
+        let result = notify_jvmti_unmount_end(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/lang/VirtualThread",
+            "notifyJvmtiUnmountEnd",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
@@ -1,3 +1,4 @@
+#[cfg(feature = "awt")]
 pub(crate) mod awt;
 pub(crate) mod io;
 pub(crate) mod lang;
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -25,7 +25,7 @@ async fn is_reuse_port_available_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.net.AbstractPlainDatagramSocketImpl.isReusePortAvailable0()Z")
+    unsatisfied_link(CLASS_NAME, "isReusePortAvailable0", "()Z")
 }
 
 #[cfg(test)]
@@ -33,11 +33,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.AbstractPlainDatagramSocketImpl.isReusePortAvailable0()Z"
-    )]
     async fn test_is_reuse_port_available_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_reuse_port_available_0(thread, Parameters::default()).await;
+        let result = is_reuse_port_available_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/AbstractPlainDatagramSocketImpl",
+            "isReusePortAvailable0",
+            "()Z",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_17};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_17};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -43,7 +43,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn get_host_by_addr(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.Inet6AddressImpl.getHostByAddr([B)Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getHostByAddr", "([B)Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
@@ -51,12 +51,12 @@ async fn get_local_host_name(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.net.Inet6AddressImpl.getLocalHostName()Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getLocalHostName", "()Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
 async fn is_reachable_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.Inet6AddressImpl.isReachable0([BII[BII)Z")
+    unsatisfied_link(CLASS_NAME, "isReachable0", "([BII[BII)Z")
 }
 
 #[async_recursion(?Send)]
@@ -64,7 +64,11 @@ async fn lookup_all_host_addr(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.net.Inet6AddressImpl.lookupAllHostAddr(Ljava/lang/String;)[Ljava/net/InetAddress;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "lookupAllHostAddr",
+        "(Ljava/lang/String;)[Ljava/net/InetAddress;",
+    )
 }
 
 #[cfg(test)]
@@ -72,38 +76,50 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.Inet6AddressImpl.getHostByAddr([B)Ljava/lang/String;"
-    )]
     async fn test_get_host_by_addr() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_host_by_addr(thread, Parameters::default()).await;
+        let result = get_host_by_addr(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/Inet6AddressImpl",
+            "getHostByAddr",
+            "([B)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.Inet6AddressImpl.getLocalHostName()Ljava/lang/String;"
-    )]
     async fn test_get_local_host_name() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_local_host_name(thread, Parameters::default()).await;
+        let result = get_local_host_name(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/Inet6AddressImpl",
+            "getLocalHostName",
+            "()Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.Inet6AddressImpl.isReachable0([BII[BII)Z"
-    )]
     async fn test_is_reachable_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_reachable_0(thread, Parameters::default()).await;
+        let result = is_reachable_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/Inet6AddressImpl",
+            "isReachable0",
+            "([BII[BII)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.Inet6AddressImpl.lookupAllHostAddr(Ljava/lang/String;)[Ljava/net/InetAddress;"
-    )]
     async fn test_lookup_all_host_addr() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = lookup_all_host_addr(thread, Parameters::default()).await;
+        let result = lookup_all_host_addr(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/Inet6AddressImpl",
+            "lookupAllHostAddr",
+            "(Ljava/lang/String;)[Ljava/net/InetAddress;",
+        );
     }
 }
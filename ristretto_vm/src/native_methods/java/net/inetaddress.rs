@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_21};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_21};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -28,7 +28,7 @@ async fn is_ipv_4_available(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.net.InetAddress.isIPv4Available()Z")
+    unsatisfied_link(CLASS_NAME, "isIPv4Available", "()Z")
 }
 
 #[async_recursion(?Send)]
@@ -36,7 +36,7 @@ async fn is_ipv_6_supported(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.net.InetAddress.isIPv6Supported()Z")
+    unsatisfied_link(CLASS_NAME, "isIPv6Supported", "()Z")
 }
 
 #[cfg(test)]
@@ -52,16 +52,26 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.net.InetAddress.isIPv4Available()Z")]
     async fn test_is_ipv_4_available() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_ipv_4_available(thread, Parameters::default()).await;
+        let result = is_ipv_4_available(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/InetAddress",
+            "isIPv4Available",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.net.InetAddress.isIPv6Supported()Z")]
     async fn test_is_ipv_6_supported() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_ipv_6_supported(thread, Parameters::default()).await;
+        let result = is_ipv_6_supported(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/InetAddress",
+            "isIPv6Supported",
+            "()Z",
+        );
     }
 }
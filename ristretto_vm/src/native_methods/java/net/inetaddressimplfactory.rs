@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -18,7 +18,7 @@ async fn is_ipv_6_supported(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.net.InetAddressImplFactory.isIPv6Supported()Z")
+    unsatisfied_link(CLASS_NAME, "isIPv6Supported", "()Z")
 }
 
 #[cfg(test)]
@@ -26,11 +26,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.InetAddressImplFactory.isIPv6Supported()Z"
-    )]
     async fn test_is_ipv_6_supported() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_ipv_6_supported(thread, Parameters::default()).await;
+        let result = is_ipv_6_supported(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/InetAddressImplFactory",
+            "isIPv6Supported",
+            "()Z",
+        );
     }
 }
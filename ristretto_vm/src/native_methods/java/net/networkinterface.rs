@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_17};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_17};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -72,17 +72,17 @@ async fn bound_inet_address_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.net.NetworkInterface.boundInetAddress0(Ljava/net/InetAddress;)Z")
+    unsatisfied_link(CLASS_NAME, "boundInetAddress0", "(Ljava/net/InetAddress;)Z")
 }
 
 #[async_recursion(?Send)]
 async fn get_all(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.NetworkInterface.getAll()[Ljava/net/NetworkInterface;")
+    unsatisfied_link(CLASS_NAME, "getAll", "()[Ljava/net/NetworkInterface;")
 }
 
 #[async_recursion(?Send)]
 async fn get_by_index_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.NetworkInterface.getByIndex0(I)Ljava/net/NetworkInterface;")
+    unsatisfied_link(CLASS_NAME, "getByIndex0", "(I)Ljava/net/NetworkInterface;")
 }
 
 #[async_recursion(?Send)]
@@ -90,22 +90,30 @@ async fn get_by_inet_address_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.net.NetworkInterface.getByInetAddress0(Ljava/net/InetAddress;)Ljava/net/NetworkInterface;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getByInetAddress0",
+        "(Ljava/net/InetAddress;)Ljava/net/NetworkInterface;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_by_name_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.NetworkInterface.getByName0(Ljava/lang/String;)Ljava/net/NetworkInterface;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getByName0",
+        "(Ljava/lang/String;)Ljava/net/NetworkInterface;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_mtu_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.NetworkInterface.getMTU0(Ljava/lang/String;I)I")
+    unsatisfied_link(CLASS_NAME, "getMTU0", "(Ljava/lang/String;I)I")
 }
 
 #[async_recursion(?Send)]
 async fn get_mac_addr_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.NetworkInterface.getMacAddr0([BLjava/lang/String;I)[B")
+    unsatisfied_link(CLASS_NAME, "getMacAddr0", "([BLjava/lang/String;I)[B")
 }
 
 #[async_recursion(?Send)]
@@ -115,17 +123,17 @@ async fn init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Va
 
 #[async_recursion(?Send)]
 async fn is_loopback_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.NetworkInterface.isLoopback0(Ljava/lang/String;I)Z")
+    unsatisfied_link(CLASS_NAME, "isLoopback0", "(Ljava/lang/String;I)Z")
 }
 
 #[async_recursion(?Send)]
 async fn is_p2p_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.NetworkInterface.isP2P0(Ljava/lang/String;I)Z")
+    unsatisfied_link(CLASS_NAME, "isP2P0", "(Ljava/lang/String;I)Z")
 }
 
 #[async_recursion(?Send)]
 async fn is_up_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.NetworkInterface.isUp0(Ljava/lang/String;I)Z")
+    unsatisfied_link(CLASS_NAME, "isUp0", "(Ljava/lang/String;I)Z")
 }
 
 #[async_recursion(?Send)]
@@ -133,7 +141,7 @@ async fn supports_multicast_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.net.NetworkInterface.supportsMulticast0(Ljava/lang/String;I)Z")
+    unsatisfied_link(CLASS_NAME, "supportsMulticast0", "(Ljava/lang/String;I)Z")
 }
 
 #[cfg(test)]
@@ -141,66 +149,87 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.NetworkInterface.boundInetAddress0(Ljava/net/InetAddress;)Z"
-    )]
     async fn test_bound_inet_address_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = bound_inet_address_0(thread, Parameters::default()).await;
+        let result = bound_inet_address_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/NetworkInterface",
+            "boundInetAddress0",
+            "(Ljava/net/InetAddress;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.NetworkInterface.getAll()[Ljava/net/NetworkInterface;"
-    )]
     async fn test_get_all() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_all(thread, Parameters::default()).await;
+        let result = get_all(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/NetworkInterface",
+            "getAll",
+            "()[Ljava/net/NetworkInterface;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.NetworkInterface.getByIndex0(I)Ljava/net/NetworkInterface;"
-    )]
     async fn test_get_by_index_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_by_index_0(thread, Parameters::default()).await;
+        let result = get_by_index_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/NetworkInterface",
+            "getByIndex0",
+            "(I)Ljava/net/NetworkInterface;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.NetworkInterface.getByInetAddress0(Ljava/net/InetAddress;)Ljava/net/NetworkInterface;"
-    )]
     async fn test_get_by_inet_address_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_by_inet_address_0(thread, Parameters::default()).await;
+        let result = get_by_inet_address_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/NetworkInterface",
+            "getByInetAddress0",
+            "(Ljava/net/InetAddress;)Ljava/net/NetworkInterface;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.NetworkInterface.getByName0(Ljava/lang/String;)Ljava/net/NetworkInterface;"
-    )]
     async fn test_get_by_name_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_by_name_0(thread, Parameters::default()).await;
+        let result = get_by_name_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/NetworkInterface",
+            "getByName0",
+            "(Ljava/lang/String;)Ljava/net/NetworkInterface;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.NetworkInterface.getMTU0(Ljava/lang/String;I)I"
-    )]
     async fn test_get_mtu_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_mtu_0(thread, Parameters::default()).await;
+        let result = get_mtu_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/NetworkInterface",
+            "getMTU0",
+            "(Ljava/lang/String;I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.NetworkInterface.getMacAddr0([BLjava/lang/String;I)[B"
-    )]
     async fn test_get_mac_addr_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_mac_addr_0(thread, Parameters::default()).await;
+        let result = get_mac_addr_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/NetworkInterface",
+            "getMacAddr0",
+            "([BLjava/lang/String;I)[B",
+        );
     }
 
     #[tokio::test]
@@ -212,38 +241,50 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.NetworkInterface.isLoopback0(Ljava/lang/String;I)Z"
-    )]
     async fn test_is_loopback_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_loopback_0(thread, Parameters::default()).await;
+        let result = is_loopback_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/NetworkInterface",
+            "isLoopback0",
+            "(Ljava/lang/String;I)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.NetworkInterface.isP2P0(Ljava/lang/String;I)Z"
-    )]
     async fn test_is_p2p_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_p2p_0(thread, Parameters::default()).await;
+        let result = is_p2p_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/NetworkInterface",
+            "isP2P0",
+            "(Ljava/lang/String;I)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.NetworkInterface.isUp0(Ljava/lang/String;I)Z"
-    )]
     async fn test_is_up_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_up_0(thread, Parameters::default()).await;
+        let result = is_up_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/NetworkInterface",
+            "isUp0",
+            "(Ljava/lang/String;I)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.NetworkInterface.supportsMulticast0(Ljava/lang/String;I)Z"
-    )]
     async fn test_supports_multicast_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = supports_multicast_0(thread, Parameters::default()).await;
+        let result = supports_multicast_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/NetworkInterface",
+            "supportsMulticast0",
+            "(Ljava/lang/String;I)Z",
+        );
     }
 }
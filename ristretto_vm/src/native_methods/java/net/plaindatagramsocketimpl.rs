@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -83,17 +83,17 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn bind_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainDatagramSocketImpl.bind0(ILjava/net/InetAddress;)V")
+    unsatisfied_link(CLASS_NAME, "bind0", "(ILjava/net/InetAddress;)V")
 }
 
 #[async_recursion(?Send)]
 async fn connect_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainDatagramSocketImpl.connect0(Ljava/net/InetAddress;I)V")
+    unsatisfied_link(CLASS_NAME, "connect0", "(Ljava/net/InetAddress;I)V")
 }
 
 #[async_recursion(?Send)]
 async fn data_available(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainDatagramSocketImpl.dataAvailable()I")
+    unsatisfied_link(CLASS_NAME, "dataAvailable", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -101,7 +101,7 @@ async fn datagram_socket_close(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.net.PlainDatagramSocketImpl.datagramSocketClose()V")
+    unsatisfied_link(CLASS_NAME, "datagramSocketClose", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -109,22 +109,22 @@ async fn datagram_socket_create(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.net.PlainDatagramSocketImpl.datagramSocketCreate()V")
+    unsatisfied_link(CLASS_NAME, "datagramSocketCreate", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn disconnect_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainDatagramSocketImpl.disconnect0(I)V")
+    unsatisfied_link(CLASS_NAME, "disconnect0", "(I)V")
 }
 
 #[async_recursion(?Send)]
 async fn get_ttl(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainDatagramSocketImpl.getTTL()B")
+    unsatisfied_link(CLASS_NAME, "getTTL", "()B")
 }
 
 #[async_recursion(?Send)]
 async fn get_time_to_live(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainDatagramSocketImpl.getTimeToLive()I")
+    unsatisfied_link(CLASS_NAME, "getTimeToLive", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -134,54 +134,60 @@ async fn init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Va
 
 #[async_recursion(?Send)]
 async fn join(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!(
-        "java.net.PlainDatagramSocketImpl.join(Ljava/net/InetAddress;Ljava/net/NetworkInterface;)V"
+    unsatisfied_link(
+        CLASS_NAME,
+        "join",
+        "(Ljava/net/InetAddress;Ljava/net/NetworkInterface;)V",
     )
 }
 
 #[async_recursion(?Send)]
 async fn leave(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainDatagramSocketImpl.leave(Ljava/net/InetAddress;Ljava/net/NetworkInterface;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "leave",
+        "(Ljava/net/InetAddress;Ljava/net/NetworkInterface;)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn peek(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainDatagramSocketImpl.peek(Ljava/net/InetAddress;)I")
+    unsatisfied_link(CLASS_NAME, "peek", "(Ljava/net/InetAddress;)I")
 }
 
 #[async_recursion(?Send)]
 async fn peek_data(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainDatagramSocketImpl.peekData(Ljava/net/DatagramPacket;)I")
+    unsatisfied_link(CLASS_NAME, "peekData", "(Ljava/net/DatagramPacket;)I")
 }
 
 #[async_recursion(?Send)]
 async fn receive_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainDatagramSocketImpl.receive0(Ljava/net/DatagramPacket;)V")
+    unsatisfied_link(CLASS_NAME, "receive0", "(Ljava/net/DatagramPacket;)V")
 }
 
 #[async_recursion(?Send)]
 async fn send(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainDatagramSocketImpl.send(Ljava/net/DatagramPacket;)V")
+    unsatisfied_link(CLASS_NAME, "send", "(Ljava/net/DatagramPacket;)V")
 }
 
 #[async_recursion(?Send)]
 async fn send_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainDatagramSocketImpl.send0(Ljava/net/DatagramPacket;)V")
+    unsatisfied_link(CLASS_NAME, "send0", "(Ljava/net/DatagramPacket;)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_ttl(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainDatagramSocketImpl.setTTL(B)V")
+    unsatisfied_link(CLASS_NAME, "setTTL", "(B)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_time_to_live(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainDatagramSocketImpl.setTimeToLive(I)V")
+    unsatisfied_link(CLASS_NAME, "setTimeToLive", "(I)V")
 }
 
 #[async_recursion(?Send)]
 async fn socket_get_option(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainDatagramSocketImpl.socketGetOption(I)Ljava/lang/Object;")
+    unsatisfied_link(CLASS_NAME, "socketGetOption", "(I)Ljava/lang/Object;")
 }
 
 #[async_recursion(?Send)]
@@ -189,7 +195,7 @@ async fn socket_set_option_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.net.PlainDatagramSocketImpl.socketSetOption0(ILjava/lang/Object;)V")
+    unsatisfied_link(CLASS_NAME, "socketSetOption0", "(ILjava/lang/Object;)V")
 }
 
 #[cfg(test)]
@@ -197,73 +203,99 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainDatagramSocketImpl.bind0(ILjava/net/InetAddress;)V"
-    )]
     async fn test_bind_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = bind_0(thread, Parameters::default()).await;
+        let result = bind_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainDatagramSocketImpl",
+            "bind0",
+            "(ILjava/net/InetAddress;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainDatagramSocketImpl.connect0(Ljava/net/InetAddress;I)V"
-    )]
     async fn test_connect_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = connect_0(thread, Parameters::default()).await;
+        let result = connect_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainDatagramSocketImpl",
+            "connect0",
+            "(Ljava/net/InetAddress;I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainDatagramSocketImpl.dataAvailable()I"
-    )]
     async fn test_data_available() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = data_available(thread, Parameters::default()).await;
+        let result = data_available(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainDatagramSocketImpl",
+            "dataAvailable",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainDatagramSocketImpl.datagramSocketClose()V"
-    )]
     async fn test_datagram_socket_close() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = datagram_socket_close(thread, Parameters::default()).await;
+        let result = datagram_socket_close(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainDatagramSocketImpl",
+            "datagramSocketClose",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainDatagramSocketImpl.datagramSocketCreate()V"
-    )]
     async fn test_datagram_socket_create() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = datagram_socket_create(thread, Parameters::default()).await;
+        let result = datagram_socket_create(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainDatagramSocketImpl",
+            "datagramSocketCreate",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainDatagramSocketImpl.disconnect0(I)V"
-    )]
     async fn test_disconnect_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = disconnect_0(thread, Parameters::default()).await;
+        let result = disconnect_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainDatagramSocketImpl",
+            "disconnect0",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.net.PlainDatagramSocketImpl.getTTL()B")]
     async fn test_get_ttl() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_ttl(thread, Parameters::default()).await;
+        let result = get_ttl(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainDatagramSocketImpl",
+            "getTTL",
+            "()B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainDatagramSocketImpl.getTimeToLive()I"
-    )]
     async fn test_get_time_to_live() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_time_to_live(thread, Parameters::default()).await;
+        let result = get_time_to_live(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainDatagramSocketImpl",
+            "getTimeToLive",
+            "()I",
+        );
     }
 
     #[tokio::test]
@@ -275,99 +307,134 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainDatagramSocketImpl.join(Ljava/net/InetAddress;Ljava/net/NetworkInterface;)V"
-    )]
     async fn test_join() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = join(thread, Parameters::default()).await;
+        let result = join(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainDatagramSocketImpl",
+            "join",
+            "(Ljava/net/InetAddress;Ljava/net/NetworkInterface;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainDatagramSocketImpl.leave(Ljava/net/InetAddress;Ljava/net/NetworkInterface;)V"
-    )]
     async fn test_leave() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = leave(thread, Parameters::default()).await;
+        let result = leave(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainDatagramSocketImpl",
+            "leave",
+            "(Ljava/net/InetAddress;Ljava/net/NetworkInterface;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainDatagramSocketImpl.peek(Ljava/net/InetAddress;)I"
-    )]
     async fn test_peek() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = peek(thread, Parameters::default()).await;
+        let result = peek(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainDatagramSocketImpl",
+            "peek",
+            "(Ljava/net/InetAddress;)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainDatagramSocketImpl.peekData(Ljava/net/DatagramPacket;)I"
-    )]
     async fn test_peek_data() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = peek_data(thread, Parameters::default()).await;
+        let result = peek_data(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainDatagramSocketImpl",
+            "peekData",
+            "(Ljava/net/DatagramPacket;)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainDatagramSocketImpl.receive0(Ljava/net/DatagramPacket;)V"
-    )]
     async fn test_receive_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = receive_0(thread, Parameters::default()).await;
+        let result = receive_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainDatagramSocketImpl",
+            "receive0",
+            "(Ljava/net/DatagramPacket;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainDatagramSocketImpl.send(Ljava/net/DatagramPacket;)V"
-    )]
     async fn test_send() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = send(thread, Parameters::default()).await;
+        let result = send(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainDatagramSocketImpl",
+            "send",
+            "(Ljava/net/DatagramPacket;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainDatagramSocketImpl.send0(Ljava/net/DatagramPacket;)V"
-    )]
     async fn test_send_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = send_0(thread, Parameters::default()).await;
+        let result = send_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainDatagramSocketImpl",
+            "send0",
+            "(Ljava/net/DatagramPacket;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.net.PlainDatagramSocketImpl.setTTL(B)V")]
     async fn test_set_ttl() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_ttl(thread, Parameters::default()).await;
+        let result = set_ttl(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainDatagramSocketImpl",
+            "setTTL",
+            "(B)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainDatagramSocketImpl.setTimeToLive(I)V"
-    )]
     async fn test_set_time_to_live() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_time_to_live(thread, Parameters::default()).await;
+        let result = set_time_to_live(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainDatagramSocketImpl",
+            "setTimeToLive",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainDatagramSocketImpl.socketGetOption(I)Ljava/lang/Object;"
-    )]
     async fn test_socket_get_option() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = socket_get_option(thread, Parameters::default()).await;
+        let result = socket_get_option(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainDatagramSocketImpl",
+            "socketGetOption",
+            "(I)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainDatagramSocketImpl.socketSetOption0(ILjava/lang/Object;)V"
-    )]
     async fn test_socket_set_option_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = socket_set_option_0(thread, Parameters::default()).await;
+        let result = socket_set_option_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainDatagramSocketImpl",
+            "socketSetOption0",
+            "(ILjava/lang/Object;)V",
+        );
     }
 }
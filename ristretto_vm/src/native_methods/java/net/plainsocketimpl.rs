@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -61,47 +61,47 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn init_proto(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainSocketImpl.initProto()V")
+    unsatisfied_link(CLASS_NAME, "initProto", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn socket_accept(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainSocketImpl.socketAccept(Ljava/net/SocketImpl;)V")
+    unsatisfied_link(CLASS_NAME, "socketAccept", "(Ljava/net/SocketImpl;)V")
 }
 
 #[async_recursion(?Send)]
 async fn socket_available(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainSocketImpl.socketAvailable()I")
+    unsatisfied_link(CLASS_NAME, "socketAvailable", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn socket_bind(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainSocketImpl.socketBind(Ljava/net/InetAddress;I)V")
+    unsatisfied_link(CLASS_NAME, "socketBind", "(Ljava/net/InetAddress;I)V")
 }
 
 #[async_recursion(?Send)]
 async fn socket_close_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainSocketImpl.socketClose0(Z)V")
+    unsatisfied_link(CLASS_NAME, "socketClose0", "(Z)V")
 }
 
 #[async_recursion(?Send)]
 async fn socket_connect(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainSocketImpl.socketConnect(Ljava/net/InetAddress;II)V")
+    unsatisfied_link(CLASS_NAME, "socketConnect", "(Ljava/net/InetAddress;II)V")
 }
 
 #[async_recursion(?Send)]
 async fn socket_create(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainSocketImpl.socketCreate(Z)V")
+    unsatisfied_link(CLASS_NAME, "socketCreate", "(Z)V")
 }
 
 #[async_recursion(?Send)]
 async fn socket_get_option(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainSocketImpl.socketGetOption(ILjava/lang/Object;)I")
+    unsatisfied_link(CLASS_NAME, "socketGetOption", "(ILjava/lang/Object;)I")
 }
 
 #[async_recursion(?Send)]
 async fn socket_listen(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainSocketImpl.socketListen(I)V")
+    unsatisfied_link(CLASS_NAME, "socketListen", "(I)V")
 }
 
 #[async_recursion(?Send)]
@@ -109,7 +109,7 @@ async fn socket_send_urgent_data(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.net.PlainSocketImpl.socketSendUrgentData(I)V")
+    unsatisfied_link(CLASS_NAME, "socketSendUrgentData", "(I)V")
 }
 
 #[async_recursion(?Send)]
@@ -117,12 +117,12 @@ async fn socket_set_option_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.net.PlainSocketImpl.socketSetOption0(IZLjava/lang/Object;)V")
+    unsatisfied_link(CLASS_NAME, "socketSetOption0", "(IZLjava/lang/Object;)V")
 }
 
 #[async_recursion(?Send)]
 async fn socket_shutdown(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.PlainSocketImpl.socketShutdown(I)V")
+    unsatisfied_link(CLASS_NAME, "socketShutdown", "(I)V")
 }
 
 #[cfg(test)]
@@ -130,98 +130,146 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.net.PlainSocketImpl.initProto()V")]
     async fn test_init_proto() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_proto(thread, Parameters::default()).await;
+        let result = init_proto(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainSocketImpl",
+            "initProto",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainSocketImpl.socketAccept(Ljava/net/SocketImpl;)V"
-    )]
     async fn test_socket_accept() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = socket_accept(thread, Parameters::default()).await;
+        let result = socket_accept(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainSocketImpl",
+            "socketAccept",
+            "(Ljava/net/SocketImpl;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.net.PlainSocketImpl.socketAvailable()I")]
     async fn test_socket_available() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = socket_available(thread, Parameters::default()).await;
+        let result = socket_available(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainSocketImpl",
+            "socketAvailable",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainSocketImpl.socketBind(Ljava/net/InetAddress;I)V"
-    )]
     async fn test_socket_bind() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = socket_bind(thread, Parameters::default()).await;
+        let result = socket_bind(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainSocketImpl",
+            "socketBind",
+            "(Ljava/net/InetAddress;I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.net.PlainSocketImpl.socketClose0(Z)V")]
     async fn test_socket_close_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = socket_close_0(thread, Parameters::default()).await;
+        let result = socket_close_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainSocketImpl",
+            "socketClose0",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainSocketImpl.socketConnect(Ljava/net/InetAddress;II)V"
-    )]
     async fn test_socket_connect() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = socket_connect(thread, Parameters::default()).await;
+        let result = socket_connect(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainSocketImpl",
+            "socketConnect",
+            "(Ljava/net/InetAddress;II)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.net.PlainSocketImpl.socketCreate(Z)V")]
     async fn test_socket_create() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = socket_create(thread, Parameters::default()).await;
+        let result = socket_create(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainSocketImpl",
+            "socketCreate",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainSocketImpl.socketGetOption(ILjava/lang/Object;)I"
-    )]
     async fn test_socket_get_option() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = socket_get_option(thread, Parameters::default()).await;
+        let result = socket_get_option(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainSocketImpl",
+            "socketGetOption",
+            "(ILjava/lang/Object;)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.net.PlainSocketImpl.socketListen(I)V")]
     async fn test_socket_listen() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = socket_listen(thread, Parameters::default()).await;
+        let result = socket_listen(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainSocketImpl",
+            "socketListen",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainSocketImpl.socketSendUrgentData(I)V"
-    )]
     async fn test_socket_send_urgent_data() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = socket_send_urgent_data(thread, Parameters::default()).await;
+        let result = socket_send_urgent_data(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainSocketImpl",
+            "socketSendUrgentData",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.PlainSocketImpl.socketSetOption0(IZLjava/lang/Object;)V"
-    )]
     async fn test_socket_set_option_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = socket_set_option_0(thread, Parameters::default()).await;
+        let result = socket_set_option_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainSocketImpl",
+            "socketSetOption0",
+            "(IZLjava/lang/Object;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.net.PlainSocketImpl.socketShutdown(I)V")]
     async fn test_socket_shutdown() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = socket_shutdown(thread, Parameters::default()).await;
+        let result = socket_shutdown(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/PlainSocketImpl",
+            "socketShutdown",
+            "(I)V",
+        );
     }
 }
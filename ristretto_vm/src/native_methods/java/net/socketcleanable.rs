@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -17,7 +17,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn cleanup_close_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.SocketCleanable.cleanupClose0(I)V")
+    unsatisfied_link(CLASS_NAME, "cleanupClose0", "(I)V")
 }
 
 #[cfg(test)]
@@ -25,9 +25,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.net.SocketCleanable.cleanupClose0(I)V")]
     async fn test_cleanup_close_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = cleanup_close_0(thread, Parameters::default()).await;
+        let result = cleanup_close_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/SocketCleanable",
+            "cleanupClose0",
+            "(I)V",
+        );
     }
 }
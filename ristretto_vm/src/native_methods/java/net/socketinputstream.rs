@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -26,7 +26,11 @@ async fn init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Va
 
 #[async_recursion(?Send)]
 async fn socket_read_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.SocketInputStream.socketRead0(Ljava/io/FileDescriptor;[BIII)I")
+    unsatisfied_link(
+        CLASS_NAME,
+        "socketRead0",
+        "(Ljava/io/FileDescriptor;[BIII)I",
+    )
 }
 
 #[cfg(test)]
@@ -42,11 +46,14 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.SocketInputStream.socketRead0(Ljava/io/FileDescriptor;[BIII)I"
-    )]
     async fn test_socket_read_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = socket_read_0(thread, Parameters::default()).await;
+        let result = socket_read_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/SocketInputStream",
+            "socketRead0",
+            "(Ljava/io/FileDescriptor;[BIII)I",
+        );
     }
 }
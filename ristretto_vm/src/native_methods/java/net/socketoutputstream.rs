@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -26,7 +26,11 @@ async fn init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Va
 
 #[async_recursion(?Send)]
 async fn socket_write_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.net.SocketOutputStream.socketWrite0(Ljava/io/FileDescriptor;[BII)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "socketWrite0",
+        "(Ljava/io/FileDescriptor;[BII)V",
+    )
 }
 
 #[cfg(test)]
@@ -42,11 +46,14 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.net.SocketOutputStream.socketWrite0(Ljava/io/FileDescriptor;[BII)V"
-    )]
     async fn test_socket_write_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = socket_write_0(thread, Parameters::default()).await;
+        let result = socket_write_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/net/SocketOutputStream",
+            "socketWrite0",
+            "(Ljava/io/FileDescriptor;[BII)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,7 +23,11 @@ async fn copy_swap_memory_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.nio.Bits.copySwapMemory0(Ljava/lang/Object;JLjava/lang/Object;JJJ)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "copySwapMemory0",
+        "(Ljava/lang/Object;JLjava/lang/Object;JJJ)V",
+    )
 }
 
 #[cfg(test)]
@@ -31,11 +35,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.nio.Bits.copySwapMemory0(Ljava/lang/Object;JLjava/lang/Object;JJJ)V"
-    )]
     async fn test_copy_swap_memory_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = copy_swap_memory_0(thread, Parameters::default()).await;
+        let result = copy_swap_memory_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/nio/Bits",
+            "copySwapMemory0",
+            "(Ljava/lang/Object;JLjava/lang/Object;JJJ)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -22,17 +22,17 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn force_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.nio.MappedByteBuffer.force0(Ljava/io/FileDescriptor;JJ)V")
+    unsatisfied_link(CLASS_NAME, "force0", "(Ljava/io/FileDescriptor;JJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn is_loaded_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.nio.MappedByteBuffer.isLoaded0(JJI)Z")
+    unsatisfied_link(CLASS_NAME, "isLoaded0", "(JJI)Z")
 }
 
 #[async_recursion(?Send)]
 async fn load_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.nio.MappedByteBuffer.load0(JJ)V")
+    unsatisfied_link(CLASS_NAME, "load0", "(JJ)V")
 }
 
 #[cfg(test)]
@@ -40,25 +40,33 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.nio.MappedByteBuffer.force0(Ljava/io/FileDescriptor;JJ)V"
-    )]
     async fn test_force_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = force_0(thread, Parameters::default()).await;
+        let result = force_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/nio/MappedByteBuffer",
+            "force0",
+            "(Ljava/io/FileDescriptor;JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.nio.MappedByteBuffer.isLoaded0(JJI)Z")]
     async fn test_is_loaded_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_loaded_0(thread, Parameters::default()).await;
+        let result = is_loaded_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/nio/MappedByteBuffer",
+            "isLoaded0",
+            "(JJI)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.nio.MappedByteBuffer.load0(JJ)V")]
     async fn test_load_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = load_0(thread, Parameters::default()).await;
+        let result = load_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/nio/MappedByteBuffer", "load0", "(JJ)V");
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,22 +23,22 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn force_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.nio.MappedMemoryUtils.force0(Ljava/io/FileDescriptor;JJ)V")
+    unsatisfied_link(CLASS_NAME, "force0", "(Ljava/io/FileDescriptor;JJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn is_loaded_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.nio.MappedMemoryUtils.isLoaded0(JJJ)Z")
+    unsatisfied_link(CLASS_NAME, "isLoaded0", "(JJJ)Z")
 }
 
 #[async_recursion(?Send)]
 async fn load_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.nio.MappedMemoryUtils.load0(JJ)V")
+    unsatisfied_link(CLASS_NAME, "load0", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn unload_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.nio.MappedMemoryUtils.unload0(JJ)V")
+    unsatisfied_link(CLASS_NAME, "unload0", "(JJ)V")
 }
 
 #[cfg(test)]
@@ -46,32 +46,50 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.nio.MappedMemoryUtils.force0(Ljava/io/FileDescriptor;JJ)V"
-    )]
     async fn test_force_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = force_0(thread, Parameters::default()).await;
+        let result = force_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/nio/MappedMemoryUtils",
+            "force0",
+            "(Ljava/io/FileDescriptor;JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.nio.MappedMemoryUtils.isLoaded0(JJJ)Z")]
     async fn test_is_loaded_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_loaded_0(thread, Parameters::default()).await;
+        let result = is_loaded_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/nio/MappedMemoryUtils",
+            "isLoaded0",
+            "(JJJ)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.nio.MappedMemoryUtils.load0(JJ)V")]
     async fn test_load_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = load_0(thread, Parameters::default()).await;
+        let result = load_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/nio/MappedMemoryUtils",
+            "load0",
+            "(JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.nio.MappedMemoryUtils.unload0(JJ)V")]
     async fn test_unload_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = unload_0(thread, Parameters::default()).await;
+        let result = unload_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/nio/MappedMemoryUtils",
+            "unload0",
+            "(JJ)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -114,7 +114,11 @@ async fn get_inherited_access_control_context(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.security.AccessController.getInheritedAccessControlContext()Ljava/security/AccessControlContext;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getInheritedAccessControlContext",
+        "()Ljava/security/AccessControlContext;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -122,7 +126,11 @@ async fn get_protection_domain(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.security.AccessController.getProtectionDomain(Ljava/lang/Class;)Ljava/security/ProtectionDomain;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getProtectionDomain",
+        "(Ljava/lang/Class;)Ljava/security/ProtectionDomain;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -146,21 +154,27 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.security.AccessController.getInheritedAccessControlContext()Ljava/security/AccessControlContext;"
-    )]
     async fn test_get_inherited_access_control_context() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_inherited_access_control_context(thread, Parameters::default()).await;
+        let result = get_inherited_access_control_context(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/security/AccessController",
+            "getInheritedAccessControlContext",
+            "()Ljava/security/AccessControlContext;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.security.AccessController.getProtectionDomain(Ljava/lang/Class;)Ljava/security/ProtectionDomain;"
-    )]
     async fn test_get_protection_domain() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_protection_domain(thread, Parameters::default()).await;
+        let result = get_protection_domain(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/security/AccessController",
+            "getProtectionDomain",
+            "(Ljava/lang/Class;)Ljava/security/ProtectionDomain;",
+        );
     }
 
     #[tokio::test]
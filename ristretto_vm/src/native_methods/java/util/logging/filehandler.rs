@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -15,7 +15,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn is_set_uid(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.logging.FileHandler.isSetUID()Z")
+    unsatisfied_link(CLASS_NAME, "isSetUID", "()Z")
 }
 
 #[cfg(test)]
@@ -23,9 +23,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.logging.FileHandler.isSetUID()Z")]
     async fn test_is_set_uid() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_set_uid(thread, Parameters::default()).await;
+        let result = is_set_uid(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/logging/FileHandler",
+            "isSetUID",
+            "()Z",
+        );
     }
 }
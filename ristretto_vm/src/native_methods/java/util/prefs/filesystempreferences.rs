@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -22,17 +22,17 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn chmod(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.prefs.FileSystemPreferences.chmod(Ljava/lang/String;I)I")
+    unsatisfied_link(CLASS_NAME, "chmod", "(Ljava/lang/String;I)I")
 }
 
 #[async_recursion(?Send)]
 async fn lock_file_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.prefs.FileSystemPreferences.lockFile0(Ljava/lang/String;IZ)[I")
+    unsatisfied_link(CLASS_NAME, "lockFile0", "(Ljava/lang/String;IZ)[I")
 }
 
 #[async_recursion(?Send)]
 async fn unlock_file_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.prefs.FileSystemPreferences.unlockFile0(I)I")
+    unsatisfied_link(CLASS_NAME, "unlockFile0", "(I)I")
 }
 
 #[cfg(test)]
@@ -40,29 +40,38 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.prefs.FileSystemPreferences.chmod(Ljava/lang/String;I)I"
-    )]
     async fn test_chmod() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = chmod(thread, Parameters::default()).await;
+        let result = chmod(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/prefs/FileSystemPreferences",
+            "chmod",
+            "(Ljava/lang/String;I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.prefs.FileSystemPreferences.lockFile0(Ljava/lang/String;IZ)[I"
-    )]
     async fn test_lock_file_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = lock_file_0(thread, Parameters::default()).await;
+        let result = lock_file_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/prefs/FileSystemPreferences",
+            "lockFile0",
+            "(Ljava/lang/String;IZ)[I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.prefs.FileSystemPreferences.unlockFile0(I)I"
-    )]
     async fn test_unlock_file_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = unlock_file_0(thread, Parameters::default()).await;
+        let result = unlock_file_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/prefs/FileSystemPreferences",
+            "unlockFile0",
+            "(I)I",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -78,37 +78,49 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn add_child_to_node(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.prefs.MacOSXPreferencesFile.addChildToNode(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;JJ)Z")
+    unsatisfied_link(
+        CLASS_NAME,
+        "addChildToNode",
+        "(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;JJ)Z",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn add_key_to_node(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.prefs.MacOSXPreferencesFile.addKeyToNode(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;JJ)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "addKeyToNode",
+        "(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;JJ)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn add_node(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.prefs.MacOSXPreferencesFile.addNode(Ljava/lang/String;Ljava/lang/String;JJ)Z")
+    unsatisfied_link(
+        CLASS_NAME,
+        "addNode",
+        "(Ljava/lang/String;Ljava/lang/String;JJ)Z",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn any_host(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.prefs.MacOSXPreferencesFile.anyHost()J")
+    unsatisfied_link(CLASS_NAME, "anyHost", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn any_user(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.prefs.MacOSXPreferencesFile.anyUser()J")
+    unsatisfied_link(CLASS_NAME, "anyUser", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn current_host(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.prefs.MacOSXPreferencesFile.currentHost()J")
+    unsatisfied_link(CLASS_NAME, "currentHost", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn current_user(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.prefs.MacOSXPreferencesFile.currentUser()J")
+    unsatisfied_link(CLASS_NAME, "currentUser", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -116,17 +128,29 @@ async fn get_children_for_node(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.util.prefs.MacOSXPreferencesFile.getChildrenForNode(Ljava/lang/String;Ljava/lang/String;JJ)[Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getChildrenForNode",
+        "(Ljava/lang/String;Ljava/lang/String;JJ)[Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_key_from_node(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.prefs.MacOSXPreferencesFile.getKeyFromNode(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;JJ)Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getKeyFromNode",
+        "(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;JJ)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_keys_for_node(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.prefs.MacOSXPreferencesFile.getKeysForNode(Ljava/lang/String;Ljava/lang/String;JJ)[Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getKeysForNode",
+        "(Ljava/lang/String;Ljava/lang/String;JJ)[Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -134,7 +158,11 @@ async fn remove_child_from_node(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.util.prefs.MacOSXPreferencesFile.removeChildFromNode(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;JJ)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "removeChildFromNode",
+        "(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;JJ)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -142,19 +170,25 @@ async fn remove_key_from_node(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.util.prefs.MacOSXPreferencesFile.removeKeyFromNode(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;JJ)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "removeKeyFromNode",
+        "(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;JJ)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn remove_node(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!(
-        "java.util.prefs.MacOSXPreferencesFile.removeNode(Ljava/lang/String;Ljava/lang/String;JJ)V"
+    unsatisfied_link(
+        CLASS_NAME,
+        "removeNode",
+        "(Ljava/lang/String;Ljava/lang/String;JJ)V",
     )
 }
 
 #[async_recursion(?Send)]
 async fn synchronize(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.prefs.MacOSXPreferencesFile.synchronize(Ljava/lang/String;JJ)Z")
+    unsatisfied_link(CLASS_NAME, "synchronize", "(Ljava/lang/String;JJ)Z")
 }
 
 #[cfg(test)]
@@ -162,128 +196,170 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.prefs.MacOSXPreferencesFile.addChildToNode(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;JJ)Z"
-    )]
     async fn test_add_child_to_node() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = add_child_to_node(thread, Parameters::default()).await;
+        let result = add_child_to_node(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/prefs/MacOSXPreferencesFile",
+            "addChildToNode",
+            "(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;JJ)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.prefs.MacOSXPreferencesFile.addKeyToNode(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;JJ)V"
-    )]
     async fn test_add_key_to_node() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = add_key_to_node(thread, Parameters::default()).await;
+        let result = add_key_to_node(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/prefs/MacOSXPreferencesFile",
+            "addKeyToNode",
+            "(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.prefs.MacOSXPreferencesFile.addNode(Ljava/lang/String;Ljava/lang/String;JJ)Z"
-    )]
     async fn test_add_node() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = add_node(thread, Parameters::default()).await;
+        let result = add_node(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/prefs/MacOSXPreferencesFile",
+            "addNode",
+            "(Ljava/lang/String;Ljava/lang/String;JJ)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.prefs.MacOSXPreferencesFile.anyHost()J"
-    )]
     async fn test_any_host() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = any_host(thread, Parameters::default()).await;
+        let result = any_host(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/prefs/MacOSXPreferencesFile",
+            "anyHost",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.prefs.MacOSXPreferencesFile.anyUser()J"
-    )]
     async fn test_any_user() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = any_user(thread, Parameters::default()).await;
+        let result = any_user(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/prefs/MacOSXPreferencesFile",
+            "anyUser",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.prefs.MacOSXPreferencesFile.currentHost()J"
-    )]
     async fn test_current_host() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = current_host(thread, Parameters::default()).await;
+        let result = current_host(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/prefs/MacOSXPreferencesFile",
+            "currentHost",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.prefs.MacOSXPreferencesFile.currentUser()J"
-    )]
     async fn test_current_user() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = current_user(thread, Parameters::default()).await;
+        let result = current_user(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/prefs/MacOSXPreferencesFile",
+            "currentUser",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.prefs.MacOSXPreferencesFile.getChildrenForNode(Ljava/lang/String;Ljava/lang/String;JJ)[Ljava/lang/String;"
-    )]
     async fn test_get_children_for_node() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_children_for_node(thread, Parameters::default()).await;
+        let result = get_children_for_node(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/prefs/MacOSXPreferencesFile",
+            "getChildrenForNode",
+            "(Ljava/lang/String;Ljava/lang/String;JJ)[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.prefs.MacOSXPreferencesFile.getKeyFromNode(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;JJ)Ljava/lang/String;"
-    )]
     async fn test_get_key_from_node() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_key_from_node(thread, Parameters::default()).await;
+        let result = get_key_from_node(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/prefs/MacOSXPreferencesFile",
+            "getKeyFromNode",
+            "(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;JJ)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.prefs.MacOSXPreferencesFile.getKeysForNode(Ljava/lang/String;Ljava/lang/String;JJ)[Ljava/lang/String;"
-    )]
     async fn test_get_keys_for_node() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_keys_for_node(thread, Parameters::default()).await;
+        let result = get_keys_for_node(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/prefs/MacOSXPreferencesFile",
+            "getKeysForNode",
+            "(Ljava/lang/String;Ljava/lang/String;JJ)[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.prefs.MacOSXPreferencesFile.removeChildFromNode(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;JJ)V"
-    )]
     async fn test_remove_child_from_node() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = remove_child_from_node(thread, Parameters::default()).await;
+        let result = remove_child_from_node(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/prefs/MacOSXPreferencesFile",
+            "removeChildFromNode",
+            "(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.prefs.MacOSXPreferencesFile.removeKeyFromNode(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;JJ)V"
-    )]
     async fn test_remove_key_from_node() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = remove_key_from_node(thread, Parameters::default()).await;
+        let result = remove_key_from_node(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/prefs/MacOSXPreferencesFile",
+            "removeKeyFromNode",
+            "(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.prefs.MacOSXPreferencesFile.removeNode(Ljava/lang/String;Ljava/lang/String;JJ)V"
-    )]
     async fn test_remove_node() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = remove_node(thread, Parameters::default()).await;
+        let result = remove_node(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/prefs/MacOSXPreferencesFile",
+            "removeNode",
+            "(Ljava/lang/String;Ljava/lang/String;JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.prefs.MacOSXPreferencesFile.synchronize(Ljava/lang/String;JJ)Z"
-    )]
     async fn test_synchronize() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = synchronize(thread, Parameters::default()).await;
+        let result = synchronize(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/prefs/MacOSXPreferencesFile",
+            "synchronize",
+            "(Ljava/lang/String;JJ)Z",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -29,7 +29,7 @@ async fn get_system_gmt_offset_id(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.util.TimeZone.getSystemGMTOffsetID()Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getSystemGMTOffsetID", "()Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
@@ -37,7 +37,11 @@ async fn get_system_time_zone_id(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.util.TimeZone.getSystemTimeZoneID(Ljava/lang/String;)Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getSystemTimeZoneID",
+        "(Ljava/lang/String;)Ljava/lang/String;",
+    )
 }
 
 #[cfg(test)]
@@ -45,20 +49,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.TimeZone.getSystemGMTOffsetID()Ljava/lang/String;"
-    )]
     async fn test_get_system_gmt_offset_id() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_system_gmt_offset_id(thread, Parameters::default()).await;
+        let result = get_system_gmt_offset_id(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/TimeZone",
+            "getSystemGMTOffsetID",
+            "()Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.TimeZone.getSystemTimeZoneID(Ljava/lang/String;)Ljava/lang/String;"
-    )]
     async fn test_get_system_time_zone_id() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_system_time_zone_id(thread, Parameters::default()).await;
+        let result = get_system_time_zone_id(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/TimeZone",
+            "getSystemTimeZoneID",
+            "(Ljava/lang/String;)Ljava/lang/String;",
+        );
     }
 }
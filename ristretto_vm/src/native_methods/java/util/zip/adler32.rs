@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -22,7 +22,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn update(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.Adler32.update(II)I")
+    unsatisfied_link(CLASS_NAME, "update", "(II)I")
 }
 
 #[async_recursion(?Send)]
@@ -30,12 +30,12 @@ async fn update_byte_buffer(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.util.zip.Adler32.updateByteBuffer(IJII)I")
+    unsatisfied_link(CLASS_NAME, "updateByteBuffer", "(IJII)I")
 }
 
 #[async_recursion(?Send)]
 async fn update_bytes(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.Adler32.updateBytes(I[BII)I")
+    unsatisfied_link(CLASS_NAME, "updateBytes", "(I[BII)I")
 }
 
 #[cfg(test)]
@@ -43,23 +43,33 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.Adler32.update(II)I")]
     async fn test_update() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = update(thread, Parameters::default()).await;
+        let result = update(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/util/zip/Adler32", "update", "(II)I");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.Adler32.updateByteBuffer(IJII)I")]
     async fn test_update_byte_buffer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = update_byte_buffer(thread, Parameters::default()).await;
+        let result = update_byte_buffer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/Adler32",
+            "updateByteBuffer",
+            "(IJII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.Adler32.updateBytes(I[BII)I")]
     async fn test_update_bytes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = update_bytes(thread, Parameters::default()).await;
+        let result = update_bytes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/Adler32",
+            "updateBytes",
+            "(I[BII)I",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -33,7 +33,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn update(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.CRC32.update(II)I")
+    unsatisfied_link(CLASS_NAME, "update", "(II)I")
 }
 
 #[async_recursion(?Send)]
@@ -46,7 +46,7 @@ async fn update_byte_buffer_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.util.zip.CRC32.updateByteBuffer0(IJII)I")
+    unsatisfied_link(CLASS_NAME, "updateByteBuffer0", "(IJII)I")
 }
 
 #[async_recursion(?Send)]
@@ -56,7 +56,7 @@ async fn update_bytes(thread: Arc<Thread>, parameters: Parameters) -> Result<Opt
 
 #[async_recursion(?Send)]
 async fn update_bytes_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.CRC32.updateBytes0(I[BII)I")
+    unsatisfied_link(CLASS_NAME, "updateBytes0", "(I[BII)I")
 }
 
 #[cfg(test)]
@@ -64,37 +64,57 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.CRC32.update(II)I")]
     async fn test_update() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = update(thread, Parameters::default()).await;
+        let result = update(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/util/zip/CRC32", "update", "(II)I");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.CRC32.updateByteBuffer0(IJII)I")]
     async fn test_update_byte_buffer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = update_byte_buffer(thread, Parameters::default()).await;
+        let result = update_byte_buffer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/CRC32",
+            "updateByteBuffer0",
+            "(IJII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.CRC32.updateByteBuffer0(IJII)I")]
     async fn test_update_byte_buffer_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = update_byte_buffer_0(thread, Parameters::default()).await;
+        let result = update_byte_buffer_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/CRC32",
+            "updateByteBuffer0",
+            "(IJII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.CRC32.updateBytes0(I[BII)I")]
     async fn test_update_bytes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = update_bytes(thread, Parameters::default()).await;
+        let result = update_bytes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/CRC32",
+            "updateBytes0",
+            "(I[BII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.CRC32.updateBytes0(I[BII)I")]
     async fn test_update_bytes_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = update_bytes_0(thread, Parameters::default()).await;
+        let result = update_bytes_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/CRC32",
+            "updateBytes0",
+            "(I[BII)I",
+        );
     }
 }
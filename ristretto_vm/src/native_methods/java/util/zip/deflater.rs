@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -55,7 +55,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn deflate_bytes(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.Deflater.deflateBytes(J[BIII)I")
+    unsatisfied_link(CLASS_NAME, "deflateBytes", "(J[BIII)I")
 }
 
 #[async_recursion(?Send)]
@@ -63,7 +63,7 @@ async fn deflate_buffer_buffer(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.util.zip.Deflater.deflateBufferBuffer(JJIJIII)J")
+    unsatisfied_link(CLASS_NAME, "deflateBufferBuffer", "(JJIJIII)J")
 }
 
 #[async_recursion(?Send)]
@@ -71,7 +71,7 @@ async fn deflate_buffer_bytes(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.util.zip.Deflater.deflateBufferBytes(JJI[BIIII)J")
+    unsatisfied_link(CLASS_NAME, "deflateBufferBytes", "(JJI[BIIII)J")
 }
 
 #[async_recursion(?Send)]
@@ -79,7 +79,7 @@ async fn deflate_bytes_buffer(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.util.zip.Deflater.deflateBytesBuffer(J[BIIJIII)J")
+    unsatisfied_link(CLASS_NAME, "deflateBytesBuffer", "(J[BIIJIII)J")
 }
 
 #[async_recursion(?Send)]
@@ -87,22 +87,22 @@ async fn deflate_bytes_bytes(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.util.zip.Deflater.deflateBytesBytes(J[BII[BIIII)J")
+    unsatisfied_link(CLASS_NAME, "deflateBytesBytes", "(J[BII[BIIII)J")
 }
 
 #[async_recursion(?Send)]
 async fn end(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.Deflater.end(J)V")
+    unsatisfied_link(CLASS_NAME, "end", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn get_adler(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.Deflater.getAdler(J)I")
+    unsatisfied_link(CLASS_NAME, "getAdler", "(J)I")
 }
 
 #[async_recursion(?Send)]
 async fn init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.Deflater.init(IIZ)J")
+    unsatisfied_link(CLASS_NAME, "init", "(IIZ)J")
 }
 
 #[async_recursion(?Send)]
@@ -112,12 +112,12 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn reset(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.Deflater.reset(J)V")
+    unsatisfied_link(CLASS_NAME, "reset", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_dictionary(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.Deflater.setDictionary(J[BII)V")
+    unsatisfied_link(CLASS_NAME, "setDictionary", "(J[BII)V")
 }
 
 #[async_recursion(?Send)]
@@ -125,7 +125,7 @@ async fn set_dictionary_buffer(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.util.zip.Deflater.setDictionaryBuffer(JJI)V")
+    unsatisfied_link(CLASS_NAME, "setDictionaryBuffer", "(JJI)V")
 }
 
 #[cfg(test)]
@@ -133,67 +133,84 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.Deflater.deflateBytes(J[BIII)I")]
     async fn test_deflate_bytes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = deflate_bytes(thread, Parameters::default()).await;
+        let result = deflate_bytes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/Deflater",
+            "deflateBytes",
+            "(J[BIII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.zip.Deflater.deflateBufferBuffer(JJIJIII)J"
-    )]
     async fn test_deflate_buffer_buffer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = deflate_buffer_buffer(thread, Parameters::default()).await;
+        let result = deflate_buffer_buffer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/Deflater",
+            "deflateBufferBuffer",
+            "(JJIJIII)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.zip.Deflater.deflateBufferBytes(JJI[BIIII)J"
-    )]
     async fn test_deflate_buffer_bytes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = deflate_buffer_bytes(thread, Parameters::default()).await;
+        let result = deflate_buffer_bytes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/Deflater",
+            "deflateBufferBytes",
+            "(JJI[BIIII)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.zip.Deflater.deflateBytesBuffer(J[BIIJIII)J"
-    )]
     async fn test_deflate_bytes_buffer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = deflate_bytes_buffer(thread, Parameters::default()).await;
+        let result = deflate_bytes_buffer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/Deflater",
+            "deflateBytesBuffer",
+            "(J[BIIJIII)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.zip.Deflater.deflateBytesBytes(J[BII[BIIII)J"
-    )]
     async fn test_deflate_bytes_bytes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = deflate_bytes_bytes(thread, Parameters::default()).await;
+        let result = deflate_bytes_bytes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/Deflater",
+            "deflateBytesBytes",
+            "(J[BII[BIIII)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.Deflater.end(J)V")]
     async fn test_end() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = end(thread, Parameters::default()).await;
+        let result = end(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/util/zip/Deflater", "end", "(J)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.Deflater.getAdler(J)I")]
     async fn test_get_adler() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_adler(thread, Parameters::default()).await;
+        let result = get_adler(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/util/zip/Deflater", "getAdler", "(J)I");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.Deflater.init(IIZ)J")]
     async fn test_init() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init(thread, Parameters::default()).await;
+        let result = init(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/util/zip/Deflater", "init", "(IIZ)J");
     }
 
     #[tokio::test]
@@ -205,25 +222,33 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.Deflater.reset(J)V")]
     async fn test_reset() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = reset(thread, Parameters::default()).await;
+        let result = reset(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/util/zip/Deflater", "reset", "(J)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.Deflater.setDictionary(J[BII)V")]
     async fn test_set_dictionary() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_dictionary(thread, Parameters::default()).await;
+        let result = set_dictionary(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/Deflater",
+            "setDictionary",
+            "(J[BII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.zip.Deflater.setDictionaryBuffer(JJI)V"
-    )]
     async fn test_set_dictionary_buffer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_dictionary_buffer(thread, Parameters::default()).await;
+        let result = set_dictionary_buffer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/Deflater",
+            "setDictionaryBuffer",
+            "(JJI)V",
+        );
     }
 }
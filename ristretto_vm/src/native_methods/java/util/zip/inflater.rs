@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -55,12 +55,12 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn end(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.Inflater.end(J)V")
+    unsatisfied_link(CLASS_NAME, "end", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn get_adler(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.Inflater.getAdler(J)I")
+    unsatisfied_link(CLASS_NAME, "getAdler", "(J)I")
 }
 
 #[async_recursion(?Send)]
@@ -68,7 +68,7 @@ async fn inflate_buffer_buffer(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.util.zip.Inflater.inflateBufferBuffer(JJIJI)J")
+    unsatisfied_link(CLASS_NAME, "inflateBufferBuffer", "(JJIJI)J")
 }
 
 #[async_recursion(?Send)]
@@ -76,7 +76,7 @@ async fn inflate_buffer_bytes(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.util.zip.Inflater.inflateBufferBytes(JJI[BII)J")
+    unsatisfied_link(CLASS_NAME, "inflateBufferBytes", "(JJI[BII)J")
 }
 
 #[async_recursion(?Send)]
@@ -84,12 +84,12 @@ async fn inflate_bytes_buffer(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.util.zip.Inflater.inflateBytesBuffer(J[BIIJI)J")
+    unsatisfied_link(CLASS_NAME, "inflateBytesBuffer", "(J[BIIJI)J")
 }
 
 #[async_recursion(?Send)]
 async fn inflate_bytes(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.Inflater.inflateBytes(J[BII)I")
+    unsatisfied_link(CLASS_NAME, "inflateBytes", "(J[BII)I")
 }
 
 #[async_recursion(?Send)]
@@ -97,12 +97,12 @@ async fn inflate_bytes_bytes(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.util.zip.Inflater.inflateBytesBytes(J[BII[BII)J")
+    unsatisfied_link(CLASS_NAME, "inflateBytesBytes", "(J[BII[BII)J")
 }
 
 #[async_recursion(?Send)]
 async fn init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.Inflater.init(Z)J")
+    unsatisfied_link(CLASS_NAME, "init", "(Z)J")
 }
 
 #[async_recursion(?Send)]
@@ -112,12 +112,12 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn reset(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.Inflater.reset(J)V")
+    unsatisfied_link(CLASS_NAME, "reset", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_dictionary(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.Inflater.setDictionary(J[BII)V")
+    unsatisfied_link(CLASS_NAME, "setDictionary", "(J[BII)V")
 }
 
 #[async_recursion(?Send)]
@@ -125,7 +125,7 @@ async fn set_dictionary_buffer(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("java.util.zip.Inflater.setDictionaryBuffer(JJI)V")
+    unsatisfied_link(CLASS_NAME, "setDictionaryBuffer", "(JJI)V")
 }
 
 #[cfg(test)]
@@ -133,67 +133,84 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.Inflater.end(J)V")]
     async fn test_end() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = end(thread, Parameters::default()).await;
+        let result = end(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/util/zip/Inflater", "end", "(J)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.Inflater.getAdler(J)I")]
     async fn test_get_adler() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_adler(thread, Parameters::default()).await;
+        let result = get_adler(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/util/zip/Inflater", "getAdler", "(J)I");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.zip.Inflater.inflateBufferBuffer(JJIJI)J"
-    )]
     async fn test_inflate_buffer_buffer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = inflate_buffer_buffer(thread, Parameters::default()).await;
+        let result = inflate_buffer_buffer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/Inflater",
+            "inflateBufferBuffer",
+            "(JJIJI)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.zip.Inflater.inflateBufferBytes(JJI[BII)J"
-    )]
     async fn test_inflate_buffer_bytes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = inflate_buffer_bytes(thread, Parameters::default()).await;
+        let result = inflate_buffer_bytes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/Inflater",
+            "inflateBufferBytes",
+            "(JJI[BII)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.zip.Inflater.inflateBytesBuffer(J[BIIJI)J"
-    )]
     async fn test_inflate_bytes_buffer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = inflate_bytes_buffer(thread, Parameters::default()).await;
+        let result = inflate_bytes_buffer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/Inflater",
+            "inflateBytesBuffer",
+            "(J[BIIJI)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.Inflater.inflateBytes(J[BII)I")]
     async fn test_inflate_bytes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = inflate_bytes(thread, Parameters::default()).await;
+        let result = inflate_bytes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/Inflater",
+            "inflateBytes",
+            "(J[BII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.zip.Inflater.inflateBytesBytes(J[BII[BII)J"
-    )]
     async fn test_inflate_bytes_bytes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = inflate_bytes_bytes(thread, Parameters::default()).await;
+        let result = inflate_bytes_bytes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/Inflater",
+            "inflateBytesBytes",
+            "(J[BII[BII)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.Inflater.init(Z)J")]
     async fn test_init() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init(thread, Parameters::default()).await;
+        let result = init(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/util/zip/Inflater", "init", "(Z)J");
     }
 
     #[tokio::test]
@@ -205,25 +222,33 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.Inflater.reset(J)V")]
     async fn test_reset() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = reset(thread, Parameters::default()).await;
+        let result = reset(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/util/zip/Inflater", "reset", "(J)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.Inflater.setDictionary(J[BII)V")]
     async fn test_set_dictionary() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_dictionary(thread, Parameters::default()).await;
+        let result = set_dictionary(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/Inflater",
+            "setDictionary",
+            "(J[BII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.zip.Inflater.setDictionaryBuffer(JJI)V"
-    )]
     async fn test_set_dictionary_buffer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_dictionary_buffer(thread, Parameters::default()).await;
+        let result = set_dictionary_buffer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/Inflater",
+            "setDictionaryBuffer",
+            "(JJI)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -38,77 +38,77 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn close(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.ZipFile.close(J)V")
+    unsatisfied_link(CLASS_NAME, "close", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn free_entry(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.ZipFile.freeEntry(JJ)V")
+    unsatisfied_link(CLASS_NAME, "freeEntry", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn get_comment_bytes(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.ZipFile.getCommentBytes(J)[B")
+    unsatisfied_link(CLASS_NAME, "getCommentBytes", "(J)[B")
 }
 
 #[async_recursion(?Send)]
 async fn get_entry(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.ZipFile.getEntry(J[BZ)J")
+    unsatisfied_link(CLASS_NAME, "getEntry", "(J[BZ)J")
 }
 
 #[async_recursion(?Send)]
 async fn get_entry_bytes(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.ZipFile.getEntryBytes(JI)[B")
+    unsatisfied_link(CLASS_NAME, "getEntryBytes", "(JI)[B")
 }
 
 #[async_recursion(?Send)]
 async fn get_entry_c_size(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.ZipFile.getEntryCSize(J)J")
+    unsatisfied_link(CLASS_NAME, "getEntryCSize", "(J)J")
 }
 
 #[async_recursion(?Send)]
 async fn get_entry_crc(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.ZipFile.getEntryCrc(J)J")
+    unsatisfied_link(CLASS_NAME, "getEntryCrc", "(J)J")
 }
 
 #[async_recursion(?Send)]
 async fn get_entry_flag(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.ZipFile.getEntryFlag(J)I")
+    unsatisfied_link(CLASS_NAME, "getEntryFlag", "(J)I")
 }
 
 #[async_recursion(?Send)]
 async fn get_entry_method(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.ZipFile.getEntryMethod(J)I")
+    unsatisfied_link(CLASS_NAME, "getEntryMethod", "(J)I")
 }
 
 #[async_recursion(?Send)]
 async fn get_entry_size(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.ZipFile.getEntrySize(J)J")
+    unsatisfied_link(CLASS_NAME, "getEntrySize", "(J)J")
 }
 
 #[async_recursion(?Send)]
 async fn get_entry_time(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.ZipFile.getEntryTime(J)J")
+    unsatisfied_link(CLASS_NAME, "getEntryTime", "(J)J")
 }
 
 #[async_recursion(?Send)]
 async fn get_manifest_num(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.ZipFile.getManifestNum(J)I")
+    unsatisfied_link(CLASS_NAME, "getManifestNum", "(J)I")
 }
 
 #[async_recursion(?Send)]
 async fn get_next_entry(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.ZipFile.getNextEntry(JI)J")
+    unsatisfied_link(CLASS_NAME, "getNextEntry", "(JI)J")
 }
 
 #[async_recursion(?Send)]
 async fn get_total(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.ZipFile.getTotal(J)I")
+    unsatisfied_link(CLASS_NAME, "getTotal", "(J)I")
 }
 
 #[async_recursion(?Send)]
 async fn get_zip_message(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.ZipFile.getZipMessage(J)Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getZipMessage", "(J)Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
@@ -118,17 +118,17 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn open(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.ZipFile.open(Ljava/lang/String;IJZ)J")
+    unsatisfied_link(CLASS_NAME, "open", "(Ljava/lang/String;IJZ)J")
 }
 
 #[async_recursion(?Send)]
 async fn read(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.ZipFile.read(JJJ[BII)I")
+    unsatisfied_link(CLASS_NAME, "read", "(JJJ[BII)I")
 }
 
 #[async_recursion(?Send)]
 async fn starts_with_loc(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("java.util.zip.ZipFile.startsWithLOC(J)Z")
+    unsatisfied_link(CLASS_NAME, "startsWithLOC", "(J)Z")
 }
 
 #[cfg(test)]
@@ -136,110 +136,168 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.ZipFile.close(J)V")]
     async fn test_close() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = close(thread, Parameters::default()).await;
+        let result = close(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/util/zip/ZipFile", "close", "(J)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.ZipFile.freeEntry(JJ)V")]
     async fn test_free_entry() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = free_entry(thread, Parameters::default()).await;
+        let result = free_entry(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/util/zip/ZipFile", "freeEntry", "(JJ)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.ZipFile.getCommentBytes(J)[B")]
     async fn test_get_comment_bytes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_comment_bytes(thread, Parameters::default()).await;
+        let result = get_comment_bytes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/ZipFile",
+            "getCommentBytes",
+            "(J)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.ZipFile.getEntry(J[BZ)J")]
     async fn test_get_entry() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_entry(thread, Parameters::default()).await;
+        let result = get_entry(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/ZipFile",
+            "getEntry",
+            "(J[BZ)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.ZipFile.getEntryBytes(JI)[B")]
     async fn test_get_entry_bytes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_entry_bytes(thread, Parameters::default()).await;
+        let result = get_entry_bytes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/ZipFile",
+            "getEntryBytes",
+            "(JI)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.ZipFile.getEntryCSize(J)J")]
     async fn test_get_entry_c_size() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_entry_c_size(thread, Parameters::default()).await;
+        let result = get_entry_c_size(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/ZipFile",
+            "getEntryCSize",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.ZipFile.getEntryCrc(J)J")]
     async fn test_get_entry_crc() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_entry_crc(thread, Parameters::default()).await;
+        let result = get_entry_crc(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/ZipFile",
+            "getEntryCrc",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.ZipFile.getEntryFlag(J)I")]
     async fn test_get_entry_flag() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_entry_flag(thread, Parameters::default()).await;
+        let result = get_entry_flag(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/ZipFile",
+            "getEntryFlag",
+            "(J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.ZipFile.getEntryMethod(J)I")]
     async fn test_get_entry_method() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_entry_method(thread, Parameters::default()).await;
+        let result = get_entry_method(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/ZipFile",
+            "getEntryMethod",
+            "(J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.ZipFile.getEntrySize(J)J")]
     async fn test_get_entry_size() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_entry_size(thread, Parameters::default()).await;
+        let result = get_entry_size(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/ZipFile",
+            "getEntrySize",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.ZipFile.getEntryTime(J)J")]
     async fn test_get_entry_time() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_entry_time(thread, Parameters::default()).await;
+        let result = get_entry_time(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/ZipFile",
+            "getEntryTime",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.ZipFile.getManifestNum(J)I")]
     async fn test_get_manifest_num() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_manifest_num(thread, Parameters::default()).await;
+        let result = get_manifest_num(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/ZipFile",
+            "getManifestNum",
+            "(J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.ZipFile.getNextEntry(JI)J")]
     async fn test_get_next_entry() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_next_entry(thread, Parameters::default()).await;
+        let result = get_next_entry(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/ZipFile",
+            "getNextEntry",
+            "(JI)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.ZipFile.getTotal(J)I")]
     async fn test_get_total() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_total(thread, Parameters::default()).await;
+        let result = get_total(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/util/zip/ZipFile", "getTotal", "(J)I");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.zip.ZipFile.getZipMessage(J)Ljava/lang/String;"
-    )]
     async fn test_get_zip_message() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_zip_message(thread, Parameters::default()).await;
+        let result = get_zip_message(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/ZipFile",
+            "getZipMessage",
+            "(J)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
@@ -251,25 +309,33 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: java.util.zip.ZipFile.open(Ljava/lang/String;IJZ)J"
-    )]
     async fn test_open() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = open(thread, Parameters::default()).await;
+        let result = open(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/ZipFile",
+            "open",
+            "(Ljava/lang/String;IJZ)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.ZipFile.read(JJJ[BII)I")]
     async fn test_read() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = read(thread, Parameters::default()).await;
+        let result = read(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "java/util/zip/ZipFile", "read", "(JJJ[BII)I");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: java.util.zip.ZipFile.startsWithLOC(J)Z")]
     async fn test_starts_with_loc() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = starts_with_loc(thread, Parameters::default()).await;
+        let result = starts_with_loc(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "java/util/zip/ZipFile",
+            "startsWithLOC",
+            "(J)Z",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,7 +23,7 @@ async fn is_access_user_only_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.agent.FileSystemImpl.isAccessUserOnly0(Ljava/lang/String;)Z")
+    unsatisfied_link(CLASS_NAME, "isAccessUserOnly0", "(Ljava/lang/String;)Z")
 }
 
 #[cfg(test)]
@@ -31,11 +31,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.agent.FileSystemImpl.isAccessUserOnly0(Ljava/lang/String;)Z"
-    )]
     async fn test_is_access_user_only_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_access_user_only_0(thread, Parameters::default()).await;
+        let result = is_access_user_only_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/agent/FileSystemImpl",
+            "isAccessUserOnly0",
+            "(Ljava/lang/String;)Z",
+        );
     }
 }
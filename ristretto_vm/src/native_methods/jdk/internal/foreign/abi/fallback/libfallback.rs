@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_21, JAVA_23};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_21, JAVA_23};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -70,27 +70,27 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn alignof_double(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.alignof_double()I")
+    unsatisfied_link(CLASS_NAME, "alignof_double", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn alignof_long_long(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.alignof_long_long()I")
+    unsatisfied_link(CLASS_NAME, "alignof_long_long", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn create_closure(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.createClosure(JLjava/lang/Object;[J)I")
+    unsatisfied_link(CLASS_NAME, "createClosure", "(JLjava/lang/Object;[J)I")
 }
 
 #[async_recursion(?Send)]
 async fn do_downcall(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.doDowncall(JJJJJ[I)V")
+    unsatisfied_link(CLASS_NAME, "doDowncall", "(JJJJJ[I)V")
 }
 
 #[async_recursion(?Send)]
 async fn ffi_default_abi(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_default_abi()I")
+    unsatisfied_link(CLASS_NAME, "ffi_default_abi", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -98,117 +98,117 @@ async fn ffi_get_struct_offsets(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_get_struct_offsets(IJJ)I")
+    unsatisfied_link(CLASS_NAME, "ffi_get_struct_offsets", "(IJJ)I")
 }
 
 #[async_recursion(?Send)]
 async fn ffi_prep_cif(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_prep_cif(JIIJJ)I")
+    unsatisfied_link(CLASS_NAME, "ffi_prep_cif", "(JIIJJ)I")
 }
 
 #[async_recursion(?Send)]
 async fn ffi_prep_cif_var(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_prep_cif_var(JIIIJJ)I")
+    unsatisfied_link(CLASS_NAME, "ffi_prep_cif_var", "(JIIIJJ)I")
 }
 
 #[async_recursion(?Send)]
 async fn ffi_sizeof_int(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_sizeof_int()I")
+    unsatisfied_link(CLASS_NAME, "ffi_sizeof_int", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn ffi_sizeof_long(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_sizeof_long()I")
+    unsatisfied_link(CLASS_NAME, "ffi_sizeof_long", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn ffi_sizeof_short(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_sizeof_short()I")
+    unsatisfied_link(CLASS_NAME, "ffi_sizeof_short", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn ffi_sizeof_wchar(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_sizeof_wchar()I")
+    unsatisfied_link(CLASS_NAME, "ffi_sizeof_wchar", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn ffi_type_double(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_double()J")
+    unsatisfied_link(CLASS_NAME, "ffi_type_double", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn ffi_type_float(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_float()J")
+    unsatisfied_link(CLASS_NAME, "ffi_type_float", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn ffi_type_pointer(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_pointer()J")
+    unsatisfied_link(CLASS_NAME, "ffi_type_pointer", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn ffi_type_sint_16(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_sint16()J")
+    unsatisfied_link(CLASS_NAME, "ffi_type_sint16", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn ffi_type_sint_32(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_sint32()J")
+    unsatisfied_link(CLASS_NAME, "ffi_type_sint32", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn ffi_type_sint_64(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_sint64()J")
+    unsatisfied_link(CLASS_NAME, "ffi_type_sint64", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn ffi_type_sint_8(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_sint8()J")
+    unsatisfied_link(CLASS_NAME, "ffi_type_sint8", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn ffi_type_struct(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_struct()S")
+    unsatisfied_link(CLASS_NAME, "ffi_type_struct", "()S")
 }
 
 #[async_recursion(?Send)]
 async fn ffi_type_uint_16(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_uint16()J")
+    unsatisfied_link(CLASS_NAME, "ffi_type_uint16", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn ffi_type_uint_32(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_uint32()J")
+    unsatisfied_link(CLASS_NAME, "ffi_type_uint32", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn ffi_type_uint_64(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_uint64()J")
+    unsatisfied_link(CLASS_NAME, "ffi_type_uint64", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn ffi_type_uint_8(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_uint8()J")
+    unsatisfied_link(CLASS_NAME, "ffi_type_uint8", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn ffi_type_void(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_void()J")
+    unsatisfied_link(CLASS_NAME, "ffi_type_void", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn free_closure(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.freeClosure(JJ)V")
+    unsatisfied_link(CLASS_NAME, "freeClosure", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.init()Z")
+    unsatisfied_link(CLASS_NAME, "init", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn sizeof_cif(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.fallback.LibFallback.sizeofCif()J")
+    unsatisfied_link(CLASS_NAME, "sizeofCif", "()J")
 }
 
 #[cfg(test)]
@@ -216,254 +216,338 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.alignof_double()I"
-    )]
     async fn test_alignof_double() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = alignof_double(thread, Parameters::default()).await;
+        let result = alignof_double(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "alignof_double",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.alignof_long_long()I"
-    )]
     async fn test_alignof_long_long() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = alignof_long_long(thread, Parameters::default()).await;
+        let result = alignof_long_long(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "alignof_long_long",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.createClosure(JLjava/lang/Object;[J)I"
-    )]
     async fn test_create_closure() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_closure(thread, Parameters::default()).await;
+        let result = create_closure(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "createClosure",
+            "(JLjava/lang/Object;[J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.doDowncall(JJJJJ[I)V"
-    )]
     async fn test_do_downcall() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = do_downcall(thread, Parameters::default()).await;
+        let result = do_downcall(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "doDowncall",
+            "(JJJJJ[I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_default_abi()I"
-    )]
     async fn test_ffi_default_abi() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_default_abi(thread, Parameters::default()).await;
+        let result = ffi_default_abi(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_default_abi",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_get_struct_offsets(IJJ)I"
-    )]
     async fn test_ffi_get_struct_offsets() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_get_struct_offsets(thread, Parameters::default()).await;
+        let result = ffi_get_struct_offsets(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_get_struct_offsets",
+            "(IJJ)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_prep_cif(JIIJJ)I"
-    )]
     async fn test_ffi_prep_cif() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_prep_cif(thread, Parameters::default()).await;
+        let result = ffi_prep_cif(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_prep_cif",
+            "(JIIJJ)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_prep_cif_var(JIIIJJ)I"
-    )]
     async fn test_ffi_prep_cif_var() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_prep_cif_var(thread, Parameters::default()).await;
+        let result = ffi_prep_cif_var(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_prep_cif_var",
+            "(JIIIJJ)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_sizeof_int()I"
-    )]
     async fn test_ffi_sizeof_int() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_sizeof_int(thread, Parameters::default()).await;
+        let result = ffi_sizeof_int(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_sizeof_int",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_sizeof_long()I"
-    )]
     async fn test_ffi_sizeof_long() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_sizeof_long(thread, Parameters::default()).await;
+        let result = ffi_sizeof_long(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_sizeof_long",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_sizeof_short()I"
-    )]
     async fn test_ffi_sizeof_short() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_sizeof_short(thread, Parameters::default()).await;
+        let result = ffi_sizeof_short(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_sizeof_short",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_sizeof_wchar()I"
-    )]
     async fn test_ffi_sizeof_wchar() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_sizeof_wchar(thread, Parameters::default()).await;
+        let result = ffi_sizeof_wchar(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_sizeof_wchar",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_double()J"
-    )]
     async fn test_ffi_type_double() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_type_double(thread, Parameters::default()).await;
+        let result = ffi_type_double(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_type_double",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_float()J"
-    )]
     async fn test_ffi_type_float() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_type_float(thread, Parameters::default()).await;
+        let result = ffi_type_float(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_type_float",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_pointer()J"
-    )]
     async fn test_ffi_type_pointer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_type_pointer(thread, Parameters::default()).await;
+        let result = ffi_type_pointer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_type_pointer",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_sint16()J"
-    )]
     async fn test_ffi_type_sint16() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_type_sint_16(thread, Parameters::default()).await;
+        let result = ffi_type_sint_16(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_type_sint16",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_sint32()J"
-    )]
     async fn test_ffi_type_sint32() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_type_sint_32(thread, Parameters::default()).await;
+        let result = ffi_type_sint_32(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_type_sint32",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_sint64()J"
-    )]
     async fn test_ffi_type_sint64() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_type_sint_64(thread, Parameters::default()).await;
+        let result = ffi_type_sint_64(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_type_sint64",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_sint8()J"
-    )]
     async fn test_ffi_type_sint8() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_type_sint_8(thread, Parameters::default()).await;
+        let result = ffi_type_sint_8(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_type_sint8",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_struct()S"
-    )]
     async fn test_ffi_type_struct() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_type_struct(thread, Parameters::default()).await;
+        let result = ffi_type_struct(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_type_struct",
+            "()S",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_uint16()J"
-    )]
     async fn test_ffi_type_uint16() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_type_uint_16(thread, Parameters::default()).await;
+        let result = ffi_type_uint_16(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_type_uint16",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_uint32()J"
-    )]
     async fn test_ffi_type_uint32() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_type_uint_32(thread, Parameters::default()).await;
+        let result = ffi_type_uint_32(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_type_uint32",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_uint64()J"
-    )]
     async fn test_ffi_type_uint64() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_type_uint_64(thread, Parameters::default()).await;
+        let result = ffi_type_uint_64(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_type_uint64",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_uint8()J"
-    )]
     async fn test_ffi_type_uint8() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_type_uint_8(thread, Parameters::default()).await;
+        let result = ffi_type_uint_8(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_type_uint8",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.ffi_type_void()J"
-    )]
     async fn test_ffi_type_void() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ffi_type_void(thread, Parameters::default()).await;
+        let result = ffi_type_void(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "ffi_type_void",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.freeClosure(JJ)V"
-    )]
     async fn test_free_closure() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = free_closure(thread, Parameters::default()).await;
+        let result = free_closure(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "freeClosure",
+            "(JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.init()Z"
-    )]
     async fn test_init() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init(thread, Parameters::default()).await;
+        let result = init(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "init",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.fallback.LibFallback.sizeofCif()J"
-    )]
     async fn test_sizeof_cif() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = sizeof_cif(thread, Parameters::default()).await;
+        let result = sizeof_cif(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/fallback/LibFallback",
+            "sizeofCif",
+            "()J",
+        );
     }
 }
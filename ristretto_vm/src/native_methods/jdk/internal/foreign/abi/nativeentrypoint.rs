@@ -1,4 +1,5 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_21};
+use crate::native_access::check_restricted_call;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_21};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -28,15 +29,13 @@ async fn free_downcall_stub_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.NativeEntryPoint.freeDowncallStub0(J)Z")
+    unsatisfied_link(CLASS_NAME, "freeDowncallStub0", "(J)Z")
 }
 
 #[async_recursion(?Send)]
-async fn make_downcall_stub(
-    _thread: Arc<Thread>,
-    _parameters: Parameters,
-) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.NativeEntryPoint.makeDowncallStub(Ljava/lang/invoke/MethodType;Ljdk/internal/foreign/abi/ABIDescriptor;[Ljdk/internal/foreign/abi/VMStorage;[Ljdk/internal/foreign/abi/VMStorage;Z)J")
+async fn make_downcall_stub(thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    check_restricted_call(&thread).await?;
+    unsatisfied_link(CLASS_NAME, "makeDowncallStub", "(Ljava/lang/invoke/MethodType;Ljdk/internal/foreign/abi/ABIDescriptor;[Ljdk/internal/foreign/abi/VMStorage;[Ljdk/internal/foreign/abi/VMStorage;ZIZ)J")
 }
 
 #[async_recursion(?Send)]
@@ -49,21 +48,22 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.NativeEntryPoint.freeDowncallStub0(J)Z"
-    )]
     async fn test_free_downcall_stub_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = free_downcall_stub_0(thread, Parameters::default()).await;
+        let result = free_downcall_stub_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/NativeEntryPoint",
+            "freeDowncallStub0",
+            "(J)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.NativeEntryPoint.makeDowncallStub(Ljava/lang/invoke/MethodType;Ljdk/internal/foreign/abi/ABIDescriptor;[Ljdk/internal/foreign/abi/VMStorage;[Ljdk/internal/foreign/abi/VMStorage;Z)J"
-    )]
     async fn test_make_downcall_stub() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = make_downcall_stub(thread, Parameters::default()).await;
+        let result = make_downcall_stub(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/internal/foreign/abi/NativeEntryPoint", "makeDowncallStub", "(Ljava/lang/invoke/MethodType;Ljdk/internal/foreign/abi/ABIDescriptor;[Ljdk/internal/foreign/abi/VMStorage;[Ljdk/internal/foreign/abi/VMStorage;ZIZ)J");
     }
 
     #[tokio::test]
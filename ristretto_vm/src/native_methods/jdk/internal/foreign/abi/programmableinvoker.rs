@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -22,12 +22,16 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn generate_adapter(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.ProgrammableInvoker.generateAdapter(Ljdk/internal/foreign/abi/ABIDescriptor;Ljdk/internal/foreign/abi/BufferLayout;)J")
+    unsatisfied_link(
+        CLASS_NAME,
+        "generateAdapter",
+        "(Ljdk/internal/foreign/abi/ABIDescriptor;Ljdk/internal/foreign/abi/BufferLayout;)J",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn invoke_native(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.ProgrammableInvoker.invokeNative(JJ)V")
+    unsatisfied_link(CLASS_NAME, "invokeNative", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
@@ -40,21 +44,27 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.ProgrammableInvoker.generateAdapter(Ljdk/internal/foreign/abi/ABIDescriptor;Ljdk/internal/foreign/abi/BufferLayout;)J"
-    )]
     async fn test_generate_adapter() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = generate_adapter(thread, Parameters::default()).await;
+        let result = generate_adapter(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/ProgrammableInvoker",
+            "generateAdapter",
+            "(Ljdk/internal/foreign/abi/ABIDescriptor;Ljdk/internal/foreign/abi/BufferLayout;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.ProgrammableInvoker.invokeNative(JJ)V"
-    )]
     async fn test_invoke_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = invoke_native(thread, Parameters::default()).await;
+        let result = invoke_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/ProgrammableInvoker",
+            "invokeNative",
+            "(JJ)V",
+        );
     }
 
     #[tokio::test]
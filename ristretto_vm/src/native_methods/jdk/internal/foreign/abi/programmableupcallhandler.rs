@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -26,7 +26,7 @@ async fn allocate_optimized_upcall_stub(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.ProgrammableUpcallHandler.allocateOptimizedUpcallStub(Ljava/lang/invoke/MethodHandle;Ljdk/internal/foreign/abi/ABIDescriptor;Ljdk/internal/foreign/abi/ProgrammableUpcallHandler$CallRegs;)J")
+    unsatisfied_link(CLASS_NAME, "allocateOptimizedUpcallStub", "(Ljava/lang/invoke/MethodHandle;Ljdk/internal/foreign/abi/ABIDescriptor;Ljdk/internal/foreign/abi/ProgrammableUpcallHandler$CallRegs;)J")
 }
 
 #[async_recursion(?Send)]
@@ -34,7 +34,7 @@ async fn allocate_upcall_stub(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.ProgrammableUpcallHandler.allocateUpcallStub(Ljava/lang/invoke/MethodHandle;Ljdk/internal/foreign/abi/ABIDescriptor;Ljdk/internal/foreign/abi/BufferLayout;)J")
+    unsatisfied_link(CLASS_NAME, "allocateUpcallStub", "(Ljava/lang/invoke/MethodHandle;Ljdk/internal/foreign/abi/ABIDescriptor;Ljdk/internal/foreign/abi/BufferLayout;)J")
 }
 
 #[async_recursion(?Send)]
@@ -47,7 +47,7 @@ async fn supports_optimized_upcalls(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.ProgrammableUpcallHandler.supportsOptimizedUpcalls()Z")
+    unsatisfied_link(CLASS_NAME, "supportsOptimizedUpcalls", "()Z")
 }
 
 #[cfg(test)]
@@ -55,30 +55,29 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.ProgrammableUpcallHandler.allocateOptimizedUpcallStub(Ljava/lang/invoke/MethodHandle;Ljdk/internal/foreign/abi/ABIDescriptor;Ljdk/internal/foreign/abi/ProgrammableUpcallHandler$CallRegs;)J"
-    )]
     async fn test_allocate_optimized_upcall_stub() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = allocate_optimized_upcall_stub(thread, Parameters::default()).await;
+        let result = allocate_optimized_upcall_stub(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/internal/foreign/abi/ProgrammableUpcallHandler", "allocateOptimizedUpcallStub", "(Ljava/lang/invoke/MethodHandle;Ljdk/internal/foreign/abi/ABIDescriptor;Ljdk/internal/foreign/abi/ProgrammableUpcallHandler$CallRegs;)J");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.ProgrammableUpcallHandler.allocateUpcallStub(Ljava/lang/invoke/MethodHandle;Ljdk/internal/foreign/abi/ABIDescriptor;Ljdk/internal/foreign/abi/BufferLayout;)J"
-    )]
     async fn test_allocate_upcall_stub() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = allocate_upcall_stub(thread, Parameters::default()).await;
+        let result = allocate_upcall_stub(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/internal/foreign/abi/ProgrammableUpcallHandler", "allocateUpcallStub", "(Ljava/lang/invoke/MethodHandle;Ljdk/internal/foreign/abi/ABIDescriptor;Ljdk/internal/foreign/abi/BufferLayout;)J");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.ProgrammableUpcallHandler.supportsOptimizedUpcalls()Z"
-    )]
     async fn test_supports_optimized_upcalls() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = supports_optimized_upcalls(thread, Parameters::default()).await;
+        let result = supports_optimized_upcalls(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/ProgrammableUpcallHandler",
+            "supportsOptimizedUpcalls",
+            "()Z",
+        );
     }
 
     #[tokio::test]
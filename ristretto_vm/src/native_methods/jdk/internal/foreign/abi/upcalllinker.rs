@@ -1,4 +1,5 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_access::check_restricted_call;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -15,8 +16,9 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 }
 
 #[async_recursion(?Send)]
-async fn make_upcall_stub(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.UpcallLinker.makeUpcallStub(Ljava/lang/invoke/MethodHandle;Ljdk/internal/foreign/abi/ABIDescriptor;Ljdk/internal/foreign/abi/UpcallLinker$CallRegs;ZJ)J")
+async fn make_upcall_stub(thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    check_restricted_call(&thread).await?;
+    unsatisfied_link(CLASS_NAME, "makeUpcallStub", "(Ljava/lang/invoke/MethodHandle;Ljdk/internal/foreign/abi/ABIDescriptor;Ljdk/internal/foreign/abi/UpcallLinker$CallRegs;ZJ)J")
 }
 
 #[async_recursion(?Send)]
@@ -29,12 +31,10 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.UpcallLinker.makeUpcallStub(Ljava/lang/invoke/MethodHandle;Ljdk/internal/foreign/abi/ABIDescriptor;Ljdk/internal/foreign/abi/UpcallLinker$CallRegs;ZJ)J"
-    )]
     async fn test_make_upcall_stub() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = make_upcall_stub(thread, Parameters::default()).await;
+        let result = make_upcall_stub(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/internal/foreign/abi/UpcallLinker", "makeUpcallStub", "(Ljava/lang/invoke/MethodHandle;Ljdk/internal/foreign/abi/ABIDescriptor;Ljdk/internal/foreign/abi/UpcallLinker$CallRegs;ZJ)J");
     }
 
     #[tokio::test]
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -19,7 +19,7 @@ async fn free_upcall_stub_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.foreign.abi.UpcallStubs.freeUpcallStub0(J)Z")
+    unsatisfied_link(CLASS_NAME, "freeUpcallStub0", "(J)Z")
 }
 
 #[async_recursion(?Send)]
@@ -32,12 +32,15 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.foreign.abi.UpcallStubs.freeUpcallStub0(J)Z"
-    )]
     async fn test_free_upcall_stub_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = free_upcall_stub_0(thread, Parameters::default()).await;
+        let result = free_upcall_stub_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/foreign/abi/UpcallStubs",
+            "freeUpcallStub0",
+            "(J)Z",
+        );
     }
 
     #[tokio::test]
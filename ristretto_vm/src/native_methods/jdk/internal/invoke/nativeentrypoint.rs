@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -29,7 +29,7 @@ async fn vm_storage_to_vm_reg(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.invoke.NativeEntryPoint.vmStorageToVMReg(II)J")
+    unsatisfied_link(CLASS_NAME, "vmStorageToVMReg", "(II)J")
 }
 
 #[cfg(test)]
@@ -45,11 +45,14 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.invoke.NativeEntryPoint.vmStorageToVMReg(II)J"
-    )]
     async fn test_vm_storage_to_vm_reg() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = vm_storage_to_vm_reg(thread, Parameters::default()).await;
+        let result = vm_storage_to_vm_reg(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/invoke/NativeEntryPoint",
+            "vmStorageToVMReg",
+            "(II)J",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -20,7 +20,11 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn get_native_map(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.jimage.NativeImageBuffer.getNativeMap(Ljava/lang/String;)Ljava/nio/ByteBuffer;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getNativeMap",
+        "(Ljava/lang/String;)Ljava/nio/ByteBuffer;",
+    )
 }
 
 #[cfg(test)]
@@ -28,11 +32,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.jimage.NativeImageBuffer.getNativeMap(Ljava/lang/String;)Ljava/nio/ByteBuffer;"
-    )]
     async fn test_get_native_map() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_native_map(thread, Parameters::default()).await;
+        let result = get_native_map(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/jimage/NativeImageBuffer",
+            "getNativeMap",
+            "(Ljava/lang/String;)Ljava/nio/ByteBuffer;",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -35,7 +35,11 @@ async fn get_system_package_location(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.loader.BootLoader.getSystemPackageLocation(Ljava/lang/String;)Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getSystemPackageLocation",
+        "(Ljava/lang/String;)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -43,7 +47,7 @@ async fn get_system_package_names(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.loader.BootLoader.getSystemPackageNames()[Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getSystemPackageNames", "()[Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
@@ -60,21 +64,27 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.loader.BootLoader.getSystemPackageLocation(Ljava/lang/String;)Ljava/lang/String;"
-    )]
     async fn test_get_system_package_location() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_system_package_location(thread, Parameters::default()).await;
+        let result = get_system_package_location(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/loader/BootLoader",
+            "getSystemPackageLocation",
+            "(Ljava/lang/String;)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.loader.BootLoader.getSystemPackageNames()[Ljava/lang/String;"
-    )]
     async fn test_get_system_package_names() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_system_package_names(thread, Parameters::default()).await;
+        let result = get_system_package_names(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/loader/BootLoader",
+            "getSystemPackageNames",
+            "()[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
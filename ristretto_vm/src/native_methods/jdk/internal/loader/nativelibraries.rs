@@ -1,5 +1,6 @@
 use crate::java_object::JavaObject;
-use crate::native_methods::registry::{MethodRegistry, JAVA_17, JAVA_21};
+use crate::native_access::check_restricted_call;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_17, JAVA_21};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -46,7 +47,11 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn find_entry_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.loader.NativeLibraries.findEntry0(Ljdk/internal/loader/NativeLibraries$NativeLibraryImpl;Ljava/lang/String;)J")
+    unsatisfied_link(
+        CLASS_NAME,
+        "findEntry0",
+        "(Ljdk/internal/loader/NativeLibraries$NativeLibraryImpl;Ljava/lang/String;)J",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -69,7 +74,8 @@ async fn find_builtin_lib(
 }
 
 #[async_recursion(?Send)]
-async fn load(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+async fn load(thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    check_restricted_call(&thread).await?;
     Ok(Some(Value::Int(1)))
 }
 
@@ -86,12 +92,15 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.loader.NativeLibraries.findEntry0(Ljdk/internal/loader/NativeLibraries$NativeLibraryImpl;Ljava/lang/String;)J"
-    )]
     async fn test_find_entry_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = find_entry_0(thread, Parameters::default()).await;
+        let result = find_entry_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/loader/NativeLibraries",
+            "findEntry0",
+            "(Ljdk/internal/loader/NativeLibraries$NativeLibraryImpl;Ljava/lang/String;)J",
+        );
     }
 
     #[tokio::test]
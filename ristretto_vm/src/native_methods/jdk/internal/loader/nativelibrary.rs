@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -20,7 +20,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn find_entry_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.loader.NativeLibrary.findEntry0(JLjava/lang/String;)J")
+    unsatisfied_link(CLASS_NAME, "findEntry0", "(JLjava/lang/String;)J")
 }
 
 #[cfg(test)]
@@ -28,11 +28,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.loader.NativeLibrary.findEntry0(JLjava/lang/String;)J"
-    )]
     async fn test_find_entry_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = find_entry_0(thread, Parameters::default()).await;
+        let result = find_entry_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/loader/NativeLibrary",
+            "findEntry0",
+            "(JLjava/lang/String;)J",
+        );
     }
 }
@@ -1,4 +1,5 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_access::check_restricted_call;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -20,13 +21,18 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 }
 
 #[async_recursion(?Send)]
-async fn load_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.loader.RawNativeLibraries.load0(Ljdk/internal/loader/RawNativeLibraries$RawNativeLibraryImpl;Ljava/lang/String;)Z")
+async fn load_0(thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    check_restricted_call(&thread).await?;
+    unsatisfied_link(
+        CLASS_NAME,
+        "load0",
+        "(Ljdk/internal/loader/RawNativeLibraries$RawNativeLibraryImpl;Ljava/lang/String;)Z",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn unload_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.loader.RawNativeLibraries.unload0(Ljava/lang/String;J)V")
+    unsatisfied_link(CLASS_NAME, "unload0", "(Ljava/lang/String;J)V")
 }
 
 #[cfg(test)]
@@ -34,20 +40,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.loader.RawNativeLibraries.load0(Ljdk/internal/loader/RawNativeLibraries$RawNativeLibraryImpl;Ljava/lang/String;)Z"
-    )]
     async fn test_load_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = load_0(thread, Parameters::default()).await;
+        let result = load_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/loader/RawNativeLibraries",
+            "load0",
+            "(Ljdk/internal/loader/RawNativeLibraries$RawNativeLibraryImpl;Ljava/lang/String;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.loader.RawNativeLibraries.unload0(Ljava/lang/String;J)V"
-    )]
     async fn test_unload_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = unload_0(thread, Parameters::default()).await;
+        let result = unload_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/loader/RawNativeLibraries",
+            "unload0",
+            "(Ljava/lang/String;J)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_21};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_21};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -72,12 +72,16 @@ async fn define_archived_modules(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.CDS.defineArchivedModules(Ljava/lang/ClassLoader;Ljava/lang/ClassLoader;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "defineArchivedModules",
+        "(Ljava/lang/ClassLoader;Ljava/lang/ClassLoader;)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn dump_class_list(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.CDS.dumpClassList(Ljava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "dumpClassList", "(Ljava/lang/String;)V")
 }
 
 #[async_recursion(?Send)]
@@ -85,7 +89,7 @@ async fn dump_dynamic_archive(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.CDS.dumpDynamicArchive(Ljava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "dumpDynamicArchive", "(Ljava/lang/String;)V")
 }
 
 #[async_recursion(?Send)]
@@ -147,7 +151,7 @@ async fn log_lambda_form_invoker(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.CDS.logLambdaFormInvoker(Ljava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "logLambdaFormInvoker", "(Ljava/lang/String;)V")
 }
 
 #[cfg(test)]
@@ -155,30 +159,39 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.CDS.defineArchivedModules(Ljava/lang/ClassLoader;Ljava/lang/ClassLoader;)V"
-    )]
     async fn test_define_archived_modules() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = define_archived_modules(thread, Parameters::default()).await;
+        let result = define_archived_modules(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/CDS",
+            "defineArchivedModules",
+            "(Ljava/lang/ClassLoader;Ljava/lang/ClassLoader;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.CDS.dumpClassList(Ljava/lang/String;)V"
-    )]
     async fn test_dump_class_list() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = dump_class_list(thread, Parameters::default()).await;
+        let result = dump_class_list(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/CDS",
+            "dumpClassList",
+            "(Ljava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.CDS.dumpDynamicArchive(Ljava/lang/String;)V"
-    )]
     async fn test_dump_dynamic_archive() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = dump_dynamic_archive(thread, Parameters::default()).await;
+        let result = dump_dynamic_archive(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/CDS",
+            "dumpDynamicArchive",
+            "(Ljava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
@@ -232,11 +245,14 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.CDS.logLambdaFormInvoker(Ljava/lang/String;)V"
-    )]
     async fn test_log_lambda_form_invoker() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = log_lambda_form_invoker(thread, Parameters::default()).await;
+        let result = log_lambda_form_invoker(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/CDS",
+            "logLambdaFormInvoker",
+            "(Ljava/lang/String;)V",
+        );
     }
 }
@@ -1,4 +1,6 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17};
+use crate::native_access::check_restricted_call;
+use crate::native_methods::java::lang::classloader;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Error::{InternalError, InvalidOperand};
@@ -381,15 +383,20 @@ pub(crate) async fn allocate_instance(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.allocateInstance(Ljava/lang/Class;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "allocateInstance",
+        "(Ljava/lang/Class;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
 pub(crate) async fn allocate_memory_0(
-    _thread: Arc<Thread>,
+    thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.allocateMemory0(J)J")
+    check_restricted_call(&thread).await?;
+    unsatisfied_link(CLASS_NAME, "allocateMemory0", "(J)J")
 }
 
 #[async_recursion(?Send)]
@@ -413,7 +420,11 @@ pub(crate) async fn compare_and_exchange_int(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.compareAndExchangeInt(Ljava/lang/Object;JII)I")
+    unsatisfied_link(
+        CLASS_NAME,
+        "compareAndExchangeInt",
+        "(Ljava/lang/Object;JII)I",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -421,7 +432,11 @@ pub(crate) async fn compare_and_exchange_long(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.compareAndExchangeLong(Ljava/lang/Object;JJJ)J")
+    unsatisfied_link(
+        CLASS_NAME,
+        "compareAndExchangeLong",
+        "(Ljava/lang/Object;JJJ)J",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -429,7 +444,11 @@ pub(crate) async fn compare_and_exchange_object(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.compareAndExchangeObject(Ljava/lang/Object;JLjava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "compareAndExchangeObject",
+        "(Ljava/lang/Object;JLjava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -437,7 +456,11 @@ pub(crate) async fn compare_and_exchange_reference(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.compareAndExchangeReference(Ljava/lang/Object;JLjava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "compareAndExchangeReference",
+        "(Ljava/lang/Object;JLjava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -610,7 +633,11 @@ pub(crate) async fn copy_swap_memory_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.copySwapMemory0(Ljava/lang/Object;JLjava/lang/Object;JJJ)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "copySwapMemory0",
+        "(Ljava/lang/Object;JLjava/lang/Object;JJJ)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -618,15 +645,27 @@ pub(crate) async fn define_anonymous_class_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.defineAnonymousClass0(Ljava/lang/Class;[B[Ljava/lang/Object;)Ljava/lang/Class;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "defineAnonymousClass0",
+        "(Ljava/lang/Class;[B[Ljava/lang/Object;)Ljava/lang/Class;",
+    )
 }
 
 #[async_recursion(?Send)]
 pub(crate) async fn define_class_0(
-    _thread: Arc<Thread>,
-    _parameters: Parameters,
+    thread: Arc<Thread>,
+    mut parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.defineClass0(Ljava/lang/String;[BIILjava/lang/ClassLoader;Ljava/security/ProtectionDomain;)Ljava/lang/Class;")
+    let _protection_domain = parameters.pop_object()?;
+    let class_loader = parameters.pop_object().ok();
+    let length = parameters.pop_int()?;
+    let offset = parameters.pop_int()?;
+    let bytes: Vec<u8> = parameters.pop()?.try_into()?;
+    let class = classloader::class_from_bytes(None, &bytes, offset, length)?;
+    classloader::check_class_name(&class, parameters.pop_reference()?)?;
+    let class = classloader::define_class(&thread, class_loader, class).await?;
+    Ok(Some(class))
 }
 
 #[async_recursion(?Send)]
@@ -639,9 +678,10 @@ pub(crate) async fn ensure_class_initialized_0(
 
 #[async_recursion(?Send)]
 pub(crate) async fn free_memory_0(
-    _thread: Arc<Thread>,
+    thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
+    check_restricted_call(&thread).await?;
     Ok(None)
 }
 
@@ -864,7 +904,7 @@ pub(crate) async fn get_load_average_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.getLoadAverage0([DI)I")
+    unsatisfied_link(CLASS_NAME, "getLoadAverage0", "([DI)I")
 }
 
 #[async_recursion(?Send)]
@@ -933,7 +973,7 @@ pub(crate) async fn get_uncompressed_object(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.getUncompressedObject(J)Ljava/lang/Object;")
+    unsatisfied_link(CLASS_NAME, "getUncompressedObject", "(J)Ljava/lang/Object;")
 }
 
 #[async_recursion(?Send)]
@@ -993,12 +1033,12 @@ pub(crate) async fn page_size(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.pageSize()I")
+    unsatisfied_link(CLASS_NAME, "pageSize", "()I")
 }
 
 #[async_recursion(?Send)]
 pub(crate) async fn park(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.park(ZJ)V")
+    unsatisfied_link(CLASS_NAME, "park", "(ZJ)V")
 }
 
 #[async_recursion(?Send)]
@@ -1234,10 +1274,11 @@ pub(crate) async fn put_short_volatile(
 
 #[async_recursion(?Send)]
 pub(crate) async fn reallocate_memory_0(
-    _thread: Arc<Thread>,
+    thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.reallocateMemory0(JJ)J")
+    check_restricted_call(&thread).await?;
+    unsatisfied_link(CLASS_NAME, "reallocateMemory0", "(JJ)J")
 }
 
 #[async_recursion(?Send)]
@@ -1253,7 +1294,7 @@ pub(crate) async fn set_memory_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.setMemory0(Ljava/lang/Object;JJB)V")
+    unsatisfied_link(CLASS_NAME, "setMemory0", "(Ljava/lang/Object;JJB)V")
 }
 
 #[async_recursion(?Send)]
@@ -1270,7 +1311,11 @@ pub(crate) async fn static_field_base_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.staticFieldBase0(Ljava/lang/reflect/Field;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "staticFieldBase0",
+        "(Ljava/lang/reflect/Field;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -1278,7 +1323,11 @@ pub(crate) async fn static_field_offset_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.staticFieldOffset0(Ljava/lang/reflect/Field;)J")
+    unsatisfied_link(
+        CLASS_NAME,
+        "staticFieldOffset0",
+        "(Ljava/lang/reflect/Field;)J",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -1294,7 +1343,7 @@ pub(crate) async fn throw_exception(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.throwException(Ljava/lang/Throwable;)V")
+    unsatisfied_link(CLASS_NAME, "throwException", "(Ljava/lang/Throwable;)V")
 }
 
 #[async_recursion(?Send)]
@@ -1307,7 +1356,7 @@ pub(crate) async fn unaligned_access_0(
 
 #[async_recursion(?Send)]
 pub(crate) async fn unpark(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.unpark(Ljava/lang/Object;)V")
+    unsatisfied_link(CLASS_NAME, "unpark", "(Ljava/lang/Object;)V")
 }
 
 #[async_recursion(?Send)]
@@ -1315,7 +1364,7 @@ pub(crate) async fn writeback_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.writeback0(J)V")
+    unsatisfied_link(CLASS_NAME, "writeback0", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -1323,7 +1372,7 @@ pub(crate) async fn writeback_post_sync_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.writebackPostSync0()V")
+    unsatisfied_link(CLASS_NAME, "writebackPostSync0", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -1331,12 +1380,13 @@ pub(crate) async fn writeback_pre_sync_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.writebackPreSync0()V")
+    unsatisfied_link(CLASS_NAME, "writebackPreSync0", "()V")
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::java_object::JavaObject;
 
     #[tokio::test]
     async fn test_address_size_0() -> Result<()> {
@@ -1347,19 +1397,27 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.Unsafe.allocateInstance(Ljava/lang/Class;)Ljava/lang/Object;"
-    )]
     async fn test_allocate_instance() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = allocate_instance(thread, Parameters::default()).await;
+        let result = allocate_instance(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/Unsafe",
+            "allocateInstance",
+            "(Ljava/lang/Class;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.internal.misc.Unsafe.allocateMemory0(J)J")]
     async fn test_allocate_memory_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = allocate_memory_0(thread, Parameters::default()).await;
+        let result = allocate_memory_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/Unsafe",
+            "allocateMemory0",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
@@ -1379,66 +1437,96 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.Unsafe.compareAndExchangeInt(Ljava/lang/Object;JII)I"
-    )]
     async fn test_compare_and_exchange_int() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = compare_and_exchange_int(thread, Parameters::default()).await;
+        let result = compare_and_exchange_int(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/Unsafe",
+            "compareAndExchangeInt",
+            "(Ljava/lang/Object;JII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.Unsafe.compareAndExchangeLong(Ljava/lang/Object;JJJ)J"
-    )]
     async fn test_compare_and_exchange_long() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = compare_and_exchange_long(thread, Parameters::default()).await;
+        let result = compare_and_exchange_long(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/Unsafe",
+            "compareAndExchangeLong",
+            "(Ljava/lang/Object;JJJ)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.Unsafe.compareAndExchangeObject(Ljava/lang/Object;JLjava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_compare_and_exchange_object() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = compare_and_exchange_object(thread, Parameters::default()).await;
+        let result = compare_and_exchange_object(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/Unsafe",
+            "compareAndExchangeObject",
+            "(Ljava/lang/Object;JLjava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.Unsafe.compareAndExchangeReference(Ljava/lang/Object;JLjava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;"
-    )]
     async fn test_compare_and_exchange_reference() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = compare_and_exchange_reference(thread, Parameters::default()).await;
+        let result = compare_and_exchange_reference(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/Unsafe",
+            "compareAndExchangeReference",
+            "(Ljava/lang/Object;JLjava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.Unsafe.copySwapMemory0(Ljava/lang/Object;JLjava/lang/Object;JJJ)V"
-    )]
     async fn test_copy_swap_memory_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = copy_swap_memory_0(thread, Parameters::default()).await;
+        let result = copy_swap_memory_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/Unsafe",
+            "copySwapMemory0",
+            "(Ljava/lang/Object;JLjava/lang/Object;JJJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.Unsafe.defineAnonymousClass0(Ljava/lang/Class;[B[Ljava/lang/Object;)Ljava/lang/Class;"
-    )]
     async fn test_define_anonymous_class_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = define_anonymous_class_0(thread, Parameters::default()).await;
+        let result = define_anonymous_class_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/Unsafe",
+            "defineAnonymousClass0",
+            "(Ljava/lang/Class;[B[Ljava/lang/Object;)Ljava/lang/Class;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.Unsafe.defineClass0(Ljava/lang/String;[BIILjava/lang/ClassLoader;Ljava/security/ProtectionDomain;)Ljava/lang/Class;"
-    )]
-    async fn test_define_class_0() {
-        let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = define_class_0(thread, Parameters::default()).await;
+    async fn test_define_class_0() -> Result<()> {
+        let (vm, thread) = crate::test::thread().await?;
+        let class_file = ristretto_classfile::ClassFileBuilder::new("Foo").build()?;
+        let mut bytes = Vec::new();
+        class_file.to_bytes(&mut bytes)?;
+        let length = i32::try_from(bytes.len())?;
+        let parameters = Parameters::new(vec![
+            "Foo".to_object(&vm).await?,
+            Value::from(bytes),
+            Value::Int(0),
+            Value::Int(length),
+            Value::Object(None),
+            Value::Object(None),
+        ]);
+        let result = define_class_0(thread.clone(), parameters).await?;
+        assert!(matches!(result, Some(Value::Object(Some(_)))));
+        assert_eq!("Foo", thread.class("Foo").await?.name());
+        Ok(())
     }
 
     #[tokio::test]
@@ -1466,21 +1554,27 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.Unsafe.getLoadAverage0([DI)I"
-    )]
     async fn test_get_load_average_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_load_average_0(thread, Parameters::default()).await;
+        let result = get_load_average_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/Unsafe",
+            "getLoadAverage0",
+            "([DI)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.Unsafe.getUncompressedObject(J)Ljava/lang/Object;"
-    )]
     async fn test_get_uncompressed_object() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_uncompressed_object(thread, Parameters::default()).await;
+        let result = get_uncompressed_object(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/Unsafe",
+            "getUncompressedObject",
+            "(J)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
@@ -1509,26 +1603,29 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.internal.misc.Unsafe.pageSize()I")]
     async fn test_page_size() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = page_size(thread, Parameters::default()).await;
+        let result = page_size(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/internal/misc/Unsafe", "pageSize", "()I");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.internal.misc.Unsafe.park(ZJ)V")]
     async fn test_park() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = park(thread, Parameters::default()).await;
+        let result = park(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/internal/misc/Unsafe", "park", "(ZJ)V");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.Unsafe.reallocateMemory0(JJ)J"
-    )]
     async fn test_reallocate_memory_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = reallocate_memory_0(thread, Parameters::default()).await;
+        let result = reallocate_memory_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/Unsafe",
+            "reallocateMemory0",
+            "(JJ)J",
+        );
     }
 
     #[tokio::test]
@@ -1540,12 +1637,15 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.Unsafe.setMemory0(Ljava/lang/Object;JJB)V"
-    )]
     async fn test_set_memory_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_memory_0(thread, Parameters::default()).await;
+        let result = set_memory_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/Unsafe",
+            "setMemory0",
+            "(Ljava/lang/Object;JJB)V",
+        );
     }
 
     #[tokio::test]
@@ -1557,21 +1657,27 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.Unsafe.staticFieldBase0(Ljava/lang/reflect/Field;)Ljava/lang/Object;"
-    )]
     async fn test_static_field_base_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = static_field_base_0(thread, Parameters::default()).await;
+        let result = static_field_base_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/Unsafe",
+            "staticFieldBase0",
+            "(Ljava/lang/reflect/Field;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.Unsafe.staticFieldOffset0(Ljava/lang/reflect/Field;)J"
-    )]
     async fn test_static_field_offset_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = static_field_offset_0(thread, Parameters::default()).await;
+        let result = static_field_offset_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/Unsafe",
+            "staticFieldOffset0",
+            "(Ljava/lang/reflect/Field;)J",
+        );
     }
 
     #[tokio::test]
@@ -1583,12 +1689,15 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.Unsafe.throwException(Ljava/lang/Throwable;)V"
-    )]
     async fn test_throw_exception() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = throw_exception(thread, Parameters::default()).await;
+        let result = throw_exception(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/Unsafe",
+            "throwException",
+            "(Ljava/lang/Throwable;)V",
+        );
     }
 
     #[tokio::test]
@@ -1600,34 +1709,50 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.Unsafe.unpark(Ljava/lang/Object;)V"
-    )]
     async fn test_unpark() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = unpark(thread, Parameters::default()).await;
+        let result = unpark(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/Unsafe",
+            "unpark",
+            "(Ljava/lang/Object;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.internal.misc.Unsafe.writeback0(J)V")]
     async fn test_writeback_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = writeback_0(thread, Parameters::default()).await;
+        let result = writeback_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/Unsafe",
+            "writeback0",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.Unsafe.writebackPostSync0()V"
-    )]
     async fn test_writeback_post_sync_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = writeback_post_sync_0(thread, Parameters::default()).await;
+        let result = writeback_post_sync_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/Unsafe",
+            "writebackPostSync0",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.internal.misc.Unsafe.writebackPreSync0()V")]
     async fn test_writeback_pre_sync_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = writeback_pre_sync_0(thread, Parameters::default()).await;
+        let result = writeback_pre_sync_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/Unsafe",
+            "writebackPreSync0",
+            "()V",
+        );
     }
 }
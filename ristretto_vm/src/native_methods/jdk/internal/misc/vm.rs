@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -49,7 +49,7 @@ async fn get_nano_time_adjustment(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.VM.getNanoTimeAdjustment(J)J")
+    unsatisfied_link(CLASS_NAME, "getNanoTimeAdjustment", "(J)J")
 }
 
 #[async_recursion(?Send)]
@@ -57,27 +57,27 @@ async fn get_runtime_arguments(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.VM.getRuntimeArguments()[Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getRuntimeArguments", "()[Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
 async fn getegid(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.VM.getegid()J")
+    unsatisfied_link(CLASS_NAME, "getegid", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn geteuid(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.VM.geteuid()J")
+    unsatisfied_link(CLASS_NAME, "geteuid", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn getgid(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.VM.getgid()J")
+    unsatisfied_link(CLASS_NAME, "getgid", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn getuid(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.VM.getuid()J")
+    unsatisfied_link(CLASS_NAME, "getuid", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -98,7 +98,11 @@ async fn latest_user_defined_loader_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.VM.latestUserDefinedLoader0()Ljava/lang/ClassLoader;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "latestUserDefinedLoader0",
+        "()Ljava/lang/ClassLoader;",
+    )
 }
 
 #[cfg(test)]
@@ -106,49 +110,55 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.VM.getNanoTimeAdjustment(J)J"
-    )]
     async fn test_get_nano_time_adjustment() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_nano_time_adjustment(thread, Parameters::default()).await;
+        let result = get_nano_time_adjustment(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/VM",
+            "getNanoTimeAdjustment",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.VM.getRuntimeArguments()[Ljava/lang/String;"
-    )]
     async fn test_get_runtime_arguments() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_runtime_arguments(thread, Parameters::default()).await;
+        let result = get_runtime_arguments(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/VM",
+            "getRuntimeArguments",
+            "()[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.internal.misc.VM.getegid()J")]
     async fn test_getegid() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = getegid(thread, Parameters::default()).await;
+        let result = getegid(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/internal/misc/VM", "getegid", "()J");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.internal.misc.VM.geteuid()J")]
     async fn test_geteuid() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = geteuid(thread, Parameters::default()).await;
+        let result = geteuid(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/internal/misc/VM", "geteuid", "()J");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.internal.misc.VM.getgid()J")]
     async fn test_getgid() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = getgid(thread, Parameters::default()).await;
+        let result = getgid(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/internal/misc/VM", "getgid", "()J");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.internal.misc.VM.getuid()J")]
     async fn test_getuid() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = getuid(thread, Parameters::default()).await;
+        let result = getuid(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/internal/misc/VM", "getuid", "()J");
     }
 
     #[tokio::test]
@@ -168,11 +178,14 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.misc.VM.latestUserDefinedLoader0()Ljava/lang/ClassLoader;"
-    )]
     async fn test_latest_user_defined_loader_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = latest_user_defined_loader_0(thread, Parameters::default()).await;
+        let result = latest_user_defined_loader_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/misc/VM",
+            "latestUserDefinedLoader0",
+            "()Ljava/lang/ClassLoader;",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_21};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_21};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -43,27 +43,39 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn ioctl_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.org.jline.terminal.impl.jna.osx.CLibraryImpl.ioctl0(IJLjdk.internal.org.jline.terminal.impl.jna.osx.CLibrary$winsize;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "ioctl0",
+        "(IJLjdk/internal/org/jline/terminal/impl/jna/osx/CLibrary$winsize;)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn isatty(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.org.jline.terminal.impl.jna.osx.CLibraryImpl.isatty(I)I")
+    unsatisfied_link(CLASS_NAME, "isatty", "(I)I")
 }
 
 #[async_recursion(?Send)]
 async fn tcgetattr(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.org.jline.terminal.impl.jna.osx.CLibraryImpl.tcgetattr(ILjdk.internal.org.jline.terminal.impl.jna.osx.CLibrary$termios;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "tcgetattr",
+        "(ILjdk/internal/org/jline/terminal/impl/jna/osx/CLibrary$termios;)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn tcsetattr(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.org.jline.terminal.impl.jna.osx.CLibraryImpl.tcsetattr(IILjdk.internal.org.jline.terminal.impl.jna.osx.CLibrary$termios;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "tcsetattr",
+        "(IILjdk/internal/org/jline/terminal/impl/jna/osx/CLibrary$termios;)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn ttyname_r(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.org.jline.terminal.impl.jna.osx.CLibraryImpl.ttyname_r(I[BI)V")
+    unsatisfied_link(CLASS_NAME, "ttyname_r", "(I[BI)V")
 }
 
 #[cfg(test)]
@@ -71,47 +83,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.org.jline.terminal.impl.jna.osx.CLibraryImpl.ioctl0(IJLjdk.internal.org.jline.terminal.impl.jna.osx.CLibrary$winsize;)V"
-    )]
     async fn test_ioctl_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ioctl_0(thread, Parameters::default()).await;
+        let result = ioctl_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/org/jline/terminal/impl/jna/osx/CLibraryImpl",
+            "ioctl0",
+            "(IJLjdk/internal/org/jline/terminal/impl/jna/osx/CLibrary$winsize;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.org.jline.terminal.impl.jna.osx.CLibraryImpl.isatty(I)I"
-    )]
     async fn test_isatty() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = isatty(thread, Parameters::default()).await;
+        let result = isatty(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/org/jline/terminal/impl/jna/osx/CLibraryImpl",
+            "isatty",
+            "(I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.org.jline.terminal.impl.jna.osx.CLibraryImpl.tcgetattr(ILjdk.internal.org.jline.terminal.impl.jna.osx.CLibrary$termios;)V"
-    )]
     async fn test_tcgetattr() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = tcgetattr(thread, Parameters::default()).await;
+        let result = tcgetattr(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/org/jline/terminal/impl/jna/osx/CLibraryImpl",
+            "tcgetattr",
+            "(ILjdk/internal/org/jline/terminal/impl/jna/osx/CLibrary$termios;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.org.jline.terminal.impl.jna.osx.CLibraryImpl.tcsetattr(IILjdk.internal.org.jline.terminal.impl.jna.osx.CLibrary$termios;)V"
-    )]
     async fn test_tcsetattr() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = tcsetattr(thread, Parameters::default()).await;
+        let result = tcsetattr(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/org/jline/terminal/impl/jna/osx/CLibraryImpl",
+            "tcsetattr",
+            "(IILjdk/internal/org/jline/terminal/impl/jna/osx/CLibrary$termios;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.org.jline.terminal.impl.jna.osx.CLibraryImpl.ttyname_r(I[BI)V"
-    )]
     async fn test_ttyname_r() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ttyname_r(thread, Parameters::default()).await;
+        let result = ttyname_r(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/org/jline/terminal/impl/jna/osx/CLibraryImpl",
+            "ttyname_r",
+            "(I[BI)V",
+        );
     }
 }
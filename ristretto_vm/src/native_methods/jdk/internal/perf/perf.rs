@@ -1,9 +1,11 @@
 use crate::native_methods::registry::{MethodRegistry, JAVA_17};
 use crate::parameters::Parameters;
+use crate::perf_data::HIGH_RES_FREQUENCY;
 use crate::thread::Thread;
+use crate::JavaError::{IllegalArgumentException, NullPointerException};
 use crate::Result;
 use async_recursion::async_recursion;
-use ristretto_classloader::Value;
+use ristretto_classloader::{ConcurrentVec, Reference, Value};
 use std::sync::Arc;
 
 const CLASS_NAME: &str = "jdk/internal/perf/Perf";
@@ -39,110 +41,213 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
     registry.register(CLASS_NAME, "registerNatives", "()V", register_natives);
 }
 
+/// Wrap the data of a performance counter, or a snapshot of the counters, in a `ByteBuffer`.
+async fn byte_buffer(thread: &Thread, data: ConcurrentVec<i8>) -> Result<Value> {
+    let class = thread.class("java.nio.ByteBuffer").await?;
+    let method = class.try_get_method("wrap", "([B)Ljava/nio/ByteBuffer;")?;
+    let array = Value::Object(Some(Reference::ByteArray(data)));
+    thread.try_execute(&class, &method, vec![array]).await
+}
+
+/// Attach to the performance counters of the VM with the local VM identifier; only the counters
+/// of the current VM (identifier 0 or the process id) are supported.  The counters are returned
+/// as a snapshot in the `hsperfdata` layout.
+async fn attach_vm(thread: &Thread, lvmid: i32) -> Result<Option<Value>> {
+    let process_id = i32::try_from(std::process::id())?;
+    if lvmid != 0 && lvmid != process_id {
+        return Err(IllegalArgumentException(format!(
+            "attaching to the performance counters of process {lvmid} is not supported"
+        ))
+        .into());
+    }
+    let vm = thread.vm()?;
+    let bytes = vm
+        .perf_data()
+        .to_bytes()?
+        .into_iter()
+        .map(|byte| byte as i8)
+        .collect::<Vec<i8>>();
+    let buffer = byte_buffer(thread, ConcurrentVec::from(bytes)).await?;
+    Ok(Some(buffer))
+}
+
 #[async_recursion(?Send)]
-async fn attach(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.perf.Perf.attach(Ljava/lang/String;II)Ljava/nio/ByteBuffer;")
+pub(crate) async fn attach(
+    thread: Arc<Thread>,
+    mut parameters: Parameters,
+) -> Result<Option<Value>> {
+    let _mode = parameters.pop_int()?;
+    let lvmid = parameters.pop_int()?;
+    let _user = parameters.pop_reference()?;
+    attach_vm(&thread, lvmid).await
 }
 
 #[async_recursion(?Send)]
-async fn attach_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.perf.Perf.attach0(I)Ljava/nio/ByteBuffer;")
+async fn attach_0(thread: Arc<Thread>, mut parameters: Parameters) -> Result<Option<Value>> {
+    let lvmid = parameters.pop_int()?;
+    attach_vm(&thread, lvmid).await
 }
 
 #[async_recursion(?Send)]
-async fn create_byte_array(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.perf.Perf.createByteArray(Ljava/lang/String;II[BI)Ljava/nio/ByteBuffer;")
+pub(crate) async fn create_byte_array(
+    thread: Arc<Thread>,
+    mut parameters: Parameters,
+) -> Result<Option<Value>> {
+    let max_length = usize::try_from(parameters.pop_int()?)?;
+    let value = match parameters.pop_reference()? {
+        Some(Reference::ByteArray(value)) => value.to_vec()?,
+        _ => return Err(NullPointerException("value cannot be null".to_string()).into()),
+    };
+    let units = u8::try_from(parameters.pop_int()?)?;
+    let variability = u8::try_from(parameters.pop_int()?)?;
+    let name: String = parameters.pop_object()?.try_into()?;
+    let vm = thread.vm()?;
+    let data = vm
+        .perf_data()
+        .create_byte_array(&name, variability, units, &value, max_length)?;
+    let buffer = byte_buffer(&thread, data).await?;
+    Ok(Some(buffer))
 }
 
 #[async_recursion(?Send)]
-async fn create_long(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.perf.Perf.createLong(Ljava/lang/String;IIJ)Ljava/nio/ByteBuffer;")
+pub(crate) async fn create_long(
+    thread: Arc<Thread>,
+    mut parameters: Parameters,
+) -> Result<Option<Value>> {
+    let value = parameters.pop_long()?;
+    let units = u8::try_from(parameters.pop_int()?)?;
+    let variability = u8::try_from(parameters.pop_int()?)?;
+    let name: String = parameters.pop_object()?.try_into()?;
+    let vm = thread.vm()?;
+    let data = vm
+        .perf_data()
+        .create_long(&name, variability, units, value)?;
+    let buffer = byte_buffer(&thread, data).await?;
+    Ok(Some(buffer))
 }
 
 #[async_recursion(?Send)]
-async fn detach(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.perf.Perf.detach(Ljava/nio/ByteBuffer;)V")
+pub(crate) async fn detach(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+    // Attached counters are snapshots that are released by the garbage collector.
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
-async fn high_res_counter(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.perf.Perf.highResCounter()J")
+pub(crate) async fn high_res_counter(
+    thread: Arc<Thread>,
+    _parameters: Parameters,
+) -> Result<Option<Value>> {
+    let vm = thread.vm()?;
+    Ok(Some(Value::Long(vm.perf_data().high_res_counter())))
 }
 
 #[async_recursion(?Send)]
-async fn high_res_frequency(
+pub(crate) async fn high_res_frequency(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.perf.Perf.highResFrequency()J")
+    Ok(Some(Value::Long(HIGH_RES_FREQUENCY)))
 }
 
 #[async_recursion(?Send)]
-async fn register_natives(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+pub(crate) async fn register_natives(
+    _thread: Arc<Thread>,
+    _parameters: Parameters,
+) -> Result<Option<Value>> {
     Ok(None)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::java_object::JavaObject;
+    use crate::PerfValue;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.perf.Perf.attach(Ljava/lang/String;II)Ljava/nio/ByteBuffer;"
-    )]
-    async fn test_attach() {
-        let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = attach(thread, Parameters::default()).await;
+    async fn test_attach_0() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let mut parameters = Parameters::default();
+        parameters.push_int(0);
+        let result = attach_0(thread, parameters).await?;
+        assert!(matches!(result, Some(Value::Object(Some(_)))));
+        Ok(())
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.perf.Perf.attach0(I)Ljava/nio/ByteBuffer;"
-    )]
-    async fn test_attach_0() {
-        let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = attach_0(thread, Parameters::default()).await;
+    async fn test_attach_0_other_process() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let mut parameters = Parameters::default();
+        parameters.push_int(-1);
+        let result = attach_0(thread, parameters).await;
+        assert!(matches!(
+            result,
+            Err(crate::Error::JavaError(IllegalArgumentException(_)))
+        ));
+        Ok(())
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.perf.Perf.createByteArray(Ljava/lang/String;II[BI)Ljava/nio/ByteBuffer;"
-    )]
-    async fn test_create_byte_array() {
-        let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_byte_array(thread, Parameters::default()).await;
+    async fn test_create_long() -> Result<()> {
+        let (vm, thread) = crate::test::thread().await?;
+        let name = "test.counter".to_object(&vm).await?;
+        let mut parameters = Parameters::default();
+        parameters.push_reference(None);
+        parameters.push(name);
+        parameters.push_int(3);
+        parameters.push_int(4);
+        parameters.push_long(42);
+        let result = create_long(thread, parameters).await?;
+        assert!(matches!(result, Some(Value::Object(Some(_)))));
+        assert_eq!(
+            Some(PerfValue::Long(42)),
+            vm.perf_data().counter("test.counter")?
+        );
+        Ok(())
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.perf.Perf.createLong(Ljava/lang/String;IIJ)Ljava/nio/ByteBuffer;"
-    )]
-    async fn test_create_long() {
-        let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_long(thread, Parameters::default()).await;
+    async fn test_create_byte_array() -> Result<()> {
+        let (vm, thread) = crate::test::thread().await?;
+        let name = "test.string".to_object(&vm).await?;
+        let value = Reference::from(vec![104i8, 105]);
+        let mut parameters = Parameters::default();
+        parameters.push_reference(None);
+        parameters.push(name);
+        parameters.push_int(1);
+        parameters.push_int(5);
+        parameters.push_reference(Some(value));
+        parameters.push_int(4);
+        let result = create_byte_array(thread, parameters).await?;
+        assert!(matches!(result, Some(Value::Object(Some(_)))));
+        assert_eq!(
+            Some(PerfValue::Bytes(vec![104, 105, 0, 0])),
+            vm.perf_data().counter("test.string")?
+        );
+        Ok(())
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.perf.Perf.detach(Ljava/nio/ByteBuffer;)V"
-    )]
-    async fn test_detach() {
-        let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = detach(thread, Parameters::default()).await;
+    async fn test_detach() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let result = detach(thread, Parameters::default()).await?;
+        assert_eq!(None, result);
+        Ok(())
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.internal.perf.Perf.highResCounter()J")]
-    async fn test_high_res_counter() {
-        let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = high_res_counter(thread, Parameters::default()).await;
+    async fn test_high_res_counter() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let result = high_res_counter(thread, Parameters::default()).await?;
+        assert!(matches!(result, Some(Value::Long(counter)) if counter >= 0));
+        Ok(())
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.internal.perf.Perf.highResFrequency()J")]
-    async fn test_high_res_frequency() {
-        let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = high_res_frequency(thread, Parameters::default()).await;
+    async fn test_high_res_frequency() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let result = high_res_frequency(thread, Parameters::default()).await?;
+        assert_eq!(Some(Value::Long(1_000_000_000)), result);
+        Ok(())
     }
 
     #[tokio::test]
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -118,7 +118,11 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn get_class_at_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.reflect.ConstantPool.getClassAt0(Ljava/lang/Object;I)Ljava/lang/Class;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getClassAt0",
+        "(Ljava/lang/Object;I)Ljava/lang/Class;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -126,7 +130,11 @@ async fn get_class_at_if_loaded_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.reflect.ConstantPool.getClassAtIfLoaded0(Ljava/lang/Object;I)Ljava/lang/Class;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getClassAtIfLoaded0",
+        "(Ljava/lang/Object;I)Ljava/lang/Class;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -134,17 +142,21 @@ async fn get_class_ref_index_at_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.reflect.ConstantPool.getClassRefIndexAt0(Ljava/lang/Object;I)I")
+    unsatisfied_link(CLASS_NAME, "getClassRefIndexAt0", "(Ljava/lang/Object;I)I")
 }
 
 #[async_recursion(?Send)]
 async fn get_double_at_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.reflect.ConstantPool.getDoubleAt0(Ljava/lang/Object;I)D")
+    unsatisfied_link(CLASS_NAME, "getDoubleAt0", "(Ljava/lang/Object;I)D")
 }
 
 #[async_recursion(?Send)]
 async fn get_field_at_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.reflect.ConstantPool.getFieldAt0(Ljava/lang/Object;I)Ljava/lang/reflect/Field;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getFieldAt0",
+        "(Ljava/lang/Object;I)Ljava/lang/reflect/Field;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -152,22 +164,26 @@ async fn get_field_at_if_loaded_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.reflect.ConstantPool.getFieldAtIfLoaded0(Ljava/lang/Object;I)Ljava/lang/reflect/Field;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getFieldAtIfLoaded0",
+        "(Ljava/lang/Object;I)Ljava/lang/reflect/Field;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_float_at_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.reflect.ConstantPool.getFloatAt0(Ljava/lang/Object;I)F")
+    unsatisfied_link(CLASS_NAME, "getFloatAt0", "(Ljava/lang/Object;I)F")
 }
 
 #[async_recursion(?Send)]
 async fn get_int_at_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.reflect.ConstantPool.getIntAt0(Ljava/lang/Object;I)I")
+    unsatisfied_link(CLASS_NAME, "getIntAt0", "(Ljava/lang/Object;I)I")
 }
 
 #[async_recursion(?Send)]
 async fn get_long_at_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.reflect.ConstantPool.getLongAt0(Ljava/lang/Object;I)J")
+    unsatisfied_link(CLASS_NAME, "getLongAt0", "(Ljava/lang/Object;I)J")
 }
 
 #[async_recursion(?Send)]
@@ -175,12 +191,20 @@ async fn get_member_ref_info_at_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.reflect.ConstantPool.getMemberRefInfoAt0(Ljava/lang/Object;I)[Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getMemberRefInfoAt0",
+        "(Ljava/lang/Object;I)[Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_method_at_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.reflect.ConstantPool.getMethodAt0(Ljava/lang/Object;I)Ljava/lang/reflect/Member;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getMethodAt0",
+        "(Ljava/lang/Object;I)Ljava/lang/reflect/Member;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -188,7 +212,11 @@ async fn get_method_at_if_loaded_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.reflect.ConstantPool.getMethodAtIfLoaded0(Ljava/lang/Object;I)Ljava/lang/reflect/Member;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getMethodAtIfLoaded0",
+        "(Ljava/lang/Object;I)Ljava/lang/reflect/Member;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -196,7 +224,11 @@ async fn get_name_and_type_ref_index_at_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.reflect.ConstantPool.getNameAndTypeRefIndexAt0(Ljava/lang/Object;I)I")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getNameAndTypeRefIndexAt0",
+        "(Ljava/lang/Object;I)I",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -204,27 +236,39 @@ async fn get_name_and_type_ref_info_at_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.reflect.ConstantPool.getNameAndTypeRefInfoAt0(Ljava/lang/Object;I)[Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getNameAndTypeRefInfoAt0",
+        "(Ljava/lang/Object;I)[Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_size_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.reflect.ConstantPool.getSize0(Ljava/lang/Object;)I")
+    unsatisfied_link(CLASS_NAME, "getSize0", "(Ljava/lang/Object;)I")
 }
 
 #[async_recursion(?Send)]
 async fn get_string_at_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.reflect.ConstantPool.getStringAt0(Ljava/lang/Object;I)Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getStringAt0",
+        "(Ljava/lang/Object;I)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_tag_at_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.reflect.ConstantPool.getTagAt0(Ljava/lang/Object;I)B")
+    unsatisfied_link(CLASS_NAME, "getTagAt0", "(Ljava/lang/Object;I)B")
 }
 
 #[async_recursion(?Send)]
 async fn get_utf_8_at_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.reflect.ConstantPool.getUTF8At0(Ljava/lang/Object;I)Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getUTF8At0",
+        "(Ljava/lang/Object;I)Ljava/lang/String;",
+    )
 }
 
 #[cfg(test)]
@@ -232,164 +276,218 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.reflect.ConstantPool.getClassAt0(Ljava/lang/Object;I)Ljava/lang/Class;"
-    )]
     async fn test_get_class_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_class_at_0(thread, Parameters::default()).await;
+        let result = get_class_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/reflect/ConstantPool",
+            "getClassAt0",
+            "(Ljava/lang/Object;I)Ljava/lang/Class;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.reflect.ConstantPool.getClassAtIfLoaded0(Ljava/lang/Object;I)Ljava/lang/Class;"
-    )]
     async fn test_get_class_at_if_loaded_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_class_at_if_loaded_0(thread, Parameters::default()).await;
+        let result = get_class_at_if_loaded_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/reflect/ConstantPool",
+            "getClassAtIfLoaded0",
+            "(Ljava/lang/Object;I)Ljava/lang/Class;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.reflect.ConstantPool.getClassRefIndexAt0(Ljava/lang/Object;I)I"
-    )]
     async fn test_get_class_ref_index_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_class_ref_index_at_0(thread, Parameters::default()).await;
+        let result = get_class_ref_index_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/reflect/ConstantPool",
+            "getClassRefIndexAt0",
+            "(Ljava/lang/Object;I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.reflect.ConstantPool.getDoubleAt0(Ljava/lang/Object;I)D"
-    )]
     async fn test_get_double_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_double_at_0(thread, Parameters::default()).await;
+        let result = get_double_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/reflect/ConstantPool",
+            "getDoubleAt0",
+            "(Ljava/lang/Object;I)D",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.reflect.ConstantPool.getFieldAt0(Ljava/lang/Object;I)Ljava/lang/reflect/Field;"
-    )]
     async fn test_get_field_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_field_at_0(thread, Parameters::default()).await;
+        let result = get_field_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/reflect/ConstantPool",
+            "getFieldAt0",
+            "(Ljava/lang/Object;I)Ljava/lang/reflect/Field;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.reflect.ConstantPool.getFieldAtIfLoaded0(Ljava/lang/Object;I)Ljava/lang/reflect/Field;"
-    )]
     async fn test_get_field_at_if_loaded_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_field_at_if_loaded_0(thread, Parameters::default()).await;
+        let result = get_field_at_if_loaded_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/reflect/ConstantPool",
+            "getFieldAtIfLoaded0",
+            "(Ljava/lang/Object;I)Ljava/lang/reflect/Field;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.reflect.ConstantPool.getFloatAt0(Ljava/lang/Object;I)F"
-    )]
     async fn test_get_float_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_float_at_0(thread, Parameters::default()).await;
+        let result = get_float_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/reflect/ConstantPool",
+            "getFloatAt0",
+            "(Ljava/lang/Object;I)F",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.reflect.ConstantPool.getIntAt0(Ljava/lang/Object;I)I"
-    )]
     async fn test_get_int_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_int_at_0(thread, Parameters::default()).await;
+        let result = get_int_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/reflect/ConstantPool",
+            "getIntAt0",
+            "(Ljava/lang/Object;I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.reflect.ConstantPool.getLongAt0(Ljava/lang/Object;I)J"
-    )]
     async fn test_get_long_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_long_at_0(thread, Parameters::default()).await;
+        let result = get_long_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/reflect/ConstantPool",
+            "getLongAt0",
+            "(Ljava/lang/Object;I)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.reflect.ConstantPool.getMemberRefInfoAt0(Ljava/lang/Object;I)[Ljava/lang/String;"
-    )]
     async fn test_get_member_ref_info_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_member_ref_info_at_0(thread, Parameters::default()).await;
+        let result = get_member_ref_info_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/reflect/ConstantPool",
+            "getMemberRefInfoAt0",
+            "(Ljava/lang/Object;I)[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.reflect.ConstantPool.getMethodAt0(Ljava/lang/Object;I)Ljava/lang/reflect/Member;"
-    )]
     async fn test_get_method_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_method_at_0(thread, Parameters::default()).await;
+        let result = get_method_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/reflect/ConstantPool",
+            "getMethodAt0",
+            "(Ljava/lang/Object;I)Ljava/lang/reflect/Member;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.reflect.ConstantPool.getMethodAtIfLoaded0(Ljava/lang/Object;I)Ljava/lang/reflect/Member;"
-    )]
     async fn test_get_method_at_if_loaded_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_method_at_if_loaded_0(thread, Parameters::default()).await;
+        let result = get_method_at_if_loaded_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/reflect/ConstantPool",
+            "getMethodAtIfLoaded0",
+            "(Ljava/lang/Object;I)Ljava/lang/reflect/Member;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.reflect.ConstantPool.getNameAndTypeRefIndexAt0(Ljava/lang/Object;I)I"
-    )]
     async fn test_get_name_and_type_ref_index_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_name_and_type_ref_index_at_0(thread, Parameters::default()).await;
+        let result = get_name_and_type_ref_index_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/reflect/ConstantPool",
+            "getNameAndTypeRefIndexAt0",
+            "(Ljava/lang/Object;I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.reflect.ConstantPool.getNameAndTypeRefInfoAt0(Ljava/lang/Object;I)[Ljava/lang/String;"
-    )]
     async fn test_get_name_and_type_ref_info_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_name_and_type_ref_info_at_0(thread, Parameters::default()).await;
+        let result = get_name_and_type_ref_info_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/reflect/ConstantPool",
+            "getNameAndTypeRefInfoAt0",
+            "(Ljava/lang/Object;I)[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.reflect.ConstantPool.getSize0(Ljava/lang/Object;)I"
-    )]
     async fn test_get_size_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_size_0(thread, Parameters::default()).await;
+        let result = get_size_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/reflect/ConstantPool",
+            "getSize0",
+            "(Ljava/lang/Object;)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.reflect.ConstantPool.getStringAt0(Ljava/lang/Object;I)Ljava/lang/String;"
-    )]
     async fn test_get_string_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_string_at_0(thread, Parameters::default()).await;
+        let result = get_string_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/reflect/ConstantPool",
+            "getStringAt0",
+            "(Ljava/lang/Object;I)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.reflect.ConstantPool.getTagAt0(Ljava/lang/Object;I)B"
-    )]
     async fn test_get_tag_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_tag_at_0(thread, Parameters::default()).await;
+        let result = get_tag_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/reflect/ConstantPool",
+            "getTagAt0",
+            "(Ljava/lang/Object;I)B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.reflect.ConstantPool.getUTF8At0(Ljava/lang/Object;I)Ljava/lang/String;"
-    )]
     async fn test_get_utf_8_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_utf_8_at_0(thread, Parameters::default()).await;
+        let result = get_utf_8_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/reflect/ConstantPool",
+            "getUTF8At0",
+            "(Ljava/lang/Object;I)Ljava/lang/String;",
+        );
     }
 }
@@ -1,5 +1,6 @@
+use crate::frame::Frame;
 use crate::java_object::JavaObject;
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Error::InternalError;
@@ -34,29 +35,54 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn are_nest_mates(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.reflect.Reflection.areNestMates(Ljava/lang/Class;Ljava/lang/Class;)Z")
+    unsatisfied_link(
+        CLASS_NAME,
+        "areNestMates",
+        "(Ljava/lang/Class;Ljava/lang/Class;)Z",
+    )
 }
 
+/// Get the class of the method that called the caller sensitive method that invoked
+/// `getCallerClass`.  Native methods do not have frames, so the last frame is the caller sensitive
+/// method; frames of the reflection implementation are skipped so that the class that invoked a
+/// method reflectively is returned.
 #[async_recursion(?Send)]
 pub(crate) async fn get_caller_class(
     thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
     let frames = thread.frames().await?;
-    for frame in frames.iter().rev() {
-        let class = frame.class();
-        let class_name = class.name();
-
-        if class_name == "java/lang/MethodHandles" {
+    for frame in frames.iter().rev().skip(1) {
+        if is_reflection_frame(frame)? {
             continue;
         }
 
         let vm = thread.vm()?;
-        let class = thread.class(class_name).await?;
+        let class = thread.class(frame.class().name()).await?;
         let class = class.to_object(&vm).await?;
         return Ok(Some(class));
     }
-    Ok(None)
+    Ok(Some(Value::Object(None)))
+}
+
+/// Determine if a frame is part of the reflection implementation, i.e. `Method.invoke` or a
+/// method or constructor accessor.
+fn is_reflection_frame(frame: &Frame) -> Result<bool> {
+    if frame.method().name() == "invoke" && frame.class().name() == "java/lang/reflect/Method" {
+        return Ok(true);
+    }
+    let mut class = Some(frame.class().clone());
+    while let Some(current_class) = class {
+        if matches!(
+            current_class.name(),
+            "jdk/internal/reflect/MethodAccessorImpl"
+                | "jdk/internal/reflect/ConstructorAccessorImpl"
+        ) {
+            return Ok(true);
+        }
+        class = current_class.parent()?;
+    }
+    Ok(false)
 }
 
 #[async_recursion(?Send)]
@@ -83,12 +109,15 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.reflect.Reflection.areNestMates(Ljava/lang/Class;Ljava/lang/Class;)Z"
-    )]
     async fn test_are_nest_mates() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = are_nest_mates(thread, Parameters::default()).await;
+        let result = are_nest_mates(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/reflect/Reflection",
+            "areNestMates",
+            "(Ljava/lang/Class;Ljava/lang/Class;)Z",
+        );
     }
 
     #[tokio::test]
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_21};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_21};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -33,22 +33,30 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn do_yield(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.vm.Continuation.doYield()I")
+    unsatisfied_link(CLASS_NAME, "doYield", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn enter_special(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.vm.Continuation.enterSpecial(Ljdk/internal/vm/Continuation;ZZ)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "enterSpecial",
+        "(Ljdk/internal/vm/Continuation;ZZ)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn is_pinned_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.vm.Continuation.isPinned0(Ljdk/internal/vm/ContinuationScope;)I")
+    unsatisfied_link(
+        CLASS_NAME,
+        "isPinned0",
+        "(Ljdk/internal/vm/ContinuationScope;)I",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn pin(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.vm.Continuation.pin()V")
+    unsatisfied_link(CLASS_NAME, "pin", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -58,7 +66,7 @@ async fn register_natives(_thread: Arc<Thread>, _parameters: Parameters) -> Resu
 
 #[async_recursion(?Send)]
 async fn unpin(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.vm.Continuation.unpin()V")
+    unsatisfied_link(CLASS_NAME, "unpin", "()V")
 }
 
 #[cfg(test)]
@@ -66,35 +74,46 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.internal.vm.Continuation.doYield()I")]
     async fn test_do_yield() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = do_yield(thread, Parameters::default()).await;
+        let result = do_yield(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/vm/Continuation",
+            "doYield",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.vm.Continuation.enterSpecial(Ljdk/internal/vm/Continuation;ZZ)V"
-    )]
     async fn test_enter_special() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = enter_special(thread, Parameters::default()).await;
+        let result = enter_special(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/vm/Continuation",
+            "enterSpecial",
+            "(Ljdk/internal/vm/Continuation;ZZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.vm.Continuation.isPinned0(Ljdk/internal/vm/ContinuationScope;)I"
-    )]
     async fn test_is_pinned_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_pinned_0(thread, Parameters::default()).await;
+        let result = is_pinned_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/vm/Continuation",
+            "isPinned0",
+            "(Ljdk/internal/vm/ContinuationScope;)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.internal.vm.Continuation.pin()V")]
     async fn test_pin() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = pin(thread, Parameters::default()).await;
+        let result = pin(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/internal/vm/Continuation", "pin", "()V");
     }
 
     #[tokio::test]
@@ -106,9 +125,14 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.internal.vm.Continuation.unpin()V")]
     async fn test_unpin() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = unpin(thread, Parameters::default()).await;
+        let result = unpin(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/vm/Continuation",
+            "unpin",
+            "()V",
+        );
     }
 }
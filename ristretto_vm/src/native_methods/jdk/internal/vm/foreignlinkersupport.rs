@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -15,7 +15,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn is_supported_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.internal.vm.ForeignLinkerSupport.isSupported0()Z")
+    unsatisfied_link(CLASS_NAME, "isSupported0", "()Z")
 }
 
 #[cfg(test)]
@@ -23,11 +23,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.vm.ForeignLinkerSupport.isSupported0()Z"
-    )]
     async fn test_is_supported_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_supported_0(thread, Parameters::default()).await;
+        let result = is_supported_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/vm/ForeignLinkerSupport",
+            "isSupported0",
+            "()Z",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -24,10 +24,27 @@ async fn get_max_lane_count(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.vm.vector.VectorSupport.getMaxLaneCount(Ljava/lang/Class;)I")
+    unsatisfied_link(CLASS_NAME, "getMaxLaneCount", "(Ljava/lang/Class;)I")
 }
 
 #[async_recursion(?Send)]
 async fn register_natives(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
     Ok(None)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_max_lane_count() {
+        let (_vm, thread) = crate::test::thread().await.expect("thread");
+        let result = get_max_lane_count(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/vm/vector/VectorSupport",
+            "getMaxLaneCount",
+            "(Ljava/lang/Class;)I",
+        );
+    }
+}
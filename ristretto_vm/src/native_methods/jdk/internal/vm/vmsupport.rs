@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -29,7 +29,11 @@ async fn get_vm_temporary_directory(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.vm.VMSupport.getVMTemporaryDirectory()Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getVMTemporaryDirectory",
+        "()Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -37,7 +41,11 @@ async fn init_agent_properties(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.vm.VMSupport.initAgentProperties(Ljava/util/Properties;)Ljava/util/Properties;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "initAgentProperties",
+        "(Ljava/util/Properties;)Ljava/util/Properties;",
+    )
 }
 
 #[cfg(test)]
@@ -45,20 +53,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.vm.VMSupport.getVMTemporaryDirectory()Ljava/lang/String;"
-    )]
     async fn test_get_vm_temporary_directory() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_vm_temporary_directory(thread, Parameters::default()).await;
+        let result = get_vm_temporary_directory(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/vm/VMSupport",
+            "getVMTemporaryDirectory",
+            "()Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.internal.vm.VMSupport.initAgentProperties(Ljava/util/Properties;)Ljava/util/Properties;"
-    )]
     async fn test_init_agent_properties() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_agent_properties(thread, Parameters::default()).await;
+        let result = init_agent_properties(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/internal/vm/VMSupport",
+            "initAgentProperties",
+            "(Ljava/util/Properties;)Ljava/util/Properties;",
+        );
     }
 }
@@ -1,4 +1,6 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17, JAVA_21, JAVA_23};
+use crate::native_methods::registry::{
+    unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17, JAVA_21, JAVA_23,
+};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -361,7 +363,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn abort(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.abort(Ljava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "abort", "(Ljava/lang/String;)V")
 }
 
 #[async_recursion(?Send)]
@@ -369,42 +371,42 @@ async fn add_string_constant(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.addStringConstant(JLjava/lang/String;)Z")
+    unsatisfied_link(CLASS_NAME, "addStringConstant", "(JLjava/lang/String;)Z")
 }
 
 #[async_recursion(?Send)]
 async fn begin_recording(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.beginRecording()V")
+    unsatisfied_link(CLASS_NAME, "beginRecording", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn commit(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.commit(J)J")
+    unsatisfied_link(CLASS_NAME, "commit", "(J)J")
 }
 
 #[async_recursion(?Send)]
 async fn counter_time(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.counterTime()J")
+    unsatisfied_link(CLASS_NAME, "counterTime", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn create_jfr(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.createJFR(Z)Z")
+    unsatisfied_link(CLASS_NAME, "createJFR", "(Z)Z")
 }
 
 #[async_recursion(?Send)]
 async fn destroy_jfr(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.destroyJFR()Z")
+    unsatisfied_link(CLASS_NAME, "destroyJFR", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn emit_data_loss(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.emitDataLoss(J)V")
+    unsatisfied_link(CLASS_NAME, "emitDataLoss", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn emit_event(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.emitEvent(JJJ)Z")
+    unsatisfied_link(CLASS_NAME, "emitEvent", "(JJJ)Z")
 }
 
 #[async_recursion(?Send)]
@@ -412,22 +414,22 @@ async fn emit_old_object_samples(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.emitOldObjectSamples(JZZ)V")
+    unsatisfied_link(CLASS_NAME, "emitOldObjectSamples", "(JZZ)V")
 }
 
 #[async_recursion(?Send)]
 async fn end_recording(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.endRecording()V")
+    unsatisfied_link(CLASS_NAME, "endRecording", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn exclude(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.exclude(Ljava/lang/Thread;)V")
+    unsatisfied_link(CLASS_NAME, "exclude", "(Ljava/lang/Thread;)V")
 }
 
 #[async_recursion(?Send)]
 async fn flush(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.flush()")
+    unsatisfied_link(CLASS_NAME, "flush", "()")
 }
 
 #[async_recursion(?Send)]
@@ -435,7 +437,7 @@ async fn get_all_event_classes(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.getAllEventClasses()Ljava/util/List;")
+    unsatisfied_link(CLASS_NAME, "getAllEventClasses", "()Ljava/util/List;")
 }
 
 #[async_recursion(?Send)]
@@ -443,7 +445,7 @@ async fn get_allowed_to_do_event_retransforms(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.getAllowedToDoEventRetransforms()Z")
+    unsatisfied_link(CLASS_NAME, "getAllowedToDoEventRetransforms", "()Z")
 }
 
 #[async_recursion(?Send)]
@@ -451,12 +453,12 @@ async fn get_chunk_start_nanos(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.getChunkStartNanos()J")
+    unsatisfied_link(CLASS_NAME, "getChunkStartNanos", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn get_class_id(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.getClassId(Ljava/lang/Class;)J")
+    unsatisfied_link(CLASS_NAME, "getClassId", "(Ljava/lang/Class;)J")
 }
 
 #[async_recursion(?Send)]
@@ -464,32 +466,40 @@ async fn get_class_id_non_intrinsic(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.getClassIdNonIntrinsic(Ljava/lang/Class;)J")
+    unsatisfied_link(CLASS_NAME, "getClassIdNonIntrinsic", "(Ljava/lang/Class;)J")
 }
 
 #[async_recursion(?Send)]
 async fn get_configuration(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.getConfiguration(Ljava/lang/Class;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getConfiguration",
+        "(Ljava/lang/Class;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_dump_path(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.getDumpPath()Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getDumpPath", "()Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
 async fn get_event_writer(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.getEventWriter()Ljava/lang/Object;")
+    unsatisfied_link(CLASS_NAME, "getEventWriter", "()Ljava/lang/Object;")
 }
 
 #[async_recursion(?Send)]
 async fn get_handler(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.getHandler(Ljava/lang/Class;)Ljava/lang")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getHandler",
+        "(Ljava/lang/Class;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_pid(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.getPid()Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getPid", "()Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
@@ -497,12 +507,12 @@ async fn get_stack_trace_id(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.getStackTraceId(IJ)J")
+    unsatisfied_link(CLASS_NAME, "getStackTraceId", "(IJ)J")
 }
 
 #[async_recursion(?Send)]
 async fn get_thread_id(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.getThreadId(Ljava/lang/Thread;)J")
+    unsatisfied_link(CLASS_NAME, "getThreadId", "(Ljava/lang/Thread;)J")
 }
 
 #[async_recursion(?Send)]
@@ -510,7 +520,7 @@ async fn get_ticks_frequency(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.getTicksFrequency()J")
+    unsatisfied_link(CLASS_NAME, "getTicksFrequency", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -518,12 +528,12 @@ async fn get_time_conversion_factor(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.getTimeConversionFactor()D")
+    unsatisfied_link(CLASS_NAME, "getTimeConversionFactor", "()D")
 }
 
 #[async_recursion(?Send)]
 async fn get_type_id(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.getTypeId(Ljava/lang/String;)J")
+    unsatisfied_link(CLASS_NAME, "getTypeId", "(Ljava/lang/String;)J")
 }
 
 #[async_recursion(?Send)]
@@ -531,12 +541,12 @@ async fn get_unloaded_event_class_count(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.getUnloadedEventClassCount()J")
+    unsatisfied_link(CLASS_NAME, "getUnloadedEventClassCount", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn host_total_memory(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.hostTotalMemory()J")
+    unsatisfied_link(CLASS_NAME, "hostTotalMemory", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -544,62 +554,66 @@ async fn host_total_swap_memory(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.hostTotalSwapMemory()J")
+    unsatisfied_link(CLASS_NAME, "hostTotalSwapMemory", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn include(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.include(Ljava/lang/Thread;)V")
+    unsatisfied_link(CLASS_NAME, "include", "(Ljava/lang/Thread;)V")
 }
 
 #[async_recursion(?Send)]
 async fn is_available(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.isAvailable()Z")
+    unsatisfied_link(CLASS_NAME, "isAvailable", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn is_containerized(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.isContainerized()Z")
+    unsatisfied_link(CLASS_NAME, "isContainerized", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn is_excluded(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.isExcluded(Ljava/lang/Thread;)Z")
+    unsatisfied_link(CLASS_NAME, "isExcluded", "(Ljava/lang/Thread;)Z")
 }
 
 #[async_recursion(?Send)]
 async fn is_instrumented(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.isInstrumented(Ljava/lang/Class;)Z")
+    unsatisfied_link(CLASS_NAME, "isInstrumented", "(Ljava/lang/Class;)Z")
 }
 
 #[async_recursion(?Send)]
 async fn is_recording(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.isRecording()Z")
+    unsatisfied_link(CLASS_NAME, "isRecording", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn log(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.log(IILjava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "log", "(IILjava/lang/String;)V")
 }
 
 #[async_recursion(?Send)]
 async fn log_event(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.logEvent(I[Ljava/lang/String;Z)V")
+    unsatisfied_link(CLASS_NAME, "logEvent", "(I[Ljava/lang/String;Z)V")
 }
 
 #[async_recursion(?Send)]
 async fn mark_chunk_final(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.markChunkFinal()V")
+    unsatisfied_link(CLASS_NAME, "markChunkFinal", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn nanos_now(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.nanosNow()J")
+    unsatisfied_link(CLASS_NAME, "nanosNow", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn new_event_writer(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.newEventWriter()Ljdk/jfr/internal/EventWriter;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "newEventWriter",
+        "()Ljdk/jfr/internal/EventWriter;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -612,7 +626,11 @@ async fn register_stack_filter(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.registerStackFilter([Ljava/lang/String;[Ljava/lang/String;)J")
+    unsatisfied_link(
+        CLASS_NAME,
+        "registerStackFilter",
+        "([Ljava/lang/String;[Ljava/lang/String;)J",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -620,7 +638,7 @@ async fn retransform_classes(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.retransformClasses([Ljava/lang/Class;)V")
+    unsatisfied_link(CLASS_NAME, "retransformClasses", "([Ljava/lang/Class;)V")
 }
 
 #[async_recursion(?Send)]
@@ -628,27 +646,31 @@ async fn set_compressed_integers(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setCompressedIntegers(Z)V")
+    unsatisfied_link(CLASS_NAME, "setCompressedIntegers", "(Z)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_configuration(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setConfiguration(Ljava/lang/Class;Ljdk/jfr/internal/event/EventConfiguration;)Z")
+    unsatisfied_link(
+        CLASS_NAME,
+        "setConfiguration",
+        "(Ljava/lang/Class;Ljdk/jfr/internal/event/EventConfiguration;)Z",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn set_cutoff(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setCutoff(JJ)Z")
+    unsatisfied_link(CLASS_NAME, "setCutoff", "(JJ)Z")
 }
 
 #[async_recursion(?Send)]
 async fn set_dump_path(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setDumpPath(Ljava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "setDumpPath", "(Ljava/lang/String;)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_enabled(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setEnabled(JZ)V")
+    unsatisfied_link(CLASS_NAME, "setEnabled", "(JZ)V")
 }
 
 #[async_recursion(?Send)]
@@ -656,7 +678,7 @@ async fn set_file_notification(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setFileNotification(J)V")
+    unsatisfied_link(CLASS_NAME, "setFileNotification", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -664,7 +686,7 @@ async fn set_force_instrumentation(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setForceInstrumentation(Z)V")
+    unsatisfied_link(CLASS_NAME, "setForceInstrumentation", "(Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -672,7 +694,7 @@ async fn set_global_buffer_count(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setGlobalBufferCount(J)V")
+    unsatisfied_link(CLASS_NAME, "setGlobalBufferCount", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -680,17 +702,21 @@ async fn set_global_buffer_size(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setGlobalBufferSize(J)V")
+    unsatisfied_link(CLASS_NAME, "setGlobalBufferSize", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_handler(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setHandler(Ljava/lang/Class;Ljdk/jfr/internal/handlers/EventHandler;)Z")
+    unsatisfied_link(
+        CLASS_NAME,
+        "setHandler",
+        "(Ljava/lang/Class;Ljdk/jfr/internal/handlers/EventHandler;)Z",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn set_memory_size(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setMemorySize(J)V")
+    unsatisfied_link(CLASS_NAME, "setMemorySize", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -698,7 +724,7 @@ async fn set_method_sampling_interval(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setMethodSamplingInterval(JJ)V")
+    unsatisfied_link(CLASS_NAME, "setMethodSamplingInterval", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
@@ -706,17 +732,17 @@ async fn set_method_sampling_period(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setMethodSamplingPeriod(JJ)V")
+    unsatisfied_link(CLASS_NAME, "setMethodSamplingPeriod", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_miscellaneous(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setMiscellaneous(JJ)V")
+    unsatisfied_link(CLASS_NAME, "setMiscellaneous", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_output(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setOutput(Ljava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "setOutput", "(Ljava/lang/String;)V")
 }
 
 #[async_recursion(?Send)]
@@ -724,7 +750,7 @@ async fn set_repository_location(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setRepositoryLocation(Ljava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "setRepositoryLocation", "(Ljava/lang/String;)V")
 }
 
 #[async_recursion(?Send)]
@@ -732,12 +758,12 @@ async fn set_sample_threads(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setSampleThreads(Z)V")
+    unsatisfied_link(CLASS_NAME, "setSampleThreads", "(Z)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_stack_depth(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setStackDepth(I)V")
+    unsatisfied_link(CLASS_NAME, "setStackDepth", "(I)V")
 }
 
 #[async_recursion(?Send)]
@@ -745,7 +771,7 @@ async fn set_stack_trace_enabled(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setStackTraceEnabled(JZ)V")
+    unsatisfied_link(CLASS_NAME, "setStackTraceEnabled", "(JZ)V")
 }
 
 #[async_recursion(?Send)]
@@ -753,17 +779,17 @@ async fn set_thread_buffer_size(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setThreadBufferSize(J)V")
+    unsatisfied_link(CLASS_NAME, "setThreadBufferSize", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_threshold(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setThreshold(JJ)Z")
+    unsatisfied_link(CLASS_NAME, "setThreshold", "(JJ)Z")
 }
 
 #[async_recursion(?Send)]
 async fn set_throttle(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.setThrottle(JJJ)Z")
+    unsatisfied_link(CLASS_NAME, "setThrottle", "(JJJ)Z")
 }
 
 #[async_recursion(?Send)]
@@ -771,7 +797,7 @@ async fn should_rotate_disk(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.shouldRotateDisk()Z")
+    unsatisfied_link(CLASS_NAME, "shouldRotateDisk", "()Z")
 }
 
 #[async_recursion(?Send)]
@@ -779,7 +805,7 @@ async fn store_metadata_descriptor(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.storeMetadataDescriptor([B)V")
+    unsatisfied_link(CLASS_NAME, "storeMetadataDescriptor", "([B)V")
 }
 
 #[async_recursion(?Send)]
@@ -787,7 +813,11 @@ async fn subscribe_log_level(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.subscribeLogLevel(Ljdk/jfr/internal/LogTag;I)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "subscribeLogLevel",
+        "(Ljdk/jfr/internal/LogTag;I)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -795,7 +825,11 @@ async fn uncaught_exception(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.uncaughtException(Ljava/lang/Thread;Ljava/lang/Throwable;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "uncaughtException",
+        "(Ljava/lang/Thread;Ljava/lang/Throwable;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -803,7 +837,7 @@ async fn unregister_stack_filter(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.jfr.internal.JVM.unregisterStackFilter(J)V")
+    unsatisfied_link(CLASS_NAME, "unregisterStackFilter", "(J)V")
 }
 
 #[cfg(test)]
@@ -811,343 +845,457 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.abort(Ljava/lang/String;)V"
-    )]
     async fn test_abort() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = abort(thread, Parameters::default()).await;
+        let result = abort(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "abort",
+            "(Ljava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.addStringConstant(JLjava/lang/String;)Z"
-    )]
     async fn test_add_string_constant() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = add_string_constant(thread, Parameters::default()).await;
+        let result = add_string_constant(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "addStringConstant",
+            "(JLjava/lang/String;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.beginRecording()V")]
     async fn test_begin_recording() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = begin_recording(thread, Parameters::default()).await;
+        let result = begin_recording(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "beginRecording",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.commit(J)J")]
     async fn test_commit() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = commit(thread, Parameters::default()).await;
+        let result = commit(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/jfr/internal/JVM", "commit", "(J)J");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.counterTime()J")]
     async fn test_counter_time() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = counter_time(thread, Parameters::default()).await;
+        let result = counter_time(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/jfr/internal/JVM", "counterTime", "()J");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.createJFR(Z)Z")]
     async fn test_create_jfr() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_jfr(thread, Parameters::default()).await;
+        let result = create_jfr(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/jfr/internal/JVM", "createJFR", "(Z)Z");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.destroyJFR()Z")]
     async fn test_destroy_jfr() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = destroy_jfr(thread, Parameters::default()).await;
+        let result = destroy_jfr(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/jfr/internal/JVM", "destroyJFR", "()Z");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.emitDataLoss(J)V")]
     async fn test_emit_data_loss() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = emit_data_loss(thread, Parameters::default()).await;
+        let result = emit_data_loss(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "emitDataLoss",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.emitEvent(JJJ)Z")]
     async fn test_emit_event() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = emit_event(thread, Parameters::default()).await;
+        let result = emit_event(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/jfr/internal/JVM", "emitEvent", "(JJJ)Z");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.emitOldObjectSamples(JZZ)V"
-    )]
     async fn test_emit_old_object_samples() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = emit_old_object_samples(thread, Parameters::default()).await;
+        let result = emit_old_object_samples(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "emitOldObjectSamples",
+            "(JZZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.endRecording()V")]
     async fn test_end_recording() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = end_recording(thread, Parameters::default()).await;
+        let result = end_recording(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/jfr/internal/JVM", "endRecording", "()V");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.exclude(Ljava/lang/Thread;)V"
-    )]
     async fn test_exclude() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = exclude(thread, Parameters::default()).await;
+        let result = exclude(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "exclude",
+            "(Ljava/lang/Thread;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.flush()")]
     async fn test_flush() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = flush(thread, Parameters::default()).await;
+        let result = flush(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/jfr/internal/JVM", "flush", "()");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.getAllEventClasses()Ljava/util/List;"
-    )]
     async fn test_get_all_event_classes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_all_event_classes(thread, Parameters::default()).await;
+        let result = get_all_event_classes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "getAllEventClasses",
+            "()Ljava/util/List;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.getAllowedToDoEventRetransforms()Z"
-    )]
     async fn test_get_allowed_to_do_event_retransforms() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_allowed_to_do_event_retransforms(thread, Parameters::default()).await;
+        let result = get_allowed_to_do_event_retransforms(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "getAllowedToDoEventRetransforms",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.getChunkStartNanos()J")]
     async fn test_get_chunk_start_nanos() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_chunk_start_nanos(thread, Parameters::default()).await;
+        let result = get_chunk_start_nanos(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "getChunkStartNanos",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.getClassId(Ljava/lang/Class;)J"
-    )]
     async fn test_get_class_id() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_class_id(thread, Parameters::default()).await;
+        let result = get_class_id(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "getClassId",
+            "(Ljava/lang/Class;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.getClassIdNonIntrinsic(Ljava/lang/Class;)J"
-    )]
     async fn test_get_class_id_non_intrinsic() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_class_id_non_intrinsic(thread, Parameters::default()).await;
+        let result = get_class_id_non_intrinsic(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "getClassIdNonIntrinsic",
+            "(Ljava/lang/Class;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.getConfiguration(Ljava/lang/Class;)Ljava/lang/Object;"
-    )]
     async fn test_get_configuration() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_configuration(thread, Parameters::default()).await;
+        let result = get_configuration(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "getConfiguration",
+            "(Ljava/lang/Class;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.getDumpPath()Ljava/lang/String;"
-    )]
     async fn test_get_dump_path() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_dump_path(thread, Parameters::default()).await;
+        let result = get_dump_path(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "getDumpPath",
+            "()Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.getEventWriter()Ljava/lang/Object;"
-    )]
     async fn test_get_event_writer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_event_writer(thread, Parameters::default()).await;
+        let result = get_event_writer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "getEventWriter",
+            "()Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.getHandler(Ljava/lang/Class;)Ljava/lang"
-    )]
     async fn test_get_handler() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_handler(thread, Parameters::default()).await;
+        let result = get_handler(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "getHandler",
+            "(Ljava/lang/Class;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.getPid()Ljava/lang/String;"
-    )]
     async fn test_get_pid() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_pid(thread, Parameters::default()).await;
+        let result = get_pid(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "getPid",
+            "()Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.getStackTraceId(IJ)J")]
     async fn test_get_stack_trace_id() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_stack_trace_id(thread, Parameters::default()).await;
+        let result = get_stack_trace_id(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "getStackTraceId",
+            "(IJ)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.getThreadId(Ljava/lang/Thread;)J"
-    )]
     async fn test_get_thread_id() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_thread_id(thread, Parameters::default()).await;
+        let result = get_thread_id(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "getThreadId",
+            "(Ljava/lang/Thread;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.getTicksFrequency()J")]
     async fn test_get_ticks_frequency() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_ticks_frequency(thread, Parameters::default()).await;
+        let result = get_ticks_frequency(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "getTicksFrequency",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.getTimeConversionFactor()D"
-    )]
     async fn test_get_time_conversion_factor() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_time_conversion_factor(thread, Parameters::default()).await;
+        let result = get_time_conversion_factor(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "getTimeConversionFactor",
+            "()D",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.getTypeId(Ljava/lang/String;)J"
-    )]
     async fn test_get_type_id() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_type_id(thread, Parameters::default()).await;
+        let result = get_type_id(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "getTypeId",
+            "(Ljava/lang/String;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.getUnloadedEventClassCount()J"
-    )]
     async fn test_get_unloaded_event_class_count() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_unloaded_event_class_count(thread, Parameters::default()).await;
+        let result = get_unloaded_event_class_count(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "getUnloadedEventClassCount",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.hostTotalMemory()J")]
     async fn test_host_total_memory() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = host_total_memory(thread, Parameters::default()).await;
+        let result = host_total_memory(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "hostTotalMemory",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.hostTotalSwapMemory()J")]
     async fn test_host_total_swap_memory() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = host_total_swap_memory(thread, Parameters::default()).await;
+        let result = host_total_swap_memory(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "hostTotalSwapMemory",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.include(Ljava/lang/Thread;)V"
-    )]
     async fn test_include() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = include(thread, Parameters::default()).await;
+        let result = include(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "include",
+            "(Ljava/lang/Thread;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.isAvailable()Z")]
     async fn test_is_available() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_available(thread, Parameters::default()).await;
+        let result = is_available(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/jfr/internal/JVM", "isAvailable", "()Z");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.isContainerized()Z")]
     async fn test_is_containerized() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_containerized(thread, Parameters::default()).await;
+        let result = is_containerized(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "isContainerized",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.isExcluded(Ljava/lang/Thread;)Z"
-    )]
     async fn test_is_excluded() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_excluded(thread, Parameters::default()).await;
+        let result = is_excluded(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "isExcluded",
+            "(Ljava/lang/Thread;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.isInstrumented(Ljava/lang/Class;)Z"
-    )]
     async fn test_is_instrumented() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_instrumented(thread, Parameters::default()).await;
+        let result = is_instrumented(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "isInstrumented",
+            "(Ljava/lang/Class;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.isRecording()Z")]
     async fn test_is_recording() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_recording(thread, Parameters::default()).await;
+        let result = is_recording(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/jfr/internal/JVM", "isRecording", "()Z");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.log(IILjava/lang/String;)V"
-    )]
     async fn test_log() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = log(thread, Parameters::default()).await;
+        let result = log(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "log",
+            "(IILjava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.logEvent(I[Ljava/lang/String;Z)V"
-    )]
     async fn test_log_event() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = log_event(thread, Parameters::default()).await;
+        let result = log_event(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "logEvent",
+            "(I[Ljava/lang/String;Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.markChunkFinal()V")]
     async fn test_mark_chunk_final() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = mark_chunk_final(thread, Parameters::default()).await;
+        let result = mark_chunk_final(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "markChunkFinal",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.nanosNow()J")]
     async fn test_nanos_now() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = nanos_now(thread, Parameters::default()).await;
+        let result = nanos_now(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/jfr/internal/JVM", "nanosNow", "()J");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.newEventWriter()Ljdk/jfr/internal/EventWriter;"
-    )]
     async fn test_new_event_writer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = new_event_writer(thread, Parameters::default()).await;
+        let result = new_event_writer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "newEventWriter",
+            "()Ljdk/jfr/internal/EventWriter;",
+        );
     }
 
     #[tokio::test]
@@ -1159,237 +1307,340 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.registerStackFilter([Ljava/lang/String;[Ljava/lang/String;)J"
-    )]
     async fn test_register_stack_filter() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = register_stack_filter(thread, Parameters::default()).await;
+        let result = register_stack_filter(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "registerStackFilter",
+            "([Ljava/lang/String;[Ljava/lang/String;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.retransformClasses([Ljava/lang/Class;)V"
-    )]
     async fn test_retransform_classes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = retransform_classes(thread, Parameters::default()).await;
+        let result = retransform_classes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "retransformClasses",
+            "([Ljava/lang/Class;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.setCompressedIntegers(Z)V"
-    )]
     async fn test_set_compressed_integers() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_compressed_integers(thread, Parameters::default()).await;
+        let result = set_compressed_integers(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "setCompressedIntegers",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.setConfiguration(Ljava/lang/Class;Ljdk/jfr/internal/event/EventConfiguration;)Z"
-    )]
     async fn test_set_configuration() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_configuration(thread, Parameters::default()).await;
+        let result = set_configuration(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "setConfiguration",
+            "(Ljava/lang/Class;Ljdk/jfr/internal/event/EventConfiguration;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.setCutoff(JJ)Z")]
     async fn test_set_cutoff() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_cutoff(thread, Parameters::default()).await;
+        let result = set_cutoff(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/jfr/internal/JVM", "setCutoff", "(JJ)Z");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.setDumpPath(Ljava/lang/String;)V"
-    )]
     async fn test_set_dump_path() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_dump_path(thread, Parameters::default()).await;
+        let result = set_dump_path(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "setDumpPath",
+            "(Ljava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.setEnabled(JZ)V")]
     async fn test_set_enabled() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_enabled(thread, Parameters::default()).await;
+        let result = set_enabled(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "jdk/jfr/internal/JVM", "setEnabled", "(JZ)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.setFileNotification(J)V")]
     async fn test_set_file_notification() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_file_notification(thread, Parameters::default()).await;
+        let result = set_file_notification(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "setFileNotification",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.setForceInstrumentation(Z)V"
-    )]
     async fn test_set_force_instrumentation() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_force_instrumentation(thread, Parameters::default()).await;
+        let result = set_force_instrumentation(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "setForceInstrumentation",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.setGlobalBufferCount(J)V")]
     async fn test_set_global_buffer_count() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_global_buffer_count(thread, Parameters::default()).await;
+        let result = set_global_buffer_count(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "setGlobalBufferCount",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.setGlobalBufferSize(J)V")]
     async fn test_set_global_buffer_size() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_global_buffer_size(thread, Parameters::default()).await;
+        let result = set_global_buffer_size(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "setGlobalBufferSize",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.setHandler(Ljava/lang/Class;Ljdk/jfr/internal/handlers/EventHandler;)Z"
-    )]
     async fn test_set_handler() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_handler(thread, Parameters::default()).await;
+        let result = set_handler(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "setHandler",
+            "(Ljava/lang/Class;Ljdk/jfr/internal/handlers/EventHandler;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.setMemorySize(J)V")]
     async fn test_set_memory_size() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_memory_size(thread, Parameters::default()).await;
+        let result = set_memory_size(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "setMemorySize",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.setMethodSamplingInterval(JJ)V"
-    )]
     async fn test_set_method_sampling_interval() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_method_sampling_interval(thread, Parameters::default()).await;
+        let result = set_method_sampling_interval(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "setMethodSamplingInterval",
+            "(JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.setMethodSamplingPeriod(JJ)V"
-    )]
     async fn test_set_method_sampling_period() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_method_sampling_period(thread, Parameters::default()).await;
+        let result = set_method_sampling_period(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "setMethodSamplingPeriod",
+            "(JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.setMiscellaneous(JJ)V")]
     async fn test_set_miscellaneous() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_miscellaneous(thread, Parameters::default()).await;
+        let result = set_miscellaneous(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "setMiscellaneous",
+            "(JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.setOutput(Ljava/lang/String;)V"
-    )]
     async fn test_set_output() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_output(thread, Parameters::default()).await;
+        let result = set_output(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "setOutput",
+            "(Ljava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.setRepositoryLocation(Ljava/lang/String;)V"
-    )]
     async fn test_set_repository_location() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_repository_location(thread, Parameters::default()).await;
+        let result = set_repository_location(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "setRepositoryLocation",
+            "(Ljava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.setSampleThreads(Z)V")]
     async fn test_set_sample_threads() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_sample_threads(thread, Parameters::default()).await;
+        let result = set_sample_threads(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "setSampleThreads",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.setStackDepth(I)V")]
     async fn test_set_stack_depth() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_stack_depth(thread, Parameters::default()).await;
+        let result = set_stack_depth(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "setStackDepth",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.setStackTraceEnabled(JZ)V"
-    )]
     async fn test_set_stack_trace_enabled() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_stack_trace_enabled(thread, Parameters::default()).await;
+        let result = set_stack_trace_enabled(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "setStackTraceEnabled",
+            "(JZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.setThreadBufferSize(J)V")]
     async fn test_set_thread_buffer_size() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_thread_buffer_size(thread, Parameters::default()).await;
+        let result = set_thread_buffer_size(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "setThreadBufferSize",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.setThreshold(JJ)Z")]
     async fn test_set_threshold() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_threshold(thread, Parameters::default()).await;
+        let result = set_threshold(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "setThreshold",
+            "(JJ)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.setThrottle(JJJ)Z")]
     async fn test_set_throttle() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_throttle(thread, Parameters::default()).await;
+        let result = set_throttle(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "setThrottle",
+            "(JJJ)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: jdk.jfr.internal.JVM.shouldRotateDisk()Z")]
     async fn test_should_rotate_disk() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = should_rotate_disk(thread, Parameters::default()).await;
+        let result = should_rotate_disk(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "shouldRotateDisk",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.storeMetadataDescriptor([B)V"
-    )]
     async fn test_store_metadata_descriptor() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = store_metadata_descriptor(thread, Parameters::default()).await;
+        let result = store_metadata_descriptor(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "storeMetadataDescriptor",
+            "([B)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.subscribeLogLevel(Ljdk/jfr/internal/LogTag;I)V"
-    )]
     async fn test_subscribe_log_level() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = subscribe_log_level(thread, Parameters::default()).await;
+        let result = subscribe_log_level(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "subscribeLogLevel",
+            "(Ljdk/jfr/internal/LogTag;I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.uncaughtException(Ljava/lang/Thread;Ljava/lang/Throwable;)V"
-    )]
     async fn test_uncaught_exception() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = uncaught_exception(thread, Parameters::default()).await;
+        let result = uncaught_exception(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "uncaughtException",
+            "(Ljava/lang/Thread;Ljava/lang/Throwable;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.jfr.internal.JVM.unregisterStackFilter(J)V"
-    )]
     async fn test_unregister_stack_filter() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = unregister_stack_filter(thread, Parameters::default()).await;
+        let result = unregister_stack_filter(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/jfr/internal/JVM",
+            "unregisterStackFilter",
+            "(J)V",
+        );
     }
 }
@@ -1,4 +1,5 @@
 pub(crate) mod internal;
+#[cfg(feature = "jfr")]
 pub(crate) mod jfr;
 pub(crate) mod net;
 pub(crate) mod vm;
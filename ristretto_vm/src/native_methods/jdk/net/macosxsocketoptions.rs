@@ -1,4 +1,6 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17, JAVA_21};
+use crate::native_methods::registry::{
+    unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17, JAVA_21,
+};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -99,7 +101,7 @@ async fn get_ip_dont_fragment_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.net.MacOSXSocketOptions.getIpDontFragment0(I)Z")
+    unsatisfied_link(CLASS_NAME, "getIpDontFragment0", "(IZ)Z")
 }
 
 #[async_recursion(?Send)]
@@ -107,7 +109,7 @@ async fn get_so_peer_cred_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.net.MacOSXSocketOptions.getSoPeerCred0(I)J")
+    unsatisfied_link(CLASS_NAME, "getSoPeerCred0", "(I)J")
 }
 
 #[async_recursion(?Send)]
@@ -115,7 +117,7 @@ async fn get_tcp_keep_alive_intvl_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.net.MacOSXSocketOptions.getTcpKeepAliveIntvl0(I)I")
+    unsatisfied_link(CLASS_NAME, "getTcpKeepAliveIntvl0", "(I)I")
 }
 
 #[async_recursion(?Send)]
@@ -123,7 +125,7 @@ async fn get_tcp_keep_alive_probes_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.net.MacOSXSocketOptions.getTcpKeepAliveProbes0(I)I")
+    unsatisfied_link(CLASS_NAME, "getTcpKeepAliveProbes0", "(I)I")
 }
 
 #[async_recursion(?Send)]
@@ -131,7 +133,7 @@ async fn get_tcp_keep_alive_time_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.net.MacOSXSocketOptions.getTcpKeepAliveTime0(I)I")
+    unsatisfied_link(CLASS_NAME, "getTcpKeepAliveTime0", "(I)I")
 }
 
 #[async_recursion(?Send)]
@@ -139,7 +141,7 @@ async fn get_tcpkeep_alive_probes_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.net.MacOSXSocketOptions.getTcpkeepAliveProbes0(I)I")
+    unsatisfied_link(CLASS_NAME, "getTcpkeepAliveProbes0", "(I)I")
 }
 
 #[async_recursion(?Send)]
@@ -147,7 +149,7 @@ async fn ip_dont_fragment_supported_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.net.MacOSXSocketOptions.ipDontFragmentSupported0()Z")
+    unsatisfied_link(CLASS_NAME, "ipDontFragmentSupported0", "()Z")
 }
 
 #[async_recursion(?Send)]
@@ -155,7 +157,7 @@ async fn keep_alive_options_supported_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.net.MacOSXSocketOptions.keepAliveOptionsSupported0()Z")
+    unsatisfied_link(CLASS_NAME, "keepAliveOptionsSupported0", "()Z")
 }
 
 #[async_recursion(?Send)]
@@ -163,7 +165,7 @@ async fn set_ip_dont_fragment_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.net.MacOSXSocketOptions.setIpDontFragment0(IZ)V")
+    unsatisfied_link(CLASS_NAME, "setIpDontFragment0", "(IZZ)V")
 }
 
 #[async_recursion(?Send)]
@@ -171,7 +173,7 @@ async fn set_tcp_keep_alive_intvl_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.net.MacOSXSocketOptions.setTcpKeepAliveIntvl0(II)V")
+    unsatisfied_link(CLASS_NAME, "setTcpKeepAliveIntvl0", "(II)V")
 }
 
 #[async_recursion(?Send)]
@@ -179,7 +181,7 @@ async fn set_tcp_keep_alive_probes_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.net.MacOSXSocketOptions.setTcpKeepAliveProbes0(II)V")
+    unsatisfied_link(CLASS_NAME, "setTcpKeepAliveProbes0", "(II)V")
 }
 
 #[async_recursion(?Send)]
@@ -187,7 +189,7 @@ async fn set_tcp_keep_alive_time_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.net.MacOSXSocketOptions.setTcpKeepAliveTime0(II)V")
+    unsatisfied_link(CLASS_NAME, "setTcpKeepAliveTime0", "(II)V")
 }
 
 #[async_recursion(?Send)]
@@ -195,7 +197,7 @@ async fn set_tcpkeep_alive_probes_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.net.MacOSXSocketOptions.setTcpkeepAliveProbes0(II)V")
+    unsatisfied_link(CLASS_NAME, "setTcpkeepAliveProbes0", "(II)V")
 }
 
 #[cfg(test)]
@@ -203,119 +205,158 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.net.MacOSXSocketOptions.getIpDontFragment0(I)Z"
-    )]
     async fn test_get_ip_dont_fragment_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_ip_dont_fragment_0(thread, Parameters::default()).await;
+        let result = get_ip_dont_fragment_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/net/MacOSXSocketOptions",
+            "getIpDontFragment0",
+            "(IZ)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.net.MacOSXSocketOptions.getSoPeerCred0(I)J"
-    )]
     async fn test_get_so_peer_cred_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_so_peer_cred_0(thread, Parameters::default()).await;
+        let result = get_so_peer_cred_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/net/MacOSXSocketOptions",
+            "getSoPeerCred0",
+            "(I)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.net.MacOSXSocketOptions.getTcpKeepAliveIntvl0(I)I"
-    )]
     async fn test_get_tcp_keep_alive_intvl_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_tcp_keep_alive_intvl_0(thread, Parameters::default()).await;
+        let result = get_tcp_keep_alive_intvl_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/net/MacOSXSocketOptions",
+            "getTcpKeepAliveIntvl0",
+            "(I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.net.MacOSXSocketOptions.getTcpKeepAliveProbes0(I)I"
-    )]
     async fn test_get_tcp_keep_alive_probes_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_tcp_keep_alive_probes_0(thread, Parameters::default()).await;
+        let result = get_tcp_keep_alive_probes_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/net/MacOSXSocketOptions",
+            "getTcpKeepAliveProbes0",
+            "(I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.net.MacOSXSocketOptions.getTcpKeepAliveTime0(I)I"
-    )]
     async fn test_get_tcp_keep_alive_time_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_tcp_keep_alive_time_0(thread, Parameters::default()).await;
+        let result = get_tcp_keep_alive_time_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/net/MacOSXSocketOptions",
+            "getTcpKeepAliveTime0",
+            "(I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.net.MacOSXSocketOptions.getTcpkeepAliveProbes0(I)I"
-    )]
     async fn test_get_tcpkeep_alive_probes_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_tcpkeep_alive_probes_0(thread, Parameters::default()).await;
+        let result = get_tcpkeep_alive_probes_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/net/MacOSXSocketOptions",
+            "getTcpkeepAliveProbes0",
+            "(I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.net.MacOSXSocketOptions.ipDontFragmentSupported0()Z"
-    )]
     async fn test_ip_dont_fragment_supported_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ip_dont_fragment_supported_0(thread, Parameters::default()).await;
+        let result = ip_dont_fragment_supported_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/net/MacOSXSocketOptions",
+            "ipDontFragmentSupported0",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.net.MacOSXSocketOptions.keepAliveOptionsSupported0()Z"
-    )]
     async fn test_keep_alive_options_supported_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = keep_alive_options_supported_0(thread, Parameters::default()).await;
+        let result = keep_alive_options_supported_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/net/MacOSXSocketOptions",
+            "keepAliveOptionsSupported0",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.net.MacOSXSocketOptions.setIpDontFragment0(IZ)V"
-    )]
     async fn test_set_ip_dont_fragment_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_ip_dont_fragment_0(thread, Parameters::default()).await;
+        let result = set_ip_dont_fragment_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/net/MacOSXSocketOptions",
+            "setIpDontFragment0",
+            "(IZZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.net.MacOSXSocketOptions.setTcpKeepAliveIntvl0(II)V"
-    )]
     async fn test_set_tcp_keep_alive_intvl_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_tcp_keep_alive_intvl_0(thread, Parameters::default()).await;
+        let result = set_tcp_keep_alive_intvl_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/net/MacOSXSocketOptions",
+            "setTcpKeepAliveIntvl0",
+            "(II)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.net.MacOSXSocketOptions.setTcpKeepAliveProbes0(II)V"
-    )]
     async fn test_set_tcp_keep_alive_probes_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_tcp_keep_alive_probes_0(thread, Parameters::default()).await;
+        let result = set_tcp_keep_alive_probes_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/net/MacOSXSocketOptions",
+            "setTcpKeepAliveProbes0",
+            "(II)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.net.MacOSXSocketOptions.setTcpKeepAliveTime0(II)V"
-    )]
     async fn test_set_tcp_keep_alive_time_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_tcp_keep_alive_time_0(thread, Parameters::default()).await;
+        let result = set_tcp_keep_alive_time_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/net/MacOSXSocketOptions",
+            "setTcpKeepAliveTime0",
+            "(II)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.net.MacOSXSocketOptions.setTcpkeepAliveProbes0(II)V"
-    )]
     async fn test_set_tcpkeep_alive_probes_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_tcpkeep_alive_probes_0(thread, Parameters::default()).await;
+        let result = set_tcpkeep_alive_probes_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/net/MacOSXSocketOptions",
+            "setTcpkeepAliveProbes0",
+            "(II)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,7 +23,11 @@ async fn initialize_runtime(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.vm.ci.runtime.JVMCI.initializeRuntime()Ljdk/vm/ci/runtime/JVMCIRuntime;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "initializeRuntime",
+        "()Ljdk/vm/ci/runtime/JVMCIRuntime;",
+    )
 }
 
 #[cfg(test)]
@@ -31,11 +35,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.vm.ci.runtime.JVMCI.initializeRuntime()Ljdk/vm/ci/runtime/JVMCIRuntime;"
-    )]
     async fn test_initialize_runtime() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = initialize_runtime(thread, Parameters::default()).await;
+        let result = initialize_runtime(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/vm/ci/runtime/JVMCI",
+            "initializeRuntime",
+            "()Ljdk/vm/ci/runtime/JVMCIRuntime;",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,7 +23,7 @@ async fn read_system_properties_info(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("jdk.vm.ci.services.Services.readSystemPropertiesInfo([I)J")
+    unsatisfied_link(CLASS_NAME, "readSystemPropertiesInfo", "([I)J")
 }
 
 #[cfg(test)]
@@ -31,11 +31,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: jdk.vm.ci.services.Services.readSystemPropertiesInfo([I)J"
-    )]
     async fn test_read_system_properties_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = read_system_properties_info(thread, Parameters::default()).await;
+        let result = read_system_properties_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "jdk/vm/ci/services/Services",
+            "readSystemPropertiesInfo",
+            "([I)J",
+        );
     }
 }
@@ -1,9 +1,12 @@
+#[cfg(feature = "apple")]
 mod apple;
 mod com;
+#[cfg(any(not(feature = "jfr"), not(feature = "management")))]
+mod inert;
 mod java;
 mod jdk;
-mod properties;
+pub(crate) mod properties;
 mod registry;
 mod sun;
 
-pub use registry::MethodRegistry;
+pub use registry::{MethodOverride, MethodRegistry, MethodStatistics, RustMethod};
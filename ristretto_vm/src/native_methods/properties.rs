@@ -21,8 +21,9 @@ pub(crate) async fn system(thread: &Arc<Thread>) -> Result<HashMap<&'static str,
     Ok(properties)
 }
 
+/// Get the default system properties of the VM.
 #[expect(clippy::too_many_lines)]
-fn system_properties(vm: &VM) -> Result<HashMap<&'static str, String>> {
+pub(crate) fn system_properties(vm: &VM) -> Result<HashMap<&'static str, String>> {
     let mut properties = HashMap::new();
     let java_home = vm.java_home().to_string_lossy().to_string();
     let class_file_version = vm.java_class_file_version();
@@ -1,13 +1,20 @@
-#[cfg(target_os = "macos")]
+#[cfg(all(feature = "apple", target_os = "macos"))]
 use crate::native_methods::apple;
+#[cfg(any(not(feature = "jfr"), not(feature = "management")))]
+use crate::native_methods::inert;
 use crate::native_methods::{com, java, jdk, sun};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
+use crate::JavaError::{UnsatisfiedLinkError, UnsupportedOperationException};
 use crate::Result;
+use async_recursion::async_recursion;
+use dashmap::DashMap;
 use ristretto_classloader::Value;
 use std::collections::HashMap;
+use std::fmt;
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
 pub(crate) const JAVA_8: u16 = 8;
@@ -16,6 +23,38 @@ pub(crate) const JAVA_17: u16 = 17;
 pub(crate) const JAVA_21: u16 = 21;
 pub(crate) const JAVA_23: u16 = 23;
 
+/// Optional groups of native methods; each group is enabled by the cargo feature of the same name
+/// and contains the native methods of classes with the specified class name prefixes.
+const FEATURE_GROUPS: [(&str, bool, &[&str]); 6] = [
+    ("apple", cfg!(feature = "apple"), &["apple/", "com/apple/"]),
+    (
+        "awt",
+        cfg!(feature = "awt"),
+        &[
+            "com/sun/imageio/",
+            "com/sun/java/swing/",
+            "java/awt/",
+            "sun/awt/",
+            "sun/font/",
+            "sun/java2d/",
+            "sun/lwawt/",
+            "sun/print/",
+        ],
+    ),
+    ("jfr", cfg!(feature = "jfr"), &["jdk/jfr/"]),
+    (
+        "management",
+        cfg!(feature = "management"),
+        &["com/sun/management/", "sun/management/"],
+    ),
+    (
+        "security",
+        cfg!(feature = "security"),
+        &["com/sun/security/", "sun/security/"],
+    ),
+    ("sound", cfg!(feature = "sound"), &["com/sun/media/sound/"]),
+];
+
 /// A Rust method is a method that is implemented in Rust and is called from Java code instead of
 /// being implemented in Java byte code.
 pub type RustMethod = fn(
@@ -23,11 +62,88 @@ pub type RustMethod = fn(
     parameters: Parameters,
 ) -> Pin<Box<dyn Future<Output = Result<Option<Value>>>>>;
 
+/// An override for a method, supplied by the VM configuration, that replaces the registered (or
+/// Java byte code) implementation of the method.
+#[derive(Clone, Debug)]
+pub enum MethodOverride {
+    /// Replace the method with the specified Rust method
+    Method(RustMethod),
+    /// Replace the method with a method that throws `java.lang.UnsupportedOperationException`
+    UnsupportedOperation,
+}
+
+/// Method overrides are equal if they are the same kind of override and, for
+/// [`MethodOverride::Method`] overrides, the Rust methods have the same address.  Function
+/// addresses are not guaranteed to be unique, so the same function may compare unequal to itself
+/// across codegen units and identical functions may be merged to share an address; overrides
+/// should be compared with this in mind.
+impl PartialEq for MethodOverride {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Method(method), Self::Method(other_method)) => {
+                *method as usize == *other_method as usize
+            }
+            (Self::UnsupportedOperation, Self::UnsupportedOperation) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for MethodOverride {}
+
+/// Statistics about the Rust methods registered with, and invoked through, a [`MethodRegistry`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MethodStatistics {
+    /// The Java major version the registry was initialized for
+    pub java_major_version: u16,
+    /// The signatures of all the registered methods, sorted by signature
+    pub registered_methods: Vec<String>,
+    /// The number of calls for each invoked registered method, sorted by signature
+    pub call_counts: Vec<(String, u64)>,
+    /// The number of calls for each invoked native method that is not linked (i.e. not registered,
+    /// excluded by a cargo feature or not yet implemented), sorted by signature
+    pub unlinked_methods: Vec<(String, u64)>,
+}
+
+/// A Rust method registered with a [`MethodRegistry`] and the number of times it was called; the
+/// count is updated without locking or allocating so that calls are always counted.
+#[derive(Debug)]
+pub(crate) struct RegisteredMethod {
+    method: RustMethod,
+    call_count: AtomicU64,
+}
+
+impl RegisteredMethod {
+    /// Create a new registered method that has not been called.
+    fn new(method: RustMethod) -> Self {
+        RegisteredMethod {
+            method,
+            call_count: AtomicU64::new(0),
+        }
+    }
+
+    /// Get the Rust method.
+    pub(crate) fn method(&self) -> RustMethod {
+        self.method
+    }
+
+    /// Record a call to the method.
+    pub(crate) fn record_call(&self) {
+        self.call_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Get the number of calls to the method.
+    fn call_count(&self) -> u64 {
+        self.call_count.load(Ordering::Relaxed)
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct MethodRegistry {
     java_major_version: u16,
     use_optimizations: bool,
-    methods: HashMap<String, RustMethod>,
+    methods: HashMap<String, RegisteredMethod>,
+    unlinked_methods: DashMap<String, u64>,
 }
 
 impl MethodRegistry {
@@ -37,6 +153,7 @@ impl MethodRegistry {
             java_major_version,
             use_optimizations,
             methods: HashMap::new(),
+            unlinked_methods: DashMap::new(),
         }
     }
 
@@ -44,50 +161,12 @@ impl MethodRegistry {
     #[expect(clippy::too_many_lines)]
     pub fn initialize(&mut self) {
         if self.java_major_version <= JAVA_8 {
-            #[cfg(target_os = "macos")]
-            {
-                apple::applescript::applescriptengine::register(self);
-                apple::applescript::applescriptenginefactory::register(self);
-                apple::launcher::javaapplauncher::register(self);
-                com::apple::concurrent::libdispatchnative::register(self);
-                com::apple::laf::screenpopupfactory::register(self);
-                sun::awt::cgraphicsconfig::register(self);
-            }
             #[cfg(not(target_os = "windows"))]
             {
-                com::sun::java::swing::plaf::gtk::gtkengine::register(self);
-                com::sun::java::swing::plaf::gtk::gtkstyle::register(self);
                 java::lang::unixprocess::register(self);
-                sun::awt::fcfontmanager::register(self);
-                sun::awt::unixtoolkit::register(self);
-                sun::awt::x11graphicsconfig::register(self);
-                sun::awt::x11graphicsdevice::register(self);
-                sun::awt::x11graphicsenvironment::register(self);
-                sun::awt::x11inputmethod::register(self);
-                sun::font::fontconfigmanager::register(self);
-                sun::font::nativefont::register(self);
-                sun::font::nativestrike::register(self);
-                sun::font::nativestrikedisposer::register(self);
-                sun::font::x11textrenderer::register(self);
-                sun::java2d::jules::julesaatilegenerator::register(self);
-                sun::java2d::jules::julespathbuf::register(self);
-                sun::java2d::opengl::glxgraphicsconfig::register(self);
-                sun::java2d::opengl::glxsurfacedata::register(self);
-                sun::java2d::x11::x11pmblitbgloops::register(self);
-                sun::java2d::x11::x11pmblitloops::register(self);
-                sun::java2d::x11::x11renderer::register(self);
-                sun::java2d::x11::x11surfacedata::register(self);
-                sun::java2d::x11::xsurfacedata::register(self);
-                sun::java2d::xr::xidgenerator::register(self);
-                sun::java2d::xr::xrbackendnative::register(self);
-                sun::java2d::xr::xrmaskblit::register(self);
-                sun::java2d::xr::xrmaskfill::register(self);
-                sun::java2d::xr::xrsurfacedata::register(self);
-                sun::management::operatingsystemimpl::register(self);
             }
 
             com::sun::demo::jvmti::hprof::tracker::register(self);
-            java::awt::image::componentsamplemodel::register(self);
             java::lang::compiler::register(self);
             java::lang::package::register(self);
             java::lang::reflect::proxy::register(self);
@@ -95,11 +174,6 @@ impl MethodRegistry {
             java::util::jar::jarfile::register(self);
             java::util::logging::filehandler::register(self);
             java::util::zip::zipfile::register(self);
-            sun::awt::defaultmouseinfopeer::register(self);
-            sun::management::diagnosticcommandimpl::register(self);
-            sun::management::filesystemimpl::register(self);
-            sun::management::flag::register(self);
-            sun::management::gcinfobuilder::register(self);
             sun::misc::gc::register(self);
             sun::misc::messageutils::register(self);
             sun::misc::nativesignalhandler::register(self);
@@ -140,26 +214,18 @@ impl MethodRegistry {
         }
         if self.java_major_version == JAVA_11 {
             java::lang::stringcoding::register(self);
-            sun::security::ec::ecdhkeyagreement::register(self);
-            sun::security::ec::ecdsasignature::register(self);
-            sun::security::ec::eckeypairgenerator::register(self);
         }
         if self.java_major_version >= JAVA_11 {
             #[cfg(not(target_os = "windows"))]
             {
-                com::sun::management::internal::operatingsystemimpl::register(self);
                 java::lang::processimpl::register(self);
             }
 
-            com::sun::management::internal::diagnosticcommandimpl::register(self);
-            com::sun::management::internal::flag::register(self);
-            com::sun::management::internal::garbagecollectorextimpl::register(self);
-            com::sun::management::internal::gcinfobuilder::register(self);
-            com::sun::security::auth::module::ntsystem::register(self);
             java::io::filecleanable::register(self);
             java::lang::module::register(self);
             java::lang::processhandleimpl::register(self);
             java::lang::processhandleimpl_info::register(self);
+            java::lang::reflect::accessibleobject::register(self);
             java::lang::stackstreamfactory::register(self);
             java::lang::stackstreamfactory_abstractstackwalker::register(self);
             java::lang::stacktraceelement::register(self);
@@ -190,11 +256,8 @@ impl MethodRegistry {
 
             jdk::internal::reflect::reflection::register(self);
             jdk::internal::vm::vmsupport::register(self);
-            jdk::jfr::internal::jvm::register(self);
             jdk::vm::ci::runtime::jvmci::register(self);
             sun::rmi::transport::gc::register(self);
-            sun::security::pkcs11::secmod::register(self);
-            sun::security::pkcs11::wrapper::pkcs11::register(self);
         }
 
         if self.java_major_version <= JAVA_17 {
@@ -217,18 +280,6 @@ impl MethodRegistry {
             jdk::internal::invoke::nativeentrypoint::register(self);
         }
         if self.java_major_version >= JAVA_17 {
-            #[cfg(target_os = "macos")]
-            {
-                sun::awt::platformgraphicsinfo::register(self);
-                sun::java2d::metal::mtlgraphicsconfig::register(self);
-                sun::java2d::metal::mtllayer::register(self);
-                sun::java2d::metal::mtlmaskfill::register(self);
-                sun::java2d::metal::mtlrenderqueue::register(self);
-                sun::java2d::metal::mtlrenderer::register(self);
-                sun::java2d::metal::mtlsurfacedata::register(self);
-                sun::java2d::metal::mtltextrenderer::register(self);
-            }
-
             java::lang::invoke::lambdaproxyclassarchive::register(self);
             java::lang::nullpointerexception::register(self);
             java::lang::r#ref::phantomreference::register(self);
@@ -239,20 +290,12 @@ impl MethodRegistry {
             jdk::internal::misc::scopedmemoryaccess::register(self);
             jdk::internal::util::systemprops_raw::register(self);
             jdk::internal::vm::vector::vectorsupport::register(self);
-            sun::font::colorglyphsurfacedata::register(self);
             sun::nio::ch::nativesocketaddress::register(self);
             sun::nio::ch::socketdispatcher::register(self);
             sun::nio::ch::unixdomainsockets::register(self);
         }
 
         if self.java_major_version <= JAVA_21 {
-            java::awt::button::register(self);
-            java::awt::color::register(self);
-            java::awt::filedialog::register(self);
-            java::awt::keyboardfocusmanager::register(self);
-            java::awt::menucomponent::register(self);
-            java::awt::rectangle::register(self);
-            java::awt::textfield::register(self);
             java::util::concurrent::atomic::atomiclong::register(self);
         }
         if self.java_major_version >= JAVA_21 {
@@ -289,6 +332,126 @@ impl MethodRegistry {
             jdk::vm::ci::services::services::register(self);
         }
 
+        #[cfg(target_os = "macos")]
+        {
+            java::util::prefs::macosxpreferencesfile::register(self);
+            jdk::internal::org::jline::terminal::r#impl::jna::osx::clibraryimpl::register(self);
+            sun::nio::ch::kqueue::register(self);
+            sun::nio::fs::bsdnativedispatcher::register(self);
+            sun::nio::fs::macosxnativedispatcher::register(self);
+            sun::util::locale::provider::hostlocaleprovideradapterimpl::register(self);
+        }
+        #[cfg(not(target_os = "windows"))]
+        {
+            java::io::unixfilesystem::register(self);
+            java::lang::processenvironment::register(self);
+            java::util::prefs::filesystempreferences::register(self);
+            sun::net::dns::resolverconfigurationimpl::register(self);
+            sun::net::portconfig::register(self);
+            sun::nio::ch::datagramdispatcher::register(self);
+            sun::nio::ch::inheritedchannel::register(self);
+            sun::nio::ch::nativethread::register(self);
+            sun::nio::ch::unixasynchronoussocketchannelimpl::register(self);
+            sun::nio::fs::unixnativedispatcher::register(self);
+        }
+        #[cfg(target_os = "windows")]
+        {
+            java::io::winntfilesystem::register(self);
+            sun::io::win32errormode::register(self);
+        }
+
+        com::sun::java::util::jar::pack::nativeunpack::register(self);
+        java::io::console::register(self);
+        java::io::filedescriptor::register(self);
+        java::io::fileinputstream::register(self);
+        java::io::fileoutputstream::register(self);
+        java::io::objectinputstream::register(self);
+        java::io::objectoutputstream::register(self);
+        java::io::objectstreamclass::register(self);
+        java::io::randomaccessfile::register(self);
+        java::lang::class::register(self);
+        java::lang::classloader::register(self);
+        java::lang::double::register(self);
+        java::lang::float::register(self);
+        java::lang::invoke::methodhandle::register(self);
+        java::lang::invoke::methodhandlenatives::register(self);
+        java::lang::object::register(self);
+        java::lang::reflect::array::register(self);
+        java::lang::reflect::executable::register(self);
+        java::lang::reflect::field::register(self);
+        java::lang::runtime::register(self);
+        java::lang::securitymanager::register(self);
+        java::lang::shutdown::register(self);
+        java::lang::string::register(self);
+        java::lang::system::register(self);
+        java::lang::thread::register(self);
+        java::lang::throwable::register(self);
+        java::net::abstractplaindatagramsocketimpl::register(self);
+        java::net::abstractplainsocketimpl::register(self);
+        java::net::inet4address::register(self);
+        java::net::inet4addressimpl::register(self);
+        java::net::inet6address::register(self);
+        java::net::inet6addressimpl::register(self);
+        java::net::inetaddress::register(self);
+        java::net::networkinterface::register(self);
+        java::net::socketcleanable::register(self);
+        java::security::accesscontroller::register(self);
+        java::util::zip::adler32::register(self);
+        java::util::zip::crc32::register(self);
+        java::util::zip::deflater::register(self);
+        java::util::zip::inflater::register(self);
+        java::util::timezone::register(self);
+        jdk::internal::module::modulebootstrap::register(self);
+        sun::instrument::instrumentationimpl::register(self);
+        sun::net::sdp::sdpsupport::register(self);
+        sun::net::spi::defaultproxyselector::register(self);
+        sun::nio::ch::datagramchannelimpl::register(self);
+        sun::nio::ch::filedispatcherimpl::register(self);
+        sun::nio::ch::filekey::register(self);
+        sun::nio::ch::ioutil::register(self);
+        sun::nio::ch::net::register(self);
+
+        if self.use_optimizations {
+            java::lang::math::register(self);
+        }
+
+        #[cfg(feature = "apple")]
+        self.register_apple();
+
+        #[cfg(feature = "awt")]
+        self.register_awt();
+
+        #[cfg(feature = "jfr")]
+        self.register_jfr();
+        #[cfg(not(feature = "jfr"))]
+        inert::jfr::register(self);
+
+        #[cfg(feature = "management")]
+        self.register_management();
+        #[cfg(not(feature = "management"))]
+        inert::management::register(self);
+
+        #[cfg(feature = "security")]
+        self.register_security();
+
+        #[cfg(feature = "sound")]
+        self.register_sound();
+    }
+
+    /// Register the Apple (macOS) specific native methods.
+    #[cfg(feature = "apple")]
+    fn register_apple(&mut self) {
+        if self.java_major_version <= JAVA_8 {
+            #[cfg(target_os = "macos")]
+            {
+                apple::applescript::applescriptengine::register(self);
+                apple::applescript::applescriptenginefactory::register(self);
+                apple::launcher::javaapplauncher::register(self);
+                com::apple::concurrent::libdispatchnative::register(self);
+                com::apple::laf::screenpopupfactory::register(self);
+            }
+        }
+
         #[cfg(target_os = "macos")]
         {
             apple::laf::jrsuiconstants::register(self);
@@ -305,8 +468,81 @@ impl MethodRegistry {
             com::apple::laf::aquafileview::register(self);
             com::apple::laf::aquanativeresources::register(self);
             com::apple::laf::screenmenu::register(self);
-            java::util::prefs::macosxpreferencesfile::register(self);
-            jdk::internal::org::jline::terminal::r#impl::jna::osx::clibraryimpl::register(self);
+        }
+    }
+
+    /// Register the AWT, Java 2D, font and printing native methods.
+    #[cfg(feature = "awt")]
+    #[expect(clippy::too_many_lines)]
+    fn register_awt(&mut self) {
+        if self.java_major_version <= JAVA_8 {
+            #[cfg(target_os = "macos")]
+            {
+                sun::awt::cgraphicsconfig::register(self);
+            }
+            #[cfg(not(target_os = "windows"))]
+            {
+                com::sun::java::swing::plaf::gtk::gtkengine::register(self);
+                com::sun::java::swing::plaf::gtk::gtkstyle::register(self);
+                sun::awt::fcfontmanager::register(self);
+                sun::awt::unixtoolkit::register(self);
+                sun::awt::x11graphicsconfig::register(self);
+                sun::awt::x11graphicsdevice::register(self);
+                sun::awt::x11graphicsenvironment::register(self);
+                sun::awt::x11inputmethod::register(self);
+                sun::font::fontconfigmanager::register(self);
+                sun::font::nativefont::register(self);
+                sun::font::nativestrike::register(self);
+                sun::font::nativestrikedisposer::register(self);
+                sun::font::x11textrenderer::register(self);
+                sun::java2d::jules::julesaatilegenerator::register(self);
+                sun::java2d::jules::julespathbuf::register(self);
+                sun::java2d::opengl::glxgraphicsconfig::register(self);
+                sun::java2d::opengl::glxsurfacedata::register(self);
+                sun::java2d::x11::x11pmblitbgloops::register(self);
+                sun::java2d::x11::x11pmblitloops::register(self);
+                sun::java2d::x11::x11renderer::register(self);
+                sun::java2d::x11::x11surfacedata::register(self);
+                sun::java2d::x11::xsurfacedata::register(self);
+                sun::java2d::xr::xidgenerator::register(self);
+                sun::java2d::xr::xrbackendnative::register(self);
+                sun::java2d::xr::xrmaskblit::register(self);
+                sun::java2d::xr::xrmaskfill::register(self);
+                sun::java2d::xr::xrsurfacedata::register(self);
+            }
+
+            java::awt::image::componentsamplemodel::register(self);
+            sun::awt::defaultmouseinfopeer::register(self);
+        }
+
+        if self.java_major_version >= JAVA_17 {
+            #[cfg(target_os = "macos")]
+            {
+                sun::awt::platformgraphicsinfo::register(self);
+                sun::java2d::metal::mtlgraphicsconfig::register(self);
+                sun::java2d::metal::mtllayer::register(self);
+                sun::java2d::metal::mtlmaskfill::register(self);
+                sun::java2d::metal::mtlrenderqueue::register(self);
+                sun::java2d::metal::mtlrenderer::register(self);
+                sun::java2d::metal::mtlsurfacedata::register(self);
+                sun::java2d::metal::mtltextrenderer::register(self);
+            }
+
+            sun::font::colorglyphsurfacedata::register(self);
+        }
+
+        if self.java_major_version <= JAVA_21 {
+            java::awt::button::register(self);
+            java::awt::color::register(self);
+            java::awt::filedialog::register(self);
+            java::awt::keyboardfocusmanager::register(self);
+            java::awt::menucomponent::register(self);
+            java::awt::rectangle::register(self);
+            java::awt::textfield::register(self);
+        }
+
+        #[cfg(target_os = "macos")]
+        {
             sun::awt::cgraphicsdevice::register(self);
             sun::awt::cgraphicsenvironment::register(self);
             sun::font::cchartoglyphmapper::register(self);
@@ -355,45 +591,14 @@ impl MethodRegistry {
             sun::lwawt::macosx::cwrapper_nswindow::register(self);
             sun::lwawt::macosx::lwctoolkit::register(self);
             sun::lwawt::macosx::nsevent::register(self);
-            sun::nio::ch::kqueue::register(self);
-            sun::nio::fs::bsdnativedispatcher::register(self);
-            sun::nio::fs::macosxnativedispatcher::register(self);
-            sun::util::locale::provider::hostlocaleprovideradapterimpl::register(self);
         }
         #[cfg(not(target_os = "windows"))]
         {
-            java::io::unixfilesystem::register(self);
-            java::lang::processenvironment::register(self);
-            java::util::prefs::filesystempreferences::register(self);
-            com::sun::security::auth::module::unixsystem::register(self);
-            sun::net::dns::resolverconfigurationimpl::register(self);
-            sun::net::portconfig::register(self);
-            sun::nio::ch::datagramdispatcher::register(self);
-            sun::nio::ch::inheritedchannel::register(self);
-            sun::nio::ch::nativethread::register(self);
-            sun::nio::ch::unixasynchronoussocketchannelimpl::register(self);
-            sun::nio::fs::unixnativedispatcher::register(self);
             sun::print::cupsprinter::register(self);
-            sun::security::smartcardio::platformpcsc::register(self);
-        }
-        #[cfg(target_os = "windows")]
-        {
-            java::io::winntfilesystem::register(self);
-            sun::io::win32errormode::register(self);
         }
 
         com::sun::imageio::plugins::jpeg::jpegimagereader::register(self);
         com::sun::imageio::plugins::jpeg::jpegimagewriter::register(self);
-        com::sun::java::util::jar::pack::nativeunpack::register(self);
-        com::sun::media::sound::directaudiodevice::register(self);
-        com::sun::media::sound::directaudiodeviceprovider::register(self);
-        com::sun::media::sound::midiindevice::register(self);
-        com::sun::media::sound::midiindeviceprovider::register(self);
-        com::sun::media::sound::midioutdevice::register(self);
-        com::sun::media::sound::midioutdeviceprovider::register(self);
-        com::sun::media::sound::platform::register(self);
-        com::sun::media::sound::portmixer::register(self);
-        com::sun::media::sound::portmixerprovider::register(self);
         java::awt::event_mod::inputevent::register(self);
         java::awt::event_mod::keyevent::register(self);
         java::awt::event_mod::mouseevent::register(self);
@@ -430,47 +635,6 @@ impl MethodRegistry {
         java::awt::image::raster::register(self);
         java::awt::image::samplemodel::register(self);
         java::awt::image::singlepixelpackedsamplemodel::register(self);
-        java::io::console::register(self);
-        java::io::filedescriptor::register(self);
-        java::io::fileinputstream::register(self);
-        java::io::fileoutputstream::register(self);
-        java::io::objectinputstream::register(self);
-        java::io::objectoutputstream::register(self);
-        java::io::objectstreamclass::register(self);
-        java::io::randomaccessfile::register(self);
-        java::lang::class::register(self);
-        java::lang::classloader::register(self);
-        java::lang::double::register(self);
-        java::lang::float::register(self);
-        java::lang::invoke::methodhandle::register(self);
-        java::lang::invoke::methodhandlenatives::register(self);
-        java::lang::object::register(self);
-        java::lang::reflect::array::register(self);
-        java::lang::reflect::executable::register(self);
-        java::lang::reflect::field::register(self);
-        java::lang::runtime::register(self);
-        java::lang::securitymanager::register(self);
-        java::lang::shutdown::register(self);
-        java::lang::string::register(self);
-        java::lang::system::register(self);
-        java::lang::thread::register(self);
-        java::lang::throwable::register(self);
-        java::net::abstractplaindatagramsocketimpl::register(self);
-        java::net::abstractplainsocketimpl::register(self);
-        java::net::inet4address::register(self);
-        java::net::inet4addressimpl::register(self);
-        java::net::inet6address::register(self);
-        java::net::inet6addressimpl::register(self);
-        java::net::inetaddress::register(self);
-        java::net::networkinterface::register(self);
-        java::net::socketcleanable::register(self);
-        java::security::accesscontroller::register(self);
-        java::util::zip::adler32::register(self);
-        java::util::zip::crc32::register(self);
-        java::util::zip::deflater::register(self);
-        java::util::zip::inflater::register(self);
-        java::util::timezone::register(self);
-        jdk::internal::module::modulebootstrap::register(self);
         sun::awt::debugsettings::register(self);
         sun::awt::fontdescriptor::register(self);
         sun::awt::platformfont::register(self);
@@ -491,7 +655,6 @@ impl MethodRegistry {
         sun::font::strikecache::register(self);
         sun::font::sunfontmanager::register(self);
         sun::font::sunlayoutengine::register(self);
-        sun::instrument::instrumentationimpl::register(self);
         sun::java2d::defaultdisposerrecord::register(self);
         sun::java2d::disposer::register(self);
         sun::java2d::loops::blit::register(self);
@@ -524,6 +687,43 @@ impl MethodRegistry {
         sun::java2d::pipe::region::register(self);
         sun::java2d::pipe::shapespaniterator::register(self);
         sun::java2d::pipe::spancliprenderer::register(self);
+    }
+
+    /// Register the Java Flight Recorder native methods.
+    #[cfg(feature = "jfr")]
+    fn register_jfr(&mut self) {
+        if self.java_major_version >= JAVA_11 {
+            jdk::jfr::internal::jvm::register(self);
+        }
+    }
+
+    /// Register the management native methods.
+    #[cfg(feature = "management")]
+    fn register_management(&mut self) {
+        if self.java_major_version <= JAVA_8 {
+            #[cfg(not(target_os = "windows"))]
+            {
+                sun::management::operatingsystemimpl::register(self);
+            }
+
+            sun::management::diagnosticcommandimpl::register(self);
+            sun::management::filesystemimpl::register(self);
+            sun::management::flag::register(self);
+            sun::management::gcinfobuilder::register(self);
+        }
+
+        if self.java_major_version >= JAVA_11 {
+            #[cfg(not(target_os = "windows"))]
+            {
+                com::sun::management::internal::operatingsystemimpl::register(self);
+            }
+
+            com::sun::management::internal::diagnosticcommandimpl::register(self);
+            com::sun::management::internal::flag::register(self);
+            com::sun::management::internal::garbagecollectorextimpl::register(self);
+            com::sun::management::internal::gcinfobuilder::register(self);
+        }
+
         sun::management::classloadingimpl::register(self);
         sun::management::garbagecollectorimpl::register(self);
         sun::management::memoryimpl::register(self);
@@ -531,22 +731,47 @@ impl MethodRegistry {
         sun::management::memorypoolimpl::register(self);
         sun::management::threadimpl::register(self);
         sun::management::vmmanagementimpl::register(self);
-        sun::net::sdp::sdpsupport::register(self);
-        sun::net::spi::defaultproxyselector::register(self);
-        sun::nio::ch::datagramchannelimpl::register(self);
-        sun::nio::ch::filedispatcherimpl::register(self);
-        sun::nio::ch::filekey::register(self);
-        sun::nio::ch::ioutil::register(self);
-        sun::nio::ch::net::register(self);
+    }
+
+    /// Register the security provider native methods.
+    #[cfg(feature = "security")]
+    fn register_security(&mut self) {
+        if self.java_major_version == JAVA_11 {
+            sun::security::ec::ecdhkeyagreement::register(self);
+            sun::security::ec::ecdsasignature::register(self);
+            sun::security::ec::eckeypairgenerator::register(self);
+        }
+        if self.java_major_version >= JAVA_11 {
+            com::sun::security::auth::module::ntsystem::register(self);
+            sun::security::pkcs11::secmod::register(self);
+            sun::security::pkcs11::wrapper::pkcs11::register(self);
+        }
+
+        #[cfg(not(target_os = "windows"))]
+        {
+            com::sun::security::auth::module::unixsystem::register(self);
+            sun::security::smartcardio::platformpcsc::register(self);
+        }
+
         sun::security::jgss::wrapper::gsslibstub::register(self);
         sun::security::krb5::config::register(self);
         sun::security::krb5::credentials::register(self);
         sun::security::krb5::scdynamicstoreconfig::register(self);
         sun::security::smartcardio::pcsc::register(self);
+    }
 
-        if self.use_optimizations {
-            java::lang::math::register(self);
-        }
+    /// Register the sound native methods.
+    #[cfg(feature = "sound")]
+    fn register_sound(&mut self) {
+        com::sun::media::sound::directaudiodevice::register(self);
+        com::sun::media::sound::directaudiodeviceprovider::register(self);
+        com::sun::media::sound::midiindevice::register(self);
+        com::sun::media::sound::midiindeviceprovider::register(self);
+        com::sun::media::sound::midioutdevice::register(self);
+        com::sun::media::sound::midioutdeviceprovider::register(self);
+        com::sun::media::sound::platform::register(self);
+        com::sun::media::sound::portmixer::register(self);
+        com::sun::media::sound::portmixerprovider::register(self);
     }
 
     /// Determine if optimizations should be used.
@@ -569,12 +794,36 @@ impl MethodRegistry {
     ) {
         self.methods.insert(
             format!("{class_name}.{method_name}{method_descriptor}"),
-            method,
+            RegisteredMethod::new(method),
         );
     }
 
+    /// Apply method overrides; overrides are keyed by method signature (e.g.
+    /// `java/lang/System.exit(I)V`) and replace any method already registered for the signature.
+    pub(crate) fn apply_overrides(&mut self, overrides: &HashMap<String, MethodOverride>) {
+        for (method_signature, method_override) in overrides {
+            let method = match method_override {
+                MethodOverride::Method(method) => *method,
+                MethodOverride::UnsupportedOperation => unsupported_operation,
+            };
+            self.methods
+                .insert(method_signature.clone(), RegisteredMethod::new(method));
+        }
+    }
+
+    /// Get the name of the disabled cargo feature that contains the native methods for the class,
+    /// if the native methods for the class were excluded from the VM.
+    pub(crate) fn disabled_feature(&self, class_name: &str) -> Option<&'static str> {
+        FEATURE_GROUPS
+            .iter()
+            .find(|(_, enabled, prefixes)| {
+                !enabled && prefixes.iter().any(|prefix| class_name.starts_with(prefix))
+            })
+            .map(|(feature, _, _)| *feature)
+    }
+
     /// Return a map of all the registered Rust methods.
-    pub(crate) fn methods(&self) -> &HashMap<String, RustMethod> {
+    pub(crate) fn methods(&self) -> &HashMap<String, RegisteredMethod> {
         &self.methods
     }
 
@@ -587,10 +836,89 @@ impl MethodRegistry {
         class_name: &str,
         method_name: &str,
         method_descriptor: &str,
-    ) -> Option<&RustMethod> {
+    ) -> Option<&RegisteredMethod> {
         let method_signature = format!("{class_name}.{method_name}{method_descriptor}");
         self.methods.get(&method_signature)
     }
+
+    /// Record a call to a native method that could not be linked to a Rust method.
+    pub(crate) fn record_unlinked(
+        &self,
+        class_name: &str,
+        method_name: &str,
+        method_descriptor: &str,
+    ) {
+        let method_signature = format!("{class_name}.{method_name}{method_descriptor}");
+        *self.unlinked_methods.entry(method_signature).or_insert(0) += 1;
+    }
+
+    /// Get the statistics for the registered methods and the method calls recorded so far.
+    #[must_use]
+    pub fn statistics(&self) -> MethodStatistics {
+        let mut registered_methods = self.methods.keys().cloned().collect::<Vec<String>>();
+        registered_methods.sort();
+        let mut call_counts = self
+            .methods
+            .iter()
+            .filter_map(|(method_signature, method)| {
+                let call_count = method.call_count();
+                (call_count > 0).then(|| (method_signature.clone(), call_count))
+            })
+            .collect::<Vec<(String, u64)>>();
+        call_counts.sort();
+        let mut unlinked_methods = self
+            .unlinked_methods
+            .iter()
+            .map(|entry| (entry.key().clone(), *entry.value()))
+            .collect::<Vec<(String, u64)>>();
+        unlinked_methods.sort();
+        MethodStatistics {
+            java_major_version: self.java_major_version,
+            registered_methods,
+            call_counts,
+            unlinked_methods,
+        }
+    }
+}
+
+impl fmt::Display for MethodStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Java version: {}", self.java_major_version)?;
+        writeln!(f, "Registered methods: {}", self.registered_methods.len())?;
+        writeln!(f, "Called methods: {}", self.call_counts.len())?;
+        for (method_signature, count) in &self.call_counts {
+            writeln!(f, "  {count:>8} {method_signature}")?;
+        }
+        writeln!(f, "Unlinked methods: {}", self.unlinked_methods.len())?;
+        for (method_signature, count) in &self.unlinked_methods {
+            writeln!(f, "  {count:>8} {method_signature}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Get the error for a native method that does not have a Rust implementation; the error is
+/// thrown as `java.lang.UnsatisfiedLinkError`.
+pub(crate) fn unsatisfied_link<T>(
+    class_name: &str,
+    method_name: &str,
+    method_descriptor: &str,
+) -> Result<T> {
+    Err(UnsatisfiedLinkError {
+        class_name: class_name.to_string(),
+        method_name: method_name.to_string(),
+        method_descriptor: method_descriptor.to_string(),
+    }
+    .into())
+}
+
+/// Method used for [`MethodOverride::UnsupportedOperation`] overrides.
+#[async_recursion(?Send)]
+async fn unsupported_operation(
+    _thread: Arc<Thread>,
+    _parameters: Parameters,
+) -> Result<Option<Value>> {
+    Err(UnsupportedOperationException("Method disabled by the VM configuration".to_string()).into())
 }
 
 #[cfg(test)]
@@ -624,6 +952,101 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_apply_overrides() -> Result<()> {
+        let mut method_registry = MethodRegistry::new(JAVA_21, true);
+        method_registry.initialize();
+        let signature = "java/lang/Object.hashCode()I".to_string();
+        let overrides = HashMap::from([(signature, MethodOverride::UnsupportedOperation)]);
+        method_registry.apply_overrides(&overrides);
+        let method = method_registry
+            .method("java/lang/Object", "hashCode", "()I")
+            .expect("method")
+            .method();
+        let (_vm, thread) = crate::test::thread().await?;
+        let result = method(thread, Parameters::default()).await;
+        assert!(matches!(
+            result,
+            Err(crate::Error::JavaError(UnsupportedOperationException(_)))
+        ));
+        Ok(())
+    }
+
+    #[async_recursion(?Send)]
+    async fn other_method(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+        Ok(None)
+    }
+
+    #[test]
+    fn test_method_override_eq() {
+        let method = MethodOverride::Method(unsupported_operation);
+        assert_eq!(
+            MethodOverride::UnsupportedOperation,
+            MethodOverride::UnsupportedOperation
+        );
+        assert_eq!(method, method.clone());
+        assert_ne!(method, MethodOverride::Method(other_method));
+        assert_ne!(method, MethodOverride::UnsupportedOperation);
+        assert_ne!(MethodOverride::UnsupportedOperation, method);
+    }
+
+    #[test]
+    fn test_disabled_feature() {
+        let method_registry = MethodRegistry::default();
+        assert_eq!(None, method_registry.disabled_feature("java/lang/Object"));
+        for (feature, enabled, prefixes) in FEATURE_GROUPS {
+            for prefix in prefixes {
+                let class_name = format!("{prefix}Foo");
+                let expected = if enabled { None } else { Some(feature) };
+                assert_eq!(expected, method_registry.disabled_feature(&class_name));
+            }
+        }
+    }
+
+    #[test]
+    fn test_statistics() {
+        let mut method_registry = MethodRegistry::new(JAVA_21, true);
+        method_registry.initialize();
+        let hash_code = method_registry
+            .method("java/lang/Object", "hashCode", "()I")
+            .expect("method");
+        hash_code.record_call();
+        hash_code.record_call();
+        method_registry
+            .method("java/lang/Float", "floatToRawIntBits", "(F)I")
+            .expect("method")
+            .record_call();
+        method_registry.record_unlinked("foo/Bar", "baz", "()V");
+
+        let statistics = method_registry.statistics();
+        assert_eq!(JAVA_21, statistics.java_major_version);
+        assert_eq!(
+            method_registry.methods().len(),
+            statistics.registered_methods.len()
+        );
+        assert!(statistics
+            .registered_methods
+            .contains(&"java/lang/Object.hashCode()I".to_string()));
+        assert_eq!(
+            vec![
+                ("java/lang/Float.floatToRawIntBits(F)I".to_string(), 1),
+                ("java/lang/Object.hashCode()I".to_string(), 2),
+            ],
+            statistics.call_counts
+        );
+        assert_eq!(
+            vec![("foo/Bar.baz()V".to_string(), 1)],
+            statistics.unlinked_methods
+        );
+
+        let display = statistics.to_string();
+        assert!(display.contains("Java version: 21"));
+        assert!(display.contains("Called methods: 2"));
+        assert!(display.contains("       2 java/lang/Object.hashCode()I"));
+        assert!(display.contains("Unlinked methods: 1"));
+        assert!(display.contains("       1 foo/Bar.baz()V"));
+    }
+
     #[tokio::test]
     async fn test_method_not_found() -> Result<()> {
         let mut method_registry = MethodRegistry::new(JAVA_21, true);
@@ -680,8 +1103,6 @@ mod tests {
         let native_methods = get_native_methods(version).await?;
         let registry_methods = get_registry_methods(version).await?;
         // Required methods for ristretto
-        #[expect(unused_mut)]
-        #[expect(clippy::useless_vec)]
         let mut required_methods = vec![
             "java/lang/ClassLoader.initSystemClassLoader()Ljava/lang/ClassLoader;".to_string(),
             "java/lang/System.allowSecurityManager()Z".to_string(),
@@ -689,6 +1110,12 @@ mod tests {
             "java/lang/System.setSecurityManager(Ljava/lang/SecurityManager;)V".to_string(),
             "jdk/internal/module/ModuleBootstrap.boot()Ljava/lang/ModuleLayer;".to_string(),
         ];
+        if !version.starts_with("8.") {
+            required_methods.push(
+                "java/lang/reflect/AccessibleObject.checkCanSetAccessible(Ljava/lang/Class;Ljava/lang/Class;Z)Z"
+                    .to_string(),
+            );
+        }
         #[cfg(target_os = "windows")]
         {
             required_methods.push("java/io/WinNTFileSystem.initIDs()V".to_string());
@@ -700,15 +1127,24 @@ mod tests {
             .filter(|method| !registry_methods.contains(method))
             .cloned()
             .collect::<Vec<String>>();
+        let method_registry = MethodRegistry::default();
         #[cfg(target_os = "macos")]
         let missing_methods = native_methods
             .iter()
-            .filter(|method| !registry_methods.contains(method))
+            .filter(|method| {
+                !registry_methods.contains(method)
+                    && method_registry.disabled_feature(method).is_none()
+            })
             .cloned()
             .collect::<Vec<String>>();
         let extra_methods = registry_methods
             .iter()
-            .filter(|method| !native_methods.contains(method) && !required_methods.contains(method))
+            .filter(|method| {
+                // Methods of disabled features are inert implementations of the Java API
+                !native_methods.contains(method)
+                    && !required_methods.contains(method)
+                    && method_registry.disabled_feature(method).is_none()
+            })
             .cloned()
             .collect::<Vec<String>>();
 
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -20,7 +20,11 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn native_get_bounds(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.CGraphicsConfig.nativeGetBounds(I)Ljava/awt/geom/Rectangle2D;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeGetBounds",
+        "(I)Ljava/awt/geom/Rectangle2D;",
+    )
 }
 
 #[cfg(test)]
@@ -28,11 +32,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.CGraphicsConfig.nativeGetBounds(I)Ljava/awt/geom/Rectangle2D;"
-    )]
     async fn test_native_get_bounds() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_bounds(thread, Parameters::default()).await;
+        let result = native_get_bounds(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/CGraphicsConfig",
+            "nativeGetBounds",
+            "(I)Ljava/awt/geom/Rectangle2D;",
+        );
     }
 }
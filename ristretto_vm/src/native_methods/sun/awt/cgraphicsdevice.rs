@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_17, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_17, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -81,7 +81,11 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn native_get_bounds(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.CGraphicsDevice.nativeGetBounds(I)Ljava/awt/geom/Rectangle2D;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeGetBounds",
+        "(I)Ljava/awt/geom/Rectangle2D;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -89,7 +93,11 @@ async fn native_get_display_mode(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.CGraphicsDevice.nativeGetDisplayMode(I)Ljava/awt/DisplayMode;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeGetDisplayMode",
+        "(I)Ljava/awt/DisplayMode;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -97,7 +105,11 @@ async fn native_get_display_modes(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.CGraphicsDevice.nativeGetDisplayModes(I)[Ljava/awt/DisplayMode;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeGetDisplayModes",
+        "(I)[Ljava/awt/DisplayMode;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -105,7 +117,7 @@ async fn native_get_scale_factor(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.CGraphicsDevice.nativeGetScaleFactor(I)D")
+    unsatisfied_link(CLASS_NAME, "nativeGetScaleFactor", "(I)D")
 }
 
 #[async_recursion(?Send)]
@@ -113,7 +125,7 @@ async fn native_get_screen_insets(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.CGraphicsDevice.nativeGetScreenInsets(I)Ljava/awt/Insets;")
+    unsatisfied_link(CLASS_NAME, "nativeGetScreenInsets", "(I)Ljava/awt/Insets;")
 }
 
 #[async_recursion(?Send)]
@@ -121,7 +133,7 @@ async fn native_get_x_resolution(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.CGraphicsDevice.nativeGetXResolution(I)D")
+    unsatisfied_link(CLASS_NAME, "nativeGetXResolution", "(I)D")
 }
 
 #[async_recursion(?Send)]
@@ -129,7 +141,7 @@ async fn native_get_y_resolution(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.CGraphicsDevice.nativeGetYResolution(I)D")
+    unsatisfied_link(CLASS_NAME, "nativeGetYResolution", "(I)D")
 }
 
 #[async_recursion(?Send)]
@@ -137,7 +149,7 @@ async fn native_reset_display_mode(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.CGraphicsDevice.nativeResetDisplayMode()V")
+    unsatisfied_link(CLASS_NAME, "nativeResetDisplayMode", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -145,7 +157,7 @@ async fn native_set_display_mode(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.CGraphicsDevice.nativeSetDisplayMode(IIIII)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetDisplayMode", "(IIIII)V")
 }
 
 #[cfg(test)]
@@ -153,83 +165,110 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.CGraphicsDevice.nativeGetBounds(I)Ljava/awt/geom/Rectangle2D;"
-    )]
     async fn test_native_get_bounds() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_bounds(thread, Parameters::default()).await;
+        let result = native_get_bounds(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/CGraphicsDevice",
+            "nativeGetBounds",
+            "(I)Ljava/awt/geom/Rectangle2D;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.CGraphicsDevice.nativeGetDisplayMode(I)Ljava/awt/DisplayMode;"
-    )]
     async fn test_native_get_display_mode() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_display_mode(thread, Parameters::default()).await;
+        let result = native_get_display_mode(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/CGraphicsDevice",
+            "nativeGetDisplayMode",
+            "(I)Ljava/awt/DisplayMode;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.CGraphicsDevice.nativeGetDisplayModes(I)[Ljava/awt/DisplayMode;"
-    )]
     async fn test_native_get_display_modes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_display_modes(thread, Parameters::default()).await;
+        let result = native_get_display_modes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/CGraphicsDevice",
+            "nativeGetDisplayModes",
+            "(I)[Ljava/awt/DisplayMode;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.CGraphicsDevice.nativeGetScaleFactor(I)D"
-    )]
     async fn test_native_get_scale_factor() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_scale_factor(thread, Parameters::default()).await;
+        let result = native_get_scale_factor(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/CGraphicsDevice",
+            "nativeGetScaleFactor",
+            "(I)D",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.CGraphicsDevice.nativeGetScreenInsets(I)Ljava/awt/Insets;"
-    )]
     async fn test_native_get_screen_insets() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_screen_insets(thread, Parameters::default()).await;
+        let result = native_get_screen_insets(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/CGraphicsDevice",
+            "nativeGetScreenInsets",
+            "(I)Ljava/awt/Insets;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.CGraphicsDevice.nativeGetXResolution(I)D"
-    )]
     async fn test_native_get_x_resolution() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_x_resolution(thread, Parameters::default()).await;
+        let result = native_get_x_resolution(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/CGraphicsDevice",
+            "nativeGetXResolution",
+            "(I)D",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.CGraphicsDevice.nativeGetYResolution(I)D"
-    )]
     async fn test_native_get_y_resolution() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_y_resolution(thread, Parameters::default()).await;
+        let result = native_get_y_resolution(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/CGraphicsDevice",
+            "nativeGetYResolution",
+            "(I)D",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.CGraphicsDevice.nativeResetDisplayMode()V"
-    )]
     async fn test_native_reset_display_mode() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_reset_display_mode(thread, Parameters::default()).await;
+        let result = native_reset_display_mode(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/CGraphicsDevice",
+            "nativeResetDisplayMode",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.CGraphicsDevice.nativeSetDisplayMode(IIIII)V"
-    )]
     async fn test_native_set_display_mode() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_display_mode(thread, Parameters::default()).await;
+        let result = native_set_display_mode(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/CGraphicsDevice",
+            "nativeSetDisplayMode",
+            "(IIIII)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -35,12 +35,12 @@ async fn deregister_display_reconfiguration(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.CGraphicsEnvironment.deregisterDisplayReconfiguration(J)V")
+    unsatisfied_link(CLASS_NAME, "deregisterDisplayReconfiguration", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn get_display_i_ds(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.CGraphicsEnvironment.getDisplayIDs()[I")
+    unsatisfied_link(CLASS_NAME, "getDisplayIDs", "()[I")
 }
 
 #[async_recursion(?Send)]
@@ -48,12 +48,12 @@ async fn get_main_display_id(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.CGraphicsEnvironment.getMainDisplayID()I")
+    unsatisfied_link(CLASS_NAME, "getMainDisplayID", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn init_cocoa(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.CGraphicsEnvironment.initCocoa()V")
+    unsatisfied_link(CLASS_NAME, "initCocoa", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -61,7 +61,7 @@ async fn register_display_reconfiguration(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.CGraphicsEnvironment.registerDisplayReconfiguration()J")
+    unsatisfied_link(CLASS_NAME, "registerDisplayReconfiguration", "()J")
 }
 
 #[cfg(test)]
@@ -69,45 +69,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.CGraphicsEnvironment.deregisterDisplayReconfiguration(J)V"
-    )]
     async fn test_deregister_display_reconfiguration() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = deregister_display_reconfiguration(thread, Parameters::default()).await;
+        let result = deregister_display_reconfiguration(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/CGraphicsEnvironment",
+            "deregisterDisplayReconfiguration",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.CGraphicsEnvironment.getDisplayIDs()[I"
-    )]
     async fn test_get_display_i_ds() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_display_i_ds(thread, Parameters::default()).await;
+        let result = get_display_i_ds(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/CGraphicsEnvironment",
+            "getDisplayIDs",
+            "()[I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.CGraphicsEnvironment.getMainDisplayID()I"
-    )]
     async fn test_get_main_display_id() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_main_display_id(thread, Parameters::default()).await;
+        let result = get_main_display_id(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/CGraphicsEnvironment",
+            "getMainDisplayID",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.CGraphicsEnvironment.initCocoa()V")]
     async fn test_init_cocoa() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_cocoa(thread, Parameters::default()).await;
+        let result = init_cocoa(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/CGraphicsEnvironment",
+            "initCocoa",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.CGraphicsEnvironment.registerDisplayReconfiguration()J"
-    )]
     async fn test_register_display_reconfiguration() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = register_display_reconfiguration(thread, Parameters::default()).await;
+        let result = register_display_reconfiguration(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/CGraphicsEnvironment",
+            "registerDisplayReconfiguration",
+            "()J",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -30,7 +30,7 @@ async fn set_c_tracing_on_1(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.DebugSettings.setCTracingOn(Z)V")
+    unsatisfied_link(CLASS_NAME, "setCTracingOn", "(Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -38,7 +38,7 @@ async fn set_c_tracing_on_2(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.DebugSettings.setCTracingOn(ZLjava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "setCTracingOn", "(ZLjava/lang/String;)V")
 }
 
 #[async_recursion(?Send)]
@@ -46,7 +46,7 @@ async fn set_c_tracing_on_3(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.DebugSettings.setCTracingOn(ZLjava/lang/String;I)V")
+    unsatisfied_link(CLASS_NAME, "setCTracingOn", "(ZLjava/lang/String;I)V")
 }
 
 #[cfg(test)]
@@ -54,27 +54,38 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.DebugSettings.setCTracingOn(Z)V")]
     async fn test_set_c_tracing_on_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_c_tracing_on_1(thread, Parameters::default()).await;
+        let result = set_c_tracing_on_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/DebugSettings",
+            "setCTracingOn",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.DebugSettings.setCTracingOn(ZLjava/lang/String;)V"
-    )]
     async fn test_set_c_tracing_on_2() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_c_tracing_on_2(thread, Parameters::default()).await;
+        let result = set_c_tracing_on_2(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/DebugSettings",
+            "setCTracingOn",
+            "(ZLjava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.DebugSettings.setCTracingOn(ZLjava/lang/String;I)V"
-    )]
     async fn test_set_c_tracing_on_3() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_c_tracing_on_3(thread, Parameters::default()).await;
+        let result = set_c_tracing_on_3(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/DebugSettings",
+            "setCTracingOn",
+            "(ZLjava/lang/String;I)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -29,7 +29,7 @@ async fn fill_point_with_coords(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.DefaultMouseInfoPeer.fillPointWithCoords(Ljava/awt/Point;)I")
+    unsatisfied_link(CLASS_NAME, "fillPointWithCoords", "(Ljava/awt/Point;)I")
 }
 
 #[async_recursion(?Send)]
@@ -37,7 +37,7 @@ async fn is_window_under_mouse(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.DefaultMouseInfoPeer.isWindowUnderMouse(Ljava/awt/Window;)Z")
+    unsatisfied_link(CLASS_NAME, "isWindowUnderMouse", "(Ljava/awt/Window;)Z")
 }
 
 #[cfg(test)]
@@ -45,20 +45,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.DefaultMouseInfoPeer.fillPointWithCoords(Ljava/awt/Point;)I"
-    )]
     async fn test_fill_point_with_coords() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fill_point_with_coords(thread, Parameters::default()).await;
+        let result = fill_point_with_coords(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/DefaultMouseInfoPeer",
+            "fillPointWithCoords",
+            "(Ljava/awt/Point;)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.DefaultMouseInfoPeer.isWindowUnderMouse(Ljava/awt/Window;)Z"
-    )]
     async fn test_is_window_under_mouse() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_window_under_mouse(thread, Parameters::default()).await;
+        let result = is_window_under_mouse(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/DefaultMouseInfoPeer",
+            "isWindowUnderMouse",
+            "(Ljava/awt/Window;)Z",
+        );
     }
 }
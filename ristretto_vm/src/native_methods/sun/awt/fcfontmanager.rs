@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,7 +23,7 @@ async fn get_font_path_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.FcFontManager.getFontPathNative(ZZ)Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getFontPathNative", "(ZZ)Ljava/lang/String;")
 }
 
 #[cfg(test)]
@@ -31,11 +31,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.FcFontManager.getFontPathNative(ZZ)Ljava/lang/String;"
-    )]
     async fn test_get_font_path_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_font_path_native(thread, Parameters::default()).await;
+        let result = get_font_path_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/FcFontManager",
+            "getFontPathNative",
+            "(ZZ)Ljava/lang/String;",
+        );
     }
 }
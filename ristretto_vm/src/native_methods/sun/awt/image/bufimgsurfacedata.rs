@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -31,7 +31,11 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn init_raster(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.image.BufImgSurfaceData.initRaster(Ljava/lang/Object;IIIIIILjava/awt/image/IndexColorModel;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "initRaster",
+        "(Ljava/lang/Object;IIIIIILjava/awt/image/IndexColorModel;)V",
+    )
 }
 
 #[cfg(test)]
@@ -47,11 +51,14 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.image.BufImgSurfaceData.initRaster(Ljava/lang/Object;IIIIIILjava/awt/image/IndexColorModel;)V"
-    )]
     async fn test_init_raster() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_raster(thread, Parameters::default()).await;
+        let result = init_raster(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/image/BufImgSurfaceData",
+            "initRaster",
+            "(Ljava/lang/Object;IIIIIILjava/awt/image/IndexColorModel;)V",
+        );
     }
 }
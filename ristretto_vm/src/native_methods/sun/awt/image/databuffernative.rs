@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -26,12 +26,12 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn get_elem(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.image.DataBufferNative.getElem(IILsun/java2d/SurfaceData;)I")
+    unsatisfied_link(CLASS_NAME, "getElem", "(IILsun/java2d/SurfaceData;)I")
 }
 
 #[async_recursion(?Send)]
 async fn set_elem(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.image.DataBufferNative.setElem(IIILsun/java2d/SurfaceData;)V")
+    unsatisfied_link(CLASS_NAME, "setElem", "(IIILsun/java2d/SurfaceData;)V")
 }
 
 #[cfg(test)]
@@ -39,20 +39,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.image.DataBufferNative.getElem(IILsun/java2d/SurfaceData;)I"
-    )]
     async fn test_get_elem() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_elem(thread, Parameters::default()).await;
+        let result = get_elem(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/image/DataBufferNative",
+            "getElem",
+            "(IILsun/java2d/SurfaceData;)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.image.DataBufferNative.setElem(IIILsun/java2d/SurfaceData;)V"
-    )]
     async fn test_set_elem() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_elem(thread, Parameters::default()).await;
+        let result = set_elem(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/image/DataBufferNative",
+            "setElem",
+            "(IIILsun/java2d/SurfaceData;)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -32,12 +32,20 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn set_diff_icm(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.image.ImageRepresentation.setDiffICM(IIII[IIILjava/awt/image/IndexColorModel;[BIILsun/awt/image/ByteComponentRaster;I)Z")
+    unsatisfied_link(
+        CLASS_NAME,
+        "setDiffICM",
+        "(IIII[IIILjava/awt/image/IndexColorModel;[BIILsun/awt/image/ByteComponentRaster;I)Z",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn set_icm_pixels(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.image.ImageRepresentation.setICMpixels(IIII[I[BIILsun/awt/image/IntegerComponentRaster;)Z")
+    unsatisfied_link(
+        CLASS_NAME,
+        "setICMpixels",
+        "(IIII[I[BIILsun/awt/image/IntegerComponentRaster;)Z",
+    )
 }
 
 #[cfg(test)]
@@ -53,20 +61,26 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.image.ImageRepresentation.setDiffICM(IIII[IIILjava/awt/image/IndexColorModel;[BIILsun/awt/image/ByteComponentRaster;I)Z"
-    )]
     async fn test_set_diff_icm() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_diff_icm(thread, Parameters::default()).await;
+        let result = set_diff_icm(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/image/ImageRepresentation",
+            "setDiffICM",
+            "(IIII[IIILjava/awt/image/IndexColorModel;[BIILsun/awt/image/ByteComponentRaster;I)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.image.ImageRepresentation.setICMpixels(IIII[I[BIILsun/awt/image/IntegerComponentRaster;)Z"
-    )]
     async fn test_set_icm_pixels() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_icm_pixels(thread, Parameters::default()).await;
+        let result = set_icm_pixels(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/image/ImageRepresentation",
+            "setICMpixels",
+            "(IIII[I[BIILsun/awt/image/IntegerComponentRaster;)Z",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -51,22 +51,34 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn convolve_bi(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.image.ImagingLib.convolveBI(Ljava/awt/image/BufferedImage;Ljava/awt/image/BufferedImage;Ljava/awt/image/Kernel;I)I")
+    unsatisfied_link(
+        CLASS_NAME,
+        "convolveBI",
+        "(Ljava/awt/image/BufferedImage;Ljava/awt/image/BufferedImage;Ljava/awt/image/Kernel;I)I",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn convolve_raster(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.image.ImagingLib.convolveRaster(Ljava/awt/image/Raster;Ljava/awt/image/Raster;Ljava/awt/image/Kernel;I)I")
+    unsatisfied_link(
+        CLASS_NAME,
+        "convolveRaster",
+        "(Ljava/awt/image/Raster;Ljava/awt/image/Raster;Ljava/awt/image/Kernel;I)I",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.image.ImagingLib.init()Z")
+    unsatisfied_link(CLASS_NAME, "init", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn lookup_byte_bi(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.image.ImagingLib.lookupByteBI(Ljava/awt/image/BufferedImage;Ljava/awt/image/BufferedImage;[[B)I")
+    unsatisfied_link(
+        CLASS_NAME,
+        "lookupByteBI",
+        "(Ljava/awt/image/BufferedImage;Ljava/awt/image/BufferedImage;[[B)I",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -74,17 +86,29 @@ async fn lookup_byte_raster(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.image.ImagingLib.lookupByteRaster(Ljava/awt/image/Raster;Ljava/awt/image/Raster;[[B)I")
+    unsatisfied_link(
+        CLASS_NAME,
+        "lookupByteRaster",
+        "(Ljava/awt/image/Raster;Ljava/awt/image/Raster;[[B)I",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn transform_bi(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.image.ImagingLib.transformBI(Ljava/awt/image/BufferedImage;Ljava/awt/image/BufferedImage;[DI)I")
+    unsatisfied_link(
+        CLASS_NAME,
+        "transformBI",
+        "(Ljava/awt/image/BufferedImage;Ljava/awt/image/BufferedImage;[DI)I",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn transform_raster(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.image.ImagingLib.transformRaster(Ljava/awt/image/Raster;Ljava/awt/image/Raster;[DI)I")
+    unsatisfied_link(
+        CLASS_NAME,
+        "transformRaster",
+        "(Ljava/awt/image/Raster;Ljava/awt/image/Raster;[DI)I",
+    )
 }
 
 #[cfg(test)]
@@ -92,63 +116,76 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.image.ImagingLib.convolveBI(Ljava/awt/image/BufferedImage;Ljava/awt/image/BufferedImage;Ljava/awt/image/Kernel;I)I"
-    )]
     async fn test_convolve_bi() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = convolve_bi(thread, Parameters::default()).await;
+        let result = convolve_bi(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/awt/image/ImagingLib", "convolveBI", "(Ljava/awt/image/BufferedImage;Ljava/awt/image/BufferedImage;Ljava/awt/image/Kernel;I)I");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.image.ImagingLib.convolveRaster(Ljava/awt/image/Raster;Ljava/awt/image/Raster;Ljava/awt/image/Kernel;I)I"
-    )]
     async fn test_convolve_raster() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = convolve_raster(thread, Parameters::default()).await;
+        let result = convolve_raster(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/image/ImagingLib",
+            "convolveRaster",
+            "(Ljava/awt/image/Raster;Ljava/awt/image/Raster;Ljava/awt/image/Kernel;I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.image.ImagingLib.init()Z")]
     async fn test_init() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init(thread, Parameters::default()).await;
+        let result = init(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/awt/image/ImagingLib", "init", "()Z");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.image.ImagingLib.lookupByteBI(Ljava/awt/image/BufferedImage;Ljava/awt/image/BufferedImage;[[B)I"
-    )]
     async fn test_lookup_byte_bi() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = lookup_byte_bi(thread, Parameters::default()).await;
+        let result = lookup_byte_bi(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/image/ImagingLib",
+            "lookupByteBI",
+            "(Ljava/awt/image/BufferedImage;Ljava/awt/image/BufferedImage;[[B)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.image.ImagingLib.lookupByteRaster(Ljava/awt/image/Raster;Ljava/awt/image/Raster;[[B)I"
-    )]
     async fn test_lookup_byte_raster() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = lookup_byte_raster(thread, Parameters::default()).await;
+        let result = lookup_byte_raster(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/image/ImagingLib",
+            "lookupByteRaster",
+            "(Ljava/awt/image/Raster;Ljava/awt/image/Raster;[[B)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.image.ImagingLib.transformBI(Ljava/awt/image/BufferedImage;Ljava/awt/image/BufferedImage;[DI)I"
-    )]
     async fn test_transform_bi() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = transform_bi(thread, Parameters::default()).await;
+        let result = transform_bi(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/image/ImagingLib",
+            "transformBI",
+            "(Ljava/awt/image/BufferedImage;Ljava/awt/image/BufferedImage;[DI)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.image.ImagingLib.transformRaster(Ljava/awt/image/Raster;Ljava/awt/image/Raster;[DI)I"
-    )]
     async fn test_transform_raster() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = transform_raster(thread, Parameters::default()).await;
+        let result = transform_raster(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/image/ImagingLib",
+            "transformRaster",
+            "(Ljava/awt/image/Raster;Ljava/awt/image/Raster;[DI)I",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -26,7 +26,7 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn read_image(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.image.JPEGImageDecoder.readImage(Ljava/io/InputStream;[B)V")
+    unsatisfied_link(CLASS_NAME, "readImage", "(Ljava/io/InputStream;[B)V")
 }
 
 #[cfg(test)]
@@ -42,11 +42,14 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.image.JPEGImageDecoder.readImage(Ljava/io/InputStream;[B)V"
-    )]
     async fn test_read_image() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = read_image(thread, Parameters::default()).await;
+        let result = read_image(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/image/JPEGImageDecoder",
+            "readImage",
+            "(Ljava/io/InputStream;[B)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -18,7 +18,7 @@ async fn is_in_aqua_session(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.PlatformGraphicsInfo.isInAquaSession()Z")
+    unsatisfied_link(CLASS_NAME, "isInAquaSession", "()Z")
 }
 
 #[cfg(test)]
@@ -26,11 +26,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.PlatformGraphicsInfo.isInAquaSession()Z"
-    )]
     async fn test_is_in_aqua_session() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_in_aqua_session(thread, Parameters::default()).await;
+        let result = is_in_aqua_session(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/PlatformGraphicsInfo",
+            "isInAquaSession",
+            "()Z",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -18,7 +18,7 @@ async fn close_splash_screen(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.SunToolkit.closeSplashScreen()V")
+    unsatisfied_link(CLASS_NAME, "closeSplashScreen", "()V")
 }
 
 #[cfg(test)]
@@ -26,9 +26,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.SunToolkit.closeSplashScreen()V")]
     async fn test_close_splash_screen() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = close_splash_screen(thread, Parameters::default()).await;
+        let result = close_splash_screen(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/SunToolkit",
+            "closeSplashScreen",
+            "()V",
+        );
     }
 }
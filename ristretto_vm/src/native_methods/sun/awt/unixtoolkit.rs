@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -37,12 +37,12 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn check_gtk(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.UNIXToolkit.check_gtk(I)Z")
+    unsatisfied_link(CLASS_NAME, "check_gtk", "(I)Z")
 }
 
 #[async_recursion(?Send)]
 async fn get_gtk_version(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.UNIXToolkit.get_gtk_version()I")
+    unsatisfied_link(CLASS_NAME, "get_gtk_version", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -50,32 +50,36 @@ async fn gtk_check_version_impl(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.UNIXToolkit.gtkCheckVersionImpl(III)Z")
+    unsatisfied_link(CLASS_NAME, "gtkCheckVersionImpl", "(III)Z")
 }
 
 #[async_recursion(?Send)]
 async fn load_gtk(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.UNIXToolkit.load_gtk(IZ)Z")
+    unsatisfied_link(CLASS_NAME, "load_gtk", "(IZ)Z")
 }
 
 #[async_recursion(?Send)]
 async fn load_gtk_icon(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.UNIXToolkit.load_gtk_icon(Ljava/lang/String;)Z")
+    unsatisfied_link(CLASS_NAME, "load_gtk_icon", "(Ljava/lang/String;)Z")
 }
 
 #[async_recursion(?Send)]
 async fn load_stock_icon(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.UNIXToolkit.load_stock_icon(ILjava/lang/String;IILjava/lang/String;)Z")
+    unsatisfied_link(
+        CLASS_NAME,
+        "load_stock_icon",
+        "(ILjava/lang/String;IILjava/lang/String;)Z",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn native_sync(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.UNIXToolkit.nativeSync()V")
+    unsatisfied_link(CLASS_NAME, "nativeSync", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn unload_gtk(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.UNIXToolkit.unload_gtk()Z")
+    unsatisfied_link(CLASS_NAME, "unload_gtk", "()Z")
 }
 
 #[cfg(test)]
@@ -83,62 +87,78 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.UNIXToolkit.check_gtk(I)Z")]
     async fn test_check_gtk() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = check_gtk(thread, Parameters::default()).await;
+        let result = check_gtk(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/awt/UNIXToolkit", "check_gtk", "(I)Z");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.UNIXToolkit.get_gtk_version()I")]
     async fn test_get_gtk_version() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_gtk_version(thread, Parameters::default()).await;
+        let result = get_gtk_version(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/UNIXToolkit",
+            "get_gtk_version",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.UNIXToolkit.gtkCheckVersionImpl(III)Z")]
     async fn test_gtk_check_version_impl() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = gtk_check_version_impl(thread, Parameters::default()).await;
+        let result = gtk_check_version_impl(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/UNIXToolkit",
+            "gtkCheckVersionImpl",
+            "(III)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.UNIXToolkit.load_gtk(IZ)Z")]
     async fn test_load_gtk() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = load_gtk(thread, Parameters::default()).await;
+        let result = load_gtk(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/awt/UNIXToolkit", "load_gtk", "(IZ)Z");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.UNIXToolkit.load_gtk_icon(Ljava/lang/String;)Z"
-    )]
     async fn test_load_gtk_icon() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = load_gtk_icon(thread, Parameters::default()).await;
+        let result = load_gtk_icon(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/UNIXToolkit",
+            "load_gtk_icon",
+            "(Ljava/lang/String;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.UNIXToolkit.load_stock_icon(ILjava/lang/String;IILjava/lang/String;)Z"
-    )]
     async fn test_load_stock_icon() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = load_stock_icon(thread, Parameters::default()).await;
+        let result = load_stock_icon(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/UNIXToolkit",
+            "load_stock_icon",
+            "(ILjava/lang/String;IILjava/lang/String;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.UNIXToolkit.nativeSync()V")]
     async fn test_native_sync() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_sync(thread, Parameters::default()).await;
+        let result = native_sync(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/awt/UNIXToolkit", "nativeSync", "()V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.UNIXToolkit.unload_gtk()Z")]
     async fn test_unload_gtk() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = unload_gtk(thread, Parameters::default()).await;
+        let result = unload_gtk(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/awt/UNIXToolkit", "unload_gtk", "()Z");
     }
 }
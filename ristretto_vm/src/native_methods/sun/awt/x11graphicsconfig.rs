@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -44,7 +44,7 @@ async fn create_back_buffer(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsConfig.createBackBuffer(JI)J")
+    unsatisfied_link(CLASS_NAME, "createBackBuffer", "(JI)J")
 }
 
 #[async_recursion(?Send)]
@@ -52,32 +52,32 @@ async fn destroy_back_buffer(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsConfig.destroyBackBuffer(J)V")
+    unsatisfied_link(CLASS_NAME, "destroyBackBuffer", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn dispose(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsConfig.dispose(J)V")
+    unsatisfied_link(CLASS_NAME, "dispose", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn get_num_colors(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsConfig.getNumColors()I")
+    unsatisfied_link(CLASS_NAME, "getNumColors", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn get_x_resolution(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsConfig.getXResolution(I)D")
+    unsatisfied_link(CLASS_NAME, "getXResolution", "(I)D")
 }
 
 #[async_recursion(?Send)]
 async fn get_y_resolution(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsConfig.getYResolution(I)D")
+    unsatisfied_link(CLASS_NAME, "getYResolution", "(I)D")
 }
 
 #[async_recursion(?Send)]
 async fn init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsConfig.init(II)V")
+    unsatisfied_link(CLASS_NAME, "init", "(II)V")
 }
 
 #[async_recursion(?Send)]
@@ -90,22 +90,26 @@ async fn is_translucency_capable(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsConfig.isTranslucencyCapable(J)Z")
+    unsatisfied_link(CLASS_NAME, "isTranslucencyCapable", "(J)Z")
 }
 
 #[async_recursion(?Send)]
 async fn make_color_model(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsConfig.makeColorModel()Ljava/awt/image/ColorModel;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "makeColorModel",
+        "()Ljava/awt/image/ColorModel;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn p_get_bounds(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsConfig.pGetBounds(I)Ljava/awt/Rectangle;")
+    unsatisfied_link(CLASS_NAME, "pGetBounds", "(I)Ljava/awt/Rectangle;")
 }
 
 #[async_recursion(?Send)]
 async fn swap_buffers(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsConfig.swapBuffers(JI)V")
+    unsatisfied_link(CLASS_NAME, "swapBuffers", "(JI)V")
 }
 
 #[cfg(test)]
@@ -113,56 +117,82 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsConfig.createBackBuffer(JI)J"
-    )]
     async fn test_create_back_buffer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_back_buffer(thread, Parameters::default()).await;
+        let result = create_back_buffer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsConfig",
+            "createBackBuffer",
+            "(JI)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsConfig.destroyBackBuffer(J)V"
-    )]
     async fn test_destroy_back_buffer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = destroy_back_buffer(thread, Parameters::default()).await;
+        let result = destroy_back_buffer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsConfig",
+            "destroyBackBuffer",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.X11GraphicsConfig.dispose(J)V")]
     async fn test_dispose() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = dispose(thread, Parameters::default()).await;
+        let result = dispose(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsConfig",
+            "dispose",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.X11GraphicsConfig.getNumColors()I")]
     async fn test_get_num_colors() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_num_colors(thread, Parameters::default()).await;
+        let result = get_num_colors(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsConfig",
+            "getNumColors",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.X11GraphicsConfig.getXResolution(I)D")]
     async fn test_get_x_resolution() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_x_resolution(thread, Parameters::default()).await;
+        let result = get_x_resolution(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsConfig",
+            "getXResolution",
+            "(I)D",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.X11GraphicsConfig.getYResolution(I)D")]
     async fn test_get_y_resolution() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_y_resolution(thread, Parameters::default()).await;
+        let result = get_y_resolution(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsConfig",
+            "getYResolution",
+            "(I)D",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.X11GraphicsConfig.init(II)V")]
     async fn test_init() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init(thread, Parameters::default()).await;
+        let result = init(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/awt/X11GraphicsConfig", "init", "(II)V");
     }
 
     #[tokio::test]
@@ -174,36 +204,50 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsConfig.isTranslucencyCapable(J)Z"
-    )]
     async fn test_is_translucency_capable() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_translucency_capable(thread, Parameters::default()).await;
+        let result = is_translucency_capable(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsConfig",
+            "isTranslucencyCapable",
+            "(J)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsConfig.makeColorModel()Ljava/awt/image/ColorModel;"
-    )]
     async fn test_make_color_model() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = make_color_model(thread, Parameters::default()).await;
+        let result = make_color_model(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsConfig",
+            "makeColorModel",
+            "()Ljava/awt/image/ColorModel;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsConfig.pGetBounds(I)Ljava/awt/Rectangle;"
-    )]
     async fn test_p_get_bounds() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = p_get_bounds(thread, Parameters::default()).await;
+        let result = p_get_bounds(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsConfig",
+            "pGetBounds",
+            "(I)Ljava/awt/Rectangle;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.X11GraphicsConfig.swapBuffers(JI)V")]
     async fn test_swap_buffers() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = swap_buffers(thread, Parameters::default()).await;
+        let result = swap_buffers(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsConfig",
+            "swapBuffers",
+            "(JI)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -77,7 +77,7 @@ async fn config_display_mode(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsDevice.configDisplayMode(IIII)V")
+    unsatisfied_link(CLASS_NAME, "configDisplayMode", "(IIII)V")
 }
 
 #[async_recursion(?Send)]
@@ -85,7 +85,7 @@ async fn enter_full_screen_exclusive(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsDevice.enterFullScreenExclusive(J)V")
+    unsatisfied_link(CLASS_NAME, "enterFullScreenExclusive", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -93,7 +93,7 @@ async fn enum_display_modes(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsDevice.enumDisplayModes(ILjava/util/ArrayList;)V")
+    unsatisfied_link(CLASS_NAME, "enumDisplayModes", "(ILjava/util/ArrayList;)V")
 }
 
 #[async_recursion(?Send)]
@@ -101,7 +101,7 @@ async fn exit_full_screen_exclusive(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsDevice.exitFullScreenExclusive(J)V")
+    unsatisfied_link(CLASS_NAME, "exitFullScreenExclusive", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -109,12 +109,12 @@ async fn get_config_colormap(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsDevice.getConfigColormap(II)I")
+    unsatisfied_link(CLASS_NAME, "getConfigColormap", "(II)I")
 }
 
 #[async_recursion(?Send)]
 async fn get_config_depth(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsDevice.getConfigDepth(II)I")
+    unsatisfied_link(CLASS_NAME, "getConfigDepth", "(II)I")
 }
 
 #[async_recursion(?Send)]
@@ -122,7 +122,7 @@ async fn get_config_visual_id(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsDevice.getConfigVisualId(II)I")
+    unsatisfied_link(CLASS_NAME, "getConfigVisualId", "(II)I")
 }
 
 #[async_recursion(?Send)]
@@ -130,12 +130,16 @@ async fn get_current_display_mode(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsDevice.getCurrentDisplayMode(I)Ljava/awt/DisplayMode;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getCurrentDisplayMode",
+        "(I)Ljava/awt/DisplayMode;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_display(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsDevice.getDisplay()J")
+    unsatisfied_link(CLASS_NAME, "getDisplay", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -143,12 +147,12 @@ async fn get_double_buffer_visuals(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsDevice.getDoubleBufferVisuals(I)V")
+    unsatisfied_link(CLASS_NAME, "getDoubleBufferVisuals", "(I)V")
 }
 
 #[async_recursion(?Send)]
 async fn get_num_configs(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsDevice.getNumConfigs(I)I")
+    unsatisfied_link(CLASS_NAME, "getNumConfigs", "(I)I")
 }
 
 #[async_recursion(?Send)]
@@ -161,17 +165,17 @@ async fn init_xrandr_extension(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsDevice.initXrandrExtension()Z")
+    unsatisfied_link(CLASS_NAME, "initXrandrExtension", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn is_dbe_supported(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsDevice.isDBESupported()Z")
+    unsatisfied_link(CLASS_NAME, "isDBESupported", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn reset_native_data(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsDevice.resetNativeData(I)V")
+    unsatisfied_link(CLASS_NAME, "resetNativeData", "(I)V")
 }
 
 #[cfg(test)]
@@ -179,96 +183,135 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsDevice.configDisplayMode(IIII)V"
-    )]
     async fn test_config_display_mode() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = config_display_mode(thread, Parameters::default()).await;
+        let result = config_display_mode(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsDevice",
+            "configDisplayMode",
+            "(IIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsDevice.enterFullScreenExclusive(J)V"
-    )]
     async fn test_enter_full_screen_exclusive() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = enter_full_screen_exclusive(thread, Parameters::default()).await;
+        let result = enter_full_screen_exclusive(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsDevice",
+            "enterFullScreenExclusive",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsDevice.enumDisplayModes(ILjava/util/ArrayList;)V"
-    )]
     async fn test_enum_display_modes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = enum_display_modes(thread, Parameters::default()).await;
+        let result = enum_display_modes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsDevice",
+            "enumDisplayModes",
+            "(ILjava/util/ArrayList;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsDevice.exitFullScreenExclusive(J)V"
-    )]
     async fn test_exit_full_screen_exclusive() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = exit_full_screen_exclusive(thread, Parameters::default()).await;
+        let result = exit_full_screen_exclusive(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsDevice",
+            "exitFullScreenExclusive",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsDevice.getConfigColormap(II)I"
-    )]
     async fn test_get_config_colormap() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_config_colormap(thread, Parameters::default()).await;
+        let result = get_config_colormap(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsDevice",
+            "getConfigColormap",
+            "(II)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.X11GraphicsDevice.getConfigDepth(II)I")]
     async fn test_get_config_depth() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_config_depth(thread, Parameters::default()).await;
+        let result = get_config_depth(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsDevice",
+            "getConfigDepth",
+            "(II)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsDevice.getConfigVisualId(II)I"
-    )]
     async fn test_get_config_visual_id() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_config_visual_id(thread, Parameters::default()).await;
+        let result = get_config_visual_id(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsDevice",
+            "getConfigVisualId",
+            "(II)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsDevice.getCurrentDisplayMode(I)Ljava/awt/DisplayMode;"
-    )]
     async fn test_get_current_display_mode() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_current_display_mode(thread, Parameters::default()).await;
+        let result = get_current_display_mode(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsDevice",
+            "getCurrentDisplayMode",
+            "(I)Ljava/awt/DisplayMode;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.X11GraphicsDevice.getDisplay()J")]
     async fn test_get_display() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_display(thread, Parameters::default()).await;
+        let result = get_display(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsDevice",
+            "getDisplay",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsDevice.getDoubleBufferVisuals(I)V"
-    )]
     async fn test_get_double_buffer_visuals() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_double_buffer_visuals(thread, Parameters::default()).await;
+        let result = get_double_buffer_visuals(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsDevice",
+            "getDoubleBufferVisuals",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.X11GraphicsDevice.getNumConfigs(I)I")]
     async fn test_get_num_configs() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_num_configs(thread, Parameters::default()).await;
+        let result = get_num_configs(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsDevice",
+            "getNumConfigs",
+            "(I)I",
+        );
     }
 
     #[tokio::test]
@@ -280,25 +323,38 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsDevice.initXrandrExtension()Z"
-    )]
     async fn test_init_xrandr_extension() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_xrandr_extension(thread, Parameters::default()).await;
+        let result = init_xrandr_extension(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsDevice",
+            "initXrandrExtension",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.X11GraphicsDevice.isDBESupported()Z")]
     async fn test_is_dbe_supported() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_dbe_supported(thread, Parameters::default()).await;
+        let result = is_dbe_supported(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsDevice",
+            "isDBESupported",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.X11GraphicsDevice.resetNativeData(I)V")]
     async fn test_reset_native_data() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = reset_native_data(thread, Parameters::default()).await;
+        let result = reset_native_data(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsDevice",
+            "resetNativeData",
+            "(I)V",
+        );
     }
 }
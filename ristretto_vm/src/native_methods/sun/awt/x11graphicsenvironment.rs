@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -38,7 +38,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn check_shm_ext(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsEnvironment.checkShmExt()I")
+    unsatisfied_link(CLASS_NAME, "checkShmExt", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -46,7 +46,7 @@ async fn get_default_screen_num(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsEnvironment.getDefaultScreenNum()I")
+    unsatisfied_link(CLASS_NAME, "getDefaultScreenNum", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -54,12 +54,12 @@ async fn get_display_string(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsEnvironment.getDisplayString()Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getDisplayString", "()Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
 async fn get_num_screens(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsEnvironment.getNumScreens()I")
+    unsatisfied_link(CLASS_NAME, "getNumScreens", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -67,22 +67,22 @@ async fn get_xinerama_center_point(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsEnvironment.getXineramaCenterPoint()Ljava/awt/Point;")
+    unsatisfied_link(CLASS_NAME, "getXineramaCenterPoint", "()Ljava/awt/Point;")
 }
 
 #[async_recursion(?Send)]
 async fn init_display(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsEnvironment.initDisplay(Z)V")
+    unsatisfied_link(CLASS_NAME, "initDisplay", "(Z)V")
 }
 
 #[async_recursion(?Send)]
 async fn init_glx(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsEnvironment.initGLX()Z")
+    unsatisfied_link(CLASS_NAME, "initGLX", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn init_x_render(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsEnvironment.initXRender(ZZ)Z")
+    unsatisfied_link(CLASS_NAME, "initXRender", "(ZZ)Z")
 }
 
 #[async_recursion(?Send)]
@@ -90,7 +90,7 @@ async fn p_running_xinerama(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.X11GraphicsEnvironment.pRunningXinerama()Z")
+    unsatisfied_link(CLASS_NAME, "pRunningXinerama", "()Z")
 }
 
 #[cfg(test)]
@@ -98,79 +98,110 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.X11GraphicsEnvironment.checkShmExt()I")]
     async fn test_check_shm_ext() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = check_shm_ext(thread, Parameters::default()).await;
+        let result = check_shm_ext(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsEnvironment",
+            "checkShmExt",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsEnvironment.getDefaultScreenNum()I"
-    )]
     async fn test_get_default_screen_num() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_default_screen_num(thread, Parameters::default()).await;
+        let result = get_default_screen_num(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsEnvironment",
+            "getDefaultScreenNum",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsEnvironment.getDisplayString()Ljava/lang/String;"
-    )]
     async fn test_get_display_string() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_display_string(thread, Parameters::default()).await;
+        let result = get_display_string(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsEnvironment",
+            "getDisplayString",
+            "()Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsEnvironment.getNumScreens()I"
-    )]
     async fn test_get_num_screens() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_num_screens(thread, Parameters::default()).await;
+        let result = get_num_screens(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsEnvironment",
+            "getNumScreens",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsEnvironment.getXineramaCenterPoint()Ljava/awt/Point;"
-    )]
     async fn test_get_xinerama_center_point() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_xinerama_center_point(thread, Parameters::default()).await;
+        let result = get_xinerama_center_point(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsEnvironment",
+            "getXineramaCenterPoint",
+            "()Ljava/awt/Point;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsEnvironment.initDisplay(Z)V"
-    )]
     async fn test_init_display() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_display(thread, Parameters::default()).await;
+        let result = init_display(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsEnvironment",
+            "initDisplay",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.X11GraphicsEnvironment.initGLX()Z")]
     async fn test_init_glx() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_glx(thread, Parameters::default()).await;
+        let result = init_glx(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsEnvironment",
+            "initGLX",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsEnvironment.initXRender(ZZ)Z"
-    )]
     async fn test_init_x_render() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_x_render(thread, Parameters::default()).await;
+        let result = init_x_render(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsEnvironment",
+            "initXRender",
+            "(ZZ)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11GraphicsEnvironment.pRunningXinerama()Z"
-    )]
     async fn test_p_running_xinerama() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = p_running_xinerama(thread, Parameters::default()).await;
+        let result = p_running_xinerama(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11GraphicsEnvironment",
+            "pRunningXinerama",
+            "()Z",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -35,7 +35,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn dispose_xic(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.X11InputMethod.disposeXIC()V")
+    unsatisfied_link(CLASS_NAME, "disposeXIC", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -48,12 +48,12 @@ async fn is_composition_enabled_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.X11InputMethod.isCompositionEnabledNative()Z")
+    unsatisfied_link(CLASS_NAME, "isCompositionEnabledNative", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn reset_xic(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.awt.X11InputMethod.resetXIC()Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "resetXIC", "()Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
@@ -61,7 +61,7 @@ async fn set_composition_enabled_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.X11InputMethod.setCompositionEnabledNative(Z)Z")
+    unsatisfied_link(CLASS_NAME, "setCompositionEnabledNative", "(Z)Z")
 }
 
 #[async_recursion(?Send)]
@@ -69,7 +69,7 @@ async fn turnoff_status_window(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.awt.X11InputMethod.turnoffStatusWindow()V")
+    unsatisfied_link(CLASS_NAME, "turnoffStatusWindow", "()V")
 }
 
 #[cfg(test)]
@@ -77,10 +77,10 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.X11InputMethod.disposeXIC()V")]
     async fn test_dispose_xic() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = dispose_xic(thread, Parameters::default()).await;
+        let result = dispose_xic(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/awt/X11InputMethod", "disposeXIC", "()V");
     }
 
     #[tokio::test]
@@ -92,36 +92,50 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11InputMethod.isCompositionEnabledNative()Z"
-    )]
     async fn test_is_composition_enabled_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_composition_enabled_native(thread, Parameters::default()).await;
+        let result = is_composition_enabled_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11InputMethod",
+            "isCompositionEnabledNative",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11InputMethod.resetXIC()Ljava/lang/String;"
-    )]
     async fn test_reset_xic() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = reset_xic(thread, Parameters::default()).await;
+        let result = reset_xic(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11InputMethod",
+            "resetXIC",
+            "()Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.awt.X11InputMethod.setCompositionEnabledNative(Z)Z"
-    )]
     async fn test_set_composition_enabled_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_composition_enabled_native(thread, Parameters::default()).await;
+        let result = set_composition_enabled_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11InputMethod",
+            "setCompositionEnabledNative",
+            "(Z)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.awt.X11InputMethod.turnoffStatusWindow()V")]
     async fn test_turnoff_status_window() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = turnoff_status_window(thread, Parameters::default()).await;
+        let result = turnoff_status_window(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/awt/X11InputMethod",
+            "turnoffStatusWindow",
+            "()V",
+        );
     }
 }
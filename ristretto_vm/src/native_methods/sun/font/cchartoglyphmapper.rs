@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -21,7 +21,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn count_glyphs(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.CCharToGlyphMapper.countGlyphs(J)I")
+    unsatisfied_link(CLASS_NAME, "countGlyphs", "(J)I")
 }
 
 #[async_recursion(?Send)]
@@ -29,7 +29,7 @@ async fn native_chars_to_glyphs(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.CCharToGlyphMapper.nativeCharsToGlyphs(JI[C[I)V")
+    unsatisfied_link(CLASS_NAME, "nativeCharsToGlyphs", "(JI[C[I)V")
 }
 
 #[cfg(test)]
@@ -37,18 +37,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.CCharToGlyphMapper.countGlyphs(J)I")]
     async fn test_count_glyphs() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = count_glyphs(thread, Parameters::default()).await;
+        let result = count_glyphs(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/CCharToGlyphMapper",
+            "countGlyphs",
+            "(J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.CCharToGlyphMapper.nativeCharsToGlyphs(JI[C[I)V"
-    )]
     async fn test_native_chars_to_glyphs() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_chars_to_glyphs(thread, Parameters::default()).await;
+        let result = native_chars_to_glyphs(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/CCharToGlyphMapper",
+            "nativeCharsToGlyphs",
+            "(JI[C[I)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -54,7 +54,7 @@ async fn create_native_font(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.CFont.createNativeFont(Ljava/lang/String;I)J")
+    unsatisfied_link(CLASS_NAME, "createNativeFont", "(Ljava/lang/String;I)J")
 }
 
 #[async_recursion(?Send)]
@@ -62,7 +62,7 @@ async fn dispose_native_font(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.CFont.disposeNativeFont(J)V")
+    unsatisfied_link(CLASS_NAME, "disposeNativeFont", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -70,12 +70,12 @@ async fn get_cg_font_ptr_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.CFont.getCGFontPtrNative(J)J")
+    unsatisfied_link(CLASS_NAME, "getCGFontPtrNative", "(J)J")
 }
 
 #[async_recursion(?Send)]
 async fn get_cascade_list(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.CFont.getCascadeList(JLjava/util/ArrayList;)V")
+    unsatisfied_link(CLASS_NAME, "getCascadeList", "(JLjava/util/ArrayList;)V")
 }
 
 #[async_recursion(?Send)]
@@ -83,7 +83,7 @@ async fn get_layout_table_cache_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.CFont.getLayoutTableCacheNative(J)J")
+    unsatisfied_link(CLASS_NAME, "getLayoutTableCacheNative", "(J)J")
 }
 
 #[async_recursion(?Send)]
@@ -91,17 +91,17 @@ async fn get_table_bytes_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.CFont.getTableBytesNative(JI)[B")
+    unsatisfied_link(CLASS_NAME, "getTableBytesNative", "(JI)[B")
 }
 
 #[async_recursion(?Send)]
 async fn get_weight_native(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.CFont.getWeightNative(J)F")
+    unsatisfied_link(CLASS_NAME, "getWeightNative", "(J)F")
 }
 
 #[async_recursion(?Send)]
 async fn get_width_native(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.CFont.getWidthNative(J)F")
+    unsatisfied_link(CLASS_NAME, "getWidthNative", "(J)F")
 }
 
 #[cfg(test)]
@@ -109,62 +109,83 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.CFont.createNativeFont(Ljava/lang/String;I)J"
-    )]
     async fn test_create_native_font() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_native_font(thread, Parameters::default()).await;
+        let result = create_native_font(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/CFont",
+            "createNativeFont",
+            "(Ljava/lang/String;I)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.CFont.disposeNativeFont(J)V")]
     async fn test_dispose_native_font() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = dispose_native_font(thread, Parameters::default()).await;
+        let result = dispose_native_font(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/font/CFont", "disposeNativeFont", "(J)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.CFont.getCGFontPtrNative(J)J")]
     async fn test_get_cg_font_ptr_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_cg_font_ptr_native(thread, Parameters::default()).await;
+        let result = get_cg_font_ptr_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/CFont",
+            "getCGFontPtrNative",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.CFont.getCascadeList(JLjava/util/ArrayList;)V"
-    )]
     async fn test_get_cascade_list() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_cascade_list(thread, Parameters::default()).await;
+        let result = get_cascade_list(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/CFont",
+            "getCascadeList",
+            "(JLjava/util/ArrayList;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.CFont.getLayoutTableCacheNative(J)J")]
     async fn test_get_layout_table_cache_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_layout_table_cache_native(thread, Parameters::default()).await;
+        let result = get_layout_table_cache_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/CFont",
+            "getLayoutTableCacheNative",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.CFont.getTableBytesNative(JI)[B")]
     async fn test_get_table_bytes_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_table_bytes_native(thread, Parameters::default()).await;
+        let result = get_table_bytes_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/CFont",
+            "getTableBytesNative",
+            "(JI)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.CFont.getWeightNative(J)F")]
     async fn test_get_weight_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_weight_native(thread, Parameters::default()).await;
+        let result = get_weight_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/font/CFont", "getWeightNative", "(J)F");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.CFont.getWidthNative(J)F")]
     async fn test_get_width_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_width_native(thread, Parameters::default()).await;
+        let result = get_width_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/font/CFont", "getWidthNative", "(J)F");
     }
 }
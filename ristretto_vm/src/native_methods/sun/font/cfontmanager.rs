@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -24,12 +24,12 @@ async fn load_native_dir_fonts(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.CFontManager.loadNativeDirFonts(Ljava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "loadNativeDirFonts", "(Ljava/lang/String;)V")
 }
 
 #[async_recursion(?Send)]
 async fn load_native_fonts(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.CFontManager.loadNativeFonts()V")
+    unsatisfied_link(CLASS_NAME, "loadNativeFonts", "()V")
 }
 
 #[cfg(test)]
@@ -37,18 +37,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.CFontManager.loadNativeDirFonts(Ljava/lang/String;)V"
-    )]
     async fn test_load_native_dir_fonts() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = load_native_dir_fonts(thread, Parameters::default()).await;
+        let result = load_native_dir_fonts(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/CFontManager",
+            "loadNativeDirFonts",
+            "(Ljava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.CFontManager.loadNativeFonts()V")]
     async fn test_load_native_fonts() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = load_native_fonts(thread, Parameters::default()).await;
+        let result = load_native_fonts(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/CFontManager",
+            "loadNativeFonts",
+            "()V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -16,12 +16,12 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn init_ops(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.ColorGlyphSurfaceData.initOps()V")
+    unsatisfied_link(CLASS_NAME, "initOps", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn set_current_glyph(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.ColorGlyphSurfaceData.setCurrentGlyph(J)V")
+    unsatisfied_link(CLASS_NAME, "setCurrentGlyph", "(J)V")
 }
 
 #[cfg(test)]
@@ -29,18 +29,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.ColorGlyphSurfaceData.initOps()V")]
     async fn test_init_ops() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_ops(thread, Parameters::default()).await;
+        let result = init_ops(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/ColorGlyphSurfaceData",
+            "initOps",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.ColorGlyphSurfaceData.setCurrentGlyph(J)V"
-    )]
     async fn test_set_current_glyph() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_current_glyph(thread, Parameters::default()).await;
+        let result = set_current_glyph(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/ColorGlyphSurfaceData",
+            "setCurrentGlyph",
+            "(J)V",
+        );
     }
 }
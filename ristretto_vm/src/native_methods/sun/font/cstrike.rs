@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -59,7 +59,7 @@ async fn create_native_strike_ptr(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.CStrike.createNativeStrikePtr(J[D[DII)J")
+    unsatisfied_link(CLASS_NAME, "createNativeStrikePtr", "(J[D[DII)J")
 }
 
 #[async_recursion(?Send)]
@@ -67,12 +67,12 @@ async fn dispose_native_strike_ptr(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.CStrike.disposeNativeStrikePtr(J)V")
+    unsatisfied_link(CLASS_NAME, "disposeNativeStrikePtr", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn get_font_metrics(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.CStrike.getFontMetrics(J)Lsun/font/StrikeMetrics;")
+    unsatisfied_link(CLASS_NAME, "getFontMetrics", "(J)Lsun/font/StrikeMetrics;")
 }
 
 #[async_recursion(?Send)]
@@ -80,7 +80,7 @@ async fn get_glyph_image_ptrs_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.CStrike.getGlyphImagePtrsNative(J[J[II)V")
+    unsatisfied_link(CLASS_NAME, "getGlyphImagePtrsNative", "(J[J[II)V")
 }
 
 #[async_recursion(?Send)]
@@ -88,7 +88,7 @@ async fn get_native_glyph_advance(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.CStrike.getNativeGlyphAdvance(JI)F")
+    unsatisfied_link(CLASS_NAME, "getNativeGlyphAdvance", "(JI)F")
 }
 
 #[async_recursion(?Send)]
@@ -96,7 +96,11 @@ async fn get_native_glyph_image_bounds(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.CStrike.getNativeGlyphImageBounds(JILjava/awt/geom/Rectangle2D$Float;DD)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getNativeGlyphImageBounds",
+        "(JILjava/awt/geom/Rectangle2D$Float;DD)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -104,7 +108,11 @@ async fn get_native_glyph_outline(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.CStrike.getNativeGlyphOutline(JIDD)Ljava/awt/geom/GeneralPath;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getNativeGlyphOutline",
+        "(JIDD)Ljava/awt/geom/GeneralPath;",
+    )
 }
 
 #[cfg(test)]
@@ -112,61 +120,86 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.CStrike.createNativeStrikePtr(J[D[DII)J"
-    )]
     async fn test_create_native_strike_ptr() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_native_strike_ptr(thread, Parameters::default()).await;
+        let result = create_native_strike_ptr(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/CStrike",
+            "createNativeStrikePtr",
+            "(J[D[DII)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.CStrike.disposeNativeStrikePtr(J)V")]
     async fn test_dispose_native_strike_ptr() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = dispose_native_strike_ptr(thread, Parameters::default()).await;
+        let result = dispose_native_strike_ptr(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/CStrike",
+            "disposeNativeStrikePtr",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.CStrike.getFontMetrics(J)Lsun/font/StrikeMetrics;"
-    )]
     async fn test_get_font_metrics() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_font_metrics(thread, Parameters::default()).await;
+        let result = get_font_metrics(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/CStrike",
+            "getFontMetrics",
+            "(J)Lsun/font/StrikeMetrics;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.CStrike.getGlyphImagePtrsNative(J[J[II)V"
-    )]
     async fn test_get_glyph_image_ptrs_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_glyph_image_ptrs_native(thread, Parameters::default()).await;
+        let result = get_glyph_image_ptrs_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/CStrike",
+            "getGlyphImagePtrsNative",
+            "(J[J[II)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.CStrike.getNativeGlyphAdvance(JI)F")]
     async fn test_get_native_glyph_advance() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_native_glyph_advance(thread, Parameters::default()).await;
+        let result = get_native_glyph_advance(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/CStrike",
+            "getNativeGlyphAdvance",
+            "(JI)F",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.CStrike.getNativeGlyphImageBounds(JILjava/awt/geom/Rectangle2D$Float;DD)V"
-    )]
     async fn test_get_native_glyph_image_bounds() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_native_glyph_image_bounds(thread, Parameters::default()).await;
+        let result = get_native_glyph_image_bounds(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/CStrike",
+            "getNativeGlyphImageBounds",
+            "(JILjava/awt/geom/Rectangle2D$Float;DD)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.CStrike.getNativeGlyphOutline(JIDD)Ljava/awt/geom/GeneralPath;"
-    )]
     async fn test_get_native_glyph_outline() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_native_glyph_outline(thread, Parameters::default()).await;
+        let result = get_native_glyph_outline(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/CStrike",
+            "getNativeGlyphOutline",
+            "(JIDD)Ljava/awt/geom/GeneralPath;",
+        );
     }
 }
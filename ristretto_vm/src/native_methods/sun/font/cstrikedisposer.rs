@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -29,7 +29,7 @@ async fn free_native_scaler_context(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.CStrikeDisposer.freeNativeScalerContext(J)V")
+    unsatisfied_link(CLASS_NAME, "freeNativeScalerContext", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -37,7 +37,7 @@ async fn remove_glyph_info_from_cache(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.CStrikeDisposer.removeGlyphInfoFromCache(J)V")
+    unsatisfied_link(CLASS_NAME, "removeGlyphInfoFromCache", "(J)V")
 }
 
 #[cfg(test)]
@@ -45,20 +45,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.CStrikeDisposer.freeNativeScalerContext(J)V"
-    )]
     async fn test_free_native_scaler_context() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = free_native_scaler_context(thread, Parameters::default()).await;
+        let result = free_native_scaler_context(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/CStrikeDisposer",
+            "freeNativeScalerContext",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.CStrikeDisposer.removeGlyphInfoFromCache(J)V"
-    )]
     async fn test_remove_glyph_info_from_cache() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = remove_glyph_info_from_cache(thread, Parameters::default()).await;
+        let result = remove_glyph_info_from_cache(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/CStrikeDisposer",
+            "removeGlyphInfoFromCache",
+            "(J)V",
+        );
     }
 }
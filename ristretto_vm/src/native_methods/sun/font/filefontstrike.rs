@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -24,12 +24,16 @@ async fn get_glyph_image_from_windows(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.FileFontStrike._getGlyphImageFromWindows(Ljava/lang/String;IIIZI)J")
+    unsatisfied_link(
+        CLASS_NAME,
+        "_getGlyphImageFromWindows",
+        "(Ljava/lang/String;IIIZI)J",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn init_native(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.FileFontStrike.initNative()Z")
+    unsatisfied_link(CLASS_NAME, "initNative", "()Z")
 }
 
 #[cfg(test)]
@@ -37,18 +41,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.FileFontStrike._getGlyphImageFromWindows(Ljava/lang/String;IIIZI)J"
-    )]
     async fn test_get_glyph_image_from_windows() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_glyph_image_from_windows(thread, Parameters::default()).await;
+        let result = get_glyph_image_from_windows(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/FileFontStrike",
+            "_getGlyphImageFromWindows",
+            "(Ljava/lang/String;IIIZI)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.FileFontStrike.initNative()Z")]
     async fn test_init_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_native(thread, Parameters::default()).await;
+        let result = init_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/FileFontStrike",
+            "initNative",
+            "()Z",
+        );
     }
 }
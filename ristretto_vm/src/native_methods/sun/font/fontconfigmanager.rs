@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -27,7 +27,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn get_font_config(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.FontConfigManager.getFontConfig(Ljava/lang/String;Lsun/font/FontConfigManager$FontConfigInfo;[Lsun/font/FontConfigManager$FcCompFont;Z)V")
+    unsatisfied_link(CLASS_NAME, "getFontConfig", "(Ljava/lang/String;Lsun/font/FontConfigManager$FontConfigInfo;[Lsun/font/FontConfigManager$FcCompFont;Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -35,8 +35,10 @@ async fn get_font_config_aa_settings(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!(
-        "sun.font.FontConfigManager.getFontConfigAASettings(Ljava/lang/String;Ljava/lang/String;)I"
+    unsatisfied_link(
+        CLASS_NAME,
+        "getFontConfigAASettings",
+        "(Ljava/lang/String;Ljava/lang/String;)I",
     )
 }
 
@@ -45,7 +47,7 @@ async fn get_font_config_version(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.FontConfigManager.getFontConfigVersion()I")
+    unsatisfied_link(CLASS_NAME, "getFontConfigVersion", "()I")
 }
 
 #[cfg(test)]
@@ -53,29 +55,33 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.FontConfigManager.getFontConfig(Ljava/lang/String;Lsun/font/FontConfigManager$FontConfigInfo;[Lsun/font/FontConfigManager$FcCompFont;Z)V"
-    )]
     async fn test_get_font_config() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_font_config(thread, Parameters::default()).await;
+        let result = get_font_config(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/font/FontConfigManager", "getFontConfig", "(Ljava/lang/String;Lsun/font/FontConfigManager$FontConfigInfo;[Lsun/font/FontConfigManager$FcCompFont;Z)V");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.FontConfigManager.getFontConfigAASettings(Ljava/lang/String;Ljava/lang/String;)I"
-    )]
     async fn test_get_font_config_aa_settings() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_font_config_aa_settings(thread, Parameters::default()).await;
+        let result = get_font_config_aa_settings(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/FontConfigManager",
+            "getFontConfigAASettings",
+            "(Ljava/lang/String;Ljava/lang/String;)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.FontConfigManager.getFontConfigVersion()I"
-    )]
     async fn test_get_font_config_version() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_font_config_version(thread, Parameters::default()).await;
+        let result = get_font_config_version(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/FontConfigManager",
+            "getFontConfigVersion",
+            "()I",
+        );
     }
 }
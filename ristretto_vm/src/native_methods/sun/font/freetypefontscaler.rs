@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -117,7 +117,7 @@ async fn create_scaler_context_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.FreetypeFontScaler.createScalerContextNative(J[DIIFF)J")
+    unsatisfied_link(CLASS_NAME, "createScalerContextNative", "(J[DIIFF)J")
 }
 
 #[async_recursion(?Send)]
@@ -125,7 +125,7 @@ async fn dispose_native_scaler(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.FreetypeFontScaler.disposeNativeScaler(Lsun/font/Font2D;J)V")
+    unsatisfied_link(CLASS_NAME, "disposeNativeScaler", "(Lsun/font/Font2D;J)V")
 }
 
 #[async_recursion(?Send)]
@@ -133,7 +133,11 @@ async fn get_font_metrics_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.FreetypeFontScaler.getFontMetricsNative(Lsun/font/Font2D;JJ)Lsun/font/StrikeMetrics;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getFontMetricsNative",
+        "(Lsun/font/Font2D;JJ)Lsun/font/StrikeMetrics;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -141,7 +145,11 @@ async fn get_glyph_advance_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.FreetypeFontScaler.getGlyphAdvanceNative(Lsun/font/Font2D;JJI)F")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getGlyphAdvanceNative",
+        "(Lsun/font/Font2D;JJI)F",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -149,7 +157,7 @@ async fn get_glyph_code_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.FreetypeFontScaler.getGlyphCodeNative(Lsun/font/Font2D;JC)I")
+    unsatisfied_link(CLASS_NAME, "getGlyphCodeNative", "(Lsun/font/Font2D;JC)I")
 }
 
 #[async_recursion(?Send)]
@@ -157,7 +165,7 @@ async fn get_glyph_image_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.FreetypeFontScaler.getGlyphImageNative(Lsun/font/Font2D;JJI)J")
+    unsatisfied_link(CLASS_NAME, "getGlyphImageNative", "(Lsun/font/Font2D;JJI)J")
 }
 
 #[async_recursion(?Send)]
@@ -165,7 +173,11 @@ async fn get_glyph_metrics_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.FreetypeFontScaler.getGlyphMetricsNative(Lsun/font/Font2D;JJILjava/awt/geom/Point2D$Float;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getGlyphMetricsNative",
+        "(Lsun/font/Font2D;JJILjava/awt/geom/Point2D$Float;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -173,7 +185,11 @@ async fn get_glyph_outline_bounds_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.FreetypeFontScaler.getGlyphOutlineBoundsNative(Lsun/font/Font2D;JJI)Ljava/awt/geom/Rectangle2D$Float;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getGlyphOutlineBoundsNative",
+        "(Lsun/font/Font2D;JJI)Ljava/awt/geom/Rectangle2D$Float;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -181,7 +197,11 @@ async fn get_glyph_outline_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.FreetypeFontScaler.getGlyphOutlineNative(Lsun/font/Font2D;JJIFF)Ljava/awt/geom/GeneralPath;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getGlyphOutlineNative",
+        "(Lsun/font/Font2D;JJIFF)Ljava/awt/geom/GeneralPath;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -189,7 +209,11 @@ async fn get_glyph_point_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.FreetypeFontScaler.getGlyphPointNative(Lsun/font/Font2D;JJII)Ljava/awt/geom/Point2D$Float;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getGlyphPointNative",
+        "(Lsun/font/Font2D;JJII)Ljava/awt/geom/Point2D$Float;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -197,7 +221,11 @@ async fn get_glyph_vector_outline_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.FreetypeFontScaler.getGlyphVectorOutlineNative(Lsun/font/Font2D;JJ[IIFF)Ljava/awt/geom/GeneralPath;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getGlyphVectorOutlineNative",
+        "(Lsun/font/Font2D;JJ[IIFF)Ljava/awt/geom/GeneralPath;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -205,7 +233,7 @@ async fn get_layout_table_cache_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.FreetypeFontScaler.getLayoutTableCacheNative(J)J")
+    unsatisfied_link(CLASS_NAME, "getLayoutTableCacheNative", "(J)J")
 }
 
 #[async_recursion(?Send)]
@@ -213,7 +241,7 @@ async fn get_missing_glyph_code_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.FreetypeFontScaler.getMissingGlyphCodeNative(J)I")
+    unsatisfied_link(CLASS_NAME, "getMissingGlyphCodeNative", "(J)I")
 }
 
 #[async_recursion(?Send)]
@@ -221,7 +249,7 @@ async fn get_num_glyphs_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.FreetypeFontScaler.getNumGlyphsNative(J)I")
+    unsatisfied_link(CLASS_NAME, "getNumGlyphsNative", "(J)I")
 }
 
 #[async_recursion(?Send)]
@@ -229,7 +257,7 @@ async fn get_units_per_em_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.FreetypeFontScaler.getUnitsPerEMNative(J)J")
+    unsatisfied_link(CLASS_NAME, "getUnitsPerEMNative", "(J)J")
 }
 
 #[async_recursion(?Send)]
@@ -242,7 +270,7 @@ async fn init_native_scaler(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.FreetypeFontScaler.initNativeScaler(Lsun/font/Font2D;IIZI)J")
+    unsatisfied_link(CLASS_NAME, "initNativeScaler", "(Lsun/font/Font2D;IIZI)J")
 }
 
 #[cfg(test)]
@@ -250,138 +278,183 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.FreetypeFontScaler.createScalerContextNative(J[DIIFF)J"
-    )]
     async fn test_create_scaler_context_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_scaler_context_native(thread, Parameters::default()).await;
+        let result = create_scaler_context_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/FreetypeFontScaler",
+            "createScalerContextNative",
+            "(J[DIIFF)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.FreetypeFontScaler.disposeNativeScaler(Lsun/font/Font2D;J)V"
-    )]
     async fn test_dispose_native_scaler() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = dispose_native_scaler(thread, Parameters::default()).await;
+        let result = dispose_native_scaler(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/FreetypeFontScaler",
+            "disposeNativeScaler",
+            "(Lsun/font/Font2D;J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.FreetypeFontScaler.getFontMetricsNative(Lsun/font/Font2D;JJ)Lsun/font/StrikeMetrics;"
-    )]
     async fn test_get_font_metrics_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_font_metrics_native(thread, Parameters::default()).await;
+        let result = get_font_metrics_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/FreetypeFontScaler",
+            "getFontMetricsNative",
+            "(Lsun/font/Font2D;JJ)Lsun/font/StrikeMetrics;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.FreetypeFontScaler.getGlyphAdvanceNative(Lsun/font/Font2D;JJI)F"
-    )]
     async fn test_get_glyph_advance_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_glyph_advance_native(thread, Parameters::default()).await;
+        let result = get_glyph_advance_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/FreetypeFontScaler",
+            "getGlyphAdvanceNative",
+            "(Lsun/font/Font2D;JJI)F",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.FreetypeFontScaler.getGlyphCodeNative(Lsun/font/Font2D;JC)I"
-    )]
     async fn test_get_glyph_code_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_glyph_code_native(thread, Parameters::default()).await;
+        let result = get_glyph_code_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/FreetypeFontScaler",
+            "getGlyphCodeNative",
+            "(Lsun/font/Font2D;JC)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.FreetypeFontScaler.getGlyphImageNative(Lsun/font/Font2D;JJI)J"
-    )]
     async fn test_get_glyph_image_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_glyph_image_native(thread, Parameters::default()).await;
+        let result = get_glyph_image_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/FreetypeFontScaler",
+            "getGlyphImageNative",
+            "(Lsun/font/Font2D;JJI)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.FreetypeFontScaler.getGlyphMetricsNative(Lsun/font/Font2D;JJILjava/awt/geom/Point2D$Float;)V"
-    )]
     async fn test_get_glyph_metrics_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_glyph_metrics_native(thread, Parameters::default()).await;
+        let result = get_glyph_metrics_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/FreetypeFontScaler",
+            "getGlyphMetricsNative",
+            "(Lsun/font/Font2D;JJILjava/awt/geom/Point2D$Float;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.FreetypeFontScaler.getGlyphOutlineBoundsNative(Lsun/font/Font2D;JJI)Ljava/awt/geom/Rectangle2D$Float;"
-    )]
     async fn test_get_glyph_outline_bounds_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_glyph_outline_bounds_native(thread, Parameters::default()).await;
+        let result = get_glyph_outline_bounds_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/FreetypeFontScaler",
+            "getGlyphOutlineBoundsNative",
+            "(Lsun/font/Font2D;JJI)Ljava/awt/geom/Rectangle2D$Float;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.FreetypeFontScaler.getGlyphOutlineNative(Lsun/font/Font2D;JJIFF)Ljava/awt/geom/GeneralPath;"
-    )]
     async fn test_get_glyph_outline_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_glyph_outline_native(thread, Parameters::default()).await;
+        let result = get_glyph_outline_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/FreetypeFontScaler",
+            "getGlyphOutlineNative",
+            "(Lsun/font/Font2D;JJIFF)Ljava/awt/geom/GeneralPath;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.FreetypeFontScaler.getGlyphPointNative(Lsun/font/Font2D;JJII)Ljava/awt/geom/Point2D$Float;"
-    )]
     async fn test_get_glyph_point_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_glyph_point_native(thread, Parameters::default()).await;
+        let result = get_glyph_point_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/FreetypeFontScaler",
+            "getGlyphPointNative",
+            "(Lsun/font/Font2D;JJII)Ljava/awt/geom/Point2D$Float;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.FreetypeFontScaler.getGlyphVectorOutlineNative(Lsun/font/Font2D;JJ[IIFF)Ljava/awt/geom/GeneralPath;"
-    )]
     async fn test_get_glyph_vector_outline_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_glyph_vector_outline_native(thread, Parameters::default()).await;
+        let result = get_glyph_vector_outline_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/FreetypeFontScaler",
+            "getGlyphVectorOutlineNative",
+            "(Lsun/font/Font2D;JJ[IIFF)Ljava/awt/geom/GeneralPath;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.FreetypeFontScaler.getLayoutTableCacheNative(J)J"
-    )]
     async fn test_get_layout_table_cache_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_layout_table_cache_native(thread, Parameters::default()).await;
+        let result = get_layout_table_cache_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/FreetypeFontScaler",
+            "getLayoutTableCacheNative",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.FreetypeFontScaler.getMissingGlyphCodeNative(J)I"
-    )]
     async fn test_get_missing_glyph_code_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_missing_glyph_code_native(thread, Parameters::default()).await;
+        let result = get_missing_glyph_code_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/FreetypeFontScaler",
+            "getMissingGlyphCodeNative",
+            "(J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.FreetypeFontScaler.getNumGlyphsNative(J)I"
-    )]
     async fn test_get_num_glyphs_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_num_glyphs_native(thread, Parameters::default()).await;
+        let result = get_num_glyphs_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/FreetypeFontScaler",
+            "getNumGlyphsNative",
+            "(J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.FreetypeFontScaler.getUnitsPerEMNative(J)J"
-    )]
     async fn test_get_units_per_em_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_units_per_em_native(thread, Parameters::default()).await;
+        let result = get_units_per_em_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/FreetypeFontScaler",
+            "getUnitsPerEMNative",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
@@ -393,11 +466,14 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.FreetypeFontScaler.initNativeScaler(Lsun/font/Font2D;IIZI)J"
-    )]
     async fn test_init_native_scaler() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_native_scaler(thread, Parameters::default()).await;
+        let result = init_native_scaler(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/FreetypeFontScaler",
+            "initNativeScaler",
+            "(Lsun/font/Font2D;IIZI)J",
+        );
     }
 }
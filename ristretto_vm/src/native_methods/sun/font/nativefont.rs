@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -31,27 +31,27 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn count_glyphs(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.NativeFont.countGlyphs([BI)I")
+    unsatisfied_link(CLASS_NAME, "countGlyphs", "([BI)I")
 }
 
 #[async_recursion(?Send)]
 async fn font_exists(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.NativeFont.fontExists([B)Z")
+    unsatisfied_link(CLASS_NAME, "fontExists", "([B)Z")
 }
 
 #[async_recursion(?Send)]
 async fn get_font_metrics(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.NativeFont.getFontMetrics(J)Lsun/font/StrikeMetrics;")
+    unsatisfied_link(CLASS_NAME, "getFontMetrics", "(J)Lsun/font/StrikeMetrics;")
 }
 
 #[async_recursion(?Send)]
 async fn get_glyph_advance(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.NativeFont.getGlyphAdvance(JI)F")
+    unsatisfied_link(CLASS_NAME, "getGlyphAdvance", "(JI)F")
 }
 
 #[async_recursion(?Send)]
 async fn get_glyph_image(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.NativeFont.getGlyphImage(JI)J")
+    unsatisfied_link(CLASS_NAME, "getGlyphImage", "(JI)J")
 }
 
 #[async_recursion(?Send)]
@@ -59,12 +59,12 @@ async fn get_glyph_image_no_default(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.NativeFont.getGlyphImageNoDefault(JI)J")
+    unsatisfied_link(CLASS_NAME, "getGlyphImageNoDefault", "(JI)J")
 }
 
 #[async_recursion(?Send)]
 async fn have_bitmap_fonts(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.NativeFont.haveBitmapFonts([B)Z")
+    unsatisfied_link(CLASS_NAME, "haveBitmapFonts", "([B)Z")
 }
 
 #[cfg(test)]
@@ -72,55 +72,81 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.NativeFont.countGlyphs([BI)I")]
     async fn test_count_glyphs() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = count_glyphs(thread, Parameters::default()).await;
+        let result = count_glyphs(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/NativeFont",
+            "countGlyphs",
+            "([BI)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.NativeFont.fontExists([B)Z")]
     async fn test_font_exists() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = font_exists(thread, Parameters::default()).await;
+        let result = font_exists(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/font/NativeFont", "fontExists", "([B)Z");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.NativeFont.getFontMetrics(J)Lsun/font/StrikeMetrics;"
-    )]
     async fn test_get_font_metrics() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_font_metrics(thread, Parameters::default()).await;
+        let result = get_font_metrics(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/NativeFont",
+            "getFontMetrics",
+            "(J)Lsun/font/StrikeMetrics;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.NativeFont.getGlyphAdvance(JI)F")]
     async fn test_get_glyph_advance() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_glyph_advance(thread, Parameters::default()).await;
+        let result = get_glyph_advance(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/NativeFont",
+            "getGlyphAdvance",
+            "(JI)F",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.NativeFont.getGlyphImage(JI)J")]
     async fn test_get_glyph_image() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_glyph_image(thread, Parameters::default()).await;
+        let result = get_glyph_image(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/NativeFont",
+            "getGlyphImage",
+            "(JI)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.NativeFont.getGlyphImageNoDefault(JI)J"
-    )]
     async fn test_get_glyph_image_no_default() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_glyph_image_no_default(thread, Parameters::default()).await;
+        let result = get_glyph_image_no_default(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/NativeFont",
+            "getGlyphImageNoDefault",
+            "(JI)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.NativeFont.haveBitmapFonts([B)Z")]
     async fn test_have_bitmap_fonts() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = have_bitmap_fonts(thread, Parameters::default()).await;
+        let result = have_bitmap_fonts(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/NativeFont",
+            "haveBitmapFonts",
+            "([B)Z",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -30,7 +30,7 @@ async fn create_null_scaler_context(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.NativeStrike.createNullScalerContext()J")
+    unsatisfied_link(CLASS_NAME, "createNullScalerContext", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -38,12 +38,12 @@ async fn create_scaler_context(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.NativeStrike.createScalerContext([BID)J")
+    unsatisfied_link(CLASS_NAME, "createScalerContext", "([BID)J")
 }
 
 #[async_recursion(?Send)]
 async fn get_max_glyph(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.NativeStrike.getMaxGlyph(J)I")
+    unsatisfied_link(CLASS_NAME, "getMaxGlyph", "(J)I")
 }
 
 #[cfg(test)]
@@ -51,27 +51,38 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.NativeStrike.createNullScalerContext()J"
-    )]
     async fn test_create_null_scaler_context() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_null_scaler_context(thread, Parameters::default()).await;
+        let result = create_null_scaler_context(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/NativeStrike",
+            "createNullScalerContext",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.NativeStrike.createScalerContext([BID)J"
-    )]
     async fn test_create_scaler_context() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_scaler_context(thread, Parameters::default()).await;
+        let result = create_scaler_context(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/NativeStrike",
+            "createScalerContext",
+            "([BID)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.NativeStrike.getMaxGlyph(J)I")]
     async fn test_get_max_glyph() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_max_glyph(thread, Parameters::default()).await;
+        let result = get_max_glyph(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/NativeStrike",
+            "getMaxGlyph",
+            "(J)I",
+        );
     }
 }
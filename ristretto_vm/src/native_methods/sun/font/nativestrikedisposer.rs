@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,7 +23,7 @@ async fn free_native_scaler_context(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.NativeStrikeDisposer.freeNativeScalerContext(J)V")
+    unsatisfied_link(CLASS_NAME, "freeNativeScalerContext", "(J)V")
 }
 
 #[cfg(test)]
@@ -31,11 +31,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.NativeStrikeDisposer.freeNativeScalerContext(J)V"
-    )]
     async fn test_free_native_scaler_context() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = free_native_scaler_context(thread, Parameters::default()).await;
+        let result = free_native_scaler_context(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/NativeStrikeDisposer",
+            "freeNativeScalerContext",
+            "(J)V",
+        );
     }
 }
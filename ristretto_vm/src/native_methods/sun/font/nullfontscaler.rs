@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -21,7 +21,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn get_glyph_image(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.NullFontScaler.getGlyphImage(JI)J")
+    unsatisfied_link(CLASS_NAME, "getGlyphImage", "(JI)J")
 }
 
 #[async_recursion(?Send)]
@@ -29,7 +29,7 @@ async fn get_null_scaler_context(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.NullFontScaler.getNullScalerContext()J")
+    unsatisfied_link(CLASS_NAME, "getNullScalerContext", "()J")
 }
 
 #[cfg(test)]
@@ -37,18 +37,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.NullFontScaler.getGlyphImage(JI)J")]
     async fn test_get_glyph_image() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_glyph_image(thread, Parameters::default()).await;
+        let result = get_glyph_image(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/NullFontScaler",
+            "getGlyphImage",
+            "(JI)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.NullFontScaler.getNullScalerContext()J"
-    )]
     async fn test_get_null_scaler_context() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_null_scaler_context(thread, Parameters::default()).await;
+        let result = get_null_scaler_context(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/NullFontScaler",
+            "getNullScalerContext",
+            "()J",
+        );
     }
 }
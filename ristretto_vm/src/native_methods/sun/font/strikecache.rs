@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -24,22 +24,22 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn free_int_memory(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.StrikeCache.freeIntMemory([IJ)V")
+    unsatisfied_link(CLASS_NAME, "freeIntMemory", "([IJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn free_int_pointer(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.StrikeCache.freeIntPointer(I)V")
+    unsatisfied_link(CLASS_NAME, "freeIntPointer", "(I)V")
 }
 
 #[async_recursion(?Send)]
 async fn free_long_memory(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.StrikeCache.freeLongMemory([JJ)V")
+    unsatisfied_link(CLASS_NAME, "freeLongMemory", "([JJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn free_long_pointer(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.StrikeCache.freeLongPointer(J)V")
+    unsatisfied_link(CLASS_NAME, "freeLongPointer", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -47,7 +47,7 @@ async fn get_glyph_cache_description(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.font.StrikeCache.getGlyphCacheDescription([J)V")
+    unsatisfied_link(CLASS_NAME, "getGlyphCacheDescription", "([J)V")
 }
 
 #[cfg(test)]
@@ -55,39 +55,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.StrikeCache.freeIntMemory([IJ)V")]
     async fn test_free_int_memory() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = free_int_memory(thread, Parameters::default()).await;
+        let result = free_int_memory(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/StrikeCache",
+            "freeIntMemory",
+            "([IJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.StrikeCache.freeIntPointer(I)V")]
     async fn test_free_int_pointer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = free_int_pointer(thread, Parameters::default()).await;
+        let result = free_int_pointer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/StrikeCache",
+            "freeIntPointer",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.StrikeCache.freeLongMemory([JJ)V")]
     async fn test_free_long_memory() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = free_long_memory(thread, Parameters::default()).await;
+        let result = free_long_memory(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/StrikeCache",
+            "freeLongMemory",
+            "([JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.StrikeCache.freeLongPointer(J)V")]
     async fn test_free_long_pointer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = free_long_pointer(thread, Parameters::default()).await;
+        let result = free_long_pointer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/StrikeCache",
+            "freeLongPointer",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.StrikeCache.getGlyphCacheDescription([J)V"
-    )]
     async fn test_get_glyph_cache_description() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_glyph_cache_description(thread, Parameters::default()).await;
+        let result = get_glyph_cache_description(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/StrikeCache",
+            "getGlyphCacheDescription",
+            "([J)V",
+        );
     }
 }
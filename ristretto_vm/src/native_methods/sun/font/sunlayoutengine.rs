@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -27,27 +27,27 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn create_face(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.SunLayoutEngine.createFace(Lsun/font/Font2D;J)J")
+    unsatisfied_link(CLASS_NAME, "createFace", "(Lsun/font/Font2D;J)J")
 }
 
 #[async_recursion(?Send)]
 async fn dispose_face(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.SunLayoutEngine.disposeFace(J)V")
+    unsatisfied_link(CLASS_NAME, "disposeFace", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn init_gv_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.SunLayoutEngine.initGVIDs()V")
+    unsatisfied_link(CLASS_NAME, "initGVIDs", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn native_layout(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.SunLayoutEngine.nativeLayout(Lsun/font/Font2D;Lsun/font/FontStrike;[FII[CIIIIIIILjava/awt/geom/Point2D$Float;Lsun/font/GlyphLayout$GVData;JJ)V")
+    unsatisfied_link(CLASS_NAME, "nativeLayout", "(Lsun/font/Font2D;Lsun/font/FontStrike;[FII[CIIIIIIILjava/awt/geom/Point2D$Float;Lsun/font/GlyphLayout$GVData;JJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn shape(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.font.SunLayoutEngine.shape(Lsun/font/Font2D;Lsun/font/FontStrike;F[FJ[CLsun/font/GlyphLayout$GVData;IIIILjava/awt/geom/Point2D$Float;II)Z")
+    unsatisfied_link(CLASS_NAME, "shape", "(Lsun/font/Font2D;Lsun/font/FontStrike;F[FJ[CLsun/font/GlyphLayout$GVData;IIIILjava/awt/geom/Point2D$Float;II)Z")
 }
 
 #[cfg(test)]
@@ -55,43 +55,52 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.SunLayoutEngine.createFace(Lsun/font/Font2D;J)J"
-    )]
     async fn test_create_face() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_face(thread, Parameters::default()).await;
+        let result = create_face(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/SunLayoutEngine",
+            "createFace",
+            "(Lsun/font/Font2D;J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.SunLayoutEngine.disposeFace(J)V")]
     async fn test_dispose_face() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = dispose_face(thread, Parameters::default()).await;
+        let result = dispose_face(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/SunLayoutEngine",
+            "disposeFace",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.font.SunLayoutEngine.initGVIDs()V")]
     async fn test_init_gv_ids() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_gv_ids(thread, Parameters::default()).await;
+        let result = init_gv_ids(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/SunLayoutEngine",
+            "initGVIDs",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.SunLayoutEngine.nativeLayout(Lsun/font/Font2D;Lsun/font/FontStrike;[FII[CIIIIIIILjava/awt/geom/Point2D$Float;Lsun/font/GlyphLayout$GVData;JJ)V"
-    )]
     async fn test_native_layout() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_layout(thread, Parameters::default()).await;
+        let result = native_layout(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/font/SunLayoutEngine", "nativeLayout", "(Lsun/font/Font2D;Lsun/font/FontStrike;[FII[CIIIIIIILjava/awt/geom/Point2D$Float;Lsun/font/GlyphLayout$GVData;JJ)V");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.SunLayoutEngine.shape(Lsun/font/Font2D;Lsun/font/FontStrike;F[FJ[CLsun/font/GlyphLayout$GVData;IIIILjava/awt/geom/Point2D$Float;II)Z"
-    )]
     async fn test_shape() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = shape(thread, Parameters::default()).await;
+        let result = shape(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/font/SunLayoutEngine", "shape", "(Lsun/font/Font2D;Lsun/font/FontStrike;F[FJ[CLsun/font/GlyphLayout$GVData;IIIILjava/awt/geom/Point2D$Float;II)Z");
     }
 }
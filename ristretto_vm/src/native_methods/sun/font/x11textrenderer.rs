@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,8 +23,10 @@ async fn do_draw_glyph_list(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!(
-        "sun.font.X11TextRenderer.doDrawGlyphList(JJLsun/java2d/pipe/Region;Lsun/font/GlyphList;)V"
+    unsatisfied_link(
+        CLASS_NAME,
+        "doDrawGlyphList",
+        "(JJLsun/java2d/pipe/Region;Lsun/font/GlyphList;)V",
     )
 }
 
@@ -33,11 +35,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.font.X11TextRenderer.doDrawGlyphList(JJLsun/java2d/pipe/Region;Lsun/font/GlyphList;)V"
-    )]
     async fn test_do_draw_glyph_list() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = do_draw_glyph_list(thread, Parameters::default()).await;
+        let result = do_draw_glyph_list(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/font/X11TextRenderer",
+            "doDrawGlyphList",
+            "(JJLsun/java2d/pipe/Region;Lsun/font/GlyphList;)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_21};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11, JAVA_21};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -96,7 +96,11 @@ async fn append_to_class_loader_search_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.appendToClassLoaderSearch0(JLjava/lang/String;Z)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "appendToClassLoaderSearch0",
+        "(JLjava/lang/String;Z)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -104,7 +108,7 @@ async fn get_all_loaded_classes_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.getAllLoadedClasses0(J)[Ljava/lang/Class;")
+    unsatisfied_link(CLASS_NAME, "getAllLoadedClasses0", "(J)[Ljava/lang/Class;")
 }
 
 #[async_recursion(?Send)]
@@ -112,12 +116,16 @@ async fn get_initiated_classes_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.getInitiatedClasses0(JLjava/lang/ClassLoader;)[Ljava/lang/Class;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getInitiatedClasses0",
+        "(JLjava/lang/ClassLoader;)[Ljava/lang/Class;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_object_size_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.getObjectSize0(JLjava/lang/Object;)J")
+    unsatisfied_link(CLASS_NAME, "getObjectSize0", "(JLjava/lang/Object;)J")
 }
 
 #[async_recursion(?Send)]
@@ -125,7 +133,7 @@ async fn is_modifiable_class_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.isModifiableClass0(JLjava/lang/Class;)Z")
+    unsatisfied_link(CLASS_NAME, "isModifiableClass0", "(JLjava/lang/Class;)Z")
 }
 
 #[async_recursion(?Send)]
@@ -133,17 +141,17 @@ async fn is_retransform_classes_supported_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.isRetransformClassesSupported0(J)Z")
+    unsatisfied_link(CLASS_NAME, "isRetransformClassesSupported0", "(J)Z")
 }
 
 #[async_recursion(?Send)]
 async fn jar_file(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.jarFile(J)Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "jarFile", "(J)Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
 async fn load_agent_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.loadAgent0(Ljava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "loadAgent0", "(Ljava/lang/String;)V")
 }
 
 #[async_recursion(?Send)]
@@ -151,7 +159,11 @@ async fn redefine_classes_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.redefineClasses0(J[Ljava/lang/instrument/ClassDefinition;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "redefineClasses0",
+        "(J[Ljava/lang/instrument/ClassDefinition;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -159,7 +171,7 @@ async fn retransform_classes_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.retransformClasses0(J[Ljava/lang/Class;)V")
+    unsatisfied_link(CLASS_NAME, "retransformClasses0", "(J[Ljava/lang/Class;)V")
 }
 
 #[async_recursion(?Send)]
@@ -167,7 +179,7 @@ async fn set_has_retransformable_transformers(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.setHasRetransformableTransformers(JZ)V")
+    unsatisfied_link(CLASS_NAME, "setHasRetransformableTransformers", "(JZ)V")
 }
 
 #[async_recursion(?Send)]
@@ -175,7 +187,7 @@ async fn set_has_transformers(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.setHasTransformers(JZ)V")
+    unsatisfied_link(CLASS_NAME, "setHasTransformers", "(JZ)V")
 }
 
 #[async_recursion(?Send)]
@@ -183,7 +195,11 @@ async fn set_native_method_prefixes(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.setNativeMethodPrefixes(J[Ljava/lang/String;Z)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "setNativeMethodPrefixes",
+        "(J[Ljava/lang/String;Z)V",
+    )
 }
 
 #[cfg(test)]
@@ -191,119 +207,158 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.instrument.InstrumentationImpl.loadAgent0(Ljava/lang/String;)V"
-    )]
     async fn test_load_agent_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = load_agent_0(thread, Parameters::default()).await;
+        let result = load_agent_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/instrument/InstrumentationImpl",
+            "loadAgent0",
+            "(Ljava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.instrument.InstrumentationImpl.setHasTransformers(JZ)V"
-    )]
     async fn test_set_has_transformers() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_has_transformers(thread, Parameters::default()).await;
+        let result = set_has_transformers(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/instrument/InstrumentationImpl",
+            "setHasTransformers",
+            "(JZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.instrument.InstrumentationImpl.jarFile(J)Ljava/lang/String;"
-    )]
     async fn test_jar_file() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = jar_file(thread, Parameters::default()).await;
+        let result = jar_file(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/instrument/InstrumentationImpl",
+            "jarFile",
+            "(J)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.instrument.InstrumentationImpl.appendToClassLoaderSearch0(JLjava/lang/String;Z)V"
-    )]
     async fn test_append_to_class_loader_search_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = append_to_class_loader_search_0(thread, Parameters::default()).await;
+        let result = append_to_class_loader_search_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/instrument/InstrumentationImpl",
+            "appendToClassLoaderSearch0",
+            "(JLjava/lang/String;Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.instrument.InstrumentationImpl.getAllLoadedClasses0(J)[Ljava/lang/Class;"
-    )]
     async fn test_get_all_loaded_classes_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_all_loaded_classes_0(thread, Parameters::default()).await;
+        let result = get_all_loaded_classes_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/instrument/InstrumentationImpl",
+            "getAllLoadedClasses0",
+            "(J)[Ljava/lang/Class;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.instrument.InstrumentationImpl.getInitiatedClasses0(JLjava/lang/ClassLoader;)[Ljava/lang/Class;"
-    )]
     async fn test_get_initiated_classes_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_initiated_classes_0(thread, Parameters::default()).await;
+        let result = get_initiated_classes_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/instrument/InstrumentationImpl",
+            "getInitiatedClasses0",
+            "(JLjava/lang/ClassLoader;)[Ljava/lang/Class;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.instrument.InstrumentationImpl.getObjectSize0(JLjava/lang/Object;)J"
-    )]
     async fn test_get_object_size_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_object_size_0(thread, Parameters::default()).await;
+        let result = get_object_size_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/instrument/InstrumentationImpl",
+            "getObjectSize0",
+            "(JLjava/lang/Object;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.instrument.InstrumentationImpl.isModifiableClass0(JLjava/lang/Class;)Z"
-    )]
     async fn test_is_modifiable_class_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_modifiable_class_0(thread, Parameters::default()).await;
+        let result = is_modifiable_class_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/instrument/InstrumentationImpl",
+            "isModifiableClass0",
+            "(JLjava/lang/Class;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.instrument.InstrumentationImpl.isRetransformClassesSupported0(J)Z"
-    )]
     async fn test_is_retransform_classes_supported_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_retransform_classes_supported_0(thread, Parameters::default()).await;
+        let result = is_retransform_classes_supported_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/instrument/InstrumentationImpl",
+            "isRetransformClassesSupported0",
+            "(J)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.instrument.InstrumentationImpl.redefineClasses0(J[Ljava/lang/instrument/ClassDefinition;)V"
-    )]
     async fn test_redefine_classes_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = redefine_classes_0(thread, Parameters::default()).await;
+        let result = redefine_classes_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/instrument/InstrumentationImpl",
+            "redefineClasses0",
+            "(J[Ljava/lang/instrument/ClassDefinition;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.instrument.InstrumentationImpl.retransformClasses0(J[Ljava/lang/Class;)V"
-    )]
     async fn test_retransform_classes_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = retransform_classes_0(thread, Parameters::default()).await;
+        let result = retransform_classes_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/instrument/InstrumentationImpl",
+            "retransformClasses0",
+            "(J[Ljava/lang/Class;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.instrument.InstrumentationImpl.setHasRetransformableTransformers(JZ)V"
-    )]
     async fn test_set_has_retransformable_transformers() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_has_retransformable_transformers(thread, Parameters::default()).await;
+        let result = set_has_retransformable_transformers(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/instrument/InstrumentationImpl",
+            "setHasRetransformableTransformers",
+            "(JZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.instrument.InstrumentationImpl.setNativeMethodPrefixes(J[Ljava/lang/String;Z)V"
-    )]
     async fn test_set_native_method_prefixes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_native_method_prefixes(thread, Parameters::default()).await;
+        let result = set_native_method_prefixes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/instrument/InstrumentationImpl",
+            "setNativeMethodPrefixes",
+            "(J[Ljava/lang/String;Z)V",
+        );
     }
 }
@@ -1,4 +1,6 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17, JAVA_21, JAVA_8};
+use crate::native_methods::registry::{
+    unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17, JAVA_21, JAVA_8,
+};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -99,7 +101,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn color_convert(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.cmm.lcms.LCMS.colorConvert(Lsun/java2d/cmm/lcms/LCMSTransform;Lsun/java2d/cmm/lcms/LCMSImageLayout;Lsun/java2d/cmm/lcms/LCMSImageLayout;)V")
+    unsatisfied_link(CLASS_NAME, "colorConvert", "(Lsun/java2d/cmm/lcms/LCMSTransform;Lsun/java2d/cmm/lcms/LCMSImageLayout;Lsun/java2d/cmm/lcms/LCMSImageLayout;)V")
 }
 
 #[async_recursion(?Send)]
@@ -107,12 +109,16 @@ async fn create_native_transform(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.cmm.lcms.LCMS.createNativeTransform([JIIILjava/lang/Object;)J")
+    unsatisfied_link(
+        CLASS_NAME,
+        "createNativeTransform",
+        "([JIIILjava/lang/Object;)J",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn free_transform(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.cmm.lcms.LCMS.freeTransform(J)V")
+    unsatisfied_link(CLASS_NAME, "freeTransform", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -120,12 +126,16 @@ async fn get_profile_data_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.cmm.lcms.LCMS.getProfileDataNative(J[B)V")
+    unsatisfied_link(CLASS_NAME, "getProfileDataNative", "(J[B)V")
 }
 
 #[async_recursion(?Send)]
 async fn get_profile_id(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.cmm.lcms.LCMS.getProfileID(Ljava/awt/color/ICC_Profile;)Lsun/java2d/cmm/lcms/LCMSProfile;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getProfileID",
+        "(Ljava/awt/color/ICC_Profile;)Lsun/java2d/cmm/lcms/LCMSProfile;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -133,17 +143,21 @@ async fn get_profile_size_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.cmm.lcms.LCMS.getProfileSizeNative(J)I")
+    unsatisfied_link(CLASS_NAME, "getProfileSizeNative", "(J)I")
 }
 
 #[async_recursion(?Send)]
 async fn get_tag_native(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.cmm.lcms.LCMS.getTagNative(JI)[B")
+    unsatisfied_link(CLASS_NAME, "getTagNative", "(JI)[B")
 }
 
 #[async_recursion(?Send)]
 async fn init_lcms(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.cmm.lcms.LCMS.initLCMS(Ljava/lang/Class;Ljava/lang/Class;Ljava/lang/Class;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "initLCMS",
+        "(Ljava/lang/Class;Ljava/lang/Class;Ljava/lang/Class;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -151,7 +165,7 @@ async fn load_profile_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.cmm.lcms.LCMS.loadProfileNative([BLjava/lang/Object;)J")
+    unsatisfied_link(CLASS_NAME, "loadProfileNative", "([BLjava/lang/Object;)J")
 }
 
 #[async_recursion(?Send)]
@@ -159,7 +173,7 @@ async fn set_tag_data_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.cmm.lcms.LCMS.setTagDataNative(JI[B)V")
+    unsatisfied_link(CLASS_NAME, "setTagDataNative", "(JI[B)V")
 }
 
 #[cfg(test)]
@@ -167,88 +181,117 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.cmm.lcms.LCMS.colorConvert(Lsun/java2d/cmm/lcms/LCMSTransform;Lsun/java2d/cmm/lcms/LCMSImageLayout;Lsun/java2d/cmm/lcms/LCMSImageLayout;)V"
-    )]
     async fn test_color_convert() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = color_convert(thread, Parameters::default()).await;
+        let result = color_convert(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/java2d/cmm/lcms/LCMS", "colorConvert", "(Lsun/java2d/cmm/lcms/LCMSTransform;Lsun/java2d/cmm/lcms/LCMSImageLayout;Lsun/java2d/cmm/lcms/LCMSImageLayout;)V");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.cmm.lcms.LCMS.createNativeTransform([JIIILjava/lang/Object;)J"
-    )]
     async fn test_create_native_transform() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_native_transform(thread, Parameters::default()).await;
+        let result = create_native_transform(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/cmm/lcms/LCMS",
+            "createNativeTransform",
+            "([JIIILjava/lang/Object;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.cmm.lcms.LCMS.freeTransform(J)V")]
     async fn test_free_transform() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = free_transform(thread, Parameters::default()).await;
+        let result = free_transform(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/cmm/lcms/LCMS",
+            "freeTransform",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.cmm.lcms.LCMS.getProfileDataNative(J[B)V"
-    )]
     async fn test_get_profile_data_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_profile_data_native(thread, Parameters::default()).await;
+        let result = get_profile_data_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/cmm/lcms/LCMS",
+            "getProfileDataNative",
+            "(J[B)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.cmm.lcms.LCMS.getProfileID(Ljava/awt/color/ICC_Profile;)Lsun/java2d/cmm/lcms/LCMSProfile;"
-    )]
     async fn test_get_profile_id() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_profile_id(thread, Parameters::default()).await;
+        let result = get_profile_id(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/cmm/lcms/LCMS",
+            "getProfileID",
+            "(Ljava/awt/color/ICC_Profile;)Lsun/java2d/cmm/lcms/LCMSProfile;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.cmm.lcms.LCMS.getProfileSizeNative(J)I"
-    )]
     async fn test_get_profile_size_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_profile_size_native(thread, Parameters::default()).await;
+        let result = get_profile_size_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/cmm/lcms/LCMS",
+            "getProfileSizeNative",
+            "(J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.cmm.lcms.LCMS.getTagNative(JI)[B")]
     async fn test_get_tag_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_tag_native(thread, Parameters::default()).await;
+        let result = get_tag_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/cmm/lcms/LCMS",
+            "getTagNative",
+            "(JI)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.cmm.lcms.LCMS.initLCMS(Ljava/lang/Class;Ljava/lang/Class;Ljava/lang/Class;)V"
-    )]
     async fn test_init_lcms() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_lcms(thread, Parameters::default()).await;
+        let result = init_lcms(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/cmm/lcms/LCMS",
+            "initLCMS",
+            "(Ljava/lang/Class;Ljava/lang/Class;Ljava/lang/Class;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.cmm.lcms.LCMS.loadProfileNative([BLjava/lang/Object;)J"
-    )]
     async fn test_load_profile_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = load_profile_native(thread, Parameters::default()).await;
+        let result = load_profile_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/cmm/lcms/LCMS",
+            "loadProfileNative",
+            "([BLjava/lang/Object;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.cmm.lcms.LCMS.setTagDataNative(JI[B)V"
-    )]
     async fn test_set_tag_data_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_tag_data_native(thread, Parameters::default()).await;
+        let result = set_tag_data_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/cmm/lcms/LCMS",
+            "setTagDataNative",
+            "(JI[B)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -63,42 +63,54 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn do_arc(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.CRenderer.doArc(Lsun/java2d/SurfaceData;FFFFFFIZ)V");
+    unsatisfied_link(CLASS_NAME, "doArc", "(Lsun/java2d/SurfaceData;FFFFFFIZ)V")
 }
 
 #[async_recursion(?Send)]
 async fn do_image(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.CRenderer.doImage(Lsun/java2d/SurfaceData;Lsun/java2d/SurfaceData;ZZIIIIIIIIII)V");
+    unsatisfied_link(
+        CLASS_NAME,
+        "doImage",
+        "(Lsun/java2d/SurfaceData;Lsun/java2d/SurfaceData;ZZIIIIIIIIII)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn do_line(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.CRenderer.doLine(Lsun/java2d/SurfaceData;FFFF)V");
+    unsatisfied_link(CLASS_NAME, "doLine", "(Lsun/java2d/SurfaceData;FFFF)V")
 }
 
 #[async_recursion(?Send)]
 async fn do_oval(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.CRenderer.doOval(Lsun/java2d/SurfaceData;FFFFZ)V");
+    unsatisfied_link(CLASS_NAME, "doOval", "(Lsun/java2d/SurfaceData;FFFFZ)V")
 }
 
 #[async_recursion(?Send)]
 async fn do_poly(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.CRenderer.doPoly(Lsun/java2d/SurfaceData;[I[IIZZ)V");
+    unsatisfied_link(CLASS_NAME, "doPoly", "(Lsun/java2d/SurfaceData;[I[IIZZ)V")
 }
 
 #[async_recursion(?Send)]
 async fn do_rect(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.CRenderer.doRect(Lsun/java2d/SurfaceData;FFFFZ)V");
+    unsatisfied_link(CLASS_NAME, "doRect", "(Lsun/java2d/SurfaceData;FFFFZ)V")
 }
 
 #[async_recursion(?Send)]
 async fn do_round_rect(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.CRenderer.doRoundRect(Lsun/java2d/SurfaceData;FFFFFFZ)V");
+    unsatisfied_link(
+        CLASS_NAME,
+        "doRoundRect",
+        "(Lsun/java2d/SurfaceData;FFFFFFZ)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn do_shape(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.CRenderer.doShape(Lsun/java2d/SurfaceData;ILjava/nio/FloatBuffer;Ljava/nio/IntBuffer;IZZ)V");
+    unsatisfied_link(
+        CLASS_NAME,
+        "doShape",
+        "(Lsun/java2d/SurfaceData;ILjava/nio/FloatBuffer;Ljava/nio/IntBuffer;IZZ)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -111,75 +123,99 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.CRenderer.doArc(Lsun/java2d/SurfaceData;FFFFFFIZ)V"
-    )]
     async fn test_do_arc() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = do_arc(thread, Parameters::default()).await;
+        let result = do_arc(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/CRenderer",
+            "doArc",
+            "(Lsun/java2d/SurfaceData;FFFFFFIZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.CRenderer.doImage(Lsun/java2d/SurfaceData;Lsun/java2d/SurfaceData;ZZIIIIIIIIII)V"
-    )]
     async fn test_do_image() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = do_image(thread, Parameters::default()).await;
+        let result = do_image(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/CRenderer",
+            "doImage",
+            "(Lsun/java2d/SurfaceData;Lsun/java2d/SurfaceData;ZZIIIIIIIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.CRenderer.doLine(Lsun/java2d/SurfaceData;FFFF)V"
-    )]
     async fn test_do_line() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = do_line(thread, Parameters::default()).await;
+        let result = do_line(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/CRenderer",
+            "doLine",
+            "(Lsun/java2d/SurfaceData;FFFF)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.CRenderer.doOval(Lsun/java2d/SurfaceData;FFFFZ)V"
-    )]
     async fn test_do_oval() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = do_oval(thread, Parameters::default()).await;
+        let result = do_oval(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/CRenderer",
+            "doOval",
+            "(Lsun/java2d/SurfaceData;FFFFZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.CRenderer.doPoly(Lsun/java2d/SurfaceData;[I[IIZZ)V"
-    )]
     async fn test_do_poly() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = do_poly(thread, Parameters::default()).await;
+        let result = do_poly(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/CRenderer",
+            "doPoly",
+            "(Lsun/java2d/SurfaceData;[I[IIZZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.CRenderer.doRect(Lsun/java2d/SurfaceData;FFFFZ)V"
-    )]
     async fn test_do_rect() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = do_rect(thread, Parameters::default()).await;
+        let result = do_rect(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/CRenderer",
+            "doRect",
+            "(Lsun/java2d/SurfaceData;FFFFZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.CRenderer.doRoundRect(Lsun/java2d/SurfaceData;FFFFFFZ)V"
-    )]
     async fn test_do_round_rect() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = do_round_rect(thread, Parameters::default()).await;
+        let result = do_round_rect(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/CRenderer",
+            "doRoundRect",
+            "(Lsun/java2d/SurfaceData;FFFFFFZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.CRenderer.doShape(Lsun/java2d/SurfaceData;ILjava/nio/FloatBuffer;Ljava/nio/IntBuffer;IZZ)V"
-    )]
     async fn test_do_shape() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = do_shape(thread, Parameters::default()).await;
+        let result = do_shape(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/CRenderer",
+            "doShape",
+            "(Lsun/java2d/SurfaceData;ILjava/nio/FloatBuffer;Ljava/nio/IntBuffer;IZZ)V",
+        );
     }
 
     #[tokio::test]
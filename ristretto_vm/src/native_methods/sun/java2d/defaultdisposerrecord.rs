@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,7 +23,7 @@ async fn invoke_native_dispose(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.DefaultDisposerRecord.invokeNativeDispose(JJ)V");
+    unsatisfied_link(CLASS_NAME, "invokeNativeDispose", "(JJ)V")
 }
 
 #[cfg(test)]
@@ -31,11 +31,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.DefaultDisposerRecord.invokeNativeDispose(JJ)V"
-    )]
     async fn test_invoke_native_dispose() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = invoke_native_dispose(thread, Parameters::default()).await;
+        let result = invoke_native_dispose(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/DefaultDisposerRecord",
+            "invokeNativeDispose",
+            "(JJ)V",
+        );
     }
 }
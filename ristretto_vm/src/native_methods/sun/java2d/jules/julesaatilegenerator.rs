@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -24,7 +24,7 @@ async fn free_pixman_img_ptr(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.jules.JulesAATileGenerator.freePixmanImgPtr(J)V")
+    unsatisfied_link(CLASS_NAME, "freePixmanImgPtr", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -32,7 +32,7 @@ async fn rasterize_trapezoids_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.jules.JulesAATileGenerator.rasterizeTrapezoidsNative(J[I[II[BII)J")
+    unsatisfied_link(CLASS_NAME, "rasterizeTrapezoidsNative", "(J[I[II[BII)J")
 }
 
 #[cfg(test)]
@@ -40,20 +40,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.jules.JulesAATileGenerator.freePixmanImgPtr(J)V"
-    )]
     async fn test_free_pixman_img_ptr() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = free_pixman_img_ptr(thread, Parameters::default()).await;
+        let result = free_pixman_img_ptr(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/jules/JulesAATileGenerator",
+            "freePixmanImgPtr",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.jules.JulesAATileGenerator.rasterizeTrapezoidsNative(J[I[II[BII)J"
-    )]
     async fn test_rasterize_trapezoids_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = rasterize_trapezoids_native(thread, Parameters::default()).await;
+        let result = rasterize_trapezoids_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/jules/JulesAATileGenerator",
+            "rasterizeTrapezoidsNative",
+            "(J[I[II[BII)J",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -29,7 +29,7 @@ async fn tesselate_fill_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.jules.JulesPathBuf.tesselateFillNative([I[BII[IIIIIII)[I")
+    unsatisfied_link(CLASS_NAME, "tesselateFillNative", "([I[BII[IIIIIII)[I")
 }
 
 #[async_recursion(?Send)]
@@ -37,7 +37,11 @@ async fn tesselate_stroke_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.jules.JulesPathBuf.tesselateStrokeNative([I[BII[IIDIID[DIDDDDDDDIIII)[I")
+    unsatisfied_link(
+        CLASS_NAME,
+        "tesselateStrokeNative",
+        "([I[BII[IIDIID[DIDDDDDDDIIII)[I",
+    )
 }
 
 #[cfg(test)]
@@ -45,20 +49,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.jules.JulesPathBuf.tesselateFillNative([I[BII[IIIIIII)[I"
-    )]
     async fn test_tesselate_fill_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = tesselate_fill_native(thread, Parameters::default()).await;
+        let result = tesselate_fill_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/jules/JulesPathBuf",
+            "tesselateFillNative",
+            "([I[BII[IIIIIII)[I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.jules.JulesPathBuf.tesselateStrokeNative([I[BII[IIDIID[DIDDDDDDDIIII)[I"
-    )]
     async fn test_tesselate_stroke_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = tesselate_stroke_native(thread, Parameters::default()).await;
+        let result = tesselate_stroke_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/jules/JulesPathBuf",
+            "tesselateStrokeNative",
+            "([I[BII[IIDIID[DIDDDDDDDIIII)[I",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -32,7 +32,11 @@ async fn draw_glyph_list_lcd(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.loops.DrawGlyphListLCD.DrawGlyphListLCD(Lsun/java2d/SunGraphics2D;Lsun/java2d/SurfaceData;Lsun/font/GlyphList;)V");
+    unsatisfied_link(
+        CLASS_NAME,
+        "DrawGlyphListLCD",
+        "(Lsun/java2d/SunGraphics2D;Lsun/java2d/SurfaceData;Lsun/font/GlyphList;)V",
+    )
 }
 
 #[cfg(test)]
@@ -40,11 +44,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.loops.DrawGlyphListLCD.DrawGlyphListLCD(Lsun/java2d/SunGraphics2D;Lsun/java2d/SurfaceData;Lsun/font/GlyphList;)V"
-    )]
     async fn test_draw_glyph_list_lcd() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = draw_glyph_list_lcd(thread, Parameters::default()).await;
+        let result = draw_glyph_list_lcd(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/loops/DrawGlyphListLCD",
+            "DrawGlyphListLCD",
+            "(Lsun/java2d/SunGraphics2D;Lsun/java2d/SurfaceData;Lsun/font/GlyphList;)V",
+        );
     }
 }
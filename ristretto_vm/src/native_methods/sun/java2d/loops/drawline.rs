@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -20,7 +20,11 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn draw_line(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.loops.DrawLine.DrawLine(Lsun/java2d/SunGraphics2D;Lsun/java2d/SurfaceData;IIII)V");
+    unsatisfied_link(
+        CLASS_NAME,
+        "DrawLine",
+        "(Lsun/java2d/SunGraphics2D;Lsun/java2d/SurfaceData;IIII)V",
+    )
 }
 
 #[cfg(test)]
@@ -28,11 +32,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.loops.DrawLine.DrawLine(Lsun/java2d/SunGraphics2D;Lsun/java2d/SurfaceData;IIII)V"
-    )]
     async fn test_draw_line() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = draw_line(thread, Parameters::default()).await;
+        let result = draw_line(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/loops/DrawLine",
+            "DrawLine",
+            "(Lsun/java2d/SunGraphics2D;Lsun/java2d/SurfaceData;IIII)V",
+        );
     }
 }
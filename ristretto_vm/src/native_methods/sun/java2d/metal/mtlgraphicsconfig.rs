@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_21};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_21};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -44,7 +44,7 @@ async fn get_mtl_config_info(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLGraphicsConfig.getMTLConfigInfo(ILjava/lang/String;)J")
+    unsatisfied_link(CLASS_NAME, "getMTLConfigInfo", "(ILjava/lang/String;)J")
 }
 
 #[async_recursion(?Send)]
@@ -52,7 +52,7 @@ async fn is_metal_framework_available(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLGraphicsConfig.isMetalFrameworkAvailable()Z")
+    unsatisfied_link(CLASS_NAME, "isMetalFrameworkAvailable", "()Z")
 }
 
 #[async_recursion(?Send)]
@@ -60,7 +60,7 @@ async fn native_get_max_texture_size(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLGraphicsConfig.nativeGetMaxTextureSize()I")
+    unsatisfied_link(CLASS_NAME, "nativeGetMaxTextureSize", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -68,7 +68,7 @@ async fn try_load_metal_library(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLGraphicsConfig.tryLoadMetalLibrary(ILjava/lang/String;)Z")
+    unsatisfied_link(CLASS_NAME, "tryLoadMetalLibrary", "(ILjava/lang/String;)Z")
 }
 
 #[cfg(test)]
@@ -76,38 +76,50 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.metal.MTLGraphicsConfig.getMTLConfigInfo(ILjava/lang/String;)J"
-    )]
     async fn test_get_mtl_config_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_mtl_config_info(thread, Parameters::default()).await;
+        let result = get_mtl_config_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/metal/MTLGraphicsConfig",
+            "getMTLConfigInfo",
+            "(ILjava/lang/String;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.metal.MTLGraphicsConfig.isMetalFrameworkAvailable()Z"
-    )]
     async fn test_is_metal_framework_available() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_metal_framework_available(thread, Parameters::default()).await;
+        let result = is_metal_framework_available(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/metal/MTLGraphicsConfig",
+            "isMetalFrameworkAvailable",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.metal.MTLGraphicsConfig.nativeGetMaxTextureSize()I"
-    )]
     async fn test_native_get_max_texture_size() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_max_texture_size(thread, Parameters::default()).await;
+        let result = native_get_max_texture_size(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/metal/MTLGraphicsConfig",
+            "nativeGetMaxTextureSize",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.metal.MTLGraphicsConfig.tryLoadMetalLibrary(ILjava/lang/String;)Z"
-    )]
     async fn test_try_load_metal_library() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = try_load_metal_library(thread, Parameters::default()).await;
+        let result = try_load_metal_library(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/metal/MTLGraphicsConfig",
+            "tryLoadMetalLibrary",
+            "(ILjava/lang/String;)Z",
+        );
     }
 }
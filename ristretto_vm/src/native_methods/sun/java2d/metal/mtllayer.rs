@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_21};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_21};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -28,7 +28,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn blit_texture(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLLayer.blitTexture(J)V")
+    unsatisfied_link(CLASS_NAME, "blitTexture", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -36,27 +36,31 @@ async fn native_create_layer(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLLayer.nativeCreateLayer()J")
+    unsatisfied_link(CLASS_NAME, "nativeCreateLayer", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn native_set_insets(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLLayer.nativeSetInsets(JII)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetInsets", "(JII)V")
 }
 
 #[async_recursion(?Send)]
 async fn native_set_opaque(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLLayer.nativeSetOpaque(JZ)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetOpaque", "(JZ)V")
 }
 
 #[async_recursion(?Send)]
 async fn native_set_scale(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLLayer.nativeSetScale(JD)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetScale", "(JD)V")
 }
 
 #[async_recursion(?Send)]
 async fn validate(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLLayer.validate(JLsun/java2d/metal/MTLSurfaceData;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "validate",
+        "(JLsun/java2d/metal/MTLSurfaceData;)V",
+    )
 }
 
 #[cfg(test)]
@@ -64,52 +68,74 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.metal.MTLLayer.blitTexture(J)V")]
     async fn test_blit_texture() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = blit_texture(thread, Parameters::default()).await;
+        let result = blit_texture(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/metal/MTLLayer",
+            "blitTexture",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.metal.MTLLayer.nativeCreateLayer()J"
-    )]
     async fn test_native_create_layer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_create_layer(thread, Parameters::default()).await;
+        let result = native_create_layer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/metal/MTLLayer",
+            "nativeCreateLayer",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.metal.MTLLayer.nativeSetInsets(JII)V"
-    )]
     async fn test_native_set_insets() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_insets(thread, Parameters::default()).await;
+        let result = native_set_insets(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/metal/MTLLayer",
+            "nativeSetInsets",
+            "(JII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.metal.MTLLayer.nativeSetOpaque(JZ)V"
-    )]
     async fn test_native_set_opaque() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_opaque(thread, Parameters::default()).await;
+        let result = native_set_opaque(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/metal/MTLLayer",
+            "nativeSetOpaque",
+            "(JZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.metal.MTLLayer.nativeSetScale(JD)V")]
     async fn test_native_set_scale() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_scale(thread, Parameters::default()).await;
+        let result = native_set_scale(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/metal/MTLLayer",
+            "nativeSetScale",
+            "(JD)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.metal.MTLLayer.validate(JLsun/java2d/metal/MTLSurfaceData;)V"
-    )]
     async fn test_validate() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = validate(thread, Parameters::default()).await;
+        let result = validate(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/metal/MTLLayer",
+            "validate",
+            "(JLsun/java2d/metal/MTLSurfaceData;)V",
+        );
     }
 }
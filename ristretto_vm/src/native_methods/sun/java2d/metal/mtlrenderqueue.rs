@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -15,7 +15,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn flush_buffer(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLRenderQueue.flushBuffer(JI)V");
+    unsatisfied_link(CLASS_NAME, "flushBuffer", "(JI)V")
 }
 
 #[cfg(test)]
@@ -23,11 +23,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.metal.MTLRenderQueue.flushBuffer(JI)V"
-    )]
     async fn test_flush_buffer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = flush_buffer(thread, Parameters::default()).await;
+        let result = flush_buffer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/metal/MTLRenderQueue",
+            "flushBuffer",
+            "(JI)V",
+        );
     }
 }
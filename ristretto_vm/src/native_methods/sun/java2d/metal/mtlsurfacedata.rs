@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -35,7 +35,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn clear_window(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLSurfaceData.clearWindow()V");
+    unsatisfied_link(CLASS_NAME, "clearWindow", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -43,7 +43,7 @@ async fn get_mtl_texture_pointer(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLSurfaceData.getMTLTexturePointer(J)J");
+    unsatisfied_link(CLASS_NAME, "getMTLTexturePointer", "(J)J")
 }
 
 #[async_recursion(?Send)]
@@ -51,22 +51,26 @@ async fn init_flip_backbuffer(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLSurfaceData.initFlipBackbuffer(J)Z");
+    unsatisfied_link(CLASS_NAME, "initFlipBackbuffer", "(J)Z")
 }
 
 #[async_recursion(?Send)]
 async fn init_ops(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLSurfaceData.initOps(Lsun/java2d/metal/MTLGraphicsConfig;JJJIIZ)V");
+    unsatisfied_link(
+        CLASS_NAME,
+        "initOps",
+        "(Lsun/java2d/metal/MTLGraphicsConfig;JJJIIZ)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn init_r_texture(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLSurfaceData.initRTexture(JZII)Z");
+    unsatisfied_link(CLASS_NAME, "initRTexture", "(JZII)Z")
 }
 
 #[async_recursion(?Send)]
 async fn init_texture(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLSurfaceData.initTexture(JZII)Z");
+    unsatisfied_link(CLASS_NAME, "initTexture", "(JZII)Z")
 }
 
 #[cfg(test)]
@@ -74,46 +78,74 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "sun.java2d.metal.MTLSurfaceData.clearWindow()V")]
     async fn test_clear_window() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = clear_window(thread, Parameters::default()).await;
+        let result = clear_window(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/metal/MTLSurfaceData",
+            "clearWindow",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "sun.java2d.metal.MTLSurfaceData.getMTLTexturePointer(J)J")]
     async fn test_get_mtl_texture_pointer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_mtl_texture_pointer(thread, Parameters::default()).await;
+        let result = get_mtl_texture_pointer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/metal/MTLSurfaceData",
+            "getMTLTexturePointer",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "sun.java2d.metal.MTLSurfaceData.initFlipBackbuffer(J)Z")]
     async fn test_init_flip_backbuffer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_flip_backbuffer(thread, Parameters::default()).await;
+        let result = init_flip_backbuffer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/metal/MTLSurfaceData",
+            "initFlipBackbuffer",
+            "(J)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "sun.java2d.metal.MTLSurfaceData.initOps(Lsun/java2d/metal/MTLGraphicsConfig;JJJIIZ)V"
-    )]
     async fn test_init_ops() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_ops(thread, Parameters::default()).await;
+        let result = init_ops(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/metal/MTLSurfaceData",
+            "initOps",
+            "(Lsun/java2d/metal/MTLGraphicsConfig;JJJIIZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "sun.java2d.metal.MTLSurfaceData.initRTexture(JZII)Z")]
     async fn test_init_r_texture() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_r_texture(thread, Parameters::default()).await;
+        let result = init_r_texture(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/metal/MTLSurfaceData",
+            "initRTexture",
+            "(JZII)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "sun.java2d.metal.MTLSurfaceData.initTexture(JZII)Z")]
     async fn test_init_texture() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_texture(thread, Parameters::default()).await;
+        let result = init_texture(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/metal/MTLSurfaceData",
+            "initTexture",
+            "(JZII)Z",
+        );
     }
 }
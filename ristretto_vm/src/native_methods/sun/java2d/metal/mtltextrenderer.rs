@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -20,7 +20,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn draw_glyph_list(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLTextRenderer.drawGlyphList(IZZZIFF[J[F)V");
+    unsatisfied_link(CLASS_NAME, "drawGlyphList", "(IZZZIFF[J[F)V")
 }
 
 #[cfg(test)]
@@ -28,11 +28,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.metal.MTLTextRenderer.drawGlyphList(IZZZIFF[J[F)V"
-    )]
     async fn test_draw_glyph_list() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = draw_glyph_list(thread, Parameters::default()).await;
+        let result = draw_glyph_list(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/metal/MTLTextRenderer",
+            "drawGlyphList",
+            "(IZZZIFF[J[F)V",
+        );
     }
 }
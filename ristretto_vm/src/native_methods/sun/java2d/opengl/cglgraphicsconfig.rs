@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -43,7 +43,7 @@ async fn get_cgl_config_info_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.CGLGraphicsConfig.getCGLConfigInfo()J")
+    unsatisfied_link(CLASS_NAME, "getCGLConfigInfo", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -51,7 +51,7 @@ async fn get_cgl_config_info_1(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.CGLGraphicsConfig.getCGLConfigInfo(III)J")
+    unsatisfied_link(CLASS_NAME, "getCGLConfigInfo", "(III)J")
 }
 
 #[async_recursion(?Send)]
@@ -59,12 +59,12 @@ async fn get_ogl_capabilities(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.CGLGraphicsConfig.getOGLCapabilities(J)I")
+    unsatisfied_link(CLASS_NAME, "getOGLCapabilities", "(J)I")
 }
 
 #[async_recursion(?Send)]
 async fn init_cgl(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.CGLGraphicsConfig.initCGL()Z")
+    unsatisfied_link(CLASS_NAME, "initCGL", "()Z")
 }
 
 #[async_recursion(?Send)]
@@ -72,7 +72,7 @@ async fn native_get_max_texture_size(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.CGLGraphicsConfig.nativeGetMaxTextureSize()I")
+    unsatisfied_link(CLASS_NAME, "nativeGetMaxTextureSize", "()I")
 }
 
 #[cfg(test)]
@@ -80,47 +80,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.CGLGraphicsConfig.getCGLConfigInfo()J"
-    )]
     async fn test_get_cgl_config_info_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_cgl_config_info_0(thread, Parameters::default()).await;
+        let result = get_cgl_config_info_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/CGLGraphicsConfig",
+            "getCGLConfigInfo",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.CGLGraphicsConfig.getCGLConfigInfo(III)J"
-    )]
     async fn test_get_cgl_config_info_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_cgl_config_info_1(thread, Parameters::default()).await;
+        let result = get_cgl_config_info_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/CGLGraphicsConfig",
+            "getCGLConfigInfo",
+            "(III)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.CGLGraphicsConfig.getOGLCapabilities(J)I"
-    )]
     async fn test_get_ogl_capabilities() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_ogl_capabilities(thread, Parameters::default()).await;
+        let result = get_ogl_capabilities(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/CGLGraphicsConfig",
+            "getOGLCapabilities",
+            "(J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.CGLGraphicsConfig.initCGL()Z"
-    )]
     async fn test_init_cgl() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_cgl(thread, Parameters::default()).await;
+        let result = init_cgl(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/CGLGraphicsConfig",
+            "initCGL",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.CGLGraphicsConfig.nativeGetMaxTextureSize()I"
-    )]
     async fn test_native_get_max_texture_size() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_max_texture_size(thread, Parameters::default()).await;
+        let result = native_get_max_texture_size(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/CGLGraphicsConfig",
+            "nativeGetMaxTextureSize",
+            "()I",
+        );
     }
 }
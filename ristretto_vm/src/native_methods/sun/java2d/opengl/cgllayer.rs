@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,7 +23,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn blit_texture(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.CGLLayer.blitTexture(J)V");
+    unsatisfied_link(CLASS_NAME, "blitTexture", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -31,17 +31,21 @@ async fn native_create_layer(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.CGLLayer.nativeCreateLayer()J");
+    unsatisfied_link(CLASS_NAME, "nativeCreateLayer", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn native_set_scale(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.CGLLayer.nativeSetScale(JD)V");
+    unsatisfied_link(CLASS_NAME, "nativeSetScale", "(JD)V")
 }
 
 #[async_recursion(?Send)]
 async fn validate(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.CGLLayer.validate(JLsun/java2d/opengl/CGLSurfaceData;)V");
+    unsatisfied_link(
+        CLASS_NAME,
+        "validate",
+        "(JLsun/java2d/opengl/CGLSurfaceData;)V",
+    )
 }
 
 #[cfg(test)]
@@ -49,36 +53,50 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.opengl.CGLLayer.blitTexture(J)V")]
     async fn test_blit_texture() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = blit_texture(thread, Parameters::default()).await;
+        let result = blit_texture(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/CGLLayer",
+            "blitTexture",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.CGLLayer.nativeCreateLayer()J"
-    )]
     async fn test_native_create_layer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_create_layer(thread, Parameters::default()).await;
+        let result = native_create_layer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/CGLLayer",
+            "nativeCreateLayer",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.CGLLayer.nativeSetScale(JD)V"
-    )]
     async fn test_native_set_scale() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_scale(thread, Parameters::default()).await;
+        let result = native_set_scale(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/CGLLayer",
+            "nativeSetScale",
+            "(JD)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.CGLLayer.validate(JLsun/java2d/opengl/CGLSurfaceData;)V"
-    )]
     async fn test_validate() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = validate(thread, Parameters::default()).await;
+        let result = validate(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/CGLLayer",
+            "validate",
+            "(JLsun/java2d/opengl/CGLSurfaceData;)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -38,7 +38,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn clear_window(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.CGLSurfaceData.clearWindow()");
+    unsatisfied_link(CLASS_NAME, "clearWindow", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -46,7 +46,11 @@ async fn create_cgl_context_on_surface(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.CGLSurfaceData.createCGLContextOnSurface(Lsun/java2d/opengl/CGLSurfaceData;J)J");
+    unsatisfied_link(
+        CLASS_NAME,
+        "createCGLContextOnSurface",
+        "(Lsun/java2d/opengl/CGLSurfaceData;J)J",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -54,12 +58,16 @@ async fn destroy_cgl_context(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.CGLSurfaceData.destroyCGLContext(J)V");
+    unsatisfied_link(CLASS_NAME, "destroyCGLContext", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn init_ops(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.CGLSurfaceData.initOps(Lsun/java2d/opengl/OGLGraphicsConfig;JJJIIZ)V");
+    unsatisfied_link(
+        CLASS_NAME,
+        "initOps",
+        "(Lsun/java2d/opengl/OGLGraphicsConfig;JJJIIZ)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -67,12 +75,16 @@ async fn make_cgl_context_current_on_surface(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.CGLSurfaceData.makeCGLContextCurrentOnSurface(Lsun/java2d/opengl/CGLSurfaceData;J)Z");
+    unsatisfied_link(
+        CLASS_NAME,
+        "makeCGLContextCurrentOnSurface",
+        "(Lsun/java2d/opengl/CGLSurfaceData;J)Z",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn validate(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.CGLSurfaceData.validate(IIIIZ)V");
+    unsatisfied_link(CLASS_NAME, "validate", "(IIIIZ)V")
 }
 
 #[cfg(test)]
@@ -80,56 +92,74 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.CGLSurfaceData.clearWindow()"
-    )]
     async fn test_clear_window() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = clear_window(thread, Parameters::default()).await;
+        let result = clear_window(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/CGLSurfaceData",
+            "clearWindow",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.CGLSurfaceData.createCGLContextOnSurface(Lsun/java2d/opengl/CGLSurfaceData;J)J"
-    )]
     async fn test_create_cgl_context_on_surface() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_cgl_context_on_surface(thread, Parameters::default()).await;
+        let result = create_cgl_context_on_surface(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/CGLSurfaceData",
+            "createCGLContextOnSurface",
+            "(Lsun/java2d/opengl/CGLSurfaceData;J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.CGLSurfaceData.destroyCGLContext(J)V"
-    )]
     async fn test_destroy_cgl_context() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = destroy_cgl_context(thread, Parameters::default()).await;
+        let result = destroy_cgl_context(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/CGLSurfaceData",
+            "destroyCGLContext",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.CGLSurfaceData.initOps(Lsun/java2d/opengl/OGLGraphicsConfig;JJJIIZ)V"
-    )]
     async fn test_init_ops() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_ops(thread, Parameters::default()).await;
+        let result = init_ops(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/CGLSurfaceData",
+            "initOps",
+            "(Lsun/java2d/opengl/OGLGraphicsConfig;JJJIIZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.CGLSurfaceData.makeCGLContextCurrentOnSurface(Lsun/java2d/opengl/CGLSurfaceData;J)Z"
-    )]
     async fn test_make_cgl_context_current_on_surface() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = make_cgl_context_current_on_surface(thread, Parameters::default()).await;
+        let result = make_cgl_context_current_on_surface(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/CGLSurfaceData",
+            "makeCGLContextCurrentOnSurface",
+            "(Lsun/java2d/opengl/CGLSurfaceData;J)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.CGLSurfaceData.validate(IIIIZ)V"
-    )]
     async fn test_validate() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = validate(thread, Parameters::default()).await;
+        let result = validate(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/CGLSurfaceData",
+            "validate",
+            "(IIIIZ)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -25,7 +25,7 @@ async fn get_glx_config_info(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.GLXGraphicsConfig.getGLXConfigInfo(II)J")
+    unsatisfied_link(CLASS_NAME, "getGLXConfigInfo", "(II)J")
 }
 
 #[async_recursion(?Send)]
@@ -33,12 +33,12 @@ async fn get_ogl_capabilities(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.GLXGraphicsConfig.getOGLCapabilities(J)I")
+    unsatisfied_link(CLASS_NAME, "getOGLCapabilities", "(J)I")
 }
 
 #[async_recursion(?Send)]
 async fn init_config(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.GLXGraphicsConfig.initConfig(JJ)V")
+    unsatisfied_link(CLASS_NAME, "initConfig", "(JJ)V")
 }
 
 #[cfg(test)]
@@ -46,29 +46,38 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.GLXGraphicsConfig.getGLXConfigInfo(II)J"
-    )]
     async fn test_get_glx_config_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_glx_config_info(thread, Parameters::default()).await;
+        let result = get_glx_config_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/GLXGraphicsConfig",
+            "getGLXConfigInfo",
+            "(II)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.GLXGraphicsConfig.getOGLCapabilities(J)I"
-    )]
     async fn test_get_ogl_capabilities() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_ogl_capabilities(thread, Parameters::default()).await;
+        let result = get_ogl_capabilities(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/GLXGraphicsConfig",
+            "getOGLCapabilities",
+            "(J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.GLXGraphicsConfig.initConfig(JJ)V"
-    )]
     async fn test_init_config() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_config(thread, Parameters::default()).await;
+        let result = init_config(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/GLXGraphicsConfig",
+            "initConfig",
+            "(JJ)V",
+        );
     }
 }
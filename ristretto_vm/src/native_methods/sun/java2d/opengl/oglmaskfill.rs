@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -15,7 +15,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn mask_fill(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.OGLMaskFill.maskFill(IIIIIII[B)V");
+    unsatisfied_link(CLASS_NAME, "maskFill", "(IIIIIII[B)V")
 }
 
 #[cfg(test)]
@@ -23,11 +23,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.OGLMaskFill.maskFill(IIIIIII[B)V"
-    )]
     async fn test_mask_fill() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = mask_fill(thread, Parameters::default()).await;
+        let result = mask_fill(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/OGLMaskFill",
+            "maskFill",
+            "(IIIIIII[B)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -15,7 +15,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn draw_poly(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.OGLRenderer.drawPoly([I[IIZII)V");
+    unsatisfied_link(CLASS_NAME, "drawPoly", "([I[IIZII)V")
 }
 
 #[cfg(test)]
@@ -23,11 +23,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.OGLRenderer.drawPoly([I[IIZII)V"
-    )]
     async fn test_draw_poly() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = draw_poly(thread, Parameters::default()).await;
+        let result = draw_poly(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/OGLRenderer",
+            "drawPoly",
+            "([I[IIZII)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -24,7 +24,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn get_texture_id(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.OGLSurfaceData.getTextureID(J)I");
+    unsatisfied_link(CLASS_NAME, "getTextureID", "(J)I")
 }
 
 #[async_recursion(?Send)]
@@ -32,12 +32,12 @@ async fn get_texture_target(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.OGLSurfaceData.getTextureTarget(J)I");
+    unsatisfied_link(CLASS_NAME, "getTextureTarget", "(J)I")
 }
 
 #[async_recursion(?Send)]
 async fn init_fb_object(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.OGLSurfaceData.initFBObject(JZZZII)Z");
+    unsatisfied_link(CLASS_NAME, "initFBObject", "(JZZZII)Z")
 }
 
 #[async_recursion(?Send)]
@@ -45,12 +45,12 @@ async fn init_flip_backbuffer(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.OGLSurfaceData.initFlipBackbuffer(J)Z");
+    unsatisfied_link(CLASS_NAME, "initFlipBackbuffer", "(J)Z")
 }
 
 #[async_recursion(?Send)]
 async fn init_texture(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.OGLSurfaceData.initTexture(JZZZII)Z");
+    unsatisfied_link(CLASS_NAME, "initTexture", "(JZZZII)Z")
 }
 
 #[cfg(test)]
@@ -58,47 +58,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.OGLSurfaceData.getTextureID(J)I"
-    )]
     async fn test_get_texture_id() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_texture_id(thread, Parameters::default()).await;
+        let result = get_texture_id(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/OGLSurfaceData",
+            "getTextureID",
+            "(J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.OGLSurfaceData.getTextureTarget(J)I"
-    )]
     async fn test_get_texture_target() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_texture_target(thread, Parameters::default()).await;
+        let result = get_texture_target(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/OGLSurfaceData",
+            "getTextureTarget",
+            "(J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.OGLSurfaceData.initFBObject(JZZZII)Z"
-    )]
     async fn test_init_fb_object() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_fb_object(thread, Parameters::default()).await;
+        let result = init_fb_object(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/OGLSurfaceData",
+            "initFBObject",
+            "(JZZZII)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.OGLSurfaceData.initFlipBackbuffer(J)Z"
-    )]
     async fn test_init_flip_backbuffer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_flip_backbuffer(thread, Parameters::default()).await;
+        let result = init_flip_backbuffer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/OGLSurfaceData",
+            "initFlipBackbuffer",
+            "(J)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.opengl.OGLSurfaceData.initTexture(JZZZII)Z"
-    )]
     async fn test_init_texture() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_texture(thread, Parameters::default()).await;
+        let result = init_texture(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/opengl/OGLSurfaceData",
+            "initTexture",
+            "(JZZZII)Z",
+        );
     }
 }
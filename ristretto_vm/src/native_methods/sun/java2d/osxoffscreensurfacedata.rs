@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -50,12 +50,16 @@ async fn clear_surface_pixels(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.OSXOffScreenSurfaceData.clearSurfacePixels(IIZ)Z")
+    unsatisfied_link(CLASS_NAME, "clearSurfacePixels", "(II)Z")
 }
 
 #[async_recursion(?Send)]
 async fn get_surface_data(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.OSXOffScreenSurfaceData.getSurfaceData(Ljava/awt/image/BufferedImage;)Lsun/java2d/SurfaceData;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getSurfaceData",
+        "(Ljava/awt/image/BufferedImage;)Lsun/java2d/SurfaceData;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -63,7 +67,11 @@ async fn init_custom_raster(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.OSXOffScreenSurfaceData.initCustomRaster(Ljava/nio/IntBuffer;IILjava/nio/ByteBuffer;Ljava/lang/Object;Ljava/nio/ByteBuffer;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "initCustomRaster",
+        "(Ljava/nio/IntBuffer;IILjava/nio/ByteBuffer;Ljava/lang/Object;Ljava/nio/ByteBuffer;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -73,12 +81,16 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn init_raster(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.OSXOffScreenSurfaceData.initRaster(Ljava/lang/Object;IIIIILjava/awt/image/IndexColorModel;ILjava/nio/ByteBuffer;Ljava/lang/Object;Ljava/nio/ByteBuffer;)V")
+    unsatisfied_link(CLASS_NAME, "initRaster", "(Ljava/lang/Object;IIIIILjava/awt/image/IndexColorModel;ILjava/nio/ByteBuffer;Ljava/lang/Object;Ljava/nio/ByteBuffer;)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_surface_data(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.OSXOffScreenSurfaceData.setSurfaceData(Ljava/awt/image/BufferedImage;Lsun/java2d/SurfaceData;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "setSurfaceData",
+        "(Ljava/awt/image/BufferedImage;Lsun/java2d/SurfaceData;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -86,7 +98,7 @@ async fn sync_to_java_pixels(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.OSXOffScreenSurfaceData.syncToJavaPixels()V")
+    unsatisfied_link(CLASS_NAME, "syncToJavaPixels", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -94,7 +106,11 @@ async fn xor_surface_pixels(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.OSXOffScreenSurfaceData.xorSurfacePixels(Lsun/java2d/SurfaceData;IIIII)Z")
+    unsatisfied_link(
+        CLASS_NAME,
+        "xorSurfacePixels",
+        "(Lsun/java2d/SurfaceData;IIIII)Z",
+    )
 }
 
 #[cfg(test)]
@@ -102,30 +118,39 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.OSXOffScreenSurfaceData.clearSurfacePixels(IIZ)Z"
-    )]
     async fn test_clear_surface_pixels() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = clear_surface_pixels(thread, Parameters::default()).await;
+        let result = clear_surface_pixels(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/OSXOffScreenSurfaceData",
+            "clearSurfacePixels",
+            "(II)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.OSXOffScreenSurfaceData.getSurfaceData(Ljava/awt/image/BufferedImage;)Lsun/java2d/SurfaceData;"
-    )]
     async fn test_get_surface_data() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_surface_data(thread, Parameters::default()).await;
+        let result = get_surface_data(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/OSXOffScreenSurfaceData",
+            "getSurfaceData",
+            "(Ljava/awt/image/BufferedImage;)Lsun/java2d/SurfaceData;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.OSXOffScreenSurfaceData.initCustomRaster(Ljava/nio/IntBuffer;IILjava/nio/ByteBuffer;Ljava/lang/Object;Ljava/nio/ByteBuffer;)V"
-    )]
     async fn test_init_custom_raster() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_custom_raster(thread, Parameters::default()).await;
+        let result = init_custom_raster(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/OSXOffScreenSurfaceData",
+            "initCustomRaster",
+            "(Ljava/nio/IntBuffer;IILjava/nio/ByteBuffer;Ljava/lang/Object;Ljava/nio/ByteBuffer;)V",
+        );
     }
 
     #[tokio::test]
@@ -137,38 +162,45 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.OSXOffScreenSurfaceData.initRaster(Ljava/lang/Object;IIIIILjava/awt/image/IndexColorModel;ILjava/nio/ByteBuffer;Ljava/lang/Object;Ljava/nio/ByteBuffer;)V"
-    )]
     async fn test_init_raster() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_raster(thread, Parameters::default()).await;
+        let result = init_raster(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/java2d/OSXOffScreenSurfaceData", "initRaster", "(Ljava/lang/Object;IIIIILjava/awt/image/IndexColorModel;ILjava/nio/ByteBuffer;Ljava/lang/Object;Ljava/nio/ByteBuffer;)V");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.OSXOffScreenSurfaceData.setSurfaceData(Ljava/awt/image/BufferedImage;Lsun/java2d/SurfaceData;)V"
-    )]
     async fn test_set_surface_data() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_surface_data(thread, Parameters::default()).await;
+        let result = set_surface_data(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/OSXOffScreenSurfaceData",
+            "setSurfaceData",
+            "(Ljava/awt/image/BufferedImage;Lsun/java2d/SurfaceData;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.OSXOffScreenSurfaceData.syncToJavaPixels()V"
-    )]
     async fn test_sync_to_java_pixels() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = sync_to_java_pixels(thread, Parameters::default()).await;
+        let result = sync_to_java_pixels(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/OSXOffScreenSurfaceData",
+            "syncToJavaPixels",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.OSXOffScreenSurfaceData.xorSurfacePixels(Lsun/java2d/SurfaceData;IIIII)Z"
-    )]
     async fn test_xor_surface_pixels() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = xor_surface_pixels(thread, Parameters::default()).await;
+        let result = xor_surface_pixels(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/OSXOffScreenSurfaceData",
+            "xorSurfacePixels",
+            "(Lsun/java2d/SurfaceData;IIIII)Z",
+        );
     }
 }
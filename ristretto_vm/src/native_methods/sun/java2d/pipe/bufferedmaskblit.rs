@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -20,7 +20,11 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn enqueue_tile(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.BufferedMaskBlit.enqueueTile(JILsun/java2d/SurfaceData;JI[BIIIIIIIII)I");
+    unsatisfied_link(
+        CLASS_NAME,
+        "enqueueTile",
+        "(JILsun/java2d/SurfaceData;JI[BIIIIIIIII)I",
+    )
 }
 
 #[cfg(test)]
@@ -28,11 +32,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.pipe.BufferedMaskBlit.enqueueTile(JILsun/java2d/SurfaceData;JI[BIIIIIIIII)I"
-    )]
     async fn test_enqueue_tile() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = enqueue_tile(thread, Parameters::default()).await;
+        let result = enqueue_tile(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/BufferedMaskBlit",
+            "enqueueTile",
+            "(JILsun/java2d/SurfaceData;JI[BIIIIIIIII)I",
+        );
     }
 }
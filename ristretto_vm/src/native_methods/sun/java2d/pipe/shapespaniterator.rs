@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -43,27 +43,27 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn add_segment(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.ShapeSpanIterator.addSegment(I[F)V");
+    unsatisfied_link(CLASS_NAME, "addSegment", "(I[F)V")
 }
 
 #[async_recursion(?Send)]
 async fn append_poly(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.ShapeSpanIterator.appendPoly([I[IIII)V");
+    unsatisfied_link(CLASS_NAME, "appendPoly", "([I[IIII)V")
 }
 
 #[async_recursion(?Send)]
 async fn close_path(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.ShapeSpanIterator.closePath()V");
+    unsatisfied_link(CLASS_NAME, "closePath", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn curve_to(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.ShapeSpanIterator.curveTo(FFFFFF)V");
+    unsatisfied_link(CLASS_NAME, "curveTo", "(FFFFFF)V")
 }
 
 #[async_recursion(?Send)]
 async fn dispose(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.ShapeSpanIterator.dispose()V");
+    unsatisfied_link(CLASS_NAME, "dispose", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -71,7 +71,7 @@ async fn get_native_consumer(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.ShapeSpanIterator.getNativeConsumer()J");
+    unsatisfied_link(CLASS_NAME, "getNativeConsumer", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -79,12 +79,12 @@ async fn get_native_iterator(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.ShapeSpanIterator.getNativeIterator()J");
+    unsatisfied_link(CLASS_NAME, "getNativeIterator", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn get_path_box(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.ShapeSpanIterator.getPathBox([I)V");
+    unsatisfied_link(CLASS_NAME, "getPathBox", "([I)V")
 }
 
 #[async_recursion(?Send)]
@@ -97,37 +97,37 @@ async fn intersect_clip_box(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.ShapeSpanIterator.intersectClipBox(IIII)V");
+    unsatisfied_link(CLASS_NAME, "intersectClipBox", "(IIII)V")
 }
 
 #[async_recursion(?Send)]
 async fn line_to(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.ShapeSpanIterator.lineTo(FF)V");
+    unsatisfied_link(CLASS_NAME, "lineTo", "(FF)V")
 }
 
 #[async_recursion(?Send)]
 async fn move_to(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.ShapeSpanIterator.moveTo(FF)V");
+    unsatisfied_link(CLASS_NAME, "moveTo", "(FF)V")
 }
 
 #[async_recursion(?Send)]
 async fn next_span(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.ShapeSpanIterator.nextSpan([I)Z");
+    unsatisfied_link(CLASS_NAME, "nextSpan", "([I)Z")
 }
 
 #[async_recursion(?Send)]
 async fn path_done(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.ShapeSpanIterator.pathDone()V");
+    unsatisfied_link(CLASS_NAME, "pathDone", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn quad_to(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.ShapeSpanIterator.quadTo(FFFF)V");
+    unsatisfied_link(CLASS_NAME, "quadTo", "(FFFF)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_normalize(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.ShapeSpanIterator.setNormalize(Z)V");
+    unsatisfied_link(CLASS_NAME, "setNormalize", "(Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -135,17 +135,17 @@ async fn set_output_area_xyxy(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.ShapeSpanIterator.setOutputAreaXYXY(IIII)V");
+    unsatisfied_link(CLASS_NAME, "setOutputAreaXYXY", "(IIII)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_rule(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.ShapeSpanIterator.setRule(I)V");
+    unsatisfied_link(CLASS_NAME, "setRule", "(I)V")
 }
 
 #[async_recursion(?Send)]
 async fn skip_down_to(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.ShapeSpanIterator.skipDownTo(I)V");
+    unsatisfied_link(CLASS_NAME, "skipDownTo", "(I)V")
 }
 
 #[cfg(test)]
@@ -153,73 +153,99 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.pipe.ShapeSpanIterator.addSegment(I[F)V"
-    )]
     async fn test_add_segment() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = add_segment(thread, Parameters::default()).await;
+        let result = add_segment(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/ShapeSpanIterator",
+            "addSegment",
+            "(I[F)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.pipe.ShapeSpanIterator.appendPoly([I[IIII)V"
-    )]
     async fn test_append_poly() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = append_poly(thread, Parameters::default()).await;
+        let result = append_poly(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/ShapeSpanIterator",
+            "appendPoly",
+            "([I[IIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.pipe.ShapeSpanIterator.closePath()V"
-    )]
     async fn test_close_path() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = close_path(thread, Parameters::default()).await;
+        let result = close_path(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/ShapeSpanIterator",
+            "closePath",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.pipe.ShapeSpanIterator.curveTo(FFFFFF)V"
-    )]
     async fn test_curve_to() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = curve_to(thread, Parameters::default()).await;
+        let result = curve_to(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/ShapeSpanIterator",
+            "curveTo",
+            "(FFFFFF)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.pipe.ShapeSpanIterator.dispose()V")]
     async fn test_dispose() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = dispose(thread, Parameters::default()).await;
+        let result = dispose(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/ShapeSpanIterator",
+            "dispose",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.pipe.ShapeSpanIterator.getNativeConsumer()J"
-    )]
     async fn test_get_native_consumer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_native_consumer(thread, Parameters::default()).await;
+        let result = get_native_consumer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/ShapeSpanIterator",
+            "getNativeConsumer",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.pipe.ShapeSpanIterator.getNativeIterator()J"
-    )]
     async fn test_get_native_iterator() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_native_iterator(thread, Parameters::default()).await;
+        let result = get_native_iterator(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/ShapeSpanIterator",
+            "getNativeIterator",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.pipe.ShapeSpanIterator.getPathBox([I)V"
-    )]
     async fn test_get_path_box() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_path_box(thread, Parameters::default()).await;
+        let result = get_path_box(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/ShapeSpanIterator",
+            "getPathBox",
+            "([I)V",
+        );
     }
 
     #[tokio::test]
@@ -231,84 +257,122 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.pipe.ShapeSpanIterator.intersectClipBox(IIII)V"
-    )]
     async fn test_intersect_clip_box() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = intersect_clip_box(thread, Parameters::default()).await;
+        let result = intersect_clip_box(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/ShapeSpanIterator",
+            "intersectClipBox",
+            "(IIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.pipe.ShapeSpanIterator.lineTo(FF)V")]
     async fn test_line_to() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = line_to(thread, Parameters::default()).await;
+        let result = line_to(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/ShapeSpanIterator",
+            "lineTo",
+            "(FF)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.pipe.ShapeSpanIterator.moveTo(FF)V")]
     async fn test_move_to() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = move_to(thread, Parameters::default()).await;
+        let result = move_to(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/ShapeSpanIterator",
+            "moveTo",
+            "(FF)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.pipe.ShapeSpanIterator.nextSpan([I)Z"
-    )]
     async fn test_next_span() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = next_span(thread, Parameters::default()).await;
+        let result = next_span(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/ShapeSpanIterator",
+            "nextSpan",
+            "([I)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.pipe.ShapeSpanIterator.pathDone()V")]
     async fn test_path_done() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = path_done(thread, Parameters::default()).await;
+        let result = path_done(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/ShapeSpanIterator",
+            "pathDone",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.pipe.ShapeSpanIterator.quadTo(FFFF)V"
-    )]
     async fn test_quad_to() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = quad_to(thread, Parameters::default()).await;
+        let result = quad_to(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/ShapeSpanIterator",
+            "quadTo",
+            "(FFFF)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.pipe.ShapeSpanIterator.setNormalize(Z)V"
-    )]
     async fn test_set_normalize() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_normalize(thread, Parameters::default()).await;
+        let result = set_normalize(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/ShapeSpanIterator",
+            "setNormalize",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.pipe.ShapeSpanIterator.setOutputAreaXYXY(IIII)V"
-    )]
     async fn test_set_output_area_xyxy() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_output_area_xyxy(thread, Parameters::default()).await;
+        let result = set_output_area_xyxy(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/ShapeSpanIterator",
+            "setOutputAreaXYXY",
+            "(IIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.pipe.ShapeSpanIterator.setRule(I)V")]
     async fn test_set_rule() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_rule(thread, Parameters::default()).await;
+        let result = set_rule(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/ShapeSpanIterator",
+            "setRule",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.pipe.ShapeSpanIterator.skipDownTo(I)V"
-    )]
     async fn test_skip_down_to() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = skip_down_to(thread, Parameters::default()).await;
+        let result = skip_down_to(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/ShapeSpanIterator",
+            "skipDownTo",
+            "(I)V",
+        );
     }
 }
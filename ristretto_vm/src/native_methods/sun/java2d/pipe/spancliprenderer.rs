@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -32,12 +32,20 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn erase_tile(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.SpanClipRenderer.eraseTile(Lsun/java2d/pipe/RegionIterator;[BII[I)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "eraseTile",
+        "(Lsun/java2d/pipe/RegionIterator;[BII[I)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn fill_tile(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.pipe.SpanClipRenderer.fillTile(Lsun/java2d/pipe/RegionIterator;[BII[I)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "fillTile",
+        "(Lsun/java2d/pipe/RegionIterator;[BII[I)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -50,21 +58,27 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.pipe.SpanClipRenderer.eraseTile(Lsun/java2d/pipe/RegionIterator;[BII[I)V"
-    )]
     async fn test_erase_tile() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = erase_tile(thread, Parameters::default()).await;
+        let result = erase_tile(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/SpanClipRenderer",
+            "eraseTile",
+            "(Lsun/java2d/pipe/RegionIterator;[BII[I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.pipe.SpanClipRenderer.fillTile(Lsun/java2d/pipe/RegionIterator;[BII[I)V"
-    )]
     async fn test_fill_tile() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fill_tile(thread, Parameters::default()).await;
+        let result = fill_tile(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/pipe/SpanClipRenderer",
+            "fillTile",
+            "(Lsun/java2d/pipe/RegionIterator;[BII[I)V",
+        );
     }
 
     #[tokio::test]
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -26,7 +26,11 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn is_opaque_gray(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.SurfaceData.isOpaqueGray(Ljava/awt/image/IndexColorModel;)Z")
+    unsatisfied_link(
+        CLASS_NAME,
+        "isOpaqueGray",
+        "(Ljava/awt/image/IndexColorModel;)Z",
+    )
 }
 
 #[cfg(test)]
@@ -42,11 +46,14 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.SurfaceData.isOpaqueGray(Ljava/awt/image/IndexColorModel;)Z"
-    )]
     async fn test_is_opaque_gray() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_opaque_gray(thread, Parameters::default()).await;
+        let result = is_opaque_gray(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/SurfaceData",
+            "isOpaqueGray",
+            "(Ljava/awt/image/IndexColorModel;)Z",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -15,7 +15,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn native_blit_bg(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11PMBlitBgLoops.nativeBlitBg(JJJIIIIIII)V");
+    unsatisfied_link(CLASS_NAME, "nativeBlitBg", "(JJJIIIIIII)V")
 }
 
 #[cfg(test)]
@@ -23,11 +23,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.X11PMBlitBgLoops.nativeBlitBg(JJJIIIIIII)V"
-    )]
     async fn test_native_blit_bg() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_blit_bg(thread, Parameters::default()).await;
+        let result = native_blit_bg(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11PMBlitBgLoops",
+            "nativeBlitBg",
+            "(JJJIIIIIII)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -26,12 +26,20 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn native_blit(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11PMBlitLoops.nativeBlit(JJJLsun/java2d/pipe/Region;IIIIII)V");
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeBlit",
+        "(JJJLsun/java2d/pipe/Region;IIIIII)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn update_bitmask(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11PMBlitLoops.updateBitmask(Lsun/java2d/SurfaceData;Lsun/java2d/SurfaceData;Z)V");
+    unsatisfied_link(
+        CLASS_NAME,
+        "updateBitmask",
+        "(Lsun/java2d/SurfaceData;Lsun/java2d/SurfaceData;Z)V",
+    )
 }
 
 #[cfg(test)]
@@ -39,20 +47,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.X11PMBlitLoops.nativeBlit(JJJLsun/java2d/pipe/Region;IIIIII)V"
-    )]
     async fn test_native_blit() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_blit(thread, Parameters::default()).await;
+        let result = native_blit(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11PMBlitLoops",
+            "nativeBlit",
+            "(JJJLsun/java2d/pipe/Region;IIIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.X11PMBlitLoops.updateBitmask(Lsun/java2d/SurfaceData;Lsun/java2d/SurfaceData;Z)V"
-    )]
     async fn test_update_bitmask() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = update_bitmask(thread, Parameters::default()).await;
+        let result = update_bitmask(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11PMBlitLoops",
+            "updateBitmask",
+            "(Lsun/java2d/SurfaceData;Lsun/java2d/SurfaceData;Z)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -48,72 +48,80 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn x_do_path(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11Renderer.XDoPath(Lsun/java2d/SunGraphics2D;JJIILjava/awt/geom/Path2D$Float;Z)V");
+    unsatisfied_link(
+        CLASS_NAME,
+        "XDoPath",
+        "(Lsun/java2d/SunGraphics2D;JJIILjava/awt/geom/Path2D$Float;Z)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn x_draw_arc(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11Renderer.XDrawArc(JJIIIIII)V");
+    unsatisfied_link(CLASS_NAME, "XDrawArc", "(JJIIIIII)V")
 }
 
 #[async_recursion(?Send)]
 async fn x_draw_line(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11Renderer.XDrawLine(JJIIII)V");
+    unsatisfied_link(CLASS_NAME, "XDrawLine", "(JJIIII)V")
 }
 
 #[async_recursion(?Send)]
 async fn x_draw_oval(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11Renderer.XDrawOval(JJIIII)V");
+    unsatisfied_link(CLASS_NAME, "XDrawOval", "(JJIIII)V")
 }
 
 #[async_recursion(?Send)]
 async fn x_draw_poly(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11Renderer.XDrawPoly(JJII[I[IIZ)V");
+    unsatisfied_link(CLASS_NAME, "XDrawPoly", "(JJII[I[IIZ)V")
 }
 
 #[async_recursion(?Send)]
 async fn x_draw_rect(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11Renderer.XDrawRect(JJIIII)V");
+    unsatisfied_link(CLASS_NAME, "XDrawRect", "(JJIIII)V")
 }
 
 #[async_recursion(?Send)]
 async fn x_draw_round_rect(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11Renderer.XDrawRoundRect(JJIIIIII)V");
+    unsatisfied_link(CLASS_NAME, "XDrawRoundRect", "(JJIIIIII)V")
 }
 
 #[async_recursion(?Send)]
 async fn x_fill_arc(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11Renderer.XFillArc(JJIIIIII)V");
+    unsatisfied_link(CLASS_NAME, "XFillArc", "(JJIIIIII)V")
 }
 
 #[async_recursion(?Send)]
 async fn x_fill_oval(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11Renderer.XFillOval(JJIIII)V");
+    unsatisfied_link(CLASS_NAME, "XFillOval", "(JJIIII)V")
 }
 
 #[async_recursion(?Send)]
 async fn x_fill_poly(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11Renderer.XFillPoly(JJII[I[II)V");
+    unsatisfied_link(CLASS_NAME, "XFillPoly", "(JJII[I[II)V")
 }
 
 #[async_recursion(?Send)]
 async fn x_fill_rect(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11Renderer.XFillRect(JJIIII)V");
+    unsatisfied_link(CLASS_NAME, "XFillRect", "(JJIIII)V")
 }
 
 #[async_recursion(?Send)]
 async fn x_fill_round_rect(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11Renderer.XFillRoundRect(JJIIIIII)V");
+    unsatisfied_link(CLASS_NAME, "XFillRoundRect", "(JJIIIIII)V")
 }
 
 #[async_recursion(?Send)]
 async fn x_fill_spans(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11Renderer.XFillSpans(JJLsun/java2d/pipe/SpanIterator;JII)V");
+    unsatisfied_link(
+        CLASS_NAME,
+        "XFillSpans",
+        "(JJLsun/java2d/pipe/SpanIterator;JII)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn dev_copy_area(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11Renderer.devCopyArea(JJIIIIII)V");
+    unsatisfied_link(CLASS_NAME, "devCopyArea", "(JJIIIIII)V")
 }
 
 #[cfg(test)]
@@ -121,118 +129,170 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.X11Renderer.XDoPath(Lsun/java2d/SunGraphics2D;JJIILjava/awt/geom/Path2D$Float;Z)V"
-    )]
     async fn test_x_do_path() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_do_path(thread, Parameters::default()).await;
+        let result = x_do_path(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11Renderer",
+            "XDoPath",
+            "(Lsun/java2d/SunGraphics2D;JJIILjava/awt/geom/Path2D$Float;Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.X11Renderer.XDrawArc(JJIIIIII)V"
-    )]
     async fn test_x_draw_arc() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_draw_arc(thread, Parameters::default()).await;
+        let result = x_draw_arc(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11Renderer",
+            "XDrawArc",
+            "(JJIIIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.x11.X11Renderer.XDrawLine(JJIIII)V")]
     async fn test_x_draw_line() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_draw_line(thread, Parameters::default()).await;
+        let result = x_draw_line(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11Renderer",
+            "XDrawLine",
+            "(JJIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.x11.X11Renderer.XDrawOval(JJIIII)V")]
     async fn test_x_draw_oval() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_draw_oval(thread, Parameters::default()).await;
+        let result = x_draw_oval(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11Renderer",
+            "XDrawOval",
+            "(JJIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.X11Renderer.XDrawPoly(JJII[I[IIZ)V"
-    )]
     async fn test_x_draw_poly() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_draw_poly(thread, Parameters::default()).await;
+        let result = x_draw_poly(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11Renderer",
+            "XDrawPoly",
+            "(JJII[I[IIZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.x11.X11Renderer.XDrawRect(JJIIII)V")]
     async fn test_x_draw_rect() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_draw_rect(thread, Parameters::default()).await;
+        let result = x_draw_rect(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11Renderer",
+            "XDrawRect",
+            "(JJIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.X11Renderer.XDrawRoundRect(JJIIIIII)V"
-    )]
     async fn test_x_draw_round_rect() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_draw_round_rect(thread, Parameters::default()).await;
+        let result = x_draw_round_rect(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11Renderer",
+            "XDrawRoundRect",
+            "(JJIIIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.X11Renderer.XFillArc(JJIIIIII)V"
-    )]
     async fn test_x_fill_arc() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_fill_arc(thread, Parameters::default()).await;
+        let result = x_fill_arc(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11Renderer",
+            "XFillArc",
+            "(JJIIIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.x11.X11Renderer.XFillOval(JJIIII)V")]
     async fn test_x_fill_oval() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_fill_oval(thread, Parameters::default()).await;
+        let result = x_fill_oval(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11Renderer",
+            "XFillOval",
+            "(JJIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.X11Renderer.XFillPoly(JJII[I[II)V"
-    )]
     async fn test_x_fill_poly() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_fill_poly(thread, Parameters::default()).await;
+        let result = x_fill_poly(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11Renderer",
+            "XFillPoly",
+            "(JJII[I[II)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.x11.X11Renderer.XFillRect(JJIIII)V")]
     async fn test_x_fill_rect() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_fill_rect(thread, Parameters::default()).await;
+        let result = x_fill_rect(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11Renderer",
+            "XFillRect",
+            "(JJIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.X11Renderer.XFillRoundRect(JJIIIIII)V"
-    )]
     async fn test_x_fill_round_rect() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_fill_round_rect(thread, Parameters::default()).await;
+        let result = x_fill_round_rect(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11Renderer",
+            "XFillRoundRect",
+            "(JJIIIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.X11Renderer.XFillSpans(JJLsun/java2d/pipe/SpanIterator;JII)V"
-    )]
     async fn test_x_fill_spans() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_fill_spans(thread, Parameters::default()).await;
+        let result = x_fill_spans(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11Renderer",
+            "XFillSpans",
+            "(JJLsun/java2d/pipe/SpanIterator;JII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.X11Renderer.devCopyArea(JJIIIIII)V"
-    )]
     async fn test_dev_copy_area() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = dev_copy_area(thread, Parameters::default()).await;
+        let result = dev_copy_area(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11Renderer",
+            "devCopyArea",
+            "(JJIIIIII)V",
+        );
     }
 }
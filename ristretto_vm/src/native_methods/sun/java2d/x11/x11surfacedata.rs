@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -21,17 +21,17 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn x_set_copy_mode(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11SurfaceData.XSetCopyMode(J)V")
+    unsatisfied_link(CLASS_NAME, "XSetCopyMode", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn x_set_foreground(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11SurfaceData.XSetForeground(JI)V")
+    unsatisfied_link(CLASS_NAME, "XSetForeground", "(JI)V")
 }
 
 #[async_recursion(?Send)]
 async fn x_set_xor_mode(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11SurfaceData.XSetXorMode(J)V")
+    unsatisfied_link(CLASS_NAME, "XSetXorMode", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -41,12 +41,12 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn init_surface(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11SurfaceData.initSurface(IIIJ)V")
+    unsatisfied_link(CLASS_NAME, "initSurface", "(IIIJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn is_dga_available(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11SurfaceData.isDgaAvailable()Z")
+    unsatisfied_link(CLASS_NAME, "isDgaAvailable", "()Z")
 }
 
 #[async_recursion(?Send)]
@@ -54,7 +54,7 @@ async fn is_shm_pm_available(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11SurfaceData.isShmPMAvailable()Z")
+    unsatisfied_link(CLASS_NAME, "isShmPMAvailable", "()Z")
 }
 
 #[cfg(test)]
@@ -62,28 +62,39 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.X11SurfaceData.XSetCopyMode(J)V"
-    )]
     async fn test_x_set_copy_mode() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_set_copy_mode(thread, Parameters::default()).await;
+        let result = x_set_copy_mode(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11SurfaceData",
+            "XSetCopyMode",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.X11SurfaceData.XSetForeground(JI)V"
-    )]
     async fn test_x_set_foreground() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_set_foreground(thread, Parameters::default()).await;
+        let result = x_set_foreground(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11SurfaceData",
+            "XSetForeground",
+            "(JI)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.x11.X11SurfaceData.XSetXorMode(J)V")]
     async fn test_x_set_xor_mode() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_set_xor_mode(thread, Parameters::default()).await;
+        let result = x_set_xor_mode(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11SurfaceData",
+            "XSetXorMode",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
@@ -95,29 +106,38 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.X11SurfaceData.initSurface(IIIJ)V"
-    )]
     async fn test_init_surface() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_surface(thread, Parameters::default()).await;
+        let result = init_surface(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11SurfaceData",
+            "initSurface",
+            "(IIIJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.X11SurfaceData.isDgaAvailable()Z"
-    )]
     async fn test_is_dga_available() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_dga_available(thread, Parameters::default()).await;
+        let result = is_dga_available(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11SurfaceData",
+            "isDgaAvailable",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.X11SurfaceData.isShmPMAvailable()Z"
-    )]
     async fn test_is_shm_pm_available() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_shm_pm_available(thread, Parameters::default()).await;
+        let result = is_shm_pm_available(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/X11SurfaceData",
+            "isShmPMAvailable",
+            "()Z",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -42,17 +42,17 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn x_create_gc(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.XSurfaceData.XCreateGC(J)J");
+    unsatisfied_link(CLASS_NAME, "XCreateGC", "(J)J")
 }
 
 #[async_recursion(?Send)]
 async fn x_reset_clip(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.XSurfaceData.XResetClip(J)V");
+    unsatisfied_link(CLASS_NAME, "XResetClip", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn x_set_clip(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.XSurfaceData.XSetClip(JIIIILsun/java2d/pipe/Region;)V");
+    unsatisfied_link(CLASS_NAME, "XSetClip", "(JIIIILsun/java2d/pipe/Region;)V")
 }
 
 #[async_recursion(?Send)]
@@ -60,7 +60,7 @@ async fn x_set_graphics_exposures(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.XSurfaceData.XSetGraphicsExposures(JZ)V");
+    unsatisfied_link(CLASS_NAME, "XSetGraphicsExposures", "(JZ)V")
 }
 
 #[async_recursion(?Send)]
@@ -68,22 +68,26 @@ async fn flush_native_surface(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.XSurfaceData.flushNativeSurface()V");
+    unsatisfied_link(CLASS_NAME, "flushNativeSurface", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn init_ops(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.XSurfaceData.initOps(Lsun/awt/X11ComponentPeer;Lsun/awt/X11GraphicsConfig;I)V");
+    unsatisfied_link(
+        CLASS_NAME,
+        "initOps",
+        "(Lsun/awt/X11ComponentPeer;Lsun/awt/X11GraphicsConfig;I)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn is_drawable_valid(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.XSurfaceData.isDrawableValid()Z");
+    unsatisfied_link(CLASS_NAME, "isDrawableValid", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn set_invalid(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.XSurfaceData.setInvalid()V");
+    unsatisfied_link(CLASS_NAME, "setInvalid", "()V")
 }
 
 #[cfg(test)]
@@ -91,68 +95,98 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.x11.XSurfaceData.XCreateGC(J)J")]
     async fn test_x_create_gc() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_create_gc(thread, Parameters::default()).await;
+        let result = x_create_gc(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/XSurfaceData",
+            "XCreateGC",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.x11.XSurfaceData.XResetClip(J)V")]
     async fn test_x_reset_clip() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_reset_clip(thread, Parameters::default()).await;
+        let result = x_reset_clip(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/XSurfaceData",
+            "XResetClip",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.XSurfaceData.XSetClip(JIIIILsun/java2d/pipe/Region;)V"
-    )]
     async fn test_x_set_clip() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_set_clip(thread, Parameters::default()).await;
+        let result = x_set_clip(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/XSurfaceData",
+            "XSetClip",
+            "(JIIIILsun/java2d/pipe/Region;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.XSurfaceData.XSetGraphicsExposures(JZ)V"
-    )]
     async fn test_x_set_graphics_exposures() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_set_graphics_exposures(thread, Parameters::default()).await;
+        let result = x_set_graphics_exposures(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/XSurfaceData",
+            "XSetGraphicsExposures",
+            "(JZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.XSurfaceData.flushNativeSurface()V"
-    )]
     async fn test_flush_native_surface() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = flush_native_surface(thread, Parameters::default()).await;
+        let result = flush_native_surface(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/XSurfaceData",
+            "flushNativeSurface",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.XSurfaceData.initOps(Lsun/awt/X11ComponentPeer;Lsun/awt/X11GraphicsConfig;I)V"
-    )]
     async fn test_init_ops() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_ops(thread, Parameters::default()).await;
+        let result = init_ops(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/XSurfaceData",
+            "initOps",
+            "(Lsun/awt/X11ComponentPeer;Lsun/awt/X11GraphicsConfig;I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.x11.XSurfaceData.isDrawableValid()Z"
-    )]
     async fn test_is_drawable_valid() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_drawable_valid(thread, Parameters::default()).await;
+        let result = is_drawable_valid(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/XSurfaceData",
+            "isDrawableValid",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.x11.XSurfaceData.setInvalid()V")]
     async fn test_set_invalid() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_invalid(thread, Parameters::default()).await;
+        let result = set_invalid(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/x11/XSurfaceData",
+            "setInvalid",
+            "()V",
+        );
     }
 }
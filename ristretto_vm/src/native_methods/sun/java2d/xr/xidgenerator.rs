@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -15,7 +15,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn buffer_x_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XIDGenerator.bufferXIDs([II)V");
+    unsatisfied_link(CLASS_NAME, "bufferXIDs", "([II)V")
 }
 
 #[cfg(test)]
@@ -23,9 +23,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.xr.XIDGenerator.bufferXIDs([II)V")]
     async fn test_buffer_x_ids() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = buffer_x_ids(thread, Parameters::default()).await;
+        let result = buffer_x_ids(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XIDGenerator",
+            "bufferXIDs",
+            "([II)V",
+        );
     }
 }
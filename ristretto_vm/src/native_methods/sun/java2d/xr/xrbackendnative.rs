@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -126,7 +126,7 @@ async fn gc_rectangles_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.GCRectanglesNative(IJ[II)V");
+    unsatisfied_link(CLASS_NAME, "GCRectanglesNative", "(IJ[II)V")
 }
 
 #[async_recursion(?Send)]
@@ -134,7 +134,7 @@ async fn xr_add_glyphs_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.XRAddGlyphsNative(I[JI[BI)V");
+    unsatisfied_link(CLASS_NAME, "XRAddGlyphsNative", "(I[JI[BI)V")
 }
 
 #[async_recursion(?Send)]
@@ -142,7 +142,11 @@ async fn xr_create_linear_gradient_paint_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.XRCreateLinearGradientPaintNative([F[SIIIIII)I");
+    unsatisfied_link(
+        CLASS_NAME,
+        "XRCreateLinearGradientPaintNative",
+        "([F[SIIIIII)I",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -150,7 +154,11 @@ async fn xr_create_radial_gradient_paint_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.XRCreateRadialGradientPaintNative([F[SIIIIII)I");
+    unsatisfied_link(
+        CLASS_NAME,
+        "XRCreateRadialGradientPaintNative",
+        "([F[SIIIIII)I",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -158,7 +166,7 @@ async fn xr_free_glyphs_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.XRFreeGlyphsNative(I[II)V");
+    unsatisfied_link(CLASS_NAME, "XRFreeGlyphsNative", "(I[II)V")
 }
 
 #[async_recursion(?Send)]
@@ -166,7 +174,11 @@ async fn xr_set_clip_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.XRSetClipNative(JIIIILsun/java2d/pipe/Region;Z)V");
+    unsatisfied_link(
+        CLASS_NAME,
+        "XRSetClipNative",
+        "(JIIIILsun/java2d/pipe/Region;Z)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -174,7 +186,7 @@ async fn xr_set_transform_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.XRSetTransformNative(IIIIIII)V");
+    unsatisfied_link(CLASS_NAME, "XRSetTransformNative", "(IIIIIII)V")
 }
 
 #[async_recursion(?Send)]
@@ -182,7 +194,7 @@ async fn x_render_composite_text_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.XRenderCompositeTextNative(IIIIIJ[I[III)V");
+    unsatisfied_link(CLASS_NAME, "XRenderCompositeTextNative", "(IIIIIJ[I[III)V")
 }
 
 #[async_recursion(?Send)]
@@ -190,7 +202,7 @@ async fn x_render_create_glyph_set_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.XRenderCreateGlyphSetNative(J)I");
+    unsatisfied_link(CLASS_NAME, "XRenderCreateGlyphSetNative", "(J)I")
 }
 
 #[async_recursion(?Send)]
@@ -198,17 +210,17 @@ async fn x_render_rectangles_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.XRenderRectanglesNative(IBSSSS[II)V");
+    unsatisfied_link(CLASS_NAME, "XRenderRectanglesNative", "(IBSSSS[II)V")
 }
 
 #[async_recursion(?Send)]
 async fn copy_area(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.copyArea(IIJIIIIII)V");
+    unsatisfied_link(CLASS_NAME, "copyArea", "(IIJIIIIII)V")
 }
 
 #[async_recursion(?Send)]
 async fn create_gc(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.createGC(I)J");
+    unsatisfied_link(CLASS_NAME, "createGC", "(I)J")
 }
 
 #[async_recursion(?Send)]
@@ -216,27 +228,27 @@ async fn create_picture_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.createPictureNative(IJ)I");
+    unsatisfied_link(CLASS_NAME, "createPictureNative", "(IJ)I")
 }
 
 #[async_recursion(?Send)]
 async fn create_pixmap(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.createPixmap(IIII)I");
+    unsatisfied_link(CLASS_NAME, "createPixmap", "(IIII)I")
 }
 
 #[async_recursion(?Send)]
 async fn free_gc(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.freeGC(J)V");
+    unsatisfied_link(CLASS_NAME, "freeGC", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn free_picture(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.freePicture(I)V");
+    unsatisfied_link(CLASS_NAME, "freePicture", "(I)V")
 }
 
 #[async_recursion(?Send)]
 async fn free_pixmap(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.freePixmap(I)V");
+    unsatisfied_link(CLASS_NAME, "freePixmap", "(I)V")
 }
 
 #[async_recursion(?Send)]
@@ -246,17 +258,17 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn pad_blit_native(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.padBlitNative(BIIIIIIIIIIIIIIIIIII)V");
+    unsatisfied_link(CLASS_NAME, "padBlitNative", "(BIIIIIIIIIIIIIIIIIII)V")
 }
 
 #[async_recursion(?Send)]
 async fn put_mask_native(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.putMaskNative(IJ[BIIIIIIIIFJ)V");
+    unsatisfied_link(CLASS_NAME, "putMaskNative", "(IJ[BIIIIIIIIFJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn render_composite(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.renderComposite(BIIIIIIIIIII)V");
+    unsatisfied_link(CLASS_NAME, "renderComposite", "(BIIIIIIIIIII)V")
 }
 
 #[async_recursion(?Send)]
@@ -264,32 +276,32 @@ async fn render_composite_trapezoids_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.renderCompositeTrapezoidsNative(BIJIII[I)V");
+    unsatisfied_link(CLASS_NAME, "renderCompositeTrapezoidsNative", "(BIJIII[I)V")
 }
 
 #[async_recursion(?Send)]
 async fn render_rectangle(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.renderRectangle(IBSSSSIIII)V");
+    unsatisfied_link(CLASS_NAME, "renderRectangle", "(IBSSSSIIII)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_filter(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.setFilter(II)V");
+    unsatisfied_link(CLASS_NAME, "setFilter", "(II)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_gc_exposures(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.setGCExposures(JZ)V");
+    unsatisfied_link(CLASS_NAME, "setGCExposures", "(JZ)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_gc_foreground(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.setGCForeground(JI)V");
+    unsatisfied_link(CLASS_NAME, "setGCForeground", "(JI)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_gc_mode(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.setGCMode(JZ)V");
+    unsatisfied_link(CLASS_NAME, "setGCMode", "(JZ)V")
 }
 
 #[async_recursion(?Send)]
@@ -297,7 +309,7 @@ async fn set_picture_repeat(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRBackendNative.setPictureRepeat(II)V");
+    unsatisfied_link(CLASS_NAME, "setPictureRepeat", "(II)V")
 }
 
 #[cfg(test)]
@@ -305,148 +317,207 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.GCRectanglesNative(IJ[II)V"
-    )]
     async fn test_gc_rectangles_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = gc_rectangles_native(thread, Parameters::default()).await;
+        let result = gc_rectangles_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "GCRectanglesNative",
+            "(IJ[II)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.XRAddGlyphsNative(I[JI[BI)V"
-    )]
     async fn test_xr_add_glyphs_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = xr_add_glyphs_native(thread, Parameters::default()).await;
+        let result = xr_add_glyphs_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "XRAddGlyphsNative",
+            "(I[JI[BI)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.XRCreateLinearGradientPaintNative([F[SIIIIII)I"
-    )]
     async fn test_xr_create_linear_gradient_paint_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = xr_create_linear_gradient_paint_native(thread, Parameters::default()).await;
+        let result = xr_create_linear_gradient_paint_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "XRCreateLinearGradientPaintNative",
+            "([F[SIIIIII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.XRCreateRadialGradientPaintNative([F[SIIIIII)I"
-    )]
     async fn test_xr_create_radial_gradient_paint_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = xr_create_radial_gradient_paint_native(thread, Parameters::default()).await;
+        let result = xr_create_radial_gradient_paint_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "XRCreateRadialGradientPaintNative",
+            "([F[SIIIIII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.XRFreeGlyphsNative(I[II)V"
-    )]
     async fn test_xr_free_glyphs_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = xr_free_glyphs_native(thread, Parameters::default()).await;
+        let result = xr_free_glyphs_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "XRFreeGlyphsNative",
+            "(I[II)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.XRSetClipNative(JIIIILsun/java2d/pipe/Region;Z)V"
-    )]
     async fn test_xr_set_clip_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = xr_set_clip_native(thread, Parameters::default()).await;
+        let result = xr_set_clip_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "XRSetClipNative",
+            "(JIIIILsun/java2d/pipe/Region;Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.XRSetTransformNative(IIIIIII)V"
-    )]
     async fn test_xr_set_transform_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = xr_set_transform_native(thread, Parameters::default()).await;
+        let result = xr_set_transform_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "XRSetTransformNative",
+            "(IIIIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.XRenderCompositeTextNative(IIIIIJ[I[III)V"
-    )]
     async fn test_x_render_composite_text_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_render_composite_text_native(thread, Parameters::default()).await;
+        let result = x_render_composite_text_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "XRenderCompositeTextNative",
+            "(IIIIIJ[I[III)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.XRenderCreateGlyphSetNative(J)I"
-    )]
     async fn test_x_render_create_glyph_set_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_render_create_glyph_set_native(thread, Parameters::default()).await;
+        let result = x_render_create_glyph_set_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "XRenderCreateGlyphSetNative",
+            "(J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.XRenderRectanglesNative(IBSSSS[II)V"
-    )]
     async fn test_x_render_rectangles_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = x_render_rectangles_native(thread, Parameters::default()).await;
+        let result = x_render_rectangles_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "XRenderRectanglesNative",
+            "(IBSSSS[II)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.copyArea(IIJIIIIII)V"
-    )]
     async fn test_copy_area() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = copy_area(thread, Parameters::default()).await;
+        let result = copy_area(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "copyArea",
+            "(IIJIIIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.xr.XRBackendNative.createGC(I)J")]
     async fn test_create_gc() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_gc(thread, Parameters::default()).await;
+        let result = create_gc(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "createGC",
+            "(I)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.createPictureNative(IJ)I"
-    )]
     async fn test_create_picture_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_picture_native(thread, Parameters::default()).await;
+        let result = create_picture_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "createPictureNative",
+            "(IJ)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.createPixmap(IIII)I"
-    )]
     async fn test_create_pixmap() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_pixmap(thread, Parameters::default()).await;
+        let result = create_pixmap(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "createPixmap",
+            "(IIII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.xr.XRBackendNative.freeGC(J)V")]
     async fn test_free_gc() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = free_gc(thread, Parameters::default()).await;
+        let result = free_gc(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "freeGC",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.xr.XRBackendNative.freePicture(I)V")]
     async fn test_free_picture() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = free_picture(thread, Parameters::default()).await;
+        let result = free_picture(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "freePicture",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.xr.XRBackendNative.freePixmap(I)V")]
     async fn test_free_pixmap() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = free_pixmap(thread, Parameters::default()).await;
+        let result = free_pixmap(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "freePixmap",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
@@ -458,88 +529,122 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.padBlitNative(BIIIIIIIIIIIIIIIIIII)V"
-    )]
     async fn test_pad_blit_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = pad_blit_native(thread, Parameters::default()).await;
+        let result = pad_blit_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "padBlitNative",
+            "(BIIIIIIIIIIIIIIIIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.putMaskNative(IJ[BIIIIIIIIFJ)V"
-    )]
     async fn test_put_mask_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = put_mask_native(thread, Parameters::default()).await;
+        let result = put_mask_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "putMaskNative",
+            "(IJ[BIIIIIIIIFJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.renderComposite(BIIIIIIIIIII)V"
-    )]
     async fn test_render_composite() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = render_composite(thread, Parameters::default()).await;
+        let result = render_composite(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "renderComposite",
+            "(BIIIIIIIIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.renderCompositeTrapezoidsNative(BIJIII[I)V"
-    )]
     async fn test_render_composite_trapezoids_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = render_composite_trapezoids_native(thread, Parameters::default()).await;
+        let result = render_composite_trapezoids_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "renderCompositeTrapezoidsNative",
+            "(BIJIII[I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.renderRectangle(IBSSSSIIII)V"
-    )]
     async fn test_render_rectangle() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = render_rectangle(thread, Parameters::default()).await;
+        let result = render_rectangle(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "renderRectangle",
+            "(IBSSSSIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.xr.XRBackendNative.setFilter(II)V")]
     async fn test_set_filter() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_filter(thread, Parameters::default()).await;
+        let result = set_filter(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "setFilter",
+            "(II)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.setGCExposures(JZ)V"
-    )]
     async fn test_set_gc_exposures() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_gc_exposures(thread, Parameters::default()).await;
+        let result = set_gc_exposures(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "setGCExposures",
+            "(JZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.setGCForeground(JI)V"
-    )]
     async fn test_set_gc_foreground() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_gc_foreground(thread, Parameters::default()).await;
+        let result = set_gc_foreground(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "setGCForeground",
+            "(JI)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.java2d.xr.XRBackendNative.setGCMode(JZ)V")]
     async fn test_set_gc_mode() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_gc_mode(thread, Parameters::default()).await;
+        let result = set_gc_mode(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "setGCMode",
+            "(JZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRBackendNative.setPictureRepeat(II)V"
-    )]
     async fn test_set_picture_repeat() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_picture_repeat(thread, Parameters::default()).await;
+        let result = set_picture_repeat(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRBackendNative",
+            "setPictureRepeat",
+            "(II)V",
+        );
     }
 }
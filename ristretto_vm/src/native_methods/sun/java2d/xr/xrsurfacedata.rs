@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -18,12 +18,12 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn xr_init_surface(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRSurfaceData.XRInitSurface(IIIJI)V");
+    unsatisfied_link(CLASS_NAME, "XRInitSurface", "(IIIJI)V")
 }
 
 #[async_recursion(?Send)]
 async fn free_xsdo_picture(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRSurfaceData.freeXSDOPicture(J)V");
+    unsatisfied_link(CLASS_NAME, "freeXSDOPicture", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -33,7 +33,7 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn init_xr_picture(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.java2d.xr.XRSurfaceData.initXRPicture(JI)V");
+    unsatisfied_link(CLASS_NAME, "initXRPicture", "(JI)V")
 }
 
 #[cfg(test)]
@@ -41,21 +41,27 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRSurfaceData.XRInitSurface(IIIJI)V"
-    )]
     async fn test_xr_init_surface() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = xr_init_surface(thread, Parameters::default()).await;
+        let result = xr_init_surface(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRSurfaceData",
+            "XRInitSurface",
+            "(IIIJI)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRSurfaceData.freeXSDOPicture(J)V"
-    )]
     async fn test_free_xsdo_picture() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = free_xsdo_picture(thread, Parameters::default()).await;
+        let result = free_xsdo_picture(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRSurfaceData",
+            "freeXSDOPicture",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
@@ -67,11 +73,14 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.java2d.xr.XRSurfaceData.initXRPicture(JI)V"
-    )]
     async fn test_init_xr_picture() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_xr_picture(thread, Parameters::default()).await;
+        let result = init_xr_picture(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/java2d/xr/XRSurfaceData",
+            "initXRPicture",
+            "(JI)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -21,12 +21,16 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn focus_changed(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CAccessibility.focusChanged()V")
+    unsatisfied_link(CLASS_NAME, "focusChanged", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn role_key(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CAccessibility.roleKey(Ljavax/accessibility/AccessibleRole;)Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "roleKey",
+        "(Ljavax/accessibility/AccessibleRole;)Ljava/lang/String;",
+    )
 }
 
 #[cfg(test)]
@@ -34,20 +38,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CAccessibility.focusChanged()V"
-    )]
     async fn test_focus_changed() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = focus_changed(thread, Parameters::default()).await;
+        let result = focus_changed(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CAccessibility",
+            "focusChanged",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CAccessibility.roleKey(Ljavax/accessibility/AccessibleRole;)Ljava/lang/String;"
-    )]
     async fn test_role_key() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = role_key(thread, Parameters::default()).await;
+        let result = role_key(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CAccessibility",
+            "roleKey",
+            "(Ljavax/accessibility/AccessibleRole;)Ljava/lang/String;",
+        );
     }
 }
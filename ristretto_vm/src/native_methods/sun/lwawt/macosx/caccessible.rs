@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -52,7 +52,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn menu_closed(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CAccessible.menuClosed(J)V")
+    unsatisfied_link(CLASS_NAME, "menuClosed", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -60,12 +60,12 @@ async fn menu_item_selected(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CAccessible.menuItemSelected(J)V")
+    unsatisfied_link(CLASS_NAME, "menuItemSelected", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn menu_opened(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CAccessible.menuOpened(J)V")
+    unsatisfied_link(CLASS_NAME, "menuOpened", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -73,7 +73,7 @@ async fn selected_cells_changed(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CAccessible.selectedCellsChanged(J)V")
+    unsatisfied_link(CLASS_NAME, "selectedCellsChanged", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -81,12 +81,12 @@ async fn selected_text_changed(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CAccessible.selectedTextChanged(J)V")
+    unsatisfied_link(CLASS_NAME, "selectedTextChanged", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn selection_changed(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CAccessible.selectionChanged(J)V")
+    unsatisfied_link(CLASS_NAME, "selectionChanged", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -94,12 +94,12 @@ async fn table_content_cache_clear(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CAccessible.tableContentCacheClear(J)V")
+    unsatisfied_link(CLASS_NAME, "tableContentCacheClear", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn title_changed(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CAccessible.titleChanged(J)V")
+    unsatisfied_link(CLASS_NAME, "titleChanged", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -107,7 +107,7 @@ async fn tree_node_collapsed(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CAccessible.treeNodeCollapsed(J)V")
+    unsatisfied_link(CLASS_NAME, "treeNodeCollapsed", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -115,7 +115,7 @@ async fn tree_node_expanded(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CAccessible.treeNodeExpanded(J)V")
+    unsatisfied_link(CLASS_NAME, "treeNodeExpanded", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -123,12 +123,12 @@ async fn unregister_from_cocoa_ax_system(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CAccessible.unregisterFromCocoaAXSystem(J)V")
+    unsatisfied_link(CLASS_NAME, "unregisterFromCocoaAXSystem", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn value_changed(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CAccessible.valueChanged(J)V")
+    unsatisfied_link(CLASS_NAME, "valueChanged", "(J)V")
 }
 
 #[cfg(test)]
@@ -136,102 +136,146 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.CAccessible.menuClosed(J)V")]
     async fn test_menu_closed() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = menu_closed(thread, Parameters::default()).await;
+        let result = menu_closed(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CAccessible",
+            "menuClosed",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CAccessible.menuItemSelected(J)V"
-    )]
     async fn test_menu_item_selected() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = menu_item_selected(thread, Parameters::default()).await;
+        let result = menu_item_selected(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CAccessible",
+            "menuItemSelected",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.CAccessible.menuOpened(J)V")]
     async fn test_menu_opened() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = menu_opened(thread, Parameters::default()).await;
+        let result = menu_opened(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CAccessible",
+            "menuOpened",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CAccessible.selectedCellsChanged(J)V"
-    )]
     async fn test_selected_cells_changed() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = selected_cells_changed(thread, Parameters::default()).await;
+        let result = selected_cells_changed(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CAccessible",
+            "selectedCellsChanged",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CAccessible.selectedTextChanged(J)V"
-    )]
     async fn test_selected_text_changed() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = selected_text_changed(thread, Parameters::default()).await;
+        let result = selected_text_changed(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CAccessible",
+            "selectedTextChanged",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CAccessible.selectionChanged(J)V"
-    )]
     async fn test_selection_changed() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = selection_changed(thread, Parameters::default()).await;
+        let result = selection_changed(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CAccessible",
+            "selectionChanged",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CAccessible.tableContentCacheClear(J)V"
-    )]
     async fn test_table_content_cache_clear() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = table_content_cache_clear(thread, Parameters::default()).await;
+        let result = table_content_cache_clear(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CAccessible",
+            "tableContentCacheClear",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.CAccessible.titleChanged(J)V")]
     async fn test_title_changed() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = title_changed(thread, Parameters::default()).await;
+        let result = title_changed(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CAccessible",
+            "titleChanged",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CAccessible.treeNodeCollapsed(J)V"
-    )]
     async fn test_tree_node_collapsed() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = tree_node_collapsed(thread, Parameters::default()).await;
+        let result = tree_node_collapsed(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CAccessible",
+            "treeNodeCollapsed",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CAccessible.treeNodeExpanded(J)V"
-    )]
     async fn test_tree_node_expanded() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = tree_node_expanded(thread, Parameters::default()).await;
+        let result = tree_node_expanded(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CAccessible",
+            "treeNodeExpanded",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CAccessible.unregisterFromCocoaAXSystem(J)V"
-    )]
     async fn test_unregister_from_cocoa_ax_system() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = unregister_from_cocoa_ax_system(thread, Parameters::default()).await;
+        let result = unregister_from_cocoa_ax_system(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CAccessible",
+            "unregisterFromCocoaAXSystem",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.CAccessible.valueChanged(J)V")]
     async fn test_value_changed() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = value_changed(thread, Parameters::default()).await;
+        let result = value_changed(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CAccessible",
+            "valueChanged",
+            "(J)V",
+        );
     }
 }
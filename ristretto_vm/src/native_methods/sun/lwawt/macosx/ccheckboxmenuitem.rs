@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -24,12 +24,12 @@ async fn native_set_is_checkbox(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CCheckboxMenuItem.nativeSetIsCheckbox(J)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetIsCheckbox", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn native_set_state(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CCheckboxMenuItem.nativeSetState(JZ)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetState", "(JZ)V")
 }
 
 #[cfg(test)]
@@ -37,20 +37,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CCheckboxMenuItem.nativeSetIsCheckbox(J)V"
-    )]
     async fn test_native_set_is_checkbox() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_is_checkbox(thread, Parameters::default()).await;
+        let result = native_set_is_checkbox(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CCheckboxMenuItem",
+            "nativeSetIsCheckbox",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CCheckboxMenuItem.nativeSetState(JZ)V"
-    )]
     async fn test_native_set_state() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_state(thread, Parameters::default()).await;
+        let result = native_set_state(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CCheckboxMenuItem",
+            "nativeSetState",
+            "(JZ)V",
+        );
     }
 }
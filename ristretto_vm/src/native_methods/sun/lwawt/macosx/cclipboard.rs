@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -37,12 +37,16 @@ async fn check_pasteboard_without_notification(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CClipboard.checkPasteboardWithoutNotification()Z")
+    unsatisfied_link(CLASS_NAME, "checkPasteboardWithoutNotification", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn declare_types(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CClipboard.declareTypes([JLsun/awt/datatransfer/SunClipboard;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "declareTypes",
+        "([JLsun/awt/datatransfer/SunClipboard;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -50,7 +54,7 @@ async fn get_clipboard_data(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CClipboard.getClipboardData(J)[B")
+    unsatisfied_link(CLASS_NAME, "getClipboardData", "(J)[B")
 }
 
 #[async_recursion(?Send)]
@@ -58,12 +62,12 @@ async fn get_clipboard_formats(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CClipboard.getClipboardFormats()[J")
+    unsatisfied_link(CLASS_NAME, "getClipboardFormats", "()[J")
 }
 
 #[async_recursion(?Send)]
 async fn set_data(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CClipboard.setData([BJ)V")
+    unsatisfied_link(CLASS_NAME, "setData", "([BJ)V")
 }
 
 #[cfg(test)]
@@ -71,45 +75,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CClipboard.checkPasteboardWithoutNotification()Z"
-    )]
     async fn test_check_pasteboard_without_notification() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = check_pasteboard_without_notification(thread, Parameters::default()).await;
+        let result = check_pasteboard_without_notification(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CClipboard",
+            "checkPasteboardWithoutNotification",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CClipboard.declareTypes([JLsun/awt/datatransfer/SunClipboard;)V"
-    )]
     async fn test_declare_types() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = declare_types(thread, Parameters::default()).await;
+        let result = declare_types(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CClipboard",
+            "declareTypes",
+            "([JLsun/awt/datatransfer/SunClipboard;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CClipboard.getClipboardData(J)[B"
-    )]
     async fn test_get_clipboard_data() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_clipboard_data(thread, Parameters::default()).await;
+        let result = get_clipboard_data(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CClipboard",
+            "getClipboardData",
+            "(J)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CClipboard.getClipboardFormats()[J"
-    )]
     async fn test_get_clipboard_formats() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_clipboard_formats(thread, Parameters::default()).await;
+        let result = get_clipboard_formats(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CClipboard",
+            "getClipboardFormats",
+            "()[J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.CClipboard.setData([BJ)V")]
     async fn test_set_data() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_data(thread, Parameters::default()).await;
+        let result = set_data(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CClipboard",
+            "setData",
+            "([BJ)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -41,7 +41,11 @@ async fn native_get_cursor_position(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CCursorManager.nativeGetCursorPosition()Ljava/awt/geom/Point2D;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeGetCursorPosition",
+        "()Ljava/awt/geom/Point2D;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -49,7 +53,7 @@ async fn native_set_allows_cursor_set_in_background(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CCursorManager.nativeSetAllowsCursorSetInBackground(Z)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetAllowsCursorSetInBackground", "(Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -57,7 +61,11 @@ async fn native_set_built_in_cursor(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CCursorManager.nativeSetBuiltInCursor(ILjava/lang/String;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeSetBuiltInCursor",
+        "(ILjava/lang/String;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -65,7 +73,7 @@ async fn native_set_custom_cursor(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CCursorManager.nativeSetCustomCursor(JDD)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetCustomCursor", "(JDD)V")
 }
 
 #[cfg(test)]
@@ -73,38 +81,51 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CCursorManager.nativeGetCursorPosition()Ljava/awt/geom/Point2D;"
-    )]
     async fn test_native_get_cursor_position() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_cursor_position(thread, Parameters::default()).await;
+        let result = native_get_cursor_position(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CCursorManager",
+            "nativeGetCursorPosition",
+            "()Ljava/awt/geom/Point2D;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CCursorManager.nativeSetAllowsCursorSetInBackground(Z)V"
-    )]
     async fn test_native_set_allows_cursor_set_in_background() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_allows_cursor_set_in_background(thread, Parameters::default()).await;
+        let result =
+            native_set_allows_cursor_set_in_background(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CCursorManager",
+            "nativeSetAllowsCursorSetInBackground",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CCursorManager.nativeSetBuiltInCursor(ILjava/lang/String;)V"
-    )]
     async fn test_native_set_built_in_cursor() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_built_in_cursor(thread, Parameters::default()).await;
+        let result = native_set_built_in_cursor(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CCursorManager",
+            "nativeSetBuiltInCursor",
+            "(ILjava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CCursorManager.nativeSetCustomCursor(JDD)V"
-    )]
     async fn test_native_set_custom_cursor() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_custom_cursor(thread, Parameters::default()).await;
+        let result = native_set_custom_cursor(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CCursorManager",
+            "nativeSetCustomCursor",
+            "(JDD)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -32,7 +32,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn format_for_index(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CDataTransferer.formatForIndex(J)Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "formatForIndex", "(J)Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
@@ -40,7 +40,7 @@ async fn native_drag_query_file(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CDataTransferer.nativeDragQueryFile([B)[Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "nativeDragQueryFile", "([B)[Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
@@ -48,7 +48,11 @@ async fn register_format_with_pasteboard(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CDataTransferer.registerFormatWithPasteboard(Ljava/lang/String;)J")
+    unsatisfied_link(
+        CLASS_NAME,
+        "registerFormatWithPasteboard",
+        "(Ljava/lang/String;)J",
+    )
 }
 
 #[cfg(test)]
@@ -56,29 +60,38 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CDataTransferer.formatForIndex(J)Ljava/lang/String;"
-    )]
     async fn test_format_for_index() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = format_for_index(thread, Parameters::default()).await;
+        let result = format_for_index(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CDataTransferer",
+            "formatForIndex",
+            "(J)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CDataTransferer.nativeDragQueryFile([B)[Ljava/lang/String;"
-    )]
     async fn test_native_drag_query_file() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_drag_query_file(thread, Parameters::default()).await;
+        let result = native_drag_query_file(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CDataTransferer",
+            "nativeDragQueryFile",
+            "([B)[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CDataTransferer.registerFormatWithPasteboard(Ljava/lang/String;)J"
-    )]
     async fn test_register_format_with_pasteboard() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = register_format_with_pasteboard(thread, Parameters::default()).await;
+        let result = register_format_with_pasteboard(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CDataTransferer",
+            "registerFormatWithPasteboard",
+            "(Ljava/lang/String;)J",
+        );
     }
 }
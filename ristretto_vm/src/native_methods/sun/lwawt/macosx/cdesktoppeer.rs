@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -26,12 +26,12 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn ls_open_file(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CDesktopPeer._lsOpenFile(Ljava/lang/String;Z)I")
+    unsatisfied_link(CLASS_NAME, "_lsOpenFile", "(Ljava/lang/String;Z)I")
 }
 
 #[async_recursion(?Send)]
 async fn ls_open_uri(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CDesktopPeer._lsOpenURI(Ljava/lang/String;)I")
+    unsatisfied_link(CLASS_NAME, "_lsOpenURI", "(Ljava/lang/String;)I")
 }
 
 #[cfg(test)]
@@ -39,20 +39,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CDesktopPeer._lsOpenFile(Ljava/lang/String;Z)I"
-    )]
     async fn test_ls_open_file() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ls_open_file(thread, Parameters::default()).await;
+        let result = ls_open_file(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CDesktopPeer",
+            "_lsOpenFile",
+            "(Ljava/lang/String;Z)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CDesktopPeer._lsOpenURI(Ljava/lang/String;)I"
-    )]
     async fn test_ls_open_uri() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ls_open_uri(thread, Parameters::default()).await;
+        let result = ls_open_uri(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CDesktopPeer",
+            "_lsOpenURI",
+            "(Ljava/lang/String;)I",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -30,12 +30,12 @@ async fn create_native_drag_source(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CDragSourceContextPeer.createNativeDragSource(Ljava/awt/Component;JLjava/awt/datatransfer/Transferable;Ljava/awt/event/InputEvent;IIIIJLsun/lwawt/macosx/CImage;III[JLjava/util/Map;)J")
+    unsatisfied_link(CLASS_NAME, "createNativeDragSource", "(Ljava/awt/Component;JLjava/awt/datatransfer/Transferable;Ljava/awt/event/InputEvent;IIIIJLsun/lwawt/macosx/CImage;III[JLjava/util/Map;)J")
 }
 
 #[async_recursion(?Send)]
 async fn do_dragging(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CDragSourceContextPeer.doDragging(J)V")
+    unsatisfied_link(CLASS_NAME, "doDragging", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -43,7 +43,7 @@ async fn release_native_drag_source(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CDragSourceContextPeer.releaseNativeDragSource(J)V")
+    unsatisfied_link(CLASS_NAME, "releaseNativeDragSource", "(J)V")
 }
 
 #[cfg(test)]
@@ -51,29 +51,33 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CDragSourceContextPeer.createNativeDragSource(Ljava/awt/Component;JLjava/awt/datatransfer/Transferable;Ljava/awt/event/InputEvent;IIIIJLsun/lwawt/macosx/CImage;III[JLjava/util/Map;)J"
-    )]
     async fn test_create_native_drag_source() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_native_drag_source(thread, Parameters::default()).await;
+        let result = create_native_drag_source(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/lwawt/macosx/CDragSourceContextPeer", "createNativeDragSource", "(Ljava/awt/Component;JLjava/awt/datatransfer/Transferable;Ljava/awt/event/InputEvent;IIIIJLsun/lwawt/macosx/CImage;III[JLjava/util/Map;)J");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CDragSourceContextPeer.doDragging(J)V"
-    )]
     async fn test_do_dragging() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = do_dragging(thread, Parameters::default()).await;
+        let result = do_dragging(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CDragSourceContextPeer",
+            "doDragging",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CDragSourceContextPeer.releaseNativeDragSource(J)V"
-    )]
     async fn test_release_native_drag_source() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = release_native_drag_source(thread, Parameters::default()).await;
+        let result = release_native_drag_source(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CDragSourceContextPeer",
+            "releaseNativeDragSource",
+            "(J)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -39,7 +39,11 @@ async fn create_native_drop_target(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CDropTarget.createNativeDropTarget(Ljava/awt/dnd/DropTarget;Ljava/awt/Component;J)J")
+    unsatisfied_link(
+        CLASS_NAME,
+        "createNativeDropTarget",
+        "(Ljava/awt/dnd/DropTarget;Ljava/awt/Component;J)J",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -47,7 +51,7 @@ async fn release_native_drop_target(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CDropTarget.releaseNativeDropTarget(J)V")
+    unsatisfied_link(CLASS_NAME, "releaseNativeDropTarget", "(J)V")
 }
 
 #[cfg(test)]
@@ -55,20 +59,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CDropTarget.createNativeDropTarget(Ljava/awt/dnd/DropTarget;Ljava/awt/Component;J)J"
-    )]
     async fn test_create_native_drop_target() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_native_drop_target(thread, Parameters::default()).await;
+        let result = create_native_drop_target(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CDropTarget",
+            "createNativeDropTarget",
+            "(Ljava/awt/dnd/DropTarget;Ljava/awt/Component;J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CDropTarget.releaseNativeDropTarget(J)V"
-    )]
     async fn test_release_native_drop_target() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = release_native_drop_target(thread, Parameters::default()).await;
+        let result = release_native_drop_target(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CDropTarget",
+            "releaseNativeDropTarget",
+            "(J)V",
+        );
     }
 }
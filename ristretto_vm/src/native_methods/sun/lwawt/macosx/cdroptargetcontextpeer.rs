@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -17,17 +17,17 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn add_transfer(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CDropTargetContextPeer.addTransfer(JJJ)V")
+    unsatisfied_link(CLASS_NAME, "addTransfer", "(JJJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn drop_done(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CDropTargetContextPeer.dropDone(JJZZI)V")
+    unsatisfied_link(CLASS_NAME, "dropDone", "(JJZZI)V")
 }
 
 #[async_recursion(?Send)]
 async fn start_transfer(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CDropTargetContextPeer.startTransfer(JJ)J")
+    unsatisfied_link(CLASS_NAME, "startTransfer", "(JJ)J")
 }
 
 #[cfg(test)]
@@ -35,29 +35,38 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CDropTargetContextPeer.addTransfer(JJJ)V"
-    )]
     async fn test_add_transfer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = add_transfer(thread, Parameters::default()).await;
+        let result = add_transfer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CDropTargetContextPeer",
+            "addTransfer",
+            "(JJJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CDropTargetContextPeer.dropDone(JJZZI)V"
-    )]
     async fn test_drop_done() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = drop_done(thread, Parameters::default()).await;
+        let result = drop_done(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CDropTargetContextPeer",
+            "dropDone",
+            "(JJZZI)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CDropTargetContextPeer.startTransfer(JJ)J"
-    )]
     async fn test_start_transfer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = start_transfer(thread, Parameters::default()).await;
+        let result = start_transfer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CDropTargetContextPeer",
+            "startTransfer",
+            "(JJ)J",
+        );
     }
 }
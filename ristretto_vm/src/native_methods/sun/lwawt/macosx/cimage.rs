@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -95,7 +95,7 @@ async fn native_copy_ns_image_into_array(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CImage.nativeCopyNSImageIntoArray(J[IIIII)V")
+    unsatisfied_link(CLASS_NAME, "nativeCopyNSImageIntoArray", "(J[IIIII)V")
 }
 
 #[async_recursion(?Send)]
@@ -103,7 +103,7 @@ async fn native_create_ns_image_from_array(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CImage.nativeCreateNSImageFromArray([III)J")
+    unsatisfied_link(CLASS_NAME, "nativeCreateNSImageFromArray", "([III)J")
 }
 
 #[async_recursion(?Send)]
@@ -111,7 +111,7 @@ async fn native_create_ns_image_from_arrays(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CImage.nativeCreateNSImageFromArrays([[I[I[I)J")
+    unsatisfied_link(CLASS_NAME, "nativeCreateNSImageFromArrays", "([[I[I[I)J")
 }
 
 #[async_recursion(?Send)]
@@ -119,7 +119,7 @@ async fn native_create_ns_image_from_bytes(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CImage.nativeCreateNSImageFromBytes([B)J")
+    unsatisfied_link(CLASS_NAME, "nativeCreateNSImageFromBytes", "([B)J")
 }
 
 #[async_recursion(?Send)]
@@ -127,7 +127,11 @@ async fn native_create_ns_image_from_file_contents(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CImage.nativeCreateNSImageFromFileContents(Ljava/lang/String;)J")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeCreateNSImageFromFileContents",
+        "(Ljava/lang/String;)J",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -135,7 +139,7 @@ async fn native_create_ns_image_from_icon_selector(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CImage.nativeCreateNSImageFromIconSelector(I)J")
+    unsatisfied_link(CLASS_NAME, "nativeCreateNSImageFromIconSelector", "(I)J")
 }
 
 #[async_recursion(?Send)]
@@ -143,7 +147,11 @@ async fn native_create_ns_image_from_image_name(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CImage.nativeCreateNSImageFromImageName(Ljava/lang/String;)J")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeCreateNSImageFromImageName",
+        "(Ljava/lang/String;)J",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -151,8 +159,10 @@ async fn native_create_ns_image_of_file_from_launch_services(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!(
-        "sun.lwawt.macosx.CImage.nativeCreateNSImageOfFileFromLaunchServices(Ljava/lang/String;)J"
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeCreateNSImageOfFileFromLaunchServices",
+        "(Ljava/lang/String;)J",
     )
 }
 
@@ -161,7 +171,11 @@ async fn native_get_ns_image_representation_sizes(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CImage.nativeGetNSImageRepresentationSizes(JDD)[Ljava/awt/geom/Dimension2D;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeGetNSImageRepresentationSizes",
+        "(JDD)[Ljava/awt/geom/Dimension2D;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -169,7 +183,11 @@ async fn native_get_ns_image_size(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CImage.nativeGetNSImageSize(J)Ljava/awt/geom/Dimension2D;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeGetNSImageSize",
+        "(J)Ljava/awt/geom/Dimension2D;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -177,7 +195,7 @@ async fn native_get_platform_image_bytes(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CImage.nativeGetPlatformImageBytes([III)[B")
+    unsatisfied_link(CLASS_NAME, "nativeGetPlatformImageBytes", "([III)[B")
 }
 
 #[async_recursion(?Send)]
@@ -185,7 +203,7 @@ async fn native_resize_ns_image_representations(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CImage.nativeResizeNSImageRepresentations(JDD)V")
+    unsatisfied_link(CLASS_NAME, "nativeResizeNSImageRepresentations", "(JDD)V")
 }
 
 #[async_recursion(?Send)]
@@ -193,7 +211,7 @@ async fn native_set_ns_image_size(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CImage.nativeSetNSImageSize(JDD)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetNSImageSize", "(JDD)V")
 }
 
 #[cfg(test)]
@@ -201,120 +219,160 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CImage.nativeCopyNSImageIntoArray(J[IIIII)V"
-    )]
     async fn test_native_copy_ns_image_into_array() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_copy_ns_image_into_array(thread, Parameters::default()).await;
+        let result = native_copy_ns_image_into_array(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CImage",
+            "nativeCopyNSImageIntoArray",
+            "(J[IIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CImage.nativeCreateNSImageFromArray([III)J"
-    )]
     async fn test_native_create_ns_image_from_array() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_create_ns_image_from_array(thread, Parameters::default()).await;
+        let result = native_create_ns_image_from_array(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CImage",
+            "nativeCreateNSImageFromArray",
+            "([III)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CImage.nativeCreateNSImageFromArrays([[I[I[I)J"
-    )]
     async fn test_native_create_ns_image_from_arrays() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_create_ns_image_from_arrays(thread, Parameters::default()).await;
+        let result = native_create_ns_image_from_arrays(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CImage",
+            "nativeCreateNSImageFromArrays",
+            "([[I[I[I)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CImage.nativeCreateNSImageFromBytes([B)J"
-    )]
     async fn test_native_create_ns_image_from_bytes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_create_ns_image_from_bytes(thread, Parameters::default()).await;
+        let result = native_create_ns_image_from_bytes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CImage",
+            "nativeCreateNSImageFromBytes",
+            "([B)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CImage.nativeCreateNSImageFromFileContents(Ljava/lang/String;)J"
-    )]
     async fn test_native_create_ns_image_from_file_contents() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_create_ns_image_from_file_contents(thread, Parameters::default()).await;
+        let result = native_create_ns_image_from_file_contents(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CImage",
+            "nativeCreateNSImageFromFileContents",
+            "(Ljava/lang/String;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CImage.nativeCreateNSImageFromIconSelector(I)J"
-    )]
     async fn test_native_create_ns_image_from_icon_selector() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_create_ns_image_from_icon_selector(thread, Parameters::default()).await;
+        let result = native_create_ns_image_from_icon_selector(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CImage",
+            "nativeCreateNSImageFromIconSelector",
+            "(I)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CImage.nativeCreateNSImageFromImageName(Ljava/lang/String;)J"
-    )]
     async fn test_native_create_ns_image_from_image_name() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_create_ns_image_from_image_name(thread, Parameters::default()).await;
+        let result = native_create_ns_image_from_image_name(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CImage",
+            "nativeCreateNSImageFromImageName",
+            "(Ljava/lang/String;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CImage.nativeCreateNSImageOfFileFromLaunchServices(Ljava/lang/String;)J"
-    )]
     async fn test_native_create_ns_image_of_file_from_launch_services() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_create_ns_image_of_file_from_launch_services(thread, Parameters::default())
-            .await;
+        let result =
+            native_create_ns_image_of_file_from_launch_services(thread, Parameters::default())
+                .await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CImage",
+            "nativeCreateNSImageOfFileFromLaunchServices",
+            "(Ljava/lang/String;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CImage.nativeGetNSImageRepresentationSizes(JDD)[Ljava/awt/geom/Dimension2D;"
-    )]
     async fn test_native_get_ns_image_representation_sizes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_ns_image_representation_sizes(thread, Parameters::default()).await;
+        let result = native_get_ns_image_representation_sizes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CImage",
+            "nativeGetNSImageRepresentationSizes",
+            "(JDD)[Ljava/awt/geom/Dimension2D;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CImage.nativeGetNSImageSize(J)Ljava/awt/geom/Dimension2D;"
-    )]
     async fn test_native_get_ns_image_size() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_ns_image_size(thread, Parameters::default()).await;
+        let result = native_get_ns_image_size(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CImage",
+            "nativeGetNSImageSize",
+            "(J)Ljava/awt/geom/Dimension2D;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CImage.nativeGetPlatformImageBytes([III)[B"
-    )]
     async fn test_native_get_platform_image_bytes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_platform_image_bytes(thread, Parameters::default()).await;
+        let result = native_get_platform_image_bytes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CImage",
+            "nativeGetPlatformImageBytes",
+            "([III)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CImage.nativeResizeNSImageRepresentations(JDD)V"
-    )]
     async fn test_native_resize_ns_image_representations() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_resize_ns_image_representations(thread, Parameters::default()).await;
+        let result = native_resize_ns_image_representations(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CImage",
+            "nativeResizeNSImageRepresentations",
+            "(JDD)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CImage.nativeSetNSImageSize(JDD)V"
-    )]
     async fn test_native_set_ns_image_size() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_ns_image_size(thread, Parameters::default()).await;
+        let result = native_set_ns_image_size(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CImage",
+            "nativeSetNSImageSize",
+            "(JDD)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -51,7 +51,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn get_native_locale(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CInputMethod.getNativeLocale()Ljava/util/Locale;")
+    unsatisfied_link(CLASS_NAME, "getNativeLocale", "()Ljava/util/Locale;")
 }
 
 #[async_recursion(?Send)]
@@ -59,7 +59,7 @@ async fn native_end_composition(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CInputMethod.nativeEndComposition(J)V")
+    unsatisfied_link(CLASS_NAME, "nativeEndComposition", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -67,7 +67,11 @@ async fn native_get_current_input_method_info(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CInputMethod.nativeGetCurrentInputMethodInfo()Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeGetCurrentInputMethodInfo",
+        "()Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -75,12 +79,16 @@ async fn native_handle_event(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CInputMethod.nativeHandleEvent(Lsun/lwawt/LWComponentPeer;Ljava/awt/AWTEvent;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeHandleEvent",
+        "(Lsun/lwawt/LWComponentPeer;Ljava/awt/AWTEvent;)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn native_init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CInputMethod.nativeInit()V")
+    unsatisfied_link(CLASS_NAME, "nativeInit", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -88,12 +96,16 @@ async fn native_notify_peer(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CInputMethod.nativeNotifyPeer(JLsun/lwawt/macosx/CInputMethod;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeNotifyPeer",
+        "(JLsun/lwawt/macosx/CInputMethod;)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn set_native_locale(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CInputMethod.setNativeLocale(Ljava/lang/String;Z)Z")
+    unsatisfied_link(CLASS_NAME, "setNativeLocale", "(Ljava/lang/String;Z)Z")
 }
 
 #[cfg(test)]
@@ -101,63 +113,86 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CInputMethod.getNativeLocale()Ljava/util/Locale;"
-    )]
     async fn test_get_native_locale() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_native_locale(thread, Parameters::default()).await;
+        let result = get_native_locale(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CInputMethod",
+            "getNativeLocale",
+            "()Ljava/util/Locale;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CInputMethod.nativeEndComposition(J)V"
-    )]
     async fn test_native_end_composition() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_end_composition(thread, Parameters::default()).await;
+        let result = native_end_composition(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CInputMethod",
+            "nativeEndComposition",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CInputMethod.nativeGetCurrentInputMethodInfo()Ljava/lang/String;"
-    )]
     async fn test_native_get_current_input_method_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_current_input_method_info(thread, Parameters::default()).await;
+        let result = native_get_current_input_method_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CInputMethod",
+            "nativeGetCurrentInputMethodInfo",
+            "()Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CInputMethod.nativeHandleEvent(Lsun/lwawt/LWComponentPeer;Ljava/awt/AWTEvent;)V"
-    )]
     async fn test_native_handle_event() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_handle_event(thread, Parameters::default()).await;
+        let result = native_handle_event(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CInputMethod",
+            "nativeHandleEvent",
+            "(Lsun/lwawt/LWComponentPeer;Ljava/awt/AWTEvent;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.CInputMethod.nativeInit()V")]
     async fn test_native_init() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_init(thread, Parameters::default()).await;
+        let result = native_init(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CInputMethod",
+            "nativeInit",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CInputMethod.nativeNotifyPeer(JLsun/lwawt/macosx/CInputMethod;)V"
-    )]
     async fn test_native_notify_peer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_notify_peer(thread, Parameters::default()).await;
+        let result = native_notify_peer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CInputMethod",
+            "nativeNotifyPeer",
+            "(JLsun/lwawt/macosx/CInputMethod;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CInputMethod.setNativeLocale(Ljava/lang/String;Z)Z"
-    )]
     async fn test_set_native_locale() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_native_locale(thread, Parameters::default()).await;
+        let result = set_native_locale(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CInputMethod",
+            "setNativeLocale",
+            "(Ljava/lang/String;Z)Z",
+        );
     }
 }
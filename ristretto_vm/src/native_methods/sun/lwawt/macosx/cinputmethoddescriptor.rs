@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -24,12 +24,16 @@ async fn native_get_available_locales(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CInputMethodDescriptor.nativeGetAvailableLocales()Ljava/util/List;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeGetAvailableLocales",
+        "()Ljava/util/List;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn native_init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CInputMethodDescriptor.nativeInit()V")
+    unsatisfied_link(CLASS_NAME, "nativeInit", "()V")
 }
 
 #[cfg(test)]
@@ -37,20 +41,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CInputMethodDescriptor.nativeGetAvailableLocales()Ljava/util/List;"
-    )]
     async fn test_native_get_available_locales() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_available_locales(thread, Parameters::default()).await;
+        let result = native_get_available_locales(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CInputMethodDescriptor",
+            "nativeGetAvailableLocales",
+            "()Ljava/util/List;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CInputMethodDescriptor.nativeInit()V"
-    )]
     async fn test_native_init() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_init(thread, Parameters::default()).await;
+        let result = native_init(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CInputMethodDescriptor",
+            "nativeInit",
+            "()V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -41,7 +41,7 @@ async fn native_add_separator(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CMenu.nativeAddSeparator(J)V")
+    unsatisfied_link(CLASS_NAME, "nativeAddSeparator", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -49,7 +49,7 @@ async fn native_create_menu(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CMenu.nativeCreateMenu(JZI)J")
+    unsatisfied_link(CLASS_NAME, "nativeCreateMenu", "(JZI)J")
 }
 
 #[async_recursion(?Send)]
@@ -57,7 +57,7 @@ async fn native_create_sub_menu(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CMenu.nativeCreateSubMenu(J)J")
+    unsatisfied_link(CLASS_NAME, "nativeCreateSubMenu", "(J)J")
 }
 
 #[async_recursion(?Send)]
@@ -65,7 +65,7 @@ async fn native_delete_item(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CMenu.nativeDeleteItem(JI)V")
+    unsatisfied_link(CLASS_NAME, "nativeDeleteItem", "(JI)V")
 }
 
 #[async_recursion(?Send)]
@@ -73,7 +73,7 @@ async fn native_get_ns_menu(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CMenu.nativeGetNSMenu(J)J")
+    unsatisfied_link(CLASS_NAME, "nativeGetNSMenu", "(J)J")
 }
 
 #[async_recursion(?Send)]
@@ -81,7 +81,7 @@ async fn native_set_menu_title(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CMenu.nativeSetMenuTitle(JLjava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetMenuTitle", "(JLjava/lang/String;)V")
 }
 
 #[cfg(test)]
@@ -89,48 +89,74 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.CMenu.nativeAddSeparator(J)V")]
     async fn test_native_add_separator() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_add_separator(thread, Parameters::default()).await;
+        let result = native_add_separator(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CMenu",
+            "nativeAddSeparator",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.CMenu.nativeCreateMenu(JZI)J")]
     async fn test_native_create_menu() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_create_menu(thread, Parameters::default()).await;
+        let result = native_create_menu(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CMenu",
+            "nativeCreateMenu",
+            "(JZI)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CMenu.nativeCreateSubMenu(J)J"
-    )]
     async fn test_native_create_sub_menu() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_create_sub_menu(thread, Parameters::default()).await;
+        let result = native_create_sub_menu(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CMenu",
+            "nativeCreateSubMenu",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.CMenu.nativeDeleteItem(JI)V")]
     async fn test_native_delete_item() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_delete_item(thread, Parameters::default()).await;
+        let result = native_delete_item(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CMenu",
+            "nativeDeleteItem",
+            "(JI)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.CMenu.nativeGetNSMenu(J)J")]
     async fn test_native_get_ns_menu() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_ns_menu(thread, Parameters::default()).await;
+        let result = native_get_ns_menu(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CMenu",
+            "nativeGetNSMenu",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CMenu.nativeSetMenuTitle(JLjava/lang/String;)V"
-    )]
     async fn test_native_set_menu_title() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_menu_title(thread, Parameters::default()).await;
+        let result = native_set_menu_title(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CMenu",
+            "nativeSetMenuTitle",
+            "(JLjava/lang/String;)V",
+        );
     }
 }
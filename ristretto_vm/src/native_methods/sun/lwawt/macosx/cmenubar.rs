@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -30,12 +30,12 @@ async fn native_create_menu_bar(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CMenuBar.nativeCreateMenuBar()J")
+    unsatisfied_link(CLASS_NAME, "nativeCreateMenuBar", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn native_del_menu(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CMenuBar.nativeDelMenu(JI)V")
+    unsatisfied_link(CLASS_NAME, "nativeDelMenu", "(JI)V")
 }
 
 #[async_recursion(?Send)]
@@ -43,7 +43,7 @@ async fn native_set_help_menu(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CMenuBar.nativeSetHelpMenu(JJ)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetHelpMenu", "(JJ)V")
 }
 
 #[cfg(test)]
@@ -51,27 +51,38 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CMenuBar.nativeCreateMenuBar()J"
-    )]
     async fn test_native_create_menu_bar() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_create_menu_bar(thread, Parameters::default()).await;
+        let result = native_create_menu_bar(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CMenuBar",
+            "nativeCreateMenuBar",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.CMenuBar.nativeDelMenu(JI)V")]
     async fn test_native_del_menu() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_del_menu(thread, Parameters::default()).await;
+        let result = native_del_menu(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CMenuBar",
+            "nativeDelMenu",
+            "(JI)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CMenuBar.nativeSetHelpMenu(JJ)V"
-    )]
     async fn test_native_set_help_menu() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_help_menu(thread, Parameters::default()).await;
+        let result = native_set_help_menu(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CMenuBar",
+            "nativeSetHelpMenu",
+            "(JJ)V",
+        );
     }
 }
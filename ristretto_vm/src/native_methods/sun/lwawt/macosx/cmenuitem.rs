@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -29,7 +29,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn native_create(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CMenuItem.nativeCreate(JZ)J")
+    unsatisfied_link(CLASS_NAME, "nativeCreate", "(JZ)J")
 }
 
 #[async_recursion(?Send)]
@@ -37,17 +37,17 @@ async fn native_set_enabled(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CMenuItem.nativeSetEnabled(JZ)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetEnabled", "(JZ)V")
 }
 
 #[async_recursion(?Send)]
 async fn native_set_image(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CMenuItem.nativeSetImage(JJ)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetImage", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn native_set_label(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CMenuItem.nativeSetLabel(JLjava/lang/String;CII)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetLabel", "(JLjava/lang/String;CII)V")
 }
 
 #[async_recursion(?Send)]
@@ -55,7 +55,7 @@ async fn native_set_tooltip(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CMenuItem.nativeSetTooltip(JLjava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetTooltip", "(JLjava/lang/String;)V")
 }
 
 #[cfg(test)]
@@ -63,45 +63,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.CMenuItem.nativeCreate(JZ)J")]
     async fn test_native_create() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_create(thread, Parameters::default()).await;
+        let result = native_create(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CMenuItem",
+            "nativeCreate",
+            "(JZ)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CMenuItem.nativeSetEnabled(JZ)V"
-    )]
     async fn test_native_set_enabled() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_enabled(thread, Parameters::default()).await;
+        let result = native_set_enabled(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CMenuItem",
+            "nativeSetEnabled",
+            "(JZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CMenuItem.nativeSetImage(JJ)V"
-    )]
     async fn test_native_set_image() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_image(thread, Parameters::default()).await;
+        let result = native_set_image(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CMenuItem",
+            "nativeSetImage",
+            "(JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CMenuItem.nativeSetLabel(JLjava/lang/String;CII)V"
-    )]
     async fn test_native_set_label() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_label(thread, Parameters::default()).await;
+        let result = native_set_label(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CMenuItem",
+            "nativeSetLabel",
+            "(JLjava/lang/String;CII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CMenuItem.nativeSetTooltip(JLjava/lang/String;)V"
-    )]
     async fn test_native_set_tooltip() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_tooltip(thread, Parameters::default()).await;
+        let result = native_set_tooltip(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CMenuItem",
+            "nativeSetTooltip",
+            "(JLjava/lang/String;)V",
+        );
     }
 }
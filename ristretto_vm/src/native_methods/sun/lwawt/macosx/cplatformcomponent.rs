@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -24,12 +24,12 @@ async fn native_create_component(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformComponent.nativeCreateComponent(J)J")
+    unsatisfied_link(CLASS_NAME, "nativeCreateComponent", "(J)J")
 }
 
 #[async_recursion(?Send)]
 async fn native_set_bounds(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformComponent.nativeSetBounds(JIIII)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetBounds", "(JIIII)V")
 }
 
 #[cfg(test)]
@@ -37,20 +37,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformComponent.nativeCreateComponent(J)J"
-    )]
     async fn test_native_create_component() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_create_component(thread, Parameters::default()).await;
+        let result = native_create_component(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformComponent",
+            "nativeCreateComponent",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformComponent.nativeSetBounds(JIIII)V"
-    )]
     async fn test_native_set_bounds() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_bounds(thread, Parameters::default()).await;
+        let result = native_set_bounds(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformComponent",
+            "nativeSetBounds",
+            "(JIIII)V",
+        );
     }
 }
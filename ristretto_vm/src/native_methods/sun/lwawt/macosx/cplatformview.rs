@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -47,7 +47,7 @@ async fn native_create_view(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformView.nativeCreateView(IIIIJ)J")
+    unsatisfied_link(CLASS_NAME, "nativeCreateView", "(IIIIJ)J")
 }
 
 #[async_recursion(?Send)]
@@ -55,7 +55,11 @@ async fn native_get_location_on_screen(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformView.nativeGetLocationOnScreen(J)Ljava/awt/geom/Rectangle2D;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeGetLocationOnScreen",
+        "(J)Ljava/awt/geom/Rectangle2D;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -63,7 +67,7 @@ async fn native_get_ns_view_display_id(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformView.nativeGetNSViewDisplayID(J)I")
+    unsatisfied_link(CLASS_NAME, "nativeGetNSViewDisplayID", "(J)I")
 }
 
 #[async_recursion(?Send)]
@@ -71,7 +75,7 @@ async fn native_is_view_under_mouse(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformView.nativeIsViewUnderMouse(J)Z")
+    unsatisfied_link(CLASS_NAME, "nativeIsViewUnderMouse", "(J)Z")
 }
 
 #[async_recursion(?Send)]
@@ -79,7 +83,7 @@ async fn native_set_auto_resizable(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformView.nativeSetAutoResizable(JZ)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetAutoResizable", "(JZ)V")
 }
 
 #[cfg(test)]
@@ -87,47 +91,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformView.nativeCreateView(IIIIJ)J"
-    )]
     async fn test_native_create_view() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_create_view(thread, Parameters::default()).await;
+        let result = native_create_view(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformView",
+            "nativeCreateView",
+            "(IIIIJ)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformView.nativeGetLocationOnScreen(J)Ljava/awt/geom/Rectangle2D;"
-    )]
     async fn test_native_get_location_on_screen() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_location_on_screen(thread, Parameters::default()).await;
+        let result = native_get_location_on_screen(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformView",
+            "nativeGetLocationOnScreen",
+            "(J)Ljava/awt/geom/Rectangle2D;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformView.nativeGetNSViewDisplayID(J)I"
-    )]
     async fn test_native_get_ns_view_display_id() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_ns_view_display_id(thread, Parameters::default()).await;
+        let result = native_get_ns_view_display_id(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformView",
+            "nativeGetNSViewDisplayID",
+            "(J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformView.nativeIsViewUnderMouse(J)Z"
-    )]
     async fn test_native_is_view_under_mouse() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_is_view_under_mouse(thread, Parameters::default()).await;
+        let result = native_is_view_under_mouse(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformView",
+            "nativeIsViewUnderMouse",
+            "(J)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformView.nativeSetAutoResizable(JZ)V"
-    )]
     async fn test_native_set_auto_resizable() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_auto_resizable(thread, Parameters::default()).await;
+        let result = native_set_auto_resizable(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformView",
+            "nativeSetAutoResizable",
+            "(JZ)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -158,7 +158,7 @@ async fn toggle_full_screen_mode(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow._toggleFullScreenMode(J)V")
+    unsatisfied_link(CLASS_NAME, "_toggleFullScreenMode", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -166,12 +166,12 @@ async fn native_create_ns_window(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativeCreateNSWindow(JJJDDDD)J")
+    unsatisfied_link(CLASS_NAME, "nativeCreateNSWindow", "(JJJDDDD)J")
 }
 
 #[async_recursion(?Send)]
 async fn native_dispose(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativeDispose(J)V")
+    unsatisfied_link(CLASS_NAME, "nativeDispose", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -179,7 +179,7 @@ async fn native_enter_full_screen_mode(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativeEnterFullScreenMode(J)V")
+    unsatisfied_link(CLASS_NAME, "nativeEnterFullScreenMode", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -187,7 +187,7 @@ async fn native_exit_full_screen_mode(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativeExitFullScreenMode(J)V")
+    unsatisfied_link(CLASS_NAME, "nativeExitFullScreenMode", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -195,7 +195,11 @@ async fn native_get_ns_window_insets(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativeGetNSWindowInsets(J)Ljava/awt/Insets;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeGetNSWindowInsets",
+        "(J)Ljava/awt/Insets;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -203,7 +207,11 @@ async fn native_get_topmost_platform_window_under_mouse(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativeGetTopmostPlatformWindowUnderMouse()Lsun/lwawt/macosx/CPlatformWindow;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeGetTopmostPlatformWindowUnderMouse",
+        "()Lsun/lwawt/macosx/CPlatformWindow;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -211,7 +219,7 @@ async fn native_push_ns_window_to_back(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativePushNSWindowToBack(J)V")
+    unsatisfied_link(CLASS_NAME, "nativePushNSWindowToBack", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -219,7 +227,7 @@ async fn native_push_ns_window_to_front(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativePushNSWindowToFront(J)V")
+    unsatisfied_link(CLASS_NAME, "nativePushNSWindowToFront", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -227,7 +235,7 @@ async fn native_revalidate_ns_window_shadow(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativeRevalidateNSWindowShadow(J)V")
+    unsatisfied_link(CLASS_NAME, "nativeRevalidateNSWindowShadow", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -235,7 +243,7 @@ async fn native_set_allow_automatic_tabbing_property(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativeSetAllowAutomaticTabbingProperty(Z)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetAllowAutomaticTabbingProperty", "(Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -243,7 +251,7 @@ async fn native_set_enabled(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativeSetEnabled(JZ)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetEnabled", "(JZ)V")
 }
 
 #[async_recursion(?Send)]
@@ -251,7 +259,7 @@ async fn native_set_ns_window_bounds(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativeSetNSWindowBounds(JDDDD)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetNSWindowBounds", "(JDDDD)V")
 }
 
 #[async_recursion(?Send)]
@@ -259,7 +267,7 @@ async fn native_set_ns_window_location_by_platform(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativeSetNSWindowLocationByPlatform(J)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetNSWindowLocationByPlatform", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -267,7 +275,7 @@ async fn native_set_ns_window_menu_bar(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativeSetNSWindowMenuBar(JJ)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetNSWindowMenuBar", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
@@ -275,7 +283,7 @@ async fn native_set_ns_window_min_max(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativeSetNSWindowMinMax(JDDDD)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetNSWindowMinMax", "(JDDDD)V")
 }
 
 #[async_recursion(?Send)]
@@ -283,7 +291,7 @@ async fn native_set_ns_window_minimized_icon(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativeSetNSWindowMinimizedIcon(JJ)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetNSWindowMinimizedIcon", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
@@ -291,7 +299,11 @@ async fn native_set_ns_window_represented_filename(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativeSetNSWindowRepresentedFilename(JLjava/lang/String;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeSetNSWindowRepresentedFilename",
+        "(JLjava/lang/String;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -299,7 +311,7 @@ async fn native_set_ns_window_standard_frame(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativeSetNSWindowStandardFrame(JDDDD)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetNSWindowStandardFrame", "(JDDDD)V")
 }
 
 #[async_recursion(?Send)]
@@ -307,7 +319,7 @@ async fn native_set_ns_window_style_bits(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativeSetNSWindowStyleBits(JII)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetNSWindowStyleBits", "(JII)V")
 }
 
 #[async_recursion(?Send)]
@@ -315,7 +327,11 @@ async fn native_set_ns_window_title(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativeSetNSWindowTitle(JLjava/lang/String;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeSetNSWindowTitle",
+        "(JLjava/lang/String;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -323,7 +339,11 @@ async fn native_synthesize_mouse_entered_exited_events_1(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativeSynthesizeMouseEnteredExitedEvents()V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeSynthesizeMouseEnteredExitedEvents",
+        "()V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -331,7 +351,11 @@ async fn native_synthesize_mouse_entered_exited_events_2(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPlatformWindow.nativeSynthesizeMouseEnteredExitedEvents(JI)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeSynthesizeMouseEnteredExitedEvents",
+        "(JI)V",
+    )
 }
 
 #[cfg(test)]
@@ -339,211 +363,282 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow._toggleFullScreenMode(J)V"
-    )]
     async fn test_toggle_full_screen_mode() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = toggle_full_screen_mode(thread, Parameters::default()).await;
+        let result = toggle_full_screen_mode(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "_toggleFullScreenMode",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativeCreateNSWindow(JJJDDDD)J"
-    )]
     async fn test_native_create_ns_window() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_create_ns_window(thread, Parameters::default()).await;
+        let result = native_create_ns_window(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativeCreateNSWindow",
+            "(JJJDDDD)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativeDispose(J)V"
-    )]
     async fn test_native_dispose() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_dispose(thread, Parameters::default()).await;
+        let result = native_dispose(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativeDispose",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativeEnterFullScreenMode(J)V"
-    )]
     async fn test_native_enter_full_screen_mode() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_enter_full_screen_mode(thread, Parameters::default()).await;
+        let result = native_enter_full_screen_mode(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativeEnterFullScreenMode",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativeExitFullScreenMode(J)V"
-    )]
     async fn test_native_exit_full_screen_mode() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_exit_full_screen_mode(thread, Parameters::default()).await;
+        let result = native_exit_full_screen_mode(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativeExitFullScreenMode",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativeGetNSWindowInsets(J)Ljava/awt/Insets;"
-    )]
     async fn test_native_get_ns_window_insets() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_ns_window_insets(thread, Parameters::default()).await;
+        let result = native_get_ns_window_insets(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativeGetNSWindowInsets",
+            "(J)Ljava/awt/Insets;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativeGetTopmostPlatformWindowUnderMouse()Lsun/lwawt/macosx/CPlatformWindow;"
-    )]
     async fn test_native_get_topmost_platform_window_under_mouse() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_topmost_platform_window_under_mouse(thread, Parameters::default()).await;
+        let result =
+            native_get_topmost_platform_window_under_mouse(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativeGetTopmostPlatformWindowUnderMouse",
+            "()Lsun/lwawt/macosx/CPlatformWindow;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativePushNSWindowToBack(J)V"
-    )]
     async fn test_native_push_ns_window_to_back() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_push_ns_window_to_back(thread, Parameters::default()).await;
+        let result = native_push_ns_window_to_back(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativePushNSWindowToBack",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativePushNSWindowToFront(J)V"
-    )]
     async fn test_native_push_ns_window_to_front() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_push_ns_window_to_front(thread, Parameters::default()).await;
+        let result = native_push_ns_window_to_front(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativePushNSWindowToFront",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativeRevalidateNSWindowShadow(J)V"
-    )]
     async fn test_native_revalidate_ns_window_shadow() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_revalidate_ns_window_shadow(thread, Parameters::default()).await;
+        let result = native_revalidate_ns_window_shadow(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativeRevalidateNSWindowShadow",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativeSetAllowAutomaticTabbingProperty(Z)V"
-    )]
     async fn test_native_set_allow_automatic_tabbing_property() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_allow_automatic_tabbing_property(thread, Parameters::default()).await;
+        let result =
+            native_set_allow_automatic_tabbing_property(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativeSetAllowAutomaticTabbingProperty",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativeSetEnabled(JZ)V"
-    )]
     async fn test_native_set_enabled() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_enabled(thread, Parameters::default()).await;
+        let result = native_set_enabled(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativeSetEnabled",
+            "(JZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativeSetNSWindowBounds(JDDDD)V"
-    )]
     async fn test_native_set_ns_window_bounds() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_ns_window_bounds(thread, Parameters::default()).await;
+        let result = native_set_ns_window_bounds(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativeSetNSWindowBounds",
+            "(JDDDD)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativeSetNSWindowLocationByPlatform(J)V"
-    )]
     async fn test_native_set_ns_window_location_by_platform() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_ns_window_location_by_platform(thread, Parameters::default()).await;
+        let result = native_set_ns_window_location_by_platform(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativeSetNSWindowLocationByPlatform",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativeSetNSWindowMenuBar(JJ)V"
-    )]
     async fn test_native_set_ns_window_menu_bar() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_ns_window_menu_bar(thread, Parameters::default()).await;
+        let result = native_set_ns_window_menu_bar(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativeSetNSWindowMenuBar",
+            "(JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativeSetNSWindowMinMax(JDDDD)V"
-    )]
     async fn test_native_set_ns_window_min_max() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_ns_window_min_max(thread, Parameters::default()).await;
+        let result = native_set_ns_window_min_max(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativeSetNSWindowMinMax",
+            "(JDDDD)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativeSetNSWindowMinimizedIcon(JJ)V"
-    )]
     async fn test_native_set_ns_window_minimized_icon() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_ns_window_minimized_icon(thread, Parameters::default()).await;
+        let result = native_set_ns_window_minimized_icon(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativeSetNSWindowMinimizedIcon",
+            "(JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativeSetNSWindowRepresentedFilename(JLjava/lang/String;)V"
-    )]
     async fn test_native_set_ns_window_represented_filename() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_ns_window_represented_filename(thread, Parameters::default()).await;
+        let result = native_set_ns_window_represented_filename(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativeSetNSWindowRepresentedFilename",
+            "(JLjava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativeSetNSWindowStandardFrame(JDDDD)V"
-    )]
     async fn testnative_set_ns_window_standard_frame() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_ns_window_standard_frame(thread, Parameters::default()).await;
+        let result = native_set_ns_window_standard_frame(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativeSetNSWindowStandardFrame",
+            "(JDDDD)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativeSetNSWindowStyleBits(JII)V"
-    )]
     async fn test_native_set_ns_window_style_bits() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_ns_window_style_bits(thread, Parameters::default()).await;
+        let result = native_set_ns_window_style_bits(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativeSetNSWindowStyleBits",
+            "(JII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativeSetNSWindowTitle(JLjava/lang/String;)V"
-    )]
     async fn test_native_set_ns_window_title() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_ns_window_title(thread, Parameters::default()).await;
+        let result = native_set_ns_window_title(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativeSetNSWindowTitle",
+            "(JLjava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativeSynthesizeMouseEnteredExitedEvents()V"
-    )]
     async fn test_native_synthesize_mouse_entered_exited_events_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ =
+        let result =
             native_synthesize_mouse_entered_exited_events_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativeSynthesizeMouseEnteredExitedEvents",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPlatformWindow.nativeSynthesizeMouseEnteredExitedEvents(JI)V"
-    )]
     async fn test_native_synthesize_mouse_entered_exited_events_2() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ =
+        let result =
             native_synthesize_mouse_entered_exited_events_2(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPlatformWindow",
+            "nativeSynthesizeMouseEnteredExitedEvents",
+            "(JI)V",
+        );
     }
 }
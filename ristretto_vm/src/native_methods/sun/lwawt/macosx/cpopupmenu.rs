@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -29,7 +29,7 @@ async fn native_create_popup_menu(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPopupMenu.nativeCreatePopupMenu()J")
+    unsatisfied_link(CLASS_NAME, "nativeCreatePopupMenu", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -37,7 +37,7 @@ async fn native_show_popup_menu(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPopupMenu.nativeShowPopupMenu(JII)J")
+    unsatisfied_link(CLASS_NAME, "nativeShowPopupMenu", "(JII)J")
 }
 
 #[cfg(test)]
@@ -45,20 +45,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPopupMenu.nativeCreatePopupMenu()J"
-    )]
     async fn test_native_create_popup_menu() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_create_popup_menu(thread, Parameters::default()).await;
+        let result = native_create_popup_menu(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPopupMenu",
+            "nativeCreatePopupMenu",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPopupMenu.nativeShowPopupMenu(JII)J"
-    )]
     async fn test_native_show_popup_menu() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_show_popup_menu(thread, Parameters::default()).await;
+        let result = native_show_popup_menu(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPopupMenu",
+            "nativeShowPopupMenu",
+            "(JII)J",
+        );
     }
 }
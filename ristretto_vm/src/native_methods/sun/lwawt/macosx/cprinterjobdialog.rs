@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -15,7 +15,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn show_dialog(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPrinterJobDialog.showDialog()Z")
+    unsatisfied_link(CLASS_NAME, "showDialog", "()Z")
 }
 
 #[cfg(test)]
@@ -23,11 +23,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPrinterJobDialog.showDialog()Z"
-    )]
     async fn test_show_dialog() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = show_dialog(thread, Parameters::default()).await;
+        let result = show_dialog(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPrinterJobDialog",
+            "showDialog",
+            "()Z",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -21,12 +21,16 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn flush(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPrinterSurfaceData._flush()V")
+    unsatisfied_link(CLASS_NAME, "_flush", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn init_ops(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CPrinterSurfaceData.initOps(JLjava/nio/ByteBuffer;[Ljava/lang/Object;II)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "initOps",
+        "(JLjava/nio/ByteBuffer;[Ljava/lang/Object;II)V",
+    )
 }
 
 #[cfg(test)]
@@ -34,20 +38,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPrinterSurfaceData._flush()V"
-    )]
     async fn test_flush() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = flush(thread, Parameters::default()).await;
+        let result = flush(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPrinterSurfaceData",
+            "_flush",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CPrinterSurfaceData.initOps(JLjava/nio/ByteBuffer;[Ljava/lang/Object;II)V"
-    )]
     async fn test_init_ops() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_ops(thread, Parameters::default()).await;
+        let result = init_ops(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CPrinterSurfaceData",
+            "initOps",
+            "(JLjava/nio/ByteBuffer;[Ljava/lang/Object;II)V",
+        );
     }
 }
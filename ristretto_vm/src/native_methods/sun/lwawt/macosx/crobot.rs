@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -35,22 +35,22 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn init_robot(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CRobot.initRobot()V")
+    unsatisfied_link(CLASS_NAME, "initRobot", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn key_event(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CRobot.keyEvent(IZ)V")
+    unsatisfied_link(CLASS_NAME, "keyEvent", "(IZ)V")
 }
 
 #[async_recursion(?Send)]
 async fn mouse_event(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CRobot.mouseEvent(IIIIZZ)V")
+    unsatisfied_link(CLASS_NAME, "mouseEvent", "(IIIIZZ)V")
 }
 
 #[async_recursion(?Send)]
 async fn mouse_wheel(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CRobot.mouseWheel(I)V")
+    unsatisfied_link(CLASS_NAME, "mouseWheel", "(I)V")
 }
 
 #[async_recursion(?Send)]
@@ -58,7 +58,7 @@ async fn native_get_screen_pixels(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CRobot.nativeGetScreenPixels(IIII[I)V")
+    unsatisfied_link(CLASS_NAME, "nativeGetScreenPixels", "(IIII[I)V")
 }
 
 #[cfg(test)]
@@ -66,39 +66,57 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.CRobot.initRobot()V")]
     async fn test_init_robot() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_robot(thread, Parameters::default()).await;
+        let result = init_robot(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/lwawt/macosx/CRobot", "initRobot", "()V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.CRobot.keyEvent(IZ)V")]
     async fn test_key_event() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = key_event(thread, Parameters::default()).await;
+        let result = key_event(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CRobot",
+            "keyEvent",
+            "(IZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.CRobot.mouseEvent(IIIIZZ)V")]
     async fn test_mouse_event() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = mouse_event(thread, Parameters::default()).await;
+        let result = mouse_event(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CRobot",
+            "mouseEvent",
+            "(IIIIZZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.CRobot.mouseWheel(I)V")]
     async fn test_mouse_wheel() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = mouse_wheel(thread, Parameters::default()).await;
+        let result = mouse_wheel(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CRobot",
+            "mouseWheel",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CRobot.nativeGetScreenPixels(IIII[I)V"
-    )]
     async fn test_native_get_screen_pixels() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_screen_pixels(thread, Parameters::default()).await;
+        let result = native_get_screen_pixels(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CRobot",
+            "nativeGetScreenPixels",
+            "(IIII[I)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -42,7 +42,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn native_create(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CTrayIcon.nativeCreate()J")
+    unsatisfied_link(CLASS_NAME, "nativeCreate", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -50,7 +50,11 @@ async fn native_get_icon_location(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CTrayIcon.nativeGetIconLocation(J)Ljava/awt/geom/Point2D;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeGetIconLocation",
+        "(J)Ljava/awt/geom/Point2D;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -58,7 +62,7 @@ async fn native_set_tool_tip(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CTrayIcon.nativeSetToolTip(JLjava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "nativeSetToolTip", "(JLjava/lang/String;)V")
 }
 
 #[async_recursion(?Send)]
@@ -66,12 +70,16 @@ async fn native_show_notification(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CTrayIcon.nativeShowNotification(JLjava/lang/String;Ljava/lang/String;J)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nativeShowNotification",
+        "(JLjava/lang/String;Ljava/lang/String;J)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn set_native_image(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CTrayIcon.setNativeImage(JJZ)V")
+    unsatisfied_link(CLASS_NAME, "setNativeImage", "(JJZ)V")
 }
 
 #[cfg(test)]
@@ -79,45 +87,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.CTrayIcon.nativeCreate()J")]
     async fn test_native_create() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_create(thread, Parameters::default()).await;
+        let result = native_create(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CTrayIcon",
+            "nativeCreate",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CTrayIcon.nativeGetIconLocation(J)Ljava/awt/geom/Point2D;"
-    )]
     async fn test_native_get_icon_location() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_get_icon_location(thread, Parameters::default()).await;
+        let result = native_get_icon_location(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CTrayIcon",
+            "nativeGetIconLocation",
+            "(J)Ljava/awt/geom/Point2D;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CTrayIcon.nativeSetToolTip(JLjava/lang/String;)V"
-    )]
     async fn test_native_set_tool_tip() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_set_tool_tip(thread, Parameters::default()).await;
+        let result = native_set_tool_tip(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CTrayIcon",
+            "nativeSetToolTip",
+            "(JLjava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CTrayIcon.nativeShowNotification(JLjava/lang/String;Ljava/lang/String;J)V"
-    )]
     async fn test_native_show_notification() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_show_notification(thread, Parameters::default()).await;
+        let result = native_show_notification(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CTrayIcon",
+            "nativeShowNotification",
+            "(JLjava/lang/String;Ljava/lang/String;J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CTrayIcon.setNativeImage(JJZ)V"
-    )]
     async fn test_set_native_image() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_native_image(thread, Parameters::default()).await;
+        let result = set_native_image(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CTrayIcon",
+            "setNativeImage",
+            "(JJZ)V",
+        );
     }
 }
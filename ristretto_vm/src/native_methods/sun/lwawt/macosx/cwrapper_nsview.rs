@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -30,7 +30,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn add_subview(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSView.addSubview(JJ)V")
+    unsatisfied_link(CLASS_NAME, "addSubview", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
@@ -38,27 +38,27 @@ async fn remove_from_superview(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSView.removeFromSuperview(J)V")
+    unsatisfied_link(CLASS_NAME, "removeFromSuperview", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_frame(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSView.setFrame(JIIII)V")
+    unsatisfied_link(CLASS_NAME, "setFrame", "(JIIII)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_hidden(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSView.setHidden(JZ)V")
+    unsatisfied_link(CLASS_NAME, "setHidden", "(JZ)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_tool_tip(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSView.setToolTip(JLjava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "setToolTip", "(JLjava/lang/String;)V")
 }
 
 #[async_recursion(?Send)]
 async fn window(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSView.window(J)J")
+    unsatisfied_link(CLASS_NAME, "window", "(J)J")
 }
 
 #[cfg(test)]
@@ -66,54 +66,74 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSView.addSubview(JJ)V"
-    )]
     async fn test_add_subview() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = add_subview(thread, Parameters::default()).await;
+        let result = add_subview(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSView",
+            "addSubview",
+            "(JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSView.removeFromSuperview(J)V"
-    )]
     async fn test_remove_from_superview() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = remove_from_superview(thread, Parameters::default()).await;
+        let result = remove_from_superview(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSView",
+            "removeFromSuperview",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSView.setFrame(JIIII)V"
-    )]
     async fn test_set_frame() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_frame(thread, Parameters::default()).await;
+        let result = set_frame(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSView",
+            "setFrame",
+            "(JIIII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSView.setHidden(JZ)V"
-    )]
     async fn test_set_hidden() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_hidden(thread, Parameters::default()).await;
+        let result = set_hidden(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSView",
+            "setHidden",
+            "(JZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSView.setToolTip(JLjava/lang/String;)V"
-    )]
     async fn test_set_tool_tip() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_tool_tip(thread, Parameters::default()).await;
+        let result = set_tool_tip(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSView",
+            "setToolTip",
+            "(JLjava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSView.window(J)J")]
     async fn test_window() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = window(thread, Parameters::default()).await;
+        let result = window(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSView",
+            "window",
+            "(J)J",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -65,7 +65,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn add_child_window(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.addChildWindow(JJI)V")
+    unsatisfied_link(CLASS_NAME, "addChildWindow", "(JJI)V")
 }
 
 #[async_recursion(?Send)]
@@ -73,27 +73,27 @@ async fn can_become_main_window(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.canBecomeMainWindow(J)Z")
+    unsatisfied_link(CLASS_NAME, "canBecomeMainWindow", "(J)Z")
 }
 
 #[async_recursion(?Send)]
 async fn close(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.close(J)V")
+    unsatisfied_link(CLASS_NAME, "close", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn deminiaturize(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.deminiaturize(J)V")
+    unsatisfied_link(CLASS_NAME, "deminiaturize", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn is_key_window(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.isKeyWindow(J)Z")
+    unsatisfied_link(CLASS_NAME, "isKeyWindow", "(J)Z")
 }
 
 #[async_recursion(?Send)]
 async fn is_zoomed(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.isZoomed(J)Z")
+    unsatisfied_link(CLASS_NAME, "isZoomed", "(J)Z")
 }
 
 #[async_recursion(?Send)]
@@ -101,7 +101,7 @@ async fn make_first_responder(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.makeFirstResponder(JJ)V")
+    unsatisfied_link(CLASS_NAME, "makeFirstResponder", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
@@ -109,27 +109,27 @@ async fn make_key_and_order_front(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.makeKeyAndOrderFront(J)V")
+    unsatisfied_link(CLASS_NAME, "makeKeyAndOrderFront", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn make_key_window(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.makeKeyWindow(J)V")
+    unsatisfied_link(CLASS_NAME, "makeKeyWindow", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn make_main_window(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.makeMainWindow(J)V")
+    unsatisfied_link(CLASS_NAME, "makeMainWindow", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn miniaturize(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.miniaturize(J)V")
+    unsatisfied_link(CLASS_NAME, "miniaturize", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn order_front(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.orderFront(J)V")
+    unsatisfied_link(CLASS_NAME, "orderFront", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -137,17 +137,17 @@ async fn order_front_regardless(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.orderFrontRegardless(J)V")
+    unsatisfied_link(CLASS_NAME, "orderFrontRegardless", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn order_out(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.orderOut(J)V")
+    unsatisfied_link(CLASS_NAME, "orderOut", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn order_window(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.orderWindow(JIJ)V")
+    unsatisfied_link(CLASS_NAME, "orderWindow", "(JIJ)V")
 }
 
 #[async_recursion(?Send)]
@@ -155,12 +155,12 @@ async fn remove_child_window(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.removeChildWindow(JJ)V")
+    unsatisfied_link(CLASS_NAME, "removeChildWindow", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_alpha_value(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.setAlphaValue(JF)V")
+    unsatisfied_link(CLASS_NAME, "setAlphaValue", "(JF)V")
 }
 
 #[async_recursion(?Send)]
@@ -168,22 +168,22 @@ async fn set_background_color(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.setBackgroundColor(JI)V")
+    unsatisfied_link(CLASS_NAME, "setBackgroundColor", "(JI)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_level(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.setLevel(JI)V")
+    unsatisfied_link(CLASS_NAME, "setLevel", "(JI)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_opaque(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.setOpaque(JZ)V")
+    unsatisfied_link(CLASS_NAME, "setOpaque", "(JZ)V")
 }
 
 #[async_recursion(?Send)]
 async fn zoom(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.CWrapper$NSWindow.zoom(J)V")
+    unsatisfied_link(CLASS_NAME, "zoom", "(J)V")
 }
 
 #[cfg(test)]
@@ -191,187 +191,254 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.addChildWindow(JJI)V"
-    )]
     async fn test_add_child_window() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = add_child_window(thread, Parameters::default()).await;
+        let result = add_child_window(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "addChildWindow",
+            "(JJI)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.canBecomeMainWindow(J)Z"
-    )]
     async fn test_can_become_main_window() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = can_become_main_window(thread, Parameters::default()).await;
+        let result = can_become_main_window(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "canBecomeMainWindow",
+            "(J)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.close(J)V")]
     async fn test_close() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = close(thread, Parameters::default()).await;
+        let result = close(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "close",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.deminiaturize(J)V"
-    )]
     async fn test_deminiaturize() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = deminiaturize(thread, Parameters::default()).await;
+        let result = deminiaturize(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "deminiaturize",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.isKeyWindow(J)Z"
-    )]
     async fn test_is_key_window() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_key_window(thread, Parameters::default()).await;
+        let result = is_key_window(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "isKeyWindow",
+            "(J)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.isZoomed(J)Z"
-    )]
     async fn test_is_zoomed() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_zoomed(thread, Parameters::default()).await;
+        let result = is_zoomed(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "isZoomed",
+            "(J)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.makeFirstResponder(JJ)V"
-    )]
     async fn test_make_first_responder() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = make_first_responder(thread, Parameters::default()).await;
+        let result = make_first_responder(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "makeFirstResponder",
+            "(JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.makeKeyAndOrderFront(J)V"
-    )]
     async fn test_make_key_and_order_front() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = make_key_and_order_front(thread, Parameters::default()).await;
+        let result = make_key_and_order_front(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "makeKeyAndOrderFront",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.makeKeyWindow(J)V"
-    )]
     async fn test_make_key_window() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = make_key_window(thread, Parameters::default()).await;
+        let result = make_key_window(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "makeKeyWindow",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.makeMainWindow(J)V"
-    )]
     async fn test_make_main_window() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = make_main_window(thread, Parameters::default()).await;
+        let result = make_main_window(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "makeMainWindow",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.miniaturize(J)V"
-    )]
     async fn test_miniaturize() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = miniaturize(thread, Parameters::default()).await;
+        let result = miniaturize(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "miniaturize",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.orderFront(J)V"
-    )]
     async fn test_order_front() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = order_front(thread, Parameters::default()).await;
+        let result = order_front(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "orderFront",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.orderFrontRegardless(J)V"
-    )]
     async fn test_order_front_regardless() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = order_front_regardless(thread, Parameters::default()).await;
+        let result = order_front_regardless(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "orderFrontRegardless",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.orderOut(J)V"
-    )]
     async fn test_order_out() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = order_out(thread, Parameters::default()).await;
+        let result = order_out(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "orderOut",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.orderWindow(JIJ)V"
-    )]
     async fn test_order_window() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = order_window(thread, Parameters::default()).await;
+        let result = order_window(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "orderWindow",
+            "(JIJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.removeChildWindow(JJ)V"
-    )]
     async fn test_remove_child_window() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = remove_child_window(thread, Parameters::default()).await;
+        let result = remove_child_window(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "removeChildWindow",
+            "(JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.setAlphaValue(JF)V"
-    )]
     async fn test_set_alpha_value() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_alpha_value(thread, Parameters::default()).await;
+        let result = set_alpha_value(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "setAlphaValue",
+            "(JF)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.setBackgroundColor(JI)V"
-    )]
     async fn test_set_background_color() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_background_color(thread, Parameters::default()).await;
+        let result = set_background_color(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "setBackgroundColor",
+            "(JI)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.setLevel(JI)V"
-    )]
     async fn test_set_level() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_level(thread, Parameters::default()).await;
+        let result = set_level(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "setLevel",
+            "(JI)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.setOpaque(JZ)V"
-    )]
     async fn test_set_opaque() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_opaque(thread, Parameters::default()).await;
+        let result = set_opaque(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "setOpaque",
+            "(JZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.CWrapper$NSWindow.zoom(J)V")]
     async fn test_zoom() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = zoom(thread, Parameters::default()).await;
+        let result = zoom(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/CWrapper$NSWindow",
+            "zoom",
+            "(J)V",
+        );
     }
 }
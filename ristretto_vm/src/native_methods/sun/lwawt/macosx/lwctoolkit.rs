@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -81,12 +81,12 @@ async fn activate_application_ignoring_other_apps(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.LWCToolkit.activateApplicationIgnoringOtherApps()V")
+    unsatisfied_link(CLASS_NAME, "activateApplicationIgnoringOtherApps", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn beep(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.LWCToolkit.beep()V")
+    unsatisfied_link(CLASS_NAME, "beep", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -94,7 +94,7 @@ async fn create_awt_run_loop_mediator(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.LWCToolkit.createAWTRunLoopMediator()J")
+    unsatisfied_link(CLASS_NAME, "createAWTRunLoopMediator", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -102,7 +102,7 @@ async fn do_awt_run_loop_impl(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.LWCToolkit.doAWTRunLoopImpl(JZZ)V")
+    unsatisfied_link(CLASS_NAME, "doAWTRunLoopImpl", "(JZZ)V")
 }
 
 #[async_recursion(?Send)]
@@ -110,7 +110,7 @@ async fn flush_native_selectors(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.LWCToolkit.flushNativeSelectors()V")
+    unsatisfied_link(CLASS_NAME, "flushNativeSelectors", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -118,12 +118,12 @@ async fn get_multi_click_time(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.LWCToolkit.getMultiClickTime()I")
+    unsatisfied_link(CLASS_NAME, "getMultiClickTime", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn init_appkit(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.LWCToolkit.initAppkit(Ljava/lang/ThreadGroup;Z)V")
+    unsatisfied_link(CLASS_NAME, "initAppkit", "(Ljava/lang/ThreadGroup;Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -136,17 +136,17 @@ async fn is_application_active(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.LWCToolkit.isApplicationActive()Z")
+    unsatisfied_link(CLASS_NAME, "isApplicationActive", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn is_caps_lock_on(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.LWCToolkit.isCapsLockOn()Z")
+    unsatisfied_link(CLASS_NAME, "isCapsLockOn", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn is_embedded(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.LWCToolkit.isEmbedded()Z")
+    unsatisfied_link(CLASS_NAME, "isEmbedded", "()Z")
 }
 
 #[async_recursion(?Send)]
@@ -154,7 +154,7 @@ async fn is_in_aqua_session(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.LWCToolkit.isInAquaSession()Z")
+    unsatisfied_link(CLASS_NAME, "isInAquaSession", "()Z")
 }
 
 #[async_recursion(?Send)]
@@ -162,12 +162,12 @@ async fn load_native_colors(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.LWCToolkit.loadNativeColors([I[I)V")
+    unsatisfied_link(CLASS_NAME, "loadNativeColors", "([I[I)V")
 }
 
 #[async_recursion(?Send)]
 async fn native_sync_queue(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.LWCToolkit.nativeSyncQueue(J)Z")
+    unsatisfied_link(CLASS_NAME, "nativeSyncQueue", "(J)Z")
 }
 
 #[async_recursion(?Send)]
@@ -175,12 +175,16 @@ async fn perform_on_main_thread_after_delay(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.LWCToolkit.performOnMainThreadAfterDelay(Ljava/lang/Runnable;J)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "performOnMainThreadAfterDelay",
+        "(Ljava/lang/Runnable;J)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn stop_awt_run_loop(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.LWCToolkit.stopAWTRunLoop(J)V")
+    unsatisfied_link(CLASS_NAME, "stopAWTRunLoop", "(J)V")
 }
 
 #[cfg(test)]
@@ -188,64 +192,82 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.LWCToolkit.activateApplicationIgnoringOtherApps()V"
-    )]
     async fn test_activate_application_ignoring_other_apps() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = activate_application_ignoring_other_apps(thread, Parameters::default()).await;
+        let result = activate_application_ignoring_other_apps(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/LWCToolkit",
+            "activateApplicationIgnoringOtherApps",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.LWCToolkit.beep()V")]
     async fn test_beep() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = beep(thread, Parameters::default()).await;
+        let result = beep(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/lwawt/macosx/LWCToolkit", "beep", "()V");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.LWCToolkit.createAWTRunLoopMediator()J"
-    )]
     async fn test_create_awt_run_loop_mediator() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_awt_run_loop_mediator(thread, Parameters::default()).await;
+        let result = create_awt_run_loop_mediator(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/LWCToolkit",
+            "createAWTRunLoopMediator",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.LWCToolkit.doAWTRunLoopImpl(JZZ)V"
-    )]
     async fn test_do_awt_run_loop_impl() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = do_awt_run_loop_impl(thread, Parameters::default()).await;
+        let result = do_awt_run_loop_impl(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/LWCToolkit",
+            "doAWTRunLoopImpl",
+            "(JZZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.LWCToolkit.flushNativeSelectors()V"
-    )]
     async fn test_flush_native_selectors() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = flush_native_selectors(thread, Parameters::default()).await;
+        let result = flush_native_selectors(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/LWCToolkit",
+            "flushNativeSelectors",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.LWCToolkit.getMultiClickTime()I"
-    )]
     async fn test_get_multi_click_time() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_multi_click_time(thread, Parameters::default()).await;
+        let result = get_multi_click_time(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/LWCToolkit",
+            "getMultiClickTime",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.LWCToolkit.initAppkit(Ljava/lang/ThreadGroup;Z)V"
-    )]
     async fn test_init_appkit() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_appkit(thread, Parameters::default()).await;
+        let result = init_appkit(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/LWCToolkit",
+            "initAppkit",
+            "(Ljava/lang/ThreadGroup;Z)V",
+        );
     }
 
     #[tokio::test]
@@ -257,70 +279,98 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.LWCToolkit.isApplicationActive()Z"
-    )]
     async fn test_is_application_active() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_application_active(thread, Parameters::default()).await;
+        let result = is_application_active(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/LWCToolkit",
+            "isApplicationActive",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.LWCToolkit.isCapsLockOn()Z")]
     async fn test_is_caps_lock_on() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_caps_lock_on(thread, Parameters::default()).await;
+        let result = is_caps_lock_on(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/LWCToolkit",
+            "isCapsLockOn",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.LWCToolkit.isEmbedded()Z")]
     async fn test_is_embedded() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_embedded(thread, Parameters::default()).await;
+        let result = is_embedded(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/LWCToolkit",
+            "isEmbedded",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.LWCToolkit.isInAquaSession()Z"
-    )]
     async fn test_is_in_aqua_session() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_in_aqua_session(thread, Parameters::default()).await;
+        let result = is_in_aqua_session(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/LWCToolkit",
+            "isInAquaSession",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.LWCToolkit.loadNativeColors([I[I)V"
-    )]
     async fn test_load_native_colors() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = load_native_colors(thread, Parameters::default()).await;
+        let result = load_native_colors(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/LWCToolkit",
+            "loadNativeColors",
+            "([I[I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.LWCToolkit.nativeSyncQueue(J)Z"
-    )]
     async fn test_native_sync_queue() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = native_sync_queue(thread, Parameters::default()).await;
+        let result = native_sync_queue(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/LWCToolkit",
+            "nativeSyncQueue",
+            "(J)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.LWCToolkit.performOnMainThreadAfterDelay(Ljava/lang/Runnable;J)V"
-    )]
     async fn test_perform_on_main_thread_after_delay() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = perform_on_main_thread_after_delay(thread, Parameters::default()).await;
+        let result = perform_on_main_thread_after_delay(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/LWCToolkit",
+            "performOnMainThreadAfterDelay",
+            "(Ljava/lang/Runnable;J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.LWCToolkit.stopAWTRunLoop(J)V"
-    )]
     async fn test_stop_awt_run_loop() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = stop_awt_run_loop(thread, Parameters::default()).await;
+        let result = stop_awt_run_loop(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/LWCToolkit",
+            "stopAWTRunLoop",
+            "(J)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -53,12 +53,12 @@ async fn ns_key_modifiers_to_java_key_info(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.NSEvent.nsKeyModifiersToJavaKeyInfo([I[I)V")
+    unsatisfied_link(CLASS_NAME, "nsKeyModifiersToJavaKeyInfo", "([I[I)V")
 }
 
 #[async_recursion(?Send)]
 async fn ns_to_java_char(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.NSEvent.nsToJavaChar(CIZ)C")
+    unsatisfied_link(CLASS_NAME, "nsToJavaChar", "(CIZ)C")
 }
 
 #[async_recursion(?Send)]
@@ -66,7 +66,7 @@ async fn ns_to_java_key_info(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.NSEvent.nsToJavaKeyInfo([I[I)Z")
+    unsatisfied_link(CLASS_NAME, "nsToJavaKeyInfo", "([I[I)Z")
 }
 
 #[async_recursion(?Send)]
@@ -74,7 +74,7 @@ async fn ns_to_java_key_modifiers(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.NSEvent.nsToJavaKeyModifiers(I)I")
+    unsatisfied_link(CLASS_NAME, "nsToJavaKeyModifiers", "(I)I")
 }
 
 #[async_recursion(?Send)]
@@ -82,7 +82,7 @@ async fn ns_to_java_modifiers(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.NSEvent.nsToJavaModifiers(I)I")
+    unsatisfied_link(CLASS_NAME, "nsToJavaModifiers", "(I)I")
 }
 
 #[async_recursion(?Send)]
@@ -90,7 +90,7 @@ async fn ns_to_java_mouse_modifiers(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.lwawt.macosx.NSEvent.nsToJavaMouseModifiers(II)I")
+    unsatisfied_link(CLASS_NAME, "nsToJavaMouseModifiers", "(II)I")
 }
 
 #[cfg(test)]
@@ -98,54 +98,74 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.NSEvent.nsKeyModifiersToJavaKeyInfo([I[I)V"
-    )]
     async fn test_ns_key_modifiers_to_java_key_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ns_key_modifiers_to_java_key_info(thread, Parameters::default()).await;
+        let result = ns_key_modifiers_to_java_key_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/NSEvent",
+            "nsKeyModifiersToJavaKeyInfo",
+            "([I[I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.lwawt.macosx.NSEvent.nsToJavaChar(CIZ)C")]
     async fn test_ns_to_java_char() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ns_to_java_char(thread, Parameters::default()).await;
+        let result = ns_to_java_char(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/NSEvent",
+            "nsToJavaChar",
+            "(CIZ)C",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.NSEvent.nsToJavaKeyInfo([I[I)Z"
-    )]
     async fn test_ns_to_java_key_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ns_to_java_key_info(thread, Parameters::default()).await;
+        let result = ns_to_java_key_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/NSEvent",
+            "nsToJavaKeyInfo",
+            "([I[I)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.NSEvent.nsToJavaKeyModifiers(I)I"
-    )]
     async fn test_ns_to_java_key_modifiers() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ns_to_java_key_modifiers(thread, Parameters::default()).await;
+        let result = ns_to_java_key_modifiers(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/NSEvent",
+            "nsToJavaKeyModifiers",
+            "(I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.NSEvent.nsToJavaModifiers(I)I"
-    )]
     async fn test_ns_to_java_modifiers() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ns_to_java_modifiers(thread, Parameters::default()).await;
+        let result = ns_to_java_modifiers(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/NSEvent",
+            "nsToJavaModifiers",
+            "(I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.lwawt.macosx.NSEvent.nsToJavaMouseModifiers(II)I"
-    )]
     async fn test_ns_to_java_mouse_modifiers() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ns_to_java_mouse_modifiers(thread, Parameters::default()).await;
+        let result = ns_to_java_mouse_modifiers(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/lwawt/macosx/NSEvent",
+            "nsToJavaMouseModifiers",
+            "(II)I",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -15,7 +15,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn set_verbose_class(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.management.ClassLoadingImpl.setVerboseClass(Z)V")
+    unsatisfied_link(CLASS_NAME, "setVerboseClass", "(Z)V")
 }
 
 #[cfg(test)]
@@ -23,11 +23,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.ClassLoadingImpl.setVerboseClass(Z)V"
-    )]
     async fn test_set_verbose_class() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_verbose_class(thread, Parameters::default()).await;
+        let result = set_verbose_class(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/ClassLoadingImpl",
+            "setVerboseClass",
+            "(Z)V",
+        );
     }
 }
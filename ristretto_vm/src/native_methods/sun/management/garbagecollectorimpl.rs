@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -33,7 +33,7 @@ async fn get_collection_count(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.GarbageCollectorImpl.getCollectionCount()J")
+    unsatisfied_link(CLASS_NAME, "getCollectionCount", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -41,7 +41,7 @@ async fn get_collection_time(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.GarbageCollectorImpl.getCollectionTime()J")
+    unsatisfied_link(CLASS_NAME, "getCollectionTime", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -49,7 +49,11 @@ async fn set_notification_enabled(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.GarbageCollectorImpl.setNotificationEnabled(Lcom/sun/management/GarbageCollectorMXBean;Z)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "setNotificationEnabled",
+        "(Lcom/sun/management/GarbageCollectorMXBean;Z)V",
+    )
 }
 
 #[cfg(test)]
@@ -57,29 +61,38 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.GarbageCollectorImpl.getCollectionCount()J"
-    )]
     async fn test_get_collection_count() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_collection_count(thread, Parameters::default()).await;
+        let result = get_collection_count(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/GarbageCollectorImpl",
+            "getCollectionCount",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.GarbageCollectorImpl.getCollectionTime()J"
-    )]
     async fn test_get_collection_time() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_collection_time(thread, Parameters::default()).await;
+        let result = get_collection_time(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/GarbageCollectorImpl",
+            "getCollectionTime",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.GarbageCollectorImpl.setNotificationEnabled(Lcom/sun/management/GarbageCollectorMXBean;Z)V"
-    )]
     async fn test_set_notification_enabled() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_notification_enabled(thread, Parameters::default()).await;
+        let result = set_notification_enabled(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/GarbageCollectorImpl",
+            "setNotificationEnabled",
+            "(Lcom/sun/management/GarbageCollectorMXBean;Z)V",
+        );
     }
 }
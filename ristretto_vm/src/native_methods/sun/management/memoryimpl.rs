@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -36,8 +36,10 @@ async fn get_memory_managers_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!(
-        "sun.management.MemoryImpl.getMemoryManagers0()[Ljava/lang/management/MemoryManagerMXBean;"
+    unsatisfied_link(
+        CLASS_NAME,
+        "getMemoryManagers0",
+        "()[Ljava/lang/management/MemoryManagerMXBean;",
     )
 }
 
@@ -46,7 +48,11 @@ async fn get_memory_pools_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.MemoryImpl.getMemoryPools0()[Ljava/lang/management/MemoryPoolMXBean;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getMemoryPools0",
+        "()[Ljava/lang/management/MemoryPoolMXBean;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -54,12 +60,16 @@ async fn get_memory_usage_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.MemoryImpl.getMemoryUsage0(Z)Ljava/lang/management/MemoryUsage;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getMemoryUsage0",
+        "(Z)Ljava/lang/management/MemoryUsage;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn set_verbose_gc(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.management.MemoryImpl.setVerboseGC(Z)V")
+    unsatisfied_link(CLASS_NAME, "setVerboseGC", "(Z)V")
 }
 
 #[cfg(test)]
@@ -67,36 +77,50 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.MemoryImpl.getMemoryManagers0()[Ljava/lang/management/MemoryManagerMXBean;"
-    )]
     async fn test_get_memory_managers_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_memory_managers_0(thread, Parameters::default()).await;
+        let result = get_memory_managers_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/MemoryImpl",
+            "getMemoryManagers0",
+            "()[Ljava/lang/management/MemoryManagerMXBean;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.MemoryImpl.getMemoryPools0()[Ljava/lang/management/MemoryPoolMXBean;"
-    )]
     async fn test_get_memory_pools_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_memory_pools_0(thread, Parameters::default()).await;
+        let result = get_memory_pools_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/MemoryImpl",
+            "getMemoryPools0",
+            "()[Ljava/lang/management/MemoryPoolMXBean;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.MemoryImpl.getMemoryUsage0(Z)Ljava/lang/management/MemoryUsage;"
-    )]
     async fn test_get_memory_usage_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_memory_usage_0(thread, Parameters::default()).await;
+        let result = get_memory_usage_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/MemoryImpl",
+            "getMemoryUsage0",
+            "(Z)Ljava/lang/management/MemoryUsage;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.management.MemoryImpl.setVerboseGC(Z)V")]
     async fn test_set_verbose_gc() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_verbose_gc(thread, Parameters::default()).await;
+        let result = set_verbose_gc(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/MemoryImpl",
+            "setVerboseGC",
+            "(Z)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -66,7 +66,11 @@ async fn get_collection_usage_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.MemoryPoolImpl.getCollectionUsage0()Ljava/lang/management/MemoryUsage;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getCollectionUsage0",
+        "()Ljava/lang/management/MemoryUsage;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -74,17 +78,29 @@ async fn get_memory_managers_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.MemoryPoolImpl.getMemoryManagers0()[Ljava/lang/management/MemoryManagerMXBean;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getMemoryManagers0",
+        "()[Ljava/lang/management/MemoryManagerMXBean;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_peak_usage_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.management.MemoryPoolImpl.getPeakUsage0()Ljava/lang/management/MemoryUsage;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getPeakUsage0",
+        "()Ljava/lang/management/MemoryUsage;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_usage_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.management.MemoryPoolImpl.getUsage0()Ljava/lang/management/MemoryUsage;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getUsage0",
+        "()Ljava/lang/management/MemoryUsage;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -92,7 +108,7 @@ async fn reset_peak_usage_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.MemoryPoolImpl.resetPeakUsage0()V")
+    unsatisfied_link(CLASS_NAME, "resetPeakUsage0", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -100,7 +116,7 @@ async fn set_collection_threshold_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.MemoryPoolImpl.setCollectionThreshold0(JJ)V")
+    unsatisfied_link(CLASS_NAME, "setCollectionThreshold0", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
@@ -108,7 +124,11 @@ async fn set_pool_collection_sensor(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.MemoryPoolImpl.setPoolCollectionSensor(Lsun/management/Sensor;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "setPoolCollectionSensor",
+        "(Lsun/management/Sensor;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -116,7 +136,11 @@ async fn set_pool_usage_sensor(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.MemoryPoolImpl.setPoolUsageSensor(Lsun/management/Sensor;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "setPoolUsageSensor",
+        "(Lsun/management/Sensor;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -124,7 +148,7 @@ async fn set_usage_threshold_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.MemoryPoolImpl.setUsageThreshold0(JJ)V")
+    unsatisfied_link(CLASS_NAME, "setUsageThreshold0", "(JJ)V")
 }
 
 #[cfg(test)]
@@ -132,83 +156,110 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.MemoryPoolImpl.getCollectionUsage0()Ljava/lang/management/MemoryUsage;"
-    )]
     async fn test_get_collection_usage_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_collection_usage_0(thread, Parameters::default()).await;
+        let result = get_collection_usage_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/MemoryPoolImpl",
+            "getCollectionUsage0",
+            "()Ljava/lang/management/MemoryUsage;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.MemoryPoolImpl.getMemoryManagers0()[Ljava/lang/management/MemoryManagerMXBean;"
-    )]
     async fn test_get_memory_managers_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_memory_managers_0(thread, Parameters::default()).await;
+        let result = get_memory_managers_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/MemoryPoolImpl",
+            "getMemoryManagers0",
+            "()[Ljava/lang/management/MemoryManagerMXBean;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.MemoryPoolImpl.getPeakUsage0()Ljava/lang/management/MemoryUsage;"
-    )]
     async fn test_get_peak_usage_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_peak_usage_0(thread, Parameters::default()).await;
+        let result = get_peak_usage_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/MemoryPoolImpl",
+            "getPeakUsage0",
+            "()Ljava/lang/management/MemoryUsage;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.MemoryPoolImpl.getUsage0()Ljava/lang/management/MemoryUsage;"
-    )]
     async fn test_get_usage_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_usage_0(thread, Parameters::default()).await;
+        let result = get_usage_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/MemoryPoolImpl",
+            "getUsage0",
+            "()Ljava/lang/management/MemoryUsage;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.MemoryPoolImpl.resetPeakUsage0()V"
-    )]
     async fn test_reset_peak_usage_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = reset_peak_usage_0(thread, Parameters::default()).await;
+        let result = reset_peak_usage_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/MemoryPoolImpl",
+            "resetPeakUsage0",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.MemoryPoolImpl.setCollectionThreshold0(JJ)V"
-    )]
     async fn test_set_collection_threshold_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_collection_threshold_0(thread, Parameters::default()).await;
+        let result = set_collection_threshold_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/MemoryPoolImpl",
+            "setCollectionThreshold0",
+            "(JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.MemoryPoolImpl.setPoolCollectionSensor(Lsun/management/Sensor;)V"
-    )]
     async fn test_set_pool_collection_sensor() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_pool_collection_sensor(thread, Parameters::default()).await;
+        let result = set_pool_collection_sensor(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/MemoryPoolImpl",
+            "setPoolCollectionSensor",
+            "(Lsun/management/Sensor;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.MemoryPoolImpl.setPoolUsageSensor(Lsun/management/Sensor;)V"
-    )]
     async fn test_set_pool_usage_sensor() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_pool_usage_sensor(thread, Parameters::default()).await;
+        let result = set_pool_usage_sensor(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/MemoryPoolImpl",
+            "setPoolUsageSensor",
+            "(Lsun/management/Sensor;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.MemoryPoolImpl.setUsageThreshold0(JJ)V"
-    )]
     async fn test_set_usage_threshold_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_usage_threshold_0(thread, Parameters::default()).await;
+        let result = set_usage_threshold_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/MemoryPoolImpl",
+            "setUsageThreshold0",
+            "(JJ)V",
+        );
     }
 }
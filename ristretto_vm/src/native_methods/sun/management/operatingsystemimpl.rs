@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -102,7 +102,7 @@ async fn get_committed_virtual_memory_size_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.OperatingSystemImpl.getCommittedVirtualMemorySize0()J")
+    unsatisfied_link(CLASS_NAME, "getCommittedVirtualMemorySize0", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -110,7 +110,7 @@ async fn get_free_physical_memory_size_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.OperatingSystemImpl.getFreePhysicalMemorySize0()J")
+    unsatisfied_link(CLASS_NAME, "getFreePhysicalMemorySize0", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -118,7 +118,7 @@ async fn get_free_swap_space_size_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.OperatingSystemImpl.getFreeSwapSpaceSize0()J")
+    unsatisfied_link(CLASS_NAME, "getFreeSwapSpaceSize0", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -126,7 +126,7 @@ async fn get_host_configured_cpu_count_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.OperatingSystemImpl.getHostConfiguredCpuCount0()I")
+    unsatisfied_link(CLASS_NAME, "getHostConfiguredCpuCount0", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -134,7 +134,7 @@ async fn get_host_online_cpu_count_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.OperatingSystemImpl.getHostOnlineCpuCount0()I")
+    unsatisfied_link(CLASS_NAME, "getHostOnlineCpuCount0", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -142,7 +142,7 @@ async fn get_host_total_cpu_ticks_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.OperatingSystemImpl.getHostTotalCpuTicks0()J")
+    unsatisfied_link(CLASS_NAME, "getHostTotalCpuTicks0", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -150,7 +150,7 @@ async fn get_max_file_descriptor_count_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.OperatingSystemImpl.getMaxFileDescriptorCount0()J")
+    unsatisfied_link(CLASS_NAME, "getMaxFileDescriptorCount0", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -158,7 +158,7 @@ async fn get_open_file_descriptor_count_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.OperatingSystemImpl.getOpenFileDescriptorCount0()J")
+    unsatisfied_link(CLASS_NAME, "getOpenFileDescriptorCount0", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -166,7 +166,7 @@ async fn get_process_cpu_load_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.OperatingSystemImpl.getProcessCpuLoad0()D")
+    unsatisfied_link(CLASS_NAME, "getProcessCpuLoad0", "()D")
 }
 
 #[async_recursion(?Send)]
@@ -174,7 +174,7 @@ async fn get_process_cpu_time_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.OperatingSystemImpl.getProcessCpuTime0()J")
+    unsatisfied_link(CLASS_NAME, "getProcessCpuTime0", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -182,7 +182,7 @@ async fn get_single_cpu_load_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.OperatingSystemImpl.getSingleCpuLoad0(I)D")
+    unsatisfied_link(CLASS_NAME, "getSingleCpuLoad0", "(I)D")
 }
 
 #[async_recursion(?Send)]
@@ -190,7 +190,7 @@ async fn get_system_cpu_load_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.OperatingSystemImpl.getSystemCpuLoad0()D")
+    unsatisfied_link(CLASS_NAME, "getSystemCpuLoad0", "()D")
 }
 
 #[async_recursion(?Send)]
@@ -198,7 +198,7 @@ async fn get_total_physical_memory_size_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.OperatingSystemImpl.getTotalPhysicalMemorySize0()J")
+    unsatisfied_link(CLASS_NAME, "getTotalPhysicalMemorySize0", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -206,12 +206,12 @@ async fn get_total_swap_space_size_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.OperatingSystemImpl.getTotalSwapSpaceSize0()J")
+    unsatisfied_link(CLASS_NAME, "getTotalSwapSpaceSize0", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn initialize_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.management.OperatingSystemImpl.initialize0()V")
+    unsatisfied_link(CLASS_NAME, "initialize0", "()V")
 }
 
 #[cfg(test)]
@@ -219,137 +219,182 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.OperatingSystemImpl.getCommittedVirtualMemorySize0()J"
-    )]
     async fn test_get_committed_virtual_memory_size_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_committed_virtual_memory_size_0(thread, Parameters::default()).await;
+        let result = get_committed_virtual_memory_size_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/OperatingSystemImpl",
+            "getCommittedVirtualMemorySize0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.OperatingSystemImpl.getFreePhysicalMemorySize0()J"
-    )]
     async fn test_get_free_physical_memory_size_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_free_physical_memory_size_0(thread, Parameters::default()).await;
+        let result = get_free_physical_memory_size_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/OperatingSystemImpl",
+            "getFreePhysicalMemorySize0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.OperatingSystemImpl.getFreeSwapSpaceSize0()J"
-    )]
     async fn test_get_free_swap_space_size_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_free_swap_space_size_0(thread, Parameters::default()).await;
+        let result = get_free_swap_space_size_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/OperatingSystemImpl",
+            "getFreeSwapSpaceSize0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.OperatingSystemImpl.getHostConfiguredCpuCount0()I"
-    )]
     async fn test_get_host_configured_cpu_count_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_host_configured_cpu_count_0(thread, Parameters::default()).await;
+        let result = get_host_configured_cpu_count_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/OperatingSystemImpl",
+            "getHostConfiguredCpuCount0",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.OperatingSystemImpl.getHostOnlineCpuCount0()I"
-    )]
     async fn test_get_host_online_cpu_count_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_host_online_cpu_count_0(thread, Parameters::default()).await;
+        let result = get_host_online_cpu_count_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/OperatingSystemImpl",
+            "getHostOnlineCpuCount0",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.OperatingSystemImpl.getHostTotalCpuTicks0()J"
-    )]
     async fn test_get_host_total_cpu_ticks_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_host_total_cpu_ticks_0(thread, Parameters::default()).await;
+        let result = get_host_total_cpu_ticks_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/OperatingSystemImpl",
+            "getHostTotalCpuTicks0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.OperatingSystemImpl.getMaxFileDescriptorCount0()J"
-    )]
     async fn test_get_max_file_descriptor_count_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_max_file_descriptor_count_0(thread, Parameters::default()).await;
+        let result = get_max_file_descriptor_count_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/OperatingSystemImpl",
+            "getMaxFileDescriptorCount0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.OperatingSystemImpl.getOpenFileDescriptorCount0()J"
-    )]
     async fn test_get_open_file_descriptor_count_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_open_file_descriptor_count_0(thread, Parameters::default()).await;
+        let result = get_open_file_descriptor_count_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/OperatingSystemImpl",
+            "getOpenFileDescriptorCount0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.OperatingSystemImpl.getProcessCpuLoad0()D"
-    )]
     async fn test_get_process_cpu_load_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_process_cpu_load_0(thread, Parameters::default()).await;
+        let result = get_process_cpu_load_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/OperatingSystemImpl",
+            "getProcessCpuLoad0",
+            "()D",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.OperatingSystemImpl.getProcessCpuTime0()J"
-    )]
     async fn test_get_process_cpu_time_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_process_cpu_time_0(thread, Parameters::default()).await;
+        let result = get_process_cpu_time_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/OperatingSystemImpl",
+            "getProcessCpuTime0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.OperatingSystemImpl.getSingleCpuLoad0(I)D"
-    )]
     async fn test_get_single_cpu_load_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_single_cpu_load_0(thread, Parameters::default()).await;
+        let result = get_single_cpu_load_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/OperatingSystemImpl",
+            "getSingleCpuLoad0",
+            "(I)D",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.OperatingSystemImpl.getSystemCpuLoad0()D"
-    )]
     async fn test_get_system_cpu_load_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_system_cpu_load_0(thread, Parameters::default()).await;
+        let result = get_system_cpu_load_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/OperatingSystemImpl",
+            "getSystemCpuLoad0",
+            "()D",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.OperatingSystemImpl.getTotalPhysicalMemorySize0()J"
-    )]
     async fn test_get_total_physical_memory_size_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_total_physical_memory_size_0(thread, Parameters::default()).await;
+        let result = get_total_physical_memory_size_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/OperatingSystemImpl",
+            "getTotalPhysicalMemorySize0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.OperatingSystemImpl.getTotalSwapSpaceSize0()J"
-    )]
     async fn test_get_total_swap_space_size_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_total_swap_space_size_0(thread, Parameters::default()).await;
+        let result = get_total_swap_space_size_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/OperatingSystemImpl",
+            "getTotalSwapSpaceSize0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.OperatingSystemImpl.initialize0()V"
-    )]
     async fn test_initialize_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = initialize_0(thread, Parameters::default()).await;
+        let result = initialize_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/OperatingSystemImpl",
+            "initialize0",
+            "()V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_17, JAVA_21};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_17, JAVA_21};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -120,7 +120,11 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn dump_threads_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.management.ThreadImpl.dumpThreads0([JZZI)[Ljava/lang/management/ThreadInfo;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "dumpThreads0",
+        "([JZZI)[Ljava/lang/management/ThreadInfo;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -128,7 +132,11 @@ async fn find_deadlocked_threads_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.ThreadImpl.findDeadlockedThreads0()[Ljava/lang/Thread;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "findDeadlockedThreads0",
+        "()[Ljava/lang/Thread;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -136,7 +144,11 @@ async fn find_monitor_deadlocked_threads_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.ThreadImpl.findMonitorDeadlockedThreads0()[Ljava/lang/Thread;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "findMonitorDeadlockedThreads0",
+        "()[Ljava/lang/Thread;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -144,7 +156,7 @@ async fn get_thread_allocated_memory_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.ThreadImpl.getThreadAllocatedMemory0(J)J")
+    unsatisfied_link(CLASS_NAME, "getThreadAllocatedMemory0", "(J)J")
 }
 
 #[async_recursion(?Send)]
@@ -152,12 +164,16 @@ async fn get_thread_allocated_memory_1(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.ThreadImpl.getThreadAllocatedMemory1([J[J)V")
+    unsatisfied_link(CLASS_NAME, "getThreadAllocatedMemory1", "([J[J)V")
 }
 
 #[async_recursion(?Send)]
 async fn get_thread_info_1(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.management.ThreadImpl.getThreadInfo1([JI[Ljava/lang/management/ThreadInfo;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getThreadInfo1",
+        "([JI[Ljava/lang/management/ThreadInfo;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -165,7 +181,7 @@ async fn get_thread_total_cpu_time_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.ThreadImpl.getThreadTotalCpuTime0(J)J")
+    unsatisfied_link(CLASS_NAME, "getThreadTotalCpuTime0", "(J)J")
 }
 
 #[async_recursion(?Send)]
@@ -173,7 +189,7 @@ async fn get_thread_total_cpu_time_1(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.ThreadImpl.getThreadTotalCpuTime1([J[J)V")
+    unsatisfied_link(CLASS_NAME, "getThreadTotalCpuTime1", "([J[J)V")
 }
 
 #[async_recursion(?Send)]
@@ -181,7 +197,7 @@ async fn get_thread_user_cpu_time_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.ThreadImpl.getThreadUserCpuTime0(J)J")
+    unsatisfied_link(CLASS_NAME, "getThreadUserCpuTime0", "(J)J")
 }
 
 #[async_recursion(?Send)]
@@ -189,12 +205,12 @@ async fn get_thread_user_cpu_time_1(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.ThreadImpl.getThreadUserCpuTime1([J[J)V")
+    unsatisfied_link(CLASS_NAME, "getThreadUserCpuTime1", "([J[J)V")
 }
 
 #[async_recursion(?Send)]
 async fn get_threads(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.management.ThreadImpl.getThreads()[Ljava/lang/Thread;")
+    unsatisfied_link(CLASS_NAME, "getThreads", "()[Ljava/lang/Thread;")
 }
 
 #[async_recursion(?Send)]
@@ -202,7 +218,7 @@ async fn get_total_thread_allocated_memory(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.ThreadImpl.getTotalThreadAllocatedMemory()J")
+    unsatisfied_link(CLASS_NAME, "getTotalThreadAllocatedMemory", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -210,7 +226,7 @@ async fn reset_contention_times_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.ThreadImpl.resetContentionTimes0(J)V")
+    unsatisfied_link(CLASS_NAME, "resetContentionTimes0", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -218,7 +234,7 @@ async fn reset_peak_thread_count_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.ThreadImpl.resetPeakThreadCount0()V")
+    unsatisfied_link(CLASS_NAME, "resetPeakThreadCount0", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -226,7 +242,7 @@ async fn set_thread_allocated_memory_enabled_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.ThreadImpl.setThreadAllocatedMemoryEnabled0(Z)V")
+    unsatisfied_link(CLASS_NAME, "setThreadAllocatedMemoryEnabled0", "(Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -234,7 +250,7 @@ async fn set_thread_contention_monitoring_enabled_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.ThreadImpl.setThreadContentionMonitoringEnabled0(Z)V")
+    unsatisfied_link(CLASS_NAME, "setThreadContentionMonitoringEnabled0", "(Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -242,7 +258,7 @@ async fn set_thread_cpu_time_enabled_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.ThreadImpl.setThreadCpuTimeEnabled0(Z)V")
+    unsatisfied_link(CLASS_NAME, "setThreadCpuTimeEnabled0", "(Z)V")
 }
 
 #[cfg(test)]
@@ -250,155 +266,207 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.ThreadImpl.dumpThreads0([JZZI)[Ljava/lang/management/ThreadInfo;"
-    )]
     async fn test_dump_threads_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = dump_threads_0(thread, Parameters::default()).await;
+        let result = dump_threads_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/ThreadImpl",
+            "dumpThreads0",
+            "([JZZI)[Ljava/lang/management/ThreadInfo;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.ThreadImpl.findDeadlockedThreads0()[Ljava/lang/Thread;"
-    )]
     async fn test_find_deadlocked_threads_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = find_deadlocked_threads_0(thread, Parameters::default()).await;
+        let result = find_deadlocked_threads_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/ThreadImpl",
+            "findDeadlockedThreads0",
+            "()[Ljava/lang/Thread;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.ThreadImpl.findMonitorDeadlockedThreads0()[Ljava/lang/Thread;"
-    )]
     async fn test_find_monitor_deadlocked_threads_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = find_monitor_deadlocked_threads_0(thread, Parameters::default()).await;
+        let result = find_monitor_deadlocked_threads_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/ThreadImpl",
+            "findMonitorDeadlockedThreads0",
+            "()[Ljava/lang/Thread;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.ThreadImpl.getThreadAllocatedMemory0(J)J"
-    )]
     async fn test_get_thread_allocated_memory_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_thread_allocated_memory_0(thread, Parameters::default()).await;
+        let result = get_thread_allocated_memory_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/ThreadImpl",
+            "getThreadAllocatedMemory0",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.ThreadImpl.getThreadAllocatedMemory1([J[J)V"
-    )]
     async fn test_get_thread_allocated_memory_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_thread_allocated_memory_1(thread, Parameters::default()).await;
+        let result = get_thread_allocated_memory_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/ThreadImpl",
+            "getThreadAllocatedMemory1",
+            "([J[J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.ThreadImpl.getThreadInfo1([JI[Ljava/lang/management/ThreadInfo;)V"
-    )]
     async fn test_get_thread_info_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_thread_info_1(thread, Parameters::default()).await;
+        let result = get_thread_info_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/ThreadImpl",
+            "getThreadInfo1",
+            "([JI[Ljava/lang/management/ThreadInfo;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.ThreadImpl.getThreadTotalCpuTime0(J)J"
-    )]
     async fn test_get_thread_total_cpu_time_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_thread_total_cpu_time_0(thread, Parameters::default()).await;
+        let result = get_thread_total_cpu_time_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/ThreadImpl",
+            "getThreadTotalCpuTime0",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.ThreadImpl.getThreadTotalCpuTime1([J[J)V"
-    )]
     async fn test_get_thread_total_cpu_time_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_thread_total_cpu_time_1(thread, Parameters::default()).await;
+        let result = get_thread_total_cpu_time_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/ThreadImpl",
+            "getThreadTotalCpuTime1",
+            "([J[J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.ThreadImpl.getThreadUserCpuTime0(J)J"
-    )]
     async fn test_get_thread_user_cpu_time_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_thread_user_cpu_time_0(thread, Parameters::default()).await;
+        let result = get_thread_user_cpu_time_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/ThreadImpl",
+            "getThreadUserCpuTime0",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.ThreadImpl.getThreadUserCpuTime1([J[J)V"
-    )]
     async fn test_get_thread_user_cpu_time_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_thread_user_cpu_time_1(thread, Parameters::default()).await;
+        let result = get_thread_user_cpu_time_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/ThreadImpl",
+            "getThreadUserCpuTime1",
+            "([J[J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.ThreadImpl.getThreads()[Ljava/lang/Thread;"
-    )]
     async fn test_get_threads() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_threads(thread, Parameters::default()).await;
+        let result = get_threads(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/ThreadImpl",
+            "getThreads",
+            "()[Ljava/lang/Thread;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.ThreadImpl.getTotalThreadAllocatedMemory()J"
-    )]
     async fn test_get_total_thread_allocated_memory() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_total_thread_allocated_memory(thread, Parameters::default()).await;
+        let result = get_total_thread_allocated_memory(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/ThreadImpl",
+            "getTotalThreadAllocatedMemory",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.ThreadImpl.resetContentionTimes0(J)V"
-    )]
     async fn test_reset_contention_times_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = reset_contention_times_0(thread, Parameters::default()).await;
+        let result = reset_contention_times_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/ThreadImpl",
+            "resetContentionTimes0",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.ThreadImpl.resetPeakThreadCount0()V"
-    )]
     async fn test_reset_peak_thread_count_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = reset_peak_thread_count_0(thread, Parameters::default()).await;
+        let result = reset_peak_thread_count_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/ThreadImpl",
+            "resetPeakThreadCount0",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.ThreadImpl.setThreadAllocatedMemoryEnabled0(Z)V"
-    )]
     async fn test_set_thread_allocated_memory_enabled_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_thread_allocated_memory_enabled_0(thread, Parameters::default()).await;
+        let result = set_thread_allocated_memory_enabled_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/ThreadImpl",
+            "setThreadAllocatedMemoryEnabled0",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.ThreadImpl.setThreadContentionMonitoringEnabled0(Z)V"
-    )]
     async fn test_set_thread_contention_monitoring_enabled_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_thread_contention_monitoring_enabled_0(thread, Parameters::default()).await;
+        let result =
+            set_thread_contention_monitoring_enabled_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/ThreadImpl",
+            "setThreadContentionMonitoringEnabled0",
+            "(Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.ThreadImpl.setThreadCpuTimeEnabled0(Z)V"
-    )]
     async fn test_set_thread_cpu_time_enabled_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_thread_cpu_time_enabled_0(thread, Parameters::default()).await;
+        let result = set_thread_cpu_time_enabled_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/ThreadImpl",
+            "setThreadCpuTimeEnabled0",
+            "(Z)V",
+        );
     }
 }
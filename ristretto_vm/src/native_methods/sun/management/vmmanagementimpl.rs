@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -163,7 +163,7 @@ async fn get_available_processors(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getAvailableProcessors()I")
+    unsatisfied_link(CLASS_NAME, "getAvailableProcessors", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -171,7 +171,7 @@ async fn get_class_initialization_time(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getClassInitializationTime()J")
+    unsatisfied_link(CLASS_NAME, "getClassInitializationTime", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -179,7 +179,7 @@ async fn get_class_loading_time(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getClassLoadingTime()J")
+    unsatisfied_link(CLASS_NAME, "getClassLoadingTime", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -187,7 +187,7 @@ async fn get_class_verification_time(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getClassVerificationTime()J")
+    unsatisfied_link(CLASS_NAME, "getClassVerificationTime", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -195,7 +195,7 @@ async fn get_daemon_thread_count(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getDaemonThreadCount()I")
+    unsatisfied_link(CLASS_NAME, "getDaemonThreadCount", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -203,7 +203,7 @@ async fn get_initialized_class_count(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getInitializedClassCount()J")
+    unsatisfied_link(CLASS_NAME, "getInitializedClassCount", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -211,7 +211,7 @@ async fn get_live_thread_count(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getLiveThreadCount()I")
+    unsatisfied_link(CLASS_NAME, "getLiveThreadCount", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -219,7 +219,7 @@ async fn get_loaded_class_size(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getLoadedClassSize()J")
+    unsatisfied_link(CLASS_NAME, "getLoadedClassSize", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -227,7 +227,7 @@ async fn get_method_data_size(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getMethodDataSize()J")
+    unsatisfied_link(CLASS_NAME, "getMethodDataSize", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -235,12 +235,12 @@ async fn get_peak_thread_count(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getPeakThreadCount()I")
+    unsatisfied_link(CLASS_NAME, "getPeakThreadCount", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn get_process_id(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getProcessId()I")
+    unsatisfied_link(CLASS_NAME, "getProcessId", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -248,7 +248,7 @@ async fn get_safepoint_count(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getSafepointCount()J")
+    unsatisfied_link(CLASS_NAME, "getSafepointCount", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -256,12 +256,12 @@ async fn get_safepoint_sync_time(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getSafepointSyncTime()J")
+    unsatisfied_link(CLASS_NAME, "getSafepointSyncTime", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn get_startup_time(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getStartupTime()J")
+    unsatisfied_link(CLASS_NAME, "getStartupTime", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -269,7 +269,7 @@ async fn get_total_application_non_stopped_time(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getTotalApplicationNonStoppedTime()J")
+    unsatisfied_link(CLASS_NAME, "getTotalApplicationNonStoppedTime", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -277,7 +277,7 @@ async fn get_total_class_count(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getTotalClassCount()J")
+    unsatisfied_link(CLASS_NAME, "getTotalClassCount", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -285,7 +285,7 @@ async fn get_total_compile_time(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getTotalCompileTime()J")
+    unsatisfied_link(CLASS_NAME, "getTotalCompileTime", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -293,7 +293,7 @@ async fn get_total_safepoint_time(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getTotalSafepointTime()J")
+    unsatisfied_link(CLASS_NAME, "getTotalSafepointTime", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -301,7 +301,7 @@ async fn get_total_thread_count(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getTotalThreadCount()J")
+    unsatisfied_link(CLASS_NAME, "getTotalThreadCount", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -309,7 +309,7 @@ async fn get_unloaded_class_count(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getUnloadedClassCount()J")
+    unsatisfied_link(CLASS_NAME, "getUnloadedClassCount", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -317,27 +317,27 @@ async fn get_unloaded_class_size(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getUnloadedClassSize()J")
+    unsatisfied_link(CLASS_NAME, "getUnloadedClassSize", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn get_uptime_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getUptime0()J")
+    unsatisfied_link(CLASS_NAME, "getUptime0", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn get_verbose_class(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getVerboseClass()Z")
+    unsatisfied_link(CLASS_NAME, "getVerboseClass", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn get_verbose_gc(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getVerboseGC()Z")
+    unsatisfied_link(CLASS_NAME, "getVerboseGC", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn get_version_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getVersion0()Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getVersion0", "()Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
@@ -345,7 +345,7 @@ async fn get_vm_arguments_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.getVmArguments0()[Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getVmArguments0", "()[Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
@@ -353,7 +353,7 @@ async fn init_optional_support_fields(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.initOptionalSupportFields()V")
+    unsatisfied_link(CLASS_NAME, "initOptionalSupportFields", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -361,7 +361,7 @@ async fn is_thread_allocated_memory_enabled(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.isThreadAllocatedMemoryEnabled()Z")
+    unsatisfied_link(CLASS_NAME, "isThreadAllocatedMemoryEnabled", "()Z")
 }
 
 #[async_recursion(?Send)]
@@ -369,7 +369,7 @@ async fn is_thread_contention_monitoring_enabled(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.isThreadContentionMonitoringEnabled()Z")
+    unsatisfied_link(CLASS_NAME, "isThreadContentionMonitoringEnabled", "()Z")
 }
 
 #[async_recursion(?Send)]
@@ -377,7 +377,7 @@ async fn is_thread_cpu_time_enabled(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.management.VMManagementImpl.isThreadCpuTimeEnabled()Z")
+    unsatisfied_link(CLASS_NAME, "isThreadCpuTimeEnabled", "()Z")
 }
 
 #[cfg(test)]
@@ -385,270 +385,362 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getAvailableProcessors()I"
-    )]
     async fn test_get_available_processors() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_available_processors(thread, Parameters::default()).await;
+        let result = get_available_processors(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getAvailableProcessors",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getClassInitializationTime()J"
-    )]
     async fn test_get_class_initialization_time() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_class_initialization_time(thread, Parameters::default()).await;
+        let result = get_class_initialization_time(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getClassInitializationTime",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getClassLoadingTime()J"
-    )]
     async fn test_get_class_loading_time() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_class_loading_time(thread, Parameters::default()).await;
+        let result = get_class_loading_time(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getClassLoadingTime",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getClassVerificationTime()J"
-    )]
     async fn test_get_class_verification_time() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_class_verification_time(thread, Parameters::default()).await;
+        let result = get_class_verification_time(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getClassVerificationTime",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getDaemonThreadCount()I"
-    )]
     async fn test_get_daemon_thread_count() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_daemon_thread_count(thread, Parameters::default()).await;
+        let result = get_daemon_thread_count(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getDaemonThreadCount",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getInitializedClassCount()J"
-    )]
     async fn test_get_initialized_class_count() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_initialized_class_count(thread, Parameters::default()).await;
+        let result = get_initialized_class_count(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getInitializedClassCount",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getLiveThreadCount()I"
-    )]
     async fn test_get_live_thread_count() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_live_thread_count(thread, Parameters::default()).await;
+        let result = get_live_thread_count(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getLiveThreadCount",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getLoadedClassSize()J"
-    )]
     async fn test_get_loaded_class_size() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_loaded_class_size(thread, Parameters::default()).await;
+        let result = get_loaded_class_size(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getLoadedClassSize",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getMethodDataSize()J"
-    )]
     async fn test_get_method_data_size() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_method_data_size(thread, Parameters::default()).await;
+        let result = get_method_data_size(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getMethodDataSize",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getPeakThreadCount()I"
-    )]
     async fn test_get_peak_thread_count() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_peak_thread_count(thread, Parameters::default()).await;
+        let result = get_peak_thread_count(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getPeakThreadCount",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getProcessId()I"
-    )]
     async fn test_get_process_id() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_process_id(thread, Parameters::default()).await;
+        let result = get_process_id(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getProcessId",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getSafepointCount()J"
-    )]
     async fn test_get_safepoint_count() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_safepoint_count(thread, Parameters::default()).await;
+        let result = get_safepoint_count(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getSafepointCount",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getSafepointSyncTime()J"
-    )]
     async fn test_get_safepoint_sync_time() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_safepoint_sync_time(thread, Parameters::default()).await;
+        let result = get_safepoint_sync_time(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getSafepointSyncTime",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getStartupTime()J"
-    )]
     async fn test_get_startup_time() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_startup_time(thread, Parameters::default()).await;
+        let result = get_startup_time(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getStartupTime",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getTotalApplicationNonStoppedTime()J"
-    )]
     async fn test_get_total_application_non_stopped_time() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_total_application_non_stopped_time(thread, Parameters::default()).await;
+        let result = get_total_application_non_stopped_time(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getTotalApplicationNonStoppedTime",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getTotalClassCount()J"
-    )]
     async fn test_get_total_class_count() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_total_class_count(thread, Parameters::default()).await;
+        let result = get_total_class_count(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getTotalClassCount",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getTotalCompileTime()J"
-    )]
     async fn test_get_total_compile_time() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_total_compile_time(thread, Parameters::default()).await;
+        let result = get_total_compile_time(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getTotalCompileTime",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getTotalSafepointTime()J"
-    )]
     async fn test_get_total_safepoint_time() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_total_safepoint_time(thread, Parameters::default()).await;
+        let result = get_total_safepoint_time(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getTotalSafepointTime",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getTotalThreadCount()J"
-    )]
     async fn test_get_total_thread_count() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_total_thread_count(thread, Parameters::default()).await;
+        let result = get_total_thread_count(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getTotalThreadCount",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getUnloadedClassCount()J"
-    )]
     async fn test_get_unloaded_class_count() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_unloaded_class_count(thread, Parameters::default()).await;
+        let result = get_unloaded_class_count(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getUnloadedClassCount",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getUnloadedClassSize()J"
-    )]
     async fn test_get_unloaded_class_size() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_unloaded_class_size(thread, Parameters::default()).await;
+        let result = get_unloaded_class_size(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getUnloadedClassSize",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.management.VMManagementImpl.getUptime0()J")]
     async fn test_get_uptime_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_uptime_0(thread, Parameters::default()).await;
+        let result = get_uptime_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getUptime0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getVerboseClass()Z"
-    )]
     async fn test_get_verbose_class() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_verbose_class(thread, Parameters::default()).await;
+        let result = get_verbose_class(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getVerboseClass",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getVerboseGC()Z"
-    )]
     async fn test_get_verbose_gc() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_verbose_gc(thread, Parameters::default()).await;
+        let result = get_verbose_gc(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getVerboseGC",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getVersion0()Ljava/lang/String;"
-    )]
     async fn test_get_version_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_version_0(thread, Parameters::default()).await;
+        let result = get_version_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getVersion0",
+            "()Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.getVmArguments0()[Ljava/lang/String;"
-    )]
     async fn test_get_vm_arguments_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_vm_arguments_0(thread, Parameters::default()).await;
+        let result = get_vm_arguments_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "getVmArguments0",
+            "()[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.initOptionalSupportFields()V"
-    )]
     async fn test_init_optional_support_fields() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_optional_support_fields(thread, Parameters::default()).await;
+        let result = init_optional_support_fields(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "initOptionalSupportFields",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.isThreadAllocatedMemoryEnabled()Z"
-    )]
     async fn test_is_thread_allocated_memory_enabled() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_thread_allocated_memory_enabled(thread, Parameters::default()).await;
+        let result = is_thread_allocated_memory_enabled(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "isThreadAllocatedMemoryEnabled",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.isThreadContentionMonitoringEnabled()Z"
-    )]
     async fn test_is_thread_contention_monitoring_enabled() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_thread_contention_monitoring_enabled(thread, Parameters::default()).await;
+        let result = is_thread_contention_monitoring_enabled(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "isThreadContentionMonitoringEnabled",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.management.VMManagementImpl.isThreadCpuTimeEnabled()Z"
-    )]
     async fn test_is_thread_cpu_time_enabled() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_thread_cpu_time_enabled(thread, Parameters::default()).await;
+        let result = is_thread_cpu_time_enabled(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/management/VMManagementImpl",
+            "isThreadCpuTimeEnabled",
+            "()Z",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -16,12 +16,12 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn to_stderr(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.MessageUtils.toStderr(Ljava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "toStderr", "(Ljava/lang/String;)V")
 }
 
 #[async_recursion(?Send)]
 async fn to_stdout(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.MessageUtils.toStdout(Ljava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "toStdout", "(Ljava/lang/String;)V")
 }
 
 #[cfg(test)]
@@ -29,20 +29,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.MessageUtils.toStderr(Ljava/lang/String;)V"
-    )]
     async fn test_to_stderr() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = to_stderr(thread, Parameters::default()).await;
+        let result = to_stderr(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/MessageUtils",
+            "toStderr",
+            "(Ljava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.MessageUtils.toStdout(Ljava/lang/String;)V"
-    )]
     async fn test_to_stdout() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = to_stdout(thread, Parameters::default()).await;
+        let result = to_stdout(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/MessageUtils",
+            "toStdout",
+            "(Ljava/lang/String;)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -15,7 +15,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn handle_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.NativeSignalHandler.handle0(IJ)V")
+    unsatisfied_link(CLASS_NAME, "handle0", "(IJ)V")
 }
 
 #[cfg(test)]
@@ -23,9 +23,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.NativeSignalHandler.handle0(IJ)V")]
     async fn test_handle_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = handle_0(thread, Parameters::default()).await;
+        let result = handle_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/NativeSignalHandler",
+            "handle0",
+            "(IJ)V",
+        );
     }
 }
@@ -1,3 +1,4 @@
+use crate::native_methods::jdk;
 use crate::native_methods::registry::MethodRegistry;
 use crate::parameters::Parameters;
 use crate::thread::Thread;
@@ -35,41 +36,38 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 }
 
 #[async_recursion(?Send)]
-async fn attach(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Perf.attach(Ljava/lang/String;II)Ljava/nio/ByteBuffer;")
+async fn attach(thread: Arc<Thread>, parameters: Parameters) -> Result<Option<Value>> {
+    jdk::internal::perf::perf::attach(thread, parameters).await
 }
 
 #[async_recursion(?Send)]
-async fn create_byte_array(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Perf.createByteArray(Ljava/lang/String;II[BI)Ljava/nio/ByteBuffer;")
+async fn create_byte_array(thread: Arc<Thread>, parameters: Parameters) -> Result<Option<Value>> {
+    jdk::internal::perf::perf::create_byte_array(thread, parameters).await
 }
 
 #[async_recursion(?Send)]
-async fn create_long(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Perf.createLong(Ljava/lang/String;IIJ)Ljava/nio/ByteBuffer;")
+async fn create_long(thread: Arc<Thread>, parameters: Parameters) -> Result<Option<Value>> {
+    jdk::internal::perf::perf::create_long(thread, parameters).await
 }
 
 #[async_recursion(?Send)]
-async fn detach(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Perf.detach(Ljava/nio/ByteBuffer;)V")
+async fn detach(thread: Arc<Thread>, parameters: Parameters) -> Result<Option<Value>> {
+    jdk::internal::perf::perf::detach(thread, parameters).await
 }
 
 #[async_recursion(?Send)]
-async fn high_res_counter(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Perf.highResCounter()J")
+async fn high_res_counter(thread: Arc<Thread>, parameters: Parameters) -> Result<Option<Value>> {
+    jdk::internal::perf::perf::high_res_counter(thread, parameters).await
 }
 
 #[async_recursion(?Send)]
-async fn high_res_frequency(
-    _thread: Arc<Thread>,
-    _parameters: Parameters,
-) -> Result<Option<Value>> {
-    todo!("sun.misc.Perf.highResFrequency()J")
+async fn high_res_frequency(thread: Arc<Thread>, parameters: Parameters) -> Result<Option<Value>> {
+    jdk::internal::perf::perf::high_res_frequency(thread, parameters).await
 }
 
 #[async_recursion(?Send)]
-async fn register_natives(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    Ok(None)
+async fn register_natives(thread: Arc<Thread>, parameters: Parameters) -> Result<Option<Value>> {
+    jdk::internal::perf::perf::register_natives(thread, parameters).await
 }
 
 #[cfg(test)]
@@ -77,51 +75,39 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.Perf.attach(Ljava/lang/String;II)Ljava/nio/ByteBuffer;"
-    )]
-    async fn test_attach() {
-        let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = attach(thread, Parameters::default()).await;
-    }
-
-    #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.Perf.createByteArray(Ljava/lang/String;II[BI)Ljava/nio/ByteBuffer;"
-    )]
-    async fn test_create_byte_array() {
-        let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_byte_array(thread, Parameters::default()).await;
-    }
-
-    #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.Perf.createLong(Ljava/lang/String;IIJ)Ljava/nio/ByteBuffer;"
-    )]
-    async fn test_create_long() {
-        let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_long(thread, Parameters::default()).await;
+    async fn test_attach() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let mut parameters = Parameters::default();
+        parameters.push_reference(None);
+        parameters.push_int(0);
+        parameters.push_int(0);
+        let result = attach(thread, parameters).await?;
+        assert!(matches!(result, Some(Value::Object(Some(_)))));
+        Ok(())
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Perf.detach(Ljava/nio/ByteBuffer;)V")]
-    async fn test_detach() {
-        let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = detach(thread, Parameters::default()).await;
+    async fn test_detach() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let result = detach(thread, Parameters::default()).await?;
+        assert_eq!(None, result);
+        Ok(())
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Perf.highResCounter()J")]
-    async fn test_high_res_counter() {
-        let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = high_res_counter(thread, Parameters::default()).await;
+    async fn test_high_res_counter() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let result = high_res_counter(thread, Parameters::default()).await?;
+        assert!(matches!(result, Some(Value::Long(counter)) if counter >= 0));
+        Ok(())
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Perf.highResFrequency()J")]
-    async fn test_high_res_frequency() {
-        let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = high_res_frequency(thread, Parameters::default()).await;
+    async fn test_high_res_frequency() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let result = high_res_frequency(thread, Parameters::default()).await?;
+        assert_eq!(Some(Value::Long(1_000_000_000)), result);
+        Ok(())
     }
 
     #[tokio::test]
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -22,17 +22,17 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn find_signal(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Signal.findSignal(Ljava/lang/String;)I")
+    unsatisfied_link(CLASS_NAME, "findSignal", "(Ljava/lang/String;)I")
 }
 
 #[async_recursion(?Send)]
 async fn handle_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Signal.handle0(IJ)J")
+    unsatisfied_link(CLASS_NAME, "handle0", "(IJ)J")
 }
 
 #[async_recursion(?Send)]
 async fn raise_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Signal.raise0(I)V")
+    unsatisfied_link(CLASS_NAME, "raise0", "(I)V")
 }
 
 #[cfg(test)]
@@ -40,25 +40,28 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.Signal.findSignal(Ljava/lang/String;)I"
-    )]
     async fn test_find_signal() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = find_signal(thread, Parameters::default()).await;
+        let result = find_signal(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/Signal",
+            "findSignal",
+            "(Ljava/lang/String;)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Signal.handle0(IJ)J")]
     async fn test_handle_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = handle_0(thread, Parameters::default()).await;
+        let result = handle_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/misc/Signal", "handle0", "(IJ)J");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Signal.raise0(I)V")]
     async fn test_raise_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = raise_0(thread, Parameters::default()).await;
+        let result = raise_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/misc/Signal", "raise0", "(I)V");
     }
 }
@@ -1,5 +1,5 @@
 use crate::native_methods::jdk;
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -383,7 +383,7 @@ async fn compare_and_swap_long(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.compareAndSwapLong(Ljava/lang/Object;JJJ)Z")
+    unsatisfied_link(CLASS_NAME, "compareAndSwapLong", "(Ljava/lang/Object;JJJ)Z")
 }
 
 #[async_recursion(?Send)]
@@ -432,7 +432,7 @@ async fn full_fence(thread: Arc<Thread>, parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn get_address(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.getAddress(J)J")
+    unsatisfied_link(CLASS_NAME, "getAddress", "(J)J")
 }
 
 #[async_recursion(?Send)]
@@ -450,7 +450,7 @@ async fn get_boolean_volatile(
 
 #[async_recursion(?Send)]
 async fn get_byte_1(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.getByte(J)B")
+    unsatisfied_link(CLASS_NAME, "getByte", "(J)B")
 }
 
 #[async_recursion(?Send)]
@@ -465,7 +465,7 @@ async fn get_byte_volatile(thread: Arc<Thread>, parameters: Parameters) -> Resul
 
 #[async_recursion(?Send)]
 async fn get_char_1(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.getChar(J)C")
+    unsatisfied_link(CLASS_NAME, "getChar", "(J)C")
 }
 
 #[async_recursion(?Send)]
@@ -480,7 +480,7 @@ async fn get_char_volatile(thread: Arc<Thread>, parameters: Parameters) -> Resul
 
 #[async_recursion(?Send)]
 async fn get_double_1(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.getDouble(J)D")
+    unsatisfied_link(CLASS_NAME, "getDouble", "(J)D")
 }
 
 #[async_recursion(?Send)]
@@ -495,7 +495,7 @@ async fn get_double_volatile(thread: Arc<Thread>, parameters: Parameters) -> Res
 
 #[async_recursion(?Send)]
 async fn get_float_1(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.getFloat(J)F")
+    unsatisfied_link(CLASS_NAME, "getFloat", "(J)F")
 }
 
 #[async_recursion(?Send)]
@@ -510,7 +510,7 @@ async fn get_float_volatile(thread: Arc<Thread>, parameters: Parameters) -> Resu
 
 #[async_recursion(?Send)]
 async fn get_int_1(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.getInt(J)I")
+    unsatisfied_link(CLASS_NAME, "getInt", "(J)I")
 }
 
 #[async_recursion(?Send)]
@@ -530,7 +530,7 @@ async fn get_load_average(thread: Arc<Thread>, parameters: Parameters) -> Result
 
 #[async_recursion(?Send)]
 async fn get_long_1(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.getLong(J)J")
+    unsatisfied_link(CLASS_NAME, "getLong", "(J)J")
 }
 
 #[async_recursion(?Send)]
@@ -555,7 +555,7 @@ async fn get_object_volatile(thread: Arc<Thread>, parameters: Parameters) -> Res
 
 #[async_recursion(?Send)]
 async fn get_short_1(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.getShort(J)S")
+    unsatisfied_link(CLASS_NAME, "getShort", "(J)S")
 }
 
 #[async_recursion(?Send)]
@@ -575,12 +575,12 @@ async fn load_fence(thread: Arc<Thread>, parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn monitor_enter(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.monitorEnter(Ljava/lang/Object;)V")
+    unsatisfied_link(CLASS_NAME, "monitorEnter", "(Ljava/lang/Object;)V")
 }
 
 #[async_recursion(?Send)]
 async fn monitor_exit(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.monitorExit(Ljava/lang/Object;)V")
+    unsatisfied_link(CLASS_NAME, "monitorExit", "(Ljava/lang/Object;)V")
 }
 
 #[async_recursion(?Send)]
@@ -600,7 +600,7 @@ async fn park(thread: Arc<Thread>, parameters: Parameters) -> Result<Option<Valu
 
 #[async_recursion(?Send)]
 async fn put_address(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putAddress(JJ)V")
+    unsatisfied_link(CLASS_NAME, "putAddress", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
@@ -618,7 +618,7 @@ async fn put_boolean_volatile(
 
 #[async_recursion(?Send)]
 async fn put_byte_1(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putByte(JB)V")
+    unsatisfied_link(CLASS_NAME, "putByte", "(JB)V")
 }
 
 #[async_recursion(?Send)]
@@ -633,7 +633,7 @@ async fn put_byte_volatile(thread: Arc<Thread>, parameters: Parameters) -> Resul
 
 #[async_recursion(?Send)]
 async fn put_char_1(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putChar(JC)V")
+    unsatisfied_link(CLASS_NAME, "putChar", "(JC)V")
 }
 
 #[async_recursion(?Send)]
@@ -648,7 +648,7 @@ async fn put_char_volatile(thread: Arc<Thread>, parameters: Parameters) -> Resul
 
 #[async_recursion(?Send)]
 async fn put_double_1(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putDouble(JD)V")
+    unsatisfied_link(CLASS_NAME, "putDouble", "(JD)V")
 }
 
 #[async_recursion(?Send)]
@@ -663,7 +663,7 @@ async fn put_double_volatile(thread: Arc<Thread>, parameters: Parameters) -> Res
 
 #[async_recursion(?Send)]
 async fn put_float_1(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putFloat(JF)V")
+    unsatisfied_link(CLASS_NAME, "putFloat", "(JF)V")
 }
 
 #[async_recursion(?Send)]
@@ -678,7 +678,7 @@ async fn put_float_volatile(thread: Arc<Thread>, parameters: Parameters) -> Resu
 
 #[async_recursion(?Send)]
 async fn put_int_1(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putInt(JI)V")
+    unsatisfied_link(CLASS_NAME, "putInt", "(JI)V")
 }
 
 #[async_recursion(?Send)]
@@ -693,7 +693,7 @@ async fn put_int_volatile(thread: Arc<Thread>, parameters: Parameters) -> Result
 
 #[async_recursion(?Send)]
 async fn put_long_1(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putLong(JJ)V")
+    unsatisfied_link(CLASS_NAME, "putLong", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
@@ -718,12 +718,12 @@ async fn put_object_volatile(thread: Arc<Thread>, parameters: Parameters) -> Res
 
 #[async_recursion(?Send)]
 async fn put_ordered_int(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putOrderedInt(Ljava/lang/Object;JI)V")
+    unsatisfied_link(CLASS_NAME, "putOrderedInt", "(Ljava/lang/Object;JI)V")
 }
 
 #[async_recursion(?Send)]
 async fn put_ordered_long(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putOrderedLong(Ljava/lang/Object;JJ)V")
+    unsatisfied_link(CLASS_NAME, "putOrderedLong", "(Ljava/lang/Object;JJ)V")
 }
 
 #[async_recursion(?Send)]
@@ -731,17 +731,21 @@ async fn put_ordered_object(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putOrderedObject(Ljava/lang/Object;JLjava/lang/Object;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "putOrderedObject",
+        "(Ljava/lang/Object;JLjava/lang/Object;)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn put_short_1(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putShort(JS)V")
+    unsatisfied_link(CLASS_NAME, "putShort", "(JS)V")
 }
 
 #[async_recursion(?Send)]
 async fn put_short_2(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putShort(Ljava/lang/Object;JS)V")
+    unsatisfied_link(CLASS_NAME, "putShort", "(Ljava/lang/Object;JS)V")
 }
 
 #[async_recursion(?Send)]
@@ -749,7 +753,7 @@ async fn put_short_volatile(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putShortVolatile(Ljava/lang/Object;JS)V")
+    unsatisfied_link(CLASS_NAME, "putShortVolatile", "(Ljava/lang/Object;JS)V")
 }
 
 #[async_recursion(?Send)]
@@ -797,7 +801,7 @@ async fn throw_exception(thread: Arc<Thread>, parameters: Parameters) -> Result<
 
 #[async_recursion(?Send)]
 async fn try_monitor_enter(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.tryMonitorEnter(Ljava/lang/Object;)Z")
+    unsatisfied_link(CLASS_NAME, "tryMonitorEnter", "(Ljava/lang/Object;)Z")
 }
 
 #[async_recursion(?Send)]
@@ -810,195 +814,222 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.Unsafe.compareAndSwapLong(Ljava/lang/Object;JJJ)Z"
-    )]
     async fn test_compare_and_swap_long() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = compare_and_swap_long(thread, Parameters::default()).await;
+        let result = compare_and_swap_long(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/Unsafe",
+            "compareAndSwapLong",
+            "(Ljava/lang/Object;JJJ)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Unsafe.getAddress(J)J")]
     async fn test_get_address() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_address(thread, Parameters::default()).await;
+        let result = get_address(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/misc/Unsafe", "getAddress", "(J)J");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Unsafe.getByte(J)B")]
     async fn test_get_byte_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_byte_1(thread, Parameters::default()).await;
+        let result = get_byte_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/misc/Unsafe", "getByte", "(J)B");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Unsafe.getChar(J)C")]
     async fn test_get_char_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_char_1(thread, Parameters::default()).await;
+        let result = get_char_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/misc/Unsafe", "getChar", "(J)C");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Unsafe.getDouble(J)D")]
     async fn test_get_double_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_double_1(thread, Parameters::default()).await;
+        let result = get_double_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/misc/Unsafe", "getDouble", "(J)D");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Unsafe.getFloat(J)F")]
     async fn test_get_float_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_float_1(thread, Parameters::default()).await;
+        let result = get_float_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/misc/Unsafe", "getFloat", "(J)F");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Unsafe.getInt(J)I")]
     async fn test_get_int_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_int_1(thread, Parameters::default()).await;
+        let result = get_int_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/misc/Unsafe", "getInt", "(J)I");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Unsafe.getLong(J)J")]
     async fn test_get_long_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_long_1(thread, Parameters::default()).await;
+        let result = get_long_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/misc/Unsafe", "getLong", "(J)J");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Unsafe.getShort(J)S")]
     async fn test_get_short_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_short_1(thread, Parameters::default()).await;
+        let result = get_short_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/misc/Unsafe", "getShort", "(J)S");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.Unsafe.monitorEnter(Ljava/lang/Object;)V"
-    )]
     async fn test_monitor_enter() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = monitor_enter(thread, Parameters::default()).await;
+        let result = monitor_enter(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/Unsafe",
+            "monitorEnter",
+            "(Ljava/lang/Object;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.Unsafe.monitorExit(Ljava/lang/Object;)V"
-    )]
     async fn test_monitor_exit() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = monitor_exit(thread, Parameters::default()).await;
+        let result = monitor_exit(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/Unsafe",
+            "monitorExit",
+            "(Ljava/lang/Object;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Unsafe.putAddress(JJ)V")]
     async fn test_put_address() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = put_address(thread, Parameters::default()).await;
+        let result = put_address(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/misc/Unsafe", "putAddress", "(JJ)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Unsafe.putByte(JB)V")]
     async fn test_put_byte_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = put_byte_1(thread, Parameters::default()).await;
+        let result = put_byte_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/misc/Unsafe", "putByte", "(JB)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Unsafe.putChar(JC)V")]
     async fn test_put_char_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = put_char_1(thread, Parameters::default()).await;
+        let result = put_char_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/misc/Unsafe", "putChar", "(JC)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Unsafe.putDouble(JD)V")]
     async fn test_put_double_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = put_double_1(thread, Parameters::default()).await;
+        let result = put_double_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/misc/Unsafe", "putDouble", "(JD)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Unsafe.putFloat(JF)V")]
     async fn test_put_float_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = put_float_1(thread, Parameters::default()).await;
+        let result = put_float_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/misc/Unsafe", "putFloat", "(JF)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Unsafe.putInt(JI)V")]
     async fn test_put_int_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = put_int_1(thread, Parameters::default()).await;
+        let result = put_int_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/misc/Unsafe", "putInt", "(JI)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Unsafe.putLong(JJ)V")]
     async fn test_put_long_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = put_long_1(thread, Parameters::default()).await;
+        let result = put_long_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/misc/Unsafe", "putLong", "(JJ)V");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.Unsafe.putOrderedInt(Ljava/lang/Object;JI)V"
-    )]
     async fn test_put_ordered_int() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = put_ordered_int(thread, Parameters::default()).await;
+        let result = put_ordered_int(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/Unsafe",
+            "putOrderedInt",
+            "(Ljava/lang/Object;JI)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.Unsafe.putOrderedLong(Ljava/lang/Object;JJ)V"
-    )]
     async fn test_put_ordered_long() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = put_ordered_long(thread, Parameters::default()).await;
+        let result = put_ordered_long(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/Unsafe",
+            "putOrderedLong",
+            "(Ljava/lang/Object;JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.Unsafe.putOrderedObject(Ljava/lang/Object;JLjava/lang/Object;)V"
-    )]
     async fn test_put_ordered_object() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = put_ordered_object(thread, Parameters::default()).await;
+        let result = put_ordered_object(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/Unsafe",
+            "putOrderedObject",
+            "(Ljava/lang/Object;JLjava/lang/Object;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Unsafe.putShort(JS)V")]
     async fn test_put_short_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = put_short_1(thread, Parameters::default()).await;
+        let result = put_short_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/misc/Unsafe", "putShort", "(JS)V");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.Unsafe.putShort(Ljava/lang/Object;JS)V"
-    )]
     async fn test_put_short_2() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = put_short_2(thread, Parameters::default()).await;
+        let result = put_short_2(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/Unsafe",
+            "putShort",
+            "(Ljava/lang/Object;JS)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.Unsafe.putShortVolatile(Ljava/lang/Object;JS)V"
-    )]
     async fn test_put_short_volatile() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = put_short_volatile(thread, Parameters::default()).await;
+        let result = put_short_volatile(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/Unsafe",
+            "putShortVolatile",
+            "(Ljava/lang/Object;JS)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.Unsafe.tryMonitorEnter(Ljava/lang/Object;)Z"
-    )]
     async fn test_try_monitor_enter() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = try_monitor_enter(thread, Parameters::default()).await;
+        let result = try_monitor_enter(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/Unsafe",
+            "tryMonitorEnter",
+            "(Ljava/lang/Object;)Z",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -35,7 +35,11 @@ async fn get_lookup_cache_for_class_loader(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.misc.URLClassPath.getLookupCacheForClassLoader(Ljava/lang/ClassLoader;Ljava/lang/String;)[I")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getLookupCacheForClassLoader",
+        "(Ljava/lang/ClassLoader;Ljava/lang/String;)[I",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -43,7 +47,11 @@ async fn get_lookup_cache_urls(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.misc.URLClassPath.getLookupCacheURLs(Ljava/lang/ClassLoader;)[Ljava/net/URL;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getLookupCacheURLs",
+        "(Ljava/lang/ClassLoader;)[Ljava/net/URL;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -51,7 +59,11 @@ async fn known_to_not_exist_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.misc.URLClassPath.knownToNotExist0(Ljava/lang/ClassLoader;Ljava/lang/String;)Z")
+    unsatisfied_link(
+        CLASS_NAME,
+        "knownToNotExist0",
+        "(Ljava/lang/ClassLoader;Ljava/lang/String;)Z",
+    )
 }
 
 #[cfg(test)]
@@ -59,29 +71,38 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.URLClassPath.getLookupCacheForClassLoader(Ljava/lang/ClassLoader;Ljava/lang/String;)[I"
-    )]
     async fn test_get_lookup_cache_for_class_loader() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_lookup_cache_for_class_loader(thread, Parameters::default()).await;
+        let result = get_lookup_cache_for_class_loader(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/URLClassPath",
+            "getLookupCacheForClassLoader",
+            "(Ljava/lang/ClassLoader;Ljava/lang/String;)[I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.URLClassPath.getLookupCacheURLs(Ljava/lang/ClassLoader;)[Ljava/net/URL;"
-    )]
     async fn test_get_lookup_cache_urls() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_lookup_cache_urls(thread, Parameters::default()).await;
+        let result = get_lookup_cache_urls(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/URLClassPath",
+            "getLookupCacheURLs",
+            "(Ljava/lang/ClassLoader;)[Ljava/net/URL;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.URLClassPath.knownToNotExist0(Ljava/lang/ClassLoader;Ljava/lang/String;)Z"
-    )]
     async fn test_known_to_not_exist_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = known_to_not_exist_0(thread, Parameters::default()).await;
+        let result = known_to_not_exist_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/URLClassPath",
+            "knownToNotExist0",
+            "(Ljava/lang/ClassLoader;Ljava/lang/String;)Z",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -31,7 +31,7 @@ async fn get_jdk_special_version(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.misc.Version.getJdkSpecialVersion()Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getJdkSpecialVersion", "()Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
@@ -39,7 +39,7 @@ async fn get_jdk_version_info(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.misc.Version.getJdkVersionInfo()V")
+    unsatisfied_link(CLASS_NAME, "getJdkVersionInfo", "()V")
 }
 
 #[async_recursion(?Send)]
@@ -47,7 +47,7 @@ async fn get_jvm_special_version(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.misc.Version.getJvmSpecialVersion()Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getJvmSpecialVersion", "()Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
@@ -55,7 +55,7 @@ async fn get_jvm_version_info(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.misc.Version.getJvmVersionInfo()Z")
+    unsatisfied_link(CLASS_NAME, "getJvmVersionInfo", "()Z")
 }
 
 #[cfg(test)]
@@ -63,34 +63,50 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.Version.getJdkSpecialVersion()Ljava/lang/String;"
-    )]
     async fn test_get_jdk_special_version() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_jdk_special_version(thread, Parameters::default()).await;
+        let result = get_jdk_special_version(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/Version",
+            "getJdkSpecialVersion",
+            "()Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Version.getJdkVersionInfo()V")]
     async fn test_get_jdk_version_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_jdk_version_info(thread, Parameters::default()).await;
+        let result = get_jdk_version_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/Version",
+            "getJdkVersionInfo",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.Version.getJvmSpecialVersion()Ljava/lang/String;"
-    )]
     async fn test_get_jvm_special_version() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_jvm_special_version(thread, Parameters::default()).await;
+        let result = get_jvm_special_version(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/Version",
+            "getJvmSpecialVersion",
+            "()Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.misc.Version.getJvmVersionInfo()Z")]
     async fn test_get_jvm_version_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_jvm_version_info(thread, Parameters::default()).await;
+        let result = get_jvm_version_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/Version",
+            "getJvmVersionInfo",
+            "()Z",
+        );
     }
 }
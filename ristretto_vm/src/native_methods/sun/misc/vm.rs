@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -29,7 +29,11 @@ async fn latest_user_defined_loader_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.misc.VM.latestUserDefinedLoader0()Ljava/lang/ClassLoader;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "latestUserDefinedLoader0",
+        "()Ljava/lang/ClassLoader;",
+    )
 }
 
 #[cfg(test)]
@@ -45,11 +49,14 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.misc.VM.latestUserDefinedLoader0()Ljava/lang/ClassLoader;"
-    )]
     async fn test_latest_user_defined_loader_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = latest_user_defined_loader_0(thread, Parameters::default()).await;
+        let result = latest_user_defined_loader_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/misc/VM",
+            "latestUserDefinedLoader0",
+            "()Ljava/lang/ClassLoader;",
+        );
     }
 }
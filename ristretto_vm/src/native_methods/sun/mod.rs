@@ -1,16 +1,23 @@
+#[cfg(feature = "awt")]
 pub(crate) mod awt;
+#[cfg(feature = "awt")]
 pub(crate) mod font;
 pub(crate) mod instrument;
 pub(crate) mod io;
+#[cfg(feature = "awt")]
 pub(crate) mod java2d;
+#[cfg(feature = "awt")]
 pub(crate) mod lwawt;
+#[cfg(feature = "management")]
 pub(crate) mod management;
 pub(crate) mod misc;
 pub(crate) mod net;
 pub(crate) mod nio;
+#[cfg(feature = "awt")]
 pub(crate) mod print;
 pub(crate) mod reflect;
 pub(crate) mod rmi;
+#[cfg(feature = "security")]
 pub(crate) mod security;
 pub(crate) mod tools;
 pub(crate) mod tracing;
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -29,12 +29,12 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn fallback_domain_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.net.dns.ResolverConfigurationImpl.fallbackDomain0()Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "fallbackDomain0", "()Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
 async fn local_domain_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.net.dns.ResolverConfigurationImpl.localDomain0()Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "localDomain0", "()Ljava/lang/String;")
 }
 
 #[cfg(test)]
@@ -42,20 +42,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.net.dns.ResolverConfigurationImpl.fallbackDomain0()Ljava/lang/String;"
-    )]
     async fn test_fallback_domain_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fallback_domain_0(thread, Parameters::default()).await;
+        let result = fallback_domain_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/net/dns/ResolverConfigurationImpl",
+            "fallbackDomain0",
+            "()Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.net.dns.ResolverConfigurationImpl.localDomain0()Ljava/lang/String;"
-    )]
     async fn test_local_domain_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = local_domain_0(thread, Parameters::default()).await;
+        let result = local_domain_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/net/dns/ResolverConfigurationImpl",
+            "localDomain0",
+            "()Ljava/lang/String;",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -70,13 +70,15 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn flow_supported(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.net.ExtendedOptionsImpl.flowSupported()Z")
+    unsatisfied_link(CLASS_NAME, "flowSupported", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn get_flow_option(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!(
-        "sun.net.ExtendedOptionsImpl.getFlowOption(Ljava/io/FileDescriptor;Ljdk/net/SocketFlow;)V"
+    unsatisfied_link(
+        CLASS_NAME,
+        "getFlowOption",
+        "(Ljava/io/FileDescriptor;Ljdk/net/SocketFlow;)V",
     )
 }
 
@@ -85,7 +87,11 @@ async fn get_tcp_keep_alive_intvl(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.net.ExtendedOptionsImpl.getTcpKeepAliveIntvl(Ljava/io/FileDescriptor;)I")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getTcpKeepAliveIntvl",
+        "(Ljava/io/FileDescriptor;)I",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -93,7 +99,11 @@ async fn get_tcp_keep_alive_probes(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.net.ExtendedOptionsImpl.getTcpKeepAliveProbes(Ljava/io/FileDescriptor;)I")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getTcpKeepAliveProbes",
+        "(Ljava/io/FileDescriptor;)I",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -101,7 +111,11 @@ async fn get_tcp_keep_alive_time(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.net.ExtendedOptionsImpl.getTcpKeepAliveTime(Ljava/io/FileDescriptor;)I")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getTcpKeepAliveTime",
+        "(Ljava/io/FileDescriptor;)I",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -114,13 +128,15 @@ async fn keep_alive_options_supported(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.net.ExtendedOptionsImpl.keepAliveOptionsSupported()Z")
+    unsatisfied_link(CLASS_NAME, "keepAliveOptionsSupported", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn set_flow_option(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!(
-        "sun.net.ExtendedOptionsImpl.setFlowOption(Ljava/io/FileDescriptor;Ljdk/net/SocketFlow;)V"
+    unsatisfied_link(
+        CLASS_NAME,
+        "setFlowOption",
+        "(Ljava/io/FileDescriptor;Ljdk/net/SocketFlow;)V",
     )
 }
 
@@ -129,7 +145,11 @@ async fn set_tcp_keep_alive_intvl(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.net.ExtendedOptionsImpl.setTcpKeepAliveIntvl(Ljava/io/FileDescriptor;I)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "setTcpKeepAliveIntvl",
+        "(Ljava/io/FileDescriptor;I)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -137,7 +157,11 @@ async fn set_tcp_keep_alive_probes(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.net.ExtendedOptionsImpl.setTcpKeepAliveProbes(Ljava/io/FileDescriptor;I)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "setTcpKeepAliveProbes",
+        "(Ljava/io/FileDescriptor;I)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -145,7 +169,11 @@ async fn set_tcp_keep_alive_time(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.net.ExtendedOptionsImpl.setTcpKeepAliveTime(Ljava/io/FileDescriptor;I)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "setTcpKeepAliveTime",
+        "(Ljava/io/FileDescriptor;I)V",
+    )
 }
 
 #[cfg(test)]
@@ -153,46 +181,63 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.net.ExtendedOptionsImpl.flowSupported()Z")]
     async fn test_flow_supported() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = flow_supported(thread, Parameters::default()).await;
+        let result = flow_supported(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/net/ExtendedOptionsImpl",
+            "flowSupported",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.net.ExtendedOptionsImpl.getFlowOption(Ljava/io/FileDescriptor;Ljdk/net/SocketFlow;)V"
-    )]
     async fn test_get_flow_option() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_flow_option(thread, Parameters::default()).await;
+        let result = get_flow_option(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/net/ExtendedOptionsImpl",
+            "getFlowOption",
+            "(Ljava/io/FileDescriptor;Ljdk/net/SocketFlow;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.net.ExtendedOptionsImpl.getTcpKeepAliveIntvl(Ljava/io/FileDescriptor;)I"
-    )]
     async fn test_get_tcp_keep_alive_intvl() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_tcp_keep_alive_intvl(thread, Parameters::default()).await;
+        let result = get_tcp_keep_alive_intvl(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/net/ExtendedOptionsImpl",
+            "getTcpKeepAliveIntvl",
+            "(Ljava/io/FileDescriptor;)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.net.ExtendedOptionsImpl.getTcpKeepAliveProbes(Ljava/io/FileDescriptor;)I"
-    )]
     async fn test_get_tcp_keep_alive_probes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_tcp_keep_alive_probes(thread, Parameters::default()).await;
+        let result = get_tcp_keep_alive_probes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/net/ExtendedOptionsImpl",
+            "getTcpKeepAliveProbes",
+            "(Ljava/io/FileDescriptor;)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.net.ExtendedOptionsImpl.getTcpKeepAliveTime(Ljava/io/FileDescriptor;)I"
-    )]
     async fn test_get_tcp_keep_alive_time() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_tcp_keep_alive_time(thread, Parameters::default()).await;
+        let result = get_tcp_keep_alive_time(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/net/ExtendedOptionsImpl",
+            "getTcpKeepAliveTime",
+            "(Ljava/io/FileDescriptor;)I",
+        );
     }
 
     #[tokio::test]
@@ -204,47 +249,62 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.net.ExtendedOptionsImpl.keepAliveOptionsSupported()Z"
-    )]
     async fn test_keep_alive_options_supported() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = keep_alive_options_supported(thread, Parameters::default()).await;
+        let result = keep_alive_options_supported(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/net/ExtendedOptionsImpl",
+            "keepAliveOptionsSupported",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.net.ExtendedOptionsImpl.setFlowOption(Ljava/io/FileDescriptor;Ljdk/net/SocketFlow;)V"
-    )]
     async fn test_set_flow_option() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_flow_option(thread, Parameters::default()).await;
+        let result = set_flow_option(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/net/ExtendedOptionsImpl",
+            "setFlowOption",
+            "(Ljava/io/FileDescriptor;Ljdk/net/SocketFlow;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.net.ExtendedOptionsImpl.setTcpKeepAliveIntvl(Ljava/io/FileDescriptor;I)V"
-    )]
     async fn test_set_tcp_keep_alive_intvl() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_tcp_keep_alive_intvl(thread, Parameters::default()).await;
+        let result = set_tcp_keep_alive_intvl(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/net/ExtendedOptionsImpl",
+            "setTcpKeepAliveIntvl",
+            "(Ljava/io/FileDescriptor;I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.net.ExtendedOptionsImpl.setTcpKeepAliveProbes(Ljava/io/FileDescriptor;I)V"
-    )]
     async fn test_set_tcp_keep_alive_probes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_tcp_keep_alive_probes(thread, Parameters::default()).await;
+        let result = set_tcp_keep_alive_probes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/net/ExtendedOptionsImpl",
+            "setTcpKeepAliveProbes",
+            "(Ljava/io/FileDescriptor;I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.net.ExtendedOptionsImpl.setTcpKeepAliveTime(Ljava/io/FileDescriptor;I)V"
-    )]
     async fn test_set_tcp_keep_alive_time() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_tcp_keep_alive_time(thread, Parameters::default()).await;
+        let result = set_tcp_keep_alive_time(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/net/ExtendedOptionsImpl",
+            "setTcpKeepAliveTime",
+            "(Ljava/io/FileDescriptor;I)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -16,12 +16,12 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn get_lower_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.net.PortConfig.getLower0()I")
+    unsatisfied_link(CLASS_NAME, "getLower0", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn get_upper_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.net.PortConfig.getUpper0()I")
+    unsatisfied_link(CLASS_NAME, "getUpper0", "()I")
 }
 
 #[cfg(test)]
@@ -29,16 +29,16 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.net.PortConfig.getLower0()I")]
     async fn test_get_lower_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_lower_0(thread, Parameters::default()).await;
+        let result = get_lower_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/net/PortConfig", "getLower0", "()I");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.net.PortConfig.getUpper0()I")]
     async fn test_get_upper_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_upper_0(thread, Parameters::default()).await;
+        let result = get_upper_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/net/PortConfig", "getUpper0", "()I");
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -16,12 +16,12 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn convert_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.net.sdp.SdpSupport.convert0(I)V")
+    unsatisfied_link(CLASS_NAME, "convert0", "(I)V")
 }
 
 #[async_recursion(?Send)]
 async fn create_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.net.sdp.SdpSupport.create0()I")
+    unsatisfied_link(CLASS_NAME, "create0", "()I")
 }
 
 #[cfg(test)]
@@ -29,16 +29,16 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.net.sdp.SdpSupport.convert0(I)V")]
     async fn test_convert_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = convert_0(thread, Parameters::default()).await;
+        let result = convert_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/net/sdp/SdpSupport", "convert0", "(I)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.net.sdp.SdpSupport.create0()I")]
     async fn test_create_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_0(thread, Parameters::default()).await;
+        let result = create_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/net/sdp/SdpSupport", "create0", "()I");
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -34,12 +34,20 @@ async fn get_system_proxies(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.net.spi.DefaultProxySelector.getSystemProxies(Ljava/lang/String;Ljava/lang/String;)[Ljava/net/Proxy;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getSystemProxies",
+        "(Ljava/lang/String;Ljava/lang/String;)[Ljava/net/Proxy;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_system_proxy(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.net.spi.DefaultProxySelector.getSystemProxy(Ljava/lang/String;Ljava/lang/String;)Ljava/net/Proxy;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getSystemProxy",
+        "(Ljava/lang/String;Ljava/lang/String;)Ljava/net/Proxy;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -52,21 +60,27 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.net.spi.DefaultProxySelector.getSystemProxies(Ljava/lang/String;Ljava/lang/String;)[Ljava/net/Proxy;"
-    )]
     async fn test_get_system_proxies() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_system_proxies(thread, Parameters::default()).await;
+        let result = get_system_proxies(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/net/spi/DefaultProxySelector",
+            "getSystemProxies",
+            "(Ljava/lang/String;Ljava/lang/String;)[Ljava/net/Proxy;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.net.spi.DefaultProxySelector.getSystemProxy(Ljava/lang/String;Ljava/lang/String;)Ljava/net/Proxy;"
-    )]
     async fn test_get_system_proxy() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_system_proxy(thread, Parameters::default()).await;
+        let result = get_system_proxy(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/net/spi/DefaultProxySelector",
+            "getSystemProxy",
+            "(Ljava/lang/String;Ljava/lang/String;)Ljava/net/Proxy;",
+        );
     }
 
     #[tokio::test]
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -49,7 +49,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn disconnect_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.DatagramChannelImpl.disconnect0(Ljava/io/FileDescriptor;Z)V");
+    unsatisfied_link(CLASS_NAME, "disconnect0", "(Ljava/io/FileDescriptor;Z)V")
 }
 
 #[async_recursion(?Send)]
@@ -59,14 +59,16 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn receive_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.DatagramChannelImpl.receive0(Ljava/io/FileDescriptor;JIZ)I");
+    unsatisfied_link(CLASS_NAME, "receive0", "(Ljava/io/FileDescriptor;JIZ)I")
 }
 
 #[async_recursion(?Send)]
 async fn send_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!(
-        "sun.nio.ch.DatagramChannelImpl.send0(ZLjava/io/FileDescriptor;JILjava/net/InetAddress;I)I"
-    );
+    unsatisfied_link(
+        CLASS_NAME,
+        "send0",
+        "(ZLjava/io/FileDescriptor;JILjava/net/InetAddress;I)I",
+    )
 }
 
 #[cfg(test)]
@@ -74,12 +76,15 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.DatagramChannelImpl.disconnect0(Ljava/io/FileDescriptor;Z)V"
-    )]
     async fn test_disconnect_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = disconnect_0(thread, Parameters::default()).await;
+        let result = disconnect_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/DatagramChannelImpl",
+            "disconnect0",
+            "(Ljava/io/FileDescriptor;Z)V",
+        );
     }
 
     #[tokio::test]
@@ -91,20 +96,26 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.DatagramChannelImpl.receive0(Ljava/io/FileDescriptor;JIZ)I"
-    )]
     async fn test_receive_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = receive_0(thread, Parameters::default()).await;
+        let result = receive_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/DatagramChannelImpl",
+            "receive0",
+            "(Ljava/io/FileDescriptor;JIZ)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.DatagramChannelImpl.send0(ZLjava/io/FileDescriptor;JILjava/net/InetAddress;I)I"
-    )]
     async fn test_send_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = send_0(thread, Parameters::default()).await;
+        let result = send_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/DatagramChannelImpl",
+            "send0",
+            "(ZLjava/io/FileDescriptor;JILjava/net/InetAddress;I)I",
+        );
     }
 }
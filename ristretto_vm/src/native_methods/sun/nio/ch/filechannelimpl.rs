@@ -1,4 +1,6 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17, JAVA_21};
+use crate::native_methods::registry::{
+    unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17, JAVA_21,
+};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -63,12 +65,12 @@ async fn allocation_granularity_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileChannelImpl.allocationGranularity0()J");
+    unsatisfied_link(CLASS_NAME, "allocationGranularity0", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn map_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileChannelImpl.map0(IJJ)J");
+    unsatisfied_link(CLASS_NAME, "map0", "(IJJ)J")
 }
 
 #[async_recursion(?Send)]
@@ -76,22 +78,30 @@ async fn max_direct_transfer_size_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileChannelImpl.maxDirectTransferSize0()I");
+    unsatisfied_link(CLASS_NAME, "maxDirectTransferSize0", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn transfer_from_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileChannelImpl.transferFrom0(Ljava/io/FileDescriptor;Ljava/io/FileDescriptor;JJ)J");
+    unsatisfied_link(
+        CLASS_NAME,
+        "transferFrom0",
+        "(Ljava/io/FileDescriptor;Ljava/io/FileDescriptor;JJ)J",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn transfer_to_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileChannelImpl.transferTo0(Ljava/io/FileDescriptor;JJLjava/io/FileDescriptor;)J");
+    unsatisfied_link(
+        CLASS_NAME,
+        "transferTo0",
+        "(Ljava/io/FileDescriptor;JJLjava/io/FileDescriptor;)J",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn unmap_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileChannelImpl.unmap0(JJ)I");
+    unsatisfied_link(CLASS_NAME, "unmap0", "(JJ)I")
 }
 
 #[cfg(test)]
@@ -107,52 +117,74 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileChannelImpl.allocationGranularity0()J"
-    )]
     async fn test_allocation_granularity_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = allocation_granularity_0(thread, Parameters::default()).await;
+        let result = allocation_granularity_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileChannelImpl",
+            "allocationGranularity0",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.FileChannelImpl.map0(IJJ)J")]
     async fn test_map_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = map_0(thread, Parameters::default()).await;
+        let result = map_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileChannelImpl",
+            "map0",
+            "(IJJ)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileChannelImpl.maxDirectTransferSize0()I"
-    )]
     async fn test_max_direct_transfer_size_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = max_direct_transfer_size_0(thread, Parameters::default()).await;
+        let result = max_direct_transfer_size_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileChannelImpl",
+            "maxDirectTransferSize0",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileChannelImpl.transferFrom0(Ljava/io/FileDescriptor;Ljava/io/FileDescriptor;JJ)J"
-    )]
     async fn test_transfer_from_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = transfer_from_0(thread, Parameters::default()).await;
+        let result = transfer_from_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileChannelImpl",
+            "transferFrom0",
+            "(Ljava/io/FileDescriptor;Ljava/io/FileDescriptor;JJ)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileChannelImpl.transferTo0(Ljava/io/FileDescriptor;JJLjava/io/FileDescriptor;)J"
-    )]
     async fn test_transfer_to_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = transfer_to_0(thread, Parameters::default()).await;
+        let result = transfer_to_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileChannelImpl",
+            "transferTo0",
+            "(Ljava/io/FileDescriptor;JJLjava/io/FileDescriptor;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.FileChannelImpl.unmap0(JJ)I")]
     async fn test_unmap_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = unmap_0(thread, Parameters::default()).await;
+        let result = unmap_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileChannelImpl",
+            "unmap0",
+            "(JJ)I",
+        );
     }
 }
@@ -1,4 +1,6 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17, JAVA_21};
+use crate::native_methods::registry::{
+    unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17, JAVA_21,
+};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -122,28 +124,32 @@ async fn can_transfer_to_from_overlapped_map_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileDispatcherImpl.canTransferToFromOverlappedMap0()Z");
+    unsatisfied_link(CLASS_NAME, "canTransferToFromOverlappedMap0", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn close_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileDispatcherImpl.close0(Ljava/io/FileDescriptor;)V");
+    unsatisfied_link(CLASS_NAME, "close0", "(Ljava/io/FileDescriptor;)V")
 }
 
 #[async_recursion(?Send)]
 async fn close_int_fd(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileDispatcherImpl.closeIntFD(I)V");
+    unsatisfied_link(CLASS_NAME, "closeIntFD", "(I)V")
 }
 
 #[async_recursion(?Send)]
 async fn dup_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileDispatcherImpl.dup0(Ljava/io/FileDescriptor;Ljava/io/FileDescriptor;)V");
+    unsatisfied_link(
+        CLASS_NAME,
+        "dup0",
+        "(Ljava/io/FileDescriptor;Ljava/io/FileDescriptor;)V",
+    )
 }
 
 #[cfg(target_os = "macos")]
 #[async_recursion(?Send)]
 async fn force_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileDispatcherImpl.force0(Ljava/io/FileDescriptor;Z)I");
+    unsatisfied_link(CLASS_NAME, "force0", "(Ljava/io/FileDescriptor;Z)I")
 }
 
 #[async_recursion(?Send)]
@@ -153,72 +159,89 @@ async fn init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Va
 
 #[async_recursion(?Send)]
 async fn lock_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileDispatcherImpl.lock0(Ljava/io/FileDescriptor;ZJJZ)I");
+    unsatisfied_link(CLASS_NAME, "lock0", "(Ljava/io/FileDescriptor;ZJJZ)I")
 }
 
 #[async_recursion(?Send)]
 async fn pre_close_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileDispatcherImpl.preClose0(Ljava/io/FileDescriptor;)V");
+    unsatisfied_link(CLASS_NAME, "preClose0", "(Ljava/io/FileDescriptor;)V")
 }
 
 #[async_recursion(?Send)]
 async fn pread_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileDispatcherImpl.pread0(Ljava/io/FileDescriptor;JIJ)I");
+    unsatisfied_link(CLASS_NAME, "pread0", "(Ljava/io/FileDescriptor;JIJ)I")
 }
 
 #[async_recursion(?Send)]
 async fn pwrite_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileDispatcherImpl.pwrite0(Ljava/io/FileDescriptor;JIJ)I");
+    unsatisfied_link(CLASS_NAME, "pwrite0", "(Ljava/io/FileDescriptor;JIJ)I")
 }
 
 #[async_recursion(?Send)]
 async fn read_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileDispatcherImpl.read0(Ljava/io/FileDescriptor;JI)I");
+    unsatisfied_link(CLASS_NAME, "read0", "(Ljava/io/FileDescriptor;JI)I")
 }
 
 #[async_recursion(?Send)]
 async fn readv_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileDispatcherImpl.readv0(Ljava/io/FileDescriptor;JI)J");
+    unsatisfied_link(CLASS_NAME, "readv0", "(Ljava/io/FileDescriptor;JI)J")
 }
 
 #[async_recursion(?Send)]
 async fn release_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileDispatcherImpl.release0(Ljava/io/FileDescriptor;JJ)V");
+    unsatisfied_link(CLASS_NAME, "release0", "(Ljava/io/FileDescriptor;JJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn seek_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileDispatcherImpl.seek0(Ljava/io/FileDescriptor;J)J");
+    unsatisfied_link(CLASS_NAME, "seek0", "(Ljava/io/FileDescriptor;J)J")
 }
 
 #[async_recursion(?Send)]
 async fn set_direct_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileDispatcherImpl.setDirect0(Ljava/io/FileDescriptor;)I");
+    unsatisfied_link(CLASS_NAME, "setDirect0", "(Ljava/io/FileDescriptor;)I")
 }
 
 #[async_recursion(?Send)]
 async fn size_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileDispatcherImpl.size0(Ljava/io/FileDescriptor;)J");
+    unsatisfied_link(CLASS_NAME, "size0", "(Ljava/io/FileDescriptor;)J")
 }
 
 #[async_recursion(?Send)]
 async fn transfer_to_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileDispatcherImpl.transferTo0(Ljava/io/FileDescriptor;JJLjava/io/FileDescriptor;Z)J");
+    unsatisfied_link(
+        CLASS_NAME,
+        "transferTo0",
+        "(Ljava/io/FileDescriptor;JJLjava/io/FileDescriptor;Z)J",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn truncate_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileDispatcherImpl.truncate0(Ljava/io/FileDescriptor;J)I");
+    unsatisfied_link(CLASS_NAME, "truncate0", "(Ljava/io/FileDescriptor;J)I")
 }
 
 #[async_recursion(?Send)]
 async fn write_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileDispatcherImpl.write0(Ljava/io/FileDescriptor;JI)I");
+    unsatisfied_link(CLASS_NAME, "write0", "(Ljava/io/FileDescriptor;JI)I")
 }
 
 #[async_recursion(?Send)]
 async fn writev_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.FileDispatcherImpl.writev0(Ljava/io/FileDescriptor;JI)J");
+    unsatisfied_link(CLASS_NAME, "writev0", "(Ljava/io/FileDescriptor;JI)J")
+}
+
+#[cfg(all(test, target_os = "macos"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_init() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let result = init(thread, Parameters::default()).await?;
+        assert_eq!(result, None);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -226,47 +249,64 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileDispatcherImpl.canTransferToFromOverlappedMap0()Z"
-    )]
     async fn test_can_transfer_to_from_overlapped_map_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = can_transfer_to_from_overlapped_map_0(thread, Parameters::default()).await;
+        let result = can_transfer_to_from_overlapped_map_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileDispatcherImpl",
+            "canTransferToFromOverlappedMap0",
+            "()Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileDispatcherImpl.close0(Ljava/io/FileDescriptor;)V"
-    )]
     async fn test_close_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = close_0(thread, Parameters::default()).await;
+        let result = close_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileDispatcherImpl",
+            "close0",
+            "(Ljava/io/FileDescriptor;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.FileDispatcherImpl.closeIntFD(I)V")]
     async fn test_close_int_fd() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = close_int_fd(thread, Parameters::default()).await;
+        let result = close_int_fd(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileDispatcherImpl",
+            "closeIntFD",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileDispatcherImpl.dup0(Ljava/io/FileDescriptor;Ljava/io/FileDescriptor;)V"
-    )]
     async fn test_dup_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = dup_0(thread, Parameters::default()).await;
+        let result = dup_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileDispatcherImpl",
+            "dup0",
+            "(Ljava/io/FileDescriptor;Ljava/io/FileDescriptor;)V",
+        );
     }
 
     #[cfg(target_os = "macos")]
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileDispatcherImpl.force0(Ljava/io/FileDescriptor;Z)I"
-    )]
     async fn test_force_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = force_0(thread, Parameters::default()).await;
+        let result = force_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileDispatcherImpl",
+            "force0",
+            "(Ljava/io/FileDescriptor;Z)I",
+        );
     }
 
     #[tokio::test]
@@ -278,128 +318,170 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileDispatcherImpl.lock0(Ljava/io/FileDescriptor;ZJJZ)I"
-    )]
     async fn test_lock_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = lock_0(thread, Parameters::default()).await;
+        let result = lock_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileDispatcherImpl",
+            "lock0",
+            "(Ljava/io/FileDescriptor;ZJJZ)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileDispatcherImpl.preClose0(Ljava/io/FileDescriptor;)V"
-    )]
     async fn test_pre_close_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = pre_close_0(thread, Parameters::default()).await;
+        let result = pre_close_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileDispatcherImpl",
+            "preClose0",
+            "(Ljava/io/FileDescriptor;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileDispatcherImpl.pread0(Ljava/io/FileDescriptor;JIJ)I"
-    )]
     async fn test_pread_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = pread_0(thread, Parameters::default()).await;
+        let result = pread_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileDispatcherImpl",
+            "pread0",
+            "(Ljava/io/FileDescriptor;JIJ)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileDispatcherImpl.pwrite0(Ljava/io/FileDescriptor;JIJ)I"
-    )]
     async fn test_pwrite_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = pwrite_0(thread, Parameters::default()).await;
+        let result = pwrite_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileDispatcherImpl",
+            "pwrite0",
+            "(Ljava/io/FileDescriptor;JIJ)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileDispatcherImpl.read0(Ljava/io/FileDescriptor;JI)I"
-    )]
     async fn test_read_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = read_0(thread, Parameters::default()).await;
+        let result = read_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileDispatcherImpl",
+            "read0",
+            "(Ljava/io/FileDescriptor;JI)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileDispatcherImpl.readv0(Ljava/io/FileDescriptor;JI)J"
-    )]
     async fn test_readv_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = readv_0(thread, Parameters::default()).await;
+        let result = readv_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileDispatcherImpl",
+            "readv0",
+            "(Ljava/io/FileDescriptor;JI)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileDispatcherImpl.release0(Ljava/io/FileDescriptor;JJ)V"
-    )]
     async fn test_release_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = release_0(thread, Parameters::default()).await;
+        let result = release_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileDispatcherImpl",
+            "release0",
+            "(Ljava/io/FileDescriptor;JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileDispatcherImpl.seek0(Ljava/io/FileDescriptor;J)J"
-    )]
     async fn test_seek_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = seek_0(thread, Parameters::default()).await;
+        let result = seek_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileDispatcherImpl",
+            "seek0",
+            "(Ljava/io/FileDescriptor;J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileDispatcherImpl.setDirect0(Ljava/io/FileDescriptor;)I"
-    )]
     async fn test_set_direct_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_direct_0(thread, Parameters::default()).await;
+        let result = set_direct_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileDispatcherImpl",
+            "setDirect0",
+            "(Ljava/io/FileDescriptor;)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileDispatcherImpl.size0(Ljava/io/FileDescriptor;)J"
-    )]
     async fn test_size_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = size_0(thread, Parameters::default()).await;
+        let result = size_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileDispatcherImpl",
+            "size0",
+            "(Ljava/io/FileDescriptor;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileDispatcherImpl.transferTo0(Ljava/io/FileDescriptor;JJLjava/io/FileDescriptor;Z)J"
-    )]
     async fn test_transfer_to_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = transfer_to_0(thread, Parameters::default()).await;
+        let result = transfer_to_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileDispatcherImpl",
+            "transferTo0",
+            "(Ljava/io/FileDescriptor;JJLjava/io/FileDescriptor;Z)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileDispatcherImpl.truncate0(Ljava/io/FileDescriptor;J)I"
-    )]
     async fn test_truncate_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = truncate_0(thread, Parameters::default()).await;
+        let result = truncate_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileDispatcherImpl",
+            "truncate0",
+            "(Ljava/io/FileDescriptor;J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileDispatcherImpl.write0(Ljava/io/FileDescriptor;JI)I"
-    )]
     async fn test_write_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = write_0(thread, Parameters::default()).await;
+        let result = write_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileDispatcherImpl",
+            "write0",
+            "(Ljava/io/FileDescriptor;JI)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.FileDispatcherImpl.writev0(Ljava/io/FileDescriptor;JI)J"
-    )]
     async fn test_writev_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = writev_0(thread, Parameters::default()).await;
+        let result = writev_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/FileDispatcherImpl",
+            "writev0",
+            "(Ljava/io/FileDescriptor;JI)J",
+        );
     }
 }
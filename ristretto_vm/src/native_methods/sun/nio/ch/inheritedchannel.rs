@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -44,22 +44,22 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn address_family(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.InheritedChannel.addressFamily(I)I");
+    unsatisfied_link(CLASS_NAME, "addressFamily", "(I)I")
 }
 
 #[async_recursion(?Send)]
 async fn close_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.InheritedChannel.close0(I)V");
+    unsatisfied_link(CLASS_NAME, "close0", "(I)V")
 }
 
 #[async_recursion(?Send)]
 async fn dup(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.InheritedChannel.dup(I)I");
+    unsatisfied_link(CLASS_NAME, "dup", "(I)I")
 }
 
 #[async_recursion(?Send)]
 async fn dup_2(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.InheritedChannel.dup2(II)V");
+    unsatisfied_link(CLASS_NAME, "dup2", "(II)V")
 }
 
 #[async_recursion(?Send)]
@@ -67,7 +67,7 @@ async fn inet_peer_address_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.InheritedChannel.inetPeerAddress0(I)Ljava/net/InetAddress;");
+    unsatisfied_link(CLASS_NAME, "inetPeerAddress0", "(I)Ljava/net/InetAddress;")
 }
 
 #[async_recursion(?Send)]
@@ -77,27 +77,27 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn is_connected(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.InheritedChannel.isConnected(I)Z");
+    unsatisfied_link(CLASS_NAME, "isConnected", "(I)Z")
 }
 
 #[async_recursion(?Send)]
 async fn open_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.InheritedChannel.open0(Ljava/lang/String;I)I");
+    unsatisfied_link(CLASS_NAME, "open0", "(Ljava/lang/String;I)I")
 }
 
 #[async_recursion(?Send)]
 async fn peer_address_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.InheritedChannel.peerAddress0(I)Ljava/net/InetAddress;");
+    unsatisfied_link(CLASS_NAME, "peerAddress0", "(I)Ljava/net/InetAddress;")
 }
 
 #[async_recursion(?Send)]
 async fn peer_port_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.InheritedChannel.peerPort0(I)I");
+    unsatisfied_link(CLASS_NAME, "peerPort0", "(I)I")
 }
 
 #[async_recursion(?Send)]
 async fn so_type_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.InheritedChannel.soType0(I)I");
+    unsatisfied_link(CLASS_NAME, "soType0", "(I)I")
 }
 
 #[async_recursion(?Send)]
@@ -105,7 +105,7 @@ async fn unix_peer_address_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.InheritedChannel.unixPeerAddress0(I)[B");
+    unsatisfied_link(CLASS_NAME, "unixPeerAddress0", "(I)[B")
 }
 
 #[cfg(test)]
@@ -113,38 +113,58 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.InheritedChannel.close0(I)V")]
     async fn test_close_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = close_0(thread, Parameters::default()).await;
+        let result = close_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/InheritedChannel",
+            "close0",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.InheritedChannel.dup(I)I")]
     async fn test_dup() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = dup(thread, Parameters::default()).await;
+        let result = dup(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/InheritedChannel", "dup", "(I)I");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.InheritedChannel.dup2(II)V")]
     async fn test_dup_2() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = dup_2(thread, Parameters::default()).await;
+        let result = dup_2(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/InheritedChannel",
+            "dup2",
+            "(II)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.InheritedChannel.peerPort0(I)I")]
     async fn test_peer_port_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = peer_port_0(thread, Parameters::default()).await;
+        let result = peer_port_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/InheritedChannel",
+            "peerPort0",
+            "(I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.InheritedChannel.soType0(I)I")]
     async fn test_so_type_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = so_type_0(thread, Parameters::default()).await;
+        let result = so_type_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/InheritedChannel",
+            "soType0",
+            "(I)I",
+        );
     }
 
     #[tokio::test]
@@ -156,52 +176,74 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.InheritedChannel.inetPeerAddress0(I)Ljava/net/InetAddress;"
-    )]
     async fn test_inet_peer_address_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = inet_peer_address_0(thread, Parameters::default()).await;
+        let result = inet_peer_address_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/InheritedChannel",
+            "inetPeerAddress0",
+            "(I)Ljava/net/InetAddress;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.InheritedChannel.addressFamily(I)I")]
     async fn test_address_family() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = address_family(thread, Parameters::default()).await;
+        let result = address_family(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/InheritedChannel",
+            "addressFamily",
+            "(I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.InheritedChannel.isConnected(I)Z")]
     async fn test_is_connected() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_connected(thread, Parameters::default()).await;
+        let result = is_connected(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/InheritedChannel",
+            "isConnected",
+            "(I)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.InheritedChannel.open0(Ljava/lang/String;I)I"
-    )]
     async fn test_open_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = open_0(thread, Parameters::default()).await;
+        let result = open_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/InheritedChannel",
+            "open0",
+            "(Ljava/lang/String;I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.InheritedChannel.peerAddress0(I)Ljava/net/InetAddress;"
-    )]
     async fn test_peer_address_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = peer_address_0(thread, Parameters::default()).await;
+        let result = peer_address_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/InheritedChannel",
+            "peerAddress0",
+            "(I)Ljava/net/InetAddress;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.InheritedChannel.unixPeerAddress0(I)[B"
-    )]
     async fn test_unix_peer_address_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = unix_peer_address_0(thread, Parameters::default()).await;
+        let result = unix_peer_address_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/InheritedChannel",
+            "unixPeerAddress0",
+            "(I)[B",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_21};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_11, JAVA_21};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -45,27 +45,31 @@ async fn configure_blocking(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.IOUtil.configureBlocking(Ljava/io/FileDescriptor;Z)V");
+    unsatisfied_link(
+        CLASS_NAME,
+        "configureBlocking",
+        "(Ljava/io/FileDescriptor;Z)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn drain(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.IOUtil.drain(I)Z");
+    unsatisfied_link(CLASS_NAME, "drain", "(I)Z")
 }
 
 #[async_recursion(?Send)]
 async fn drain_1(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.IOUtil.drain1(I)I");
+    unsatisfied_link(CLASS_NAME, "drain1", "(I)I")
 }
 
 #[async_recursion(?Send)]
 async fn fd_limit(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.IOUtil.fdLimit()I");
+    unsatisfied_link(CLASS_NAME, "fdLimit", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn fd_val(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.IOUtil.fdVal(Ljava/io/FileDescriptor;)I");
+    unsatisfied_link(CLASS_NAME, "fdVal", "(Ljava/io/FileDescriptor;)I")
 }
 
 #[async_recursion(?Send)]
@@ -80,22 +84,22 @@ async fn iov_max(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option
 
 #[async_recursion(?Send)]
 async fn make_pipe(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.IOUtil.makePipe(Z)J");
+    unsatisfied_link(CLASS_NAME, "makePipe", "(Z)J")
 }
 
 #[async_recursion(?Send)]
 async fn random_bytes(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.IOUtil.randomBytes([B)Z");
+    unsatisfied_link(CLASS_NAME, "randomBytes", "([B)Z")
 }
 
 #[async_recursion(?Send)]
 async fn setfd_val(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.IOUtil.setfdVal(Ljava/io/FileDescriptor;I)V");
+    unsatisfied_link(CLASS_NAME, "setfdVal", "(Ljava/io/FileDescriptor;I)V")
 }
 
 #[async_recursion(?Send)]
 async fn write_1(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.IOUtil.write1(IB)I");
+    unsatisfied_link(CLASS_NAME, "write1", "(IB)I")
 }
 
 #[async_recursion(?Send)]
@@ -116,35 +120,41 @@ mod tests {
     use std::env::consts::OS;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.IOUtil.configureBlocking(Ljava/io/FileDescriptor;Z)V"
-    )]
     async fn test_configure_blocking() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = configure_blocking(thread, Parameters::default()).await;
+        let result = configure_blocking(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/IOUtil",
+            "configureBlocking",
+            "(Ljava/io/FileDescriptor;Z)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.IOUtil.drain(I)Z")]
     async fn test_drain() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = drain(thread, Parameters::default()).await;
+        let result = drain(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/IOUtil", "drain", "(I)Z");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.IOUtil.fdLimit()I")]
     async fn test_fd_limit() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fd_limit(thread, Parameters::default()).await;
+        let result = fd_limit(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/IOUtil", "fdLimit", "()I");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.IOUtil.fdVal(Ljava/io/FileDescriptor;)I"
-    )]
     async fn test_fd_val() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fd_val(thread, Parameters::default()).await;
+        let result = fd_val(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/IOUtil",
+            "fdVal",
+            "(Ljava/io/FileDescriptor;)I",
+        );
     }
 
     #[tokio::test]
@@ -164,40 +174,43 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.IOUtil.makePipe(Z)J")]
     async fn test_make_pipe() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = make_pipe(thread, Parameters::default()).await;
+        let result = make_pipe(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/IOUtil", "makePipe", "(Z)J");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.IOUtil.randomBytes([B)Z")]
     async fn test_random_bytes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = random_bytes(thread, Parameters::default()).await;
+        let result = random_bytes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/IOUtil", "randomBytes", "([B)Z");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.IOUtil.setfdVal(Ljava/io/FileDescriptor;I)V"
-    )]
     async fn test_setfd_val() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = setfd_val(thread, Parameters::default()).await;
+        let result = setfd_val(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/IOUtil",
+            "setfdVal",
+            "(Ljava/io/FileDescriptor;I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.IOUtil.drain1(I)I")]
     async fn test_drain_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = drain_1(thread, Parameters::default()).await;
+        let result = drain_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/IOUtil", "drain1", "(I)I");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.IOUtil.write1(IB)I")]
     async fn test_write_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = write_1(thread, Parameters::default()).await;
+        let result = write_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/IOUtil", "write1", "(IB)I");
     }
 
     #[tokio::test]
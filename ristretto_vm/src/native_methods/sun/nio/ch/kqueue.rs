@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -28,52 +28,52 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn create(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.KQueue.create()I");
+    unsatisfied_link(CLASS_NAME, "create", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn filter_offset(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.KQueue.filterOffset()I");
+    unsatisfied_link(CLASS_NAME, "filterOffset", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn flags_offset(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.KQueue.flagsOffset()I");
+    unsatisfied_link(CLASS_NAME, "flagsOffset", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn ident_offset(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.KQueue.identOffset()I");
+    unsatisfied_link(CLASS_NAME, "identOffset", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn kevent_poll(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.KQueue.keventPoll(IJI)I");
+    unsatisfied_link(CLASS_NAME, "keventPoll", "(IJI)I")
 }
 
 #[async_recursion(?Send)]
 async fn kevent_register(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.KQueue.keventRegister(IIII)I");
+    unsatisfied_link(CLASS_NAME, "keventRegister", "(IIII)I")
 }
 
 #[async_recursion(?Send)]
 async fn kevent_size(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.KQueue.keventSize()I");
+    unsatisfied_link(CLASS_NAME, "keventSize", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn kqueue(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.KQueue.kqueue()I");
+    unsatisfied_link(CLASS_NAME, "kqueue", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn poll(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.KQueue.poll(IJIJ)I");
+    unsatisfied_link(CLASS_NAME, "poll", "(IJIJ)I")
 }
 
 #[async_recursion(?Send)]
 async fn register_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.KQueue.register(IIII)I");
+    unsatisfied_link(CLASS_NAME, "register", "(IIII)I")
 }
 
 #[cfg(test)]
@@ -81,72 +81,77 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.KQueue.create()I")]
     async fn test_create() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create(thread, Parameters::default()).await;
+        let result = create(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/KQueue", "create", "()I");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.KQueue.filterOffset()I")]
     async fn test_filter_offset() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = filter_offset(thread, Parameters::default()).await;
+        let result = filter_offset(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/KQueue", "filterOffset", "()I");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.KQueue.flagsOffset()I")]
     async fn test_flags_offset() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = flags_offset(thread, Parameters::default()).await;
+        let result = flags_offset(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/KQueue", "flagsOffset", "()I");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.KQueue.identOffset()I")]
     async fn test_ident_offset() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = ident_offset(thread, Parameters::default()).await;
+        let result = ident_offset(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/KQueue", "identOffset", "()I");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.KQueue.keventPoll(IJI)I")]
     async fn test_kevent_poll() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = kevent_poll(thread, Parameters::default()).await;
+        let result = kevent_poll(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/KQueue", "keventPoll", "(IJI)I");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.KQueue.keventRegister(IIII)I")]
     async fn test_kevent_register() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = kevent_register(thread, Parameters::default()).await;
+        let result = kevent_register(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/KQueue",
+            "keventRegister",
+            "(IIII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.KQueue.keventSize()I")]
     async fn test_kevent_size() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = kevent_size(thread, Parameters::default()).await;
+        let result = kevent_size(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/KQueue", "keventSize", "()I");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.KQueue.kqueue()I")]
     async fn test_kqueue() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = kqueue(thread, Parameters::default()).await;
+        let result = kqueue(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/KQueue", "kqueue", "()I");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.KQueue.poll(IJIJ)I")]
     async fn test_poll() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = poll(thread, Parameters::default()).await;
+        let result = poll(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/KQueue", "poll", "(IJIJ)I");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.KQueue.register(IIII)I")]
     async fn test_register_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = register_0(thread, Parameters::default()).await;
+        let result = register_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/KQueue", "register", "(IIII)I");
     }
 }
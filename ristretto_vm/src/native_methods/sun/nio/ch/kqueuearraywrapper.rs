@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -19,27 +19,27 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.KQueueArrayWrapper.init()I");
+    unsatisfied_link(CLASS_NAME, "init", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn init_struct_sizes(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.KQueueArrayWrapper.initStructSizes()V");
+    unsatisfied_link(CLASS_NAME, "initStructSizes", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn interrupt(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.KQueueArrayWrapper.interrupt(I)V");
+    unsatisfied_link(CLASS_NAME, "interrupt", "(I)V")
 }
 
 #[async_recursion(?Send)]
 async fn kevent_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.KQueueArrayWrapper.kevent0(IJIJ)I");
+    unsatisfied_link(CLASS_NAME, "kevent0", "(IJIJ)I")
 }
 
 #[async_recursion(?Send)]
 async fn register_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.KQueueArrayWrapper.register0(IIII)V");
+    unsatisfied_link(CLASS_NAME, "register0", "(IIII)V")
 }
 
 #[cfg(test)]
@@ -47,41 +47,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.KQueueArrayWrapper.init()I")]
     async fn test_init() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init(thread, Parameters::default()).await;
+        let result = init(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/KQueueArrayWrapper",
+            "init",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.KQueueArrayWrapper.initStructSizes()V"
-    )]
     async fn test_init_struct_sizes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init_struct_sizes(thread, Parameters::default()).await;
+        let result = init_struct_sizes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/KQueueArrayWrapper",
+            "initStructSizes",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.KQueueArrayWrapper.interrupt(I)V")]
     async fn test_interrupt() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = interrupt(thread, Parameters::default()).await;
+        let result = interrupt(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/KQueueArrayWrapper",
+            "interrupt",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.KQueueArrayWrapper.kevent0(IJIJ)I")]
     async fn test_kevent_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = kevent_0(thread, Parameters::default()).await;
+        let result = kevent_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/KQueueArrayWrapper",
+            "kevent0",
+            "(IJIJ)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.KQueueArrayWrapper.register0(IIII)V"
-    )]
     async fn test_register_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = register_0(thread, Parameters::default()).await;
+        let result = register_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/KQueueArrayWrapper",
+            "register0",
+            "(IIII)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -18,22 +18,22 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn close_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.KQueuePort.close0(I)V");
+    unsatisfied_link(CLASS_NAME, "close0", "(I)V")
 }
 
 #[async_recursion(?Send)]
 async fn drain_1(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.KQueuePort.drain1(I)V");
+    unsatisfied_link(CLASS_NAME, "drain1", "(I)V")
 }
 
 #[async_recursion(?Send)]
 async fn interrupt(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.KQueuePort.interrupt(I)V");
+    unsatisfied_link(CLASS_NAME, "interrupt", "(I)V")
 }
 
 #[async_recursion(?Send)]
 async fn socketpair(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.KQueuePort.socketpair([I)V");
+    unsatisfied_link(CLASS_NAME, "socketpair", "([I)V")
 }
 
 #[cfg(test)]
@@ -41,30 +41,35 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.KQueuePort.close0(I)V")]
     async fn test_close_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = close_0(thread, Parameters::default()).await;
+        let result = close_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/KQueuePort", "close0", "(I)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.KQueuePort.drain1(I)V")]
     async fn test_drain_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = drain_1(thread, Parameters::default()).await;
+        let result = drain_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/KQueuePort", "drain1", "(I)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.KQueuePort.interrupt(I)V")]
     async fn test_interrupt() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = interrupt(thread, Parameters::default()).await;
+        let result = interrupt(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/KQueuePort", "interrupt", "(I)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.KQueuePort.socketpair([I)V")]
     async fn test_socketpair() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = socketpair(thread, Parameters::default()).await;
+        let result = socketpair(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/KQueuePort",
+            "socketpair",
+            "([I)V",
+        );
     }
 }
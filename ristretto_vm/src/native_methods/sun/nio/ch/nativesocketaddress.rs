@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -36,32 +36,32 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn afinet(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.NativeSocketAddress.AF_INET()I");
+    unsatisfied_link(CLASS_NAME, "AF_INET", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn afinet_6(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.NativeSocketAddress.AF_INET6()I");
+    unsatisfied_link(CLASS_NAME, "AF_INET6", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn offset_family(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.NativeSocketAddress.offsetFamily()I");
+    unsatisfied_link(CLASS_NAME, "offsetFamily", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn offset_sin_4_addr(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.NativeSocketAddress.offsetSin4Addr()I");
+    unsatisfied_link(CLASS_NAME, "offsetSin4Addr", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn offset_sin_4_port(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.NativeSocketAddress.offsetSin4Port()I");
+    unsatisfied_link(CLASS_NAME, "offsetSin4Port", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn offset_sin_6_addr(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.NativeSocketAddress.offsetSin6Addr()I");
+    unsatisfied_link(CLASS_NAME, "offsetSin6Addr", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -69,12 +69,12 @@ async fn offset_sin_6_flow_info(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.NativeSocketAddress.offsetSin6FlowInfo()I");
+    unsatisfied_link(CLASS_NAME, "offsetSin6FlowInfo", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn offset_sin_6_port(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.NativeSocketAddress.offsetSin6Port()I");
+    unsatisfied_link(CLASS_NAME, "offsetSin6Port", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -82,12 +82,12 @@ async fn offset_sin_6_scope_id(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.NativeSocketAddress.offsetSin6ScopeId()I");
+    unsatisfied_link(CLASS_NAME, "offsetSin6ScopeId", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn sizeof_family(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.NativeSocketAddress.sizeofFamily()I");
+    unsatisfied_link(CLASS_NAME, "sizeofFamily", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -95,7 +95,7 @@ async fn sizeof_sock_addr_4(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.NativeSocketAddress.sizeofSockAddr4()I");
+    unsatisfied_link(CLASS_NAME, "sizeofSockAddr4", "()I")
 }
 
 #[async_recursion(?Send)]
@@ -103,7 +103,7 @@ async fn sizeof_sock_addr_6(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.NativeSocketAddress.sizeofSockAddr6()I");
+    unsatisfied_link(CLASS_NAME, "sizeofSockAddr6", "()I")
 }
 
 #[cfg(test)]
@@ -111,106 +111,146 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.NativeSocketAddress.AF_INET()I")]
     async fn test_afinet() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = afinet(thread, Parameters::default()).await;
+        let result = afinet(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/NativeSocketAddress",
+            "AF_INET",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.NativeSocketAddress.AF_INET6()I")]
     async fn test_afinet_6() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = afinet_6(thread, Parameters::default()).await;
+        let result = afinet_6(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/NativeSocketAddress",
+            "AF_INET6",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.NativeSocketAddress.offsetFamily()I"
-    )]
     async fn test_offset_family() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = offset_family(thread, Parameters::default()).await;
+        let result = offset_family(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/NativeSocketAddress",
+            "offsetFamily",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.NativeSocketAddress.offsetSin4Addr()I"
-    )]
     async fn test_offset_sin_4_addr() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = offset_sin_4_addr(thread, Parameters::default()).await;
+        let result = offset_sin_4_addr(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/NativeSocketAddress",
+            "offsetSin4Addr",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.NativeSocketAddress.offsetSin4Port()I"
-    )]
     async fn test_offset_sin_4_port() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = offset_sin_4_port(thread, Parameters::default()).await;
+        let result = offset_sin_4_port(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/NativeSocketAddress",
+            "offsetSin4Port",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.NativeSocketAddress.offsetSin6Addr()I"
-    )]
     async fn test_offset_sin_6_addr() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = offset_sin_6_addr(thread, Parameters::default()).await;
+        let result = offset_sin_6_addr(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/NativeSocketAddress",
+            "offsetSin6Addr",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.NativeSocketAddress.offsetSin6FlowInfo()I"
-    )]
     async fn test_offset_sin_6_flow_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = offset_sin_6_flow_info(thread, Parameters::default()).await;
+        let result = offset_sin_6_flow_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/NativeSocketAddress",
+            "offsetSin6FlowInfo",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.NativeSocketAddress.offsetSin6Port()I"
-    )]
     async fn test_offset_sin_6_port() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = offset_sin_6_port(thread, Parameters::default()).await;
+        let result = offset_sin_6_port(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/NativeSocketAddress",
+            "offsetSin6Port",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.NativeSocketAddress.offsetSin6ScopeId()I"
-    )]
     async fn test_offset_sin_6_scope_id() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = offset_sin_6_scope_id(thread, Parameters::default()).await;
+        let result = offset_sin_6_scope_id(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/NativeSocketAddress",
+            "offsetSin6ScopeId",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.NativeSocketAddress.sizeofFamily()I"
-    )]
     async fn test_sizeof_family() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = sizeof_family(thread, Parameters::default()).await;
+        let result = sizeof_family(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/NativeSocketAddress",
+            "sizeofFamily",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.NativeSocketAddress.sizeofSockAddr4()I"
-    )]
     async fn test_sizeof_sock_addr_4() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = sizeof_sock_addr_4(thread, Parameters::default()).await;
+        let result = sizeof_sock_addr_4(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/NativeSocketAddress",
+            "sizeofSockAddr4",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.NativeSocketAddress.sizeofSockAddr6()I"
-    )]
     async fn test_sizeof_sock_addr_6() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = sizeof_sock_addr_6(thread, Parameters::default()).await;
+        let result = sizeof_sock_addr_6(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/NativeSocketAddress",
+            "sizeofSockAddr6",
+            "()I",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_17};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_17};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,7 +23,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn current(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.NativeThread.current()J");
+    unsatisfied_link(CLASS_NAME, "current", "()J")
 }
 
 #[async_recursion(?Send)]
@@ -38,7 +38,7 @@ async fn init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Va
 
 #[async_recursion(?Send)]
 async fn signal(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.NativeThread.signal(J)V");
+    unsatisfied_link(CLASS_NAME, "signal", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -51,31 +51,31 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.NativeThread.current()J")]
     async fn test_current() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = current(thread, Parameters::default()).await;
+        let result = current(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/NativeThread", "current", "()J");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.NativeThread.current()J")]
     async fn test_current_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = current_0(thread, Parameters::default()).await;
+        let result = current_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/NativeThread", "current", "()J");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.NativeThread.signal(J)V")]
     async fn test_signal() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = signal(thread, Parameters::default()).await;
+        let result = signal(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/NativeThread", "signal", "(J)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.NativeThread.signal(J)V")]
     async fn test_signal_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = signal_0(thread, Parameters::default()).await;
+        let result = signal_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/NativeThread", "signal", "(J)V");
     }
 
     #[tokio::test]
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -16,12 +16,12 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn interrupt(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.PollArrayWrapper.interrupt(I)V")
+    unsatisfied_link(CLASS_NAME, "interrupt", "(I)V")
 }
 
 #[async_recursion(?Send)]
 async fn poll_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.PollArrayWrapper.poll0(JIJ)I")
+    unsatisfied_link(CLASS_NAME, "poll0", "(JIJ)I")
 }
 
 #[cfg(test)]
@@ -29,16 +29,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.PollArrayWrapper.interrupt(I)V")]
     async fn test_interrupt() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = interrupt(thread, Parameters::default()).await;
+        let result = interrupt(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/PollArrayWrapper",
+            "interrupt",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.PollArrayWrapper.poll0(JIJ)I")]
     async fn test_poll_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = poll_0(thread, Parameters::default()).await;
+        let result = poll_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/PollArrayWrapper",
+            "poll0",
+            "(JIJ)I",
+        );
     }
 }
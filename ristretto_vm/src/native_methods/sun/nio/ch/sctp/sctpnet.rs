@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -77,22 +77,22 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn bindx(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.sctp.SctpNet.bindx(I[Ljava/net/InetAddress;IIZZ)V")
+    unsatisfied_link(CLASS_NAME, "bindx", "(I[Ljava/net/InetAddress;IIZZ)V")
 }
 
 #[async_recursion(?Send)]
 async fn branch_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.sctp.SctpNet.branch0(II)I")
+    unsatisfied_link(CLASS_NAME, "branch0", "(II)I")
 }
 
 #[async_recursion(?Send)]
 async fn close_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.sctp.SctpNet.close0(I)V")
+    unsatisfied_link(CLASS_NAME, "close0", "(I)V")
 }
 
 #[async_recursion(?Send)]
 async fn connect_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.sctp.SctpNet.connect0(ILjava/net/InetAddress;I)I")
+    unsatisfied_link(CLASS_NAME, "connect0", "(ILjava/net/InetAddress;I)I")
 }
 
 #[async_recursion(?Send)]
@@ -100,12 +100,12 @@ async fn get_init_msg_option_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.sctp.SctpNet.getInitMsgOption0(I[I)V")
+    unsatisfied_link(CLASS_NAME, "getInitMsgOption0", "(I[I)V")
 }
 
 #[async_recursion(?Send)]
 async fn get_int_option_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.sctp.SctpNet.getIntOption0(II)I")
+    unsatisfied_link(CLASS_NAME, "getIntOption0", "(II)I")
 }
 
 #[async_recursion(?Send)]
@@ -113,7 +113,11 @@ async fn get_local_addresses_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.sctp.SctpNet.getLocalAddresses0(I)[Ljava/net/SocketAddress;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getLocalAddresses0",
+        "(I)[Ljava/net/SocketAddress;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -121,7 +125,11 @@ async fn get_prim_addr_option_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.sctp.SctpNet.getPrimAddrOption0(II)Ljava/net/SocketAddress;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getPrimAddrOption0",
+        "(II)Ljava/net/SocketAddress;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -129,7 +137,11 @@ async fn get_remote_addresses_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.sctp.SctpNet.getRemoteAddresses0(II)[Ljava/net/SocketAddress;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getRemoteAddresses0",
+        "(II)[Ljava/net/SocketAddress;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -139,12 +151,12 @@ async fn init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Va
 
 #[async_recursion(?Send)]
 async fn listen_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.sctp.SctpNet.listen0(II)V")
+    unsatisfied_link(CLASS_NAME, "listen0", "(II)V")
 }
 
 #[async_recursion(?Send)]
 async fn pre_close_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.sctp.SctpNet.preClose0(I)V")
+    unsatisfied_link(CLASS_NAME, "preClose0", "(I)V")
 }
 
 #[async_recursion(?Send)]
@@ -152,12 +164,12 @@ async fn set_init_msg_option_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.sctp.SctpNet.setInitMsgOption0(III)V")
+    unsatisfied_link(CLASS_NAME, "setInitMsgOption0", "(III)V")
 }
 
 #[async_recursion(?Send)]
 async fn set_int_option_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.sctp.SctpNet.setIntOption0(III)V")
+    unsatisfied_link(CLASS_NAME, "setIntOption0", "(III)V")
 }
 
 #[async_recursion(?Send)]
@@ -165,7 +177,11 @@ async fn set_peer_prim_addr_option_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.sctp.SctpNet.setPeerPrimAddrOption0(IILjava/net/InetAddress;IZ)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "setPeerPrimAddrOption0",
+        "(IILjava/net/InetAddress;IZ)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -173,17 +189,21 @@ async fn set_prim_addr_option_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.sctp.SctpNet.setPrimAddrOption0(IILjava/net/InetAddress;I)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "setPrimAddrOption0",
+        "(IILjava/net/InetAddress;I)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn shutdown_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.sctp.SctpNet.shutdown0(II)V")
+    unsatisfied_link(CLASS_NAME, "shutdown0", "(II)V")
 }
 
 #[async_recursion(?Send)]
 async fn socket_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.sctp.SctpNet.socket0(Z)I")
+    unsatisfied_link(CLASS_NAME, "socket0", "(Z)I")
 }
 
 #[cfg(test)]
@@ -191,78 +211,101 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.sctp.SctpNet.bindx(I[Ljava/net/InetAddress;IIZZ)V"
-    )]
     async fn test_bindx() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = bindx(thread, Parameters::default()).await;
+        let result = bindx(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/sctp/SctpNet",
+            "bindx",
+            "(I[Ljava/net/InetAddress;IIZZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.sctp.SctpNet.branch0(II)I")]
     async fn test_branch_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = branch_0(thread, Parameters::default()).await;
+        let result = branch_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/sctp/SctpNet", "branch0", "(II)I");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.sctp.SctpNet.close0(I)V")]
     async fn test_close_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = close_0(thread, Parameters::default()).await;
+        let result = close_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/sctp/SctpNet", "close0", "(I)V");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.sctp.SctpNet.connect0(ILjava/net/InetAddress;I)I"
-    )]
     async fn test_connect_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = connect_0(thread, Parameters::default()).await;
+        let result = connect_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/sctp/SctpNet",
+            "connect0",
+            "(ILjava/net/InetAddress;I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.sctp.SctpNet.getInitMsgOption0(I[I)V"
-    )]
     async fn test_get_init_msg_option_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_init_msg_option_0(thread, Parameters::default()).await;
+        let result = get_init_msg_option_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/sctp/SctpNet",
+            "getInitMsgOption0",
+            "(I[I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.sctp.SctpNet.getIntOption0(II)I")]
     async fn test_get_int_option_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_int_option_0(thread, Parameters::default()).await;
+        let result = get_int_option_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/sctp/SctpNet",
+            "getIntOption0",
+            "(II)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.sctp.SctpNet.getLocalAddresses0(I)[Ljava/net/SocketAddress;"
-    )]
     async fn test_get_local_addresses_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_local_addresses_0(thread, Parameters::default()).await;
+        let result = get_local_addresses_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/sctp/SctpNet",
+            "getLocalAddresses0",
+            "(I)[Ljava/net/SocketAddress;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.sctp.SctpNet.getPrimAddrOption0(II)Ljava/net/SocketAddress;"
-    )]
     async fn test_get_prim_addr_option_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_prim_addr_option_0(thread, Parameters::default()).await;
+        let result = get_prim_addr_option_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/sctp/SctpNet",
+            "getPrimAddrOption0",
+            "(II)Ljava/net/SocketAddress;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.sctp.SctpNet.getRemoteAddresses0(II)[Ljava/net/SocketAddress;"
-    )]
     async fn test_get_remote_addresses_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_remote_addresses_0(thread, Parameters::default()).await;
+        let result = get_remote_addresses_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/sctp/SctpNet",
+            "getRemoteAddresses0",
+            "(II)[Ljava/net/SocketAddress;",
+        );
     }
 
     #[tokio::test]
@@ -274,64 +317,88 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.sctp.SctpNet.listen0(II)V")]
     async fn test_listen_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = listen_0(thread, Parameters::default()).await;
+        let result = listen_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/sctp/SctpNet", "listen0", "(II)V");
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.sctp.SctpNet.preClose0(I)V")]
     async fn test_pre_close_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = pre_close_0(thread, Parameters::default()).await;
+        let result = pre_close_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/sctp/SctpNet",
+            "preClose0",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.sctp.SctpNet.setInitMsgOption0(III)V"
-    )]
     async fn test_set_init_msg_option_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_init_msg_option_0(thread, Parameters::default()).await;
+        let result = set_init_msg_option_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/sctp/SctpNet",
+            "setInitMsgOption0",
+            "(III)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.sctp.SctpNet.setIntOption0(III)V")]
     async fn test_set_int_option_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_int_option_0(thread, Parameters::default()).await;
+        let result = set_int_option_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/sctp/SctpNet",
+            "setIntOption0",
+            "(III)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.sctp.SctpNet.setPeerPrimAddrOption0(IILjava/net/InetAddress;IZ)V"
-    )]
     async fn test_set_peer_prim_addr_option_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_peer_prim_addr_option_0(thread, Parameters::default()).await;
+        let result = set_peer_prim_addr_option_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/sctp/SctpNet",
+            "setPeerPrimAddrOption0",
+            "(IILjava/net/InetAddress;IZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.sctp.SctpNet.setPrimAddrOption0(IILjava/net/InetAddress;I)V"
-    )]
     async fn test_set_prim_addr_option_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = set_prim_addr_option_0(thread, Parameters::default()).await;
+        let result = set_prim_addr_option_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/sctp/SctpNet",
+            "setPrimAddrOption0",
+            "(IILjava/net/InetAddress;I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.sctp.SctpNet.shutdown0(II)V")]
     async fn test_shutdown_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = shutdown_0(thread, Parameters::default()).await;
+        let result = shutdown_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/sctp/SctpNet",
+            "shutdown0",
+            "(II)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.sctp.SctpNet.socket0(Z)I")]
     async fn test_socket_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = socket_0(thread, Parameters::default()).await;
+        let result = socket_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/sctp/SctpNet", "socket0", "(Z)I");
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -36,7 +36,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn check_connect(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.SocketChannelImpl.checkConnect(Ljava/io/FileDescriptor;ZZ)I")
+    unsatisfied_link(CLASS_NAME, "checkConnect", "(Ljava/io/FileDescriptor;ZZ)I")
 }
 
 #[async_recursion(?Send)]
@@ -44,7 +44,11 @@ async fn send_out_of_band_data(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.SocketChannelImpl.sendOutOfBandData(Ljava/io/FileDescriptor;B)I")
+    unsatisfied_link(
+        CLASS_NAME,
+        "sendOutOfBandData",
+        "(Ljava/io/FileDescriptor;B)I",
+    )
 }
 
 #[cfg(test)]
@@ -52,20 +56,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.SocketChannelImpl.checkConnect(Ljava/io/FileDescriptor;ZZ)I"
-    )]
     async fn test_check_connect() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = check_connect(thread, Parameters::default()).await;
+        let result = check_connect(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/SocketChannelImpl",
+            "checkConnect",
+            "(Ljava/io/FileDescriptor;ZZ)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.SocketChannelImpl.sendOutOfBandData(Ljava/io/FileDescriptor;B)I"
-    )]
     async fn test_send_out_of_band_data() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = send_out_of_band_data(thread, Parameters::default()).await;
+        let result = send_out_of_band_data(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/SocketChannelImpl",
+            "sendOutOfBandData",
+            "(Ljava/io/FileDescriptor;B)I",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_21};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_21};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -36,22 +36,22 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn read_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.SocketDispatcher.read0(Ljava/io/FileDescriptor;JI)I")
+    unsatisfied_link(CLASS_NAME, "read0", "(Ljava/io/FileDescriptor;JI)I")
 }
 
 #[async_recursion(?Send)]
 async fn readv_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.SocketDispatcher.readv0(Ljava/io/FileDescriptor;JI)J")
+    unsatisfied_link(CLASS_NAME, "readv0", "(Ljava/io/FileDescriptor;JI)J")
 }
 
 #[async_recursion(?Send)]
 async fn write_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.SocketDispatcher.write0(Ljava/io/FileDescriptor;JI)I")
+    unsatisfied_link(CLASS_NAME, "write0", "(Ljava/io/FileDescriptor;JI)I")
 }
 
 #[async_recursion(?Send)]
 async fn writev_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.SocketDispatcher.writev0(Ljava/io/FileDescriptor;JI)J")
+    unsatisfied_link(CLASS_NAME, "writev0", "(Ljava/io/FileDescriptor;JI)J")
 }
 
 #[cfg(test)]
@@ -59,38 +59,50 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.SocketDispatcher.read0(Ljava/io/FileDescriptor;JI)I"
-    )]
     async fn test_read_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = read_0(thread, Parameters::default()).await;
+        let result = read_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/SocketDispatcher",
+            "read0",
+            "(Ljava/io/FileDescriptor;JI)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.SocketDispatcher.readv0(Ljava/io/FileDescriptor;JI)J"
-    )]
     async fn test_readv_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = readv_0(thread, Parameters::default()).await;
+        let result = readv_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/SocketDispatcher",
+            "readv0",
+            "(Ljava/io/FileDescriptor;JI)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.SocketDispatcher.write0(Ljava/io/FileDescriptor;JI)I"
-    )]
     async fn test_write_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = write_0(thread, Parameters::default()).await;
+        let result = write_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/SocketDispatcher",
+            "write0",
+            "(Ljava/io/FileDescriptor;JI)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.SocketDispatcher.writev0(Ljava/io/FileDescriptor;JI)J"
-    )]
     async fn test_writev_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = writev_0(thread, Parameters::default()).await;
+        let result = writev_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/SocketDispatcher",
+            "writev0",
+            "(Ljava/io/FileDescriptor;JI)J",
+        );
     }
 }
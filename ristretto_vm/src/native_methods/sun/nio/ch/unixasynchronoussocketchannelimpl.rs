@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -15,7 +15,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn check_connect(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.UnixAsynchronousSocketChannelImpl.checkConnect(I)V")
+    unsatisfied_link(CLASS_NAME, "checkConnect", "(I)V")
 }
 
 #[cfg(test)]
@@ -23,11 +23,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.UnixAsynchronousSocketChannelImpl.checkConnect(I)V"
-    )]
     async fn test_check_connect() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = check_connect(thread, Parameters::default()).await;
+        let result = check_connect(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/UnixAsynchronousSocketChannelImpl",
+            "checkConnect",
+            "(I)V",
+        );
     }
 }
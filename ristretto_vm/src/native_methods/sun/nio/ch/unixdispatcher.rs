@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -22,7 +22,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn close_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.UnixDispatcher.close0(Ljava/io/FileDescriptor;)V")
+    unsatisfied_link(CLASS_NAME, "close0", "(Ljava/io/FileDescriptor;)V")
 }
 
 #[async_recursion(?Send)]
@@ -32,7 +32,7 @@ async fn init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Va
 
 #[async_recursion(?Send)]
 async fn pre_close_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.UnixDispatcher.preClose0(Ljava/io/FileDescriptor;)V")
+    unsatisfied_link(CLASS_NAME, "preClose0", "(Ljava/io/FileDescriptor;)V")
 }
 
 #[cfg(test)]
@@ -40,12 +40,15 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.UnixDispatcher.close0(Ljava/io/FileDescriptor;)V"
-    )]
     async fn test_close_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = close_0(thread, Parameters::default()).await;
+        let result = close_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/UnixDispatcher",
+            "close0",
+            "(Ljava/io/FileDescriptor;)V",
+        );
     }
 
     #[tokio::test]
@@ -57,11 +60,14 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.UnixDispatcher.preClose0(Ljava/io/FileDescriptor;)V"
-    )]
     async fn test_pre_close_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = pre_close_0(thread, Parameters::default()).await;
+        let result = pre_close_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/UnixDispatcher",
+            "preClose0",
+            "(Ljava/io/FileDescriptor;)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -35,32 +35,36 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn accept_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.UnixDomainSockets.accept0(Ljava/io/FileDescriptor;Ljava/io/FileDescriptor;[Ljava/lang/Object;)I");
+    unsatisfied_link(
+        CLASS_NAME,
+        "accept0",
+        "(Ljava/io/FileDescriptor;Ljava/io/FileDescriptor;[Ljava/lang/Object;)I",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn bind_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.UnixDomainSockets.bind0(Ljava/io/FileDescriptor;[B)V");
+    unsatisfied_link(CLASS_NAME, "bind0", "(Ljava/io/FileDescriptor;[B)V")
 }
 
 #[async_recursion(?Send)]
 async fn connect_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.UnixDomainSockets.connect0(Ljava/io/FileDescriptor;[B)I");
+    unsatisfied_link(CLASS_NAME, "connect0", "(Ljava/io/FileDescriptor;[B)I")
 }
 
 #[async_recursion(?Send)]
 async fn init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.UnixDomainSockets.init()Z");
+    unsatisfied_link(CLASS_NAME, "init", "()Z")
 }
 
 #[async_recursion(?Send)]
 async fn local_address_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.UnixDomainSockets.localAddress0(Ljava/io/FileDescriptor;)[B");
+    unsatisfied_link(CLASS_NAME, "localAddress0", "(Ljava/io/FileDescriptor;)[B")
 }
 
 #[async_recursion(?Send)]
 async fn socket_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.ch.UnixDomainSockets.socket0()I");
+    unsatisfied_link(CLASS_NAME, "socket0", "()I")
 }
 
 #[cfg(test)]
@@ -68,52 +72,69 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.UnixDomainSockets.accept0(Ljava/io/FileDescriptor;Ljava/io/FileDescriptor;[Ljava/lang/Object;)I"
-    )]
     async fn test_accept_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = accept_0(thread, Parameters::default()).await;
+        let result = accept_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/UnixDomainSockets",
+            "accept0",
+            "(Ljava/io/FileDescriptor;Ljava/io/FileDescriptor;[Ljava/lang/Object;)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.UnixDomainSockets.bind0(Ljava/io/FileDescriptor;[B)V"
-    )]
     async fn test_bind_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = bind_0(thread, Parameters::default()).await;
+        let result = bind_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/UnixDomainSockets",
+            "bind0",
+            "(Ljava/io/FileDescriptor;[B)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.UnixDomainSockets.connect0(Ljava/io/FileDescriptor;[B)I"
-    )]
     async fn test_connect_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = connect_0(thread, Parameters::default()).await;
+        let result = connect_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/UnixDomainSockets",
+            "connect0",
+            "(Ljava/io/FileDescriptor;[B)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.UnixDomainSockets.init()Z")]
     async fn test_init() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = init(thread, Parameters::default()).await;
+        let result = init(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/nio/ch/UnixDomainSockets", "init", "()Z");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.ch.UnixDomainSockets.localAddress0(Ljava/io/FileDescriptor;)[B"
-    )]
     async fn test_local_address_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = local_address_0(thread, Parameters::default()).await;
+        let result = local_address_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/UnixDomainSockets",
+            "localAddress0",
+            "(Ljava/io/FileDescriptor;)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.ch.UnixDomainSockets.socket0()I")]
     async fn test_socket_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = socket_0(thread, Parameters::default()).await;
+        let result = socket_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/ch/UnixDomainSockets",
+            "socket0",
+            "()I",
+        );
     }
 }
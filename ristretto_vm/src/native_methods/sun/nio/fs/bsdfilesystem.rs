@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -15,7 +15,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn direct_copy_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.BsdFileSystem.directCopy0(IJJ)I");
+    unsatisfied_link(CLASS_NAME, "directCopy0", "(IIJ)I")
 }
 
 #[cfg(test)]
@@ -23,9 +23,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.BsdFileSystem.directCopy0(IJJ)I")]
     async fn test_direct_copy_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = direct_copy_0(thread, Parameters::default()).await;
+        let result = direct_copy_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/BsdFileSystem",
+            "directCopy0",
+            "(IIJ)I",
+        );
     }
 }
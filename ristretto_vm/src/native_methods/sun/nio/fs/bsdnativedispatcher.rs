@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_21};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_21};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -30,32 +30,32 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn clonefile_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.BsdNativeDispatcher.clonefile0(JJI)I");
+    unsatisfied_link(CLASS_NAME, "clonefile0", "(JJI)I")
 }
 
 #[async_recursion(?Send)]
 async fn endfsstat(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.BsdNativeDispatcher.endfsstat(J)V");
+    unsatisfied_link(CLASS_NAME, "endfsstat", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn fsetattrlist_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.BsdNativeDispatcher.fsetattrlist0(IIJJJJ)V");
+    unsatisfied_link(CLASS_NAME, "fsetattrlist0", "(IIJJJJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn fsstat_entry(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.BsdNativeDispatcher.fsstatEntry(JLsun/nio/fs/UnixMountEntry;)I");
+    unsatisfied_link(CLASS_NAME, "fsstatEntry", "(JLsun/nio/fs/UnixMountEntry;)I")
 }
 
 #[async_recursion(?Send)]
 async fn getfsstat(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.BsdNativeDispatcher.getfsstat()J");
+    unsatisfied_link(CLASS_NAME, "getfsstat", "()J")
 }
 
 #[async_recursion(?Send)]
 async fn getmntonname_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.BsdNativeDispatcher.getmntonname0(J)[B");
+    unsatisfied_link(CLASS_NAME, "getmntonname0", "(J)[B")
 }
 
 #[async_recursion(?Send)]
@@ -65,7 +65,7 @@ async fn init_ids(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn setattrlist_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.BsdNativeDispatcher.setattrlist0(JIJJJJ)V");
+    unsatisfied_link(CLASS_NAME, "setattrlist0", "(JIJJJJ)V")
 }
 
 #[cfg(test)]
@@ -73,53 +73,75 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.BsdNativeDispatcher.clonefile0(JJI)I"
-    )]
     async fn test_clonefile_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = clonefile_0(thread, Parameters::default()).await;
+        let result = clonefile_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/BsdNativeDispatcher",
+            "clonefile0",
+            "(JJI)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.BsdNativeDispatcher.endfsstat(J)V")]
     async fn test_endfsstat() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = endfsstat(thread, Parameters::default()).await;
+        let result = endfsstat(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/BsdNativeDispatcher",
+            "endfsstat",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.BsdNativeDispatcher.fsetattrlist0(IIJJJJ)V"
-    )]
     async fn test_fsetattrlist_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fsetattrlist_0(thread, Parameters::default()).await;
+        let result = fsetattrlist_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/BsdNativeDispatcher",
+            "fsetattrlist0",
+            "(IIJJJJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.BsdNativeDispatcher.fsstatEntry(JLsun/nio/fs/UnixMountEntry;)I"
-    )]
     async fn test_fsstat_entry() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fsstat_entry(thread, Parameters::default()).await;
+        let result = fsstat_entry(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/BsdNativeDispatcher",
+            "fsstatEntry",
+            "(JLsun/nio/fs/UnixMountEntry;)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.BsdNativeDispatcher.getfsstat()J")]
     async fn test_getfsstat() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = getfsstat(thread, Parameters::default()).await;
+        let result = getfsstat(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/BsdNativeDispatcher",
+            "getfsstat",
+            "()J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.BsdNativeDispatcher.getmntonname0(J)[B"
-    )]
     async fn test_getmntonname_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = getmntonname_0(thread, Parameters::default()).await;
+        let result = getmntonname_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/BsdNativeDispatcher",
+            "getmntonname0",
+            "(J)[B",
+        );
     }
 
     #[tokio::test]
@@ -131,11 +153,14 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.BsdNativeDispatcher.setattrlist0(JIJJJJ)V"
-    )]
     async fn test_setattrlist_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = setattrlist_0(thread, Parameters::default()).await;
+        let result = setattrlist_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/BsdNativeDispatcher",
+            "setattrlist0",
+            "(JIJJJJ)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -15,7 +15,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn normalizepath(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.MacOSXNativeDispatcher.normalizepath([CI)[C");
+    unsatisfied_link(CLASS_NAME, "normalizepath", "([CI)[C")
 }
 
 #[cfg(test)]
@@ -23,11 +23,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.MacOSXNativeDispatcher.normalizepath([CI)[C"
-    )]
     async fn test_normalizepath() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = normalizepath(thread, Parameters::default()).await;
+        let result = normalizepath(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/MacOSXNativeDispatcher",
+            "normalizepath",
+            "([CI)[C",
+        );
     }
 }
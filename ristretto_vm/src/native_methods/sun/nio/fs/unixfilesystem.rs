@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -15,7 +15,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn buffered_copy_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixFileSystem.bufferedCopy0(IIJIJ)V");
+    unsatisfied_link(CLASS_NAME, "bufferedCopy0", "(IIJIJ)V")
 }
 
 #[cfg(test)]
@@ -23,11 +23,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixFileSystem.bufferedCopy0(IIJIJ)V"
-    )]
     async fn test_buffered_copy_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = buffered_copy_0(thread, Parameters::default()).await;
+        let result = buffered_copy_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixFileSystem",
+            "bufferedCopy0",
+            "(IIJIJ)V",
+        );
     }
 }
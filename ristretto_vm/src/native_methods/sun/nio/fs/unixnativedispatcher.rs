@@ -1,4 +1,6 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17, JAVA_21, JAVA_23, JAVA_8};
+use crate::native_methods::registry::{
+    unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17, JAVA_21, JAVA_23, JAVA_8,
+};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Error::InternalError;
@@ -165,17 +167,17 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn access_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.access0(JI)I");
+    unsatisfied_link(CLASS_NAME, "access0", "(JI)I")
 }
 
 #[async_recursion(?Send)]
 async fn chmod_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.chmod0(JI)V");
+    unsatisfied_link(CLASS_NAME, "chmod0", "(JI)V")
 }
 
 #[async_recursion(?Send)]
 async fn chown_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.chown0(JII)V");
+    unsatisfied_link(CLASS_NAME, "chown0", "(JII)V")
 }
 
 #[async_recursion(?Send)]
@@ -185,27 +187,27 @@ async fn close(thread: Arc<Thread>, parameters: Parameters) -> Result<Option<Val
 
 #[async_recursion(?Send)]
 async fn close_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.close0(I)V");
+    unsatisfied_link(CLASS_NAME, "close0", "(I)V")
 }
 
 #[async_recursion(?Send)]
 async fn closedir(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.closedir(J)V");
+    unsatisfied_link(CLASS_NAME, "closedir", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn dup(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.dup(I)I");
+    unsatisfied_link(CLASS_NAME, "dup", "(I)I")
 }
 
 #[async_recursion(?Send)]
 async fn exists_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.exists0(J)Z");
+    unsatisfied_link(CLASS_NAME, "exists0", "(J)Z")
 }
 
 #[async_recursion(?Send)]
 async fn fclose(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.fclose(J)V");
+    unsatisfied_link(CLASS_NAME, "fclose", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -215,7 +217,7 @@ async fn fchmod(thread: Arc<Thread>, parameters: Parameters) -> Result<Option<Va
 
 #[async_recursion(?Send)]
 async fn fchmod_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.fchmod0(II)V");
+    unsatisfied_link(CLASS_NAME, "fchmod0", "(II)V")
 }
 
 #[async_recursion(?Send)]
@@ -225,42 +227,42 @@ async fn fchown(thread: Arc<Thread>, parameters: Parameters) -> Result<Option<Va
 
 #[async_recursion(?Send)]
 async fn fchown_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.fchown0(III)V");
+    unsatisfied_link(CLASS_NAME, "fchown0", "(III)V")
 }
 
 #[async_recursion(?Send)]
 async fn fdopendir(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.fdopendir(I)J");
+    unsatisfied_link(CLASS_NAME, "fdopendir", "(I)J")
 }
 
 #[async_recursion(?Send)]
 async fn fgetxattr_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.fgetxattr0(IJJI)I");
+    unsatisfied_link(CLASS_NAME, "fgetxattr0", "(IJJI)I")
 }
 
 #[async_recursion(?Send)]
 async fn flistxattr(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.flistxattr(IJI)I");
+    unsatisfied_link(CLASS_NAME, "flistxattr", "(IJI)I")
 }
 
 #[async_recursion(?Send)]
 async fn fopen_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.fopen0(JJ)J");
+    unsatisfied_link(CLASS_NAME, "fopen0", "(JJ)J")
 }
 
 #[async_recursion(?Send)]
 async fn fpathconf(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.fpathconf(II)J");
+    unsatisfied_link(CLASS_NAME, "fpathconf", "(II)J")
 }
 
 #[async_recursion(?Send)]
 async fn fremovexattr_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.fremovexattr0(IJ)V");
+    unsatisfied_link(CLASS_NAME, "fremovexattr0", "(IJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn fsetxattr_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.fsetxattr0(IJJI)V");
+    unsatisfied_link(CLASS_NAME, "fsetxattr0", "(IJJI)V")
 }
 
 #[async_recursion(?Send)]
@@ -270,12 +272,16 @@ async fn fstat(thread: Arc<Thread>, parameters: Parameters) -> Result<Option<Val
 
 #[async_recursion(?Send)]
 async fn fstat_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.fstat0(ILsun/nio/fs/UnixFileAttributes;)V");
+    unsatisfied_link(CLASS_NAME, "fstat0", "(ILsun/nio/fs/UnixFileAttributes;)V")
 }
 
 #[async_recursion(?Send)]
 async fn fstatat_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.fstatat0(IJILsun/nio/fs/UnixFileAttributes;)V");
+    unsatisfied_link(
+        CLASS_NAME,
+        "fstatat0",
+        "(IJILsun/nio/fs/UnixFileAttributes;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -285,7 +291,7 @@ async fn futimens(thread: Arc<Thread>, parameters: Parameters) -> Result<Option<
 
 #[async_recursion(?Send)]
 async fn futimens_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.futimens0(IJJ)V");
+    unsatisfied_link(CLASS_NAME, "futimens0", "(IJJ)V")
 }
 
 #[async_recursion(?Send)]
@@ -295,7 +301,7 @@ async fn futimes(thread: Arc<Thread>, parameters: Parameters) -> Result<Option<V
 
 #[async_recursion(?Send)]
 async fn futimes_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.futimes0(IJJ)V");
+    unsatisfied_link(CLASS_NAME, "futimes0", "(IJJ)V")
 }
 
 #[expect(clippy::cast_possible_wrap)]
@@ -317,27 +323,27 @@ async fn getcwd(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<
 
 #[async_recursion(?Send)]
 async fn getgrgid(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.getgrgid(I)[B");
+    unsatisfied_link(CLASS_NAME, "getgrgid", "(I)[B")
 }
 
 #[async_recursion(?Send)]
 async fn getgrnam_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.getgrnam0(J)I");
+    unsatisfied_link(CLASS_NAME, "getgrnam0", "(J)I")
 }
 
 #[async_recursion(?Send)]
 async fn getlinelen(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.getlinelen(J)I");
+    unsatisfied_link(CLASS_NAME, "getlinelen", "(J)I")
 }
 
 #[async_recursion(?Send)]
 async fn getpwnam_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.getpwnam0(J)I");
+    unsatisfied_link(CLASS_NAME, "getpwnam0", "(J)I")
 }
 
 #[async_recursion(?Send)]
 async fn getpwuid(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.getpwuid(I)[B");
+    unsatisfied_link(CLASS_NAME, "getpwuid", "(I)[B")
 }
 
 #[async_recursion(?Send)]
@@ -350,52 +356,52 @@ async fn init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Va
 
 #[async_recursion(?Send)]
 async fn lchown_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.lchown0(JII)V");
+    unsatisfied_link(CLASS_NAME, "lchown0", "(JII)V")
 }
 
 #[async_recursion(?Send)]
 async fn link_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.link0(JJ)V");
+    unsatisfied_link(CLASS_NAME, "link0", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn lstat_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.lstat0(JLsun/nio/fs/UnixFileAttributes;)V");
+    unsatisfied_link(CLASS_NAME, "lstat0", "(JLsun/nio/fs/UnixFileAttributes;)V")
 }
 
 #[async_recursion(?Send)]
 async fn lutimes_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.lutimes0(JJJ)V");
+    unsatisfied_link(CLASS_NAME, "lutimes0", "(JJJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn mkdir_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.mkdir0(JI)V");
+    unsatisfied_link(CLASS_NAME, "mkdir0", "(JI)V")
 }
 
 #[async_recursion(?Send)]
 async fn mknod_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.mknod0(JIJ)V");
+    unsatisfied_link(CLASS_NAME, "mknod0", "(JIJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn open_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.open0(JII)I");
+    unsatisfied_link(CLASS_NAME, "open0", "(JII)I")
 }
 
 #[async_recursion(?Send)]
 async fn openat_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.openat0(IJII)I");
+    unsatisfied_link(CLASS_NAME, "openat0", "(IJII)I")
 }
 
 #[async_recursion(?Send)]
 async fn opendir_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.opendir0(J)J");
+    unsatisfied_link(CLASS_NAME, "opendir0", "(J)J")
 }
 
 #[async_recursion(?Send)]
 async fn pathconf_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.pathconf0(JI)J");
+    unsatisfied_link(CLASS_NAME, "pathconf0", "(JI)J")
 }
 
 #[async_recursion(?Send)]
@@ -405,7 +411,7 @@ async fn read(thread: Arc<Thread>, parameters: Parameters) -> Result<Option<Valu
 
 #[async_recursion(?Send)]
 async fn read_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.read0(IJI)I");
+    unsatisfied_link(CLASS_NAME, "read0", "(IJI)I")
 }
 
 #[async_recursion(?Send)]
@@ -415,37 +421,37 @@ async fn readdir(thread: Arc<Thread>, parameters: Parameters) -> Result<Option<V
 
 #[async_recursion(?Send)]
 async fn readdir_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.readdir0(J)[B");
+    unsatisfied_link(CLASS_NAME, "readdir0", "(J)[B")
 }
 
 #[async_recursion(?Send)]
 async fn readlink_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.readlink0(J)[B");
+    unsatisfied_link(CLASS_NAME, "readlink0", "(J)[B")
 }
 
 #[async_recursion(?Send)]
 async fn realpath_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.realpath0(J)[B");
+    unsatisfied_link(CLASS_NAME, "realpath0", "(J)[B")
 }
 
 #[async_recursion(?Send)]
 async fn rename_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.rename0(JJ)V");
+    unsatisfied_link(CLASS_NAME, "rename0", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn renameat_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.renameat0(IJIJ)V");
+    unsatisfied_link(CLASS_NAME, "renameat0", "(IJIJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn rewind(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.rewind(J)V");
+    unsatisfied_link(CLASS_NAME, "rewind", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn rmdir_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.rmdir0(J)V");
+    unsatisfied_link(CLASS_NAME, "rmdir0", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -466,37 +472,41 @@ async fn stat_0(thread: Arc<Thread>, mut parameters: Parameters) -> Result<Optio
 
 #[async_recursion(?Send)]
 async fn stat_1(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.stat1(J)I");
+    unsatisfied_link(CLASS_NAME, "stat1", "(J)I")
 }
 
 #[async_recursion(?Send)]
 async fn statvfs_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.statvfs0(JLsun/nio/fs/UnixFileStoreAttributes;)V");
+    unsatisfied_link(
+        CLASS_NAME,
+        "statvfs0",
+        "(JLsun/nio/fs/UnixFileStoreAttributes;)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn strerror(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.strerror(I)[B");
+    unsatisfied_link(CLASS_NAME, "strerror", "(I)[B")
 }
 
 #[async_recursion(?Send)]
 async fn symlink_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.symlink0(JJ)V");
+    unsatisfied_link(CLASS_NAME, "symlink0", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn unlink_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.unlink0(J)V");
+    unsatisfied_link(CLASS_NAME, "unlink0", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn unlinkat_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.unlinkat0(IJI)V");
+    unsatisfied_link(CLASS_NAME, "unlinkat0", "(IJI)V")
 }
 
 #[async_recursion(?Send)]
 async fn utimes_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.utimes0(JJJ)V");
+    unsatisfied_link(CLASS_NAME, "utimes0", "(JJJ)V")
 }
 
 #[async_recursion(?Send)]
@@ -506,7 +516,7 @@ async fn write(thread: Arc<Thread>, parameters: Parameters) -> Result<Option<Val
 
 #[async_recursion(?Send)]
 async fn write_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.write0(IJI)I");
+    unsatisfied_link(CLASS_NAME, "write0", "(IJI)I")
 }
 
 #[cfg(test)]
@@ -514,216 +524,327 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.access0(JI)I")]
     async fn test_access_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = access_0(thread, Parameters::default()).await;
+        let result = access_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "access0",
+            "(JI)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.chmod0(JI)V")]
     async fn test_chmod_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = chmod_0(thread, Parameters::default()).await;
+        let result = chmod_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "chmod0",
+            "(JI)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.chown0(JII)V")]
     async fn test_chown_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = chown_0(thread, Parameters::default()).await;
+        let result = chown_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "chown0",
+            "(JII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.close0(I)V")]
     async fn test_close() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = close(thread, Parameters::default()).await;
+        let result = close(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "close0",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.close0(I)V")]
     async fn test_close_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = close_0(thread, Parameters::default()).await;
+        let result = close_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "close0",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.closedir(J)V")]
     async fn test_closedir() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = closedir(thread, Parameters::default()).await;
+        let result = closedir(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "closedir",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.dup(I)I")]
     async fn test_dup() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = dup(thread, Parameters::default()).await;
+        let result = dup(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "dup",
+            "(I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.exists0(J)Z")]
     async fn test_exists_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = exists_0(thread, Parameters::default()).await;
+        let result = exists_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "exists0",
+            "(J)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.fchmod0(II)V")]
     async fn test_fchmod() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fchmod(thread, Parameters::default()).await;
+        let result = fchmod(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "fchmod0",
+            "(II)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.fchmod0(II)V")]
     async fn test_fchmod_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fchmod_0(thread, Parameters::default()).await;
+        let result = fchmod_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "fchmod0",
+            "(II)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.fchown0(III)V")]
     async fn test_fchown() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fchown(thread, Parameters::default()).await;
+        let result = fchown(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "fchown0",
+            "(III)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.fchown0(III)V")]
     async fn test_fchown_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fchown_0(thread, Parameters::default()).await;
+        let result = fchown_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "fchown0",
+            "(III)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.fclose(J)V")]
     async fn test_fclose() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fclose(thread, Parameters::default()).await;
+        let result = fclose(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "fclose",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.fdopendir(I)J")]
     async fn test_fdopendir() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fdopendir(thread, Parameters::default()).await;
+        let result = fdopendir(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "fdopendir",
+            "(I)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.fgetxattr0(IJJI)I"
-    )]
     async fn test_fgetxattr_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fgetxattr_0(thread, Parameters::default()).await;
+        let result = fgetxattr_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "fgetxattr0",
+            "(IJJI)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.flistxattr(IJI)I"
-    )]
     async fn test_flistxattr() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = flistxattr(thread, Parameters::default()).await;
+        let result = flistxattr(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "flistxattr",
+            "(IJI)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.fopen0(JJ)J")]
     async fn test_fopen_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fopen_0(thread, Parameters::default()).await;
+        let result = fopen_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "fopen0",
+            "(JJ)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.fpathconf(II)J"
-    )]
     async fn test_fpathconf() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fpathconf(thread, Parameters::default()).await;
+        let result = fpathconf(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "fpathconf",
+            "(II)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.fremovexattr0(IJ)V"
-    )]
     async fn test_fremovexattr_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fremovexattr_0(thread, Parameters::default()).await;
+        let result = fremovexattr_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "fremovexattr0",
+            "(IJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.fsetxattr0(IJJI)V"
-    )]
     async fn test_fsetxattr_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fsetxattr_0(thread, Parameters::default()).await;
+        let result = fsetxattr_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "fsetxattr0",
+            "(IJJI)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.fstat0(ILsun/nio/fs/UnixFileAttributes;)V"
-    )]
     async fn test_fstat() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fstat(thread, Parameters::default()).await;
+        let result = fstat(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "fstat0",
+            "(ILsun/nio/fs/UnixFileAttributes;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.fstat0(ILsun/nio/fs/UnixFileAttributes;)V"
-    )]
     async fn test_fstat_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fstat_0(thread, Parameters::default()).await;
+        let result = fstat_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "fstat0",
+            "(ILsun/nio/fs/UnixFileAttributes;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.fstatat0(IJILsun/nio/fs/UnixFileAttributes;)V"
-    )]
     async fn test_fstatat_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = fstatat_0(thread, Parameters::default()).await;
+        let result = fstatat_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "fstatat0",
+            "(IJILsun/nio/fs/UnixFileAttributes;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.futimens0(IJJ)V"
-    )]
     async fn test_futimens() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = futimens(thread, Parameters::default()).await;
+        let result = futimens(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "futimens0",
+            "(IJJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.futimens0(IJJ)V"
-    )]
     async fn test_futimens_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = futimens_0(thread, Parameters::default()).await;
+        let result = futimens_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "futimens0",
+            "(IJJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.futimes0(IJJ)V"
-    )]
     async fn test_futimes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = futimes(thread, Parameters::default()).await;
+        let result = futimes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "futimes0",
+            "(IJJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.futimes0(IJJ)V"
-    )]
     async fn test_futimes_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = futimes_0(thread, Parameters::default()).await;
+        let result = futimes_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "futimes0",
+            "(IJJ)V",
+        );
     }
 
     #[tokio::test]
@@ -740,40 +861,63 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.getgrgid(I)[B")]
     async fn test_getgrgid() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = getgrgid(thread, Parameters::default()).await;
+        let result = getgrgid(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "getgrgid",
+            "(I)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.getgrnam0(J)I")]
     async fn test_getgrnam_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = getgrnam_0(thread, Parameters::default()).await;
+        let result = getgrnam_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "getgrnam0",
+            "(J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.getlinelen(J)I"
-    )]
     async fn test_getlinelen() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = getlinelen(thread, Parameters::default()).await;
+        let result = getlinelen(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "getlinelen",
+            "(J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.getpwnam0(J)I")]
     async fn test_getpwnam_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = getpwnam_0(thread, Parameters::default()).await;
+        let result = getpwnam_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "getpwnam0",
+            "(J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.getpwuid(I)[B")]
     async fn test_getpwuid() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = getpwuid(thread, Parameters::default()).await;
+        let result = getpwuid(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "getpwuid",
+            "(I)[B",
+        );
     }
 
     #[tokio::test]
@@ -785,157 +929,243 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.lchown0(JII)V")]
     async fn test_lchown_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = lchown_0(thread, Parameters::default()).await;
+        let result = lchown_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "lchown0",
+            "(JII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.link0(JJ)V")]
     async fn test_link_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = link_0(thread, Parameters::default()).await;
+        let result = link_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "link0",
+            "(JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.lstat0(JLsun/nio/fs/UnixFileAttributes;)V"
-    )]
     async fn test_lstat_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = lstat_0(thread, Parameters::default()).await;
+        let result = lstat_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "lstat0",
+            "(JLsun/nio/fs/UnixFileAttributes;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.lutimes0(JJJ)V"
-    )]
     async fn test_lutimes_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = lutimes_0(thread, Parameters::default()).await;
+        let result = lutimes_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "lutimes0",
+            "(JJJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.mkdir0(JI)V")]
     async fn test_mkdir_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = mkdir_0(thread, Parameters::default()).await;
+        let result = mkdir_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "mkdir0",
+            "(JI)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.mknod0(JIJ)V")]
     async fn test_mknod_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = mknod_0(thread, Parameters::default()).await;
+        let result = mknod_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "mknod0",
+            "(JIJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.open0(JII)I")]
     async fn test_open_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = open_0(thread, Parameters::default()).await;
+        let result = open_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "open0",
+            "(JII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.openat0(IJII)I"
-    )]
     async fn test_openat_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = openat_0(thread, Parameters::default()).await;
+        let result = openat_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "openat0",
+            "(IJII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.opendir0(J)J")]
     async fn test_opendir_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = opendir_0(thread, Parameters::default()).await;
+        let result = opendir_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "opendir0",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.pathconf0(JI)J"
-    )]
     async fn test_pathconf_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = pathconf_0(thread, Parameters::default()).await;
+        let result = pathconf_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "pathconf0",
+            "(JI)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.read0(IJI)I")]
     async fn test_read() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = read(thread, Parameters::default()).await;
+        let result = read(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "read0",
+            "(IJI)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.read0(IJI)I")]
     async fn test_read_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = read_0(thread, Parameters::default()).await;
+        let result = read_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "read0",
+            "(IJI)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.readdir0(J)[B")]
     async fn test_readdir() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = readdir(thread, Parameters::default()).await;
+        let result = readdir(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "readdir0",
+            "(J)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.readdir0(J)[B")]
     async fn test_readdir_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = readdir_0(thread, Parameters::default()).await;
+        let result = readdir_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "readdir0",
+            "(J)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.readlink0(J)[B"
-    )]
     async fn test_readlink_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = readlink_0(thread, Parameters::default()).await;
+        let result = readlink_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "readlink0",
+            "(J)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.realpath0(J)[B"
-    )]
     async fn test_realpath_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = realpath_0(thread, Parameters::default()).await;
+        let result = realpath_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "realpath0",
+            "(J)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.rename0(JJ)V")]
     async fn test_rename_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = rename_0(thread, Parameters::default()).await;
+        let result = rename_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "rename0",
+            "(JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.renameat0(IJIJ)V"
-    )]
     async fn test_renameat_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = renameat_0(thread, Parameters::default()).await;
+        let result = renameat_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "renameat0",
+            "(IJIJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.rewind(J)V")]
     async fn test_rewind() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = rewind(thread, Parameters::default()).await;
+        let result = rewind(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "rewind",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.rmdir0(J)V")]
     async fn test_rmdir_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = rmdir_0(thread, Parameters::default()).await;
+        let result = rmdir_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "rmdir0",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
@@ -952,69 +1182,110 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.stat1(J)I")]
     async fn test_stat_1() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = stat_1(thread, Parameters::default()).await;
+        let result = stat_1(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "stat1",
+            "(J)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.statvfs0(JLsun/nio/fs/UnixFileStoreAttributes;)V"
-    )]
     async fn test_statvfs_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = statvfs_0(thread, Parameters::default()).await;
+        let result = statvfs_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "statvfs0",
+            "(JLsun/nio/fs/UnixFileStoreAttributes;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.strerror(I)[B")]
     async fn test_strerror() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = strerror(thread, Parameters::default()).await;
+        let result = strerror(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "strerror",
+            "(I)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.symlink0(JJ)V")]
     async fn test_symlink_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = symlink_0(thread, Parameters::default()).await;
+        let result = symlink_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "symlink0",
+            "(JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.unlink0(J)V")]
     async fn test_unlink_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = unlink_0(thread, Parameters::default()).await;
+        let result = unlink_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "unlink0",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.unlinkat0(IJI)V"
-    )]
     async fn test_unlinkat_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = unlinkat_0(thread, Parameters::default()).await;
+        let result = unlinkat_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "unlinkat0",
+            "(IJI)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.utimes0(JJJ)V")]
     async fn test_utimes_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = utimes_0(thread, Parameters::default()).await;
+        let result = utimes_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "utimes0",
+            "(JJJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.write0(IJI)I")]
     async fn test_write() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = write(thread, Parameters::default()).await;
+        let result = write(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "write0",
+            "(IJI)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.nio.fs.UnixNativeDispatcher.write0(IJI)I")]
     async fn test_write_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = write_0(thread, Parameters::default()).await;
+        let result = write_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/nio/fs/UnixNativeDispatcher",
+            "write0",
+            "(IJI)I",
+        );
     }
 }
@@ -1,4 +1,6 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17, JAVA_21, JAVA_23};
+use crate::native_methods::registry::{
+    unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17, JAVA_21, JAVA_23,
+};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -82,7 +84,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn can_connect(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.print.CUPSPrinter.canConnect(Ljava/lang/String;I)Z")
+    unsatisfied_link(CLASS_NAME, "canConnect", "(Ljava/lang/String;I)Z")
 }
 
 #[async_recursion(?Send)]
@@ -90,7 +92,7 @@ async fn get_cups_default_printer(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.print.CUPSPrinter.getCupsDefaultPrinter()Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getCupsDefaultPrinter", "()Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
@@ -98,37 +100,53 @@ async fn get_cups_default_printers(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.print.CUPSPrinter.getCupsDefaultPrinters()[Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getCupsDefaultPrinters",
+        "()[Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_cups_port(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.print.CUPSPrinter.getCupsPort()I")
+    unsatisfied_link(CLASS_NAME, "getCupsPort", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn get_cups_server(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.print.CUPSPrinter.getCupsServer()Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getCupsServer", "()Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
 async fn get_media(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.print.CUPSPrinter.getMedia(Ljava/lang/String;)[Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getMedia",
+        "(Ljava/lang/String;)[Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_output_bins(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.print.CUPSPrinter.getOutputBins(Ljava/lang/String;)[Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getOutputBins",
+        "(Ljava/lang/String;)[Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_page_sizes(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.print.CUPSPrinter.getPageSizes(Ljava/lang/String;)[F")
+    unsatisfied_link(CLASS_NAME, "getPageSizes", "(Ljava/lang/String;)[F")
 }
 
 #[async_recursion(?Send)]
 async fn get_resolutions(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.print.CUPSPrinter.getResolutions(Ljava/lang/String;Ljava/util/ArrayList;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getResolutions",
+        "(Ljava/lang/String;Ljava/util/ArrayList;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -141,82 +159,106 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.print.CUPSPrinter.canConnect(Ljava/lang/String;I)Z"
-    )]
     async fn test_can_connect() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = can_connect(thread, Parameters::default()).await;
+        let result = can_connect(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/print/CUPSPrinter",
+            "canConnect",
+            "(Ljava/lang/String;I)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.print.CUPSPrinter.getCupsDefaultPrinter()Ljava/lang/String;"
-    )]
     async fn test_get_cups_default_printer() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_cups_default_printer(thread, Parameters::default()).await;
+        let result = get_cups_default_printer(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/print/CUPSPrinter",
+            "getCupsDefaultPrinter",
+            "()Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.print.CUPSPrinter.getCupsDefaultPrinters()[Ljava/lang/String;"
-    )]
     async fn test_get_cups_default_printers() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_cups_default_printers(thread, Parameters::default()).await;
+        let result = get_cups_default_printers(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/print/CUPSPrinter",
+            "getCupsDefaultPrinters",
+            "()[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.print.CUPSPrinter.getCupsPort()I")]
     async fn test_get_cups_port() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_cups_port(thread, Parameters::default()).await;
+        let result = get_cups_port(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/print/CUPSPrinter", "getCupsPort", "()I");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.print.CUPSPrinter.getCupsServer()Ljava/lang/String;"
-    )]
     async fn test_get_cups_server() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_cups_server(thread, Parameters::default()).await;
+        let result = get_cups_server(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/print/CUPSPrinter",
+            "getCupsServer",
+            "()Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.print.CUPSPrinter.getMedia(Ljava/lang/String;)[Ljava/lang/String;"
-    )]
     async fn test_get_media() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_media(thread, Parameters::default()).await;
+        let result = get_media(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/print/CUPSPrinter",
+            "getMedia",
+            "(Ljava/lang/String;)[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.print.CUPSPrinter.getOutputBins(Ljava/lang/String;)[Ljava/lang/String;"
-    )]
     async fn test_get_output_bins() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_output_bins(thread, Parameters::default()).await;
+        let result = get_output_bins(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/print/CUPSPrinter",
+            "getOutputBins",
+            "(Ljava/lang/String;)[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.print.CUPSPrinter.getPageSizes(Ljava/lang/String;)[F"
-    )]
     async fn test_get_page_sizes() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_page_sizes(thread, Parameters::default()).await;
+        let result = get_page_sizes(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/print/CUPSPrinter",
+            "getPageSizes",
+            "(Ljava/lang/String;)[F",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.print.CUPSPrinter.getResolutions(Ljava/lang/String;Ljava/util/ArrayList;)V"
-    )]
     async fn test_get_resolutions() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_resolutions(thread, Parameters::default()).await;
+        let result = get_resolutions(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/print/CUPSPrinter",
+            "getResolutions",
+            "(Ljava/lang/String;Ljava/util/ArrayList;)V",
+        );
     }
 
     #[tokio::test]
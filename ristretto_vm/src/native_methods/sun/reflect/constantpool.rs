@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -93,7 +93,11 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn get_class_at_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.reflect.ConstantPool.getClassAt0(Ljava/lang/Object;I)Ljava/lang/Class;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getClassAt0",
+        "(Ljava/lang/Object;I)Ljava/lang/Class;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -101,17 +105,25 @@ async fn get_class_at_if_loaded_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.reflect.ConstantPool.getClassAtIfLoaded0(Ljava/lang/Object;I)Ljava/lang/Class;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getClassAtIfLoaded0",
+        "(Ljava/lang/Object;I)Ljava/lang/Class;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_double_at_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.reflect.ConstantPool.getDoubleAt0(Ljava/lang/Object;I)D")
+    unsatisfied_link(CLASS_NAME, "getDoubleAt0", "(Ljava/lang/Object;I)D")
 }
 
 #[async_recursion(?Send)]
 async fn get_field_at_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.reflect.ConstantPool.getFieldAt0(Ljava/lang/Object;I)Ljava/lang/reflect/Field;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getFieldAt0",
+        "(Ljava/lang/Object;I)Ljava/lang/reflect/Field;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -119,22 +131,26 @@ async fn get_field_at_if_loaded_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.reflect.ConstantPool.getFieldAtIfLoaded0(Ljava/lang/Object;I)Ljava/lang/reflect/Field;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getFieldAtIfLoaded0",
+        "(Ljava/lang/Object;I)Ljava/lang/reflect/Field;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_float_at_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.reflect.ConstantPool.getFloatAt0(Ljava/lang/Object;I)F")
+    unsatisfied_link(CLASS_NAME, "getFloatAt0", "(Ljava/lang/Object;I)F")
 }
 
 #[async_recursion(?Send)]
 async fn get_int_at_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.reflect.ConstantPool.getIntAt0(Ljava/lang/Object;I)I")
+    unsatisfied_link(CLASS_NAME, "getIntAt0", "(Ljava/lang/Object;I)I")
 }
 
 #[async_recursion(?Send)]
 async fn get_long_at_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.reflect.ConstantPool.getLongAt0(Ljava/lang/Object;I)J")
+    unsatisfied_link(CLASS_NAME, "getLongAt0", "(Ljava/lang/Object;I)J")
 }
 
 #[async_recursion(?Send)]
@@ -142,12 +158,20 @@ async fn get_member_ref_info_at_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.reflect.ConstantPool.getMemberRefInfoAt0(Ljava/lang/Object;I)[Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getMemberRefInfoAt0",
+        "(Ljava/lang/Object;I)[Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_method_at_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.reflect.ConstantPool.getMethodAt0(Ljava/lang/Object;I)Ljava/lang/reflect/Member;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getMethodAt0",
+        "(Ljava/lang/Object;I)Ljava/lang/reflect/Member;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -155,22 +179,34 @@ async fn get_method_at_if_loaded_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.reflect.ConstantPool.getMethodAtIfLoaded0(Ljava/lang/Object;I)Ljava/lang/reflect/Member;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getMethodAtIfLoaded0",
+        "(Ljava/lang/Object;I)Ljava/lang/reflect/Member;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_size_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.reflect.ConstantPool.getSize0(Ljava/lang/Object;)I")
+    unsatisfied_link(CLASS_NAME, "getSize0", "(Ljava/lang/Object;)I")
 }
 
 #[async_recursion(?Send)]
 async fn get_string_at_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.reflect.ConstantPool.getStringAt0(Ljava/lang/Object;I)Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getStringAt0",
+        "(Ljava/lang/Object;I)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_utf_8_at_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.reflect.ConstantPool.getUTF8At0(Ljava/lang/Object;I)Ljava/lang/String;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getUTF8At0",
+        "(Ljava/lang/Object;I)Ljava/lang/String;",
+    )
 }
 
 #[cfg(test)]
@@ -178,128 +214,170 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.reflect.ConstantPool.getClassAt0(Ljava/lang/Object;I)Ljava/lang/Class;"
-    )]
     async fn test_get_class_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_class_at_0(thread, Parameters::default()).await;
+        let result = get_class_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/reflect/ConstantPool",
+            "getClassAt0",
+            "(Ljava/lang/Object;I)Ljava/lang/Class;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.reflect.ConstantPool.getClassAtIfLoaded0(Ljava/lang/Object;I)Ljava/lang/Class;"
-    )]
     async fn test_get_class_at_if_loaded_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_class_at_if_loaded_0(thread, Parameters::default()).await;
+        let result = get_class_at_if_loaded_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/reflect/ConstantPool",
+            "getClassAtIfLoaded0",
+            "(Ljava/lang/Object;I)Ljava/lang/Class;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.reflect.ConstantPool.getDoubleAt0(Ljava/lang/Object;I)D"
-    )]
     async fn test_get_double_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_double_at_0(thread, Parameters::default()).await;
+        let result = get_double_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/reflect/ConstantPool",
+            "getDoubleAt0",
+            "(Ljava/lang/Object;I)D",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.reflect.ConstantPool.getFieldAt0(Ljava/lang/Object;I)Ljava/lang/reflect/Field;"
-    )]
     async fn test_get_field_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_field_at_0(thread, Parameters::default()).await;
+        let result = get_field_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/reflect/ConstantPool",
+            "getFieldAt0",
+            "(Ljava/lang/Object;I)Ljava/lang/reflect/Field;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.reflect.ConstantPool.getFieldAtIfLoaded0(Ljava/lang/Object;I)Ljava/lang/reflect/Field;"
-    )]
     async fn test_get_field_at_if_loaded_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_field_at_if_loaded_0(thread, Parameters::default()).await;
+        let result = get_field_at_if_loaded_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/reflect/ConstantPool",
+            "getFieldAtIfLoaded0",
+            "(Ljava/lang/Object;I)Ljava/lang/reflect/Field;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.reflect.ConstantPool.getFloatAt0(Ljava/lang/Object;I)F"
-    )]
     async fn test_get_float_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_float_at_0(thread, Parameters::default()).await;
+        let result = get_float_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/reflect/ConstantPool",
+            "getFloatAt0",
+            "(Ljava/lang/Object;I)F",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.reflect.ConstantPool.getIntAt0(Ljava/lang/Object;I)I"
-    )]
     async fn test_get_int_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_int_at_0(thread, Parameters::default()).await;
+        let result = get_int_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/reflect/ConstantPool",
+            "getIntAt0",
+            "(Ljava/lang/Object;I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.reflect.ConstantPool.getLongAt0(Ljava/lang/Object;I)J"
-    )]
     async fn test_get_long_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_long_at_0(thread, Parameters::default()).await;
+        let result = get_long_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/reflect/ConstantPool",
+            "getLongAt0",
+            "(Ljava/lang/Object;I)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.reflect.ConstantPool.getMemberRefInfoAt0(Ljava/lang/Object;I)[Ljava/lang/String;"
-    )]
     async fn test_get_member_ref_info_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_member_ref_info_at_0(thread, Parameters::default()).await;
+        let result = get_member_ref_info_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/reflect/ConstantPool",
+            "getMemberRefInfoAt0",
+            "(Ljava/lang/Object;I)[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.reflect.ConstantPool.getMethodAt0(Ljava/lang/Object;I)Ljava/lang/reflect/Member;"
-    )]
     async fn test_get_method_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_method_at_0(thread, Parameters::default()).await;
+        let result = get_method_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/reflect/ConstantPool",
+            "getMethodAt0",
+            "(Ljava/lang/Object;I)Ljava/lang/reflect/Member;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.reflect.ConstantPool.getMethodAtIfLoaded0(Ljava/lang/Object;I)Ljava/lang/reflect/Member;"
-    )]
     async fn test_get_method_at_if_loaded_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_method_at_if_loaded_0(thread, Parameters::default()).await;
+        let result = get_method_at_if_loaded_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/reflect/ConstantPool",
+            "getMethodAtIfLoaded0",
+            "(Ljava/lang/Object;I)Ljava/lang/reflect/Member;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.reflect.ConstantPool.getSize0(Ljava/lang/Object;)I"
-    )]
     async fn test_get_size_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_size_0(thread, Parameters::default()).await;
+        let result = get_size_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/reflect/ConstantPool",
+            "getSize0",
+            "(Ljava/lang/Object;)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.reflect.ConstantPool.getStringAt0(Ljava/lang/Object;I)Ljava/lang/String;"
-    )]
     async fn test_get_string_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_string_at_0(thread, Parameters::default()).await;
+        let result = get_string_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/reflect/ConstantPool",
+            "getStringAt0",
+            "(Ljava/lang/Object;I)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.reflect.ConstantPool.getUTF8At0(Ljava/lang/Object;I)Ljava/lang/String;"
-    )]
     async fn test_get_utf_8_at_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_utf_8_at_0(thread, Parameters::default()).await;
+        let result = get_utf_8_at_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/reflect/ConstantPool",
+            "getUTF8At0",
+            "(Ljava/lang/Object;I)Ljava/lang/String;",
+        );
     }
 }
@@ -1,5 +1,5 @@
 use crate::java_object::JavaObject;
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -50,7 +50,7 @@ async fn get_caller_class_2(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.reflect.Reflection.getCallerClass(I)Ljava/lang/Class;")
+    unsatisfied_link(CLASS_NAME, "getCallerClass", "(I)Ljava/lang/Class;")
 }
 
 #[async_recursion(?Send)]
@@ -82,13 +82,16 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.reflect.Reflection.getCallerClass(I)Ljava/lang/Class;"
-    )]
     async fn test_get_caller_class_2() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
         let parameters = Parameters::default();
-        let _ = get_caller_class_2(thread, parameters).await;
+        let result = get_caller_class_2(thread, parameters).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/reflect/Reflection",
+            "getCallerClass",
+            "(I)Ljava/lang/Class;",
+        );
     }
 
     #[tokio::test]
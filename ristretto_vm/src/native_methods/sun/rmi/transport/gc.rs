@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,7 +23,7 @@ async fn max_object_inspection_age(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.rmi.transport.GC.maxObjectInspectionAge()J")
+    unsatisfied_link(CLASS_NAME, "maxObjectInspectionAge", "()J")
 }
 
 #[cfg(test)]
@@ -31,11 +31,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.rmi.transport.GC.maxObjectInspectionAge()J"
-    )]
     async fn test_max_object_inspection_age() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = max_object_inspection_age(thread, Parameters::default()).await;
+        let result = max_object_inspection_age(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/rmi/transport/GC",
+            "maxObjectInspectionAge",
+            "()J",
+        );
     }
 }
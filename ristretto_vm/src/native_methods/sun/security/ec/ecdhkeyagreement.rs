@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -15,7 +15,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn derive_key(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.ec.ECDHKeyAgreement.deriveKey([B[B[B)[B")
+    unsatisfied_link(CLASS_NAME, "deriveKey", "([B[B[B)[B")
 }
 
 #[cfg(test)]
@@ -23,11 +23,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.ec.ECDHKeyAgreement.deriveKey([B[B[B)[B"
-    )]
     async fn test_derive_key() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = derive_key(thread, Parameters::default()).await;
+        let result = derive_key(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/ec/ECDHKeyAgreement",
+            "deriveKey",
+            "([B[B[B)[B",
+        );
     }
 }
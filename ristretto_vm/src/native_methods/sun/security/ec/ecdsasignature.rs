@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -21,7 +21,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn sign_digest(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.ec.ECDSASignature.signDigest([B[B[B[BI)[B")
+    unsatisfied_link(CLASS_NAME, "signDigest", "([B[B[B[BI)[B")
 }
 
 #[async_recursion(?Send)]
@@ -29,7 +29,7 @@ async fn verify_signed_digest(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.ec.ECDSASignature.verifySignedDigest([B[B[B[B)Z")
+    unsatisfied_link(CLASS_NAME, "verifySignedDigest", "([B[B[B[B)Z")
 }
 
 #[cfg(test)]
@@ -37,20 +37,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.ec.ECDSASignature.signDigest([B[B[B[BI)[B"
-    )]
     async fn test_sign_digest() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = sign_digest(thread, Parameters::default()).await;
+        let result = sign_digest(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/ec/ECDSASignature",
+            "signDigest",
+            "([B[B[B[BI)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.ec.ECDSASignature.verifySignedDigest([B[B[B[B)Z"
-    )]
     async fn test_verify_signed_digest() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = verify_signed_digest(thread, Parameters::default()).await;
+        let result = verify_signed_digest(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/ec/ECDSASignature",
+            "verifySignedDigest",
+            "([B[B[B[B)Z",
+        );
     }
 }
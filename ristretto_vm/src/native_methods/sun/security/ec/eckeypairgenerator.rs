@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -24,7 +24,11 @@ async fn generate_ec_key_pair(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.ec.ECKeyPairGenerator.generateECKeyPair(I[B[B)[Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "generateECKeyPair",
+        "(I[B[B)[Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -32,7 +36,7 @@ async fn is_curve_supported(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.ec.ECKeyPairGenerator.isCurveSupported([B)Z")
+    unsatisfied_link(CLASS_NAME, "isCurveSupported", "([B)Z")
 }
 
 #[cfg(test)]
@@ -40,20 +44,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.ec.ECKeyPairGenerator.generateECKeyPair(I[B[B)[Ljava/lang/Object;"
-    )]
     async fn test_generate_ec_key_pair() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = generate_ec_key_pair(thread, Parameters::default()).await;
+        let result = generate_ec_key_pair(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/ec/ECKeyPairGenerator",
+            "generateECKeyPair",
+            "(I[B[B)[Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.ec.ECKeyPairGenerator.isCurveSupported([B)Z"
-    )]
     async fn test_is_curve_supported() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_curve_supported(thread, Parameters::default()).await;
+        let result = is_curve_supported(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/ec/ECKeyPairGenerator",
+            "isCurveSupported",
+            "([B)Z",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -23,7 +23,7 @@ async fn get_windows_directory(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.krb5.Config.getWindowsDirectory(Z)Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getWindowsDirectory", "(Z)Ljava/lang/String;")
 }
 
 #[cfg(test)]
@@ -31,11 +31,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.krb5.Config.getWindowsDirectory(Z)Ljava/lang/String;"
-    )]
     async fn test_get_windows_directory() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_windows_directory(thread, Parameters::default()).await;
+        let result = get_windows_directory(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/krb5/Config",
+            "getWindowsDirectory",
+            "(Z)Ljava/lang/String;",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_8};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_8};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -39,7 +39,7 @@ async fn get_kerberos_config(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.krb5.SCDynamicStoreConfig.getKerberosConfig()Ljava/util/Hashtable;")
+    unsatisfied_link(CLASS_NAME, "getKerberosConfig", "()Ljava/util/Hashtable;")
 }
 
 #[async_recursion(?Send)]
@@ -47,7 +47,7 @@ async fn install_notification_callback(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.krb5.SCDynamicStoreConfig.installNotificationCallback()V")
+    unsatisfied_link(CLASS_NAME, "installNotificationCallback", "()V")
 }
 
 #[cfg(test)]
@@ -55,20 +55,26 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.krb5.SCDynamicStoreConfig.getKerberosConfig()Ljava/util/Hashtable;"
-    )]
     async fn test_get_kerberos_config() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_kerberos_config(thread, Parameters::default()).await;
+        let result = get_kerberos_config(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/krb5/SCDynamicStoreConfig",
+            "getKerberosConfig",
+            "()Ljava/util/Hashtable;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.krb5.SCDynamicStoreConfig.installNotificationCallback()V"
-    )]
     async fn test_install_notification_callback() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = install_notification_callback(thread, Parameters::default()).await;
+        let result = install_notification_callback(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/krb5/SCDynamicStoreConfig",
+            "installNotificationCallback",
+            "()V",
+        );
     }
 }
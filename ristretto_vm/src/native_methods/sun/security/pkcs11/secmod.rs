@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -47,7 +47,7 @@ async fn nss_get_library_handle(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.Secmod.nssGetLibraryHandle(Ljava/lang/String;)J")
+    unsatisfied_link(CLASS_NAME, "nssGetLibraryHandle", "(Ljava/lang/String;)J")
 }
 
 #[async_recursion(?Send)]
@@ -55,22 +55,30 @@ async fn nss_get_module_list(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.Secmod.nssGetModuleList(JLjava/lang/String;)Ljava/lang/Object;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nssGetModuleList",
+        "(JLjava/lang/String;)Ljava/lang/Object;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn nss_initialize(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.Secmod.nssInitialize(Ljava/lang/String;JLjava/lang/String;Z)Z")
+    unsatisfied_link(
+        CLASS_NAME,
+        "nssInitialize",
+        "(Ljava/lang/String;JLjava/lang/String;Z)Z",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn nss_load_library(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.Secmod.nssLoadLibrary(Ljava/lang/String;)J")
+    unsatisfied_link(CLASS_NAME, "nssLoadLibrary", "(Ljava/lang/String;)J")
 }
 
 #[async_recursion(?Send)]
 async fn nss_version_check(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.Secmod.nssVersionCheck(JLjava/lang/String;)Z")
+    unsatisfied_link(CLASS_NAME, "nssVersionCheck", "(JLjava/lang/String;)Z")
 }
 
 #[cfg(test)]
@@ -78,47 +86,62 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.Secmod.nssGetLibraryHandle(Ljava/lang/String;)J"
-    )]
     async fn test_nss_get_library_handle() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = nss_get_library_handle(thread, Parameters::default()).await;
+        let result = nss_get_library_handle(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/Secmod",
+            "nssGetLibraryHandle",
+            "(Ljava/lang/String;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.Secmod.nssGetModuleList(JLjava/lang/String;)Ljava/lang/Object;"
-    )]
     async fn test_nss_get_module_list() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = nss_get_module_list(thread, Parameters::default()).await;
+        let result = nss_get_module_list(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/Secmod",
+            "nssGetModuleList",
+            "(JLjava/lang/String;)Ljava/lang/Object;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.Secmod.nssInitialize(Ljava/lang/String;JLjava/lang/String;Z)Z"
-    )]
     async fn test_nss_initialize() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = nss_initialize(thread, Parameters::default()).await;
+        let result = nss_initialize(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/Secmod",
+            "nssInitialize",
+            "(Ljava/lang/String;JLjava/lang/String;Z)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.Secmod.nssLoadLibrary(Ljava/lang/String;)J"
-    )]
     async fn test_nss_load_library() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = nss_load_library(thread, Parameters::default()).await;
+        let result = nss_load_library(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/Secmod",
+            "nssLoadLibrary",
+            "(Ljava/lang/String;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.Secmod.nssVersionCheck(JLjava/lang/String;)Z"
-    )]
     async fn test_nss_version_check() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = nss_version_check(thread, Parameters::default()).await;
+        let result = nss_version_check(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/Secmod",
+            "nssVersionCheck",
+            "(JLjava/lang/String;)Z",
+        );
     }
 }
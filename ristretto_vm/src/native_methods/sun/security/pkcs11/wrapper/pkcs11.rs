@@ -1,4 +1,6 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_11, JAVA_17, JAVA_21};
+use crate::native_methods::registry::{
+    unsatisfied_link, MethodRegistry, JAVA_11, JAVA_17, JAVA_21,
+};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -290,102 +292,126 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn c_close_session(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_CloseSession(J)V")
+    unsatisfied_link(CLASS_NAME, "C_CloseSession", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn c_copy_object(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_CopyObject(JJ[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)J")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_CopyObject",
+        "(JJ[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)J",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn c_create_object(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_CreateObject(J[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)J")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_CreateObject",
+        "(J[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)J",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn c_decrypt(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_Decrypt(JJ[BIIJ[BII)I")
+    unsatisfied_link(CLASS_NAME, "C_Decrypt", "(JJ[BIIJ[BII)I")
 }
 
 #[async_recursion(?Send)]
 async fn c_decrypt_final(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_DecryptFinal(JJ[BII)I")
+    unsatisfied_link(CLASS_NAME, "C_DecryptFinal", "(JJ[BII)I")
 }
 
 #[async_recursion(?Send)]
 async fn c_decrypt_init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_DecryptInit(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_DecryptInit",
+        "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn c_decrypt_update(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_DecryptUpdate(JJ[BIIJ[BII)I")
+    unsatisfied_link(CLASS_NAME, "C_DecryptUpdate", "(JJ[BIIJ[BII)I")
 }
 
 #[async_recursion(?Send)]
 async fn c_derive_key(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_DeriveKey(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)J")
+    unsatisfied_link(CLASS_NAME, "C_DeriveKey", "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)J")
 }
 
 #[async_recursion(?Send)]
 async fn c_destroy_object(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_DestroyObject(JJ)V")
+    unsatisfied_link(CLASS_NAME, "C_DestroyObject", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn c_digest_final(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_DigestFinal(J[BII)I")
+    unsatisfied_link(CLASS_NAME, "C_DigestFinal", "(J[BII)I")
 }
 
 #[async_recursion(?Send)]
 async fn c_digest_init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_DigestInit(JLsun/security/pkcs11/wrapper/CK_MECHANISM;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_DigestInit",
+        "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn c_digest_key(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_DigestKey(JJ)V")
+    unsatisfied_link(CLASS_NAME, "C_DigestKey", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn c_digest_single(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_DigestSingle(JLsun/security/pkcs11/wrapper/CK_MECHANISM;[BII[BII)I")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_DigestSingle",
+        "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;[BII[BII)I",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn c_digest_update(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_DigestUpdate(JJ[BII)V")
+    unsatisfied_link(CLASS_NAME, "C_DigestUpdate", "(JJ[BII)V")
 }
 
 #[async_recursion(?Send)]
 async fn c_encrypt(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_Encrypt(JJ[BIIJ[BII)I")
+    unsatisfied_link(CLASS_NAME, "C_Encrypt", "(JJ[BIIJ[BII)I")
 }
 
 #[async_recursion(?Send)]
 async fn c_encrypt_final(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_EncryptFinal(JJ[BII)I")
+    unsatisfied_link(CLASS_NAME, "C_EncryptFinal", "(JJ[BII)I")
 }
 
 #[async_recursion(?Send)]
 async fn c_encrypt_init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_EncryptInit(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_EncryptInit",
+        "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn c_encrypt_update(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_EncryptUpdate(JJ[BIIJ[BII)I")
+    unsatisfied_link(CLASS_NAME, "C_EncryptUpdate", "(JJ[BIIJ[BII)I")
 }
 
 #[async_recursion(?Send)]
 async fn c_finalize(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_Finalize(Ljava/lang/Object;)V")
+    unsatisfied_link(CLASS_NAME, "C_Finalize", "(Ljava/lang/Object;)V")
 }
 
 #[async_recursion(?Send)]
 async fn c_find_objects(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_FindObjects(JJ)[J")
+    unsatisfied_link(CLASS_NAME, "C_FindObjects", "(JJ)[J")
 }
 
 #[async_recursion(?Send)]
@@ -393,7 +419,7 @@ async fn c_find_objects_final(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_FindObjectsFinal(J)V")
+    unsatisfied_link(CLASS_NAME, "C_FindObjectsFinal", "(J)V")
 }
 
 #[async_recursion(?Send)]
@@ -401,7 +427,11 @@ async fn c_find_objects_init(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_FindObjectsInit(J[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_FindObjectsInit",
+        "(J[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -409,7 +439,11 @@ async fn c_gcm_decrypt_init_with_retry(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_GCMDecryptInitWithRetry(JLsun/security/pkcs11/wrapper/CK_MECHANISM;JZ)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_GCMDecryptInitWithRetry",
+        "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;JZ)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -417,12 +451,20 @@ async fn c_gcm_encrypt_init_with_retry(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_GCMEncryptInitWithRetry(JLsun/security/pkcs11/wrapper/CK_MECHANISM;JZ)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_GCMEncryptInitWithRetry",
+        "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;JZ)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn c_generate_key(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_GenerateKey(JLsun/security/pkcs11/wrapper/CK_MECHANISM;[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)J")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_GenerateKey",
+        "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)J",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -430,12 +472,12 @@ async fn c_generate_key_pair(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_GenerateKeyPair(JLsun/security/pkcs11/wrapper/CK_MECHANISM;[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)[J")
+    unsatisfied_link(CLASS_NAME, "C_GenerateKeyPair", "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)[J")
 }
 
 #[async_recursion(?Send)]
 async fn c_generate_random(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_GenerateRandom(J[B)V")
+    unsatisfied_link(CLASS_NAME, "C_GenerateRandom", "(J[B)V")
 }
 
 #[async_recursion(?Send)]
@@ -443,12 +485,20 @@ async fn c_get_attribute_value(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_GetAttributeValue(JJ[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_GetAttributeValue",
+        "(JJ[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn c_get_info(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_GetInfo()Lsun/security/pkcs11/wrapper/CK_INFO;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_GetInfo",
+        "()Lsun/security/pkcs11/wrapper/CK_INFO;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -456,7 +506,11 @@ async fn c_get_mechanism_info(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_GetMechanismInfo(JJ)Lsun/security/pkcs11/wrapper/CK_MECHANISM_INFO;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_GetMechanismInfo",
+        "(JJ)Lsun/security/pkcs11/wrapper/CK_MECHANISM_INFO;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -464,7 +518,7 @@ async fn c_get_mechanism_list(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_GetMechanismList(J)[J")
+    unsatisfied_link(CLASS_NAME, "C_GetMechanismList", "(J)[J")
 }
 
 #[async_recursion(?Send)]
@@ -472,7 +526,7 @@ async fn c_get_operation_state(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_GetOperationState(J)[B")
+    unsatisfied_link(CLASS_NAME, "C_GetOperationState", "(J)[B")
 }
 
 #[async_recursion(?Send)]
@@ -480,52 +534,68 @@ async fn c_get_session_info(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_GetSessionInfo(J)Lsun/security/pkcs11/wrapper/CK_SESSION_INFO;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_GetSessionInfo",
+        "(J)Lsun/security/pkcs11/wrapper/CK_SESSION_INFO;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn c_get_slot_info(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_GetSlotInfo(J)Lsun/security/pkcs11/wrapper/CK_SLOT_INFO;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_GetSlotInfo",
+        "(J)Lsun/security/pkcs11/wrapper/CK_SLOT_INFO;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn c_get_slot_list(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_GetSlotList(Z)[J")
+    unsatisfied_link(CLASS_NAME, "C_GetSlotList", "(Z)[J")
 }
 
 #[async_recursion(?Send)]
 async fn c_get_token_info(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_GetTokenInfo(J)Lsun/security/pkcs11/wrapper/CK_TOKEN_INFO;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_GetTokenInfo",
+        "(J)Lsun/security/pkcs11/wrapper/CK_TOKEN_INFO;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn c_initialize(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_Initialize(Ljava/lang/Object;)V")
+    unsatisfied_link(CLASS_NAME, "C_Initialize", "(Ljava/lang/Object;)V")
 }
 
 #[async_recursion(?Send)]
 async fn c_login(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_Login(JJ[C)V")
+    unsatisfied_link(CLASS_NAME, "C_Login", "(JJ[C)V")
 }
 
 #[async_recursion(?Send)]
 async fn c_logout(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_Logout(J)V")
+    unsatisfied_link(CLASS_NAME, "C_Logout", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn c_open_session(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_OpenSession(JJLjava/lang/Object;Lsun/security/pkcs11/wrapper/CK_NOTIFY;)J")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_OpenSession",
+        "(JJLjava/lang/Object;Lsun/security/pkcs11/wrapper/CK_NOTIFY;)J",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn c_seed_random(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_SeedRandom(J[B)V")
+    unsatisfied_link(CLASS_NAME, "C_SeedRandom", "(J[B)V")
 }
 
 #[async_recursion(?Send)]
 async fn c_session_cancel(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_SessionCancel(JJ)V")
+    unsatisfied_link(CLASS_NAME, "C_SessionCancel", "(JJ)V")
 }
 
 #[async_recursion(?Send)]
@@ -533,7 +603,11 @@ async fn c_set_attribute_value(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_SetAttributeValue(JJ[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_SetAttributeValue",
+        "(JJ[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)V",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -541,27 +615,31 @@ async fn c_set_operation_state(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_SetOperationState(J[BJJ)V")
+    unsatisfied_link(CLASS_NAME, "C_SetOperationState", "(J[BJJ)V")
 }
 
 #[async_recursion(?Send)]
 async fn c_sign(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_Sign(J[B)[B")
+    unsatisfied_link(CLASS_NAME, "C_Sign", "(J[B)[B")
 }
 
 #[async_recursion(?Send)]
 async fn c_sign_final(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_SignFinal(JI)[B")
+    unsatisfied_link(CLASS_NAME, "C_SignFinal", "(JI)[B")
 }
 
 #[async_recursion(?Send)]
 async fn c_sign_init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_SignInit(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_SignInit",
+        "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn c_sign_recover(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_SignRecover(J[BII[BII)I")
+    unsatisfied_link(CLASS_NAME, "C_SignRecover", "(J[BII[BII)I")
 }
 
 #[async_recursion(?Send)]
@@ -569,37 +647,45 @@ async fn c_sign_recover_init(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_SignRecoverInit(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_SignRecoverInit",
+        "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn c_sign_update(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_SignUpdate(JJ[BII)V")
+    unsatisfied_link(CLASS_NAME, "C_SignUpdate", "(JJ[BII)V")
 }
 
 #[async_recursion(?Send)]
 async fn c_unwrap_key(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_UnwrapKey(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J[B[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)J")
+    unsatisfied_link(CLASS_NAME, "C_UnwrapKey", "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J[B[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)J")
 }
 
 #[async_recursion(?Send)]
 async fn c_verify(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_Verify(J[B[B)V")
+    unsatisfied_link(CLASS_NAME, "C_Verify", "(J[B[B)V")
 }
 
 #[async_recursion(?Send)]
 async fn c_verify_final(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_VerifyFinal(J[B)V")
+    unsatisfied_link(CLASS_NAME, "C_VerifyFinal", "(J[B)V")
 }
 
 #[async_recursion(?Send)]
 async fn c_verify_init(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_VerifyInit(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_VerifyInit",
+        "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn c_verify_recover(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_VerifyRecover(J[BII[BII)I")
+    unsatisfied_link(CLASS_NAME, "C_VerifyRecover", "(J[BII[BII)I")
 }
 
 #[async_recursion(?Send)]
@@ -607,42 +693,58 @@ async fn c_verify_recover_init(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_VerifyRecoverInit(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_VerifyRecoverInit",
+        "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn c_verify_update(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_VerifyUpdate(JJ[BII)V")
+    unsatisfied_link(CLASS_NAME, "C_VerifyUpdate", "(JJ[BII)V")
 }
 
 #[async_recursion(?Send)]
 async fn c_wrap_key(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.C_WrapKey(JLsun/security/pkcs11/wrapper/CK_MECHANISM;JJ)[B")
+    unsatisfied_link(
+        CLASS_NAME,
+        "C_WrapKey",
+        "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;JJ)[B",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn connect(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.connect(Ljava/lang/String;Ljava/lang/String;)Lsun/security/pkcs11/wrapper/CK_VERSION;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "connect",
+        "(Ljava/lang/String;Ljava/lang/String;)Lsun/security/pkcs11/wrapper/CK_VERSION;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn create_native_key(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.createNativeKey(J[BJLsun/security/pkcs11/wrapper/CK_MECHANISM;)J")
+    unsatisfied_link(
+        CLASS_NAME,
+        "createNativeKey",
+        "(J[BJLsun/security/pkcs11/wrapper/CK_MECHANISM;)J",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn disconnect(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.disconnect()V")
+    unsatisfied_link(CLASS_NAME, "disconnect", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn finalize_library(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.finalizeLibrary()V")
+    unsatisfied_link(CLASS_NAME, "finalizeLibrary", "()V")
 }
 
 #[async_recursion(?Send)]
 async fn free_mechanism(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.freeMechanism(J)J")
+    unsatisfied_link(CLASS_NAME, "freeMechanism", "(J)J")
 }
 
 #[async_recursion(?Send)]
@@ -650,7 +752,11 @@ async fn get_native_key_info(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.getNativeKeyInfo(JJJLsun/security/pkcs11/wrapper/CK_MECHANISM;)[B")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getNativeKeyInfo",
+        "(JJJLsun/security/pkcs11/wrapper/CK_MECHANISM;)[B",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -658,7 +764,7 @@ async fn initialize_library(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.pkcs11.wrapper.PKCS11.initializeLibrary(Z)V")
+    unsatisfied_link(CLASS_NAME, "initializeLibrary", "(Z)V")
 }
 
 #[cfg(test)]
@@ -666,587 +772,762 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_CloseSession(J)V"
-    )]
     async fn test_c_close_session() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_close_session(thread, Parameters::default()).await;
+        let result = c_close_session(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_CloseSession",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_CopyObject(JJ[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)J"
-    )]
     async fn test_c_copy_object() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_copy_object(thread, Parameters::default()).await;
+        let result = c_copy_object(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_CopyObject",
+            "(JJ[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_CreateObject(J[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)J"
-    )]
     async fn test_c_create_object() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_create_object(thread, Parameters::default()).await;
+        let result = c_create_object(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_CreateObject",
+            "(J[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_Decrypt(JJ[BIIJ[BII)I"
-    )]
     async fn test_c_decrypt() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_decrypt(thread, Parameters::default()).await;
+        let result = c_decrypt(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_Decrypt",
+            "(JJ[BIIJ[BII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_DecryptFinal(JJ[BII)I"
-    )]
     async fn test_c_decrypt_final() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_decrypt_final(thread, Parameters::default()).await;
+        let result = c_decrypt_final(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_DecryptFinal",
+            "(JJ[BII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_DecryptInit(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V"
-    )]
     async fn test_c_decrypt_init() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_decrypt_init(thread, Parameters::default()).await;
+        let result = c_decrypt_init(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_DecryptInit",
+            "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_DecryptUpdate(JJ[BIIJ[BII)I"
-    )]
     async fn test_c_decrypt_update() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_decrypt_update(thread, Parameters::default()).await;
+        let result = c_decrypt_update(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_DecryptUpdate",
+            "(JJ[BIIJ[BII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_DeriveKey(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)J"
-    )]
     async fn test_c_derive_key() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_derive_key(thread, Parameters::default()).await;
+        let result = c_derive_key(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/security/pkcs11/wrapper/PKCS11", "C_DeriveKey", "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)J");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_DestroyObject(JJ)V"
-    )]
     async fn test_c_destroy_object() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_destroy_object(thread, Parameters::default()).await;
+        let result = c_destroy_object(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_DestroyObject",
+            "(JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_DigestFinal(J[BII)I"
-    )]
     async fn test_c_digest_final() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_digest_final(thread, Parameters::default()).await;
+        let result = c_digest_final(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_DigestFinal",
+            "(J[BII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_DigestInit(JLsun/security/pkcs11/wrapper/CK_MECHANISM;)V"
-    )]
     async fn test_c_digest_init() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_digest_init(thread, Parameters::default()).await;
+        let result = c_digest_init(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_DigestInit",
+            "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_DigestKey(JJ)V"
-    )]
     async fn test_c_digest_key() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_digest_key(thread, Parameters::default()).await;
+        let result = c_digest_key(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_DigestKey",
+            "(JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_DigestSingle(JLsun/security/pkcs11/wrapper/CK_MECHANISM;[BII[BII)I"
-    )]
     async fn test_c_digest_single() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_digest_single(thread, Parameters::default()).await;
+        let result = c_digest_single(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_DigestSingle",
+            "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;[BII[BII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_DigestUpdate(JJ[BII)V"
-    )]
     async fn test_c_digest_update() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_digest_update(thread, Parameters::default()).await;
+        let result = c_digest_update(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_DigestUpdate",
+            "(JJ[BII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_Encrypt(JJ[BIIJ[BII)I"
-    )]
     async fn test_c_encrypt() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_encrypt(thread, Parameters::default()).await;
+        let result = c_encrypt(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_Encrypt",
+            "(JJ[BIIJ[BII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_EncryptFinal(JJ[BII)I"
-    )]
     async fn test_c_encrypt_final() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_encrypt_final(thread, Parameters::default()).await;
+        let result = c_encrypt_final(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_EncryptFinal",
+            "(JJ[BII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_EncryptInit(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V"
-    )]
     async fn test_c_encrypt_init() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_encrypt_init(thread, Parameters::default()).await;
+        let result = c_encrypt_init(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_EncryptInit",
+            "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_EncryptUpdate(JJ[BIIJ[BII)I"
-    )]
     async fn test_c_encrypt_update() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_encrypt_update(thread, Parameters::default()).await;
+        let result = c_encrypt_update(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_EncryptUpdate",
+            "(JJ[BIIJ[BII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_Finalize(Ljava/lang/Object;)V"
-    )]
     async fn test_c_finalize() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_finalize(thread, Parameters::default()).await;
+        let result = c_finalize(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_Finalize",
+            "(Ljava/lang/Object;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_FindObjects(JJ)[J"
-    )]
     async fn test_c_find_objects() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_find_objects(thread, Parameters::default()).await;
+        let result = c_find_objects(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_FindObjects",
+            "(JJ)[J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_FindObjectsFinal(J)V"
-    )]
     async fn test_c_find_objects_final() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_find_objects_final(thread, Parameters::default()).await;
+        let result = c_find_objects_final(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_FindObjectsFinal",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_FindObjectsInit(J[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)V"
-    )]
     async fn test_c_find_objects_init() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_find_objects_init(thread, Parameters::default()).await;
+        let result = c_find_objects_init(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_FindObjectsInit",
+            "(J[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_GCMDecryptInitWithRetry(JLsun/security/pkcs11/wrapper/CK_MECHANISM;JZ)V"
-    )]
     async fn test_c_gcm_decrypt_init_with_retry() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_gcm_decrypt_init_with_retry(thread, Parameters::default()).await;
+        let result = c_gcm_decrypt_init_with_retry(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_GCMDecryptInitWithRetry",
+            "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;JZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_GCMEncryptInitWithRetry(JLsun/security/pkcs11/wrapper/CK_MECHANISM;JZ)V"
-    )]
     async fn test_c_gcm_encrypt_init_with_retry() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_gcm_encrypt_init_with_retry(thread, Parameters::default()).await;
+        let result = c_gcm_encrypt_init_with_retry(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_GCMEncryptInitWithRetry",
+            "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;JZ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_GenerateKey(JLsun/security/pkcs11/wrapper/CK_MECHANISM;[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)J"
-    )]
     async fn test_c_generate_key() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_generate_key(thread, Parameters::default()).await;
+        let result = c_generate_key(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/security/pkcs11/wrapper/PKCS11", "C_GenerateKey", "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)J");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_GenerateKeyPair(JLsun/security/pkcs11/wrapper/CK_MECHANISM;[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)[J"
-    )]
     async fn test_c_generate_key_pair() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_generate_key_pair(thread, Parameters::default()).await;
+        let result = c_generate_key_pair(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/security/pkcs11/wrapper/PKCS11", "C_GenerateKeyPair", "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)[J");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_GenerateRandom(J[B)V"
-    )]
     async fn test_c_generate_random() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_generate_random(thread, Parameters::default()).await;
+        let result = c_generate_random(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_GenerateRandom",
+            "(J[B)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_GetAttributeValue(JJ[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)V"
-    )]
     async fn test_c_get_attribute_value() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_get_attribute_value(thread, Parameters::default()).await;
+        let result = c_get_attribute_value(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_GetAttributeValue",
+            "(JJ[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_GetInfo()Lsun/security/pkcs11/wrapper/CK_INFO;"
-    )]
     async fn test_c_get_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_get_info(thread, Parameters::default()).await;
+        let result = c_get_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_GetInfo",
+            "()Lsun/security/pkcs11/wrapper/CK_INFO;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_GetMechanismInfo(JJ)Lsun/security/pkcs11/wrapper/CK_MECHANISM_INFO;"
-    )]
     async fn test_c_get_mechanism_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_get_mechanism_info(thread, Parameters::default()).await;
+        let result = c_get_mechanism_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_GetMechanismInfo",
+            "(JJ)Lsun/security/pkcs11/wrapper/CK_MECHANISM_INFO;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_GetMechanismList(J)[J"
-    )]
     async fn test_c_get_mechanism_list() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_get_mechanism_list(thread, Parameters::default()).await;
+        let result = c_get_mechanism_list(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_GetMechanismList",
+            "(J)[J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_GetOperationState(J)[B"
-    )]
     async fn test_c_get_operation_state() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_get_operation_state(thread, Parameters::default()).await;
+        let result = c_get_operation_state(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_GetOperationState",
+            "(J)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_GetSessionInfo(J)Lsun/security/pkcs11/wrapper/CK_SESSION_INFO;"
-    )]
     async fn test_c_get_session_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_get_session_info(thread, Parameters::default()).await;
+        let result = c_get_session_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_GetSessionInfo",
+            "(J)Lsun/security/pkcs11/wrapper/CK_SESSION_INFO;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_GetSlotInfo(J)Lsun/security/pkcs11/wrapper/CK_SLOT_INFO;"
-    )]
     async fn test_c_get_slot_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_get_slot_info(thread, Parameters::default()).await;
+        let result = c_get_slot_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_GetSlotInfo",
+            "(J)Lsun/security/pkcs11/wrapper/CK_SLOT_INFO;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_GetSlotList(Z)[J"
-    )]
     async fn test_c_get_slot_list() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_get_slot_list(thread, Parameters::default()).await;
+        let result = c_get_slot_list(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_GetSlotList",
+            "(Z)[J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_GetTokenInfo(J)Lsun/security/pkcs11/wrapper/CK_TOKEN_INFO;"
-    )]
     async fn test_c_get_token_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_get_token_info(thread, Parameters::default()).await;
+        let result = c_get_token_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_GetTokenInfo",
+            "(J)Lsun/security/pkcs11/wrapper/CK_TOKEN_INFO;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_Initialize(Ljava/lang/Object;)V"
-    )]
     async fn test_c_initialize() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_initialize(thread, Parameters::default()).await;
+        let result = c_initialize(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_Initialize",
+            "(Ljava/lang/Object;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_Login(JJ[C)V"
-    )]
     async fn test_c_login() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_login(thread, Parameters::default()).await;
+        let result = c_login(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_Login",
+            "(JJ[C)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_Logout(J)V"
-    )]
     async fn test_c_logout() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_logout(thread, Parameters::default()).await;
+        let result = c_logout(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_Logout",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_OpenSession(JJLjava/lang/Object;Lsun/security/pkcs11/wrapper/CK_NOTIFY;)J"
-    )]
     async fn test_c_open_session() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_open_session(thread, Parameters::default()).await;
+        let result = c_open_session(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_OpenSession",
+            "(JJLjava/lang/Object;Lsun/security/pkcs11/wrapper/CK_NOTIFY;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_SeedRandom(J[B)V"
-    )]
     async fn test_c_seed_random() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_seed_random(thread, Parameters::default()).await;
+        let result = c_seed_random(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_SeedRandom",
+            "(J[B)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_SessionCancel(JJ)V"
-    )]
     async fn test_c_session_cancel() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_session_cancel(thread, Parameters::default()).await;
+        let result = c_session_cancel(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_SessionCancel",
+            "(JJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_SetAttributeValue(JJ[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)V"
-    )]
     async fn test_c_set_attribute_value() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_set_attribute_value(thread, Parameters::default()).await;
+        let result = c_set_attribute_value(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_SetAttributeValue",
+            "(JJ[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_SetOperationState(J[BJJ)V"
-    )]
     async fn test_c_set_operation_state() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_set_operation_state(thread, Parameters::default()).await;
+        let result = c_set_operation_state(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_SetOperationState",
+            "(J[BJJ)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_Sign(J[B)[B"
-    )]
     async fn test_c_sign() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_sign(thread, Parameters::default()).await;
+        let result = c_sign(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_Sign",
+            "(J[B)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_SignFinal(JI)[B"
-    )]
     async fn test_c_sign_final() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_sign_final(thread, Parameters::default()).await;
+        let result = c_sign_final(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_SignFinal",
+            "(JI)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_SignInit(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V"
-    )]
     async fn test_c_sign_init() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_sign_init(thread, Parameters::default()).await;
+        let result = c_sign_init(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_SignInit",
+            "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_SignRecover(J[BII[BII)I"
-    )]
     async fn test_c_sign_recover() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_sign_recover(thread, Parameters::default()).await;
+        let result = c_sign_recover(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_SignRecover",
+            "(J[BII[BII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_SignRecoverInit(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V"
-    )]
     async fn test_c_sign_recover_init() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_sign_recover_init(thread, Parameters::default()).await;
+        let result = c_sign_recover_init(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_SignRecoverInit",
+            "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_SignUpdate(JJ[BII)V"
-    )]
     async fn test_c_sign_update() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_sign_update(thread, Parameters::default()).await;
+        let result = c_sign_update(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_SignUpdate",
+            "(JJ[BII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_UnwrapKey(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J[B[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)J"
-    )]
     async fn test_c_unwrap_key() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_unwrap_key(thread, Parameters::default()).await;
+        let result = c_unwrap_key(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/security/pkcs11/wrapper/PKCS11", "C_UnwrapKey", "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J[B[Lsun/security/pkcs11/wrapper/CK_ATTRIBUTE;)J");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_Verify(J[B[B)V"
-    )]
     async fn test_c_verify() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_verify(thread, Parameters::default()).await;
+        let result = c_verify(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_Verify",
+            "(J[B[B)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_VerifyFinal(J[B)V"
-    )]
     async fn test_c_verify_final() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_verify_final(thread, Parameters::default()).await;
+        let result = c_verify_final(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_VerifyFinal",
+            "(J[B)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_VerifyInit(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V"
-    )]
     async fn test_c_verify_init() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_verify_init(thread, Parameters::default()).await;
+        let result = c_verify_init(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_VerifyInit",
+            "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_VerifyRecover(J[BII[BII)I"
-    )]
     async fn test_c_verify_recover() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_verify_recover(thread, Parameters::default()).await;
+        let result = c_verify_recover(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_VerifyRecover",
+            "(J[BII[BII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_VerifyRecoverInit(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V"
-    )]
     async fn test_c_verify_recover_init() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_verify_recover_init(thread, Parameters::default()).await;
+        let result = c_verify_recover_init(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_VerifyRecoverInit",
+            "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_VerifyUpdate(JJ[BII)V"
-    )]
     async fn test_c_verify_update() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_verify_update(thread, Parameters::default()).await;
+        let result = c_verify_update(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_VerifyUpdate",
+            "(JJ[BII)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.C_WrapKey(JLsun/security/pkcs11/wrapper/CK_MECHANISM;JJ)[B"
-    )]
     async fn test_c_wrap_key() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = c_wrap_key(thread, Parameters::default()).await;
+        let result = c_wrap_key(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "C_WrapKey",
+            "(JLsun/security/pkcs11/wrapper/CK_MECHANISM;JJ)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.connect(Ljava/lang/String;Ljava/lang/String;)Lsun/security/pkcs11/wrapper/CK_VERSION;"
-    )]
     async fn test_connect() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = connect(thread, Parameters::default()).await;
+        let result = connect(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "connect",
+            "(Ljava/lang/String;Ljava/lang/String;)Lsun/security/pkcs11/wrapper/CK_VERSION;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.createNativeKey(J[BJLsun/security/pkcs11/wrapper/CK_MECHANISM;)J"
-    )]
     async fn test_create_native_key() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_native_key(thread, Parameters::default()).await;
+        let result = create_native_key(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "createNativeKey",
+            "(J[BJLsun/security/pkcs11/wrapper/CK_MECHANISM;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.disconnect()V"
-    )]
     async fn test_disconnect() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = disconnect(thread, Parameters::default()).await;
+        let result = disconnect(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "disconnect",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.finalizeLibrary()V"
-    )]
     async fn test_finalize_library() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = finalize_library(thread, Parameters::default()).await;
+        let result = finalize_library(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "finalizeLibrary",
+            "()V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.freeMechanism(J)J"
-    )]
     async fn test_free_mechanism() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = free_mechanism(thread, Parameters::default()).await;
+        let result = free_mechanism(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "freeMechanism",
+            "(J)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.getNativeKeyInfo(JJJLsun/security/pkcs11/wrapper/CK_MECHANISM;)[B"
-    )]
     async fn test_get_native_key_info() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_native_key_info(thread, Parameters::default()).await;
+        let result = get_native_key_info(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "getNativeKeyInfo",
+            "(JJJLsun/security/pkcs11/wrapper/CK_MECHANISM;)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.pkcs11.wrapper.PKCS11.initializeLibrary(Z)V"
-    )]
     async fn test_initialize_library() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = initialize_library(thread, Parameters::default()).await;
+        let result = initialize_library(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/pkcs11/wrapper/PKCS11",
+            "initializeLibrary",
+            "(Z)V",
+        );
     }
 }
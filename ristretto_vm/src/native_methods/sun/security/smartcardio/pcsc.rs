@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -57,22 +57,22 @@ async fn s_card_begin_transaction(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.smartcardio.PCSC.SCardBeginTransaction(J)V")
+    unsatisfied_link(CLASS_NAME, "SCardBeginTransaction", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn s_card_connect(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.smartcardio.PCSC.SCardConnect(JLjava/lang/String;II)J")
+    unsatisfied_link(CLASS_NAME, "SCardConnect", "(JLjava/lang/String;II)J")
 }
 
 #[async_recursion(?Send)]
 async fn s_card_control(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.smartcardio.PCSC.SCardControl(JI[B)[B")
+    unsatisfied_link(CLASS_NAME, "SCardControl", "(JI[B)[B")
 }
 
 #[async_recursion(?Send)]
 async fn s_card_disconnect(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.smartcardio.PCSC.SCardDisconnect(JI)V")
+    unsatisfied_link(CLASS_NAME, "SCardDisconnect", "(JI)V")
 }
 
 #[async_recursion(?Send)]
@@ -80,7 +80,7 @@ async fn s_card_end_transaction(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.smartcardio.PCSC.SCardEndTransaction(JI)V")
+    unsatisfied_link(CLASS_NAME, "SCardEndTransaction", "(JI)V")
 }
 
 #[async_recursion(?Send)]
@@ -88,7 +88,7 @@ async fn s_card_establish_context(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.smartcardio.PCSC.SCardEstablishContext(I)J")
+    unsatisfied_link(CLASS_NAME, "SCardEstablishContext", "(I)J")
 }
 
 #[async_recursion(?Send)]
@@ -96,7 +96,11 @@ async fn s_card_get_status_change(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.smartcardio.PCSC.SCardGetStatusChange(JJ[I[Ljava/lang/String;)[I")
+    unsatisfied_link(
+        CLASS_NAME,
+        "SCardGetStatusChange",
+        "(JJ[I[Ljava/lang/String;)[I",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -104,17 +108,17 @@ async fn s_card_list_readers(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.security.smartcardio.PCSC.SCardListReaders(J)[Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "SCardListReaders", "(J)[Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
 async fn s_card_status(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.smartcardio.PCSC.SCardStatus(J[B)[B")
+    unsatisfied_link(CLASS_NAME, "SCardStatus", "(J[B)[B")
 }
 
 #[async_recursion(?Send)]
 async fn s_card_transmit(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.smartcardio.PCSC.SCardTransmit(JI[BII)[B")
+    unsatisfied_link(CLASS_NAME, "SCardTransmit", "(JI[BII)[B")
 }
 
 #[cfg(test)]
@@ -122,92 +126,122 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.smartcardio.PCSC.SCardBeginTransaction(J)V"
-    )]
     async fn test_s_card_begin_transaction() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = s_card_begin_transaction(thread, Parameters::default()).await;
+        let result = s_card_begin_transaction(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/smartcardio/PCSC",
+            "SCardBeginTransaction",
+            "(J)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.smartcardio.PCSC.SCardConnect(JLjava/lang/String;II)J"
-    )]
     async fn test_s_card_connect() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = s_card_connect(thread, Parameters::default()).await;
+        let result = s_card_connect(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/smartcardio/PCSC",
+            "SCardConnect",
+            "(JLjava/lang/String;II)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.smartcardio.PCSC.SCardControl(JI[B)[B"
-    )]
     async fn test_s_card_control() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = s_card_control(thread, Parameters::default()).await;
+        let result = s_card_control(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/smartcardio/PCSC",
+            "SCardControl",
+            "(JI[B)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.smartcardio.PCSC.SCardDisconnect(JI)V"
-    )]
     async fn test_s_card_disconnect() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = s_card_disconnect(thread, Parameters::default()).await;
+        let result = s_card_disconnect(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/smartcardio/PCSC",
+            "SCardDisconnect",
+            "(JI)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.smartcardio.PCSC.SCardEndTransaction(JI)V"
-    )]
     async fn test_s_card_end_transaction() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = s_card_end_transaction(thread, Parameters::default()).await;
+        let result = s_card_end_transaction(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/smartcardio/PCSC",
+            "SCardEndTransaction",
+            "(JI)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.smartcardio.PCSC.SCardEstablishContext(I)J"
-    )]
     async fn test_s_card_establish_context() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = s_card_establish_context(thread, Parameters::default()).await;
+        let result = s_card_establish_context(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/smartcardio/PCSC",
+            "SCardEstablishContext",
+            "(I)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.smartcardio.PCSC.SCardGetStatusChange(JJ[I[Ljava/lang/String;)[I"
-    )]
     async fn test_s_card_get_status_change() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = s_card_get_status_change(thread, Parameters::default()).await;
+        let result = s_card_get_status_change(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/smartcardio/PCSC",
+            "SCardGetStatusChange",
+            "(JJ[I[Ljava/lang/String;)[I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.smartcardio.PCSC.SCardListReaders(J)[Ljava/lang/String;"
-    )]
     async fn test_s_card_list_readers() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = s_card_list_readers(thread, Parameters::default()).await;
+        let result = s_card_list_readers(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/smartcardio/PCSC",
+            "SCardListReaders",
+            "(J)[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.smartcardio.PCSC.SCardStatus(J[B)[B"
-    )]
     async fn test_s_card_status() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = s_card_status(thread, Parameters::default()).await;
+        let result = s_card_status(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/smartcardio/PCSC",
+            "SCardStatus",
+            "(J[B)[B",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.smartcardio.PCSC.SCardTransmit(JI[BII)[B"
-    )]
     async fn test_s_card_transmit() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = s_card_transmit(thread, Parameters::default()).await;
+        let result = s_card_transmit(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/smartcardio/PCSC",
+            "SCardTransmit",
+            "(JI[BII)[B",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -20,7 +20,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn initialize(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.security.smartcardio.PlatformPCSC.initialize(Ljava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "initialize", "(Ljava/lang/String;)V")
 }
 
 #[cfg(test)]
@@ -28,11 +28,14 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.security.smartcardio.PlatformPCSC.initialize(Ljava/lang/String;)V"
-    )]
     async fn test_initialize() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = initialize(thread, Parameters::default()).await;
+        let result = initialize(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/security/smartcardio/PlatformPCSC",
+            "initialize",
+            "(Ljava/lang/String;)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -38,17 +38,17 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn check_permissions(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.tools.attach.VirtualMachineImpl.checkPermissions(Ljava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "checkPermissions", "(Ljava/lang/String;)V")
 }
 
 #[async_recursion(?Send)]
 async fn close(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.tools.attach.VirtualMachineImpl.close(I)V")
+    unsatisfied_link(CLASS_NAME, "close", "(I)V")
 }
 
 #[async_recursion(?Send)]
 async fn connect(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.tools.attach.VirtualMachineImpl.connect(ILjava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "connect", "(ILjava/lang/String;)V")
 }
 
 #[async_recursion(?Send)]
@@ -56,32 +56,32 @@ async fn create_attach_file_0(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.tools.attach.VirtualMachineImpl.createAttachFile0(Ljava/lang/String;)V")
+    unsatisfied_link(CLASS_NAME, "createAttachFile0", "(Ljava/lang/String;)V")
 }
 
 #[async_recursion(?Send)]
 async fn get_temp_dir(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.tools.attach.VirtualMachineImpl.getTempDir()Ljava/lang/String;")
+    unsatisfied_link(CLASS_NAME, "getTempDir", "()Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
 async fn read(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.tools.attach.VirtualMachineImpl.read(I[BII)I")
+    unsatisfied_link(CLASS_NAME, "read", "(I[BII)I")
 }
 
 #[async_recursion(?Send)]
 async fn send_quit_to(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.tools.attach.VirtualMachineImpl.sendQuitTo(I)V")
+    unsatisfied_link(CLASS_NAME, "sendQuitTo", "(I)V")
 }
 
 #[async_recursion(?Send)]
 async fn socket(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.tools.attach.VirtualMachineImpl.socket()I")
+    unsatisfied_link(CLASS_NAME, "socket", "()I")
 }
 
 #[async_recursion(?Send)]
 async fn write(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.tools.attach.VirtualMachineImpl.write(I[BII)V")
+    unsatisfied_link(CLASS_NAME, "write", "(I[BII)V")
 }
 
 #[cfg(test)]
@@ -89,79 +89,110 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.tools.attach.VirtualMachineImpl.checkPermissions(Ljava/lang/String;)V"
-    )]
     async fn test_check_permissions() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = check_permissions(thread, Parameters::default()).await;
+        let result = check_permissions(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/tools/attach/VirtualMachineImpl",
+            "checkPermissions",
+            "(Ljava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.tools.attach.VirtualMachineImpl.close(I)V")]
     async fn test_close() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = close(thread, Parameters::default()).await;
+        let result = close(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/tools/attach/VirtualMachineImpl",
+            "close",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.tools.attach.VirtualMachineImpl.connect(ILjava/lang/String;)V"
-    )]
     async fn test_connect() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = connect(thread, Parameters::default()).await;
+        let result = connect(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/tools/attach/VirtualMachineImpl",
+            "connect",
+            "(ILjava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.tools.attach.VirtualMachineImpl.createAttachFile0(Ljava/lang/String;)V"
-    )]
     async fn test_create_attach_file_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = create_attach_file_0(thread, Parameters::default()).await;
+        let result = create_attach_file_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/tools/attach/VirtualMachineImpl",
+            "createAttachFile0",
+            "(Ljava/lang/String;)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.tools.attach.VirtualMachineImpl.getTempDir()Ljava/lang/String;"
-    )]
     async fn test_get_temp_dir() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_temp_dir(thread, Parameters::default()).await;
+        let result = get_temp_dir(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/tools/attach/VirtualMachineImpl",
+            "getTempDir",
+            "()Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.tools.attach.VirtualMachineImpl.read(I[BII)I"
-    )]
     async fn test_read() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = read(thread, Parameters::default()).await;
+        let result = read(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/tools/attach/VirtualMachineImpl",
+            "read",
+            "(I[BII)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.tools.attach.VirtualMachineImpl.sendQuitTo(I)V"
-    )]
     async fn test_send_quit_to() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = send_quit_to(thread, Parameters::default()).await;
+        let result = send_quit_to(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/tools/attach/VirtualMachineImpl",
+            "sendQuitTo",
+            "(I)V",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.tools.attach.VirtualMachineImpl.socket()I")]
     async fn test_socket() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = socket(thread, Parameters::default()).await;
+        let result = socket(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/tools/attach/VirtualMachineImpl",
+            "socket",
+            "()I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.tools.attach.VirtualMachineImpl.write(I[BII)V"
-    )]
     async fn test_write() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = write(thread, Parameters::default()).await;
+        let result = write(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/tools/attach/VirtualMachineImpl",
+            "write",
+            "(I[BII)V",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::MethodRegistry;
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -34,29 +34,35 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn activate_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!(
-        "sun.tracing.dtrace.JVM.activate0(Ljava/lang/String;[Lsun/tracing/dtrace/DTraceProvider;)J"
+    unsatisfied_link(
+        CLASS_NAME,
+        "activate0",
+        "(Ljava/lang/String;[Lsun/tracing/dtrace/DTraceProvider;)J",
     )
 }
 
 #[async_recursion(?Send)]
 async fn define_class_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.tracing.dtrace.JVM.defineClass0(Ljava/lang/ClassLoader;Ljava/lang/String;[BII)Ljava/lang/Class;")
+    unsatisfied_link(
+        CLASS_NAME,
+        "defineClass0",
+        "(Ljava/lang/ClassLoader;Ljava/lang/String;[BII)Ljava/lang/Class;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn dispose_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.tracing.dtrace.JVM.dispose0(J)")
+    unsatisfied_link(CLASS_NAME, "dispose0", "(J)V")
 }
 
 #[async_recursion(?Send)]
 async fn is_enabled_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.tracing.dtrace.JVM.isEnabled0(Ljava/lang/reflect/Method;)Z")
+    unsatisfied_link(CLASS_NAME, "isEnabled0", "(Ljava/lang/reflect/Method;)Z")
 }
 
 #[async_recursion(?Send)]
 async fn is_supported_0(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.tracing.dtrace.JVM.isSupported0()Z")
+    unsatisfied_link(CLASS_NAME, "isSupported0", "()Z")
 }
 
 #[cfg(test)]
@@ -64,43 +70,57 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.tracing.dtrace.JVM.activate0(Ljava/lang/String;[Lsun/tracing/dtrace/DTraceProvider;)J"
-    )]
     async fn test_activate_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = activate_0(thread, Parameters::default()).await;
+        let result = activate_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/tracing/dtrace/JVM",
+            "activate0",
+            "(Ljava/lang/String;[Lsun/tracing/dtrace/DTraceProvider;)J",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.tracing.dtrace.JVM.defineClass0(Ljava/lang/ClassLoader;Ljava/lang/String;[BII)Ljava/lang/Class;"
-    )]
     async fn test_define_class_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = define_class_0(thread, Parameters::default()).await;
+        let result = define_class_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/tracing/dtrace/JVM",
+            "defineClass0",
+            "(Ljava/lang/ClassLoader;Ljava/lang/String;[BII)Ljava/lang/Class;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.tracing.dtrace.JVM.dispose0(J)")]
     async fn test_dispose_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = dispose_0(thread, Parameters::default()).await;
+        let result = dispose_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(result, "sun/tracing/dtrace/JVM", "dispose0", "(J)V");
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.tracing.dtrace.JVM.isEnabled0(Ljava/lang/reflect/Method;)Z"
-    )]
     async fn test_is_enabled_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_enabled_0(thread, Parameters::default()).await;
+        let result = is_enabled_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/tracing/dtrace/JVM",
+            "isEnabled0",
+            "(Ljava/lang/reflect/Method;)Z",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "not yet implemented: sun.tracing.dtrace.JVM.isSupported0()Z")]
     async fn test_is_supported_0() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = is_supported_0(thread, Parameters::default()).await;
+        let result = is_supported_0(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/tracing/dtrace/JVM",
+            "isSupported0",
+            "()Z",
+        );
     }
 }
@@ -1,4 +1,4 @@
-use crate::native_methods::registry::{MethodRegistry, JAVA_17};
+use crate::native_methods::registry::{unsatisfied_link, MethodRegistry, JAVA_17};
 use crate::parameters::Parameters;
 use crate::thread::Thread;
 use crate::Result;
@@ -174,7 +174,11 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn get_am_pm_strings(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getAmPmStrings(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getAmPmStrings",
+        "(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -182,17 +186,25 @@ async fn get_calendar_display_strings(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getCalendarDisplayStrings(Ljava/lang/String;II)[Ljava/lang/String")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getCalendarDisplayStrings",
+        "(Ljava/lang/String;II)[Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_calendar_id(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getCalendarID(Ljava/lang/String;)Ljava/lang/String")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getCalendarID",
+        "(Ljava/lang/String;)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_calendar_int(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getCalendarInt(Ljava/lang/String;I)I")
+    unsatisfied_link(CLASS_NAME, "getCalendarInt", "(Ljava/lang/String;I)I")
 }
 
 #[async_recursion(?Send)]
@@ -200,7 +212,11 @@ async fn get_currency_symbol(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getCurrencySymbol(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getCurrencySymbol",
+        "(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -208,7 +224,11 @@ async fn get_date_time_pattern_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getDateTimePatternNative(IILjava/lang/String;)Ljava/lang/String")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getDateTimePatternNative",
+        "(IILjava/lang/String;)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -216,7 +236,7 @@ async fn get_decimal_separator(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getDecimalSeparator(Ljava/lang/String;C)C")
+    unsatisfied_link(CLASS_NAME, "getDecimalSeparator", "(Ljava/lang/String;C)C")
 }
 
 #[async_recursion(?Send)]
@@ -224,7 +244,7 @@ async fn get_default_locale(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getDefaultLocale(I)Ljava/lang/String")
+    unsatisfied_link(CLASS_NAME, "getDefaultLocale", "(I)Ljava/lang/String;")
 }
 
 #[async_recursion(?Send)]
@@ -232,12 +252,20 @@ async fn get_display_string(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getDisplayString(Ljava/lang/String;ILjava/lang/String;)Ljava/lang/String")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getDisplayString",
+        "(Ljava/lang/String;ILjava/lang/String;)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_eras(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getEras(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getEras",
+        "(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -245,7 +273,11 @@ async fn get_exponent_separator(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getExponentSeparator(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getExponentSeparator",
+        "(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -253,12 +285,16 @@ async fn get_grouping_separator(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getGroupingSeparator(Ljava/lang/String;C)C")
+    unsatisfied_link(CLASS_NAME, "getGroupingSeparator", "(Ljava/lang/String;C)C")
 }
 
 #[async_recursion(?Send)]
 async fn get_infinity(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getInfinity(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getInfinity",
+        "(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -266,14 +302,16 @@ async fn get_international_currency_symbol(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getInternationalCurrencySymbol(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getInternationalCurrencySymbol",
+        "(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_minus_sign(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!(
-        "sun.util.locale.provider.HostLocaleProviderAdapterImpl.getMinusSign(Ljava/lang/String;C)C"
-    )
+    unsatisfied_link(CLASS_NAME, "getMinusSign", "(Ljava/lang/String;C)C")
 }
 
 #[async_recursion(?Send)]
@@ -281,17 +319,29 @@ async fn get_monetary_decimal_separator(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getMonetaryDecimalSeparator(Ljava/lang/String;C)C")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getMonetaryDecimalSeparator",
+        "(Ljava/lang/String;C)C",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_months(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getMonths(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getMonths",
+        "(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_nan(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getNaN(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getNaN",
+        "(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -299,22 +349,30 @@ async fn get_number_pattern_native(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getNumberPatternNative(ILjava/lang/String;)Ljava/lang/String")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getNumberPatternNative",
+        "(ILjava/lang/String;)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_per_mill(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getPerMill(Ljava/lang/String;C)C")
+    unsatisfied_link(CLASS_NAME, "getPerMill", "(Ljava/lang/String;C)C")
 }
 
 #[async_recursion(?Send)]
 async fn get_percent(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getPercent(Ljava/lang/String;C)C")
+    unsatisfied_link(CLASS_NAME, "getPercent", "(Ljava/lang/String;C)C")
 }
 
 #[async_recursion(?Send)]
 async fn get_short_months(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getShortMonths(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getShortMonths",
+        "(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -322,7 +380,11 @@ async fn get_short_weekdays(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getShortWeekdays(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getShortWeekdays",
+        "(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
@@ -330,19 +392,25 @@ async fn get_time_zone_display_string(
     _thread: Arc<Thread>,
     _parameters: Parameters,
 ) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getTimeZoneDisplayString(Ljava/lang/String;ILjava/lang/String;)Ljava/lang/String")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getTimeZoneDisplayString",
+        "(Ljava/lang/String;ILjava/lang/String;)Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_weekdays(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!("sun.util.locale.provider.HostLocaleProviderAdapterImpl.getWeekdays(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String")
+    unsatisfied_link(
+        CLASS_NAME,
+        "getWeekdays",
+        "(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String;",
+    )
 }
 
 #[async_recursion(?Send)]
 async fn get_zero_digit(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
-    todo!(
-        "sun.util.locale.provider.HostLocaleProviderAdapterImpl.getZeroDigit(Ljava/lang/String;C)C"
-    )
+    unsatisfied_link(CLASS_NAME, "getZeroDigit", "(Ljava/lang/String;C)C")
 }
 
 #[cfg(test)]
@@ -350,236 +418,314 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getAmPmStrings(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String"
-    )]
     async fn test_get_am_pm_strings() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_am_pm_strings(thread, Parameters::default()).await;
+        let result = get_am_pm_strings(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getAmPmStrings",
+            "(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getCalendarDisplayStrings(Ljava/lang/String;II)[Ljava/lang/String"
-    )]
     async fn test_get_calendar_display_strings() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_calendar_display_strings(thread, Parameters::default()).await;
+        let result = get_calendar_display_strings(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getCalendarDisplayStrings",
+            "(Ljava/lang/String;II)[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getCalendarID(Ljava/lang/String;)Ljava/lang/String"
-    )]
     async fn test_get_calendar_id() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_calendar_id(thread, Parameters::default()).await;
+        let result = get_calendar_id(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getCalendarID",
+            "(Ljava/lang/String;)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getCalendarInt(Ljava/lang/String;I)I"
-    )]
     async fn test_get_calendar_int() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_calendar_int(thread, Parameters::default()).await;
+        let result = get_calendar_int(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getCalendarInt",
+            "(Ljava/lang/String;I)I",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getCurrencySymbol(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String"
-    )]
     async fn test_get_currency_symbol() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_currency_symbol(thread, Parameters::default()).await;
+        let result = get_currency_symbol(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getCurrencySymbol",
+            "(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getDateTimePatternNative(IILjava/lang/String;)Ljava/lang/String"
-    )]
     async fn test_get_date_time_pattern_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_date_time_pattern_native(thread, Parameters::default()).await;
+        let result = get_date_time_pattern_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getDateTimePatternNative",
+            "(IILjava/lang/String;)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getDecimalSeparator(Ljava/lang/String;C)C"
-    )]
     async fn test_get_decimal_separator() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_decimal_separator(thread, Parameters::default()).await;
+        let result = get_decimal_separator(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getDecimalSeparator",
+            "(Ljava/lang/String;C)C",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getDefaultLocale(I)Ljava/lang/String"
-    )]
     async fn test_get_default_locale() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_default_locale(thread, Parameters::default()).await;
+        let result = get_default_locale(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getDefaultLocale",
+            "(I)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getDisplayString(Ljava/lang/String;ILjava/lang/String;)Ljava/lang/String"
-    )]
     async fn test_get_display_string() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_display_string(thread, Parameters::default()).await;
+        let result = get_display_string(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getDisplayString",
+            "(Ljava/lang/String;ILjava/lang/String;)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getEras(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String"
-    )]
     async fn test_get_eras() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_eras(thread, Parameters::default()).await;
+        let result = get_eras(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getEras",
+            "(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getExponentSeparator(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String"
-    )]
     async fn test_get_exponent_separator() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_exponent_separator(thread, Parameters::default()).await;
+        let result = get_exponent_separator(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getExponentSeparator",
+            "(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getGroupingSeparator(Ljava/lang/String;C)C"
-    )]
     async fn test_get_grouping_separator() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_grouping_separator(thread, Parameters::default()).await;
+        let result = get_grouping_separator(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getGroupingSeparator",
+            "(Ljava/lang/String;C)C",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getInfinity(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String"
-    )]
     async fn test_get_infinity() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_infinity(thread, Parameters::default()).await;
+        let result = get_infinity(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getInfinity",
+            "(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getInternationalCurrencySymbol(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String"
-    )]
     async fn test_get_international_currency_symbol() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_international_currency_symbol(thread, Parameters::default()).await;
+        let result = get_international_currency_symbol(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getInternationalCurrencySymbol",
+            "(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getMinusSign(Ljava/lang/String;C)C"
-    )]
     async fn test_get_minus_sign() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_minus_sign(thread, Parameters::default()).await;
+        let result = get_minus_sign(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getMinusSign",
+            "(Ljava/lang/String;C)C",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getMonetaryDecimalSeparator(Ljava/lang/String;C)C"
-    )]
     async fn test_get_monetary_decimal_separator() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_monetary_decimal_separator(thread, Parameters::default()).await;
+        let result = get_monetary_decimal_separator(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getMonetaryDecimalSeparator",
+            "(Ljava/lang/String;C)C",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getMonths(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String"
-    )]
     async fn test_get_months() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_months(thread, Parameters::default()).await;
+        let result = get_months(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getMonths",
+            "(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getNaN(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String"
-    )]
     async fn test_get_nan() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_nan(thread, Parameters::default()).await;
+        let result = get_nan(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getNaN",
+            "(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getNumberPatternNative(ILjava/lang/String;)Ljava/lang/String"
-    )]
     async fn test_get_number_pattern_native() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_number_pattern_native(thread, Parameters::default()).await;
+        let result = get_number_pattern_native(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getNumberPatternNative",
+            "(ILjava/lang/String;)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getPerMill(Ljava/lang/String;C)C"
-    )]
     async fn test_get_per_mill() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_per_mill(thread, Parameters::default()).await;
+        let result = get_per_mill(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getPerMill",
+            "(Ljava/lang/String;C)C",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getPercent(Ljava/lang/String;C)C"
-    )]
     async fn test_get_percent() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_percent(thread, Parameters::default()).await;
+        let result = get_percent(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getPercent",
+            "(Ljava/lang/String;C)C",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getShortMonths(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String"
-    )]
     async fn test_get_short_months() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_short_months(thread, Parameters::default()).await;
+        let result = get_short_months(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getShortMonths",
+            "(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getShortWeekdays(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String"
-    )]
     async fn test_get_short_weekdays() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_short_weekdays(thread, Parameters::default()).await;
+        let result = get_short_weekdays(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getShortWeekdays",
+            "(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getTimeZoneDisplayString(Ljava/lang/String;ILjava/lang/String;)Ljava/lang/String"
-    )]
     async fn test_get_time_zone_display_string() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_time_zone_display_string(thread, Parameters::default()).await;
+        let result = get_time_zone_display_string(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getTimeZoneDisplayString",
+            "(Ljava/lang/String;ILjava/lang/String;)Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getWeekdays(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String"
-    )]
     async fn test_get_weekdays() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_weekdays(thread, Parameters::default()).await;
+        let result = get_weekdays(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getWeekdays",
+            "(Ljava/lang/String;[Ljava/lang/String;)[Ljava/lang/String;",
+        );
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "not yet implemented: sun.util.locale.provider.HostLocaleProviderAdapterImpl.getZeroDigit(Ljava/lang/String;C)C"
-    )]
     async fn test_get_zero_digit() {
         let (_vm, thread) = crate::test::thread().await.expect("thread");
-        let _ = get_zero_digit(thread, Parameters::default()).await;
+        let result = get_zero_digit(thread, Parameters::default()).await;
+        crate::test::assert_unsatisfied_link(
+            result,
+            "sun/util/locale/provider/HostLocaleProviderAdapterImpl",
+            "getZeroDigit",
+            "(Ljava/lang/String;C)C",
+        );
     }
 }
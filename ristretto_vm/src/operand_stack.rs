@@ -133,6 +133,14 @@ impl OperandStack {
         Ok(value.clone())
     }
 
+    /// Peek at a value on the operand stack; a depth of zero is the top value.
+    pub fn peek_at(&self, depth: usize) -> Result<Value> {
+        let Some(value) = self.stack.iter().rev().nth(depth) else {
+            return Err(OperandStackUnderflow);
+        };
+        Ok(value.clone())
+    }
+
     /// Get the number of values on the operand stack.
     pub fn len(&self) -> usize {
         self.stack.len()
@@ -330,6 +338,19 @@ mod tests {
         assert!(matches!(result, Err(OperandStackUnderflow)));
     }
 
+    #[test]
+    fn test_peek_at() -> Result<()> {
+        let mut stack = OperandStack::with_max_size(2);
+        stack.push_int(1)?;
+        stack.push_int(2)?;
+
+        assert_eq!(stack.peek_at(0)?, Value::Int(2));
+        assert_eq!(stack.peek_at(1)?, Value::Int(1));
+        assert!(matches!(stack.peek_at(2), Err(OperandStackUnderflow)));
+        assert_eq!(stack.len(), 2);
+        Ok(())
+    }
+
     #[test]
     fn test_is_empty() -> Result<()> {
         let mut stack = OperandStack::with_max_size(1);
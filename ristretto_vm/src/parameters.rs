@@ -5,13 +5,13 @@ use std::fmt::Display;
 
 /// Parameters for Ristretto VM methods
 #[derive(Clone, Debug, Default)]
-pub(crate) struct Parameters {
+pub struct Parameters {
     parameters: Vec<Value>,
 }
 
 impl Parameters {
     /// Create parameters from a vector of values.
-    pub(crate) fn new(parameters: Vec<Value>) -> Self {
+    pub fn new(parameters: Vec<Value>) -> Self {
         Parameters { parameters }
     }
 
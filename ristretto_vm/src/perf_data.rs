@@ -0,0 +1,367 @@
+//! Performance counters created through the `Perf` intrinsics (`jdk.internal.perf.Perf` and
+//! `sun.misc.Perf`).
+//!
+//! The JDK creates counters such as `sun.rt.createVmBeginTime` and `java.cls.loadedClasses` and
+//! updates them through the `ByteBuffer` returned when each counter is created.  The buffers are
+//! backed by Java byte arrays that are also held by the counter registry, so the current value of
+//! every counter can be sampled from the VM with [`PerfData::counters`].  A snapshot of the
+//! counters can be serialized in the `hsperfdata` layout read by `jps`, `jstat` and
+//! `sun.jvmstat`; the snapshot is not updated when the counters change.
+
+use crate::Error::{InternalError, PoisonedLock};
+use crate::JavaError::IllegalArgumentException;
+use crate::Result;
+use ristretto_classloader::ConcurrentVec;
+use std::path::Path;
+use std::sync::RwLock;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// Frequency of the high resolution counter; the counter is measured in nanoseconds.
+pub(crate) const HIGH_RES_FREQUENCY: i64 = 1_000_000_000;
+
+/// Magic number of the `hsperfdata` prologue; always stored in big endian byte order.
+const MAGIC: u32 = 0xcafe_c0c0;
+const MAJOR_VERSION: u8 = 2;
+const MINOR_VERSION: u8 = 0;
+const PROLOGUE_SIZE: usize = 32;
+const ENTRY_HEADER_SIZE: usize = 20;
+/// Flag that marks a counter as supported; see `PerfData::Flags`.
+const FLAG_SUPPORTED: u8 = 1;
+const TYPE_LONG: u8 = b'J';
+const TYPE_BYTE: u8 = b'B';
+
+/// The value of a performance counter.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PerfValue {
+    Long(i64),
+    Bytes(Vec<u8>),
+}
+
+/// A performance counter; the data is shared with the `ByteBuffer` returned to Java code.
+#[derive(Debug)]
+struct PerfEntry {
+    name: String,
+    data_type: u8,
+    variability: u8,
+    units: u8,
+    data: ConcurrentVec<i8>,
+}
+
+impl PerfEntry {
+    /// Get the current value of the counter; long values are stored in native byte order.
+    fn value(&self) -> Result<PerfValue> {
+        let bytes = self
+            .data
+            .to_vec()?
+            .into_iter()
+            .map(|byte| byte as u8)
+            .collect::<Vec<u8>>();
+        if self.data_type == TYPE_LONG {
+            let bytes = <[u8; 8]>::try_from(bytes.as_slice())
+                .map_err(|error| InternalError(error.to_string()))?;
+            return Ok(PerfValue::Long(i64::from_ne_bytes(bytes)));
+        }
+        Ok(PerfValue::Bytes(bytes))
+    }
+}
+
+/// Registry of the performance counters of a VM.
+#[derive(Debug)]
+pub struct PerfData {
+    start: Instant,
+    entries: RwLock<Vec<PerfEntry>>,
+}
+
+impl PerfData {
+    /// Create a new, empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        PerfData {
+            start: Instant::now(),
+            entries: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Get the value of the high resolution counter; the number of nanoseconds since the registry
+    /// was created.
+    #[must_use]
+    pub fn high_res_counter(&self) -> i64 {
+        i64::try_from(self.start.elapsed().as_nanos()).unwrap_or(i64::MAX)
+    }
+
+    /// Create a long counter with the initial value and return the data that backs the counter.
+    ///
+    /// # Errors
+    /// if a counter with the name already exists.
+    pub fn create_long(
+        &self,
+        name: &str,
+        variability: u8,
+        units: u8,
+        value: i64,
+    ) -> Result<ConcurrentVec<i8>> {
+        let data = value
+            .to_ne_bytes()
+            .into_iter()
+            .map(|byte| byte as i8)
+            .collect::<Vec<i8>>();
+        self.create(name, TYPE_LONG, variability, units, data)
+    }
+
+    /// Create a byte array counter of `max_length` bytes that starts with the initial value and
+    /// return the data that backs the counter.
+    ///
+    /// # Errors
+    /// if a counter with the name already exists or the value is longer than `max_length`.
+    pub fn create_byte_array(
+        &self,
+        name: &str,
+        variability: u8,
+        units: u8,
+        value: &[i8],
+        max_length: usize,
+    ) -> Result<ConcurrentVec<i8>> {
+        if value.len() > max_length {
+            return Err(IllegalArgumentException(format!(
+                "value of counter {name} is longer than {max_length} bytes"
+            ))
+            .into());
+        }
+        let mut data = value.to_vec();
+        data.resize(max_length, 0);
+        self.create(name, TYPE_BYTE, variability, units, data)
+    }
+
+    /// Register a counter with the data.
+    fn create(
+        &self,
+        name: &str,
+        data_type: u8,
+        variability: u8,
+        units: u8,
+        data: Vec<i8>,
+    ) -> Result<ConcurrentVec<i8>> {
+        let mut entries = self
+            .entries
+            .write()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        if entries.iter().any(|entry| entry.name == name) {
+            return Err(IllegalArgumentException(format!("duplicate counter name: {name}")).into());
+        }
+        let data = ConcurrentVec::from(data);
+        entries.push(PerfEntry {
+            name: name.to_string(),
+            data_type,
+            variability,
+            units,
+            data: data.clone(),
+        });
+        Ok(data)
+    }
+
+    /// Get the names and current values of the counters, in the order they were created.
+    ///
+    /// # Errors
+    /// if the counters cannot be read.
+    pub fn counters(&self) -> Result<Vec<(String, PerfValue)>> {
+        let entries = self
+            .entries
+            .read()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        entries
+            .iter()
+            .map(|entry| Ok((entry.name.clone(), entry.value()?)))
+            .collect()
+    }
+
+    /// Get the current value of the named counter.
+    ///
+    /// # Errors
+    /// if the counters cannot be read.
+    pub fn counter(&self, name: &str) -> Result<Option<PerfValue>> {
+        let entries = self
+            .entries
+            .read()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        entries
+            .iter()
+            .find(|entry| entry.name == name)
+            .map(PerfEntry::value)
+            .transpose()
+    }
+
+    /// Serialize a snapshot of the counters in the `hsperfdata` layout.
+    ///
+    /// See: <https://github.com/openjdk/jdk/blob/master/src/hotspot/share/runtime/perfMemory.hpp>
+    ///
+    /// # Errors
+    /// if the counters cannot be read or are too large for the layout.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let entries = self
+            .entries
+            .read()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        let mut bytes = vec![0; PROLOGUE_SIZE];
+        for entry in entries.iter() {
+            let data = entry.value()?;
+            let (vector_length, data_size, data) = match data {
+                PerfValue::Long(value) => (0, 8, value.to_ne_bytes().to_vec()),
+                PerfValue::Bytes(value) => (i32::try_from(value.len())?, 1, value),
+            };
+            let name_offset = ENTRY_HEADER_SIZE;
+            let mut data_offset = name_offset + entry.name.len() + 1;
+            data_offset = data_offset.next_multiple_of(data_size);
+            let entry_length = (data_offset + data.len()).next_multiple_of(8);
+
+            let start = bytes.len();
+            bytes.extend_from_slice(&i32::try_from(entry_length)?.to_ne_bytes());
+            bytes.extend_from_slice(&i32::try_from(name_offset)?.to_ne_bytes());
+            bytes.extend_from_slice(&vector_length.to_ne_bytes());
+            bytes.push(entry.data_type);
+            bytes.push(FLAG_SUPPORTED);
+            bytes.push(entry.units);
+            bytes.push(entry.variability);
+            bytes.extend_from_slice(&i32::try_from(data_offset)?.to_ne_bytes());
+            bytes.extend_from_slice(entry.name.as_bytes());
+            bytes.resize(start + data_offset, 0);
+            bytes.extend_from_slice(&data);
+            bytes.resize(start + entry_length, 0);
+        }
+
+        let used = i32::try_from(bytes.len())?;
+        let num_entries = i32::try_from(entries.len())?;
+        let mod_time_stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|error| InternalError(error.to_string()))?;
+        let mod_time_stamp = i64::try_from(mod_time_stamp.as_millis())?;
+        let mut prologue = Vec::with_capacity(PROLOGUE_SIZE);
+        prologue.extend_from_slice(&MAGIC.to_be_bytes());
+        prologue.push(u8::from(cfg!(target_endian = "little")));
+        prologue.push(MAJOR_VERSION);
+        prologue.push(MINOR_VERSION);
+        prologue.push(1); // accessible
+        prologue.extend_from_slice(&used.to_ne_bytes());
+        prologue.extend_from_slice(&0i32.to_ne_bytes()); // overflow
+        prologue.extend_from_slice(&mod_time_stamp.to_ne_bytes());
+        prologue.extend_from_slice(&i32::try_from(PROLOGUE_SIZE)?.to_ne_bytes());
+        prologue.extend_from_slice(&num_entries.to_ne_bytes());
+        bytes[..PROLOGUE_SIZE].copy_from_slice(&prologue);
+        Ok(bytes)
+    }
+
+    /// Write a snapshot of the counters in the `hsperfdata` layout to the file; tools discover
+    /// processes by the files named with the process id in the `hsperfdata_<user>` directory of
+    /// the temporary directory.
+    ///
+    /// # Errors
+    /// if the counters cannot be read or the file cannot be written.
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let bytes = self.to_bytes()?;
+        std::fs::write(path, bytes).map_err(|error| InternalError(error.to_string()))?;
+        Ok(())
+    }
+}
+
+impl Default for PerfData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_i32(bytes: &[u8], offset: usize) -> Result<i32> {
+        let bytes = <[u8; 4]>::try_from(&bytes[offset..offset + 4])
+            .map_err(|error| InternalError(error.to_string()))?;
+        Ok(i32::from_ne_bytes(bytes))
+    }
+
+    #[test]
+    fn test_create_long() -> Result<()> {
+        let perf_data = PerfData::new();
+        let data = perf_data.create_long("sun.rt.counter", 3, 4, 42)?;
+        assert_eq!(
+            Some(PerfValue::Long(42)),
+            perf_data.counter("sun.rt.counter")?
+        );
+
+        for (index, byte) in 7i64.to_ne_bytes().into_iter().enumerate() {
+            data.set(index, byte as i8)?;
+        }
+        assert_eq!(
+            vec![("sun.rt.counter".to_string(), PerfValue::Long(7))],
+            perf_data.counters()?
+        );
+        assert_eq!(None, perf_data.counter("unknown")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_byte_array() -> Result<()> {
+        let perf_data = PerfData::new();
+        let data = perf_data.create_byte_array("java.property", 1, 5, &[97, 98], 4)?;
+        assert_eq!(4, data.len()?);
+        assert_eq!(
+            Some(PerfValue::Bytes(vec![97, 98, 0, 0])),
+            perf_data.counter("java.property")?
+        );
+        assert!(perf_data
+            .create_byte_array("too.long", 1, 5, &[1, 2, 3], 2)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicate_name() -> Result<()> {
+        let perf_data = PerfData::new();
+        perf_data.create_long("counter", 3, 1, 0)?;
+        assert!(perf_data.create_long("counter", 3, 1, 0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_high_res_counter() {
+        let perf_data = PerfData::default();
+        let first = perf_data.high_res_counter();
+        let second = perf_data.high_res_counter();
+        assert!(first >= 0);
+        assert!(second >= first);
+    }
+
+    #[test]
+    fn test_to_bytes() -> Result<()> {
+        let perf_data = PerfData::new();
+        perf_data.create_long("a", 3, 4, 42)?;
+        perf_data.create_byte_array("bc", 1, 5, &[120], 3)?;
+        let bytes = perf_data.to_bytes()?;
+
+        assert_eq!([0xca, 0xfe, 0xc0, 0xc0], bytes[0..4]);
+        assert_eq!(u8::from(cfg!(target_endian = "little")), bytes[4]);
+        assert_eq!([MAJOR_VERSION, MINOR_VERSION, 1], bytes[5..8]);
+        let used = read_i32(&bytes, 8)?;
+        assert_eq!(bytes.len(), usize::try_from(used)?);
+        assert_eq!(32, read_i32(&bytes, 24)?);
+        assert_eq!(2, read_i32(&bytes, 28)?);
+
+        // long entry: 20 byte header, "a\0", padded to 24, 8 byte value
+        assert_eq!(32, read_i32(&bytes, 32)?);
+        assert_eq!(20, read_i32(&bytes, 36)?);
+        assert_eq!(0, read_i32(&bytes, 40)?);
+        assert_eq!([b'J', FLAG_SUPPORTED, 4, 3], bytes[44..48]);
+        assert_eq!(24, read_i32(&bytes, 48)?);
+        assert_eq!(*b"a\0", bytes[52..54]);
+        assert_eq!(42i64.to_ne_bytes(), bytes[56..64]);
+
+        // byte array entry: 20 byte header, "bc\0", 3 byte value, padded to 32
+        assert_eq!(32, read_i32(&bytes, 64)?);
+        assert_eq!(3, read_i32(&bytes, 72)?);
+        assert_eq!([b'B', FLAG_SUPPORTED, 5, 1], bytes[76..80]);
+        assert_eq!(23, read_i32(&bytes, 80)?);
+        assert_eq!(*b"bc\0", bytes[84..87]);
+        assert_eq!([120, 0, 0], bytes[87..90]);
+        assert_eq!(96, bytes.len());
+        Ok(())
+    }
+}
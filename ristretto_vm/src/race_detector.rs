@@ -0,0 +1,660 @@
+//! Detection of data races between Java threads; enabled with the `race-detector` feature.
+//!
+//! The detector tracks the happens-before order of the
+//! [Java memory model](https://docs.oracle.com/javase/specs/jls/se23/html/jls-17.html#jls-17.4.5)
+//! with vector clocks:
+//!
+//! * releasing a monitor (`monitorexit` or returning from a `synchronized` method) or writing a
+//!   `volatile` field joins the clock of the thread into the clock of the monitor or field, and
+//!   acquiring the monitor or reading the field joins that clock into the clock of the thread;
+//! * the initialization of a class happens before accesses to the static fields of the class;
+//! * a new thread starts after the actions already performed by the existing threads.
+//!
+//! Every read and write of a non-`volatile` field records the accessing thread, the clock of the
+//! thread and a stack trace.  Two accesses of the same field by different threads, at least one
+//! of which is a write, race when neither access happens before the other.  Races are logged as
+//! warnings and can be retrieved with [`RaceDetector::races`].
+//!
+//! Field state is kept for the lifetime of the VM, so the detector is intended for debugging
+//! rather than production use.
+
+use crate::{Frame, OperandStack, Result, StackTraceElement, Thread};
+use ristretto_classfile::attributes::Instruction;
+use ristretto_classfile::{FieldAccessFlags, MethodAccessFlags};
+use ristretto_classloader::{Class, Field, Method, Reference, Value};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tracing::warn;
+
+/// Type of field access.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AccessKind {
+    Read,
+    Write,
+}
+
+impl fmt::Display for AccessKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccessKind::Read => write!(f, "read"),
+            AccessKind::Write => write!(f, "write"),
+        }
+    }
+}
+
+/// An access of a field by a thread.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldAccess {
+    thread_id: u64,
+    kind: AccessKind,
+    stack_trace: Vec<StackTraceElement>,
+}
+
+impl FieldAccess {
+    /// Get the identifier of the thread that accessed the field.
+    #[must_use]
+    pub fn thread_id(&self) -> u64 {
+        self.thread_id
+    }
+
+    /// Get the type of access.
+    #[must_use]
+    pub fn kind(&self) -> AccessKind {
+        self.kind
+    }
+
+    /// Get the stack trace of the access; the innermost frame is first.
+    #[must_use]
+    pub fn stack_trace(&self) -> &[StackTraceElement] {
+        &self.stack_trace
+    }
+}
+
+impl fmt::Display for FieldAccess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} by thread {}", self.kind, self.thread_id)?;
+        for element in &self.stack_trace {
+            write!(f, "\n    at {element}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Two accesses of a field by different threads, at least one of which is a write, that are not
+/// ordered by happens-before.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataRace {
+    field: String,
+    previous: FieldAccess,
+    current: FieldAccess,
+}
+
+impl DataRace {
+    /// Get the name of the field; e.g. `java.lang.Integer.value`.
+    #[must_use]
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// Get the earlier of the racing accesses.
+    #[must_use]
+    pub fn previous(&self) -> &FieldAccess {
+        &self.previous
+    }
+
+    /// Get the later of the racing accesses.
+    #[must_use]
+    pub fn current(&self) -> &FieldAccess {
+        &self.current
+    }
+}
+
+impl fmt::Display for DataRace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "data race on field {}", self.field)?;
+        writeln!(f, "  {}", self.current)?;
+        write!(f, "  previous {}", self.previous)
+    }
+}
+
+/// Identifies the storage of a field; static fields have an object identity of zero.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) struct FieldLocation {
+    object: i32,
+    field: usize,
+}
+
+impl FieldLocation {
+    /// Create a location for a field of an object; the identity hash code distinguishes objects
+    /// that reuse the storage of a dropped object.
+    pub(crate) fn new(object: i32, field: &Field) -> Self {
+        FieldLocation {
+            object,
+            field: std::ptr::from_ref(field).addr(),
+        }
+    }
+}
+
+/// An object used to synchronize threads.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub(crate) enum SyncObject {
+    /// Monitor of an object identified by its identity hash code
+    Monitor(i32),
+    /// Monitor and initialization of a class identified by its name
+    Class(String),
+    /// A `volatile` field
+    Volatile(FieldLocation),
+}
+
+/// Logical time of each thread.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct VectorClock(HashMap<u64, u64>);
+
+impl VectorClock {
+    fn get(&self, thread_id: u64) -> u64 {
+        self.0.get(&thread_id).copied().unwrap_or_default()
+    }
+
+    fn increment(&mut self, thread_id: u64) {
+        *self.0.entry(thread_id).or_default() += 1;
+    }
+
+    fn join(&mut self, other: &VectorClock) {
+        for (thread_id, time) in &other.0 {
+            let current = self.0.entry(*thread_id).or_default();
+            *current = (*current).max(*time);
+        }
+    }
+}
+
+/// An access together with the time of the accessing thread.
+#[derive(Debug)]
+struct TimedAccess {
+    time: u64,
+    access: FieldAccess,
+}
+
+impl TimedAccess {
+    /// Returns true if the access happens before the current time of a thread.
+    fn happens_before(&self, clock: &VectorClock) -> bool {
+        self.time <= clock.get(self.access.thread_id)
+    }
+}
+
+/// Accesses of a field that are not yet known to happen before every thread.
+#[derive(Debug, Default)]
+struct Shadow {
+    write: Option<TimedAccess>,
+    reads: HashMap<u64, TimedAccess>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    threads: HashMap<u64, VectorClock>,
+    sync_objects: HashMap<SyncObject, VectorClock>,
+    fields: HashMap<FieldLocation, Shadow>,
+    races: Vec<DataRace>,
+    reported: HashSet<String>,
+}
+
+impl State {
+    fn thread(&mut self, thread_id: u64) -> &mut VectorClock {
+        self.threads.entry(thread_id).or_insert_with(|| {
+            let mut clock = VectorClock::default();
+            clock.increment(thread_id);
+            clock
+        })
+    }
+
+    /// Record a race unless a race between the same field and code locations was reported.
+    fn report(&mut self, field: &str, previous: &FieldAccess, current: &FieldAccess) {
+        let location = |access: &FieldAccess| {
+            access
+                .stack_trace
+                .first()
+                .map(ToString::to_string)
+                .unwrap_or_default()
+        };
+        let key = format!(
+            "{field}|{}:{}|{}:{}",
+            previous.kind,
+            location(previous),
+            current.kind,
+            location(current)
+        );
+        if !self.reported.insert(key) {
+            return;
+        }
+        let race = DataRace {
+            field: field.to_string(),
+            previous: previous.clone(),
+            current: current.clone(),
+        };
+        warn!("{race}");
+        self.races.push(race);
+    }
+}
+
+/// Detects data races on Java fields using the happens-before order of the Java memory model.
+#[derive(Debug, Default)]
+pub struct RaceDetector {
+    state: Mutex<State>,
+}
+
+impl RaceDetector {
+    /// Lock the state; the state remains consistent if an operation panicked while holding the
+    /// lock, so a poisoned lock is recovered.
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Get the data races detected so far.
+    #[must_use]
+    pub fn races(&self) -> Vec<DataRace> {
+        self.lock().races.clone()
+    }
+
+    /// Start tracking a thread; the actions of the existing threads happen before the actions of
+    /// the new thread.
+    pub(crate) fn thread_started(&self, thread_id: u64) {
+        let mut state = self.lock();
+        let mut clock = VectorClock::default();
+        for (id, thread_clock) in &mut state.threads {
+            clock.join(thread_clock);
+            thread_clock.increment(*id);
+        }
+        clock.increment(thread_id);
+        state.threads.insert(thread_id, clock);
+    }
+
+    /// Acquire a synchronization object; e.g. enter a monitor or read a `volatile` field.
+    pub(crate) fn acquire(&self, thread_id: u64, object: &SyncObject) {
+        let mut state = self.lock();
+        let Some(object_clock) = state.sync_objects.get(object).cloned() else {
+            return;
+        };
+        state.thread(thread_id).join(&object_clock);
+    }
+
+    /// Release a synchronization object; e.g. exit a monitor or write a `volatile` field.
+    pub(crate) fn release(&self, thread_id: u64, object: SyncObject) {
+        let mut state = self.lock();
+        let thread_clock = state.thread(thread_id).clone();
+        state
+            .sync_objects
+            .entry(object)
+            .or_default()
+            .join(&thread_clock);
+        state.thread(thread_id).increment(thread_id);
+    }
+
+    /// Acquire a synchronization object that is released when the returned guard is dropped.
+    pub(crate) fn hold(&self, thread_id: u64, object: SyncObject) -> SyncGuard<'_> {
+        self.acquire(thread_id, &object);
+        SyncGuard {
+            race_detector: self,
+            thread_id,
+            object: Some(object),
+        }
+    }
+
+    /// Record an access of a non-`volatile` field and report any races with earlier accesses.
+    pub(crate) fn access(
+        &self,
+        location: FieldLocation,
+        field: &str,
+        thread_id: u64,
+        kind: AccessKind,
+        stack_trace: Vec<StackTraceElement>,
+    ) {
+        let mut state = self.lock();
+        let clock = state.thread(thread_id).clone();
+        let access = FieldAccess {
+            thread_id,
+            kind,
+            stack_trace,
+        };
+        let shadow = state.fields.entry(location).or_default();
+
+        let mut races = Vec::new();
+        if let Some(write) = &shadow.write {
+            if write.access.thread_id != thread_id && !write.happens_before(&clock) {
+                races.push(write.access.clone());
+            }
+        }
+        if kind == AccessKind::Write {
+            for read in shadow.reads.values() {
+                if read.access.thread_id != thread_id && !read.happens_before(&clock) {
+                    races.push(read.access.clone());
+                }
+            }
+        }
+
+        let timed_access = TimedAccess {
+            time: clock.get(thread_id),
+            access: access.clone(),
+        };
+        match kind {
+            AccessKind::Read => {
+                shadow.reads.insert(thread_id, timed_access);
+            }
+            AccessKind::Write => {
+                shadow.reads.clear();
+                shadow.write = Some(timed_access);
+            }
+        }
+
+        for previous in races {
+            state.report(field, &previous, &access);
+        }
+    }
+}
+
+/// A synchronization object held by a thread; the object is released when the guard is dropped, so
+/// that it is released on every path out of the synchronized region.
+#[derive(Debug)]
+pub(crate) struct SyncGuard<'a> {
+    race_detector: &'a RaceDetector,
+    thread_id: u64,
+    object: Option<SyncObject>,
+}
+
+impl Drop for SyncGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(object) = self.object.take() {
+            self.race_detector.release(self.thread_id, object);
+        }
+    }
+}
+
+/// Get the monitor entered by a `synchronized` method invocation.
+pub(crate) fn method_monitor(
+    class: &Class,
+    method: &Method,
+    parameters: &[Value],
+) -> Option<SyncObject> {
+    if !method
+        .access_flags()
+        .contains(MethodAccessFlags::SYNCHRONIZED)
+    {
+        return None;
+    }
+    if method.is_static() {
+        return Some(SyncObject::Class(class.name().to_string()));
+    }
+    match parameters.first() {
+        Some(Value::Object(Some(reference))) => {
+            Some(SyncObject::Monitor(reference.identity_hash_code()))
+        }
+        _ => None,
+    }
+}
+
+/// Record the field accesses and synchronization performed by an instruction before the
+/// instruction is executed.  Errors (e.g. a null object) are ignored here and raised when the
+/// instruction is executed.
+pub(crate) async fn instrument(frame: &Frame, stack: &OperandStack, instruction: &Instruction) {
+    let _ = try_instrument(frame, stack, instruction).await;
+}
+
+async fn try_instrument(
+    frame: &Frame,
+    stack: &OperandStack,
+    instruction: &Instruction,
+) -> Result<()> {
+    let thread = frame.thread()?;
+    let vm = thread.vm()?;
+    let detector = vm.race_detector();
+    match instruction {
+        Instruction::Getfield(index) | Instruction::Putfield(index) => {
+            let (kind, depth) = if matches!(instruction, Instruction::Getfield(_)) {
+                (AccessKind::Read, 0)
+            } else {
+                (AccessKind::Write, 1)
+            };
+            let Value::Object(Some(reference)) = stack.peek_at(depth)? else {
+                return Ok(());
+            };
+            let Reference::Object(object) = &reference else {
+                return Ok(());
+            };
+            let (class_name, field_name) = field_ref(frame.class(), *index)?;
+            let field = object.field(field_name)?;
+            let location = FieldLocation::new(reference.identity_hash_code(), field);
+            let name = format!("{}.{field_name}", class_name.replace('/', "."));
+            field_access(detector, &thread, location, field, name, kind).await;
+        }
+        Instruction::Getstatic(index) | Instruction::Putstatic(index) => {
+            let kind = if matches!(instruction, Instruction::Getstatic(_)) {
+                AccessKind::Read
+            } else {
+                AccessKind::Write
+            };
+            let (class_name, field_name) = field_ref(frame.class(), *index)?;
+            let class = thread.class(class_name).await?;
+            detector.acquire(thread.id(), &SyncObject::Class(class.name().to_string()));
+            let field = class.static_field(field_name)?;
+            let location = FieldLocation::new(0, &field);
+            let name = format!("{}.{field_name}", class.name().replace('/', "."));
+            field_access(detector, &thread, location, &field, name, kind).await;
+        }
+        Instruction::Monitorenter => {
+            if let Value::Object(Some(reference)) = stack.peek_at(0)? {
+                let monitor = SyncObject::Monitor(reference.identity_hash_code());
+                detector.acquire(thread.id(), &monitor);
+            }
+        }
+        Instruction::Monitorexit => {
+            if let Value::Object(Some(reference)) = stack.peek_at(0)? {
+                let monitor = SyncObject::Monitor(reference.identity_hash_code());
+                detector.release(thread.id(), monitor);
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Get the class and field names of a field reference.
+fn field_ref(class: &Class, index: u16) -> Result<(&str, &str)> {
+    let constant_pool = class.constant_pool();
+    let (class_index, name_and_type_index) = constant_pool.try_get_field_ref(index)?;
+    let (name_index, _descriptor_index) =
+        constant_pool.try_get_name_and_type(*name_and_type_index)?;
+    let class_name = constant_pool.try_get_class(*class_index)?;
+    let field_name = constant_pool.try_get_utf8(*name_index)?;
+    Ok((class_name, field_name))
+}
+
+/// Record an access of a field; accesses of `volatile` fields synchronize the thread instead.
+async fn field_access(
+    detector: &RaceDetector,
+    thread: &Arc<Thread>,
+    location: FieldLocation,
+    field: &Field,
+    name: String,
+    kind: AccessKind,
+) {
+    if field.access_flags().contains(FieldAccessFlags::VOLATILE) {
+        let object = SyncObject::Volatile(location);
+        match kind {
+            AccessKind::Read => detector.acquire(thread.id(), &object),
+            AccessKind::Write => detector.release(thread.id(), object),
+        }
+        return;
+    }
+    let stack_trace = stack_trace(thread).await;
+    detector.access(location, &name, thread.id(), kind, stack_trace);
+}
+
+/// Get the stack trace of a thread; the innermost frame is first.
+async fn stack_trace(thread: &Thread) -> Vec<StackTraceElement> {
+    let Ok(frames) = thread.frames().await else {
+        return Vec::new();
+    };
+    frames
+        .iter()
+        .rev()
+        .map(|frame| {
+            let class = frame.class();
+            let method = frame.method();
+            let line_number = method.line_number(frame.program_counter());
+            StackTraceElement::new(
+                class.name().replace('/', "."),
+                method.name(),
+                class.source_file().map(ToString::to_string),
+                i32::try_from(line_number).unwrap_or_default(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIELD: &str = "Counter.count";
+
+    fn location() -> FieldLocation {
+        FieldLocation {
+            object: 1,
+            field: 1,
+        }
+    }
+
+    fn stack_trace(method_name: &str) -> Vec<StackTraceElement> {
+        vec![StackTraceElement::new(
+            "Counter",
+            method_name,
+            Some("Counter.java".to_string()),
+            1,
+        )]
+    }
+
+    fn detector() -> RaceDetector {
+        let detector = RaceDetector::default();
+        detector.thread_started(1);
+        detector.thread_started(2);
+        detector
+    }
+
+    #[test]
+    fn test_write_write_race() {
+        let detector = detector();
+        detector.access(location(), FIELD, 1, AccessKind::Write, stack_trace("a"));
+        detector.access(location(), FIELD, 2, AccessKind::Write, stack_trace("b"));
+        let races = detector.races();
+        assert_eq!(1, races.len());
+        let race = &races[0];
+        assert_eq!(FIELD, race.field());
+        assert_eq!(1, race.previous().thread_id());
+        assert_eq!(AccessKind::Write, race.previous().kind());
+        assert_eq!(2, race.current().thread_id());
+        assert_eq!("b", race.current().stack_trace()[0].method_name());
+        assert_eq!(
+            "data race on field Counter.count\n  \
+            write by thread 2\n    at Counter.b(Counter.java:1)\n  \
+            previous write by thread 1\n    at Counter.a(Counter.java:1)",
+            race.to_string()
+        );
+    }
+
+    #[test]
+    fn test_read_write_race() {
+        let detector = detector();
+        detector.access(location(), FIELD, 1, AccessKind::Read, stack_trace("a"));
+        detector.access(location(), FIELD, 2, AccessKind::Read, stack_trace("b"));
+        assert!(detector.races().is_empty());
+        detector.access(location(), FIELD, 2, AccessKind::Write, stack_trace("c"));
+        let races = detector.races();
+        assert_eq!(1, races.len());
+        assert_eq!(AccessKind::Read, races[0].previous().kind());
+        assert_eq!(1, races[0].previous().thread_id());
+    }
+
+    #[test]
+    fn test_duplicate_races_reported_once() {
+        let detector = detector();
+        for _ in 0..2 {
+            detector.access(location(), FIELD, 1, AccessKind::Write, stack_trace("a"));
+            detector.access(location(), FIELD, 2, AccessKind::Write, stack_trace("b"));
+        }
+        assert_eq!(2, detector.races().len());
+    }
+
+    #[test]
+    fn test_monitor_orders_accesses() {
+        let detector = detector();
+        let monitor = SyncObject::Monitor(42);
+        detector.acquire(1, &monitor);
+        detector.access(location(), FIELD, 1, AccessKind::Write, stack_trace("a"));
+        detector.release(1, monitor.clone());
+        detector.acquire(2, &monitor);
+        detector.access(location(), FIELD, 2, AccessKind::Write, stack_trace("b"));
+        detector.release(2, monitor);
+        assert!(detector.races().is_empty());
+    }
+
+    #[test]
+    fn test_guard_releases_monitor() {
+        let detector = detector();
+        let monitor = SyncObject::Monitor(42);
+        {
+            let _guard = detector.hold(1, monitor.clone());
+            detector.access(location(), FIELD, 1, AccessKind::Write, stack_trace("a"));
+        }
+        let _guard = detector.hold(2, monitor);
+        detector.access(location(), FIELD, 2, AccessKind::Write, stack_trace("b"));
+        assert!(detector.races().is_empty());
+    }
+
+    #[test]
+    fn test_different_monitors_race() {
+        let detector = detector();
+        detector.access(location(), FIELD, 1, AccessKind::Write, stack_trace("a"));
+        detector.release(1, SyncObject::Monitor(1));
+        detector.acquire(2, &SyncObject::Monitor(2));
+        detector.access(location(), FIELD, 2, AccessKind::Read, stack_trace("b"));
+        assert_eq!(1, detector.races().len());
+    }
+
+    #[test]
+    fn test_volatile_orders_accesses() {
+        let detector = detector();
+        let flag = SyncObject::Volatile(FieldLocation {
+            object: 1,
+            field: 2,
+        });
+        detector.access(location(), FIELD, 1, AccessKind::Write, stack_trace("a"));
+        detector.release(1, flag.clone());
+        detector.acquire(2, &flag);
+        detector.access(location(), FIELD, 2, AccessKind::Read, stack_trace("b"));
+        assert!(detector.races().is_empty());
+    }
+
+    #[test]
+    fn test_thread_start_orders_accesses() {
+        let detector = RaceDetector::default();
+        detector.thread_started(1);
+        detector.access(location(), FIELD, 1, AccessKind::Write, stack_trace("a"));
+        detector.thread_started(2);
+        detector.access(location(), FIELD, 2, AccessKind::Read, stack_trace("b"));
+        assert!(detector.races().is_empty());
+
+        // Accesses by the existing thread after the new thread starts are not ordered
+        detector.access(location(), FIELD, 1, AccessKind::Write, stack_trace("c"));
+        assert_eq!(1, detector.races().len());
+    }
+
+    #[test]
+    fn test_same_thread_no_race() {
+        let detector = detector();
+        detector.access(location(), FIELD, 1, AccessKind::Write, stack_trace("a"));
+        detector.access(location(), FIELD, 1, AccessKind::Read, stack_trace("b"));
+        detector.access(location(), FIELD, 1, AccessKind::Write, stack_trace("c"));
+        assert!(detector.races().is_empty());
+    }
+}
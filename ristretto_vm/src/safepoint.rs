@@ -0,0 +1,409 @@
+//! Safepoints and handshakes used to stop Java threads at well defined points.
+//!
+//! Java threads poll for safepoint requests in the interpreter loop between instructions.  A
+//! thread is *safe* when it is not interpreting Java code, is in a safe region (e.g. sleeping or
+//! running a blocking operation) or is stopped at a safepoint poll; a safe thread does not modify
+//! its frames until it leaves the safe state.  Subsystems that need to inspect or update the
+//! state of other threads (e.g. thread dumps or a debugger) use one of two protocols:
+//!
+//! * [`VM::safepoint`](crate::VM::safepoint) stops every Java thread until the returned
+//!   [`SafepointGuard`] is dropped.
+//! * [`Thread::handshake`](crate::Thread::handshake) runs an operation for a single thread while
+//!   that thread is safe, without stopping the other threads.
+
+use crate::Thread;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use tokio::sync::Notify;
+
+/// Operation run for a thread at a handshake.
+pub(crate) type HandshakeOperation = Box<dyn FnOnce(&Thread) + Send>;
+
+/// Coordinates the safepoints of the threads of a VM.
+#[derive(Debug, Default)]
+pub struct Safepoints {
+    /// True while a safepoint is requested or in progress
+    requested: AtomicBool,
+    /// Serializes safepoint requests
+    synchronizing: tokio::sync::Mutex<()>,
+    /// Notified when a thread becomes safe or a safepoint is released
+    changed: Notify,
+}
+
+impl Safepoints {
+    /// Returns true if a safepoint is requested or in progress.
+    #[inline]
+    pub fn is_requested(&self) -> bool {
+        self.requested.load(Ordering::SeqCst)
+    }
+
+    /// Stop the threads at a safepoint; waits until every thread other than the requester is
+    /// safe.  The requester, if any, is safe while it waits for another safepoint to complete.
+    /// Cancelling the wait (e.g. with a timeout) releases the safepoint.
+    pub(crate) async fn synchronize(
+        &self,
+        threads: &[std::sync::Arc<Thread>],
+        requester: Option<&Thread>,
+    ) -> SafepointGuard<'_> {
+        let lock = match requester {
+            Some(requester) => requester.safe_region(self.synchronizing.lock()).await,
+            None => self.synchronizing.lock().await,
+        };
+        self.requested.store(true, Ordering::SeqCst);
+        let guard = SafepointGuard {
+            safepoints: self,
+            _lock: lock,
+        };
+        loop {
+            let changed = self.changed.notified();
+            let all_safe = threads
+                .iter()
+                .filter(|thread| requester.is_none_or(|requester| requester.id() != thread.id()))
+                .all(|thread| thread.safepoint_state().is_safe());
+            if all_safe {
+                break;
+            }
+            changed.await;
+        }
+        guard
+    }
+
+    /// Wait until no safepoint is requested or in progress.
+    async fn wait_for_release(&self) {
+        loop {
+            let changed = self.changed.notified();
+            if !self.is_requested() {
+                return;
+            }
+            changed.await;
+        }
+    }
+
+    /// Notify the requester of a safepoint that a thread may have become safe.
+    fn notify(&self) {
+        self.changed.notify_waiters();
+    }
+}
+
+/// Keeps the threads of a VM stopped at a safepoint; the threads resume when the guard is
+/// dropped.
+pub struct SafepointGuard<'a> {
+    safepoints: &'a Safepoints,
+    _lock: tokio::sync::MutexGuard<'a, ()>,
+}
+
+impl fmt::Debug for SafepointGuard<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SafepointGuard").finish_non_exhaustive()
+    }
+}
+
+impl Drop for SafepointGuard<'_> {
+    fn drop(&mut self) {
+        self.safepoints.requested.store(false, Ordering::SeqCst);
+        self.safepoints.notify();
+    }
+}
+
+/// Safepoint state of a thread.
+#[derive(Default)]
+pub(crate) struct ThreadSafepoint {
+    /// True when a handshake operation is pending; polled by the interpreter
+    handshake_pending: AtomicBool,
+    state: Mutex<SafepointState>,
+}
+
+/// State transitions of a thread that determine whether the thread is safe.
+#[derive(Default)]
+pub(crate) struct SafepointState {
+    /// Number of nested frames being interpreted
+    java_depth: usize,
+    /// Number of nested safe regions entered
+    safe_regions: usize,
+    /// True while stopped at a safepoint poll
+    at_safepoint: bool,
+    handshakes: Vec<HandshakeOperation>,
+}
+
+impl SafepointState {
+    /// Returns true if the thread is not interpreting Java code, is in a safe region or is stopped
+    /// at a safepoint poll.
+    pub(crate) fn is_safe(&self) -> bool {
+        self.java_depth == 0 || self.safe_regions > 0 || self.at_safepoint
+    }
+}
+
+impl fmt::Debug for ThreadSafepoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.lock();
+        f.debug_struct("ThreadSafepoint")
+            .field("java_depth", &state.java_depth)
+            .field("safe_regions", &state.safe_regions)
+            .field("at_safepoint", &state.at_safepoint)
+            .field("handshakes", &state.handshakes.len())
+            .finish()
+    }
+}
+
+impl ThreadSafepoint {
+    /// Lock the state; the state remains consistent if an operation panicked while holding the
+    /// lock, so a poisoned lock is recovered.
+    pub(crate) fn lock(&self) -> MutexGuard<'_, SafepointState> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Returns true if a handshake operation is pending.
+    #[inline]
+    pub(crate) fn is_handshake_pending(&self) -> bool {
+        self.handshake_pending.load(Ordering::Relaxed)
+    }
+
+    /// Run the pending handshake operations; the thread must be safe.
+    pub(crate) fn run_handshakes(&self, thread: &Thread, state: &mut SafepointState) {
+        self.handshake_pending.store(false, Ordering::Relaxed);
+        for operation in state.handshakes.drain(..) {
+            operation(thread);
+        }
+    }
+
+    /// Queue a handshake operation to be run when the thread is next safe, or run the operation
+    /// immediately if the thread is safe.
+    pub(crate) fn handshake(&self, thread: &Thread, operation: HandshakeOperation) {
+        let mut state = self.lock();
+        if state.is_safe() {
+            operation(thread);
+        } else {
+            state.handshakes.push(operation);
+            self.handshake_pending.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Apply a safe transition (one after which the thread is at least as safe as before) and
+    /// run any pending handshakes if the thread is now safe.
+    pub(crate) fn make_safe<F>(&self, safepoints: &Safepoints, thread: &Thread, transition: F)
+    where
+        F: FnOnce(&mut SafepointState),
+    {
+        let mut state = self.lock();
+        transition(&mut state);
+        if state.is_safe() {
+            self.run_handshakes(thread, &mut state);
+            safepoints.notify();
+        }
+    }
+
+    /// Get a guard that applies a transition when it is dropped, including when the future that
+    /// holds the guard is cancelled.  The transition does not wait for a safepoint in progress;
+    /// a cancelled thread does not continue executing the code it was cancelled in.
+    pub(crate) fn transition_on_drop<'a>(
+        &'a self,
+        safepoints: &'a Safepoints,
+        thread: &'a Thread,
+        transition: fn(&mut SafepointState),
+    ) -> TransitionGuard<'a> {
+        TransitionGuard {
+            safepoint: self,
+            safepoints,
+            thread,
+            transition: Some(transition),
+        }
+    }
+
+    /// Apply a transition that may make the thread unsafe.  A safe thread waits until no
+    /// safepoint is in progress before applying the transition so that it does not resume
+    /// executing Java code while the other threads are stopped.
+    pub(crate) async fn make_unsafe<F>(&self, safepoints: &Safepoints, transition: F)
+    where
+        F: Fn(&mut SafepointState),
+    {
+        loop {
+            {
+                let mut state = self.lock();
+                // The request flag is checked while holding the state lock; a requester sets the
+                // flag before inspecting the state, so either the requester observes the
+                // transition or this thread observes the request.
+                if !state.is_safe() || !safepoints.is_requested() {
+                    transition(&mut state);
+                    return;
+                }
+            }
+            safepoints.wait_for_release().await;
+        }
+    }
+}
+
+/// Applies a transition to the safepoint state of a thread when it is dropped; see
+/// [`ThreadSafepoint::transition_on_drop`].
+pub(crate) struct TransitionGuard<'a> {
+    safepoint: &'a ThreadSafepoint,
+    safepoints: &'a Safepoints,
+    thread: &'a Thread,
+    transition: Option<fn(&mut SafepointState)>,
+}
+
+impl TransitionGuard<'_> {
+    /// Drop the guard without applying the transition; e.g. when the transition was applied by
+    /// awaiting it.
+    pub(crate) fn disarm(mut self) {
+        self.transition = None;
+    }
+}
+
+impl Drop for TransitionGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(transition) = self.transition.take() {
+            self.safepoint
+                .make_safe(self.safepoints, self.thread, transition);
+        }
+    }
+}
+
+/// Transitions of the safepoint state of a thread.
+impl SafepointState {
+    pub(crate) fn enter_java(&mut self) {
+        self.java_depth += 1;
+    }
+
+    pub(crate) fn exit_java(&mut self) {
+        self.java_depth = self.java_depth.saturating_sub(1);
+    }
+
+    pub(crate) fn enter_safe_region(&mut self) {
+        self.safe_regions += 1;
+    }
+
+    pub(crate) fn exit_safe_region(&mut self) {
+        self.safe_regions = self.safe_regions.saturating_sub(1);
+    }
+
+    pub(crate) fn stop_at_safepoint(&mut self) {
+        self.at_safepoint = true;
+    }
+
+    pub(crate) fn resume_from_safepoint(&mut self) {
+        self.at_safepoint = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Result;
+    use ristretto_classloader::Value;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_is_safe() {
+        let mut state = SafepointState::default();
+        assert!(state.is_safe());
+        state.enter_java();
+        assert!(!state.is_safe());
+        state.enter_safe_region();
+        assert!(state.is_safe());
+        state.exit_safe_region();
+        assert!(!state.is_safe());
+        state.stop_at_safepoint();
+        assert!(state.is_safe());
+        state.resume_from_safepoint();
+        state.exit_java();
+        assert!(state.is_safe());
+    }
+
+    #[tokio::test]
+    async fn test_safepoint() -> Result<()> {
+        let (vm, thread) = crate::test::thread().await?;
+        let safepoint = vm.safepoint(None).await;
+        assert!(vm.safepoints().is_requested());
+        drop(safepoint);
+        assert!(!vm.safepoints().is_requested());
+
+        let safepoint = vm.safepoint(Some(&thread)).await;
+        assert!(thread.safepoint_requested());
+        drop(safepoint);
+        assert!(!thread.safepoint_requested());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_safepoint_waits_for_running_thread() -> Result<()> {
+        let (vm, thread) = crate::test::thread().await?;
+        thread.safepoint_state().enter_java();
+
+        let stopped = Arc::new(AtomicBool::new(false));
+        let requester = {
+            let vm = vm.clone();
+            let stopped = stopped.clone();
+            async move {
+                let _safepoint = vm.safepoint(None).await;
+                stopped.store(true, Ordering::SeqCst);
+            }
+        };
+        let poller = async {
+            while !thread.safepoint_requested() {
+                tokio::task::yield_now().await;
+            }
+            assert!(!stopped.load(Ordering::SeqCst));
+            thread.safepoint_poll().await;
+        };
+        tokio::join!(requester, poller);
+        assert!(stopped.load(Ordering::SeqCst));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_safepoint_timeout_releases_request() -> Result<()> {
+        let (vm, thread) = crate::test::thread().await?;
+        thread.safepoint_state().enter_java();
+        let result =
+            tokio::time::timeout(std::time::Duration::from_millis(10), vm.safepoint(None)).await;
+        assert!(result.is_err());
+        assert!(!vm.safepoints().is_requested());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handshake() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let id = thread.id();
+        assert_eq!(id, thread.handshake(Thread::id).await?);
+
+        thread.safepoint_state().enter_java();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let handshake = {
+            let calls = calls.clone();
+            thread.handshake(move |_thread| calls.fetch_add(1, Ordering::SeqCst))
+        };
+        let poller = async {
+            while !thread.safepoint_requested() {
+                tokio::task::yield_now().await;
+            }
+            thread.safepoint_poll().await;
+        };
+        let (result, ()) = tokio::join!(handshake, poller);
+        assert_eq!(0, result?);
+        assert_eq!(1, calls.load(Ordering::SeqCst));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_execution_restores_state() -> Result<()> {
+        let (_vm, thread) = crate::test::thread().await?;
+        let class = thread.class("java.lang.Thread").await?;
+        let method = class.try_get_method("sleep", "(J)V")?;
+        // Cancel the execution while the thread sleeps in a Java frame and a safe region
+        let result = tokio::time::timeout(
+            std::time::Duration::from_millis(50),
+            thread.execute(&class, &method, vec![Value::Long(60_000)]),
+        )
+        .await;
+        assert!(result.is_err());
+        let state = thread.safepoint_state();
+        assert_eq!(0, state.java_depth);
+        assert_eq!(0, state.safe_regions);
+        Ok(())
+    }
+}
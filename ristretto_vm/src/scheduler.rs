@@ -0,0 +1,227 @@
+use crate::Error::InternalError;
+use crate::Result;
+use std::any::Any;
+use std::fmt::Debug;
+use std::future::Future;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Future returned by a [`Scheduler`].
+pub type SchedulerFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
+/// Type erased blocking operation run by a [`Scheduler`].
+pub type BlockingFunction = Box<dyn FnOnce() -> Box<dyn Any + Send> + Send>;
+
+/// Scheduler used by the VM to sleep, yield and run blocking operations for Java threads; the
+/// scheduler determines how time passes and where blocking operations run.  The scheduler does
+/// not create threads: the VM runs Java code on the task or thread that invokes it, and Java
+/// threads are started as tokio tasks.  Sleeping and yielding either suspend the task
+/// ([`TokioScheduler`]), block the current thread ([`ThreadScheduler`]) or advance a virtual clock
+/// ([`DeterministicScheduler`]).
+pub trait Scheduler: Debug + Send + Sync {
+    /// Suspend the current Java thread for the duration.
+    fn sleep(&self, duration: Duration) -> SchedulerFuture<'_, ()>;
+
+    /// Yield the current Java thread so that other Java threads may run.
+    fn yield_now(&self) -> SchedulerFuture<'_, ()>;
+
+    /// Run a blocking operation (e.g. file or process IO) without blocking other Java
+    /// threads where the scheduler supports it.
+    ///
+    /// # Errors
+    /// if the blocking operation panics or is cancelled.
+    fn spawn_blocking(
+        &self,
+        function: BlockingFunction,
+    ) -> SchedulerFuture<'_, Result<Box<dyn Any + Send>>>;
+}
+
+/// Schedulers are compared by identity.
+impl PartialEq for dyn Scheduler {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::addr_eq(self, other)
+    }
+}
+
+/// Create the default scheduler; [`TokioScheduler`] on native targets and [`ThreadScheduler`] on
+/// wasm.  The [`DeterministicScheduler`] is never the default, as it does not wait when sleeping.
+#[must_use]
+pub fn default_scheduler() -> Arc<dyn Scheduler> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        Arc::new(TokioScheduler)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Arc::new(ThreadScheduler)
+    }
+}
+
+/// Run a blocking operation on the current thread, converting a panic into an error.
+fn run_blocking(function: BlockingFunction) -> Result<Box<dyn Any + Send>> {
+    catch_unwind(AssertUnwindSafe(function))
+        .map_err(|_| InternalError("blocking operation panicked".to_string()))
+}
+
+/// Scheduler that suspends the tokio task of a Java thread when sleeping or yielding; blocking
+/// operations are run on the tokio blocking thread pool.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Default)]
+pub struct TokioScheduler;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Scheduler for TokioScheduler {
+    fn sleep(&self, duration: Duration) -> SchedulerFuture<'_, ()> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+
+    fn yield_now(&self) -> SchedulerFuture<'_, ()> {
+        Box::pin(tokio::task::yield_now())
+    }
+
+    fn spawn_blocking(
+        &self,
+        function: BlockingFunction,
+    ) -> SchedulerFuture<'_, Result<Box<dyn Any + Send>>> {
+        Box::pin(async move {
+            tokio::task::spawn_blocking(function)
+                .await
+                .map_err(|error| InternalError(error.to_string()))
+        })
+    }
+}
+
+/// Scheduler that blocks the current thread when sleeping or yielding and runs blocking operations
+/// in place, e.g. on wasm where there is no tokio timer or blocking thread pool.  It does not
+/// change how Java threads are run; a sleeping Java thread blocks every task on the same thread.
+#[derive(Debug, Default)]
+pub struct ThreadScheduler;
+
+impl Scheduler for ThreadScheduler {
+    fn sleep(&self, duration: Duration) -> SchedulerFuture<'_, ()> {
+        Box::pin(async move { std::thread::sleep(duration) })
+    }
+
+    fn yield_now(&self) -> SchedulerFuture<'_, ()> {
+        Box::pin(async { std::thread::yield_now() })
+    }
+
+    fn spawn_blocking(
+        &self,
+        function: BlockingFunction,
+    ) -> SchedulerFuture<'_, Result<Box<dyn Any + Send>>> {
+        Box::pin(async move { run_blocking(function) })
+    }
+}
+
+/// Single threaded scheduler with a virtual clock; sleeping advances the clock instead of
+/// waiting and blocking operations run in place, so execution is reproducible.  Intended for
+/// tests and simulations; it must be configured explicitly.
+#[derive(Debug, Default)]
+pub struct DeterministicScheduler {
+    elapsed_nanos: AtomicU64,
+}
+
+impl DeterministicScheduler {
+    /// Create a new deterministic scheduler with the virtual clock at zero.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the time that has elapsed on the virtual clock.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_nanos.load(Ordering::Acquire))
+    }
+}
+
+impl Scheduler for DeterministicScheduler {
+    fn sleep(&self, duration: Duration) -> SchedulerFuture<'_, ()> {
+        Box::pin(async move {
+            let nanos = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+            let _ =
+                self.elapsed_nanos
+                    .fetch_update(Ordering::AcqRel, Ordering::Acquire, |elapsed| {
+                        Some(elapsed.saturating_add(nanos))
+                    });
+        })
+    }
+
+    fn yield_now(&self) -> SchedulerFuture<'_, ()> {
+        Box::pin(async {})
+    }
+
+    fn spawn_blocking(
+        &self,
+        function: BlockingFunction,
+    ) -> SchedulerFuture<'_, Result<Box<dyn Any + Send>>> {
+        Box::pin(async move { run_blocking(function) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blocking_value(value: i32) -> BlockingFunction {
+        Box::new(move || Box::new(value) as Box<dyn Any + Send>)
+    }
+
+    fn blocking_panic() -> BlockingFunction {
+        Box::new(|| panic!("test panic"))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn test_tokio_scheduler() -> Result<()> {
+        let scheduler = TokioScheduler;
+        scheduler.sleep(Duration::from_millis(1)).await;
+        scheduler.yield_now().await;
+        let value = scheduler.spawn_blocking(blocking_value(42)).await?;
+        assert_eq!(Some(&42), value.downcast_ref::<i32>());
+        assert!(scheduler.spawn_blocking(blocking_panic()).await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_thread_scheduler() -> Result<()> {
+        let scheduler = ThreadScheduler;
+        let start = std::time::Instant::now();
+        scheduler.sleep(Duration::from_millis(10)).await;
+        assert!(start.elapsed() >= Duration::from_millis(10));
+        scheduler.yield_now().await;
+        let value = scheduler.spawn_blocking(blocking_value(42)).await?;
+        assert_eq!(Some(&42), value.downcast_ref::<i32>());
+        assert!(scheduler.spawn_blocking(blocking_panic()).await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_deterministic_scheduler() -> Result<()> {
+        let scheduler = DeterministicScheduler::new();
+        assert_eq!(Duration::ZERO, scheduler.elapsed());
+        scheduler.sleep(Duration::from_secs(3600)).await;
+        scheduler.sleep(Duration::from_millis(5)).await;
+        scheduler.yield_now().await;
+        assert_eq!(
+            Duration::from_secs(3600) + Duration::from_millis(5),
+            scheduler.elapsed()
+        );
+        let value = scheduler.spawn_blocking(blocking_value(42)).await?;
+        assert_eq!(Some(&42), value.downcast_ref::<i32>());
+        assert!(scheduler.spawn_blocking(blocking_panic()).await.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_scheduler_eq() {
+        let scheduler = default_scheduler();
+        let other: Arc<dyn Scheduler> = Arc::new(DeterministicScheduler::new());
+        assert!(*scheduler == *scheduler.clone());
+        assert!(*scheduler != *other);
+    }
+}
@@ -1,7 +1,9 @@
 use crate::frame::Frame;
-use crate::{Class, ConfigurationBuilder, Result, Thread, VM};
+use crate::JavaError::UnsatisfiedLinkError;
+use crate::{Class, ConfigurationBuilder, Error, Result, Thread, VM};
+use ristretto_classfile::attributes::{Attribute, Instruction};
 use ristretto_classfile::{ClassFile, ConstantPool, MethodAccessFlags};
-use ristretto_classloader::ClassPath;
+use ristretto_classloader::{ClassPath, Value};
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -19,16 +21,31 @@ pub(crate) async fn thread() -> Result<(Arc<VM>, Arc<Thread>)> {
 
 /// Get a test class for testing.
 pub(crate) async fn class() -> Result<(Arc<VM>, Arc<Thread>, Arc<Class>)> {
+    class_with_code(Vec::new()).await
+}
+
+/// Get a test class whose `test` method has the given code.
+pub(crate) async fn class_with_code(
+    code: Vec<Instruction>,
+) -> Result<(Arc<VM>, Arc<Thread>, Arc<Class>)> {
     let (vm, thread) = thread().await?;
     let mut constant_pool = ConstantPool::default();
     let this_class = constant_pool.add_class("Test")?;
     let test_index = constant_pool.add_utf8("test")?;
     let test_descriptor_index = constant_pool.add_utf8("()V")?;
+    let code_index = constant_pool.add_utf8("Code")?;
     let test_method = ristretto_classfile::Method {
         access_flags: MethodAccessFlags::PUBLIC,
         name_index: test_index,
         descriptor_index: test_descriptor_index,
-        ..Default::default()
+        attributes: vec![Attribute::Code {
+            name_index: code_index,
+            max_stack: 0,
+            max_locals: 0,
+            code,
+            exception_table: Vec::new(),
+            attributes: Vec::new(),
+        }],
     };
 
     let class_file = ClassFile {
@@ -43,8 +60,38 @@ pub(crate) async fn class() -> Result<(Arc<VM>, Arc<Thread>, Arc<Class>)> {
 
 /// Get a test frame for testing.
 pub(crate) async fn frame() -> Result<(Arc<VM>, Arc<Thread>, Frame)> {
-    let (vm, thread, class) = class().await?;
+    frame_with_code(Vec::new()).await
+}
+
+/// Get a test frame whose method has the given code; the instruction at the program counter of
+/// the frame can be quickened if the code is not empty.
+pub(crate) async fn frame_with_code(
+    code: Vec<Instruction>,
+) -> Result<(Arc<VM>, Arc<Thread>, Frame)> {
+    let (vm, thread, class) = class_with_code(code).await?;
     let method = class.try_get_method("test", "()V")?;
     let frame = Frame::new(&Arc::downgrade(&thread), &class, &method);
     Ok((vm, thread, frame))
 }
+
+/// Assert that a native method result is an `UnsatisfiedLinkError` for the given method.
+#[track_caller]
+pub(crate) fn assert_unsatisfied_link(
+    result: Result<Option<Value>>,
+    class_name: &str,
+    method_name: &str,
+    method_descriptor: &str,
+) {
+    match result {
+        Err(Error::JavaError(UnsatisfiedLinkError {
+            class_name: actual_class_name,
+            method_name: actual_method_name,
+            method_descriptor: actual_method_descriptor,
+        })) => {
+            assert_eq!(class_name, actual_class_name);
+            assert_eq!(method_name, actual_method_name);
+            assert_eq!(method_descriptor, actual_method_descriptor);
+        }
+        result => panic!("expected UnsatisfiedLinkError; found {result:?}"),
+    }
+}
@@ -1,19 +1,25 @@
+use crate::java_object::JavaObject;
 use crate::parameters::Parameters;
 use crate::rust_value::{process_values, RustValue};
+use crate::safepoint::{SafepointState, Safepoints, ThreadSafepoint};
 use crate::Error::{InternalError, UnsupportedClassFileVersion};
+use crate::JavaError::{
+    ClassCircularityError, LinkageError, NoClassDefFoundError, UnsatisfiedLinkError,
+};
 use crate::{Frame, Result, VM};
 use async_recursion::async_recursion;
-use ristretto_classloader::Error::MethodNotFound;
-use ristretto_classloader::{Class, Method, Object, Value};
-use std::sync::{Arc, Weak};
+use ristretto_classloader::Error::ClassNotFound;
+use ristretto_classloader::{Class, ClassLoader, ClassState, Method, Object, Reference, Value};
+use std::any::Any;
+use std::future::Future;
+use std::sync::{Arc, MutexGuard, Weak};
 use tokio::sync::RwLock;
-use tracing::{debug, event_enabled, Level};
+use tracing::{debug, event_enabled, warn, Level};
 
 /// A thread is a single sequential flow of control within a program. It has its own call stack
 /// and program counter.
 ///
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-2.html#jvms-2.5.2>
-#[expect(clippy::struct_field_names)]
 #[derive(Debug)]
 pub struct Thread {
     id: u64,
@@ -22,6 +28,8 @@ pub struct Thread {
     name: Arc<RwLock<String>>,
     java_object: Arc<RwLock<Value>>,
     frames: Arc<RwLock<Vec<Arc<Frame>>>>,
+    safepoints: Arc<Safepoints>,
+    safepoint: ThreadSafepoint,
 }
 
 impl Thread {
@@ -34,6 +42,7 @@ impl Thread {
         let id = vm.next_thread_id()?;
         let name = format!("Thread-{id}");
         let java_object = Value::Object(None);
+        let safepoints = vm.safepoints().clone();
         let thread = Arc::new_cyclic(|thread| Thread {
             id,
             vm: vm_ref,
@@ -41,6 +50,8 @@ impl Thread {
             name: Arc::new(RwLock::new(name)),
             java_object: Arc::new(RwLock::new(java_object)),
             frames: Arc::new(RwLock::new(Vec::new())),
+            safepoints,
+            safepoint: ThreadSafepoint::default(),
         });
         Ok(thread)
     }
@@ -115,22 +126,8 @@ impl Thread {
     #[async_recursion(?Send)]
     pub(crate) async fn class<S: AsRef<str>>(&self, class_name: S) -> Result<Arc<Class>> {
         let class_name = class_name.as_ref();
-        let class_load_result = {
-            let vm = self.vm()?;
-            let class_loader_lock = vm.class_loader();
-            let class_loader = class_loader_lock.read().await;
-            class_loader.load_with_status(class_name).await
-        };
-
-        let class = match class_load_result {
-            Ok((class, previously_loaded)) => {
-                // Determine if the class has already been loaded.  If the class has already been loaded,
-                // return the class. Otherwise, the class must be initialized.
-                if previously_loaded {
-                    return Ok(class);
-                }
-                class
-            }
+        let class = match self.load_class(class_name).await {
+            Ok(class) => class,
             Err(error) => {
                 if class_name.starts_with('[')
                     || [
@@ -144,89 +141,272 @@ impl Thread {
                     self.register_class(array_class.clone()).await?;
                     array_class
                 } else {
-                    return Err(error.into());
+                    return Err(error);
                 }
             }
         };
 
-        let classes = self.prepare_class_initialization(&class).await?;
-        for current_class in classes {
-            if let Some(class_initializer) = current_class.class_initializer() {
-                // Execute the class initializer on the current thread.
-                self.execute(&current_class, &class_initializer, Vec::<Value>::new())
-                    .await?;
-            }
-        }
-        Ok(class)
+        self.initialize_class(class).await
     }
-    /// Prepare class initialization.
+
+    /// Get a class with a `java.lang.ClassLoader` object, or with the VM class loader for a `null`
+    /// class loader.
     ///
     /// # Errors
-    /// if the class cannot be resolved
-    async fn prepare_class_initialization(&self, class: &Arc<Class>) -> Result<Vec<Arc<Class>>> {
+    /// if the class cannot be loaded
+    pub(crate) async fn class_with_loader<S: AsRef<str>>(
+        &self,
+        class_loader: Option<&Object>,
+        class_name: S,
+    ) -> Result<Arc<Class>> {
+        let class_name = class_name.as_ref();
+        // Array classes are created by the VM rather than loaded by a class loader
+        let Some(class_loader_object) = class_loader.filter(|_| !class_name.starts_with('['))
+        else {
+            return self.class(class_name).await;
+        };
         let vm = self.vm()?;
+        let class_loader = vm.java_class_loader(class_loader_object).await?;
+        let class = self
+            .load_class_with_loader(class_loader_object, &class_loader, class_name)
+            .await?;
+        self.initialize_class(class).await
+    }
+
+    /// Load a class without linking or initializing it.  The class is loaded by the class loader
+    /// that defined the class of the current frame, which is the VM class loader unless the class
+    /// was defined by a `java.lang.ClassLoader` object.
+    ///
+    /// # Errors
+    /// if the class cannot be loaded
+    async fn load_class(&self, class_name: &str) -> Result<Arc<Class>> {
+        let current_class = match self.current_frame().await {
+            Ok(frame) => Some(frame.class().clone()),
+            Err(_) => None,
+        };
+        self.load_class_for(current_class.as_ref(), class_name)
+            .await
+    }
+
+    /// Load a class referenced by a class without linking or initializing it; the class is loaded
+    /// by the class loader that defined the referencing class.
+    ///
+    /// # Errors
+    /// if the class cannot be loaded
+    async fn load_class_for(
+        &self,
+        referencing_class: Option<&Arc<Class>>,
+        class_name: &str,
+    ) -> Result<Arc<Class>> {
+        let vm = self.vm()?;
+        if let Some((class_loader_object, class_loader)) =
+            referencing_class.and_then(|class| vm.defining_class_loader(class))
+        {
+            return self
+                .load_class_with_loader(&class_loader_object, &class_loader, class_name)
+                .await;
+        }
         let class_loader_lock = vm.class_loader();
         let class_loader = class_loader_lock.read().await;
-        let mut classes = Vec::new();
-        let mut index = 0;
-        classes.push(class.clone());
+        Ok(class_loader.load(class_name).await?)
+    }
 
-        while index < classes.len() {
-            let Some(current_class) = classes.get(index) else {
-                break;
-            };
-            let current_class = current_class.clone();
+    /// Load a class with the class loader backing a `java.lang.ClassLoader` object, which
+    /// delegates to the class loaders of its parents.  Classes that none of the class loaders have
+    /// loaded are loaded by delegating to `ClassLoader.loadClass()`, which may define the class
+    /// with the Java class loader or one of its parents.
+    ///
+    /// # Errors
+    /// if the class cannot be loaded
+    async fn load_class_with_loader(
+        &self,
+        class_loader_object: &Object,
+        class_loader: &ClassLoader,
+        class_name: &str,
+    ) -> Result<Arc<Class>> {
+        match class_loader.load(class_name).await {
+            Ok(class) => return Ok(class),
+            Err(ClassNotFound(_)) => {}
+            Err(error) => return Err(error.into()),
+        }
 
-            if current_class.class_file().version > *vm.java_class_file_version() {
-                return Err(UnsupportedClassFileVersion(
-                    current_class.class_file().version.major(),
-                ));
-            }
+        let vm = self.vm()?;
+        let class_loader_class = self.class("java/lang/ClassLoader").await?;
+        let load_class = class_loader_class
+            .try_get_method("loadClass", "(Ljava/lang/String;)Ljava/lang/Class;")?;
+        let java_class_name = class_name.replace('/', ".").to_object(&vm).await?;
+        let result = self
+            .execute(
+                &class_loader_class,
+                &load_class,
+                vec![Value::from(class_loader_object.clone()), java_class_name],
+            )
+            .await?;
+        let Some(Value::Object(Some(Reference::Object(class_object)))) = result else {
+            return Err(ClassNotFound(class_name.to_string()).into());
+        };
 
-            let mut interfaces = Vec::new();
-            for interface_index in &current_class.class_file().interfaces {
-                let interface_name = current_class
-                    .constant_pool()
-                    .try_get_class(*interface_index)?;
-                let (interface_class, previously_loaded) =
-                    class_loader.load_with_status(interface_name).await?;
-                interfaces.push(interface_class.clone());
-                if !previously_loaded && !classes.contains(&interface_class) {
-                    classes.push(interface_class);
-                }
-            }
-            current_class.set_interfaces(interfaces)?;
+        // The class may have been defined by a class loader that is not a parent of the class
+        // loader, so the class is found with the class loader that defined it
+        let defining_class_loader = match class_object.value("classLoader")? {
+            Value::Object(Some(Reference::Object(object))) => vm.java_class_loader(&object).await?,
+            _ => vm.class_loader().read().await.clone(),
+        };
+        Ok(defining_class_loader.load(class_name).await?)
+    }
 
-            // If the current class is java.lang.Object, skip the parent class logic since Object is
-            // the root class.
-            if current_class.name() == "java/lang/Object" {
-                index += 1;
-                continue;
-            }
+    /// Define a class that is not loaded from the class path (e.g. a class read from memory).
+    /// The class is registered with the VM class loader and then initialized.
+    ///
+    /// # Errors
+    /// if the class cannot be registered or initialized
+    pub(crate) async fn define_class(&self, class: Arc<Class>) -> Result<Arc<Class>> {
+        self.register_class(class.clone()).await?;
+        self.initialize_class(class).await
+    }
 
-            let super_class_index = current_class.class_file().super_class;
-            let super_class_name = if super_class_index == 0 {
-                "java/lang/Object"
-            } else {
-                let constant_pool = current_class.constant_pool();
-                constant_pool.try_get_class(super_class_index)?
-            };
+    /// Link a class by resolving, verifying and preparing its super class and interfaces, which
+    /// are linked first.  A class is only linked once; a failure is recorded so that later
+    /// attempts fail with a `LinkageError` instead of repeating the work.
+    ///
+    /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-5.html#jvms-5.4>
+    ///
+    /// # Errors
+    /// if the class, its super classes or its interfaces cannot be linked
+    #[async_recursion(?Send)]
+    async fn link_class(&self, class: &Arc<Class>, linking: &mut Vec<String>) -> Result<()> {
+        match class.state()? {
+            ClassState::Loaded => {}
+            ClassState::LinkageFailed(message) => return Err(LinkageError(message).into()),
+            _ => return Ok(()),
+        }
+        let class_name = class.name().to_string();
+        if linking.contains(&class_name) {
+            return Err(ClassCircularityError(class_name.replace('/', ".")).into());
+        }
 
-            let (super_class, previously_loaded) =
-                class_loader.load_with_status(super_class_name).await?;
-            current_class.set_parent(Some(super_class.clone()))?;
-            if !previously_loaded && !classes.contains(&super_class) {
-                classes.push(super_class);
-            }
+        linking.push(class_name);
+        let result = self.link_super_classes(class, linking).await;
+        linking.pop();
+        let next = match &result {
+            Ok(()) => ClassState::Linked,
+            Err(error) => ClassState::LinkageFailed(error.to_string()),
+        };
+        // If another thread linked the class concurrently, its result is kept
+        class.transition(&ClassState::Loaded, next)?;
+        result
+    }
+
+    /// Resolve and link the super class and interfaces of a class.
+    ///
+    /// # Errors
+    /// if the class version is not supported or a super class or interface cannot be linked
+    async fn link_super_classes(
+        &self,
+        class: &Arc<Class>,
+        linking: &mut Vec<String>,
+    ) -> Result<()> {
+        let vm = self.vm()?;
+        if class.class_file().version > *vm.java_class_file_version() {
+            return Err(UnsupportedClassFileVersion(
+                class.class_file().version.major(),
+            ));
+        }
+
+        let mut interfaces = Vec::new();
+        for interface_index in &class.class_file().interfaces {
+            let interface_name = class.constant_pool().try_get_class(*interface_index)?;
+            let interface_class = self.load_class_for(Some(class), interface_name).await?;
+            self.link_class(&interface_class, linking).await?;
+            interfaces.push(interface_class);
+        }
+        class.set_interfaces(interfaces)?;
+
+        // If the class is java.lang.Object, skip the parent class logic since Object is the root
+        // class.
+        if class.name() == "java/lang/Object" {
+            return Ok(());
+        }
+
+        let super_class_index = class.class_file().super_class;
+        let super_class_name = if super_class_index == 0 {
+            "java/lang/Object"
+        } else {
+            class.constant_pool().try_get_class(super_class_index)?
+        };
+        let super_class = self.load_class_for(Some(class), super_class_name).await?;
+        self.link_class(&super_class, linking).await?;
+        class.set_parent(Some(super_class))?;
+        Ok(())
+    }
 
-            index += 1;
+    /// Initialize a class after linking it; the super class and interfaces of the class are
+    /// initialized before the class initializer is executed.  A class that is being initialized
+    /// by this thread is returned as is, which allows the class initializer to use its own class;
+    /// a class that is being initialized by another thread is returned once the initialization
+    /// completes.  A failure is recorded so that later attempts fail with a `NoClassDefFoundError`.
+    ///
+    /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-5.html#jvms-5.5>
+    ///
+    /// # Errors
+    /// if the class cannot be linked or initialized
+    #[async_recursion(?Send)]
+    pub(crate) async fn initialize_class(&self, class: Arc<Class>) -> Result<Arc<Class>> {
+        self.link_class(&class, &mut Vec::new()).await?;
+        loop {
+            match class.state()? {
+                ClassState::Linked => {
+                    if class.transition(&ClassState::Linked, ClassState::Initializing(self.id))? {
+                        break;
+                    }
+                    // Another thread started initializing the class
+                }
+                ClassState::Initializing(thread_id) if thread_id != self.id => {
+                    self.safe_region(class.wait_for_initialization(self.id))
+                        .await?;
+                }
+                ClassState::InitializationFailed(_) => {
+                    let class_name = class.name().replace('/', ".");
+                    return Err(NoClassDefFoundError(format!(
+                        "Could not initialize class {class_name}"
+                    ))
+                    .into());
+                }
+                _ => return Ok(class),
+            }
         }
 
-        // Classes are discovered from the top of the hierarchy to the bottom.  However, the class
-        // initialization order is from the bottom to the top.  Reverse the classes so that the
-        // classes are initialized from the bottom to the top.
-        classes.reverse();
-        Ok(classes)
+        let result = self.execute_class_initializers(&class).await;
+        let next = match &result {
+            Ok(()) => ClassState::Initialized,
+            Err(error) => ClassState::InitializationFailed(error.to_string()),
+        };
+        class.transition(&ClassState::Initializing(self.id), next)?;
+        result.map(|()| class)
+    }
+
+    /// Initialize the super class and interfaces of a class and execute its class initializer.
+    ///
+    /// # Errors
+    /// if a super class, interface or the class initializer fails
+    async fn execute_class_initializers(&self, class: &Arc<Class>) -> Result<()> {
+        if let Some(super_class) = class.parent()? {
+            self.initialize_class(super_class).await?;
+        }
+        for interface in class.interfaces()? {
+            self.initialize_class(interface).await?;
+        }
+        if let Some(class_initializer) = class.class_initializer() {
+            // Execute the class initializer on the current thread.
+            self.execute(class, &class_initializer, Vec::<Value>::new())
+                .await?;
+        }
+        #[cfg(feature = "race-detector")]
+        self.vm()?.race_detector().release(
+            self.id,
+            crate::race_detector::SyncObject::Class(class.name().to_string()),
+        );
+        Ok(())
     }
 
     /// Register a class.
@@ -264,6 +444,12 @@ impl Thread {
             debug!("execute: {class_name}.{method_name}{method_descriptor} {access_flags}");
         }
 
+        // The monitor of a synchronized method is released when the guard is dropped, including
+        // on early returns
+        #[cfg(feature = "race-detector")]
+        let _monitor = crate::race_detector::method_monitor(class, method, &parameters)
+            .map(|monitor| vm.race_detector().hold(self.id, monitor));
+
         let method_registry = vm.method_registry();
         let rust_method = method_registry.method(class_name, method_name, method_descriptor);
 
@@ -272,10 +458,33 @@ impl Thread {
             let Some(thread) = self.thread.upgrade() else {
                 return Err(InternalError("Call stack is not available".to_string()));
             };
-            let result = rust_method(thread, parameters).await;
+            rust_method.record_call();
+            let result = rust_method.method()(thread, parameters).await;
+            // Rust methods that are not implemented throw an error for their own method; errors
+            // thrown by the methods that a Rust method calls are recorded where they are thrown.
+            if let Err(crate::Error::JavaError(UnsatisfiedLinkError {
+                class_name: error_class_name,
+                method_name: error_method_name,
+                method_descriptor: error_method_descriptor,
+            })) = &result
+            {
+                if error_class_name == class_name
+                    && error_method_name == method_name
+                    && error_method_descriptor == method_descriptor
+                {
+                    record_unsatisfied_link(&vm, class_name, method_name, method_descriptor);
+                }
+            }
             (result, false)
         } else if method.is_native() {
-            return Err(MethodNotFound {
+            record_unsatisfied_link(&vm, class_name, method_name, method_descriptor);
+            if let Some(feature) = method_registry.disabled_feature(class_name) {
+                warn!(
+                    "native method not linked: {class_name}.{method_name}{method_descriptor} \
+                    (requires the \"{feature}\" feature)"
+                );
+            }
+            return Err(UnsatisfiedLinkError {
                 class_name: class_name.to_string(),
                 method_name: method_name.to_string(),
                 method_descriptor: method_descriptor.to_string(),
@@ -291,7 +500,17 @@ impl Thread {
                 let mut frames = self.frames.write().await;
                 frames.push(frame.clone());
             }
+            self.safepoint
+                .make_unsafe(&self.safepoints, SafepointState::enter_java)
+                .await;
+            // The thread leaves Java code when the frame completes or its execution is cancelled
+            let exit_java = self.safepoint.transition_on_drop(
+                &self.safepoints,
+                self,
+                SafepointState::exit_java,
+            );
             let result = frame.execute(parameters).await;
+            drop(exit_java);
             (result, true)
         };
 
@@ -374,12 +593,116 @@ impl Thread {
         self.execute(&class, &constructor, parameters).await?;
         Ok(object)
     }
+
+    /// Run a blocking operation for a native method using the configured
+    /// [scheduler](crate::Scheduler). The calling Java thread waits for the operation to complete;
+    /// with the tokio scheduler the operation runs on the blocking thread pool so other Java threads
+    /// continue to execute.
+    ///
+    /// Native methods are not marked as blocking when they are registered; native method futures
+    /// are not `Send`, so a native method calls this for the part of its work that blocks, e.g.
+    /// writing to a file or waiting for a process to exit.
+    ///
+    /// # Errors
+    /// if the blocking operation panics or is cancelled.
+    pub async fn spawn_blocking<F, T>(&self, function: F) -> Result<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let scheduler = self.vm()?.configuration().scheduler().clone();
+        let value = self
+            .safe_region(scheduler.spawn_blocking(Box::new(move || {
+                Box::new(function()) as Box<dyn Any + Send>
+            })))
+            .await?;
+        let value = value
+            .downcast::<T>()
+            .map_err(|_| InternalError("invalid blocking operation result".to_string()))?;
+        Ok(*value)
+    }
+
+    /// Get the safepoint state of the thread.
+    pub(crate) fn safepoint_state(&self) -> MutexGuard<'_, SafepointState> {
+        self.safepoint.lock()
+    }
+
+    /// Returns true if the thread should stop at the next safepoint poll; i.e. a safepoint or a
+    /// handshake with this thread is requested.
+    #[inline]
+    pub(crate) fn safepoint_requested(&self) -> bool {
+        self.safepoints.is_requested() || self.safepoint.is_handshake_pending()
+    }
+
+    /// Stop at a safepoint poll; runs pending handshakes and, if a safepoint is in progress, waits
+    /// until the safepoint is released.
+    pub(crate) async fn safepoint_poll(&self) {
+        self.safepoint
+            .make_safe(&self.safepoints, self, SafepointState::stop_at_safepoint);
+        self.safepoint
+            .make_unsafe(&self.safepoints, SafepointState::resume_from_safepoint)
+            .await;
+    }
+
+    /// Await a future in a safe region; e.g. while sleeping or waiting for a blocking operation.
+    /// The thread does not interpret Java code while in a safe region, so safepoints and
+    /// handshakes do not wait for the future to complete.
+    pub async fn safe_region<F: Future>(&self, future: F) -> F::Output {
+        self.safepoint
+            .make_safe(&self.safepoints, self, SafepointState::enter_safe_region);
+        let exit_safe_region = self.safepoint.transition_on_drop(
+            &self.safepoints,
+            self,
+            SafepointState::exit_safe_region,
+        );
+        let output = future.await;
+        self.safepoint
+            .make_unsafe(&self.safepoints, SafepointState::exit_safe_region)
+            .await;
+        exit_safe_region.disarm();
+        output
+    }
+
+    /// Run an operation for this thread while the thread is safe.  If the thread is safe the
+    /// operation runs immediately; otherwise the operation runs when the thread reaches its next
+    /// safepoint poll or becomes safe.  The operation must not wait for the thread or request a
+    /// safepoint.
+    ///
+    /// # Errors
+    /// if the thread is dropped before the operation runs.
+    pub async fn handshake<F, T>(&self, operation: F) -> Result<T>
+    where
+        F: FnOnce(&Thread) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let (sender, receiver) = tokio::sync::oneshot::channel();
+        self.safepoint.handshake(
+            self,
+            Box::new(move |thread| {
+                let _ = sender.send(operation(thread));
+            }),
+        );
+        receiver
+            .await
+            .map_err(|_| InternalError("handshake cancelled".to_string()))
+    }
+}
+
+/// Record a call to a native method that is not linked to a Rust method; panics instead when the
+/// VM is configured to panic on unsatisfied links.
+fn record_unsatisfied_link(vm: &VM, class_name: &str, method_name: &str, method_descriptor: &str) {
+    vm.method_registry()
+        .record_unlinked(class_name, method_name, method_descriptor);
+    if vm.configuration().panic_on_unsatisfied_link() {
+        panic!("not yet implemented: {class_name}.{method_name}{method_descriptor}");
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::ConfigurationBuilder;
+    use ristretto_classfile::MethodAccessFlags;
     use ristretto_classloader::ClassPath;
     use std::path::PathBuf;
 
@@ -413,6 +736,67 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_execute_unsatisfied_link() -> Result<()> {
+        let vm = test_vm().await?;
+        let thread = vm.new_thread()?;
+        let class = thread.class("java.lang.Class").await?;
+        let method = class.try_get_method("isRecord0", "()Z")?;
+        let result = thread
+            .execute(&class, &method, vec![Value::Object(None)])
+            .await;
+        assert!(matches!(
+            result,
+            Err(crate::Error::JavaError(UnsatisfiedLinkError { .. }))
+        ));
+        let statistics = vm.method_registry().statistics();
+        assert!(statistics
+            .unlinked_methods
+            .contains(&("java/lang/Class.isRecord0()Z".to_string(), 1)));
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "not yet implemented: java/lang/Class.isRecord0()Z")]
+    async fn test_execute_unsatisfied_link_panic() {
+        let configuration = ConfigurationBuilder::new()
+            .class_path(classes_jar_class_path())
+            .panic_on_unsatisfied_link()
+            .build()
+            .expect("configuration");
+        let vm = VM::new(configuration).await.expect("vm");
+        let thread = vm.new_thread().expect("thread");
+        let class = thread.class("java.lang.Class").await.expect("class");
+        let method = class.try_get_method("isRecord0", "()Z").expect("isRecord0");
+        let _ = thread
+            .execute(&class, &method, vec![Value::Object(None)])
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_execute_unregistered_native_method() -> Result<()> {
+        let vm = test_vm().await?;
+        let thread = vm.new_thread()?;
+        let class_file = ristretto_classfile::ClassFileBuilder::new("Unlinked")
+            .public()
+            .method(
+                MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC | MethodAccessFlags::NATIVE,
+                "run",
+                "()V",
+                Vec::new(),
+            )
+            .build()?;
+        let class = Arc::new(Class::from(class_file)?);
+        thread.register_class(class.clone()).await?;
+        let method = class.try_get_method("run", "()V")?;
+        let result = thread.execute(&class, &method, Vec::<Value>::new()).await;
+        assert!(matches!(
+            result,
+            Err(crate::Error::JavaError(UnsatisfiedLinkError { .. }))
+        ));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_constants_class() -> Result<()> {
         let vm = test_vm().await?;
@@ -438,6 +822,94 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_class_state() -> Result<()> {
+        let vm = test_vm().await?;
+        let thread = vm.new_thread()?;
+        let class = thread.load_class("Child").await?;
+        assert_eq!(ClassState::Loaded, class.state()?);
+        thread.link_class(&class, &mut Vec::new()).await?;
+        assert_eq!(ClassState::Linked, class.state()?);
+        let parent = class.parent()?.expect("Child parent");
+        assert_eq!(ClassState::Linked, parent.state()?);
+
+        let class = thread.class("Child").await?;
+        assert_eq!(ClassState::Initialized, class.state()?);
+        assert_eq!(ClassState::Initialized, parent.state()?);
+        Ok(())
+    }
+
+    fn class_with_super_class(class_name: &str, super_class_name: &str) -> Result<Arc<Class>> {
+        let mut constant_pool = ristretto_classfile::ConstantPool::default();
+        let this_class = constant_pool.add_class(class_name)?;
+        let super_class = constant_pool.add_class(super_class_name)?;
+        let class_file = ristretto_classfile::ClassFile {
+            version: ristretto_classfile::Version::Java8 { minor: 0 },
+            constant_pool,
+            this_class,
+            super_class,
+            ..Default::default()
+        };
+        Ok(Arc::new(Class::from(class_file)?))
+    }
+
+    #[tokio::test]
+    async fn test_linkage_error_replay() -> Result<()> {
+        let vm = test_vm().await?;
+        let thread = vm.new_thread()?;
+        let class = class_with_super_class("Broken", "Missing")?;
+        let result = thread.define_class(class.clone()).await;
+        assert!(matches!(
+            result,
+            Err(crate::Error::ClassLoaderError(
+                ristretto_classloader::Error::ClassNotFound(_)
+            ))
+        ));
+        assert!(matches!(class.state()?, ClassState::LinkageFailed(_)));
+
+        let result = thread.class("Broken").await;
+        assert!(matches!(
+            result,
+            Err(crate::Error::JavaError(LinkageError(message))) if message == "Class not found: Missing"
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_class_circularity_error() -> Result<()> {
+        let vm = test_vm().await?;
+        let thread = vm.new_thread()?;
+        let first = class_with_super_class("First", "Second")?;
+        let second = class_with_super_class("Second", "First")?;
+        thread.register_class(second).await?;
+        let result = thread.define_class(first).await;
+        assert!(matches!(
+            result,
+            Err(crate::Error::JavaError(ClassCircularityError(name))) if name == "First"
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_spawn_blocking() -> Result<()> {
+        let vm = test_vm().await?;
+        let thread = vm.new_thread()?;
+        let value = thread.spawn_blocking(|| 40 + 2).await?;
+        assert_eq!(42, value);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_spawn_blocking_panic() -> Result<()> {
+        let vm = test_vm().await?;
+        let thread = vm.new_thread()?;
+        let result = thread
+            .spawn_blocking(|| -> i32 { panic!("blocking operation failed") })
+            .await;
+        assert!(matches!(result, Err(InternalError(_))));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_new_object_integer() -> Result<()> {
         let vm = test_vm().await?;
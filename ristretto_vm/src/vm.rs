@@ -1,24 +1,55 @@
 use crate::java_object::JavaObject;
+use crate::module_access::ModuleAccess;
+use crate::native_access::NativeAccess;
 use crate::native_methods::MethodRegistry;
+use crate::perf_data::PerfData;
+#[cfg(feature = "race-detector")]
+use crate::race_detector::RaceDetector;
 use crate::rust_value::RustValue;
+use crate::safepoint::{SafepointGuard, Safepoints};
 use crate::thread::Thread;
 use crate::Error::InternalError;
 use crate::{Configuration, ConfigurationBuilder, Result};
 use dashmap::DashMap;
-use ristretto_classfile::{Version, JAVA_PREVIEW_MINOR_VERSION};
-use ristretto_classloader::manifest::MAIN_CLASS;
+use ristretto_classfile::{ClassFile, Version, JAVA_PREVIEW_MINOR_VERSION};
+use ristretto_classloader::manifest::{MAIN_CLASS, START_CLASS};
 use ristretto_classloader::{
-    runtime, Class, ClassLoader, ClassPath, ClassPathEntry, ConcurrentVec, Object, Reference, Value,
+    runtime, Class, ClassLoader, ClassPath, ClassPathEntry, ConcurrentVec, Object, Reference,
+    Value, WeakObject,
 };
 use std::collections::HashMap;
+use std::io::Cursor;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Weak};
-use tokio::sync::RwLock;
+use tokio::sync::{OnceCell, RwLock};
 use tracing::debug;
 
 const JAVA_8: Version = Version::Java8 { minor: 0 };
 const JAVA_17: Version = Version::Java17 { minor: 0 };
+/// Number of reference levels expanded by [`VM::dump_object`]
+const DUMP_OBJECT_DEPTH: usize = 8;
+
+/// The Java runtime, class loader and main class resolved from a configuration
+#[derive(Debug)]
+pub(crate) struct Resolution {
+    pub(crate) java_home: PathBuf,
+    pub(crate) java_version: String,
+    pub(crate) java_major_version: u16,
+    pub(crate) java_class_file_version: Version,
+    pub(crate) class_loader: ClassLoader,
+    pub(crate) main_class: Option<String>,
+    /// Main class defined from the configured class bytes
+    pub(crate) defined_main_class: Option<Arc<Class>>,
+}
+
+/// The class loader backing a `java.lang.ClassLoader` object.  The object is held weakly so that
+/// the VM does not keep Java class loaders alive.
+#[derive(Clone, Debug)]
+struct JavaClassLoader {
+    object: WeakObject,
+    class_loader: ClassLoader,
+}
 
 /// Java Virtual Machine
 #[derive(Debug)]
@@ -34,6 +65,19 @@ pub struct VM {
     method_registry: MethodRegistry,
     next_thread_id: AtomicU64,
     threads: DashMap<u64, Arc<Thread>>,
+    safepoints: Arc<Safepoints>,
+    module_access: OnceCell<ModuleAccess>,
+    #[cfg(all(unix, not(target_arch = "wasm32")))]
+    attach_listener_thread: OnceCell<Arc<Thread>>,
+    native_access: NativeAccess,
+    /// Class loaders backing the `java.lang.ClassLoader` objects, keyed by the object identity
+    java_class_loaders: DashMap<usize, JavaClassLoader>,
+    /// The identities of the `java.lang.ClassLoader` objects that defined classes, keyed by the
+    /// class identity
+    defining_class_loaders: DashMap<usize, (Weak<Class>, usize)>,
+    perf_data: PerfData,
+    #[cfg(feature = "race-detector")]
+    race_detector: RaceDetector,
 }
 
 /// VM
@@ -48,14 +92,82 @@ impl VM {
     /// # Errors
     /// if the VM cannot be created
     pub async fn new(configuration: Configuration) -> Result<Arc<Self>> {
+        let Resolution {
+            java_home,
+            java_version,
+            java_major_version,
+            java_class_file_version,
+            class_loader,
+            main_class,
+            defined_main_class,
+        } = Self::resolve(&configuration).await?;
+
+        // TODO: set use_optimizations based on the environment (e.g. -Xdebug / -Xint).
+        // The bespoke method optimizations should likely be removed if/when a JIT is implemented.
+        let use_optimizations = true;
+        let mut method_registry = MethodRegistry::new(java_major_version, use_optimizations);
+        method_registry.initialize();
+        method_registry.apply_overrides(configuration.method_overrides());
+
+        let native_access = NativeAccess::new(&configuration);
+        let vm = Arc::new_cyclic(|vm| VM {
+            vm: vm.clone(),
+            configuration,
+            class_loader: Arc::new(RwLock::new(class_loader)),
+            main_class,
+            java_home,
+            java_version,
+            java_major_version,
+            java_class_file_version,
+            method_registry,
+            next_thread_id: AtomicU64::new(1),
+            threads: DashMap::new(),
+            safepoints: Arc::new(Safepoints::default()),
+            module_access: OnceCell::new(),
+            #[cfg(all(unix, not(target_arch = "wasm32")))]
+            attach_listener_thread: OnceCell::new(),
+            native_access,
+            java_class_loaders: DashMap::new(),
+            defining_class_loaders: DashMap::new(),
+            perf_data: PerfData::new(),
+            #[cfg(feature = "race-detector")]
+            race_detector: RaceDetector::default(),
+        });
+        vm.initialize().await?;
+        if let Some(class) = defined_main_class {
+            let thread = vm.primordial_thread()?;
+            thread.define_class(class).await?;
+        }
+        Ok(vm)
+    }
+
+    /// Create a new VM with the default configuration
+    ///
+    /// # Errors
+    /// if the VM cannot be created
+    pub async fn default() -> Result<Arc<VM>> {
+        let configuration = ConfigurationBuilder::default().build()?;
+        VM::new(configuration).await
+    }
+
+    /// Resolve the Java runtime, class loader and main class for a configuration without
+    /// initializing a VM.
+    ///
+    /// # Errors
+    /// if the Java runtime cannot be loaded or the main class cannot be determined
+    pub(crate) async fn resolve(configuration: &Configuration) -> Result<Resolution> {
         let (java_home, java_version, bootstrap_class_loader) =
             if let Some(java_version) = configuration.java_version() {
                 let (java_home, java_version, boostrap_class_loader) =
-                    runtime::version_class_loader(java_version).await?;
+                    runtime::version_class_loader_with_progress(
+                        java_version,
+                        configuration.download_progress(),
+                    )
+                    .await?;
                 (java_home, java_version, boostrap_class_loader)
             } else if let Some(java_home) = configuration.java_home() {
                 let (java_home, java_version, boostrap_class_loader) =
-                    runtime::home_class_loader(java_home).await?;
+                    runtime::home_class_loader_from(java_home).await?;
                 (java_home, java_version, boostrap_class_loader)
             } else {
                 return Err(InternalError(
@@ -83,30 +195,59 @@ impl VM {
         // <JAVA_HOME>/jre/lib/ext directory or any other directory specified by the java.ext.dirs
         // system property
 
-        let class_path = configuration.class_path().clone();
+        let class_path = configuration
+            .class_path()
+            .with_manifest_class_path()
+            .await?;
         let mut system_class_loader = ClassLoader::new("system", class_path);
         system_class_loader.set_parent(Some(bootstrap_class_loader.clone()));
         let mut main_class_name = configuration.main_class().cloned();
 
+        // A main class defined from bytes takes its name from the class file
+        let defined_main_class = match configuration.main_class_bytes() {
+            Some(bytes) => {
+                let class = Class::from(ClassFile::from_bytes(&mut Cursor::new(bytes.to_vec()))?)?;
+                main_class_name = Some(class.name().replace('/', "."));
+                Some(Arc::new(class))
+            }
+            None => None,
+        };
+
         let class_loader = if let Some(jar) = configuration.jar() {
             let path = jar.to_string_lossy();
             let jar_class_path = ClassPath::from(path);
-            let mut jar_class_loader = ClassLoader::new("jar", jar_class_path);
-            jar_class_loader.set_parent(Some(system_class_loader.clone()));
-
-            // If the main class is not specified, try to get it from the jar manifest file
-            if main_class_name.is_none() {
-                for class_path_entry in jar_class_loader.class_path().iter() {
-                    if let ClassPathEntry::Jar(jar) = class_path_entry {
-                        let manifest = jar.manifest().await?;
-                        if let Some(jar_main_class) = manifest.attribute(MAIN_CLASS) {
-                            main_class_name = Some(jar_main_class.to_string());
-                            break;
-                        };
-                    }
+            let mut class_path_entries = Vec::new();
+            for class_path_entry in jar_class_path.iter() {
+                class_path_entries.push(class_path_entry.clone());
+                let ClassPathEntry::Jar(jar) = class_path_entry else {
+                    continue;
+                };
+                let manifest = match jar.manifest().await {
+                    Ok(manifest) => manifest,
+                    Err(error) if main_class_name.is_none() => return Err(error.into()),
+                    Err(_) => continue,
+                };
+
+                // Spring Boot executable jars declare their classes and libraries as nested class
+                // path entries, and the application main class as the start class
+                let nested_class_path = jar.nested_class_path(&manifest).await?;
+                let jar_main_class = if nested_class_path.is_empty() {
+                    manifest.attribute(MAIN_CLASS)
+                } else {
+                    manifest.attribute(START_CLASS)
+                };
+                class_path_entries.extend(nested_class_path);
+
+                // If the main class is not specified, try to get it from the jar manifest file
+                if main_class_name.is_none() {
+                    main_class_name = jar_main_class.map(ToString::to_string);
                 }
             }
-
+            let jar_class_path = ClassPath::new(class_path_entries)
+                .with_manifest_class_path()
+                .await?;
+            let mut jar_class_loader = ClassLoader::new("jar", jar_class_path);
+            jar_class_loader.set_parent(Some(system_class_loader.clone()));
             jar_class_loader
         } else {
             system_class_loader.clone()
@@ -120,36 +261,15 @@ impl VM {
             None
         };
 
-        // TODO: set use_optimizations based on the environment (e.g. -Xdebug / -Xint).
-        // The bespoke method optimizations should likely be removed if/when a JIT is implemented.
-        let use_optimizations = true;
-        let mut method_registry = MethodRegistry::new(java_major_version, use_optimizations);
-        method_registry.initialize();
-
-        let vm = Arc::new_cyclic(|vm| VM {
-            vm: vm.clone(),
-            configuration,
-            class_loader: Arc::new(RwLock::new(class_loader)),
-            main_class,
+        Ok(Resolution {
             java_home,
             java_version,
             java_major_version,
             java_class_file_version,
-            method_registry,
-            next_thread_id: AtomicU64::new(1),
-            threads: DashMap::new(),
-        });
-        vm.initialize().await?;
-        Ok(vm)
-    }
-
-    /// Create a new VM with the default configuration
-    ///
-    /// # Errors
-    /// if the VM cannot be created
-    pub async fn default() -> Result<Arc<VM>> {
-        let configuration = ConfigurationBuilder::default().build()?;
-        VM::new(configuration).await
+            class_loader,
+            main_class,
+            defined_main_class,
+        })
     }
 
     /// Get the configuration
@@ -163,6 +283,133 @@ impl VM {
         self.class_loader.clone()
     }
 
+    /// Get the module access checker; the module graph is read from the `module-info` classes of
+    /// the bootstrap class path on first use.
+    ///
+    /// # Errors
+    /// if a `module-info` class cannot be read
+    pub(crate) async fn module_access(&self) -> Result<&ModuleAccess> {
+        self.module_access
+            .get_or_try_init(|| async {
+                let class_loader = self.class_loader.read().await;
+                let mut bootstrap_class_loader = &*class_loader;
+                while let Some(parent) = bootstrap_class_loader.parent() {
+                    bootstrap_class_loader = parent;
+                }
+                let graph = bootstrap_class_loader.class_path().module_graph().await?;
+                Ok(ModuleAccess::new(
+                    graph,
+                    &self.configuration,
+                    self.java_major_version,
+                ))
+            })
+            .await
+    }
+
+    /// Get the native access checker, which records the calls of restricted methods such as
+    /// `System.loadLibrary` and enforces `--enable-native-access`
+    #[must_use]
+    pub fn native_access(&self) -> &NativeAccess {
+        &self.native_access
+    }
+
+    /// Get the class loader backing a `java.lang.ClassLoader` object.  The class loader and the
+    /// class loaders of its Java parents are created on first use; the parent of a Java class
+    /// loader without a parent is the VM class loader.
+    ///
+    /// # Errors
+    /// if the parent of a Java class loader cannot be read
+    pub(crate) async fn java_class_loader(&self, object: &Object) -> Result<ClassLoader> {
+        let mut objects = Vec::new();
+        let mut parent = None;
+        let mut current = Some(object.clone());
+        while let Some(object) = current {
+            if let Some(java_class_loader) = self.java_class_loaders.get(&object.identity().addr())
+            {
+                parent = Some(java_class_loader.class_loader.clone());
+                break;
+            }
+            current = match object.value("parent")? {
+                Value::Object(Some(Reference::Object(parent))) => Some(parent),
+                _ => None,
+            };
+            objects.push(object);
+        }
+        let mut class_loader = match parent {
+            Some(class_loader) => class_loader,
+            None => self.class_loader.read().await.clone(),
+        };
+        if objects.is_empty() {
+            return Ok(class_loader);
+        }
+
+        // Create the class loaders from the outermost parent to the requested class loader
+        self.remove_unreachable_class_loaders();
+        for object in objects.into_iter().rev() {
+            let name = match object.value("name") {
+                Ok(name @ Value::Object(Some(_))) => name.try_into()?,
+                _ => object.class().name().replace('/', "."),
+            };
+            let java_class_loader = self
+                .java_class_loaders
+                .entry(object.identity().addr())
+                .or_insert_with(|| {
+                    let mut child = ClassLoader::new(name, ClassPath::new(Vec::new()));
+                    child.set_parent(Some(class_loader.clone()));
+                    JavaClassLoader {
+                        object: object.downgrade(),
+                        class_loader: child,
+                    }
+                });
+            class_loader = java_class_loader.class_loader.clone();
+        }
+        Ok(class_loader)
+    }
+
+    /// Remove the class loaders of `java.lang.ClassLoader` objects that are no longer reachable,
+    /// and the classes that they defined.
+    fn remove_unreachable_class_loaders(&self) {
+        self.java_class_loaders
+            .retain(|_, java_class_loader| java_class_loader.object.upgrade().is_some());
+        self.defining_class_loaders.retain(|_, (class, identity)| {
+            class.strong_count() > 0 && self.java_class_loaders.contains_key(identity)
+        });
+    }
+
+    /// Record the `java.lang.ClassLoader` object that defined a class.
+    pub(crate) fn set_java_class_loader_object(&self, class: &Arc<Class>, object: &Object) {
+        self.defining_class_loaders.insert(
+            Arc::as_ptr(class).addr(),
+            (Arc::downgrade(class), object.identity().addr()),
+        );
+    }
+
+    /// Get the `java.lang.ClassLoader` object that defined a class and its backing class loader;
+    /// `None` is returned for classes defined by the VM class loader.
+    pub(crate) fn defining_class_loader(
+        &self,
+        class: &Arc<Class>,
+    ) -> Option<(Object, ClassLoader)> {
+        if self.defining_class_loaders.is_empty() {
+            return None;
+        }
+        // The identity of a class is not reused while the weak reference to the class is held
+        let identity = self
+            .defining_class_loaders
+            .get(&Arc::as_ptr(class).addr())
+            .map(|defining_class_loader| defining_class_loader.1)?;
+        let java_class_loader = self.java_class_loaders.get(&identity)?;
+        let object = java_class_loader.object.upgrade()?;
+        Some((object, java_class_loader.class_loader.clone()))
+    }
+
+    /// Get the `java.lang.ClassLoader` object that defined a class; `None` is returned for classes
+    /// defined by the VM class loader.
+    pub(crate) fn java_class_loader_object(&self, class: &Arc<Class>) -> Option<Object> {
+        self.defining_class_loader(class)
+            .map(|(object, _class_loader)| object)
+    }
+
     /// Get the main class
     #[must_use]
     pub fn main_class(&self) -> Option<&String> {
@@ -225,12 +472,41 @@ impl VM {
             .collect()
     }
 
+    /// Get the safepoint coordinator of the VM threads
+    #[must_use]
+    pub fn safepoints(&self) -> &Arc<Safepoints> {
+        &self.safepoints
+    }
+
+    /// Stop every Java thread at a safepoint; the threads resume when the returned guard is
+    /// dropped.  When called from a Java thread (e.g. by a native method), the calling thread must
+    /// be passed as the requester so that the safepoint does not wait for it.
+    pub async fn safepoint(&self, requester: Option<&Thread>) -> SafepointGuard<'_> {
+        let threads = self.threads();
+        self.safepoints.synchronize(&threads, requester).await
+    }
+
+    /// Get the performance counters created by the Java runtime through the `Perf` intrinsics
+    #[must_use]
+    pub fn perf_data(&self) -> &PerfData {
+        &self.perf_data
+    }
+
+    /// Get the data race detector
+    #[cfg(feature = "race-detector")]
+    #[must_use]
+    pub fn race_detector(&self) -> &RaceDetector {
+        &self.race_detector
+    }
+
     /// Create a new thread
     ///
     /// # Errors
     /// if the thread cannot be created
     pub(crate) fn new_thread(&self) -> Result<Arc<Thread>> {
         let thread = Thread::new(&self.vm)?;
+        #[cfg(feature = "race-detector")]
+        self.race_detector.thread_started(thread.id());
         self.threads.insert(thread.id(), thread.clone());
         Ok(thread)
     }
@@ -285,20 +561,35 @@ impl VM {
     /// if the primordial thread cannot be initialized
     async fn initialize_primordial_thread(&self) -> Result<()> {
         let thread = self.new_thread()?;
-        let thread_id = i64::try_from(thread.id())?;
         let thread_group = thread
             .object("java.lang.ThreadGroup", "", Vec::<Value>::new())
             .await?;
+        let java_thread = self.java_thread(&thread, thread_group, false).await?;
+        thread.set_java_object(java_thread).await;
+        Ok(())
+    }
 
+    /// Create the `java.lang.Thread` object of a thread that is started by the VM.
+    ///
+    /// # Errors
+    /// if the thread object cannot be created
+    async fn java_thread(
+        &self,
+        thread: &Thread,
+        thread_group: Value,
+        daemon: bool,
+    ) -> Result<Value> {
+        let thread_id = i64::try_from(thread.id())?;
+        let daemon = Value::Int(i32::from(daemon));
         let java_version = self.java_class_file_version();
 
         // The internal structure of Thread changed in Java 19
         let new_thread = if java_version <= &JAVA_17 {
             let thread_class = self.class("java.lang.Thread").await?;
             let new_thread = Object::new(thread_class)?;
-            new_thread.set_value("daemon", Value::Int(0))?;
+            new_thread.set_value("daemon", daemon)?;
             new_thread.set_value("eetop", Value::Long(0))?;
-            new_thread.set_value("group", thread_group.clone())?;
+            new_thread.set_value("group", thread_group)?;
             new_thread.set_value("priority", Value::Int(5))?;
             new_thread.set_value("stackSize", Value::Long(0))?;
             new_thread.set_value("threadStatus", Value::Int(4))?; // Runnable
@@ -307,8 +598,8 @@ impl VM {
         } else {
             let field_holder_class = self.class("java.lang.Thread$FieldHolder").await?;
             let field_holder = Object::new(field_holder_class)?;
-            field_holder.set_value("daemon", Value::Int(0))?;
-            field_holder.set_value("group", thread_group.clone())?;
+            field_holder.set_value("daemon", daemon)?;
+            field_holder.set_value("group", thread_group)?;
             field_holder.set_value("priority", Value::Int(5))?;
             field_holder.set_value("stackSize", Value::Long(0))?;
             field_holder.set_value("threadStatus", Value::Int(4))?; // Runnable
@@ -323,16 +614,44 @@ impl VM {
             new_thread.set_value("tid", Value::Long(thread_id))?;
             Value::from(new_thread)
         };
-        thread.set_java_object(new_thread).await;
+        Ok(new_thread)
+    }
 
-        Ok(())
+    /// Get the "Attach Listener" thread that executes the Java code of attach requests, e.g. the
+    /// `agentmain` method of an agent.  As in HotSpot, this is a daemon thread with its own frames,
+    /// so that the requests do not run on a thread that is executing application code.  The
+    /// thread is created in the thread group of the primordial thread on first use.
+    ///
+    /// # Errors
+    /// if the thread cannot be created
+    #[cfg(all(unix, not(target_arch = "wasm32")))]
+    pub(crate) async fn attach_listener_thread(&self) -> Result<Arc<Thread>> {
+        let thread = self
+            .attach_listener_thread
+            .get_or_try_init(|| async {
+                let primordial_thread: Object =
+                    self.primordial_thread()?.java_object().await.try_into()?;
+                let thread_group = if self.java_class_file_version() <= &JAVA_17 {
+                    primordial_thread.value("group")?
+                } else {
+                    let field_holder: Object = primordial_thread.value("holder")?.try_into()?;
+                    field_holder.value("group")?
+                };
+                let thread = self.new_thread()?;
+                thread.set_name("Attach Listener").await;
+                let java_thread = self.java_thread(&thread, thread_group, true).await?;
+                thread.set_java_object(java_thread).await;
+                Ok::<_, crate::Error>(thread)
+            })
+            .await?;
+        Ok(thread.clone())
     }
 
     /// Get the primordial thread
     ///
     /// # Errors
     /// if the primordial thread cannot be found
-    fn primordial_thread(&self) -> Result<Arc<Thread>> {
+    pub(crate) fn primordial_thread(&self) -> Result<Arc<Thread>> {
         let thread = self.threads.get(&1).map(|entry| entry.value().clone());
         let Some(thread) = thread else {
             return Err(InternalError("Primordial thread not found".into()));
@@ -456,12 +775,24 @@ impl VM {
         let thread = self.primordial_thread()?;
         thread.object(class_name, descriptor, parameters).await
     }
+
+    /// Render a value and the object graph it references as JSON for debugging.  Cycles are
+    /// rendered as references to previously rendered objects.
+    ///
+    /// # Errors
+    /// if the value cannot be rendered
+    pub fn dump_object(&self, value: &Value) -> Result<String> {
+        let json = value.to_debug_json(DUMP_OBJECT_DEPTH)?;
+        Ok(json)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::configuration::ConfigurationBuilder;
+    use crate::{MethodOverride, Parameters, Thread};
+    use async_recursion::async_recursion;
     use ristretto_classloader::{ClassPath, DEFAULT_JAVA_VERSION};
     use std::path::PathBuf;
 
@@ -524,6 +855,26 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_vm_set_main_class_bytes() -> Result<()> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let class_file = cargo_manifest
+            .join("..")
+            .join("classes")
+            .join("HelloWorld.class");
+        let bytes = std::fs::read(class_file).expect("class file");
+        let configuration = ConfigurationBuilder::new()
+            .main_class_bytes(bytes)
+            .build()?;
+        let vm = VM::new(configuration).await?;
+        let main_class = vm.main_class().expect("main class");
+        assert_eq!("HelloWorld", main_class);
+        let class = vm.class(main_class).await?;
+        assert_eq!("HelloWorld", class.name());
+        assert!(class.main_method().is_some());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_vm_set_jar_with_main_class() -> Result<()> {
         let classes_jar_path = classes_jar_path();
@@ -625,4 +976,72 @@ mod tests {
         assert_eq!("foo", value);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_dump_object() -> Result<()> {
+        let vm = test_vm().await?;
+        let object = vm.object("java.lang.Integer", "I", vec![42]).await?;
+        let json = vm.dump_object(&object)?;
+        assert!(json.contains(r#""class": "java/lang/Integer""#));
+        assert!(json.contains(r#""value": 42"#));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_method_override_unsupported_operation() -> Result<()> {
+        let configuration = ConfigurationBuilder::new()
+            .class_path(classes_jar_class_path())
+            .method_override(
+                "java.lang.Integer",
+                "parseInt",
+                "(Ljava/lang/String;)I",
+                MethodOverride::UnsupportedOperation,
+            )
+            .build()?;
+        let vm = VM::new(configuration).await?;
+        let result = vm
+            .invoke(
+                "java.lang.Integer",
+                "parseInt",
+                "(Ljava/lang/String;)I",
+                vec!["42"],
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(crate::Error::JavaError(
+                crate::JavaError::UnsupportedOperationException(_)
+            ))
+        ));
+        Ok(())
+    }
+
+    #[async_recursion(?Send)]
+    async fn parse_int(_thread: Arc<Thread>, _parameters: Parameters) -> Result<Option<Value>> {
+        Ok(Some(Value::Int(7)))
+    }
+
+    #[tokio::test]
+    async fn test_method_override_method() -> Result<()> {
+        let configuration = ConfigurationBuilder::new()
+            .class_path(classes_jar_class_path())
+            .method_override(
+                "java.lang.Integer",
+                "parseInt",
+                "(Ljava/lang/String;)I",
+                MethodOverride::Method(parse_int),
+            )
+            .build()?;
+        let vm = VM::new(configuration).await?;
+        let value = vm
+            .try_invoke(
+                "java.lang.Integer",
+                "parseInt",
+                "(Ljava/lang/String;)I",
+                vec!["42"],
+            )
+            .await?;
+        assert_eq!(Value::Int(7), value);
+        Ok(())
+    }
 }